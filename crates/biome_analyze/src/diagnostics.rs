@@ -139,6 +139,15 @@ impl AnalyzerDiagnostic {
     }
 }
 
+/// Emitted when the analysis of a file is stopped because it exceeded its deadline
+#[derive(Debug, Diagnostic, Clone)]
+#[diagnostic(
+    category = "internalError/timeout",
+    severity = Error,
+    message = "The analysis of this file was stopped because it took too long, some diagnostics could be missing."
+)]
+pub struct TimeoutDiagnostic;
+
 #[derive(Debug, Diagnostic, Clone)]
 #[diagnostic(severity = Warning)]
 pub struct SuppressionDiagnostic {
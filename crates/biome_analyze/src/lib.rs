@@ -11,6 +11,7 @@ pub mod context;
 mod diagnostics;
 mod matcher;
 pub mod options;
+pub mod profiling;
 mod query;
 mod registry;
 mod rule;
@@ -27,7 +28,9 @@ pub use crate::categories::{
     ActionCategory, RefactorKind, RuleCategories, RuleCategoriesBuilder, RuleCategory,
     SourceActionKind,
 };
-pub use crate::diagnostics::{AnalyzerDiagnostic, RuleError, SuppressionDiagnostic};
+pub use crate::diagnostics::{
+    AnalyzerDiagnostic, RuleError, SuppressionDiagnostic, TimeoutDiagnostic,
+};
 pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
 pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
 pub use crate::query::{AddVisitor, QueryKey, QueryMatch, Queryable};
@@ -36,8 +39,8 @@ pub use crate::registry::{
     RuleRegistry, RuleRegistryBuilder, RuleSuppressions,
 };
 pub use crate::rule::{
-    CategoryLanguage, FixKind, GroupCategory, GroupLanguage, Rule, RuleAction, RuleDiagnostic,
-    RuleGroup, RuleMeta, RuleMetadata, RuleSource, RuleSourceKind, SuppressAction,
+    CategoryLanguage, FileEdit, FixKind, GroupCategory, GroupLanguage, Rule, RuleAction,
+    RuleDiagnostic, RuleGroup, RuleMeta, RuleMetadata, RuleSource, RuleSourceKind, SuppressAction,
 };
 pub use crate::services::{FromServices, MissingServicesDiagnostic, ServiceBag};
 pub use crate::signals::{
@@ -55,6 +58,7 @@ use biome_rowan::{
     AstNode, BatchMutation, Direction, Language, SyntaxElement, SyntaxToken, TextLen, TextRange,
     TextSize, TokenAtOffset, TriviaPiece, TriviaPieceKind, WalkEvent,
 };
+use biome_suppression::ExpirationDate;
 
 /// The analyzer is the main entry point into the `biome_analyze` infrastructure.
 /// Its role is to run a collection of [Visitor]s over a syntax tree, with each
@@ -162,6 +166,14 @@ where
                 return Some(br);
             }
 
+            if ctx.options.is_past_deadline() {
+                let signal = DiagnosticSignal::new(|| TimeoutDiagnostic);
+                return match (emit_signal)(&signal) {
+                    ControlFlow::Break(br) => Some(br),
+                    ControlFlow::Continue(()) => None,
+                };
+            }
+
             // Finish all the active visitors, this is executed outside of the
             // phase runner as it needs mutable access to the service bag (the
             // runner borrows the services for the entire phase)
@@ -275,6 +287,9 @@ where
                     continue;
                 }
             };
+            if self.options.is_past_deadline() {
+                return ControlFlow::Continue(());
+            }
             // If this is a node event pass it to the visitors for this phase
             for visitor in self.visitors.iter_mut() {
                 let ctx = VisitorContext {
@@ -300,6 +315,10 @@ where
     /// processed and cached in `run_initial_phase`
     fn run_remaining_phases(mut self) -> ControlFlow<Break> {
         for event in self.root.syntax().preorder() {
+            if self.options.is_past_deadline() {
+                return ControlFlow::Continue(());
+            }
+
             // Run all the active visitors for the phase on the event
             for visitor in self.visitors.iter_mut() {
                 let ctx = VisitorContext {
@@ -489,12 +508,30 @@ where
                 (self.emit_signal)(&signal)?;
             }
 
+            if let SuppressionKind::Expires(date) = kind {
+                // An expired suppression keeps suppressing, but is reported
+                if date.is_past() && range_match(self.range, range) {
+                    let signal = DiagnosticSignal::new(move || {
+                        SuppressionDiagnostic::new(
+                            category!("suppressions/expired"),
+                            range,
+                            format_args!("This suppression comment expired on {date}."),
+                        )
+                        .with_severity(Severity::Error)
+                    });
+
+                    (self.emit_signal)(&signal)?;
+                }
+                continue;
+            }
+
             let (rule, instance) = match kind {
                 SuppressionKind::Everything => (None, None),
                 SuppressionKind::Rule(rule) => (Some(rule), None),
                 SuppressionKind::RuleInstance(rule, instance) => (Some(rule), Some(instance)),
                 SuppressionKind::MaybeLegacy(rule) => (Some(rule), None),
                 SuppressionKind::Deprecated => (None, None),
+                SuppressionKind::Expires(_) => continue,
             };
 
             if let Some(rule) = rule {
@@ -683,6 +720,7 @@ fn create_suppression_comment_action<L: Language>(
         }
         .to_owned(),
         rule_name: None,
+        file_edits: Vec::new(),
     })
 }
 
@@ -719,6 +757,8 @@ pub enum SuppressionKind<'a> {
     MaybeLegacy(&'a str),
     /// `rome-ignore` is legacy
     Deprecated,
+    /// The expiration date of the suppression eg. `// biome-ignore lint/style/useWhile: reason [expires:2025-12-31]`
+    Expires(ExpirationDate),
 }
 
 fn update_suppression<L: Language>(
@@ -773,6 +813,7 @@ fn update_suppression<L: Language>(
         }
         .to_owned(),
         mutation,
+        file_edits: Vec::new(),
     })
 }
 
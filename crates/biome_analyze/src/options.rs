@@ -1,9 +1,11 @@
 use rustc_hash::FxHashMap;
 
+use crate::profiling::RuleProfiler;
 use crate::{FixKind, Rule, RuleKey};
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// A convenient new type data structure to store the options that belong to a rule
 #[derive(Debug)]
@@ -67,6 +69,12 @@ pub struct AnalyzerConfiguration {
 
     /// Indicates the type of runtime or transformation used for interpreting JSX.
     pub jsx_runtime: Option<JsxRuntime>,
+
+    /// The custom ARIA roles, mapped to the names of the roles that they behave like
+    pub aria_roles: Vec<(String, String)>,
+
+    /// The custom `aria-*` properties that the accessibility rules should accept
+    pub aria_properties: Vec<String>,
 }
 
 /// A set of information useful to the analyzer infrastructure
@@ -77,9 +85,24 @@ pub struct AnalyzerOptions {
 
     /// The file that is being analyzed
     pub file_path: PathBuf,
+
+    /// When set, the analysis stops once this instant is reached.
+    ///
+    /// The deadline is checked between the nodes visited by the analyzer:
+    /// a rule that doesn't return, for example because of a catastrophic backtracking, isn't interrupted.
+    pub deadline: Option<Instant>,
+
+    /// When set, the time spent running each rule is recorded by this profiler
+    pub profiler: Option<RuleProfiler>,
 }
 
 impl AnalyzerOptions {
+    /// Returns `true` if the analysis has exceeded its deadline
+    pub fn is_past_deadline(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
     pub fn globals(&self) -> Vec<&str> {
         self.configuration
             .globals
@@ -0,0 +1,74 @@
+//! Measures the time spent running each rule.
+//!
+//! The measurements are only recorded by the analyses that are given a [RuleProfiler]
+//! in their [AnalyzerOptions](crate::AnalyzerOptions), so that the analyzer doesn't pay
+//! for the instrumentation by default, and the measurements of unrelated analyses don't mix.
+
+use crate::RuleKey;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The time spent running a rule
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RuleMeasurement {
+    pub rule: RuleKey,
+    /// The total time spent running the rule
+    pub total: Duration,
+    /// The number of nodes the rule was run on
+    pub count: u64,
+}
+
+/// Records the time spent running the rules.
+///
+/// The profiler is a handle: its clones share the same measurements,
+/// which are dropped with the last handle.
+#[derive(Clone, Debug, Default)]
+pub struct RuleProfiler {
+    measurements: Arc<Mutex<BTreeMap<RuleKey, RuleMeasurement>>>,
+}
+
+impl RuleProfiler {
+    /// Adds `total` and `count` to the measurement of `rule`
+    fn record(&self, rule: RuleKey, total: Duration, count: u64) {
+        let mut measurements = self.measurements.lock().unwrap();
+        let measurement = measurements.entry(rule).or_insert_with(|| RuleMeasurement {
+            rule,
+            total: Duration::ZERO,
+            count: 0,
+        });
+        measurement.total += total;
+        measurement.count += count;
+    }
+
+    /// Returns the measurements recorded so far, from the most expensive rule to the cheapest,
+    /// and clears them.
+    pub fn take_measurements(&self) -> Vec<RuleMeasurement> {
+        let measurements = std::mem::take(&mut *self.measurements.lock().unwrap());
+        let mut measurements: Vec<_> = measurements.into_values().collect();
+        measurements.sort_by(|a, b| b.total.cmp(&a.total).then(a.rule.cmp(&b.rule)));
+        measurements
+    }
+
+    /// Returns a timer that records the time spent running `rule` when it's dropped
+    pub(crate) fn start(&self, rule: RuleKey) -> RuleTimer<'_> {
+        RuleTimer {
+            profiler: self,
+            rule,
+            start: Instant::now(),
+        }
+    }
+}
+
+/// Records the time elapsed between its creation and its drop for the given rule
+pub(crate) struct RuleTimer<'a> {
+    profiler: &'a RuleProfiler,
+    rule: RuleKey,
+    start: Instant,
+}
+
+impl Drop for RuleTimer<'_> {
+    fn drop(&mut self) {
+        self.profiler.record(self.rule, self.start.elapsed(), 1);
+    }
+}
@@ -387,6 +387,12 @@ impl<L: Language + Default> RegistryRule<L> {
         where
             R: Rule<Options: Default, Query: Queryable<Output: Clone>> + 'static,
         {
+            let _timer = params
+                .options
+                .profiler
+                .as_ref()
+                .map(|profiler| profiler.start(RuleKey::rule::<R>()));
+
             if let Some(node) = params.query.downcast_ref::<SyntaxNode<RuleLanguage<R>>>() {
                 if state.suppressions.inner.contains(node) {
                     return Ok(());
@@ -4,7 +4,8 @@ use crate::{
     context::RuleContext,
     registry::{RuleLanguage, RuleRoot},
     rule::Rule,
-    AnalyzerDiagnostic, AnalyzerOptions, Queryable, RuleGroup, ServiceBag, SuppressionAction,
+    AnalyzerDiagnostic, AnalyzerOptions, FileEdit, Queryable, RuleGroup, ServiceBag,
+    SuppressionAction,
 };
 use biome_console::MarkupBuf;
 use biome_diagnostics::{advice::CodeSuggestionAdvice, Applicability, CodeSuggestion, Error};
@@ -111,6 +112,8 @@ pub struct AnalyzerAction<L: Language> {
     pub applicability: Applicability,
     pub message: MarkupBuf,
     pub mutation: BatchMutation<L>,
+    /// The edits of the other files of the project, see [FileEdit]
+    pub file_edits: Vec<FileEdit>,
 }
 
 impl<L: Language> AnalyzerAction<L> {
@@ -156,6 +159,7 @@ impl<L: Language> From<AnalyzerAction<L>> for CodeSuggestionItem {
                 suggestion,
                 labels: vec![],
             },
+            file_edits: action.file_edits,
         }
     }
 }
@@ -220,6 +224,7 @@ pub struct CodeSuggestionItem {
     pub category: ActionCategory,
     pub suggestion: CodeSuggestion,
     pub rule_name: Option<(&'static str, &'static str)>,
+    pub file_edits: Vec<FileEdit>,
 }
 
 impl<L: Language> Iterator for CodeActionIter<L> {
@@ -399,6 +404,7 @@ where
                     category: action.category,
                     mutation: action.mutation,
                     message: action.message,
+                    file_edits: action.file_edits,
                 });
             };
             if let Some(text_range) = R::text_range(&ctx, &self.state) {
@@ -411,6 +417,7 @@ where
                         applicability: Applicability::Always,
                         mutation: suppression_action.mutation,
                         message: suppression_action.message,
+                        file_edits: Vec::new(),
                     };
                     actions.push(action);
                 }
@@ -0,0 +1,216 @@
+//! Accessibility checks shared by the analyzers of the markup languages.
+//!
+//! The checks are written against the [MarkupElement] trait,
+//! so that the JSX rules and the HTML rules report the same issues.
+
+use crate::markup::{MarkupAttribute, MarkupAttributeValue, MarkupElement};
+use crate::AriaProperties;
+
+/// An element that requires a text alternative
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextAlternativeElement {
+    Object,
+    Img,
+    Area,
+    Input,
+}
+
+impl TextAlternativeElement {
+    /// The attribute that provides the text alternative of the element
+    pub const fn attribute_name(self) -> &'static str {
+        match self {
+            Self::Object => "title",
+            Self::Img | Self::Area | Self::Input => "alt",
+        }
+    }
+}
+
+/// Returns the kind of `element` if the element requires a text alternative, but doesn't provide one
+///
+/// The text alternative is provided through the `alt` attribute, the `title` attribute of `<object>`,
+/// the `aria-label` attribute or the `aria-labelledby` attribute.
+/// The elements hidden with `aria-hidden` don't require a text alternative.
+pub fn missing_text_alternative<E: MarkupElement>(element: &E) -> Option<TextAlternativeElement> {
+    if element.is_custom_component() {
+        return None;
+    }
+
+    let kind = match element.name()?.as_str() {
+        "object" => TextAlternativeElement::Object,
+        "img" => TextAlternativeElement::Img,
+        "area" => TextAlternativeElement::Area,
+        "input" if has_type_image_attribute(element) => TextAlternativeElement::Input,
+        _ => return None,
+    };
+
+    let has_text_alternative = match kind {
+        TextAlternativeElement::Object => {
+            has_valid_label(element, "title") || element.has_accessible_child()
+        }
+        _ => has_valid_alt_text(element),
+    };
+
+    if has_text_alternative
+        || has_valid_label(element, "aria-label")
+        || has_valid_label(element, "aria-labelledby")
+        || is_aria_hidden(element)
+    {
+        return None;
+    }
+
+    Some(kind)
+}
+
+/// Returns the `aria-*` attributes of `element` that aren't valid ARIA attributes, or custom attributes of `properties`
+pub fn invalid_aria_attributes<E: MarkupElement>(
+    element: &E,
+    properties: &AriaProperties,
+) -> Vec<E::Attribute> {
+    if element.is_custom_component() {
+        return Vec::new();
+    }
+
+    element
+        .attributes()
+        .into_iter()
+        .filter(|attribute| {
+            attribute.name().is_some_and(|name| {
+                name.starts_with("aria-") && properties.get_property(&name).is_none()
+            })
+        })
+        .collect()
+}
+
+/// The names of the components that the checks of the labels accept, in addition to the default ones
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LabelAssociationOptions<'a> {
+    /// The names of the components that are considered inputs
+    pub input_components: &'a [String],
+    /// The names of the attributes that provide the text of a label
+    pub label_attributes: &'a [String],
+    /// The names of the components that are considered labels
+    pub label_components: &'a [String],
+}
+
+/// How a label is associated with its text and its control
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LabelAssociation {
+    /// Whether the label has an accessible text
+    pub has_text_content: bool,
+    /// Whether the label is associated with a control,
+    /// through the `for` attribute or by nesting the control inside the label
+    pub has_control_association: bool,
+}
+
+impl LabelAssociation {
+    /// Returns `true` if the label has both an accessible text and a control
+    pub const fn is_valid(&self) -> bool {
+        self.has_text_content && self.has_control_association
+    }
+}
+
+const DEFAULT_LABEL_ATTRIBUTES: &[&str; 2] = &["aria-label", "alt"];
+const DEFAULT_LABEL_COMPONENTS: &[&str; 1] = &["label"];
+const DEFAULT_INPUT_COMPONENTS: &[&str; 6] =
+    &["input", "meter", "output", "progress", "select", "textarea"];
+
+/// Returns how `element` is associated with its text and its control, if `element` is a label
+pub fn label_association<E: MarkupElement>(
+    element: &E,
+    options: LabelAssociationOptions,
+) -> Option<LabelAssociation> {
+    let name = element.name()?;
+    if !contains_name(options.label_components, DEFAULT_LABEL_COMPONENTS, &name) {
+        return None;
+    }
+
+    let has_for_attribute = element.attributes().iter().any(|attribute| {
+        attribute
+            .name()
+            .is_some_and(|name| matches!(name.as_str(), "for" | "htmlFor"))
+    });
+    let has_nested_control = element.descendants().iter().any(|descendant| {
+        descendant.name().is_some_and(|name| {
+            contains_name(options.input_components, DEFAULT_INPUT_COMPONENTS, &name)
+        })
+    });
+
+    Some(LabelAssociation {
+        has_text_content: has_accessible_label(element, options.label_attributes),
+        has_control_association: has_for_attribute || has_nested_control,
+    })
+}
+
+/// Returns whether `element` meets one of the following conditions:
+/// - Has a label attribute that corresponds to the `label_attributes` parameter
+/// - Has an `aria-labelledby` attribute
+/// - Contains text
+///
+/// The attributes of the descendants of `element` are also taken into account.
+fn has_accessible_label<E: MarkupElement>(element: &E, label_attributes: &[String]) -> bool {
+    if element.has_text_content() {
+        return true;
+    }
+
+    std::iter::once(element)
+        .chain(element.descendants().iter())
+        .flat_map(|element| element.attributes())
+        .any(|attribute| {
+            let Some(name) = attribute.name() else {
+                return false;
+            };
+            let is_label_attribute = name == "aria-labelledby"
+                || contains_name(label_attributes, DEFAULT_LABEL_ATTRIBUTES, &name);
+            is_label_attribute
+                && attribute
+                    .value()
+                    .as_text()
+                    .is_some_and(|text| !text.trim().is_empty())
+        })
+}
+
+fn contains_name(names: &[String], default_names: &[&str], name: &str) -> bool {
+    names.iter().any(|name_| name_ == name) || default_names.contains(&name)
+}
+
+fn has_type_image_attribute<E: MarkupElement>(element: &E) -> bool {
+    element
+        .find_attribute_by_name("type")
+        .is_some_and(|attribute| attribute.value().as_text() == Some("image"))
+}
+
+fn has_valid_alt_text<E: MarkupElement>(element: &E) -> bool {
+    element
+        .find_attribute_by_name("alt")
+        .is_some_and(|attribute| {
+            !matches!(
+                attribute.value(),
+                MarkupAttributeValue::Missing | MarkupAttributeValue::Nullish
+            ) && !element.has_trailing_spread_prop(&attribute)
+        })
+}
+
+fn has_valid_label<E: MarkupElement>(element: &E, name_to_lookup: &str) -> bool {
+    element
+        .find_attribute_by_name(name_to_lookup)
+        .is_some_and(|attribute| {
+            let is_empty = match attribute.value() {
+                MarkupAttributeValue::Missing | MarkupAttributeValue::Nullish => true,
+                MarkupAttributeValue::Text(text) => text.is_empty(),
+                MarkupAttributeValue::Dynamic | MarkupAttributeValue::Boolean(_) => false,
+            };
+            !is_empty && !element.has_trailing_spread_prop(&attribute)
+        })
+}
+
+fn is_aria_hidden<E: MarkupElement>(element: &E) -> bool {
+    element
+        .find_attribute_by_name("aria-hidden")
+        .is_some_and(|attribute| match attribute.value() {
+            MarkupAttributeValue::Missing
+            | MarkupAttributeValue::Dynamic
+            | MarkupAttributeValue::Boolean(true) => true,
+            MarkupAttributeValue::Text(text) => text == "true",
+            MarkupAttributeValue::Nullish | MarkupAttributeValue::Boolean(false) => false,
+        })
+}
@@ -0,0 +1,643 @@
+//! The attributes of the HTML elements, and the names that the JSX frameworks give to them.
+//!
+//! The dataset covers the global attributes and the attributes of the HTML elements of the
+//! [HTML Living Standard](https://html.spec.whatwg.org/multipage/indices.html#attributes-3).
+//! The SVG and MathML elements, and the custom elements, aren't checked.
+
+use crate::markup::{MarkupAttribute, MarkupElement};
+
+/// How the attributes of the elements are named, which depends on the framework that renders them
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AttributeNaming {
+    /// The names of the HTML specification, such as `class` and `tabindex`
+    #[default]
+    Html,
+    /// The names of the properties of the DOM elements in React, such as `className` and `tabIndex`
+    React,
+    /// Preact accepts both the HTML names and the React names
+    Preact,
+    /// Solid uses the HTML names, and accepts namespaced attributes such as `on:click`
+    Solid,
+}
+
+/// Why an attribute of an element is reported by [unknown_attributes]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnknownAttributeKind {
+    /// The attribute doesn't exist
+    Unknown,
+    /// The attribute exists, but is spelled differently, such as `class` instead of `className` in React
+    Misnamed { expected: &'static str },
+    /// The attribute only exists on other elements, such as `href` on a `div`
+    NotOnElement { elements: &'static [&'static str] },
+}
+
+/// An attribute of the HTML specification
+struct AttributeDefinition {
+    /// The name of the attribute in HTML
+    name: &'static str,
+    /// The name of the attribute in React, if it isn't the HTML name
+    react_name: Option<&'static str>,
+    /// The elements that accept the attribute, or an empty list if the attribute is global
+    elements: &'static [&'static str],
+}
+
+impl AttributeDefinition {
+    const fn new(name: &'static str, elements: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            react_name: None,
+            elements,
+        }
+    }
+
+    const fn react(mut self, react_name: &'static str) -> Self {
+        self.react_name = Some(react_name);
+        self
+    }
+
+    fn react_name(&self) -> &'static str {
+        self.react_name.unwrap_or(self.name)
+    }
+
+    /// Returns `true` if `name` is the name of the attribute with the given naming
+    fn is_named(&self, name: &str, naming: AttributeNaming) -> bool {
+        match naming {
+            AttributeNaming::Html => self.name.eq_ignore_ascii_case(name),
+            AttributeNaming::React => self.react_name() == name,
+            AttributeNaming::Preact => self.name == name || self.react_name() == name,
+            AttributeNaming::Solid => self.name == name,
+        }
+    }
+
+    /// The name of the attribute with the given naming
+    fn expected_name(&self, naming: AttributeNaming) -> &'static str {
+        match naming {
+            AttributeNaming::Html | AttributeNaming::Solid => self.name,
+            AttributeNaming::React | AttributeNaming::Preact => self.react_name(),
+        }
+    }
+}
+
+const GLOBAL: &[&str] = &[];
+
+const ATTRIBUTES: &[AttributeDefinition] = &[
+    // Global attributes
+    AttributeDefinition::new("accesskey", GLOBAL).react("accessKey"),
+    AttributeDefinition::new("autocapitalize", GLOBAL).react("autoCapitalize"),
+    AttributeDefinition::new("autofocus", GLOBAL).react("autoFocus"),
+    AttributeDefinition::new("class", GLOBAL).react("className"),
+    AttributeDefinition::new("contenteditable", GLOBAL).react("contentEditable"),
+    AttributeDefinition::new("dir", GLOBAL),
+    AttributeDefinition::new("draggable", GLOBAL),
+    AttributeDefinition::new("enterkeyhint", GLOBAL).react("enterKeyHint"),
+    AttributeDefinition::new("hidden", GLOBAL),
+    AttributeDefinition::new("id", GLOBAL),
+    AttributeDefinition::new("inert", GLOBAL),
+    AttributeDefinition::new("inputmode", GLOBAL).react("inputMode"),
+    AttributeDefinition::new("is", GLOBAL),
+    AttributeDefinition::new("itemid", GLOBAL).react("itemID"),
+    AttributeDefinition::new("itemprop", GLOBAL).react("itemProp"),
+    AttributeDefinition::new("itemref", GLOBAL).react("itemRef"),
+    AttributeDefinition::new("itemscope", GLOBAL).react("itemScope"),
+    AttributeDefinition::new("itemtype", GLOBAL).react("itemType"),
+    AttributeDefinition::new("lang", GLOBAL),
+    AttributeDefinition::new("nonce", GLOBAL),
+    AttributeDefinition::new("part", GLOBAL),
+    AttributeDefinition::new("popover", GLOBAL),
+    AttributeDefinition::new("role", GLOBAL),
+    AttributeDefinition::new("slot", GLOBAL),
+    AttributeDefinition::new("spellcheck", GLOBAL).react("spellCheck"),
+    AttributeDefinition::new("style", GLOBAL),
+    AttributeDefinition::new("tabindex", GLOBAL).react("tabIndex"),
+    AttributeDefinition::new("title", GLOBAL),
+    AttributeDefinition::new("translate", GLOBAL),
+    // Attributes of specific elements
+    AttributeDefinition::new("abbr", &["th"]),
+    AttributeDefinition::new("accept", &["input"]),
+    AttributeDefinition::new("accept-charset", &["form"]).react("acceptCharset"),
+    AttributeDefinition::new("action", &["form"]),
+    AttributeDefinition::new("allow", &["iframe"]),
+    AttributeDefinition::new("allowfullscreen", &["iframe"]).react("allowFullScreen"),
+    AttributeDefinition::new("alt", &["area", "img", "input"]),
+    AttributeDefinition::new("as", &["link"]),
+    AttributeDefinition::new("async", &["script"]),
+    AttributeDefinition::new("autocomplete", &["form", "input", "select", "textarea"])
+        .react("autoComplete"),
+    AttributeDefinition::new("autoplay", &["audio", "video"]).react("autoPlay"),
+    AttributeDefinition::new("blocking", &["link", "script", "style"]),
+    AttributeDefinition::new("capture", &["input"]),
+    AttributeDefinition::new("charset", &["meta"]).react("charSet"),
+    AttributeDefinition::new("checked", &["input"]),
+    AttributeDefinition::new("cite", &["blockquote", "del", "ins", "q"]),
+    AttributeDefinition::new("cols", &["textarea"]),
+    AttributeDefinition::new("colspan", &["td", "th"]).react("colSpan"),
+    AttributeDefinition::new("content", &["meta"]),
+    AttributeDefinition::new("controls", &["audio", "video"]),
+    AttributeDefinition::new("controlslist", &["audio", "video"]).react("controlsList"),
+    AttributeDefinition::new("coords", &["area"]),
+    AttributeDefinition::new("crossorigin", &["audio", "img", "link", "script", "video"])
+        .react("crossOrigin"),
+    AttributeDefinition::new("data", &["object"]),
+    AttributeDefinition::new("datetime", &["del", "ins", "time"]).react("dateTime"),
+    AttributeDefinition::new("decoding", &["img"]),
+    AttributeDefinition::new("default", &["track"]),
+    AttributeDefinition::new("defer", &["script"]),
+    AttributeDefinition::new("dirname", &["input", "textarea"]),
+    AttributeDefinition::new(
+        "disabled",
+        &[
+            "button", "fieldset", "input", "link", "optgroup", "option", "select", "textarea",
+        ],
+    ),
+    AttributeDefinition::new("disablepictureinpicture", &["video"])
+        .react("disablePictureInPicture"),
+    AttributeDefinition::new("disableremoteplayback", &["audio", "video"])
+        .react("disableRemotePlayback"),
+    AttributeDefinition::new("download", &["a", "area"]),
+    AttributeDefinition::new("enctype", &["form"]).react("encType"),
+    AttributeDefinition::new("fetchpriority", &["img", "link", "script"]).react("fetchPriority"),
+    AttributeDefinition::new("for", &["label", "output"]).react("htmlFor"),
+    AttributeDefinition::new(
+        "form",
+        &[
+            "button", "fieldset", "input", "object", "output", "select", "textarea",
+        ],
+    ),
+    AttributeDefinition::new("formaction", &["button", "input"]).react("formAction"),
+    AttributeDefinition::new("formenctype", &["button", "input"]).react("formEncType"),
+    AttributeDefinition::new("formmethod", &["button", "input"]).react("formMethod"),
+    AttributeDefinition::new("formnovalidate", &["button", "input"]).react("formNoValidate"),
+    AttributeDefinition::new("formtarget", &["button", "input"]).react("formTarget"),
+    AttributeDefinition::new("frameborder", &["iframe"]).react("frameBorder"),
+    AttributeDefinition::new("headers", &["td", "th"]),
+    AttributeDefinition::new(
+        "height",
+        &[
+            "canvas", "embed", "iframe", "img", "input", "object", "source", "video",
+        ],
+    ),
+    AttributeDefinition::new("high", &["meter"]),
+    AttributeDefinition::new("href", &["a", "area", "base", "link"]),
+    AttributeDefinition::new("hreflang", &["a", "link"]).react("hrefLang"),
+    AttributeDefinition::new("http-equiv", &["meta"]).react("httpEquiv"),
+    AttributeDefinition::new("imagesizes", &["link"]).react("imageSizes"),
+    AttributeDefinition::new("imagesrcset", &["link"]).react("imageSrcSet"),
+    AttributeDefinition::new("integrity", &["link", "script"]),
+    AttributeDefinition::new("kind", &["track"]),
+    AttributeDefinition::new("label", &["optgroup", "option", "track"]),
+    AttributeDefinition::new("list", &["input"]),
+    AttributeDefinition::new("loading", &["iframe", "img"]),
+    AttributeDefinition::new("loop", &["audio", "video"]),
+    AttributeDefinition::new("low", &["meter"]),
+    AttributeDefinition::new("max", &["input", "meter", "progress"]),
+    AttributeDefinition::new("maxlength", &["input", "textarea"]).react("maxLength"),
+    AttributeDefinition::new("media", &["link", "meta", "source", "style"]),
+    AttributeDefinition::new("method", &["form"]),
+    AttributeDefinition::new("min", &["input", "meter"]),
+    AttributeDefinition::new("minlength", &["input", "textarea"]).react("minLength"),
+    AttributeDefinition::new("multiple", &["input", "select"]),
+    AttributeDefinition::new("muted", &["audio", "video"]),
+    AttributeDefinition::new(
+        "name",
+        &[
+            "button", "details", "fieldset", "form", "iframe", "input", "map", "meta", "object",
+            "output", "select", "slot", "textarea",
+        ],
+    ),
+    AttributeDefinition::new("nomodule", &["script"]).react("noModule"),
+    AttributeDefinition::new("novalidate", &["form"]).react("noValidate"),
+    AttributeDefinition::new("open", &["details", "dialog"]),
+    AttributeDefinition::new("optimum", &["meter"]),
+    AttributeDefinition::new("pattern", &["input"]),
+    AttributeDefinition::new("ping", &["a", "area"]),
+    AttributeDefinition::new("placeholder", &["input", "textarea"]),
+    AttributeDefinition::new("playsinline", &["video"]).react("playsInline"),
+    AttributeDefinition::new("popovertarget", &["button", "input"]).react("popoverTarget"),
+    AttributeDefinition::new("popovertargetaction", &["button", "input"])
+        .react("popoverTargetAction"),
+    AttributeDefinition::new("poster", &["video"]),
+    AttributeDefinition::new("preload", &["audio", "video"]),
+    // Used by the Open Graph protocol
+    AttributeDefinition::new("property", &["meta"]),
+    AttributeDefinition::new("readonly", &["input", "textarea"]).react("readOnly"),
+    AttributeDefinition::new(
+        "referrerpolicy",
+        &["a", "area", "iframe", "img", "link", "script"],
+    )
+    .react("referrerPolicy"),
+    AttributeDefinition::new("rel", &["a", "area", "form", "link"]),
+    AttributeDefinition::new("required", &["input", "select", "textarea"]),
+    AttributeDefinition::new("reversed", &["ol"]),
+    AttributeDefinition::new("rows", &["textarea"]),
+    AttributeDefinition::new("rowspan", &["td", "th"]).react("rowSpan"),
+    AttributeDefinition::new("sandbox", &["iframe"]),
+    AttributeDefinition::new("scope", &["th"]),
+    AttributeDefinition::new("scrolling", &["iframe"]),
+    AttributeDefinition::new("selected", &["option"]),
+    AttributeDefinition::new("shape", &["area"]),
+    AttributeDefinition::new("size", &["input", "select"]),
+    AttributeDefinition::new("sizes", &["img", "link", "source"]),
+    AttributeDefinition::new("span", &["col", "colgroup"]),
+    AttributeDefinition::new(
+        "src",
+        &[
+            "audio", "embed", "iframe", "img", "input", "script", "source", "track", "video",
+        ],
+    ),
+    AttributeDefinition::new("srcdoc", &["iframe"]).react("srcDoc"),
+    AttributeDefinition::new("srclang", &["track"]).react("srcLang"),
+    AttributeDefinition::new("srcset", &["img", "source"]).react("srcSet"),
+    AttributeDefinition::new("start", &["ol"]),
+    AttributeDefinition::new("step", &["input"]),
+    AttributeDefinition::new("target", &["a", "area", "base", "form"]),
+    AttributeDefinition::new(
+        "type",
+        &[
+            "a", "button", "embed", "input", "link", "object", "ol", "script", "source", "style",
+        ],
+    ),
+    AttributeDefinition::new("usemap", &["img"]).react("useMap"),
+    AttributeDefinition::new(
+        "value",
+        &[
+            "button", "data", "input", "li", "meter", "option", "output", "progress", "select",
+            "textarea",
+        ],
+    ),
+    AttributeDefinition::new(
+        "width",
+        &[
+            "canvas", "embed", "iframe", "img", "input", "object", "source", "video",
+        ],
+    ),
+    AttributeDefinition::new("wrap", &["textarea"]),
+    AttributeDefinition::new("xmlns", &["html"]),
+];
+
+/// The events of the event handler attributes, with the name of their handler in React
+const EVENTS: &[(&str, &str)] = &[
+    ("abort", "onAbort"),
+    ("afterprint", "onAfterPrint"),
+    ("animationend", "onAnimationEnd"),
+    ("animationiteration", "onAnimationIteration"),
+    ("animationstart", "onAnimationStart"),
+    ("auxclick", "onAuxClick"),
+    ("beforeinput", "onBeforeInput"),
+    ("beforeprint", "onBeforePrint"),
+    ("beforetoggle", "onBeforeToggle"),
+    ("beforeunload", "onBeforeUnload"),
+    ("blur", "onBlur"),
+    ("cancel", "onCancel"),
+    ("canplay", "onCanPlay"),
+    ("canplaythrough", "onCanPlayThrough"),
+    ("change", "onChange"),
+    ("click", "onClick"),
+    ("close", "onClose"),
+    ("compositionend", "onCompositionEnd"),
+    ("compositionstart", "onCompositionStart"),
+    ("compositionupdate", "onCompositionUpdate"),
+    ("contextmenu", "onContextMenu"),
+    ("copy", "onCopy"),
+    ("cuechange", "onCueChange"),
+    ("cut", "onCut"),
+    ("dblclick", "onDoubleClick"),
+    ("drag", "onDrag"),
+    ("dragend", "onDragEnd"),
+    ("dragenter", "onDragEnter"),
+    ("dragleave", "onDragLeave"),
+    ("dragover", "onDragOver"),
+    ("dragstart", "onDragStart"),
+    ("drop", "onDrop"),
+    ("durationchange", "onDurationChange"),
+    ("emptied", "onEmptied"),
+    ("ended", "onEnded"),
+    ("error", "onError"),
+    ("focus", "onFocus"),
+    ("focusin", "onFocusIn"),
+    ("focusout", "onFocusOut"),
+    ("formdata", "onFormData"),
+    ("gotpointercapture", "onGotPointerCapture"),
+    ("hashchange", "onHashChange"),
+    ("input", "onInput"),
+    ("invalid", "onInvalid"),
+    ("keydown", "onKeyDown"),
+    ("keypress", "onKeyPress"),
+    ("keyup", "onKeyUp"),
+    ("load", "onLoad"),
+    ("loadeddata", "onLoadedData"),
+    ("loadedmetadata", "onLoadedMetadata"),
+    ("loadstart", "onLoadStart"),
+    ("lostpointercapture", "onLostPointerCapture"),
+    ("message", "onMessage"),
+    ("mousedown", "onMouseDown"),
+    ("mouseenter", "onMouseEnter"),
+    ("mouseleave", "onMouseLeave"),
+    ("mousemove", "onMouseMove"),
+    ("mouseout", "onMouseOut"),
+    ("mouseover", "onMouseOver"),
+    ("mouseup", "onMouseUp"),
+    ("offline", "onOffline"),
+    ("online", "onOnline"),
+    ("pagehide", "onPageHide"),
+    ("pageshow", "onPageShow"),
+    ("paste", "onPaste"),
+    ("pause", "onPause"),
+    ("play", "onPlay"),
+    ("playing", "onPlaying"),
+    ("pointercancel", "onPointerCancel"),
+    ("pointerdown", "onPointerDown"),
+    ("pointerenter", "onPointerEnter"),
+    ("pointerleave", "onPointerLeave"),
+    ("pointermove", "onPointerMove"),
+    ("pointerout", "onPointerOut"),
+    ("pointerover", "onPointerOver"),
+    ("pointerup", "onPointerUp"),
+    ("popstate", "onPopState"),
+    ("progress", "onProgress"),
+    ("ratechange", "onRateChange"),
+    ("reset", "onReset"),
+    ("resize", "onResize"),
+    ("scroll", "onScroll"),
+    ("scrollend", "onScrollEnd"),
+    ("seeked", "onSeeked"),
+    ("seeking", "onSeeking"),
+    ("select", "onSelect"),
+    ("selectionchange", "onSelectionChange"),
+    ("selectstart", "onSelectStart"),
+    ("stalled", "onStalled"),
+    ("storage", "onStorage"),
+    ("submit", "onSubmit"),
+    ("suspend", "onSuspend"),
+    ("timeupdate", "onTimeUpdate"),
+    ("toggle", "onToggle"),
+    ("touchcancel", "onTouchCancel"),
+    ("touchend", "onTouchEnd"),
+    ("touchmove", "onTouchMove"),
+    ("touchstart", "onTouchStart"),
+    ("transitioncancel", "onTransitionCancel"),
+    ("transitionend", "onTransitionEnd"),
+    ("transitionrun", "onTransitionRun"),
+    ("transitionstart", "onTransitionStart"),
+    ("unload", "onUnload"),
+    ("volumechange", "onVolumeChange"),
+    ("waiting", "onWaiting"),
+    ("wheel", "onWheel"),
+];
+
+/// The properties that the frameworks accept on every element, and don't render as attributes
+const REACT_PROPS: &[&str] = &[
+    "children",
+    "dangerouslySetInnerHTML",
+    "defaultChecked",
+    "defaultValue",
+    "key",
+    "ref",
+    "suppressContentEditableWarning",
+    "suppressHydrationWarning",
+];
+const PREACT_PROPS: &[&str] = &[
+    "children",
+    "dangerouslySetInnerHTML",
+    "defaultChecked",
+    "defaultValue",
+    "key",
+    "ref",
+];
+const SOLID_PROPS: &[&str] = &["children", "classList", "innerHTML", "ref", "textContent"];
+
+/// The elements of the HTML specification
+const HTML_ELEMENTS: &[&str] = &[
+    "a",
+    "abbr",
+    "address",
+    "area",
+    "article",
+    "aside",
+    "audio",
+    "b",
+    "base",
+    "bdi",
+    "bdo",
+    "blockquote",
+    "body",
+    "br",
+    "button",
+    "canvas",
+    "caption",
+    "cite",
+    "code",
+    "col",
+    "colgroup",
+    "data",
+    "datalist",
+    "dd",
+    "del",
+    "details",
+    "dfn",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "embed",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "i",
+    "iframe",
+    "img",
+    "input",
+    "ins",
+    "kbd",
+    "label",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "map",
+    "mark",
+    "menu",
+    "meta",
+    "meter",
+    "nav",
+    "noscript",
+    "object",
+    "ol",
+    "optgroup",
+    "option",
+    "output",
+    "p",
+    "picture",
+    "pre",
+    "progress",
+    "q",
+    "rp",
+    "rt",
+    "ruby",
+    "s",
+    "samp",
+    "script",
+    "search",
+    "section",
+    "select",
+    "slot",
+    "small",
+    "source",
+    "span",
+    "strong",
+    "style",
+    "sub",
+    "summary",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "template",
+    "textarea",
+    "tfoot",
+    "th",
+    "thead",
+    "time",
+    "title",
+    "tr",
+    "track",
+    "u",
+    "ul",
+    "var",
+    "video",
+    "wbr",
+];
+
+/// Returns the attributes of `element` that aren't attributes of the element with the given naming,
+/// except the attributes of `ignore`
+///
+/// The `data-*` and `aria-*` attributes, and the namespaced attributes such as `xml:lang`, are accepted.
+/// Only the elements of the HTML specification are checked.
+///
+/// ## Examples
+///
+/// ```
+/// use biome_aria::attributes::{attribute_kind, AttributeNaming, UnknownAttributeKind};
+///
+/// assert_eq!(attribute_kind("div", "id", AttributeNaming::Html), None);
+/// assert_eq!(
+///     attribute_kind("div", "class", AttributeNaming::React),
+///     Some(UnknownAttributeKind::Misnamed { expected: "className" })
+/// );
+/// ```
+pub fn unknown_attributes<E: MarkupElement>(
+    element: &E,
+    naming: AttributeNaming,
+    ignore: &[String],
+) -> Vec<(E::Attribute, UnknownAttributeKind)> {
+    if element.is_custom_component() {
+        return Vec::new();
+    }
+    let Some(element_name) = element.name() else {
+        return Vec::new();
+    };
+
+    element
+        .attributes()
+        .into_iter()
+        .filter_map(|attribute| {
+            let name = attribute.name()?;
+            if ignore.contains(&name) {
+                return None;
+            }
+            let kind = attribute_kind(&element_name, &name, naming)?;
+            Some((attribute, kind))
+        })
+        .collect()
+}
+
+/// Returns why the attribute `name` of the element `element_name` is reported by [unknown_attributes],
+/// or `None` if the attribute is valid
+pub fn attribute_kind(
+    element_name: &str,
+    name: &str,
+    naming: AttributeNaming,
+) -> Option<UnknownAttributeKind> {
+    if !HTML_ELEMENTS.contains(&element_name)
+        || name.starts_with("data-")
+        || name.starts_with("aria-")
+        || name.contains(':')
+    {
+        return None;
+    }
+
+    if let Some(definition) = ATTRIBUTES
+        .iter()
+        .find(|definition| definition.is_named(name, naming))
+    {
+        return (!definition.elements.is_empty() && !definition.elements.contains(&element_name))
+            .then_some(UnknownAttributeKind::NotOnElement {
+                elements: definition.elements,
+            });
+    }
+
+    let framework_props = match naming {
+        AttributeNaming::Html => &[][..],
+        AttributeNaming::React => REACT_PROPS,
+        AttributeNaming::Preact => PREACT_PROPS,
+        AttributeNaming::Solid => SOLID_PROPS,
+    };
+    if framework_props.contains(&name) {
+        return None;
+    }
+
+    if let Some(event) = name.strip_prefix("on") {
+        if let Some(kind) = event_handler_kind(event, naming) {
+            return kind;
+        }
+    }
+
+    // The attribute may be spelled with the wrong case, or with the name of another framework
+    let misnamed = ATTRIBUTES.iter().find(|definition| {
+        definition.name.eq_ignore_ascii_case(name)
+            || definition.react_name().eq_ignore_ascii_case(name)
+    });
+    Some(match misnamed {
+        Some(definition) => UnknownAttributeKind::Misnamed {
+            expected: definition.expected_name(naming),
+        },
+        None => UnknownAttributeKind::Unknown,
+    })
+}
+
+/// Returns the result of [attribute_kind] for the event handler attribute of `event`,
+/// or `None` if `event` isn't an event
+fn event_handler_kind(
+    event: &str,
+    naming: AttributeNaming,
+) -> Option<Option<UnknownAttributeKind>> {
+    let (_, react_handler) = EVENTS
+        .iter()
+        .find(|(html_event, _)| html_event.eq_ignore_ascii_case(event))?;
+    let is_html_name = event.bytes().all(|byte| byte.is_ascii_lowercase());
+    let is_react_name = event == &react_handler[2..];
+    let is_valid = match naming {
+        AttributeNaming::Html => true,
+        AttributeNaming::React => is_react_name,
+        AttributeNaming::Preact | AttributeNaming::Solid => is_html_name || is_react_name,
+    };
+    if is_valid {
+        return Some(None);
+    }
+    Some(Some(UnknownAttributeKind::Misnamed {
+        expected: react_handler,
+    }))
+}
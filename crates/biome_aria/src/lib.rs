@@ -1,7 +1,10 @@
 use std::str::FromStr;
 
+pub mod a11y;
+pub mod attributes;
 pub mod iso;
 mod macros;
+pub mod markup;
 pub mod properties;
 pub mod roles;
 
@@ -44,7 +47,7 @@ mod test {
 
     #[test]
     fn property_is_required() {
-        let roles = AriaRoles;
+        let roles = AriaRoles::default();
 
         let role = roles.get_role("checkbox");
 
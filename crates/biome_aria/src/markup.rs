@@ -0,0 +1,78 @@
+//! An abstraction over the elements of the markup languages, such as JSX and HTML.
+//!
+//! The accessibility checks of [crate::a11y] are written against the [MarkupElement] trait,
+//! so that the analyzers of the different languages share the same logic.
+
+/// The value of an attribute of a [MarkupElement]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MarkupAttributeValue {
+    /// The attribute doesn't have a value, such as `disabled` in `<input disabled>`
+    Missing,
+    /// The value is only known at runtime, such as `alt={text}` in JSX
+    Dynamic,
+    /// The value is `null` or `undefined`, such as `alt={null}` in JSX
+    Nullish,
+    /// A boolean value, such as `aria-hidden={true}` in JSX
+    Boolean(bool),
+    /// A text value, such as `alt="Biome"`, or any other static value, such as `tabIndex={0}` in JSX
+    Text(String),
+}
+
+impl MarkupAttributeValue {
+    /// Returns the text of the value, if the value is static
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Boolean(true) => Some("true"),
+            Self::Boolean(false) => Some("false"),
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+/// An attribute of a [MarkupElement]
+pub trait MarkupAttribute {
+    /// The name of the attribute, such as `alt`
+    fn name(&self) -> Option<String>;
+
+    /// The value of the attribute
+    fn value(&self) -> MarkupAttributeValue;
+}
+
+/// An element of a markup language, such as `<img alt="Biome" />`
+pub trait MarkupElement: Sized {
+    type Attribute: MarkupAttribute;
+
+    /// The name of the element, such as `img`
+    fn name(&self) -> Option<String>;
+
+    /// The attributes of the element, in the order of the source
+    fn attributes(&self) -> Vec<Self::Attribute>;
+
+    /// Returns the first attribute with the given `name`
+    fn find_attribute_by_name(&self, name: &str) -> Option<Self::Attribute> {
+        self.attributes()
+            .into_iter()
+            .find(|attribute| attribute.name().is_some_and(|name_| name_ == name))
+    }
+
+    /// Returns `true` if the element is a component, such as `<Button />` in JSX,
+    /// and not an element of the platform
+    fn is_custom_component(&self) -> bool;
+
+    /// Returns `true` if a spread of props follows the given `attribute`,
+    /// and may override its value
+    fn has_trailing_spread_prop(&self, _attribute: &Self::Attribute) -> bool {
+        false
+    }
+
+    /// Returns `true` if the element has a child that is announced by the screen readers,
+    /// such as text content
+    fn has_accessible_child(&self) -> bool;
+
+    /// Returns `true` if the element, or one of its descendants, contains text
+    fn has_text_content(&self) -> bool;
+
+    /// The elements nested inside the element, in the order of the source
+    fn descendants(&self) -> Vec<Self>;
+}
@@ -1,5 +1,6 @@
 use crate::define_property;
 use biome_aria_metadata::AriaPropertyTypeEnum;
+use rustc_hash::FxHashSet;
 use std::fmt::Debug;
 use std::slice::Iter;
 use std::str::FromStr;
@@ -25,6 +26,20 @@ define_property! {
     }
 }
 
+define_property! {
+    AriaBraillelabel {
+        PROPERTY_TYPE: "string",
+        VALUES: [],
+    }
+}
+
+define_property! {
+    AriaBrailleroledescription {
+        PROPERTY_TYPE: "string",
+        VALUES: [],
+    }
+}
+
 define_property! {
     AriaBusy {
         PROPERTY_TYPE: "boolean",
@@ -53,6 +68,13 @@ define_property! {
     }
 }
 
+define_property! {
+    AriaColindextext {
+        PROPERTY_TYPE: "string",
+        VALUES: [],
+    }
+}
+
 define_property! {
     AriaColspan {
         PROPERTY_TYPE: "integer",
@@ -81,6 +103,13 @@ define_property! {
     }
 }
 
+define_property! {
+    AriaDescription {
+        PROPERTY_TYPE: "string",
+        VALUES: [],
+    }
+}
+
 define_property! {
     AriaDetails {
         PROPERTY_TYPE: "id",
@@ -282,6 +311,13 @@ define_property! {
     }
 }
 
+define_property! {
+    AriaRowindextext {
+        PROPERTY_TYPE: "string",
+        VALUES: [],
+    }
+}
+
 define_property! {
     AriaRowspan {
         PROPERTY_TYPE: "integer",
@@ -337,23 +373,76 @@ define_property! {
         VALUES: [],
     }
 }
+/// A custom `aria-*` property, that accepts any value
+#[derive(Debug)]
+struct AriaCustomProperty;
+
+impl AriaPropertyDefinition for AriaCustomProperty {
+    fn values(&self) -> Iter<&'static str> {
+        [].iter()
+    }
+
+    fn property_type(&self) -> AriaPropertyTypeEnum {
+        AriaPropertyTypeEnum::String
+    }
+
+    fn contains_correct_value(&self, input_value: &str) -> bool {
+        !input_value.is_empty()
+    }
+}
+
 /// A collection of ARIA properties with their metadata, necessary to perform various operations.
 #[derive(Debug, Default)]
-pub struct AriaProperties;
+pub struct AriaProperties {
+    /// The names of the custom properties
+    custom_properties: FxHashSet<String>,
+}
 
 impl AriaProperties {
+    /// Returns the ARIA properties extended with `custom_properties`, that accept any value.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_aria::AriaProperties;
+    /// let properties = AriaProperties::with_custom_properties(["aria-x-state".to_string()]);
+    ///
+    /// assert!(properties.get_property("aria-x-state").is_some());
+    /// assert!(properties.get_property("aria-x-other").is_none());
+    /// ```
+    pub fn with_custom_properties(custom_properties: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            custom_properties: custom_properties.into_iter().collect(),
+        }
+    }
+
     pub fn get_property<'a>(&self, property_name: &str) -> Option<&'a dyn AriaPropertyDefinition> {
+        Self::get_standard_property(property_name).or_else(|| {
+            self.custom_properties
+                .contains(property_name)
+                .then_some(&AriaCustomProperty as &dyn AriaPropertyDefinition)
+        })
+    }
+
+    /// Returns the metadata of a property defined by the ARIA specification, if it exists.
+    fn get_standard_property<'a>(property_name: &str) -> Option<&'a dyn AriaPropertyDefinition> {
         Some(match property_name {
             "aria-activedescendant" => &AriaActivedescendant as &dyn AriaPropertyDefinition,
             "aria-autocomplete" => &AriaAutocomplete as &dyn AriaPropertyDefinition,
+            "aria-braillelabel" => &AriaBraillelabel as &dyn AriaPropertyDefinition,
+            "aria-brailleroledescription" => {
+                &AriaBrailleroledescription as &dyn AriaPropertyDefinition
+            }
             "aria-busy" => &AriaBusy as &dyn AriaPropertyDefinition,
             "aria-checked" => &AriaChecked as &dyn AriaPropertyDefinition,
             "aria-colcount" => &AriaColcount as &dyn AriaPropertyDefinition,
             "aria-colindex" => &AriaColindex as &dyn AriaPropertyDefinition,
+            "aria-colindextext" => &AriaColindextext as &dyn AriaPropertyDefinition,
             "aria-colspan" => &AriaColspan as &dyn AriaPropertyDefinition,
             "aria-controls" => &AriaControls as &dyn AriaPropertyDefinition,
             "aria-current" => &AriaCurrent as &dyn AriaPropertyDefinition,
             "aria-describedby" => &AriaDescribedby as &dyn AriaPropertyDefinition,
+            "aria-description" => &AriaDescription as &dyn AriaPropertyDefinition,
             "aria-details" => &AriaDetails as &dyn AriaPropertyDefinition,
             "aria-disabled" => &AriaDisabled as &dyn AriaPropertyDefinition,
             "aria-dropeffect" => &AriaDropeffect as &dyn AriaPropertyDefinition,
@@ -383,6 +472,7 @@ impl AriaProperties {
             "aria-roledescription" => &AriaRoledescription as &dyn AriaPropertyDefinition,
             "aria-rowcount" => &AriaRowcount as &dyn AriaPropertyDefinition,
             "aria-rowindex" => &AriaRowindex as &dyn AriaPropertyDefinition,
+            "aria-rowindextext" => &AriaRowindextext as &dyn AriaPropertyDefinition,
             "aria-rowspan" => &AriaRowspan as &dyn AriaPropertyDefinition,
             "aria-selected" => &AriaSelected as &dyn AriaPropertyDefinition,
             "aria-setsize" => &AriaSetsize as &dyn AriaPropertyDefinition,
@@ -841,6 +841,38 @@ define_role! {
     }
 }
 
+define_role! {
+    /// https://w3c.github.io/aria/#comment
+    CommentRole {
+        PROPS: [("aria-level", false), ("aria-posinset", false), ("aria-setsize", false)],
+        ROLES: ["article"],
+    }
+}
+
+define_role! {
+    /// https://w3c.github.io/aria/#sectionfooter
+    SectionFooterRole {
+        PROPS: [],
+        ROLES: ["section"],
+    }
+}
+
+define_role! {
+    /// https://w3c.github.io/aria/#sectionheader
+    SectionHeaderRole {
+        PROPS: [],
+        ROLES: ["section"],
+    }
+}
+
+define_role! {
+    /// https://w3c.github.io/aria/#suggestion
+    SuggestionRole {
+        PROPS: [],
+        ROLES: ["section"],
+    }
+}
+
 impl<'a> AriaRoles {
     /// These are roles that will contain "concepts".
     pub(crate) const ROLE_WITH_CONCEPTS: &'a [&'a str] = &[
@@ -910,6 +942,15 @@ impl<'a> AriaRoles {
     /// assert!(made_up_role.is_none());
     /// ```
     pub fn get_role(&self, role: &str) -> Option<&'static dyn AriaRoleDefinition> {
+        Self::get_standard_role(role).or_else(|| {
+            self.custom_roles
+                .get(role)
+                .and_then(|standard_role| Self::get_standard_role(standard_role))
+        })
+    }
+
+    /// It returns the metadata of a role defined by the ARIA specification, if it exists.
+    fn get_standard_role(role: &str) -> Option<&'static dyn AriaRoleDefinition> {
         let result = match role {
             "alert" => &AlertRole as &dyn AriaRoleDefinition,
             "alertdialog" => &AlertDialogRole as &dyn AriaRoleDefinition,
@@ -924,10 +965,11 @@ impl<'a> AriaRoles {
             "code" => &CodeRole as &dyn AriaRoleDefinition,
             "columnheader" => &ColumnHeaderRole as &dyn AriaRoleDefinition,
             "combobox" => &ComboBoxRole as &dyn AriaRoleDefinition,
+            "comment" => &CommentRole as &dyn AriaRoleDefinition,
             "complementary" => &ComplementaryRole as &dyn AriaRoleDefinition,
             "contentinfo" => &ContentInfoRole as &dyn AriaRoleDefinition,
             "definition" => &DefinitionRole as &dyn AriaRoleDefinition,
-            "delete" => &DeletionRole as &dyn AriaRoleDefinition,
+            "deletion" => &DeletionRole as &dyn AriaRoleDefinition,
             "dialog" => &DialogRole as &dyn AriaRoleDefinition,
             "directory" => &DirectoryRole as &dyn AriaRoleDefinition,
             "document" => &DocumentRole as &dyn AriaRoleDefinition,
@@ -943,7 +985,8 @@ impl<'a> AriaRoles {
             "gridcell" => &GridCellRole as &dyn AriaRoleDefinition,
             "group" => &GroupRole as &dyn AriaRoleDefinition,
             "heading" => &HeadingRole as &dyn AriaRoleDefinition,
-            "img" => &ImgRole as &dyn AriaRoleDefinition,
+            // `image` is the synonym of `img` introduced by ARIA 1.3
+            "image" | "img" => &ImgRole as &dyn AriaRoleDefinition,
             "insertion" => &InsertionRole as &dyn AriaRoleDefinition,
             "link" => &LinkRole as &dyn AriaRoleDefinition,
             "list" => &ListRole as &dyn AriaRoleDefinition,
@@ -974,6 +1017,8 @@ impl<'a> AriaRoles {
             "rowheader" => &RowHeaderRole as &dyn AriaRoleDefinition,
             "search" => &SearchRole as &dyn AriaRoleDefinition,
             "searchbox" => &SearchboxRole as &dyn AriaRoleDefinition,
+            "sectionfooter" => &SectionFooterRole as &dyn AriaRoleDefinition,
+            "sectionheader" => &SectionHeaderRole as &dyn AriaRoleDefinition,
             "separator" => &SeparatorRole as &dyn AriaRoleDefinition,
             "slider" => &SliderRole as &dyn AriaRoleDefinition,
             "spinbutton" => &SpinButtonRole as &dyn AriaRoleDefinition,
@@ -981,6 +1026,7 @@ impl<'a> AriaRoles {
             "status" => &StatusRole as &dyn AriaRoleDefinition,
             "strong" => &StrongRole as &dyn AriaRoleDefinition,
             "subscript" => &SubScriptRole as &dyn AriaRoleDefinition,
+            "suggestion" => &SuggestionRole as &dyn AriaRoleDefinition,
             "superscript" => &SuperScriptRole as &dyn AriaRoleDefinition,
             "switch" => &SwitchRole as &dyn AriaRoleDefinition,
             "tab" => &TabRole as &dyn AriaRoleDefinition,
@@ -1364,7 +1410,33 @@ pub trait AriaRoleDefinitionWithConcepts: AriaRoleDefinition {
 
 /// Convenient type to retrieve metadata regarding ARIA roles
 #[derive(Debug, Default)]
-pub struct AriaRoles;
+pub struct AriaRoles {
+    /// The names of the custom roles, mapped to the names of the roles that they behave like
+    custom_roles: FxHashMap<String, String>,
+}
+
+impl AriaRoles {
+    /// Returns the ARIA roles extended with `custom_roles`.
+    ///
+    /// A custom role is mapped to the name of the role that it behaves like,
+    /// and it has the same metadata as this role.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_aria::AriaRoles;
+    /// let roles = AriaRoles::with_custom_roles([("x-toggle".to_string(), "switch".to_string())]);
+    ///
+    /// assert!(roles.get_role("x-toggle").is_some());
+    /// assert!(roles.get_role("x-other").is_none());
+    /// assert!(roles.is_role_interactive("x-toggle"));
+    /// ```
+    pub fn with_custom_roles(custom_roles: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            custom_roles: custom_roles.into_iter().collect(),
+        }
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -1374,7 +1446,7 @@ mod test {
 
     #[test]
     fn should_be_interactive() {
-        let aria_roles = AriaRoles {};
+        let aria_roles = AriaRoles::default();
         assert!(!aria_roles.is_not_interactive_element("header", None));
         assert!(!aria_roles.is_not_interactive_element("input", {
             let mut attributes = FxHashMap::default();
@@ -1385,7 +1457,7 @@ mod test {
 
     #[test]
     fn should_not_be_interactive() {
-        let aria_roles = AriaRoles {};
+        let aria_roles = AriaRoles::default();
         assert!(aria_roles.is_not_interactive_element("h1", None));
         assert!(aria_roles.is_not_interactive_element("h2", None));
         assert!(aria_roles.is_not_interactive_element("h3", None));
@@ -1402,7 +1474,7 @@ mod test {
 
     #[test]
     fn test_get_implicit_role() {
-        let aria_roles = AriaRoles {};
+        let aria_roles = AriaRoles::default();
 
         // No attributes
         let implicit_role = aria_roles
@@ -9,18 +9,22 @@ use quote::quote;
 use std::path::PathBuf;
 use std::{env, fs, io};
 
-pub const ARIA_PROPERTIES: [&str; 48] = [
+pub const ARIA_PROPERTIES: [&str; 53] = [
     "aria-activedescendant",
     "aria-atomic",
     "aria-autocomplete",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
     "aria-busy",
     "aria-checked",
     "aria-colcount",
     "aria-colindex",
+    "aria-colindextext",
     "aria-colspan",
     "aria-controls",
     "aria-current",
     "aria-describedby",
+    "aria-description",
     "aria-details",
     "aria-disabled",
     "aria-dropeffect",
@@ -50,6 +54,7 @@ pub const ARIA_PROPERTIES: [&str; 48] = [
     "aria-roledescription",
     "aria-rowcount",
     "aria-rowindex",
+    "aria-rowindextext",
     "aria-rowspan",
     "aria-selected",
     "aria-setsize",
@@ -117,10 +122,11 @@ pub const ARIA_ABSTRACT_ROLES: [&str; 12] = [
     "window",
 ];
 
-pub const ARIA_DOCUMENT_STRUCTURE_ROLES: [&str; 25] = [
+pub const ARIA_DOCUMENT_STRUCTURE_ROLES: [&str; 30] = [
     "article",
     "cell",
     "columnheader",
+    "comment",
     "definition",
     "directory",
     "document",
@@ -128,6 +134,7 @@ pub const ARIA_DOCUMENT_STRUCTURE_ROLES: [&str; 25] = [
     "figure",
     "group",
     "heading",
+    "image",
     "img",
     "list",
     "listitem",
@@ -139,7 +146,10 @@ pub const ARIA_DOCUMENT_STRUCTURE_ROLES: [&str; 25] = [
     "row",
     "rowgroup",
     "rowheader",
+    "sectionfooter",
+    "sectionheader",
     "separator",
+    "suggestion",
     "table",
     "term",
     "toolbar",
@@ -15,6 +15,7 @@ impl Reporter for TextReport {
         let execution = Execution::new_format(VcsTargeted {
             staged: false,
             changed: false,
+            files_from: false,
         });
         visitor.report_summary(&execution, self.summary)?;
         Ok(())
@@ -1,10 +1,13 @@
 use crate::logging::LoggingKind;
+use crate::CliDiagnostic;
 use crate::LoggingLevel;
 use biome_configuration::ConfigurationPathHint;
 use biome_diagnostics::Severity;
+use biome_json_parser::{parse_json, JsonParserOptions};
 use bpaf::Bpaf;
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::num::{NonZeroU32, NonZeroUsize};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 /// Global options applied to all commands
@@ -27,6 +30,11 @@ pub struct CliOptions {
     #[bpaf(long("config-path"), argument("PATH"), optional)]
     pub config_path: Option<String>,
 
+    /// Override a field of the configuration, such as `--config-override=linter.rules.style.noVar=off`.
+    /// The value is read as JSON, or as a string when it isn't valid JSON. The option can be repeated.
+    #[bpaf(long("config-override"), argument("PATH=VALUE"), many)]
+    pub config_override: Vec<ConfigOverride>,
+
     /// Cap the amount of diagnostics displayed. When `none` is provided, the limit is lifted.
     #[bpaf(
         long("max-diagnostics"),
@@ -48,10 +56,35 @@ pub struct CliOptions {
     #[bpaf(long("error-on-warnings"), switch)]
     pub error_on_warnings: bool,
 
+    /// The number of threads used to process the files. By default, Biome uses one thread per CPU core.
+    #[bpaf(long("threads"), argument("NUMBER"), optional)]
+    pub threads: Option<NonZeroUsize>,
+
+    /// The maximum time, in milliseconds, spent analyzing a single file.
+    /// The analysis of a file that exceeds this time is stopped, and an error diagnostic is emitted instead.
+    #[bpaf(long("file-timeout"), argument("MILLISECONDS"), optional)]
+    pub file_timeout: Option<u64>,
+
+    /// Stop processing the files as soon as a diagnostic of error severity is emitted.
+    /// It's the same as `--max-failures=1`, and it can't be used with `--max-failures`.
+    #[bpaf(long("fail-fast"), switch)]
+    pub fail_fast: bool,
+
+    /// Stop processing the files once the given number of diagnostics of error severity is emitted.
+    /// The files that are being processed are completed, the remaining files are skipped.
+    #[bpaf(long("max-failures"), argument("NUMBER"), optional)]
+    pub max_failures: Option<NonZeroU32>,
+
+    /// Process only the files of a shard, such as `--shard=2/5` for the second of five shards.
+    /// The files are split between the shards based on their path, so that several machines can share a single run.
+    /// Use `biome merge-reports` to merge the JSON reports of the shards.
+    #[bpaf(long("shard"), argument("INDEX/COUNT"), optional)]
+    pub shard: Option<Shard>,
+
     /// Allows to change how diagnostics and summary are reported.
     #[bpaf(
         long("reporter"),
-        argument("json|json-pretty|github|junit|summary|gitlab"),
+        argument("json|json-pretty|json-lines|github|junit|summary|gitlab|checkstyle"),
         fallback(CliReporter::default())
     )]
     pub reporter: CliReporter,
@@ -94,6 +127,126 @@ impl CliOptions {
             Some(path) => ConfigurationPathHint::FromUser(PathBuf::from(path)),
         }
     }
+
+    /// Returns the number of errors after which the files aren't processed anymore, if any
+    ///
+    /// ## Errors
+    ///
+    /// - `--fail-fast` and `--max-failures` are both passed
+    pub(crate) fn max_failures(&self) -> Result<Option<u32>, CliDiagnostic> {
+        match (self.fail_fast, self.max_failures) {
+            (true, Some(_)) => Err(CliDiagnostic::incompatible_arguments(
+                "fail-fast",
+                "max-failures",
+            )),
+            (true, None) => Ok(Some(1)),
+            (false, max_failures) => Ok(max_failures.map(NonZeroU32::get)),
+        }
+    }
+}
+
+/// A field of the configuration overridden with `--config-override`, such as `linter.rules.style.noVar=off`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConfigOverride {
+    /// The keys that lead to the field, such as `["linter", "rules", "style", "noVar"]`
+    path: Vec<String>,
+    /// The value of the field, such as `off`
+    value: String,
+}
+
+impl ConfigOverride {
+    /// Returns the override as a JSON configuration, such as `{"linter":{"rules":{"style":{"noVar":"off"}}}}`.
+    ///
+    /// The value is kept as is if it's valid JSON, and it's quoted otherwise.
+    pub(crate) fn to_json(&self) -> String {
+        let value = self.value.trim();
+        let mut json =
+            if value.is_empty() || parse_json(value, JsonParserOptions::default()).has_errors() {
+                serde_json::Value::from(self.value.as_str()).to_string()
+            } else {
+                value.to_string()
+            };
+        for key in self.path.iter().rev() {
+            json = format!("{{{}:{json}}}", serde_json::Value::from(key.as_str()));
+        }
+        json
+    }
+}
+
+impl FromStr for ConfigOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((path, value)) = s.split_once('=') else {
+            return Err(format!(
+                "value {s:?} is not valid for the --config-override argument, expected PATH=VALUE, such as linter.rules.style.noVar=off"
+            ));
+        };
+        let path: Vec<_> = path.trim().split('.').map(String::from).collect();
+        if path.iter().any(String::is_empty) {
+            return Err(format!(
+                "value {s:?} is not valid for the --config-override argument, the path contains an empty key"
+            ));
+        }
+        Ok(Self {
+            path,
+            value: value.to_string(),
+        })
+    }
+}
+
+/// A subset of the files processed by a run, selected with `--shard`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize)]
+pub struct Shard {
+    /// The position of the shard, starting from `1`
+    pub index: u32,
+    /// The number of shards
+    pub count: u32,
+}
+
+impl Shard {
+    /// Returns `true` if the file at `path` belongs to this shard.
+    ///
+    /// The shard of a file only depends on its path, so that every machine that runs a shard makes the same split.
+    /// `path` should be relative to the working directory.
+    pub(crate) fn contains(&self, path: &Path) -> bool {
+        // FNV-1a, a hash that is stable across platforms and releases
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for (position, component) in path.components().enumerate() {
+            if position > 0 {
+                hash = (hash ^ u64::from(b'/')).wrapping_mul(0x0100_0000_01b3);
+            }
+            for byte in component.as_os_str().to_string_lossy().bytes() {
+                hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        hash % u64::from(self.count) == u64::from(self.index - 1)
+    }
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!("value {s:?} is not valid for the --shard argument, expected INDEX/COUNT, such as 2/5")
+        };
+        let (index, count) = s.split_once('/').ok_or_else(invalid)?;
+        let index: u32 = index.trim().parse().map_err(|_| invalid())?;
+        let count: u32 = count.trim().parse().map_err(|_| invalid())?;
+        if index == 0 || index > count {
+            return Err(format!(
+                "value {s:?} is not valid for the --shard argument, the index must be between 1 and the number of shards"
+            ));
+        }
+        Ok(Self { index, count })
+    }
+}
+
+impl Display for Shard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -125,6 +278,8 @@ pub enum CliReporter {
     Json,
     /// Reports information using the JSON format, formatted.
     JsonPretty,
+    /// Reports each diagnostic as a JSON object on its own line, as soon as its file is processed.
+    JsonLines,
     /// Diagnostics are printed for GitHub workflow commands
     GitHub,
     /// Diagnostics and summary are printed in JUnit format
@@ -133,6 +288,8 @@ pub enum CliReporter {
     Summary,
     /// Reports linter diagnostics using the [GitLab Code Quality report](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool).
     GitLab,
+    /// Diagnostics are printed in the [Checkstyle](https://checkstyle.sourceforge.io/) XML format
+    Checkstyle,
 }
 
 impl CliReporter {
@@ -148,10 +305,12 @@ impl FromStr for CliReporter {
         match s {
             "json" => Ok(Self::Json),
             "json-pretty" => Ok(Self::JsonPretty),
+            "json-lines" => Ok(Self::JsonLines),
             "summary" => Ok(Self::Summary),
             "github" => Ok(Self::GitHub),
             "junit" => Ok(Self::Junit),
             "gitlab" => Ok(Self::GitLab),
+            "checkstyle" => Ok(Self::Checkstyle),
             _ => Err(format!(
                 "value {s:?} is not valid for the --reporter argument"
             )),
@@ -165,10 +324,12 @@ impl Display for CliReporter {
             CliReporter::Default => f.write_str("default"),
             CliReporter::Json => f.write_str("json"),
             CliReporter::JsonPretty => f.write_str("json-pretty"),
+            CliReporter::JsonLines => f.write_str("json-lines"),
             CliReporter::Summary => f.write_str("summary"),
             CliReporter::GitHub => f.write_str("github"),
             CliReporter::Junit => f.write_str("junit"),
             CliReporter::GitLab => f.write_str("gitlab"),
+            CliReporter::Checkstyle => f.write_str("checkstyle"),
         }
     }
 }
@@ -0,0 +1,180 @@
+use crate::cli_options::CliOptions;
+use crate::commands::{
+    apply_configuration_overrides, validate_configuration_diagnostics, AssistSubCommand,
+};
+use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_fs::{BiomePath, FileSystemExt};
+use biome_service::configuration::{load_configuration, LoadedConfiguration};
+use biome_service::workspace::{
+    CloseFileParams, GenerateBarrelFileParams, OpenFileParams, UpdateSettingsParams,
+};
+use biome_service::WorkspaceError;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The extensions of the modules that a barrel file can re-export,
+/// in order of preference for the barrel file itself
+const MODULE_EXTENSIONS: [&str; 6] = ["ts", "tsx", "mts", "js", "jsx", "mjs"];
+
+/// An assist that can be run with `biome assist run`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AssistAction {
+    /// Generates or updates the `index` file of a directory,
+    /// so that it re-exports the names exported by the other modules of the directory
+    GenerateBarrelFile,
+}
+
+impl FromStr for AssistAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "generateBarrelFile" => Ok(Self::GenerateBarrelFile),
+            _ => Err(format!(
+                "unknown assist `{s}`, the available assists are: generateBarrelFile"
+            )),
+        }
+    }
+}
+
+/// Handler for the "assist" command of the Biome CLI
+pub(crate) fn assist(
+    session: CliSession,
+    cli_options: CliOptions,
+    sub_command: AssistSubCommand,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+
+    let mut loaded_configuration =
+        load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
+    apply_configuration_overrides(&mut loaded_configuration, &cli_options.config_override);
+    validate_configuration_diagnostics(
+        &loaded_configuration,
+        session.app.console,
+        cli_options.verbose,
+    )?;
+    let LoadedConfiguration {
+        configuration,
+        directory_path,
+        ..
+    } = loaded_configuration;
+    let vcs_base_path = directory_path.or(session.app.fs.working_directory());
+    let (vcs_base_path, gitignore_matches) =
+        configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            workspace_directory: session.app.fs.working_directory(),
+            configuration,
+            vcs_base_path,
+            gitignore_matches,
+        })?;
+
+    let AssistSubCommand::Run {
+        write,
+        exclude,
+        action,
+        path,
+    } = sub_command;
+    match action {
+        AssistAction::GenerateBarrelFile => generate_barrel_file(session, write, exclude, &path),
+    }
+}
+
+/// Generates the barrel file of `directory`, and writes it when `write` is `true`
+fn generate_barrel_file(
+    session: CliSession,
+    write: bool,
+    exclude: Vec<String>,
+    directory: &Path,
+) -> Result<(), CliDiagnostic> {
+    let fs = &session.app.fs;
+    let workspace = &session.app.workspace;
+    let mut modules: Vec<PathBuf> = fs
+        .read_dir(directory)?
+        .into_iter()
+        .filter(|path| {
+            fs.path_is_file(path)
+                && path
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .is_some_and(|extension| MODULE_EXTENSIONS.contains(&extension))
+        })
+        .collect();
+    modules.sort();
+    let barrel_path = barrel_file_path(directory, &modules);
+
+    let mut opened = Vec::new();
+    let mut current_content = None;
+    for path in &modules {
+        let content = fs.read_file_from_path(path).map_err(WorkspaceError::from)?;
+        if path == &barrel_path {
+            current_content = Some(content.clone());
+        }
+        let path = BiomePath::new(path);
+        workspace.open_file(OpenFileParams {
+            path: path.clone(),
+            content,
+            version: 0,
+            document_file_source: None,
+        })?;
+        opened.push(path);
+    }
+    let result = workspace.generate_barrel_file(GenerateBarrelFileParams {
+        path: BiomePath::new(&barrel_path),
+        modules: opened.clone(),
+        exclude,
+    });
+    for path in opened {
+        workspace.close_file(CloseFileParams { path })?;
+    }
+    let content = result?.content;
+
+    let console = session.app.console;
+    let display_path = barrel_path.display().to_string();
+    if current_content.as_deref() == Some(content.as_str()) {
+        console.log(markup! {
+            <Info>"The barrel file "<Emphasis>{display_path}</Emphasis>" is up to date."</Info>
+        });
+    } else if write {
+        fs.create(&barrel_path)?.set_content(content.as_bytes())?;
+        console.log(markup! {
+            <Success>"The barrel file "<Emphasis>{display_path}</Emphasis>" was written."</Success>
+        });
+    } else {
+        console.log(markup! {
+            <Emphasis>{display_path}</Emphasis>"\n"{content}
+        });
+        console.log(markup! {
+            <Info>"Run the command with the option "<Emphasis>"--write"</Emphasis>" to write the barrel file."</Info>
+        });
+    }
+    Ok(())
+}
+
+/// Returns the path of the barrel file of `directory`: its existing `index` file,
+/// or else `index.ts` if the directory contains TypeScript files, and `index.js` otherwise
+fn barrel_file_path(directory: &Path, files: &[PathBuf]) -> PathBuf {
+    let existing = MODULE_EXTENSIONS.iter().find_map(|extension| {
+        files
+            .iter()
+            .find(|path| {
+                path.file_stem().is_some_and(|stem| stem == "index")
+                    && path.extension().is_some_and(|ext| ext == *extension)
+            })
+            .cloned()
+    });
+    existing.unwrap_or_else(|| {
+        let is_typescript = files.iter().any(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == "ts" || ext == "tsx" || ext == "mts")
+        });
+        directory.join(if is_typescript {
+            "index.ts"
+        } else {
+            "index.js"
+        })
+    })
+}
@@ -1,6 +1,7 @@
 use crate::cli_options::CliOptions;
 use crate::commands::{
-    get_files_to_process, get_stdin, resolve_manifest, validate_configuration_diagnostics,
+    apply_configuration_overrides, get_files_to_process, get_stdin, resolve_manifest,
+    validate_configuration_diagnostics,
 };
 use crate::execute::VcsTargeted;
 use crate::{
@@ -21,13 +22,16 @@ use biome_service::{
     workspace::UpdateSettingsParams,
 };
 use std::ffi::OsString;
+use std::path::PathBuf;
 
-use super::{determine_fix_file_mode, FixFileModeOptions};
+use super::{determine_dry_run, determine_fix_file_mode, FixFileModeOptions};
 
 pub(crate) struct CheckCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
     pub(crate) write: bool,
+    pub(crate) dry_run: bool,
+    pub(crate) patch_path: Option<PathBuf>,
     pub(crate) fix: bool,
     pub(crate) unsafe_: bool,
     pub(crate) cli_options: CliOptions,
@@ -41,17 +45,20 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) files_from: Option<String>,
 }
 
 /// Handler for the "check" command of the Biome CLI
 pub(crate) fn check(
-    session: CliSession,
+    mut session: CliSession,
     payload: CheckCommandPayload,
 ) -> Result<(), CliDiagnostic> {
     let CheckCommandPayload {
         apply,
         apply_unsafe,
         write,
+        dry_run,
+        patch_path,
         fix,
         unsafe_,
         cli_options,
@@ -62,6 +69,7 @@ pub(crate) fn check(
         organize_imports_enabled,
         formatter_enabled,
         since,
+        files_from,
         assists_enabled,
         staged,
         changed,
@@ -78,9 +86,17 @@ pub(crate) fn check(
         },
         session.app.console,
     )?;
+    let dry_run = determine_dry_run(
+        dry_run,
+        patch_path,
+        fix_file_mode.is_some(),
+        stdin_file_path.as_deref(),
+        "check",
+    )?;
 
-    let loaded_configuration =
+    let mut loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
+    apply_configuration_overrides(&mut loaded_configuration, &cli_options.config_override);
     validate_configuration_diagnostics(
         &loaded_configuration,
         session.app.console,
@@ -175,10 +191,26 @@ pub(crate) fn check(
     let (vcs_base_path, gitignore_matches) =
         fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
 
+    if files_from.as_deref() == Some("-") && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "files-from",
+            "stdin-file-path",
+        ));
+    }
+
     let stdin = get_stdin(stdin_file_path, &mut *session.app.console, "check")?;
 
-    let vcs_targeted_paths =
-        get_files_to_process(since, changed, staged, &session.app.fs, &fs_configuration)?;
+    let is_files_from = files_from.is_some();
+    let vcs_targeted_paths = get_files_to_process(
+        since,
+        changed,
+        staged,
+        files_from,
+        &paths,
+        &mut session,
+        &fs_configuration,
+        "check",
+    )?;
 
     session
         .app
@@ -202,9 +234,14 @@ pub(crate) fn check(
         Execution::new(TraversalMode::Check {
             fix_file_mode,
             stdin,
-            vcs_targeted: VcsTargeted { staged, changed },
+            vcs_targeted: VcsTargeted {
+                staged,
+                changed,
+                files_from: is_files_from,
+            },
         })
-        .set_report(&cli_options),
+        .set_report(&cli_options)
+        .set_dry_run(dry_run),
         session,
         &cli_options,
         vcs_targeted_paths.unwrap_or(paths),
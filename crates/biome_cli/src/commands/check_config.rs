@@ -0,0 +1,104 @@
+use crate::cli_options::CliOptions;
+use crate::commands::{apply_configuration_overrides, validate_configuration_diagnostics};
+use crate::diagnostics::ReportDiagnostic;
+use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use biome_configuration::PartialConfiguration;
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::adapters::SerdeJsonError;
+use biome_service::configuration::{
+    load_configuration, LoadedConfiguration, PartialConfigurationExt,
+};
+use biome_service::settings::to_matcher;
+use biome_service::workspace::UpdateSettingsParams;
+use biome_service::WorkspaceError;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Handler for the "check-config" command of the Biome CLI
+pub(crate) fn check_config(
+    session: CliSession,
+    cli_options: CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+
+    let mut loaded_configuration =
+        load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
+    apply_configuration_overrides(&mut loaded_configuration, &cli_options.config_override);
+    validate_configuration_diagnostics(
+        &loaded_configuration,
+        session.app.console,
+        cli_options.verbose,
+    )?;
+
+    let LoadedConfiguration {
+        configuration,
+        directory_path,
+        file_path,
+        ..
+    } = loaded_configuration;
+
+    // The settings catch the errors that the deserialization can't, such as the invalid globs
+    let vcs_base_path = directory_path
+        .clone()
+        .or(session.app.fs.working_directory());
+    let (vcs_base_path, gitignore_matches) =
+        configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            workspace_directory: session.app.fs.working_directory(),
+            configuration: configuration.clone(),
+            vcs_base_path,
+            gitignore_matches,
+        })?;
+
+    let console = session.app.console;
+    match &file_path {
+        Some(file_path) => {
+            let file_path = file_path.display().to_string();
+            console.log(markup! {
+                <Success>"The configuration "<Emphasis>{file_path}</Emphasis>" is valid."</Success>
+            });
+        }
+        None => {
+            console.log(markup! {
+                "No configuration file was found, the default configuration applies."
+            });
+        }
+    }
+
+    for path in paths {
+        let path = PathBuf::from(path);
+        let effective = effective_configuration(&configuration, directory_path.as_ref(), &path)?;
+        let content = serde_json::to_string_pretty(&effective).map_err(|error| {
+            CliDiagnostic::Report(ReportDiagnostic::Serialization(SerdeJsonError::from(error)))
+        })?;
+        let path = path.display().to_string();
+        console.log(markup! {
+            "\n"<Emphasis>"Effective configuration of "{path}</Emphasis>"\n"{content}
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the configuration that applies to the file at `path`:
+/// the overrides whose patterns match `path` are merged into the configuration, in order.
+fn effective_configuration(
+    configuration: &PartialConfiguration,
+    root: Option<&PathBuf>,
+    path: &Path,
+) -> Result<PartialConfiguration, WorkspaceError> {
+    let mut effective = configuration.clone();
+    let overrides = effective.overrides.take().unwrap_or_default();
+    for pattern in overrides.0 {
+        let include = to_matcher(root.cloned(), pattern.include.as_ref())?;
+        let exclude = to_matcher(root.cloned(), pattern.ignore.as_ref())?;
+        if include.matches_path(path) && !exclude.matches_path(path) {
+            pattern.merge_into(&mut effective);
+        }
+    }
+    Ok(effective)
+}
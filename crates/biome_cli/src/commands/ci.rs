@@ -1,6 +1,8 @@
-use crate::changed::get_changed_files;
 use crate::cli_options::CliOptions;
-use crate::commands::{resolve_manifest, validate_configuration_diagnostics};
+use crate::commands::{
+    apply_configuration_overrides, get_files_to_process, resolve_manifest,
+    validate_configuration_diagnostics,
+};
 use crate::execute::VcsTargeted;
 use crate::{execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution};
 use biome_configuration::analyzer::assists::PartialAssistsConfiguration;
@@ -23,10 +25,11 @@ pub(crate) struct CiCommandPayload {
     pub(crate) cli_options: CliOptions,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) files_from: Option<String>,
 }
 
 /// Handler for the "ci" command of the Biome CLI
-pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), CliDiagnostic> {
+pub(crate) fn ci(mut session: CliSession, payload: CiCommandPayload) -> Result<(), CliDiagnostic> {
     let CiCommandPayload {
         cli_options,
         formatter_enabled,
@@ -36,12 +39,14 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
         configuration,
         mut paths,
         since,
+        files_from,
         changed,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
-    let loaded_configuration =
+    let mut loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
+    apply_configuration_overrides(&mut loaded_configuration, &cli_options.config_override);
 
     validate_configuration_diagnostics(
         &loaded_configuration,
@@ -111,12 +116,18 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
     let (vcs_base_path, gitignore_matches) =
         fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
 
-    if since.is_some() && !changed {
-        return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
-    }
-
-    if changed {
-        paths = get_changed_files(&session.app.fs, &fs_configuration, since)?;
+    let is_files_from = files_from.is_some();
+    if let Some(_paths) = get_files_to_process(
+        since,
+        changed,
+        false,
+        files_from,
+        &paths,
+        &mut session,
+        &fs_configuration,
+        "ci",
+    )? {
+        paths = _paths;
     }
 
     session
@@ -141,6 +152,7 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
         Execution::new_ci(VcsTargeted {
             staged: false,
             changed,
+            files_from: is_files_from,
         })
         .set_report(&cli_options),
         session,
@@ -1,6 +1,7 @@
 use crate::cli_options::CliOptions;
 use crate::commands::{
-    get_files_to_process, get_stdin, resolve_manifest, validate_configuration_diagnostics,
+    apply_configuration_overrides, get_files_to_process, get_stdin, resolve_manifest,
+    validate_configuration_diagnostics,
 };
 use crate::diagnostics::DeprecatedArgument;
 use crate::execute::VcsTargeted;
@@ -11,6 +12,7 @@ use biome_configuration::vcs::PartialVcsConfiguration;
 use biome_configuration::{
     PartialCssFormatter, PartialFilesConfiguration, PartialFormatterConfiguration,
     PartialGraphqlFormatter, PartialJavascriptFormatter, PartialJsonFormatter,
+    PartialTomlFormatter,
 };
 use biome_console::{markup, ConsoleExt};
 use biome_deserialize::Merge;
@@ -20,30 +22,37 @@ use biome_service::configuration::{
 };
 use biome_service::workspace::{RegisterProjectFolderParams, UpdateSettingsParams};
 use std::ffi::OsString;
+use std::path::PathBuf;
 
-use super::check_fix_incompatible_arguments;
+use super::{check_fix_incompatible_arguments, determine_dry_run};
 
 pub(crate) struct FormatCommandPayload {
     pub(crate) javascript_formatter: Option<PartialJavascriptFormatter>,
     pub(crate) json_formatter: Option<PartialJsonFormatter>,
     pub(crate) css_formatter: Option<PartialCssFormatter>,
     pub(crate) graphql_formatter: Option<PartialGraphqlFormatter>,
+    pub(crate) toml_formatter: Option<PartialTomlFormatter>,
     pub(crate) formatter_configuration: Option<PartialFormatterConfiguration>,
     pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
     pub(crate) files_configuration: Option<PartialFilesConfiguration>,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) write: bool,
     pub(crate) fix: bool,
+    pub(crate) dry_run: bool,
+    pub(crate) patch_path: Option<PathBuf>,
+    pub(crate) verify: bool,
     pub(crate) cli_options: CliOptions,
     pub(crate) paths: Vec<OsString>,
     pub(crate) staged: bool,
+    pub(crate) staged_hunks: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) files_from: Option<String>,
 }
 
 /// Handler for the "format" command of the Biome CLI
 pub(crate) fn format(
-    session: CliSession,
+    mut session: CliSession,
     payload: FormatCommandPayload,
 ) -> Result<(), CliDiagnostic> {
     let FormatCommandPayload {
@@ -56,11 +65,17 @@ pub(crate) fn format(
         files_configuration,
         write,
         fix,
+        dry_run,
+        patch_path,
+        verify,
         mut json_formatter,
         css_formatter,
         graphql_formatter,
+        toml_formatter,
         since,
+        files_from,
         staged,
+        staged_hunks,
         changed,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
@@ -72,9 +87,43 @@ pub(crate) fn format(
         fix,
         unsafe_: false,
     })?;
+    if verify && (write || fix) {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--verify",
+            if fix { "--fix" } else { "--write" },
+        ));
+    }
+    let dry_run = determine_dry_run(
+        dry_run,
+        patch_path,
+        write || fix,
+        stdin_file_path.as_deref(),
+        "format",
+    )?;
+    if verify && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--verify",
+            "--stdin-file-path",
+        ));
+    }
+    if staged_hunks {
+        if staged {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "staged",
+                "staged-hunks",
+            ));
+        }
+        if stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "stdin-file-path",
+                "staged-hunks",
+            ));
+        }
+    }
 
-    let loaded_configuration =
+    let mut loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
+    apply_configuration_overrides(&mut loaded_configuration, &cli_options.config_override);
     validate_configuration_diagnostics(
         &loaded_configuration,
         session.app.console,
@@ -202,6 +251,10 @@ pub(crate) fn format(
         let graphql = configuration.graphql.get_or_insert_with(Default::default);
         graphql.formatter.merge_with(graphql_formatter);
     }
+    if toml_formatter.is_some() {
+        let toml = configuration.toml.get_or_insert_with(Default::default);
+        toml.formatter.merge_with(toml_formatter);
+    }
 
     if javascript_formatter.is_some() {
         let javascript = configuration
@@ -222,9 +275,25 @@ pub(crate) fn format(
     let (vcs_base_path, gitignore_matches) =
         configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
 
-    if let Some(_paths) =
-        get_files_to_process(since, changed, staged, &session.app.fs, &configuration)?
-    {
+    if files_from.as_deref() == Some("-") && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "files-from",
+            "stdin-file-path",
+        ));
+    }
+
+    let is_files_from = files_from.is_some();
+    // The staged hunks are formatted in the files that have been staged
+    if let Some(_paths) = get_files_to_process(
+        since,
+        changed,
+        staged || staged_hunks,
+        files_from,
+        &paths,
+        &mut session,
+        &configuration,
+        "format",
+    )? {
         paths = _paths;
     }
 
@@ -246,15 +315,22 @@ pub(crate) fn format(
             gitignore_matches,
         })?;
 
-    let stdin = get_stdin(stdin_file_path, console, "format")?;
+    let stdin = get_stdin(stdin_file_path, &mut *session.app.console, "format")?;
 
     let execution = Execution::new(TraversalMode::Format {
         ignore_errors: cli_options.skip_errors,
         write: write || fix,
+        verify,
+        staged_hunks,
         stdin,
-        vcs_targeted: VcsTargeted { staged, changed },
+        vcs_targeted: VcsTargeted {
+            staged: staged || staged_hunks,
+            changed,
+            files_from: is_files_from,
+        },
     })
-    .set_report(&cli_options);
+    .set_report(&cli_options)
+    .set_dry_run(dry_run);
 
     execute_mode(execution, session, &cli_options, paths)
 }
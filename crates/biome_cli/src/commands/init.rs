@@ -1,17 +1,41 @@
 use crate::{CliDiagnostic, CliSession};
-use biome_configuration::PartialConfiguration;
-use biome_console::{markup, ConsoleExt};
-use biome_fs::ConfigName;
+use biome_configuration::analyzer::{Correctness, Suspicious};
+use biome_configuration::vcs::PartialVcsConfiguration;
+use biome_configuration::{
+    PartialConfiguration, PartialFilesConfiguration, RuleConfiguration, RuleFixConfiguration,
+    RulePlainConfiguration, Rules,
+};
+use biome_console::{markup, Console, ConsoleExt, LogLevel};
+use biome_fs::{ConfigName, FileSystem};
 use biome_service::configuration::create_config;
+use std::path::{Path, PathBuf};
 
-pub(crate) fn init(mut session: CliSession, emit_jsonc: bool) -> Result<(), CliDiagnostic> {
-    let fs = &mut session.app.fs;
-    create_config(fs, PartialConfiguration::init(), emit_jsonc)?;
+pub(crate) fn init(
+    mut session: CliSession,
+    emit_jsonc: bool,
+    interactive: bool,
+) -> Result<(), CliDiagnostic> {
     let file_created = if emit_jsonc {
         ConfigName::biome_jsonc()
     } else {
         ConfigName::biome_json()
     };
+    let configuration = if interactive {
+        let profile = ProjectProfile::detect(&**session.app.fs);
+        let console = &mut *session.app.console;
+        profile.print(console);
+        if !confirm(console, file_created) {
+            console.log(markup! {
+                <Info>"No configuration file was created."</Info>
+            });
+            return Ok(());
+        }
+        profile.configuration()
+    } else {
+        PartialConfiguration::init()
+    };
+    let fs = &mut session.app.fs;
+    create_config(fs, configuration, emit_jsonc)?;
     session.app.console.log(markup! {
 "
 Welcome to Biome! Let's get you started...
@@ -45,3 +69,303 @@ Welcome to Biome! Let's get you started...
     });
     Ok(())
 }
+
+const PACKAGE_JSON: &str = "package.json";
+
+const GIT_DIRECTORY: &str = ".git";
+
+const GIT_IGNORE_FILE: &str = ".gitignore";
+
+/// The configuration files of ESLint, flat and legacy
+const ESLINT_CONFIG_FILES: [&str; 10] = [
+    "eslint.config.js",
+    "eslint.config.mjs",
+    "eslint.config.cjs",
+    "eslint.config.ts",
+    ".eslintrc.js",
+    ".eslintrc.cjs",
+    ".eslintrc.yaml",
+    ".eslintrc.yml",
+    ".eslintrc.json",
+    ".eslintrc",
+];
+
+/// The configuration files of Prettier
+const PRETTIER_CONFIG_FILES: [&str; 11] = [
+    ".prettierrc",
+    ".prettierrc.json",
+    ".prettierrc.yaml",
+    ".prettierrc.yml",
+    ".prettierrc.json5",
+    ".prettierrc.toml",
+    ".prettierrc.js",
+    ".prettierrc.mjs",
+    ".prettierrc.cjs",
+    "prettier.config.js",
+    "prettier.config.mjs",
+];
+
+/// The fields of `package.json` that list the dependencies of the project
+const DEPENDENCY_FIELDS: [&str; 3] = ["dependencies", "devDependencies", "peerDependencies"];
+
+/// The type of a project, detected from its `package.json`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ProjectKind {
+    /// A project that depends on React, Preact or Next.js
+    React,
+    /// A project that depends on Vue or Nuxt
+    Vue,
+    /// A package that exports its modules or its types to other projects
+    Library,
+    /// Any other project that has a `package.json`
+    Node,
+}
+
+impl ProjectKind {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::React => "React",
+            Self::Vue => "Vue",
+            Self::Library => "library",
+            Self::Node => "Node.js",
+        }
+    }
+}
+
+/// What `biome init --interactive` knows about the project in the working directory
+#[derive(Debug)]
+struct ProjectProfile {
+    /// The type of the project, or `None` when there isn't any `package.json`
+    kind: Option<ProjectKind>,
+    /// Whether the project is a Git repository
+    has_git: bool,
+    /// Whether the project has a `.gitignore` file
+    has_git_ignore: bool,
+    /// The configuration file of ESLint, if any
+    eslint: Option<&'static str>,
+    /// The configuration file of Prettier, if any
+    prettier: Option<&'static str>,
+    /// The directories generated by the tools of the project, which aren't checked
+    generated_directories: Vec<&'static str>,
+}
+
+impl ProjectProfile {
+    /// Inspects the working directory
+    fn detect(fs: &dyn FileSystem) -> Self {
+        let manifest = fs
+            .path_exists(Path::new(PACKAGE_JSON))
+            .then(|| fs.read_file_from_path(&PathBuf::from(PACKAGE_JSON)).ok())
+            .flatten()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok());
+        let has_dependency = |name: &str| {
+            manifest.as_ref().is_some_and(|manifest| {
+                DEPENDENCY_FIELDS.iter().any(|field| {
+                    manifest
+                        .get(field)
+                        .and_then(|deps| deps.get(name))
+                        .is_some()
+                })
+            })
+        };
+        let has_field = |name: &str| {
+            manifest
+                .as_ref()
+                .is_some_and(|manifest| manifest.get(name).is_some())
+        };
+        let kind = manifest.as_ref().map(|_| {
+            if ["react", "preact", "next"].into_iter().any(has_dependency) {
+                ProjectKind::React
+            } else if ["vue", "nuxt"].into_iter().any(has_dependency) {
+                ProjectKind::Vue
+            } else if ["exports", "types", "typings", "peerDependencies"]
+                .into_iter()
+                .any(has_field)
+            {
+                ProjectKind::Library
+            } else {
+                ProjectKind::Node
+            }
+        });
+        let find_config = |files: &[&'static str], package_field: &str| {
+            files
+                .iter()
+                .copied()
+                .find(|file| fs.path_exists(Path::new(file)))
+                .or_else(|| has_field(package_field).then_some(PACKAGE_JSON))
+        };
+        let mut generated_directories = vec!["dist", "coverage"];
+        match kind {
+            Some(ProjectKind::React) if has_dependency("next") => {
+                generated_directories.extend([".next", "out"])
+            }
+            Some(ProjectKind::React) => generated_directories.push("build"),
+            Some(ProjectKind::Vue) if has_dependency("nuxt") => {
+                generated_directories.extend([".nuxt", ".output"])
+            }
+            _ => {}
+        }
+        Self {
+            kind,
+            has_git: fs.path_exists(Path::new(GIT_DIRECTORY)),
+            has_git_ignore: fs.path_exists(Path::new(GIT_IGNORE_FILE)),
+            eslint: find_config(&ESLINT_CONFIG_FILES, "eslintConfig"),
+            prettier: find_config(&PRETTIER_CONFIG_FILES, "prettier"),
+            generated_directories,
+        }
+    }
+
+    /// Whether the VCS integration is enabled
+    fn uses_vcs(&self) -> bool {
+        self.has_git || self.has_git_ignore
+    }
+
+    /// The directories that are ignored with `files.ignore`.
+    ///
+    /// They aren't needed when the project has a `.gitignore` file, which usually lists them.
+    fn ignored_directories(&self) -> &[&'static str] {
+        if self.has_git_ignore {
+            &[]
+        } else {
+            &self.generated_directories
+        }
+    }
+
+    /// The rules enabled in addition to the recommended rules
+    fn extra_rules(&self) -> &'static [&'static str] {
+        match self.kind {
+            Some(ProjectKind::React) => &["correctness/useHookAtTopLevel"],
+            Some(ProjectKind::Library) => &["suspicious/noConsoleLog"],
+            Some(ProjectKind::Vue | ProjectKind::Node) | None => &[],
+        }
+    }
+
+    /// Returns the configuration proposed for the project
+    fn configuration(&self) -> PartialConfiguration {
+        let mut configuration = PartialConfiguration::init();
+        if self.uses_vcs() {
+            configuration.vcs = Some(PartialVcsConfiguration {
+                enabled: Some(true),
+                use_ignore_file: Some(self.has_git_ignore),
+                ..configuration.vcs.unwrap_or_default()
+            });
+        }
+        configuration.files = Some(PartialFilesConfiguration {
+            ignore: Some(
+                self.ignored_directories()
+                    .iter()
+                    .map(|directory| directory.to_string())
+                    .collect(),
+            ),
+            ..configuration.files.unwrap_or_default()
+        });
+        if let Some(rules) = configuration
+            .linter
+            .as_mut()
+            .and_then(|linter| linter.rules.as_mut())
+        {
+            set_extra_rules(rules, self.kind);
+        }
+        configuration
+    }
+
+    /// Prints what was detected, and the configuration proposed for the project
+    fn print(&self, console: &mut dyn Console) {
+        match self.kind {
+            Some(kind) => {
+                let kind = kind.name();
+                console.log(markup! {
+                    "Detected a "<Emphasis>{kind}</Emphasis>" project."
+                });
+            }
+            None => console.log(markup! {
+                "No "<Emphasis>{PACKAGE_JSON}</Emphasis>" was found, the default configuration is proposed."
+            }),
+        }
+        if self.has_git {
+            console.log(markup! {
+                "  "<Dim>"- "</Dim>"Git repository"
+            });
+        }
+        if let Some(eslint) = self.eslint {
+            console.log(markup! {
+                "  "<Dim>"- "</Dim>"ESLint configuration in "<Emphasis>{eslint}</Emphasis>", migrate it with "<Italic>"biome migrate eslint --write"</Italic>
+            });
+        }
+        if let Some(prettier) = self.prettier {
+            console.log(markup! {
+                "  "<Dim>"- "</Dim>"Prettier configuration in "<Emphasis>{prettier}</Emphasis>", migrate it with "<Italic>"biome migrate prettier --write"</Italic>
+            });
+        }
+        console.log(markup! {
+            "\n"<Info><Emphasis>"Proposed configuration"</Emphasis></Info>
+        });
+        if self.uses_vcs() {
+            if self.has_git_ignore {
+                console.log(markup! {
+                    "  "<Dim>"- "</Dim>"The Git integration is enabled, and the files listed in "<Emphasis>{GIT_IGNORE_FILE}</Emphasis>" are ignored"
+                });
+            } else {
+                console.log(markup! {
+                    "  "<Dim>"- "</Dim>"The Git integration is enabled"
+                });
+            }
+        }
+        let ignored = self.ignored_directories().join(", ");
+        if !ignored.is_empty() {
+            console.log(markup! {
+                "  "<Dim>"- "</Dim>"The generated directories are ignored: "<Emphasis>{ignored}</Emphasis>
+            });
+        }
+        console.log(markup! {
+            "  "<Dim>"- "</Dim>"The recommended rules are enabled"
+        });
+        for rule in self.extra_rules() {
+            console.log(markup! {
+                "  "<Dim>"- "</Dim>"The rule "<Emphasis>{rule}</Emphasis>" is enabled"
+            });
+        }
+    }
+}
+
+/// Enables the rules that fit the type of the project, in addition to the recommended rules
+fn set_extra_rules(rules: &mut Rules, kind: Option<ProjectKind>) {
+    match kind {
+        Some(ProjectKind::React) => {
+            rules.correctness = Some(Correctness {
+                use_hook_at_top_level: Some(RuleConfiguration::Plain(
+                    RulePlainConfiguration::Error,
+                )),
+                ..Default::default()
+            });
+        }
+        Some(ProjectKind::Library) => {
+            rules.suspicious = Some(Suspicious {
+                no_console_log: Some(RuleFixConfiguration::Plain(RulePlainConfiguration::Error)),
+                ..Default::default()
+            });
+        }
+        Some(ProjectKind::Vue | ProjectKind::Node) | None => {}
+    }
+}
+
+/// Asks whether the configuration file must be created.
+///
+/// It's `false` when the console doesn't have any input left.
+fn confirm(console: &mut dyn Console, file: &str) -> bool {
+    loop {
+        console.print(
+            LogLevel::Log,
+            markup! {
+                "\nCreate "<Emphasis>{file}</Emphasis>" with this configuration? "<Dim>"[y]es, [n]o: "</Dim>
+            },
+        );
+        let Some(line) = console.read_line() else {
+            return false;
+        };
+        match line.trim() {
+            "y" | "yes" => return true,
+            "n" | "no" => return false,
+            _ => {}
+        }
+    }
+}
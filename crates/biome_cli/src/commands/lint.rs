@@ -1,6 +1,7 @@
 use crate::cli_options::CliOptions;
 use crate::commands::{
-    get_files_to_process, get_stdin, resolve_manifest, validate_configuration_diagnostics,
+    apply_configuration_overrides, get_files_to_process, get_stdin, resolve_manifest,
+    validate_configuration_diagnostics,
 };
 use crate::execute::VcsTargeted;
 use crate::{
@@ -21,13 +22,19 @@ use biome_service::configuration::{
 };
 use biome_service::workspace::{RegisterProjectFolderParams, UpdateSettingsParams};
 use std::ffi::OsString;
+use std::path::PathBuf;
 
-use super::{determine_fix_file_mode, FixFileModeOptions};
+use super::{determine_dry_run, determine_fix_file_mode, FixFileModeOptions};
 
 pub(crate) struct LintCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
     pub(crate) write: bool,
+    pub(crate) dry_run: bool,
+    pub(crate) patch_path: Option<PathBuf>,
+    pub(crate) interactive: bool,
+    pub(crate) explain_fixes: bool,
+    pub(crate) fix_log: Option<PathBuf>,
     pub(crate) fix: bool,
     pub(crate) unsafe_: bool,
     pub(crate) cli_options: CliOptions,
@@ -41,6 +48,8 @@ pub(crate) struct LintCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) files_from: Option<String>,
+    pub(crate) profile: bool,
     pub(crate) javascript_linter: Option<PartialJavascriptLinter>,
     pub(crate) json_linter: Option<PartialJsonLinter>,
     pub(crate) css_linter: Option<PartialCssLinter>,
@@ -48,11 +57,19 @@ pub(crate) struct LintCommandPayload {
 }
 
 /// Handler for the "lint" command of the Biome CLI
-pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(), CliDiagnostic> {
+pub(crate) fn lint(
+    mut session: CliSession,
+    payload: LintCommandPayload,
+) -> Result<(), CliDiagnostic> {
     let LintCommandPayload {
         apply,
         apply_unsafe,
         write,
+        dry_run,
+        patch_path,
+        interactive,
+        explain_fixes,
+        fix_log,
         fix,
         unsafe_,
         cli_options,
@@ -66,6 +83,8 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         staged,
         changed,
         since,
+        files_from,
+        profile,
         javascript_linter,
         css_linter,
         json_linter,
@@ -83,9 +102,56 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         },
         session.app.console,
     )?;
+    let dry_run = determine_dry_run(
+        dry_run,
+        patch_path,
+        fix_file_mode.is_some(),
+        stdin_file_path.as_deref(),
+        "lint",
+    )?;
+    if interactive {
+        if fix_file_mode.is_none() {
+            return Err(CliDiagnostic::missing_argument("--write", "lint"));
+        }
+        if dry_run.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--interactive",
+                "--dry-run",
+            ));
+        }
+        if stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--interactive",
+                "--stdin-file-path",
+            ));
+        }
+    }
+    if explain_fixes || fix_log.is_some() {
+        let argument = if explain_fixes {
+            "--explain-fixes"
+        } else {
+            "--fix-log"
+        };
+        if fix_file_mode.is_none() {
+            return Err(CliDiagnostic::missing_argument("--write", "lint"));
+        }
+        if interactive {
+            return Err(CliDiagnostic::incompatible_arguments(
+                argument,
+                "--interactive",
+            ));
+        }
+        if stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                argument,
+                "--stdin-file-path",
+            ));
+        }
+    }
 
-    let loaded_configuration =
+    let mut loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
+    apply_configuration_overrides(&mut loaded_configuration, &cli_options.config_override);
     validate_configuration_diagnostics(
         &loaded_configuration,
         session.app.console,
@@ -139,8 +205,24 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         json.linter.merge_with(json_linter);
     }
 
-    let vcs_targeted_paths =
-        get_files_to_process(since, changed, staged, &session.app.fs, &fs_configuration)?;
+    if files_from.as_deref() == Some("-") && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "files-from",
+            "stdin-file-path",
+        ));
+    }
+
+    let is_files_from = files_from.is_some();
+    let vcs_targeted_paths = get_files_to_process(
+        since,
+        changed,
+        staged,
+        files_from,
+        &paths,
+        &mut session,
+        &fs_configuration,
+        "lint",
+    )?;
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
@@ -173,9 +255,18 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
             stdin,
             only,
             skip,
-            vcs_targeted: VcsTargeted { staged, changed },
+            vcs_targeted: VcsTargeted {
+                staged,
+                changed,
+                files_from: is_files_from,
+            },
+            profile,
+            interactive,
+            explain_fixes,
+            fix_log,
         })
-        .set_report(&cli_options),
+        .set_report(&cli_options)
+        .set_dry_run(dry_run),
         session,
         &cli_options,
         vcs_targeted_paths.unwrap_or(paths),
@@ -0,0 +1,181 @@
+use crate::diagnostics::ReportDiagnostic;
+use crate::reporter::json::JSON_SCHEMA_VERSION;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::adapters::SerdeJsonError;
+use biome_fs::BiomePath;
+use biome_service::workspace::{FormatFileParams, OpenFileParams};
+use biome_service::WorkspaceError;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+/// Handler for the "merge-reports" command of the Biome CLI
+pub(crate) fn merge_reports(
+    session: CliSession,
+    pretty: bool,
+    reports: Vec<PathBuf>,
+) -> Result<(), CliDiagnostic> {
+    let mut merged = MergedReport::default();
+    for path in &reports {
+        let display_path = path.display().to_string();
+        let content = session
+            .app
+            .fs
+            .read_file_from_path(path)
+            .map_err(WorkspaceError::from)?;
+        let report = serde_json::from_str(&content)
+            .map_err(|_| CliDiagnostic::invalid_report(&display_path, "it isn't valid JSON."))?;
+        merged.add(&display_path, report)?;
+    }
+
+    let console = session.app.console;
+    if let Some(shards) = &merged.shards {
+        let missing: Vec<_> = (1..=shards.count)
+            .filter(|index| !shards.indices.contains(index))
+            .map(|index| format!("{index}/{}", shards.count))
+            .collect();
+        if !missing.is_empty() {
+            let missing = missing.join(", ");
+            console.error(markup! {
+                <Warn>"The reports of these shards are missing: "{missing}"."</Warn>
+            });
+        }
+    }
+
+    let content = serde_json::to_string(&merged.into_report()).map_err(|error| {
+        CliDiagnostic::Report(ReportDiagnostic::Serialization(SerdeJsonError::from(error)))
+    })?;
+    if pretty {
+        let report_file = BiomePath::new("_report_output.json");
+        session.app.workspace.open_file(OpenFileParams {
+            content,
+            path: report_file.clone(),
+            version: 0,
+            document_file_source: None,
+        })?;
+        let code = session.app.workspace.format_file(FormatFileParams {
+            path: report_file.clone(),
+        })?;
+        console.log(markup! {
+            {code.as_code()}
+        });
+    } else {
+        console.log(markup! {
+            {content}
+        });
+    }
+    Ok(())
+}
+
+/// The reports merged so far
+#[derive(Debug, Default)]
+struct MergedReport {
+    command: Option<String>,
+    summary: Map<String, Value>,
+    diagnostics: Vec<Value>,
+    /// The shards of the merged reports, if the reports come from `--shard`
+    shards: Option<MergedShards>,
+}
+
+#[derive(Debug)]
+struct MergedShards {
+    count: u64,
+    indices: BTreeSet<u64>,
+}
+
+impl MergedReport {
+    /// Adds the JSON report read from `path`.
+    ///
+    /// The counts of the summaries are added, and the duration is the longest one,
+    /// because the shards usually run in parallel.
+    fn add(&mut self, path: &str, report: Value) -> Result<(), CliDiagnostic> {
+        let invalid = |reason: String| CliDiagnostic::invalid_report(path, reason);
+        let Value::Object(mut report) = report else {
+            return Err(invalid("it isn't a JSON report.".to_string()));
+        };
+        if report.get("schemaVersion").and_then(Value::as_u64) != Some(JSON_SCHEMA_VERSION.into()) {
+            return Err(invalid(format!(
+                "it doesn't use the version {JSON_SCHEMA_VERSION} of the schema of the JSON reports."
+            )));
+        }
+
+        let command = report
+            .get("command")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        match &self.command {
+            Some(merged_command) if merged_command != command => {
+                return Err(invalid(format!(
+                    "it's a report of the command {command}, and the other reports are reports of the command {merged_command}."
+                )));
+            }
+            Some(_) => {}
+            None => self.command = Some(command.to_string()),
+        }
+
+        if let Some(shard) = report.get("shard") {
+            let index = shard.get("index").and_then(Value::as_u64).unwrap_or(0);
+            let count = shard.get("count").and_then(Value::as_u64).unwrap_or(0);
+            let shards = self.shards.get_or_insert_with(|| MergedShards {
+                count,
+                indices: BTreeSet::new(),
+            });
+            if shards.count != count {
+                return Err(invalid(format!(
+                    "it's a report of one of {count} shards, and the other reports are reports of one of {} shards.",
+                    shards.count
+                )));
+            }
+            if !shards.indices.insert(index) {
+                return Err(invalid(format!(
+                    "the report of the shard {index}/{count} was already merged."
+                )));
+            }
+        }
+
+        if let Some(Value::Object(summary)) = report.remove("summary") {
+            for (key, value) in summary {
+                match self.summary.get_mut(&key) {
+                    Some(merged) if key == "duration" => {
+                        if duration(&value) > duration(merged) {
+                            *merged = value;
+                        }
+                    }
+                    Some(merged) => {
+                        if let (Some(merged_count), Some(count)) = (merged.as_u64(), value.as_u64())
+                        {
+                            *merged = Value::from(merged_count + count);
+                        }
+                    }
+                    None => {
+                        self.summary.insert(key, value);
+                    }
+                }
+            }
+        }
+
+        if let Some(Value::Array(diagnostics)) = report.remove("diagnostics") {
+            self.diagnostics.extend(diagnostics);
+        }
+        Ok(())
+    }
+
+    fn into_report(self) -> Value {
+        let mut report = Map::new();
+        report.insert("schemaVersion".to_string(), JSON_SCHEMA_VERSION.into());
+        report.insert("summary".to_string(), Value::Object(self.summary));
+        report.insert("diagnostics".to_string(), Value::Array(self.diagnostics));
+        report.insert(
+            "command".to_string(),
+            self.command.unwrap_or_default().into(),
+        );
+        Value::Object(report)
+    }
+}
+
+/// Returns the seconds and the nanoseconds of a serialized [std::time::Duration].
+fn duration(value: &Value) -> (u64, u64) {
+    let field = |name| value.get(name).and_then(Value::as_u64).unwrap_or(0);
+    (field("secs"), field("nanos"))
+}
@@ -1,7 +1,8 @@
 use crate::changed::{get_changed_files, get_staged_files};
-use crate::cli_options::{cli_options, CliOptions, CliReporter, ColorsArg};
+use crate::cli_options::{cli_options, CliOptions, CliReporter, ColorsArg, ConfigOverride};
+use crate::commands::assist::AssistAction;
 use crate::diagnostics::{DeprecatedArgument, DeprecatedConfigurationFile};
-use crate::execute::Stdin;
+use crate::execute::{DryRun, Stdin};
 use crate::logging::LoggingKind;
 use crate::{CliDiagnostic, CliSession, LoggingLevel, VERSION};
 use biome_configuration::analyzer::RuleSelector;
@@ -13,24 +14,30 @@ use biome_configuration::{
     graphql::partial_graphql_linter, javascript::partial_javascript_formatter,
     javascript::partial_javascript_linter, json::partial_json_formatter, json::partial_json_linter,
     partial_configuration, partial_files_configuration, partial_formatter_configuration,
-    partial_linter_configuration, vcs::partial_vcs_configuration, vcs::PartialVcsConfiguration,
-    PartialCssFormatter, PartialFilesConfiguration, PartialFormatterConfiguration,
-    PartialGraphqlFormatter, PartialGraphqlLinter, PartialJavascriptFormatter,
-    PartialJsonFormatter, PartialLinterConfiguration,
+    partial_linter_configuration, toml::partial_toml_formatter, vcs::partial_vcs_configuration,
+    vcs::PartialVcsConfiguration, PartialCssFormatter, PartialFilesConfiguration,
+    PartialFormatterConfiguration, PartialGraphqlFormatter, PartialGraphqlLinter,
+    PartialJavascriptFormatter, PartialJsonFormatter, PartialLinterConfiguration,
+    PartialTomlFormatter,
 };
 use biome_configuration::{BiomeDiagnostic, PartialConfiguration};
 use biome_console::{markup, Console, ConsoleExt};
-use biome_diagnostics::{Diagnostic, PrintDiagnostic};
-use biome_fs::{BiomePath, FileSystem};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::Merge;
+use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic};
+use biome_fs::BiomePath;
+use biome_json_parser::JsonParserOptions;
 use biome_service::configuration::LoadedConfiguration;
 use biome_service::documentation::Doc;
 use biome_service::workspace::{FixFileMode, OpenProjectParams, UpdateProjectParams};
-use biome_service::{DynRef, WorkspaceError};
+use biome_service::WorkspaceError;
 use bpaf::Bpaf;
 use std::ffi::OsString;
 use std::path::PathBuf;
 
+pub(crate) mod assist;
 pub(crate) mod check;
+pub(crate) mod check_config;
 pub(crate) mod ci;
 pub(crate) mod clean;
 pub(crate) mod daemon;
@@ -38,6 +45,7 @@ pub(crate) mod explain;
 pub(crate) mod format;
 pub(crate) mod init;
 pub(crate) mod lint;
+pub(crate) mod merge_reports;
 pub(crate) mod migrate;
 pub(crate) mod rage;
 pub(crate) mod search;
@@ -121,6 +129,15 @@ pub enum BiomeCommand {
         #[bpaf(long("apply-unsafe"), switch, hide_usage)]
         apply_unsafe: bool,
 
+        /// Computes the fixes, formatting and import sorting without modifying the files, and prints them as a unified diff.
+        /// The diff can be applied with `git apply`. Should be used with `--write`
+        #[bpaf(long("dry-run"), switch)]
+        dry_run: bool,
+
+        /// Writes the unified diff of `--dry-run` to the given file, instead of printing it
+        #[bpaf(long("patch-path"), argument("PATH"), optional)]
+        patch_path: Option<PathBuf>,
+
         /// Allow to enable or disable the formatter check.
         #[bpaf(
             long("formatter-enabled"),
@@ -172,6 +189,13 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Reads the paths to process from the given file, one path per line, or from `stdin` when the value is `-`.
+        /// The paths are passed as they are, and can't be combined with the paths given as arguments. An empty list processes no file.
+        ///
+        /// Example: `git ls-files '*.js' | biome check --files-from=-`
+        #[bpaf(long("files-from"), argument("PATH"))]
+        files_from: Option<String>,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -199,6 +223,30 @@ pub enum BiomeCommand {
         #[bpaf(long("apply-unsafe"), switch, hide_usage)]
         apply_unsafe: bool,
 
+        /// Computes the fixes without modifying the files, and prints them as a unified diff.
+        /// The diff can be applied with `git apply`. Should be used with `--write`
+        #[bpaf(long("dry-run"), switch)]
+        dry_run: bool,
+
+        /// Writes the unified diff of `--dry-run` to the given file, instead of printing it
+        #[bpaf(long("patch-path"), argument("PATH"), optional)]
+        patch_path: Option<PathBuf>,
+
+        /// Prints the fixes one by one, and asks whether to write each of them.
+        /// Should be used with `--write`, and with `--unsafe` to review the unsafe fixes too
+        #[bpaf(long("interactive"), switch)]
+        interactive: bool,
+
+        /// Prints the rule that produced each applied fix, with the code before and after it.
+        /// Should be used with `--write`
+        #[bpaf(long("explain-fixes"), switch)]
+        explain_fixes: bool,
+
+        /// Writes the applied fixes, with their rule and the code before and after them, to the given JSON file.
+        /// Should be used with `--write`
+        #[bpaf(long("fix-log"), argument("PATH"), optional)]
+        fix_log: Option<PathBuf>,
+
         #[bpaf(external(partial_linter_configuration), hide_usage, optional)]
         linter_configuration: Option<PartialLinterConfiguration>,
 
@@ -257,6 +305,16 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+        /// Measure the time spent running each rule, and print the most expensive rules at the end of the run.
+        /// The measurements aren't available when the rules run in the daemon, with `--use-server`.
+        #[bpaf(long("profile"), switch)]
+        profile: bool,
+        /// Reads the paths to process from the given file, one path per line, or from `stdin` when the value is `-`.
+        /// The paths are passed as they are, and can't be combined with the paths given as arguments. An empty list processes no file.
+        ///
+        /// Example: `git ls-files '*.js' | biome lint --files-from=-`
+        #[bpaf(long("files-from"), argument("PATH"))]
+        files_from: Option<String>,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -279,6 +337,9 @@ pub enum BiomeCommand {
         #[bpaf(external(partial_graphql_formatter), optional, hide_usage, hide)]
         graphql_formatter: Option<PartialGraphqlFormatter>,
 
+        #[bpaf(external(partial_toml_formatter), optional, hide_usage, hide)]
+        toml_formatter: Option<PartialTomlFormatter>,
+
         #[bpaf(external(partial_vcs_configuration), optional, hide_usage)]
         vcs_configuration: Option<PartialVcsConfiguration>,
 
@@ -303,11 +364,35 @@ pub enum BiomeCommand {
         #[bpaf(long("fix"), switch, hide_usage)]
         fix: bool,
 
+        /// Computes the formatting without modifying the files, and prints them as a unified diff.
+        /// The diff can be applied with `git apply`. Should be used with `--write`
+        #[bpaf(long("dry-run"), switch)]
+        dry_run: bool,
+
+        /// Writes the unified diff of `--dry-run` to the given file, instead of printing it
+        #[bpaf(long("patch-path"), argument("PATH"), optional)]
+        patch_path: Option<PathBuf>,
+
+        /// Checks the stability of the formatter instead of reporting the unformatted files.
+        ///
+        /// Each file is formatted twice: Biome reports an error if the formatted code can't be parsed,
+        /// if formatting it again changes it, or if its syntax differs from the syntax of the original code.
+        /// The files aren't modified.
+        #[bpaf(long("verify"), switch)]
+        verify: bool,
+
         /// When set to true, only the files that have been staged (the ones prepared to be committed)
         /// will be linted.
         #[bpaf(long("staged"), switch)]
         staged: bool,
 
+        /// When set to true, only the staged changes of the files that have been staged are formatted.
+        /// The content of the files in the git index is formatted, instead of the content of the working tree.
+        /// With `--write`, the formatted content is written to the git index,
+        /// and to the files of the working tree that don't have unstaged changes.
+        #[bpaf(long("staged-hunks"), switch)]
+        staged_hunks: bool,
+
         /// When set to true, only the files that have been changed compared to your `defaultBranch`
         /// configuration will be linted.
         #[bpaf(long("changed"), switch)]
@@ -318,6 +403,13 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Reads the paths to process from the given file, one path per line, or from `stdin` when the value is `-`.
+        /// The paths are passed as they are, and can't be combined with the paths given as arguments. An empty list processes no file.
+        ///
+        /// Example: `git ls-files '*.js' | biome format --files-from=-`
+        #[bpaf(long("files-from"), argument("PATH"))]
+        files_from: Option<String>,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -356,6 +448,13 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Reads the paths to process from the given file, one path per line, or from `stdin` when the value is `-`.
+        /// The paths are passed as they are, and can't be combined with the paths given as arguments. An empty list processes no file.
+        ///
+        /// Example: `git ls-files '*.js' | biome ci --files-from=-`
+        #[bpaf(long("files-from"), argument("PATH"))]
+        files_from: Option<String>,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -363,11 +462,14 @@ pub enum BiomeCommand {
 
     /// Bootstraps a new biome project. Creates a configuration file with some defaults.
     #[bpaf(command)]
-    Init(
+    Init {
         /// Tells Biome to emit a `biome.jsonc` file.
         #[bpaf(long("jsonc"), switch)]
-        bool,
-    ),
+        emit_jsonc: bool,
+        /// Detects the project type and tools, and proposes a tailored configuration.
+        #[bpaf(long("interactive"), switch)]
+        interactive: bool,
+    },
     /// Acts as a server for the Language Server Protocol over stdin/stdout
     #[bpaf(command("lsp-proxy"))]
     LspProxy {
@@ -450,6 +552,59 @@ pub enum BiomeCommand {
         paths: Vec<OsString>,
     },
 
+    /// Validates the configuration, including the configurations that it extends,
+    /// and prints the configuration that applies to each of the given files.
+    ///
+    /// ## Examples
+    ///
+    /// ```shell
+    /// biome check-config src/index.ts tests/index.test.ts
+    /// ```
+    #[bpaf(command("check-config"))]
+    CheckConfig {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// The files to print the effective configuration of.
+        /// The overrides that match a file are merged into the configuration printed for it.
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+
+    /// Runs an assist on the files of a project, such as the generation of the barrel file of a directory.
+    ///
+    /// ## Examples
+    ///
+    /// ```shell
+    /// biome assist run generateBarrelFile src/components --exclude="*.test.ts" --write
+    /// ```
+    #[bpaf(command)]
+    Assist {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        #[bpaf(external(assist_sub_command))]
+        sub_command: AssistSubCommand,
+    },
+
+    /// Merges the JSON reports of the shards of a run, created with `--shard` and `--reporter=json`, into a single JSON report.
+    ///
+    /// ## Examples
+    ///
+    /// ```shell
+    /// biome merge-reports shard-1.json shard-2.json shard-3.json
+    /// ```
+    #[bpaf(command("merge-reports"))]
+    MergeReports {
+        /// Formats the merged report, like `--reporter=json-pretty`.
+        #[bpaf(long("pretty"), switch)]
+        pretty: bool,
+
+        /// The JSON reports to merge.
+        #[bpaf(positional("PATH"), some("at least one report is required"))]
+        reports: Vec<PathBuf>,
+    },
+
     /// A command to retrieve the documentation of various aspects of the CLI.
     ///
     /// ## Examples
@@ -520,6 +675,39 @@ pub enum MigrateSubCommand {
         #[bpaf(long("include-nursery"))]
         include_nursery: bool,
     },
+    /// It attempts to find the Stylelint configuration file in the working directory, and map the Stylelint's rules into Biome's CSS rules.
+    #[bpaf(command)]
+    Stylelint {
+        /// Includes nursery rules in the migration
+        #[bpaf(long("include-nursery"))]
+        include_nursery: bool,
+    },
+}
+
+#[derive(Debug, Bpaf, Clone)]
+pub enum AssistSubCommand {
+    /// Runs an assist on a directory, and prints its result.
+    /// The only available assist is `generateBarrelFile`, which generates or updates the `index` file of the directory,
+    /// so that it re-exports the names exported by the other modules of the directory.
+    #[bpaf(command)]
+    Run {
+        /// Writes the result of the assist to disk
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
+        /// The glob pattern of the file names of the modules that the barrel file doesn't re-export,
+        /// such as `--exclude="*.test.ts"`. The option can be repeated.
+        #[bpaf(long("exclude"), argument("GLOB"), many)]
+        exclude: Vec<String>,
+
+        /// The assist to run.
+        #[bpaf(positional("ASSIST"))]
+        action: AssistAction,
+
+        /// The directory to run the assist on.
+        #[bpaf(positional("PATH"))]
+        path: PathBuf,
+    },
 }
 
 impl MigrateSubCommand {
@@ -538,12 +726,15 @@ impl BiomeCommand {
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Migrate { cli_options, .. }
+            | BiomeCommand::CheckConfig { cli_options, .. }
+            | BiomeCommand::Assist { cli_options, .. }
             | BiomeCommand::Search { cli_options, .. } => Some(cli_options),
             BiomeCommand::LspProxy { .. }
             | BiomeCommand::Start { .. }
             | BiomeCommand::Stop
-            | BiomeCommand::Init(_)
+            | BiomeCommand::Init { .. }
             | BiomeCommand::Explain { .. }
+            | BiomeCommand::MergeReports { .. }
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::Clean { .. }
             | BiomeCommand::PrintSocket => None,
@@ -596,6 +787,35 @@ impl BiomeCommand {
     }
 }
 
+/// Merges the fields overridden with `--config-override` into the loaded configuration.
+///
+/// The diagnostics emitted while deserializing the overrides are added to the diagnostics of the configuration,
+/// so that they are printed by [validate_configuration_diagnostics].
+pub(crate) fn apply_configuration_overrides(
+    loaded_configuration: &mut LoadedConfiguration,
+    config_overrides: &[ConfigOverride],
+) {
+    for config_override in config_overrides {
+        let json = config_override.to_json();
+        let (configuration, diagnostics) = deserialize_from_json_str::<PartialConfiguration>(
+            &json,
+            JsonParserOptions::default(),
+            "",
+        )
+        .consume();
+        loaded_configuration
+            .diagnostics
+            .extend(diagnostics.into_iter().map(|diagnostic| {
+                diagnostic
+                    .with_file_path("--config-override")
+                    .with_file_source_code(&json)
+            }));
+        if let Some(configuration) = configuration {
+            loaded_configuration.configuration.merge_with(configuration);
+        }
+    }
+}
+
 /// It accepts a [LoadedPartialConfiguration] and it prints the diagnostics emitted during parsing and deserialization.
 ///
 /// If it contains errors, it return an error.
@@ -692,9 +912,37 @@ fn get_files_to_process(
     since: Option<String>,
     changed: bool,
     staged: bool,
-    fs: &DynRef<'_, dyn FileSystem>,
+    files_from: Option<String>,
+    paths: &[OsString],
+    session: &mut CliSession,
     configuration: &PartialConfiguration,
+    command_name: &str,
 ) -> Result<Option<Vec<OsString>>, CliDiagnostic> {
+    if let Some(files_from) = files_from {
+        if changed {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "files-from",
+                "changed",
+            ));
+        }
+        if staged {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "files-from",
+                "staged",
+            ));
+        }
+        if since.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments("files-from", "since"));
+        }
+        if !paths.is_empty() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "files-from",
+                "<INPUT>",
+            ));
+        }
+        return Ok(Some(get_files_from(&files_from, session, command_name)?));
+    }
+
     if since.is_some() {
         if !changed {
             return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
@@ -708,14 +956,49 @@ fn get_files_to_process(
         if staged {
             return Err(CliDiagnostic::incompatible_arguments("changed", "staged"));
         }
-        Ok(Some(get_changed_files(fs, configuration, since)?))
+        Ok(Some(get_changed_files(
+            &session.app.fs,
+            configuration,
+            since,
+        )?))
     } else if staged {
-        Ok(Some(get_staged_files(fs)?))
+        Ok(Some(get_staged_files(&session.app.fs)?))
     } else {
         Ok(None)
     }
 }
 
+/// Reads the newline-delimited list of paths of `--files-from`, from `stdin` when `files_from` is `-`.
+///
+/// The empty lines are ignored.
+fn get_files_from(
+    files_from: &str,
+    session: &mut CliSession,
+    command_name: &str,
+) -> Result<Vec<OsString>, CliDiagnostic> {
+    let content = if files_from == "-" {
+        // we provided the argument without a piped stdin, we bail
+        session
+            .app
+            .console
+            .read()
+            .ok_or_else(|| CliDiagnostic::missing_argument("stdin", command_name))?
+    } else {
+        session
+            .app
+            .fs
+            .read_file_from_path(&PathBuf::from(files_from))
+            .map_err(WorkspaceError::from)?
+    };
+
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(OsString::from)
+        .collect())
+}
+
 /// Holds the options to determine the fix file mode.
 pub(crate) struct FixFileModeOptions {
     apply: bool,
@@ -766,6 +1049,36 @@ pub(crate) fn determine_fix_file_mode(
     }
 }
 
+/// Returns the [DryRun] options of the command `subcommand`.
+///
+/// - [Result]: if the given options are incompatible
+/// - [Option]: if `--dry-run` isn't set
+pub(crate) fn determine_dry_run(
+    dry_run: bool,
+    patch_path: Option<PathBuf>,
+    write: bool,
+    stdin_file_path: Option<&str>,
+    subcommand: &str,
+) -> Result<Option<DryRun>, CliDiagnostic> {
+    if !dry_run {
+        return if patch_path.is_some() {
+            Err(CliDiagnostic::missing_argument("--dry-run", subcommand))
+        } else {
+            Ok(None)
+        };
+    }
+    if !write {
+        return Err(CliDiagnostic::missing_argument("--write", subcommand));
+    }
+    if stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--dry-run",
+            "--stdin-file-path",
+        ));
+    }
+    Ok(Some(DryRun { patch_path }))
+}
+
 /// Checks if the fix file options are incompatible.
 fn check_fix_incompatible_arguments(options: FixFileModeOptions) -> Result<(), CliDiagnostic> {
     let FixFileModeOptions {
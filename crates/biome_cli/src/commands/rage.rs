@@ -224,6 +224,7 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Line width", markup!({DebugDisplay(formatter_configuration.line_width.value())}))}
                             {KeyValuePair("Attribute position", markup!({DebugDisplay(formatter_configuration.attribute_position)}))}
                             {KeyValuePair("Bracket spacing", markup!({DebugDisplay(formatter_configuration.bracket_spacing)}))}
+                            {KeyValuePair("Comments", markup!({DebugDisplay(&formatter_configuration.comments)}))}
                             {KeyValuePair("Ignore", markup!({DebugDisplay(formatter_configuration.ignore.iter().collect::<Vec<_>>())}))}
                             {KeyValuePair("Include", markup!({DebugDisplay(formatter_configuration.include.iter().collect::<Vec<_>>())}))}
                         ).fmt(fmt)?;
@@ -246,6 +247,11 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Line ending", markup!({DebugDisplayOption(javascript_formatter_configuration.line_ending)}))}
                             {KeyValuePair("Line width", markup!({DebugDisplayOption(javascript_formatter_configuration.line_width.map(|lw| lw.value()))}))}
                             {KeyValuePair("Attribute position", markup!({DebugDisplayOption(javascript_formatter_configuration.attribute_position)}))}
+                            {KeyValuePair("Vertical alignment", markup!({DebugDisplay(javascript_formatter_configuration.vertical_alignment)}))}
+                            {KeyValuePair("String escapes", markup!({DebugDisplay(javascript_formatter_configuration.string_escapes)}))}
+                            {KeyValuePair("Embedded language formatting", markup!({DebugDisplay(javascript_formatter_configuration.embedded_language_formatting)}))}
+                            {KeyValuePair("Max inline specifiers", markup!({DebugDisplayOption(javascript_formatter_configuration.max_inline_specifiers)}))}
+                            {KeyValuePair("Markup formatting", markup!({DebugDisplay(javascript_formatter_configuration.markup_formatting)}))}
                         )
                         .fmt(fmt)?;
 
@@ -285,6 +291,18 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Bracket spacing", markup!({DebugDisplayOption(graphql_formatter_configuration.bracket_spacing)}))}
                             {KeyValuePair("Quote style", markup!({DebugDisplayOption(graphql_formatter_configuration.quote_style)}))}
                         ).fmt(fmt)?;
+
+                        let toml_formatter_configuration =
+                            configuration.get_toml_formatter_configuration();
+                        markup! (
+                            {Section("TOML Formatter")}
+                            {KeyValuePair("Enabled", markup!({DebugDisplayOption(toml_formatter_configuration.enabled)}))}
+                            {KeyValuePair("Indent style", markup!({DebugDisplayOption(toml_formatter_configuration.indent_style)}))}
+                            {KeyValuePair("Indent width", markup!({DebugDisplayOption(toml_formatter_configuration.indent_width)}))}
+                            {KeyValuePair("Line ending", markup!({DebugDisplayOption(toml_formatter_configuration.line_ending)}))}
+                            {KeyValuePair("Line width", markup!({DebugDisplayOption(toml_formatter_configuration.line_width)}))}
+                            {KeyValuePair("Align entries", markup!({DebugDisplayOption(toml_formatter_configuration.align_entries)}))}
+                        ).fmt(fmt)?;
                     }
 
                     // Print linter configuration if --linter option is true
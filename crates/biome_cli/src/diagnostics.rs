@@ -268,6 +268,22 @@ impl DeprecatedArgument {
 pub enum ReportDiagnostic {
     /// Emitted when trying to serialise the report
     Serialization(SerdeJsonError),
+    /// Emitted when a report can't be merged by `biome merge-reports`
+    InvalidReport(InvalidReport),
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "The report {path} can't be merged: {reason}",
+        message("The report "<Emphasis>{self.path}</Emphasis>" can't be merged: "{{&self.reason}})
+    )
+)]
+pub struct InvalidReport {
+    path: String,
+    reason: String,
 }
 
 /// Advices for the [CliDiagnostic]
@@ -431,6 +447,14 @@ impl CliDiagnostic {
     }
 
     /// Emitted when an argument value is greater than the allowed value
+    /// Emitted when `biome merge-reports` can't merge the report at `path`
+    pub fn invalid_report(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::Report(ReportDiagnostic::InvalidReport(InvalidReport {
+            path: path.into(),
+            reason: reason.into(),
+        }))
+    }
+
     pub fn overflown_argument(argument: impl Into<String>, maximum: u16) -> Self {
         Self::OverflowNumberArgument(OverflowNumberArgument {
             argument: argument.into(),
@@ -1,7 +1,11 @@
+use biome_console::{markup, MarkupBuf};
 use biome_diagnostics::adapters::{IoError, StdError};
+use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{
-    Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, Visit,
+    Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, Location, LogCategory,
+    Visit,
 };
+use biome_rowan::TextRange;
 use biome_text_edit::TextEdit;
 use std::io;
 
@@ -93,6 +97,89 @@ pub(crate) struct MigrateDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(severity = Information, message = "This code can be fixed:")]
+pub(crate) struct FixReviewDiagnostic {
+    #[category]
+    pub(crate) category: &'static Category,
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[location(span)]
+    pub(crate) range: TextRange,
+    #[location(source_code)]
+    pub(crate) source_code: String,
+    #[advice]
+    pub(crate) fix: CodeSuggestionAdvice<MarkupBuf>,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "format",
+    severity = Error,
+    message = "The formatted code contains syntax errors. This is a bug of the formatter, please report it with the original code of this file."
+)]
+pub(crate) struct FormatSyntaxErrorsDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "format",
+    severity = Error,
+    message = "Formatting the formatted code changes it again. This is a bug of the formatter, please report it with the original code of this file:"
+)]
+pub(crate) struct FormatNotIdempotentDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "format",
+    severity = Error,
+    message = "The syntax of the formatted code differs from the syntax of the original code. This is a bug of the formatter, please report it with the highlighted code."
+)]
+pub(crate) struct FormatSyntaxChangedDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[location(span)]
+    pub(crate) original_range: Option<TextRange>,
+    #[advice]
+    pub(crate) formatted: FormattedCodeAdvice,
+}
+
+/// Shows the code printed by the formatter where it differs from the original code
+#[derive(Debug)]
+pub(crate) struct FormattedCodeAdvice {
+    pub(crate) file_name: String,
+    pub(crate) range: Option<TextRange>,
+    pub(crate) code: String,
+}
+
+impl Advices for FormattedCodeAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        match self.range {
+            Some(range) => {
+                visitor.record_log(LogCategory::Info, &markup! { "The formatter printed:" })?;
+                let code = self.code.as_str();
+                let location = Location::builder()
+                    .resource(&self.file_name)
+                    .span(&range)
+                    .source_code(&code)
+                    .build();
+                visitor.record_frame(location)
+            }
+            None => visitor.record_log(
+                LogCategory::Info,
+                &markup! { "The formatter didn't print this code." },
+            ),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct ContentDiffAdvice {
     pub(crate) old: String,
@@ -127,6 +214,14 @@ pub(crate) struct UnhandledDiagnostic;
 #[diagnostic(category = "parse", message = "Skipped file with syntax errors")]
 pub(crate) struct SkippedDiagnostic;
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "internalError/timeout",
+    severity = Error,
+    message = "The formatting of this file was skipped because its processing exceeded the time given by --file-timeout."
+)]
+pub(crate) struct FormatTimeoutDiagnostic;
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(category = "search", severity = Information)]
 pub(crate) struct SearchDiagnostic;
@@ -0,0 +1,99 @@
+//! The provenance of the fixes of `biome lint --write`, printed with `--explain-fixes`
+//! and written to the file of `--fix-log`.
+
+use crate::diagnostics::ReportDiagnostic;
+use crate::{CliDiagnostic, CliSession};
+use biome_diagnostics::adapters::SerdeJsonError;
+use biome_fs::OpenOptions;
+use biome_service::workspace::FixAction;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// A fix applied to a file
+#[derive(Debug, Serialize)]
+pub(crate) struct AppliedFix {
+    /// The category of the rule that produced the fix, such as `lint/style/useConst`
+    pub(crate) rule: String,
+    /// The line of the fix, starting from 1, in the code as it was when the fix was applied
+    pub(crate) line: usize,
+    /// The column of the fix, starting from 1
+    pub(crate) column: usize,
+    /// The code replaced by the fix
+    pub(crate) before: String,
+    /// The code inserted by the fix
+    pub(crate) after: String,
+}
+
+/// Returns the fixes of the rules among `actions`, in the order they were applied to `input`.
+///
+/// `offset` is the start of the script in `input`, for the files that embed a script, such as Vue files.
+pub(crate) fn applied_fixes(
+    input: &str,
+    offset: Option<u32>,
+    actions: Vec<FixAction>,
+) -> Vec<AppliedFix> {
+    let offset = offset.unwrap_or_default() as usize;
+    // The range of an action is in the code as it was when the action was applied,
+    // so the actions are replayed to find the position of the next one
+    let mut code = input.to_string();
+    let mut fixes = Vec::with_capacity(actions.len());
+    for action in actions {
+        let start = offset + usize::from(action.range.start());
+        let end = start + action.before.len();
+        if code.get(start..end) != Some(action.before.as_str()) {
+            break;
+        }
+        let prefix = &code[..start];
+        let line = prefix.matches('\n').count() + 1;
+        let line_start = prefix.rfind('\n').map_or(0, |index| index + 1);
+        let column = prefix[line_start..].chars().count() + 1;
+        code.replace_range(start..end, &action.after);
+        if let Some((group, rule)) = action.rule_name {
+            fixes.push(AppliedFix {
+                rule: format!("lint/{group}/{rule}"),
+                line,
+                column,
+                before: action.before,
+                after: action.after,
+            });
+        }
+    }
+    fixes
+}
+
+#[derive(Serialize)]
+struct FixLog<'a> {
+    files: Vec<FixLogFile<'a>>,
+}
+
+#[derive(Serialize)]
+struct FixLogFile<'a> {
+    path: &'a str,
+    fixes: &'a [AppliedFix],
+}
+
+/// Writes the fixes applied to the files, by file name, to the JSON file `fix_log`
+pub(crate) fn write_fix_log(
+    session: &mut CliSession,
+    fix_log: &Path,
+    fixes: &BTreeMap<String, Vec<AppliedFix>>,
+) -> Result<(), CliDiagnostic> {
+    let log = FixLog {
+        files: fixes
+            .iter()
+            .map(|(path, fixes)| FixLogFile { path, fixes })
+            .collect(),
+    };
+    let content = serde_json::to_string_pretty(&log).map_err(|error| {
+        CliDiagnostic::Report(ReportDiagnostic::Serialization(SerdeJsonError::from(error)))
+    })?;
+    let mut file = session
+        .app
+        .fs
+        .open_with_options(fix_log, OpenOptions::default().write(true).create(true))
+        .map_err(CliDiagnostic::io_error)?;
+    file.set_content(content.as_bytes())
+        .map_err(CliDiagnostic::io_error)?;
+    Ok(())
+}
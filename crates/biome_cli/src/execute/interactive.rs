@@ -0,0 +1,305 @@
+//! The review of the fixes of `biome lint --write --interactive`.
+//!
+//! The traversal collects the files that have fixes, then the fixes of these files are
+//! printed one by one, and written when they are accepted.
+
+use crate::execute::diagnostics::FixReviewDiagnostic;
+use crate::execute::{Execution, TraversalMode};
+use crate::{CliDiagnostic, CliSession};
+use biome_analyze::ActionCategory;
+use biome_configuration::analyzer::RuleSelector;
+use biome_console::{markup, Console, ConsoleExt, LogLevel};
+use biome_diagnostics::advice::CodeSuggestionAdvice;
+use biome_diagnostics::{category, Applicability, Category, PrintDiagnostic};
+use biome_fs::{BiomePath, FileSystem, OpenOptions};
+use biome_rowan::{TextRange, TextSize};
+use biome_service::file_handlers::{
+    AstroFileHandler, MdxFileHandler, SvelteFileHandler, VueFileHandler,
+};
+use biome_service::workspace::{CodeAction, FileGuard, FixFileMode, OpenFileParams};
+use biome_service::Workspace;
+use rustc_hash::FxHashSet;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+/// Returns the fixes of the lint rules that `fix_file_mode` allows to apply
+pub(crate) fn fixable_actions(
+    actions: Vec<CodeAction>,
+    fix_file_mode: FixFileMode,
+) -> Vec<CodeAction> {
+    actions
+        .into_iter()
+        .filter(|action| {
+            // The suppression comments aren't fixes
+            action.category == ActionCategory::QuickFix
+                && action.rule_name.is_some()
+                && !action.suggestion.suggestion.is_empty()
+                && match action.suggestion.applicability {
+                    Applicability::Always => true,
+                    Applicability::MaybeIncorrect => {
+                        fix_file_mode == FixFileMode::SafeAndUnsafeFixes
+                    }
+                }
+        })
+        .collect()
+}
+
+/// Prints the fixes of the files one by one, and writes the accepted fixes
+pub(crate) fn run(
+    execution: &Execution,
+    session: &mut CliSession,
+    paths: Vec<PathBuf>,
+) -> Result<(), CliDiagnostic> {
+    let TraversalMode::Lint {
+        fix_file_mode: Some(fix_file_mode),
+        only,
+        skip,
+        ..
+    } = execution.traversal_mode()
+    else {
+        return Ok(());
+    };
+    let mut review = Review {
+        fix_file_mode: *fix_file_mode,
+        only,
+        skip,
+        accepted_rules: FxHashSet::default(),
+        skipped_rules: FxHashSet::default(),
+        applied: 0,
+        skipped: 0,
+    };
+    let fs = &*session.app.fs;
+    let workspace = &*session.app.workspace;
+    let console = &mut *session.app.console;
+    for path in paths {
+        if review.review_file(fs, workspace, console, &path)? == Answer::Quit {
+            break;
+        }
+    }
+    let Review {
+        applied, skipped, ..
+    } = review;
+    console.log(markup! {
+        <Info>"Applied "{applied}" fixes, skipped "{skipped}" fixes."</Info>
+    });
+    Ok(())
+}
+
+/// The answer to the prompt of a fix
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Answer {
+    /// Writes the fix
+    Yes,
+    /// Doesn't write the fix
+    No,
+    /// Writes the fix, and the next fixes of the same rule without asking
+    All,
+    /// Doesn't write the fix, and skips the next fixes of the same rule
+    Skip,
+    /// Stops the review, the fixes accepted until then are written
+    Quit,
+}
+
+impl Answer {
+    /// Prints the prompt until it gets a valid answer.
+    ///
+    /// It's [Answer::Quit] when the console doesn't have any input left.
+    fn prompt(console: &mut dyn Console) -> Self {
+        loop {
+            console.print(
+                LogLevel::Log,
+                markup! {
+                    "Apply this fix? "<Dim>"[y]es, [n]o, [a]ll of this rule, [s]kip this rule, [q]uit: "</Dim>
+                },
+            );
+            let Some(line) = console.read_line() else {
+                return Self::Quit;
+            };
+            match line.trim() {
+                "y" | "yes" => return Self::Yes,
+                "n" | "no" => return Self::No,
+                "a" | "all" => return Self::All,
+                "s" | "skip" => return Self::Skip,
+                "q" | "quit" => return Self::Quit,
+                _ => {}
+            }
+        }
+    }
+}
+
+struct Review<'a> {
+    fix_file_mode: FixFileMode,
+    only: &'a [RuleSelector],
+    skip: &'a [RuleSelector],
+    /// The rules whose fixes are applied without asking
+    accepted_rules: FxHashSet<String>,
+    /// The rules whose fixes are skipped without asking
+    skipped_rules: FxHashSet<String>,
+    applied: usize,
+    skipped: usize,
+}
+
+impl Review<'_> {
+    /// Reviews the fixes of the file `path` from its start to its end, and writes it if a fix was accepted.
+    ///
+    /// It returns [Answer::Quit] if the review must stop.
+    fn review_file(
+        &mut self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+        console: &mut dyn Console,
+        path: &Path,
+    ) -> Result<Answer, CliDiagnostic> {
+        let mut file = fs
+            .open_with_options(path, OpenOptions::default().read(true).write(true))
+            .map_err(CliDiagnostic::io_error)?;
+        let mut input = String::new();
+        file.read_to_string(&mut input)
+            .map_err(CliDiagnostic::io_error)?;
+        let guard = FileGuard::open(
+            workspace,
+            OpenFileParams {
+                path: BiomePath::new(path),
+                content: input.clone(),
+                version: 0,
+                document_file_source: None,
+            },
+        )?;
+        let extension = path.extension().and_then(|extension| extension.to_str());
+
+        let mut content = input.clone();
+        let mut version = 0;
+        // The rules and the changed ranges of the fixes that were already reviewed, which aren't offered again.
+        // The fixes are pulled again after every accepted fix, because it changes the code.
+        let mut reviewed: Vec<(String, TextRange)> = Vec::new();
+        let mut answer = Answer::No;
+        while answer != Answer::Quit {
+            let actions = guard
+                .pull_actions(None, self.only.to_vec(), self.skip.to_vec())?
+                .actions;
+            let script = script(extension, &content);
+            // The range of a fix can be larger than the code that it changes, for example
+            // when it removes a statement of a list
+            let next = fixable_actions(actions, self.fix_file_mode)
+                .into_iter()
+                .filter_map(|action| {
+                    let (group, name) = action.rule_name.as_ref()?;
+                    let rule = format!("{group}/{name}");
+                    let changed_range = action.suggestion.suggestion.changed_range(&script)?;
+                    let is_reviewed = reviewed.iter().any(|(reviewed_rule, range)| {
+                        *reviewed_rule == rule && *range == changed_range
+                    });
+                    (!is_reviewed).then_some((rule, changed_range, action))
+                })
+                .min_by_key(|(_, changed_range, _)| changed_range.start());
+            let Some((rule, changed_range, action)) = next else {
+                break;
+            };
+
+            answer = if self.accepted_rules.contains(&rule) {
+                Answer::Yes
+            } else if self.skipped_rules.contains(&rule) {
+                Answer::No
+            } else {
+                let offset = script_offset(extension, &content);
+                let diagnostic = FixReviewDiagnostic {
+                    category: format!("lint/{rule}")
+                        .parse::<&'static Category>()
+                        .unwrap_or(category!("lint")),
+                    file_name: path.display().to_string(),
+                    range: changed_range + offset,
+                    source_code: content.clone(),
+                    fix: CodeSuggestionAdvice {
+                        applicability: action.suggestion.applicability,
+                        msg: action.suggestion.msg.clone(),
+                        suggestion: action.suggestion.suggestion.clone(),
+                    },
+                };
+                console.log(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+                Answer::prompt(console)
+            };
+
+            match answer {
+                Answer::Yes | Answer::All => {
+                    let fixed_script = action.suggestion.suggestion.new_string(&script);
+                    let (old_len, new_len) =
+                        (TextSize::of(&*script), TextSize::of(fixed_script.as_str()));
+                    // The ranges of the reviewed fixes after the fixed code move with it,
+                    // and the reviewed fixes of the fixed code are forgotten
+                    let fixed_range = TextRange::new(
+                        changed_range.start(),
+                        changed_range.end() + new_len - old_len,
+                    );
+                    reviewed.retain_mut(|(_, range)| {
+                        if range.end() <= changed_range.start() {
+                            true
+                        } else if range.start() >= changed_range.end() {
+                            *range = *range + new_len - old_len;
+                            true
+                        } else {
+                            false
+                        }
+                    });
+                    reviewed.push((rule.clone(), fixed_range));
+                    content = with_script(extension, &content, &fixed_script);
+                    version += 1;
+                    guard.change_file(version, content.clone())?;
+                    self.applied += 1;
+                    if answer == Answer::All {
+                        self.accepted_rules.insert(rule);
+                    }
+                }
+                Answer::No | Answer::Skip => {
+                    self.skipped += 1;
+                    if answer == Answer::Skip {
+                        self.skipped_rules.insert(rule.clone());
+                    }
+                    reviewed.push((rule, changed_range));
+                }
+                Answer::Quit => {}
+            }
+        }
+
+        if content != input {
+            file.set_content(content.as_bytes())
+                .map_err(CliDiagnostic::io_error)?;
+        }
+        Ok(answer)
+    }
+}
+
+/// Returns the code analyzed by the linter: the script of the Vue, Astro, and Svelte files,
+/// the code of the MDX files, and the whole content of the other files
+fn script<'a>(extension: Option<&str>, content: &'a str) -> Cow<'a, str> {
+    match extension {
+        Some("astro") => Cow::Borrowed(AstroFileHandler::input(content)),
+        Some("vue") => Cow::Borrowed(VueFileHandler::input(content)),
+        Some("svelte") => Cow::Borrowed(SvelteFileHandler::input(content)),
+        Some("mdx") => Cow::Owned(MdxFileHandler::input(content)),
+        _ => Cow::Borrowed(content),
+    }
+}
+
+/// Returns the content of the file with `script` in place of its [script]
+fn with_script(extension: Option<&str>, content: &str, script: &str) -> String {
+    match extension {
+        Some("astro") => AstroFileHandler::output(content, script),
+        Some("vue") => VueFileHandler::output(content, script),
+        Some("svelte") => SvelteFileHandler::output(content, script),
+        Some("mdx") => MdxFileHandler::output(content, script),
+        _ => script.to_string(),
+    }
+}
+
+/// Returns the position of the [script] in the content of the file
+///
+/// The code of the MDX files has the positions of the file.
+fn script_offset(extension: Option<&str>, content: &str) -> TextSize {
+    let offset = match extension {
+        Some("astro") => AstroFileHandler::start(content),
+        Some("vue") => VueFileHandler::start(content),
+        Some("svelte") => SvelteFileHandler::start(content),
+        _ => None,
+    };
+    TextSize::from(offset.unwrap_or_default())
+}
@@ -15,7 +15,6 @@ use biome_json_syntax::{JsonFileSource, JsonRoot};
 use biome_migrate::{migrate_configuration, ControlFlow};
 use biome_rowan::{AstNode, NodeCache};
 use biome_service::workspace::{ChangeFileParams, FixAction, FormatFileParams, OpenFileParams};
-use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::path::PathBuf;
 
@@ -29,6 +28,9 @@ mod eslint_unicorn;
 mod ignorefile;
 mod node;
 mod prettier;
+mod stylelint;
+mod stylelint_any_rule_to_biome;
+mod stylelint_to_biome;
 
 pub(crate) struct MigratePayload<'a> {
     pub(crate) session: CliSession<'a>,
@@ -236,6 +238,79 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                 })
             }
         }
+        Some(MigrateSubCommand::Stylelint { include_nursery }) => {
+            let stylelint::Config {
+                path: stylelint_path,
+                data: stylelint_config,
+            } = stylelint::read_config_file(fs, console)?;
+            let biome_config =
+                deserialize_from_json_ast::<PartialConfiguration>(&parsed.tree(), "")
+                    .into_deserialized();
+            let Some(mut biome_config) = biome_config else {
+                return Ok(());
+            };
+            let (biome_stylelint_config, results) = stylelint_config
+                .into_biome_config(&stylelint_to_biome::MigrationOptions { include_nursery });
+            let old_biome_config = biome_config.clone();
+            biome_config.merge_with(biome_stylelint_config);
+            if biome_config == old_biome_config {
+                console.log(markup! {
+                    <Info>"No changes to apply to the Biome configuration file."</Info>
+                });
+            } else {
+                let new_content = serde_json::to_string(&biome_config).map_err(|err| {
+                    CliDiagnostic::MigrateError(MigrationDiagnostic {
+                        reason: err.to_string(),
+                    })
+                })?;
+                workspace.change_file(ChangeFileParams {
+                    path: biome_path.clone(),
+                    content: new_content,
+                    version: 1,
+                })?;
+                let printed = workspace.format_file(FormatFileParams { path: biome_path })?;
+                if write {
+                    biome_config_file.set_content(printed.as_code().as_bytes())?;
+                    console.log(markup!{
+                        <Info><Emphasis>{stylelint_path}</Emphasis>" has been successfully migrated."</Info>
+                    });
+                } else {
+                    let file_name = configuration_file_path.display().to_string();
+                    let diagnostic = MigrateDiffDiagnostic {
+                        file_name,
+                        diff: ContentDiffAdvice {
+                            old: biome_config_content,
+                            new: printed.as_code().to_string(),
+                        },
+                    };
+                    console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+                    console.log(markup! {
+                        <Info>"Run the command with the option "<Emphasis>"--write"</Emphasis>" to apply the changes."</Info>
+                    })
+                }
+            }
+            for rule in &results.formatter_rules {
+                console.log(markup! {
+                    <Info>"The Stylelint rule `"{rule}"` is enforced by the Biome formatter, so it isn't migrated."</Info>
+                })
+            }
+            if !results.unmapped_rules.is_empty() {
+                let unmapped_rules = results
+                    .unmapped_rules
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                console.log(markup! {
+                    <Warn>"The following Stylelint rules have no equivalent in Biome and aren't migrated: "{unmapped_rules}</Warn>
+                })
+            }
+            if !results.nursery_rules.is_empty() {
+                console.log(markup! {
+                    <Info>"Run the command with the option "<Emphasis>"--include-nursery"</Emphasis>" to also migrate nursery rules."</Info>
+                })
+            }
+        }
         None => {
             let has_deprecated_configuration =
                 configuration_file_path.file_name() == Some(OsStr::new("rome.json"));
@@ -264,18 +339,15 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                         if let (root, Some((range, _))) =
                             action.mutation.commit_with_text_range_and_edit(true)
                         {
+                            let fix_action =
+                                FixAction::new(action.rule_name, tree.syntax(), &root, range);
                             tree = match JsonRoot::cast(root) {
                                 Some(tree) => tree,
                                 None => {
                                     return Err(CliDiagnostic::check_error(category!("migrate")))
                                 }
                             };
-                            actions.push(FixAction {
-                                rule_name: action.rule_name.map(|(group, rule)| {
-                                    (Cow::Borrowed(group), Cow::Borrowed(rule))
-                                }),
-                                range,
-                            });
+                            actions.push(fix_action);
                         }
                     }
                     None => {
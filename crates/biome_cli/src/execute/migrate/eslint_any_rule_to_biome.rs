@@ -43,6 +43,16 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_banned_types.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/consistent-indexed-object-style" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_consistent_record_type
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/consistent-type-exports" => {
             if !options.include_inspired {
                 results.has_inspired_rules = true;
@@ -186,18 +196,6 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
-        "@typescript-eslint/no-throw-literal" => {
-            if !options.include_inspired {
-                results.has_inspired_rules = true;
-                return false;
-            }
-            if !options.include_nursery {
-                return false;
-            }
-            let group = rules.nursery.get_or_insert_with(Default::default);
-            let rule = group.use_throw_only_error.get_or_insert(Default::default());
-            rule.set_level(rule_severity.into());
-        }
         "@typescript-eslint/no-unnecessary-type-constraint" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group
@@ -287,6 +285,14 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/prefer-includes" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_includes.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/prefer-literal-enum-member" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group
@@ -388,6 +394,14 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_getter_return.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "guard-for-in" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_guard_for_in.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "import-access/eslint-plugin-import-access" => {
             if !options.include_inspired {
                 results.has_inspired_rules = true;
@@ -479,6 +493,34 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "jsdoc/check-param-names" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_js_doc_params_match
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "jsdoc/require-returns-check" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_js_doc_params_match
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jsx-a11y/alt-text" => {
             let group = rules.a11y.get_or_insert_with(Default::default);
             let rule = group.use_alt_text.get_or_insert(Default::default());
@@ -698,6 +740,14 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "no-await-in-loop" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_await_in_loop.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-case-declarations" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group
@@ -989,6 +1039,14 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_parameter_assign.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "no-proto" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_proto.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-prototype-builtins" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group
@@ -1251,6 +1309,14 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_template.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "preserve-caught-error" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_error_cause.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "react-hooks/exhaustive-deps" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group
@@ -1327,6 +1393,20 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_useless_fragments.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "react/jsx-props-no-spreading" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_implicit_spread_props
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "react/no-array-index-key" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group.no_array_index_key.get_or_insert(Default::default());
@@ -1351,6 +1431,18 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "react/no-unknown-property" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_unknown_attribute.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "react/void-dom-elements-no-children" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group
@@ -1358,6 +1450,126 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "regexp/no-empty-alternative" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_empty_alternatives
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "regexp/no-super-linear-backtracking" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_superlinear_backtracking
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "regexp/no-useless-character-class" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_optimized_regex.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "regexp/no-useless-non-capturing-group" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_optimized_regex.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "regexp/no-useless-two-nums-quantifier" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_optimized_regex.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "regexp/prefer-d" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_optimized_regex.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "regexp/prefer-plus-quantifier" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_optimized_regex.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "regexp/prefer-question-quantifier" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_optimized_regex.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "regexp/prefer-star-quantifier" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_optimized_regex.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "regexp/prefer-w" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_optimized_regex.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "require-await" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group.use_await.get_or_insert(Default::default());
@@ -1368,6 +1580,20 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_yield.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "solidjs/no-destructure" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_untracked_props_access
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "solidjs/no-react-specific-props" => {
             if !options.include_nursery {
                 return false;
@@ -1378,6 +1604,20 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "solidjs/reactivity" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_untracked_signal_calls
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "sonarjs/cognitive-complexity" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group
@@ -1465,6 +1705,20 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "unicorn/numeric-separators-style" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_numeric_separators
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "unicorn/prefer-array-flat-map" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group.use_flat_map.get_or_insert(Default::default());
@@ -1478,6 +1732,14 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_date_now.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "unicorn/prefer-includes" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_includes.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "unicorn/prefer-node-protocol" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group
@@ -1490,6 +1752,18 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_number_namespace.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "unicorn/prefer-spread" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_spread.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "unicorn/prefer-string-slice" => {
             if !options.include_nursery {
                 return false;
@@ -1544,6 +1818,44 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_valid_typeof.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "vue/no-dupe-keys" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_vue_duplicate_keys
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "vue/no-reserved-props" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_vue_reserved_props
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "vue/no-side-effects-in-computed-properties" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_vue_side_effects_in_computed
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "yoda" => {
             if !options.include_nursery {
                 return false;
@@ -215,6 +215,8 @@ fn migrate_eslint_rule(
                 group.no_restricted_globals = Some(biome_config::RuleConfiguration::WithOptions(
                     biome_config::RuleWithOptions {
                         level: severity.into(),
+                        includes: None,
+                        excludes: None,
                         options: Box::new(no_restricted_globals::RestrictedGlobalsOptions {
                             denied_globals: globals.collect(),
                         }),
@@ -231,6 +233,8 @@ fn migrate_eslint_rule(
                             biome_config::RuleWithFixOptions {
                                 level: severity.into(),
                                 fix: None,
+                                includes: None,
+                                excludes: None,
                                 options: Box::new((*rule_options).into()),
                             },
                         ));
@@ -246,6 +250,8 @@ fn migrate_eslint_rule(
                             biome_config::RuleWithFixOptions {
                                 level: severity.into(),
                                 fix: None,
+                                includes: None,
+                                excludes: None,
                                 options: rule_options.into(),
                             },
                         ));
@@ -264,6 +270,8 @@ fn migrate_eslint_rule(
                         biome_config::RuleWithFixOptions {
                             level: severity.into(),
                             fix: None,
+                            includes: None,
+                            excludes: None,
                             options: options.into(),
                         },
                     ));
@@ -275,6 +283,8 @@ fn migrate_eslint_rule(
                 group.use_filenaming_convention = Some(
                     biome_config::RuleConfiguration::WithOptions(biome_config::RuleWithOptions {
                         level: conf.severity().into(),
+                        includes: None,
+                        excludes: None,
                         options: Box::new(conf.option_or_default().into()),
                     }),
                 );
@@ -3,7 +3,7 @@ use std::{cmp::Ordering, str::FromStr};
 /// Configuration related to [TypeScript Eslint](https://typescript-eslint.io/).
 ///
 /// Also, the module includes implementation to convert rule options to Biome's rule options.
-use biome_deserialize::Deserializable;
+use biome_deserialize::{Deserializable, DeserializableType};
 use biome_deserialize_macros::Deserializable;
 use biome_js_analyze::{
     lint::style::{use_consistent_array_type, use_naming_convention},
@@ -58,11 +58,20 @@ impl From<NamingConventionOptions> for use_naming_convention::NamingConventionOp
     fn from(val: NamingConventionOptions) -> Self {
         let mut conventions = Vec::new();
         for selection in val.0 {
-            if selection.types.is_some() || selection.filter.is_some() || selection.custom.is_some()
-            {
-                // We don't support types/filter/custom
+            if selection.types.is_some() || selection.custom.is_some() {
+                // We don't support types/custom
                 continue;
             }
+            let filter = match &selection.filter {
+                Some(filter) => {
+                    let Some(filter) = filter.to_restricted_regex() else {
+                        // We don't support negated filters and unsupported regexes
+                        continue;
+                    };
+                    Some(filter)
+                }
+                None => None,
+            };
             let matching = if selection.leading_underscore.is_some()
                 || selection.trailing_underscore.is_some()
             {
@@ -119,6 +128,7 @@ impl From<NamingConventionOptions> for use_naming_convention::NamingConventionOp
             for selector in selectors {
                 conventions.push(use_naming_convention::Convention {
                     selector,
+                    filter: filter.clone(),
                     matching: matching.clone(),
                     formats,
                 });
@@ -144,7 +154,7 @@ pub(crate) struct NamingConventionSelection {
     pub(crate) trailing_underscore: Option<Underscore>,
     pub(crate) prefix: Vec<String>,
     pub(crate) suffix: Vec<String>,
-    pub(crate) filter: Option<Anything>,
+    pub(crate) filter: Option<NamingConventionFilter>,
 }
 impl NamingConventionSelection {
     fn precedence(&self, other: &Self) -> Ordering {
@@ -160,6 +170,11 @@ impl NamingConventionSelection {
             (None, Some(_)) => return Ordering::Greater,
             (Some(_), None) => return Ordering::Less,
         }
+        match (&self.filter, &other.filter) {
+            (None, None) | (Some(_), Some(_)) => {}
+            (None, Some(_)) => return Ordering::Greater,
+            (Some(_), None) => return Ordering::Less,
+        }
         match (&self.modifiers, &other.modifiers) {
             (None, None) | (Some(_), Some(_)) => {}
             (None, Some(_)) => return Ordering::Greater,
@@ -473,6 +488,56 @@ impl Deserializable for Anything {
         Some(Anything)
     }
 }
+#[derive(Debug)]
+pub(crate) struct NamingConventionFilter {
+    regex: String,
+    matching: bool,
+}
+impl NamingConventionFilter {
+    /// Returns the equivalent restricted regex, if the filter selects the names that match the regex.
+    fn to_restricted_regex(&self) -> Option<RestrictedRegex> {
+        if !self.matching {
+            return None;
+        }
+        // Unlike a restricted regex, the filter is not anchored.
+        let (prefix, regex) = match self.regex.strip_prefix('^') {
+            Some(regex) => ("", regex),
+            None => (".*", self.regex.as_str()),
+        };
+        let (regex, suffix) = match regex.strip_suffix('$') {
+            Some(regex) => (regex, ""),
+            None => (regex, ".*"),
+        };
+        RestrictedRegex::from_str(&format!("{prefix}(?:{regex}){suffix}")).ok()
+    }
+}
+impl Deserializable for NamingConventionFilter {
+    fn deserialize(
+        value: &impl biome_deserialize::DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<biome_deserialize::DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        if value.visitable_type()? == DeserializableType::Str {
+            Some(Self {
+                regex: Deserializable::deserialize(value, name, diagnostics)?,
+                matching: true,
+            })
+        } else {
+            let filter: NamingConventionFilterObject =
+                Deserializable::deserialize(value, name, diagnostics)?;
+            Some(Self {
+                regex: filter.regex,
+                matching: filter.matching,
+            })
+        }
+    }
+}
+#[derive(Debug, Default, Deserializable)]
+struct NamingConventionFilterObject {
+    regex: String,
+    #[deserializable(rename = "match")]
+    matching: bool,
+}
 #[derive(Copy, Clone, Debug, Deserializable)]
 pub(crate) enum NamingConventionCase {
     #[deserializable(rename = "camelCase")]
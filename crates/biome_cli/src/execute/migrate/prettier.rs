@@ -1,5 +1,6 @@
 use crate::diagnostics::MigrationDiagnostic;
 use crate::CliDiagnostic;
+use biome_analyze::FixKind;
 use biome_console::{markup, Console, ConsoleExt};
 use biome_deserialize::{json::deserialize_from_json_str, StringSet};
 use biome_deserialize_macros::Deserializable;
@@ -9,6 +10,7 @@ use biome_formatter::{
     QuoteStyle,
 };
 use biome_fs::{FileSystem, OpenOptions};
+use biome_js_analyze::lint::nursery::use_sorted_classes::UtilityClassSortingOptions;
 use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, Semicolons, TrailingCommas};
 use biome_json_parser::JsonParserOptions;
 use biome_service::DynRef;
@@ -57,8 +59,26 @@ pub(crate) struct PrettierConfiguration {
     arrow_parens: ArrowParens,
     /// https://prettier.io/docs/en/options#end-of-line
     end_of_line: EndOfLine,
+    /// https://prettier.io/docs/en/options#single-attribute-per-line
+    single_attribute_per_line: bool,
+    /// https://prettier.io/docs/en/options#experimental-ternaries
+    experimental_ternaries: bool,
+    /// https://prettier.io/docs/en/options#object-wrap
+    object_wrap: ObjectWrap,
+    /// https://prettier.io/docs/en/options#require-pragma
+    require_pragma: bool,
+    /// https://prettier.io/docs/en/options#insert-pragma
+    insert_pragma: bool,
     /// https://prettier.io/docs/en/configuration.html#configuration-overrides
     overrides: Vec<Override>,
+    /// https://prettier.io/docs/en/plugins
+    plugins: Vec<String>,
+    /// Option of `prettier-plugin-tailwindcss`:
+    /// https://github.com/tailwindlabs/prettier-plugin-tailwindcss#sorting-classes-in-function-calls
+    tailwind_functions: Vec<String>,
+    /// Option of `prettier-plugin-tailwindcss`:
+    /// https://github.com/tailwindlabs/prettier-plugin-tailwindcss#sorting-non-standard-attributes
+    tailwind_attributes: Vec<String>,
 }
 
 impl Default for PrettierConfiguration {
@@ -76,7 +96,15 @@ impl Default for PrettierConfiguration {
             jsx_single_quote: false,
             arrow_parens: ArrowParens::default(),
             end_of_line: EndOfLine::default(),
+            single_attribute_per_line: false,
+            experimental_ternaries: false,
+            object_wrap: ObjectWrap::default(),
+            require_pragma: false,
+            insert_pragma: false,
             overrides: vec![],
+            plugins: vec![],
+            tailwind_functions: vec![],
+            tailwind_attributes: vec![],
         }
     }
 }
@@ -84,6 +112,7 @@ impl Default for PrettierConfiguration {
 #[derive(Debug, Default, Deserializable)]
 pub(crate) struct Override {
     files: ShorthandVec<String>,
+    exclude_files: ShorthandVec<String>,
     options: OverrideOptions,
 }
 
@@ -114,6 +143,14 @@ pub(crate) struct OverrideOptions {
     arrow_parens: Option<ArrowParens>,
     /// https://prettier.io/docs/en/options#end-of-line
     end_of_line: Option<EndOfLine>,
+    /// https://prettier.io/docs/en/options#single-attribute-per-line
+    single_attribute_per_line: Option<bool>,
+    /// https://prettier.io/docs/en/options#experimental-ternaries
+    experimental_ternaries: Option<bool>,
+    /// https://prettier.io/docs/en/options#object-wrap
+    object_wrap: Option<ObjectWrap>,
+    /// https://prettier.io/docs/en/options#parser
+    parser: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
@@ -145,9 +182,17 @@ enum QuoteProps {
     #[default]
     #[deserializable(rename = "as-needed")]
     AsNeeded,
+    Consistent,
     Preserve,
 }
 
+#[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
+enum ObjectWrap {
+    #[default]
+    Preserve,
+    Collapse,
+}
+
 impl From<PrettierTrailingComma> for TrailingCommas {
     fn from(value: PrettierTrailingComma) -> Self {
         match value {
@@ -181,7 +226,8 @@ impl From<EndOfLine> for LineEnding {
 impl From<QuoteProps> for QuoteProperties {
     fn from(value: QuoteProps) -> Self {
         match value {
-            QuoteProps::AsNeeded => Self::AsNeeded,
+            // Biome doesn't support `consistent`
+            QuoteProps::AsNeeded | QuoteProps::Consistent => Self::AsNeeded,
             QuoteProps::Preserve => Self::Preserve,
         }
     }
@@ -199,13 +245,15 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
         } else {
             biome_formatter::IndentStyle::Space
         };
+        let attribute_position = attribute_position(value.single_attribute_per_line);
         let formatter = biome_configuration::PartialFormatterConfiguration {
             indent_width: Some(indent_width),
             line_width: Some(line_width),
             indent_style: Some(indent_style),
             line_ending: Some(value.end_of_line.into()),
-            attribute_position: Some(AttributePosition::default()),
+            attribute_position: Some(attribute_position),
             format_with_errors: Some(false),
+            max_complexity: None,
             ignore: None,
             include: None,
             enabled: Some(true),
@@ -215,6 +263,7 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
             // deprecated
             indent_size: None,
             bracket_spacing: Some(BracketSpacing::default()),
+            comments: None,
         };
         result.formatter = Some(formatter);
 
@@ -253,13 +302,69 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
             quote_properties: Some(value.quote_props.into()),
             bracket_spacing: Some(value.bracket_spacing.into()),
             jsx_quote_style: Some(jsx_quote_style),
-            attribute_position: Some(AttributePosition::default()),
+            attribute_position: Some(attribute_position),
+            // Prettier doesn't support vertical alignment
+            vertical_alignment: None,
+            // Prettier always removes the unnecessary escapes
+            string_escapes: None,
+            embedded_language_formatting: None,
+            max_inline_specifiers: None,
+            markup_formatting: None,
         };
         let js_config = biome_configuration::PartialJavascriptConfiguration {
             formatter: Some(js_formatter),
             ..Default::default()
         };
         result.javascript = Some(js_config);
+        if value
+            .plugins
+            .iter()
+            .any(|plugin| SORT_IMPORTS_PLUGINS.contains(&plugin_package_name(plugin)))
+        {
+            result.organize_imports = Some(
+                biome_configuration::organize_imports::PartialOrganizeImports {
+                    enabled: Some(true),
+                    ..Default::default()
+                },
+            );
+        }
+        if value
+            .plugins
+            .iter()
+            .any(|plugin| plugin_package_name(plugin) == TAILWIND_PLUGIN)
+        {
+            let mut attributes = UtilityClassSortingOptions::default().attributes;
+            if let Some(attributes) = &mut attributes {
+                attributes.extend(value.tailwind_attributes);
+            }
+            let functions =
+                (!value.tailwind_functions.is_empty()).then_some(value.tailwind_functions);
+            let rules = biome_configuration::Rules {
+                nursery: Some(biome_configuration::analyzer::Nursery {
+                    use_sorted_classes: Some(
+                        biome_configuration::RuleFixConfiguration::WithOptions(
+                            biome_configuration::RuleWithFixOptions {
+                                level: biome_configuration::RulePlainConfiguration::Error,
+                                // Prettier sorts the classes when it formats the files
+                                fix: Some(FixKind::Safe),
+                                includes: None,
+                                excludes: None,
+                                options: Box::new(UtilityClassSortingOptions {
+                                    attributes,
+                                    functions,
+                                }),
+                            },
+                        ),
+                    ),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            result.linter = Some(biome_configuration::PartialLinterConfiguration {
+                rules: Some(rules),
+                ..Default::default()
+            });
+        }
         if !value.overrides.is_empty() {
             let mut overrides = biome_configuration::Overrides::default();
             for override_elt in value.overrides {
@@ -273,15 +378,25 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
 
 impl TryFrom<Override> for biome_configuration::OverridePattern {
     type Error = ParseFormatNumberError;
-    fn try_from(Override { files, options }: Override) -> Result<Self, Self::Error> {
+    fn try_from(
+        Override {
+            files,
+            exclude_files,
+            options,
+        }: Override,
+    ) -> Result<Self, Self::Error> {
         let mut result = biome_configuration::OverridePattern {
             include: Some(StringSet::new(files.into_iter().collect())),
+            ignore: (!exclude_files.is_empty())
+                .then(|| StringSet::new(exclude_files.into_iter().collect())),
             ..Default::default()
         };
+        let attribute_position = options.single_attribute_per_line.map(attribute_position);
         if options.print_width.is_some()
             || options.use_tabs.is_some()
             || options.tab_width.is_some()
             || options.end_of_line.is_some()
+            || attribute_position.is_some()
         {
             // are global options are set
             let line_width = if let Some(print_width) = options.print_width {
@@ -307,6 +422,7 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
                 line_width,
                 indent_style,
                 line_ending: options.end_of_line.map(|end_of_line| end_of_line.into()),
+                attribute_position,
                 ..Default::default()
             };
             result.formatter = Some(formatter);
@@ -319,6 +435,7 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
             && options.trailing_comma.is_none()
             && options.quote_props.is_none()
             && options.bracket_spacing.is_none()
+            && attribute_position.is_none()
         {
             // no js option are set
             return Ok(result);
@@ -354,7 +471,11 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
                 .map(|trailing_comma| trailing_comma.into()),
             quote_style,
             quote_properties: options.quote_props.map(|quote_props| quote_props.into()),
+            bracket_spacing: options
+                .bracket_spacing
+                .map(|bracket_spacing| bracket_spacing.into()),
             jsx_quote_style,
+            attribute_position,
             ..Default::default()
         };
         let js_config = biome_configuration::PartialJavascriptConfiguration {
@@ -366,6 +487,139 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
     }
 }
 
+/// Returns the Biome equivalent of Prettier's `singleAttributePerLine`
+fn attribute_position(single_attribute_per_line: bool) -> AttributePosition {
+    if single_attribute_per_line {
+        AttributePosition::Multiline
+    } else {
+        AttributePosition::Auto
+    }
+}
+
+/// The Prettier plugins that sort the imports, migrated to the import sorting of Biome
+const SORT_IMPORTS_PLUGINS: [&str; 3] = [
+    "prettier-plugin-organize-imports",
+    "@trivago/prettier-plugin-sort-imports",
+    "@ianvs/prettier-plugin-sort-imports",
+];
+
+/// The Prettier plugin that sorts the Tailwind CSS classes, migrated to `nursery/useSortedClasses`
+const TAILWIND_PLUGIN: &str = "prettier-plugin-tailwindcss";
+
+/// Returns the name of the package of a Prettier plugin.
+///
+/// A plugin can be referenced by its package name, or by a path inside `node_modules`.
+fn plugin_package_name(plugin: &str) -> &str {
+    let Some((_, path)) = plugin.rsplit_once("node_modules/") else {
+        return plugin;
+    };
+    let end = if path.starts_with('@') {
+        path.match_indices('/').nth(1)
+    } else {
+        path.match_indices('/').next()
+    };
+    end.map_or(path, |(index, _)| &path[..index])
+}
+
+/// A Prettier option that Biome doesn't support
+enum UnsupportedOption {
+    Option {
+        /// The option and its value, as written in the Prettier configuration
+        option: String,
+        /// The nearest equivalent in Biome, or what happens instead
+        suggestion: &'static str,
+    },
+    /// A plugin without an equivalent in Biome
+    Plugin(String),
+}
+
+impl PrettierConfiguration {
+    /// Returns the options of the configuration, and of its overrides, that can't be migrated
+    fn unsupported_options(&self) -> Vec<UnsupportedOption> {
+        let mut unsupported = Vec::new();
+        if self.end_of_line == EndOfLine::Auto {
+            unsupported.push(UnsupportedOption::Option {
+                option: r#""endOfLine": "auto""#.to_string(),
+                suggestion: r#"The default `"lf"` option is used instead."#,
+            });
+        }
+        push_unsupported_options(
+            &mut unsupported,
+            Some(&self.quote_props),
+            Some(self.experimental_ternaries),
+            Some(&self.object_wrap),
+            None,
+        );
+        for (enabled, name) in [
+            (self.require_pragma, "requirePragma"),
+            (self.insert_pragma, "insertPragma"),
+        ] {
+            if enabled {
+                unsupported.push(UnsupportedOption::Option {
+                    option: format!(r#""{name}": true"#),
+                    suggestion: "Use `formatter.include` or `formatter.ignore` to select the formatted files instead.",
+                });
+            }
+        }
+        for plugin in &self.plugins {
+            let name = plugin_package_name(plugin);
+            if name != TAILWIND_PLUGIN && !SORT_IMPORTS_PLUGINS.contains(&name) {
+                unsupported.push(UnsupportedOption::Plugin(plugin.clone()));
+            }
+        }
+        for override_elt in &self.overrides {
+            let options = &override_elt.options;
+            if options.end_of_line == Some(EndOfLine::Auto) {
+                unsupported.push(UnsupportedOption::Option {
+                    option: r#""endOfLine": "auto""#.to_string(),
+                    suggestion: r#"The "lf" option of the base configuration is used instead."#,
+                });
+            }
+            push_unsupported_options(
+                &mut unsupported,
+                options.quote_props.as_ref(),
+                options.experimental_ternaries,
+                options.object_wrap.as_ref(),
+                options.parser.as_deref(),
+            );
+        }
+        unsupported
+    }
+}
+
+fn push_unsupported_options(
+    unsupported: &mut Vec<UnsupportedOption>,
+    quote_props: Option<&QuoteProps>,
+    experimental_ternaries: Option<bool>,
+    object_wrap: Option<&ObjectWrap>,
+    parser: Option<&str>,
+) {
+    if quote_props == Some(&QuoteProps::Consistent) {
+        unsupported.push(UnsupportedOption::Option {
+            option: r#""quoteProps": "consistent""#.to_string(),
+            suggestion: r#"The nearest equivalent, `"asNeeded"`, is used instead."#,
+        });
+    }
+    if experimental_ternaries == Some(true) {
+        unsupported.push(UnsupportedOption::Option {
+            option: r#""experimentalTernaries": true"#.to_string(),
+            suggestion: "Biome formats the ternaries like Prettier's default formatting.",
+        });
+    }
+    if object_wrap == Some(&ObjectWrap::Collapse) {
+        unsupported.push(UnsupportedOption::Option {
+            option: r#""objectWrap": "collapse""#.to_string(),
+            suggestion: r#"Biome formats the objects like Prettier's `"preserve"` option."#,
+        });
+    }
+    if let Some(parser) = parser {
+        unsupported.push(UnsupportedOption::Option {
+            option: format!(r#""parser": "{parser}""#),
+            suggestion: "Biome infers the language of a file from its extension.",
+        });
+    }
+}
+
 /// A Prettier config can be embedded in `package.json`
 const PACKAGE_JSON: &str = "package.json";
 
@@ -483,10 +737,15 @@ fn load_config(
         }
     }
     if let Some(result) = deserialized {
-        if result.end_of_line == EndOfLine::Auto {
-            console.log(markup! {
-                <Warn>"Prettier's `\"endOfLine\": \"auto\"` option is not supported in Biome. The default `\"lf\"` option is used instead."</Warn>
-            });
+        for unsupported in result.unsupported_options() {
+            match unsupported {
+                UnsupportedOption::Option { option, suggestion } => console.log(markup! {
+                    <Warn>"Prettier's `"{option}"` option is not supported in Biome. "{suggestion}</Warn>
+                }),
+                UnsupportedOption::Plugin(plugin) => console.log(markup! {
+                    <Warn>"The Prettier plugin `"{plugin}"` is not supported in Biome. The plugin is ignored."</Warn>
+                }),
+            }
         }
         Ok(result)
     } else if path.extension().is_none() {
@@ -503,7 +762,9 @@ fn load_config(
 
 #[cfg(test)]
 mod tests {
-    use crate::execute::migrate::prettier::{PrettierConfiguration, PrettierTrailingComma};
+    use crate::execute::migrate::prettier::{
+        plugin_package_name, PrettierConfiguration, PrettierTrailingComma,
+    };
     use biome_deserialize::json::deserialize_from_json_str;
     use biome_json_parser::JsonParserOptions;
 
@@ -557,4 +818,26 @@ mod tests {
             }
         ))
     }
+
+    #[test]
+    fn plugin_package_names() {
+        assert_eq!(
+            plugin_package_name("prettier-plugin-tailwindcss"),
+            "prettier-plugin-tailwindcss"
+        );
+        assert_eq!(
+            plugin_package_name("./node_modules/prettier-plugin-tailwindcss/dist/index.mjs"),
+            "prettier-plugin-tailwindcss"
+        );
+        assert_eq!(
+            plugin_package_name(
+                "/project/node_modules/@ianvs/prettier-plugin-sort-imports/lib/index.js"
+            ),
+            "@ianvs/prettier-plugin-sort-imports"
+        );
+        assert_eq!(
+            plugin_package_name("./plugins/prettier-plugin-local.js"),
+            "./plugins/prettier-plugin-local.js"
+        );
+    }
 }
@@ -0,0 +1,295 @@
+use biome_console::{markup, Console, ConsoleExt};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{
+    Deserializable, DeserializableType, DeserializableTypes, DeserializableValue,
+    DeserializationDiagnostic, DeserializationVisitor,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::{DiagnosticExt, PrintDiagnostic};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use biome_rowan::TextRange;
+use biome_service::DynRef;
+use indexmap::IndexMap;
+use std::path::Path;
+
+use crate::diagnostics::MigrationDiagnostic;
+use crate::CliDiagnostic;
+
+use super::{eslint_eslint::ShorthandVec, node};
+
+/// This modules includes implementations for loading and deserializing a Stylelint configuration.
+///
+/// The defined types follow the Stylelint configuration described at
+/// <https://stylelint.io/user-guide/configure>.
+/// Only the rules that have an equivalent Biome rule are migrated,
+/// so the options of the rules are mostly ignored.
+///
+/// See [super::stylelint_to_biome] for converting a Stylelint config to a Biome config.
+
+/// A Stylelint config can be embedded in `package.json`
+const PACKAGE_JSON: &str = "package.json";
+
+/// Stylelint config files ordered by precedence
+///
+/// See https://stylelint.io/user-guide/configure
+const CONFIG_FILES: [&str; 8] = [
+    ".stylelintrc",
+    ".stylelintrc.json",
+    // Prefixed with `./` to ensure that it is loadable via Node.js's `import()`
+    "./.stylelintrc.js",
+    "./stylelint.config.js",
+    "./.stylelintrc.mjs",
+    "./stylelint.config.mjs",
+    "./.stylelintrc.cjs",
+    "./stylelint.config.cjs",
+];
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct StylelintPackageJson {
+    pub(crate) stylelint: Option<StylelintConfiguration>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Config {
+    /// Path of the Stylelint config file
+    pub(crate) path: &'static str,
+    /// Resolved Stylelint config
+    pub(crate) data: StylelintConfiguration,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct StylelintConfiguration {
+    /// The shared configurations. They aren't resolved.
+    pub(crate) extends: ShorthandVec<String>,
+    pub(crate) plugins: ShorthandVec<String>,
+    /// The severity of the rules that don't specify a severity.
+    pub(crate) default_severity: Option<Severity>,
+    /// The glob patterns of the files to ignore.
+    pub(crate) ignore_files: ShorthandVec<String>,
+    pub(crate) rules: IndexMap<String, RuleConf>,
+    pub(crate) overrides: Vec<Override>,
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub(crate) struct Override {
+    /// The glob patterns for target files.
+    pub(crate) files: ShorthandVec<String>,
+    pub(crate) rules: IndexMap<String, RuleConf>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, PartialEq)]
+pub(crate) enum Severity {
+    Warning,
+    #[default]
+    Error,
+}
+impl From<Severity> for biome_configuration::RulePlainConfiguration {
+    fn from(value: Severity) -> biome_configuration::RulePlainConfiguration {
+        match value {
+            Severity::Warning => biome_configuration::RulePlainConfiguration::Warn,
+            Severity::Error => biome_configuration::RulePlainConfiguration::Error,
+        }
+    }
+}
+
+/// Model the possible shapes of a Stylelint's rule configuration
+#[derive(Debug)]
+pub(crate) enum RuleConf {
+    /// `{ rule: null }`
+    Off,
+    /// `{ rule: <primary> }` and `{ rule: [<primary>, <secondary>] }`
+    On {
+        /// The primary option, if it is a string
+        primary: Option<String>,
+        /// The severity set in the secondary options
+        severity: Option<Severity>,
+    },
+}
+impl Deserializable for RuleConf {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        struct Visitor;
+        impl DeserializationVisitor for Visitor {
+            type Output = RuleConf;
+            const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::ARRAY;
+            fn visit_array(
+                self,
+                values: impl Iterator<Item = Option<impl DeserializableValue>>,
+                _range: TextRange,
+                name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let mut values = values.flatten();
+                let primary = values.next()?;
+                if primary.visitable_type()? == DeserializableType::Null {
+                    return Some(RuleConf::Off);
+                }
+                let primary = if primary.visitable_type()? == DeserializableType::Str {
+                    Some(String::deserialize(&primary, name, diagnostics)?)
+                } else {
+                    None
+                };
+                let severity = values
+                    .next()
+                    .and_then(|secondary| {
+                        SecondaryOptions::deserialize(&secondary, name, diagnostics)
+                    })
+                    .and_then(|secondary| secondary.severity);
+                Some(RuleConf::On { primary, severity })
+            }
+        }
+        match value.visitable_type()? {
+            DeserializableType::Null => Some(RuleConf::Off),
+            DeserializableType::Bool => {
+                let enabled = bool::deserialize(value, name, diagnostics)?;
+                Some(if enabled {
+                    RuleConf::On {
+                        primary: None,
+                        severity: None,
+                    }
+                } else {
+                    RuleConf::Off
+                })
+            }
+            DeserializableType::Str => Some(RuleConf::On {
+                primary: Some(String::deserialize(value, name, diagnostics)?),
+                severity: None,
+            }),
+            DeserializableType::Array => value.deserialize(Visitor, name, diagnostics),
+            DeserializableType::Number | DeserializableType::Map => Some(RuleConf::On {
+                primary: None,
+                severity: None,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+struct SecondaryOptions {
+    severity: Option<Severity>,
+}
+
+/// Returns the Stylelint configuration file in the working directory with the highest priority.
+///
+/// Unlike Stylelint, it doesn't look for a configuration file in parent directories
+/// when no configuration file is found in the working directory.
+///
+/// Deserialization errors are reported using `console`.
+/// Other errors (File Not found, unsupported config format, ...) are directly returned.
+///
+/// The `extends` field isn't resolved.
+pub(crate) fn read_config_file(
+    fs: &DynRef<'_, dyn FileSystem>,
+    console: &mut dyn Console,
+) -> Result<Config, CliDiagnostic> {
+    // We don't report an error if Stylelint config is not embedded in `PACKAGE_JSON`.
+    if let Ok(data) = load_config(fs, Path::new(PACKAGE_JSON), console) {
+        return Ok(Config {
+            path: PACKAGE_JSON,
+            data,
+        });
+    }
+    for config_name in CONFIG_FILES {
+        let path = Path::new(config_name);
+        if fs.path_exists(path) {
+            return Ok(Config {
+                path: config_name,
+                data: load_config(fs, path, console)?,
+            });
+        }
+    }
+    Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+        reason: "Biome couldn't find a Stylelint configuration file.".to_string(),
+    }))
+}
+
+fn load_config(
+    fs: &DynRef<'_, dyn FileSystem>,
+    path: &Path,
+    console: &mut dyn Console,
+) -> Result<StylelintConfiguration, CliDiagnostic> {
+    let (deserialized, diagnostics) = match path.extension().and_then(|file_ext| file_ext.to_str())
+    {
+        None | Some("json") => {
+            let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            if path.file_name().is_some_and(|name| name == PACKAGE_JSON) {
+                let (deserialized, _) = deserialize_from_json_str::<StylelintPackageJson>(
+                    &content,
+                    JsonParserOptions::default()
+                        .with_allow_trailing_commas()
+                        .with_allow_comments(),
+                    "",
+                )
+                .consume();
+                (
+                    deserialized.and_then(|packagejson| packagejson.stylelint),
+                    vec![],
+                )
+            } else {
+                deserialize_from_json_str::<StylelintConfiguration>(
+                    &content,
+                    JsonParserOptions::default()
+                        .with_allow_trailing_commas()
+                        .with_allow_comments(),
+                    "",
+                )
+                .consume()
+            }
+        }
+        Some("js" | "mjs" | "cjs") => {
+            let node::Resolution { content, .. } = node::load_config(&path.to_string_lossy())?;
+            deserialize_from_json_str::<StylelintConfiguration>(
+                &content,
+                JsonParserOptions::default(),
+                "",
+            )
+            .consume()
+        }
+        Some(ext) => {
+            return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+                reason: format!(
+                    "Stylelint configuration ending with the extension `{ext}` are not supported."
+                ),
+            }))
+        }
+    };
+    let path_str = path.to_string_lossy();
+    for diagnostic in diagnostics.into_iter().filter(|diag| {
+        matches!(
+            diag.severity(),
+            biome_diagnostics::Severity::Fatal
+                | biome_diagnostics::Severity::Error
+                | biome_diagnostics::Severity::Warning
+        )
+    }) {
+        let diagnostic = diagnostic.with_file_path(path_str.to_string());
+        console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+    }
+    if let Some(result) = deserialized {
+        for extended in result.extends.iter() {
+            console.log(markup! {
+                <Warn>"The shared configuration `"{extended}"` is not migrated. Only the rules set in "<Emphasis>{path_str}</Emphasis>" are migrated."</Warn>
+            });
+        }
+        for plugin in result.plugins.iter() {
+            console.log(markup! {
+                <Warn>"The Stylelint plugin `"{plugin}"` is not supported in Biome. The rules of the plugin are not migrated."</Warn>
+            });
+        }
+        Ok(result)
+    } else {
+        Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Could not deserialize the Stylelint configuration file".to_string(),
+        }))
+    }
+}
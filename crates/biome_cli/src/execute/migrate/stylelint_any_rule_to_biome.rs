@@ -0,0 +1,218 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use super::stylelint_to_biome;
+pub(crate) fn migrate_stylelint_any_rule(
+    rules: &mut biome_configuration::Rules,
+    stylelint_name: &str,
+    rule_level: biome_configuration::RulePlainConfiguration,
+    options: &stylelint_to_biome::MigrationOptions,
+    results: &mut stylelint_to_biome::MigrationResults,
+) -> bool {
+    match stylelint_name {
+        "block-no-empty" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_empty_block.get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "declaration-block-no-shorthand-property-overrides" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_shorthand_property_overrides
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "font-family-no-duplicate-names" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_duplicate_font_names
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "font-family-no-missing-generic-family-keyword" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_generic_font_names
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "function-linear-gradient-no-nonstandard-direction" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_invalid_direction_in_linear_gradient
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "function-no-unknown" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_unknown_function.get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "keyframe-block-no-duplicate-selectors" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_duplicate_selectors_keyframe_block
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "keyframe-declaration-no-important" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_important_in_keyframe
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "media-feature-name-no-unknown" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unknown_media_feature_name
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "media-feature-name-value-no-unknown" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unknown_media_feature_value
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "named-grid-areas-no-invalid" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_consistent_grid_areas
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "no-duplicate-at-import-rules" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_duplicate_at_import_rules
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "no-invalid-position-at-import-rule" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_invalid_position_at_import_rule
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "no-irregular-whitespace" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_irregular_whitespace
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "property-no-unknown" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_unknown_property.get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "selector-anb-no-unmatchable" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unmatchable_anb_selector
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "selector-pseudo-class-no-unknown" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unknown_pseudo_class_selector
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "selector-pseudo-element-no-unknown" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unknown_selector_pseudo_element
+                .get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        "unit-no-unknown" => {
+            if !options.include_nursery {
+                results.nursery_rules.insert(stylelint_name.to_string());
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_unknown_unit.get_or_insert(Default::default());
+            rule.set_level(rule_level);
+        }
+        _ => {
+            return false;
+        }
+    }
+    true
+}
@@ -0,0 +1,139 @@
+use biome_configuration::css::PartialCssLinter;
+use biome_configuration::{self as biome_config};
+use indexmap::IndexMap;
+use std::collections::BTreeSet;
+
+use super::{stylelint, stylelint_any_rule_to_biome::migrate_stylelint_any_rule};
+
+/// This modules includes implementations for converting a Stylelint config to a Biome config.
+///
+/// The conversion relies on the generated [super::stylelint_any_rule_to_biome::migrate_stylelint_any_rule]
+/// module that relies on Biome's rule metadata to determine
+/// the equivalent Biome's rule of a Stylelint rule.
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MigrationOptions {
+    /// Migrate nursery rules from Stylelint?
+    pub(crate) include_nursery: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct MigrationResults {
+    /// Rules that were not migrated because their equivalent is a nursery rule
+    /// and `include_nursery` is disabled
+    pub(crate) nursery_rules: BTreeSet<String>,
+    /// Enabled rules that have no equivalent in Biome
+    pub(crate) unmapped_rules: BTreeSet<String>,
+    /// Enabled rules that are enforced by the Biome formatter
+    pub(crate) formatter_rules: BTreeSet<String>,
+}
+
+impl stylelint::StylelintConfiguration {
+    pub(crate) fn into_biome_config(
+        self,
+        options: &MigrationOptions,
+    ) -> (biome_config::PartialConfiguration, MigrationResults) {
+        let mut results = MigrationResults::default();
+        let mut biome_config = biome_config::PartialConfiguration::default();
+        let default_severity = self.default_severity.unwrap_or_default();
+        let rules = into_biome_rules(self.rules, default_severity, options, &mut results);
+        if rules != biome_config::Rules::default() {
+            biome_config.linter = Some(biome_config::PartialLinterConfiguration {
+                rules: Some(rules),
+                ..Default::default()
+            });
+        }
+        // The CSS linter is disabled by default
+        biome_config.css = Some(css_linter_configuration(true));
+        let mut overrides = biome_config::Overrides::default();
+        for override_elt in self.overrides {
+            let rules =
+                into_biome_rules(override_elt.rules, default_severity, options, &mut results);
+            if override_elt.files.is_empty() || rules == biome_config::Rules::default() {
+                continue;
+            }
+            overrides.0.push(biome_config::OverridePattern {
+                include: Some(override_elt.files.into_iter().collect()),
+                linter: Some(biome_config::OverrideLinterConfiguration {
+                    rules: Some(rules),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        }
+        if !self.ignore_files.is_empty() {
+            // `ignoreFiles` only affects the CSS files, unlike `linter.ignore`
+            overrides.0.push(biome_config::OverridePattern {
+                include: Some(self.ignore_files.into_iter().collect()),
+                css: Some(css_linter_configuration(false)),
+                ..Default::default()
+            });
+        }
+        if !overrides.0.is_empty() {
+            biome_config.overrides = Some(overrides);
+        }
+        (biome_config, results)
+    }
+}
+
+fn css_linter_configuration(enabled: bool) -> biome_config::PartialCssConfiguration {
+    biome_config::PartialCssConfiguration {
+        linter: Some(PartialCssLinter {
+            enabled: Some(enabled),
+        }),
+        ..Default::default()
+    }
+}
+
+fn into_biome_rules(
+    stylelint_rules: IndexMap<String, stylelint::RuleConf>,
+    default_severity: stylelint::Severity,
+    options: &MigrationOptions,
+    results: &mut MigrationResults,
+) -> biome_config::Rules {
+    let mut rules = biome_config::Rules::default();
+    for (name, conf) in stylelint_rules {
+        migrate_stylelint_rule(&mut rules, name, conf, default_severity, options, results);
+    }
+    rules
+}
+
+/// Look for an equivalent Biome rule for the Stylelint rule `name`,
+/// and then mutate `rules` if a equivalent rule is found.
+/// Enabled rules without an equivalent rule are recorded in `results`.
+fn migrate_stylelint_rule(
+    rules: &mut biome_config::Rules,
+    name: String,
+    conf: stylelint::RuleConf,
+    default_severity: stylelint::Severity,
+    opts: &MigrationOptions,
+    results: &mut MigrationResults,
+) {
+    let (rule_level, primary) = match conf {
+        stylelint::RuleConf::Off => (biome_config::RulePlainConfiguration::Off, None),
+        stylelint::RuleConf::On { primary, severity } => {
+            (severity.unwrap_or(default_severity).into(), primary)
+        }
+    };
+    let is_enabled = rule_level != biome_config::RulePlainConfiguration::Off;
+    match name.as_str() {
+        // The formatter prints the hexadecimal colors in lowercase
+        "color-hex-case" => {
+            if is_enabled {
+                if primary.as_deref() == Some("lower") {
+                    results.formatter_rules.insert(name);
+                } else {
+                    results.unmapped_rules.insert(name);
+                }
+            }
+        }
+        _ => {
+            if !migrate_stylelint_any_rule(rules, &name, rule_level, opts, results)
+                && is_enabled
+                && !results.nursery_rules.contains(&name)
+            {
+                results.unmapped_rules.insert(name);
+            }
+        }
+    }
+}
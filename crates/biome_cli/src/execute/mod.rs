@@ -1,4 +1,6 @@
 mod diagnostics;
+pub(crate) mod fix_log;
+mod interactive;
 mod migrate;
 mod process_file;
 mod std_in;
@@ -9,10 +11,13 @@ use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::{traverse, TraverseResult};
+use crate::reporter::checkstyle::{CheckstyleReporter, CheckstyleReporterVisitor};
 use crate::reporter::github::{GithubReporter, GithubReporterVisitor};
 use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
+use crate::reporter::json_lines::{JsonLinesReporter, JsonLinesReporterVisitor};
 use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
+use crate::reporter::rule_profile::RuleProfile;
 use crate::reporter::summary::{SummaryReporter, SummaryReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{CliDiagnostic, CliSession, DiagnosticsPayload, Reporter};
@@ -41,6 +46,17 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u32,
+
+    /// When set, the changes are computed without being written, and reported as a patch
+    dry_run: Option<DryRun>,
+}
+
+/// The options of a traversal that computes the changes of the files without writing them
+#[derive(Debug, Clone)]
+pub(crate) struct DryRun {
+    /// The file where the unified diff of the changes is written.
+    /// The diff is printed in the console when it's [None]
+    pub(crate) patch_path: Option<PathBuf>,
 }
 
 impl Execution {
@@ -49,11 +65,14 @@ impl Execution {
             traversal_mode: TraversalMode::Format {
                 ignore_errors: false,
                 write: false,
+                verify: false,
+                staged_hunks: false,
                 stdin: None,
                 vcs_targeted,
             },
             report_mode: ReportMode::default(),
             max_diagnostics: 0,
+            dry_run: None,
         }
     }
 
@@ -113,6 +132,8 @@ impl From<(PathBuf, String)> for Stdin {
 pub struct VcsTargeted {
     pub staged: bool,
     pub changed: bool,
+    /// The paths were read from the list of `--files-from`
+    pub files_from: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -151,6 +172,16 @@ pub enum TraversalMode {
         skip: Vec<RuleSelector>,
         /// A flag to know vcs integrated options such as `--staged` or `--changed` are enabled
         vcs_targeted: VcsTargeted,
+        /// Whether the time spent running each rule is printed at the end of the run
+        profile: bool,
+        /// Whether the fixes are reviewed one by one before they are written.
+        ///
+        /// The traversal only collects the files that have fixes, which are then reviewed by [interactive::run].
+        interactive: bool,
+        /// Whether the rule, and the code before and after, of each applied fix is printed at the end of the run
+        explain_fixes: bool,
+        /// The JSON file where the applied fixes are logged
+        fix_log: Option<PathBuf>,
     },
     /// This mode is enabled when running the command `biome ci`
     CI {
@@ -165,6 +196,10 @@ pub enum TraversalMode {
         ignore_errors: bool,
         /// It writes the new content on file
         write: bool,
+        /// It checks the stability of the formatter instead of reporting the unformatted files
+        verify: bool,
+        /// It formats the content of the files in the git index, instead of the working tree
+        staged_hunks: bool,
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
@@ -216,6 +251,8 @@ pub enum ReportMode {
     Terminal { with_summary: bool },
     /// Reports information in JSON format
     Json { pretty: bool },
+    /// Reports each diagnostic in JSON format on its own line, while the traversal is running
+    JsonLines,
     /// Reports information for GitHub
     GitHub,
     /// JUnit output
@@ -223,6 +260,8 @@ pub enum ReportMode {
     Junit,
     /// Reports information in the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool) format.
     GitLab,
+    /// Reports the diagnostics in the Checkstyle XML format, grouped by file
+    Checkstyle,
 }
 
 impl Default for ReportMode {
@@ -242,9 +281,11 @@ impl From<CliReporter> for ReportMode {
             CliReporter::Summary => Self::Terminal { with_summary: true },
             CliReporter::Json => Self::Json { pretty: false },
             CliReporter::JsonPretty => Self::Json { pretty: true },
+            CliReporter::JsonLines => Self::JsonLines,
             CliReporter::GitHub => Self::GitHub,
             CliReporter::Junit => Self::Junit,
             CliReporter::GitLab => Self::GitLab {},
+            CliReporter::Checkstyle => Self::Checkstyle,
         }
     }
 }
@@ -255,6 +296,7 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            dry_run: None,
         }
     }
 
@@ -275,6 +317,7 @@ impl Execution {
                 vcs_targeted,
             },
             max_diagnostics: 20,
+            dry_run: None,
         }
     }
 
@@ -284,6 +327,16 @@ impl Execution {
         self
     }
 
+    /// It sets the dry-run options, the changes are reported as a patch instead of being written
+    pub(crate) fn set_dry_run(mut self, dry_run: Option<DryRun>) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub(crate) fn as_dry_run(&self) -> Option<&DryRun> {
+        self.dry_run.as_ref()
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -363,8 +416,22 @@ impl Execution {
         }
     }
 
+    /// Whether the formatter processes the content of the files in the git index, with `--staged-hunks`
+    pub(crate) const fn is_format_staged_hunks(&self) -> bool {
+        matches!(
+            self.traversal_mode,
+            TraversalMode::Format {
+                staged_hunks: true,
+                ..
+            }
+        )
+    }
+
     /// Whether the traversal mode requires write access to files
     pub(crate) const fn requires_write_access(&self) -> bool {
+        if self.dry_run.is_some() {
+            return false;
+        }
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
@@ -383,12 +450,16 @@ impl Execution {
         }
     }
 
+    /// Whether the paths to process were computed from `--staged`, `--changed` or `--files-from`,
+    /// instead of being passed as arguments
     pub(crate) fn is_vcs_targeted(&self) -> bool {
         match &self.traversal_mode {
             TraversalMode::Check { vcs_targeted, .. }
             | TraversalMode::Lint { vcs_targeted, .. }
             | TraversalMode::Format { vcs_targeted, .. }
-            | TraversalMode::CI { vcs_targeted, .. } => vcs_targeted.staged || vcs_targeted.changed,
+            | TraversalMode::CI { vcs_targeted, .. } => {
+                vcs_targeted.staged || vcs_targeted.changed || vcs_targeted.files_from
+            }
             TraversalMode::Migrate { .. } | TraversalMode::Search { .. } => false,
         }
     }
@@ -437,10 +508,31 @@ pub fn execute_mode(
         };
         migrate::run(payload)
     } else {
+        if let TraversalMode::Lint {
+            interactive: true, ..
+        } = execution.traversal_mode
+        {
+            let TraverseResult { fixable_paths, .. } =
+                traverse(&execution, &mut session, cli_options, paths.clone())?;
+            interactive::run(&execution, &mut session, fixable_paths)?;
+            // The diagnostics left after the review are reported without fixing them
+            if let TraversalMode::Lint {
+                fix_file_mode,
+                interactive,
+                ..
+            } = &mut execution.traversal_mode
+            {
+                *fix_file_mode = None;
+                *interactive = false;
+            }
+        }
         let TraverseResult {
             summary,
             evaluated_paths,
             diagnostics,
+            not_processed,
+            rule_timings,
+            ..
         } = traverse(&execution, &mut session, cli_options, paths)?;
         let console = session.app.console;
         let errors = summary.errors;
@@ -488,7 +580,7 @@ pub fn execute_mode(
                     },
                     execution: execution.clone(),
                 };
-                let mut buffer = JsonReporterVisitor::new(summary);
+                let mut buffer = JsonReporterVisitor::new(summary).with_shard(cli_options.shard);
                 reporter.write(&mut buffer)?;
                 if pretty {
                     let content = serde_json::to_string(&buffer).map_err(|error| {
@@ -515,6 +607,19 @@ pub fn execute_mode(
                     });
                 }
             }
+            ReportMode::JsonLines => {
+                // The diagnostics were already streamed during the traversal, only the summary is left
+                let reporter = JsonLinesReporter {
+                    summary,
+                    diagnostics: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    execution: execution.clone(),
+                };
+                reporter.write(&mut JsonLinesReporterVisitor::new(console))?;
+            }
             ReportMode::GitHub => {
                 let reporter = GithubReporter {
                     diagnostics_payload: DiagnosticsPayload {
@@ -552,10 +657,43 @@ pub fn execute_mode(
                 };
                 reporter.write(&mut JunitReporterVisitor::new(console))?;
             }
+            ReportMode::Checkstyle => {
+                let reporter = CheckstyleReporter {
+                    diagnostics_payload: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    execution: execution.clone(),
+                };
+                reporter.write(&mut CheckstyleReporterVisitor(console))?;
+            }
+        }
+
+        // The other reporters print a machine-readable output
+        if matches!(
+            execution.traversal_mode,
+            TraversalMode::Lint { profile: true, .. }
+        ) && matches!(execution.report_mode, ReportMode::Terminal { .. })
+        {
+            console.log(markup! {
+                {RuleProfile(rule_timings)}
+            });
+        }
+
+        // The other reporters print a machine-readable output
+        if not_processed > 0 && matches!(execution.report_mode, ReportMode::Terminal { .. }) {
+            console.log(markup! {
+                <Warn>"The maximum number of failures was reached, "{not_processed}" file(s) weren't processed."</Warn>
+            });
         }
 
         // Processing emitted error diagnostics, exit with a non-zero code
-        if processed.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
+        // A shard can be empty when there are fewer files than shards
+        if processed.saturating_sub(skipped) == 0
+            && !cli_options.no_errors_on_unmatched
+            && cli_options.shard.is_none()
+        {
             Err(CliDiagnostic::no_files_processed())
         } else if errors > 0 || should_exit_on_warnings {
             let category = execution.as_diagnostic_category();
@@ -7,9 +7,10 @@ mod search;
 pub(crate) mod workspace_file;
 
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
+use crate::execute::fix_log::AppliedFix;
 use crate::execute::traverse::TraversalOptions;
-use crate::execute::TraversalMode;
-use biome_diagnostics::{category, DiagnosticExt, DiagnosticTags, Error};
+use crate::execute::{Execution, TraversalMode};
+use biome_diagnostics::{category, DiagnosticExt, DiagnosticTags, Error, Severity};
 use biome_fs::BiomePath;
 use biome_service::workspace::{FeatureKind, SupportKind, SupportsFeatureParams};
 use check::check_file;
@@ -18,7 +19,7 @@ use lint::lint;
 use search::search;
 use std::marker::PhantomData;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 #[derive(Debug)]
 pub(crate) enum FileStatus {
     /// File changed and it was a success
@@ -60,12 +61,47 @@ pub(crate) enum Message {
         new: String,
         diff_kind: DiffKind,
     },
+    /// A change that would have been written to the file, in dry-run mode
+    Patch {
+        file_name: String,
+        old: String,
+        new: String,
+    },
+    /// A change to write to the git index after the traversal, with `--staged-hunks`
+    Staged {
+        path: PathBuf,
+        content: String,
+    },
+    /// A file that has fixes to review, in interactive mode
+    Fixable {
+        path: PathBuf,
+    },
+    /// The fixes applied to a file, when they are explained or logged
+    AppliedFixes {
+        file_name: String,
+        fixes: Vec<AppliedFix>,
+    },
 }
 
 impl Message {
     pub(crate) const fn is_failure(&self) -> bool {
         matches!(self, Message::Failure)
     }
+
+    /// Returns the number of errors reported by the message, which are counted
+    /// towards the maximum number of failures of the traversal
+    pub(crate) fn errors(&self, execution: &Execution) -> u32 {
+        match self {
+            Message::Failure => 1,
+            Message::Diagnostics { diagnostics, .. } => diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.severity() == Severity::Error)
+                .count() as u32,
+            // A diff is an error in CI mode and in format check mode
+            Message::Diff { .. } if execution.is_ci() || !execution.is_format_write() => 1,
+            _ => 0,
+        }
+    }
 }
 
 #[derive(Debug)]
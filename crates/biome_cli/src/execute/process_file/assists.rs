@@ -26,6 +26,7 @@ pub(crate) fn assists_with_guard<'ctx>(
                     RuleCategoriesBuilder::default().with_action().build(),
                     only.clone(),
                     skip.clone(),
+                    workspace_file.remaining_time(ctx.file_timeout),
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
@@ -1,4 +1,8 @@
-use crate::execute::diagnostics::{ResultExt, SkippedDiagnostic};
+use crate::execute::diagnostics::{
+    ContentDiffAdvice, FormatNotIdempotentDiagnostic, FormatSyntaxChangedDiagnostic,
+    FormatSyntaxErrorsDiagnostic, FormatTimeoutDiagnostic, FormattedCodeAdvice, ResultExt,
+    SkippedDiagnostic,
+};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{
     DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
@@ -6,7 +10,7 @@ use crate::execute::process_file::{
 use crate::execute::TraversalMode;
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Error, Severity};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::workspace::FormatStabilityIssue;
 use std::path::Path;
 use std::sync::atomic::Ordering;
 use tracing::debug;
@@ -31,6 +35,8 @@ pub(crate) fn format_with_guard<'ctx>(
                     max_diagnostics,
                     Vec::new(),
                     Vec::new(),
+                    workspace_file.remaining_time(ctx.file_timeout),
+                    false,
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
@@ -38,16 +44,18 @@ pub(crate) fn format_with_guard<'ctx>(
                 )?;
 
             let input = workspace_file.input()?;
-            let (should_write, ignore_errors) = match ctx.execution.traversal_mode {
+            let (should_write, ignore_errors, verify) = match ctx.execution.traversal_mode {
                 TraversalMode::Format {
                     write,
                     ignore_errors,
+                    verify,
                     ..
-                } => (write, ignore_errors),
+                } => (write, ignore_errors, verify),
 
                 _ => (
                     ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe(),
                     false,
+                    false,
                 ),
             };
             debug!("Should write the file to disk? {}", should_write);
@@ -76,6 +84,19 @@ pub(crate) fn format_with_guard<'ctx>(
                 skipped_diagnostics: diagnostics_result.skipped_diagnostics as u32,
             });
 
+            // The formatter can't be stopped once it started, so the file isn't formatted
+            // when the previous steps already used the time given to it
+            if workspace_file.is_past_timeout(ctx.file_timeout) {
+                return Err(Message::from(
+                    FormatTimeoutDiagnostic
+                        .with_file_path(workspace_file.path.display().to_string()),
+                ));
+            }
+
+            if verify {
+                return verify_format(ctx, workspace_file, input);
+            }
+
             let printed = workspace_file
                 .guard()
                 .format_file()
@@ -84,35 +105,12 @@ pub(crate) fn format_with_guard<'ctx>(
                     category!("format"),
                 )?;
 
-            let mut output = printed.into_code();
+            let output = printed.into_code();
 
             if ignore_errors {
                 return Ok(FileStatus::Ignored);
             }
 
-            match workspace_file.as_extension() {
-                Some("astro") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = AstroFileHandler::output(input.as_str(), output.as_str());
-                }
-                Some("vue") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = VueFileHandler::output(input.as_str(), output.as_str());
-                }
-
-                Some("svelte") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = SvelteFileHandler::output(input.as_str(), output.as_str());
-                }
-                _ => {}
-            }
-
             if output != input {
                 if should_write {
                     workspace_file.update_file(output)?;
@@ -131,3 +129,68 @@ pub(crate) fn format_with_guard<'ctx>(
         },
     )
 }
+
+/// Checks that formatting the file produces code that can be parsed, is stable,
+/// and has the same syntax as the original code
+fn verify_format<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    workspace_file: &mut WorkspaceFile,
+    input: String,
+) -> FileResult {
+    // The stability of the embedded languages can't be verified yet
+    if matches!(
+        workspace_file.as_extension(),
+        Some("astro" | "vue" | "svelte" | "mdx")
+    ) {
+        return Ok(FileStatus::Ignored);
+    }
+
+    let file_name = workspace_file.path.display().to_string();
+    let result = workspace_file
+        .guard()
+        .verify_format_file()
+        .with_file_path_and_code(file_name.clone(), category!("format"))?;
+
+    let Some(issue) = result.issue else {
+        return Ok(FileStatus::Unchanged);
+    };
+    let (content, diagnostics) = match issue {
+        FormatStabilityIssue::SyntaxErrors { diagnostics } => {
+            let mut errors = vec![Error::from(FormatSyntaxErrorsDiagnostic {
+                file_name: file_name.clone(),
+            })];
+            errors.extend(diagnostics.into_iter().map(Error::from));
+            (result.formatted, errors)
+        }
+        FormatStabilityIssue::NotIdempotent { reformatted } => (
+            input,
+            vec![Error::from(FormatNotIdempotentDiagnostic {
+                file_name: file_name.clone(),
+                diff: ContentDiffAdvice {
+                    old: result.formatted,
+                    new: reformatted,
+                },
+            })],
+        ),
+        FormatStabilityIssue::SyntaxTreeChanged(difference) => (
+            input,
+            vec![Error::from(FormatSyntaxChangedDiagnostic {
+                file_name: file_name.clone(),
+                original_range: difference.original_range,
+                formatted: FormattedCodeAdvice {
+                    file_name: file_name.clone(),
+                    range: difference.formatted_range,
+                    code: result.formatted,
+                },
+            })],
+        ),
+    };
+    ctx.push_message(Message::Diagnostics {
+        name: file_name,
+        content,
+        diagnostics,
+        skipped_diagnostics: 0,
+    });
+
+    Ok(FileStatus::Unchanged)
+}
@@ -1,11 +1,15 @@
 use crate::execute::diagnostics::ResultExt;
+use crate::execute::fix_log::applied_fixes;
+use crate::execute::interactive::fixable_actions;
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
 use crate::TraversalMode;
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{category, Error};
 use biome_rowan::TextSize;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{
+    AstroFileHandler, MdxFileHandler, SvelteFileHandler, VueFileHandler,
+};
 use std::path::Path;
 use std::sync::atomic::Ordering;
 
@@ -21,14 +25,51 @@ pub(crate) fn lint_with_guard<'ctx>(
 ) -> FileResult {
     tracing::info_span!("Processes linting", path =? workspace_file.path.display()).in_scope(
         move || {
+            if let TraversalMode::Lint {
+                fix_file_mode: Some(fix_file_mode),
+                only,
+                skip,
+                interactive: true,
+                ..
+            } = ctx.execution.traversal_mode()
+            {
+                // The fixes are reviewed one by one once the traversal is done
+                let actions = workspace_file
+                    .guard()
+                    .pull_actions(None, only.clone(), skip.clone())
+                    .with_file_path_and_code(
+                        workspace_file.path.display().to_string(),
+                        category!("lint"),
+                    )?
+                    .actions;
+                if !fixable_actions(actions, *fix_file_mode).is_empty() {
+                    ctx.push_message(Message::Fixable {
+                        path: workspace_file.path.clone(),
+                    });
+                }
+                return Ok(FileStatus::Unchanged);
+            }
+
             let mut input = workspace_file.input()?;
             let mut changed = false;
-            let (only, skip) =
-                if let TraversalMode::Lint { only, skip, .. } = ctx.execution.traversal_mode() {
-                    (only.clone(), skip.clone())
-                } else {
-                    (Vec::new(), Vec::new())
-                };
+            let (only, skip, track_fixes, profile) = if let TraversalMode::Lint {
+                only,
+                skip,
+                explain_fixes,
+                fix_log,
+                profile,
+                ..
+            } = ctx.execution.traversal_mode()
+            {
+                (
+                    only.clone(),
+                    skip.clone(),
+                    *explain_fixes || fix_log.is_some(),
+                    *profile,
+                )
+            } else {
+                (Vec::new(), Vec::new(), false, false)
+            };
             if let Some(fix_mode) = ctx.execution.as_fix_file_mode() {
                 let fix_result = workspace_file
                     .guard()
@@ -41,6 +82,7 @@ pub(crate) fn lint_with_guard<'ctx>(
                             .build(),
                         only.clone(),
                         skip.clone(),
+                        workspace_file.remaining_time(ctx.file_timeout),
                     )
                     .with_file_path_and_code(
                         workspace_file.path.display().to_string(),
@@ -51,6 +93,19 @@ pub(crate) fn lint_with_guard<'ctx>(
                     skipped_suggested_fixes: fix_result.skipped_suggested_fixes,
                 });
 
+                if track_fixes {
+                    let offset = match workspace_file.as_extension() {
+                        Some("vue") => VueFileHandler::start(input.as_str()),
+                        Some("astro") => AstroFileHandler::start(input.as_str()),
+                        Some("svelte") => SvelteFileHandler::start(input.as_str()),
+                        _ => None,
+                    };
+                    ctx.push_message(Message::AppliedFixes {
+                        file_name: workspace_file.path.display().to_string(),
+                        fixes: applied_fixes(input.as_str(), offset, fix_result.actions),
+                    });
+                }
+
                 let mut output = fix_result.code;
 
                 match workspace_file.as_extension() {
@@ -63,6 +118,9 @@ pub(crate) fn lint_with_guard<'ctx>(
                     Some("svelte") => {
                         output = SvelteFileHandler::output(input.as_str(), output.as_str());
                     }
+                    Some("mdx") => {
+                        output = MdxFileHandler::output(input.as_str(), output.as_str());
+                    }
                     _ => {}
                 }
                 if output != input {
@@ -83,12 +141,19 @@ pub(crate) fn lint_with_guard<'ctx>(
                     max_diagnostics,
                     only,
                     skip,
+                    workspace_file.remaining_time(ctx.file_timeout),
+                    profile,
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("lint"),
                 )?;
 
+            if profile {
+                ctx.rule_timings
+                    .extend(pull_diagnostics_result.rule_timings);
+            }
+
             let no_diagnostics = pull_diagnostics_result.diagnostics.is_empty()
                 && pull_diagnostics_result.skipped_diagnostics == 0;
 
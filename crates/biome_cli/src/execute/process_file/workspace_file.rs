@@ -1,16 +1,29 @@
 use crate::execute::diagnostics::{ResultExt, ResultIoExt};
-use crate::execute::process_file::SharedTraversalOptions;
+use crate::execute::process_file::{Message, SharedTraversalOptions};
 use biome_diagnostics::{category, Error};
 use biome_fs::{BiomePath, File, OpenOptions};
 use biome_service::workspace::{FileGuard, OpenFileParams};
 use biome_service::{Workspace, WorkspaceError};
+use crossbeam::channel::Sender;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 /// Small wrapper that holds information and operations around the current processed file
 pub(crate) struct WorkspaceFile<'ctx, 'app> {
     guard: FileGuard<'app, dyn Workspace + 'ctx>,
     file: Box<dyn File>,
     pub(crate) path: PathBuf,
+    /// When the processing of the file started
+    opened_at: Instant,
+    /// In dry-run mode, the channel receiving the changes instead of the file
+    patches: Option<Sender<Message>>,
+    /// With `--staged-hunks`, the channel receiving the changes to write to the git index after the traversal
+    staged: Option<Sender<Message>>,
+    /// With `--staged-hunks`, whether the file has changes that aren't staged.
+    /// The working tree of these files isn't written, so that their unstaged changes are kept as they are
+    has_unstaged_changes: bool,
+    /// The version of the workspace document, when the changes aren't written to the file
+    document_version: i32,
 }
 
 impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
@@ -20,6 +33,7 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         ctx: &SharedTraversalOptions<'ctx, 'app>,
         path: &Path,
     ) -> Result<Self, Error> {
+        let opened_at = Instant::now();
         let biome_path = BiomePath::new(path);
         let open_options = OpenOptions::default()
             .read(true)
@@ -33,6 +47,18 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         file.read_to_string(&mut input)
             .with_file_path(path.display().to_string())?;
 
+        // With `--staged-hunks`, the content that will be committed is processed instead of the working tree
+        let is_format_staged_hunks = ctx.execution.is_format_staged_hunks();
+        let mut has_unstaged_changes = false;
+        if is_format_staged_hunks {
+            let staged_content = ctx
+                .fs
+                .get_staged_content(path)
+                .with_file_path(path.display().to_string())?;
+            has_unstaged_changes = staged_content != input;
+            input = staged_content;
+        }
+
         let guard = FileGuard::open(
             ctx.workspace,
             OpenFileParams {
@@ -48,9 +74,25 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
             file,
             guard,
             path: PathBuf::from(path),
+            opened_at,
+            patches: ctx.execution.as_dry_run().map(|_| ctx.messages.clone()),
+            staged: is_format_staged_hunks.then(|| ctx.messages.clone()),
+            has_unstaged_changes,
+            document_version: 0,
         })
     }
 
+    /// Returns the time left to analyze the file, when the traversal has a per-file `timeout`
+    pub(crate) fn remaining_time(&self, timeout: Option<Duration>) -> Option<Duration> {
+        timeout.map(|timeout| timeout.saturating_sub(self.opened_at.elapsed()))
+    }
+
+    /// Whether the per-file `timeout` was exceeded, including the time spent reading and parsing the file
+    pub(crate) fn is_past_timeout(&self, timeout: Option<Duration>) -> bool {
+        self.remaining_time(timeout)
+            .is_some_and(|remaining| remaining.is_zero())
+    }
+
     pub(crate) fn guard(&self) -> &FileGuard<'app, dyn Workspace + 'ctx> {
         &self.guard
     }
@@ -64,14 +106,43 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
     }
 
     /// It updates the workspace file with `new_content`
+    ///
+    /// In dry-run mode, the file isn't written and the change is sent as a [Message::Patch].
+    /// With `--staged-hunks`, the change is sent as a [Message::Staged] to be written to the git index,
+    /// and it's written to the file when it doesn't have unstaged changes
     pub(crate) fn update_file(&mut self, new_content: impl Into<String>) -> Result<(), Error> {
         let new_content = new_content.into();
 
-        self.file
-            .set_content(new_content.as_bytes())
-            .with_file_path(self.path.display().to_string())?;
-        self.guard
-            .change_file(self.file.file_version(), new_content)?;
+        let version = if let Some(patches) = &self.patches {
+            patches
+                .send(Message::Patch {
+                    file_name: self.path.display().to_string(),
+                    old: self.input()?,
+                    new: new_content.clone(),
+                })
+                .ok();
+            self.document_version += 1;
+            self.document_version
+        } else {
+            if let Some(staged) = &self.staged {
+                staged
+                    .send(Message::Staged {
+                        path: self.path.clone(),
+                        content: new_content.clone(),
+                    })
+                    .ok();
+            }
+            if self.has_unstaged_changes {
+                self.document_version += 1;
+                self.document_version
+            } else {
+                self.file
+                    .set_content(new_content.as_bytes())
+                    .with_file_path(self.path.display().to_string())?;
+                self.file.file_version()
+            }
+        };
+        self.guard.change_file(version, new_content)?;
         Ok(())
     }
 }
@@ -8,7 +8,9 @@ use biome_console::{markup, ConsoleExt};
 use biome_diagnostics::PrintDiagnostic;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, Error};
 use biome_fs::BiomePath;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{
+    AstroFileHandler, MdxFileHandler, SvelteFileHandler, VueFileHandler,
+};
 use biome_service::workspace::{
     ChangeFileParams, DropPatternParams, FeaturesBuilder, FixFileParams, FormatFileParams,
     OpenFileParams, OrganizeImportsParams, PullDiagnosticsParams, SupportsFeatureParams,
@@ -56,13 +58,7 @@ pub(crate) fn run<'a>(
                 path: biome_path.clone(),
             })?;
 
-            let code = printed.into_code();
-            let output = match biome_path.extension_as_str() {
-                Some("astro") => AstroFileHandler::output(content, code.as_str()),
-                Some("vue") => VueFileHandler::output(content, code.as_str()),
-                Some("svelte") => SvelteFileHandler::output(content, code.as_str()),
-                _ => code,
-            };
+            let output = printed.into_code();
             console.append(markup! {
                 {output}
             });
@@ -126,12 +122,14 @@ pub(crate) fn run<'a>(
                         .with_syntax()
                         .with_lint()
                         .build(),
+                    timeout: None,
                 })?;
                 let code = fix_file_result.code;
                 let output = match biome_path.extension_as_str() {
                     Some("astro") => AstroFileHandler::output(&new_content, code.as_str()),
                     Some("vue") => VueFileHandler::output(&new_content, code.as_str()),
                     Some("svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
+                    Some("mdx") => MdxFileHandler::output(&new_content, code.as_str()),
                     _ => code,
                 };
                 if output != new_content {
@@ -178,6 +176,8 @@ pub(crate) fn run<'a>(
                 max_diagnostics: mode.max_diagnostics.into(),
                 only,
                 skip,
+                timeout: None,
+                profile: false,
             })?;
             let content = match biome_path.extension_as_str() {
                 Some("astro") => AstroFileHandler::input(&new_content),
@@ -199,13 +199,7 @@ pub(crate) fn run<'a>(
             let printed = workspace.format_file(FormatFileParams {
                 path: biome_path.clone(),
             })?;
-            let code = printed.into_code();
-            let output = match biome_path.extension_as_str() {
-                Some("astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                Some("vue") => VueFileHandler::output(&new_content, code.as_str()),
-                Some("svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
-                _ => code,
-            };
+            let output = printed.into_code();
             if mode.is_check_apply() || mode.is_check_apply_unsafe() {
                 if output != new_content {
                     new_content = Cow::Owned(output);
@@ -1,28 +1,43 @@
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
-use super::{Execution, TraversalMode};
-use crate::cli_options::CliOptions;
+use super::{DryRun, Execution, ReportMode, TraversalMode};
+use crate::cli_options::{CliOptions, Shard};
 use crate::execute::diagnostics::{
     AssistsDiffDiagnostic, CIAssistsDiffDiagnostic, CIFormatDiffDiagnostic,
     CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic,
     OrganizeImportsDiffDiagnostic, PanicDiagnostic,
 };
+use crate::execute::fix_log::{write_fix_log, AppliedFix};
+use crate::reporter::applied_fixes::AppliedFixes;
+use crate::reporter::json_lines::JsonLinesReporterVisitor;
+use crate::reporter::rule_profile::RuleTimings;
 use crate::reporter::TraversalSummary;
-use crate::{CliDiagnostic, CliSession};
+use crate::{CliDiagnostic, CliSession, DiagnosticsPayload, ReporterVisitor};
+use biome_console::{markup, ConsoleExt};
 use biome_diagnostics::DiagnosticTags;
 use biome_diagnostics::{category, DiagnosticExt, Error, Resource, Severity};
-use biome_fs::{BiomePath, EvaluatedPath, FileSystem, PathInterner};
+use biome_fs::{BiomePath, EvaluatedPath, FileSystem, OpenOptions, PathInterner};
 use biome_fs::{TraversalContext, TraversalScope};
-use biome_service::workspace::{DropPatternParams, IsPathIgnoredParams};
+use biome_service::workspace::{
+    DocumentFileSource, DropPatternParams, FileGuard, IsPathIgnoredParams, OpenFileParams,
+    ProjectIndexesParams, RuleTiming,
+};
 use biome_service::{extension_error, workspace::SupportsFeatureParams, Workspace, WorkspaceError};
 use crossbeam::channel::{unbounded, Receiver, Sender};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rayon::ThreadPool;
 use rustc_hash::FxHashSet;
+use similar::TextDiff;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
 use std::sync::atomic::AtomicU32;
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
 use std::{
     env::current_dir,
     ffi::OsString,
+    io,
+    num::NonZeroUsize,
     panic::catch_unwind,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
     sync::{
         atomic::{AtomicUsize, Ordering},
         Once,
@@ -35,6 +50,12 @@ pub(crate) struct TraverseResult {
     pub(crate) summary: TraversalSummary,
     pub(crate) evaluated_paths: FxHashSet<EvaluatedPath>,
     pub(crate) diagnostics: Vec<Error>,
+    /// The files that have fixes to review, in interactive mode
+    pub(crate) fixable_paths: Vec<PathBuf>,
+    /// The number of files that weren't processed because the maximum number of failures was reached
+    pub(crate) not_processed: usize,
+    /// The time spent running each rule, when `--profile` is set
+    pub(crate) rule_timings: Vec<RuleTiming>,
 }
 
 pub(crate) fn traverse(
@@ -43,7 +64,8 @@ pub(crate) fn traverse(
     cli_options: &CliOptions,
     mut inputs: Vec<OsString>,
 ) -> Result<TraverseResult, CliDiagnostic> {
-    init_thread_pool();
+    let thread_pool = init_thread_pool(cli_options.threads)?;
+    let max_failures = cli_options.max_failures()?;
 
     if inputs.is_empty() {
         match &execution.traversal_mode {
@@ -52,7 +74,7 @@ pub(crate) fn traverse(
             | TraversalMode::Format { .. }
             | TraversalMode::CI { .. }
             | TraversalMode::Search { .. } => {
-                // If `--staged` or `--changed` is specified, it's acceptable for them to be empty, so ignore it.
+                // If `--staged`, `--changed` or `--files-from` is specified, it's acceptable for them to be empty, so ignore it.
                 if !execution.is_vcs_targeted() {
                     match current_dir() {
                         Ok(current_dir) => inputs.push(current_dir.into_os_string()),
@@ -77,9 +99,17 @@ pub(crate) fn traverse(
     let changed = AtomicUsize::new(0);
     let unchanged = AtomicUsize::new(0);
     let skipped = AtomicUsize::new(0);
+    let not_processed = AtomicUsize::new(0);
+    let failures = AtomicU32::new(0);
+    let rule_timings = RuleTimings::default();
 
     let fs = &*session.app.fs;
     let workspace = &*session.app.workspace;
+    // Reporters that support streaming receive the diagnostics as soon as a file is processed
+    let mut stream = match execution.report_mode {
+        ReportMode::JsonLines => Some(JsonLinesReporterVisitor::new(&mut *session.app.console)),
+        _ => None,
+    };
 
     let max_diagnostics = execution.get_max_diagnostics();
     let remaining_diagnostics = AtomicU32::new(max_diagnostics);
@@ -92,33 +122,91 @@ pub(crate) fn traverse(
     let (duration, evaluated_paths, diagnostics) = thread::scope(|s| {
         let handler = thread::Builder::new()
             .name(String::from("biome::console"))
-            .spawn_scoped(s, || printer.run(receiver, recv_files))
+            .spawn_scoped(s, || {
+                printer.run(
+                    receiver,
+                    recv_files,
+                    stream
+                        .as_mut()
+                        .map(|stream| stream as &mut (dyn ReporterVisitor + Send)),
+                )
+            })
             .expect("failed to spawn console thread");
 
         // The traversal context is scoped to ensure all the channels it
         // contains are properly closed once the traversal finishes
-        let (elapsed, evaluated_paths) = traverse_inputs(
-            fs,
-            inputs,
-            &TraversalOptions {
+        let run_traversal = || {
+            traverse_inputs(
                 fs,
-                workspace,
-                execution,
-                interner,
-                changed: &changed,
-                unchanged: &unchanged,
-                skipped: &skipped,
-                messages: sender,
-                remaining_diagnostics: &remaining_diagnostics,
-                evaluated_paths: RwLock::default(),
-            },
-        );
+                inputs,
+                &TraversalOptions {
+                    fs,
+                    workspace,
+                    execution,
+                    interner,
+                    changed: &changed,
+                    unchanged: &unchanged,
+                    skipped: &skipped,
+                    not_processed: &not_processed,
+                    failures: &failures,
+                    max_failures,
+                    messages: sender,
+                    remaining_diagnostics: &remaining_diagnostics,
+                    rule_timings: &rule_timings,
+                    evaluated_paths: RwLock::default(),
+                    file_timeout: cli_options.file_timeout.map(Duration::from_millis),
+                    shard: cli_options.shard,
+                    working_directory: fs.working_directory(),
+                },
+            )
+        };
+        let (elapsed, evaluated_paths) = match &thread_pool {
+            Some(thread_pool) => thread_pool.install(run_traversal),
+            None => run_traversal(),
+        };
         // wait for the main thread to finish
         let diagnostics = handler.join().unwrap();
 
         (elapsed, evaluated_paths, diagnostics)
     });
 
+    if let Some(dry_run) = execution.as_dry_run() {
+        let patch = printer.patch(fs.working_directory().as_deref());
+        write_patch(session, dry_run, &patch)?;
+    }
+
+    // The git index is updated once, after the files were processed in parallel
+    let staged_contents = std::mem::take(&mut *printer.staged_contents.lock().unwrap());
+    if !staged_contents.is_empty() {
+        let staged_contents = staged_contents
+            .into_iter()
+            .map(|(path, content)| (path, content.into_bytes()))
+            .collect::<Vec<_>>();
+        session
+            .app
+            .fs
+            .set_staged_contents(&staged_contents)
+            .map_err(CliDiagnostic::io_error)?;
+    }
+
+    if let TraversalMode::Lint {
+        explain_fixes,
+        fix_log,
+        ..
+    } = execution.traversal_mode()
+    {
+        let applied_fixes = printer.applied_fixes.lock().unwrap();
+        // The other reporters print a machine-readable output
+        if *explain_fixes && matches!(execution.report_mode, ReportMode::Terminal { .. }) {
+            session.app.console.log(markup! {
+                {AppliedFixes(&applied_fixes)}
+            });
+        }
+        if let Some(fix_log) = fix_log {
+            write_fix_log(session, fix_log, &applied_fixes)?;
+        }
+    }
+
     // Make sure patterns are always cleaned up at the end of traversal.
     if let TraversalMode::Search { pattern, .. } = execution.traversal_mode() {
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
@@ -131,7 +219,9 @@ pub(crate) fn traverse(
     let changed = changed.load(Ordering::Relaxed);
     let unchanged = unchanged.load(Ordering::Relaxed);
     let skipped = skipped.load(Ordering::Relaxed);
+    let not_processed = not_processed.load(Ordering::Relaxed);
     let suggested_fixes_skipped = printer.skipped_fixes();
+    let fixable_paths = std::mem::take(&mut *printer.fixable_paths.lock().unwrap());
     let diagnostics_not_printed = printer.not_printed_diagnostics();
     Ok(TraverseResult {
         summary: TraversalSummary {
@@ -146,13 +236,43 @@ pub(crate) fn traverse(
         },
         evaluated_paths,
         diagnostics,
+        fixable_paths: fixable_paths.into_iter().collect(),
+        not_processed,
+        rule_timings: rule_timings.into_sorted(),
     })
 }
 
-/// This function will setup the global Rayon thread pool the first time it's called
+/// Writes the patch of a dry-run to the file `--patch-path`, or to the console when it isn't set
+fn write_patch(
+    session: &mut CliSession,
+    dry_run: &DryRun,
+    patch: &str,
+) -> Result<(), CliDiagnostic> {
+    match &dry_run.patch_path {
+        Some(patch_path) => {
+            let mut file = session
+                .app
+                .fs
+                .open_with_options(patch_path, OpenOptions::default().write(true).create(true))
+                .map_err(CliDiagnostic::io_error)?;
+            file.set_content(patch.as_bytes())
+                .map_err(CliDiagnostic::io_error)?;
+        }
+        None => {
+            if !patch.is_empty() {
+                session.app.console.log(markup! {{patch}});
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Initializes the global thread pool, that uses one thread per CPU core.
 ///
-/// This is currently only used to assign friendly debug names to the threads of the pool
-fn init_thread_pool() {
+/// The global pool is initialized once per process, so it can't be resized for a run:
+/// when `threads` is set, a dedicated pool with this number of threads is returned instead,
+/// and the files are processed inside it.
+fn init_thread_pool(threads: Option<NonZeroUsize>) -> Result<Option<ThreadPool>, CliDiagnostic> {
     static INIT_ONCE: Once = Once::new();
     INIT_ONCE.call_once(|| {
         rayon::ThreadPoolBuilder::new()
@@ -160,6 +280,20 @@ fn init_thread_pool() {
             .build_global()
             .expect("failed to initialize the global thread pool");
     });
+
+    let Some(threads) = threads else {
+        return Ok(None);
+    };
+    rayon::ThreadPoolBuilder::new()
+        .thread_name(|index| format!("biome::worker_{index}"))
+        .num_threads(threads.get())
+        .build()
+        .map(Some)
+        .map_err(|error| {
+            CliDiagnostic::io_error(io::Error::other(format!(
+                "The thread pool of --threads={threads} can't be created: {error}"
+            )))
+        })
 }
 
 /// Initiate the filesystem traversal tasks with the provided input paths and
@@ -178,8 +312,18 @@ fn traverse_inputs(
 
     let paths = ctx.evaluated_paths();
 
+    if ctx.execution.is_ci() || ctx.execution.is_check() || ctx.execution.is_lint() {
+        index_project_files(ctx, &paths);
+    }
+
     fs.traversal(Box::new(|scope: &dyn TraversalScope| {
-        for path in paths.clone() {
+        for (index, path) in paths.iter().enumerate() {
+            // The remaining files aren't scheduled once the maximum number of failures is reached
+            if ctx.should_stop() {
+                ctx.not_processed
+                    .fetch_add(paths.len() - index, Ordering::Relaxed);
+                break;
+            }
             scope.handle(ctx, path.to_path_buf());
         }
     }));
@@ -187,6 +331,54 @@ fn traverse_inputs(
     (start.elapsed(), paths)
 }
 
+/// Opens the CSS, JavaScript, and JSON files that are about to be processed, so that the workspace
+/// knows the custom properties declared, the names exported, the globals declared by the
+/// TypeScript declaration files, and the keys of the JSON documents across the project before linting them
+///
+/// Only the files indexed by the workspace, according to the enabled rules, are opened.
+/// They are opened in parallel, like the files are processed.
+fn index_project_files(ctx: &TraversalOptions, paths: &FxHashSet<EvaluatedPath>) {
+    let indexes = ctx
+        .workspace
+        .project_indexes(ProjectIndexesParams {})
+        .unwrap_or_default();
+    paths.par_iter().for_each(|path| {
+        let path = path.to_path_buf();
+        let is_indexed = match DocumentFileSource::from_path(&path) {
+            DocumentFileSource::Css(_) => indexes.css_custom_properties,
+            DocumentFileSource::Js(file_source) => {
+                indexes.module_exports
+                    || (indexes.ambient_globals && file_source.language().is_definition_file())
+            }
+            DocumentFileSource::Json(_) => indexes.json_document_keys,
+            _ => false,
+        };
+        if !is_indexed {
+            return;
+        }
+        // The errors are reported when the file is processed
+        let Ok(mut file) = ctx
+            .fs
+            .open_with_options(&path, OpenOptions::default().read(true))
+        else {
+            return;
+        };
+        let mut content = String::new();
+        if file.read_to_string(&mut content).is_err() {
+            return;
+        }
+        let _ = FileGuard::open(
+            ctx.workspace,
+            OpenFileParams {
+                document_file_source: None,
+                path: BiomePath::new(&path),
+                version: 0,
+                content,
+            },
+        );
+    });
+}
+
 // struct DiagnosticsReporter<'ctx> {}
 
 struct DiagnosticsPrinter<'ctx> {
@@ -211,6 +403,14 @@ struct DiagnosticsPrinter<'ctx> {
     not_printed_diagnostics: AtomicU32,
     printed_diagnostics: AtomicU32,
     total_skipped_suggested_fixes: AtomicU32,
+    /// The original and the last content of the files changed in dry-run mode, by file name
+    patches: Mutex<BTreeMap<String, (String, String)>>,
+    /// The last content of the files changed with `--staged-hunks`, which is written to the git index after the traversal
+    staged_contents: Mutex<BTreeMap<PathBuf, String>>,
+    /// The files that have fixes to review, in interactive mode
+    fixable_paths: Mutex<BTreeSet<PathBuf>>,
+    /// The fixes applied to the files, by file name, when they are explained or logged
+    applied_fixes: Mutex<BTreeMap<String, Vec<AppliedFix>>>,
 }
 
 impl<'ctx> DiagnosticsPrinter<'ctx> {
@@ -226,6 +426,10 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             not_printed_diagnostics: AtomicU32::new(0),
             printed_diagnostics: AtomicU32::new(0),
             total_skipped_suggested_fixes: AtomicU32::new(0),
+            patches: Mutex::default(),
+            staged_contents: Mutex::default(),
+            fixable_paths: Mutex::default(),
+            applied_fixes: Mutex::default(),
         }
     }
 
@@ -260,6 +464,35 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self.total_skipped_suggested_fixes.load(Ordering::Relaxed)
     }
 
+    /// Returns the unified diff of the files changed in dry-run mode.
+    ///
+    /// The file names are relative to `working_directory`, so that the diff can be applied with `git apply`.
+    fn patch(&self, working_directory: Option<&Path>) -> String {
+        let mut patch = String::new();
+        for (file_name, (old, new)) in self.patches.lock().unwrap().iter() {
+            if old == new {
+                continue;
+            }
+            let path = Path::new(file_name);
+            let path = working_directory
+                .and_then(|directory| path.strip_prefix(directory).ok())
+                .unwrap_or(path)
+                .components()
+                .filter(|component| !matches!(component, Component::CurDir))
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let diff = TextDiff::from_lines(old, new);
+            let _ = write!(
+                patch,
+                "{}",
+                diff.unified_diff()
+                    .header(&format!("a/{path}"), &format!("b/{path}"))
+            );
+        }
+        patch
+    }
+
     /// Checks if the diagnostic we received from the thread should be considered or not. Logic:
     /// - it should not be considered if its severity level is lower than the one provided via CLI;
     /// - it should not be considered if it's a verbose diagnostic and the CLI **didn't** request a `--verbose` option.
@@ -292,7 +525,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         should_print
     }
 
-    fn run(&self, receiver: Receiver<Message>, interner: Receiver<PathBuf>) -> Vec<Error> {
+    /// Collects the diagnostics sent by the traversal.
+    ///
+    /// When a `stream` is provided, the diagnostics are passed to it as soon as they are received,
+    /// and the returned list only contains the diagnostics that couldn't be streamed.
+    fn run(
+        &self,
+        receiver: Receiver<Message>,
+        interner: Receiver<PathBuf>,
+        mut stream: Option<&mut (dyn ReporterVisitor + Send)>,
+    ) -> Vec<Error> {
         let mut paths: FxHashSet<String> = FxHashSet::default();
 
         let mut diagnostics_to_print = vec![];
@@ -310,6 +552,33 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     self.errors.fetch_add(1, Ordering::Relaxed);
                 }
 
+                Message::Patch {
+                    file_name,
+                    old,
+                    new,
+                } => {
+                    // A file can be changed several times, e.g. by the linter and then by the formatter.
+                    // The patch goes from its original content to its last content.
+                    self.patches
+                        .lock()
+                        .unwrap()
+                        .entry(file_name)
+                        .or_insert_with(|| (old, String::new()))
+                        .1 = new;
+                }
+
+                Message::Staged { path, content } => {
+                    self.staged_contents.lock().unwrap().insert(path, content);
+                }
+
+                Message::Fixable { path } => {
+                    self.fixable_paths.lock().unwrap().insert(path);
+                }
+
+                Message::AppliedFixes { file_name, fixes } => {
+                    self.applied_fixes.lock().unwrap().insert(file_name, fixes);
+                }
+
                 Message::Error(mut err) => {
                     let location = err.location();
                     if self.should_skip_diagnostic(err.severity(), err.tags()) {
@@ -517,6 +786,19 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     }
                 }
             }
+
+            if let Some(stream) = stream.as_mut() {
+                if !diagnostics_to_print.is_empty() {
+                    let payload = DiagnosticsPayload {
+                        diagnostics: std::mem::take(&mut diagnostics_to_print),
+                        verbose: self.verbose,
+                        diagnostic_level: self.diagnostic_level,
+                    };
+                    if let Err(error) = stream.report_diagnostics(self.execution, payload) {
+                        tracing::error!("Failed to stream the diagnostics: {error}");
+                    }
+                }
+            }
         }
         diagnostics_to_print
     }
@@ -538,14 +820,36 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     unchanged: &'ctx AtomicUsize,
     /// Shared atomic counter storing the number of skipped files
     skipped: &'ctx AtomicUsize,
+    /// Shared atomic counter storing the number of files that weren't processed
+    /// because the maximum number of failures was reached
+    not_processed: &'ctx AtomicUsize,
+    /// Shared atomic counter storing the number of errors reported by the processed files
+    failures: &'ctx AtomicU32,
+    /// The number of errors after which the remaining files aren't processed, if any
+    max_failures: Option<u32>,
     /// Channel sending messages to the display thread
     pub(crate) messages: Sender<Message>,
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
     pub(crate) remaining_diagnostics: &'ctx AtomicU32,
 
+    /// The time spent running each rule on the processed files
+    pub(crate) rule_timings: &'ctx RuleTimings,
+
     /// List of paths that should be processed
     pub(crate) evaluated_paths: RwLock<FxHashSet<EvaluatedPath>>,
+
+    /// The maximum time spent processing a single file, starting when the file is opened
+    ///
+    /// The deadline is checked by the analyzer between the nodes that it visits, and before formatting the file.
+    /// A single step, such as parsing or formatting the file, or a rule that never returns on a node, isn't interrupted.
+    pub(crate) file_timeout: Option<Duration>,
+
+    /// The shard of files to process, if any
+    shard: Option<Shard>,
+
+    /// The directory that the paths of the shards are relative to
+    working_directory: Option<PathBuf>,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
@@ -560,7 +864,12 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
 
     /// Send a message to the display thread
     pub(crate) fn push_message(&self, msg: impl Into<Message>) {
-        self.messages.send(msg.into()).ok();
+        let msg = msg.into();
+        if self.max_failures.is_some() {
+            self.failures
+                .fetch_add(msg.errors(self.execution), Ordering::Relaxed);
+        }
+        self.messages.send(msg).ok();
     }
 
     pub(crate) fn miss_handler_err(&self, err: WorkspaceError, biome_path: &BiomePath) {
@@ -618,6 +927,17 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
             return false;
         }
 
+        if let Some(shard) = &self.shard {
+            let relative_path = self
+                .working_directory
+                .as_deref()
+                .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+                .unwrap_or(path);
+            if !shard.contains(relative_path) {
+                return false;
+            }
+        }
+
         let file_features = self.workspace.file_features(SupportsFeatureParams {
             path: biome_path.clone(),
             features: self.execution.to_feature(),
@@ -664,12 +984,25 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
     fn store_path(&self, path: &Path) {
         self.evaluated_paths.write().unwrap().insert(path.into());
     }
+
+    /// Returns `true` if the processed files reported the maximum number of failures,
+    /// in which case the crawler stops and the remaining files aren't processed
+    fn should_stop(&self) -> bool {
+        self.max_failures
+            .is_some_and(|max_failures| self.failures.load(Ordering::Relaxed) >= max_failures)
+    }
 }
 
 /// This function wraps the [process_file] function implementing the traversal
 /// in a [catch_unwind] block and emit diagnostics in case of error (either the
 /// traversal function returns Err or panics)
 fn handle_file(ctx: &TraversalOptions, path: &Path) {
+    // The files that were scheduled before the maximum number of failures
+    // was reached aren't processed anymore
+    if ctx.should_stop() {
+        ctx.not_processed.fetch_add(1, Ordering::Relaxed);
+        return;
+    }
     match catch_unwind(move || process_file(ctx, path)) {
         Ok(Ok(FileStatus::Changed)) => {
             ctx.increment_changed(path);
@@ -90,6 +90,8 @@ impl<'app> CliSession<'app> {
                 apply,
                 apply_unsafe,
                 write,
+                dry_run,
+                patch_path,
                 fix,
                 unsafe_,
                 cli_options,
@@ -103,12 +105,15 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                files_from,
             } => commands::check::check(
                 self,
                 CheckCommandPayload {
                     apply_unsafe,
                     apply,
                     write,
+                    dry_run,
+                    patch_path,
                     fix,
                     unsafe_,
                     cli_options,
@@ -122,12 +127,18 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    files_from,
                 },
             ),
             BiomeCommand::Lint {
                 apply,
                 apply_unsafe,
                 write,
+                dry_run,
+                patch_path,
+                interactive,
+                explain_fixes,
+                fix_log,
                 fix,
                 unsafe_,
                 cli_options,
@@ -141,6 +152,8 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                files_from,
+                profile,
                 css_linter,
                 javascript_linter,
                 json_linter,
@@ -151,6 +164,11 @@ impl<'app> CliSession<'app> {
                     apply_unsafe,
                     apply,
                     write,
+                    dry_run,
+                    patch_path,
+                    interactive,
+                    explain_fixes,
+                    fix_log,
                     fix,
                     unsafe_,
                     cli_options,
@@ -164,6 +182,8 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    files_from,
+                    profile,
                     css_linter,
                     javascript_linter,
                     json_linter,
@@ -180,6 +200,7 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 changed,
                 since,
+                files_from,
             } => commands::ci::ci(
                 self,
                 CiCommandPayload {
@@ -192,6 +213,7 @@ impl<'app> CliSession<'app> {
                     cli_options,
                     changed,
                     since,
+                    files_from,
                 },
             ),
             BiomeCommand::Format {
@@ -200,6 +222,9 @@ impl<'app> CliSession<'app> {
                 stdin_file_path,
                 write,
                 fix,
+                dry_run,
+                patch_path,
+                verify,
                 cli_options,
                 paths,
                 vcs_configuration,
@@ -207,9 +232,12 @@ impl<'app> CliSession<'app> {
                 json_formatter,
                 css_formatter,
                 graphql_formatter,
+                toml_formatter,
                 staged,
+                staged_hunks,
                 changed,
                 since,
+                files_from,
             } => commands::format::format(
                 self,
                 FormatCommandPayload {
@@ -218,6 +246,9 @@ impl<'app> CliSession<'app> {
                     stdin_file_path,
                     write,
                     fix,
+                    dry_run,
+                    patch_path,
+                    verify,
                     cli_options,
                     paths,
                     vcs_configuration,
@@ -225,13 +256,29 @@ impl<'app> CliSession<'app> {
                     json_formatter,
                     css_formatter,
                     graphql_formatter,
+                    toml_formatter,
                     staged,
+                    staged_hunks,
                     changed,
                     since,
+                    files_from,
                 },
             ),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
-            BiomeCommand::Init(emit_jsonc) => commands::init::init(self, emit_jsonc),
+            BiomeCommand::CheckConfig { cli_options, paths } => {
+                commands::check_config::check_config(self, cli_options, paths)
+            }
+            BiomeCommand::Assist {
+                cli_options,
+                sub_command,
+            } => commands::assist::assist(self, cli_options, sub_command),
+            BiomeCommand::MergeReports { pretty, reports } => {
+                commands::merge_reports::merge_reports(self, pretty, reports)
+            }
+            BiomeCommand::Init {
+                emit_jsonc,
+                interactive,
+            } => commands::init::init(self, emit_jsonc, interactive),
             BiomeCommand::LspProxy {
                 config_path,
                 log_path,
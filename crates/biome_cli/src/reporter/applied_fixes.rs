@@ -0,0 +1,44 @@
+use crate::execute::fix_log::AppliedFix;
+use biome_console::fmt::{Display, Formatter};
+use biome_console::markup;
+use std::collections::BTreeMap;
+use std::io;
+
+/// Prints the fixes applied to the files, by file name, with the rule that produced them
+/// and the code before and after them
+pub(crate) struct AppliedFixes<'a>(pub(crate) &'a BTreeMap<String, Vec<AppliedFix>>);
+
+impl Display for AppliedFixes<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        fmt.write_markup(markup! {
+            <Emphasis>"Applied fixes"</Emphasis>"\n"
+        })?;
+        if self.0.values().all(Vec::is_empty) {
+            return fmt.write_markup(markup! {
+                "No fix was applied.\n"
+            });
+        }
+
+        for (path, fixes) in self.0 {
+            for fix in fixes {
+                let location = format!("{path}:{}:{}", fix.line, fix.column);
+                let rule = fix.rule.as_str();
+                fmt.write_markup(markup! {
+                    "\n"<Info>{location}</Info>" "<Emphasis>{rule}</Emphasis>"\n"
+                })?;
+                for line in fix.before.lines() {
+                    fmt.write_markup(markup! {
+                        <Error>"- "{line}</Error>"\n"
+                    })?;
+                }
+                for line in fix.after.lines() {
+                    fmt.write_markup(markup! {
+                        <Success>"+ "{line}</Success>"\n"
+                    })?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
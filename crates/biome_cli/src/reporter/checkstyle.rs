@@ -0,0 +1,162 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity};
+use indexmap::IndexMap;
+use std::io;
+
+pub(crate) struct CheckstyleReporter {
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) execution: Execution,
+}
+
+impl Reporter for CheckstyleReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct CheckstyleReporterVisitor<'a>(pub(crate) &'a mut dyn Console);
+
+impl<'a> ReporterVisitor for CheckstyleReporterVisitor<'a> {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        let diagnostics = payload.diagnostics.iter().filter(|diagnostic| {
+            diagnostic.severity() >= payload.diagnostic_level
+                && (!diagnostic.tags().is_verbose() || payload.verbose)
+        });
+
+        // Checkstyle groups the errors by file, in the order the files were first reported
+        let mut files: IndexMap<&str, Vec<CheckstyleError>> = IndexMap::new();
+        for diagnostic in diagnostics {
+            let location = diagnostic.location();
+            let Some(Resource::File(path)) = location.resource else {
+                continue;
+            };
+            let position = match (location.span, location.source_code) {
+                (Some(span), Some(source_code)) => {
+                    let start = SourceFile::new(source_code).location(span.start())?;
+                    Some((start.line_number.get(), start.column_number.get()))
+                }
+                _ => None,
+            };
+            files.entry(path).or_default().push(CheckstyleError {
+                diagnostic,
+                position,
+            });
+        }
+
+        self.0.log(markup! {{CheckstyleReport(&files)}});
+
+        Ok(())
+    }
+}
+
+/// An `<error>` element of a `<file>`
+struct CheckstyleError<'a> {
+    diagnostic: &'a Error,
+    /// The line and the column of the diagnostic, when it has a span
+    position: Option<(usize, usize)>,
+}
+
+/// The Checkstyle XML document, as read by the Checkstyle plugins of the CI services.
+///
+/// Ref: https://checkstyle.sourceforge.io/
+struct CheckstyleReport<'a>(&'a IndexMap<&'a str, Vec<CheckstyleError<'a>>>);
+
+impl Display for CheckstyleReport<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        fmt.write_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")?;
+        fmt.write_str("<checkstyle version=\"4.3\">\n")?;
+        for (path, errors) in self.0 {
+            fmt.write_fmt(format_args!("  <file name=\"{}\">\n", escape_xml(path)))?;
+            for CheckstyleError {
+                diagnostic,
+                position,
+            } in errors
+            {
+                fmt.write_str("    <error")?;
+                if let Some((line, column)) = position {
+                    fmt.write_fmt(format_args!(" line=\"{line}\" column=\"{column}\""))?;
+                }
+                let severity = match diagnostic.severity() {
+                    Severity::Fatal | Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Information | Severity::Hint => "info",
+                };
+                let message = PrintDescription(diagnostic).to_string();
+                fmt.write_fmt(format_args!(
+                    " severity=\"{severity}\" message=\"{}\"",
+                    escape_xml(&message)
+                ))?;
+                if let Some(category) = diagnostic.category() {
+                    fmt.write_fmt(format_args!(" source=\"{}\"", escape_xml(category.name())))?;
+                }
+                fmt.write_str(" />\n")?;
+            }
+            fmt.write_str("  </file>\n")?;
+        }
+        fmt.write_str("</checkstyle>")
+    }
+}
+
+/// Escapes the characters that can't appear as is in the value of an XML attribute
+///
+/// The control characters that XML 1.0 doesn't allow, even escaped, are replaced with `U+FFFD`.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' => escaped.push_str("&#9;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\u{0}'..='\u{1f}' => escaped.push(char::REPLACEMENT_CHARACTER),
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_markup_characters() {
+        assert_eq!(
+            escape_xml(r#"<a href="b">'c' & d</a>"#),
+            "&lt;a href=&quot;b&quot;&gt;&apos;c&apos; &amp; d&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn escapes_whitespace_characters() {
+        assert_eq!(escape_xml("a\tb\r\nc"), "a&#9;b&#13;&#10;c");
+    }
+
+    #[test]
+    fn replaces_control_characters() {
+        assert_eq!(
+            escape_xml("a\u{0}b\u{1b}[0mc\u{7f}"),
+            "a\u{fffd}b\u{fffd}[0mc\u{7f}"
+        );
+    }
+}
@@ -1,22 +1,139 @@
+use crate::cli_options::Shard;
 use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
 use biome_console::fmt::Formatter;
+use biome_diagnostics::{category, Error, Visit};
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
 use serde::Serialize;
+use std::io;
+
+/// The version of the schema of the JSON reports.
+///
+/// It must be bumped every time a breaking change is made to the shape of the
+/// reports emitted by the `json`, `json-pretty` and `json-lines` reporters.
+pub(crate) const JSON_SCHEMA_VERSION: u32 = 1;
 
 #[derive(Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct JsonReporterVisitor {
+    schema_version: u32,
     summary: TraversalSummary,
-    diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
+    diagnostics: Vec<JsonDiagnostic>,
     command: String,
+    /// The shard processed by the run, when `--shard` is used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shard: Option<Shard>,
 }
 
 impl JsonReporterVisitor {
     pub(crate) fn new(summary: TraversalSummary) -> Self {
         Self {
+            schema_version: JSON_SCHEMA_VERSION,
             summary,
             diagnostics: vec![],
             command: String::new(),
+            shard: None,
+        }
+    }
+
+    pub(crate) fn with_shard(mut self, shard: Option<Shard>) -> Self {
+        self.shard = shard;
+        self
+    }
+}
+
+/// A diagnostic of the JSON reports
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonDiagnostic {
+    #[serde(flatten)]
+    diagnostic: biome_diagnostics::serde::Diagnostic,
+    /// The ranges of the file that the formatter would change, one for each hunk of the diff
+    /// of a `format` diagnostic, so that the tools can format only these ranges
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    format_ranges: Vec<FormatRange>,
+}
+
+impl JsonDiagnostic {
+    fn new(diagnostic: Error) -> Self {
+        let format_ranges = format_ranges(&diagnostic);
+        Self {
+            diagnostic: biome_diagnostics::serde::Diagnostic::new(diagnostic),
+            format_ranges,
+        }
+    }
+}
+
+/// The start and end offsets of a range of the file, in bytes
+#[derive(Debug, Serialize)]
+struct FormatRange {
+    start: u32,
+    end: u32,
+}
+
+/// Returns the ranges of the source code of a `format` diagnostic that are changed by its diff
+fn format_ranges(diagnostic: &Error) -> Vec<FormatRange> {
+    if diagnostic.category() != Some(category!("format")) {
+        return Vec::new();
+    }
+    let Some(source_code) = diagnostic.location().source_code else {
+        return Vec::new();
+    };
+    let mut visitor = DiffVisitor::default();
+    if diagnostic.advices(&mut visitor).is_err() {
+        return Vec::new();
+    }
+    match visitor.diff {
+        Some(diff) if is_diff_of(&diff, source_code.text) => diff
+            .changed_ranges(source_code.text)
+            .into_iter()
+            .map(|range| FormatRange {
+                start: range.start().into(),
+                end: range.end().into(),
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns `true` if `old` is the "old" revision of the text of `diff`.
+///
+/// It isn't the case of the diff of the formatted code when the formatter isn't idempotent.
+fn is_diff_of(diff: &TextEdit, old: &str) -> bool {
+    let mut rest = old;
+    for op in diff {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range } | DiffOp::Delete { range }) => {
+                let Some(remaining) = rest.strip_prefix(diff.get_text(*range)) else {
+                    return false;
+                };
+                rest = remaining;
+            }
+            CompressedOp::DiffOp(DiffOp::Insert { .. }) => {}
+            CompressedOp::EqualLines { line_count } => {
+                for _ in 0..=line_count.get() {
+                    let Some(index) = rest.find('\n') else {
+                        return false;
+                    };
+                    rest = &rest[index + 1..];
+                }
+            }
+        }
+    }
+    rest.is_empty()
+}
+
+/// Captures the first diff of the advices of a diagnostic
+#[derive(Default)]
+struct DiffVisitor {
+    diff: Option<TextEdit>,
+}
+
+impl Visit for DiffVisitor {
+    fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
+        if self.diff.is_none() {
+            self.diff = Some(diff.clone());
         }
+        Ok(())
     }
 }
 
@@ -63,12 +180,10 @@ impl ReporterVisitor for JsonReporterVisitor {
             if diagnostic.severity() >= payload.diagnostic_level {
                 if diagnostic.tags().is_verbose() {
                     if payload.verbose {
-                        self.diagnostics
-                            .push(biome_diagnostics::serde::Diagnostic::new(diagnostic))
+                        self.diagnostics.push(JsonDiagnostic::new(diagnostic))
                     }
                 } else {
-                    self.diagnostics
-                        .push(biome_diagnostics::serde::Diagnostic::new(diagnostic))
+                    self.diagnostics.push(JsonDiagnostic::new(diagnostic))
                 }
             }
         }
@@ -0,0 +1,94 @@
+use crate::reporter::json::JSON_SCHEMA_VERSION;
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::{markup, Console, ConsoleExt};
+use serde::Serialize;
+
+/// A line of the `json-lines` report that contains a single diagnostic
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticLine {
+    r#type: &'static str,
+    schema_version: u32,
+    diagnostic: biome_diagnostics::serde::Diagnostic,
+}
+
+/// The last line of the `json-lines` report, emitted once the traversal is over
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SummaryLine {
+    r#type: &'static str,
+    schema_version: u32,
+    command: String,
+    summary: TraversalSummary,
+}
+
+/// Prints every diagnostic as a JSON object on its own line.
+///
+/// Unlike [crate::reporter::json::JsonReporterVisitor], this visitor doesn't buffer anything:
+/// it's used by the traversal to stream the diagnostics of a file as soon as the file is processed.
+pub(crate) struct JsonLinesReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+}
+
+impl<'a> JsonLinesReporterVisitor<'a> {
+    pub(crate) fn new(console: &'a mut dyn Console) -> Self {
+        Self { console }
+    }
+
+    fn write_line(&mut self, line: &impl Serialize) -> std::io::Result<()> {
+        let content = serde_json::to_string(line)?;
+        self.console.log(markup! {{content}});
+        Ok(())
+    }
+}
+
+pub struct JsonLinesReporter {
+    pub execution: Execution,
+    pub diagnostics: DiagnosticsPayload,
+    pub summary: TraversalSummary,
+}
+
+impl Reporter for JsonLinesReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> std::io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics)?;
+        visitor.report_summary(&self.execution, self.summary)?;
+
+        Ok(())
+    }
+}
+
+impl<'a> ReporterVisitor for JsonLinesReporterVisitor<'a> {
+    fn report_summary(
+        &mut self,
+        execution: &Execution,
+        summary: TraversalSummary,
+    ) -> std::io::Result<()> {
+        self.write_line(&SummaryLine {
+            r#type: "summary",
+            schema_version: JSON_SCHEMA_VERSION,
+            command: format!("{}", execution.traversal_mode()),
+            summary,
+        })
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> std::io::Result<()> {
+        for diagnostic in payload.diagnostics {
+            if diagnostic.severity() < payload.diagnostic_level {
+                continue;
+            }
+            if diagnostic.tags().is_verbose() && !payload.verbose {
+                continue;
+            }
+            self.write_line(&DiagnosticLine {
+                r#type: "diagnostic",
+                schema_version: JSON_SCHEMA_VERSION,
+                diagnostic: biome_diagnostics::serde::Diagnostic::new(diagnostic),
+            })?;
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,11 @@
+pub(crate) mod applied_fixes;
+pub(crate) mod checkstyle;
 pub(crate) mod github;
 pub(crate) mod gitlab;
 pub(crate) mod json;
+pub(crate) mod json_lines;
 pub(crate) mod junit;
+pub(crate) mod rule_profile;
 pub(crate) mod summary;
 pub(crate) mod terminal;
 
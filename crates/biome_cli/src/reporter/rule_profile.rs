@@ -0,0 +1,97 @@
+use biome_console::fmt::{Display, Formatter};
+use biome_console::markup;
+use biome_service::workspace::RuleTiming;
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Maximum number of rules printed in the report
+const MAX_RULES: usize = 20;
+
+/// Width of the bar of the most expensive rule
+const BAR_WIDTH: f64 = 40.0;
+
+/// The time spent running each rule, summed over the files processed by a run
+#[derive(Debug, Default)]
+pub(crate) struct RuleTimings(Mutex<BTreeMap<(String, String), RuleTiming>>);
+
+impl RuleTimings {
+    /// Adds the timings of a file
+    pub(crate) fn extend(&self, timings: Vec<RuleTiming>) {
+        let mut merged = self.0.lock().unwrap();
+        for timing in timings {
+            merged
+                .entry((timing.group.clone(), timing.rule.clone()))
+                .and_modify(|merged| {
+                    merged.total += timing.total;
+                    merged.count += timing.count;
+                })
+                .or_insert(timing);
+        }
+    }
+
+    /// Returns the timings, from the most expensive rule to the cheapest
+    pub(crate) fn into_sorted(self) -> Vec<RuleTiming> {
+        let mut timings: Vec<_> = self.0.into_inner().unwrap().into_values().collect();
+        timings.sort_by(|a, b| {
+            b.total
+                .cmp(&a.total)
+                .then_with(|| (&a.group, &a.rule).cmp(&(&b.group, &b.rule)))
+        });
+        timings
+    }
+}
+
+/// Prints the most expensive rules, with a bar proportional to the time spent running them
+pub(crate) struct RuleProfile(pub(crate) Vec<RuleTiming>);
+
+impl Display for RuleProfile {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let Some(most_expensive) = self.0.first() else {
+            return fmt.write_markup(markup! {
+                <Emphasis>"Rule profile"</Emphasis>"\n"
+                "No rule was run.\n"
+            });
+        };
+        let total: Duration = self.0.iter().map(|measurement| measurement.total).sum();
+        fmt.write_markup(markup! {
+            <Emphasis>"Rule profile"</Emphasis>" (total: "{format!("{:.2?}", total)}")\n"
+        })?;
+
+        for measurement in self.0.iter().take(MAX_RULES) {
+            let time = format!("{:>10.2?}", measurement.total);
+            let share = format!("{:>5.1}%", ratio(measurement.total, total) * 100.0);
+            let bar_length = (ratio(measurement.total, most_expensive.total) * BAR_WIDTH).ceil();
+            let bar = format!(
+                "{:<width$}",
+                "█".repeat(bar_length as usize),
+                width = BAR_WIDTH as usize
+            );
+            let group = &measurement.group;
+            let rule = &measurement.rule;
+            let count = measurement.count;
+            fmt.write_markup(markup! {
+                {time}" "{share}" "<Info>{bar}</Info>" "{group}"/"{rule}<Dim>" ("{count}" nodes)"</Dim>"\n"
+            })?;
+        }
+
+        let remaining = self.0.len().saturating_sub(MAX_RULES);
+        if remaining > 0 {
+            fmt.write_markup(markup! {
+                <Dim>"And "{remaining}" other rules.\n"</Dim>
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `duration / total`, or `0` when `total` is zero
+fn ratio(duration: Duration, total: Duration) -> f64 {
+    if total.is_zero() {
+        0.0
+    } else {
+        duration.as_secs_f64() / total.as_secs_f64()
+    }
+}
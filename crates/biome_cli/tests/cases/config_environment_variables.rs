@@ -0,0 +1,135 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "  statement(  )  ";
+const FORMATTED: &str = "statement();\n";
+
+#[test]
+fn interpolates_environment_variables_in_paths() {
+    std::env::set_var("BIOME_TEST_GENERATED_DIRECTORY", "generated");
+
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "files": {
+    "ignore": ["${BIOME_TEST_GENERATED_DIRECTORY}/**"]
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let generated = Path::new("generated/file.js");
+    fs.insert(generated.into(), UNFORMATTED.as_bytes());
+
+    let source = Path::new("src/file.js");
+    fs.insert(source.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("generated"), ("src")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, generated, UNFORMATTED);
+    assert_file_contents(&fs, source, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interpolates_environment_variables_in_paths",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn interpolates_environment_variables_in_globals() {
+    std::env::set_var("BIOME_TEST_GLOBAL", "analytics");
+
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "javascript": {
+    "globals": ["${BIOME_TEST_GLOBAL}"]
+  },
+  "linter": {
+    "rules": {
+      "correctness": {
+        "noUndeclaredVariables": "error"
+      }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "analytics.track();\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interpolates_environment_variables_in_globals",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_unset_environment_variables() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "files": {
+    "ignore": ["${BIOME_TEST_UNSET_VARIABLE}/**"]
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_unset_environment_variables",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -57,12 +57,36 @@ if (foo) {
 ---
 <div></div>"#;
 
+const ASTRO_MARKUP_WITH_FENCES: &str = r#"<article>
+---
+Some text between two rules
+---
+</article>"#;
+
 const ASTRO_FILE_IMPORTS_AFTER: &str = r#"---
 import { Code } from "astro:components";
 import { getLocale } from "astro:i18n";
 ---
 <div></div>"#;
 
+const ASTRO_MARKUP_UNFORMATTED: &str = r#"---
+const items = ["a", "b"];
+---
+<ul>
+<li>First</li>
+{items.map((item)=><li>{item}</li>)}
+</ul>
+<Card   title = {"Hello"}   {...props}/>"#;
+
+const ASTRO_MARKUP_FORMATTED: &str = r#"---
+const items = ["a", "b"];
+---
+<ul>
+	<li>First</li>
+	{items.map((item) => <li>{item}</li>)}
+</ul>
+<Card title={"Hello"} {...props} />"#;
+
 const ASTRO_CARRIAGE_RETURN_LINE_FEED_FILE_UNFORMATTED: &str =
     "---\r\n  const a    = \"b\";\r\n---\r\n<div></div>";
 
@@ -158,6 +182,41 @@ fn format_astro_files_write() {
     ));
 }
 
+#[test]
+fn format_astro_markup_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let astro_file_path = Path::new("file.astro");
+    fs.insert(astro_file_path.into(), ASTRO_MARKUP_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--experimental-markup-formatting=true",
+                astro_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, astro_file_path, ASTRO_MARKUP_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_astro_markup_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn format_empty_astro_files_write() {
     let mut fs = MemoryFileSystem::default();
@@ -387,6 +446,33 @@ fn does_not_throw_parse_error_for_return() {
     ));
 }
 
+#[test]
+fn does_not_parse_fences_after_the_start() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let astro_file_path = Path::new("file.astro");
+    fs.insert(astro_file_path.into(), ASTRO_MARKUP_WITH_FENCES.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), astro_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, astro_file_path, ASTRO_MARKUP_WITH_FENCES);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_parse_fences_after_the_start",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn format_stdin_successfully() {
     let mut fs = MemoryFileSystem::default();
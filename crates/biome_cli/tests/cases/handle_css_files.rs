@@ -181,3 +181,55 @@ fn should_lint_files_by_when_enabled() {
         result,
     ));
 }
+
+#[test]
+fn should_resolve_custom_properties_declared_in_other_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "css": { "linter": { "enabled": true } },
+  "linter": { "rules": { "nursery": { "noUnknownCustomProperty": "error" } } }
+}
+"#
+        .as_bytes(),
+    );
+
+    let tokens_file = Path::new("tokens.css");
+    fs.insert(
+        tokens_file.into(),
+        r#":root { --brand-color: red; }"#.as_bytes(),
+    );
+
+    let css_file = Path::new("input.css");
+    fs.insert(
+        css_file.into(),
+        r#"a { color: var(--brand-color); background: var(--brand-colour); }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                css_file.as_os_str().to_str().unwrap(),
+                tokens_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_resolve_custom_properties_declared_in_other_files",
+        fs,
+        console,
+        result,
+    ));
+}
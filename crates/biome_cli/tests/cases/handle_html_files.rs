@@ -0,0 +1,37 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const HTML_FILE_IMG_WITHOUT_ALT: &str = r#"<div>
+	<img src="image.png" />
+</div>
+"#;
+
+#[test]
+fn lint_html_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let html_file = Path::new("file.html");
+    fs.insert(html_file.into(), HTML_FILE_IMG_WITHOUT_ALT.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", html_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_html_files",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -32,6 +32,53 @@ const hello: string = "world";
 </script>
 <div></div>"#;
 
+const SVELTE_MARKUP_UNFORMATTED: &str = r#"<script>
+let count   = 0;
+</script>
+
+<div   class="counter"
+id="main">
+<button on:click="{() => count+=1}"   disabled = {count>10}>
+Count: {count*2}
+</button>
+
+
+{#if count>5}
+<p class:warning={warning}>Too many</p>
+{:else}
+<input bind:value={count} />
+{/if}
+</div>"#;
+
+const SVELTE_MARKUP_FORMATTED: &str = r#"<script>
+let count = 0;
+</script>
+
+<div
+	class="counter"
+	id="main"
+>
+	<button on:click={() => (count += 1)} disabled={count > 10}>
+		Count: {count * 2}
+	</button>
+
+	{#if count > 5}
+		<p class:warning>Too many</p>
+	{:else}
+		<input bind:value={count} />
+	{/if}
+</div>"#;
+
+const SVELTE_MARKUP_TEMPLATE_LITERAL_UNFORMATTED: &str = r#"<div>
+<p>{`first line
+  second line`}</p>
+</div>"#;
+
+const SVELTE_MARKUP_TEMPLATE_LITERAL_FORMATTED: &str = r#"<div>
+	<p>{`first line
+  second line`}</p>
+</div>"#;
+
 const SVELTE_CARRIAGE_RETURN_LINE_FEED_FILE_UNFORMATTED: &str =
     "<script>\r\n  const a    = \"b\";\r\n</script>\r\n<div></div>";
 
@@ -228,6 +275,127 @@ fn format_svelte_ts_context_module_files_write() {
     ));
 }
 
+#[test]
+fn format_svelte_markup_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_MARKUP_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--experimental-markup-formatting=true",
+                svelte_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, svelte_file_path, SVELTE_MARKUP_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_svelte_markup_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_format_svelte_markup_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_MARKUP_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                svelte_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        svelte_file_path,
+        &SVELTE_MARKUP_UNFORMATTED.replace("let count   = 0;", "let count = 0;"),
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_format_svelte_markup_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_svelte_markup_keeps_template_literals() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_MARKUP_TEMPLATE_LITERAL_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--experimental-markup-formatting=true",
+                svelte_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        svelte_file_path,
+        SVELTE_MARKUP_TEMPLATE_LITERAL_FORMATTED,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_svelte_markup_keeps_template_literals",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn format_svelte_carriage_return_line_feed_files() {
     let mut fs = MemoryFileSystem::default();
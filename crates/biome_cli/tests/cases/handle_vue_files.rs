@@ -14,6 +14,36 @@ statement ( ) ;
 </script>
 <template></template>"#;
 
+const VUE_TEMPLATE_ATTRIBUTES_UNFORMATTED: &str = r#"<template>
+  <div :class="{ active:isActive,'text-danger' : hasError }" @click="count ++">
+    <!-- <p :title="not   formatted"></p> -->
+    <p v-if="  seen&&visible  " v-for="item in items" :title='"quoted"+name'>{{ message }}</p>
+    <Component [value]="value  ??  fallback" (change)="onChange( $event )" :invalid="a +" />
+    <input :value="someFunctionName(firstArgument, secondArgument, thirdArgument)" />
+  </div>
+</template>
+<script>
+const a    =    1;
+</script>
+"#;
+
+const VUE_TEMPLATE_ATTRIBUTES_FORMATTED: &str = r#"<template>
+  <div :class="{ active: isActive, 'text-danger': hasError }" @click="count++">
+    <!-- <p :title="not   formatted"></p> -->
+    <p v-if="seen && visible" v-for="item in items" :title='"quoted" + name'>{{ message }}</p>
+    <Component [value]="value ?? fallback" (change)="onChange($event)" :invalid="a +" />
+    <input :value="someFunctionName(
+    	firstArgument,
+    	secondArgument,
+    	thirdArgument,
+    )" />
+  </div>
+</template>
+<script>
+const a = 1;
+</script>
+"#;
+
 const VUE_IMPLICIT_JS_FILE_FORMATTED: &str = r#"<script>
 import { something } from "file.vue";
 statement();
@@ -913,3 +943,70 @@ fn vue_compiler_macros_as_globals() {
         result,
     ));
 }
+
+#[test]
+fn format_vue_template_attributes_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_TEMPLATE_ATTRIBUTES_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                vue_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, vue_file_path, VUE_TEMPLATE_ATTRIBUTES_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_vue_template_attributes_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_vue_template_attributes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_TEMPLATE_ATTRIBUTES_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", vue_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, vue_file_path, VUE_TEMPLATE_ATTRIBUTES_UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_vue_template_attributes",
+        fs,
+        console,
+        result,
+    ));
+}
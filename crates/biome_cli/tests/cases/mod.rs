@@ -3,7 +3,9 @@
 
 mod assists;
 mod biome_json_support;
+mod config_environment_variables;
 mod config_extends;
+mod config_override;
 mod config_path;
 mod cts_files;
 mod diagnostics;
@@ -11,6 +13,8 @@ mod editorconfig;
 mod graphql;
 mod handle_astro_files;
 mod handle_css_files;
+mod handle_html_files;
+mod handle_mdx_files;
 mod handle_svelte_files;
 mod handle_vue_files;
 mod included_files;
@@ -18,8 +22,10 @@ mod overrides_formatter;
 mod overrides_linter;
 mod overrides_organize_imports;
 mod protected_files;
+mod reporter_checkstyle;
 mod reporter_github;
 mod reporter_gitlab;
+mod reporter_json_lines;
 mod reporter_junit;
 mod reporter_summary;
 mod unknown_files;
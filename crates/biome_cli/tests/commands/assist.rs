@@ -0,0 +1,95 @@
+use bpaf::Args;
+use std::path::Path;
+
+use crate::snap_test::{assert_file_contents, SnapshotPayload};
+use crate::{assert_cli_snapshot, run_cli};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystem, MemoryFileSystem};
+use biome_service::DynRef;
+
+#[test]
+fn assist_generates_barrel_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("src/components/button.tsx").into(),
+        "export function Button() {}\nexport interface ButtonProps {}\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/components/size.ts").into(),
+        "export type Size = \"small\" | \"large\";\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/components/button.test.tsx").into(),
+        "export const cases = [];\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/components/index.ts").into(),
+        "export default \"components\";\nexport { Removed } from \"./removed\";\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "assist",
+                "run",
+                "--write",
+                "--exclude=*.test.tsx",
+                "generateBarrelFile",
+                "src/components",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        Path::new("src/components/index.ts"),
+        "export default \"components\";\n\nexport { Button, type ButtonProps } from \"./button\";\nexport type { Size } from \"./size\";\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "assist_generates_barrel_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn assist_prints_barrel_file_without_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("src/utils/math.js").into(),
+        "export const sum = (a, b) => a + b;\nexport default sum;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["assist", "run", "generateBarrelFile", "src/utils"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert!(
+        !fs.path_exists(Path::new("src/utils/index.js")),
+        "the barrel file was written"
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "assist_prints_barrel_file_without_write",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1604,6 +1604,42 @@ import * as something from "../something";
     ));
 }
 
+#[test]
+fn applies_organize_imports_with_merged_type_imports() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config = r#"{ "organizeImports": { "enabled": true, "mergeTypeImports": true } }"#;
+    let file_path = Path::new("biome.json");
+    fs.insert(file_path.into(), config.as_bytes());
+
+    let file_path = Path::new("check.ts");
+    let content = r#"import type { Lorem } from "foo";
+import { bar } from "foo";
+"#;
+    let expected = r#"import { type Lorem, bar } from "foo";
+"#;
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["check", "--apply", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, expected);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_organize_imports_with_merged_type_imports",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn shows_organize_imports_diff_on_check() {
     let mut fs = MemoryFileSystem::default();
@@ -3342,3 +3378,111 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
         result,
     ));
 }
+
+#[test]
+fn dry_run_prints_a_patch() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), FIX_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--write"),
+                ("--dry-run"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FIX_BEFORE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "dry_run_prints_a_patch",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn dry_run_writes_the_patch_to_patch_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), FIX_BEFORE.as_bytes());
+    let other_file_path = Path::new("other.js");
+    fs.insert(other_file_path.into(), APPLY_SUGGESTED_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--write"),
+                ("--unsafe"),
+                ("--dry-run"),
+                ("--patch-path=fixes.patch"),
+                file_path.as_os_str().to_str().unwrap(),
+                other_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FIX_BEFORE);
+    assert_file_contents(&fs, other_file_path, APPLY_SUGGESTED_BEFORE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "dry_run_writes_the_patch_to_patch_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn dry_run_requires_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), FIX_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--dry-run"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "dry_run_requires_write",
+        fs,
+        console,
+        result,
+    ));
+}
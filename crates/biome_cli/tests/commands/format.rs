@@ -1753,6 +1753,87 @@ fn file_too_large_cli_limit() {
     ));
 }
 
+#[test]
+fn file_too_complex() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        PathBuf::from("biome.json"),
+        r#"{
+  "formatter": {
+    "maxComplexity": 5
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), "statement();".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "file_too_complex",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn file_too_complex_in_override() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        PathBuf::from("biome.json"),
+        r#"{
+  "overrides": [
+    {
+      "include": ["generated/**"],
+      "formatter": {
+        "maxComplexity": 5
+      }
+    }
+  ]
+}"#
+        .as_bytes(),
+    );
+
+    let generated_path = Path::new("generated/format.js");
+    fs.insert(generated_path.into(), "statement( );".as_bytes());
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), "statement( );".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                generated_path.as_os_str().to_str().unwrap(),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, generated_path, "statement( );");
+    assert_file_contents(&fs, file_path, "statement();\n");
+}
+
 #[test]
 fn files_max_size_parse_error() {
     let mut fs = MemoryFileSystem::default();
@@ -3818,3 +3899,336 @@ fn applies_custom_bracket_spacing_for_graphql() {
         result,
     ));
 }
+
+#[test]
+fn verify_does_not_report_stable_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--verify"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "verify_does_not_report_stable_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn verify_reports_code_that_is_not_idempotent() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "Math // 0\n    .pow( // 1\n    a)\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--verify"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "verify_reports_code_that_is_not_idempotent",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn verify_reports_code_whose_syntax_changed() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.css");
+    fs.insert(
+        file_path.into(),
+        r#"a { background: url("//aa.com/img.svg" foo bar baz func(test)); }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--verify"),
+                ("--css-formatter-enabled=true"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "verify_reports_code_whose_syntax_changed",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn verify_is_incompatible_with_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--verify"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "verify_is_incompatible_with_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_dry_run_prints_a_patch() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                ("--dry-run"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_dry_run_prints_a_patch",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn files_from_stdin_is_incompatible_with_stdin_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push("file.js\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--files-from=-"),
+                ("--stdin-file-path"),
+                ("file.js"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "files_from_stdin_is_incompatible_with_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_staged_hunks_write() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_staged_files(Box::new(|| vec![String::from("format.js")]));
+
+    // The file has changes that aren't staged
+    let file_path = Path::new("format.js");
+    fs.insert(
+        file_path.into(),
+        "statement( ) ;\nunstaged( ) ;\n".as_bytes(),
+    );
+    fs.insert_staged(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--staged-hunks", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_eq!(fs.staged_content(file_path).as_deref(), Some(FORMATTED));
+    assert_file_contents(&fs, file_path, "statement( ) ;\nunstaged( ) ;\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_staged_hunks_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_staged_hunks_write_fully_staged_file() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_staged_files(Box::new(|| vec![String::from("format.js")]));
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+    fs.insert_staged(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--staged-hunks", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_eq!(fs.staged_content(file_path).as_deref(), Some(FORMATTED));
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_staged_hunks_write_fully_staged_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_staged_hunks_and_staged_are_incompatible() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--staged-hunks", "--staged"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_staged_hunks_and_staged_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_not_format_files_that_exceed_the_timeout() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                "--file-timeout=0",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! { {message.content} }))
+        .collect();
+    assert!(
+        messages.iter().any(|message| message.contains(
+            "The formatting of this file was skipped because its processing exceeded the time given by --file-timeout."
+        )),
+        "the timeout should be reported: {messages:?}"
+    );
+}
@@ -1,7 +1,7 @@
 use crate::run_cli;
 use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
 use biome_console::BufferConsole;
-use biome_fs::MemoryFileSystem;
+use biome_fs::{FileSystem, MemoryFileSystem};
 use biome_service::DynRef;
 use bpaf::Args;
 use std::path::Path;
@@ -142,3 +142,89 @@ fn does_not_create_config_file_if_jsonc_exists() {
         result,
     ));
 }
+
+#[test]
+fn interactive_proposes_config_of_react_project() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "dependencies": { "next": "^14.0.0", "react": "^18.0.0" } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new(".gitignore").into(),
+        "node_modules\n.next\n".as_bytes(),
+    );
+    fs.insert(Path::new(".eslintrc.json").into(), "{}".as_bytes());
+    console.in_buffer.push("y\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), "--interactive"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interactive_proposes_config_of_react_project",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn interactive_proposes_config_of_library() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "name": "lib", "exports": "./index.js" }"#.as_bytes(),
+    );
+    fs.insert(Path::new(".prettierrc").into(), "{}".as_bytes());
+    console.in_buffer.push("y\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), "--interactive"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interactive_proposes_config_of_library",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn interactive_does_not_create_config_file_when_declined() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("package.json").into(), "{}".as_bytes());
+    console.in_buffer.push("n\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), "--interactive"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert!(!fs.path_exists(Path::new("biome.json")));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interactive_does_not_create_config_file_when_declined",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -4358,3 +4358,1132 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
         result,
     ));
 }
+
+#[test]
+fn should_report_files_that_exceed_the_timeout() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--file-timeout=0",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_report_files_that_exceed_the_timeout",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_threads() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--threads=1",
+                "--file-timeout=60000",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn should_print_the_rule_profile() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--profile",
+                "--only=suspicious/noDebugger",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let profile = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! { {message.content} }))
+        .find(|content| content.starts_with("Rule profile"))
+        .expect("the rule profile should be printed");
+    assert!(
+        profile.contains("suspicious/noDebugger"),
+        "the profile should contain the rule: {profile}"
+    );
+}
+
+#[test]
+fn should_stop_processing_files_after_max_failures() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let files = ["a.js", "b.js", "c.js"].map(Path::new);
+    for file_path in files {
+        fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--max-failures=2",
+                // The files are processed one after the other, so the failures are counted
+                // before the next file is processed
+                "--threads=1",
+                files[0].as_os_str().to_str().unwrap(),
+                files[1].as_os_str().to_str().unwrap(),
+                files[2].as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! { {message.content} }))
+        .collect();
+    assert!(
+        messages.iter().any(|message| message
+            == "The maximum number of failures was reached, 1 file(s) weren't processed."),
+        "the files that weren't processed should be reported: {messages:?}"
+    );
+}
+
+#[test]
+fn should_stop_processing_files_at_the_first_failure_with_fail_fast() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let files = ["a.js", "b.js", "c.js"].map(Path::new);
+    for file_path in files {
+        fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--fail-fast",
+                // The files are processed one after the other, so the failures are counted
+                // before the next file is processed
+                "--threads=1",
+                files[0].as_os_str().to_str().unwrap(),
+                files[1].as_os_str().to_str().unwrap(),
+                files[2].as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! { {message.content} }))
+        .collect();
+    assert!(
+        messages.iter().any(|message| message
+            == "The maximum number of failures was reached, 2 file(s) weren't processed."),
+        "the files that weren't processed should be reported: {messages:?}"
+    );
+}
+
+#[test]
+fn fail_fast_is_incompatible_with_max_failures() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("a.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--fail-fast",
+                "--max-failures=2",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "fail_fast_is_incompatible_with_max_failures",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_process_all_the_files_below_max_failures() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let files = ["a.js", "b.js"].map(Path::new);
+    for file_path in files {
+        fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--max-failures=3",
+                files[0].as_os_str().to_str().unwrap(),
+                files[1].as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! { {message.content} }))
+        .collect();
+    assert!(
+        !messages
+            .iter()
+            .any(|message| message.starts_with("The maximum number of failures was reached")),
+        "all the files should be processed: {messages:?}"
+    );
+}
+
+#[test]
+fn should_report_names_re_exported_from_several_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": { "rules": { "nursery": { "noDuplicateImportsAcrossFiles": "error" } } }
+}
+"#
+        .as_bytes(),
+    );
+
+    let button_file = Path::new("src/button.ts");
+    fs.insert(
+        button_file.into(),
+        r#"export const Button = () => {};"#.as_bytes(),
+    );
+
+    let icon_button_file = Path::new("src/iconButton.ts");
+    fs.insert(
+        icon_button_file.into(),
+        r#"export const Button = () => {};
+export const IconButton = () => {};"#
+            .as_bytes(),
+    );
+
+    let index_file = Path::new("src/index.ts");
+    fs.insert(
+        index_file.into(),
+        r#"export * from "./button";
+export * from "./iconButton";"#
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "src"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_report_names_re_exported_from_several_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_not_report_globals_declared_by_declaration_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": { "rules": { "correctness": { "noUndeclaredVariables": "error" } } }
+}
+"#
+        .as_bytes(),
+    );
+
+    let global_file = Path::new("src/env.d.ts");
+    fs.insert(
+        global_file.into(),
+        r#"declare const APP_VERSION: string;
+declare function track(event: string): void;
+interface Env {
+    mode: string;
+}"#
+        .as_bytes(),
+    );
+
+    let module_file = Path::new("src/window.d.ts");
+    fs.insert(
+        module_file.into(),
+        r#"export {};
+declare global {
+    var analytics: { send(event: string): void };
+}
+declare const moduleScoped: string;"#
+            .as_bytes(),
+    );
+
+    let index_file = Path::new("src/index.ts");
+    fs.insert(
+        index_file.into(),
+        r#"const env: Env = { mode: APP_VERSION };
+track(env.mode);
+analytics.send(moduleScoped);"#
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "src"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_not_report_globals_declared_by_declaration_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_preset() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "preset": "pedantic",
+        "rules": {
+            "suspicious": {
+                "noConsoleLog": "off"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"export function run(ready, start) {
+    debugger;
+    console.log("run");
+    if (ready) start();
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_with_preset",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_formatting_only_preset() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "preset": "formatting-only" } }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_with_formatting_only_preset",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_with_preset_in_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "overrides": [
+        {
+            "include": ["b.js"],
+            "linter": { "preset": "formatting-only" }
+        }
+    ]
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("a.js");
+    fs.insert(file_path.into(), "debugger;".as_bytes());
+    let other_file_path = Path::new("b.js");
+    fs.insert(other_file_path.into(), "debugger;".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                file_path.as_os_str().to_str().unwrap(),
+                other_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_with_preset_in_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_files_from_stdin() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("src/a.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("src/b.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("src/c.js").into(), "debugger;\n".as_bytes());
+
+    console
+        .in_buffer
+        .push("src/a.js\r\n\nsrc/c.js\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--files-from=-"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_files_from_stdin",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_files_from_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("src/a.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("src/b.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("files.txt").into(), "src/b.js\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--files-from", "files.txt"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_files_from_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_shards() {
+    for shard in ["1/2", "2/2"] {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        for name in ["a", "b", "c", "d", "e", "f"] {
+            fs.insert(
+                PathBuf::from(format!("src/{name}.js")),
+                "debugger;\n".as_bytes(),
+            );
+        }
+
+        let shard_flag = format!("--shard={shard}");
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from([("lint"), shard_flag.as_str(), "src"].as_slice()),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            &format!("lint_shard_{}", shard.replace('/', "_of_")),
+            fs,
+            console,
+            result,
+        ));
+    }
+}
+
+#[test]
+fn should_error_if_shard_is_out_of_range() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("src/a.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--shard=3/2", "src"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_shard_is_out_of_range",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_files_from_and_changed_flag_are_active_at_the_same_time() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("files.txt").into(), "src/a.js\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--files-from", "files.txt", "--changed"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_files_from_and_changed_flag_are_active_at_the_same_time",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_files_from_empty_list() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("src/a.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("files.txt").into(), "\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--files-from", "files.txt"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_files_from_empty_list",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_files_from_and_since_are_active_at_the_same_time() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("files.txt").into(), "src/a.js\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--files-from", "files.txt", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_files_from_and_since_are_active_at_the_same_time",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_files_from_and_paths_are_passed_at_the_same_time() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("src/a.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("files.txt").into(), "src/a.js\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--files-from", "files.txt", "src/a.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_files_from_and_paths_are_passed_at_the_same_time",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn interactive_writes_the_accepted_fixes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/a.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nlet a = 1;\ndebugger;\nexport { a };\n".as_bytes(),
+    );
+    console.in_buffer.push("y\n".to_string());
+    console.in_buffer.push("n\n".to_string());
+    console.in_buffer.push("y\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--write"),
+                ("--unsafe"),
+                ("--interactive"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "\nlet a = 1;\nexport { a };\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interactive_writes_the_accepted_fixes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn interactive_applies_all_the_fixes_of_a_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/a.js");
+    fs.insert(file_path.into(), "debugger;\ndebugger;\n".as_bytes());
+    let other_file_path = Path::new("src/b.js");
+    fs.insert(other_file_path.into(), "debugger;\n".as_bytes());
+    console.in_buffer.push("a\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--write"),
+                ("--unsafe"),
+                ("--interactive"),
+                ("src"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "\n");
+    assert_file_contents(&fs, other_file_path, "\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interactive_applies_all_the_fixes_of_a_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn interactive_requires_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/a.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--interactive"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interactive_requires_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn explain_fixes_prints_the_applied_fixes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/a.js");
+    fs.insert(file_path.into(), "let a = 1;\nexport { a };\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--write"),
+                ("--explain-fixes"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "const a = 1;\nexport { a };\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "explain_fixes_prints_the_applied_fixes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn fix_log_writes_the_applied_fixes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/a.js");
+    fs.insert(file_path.into(), "let a = 1;\nexport { a };\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--write"),
+                ("--fix-log=fixes.json"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "const a = 1;\nexport { a };\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "fix_log_writes_the_applied_fixes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn explain_fixes_requires_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/a.js");
+    fs.insert(file_path.into(), "let a = 1;\nexport { a };\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--explain-fixes"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "explain_fixes_requires_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_only_run_rule_on_included_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noCompareNegZero": { "level": "error", "includes": ["special/**"] }
+      }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("test.js");
+    fs.insert(file_path.into(), FIX_BEFORE.as_bytes());
+
+    let special_path = Path::new("special/test.js");
+    fs.insert(special_path.into(), FIX_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+                special_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FIX_BEFORE);
+    assert_file_contents(&fs, special_path, FIX_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_only_run_rule_on_included_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn uses_the_rule_includes_of_the_matching_override() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noCompareNegZero": { "level": "error", "includes": ["special/**"] }
+      }
+    }
+  },
+  "overrides": [
+    {
+      "include": ["other/**"],
+      "linter": {
+        "rules": {
+          "suspicious": {
+            "noCompareNegZero": { "level": "error", "includes": ["other/fixed/**"] }
+          }
+        }
+      }
+    }
+  ]
+}"#
+        .as_bytes(),
+    );
+
+    let special_path = Path::new("special/test.js");
+    fs.insert(special_path.into(), FIX_BEFORE.as_bytes());
+
+    let other_path = Path::new("other/test.js");
+    fs.insert(other_path.into(), FIX_BEFORE.as_bytes());
+
+    let other_fixed_path = Path::new("other/fixed/test.js");
+    fs.insert(other_fixed_path.into(), FIX_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--write"),
+                special_path.as_os_str().to_str().unwrap(),
+                other_path.as_os_str().to_str().unwrap(),
+                other_fixed_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, special_path, FIX_AFTER);
+    // The includes of the override replace the ones of the base configuration
+    assert_file_contents(&fs, other_path, FIX_BEFORE);
+    assert_file_contents(&fs, other_fixed_path, FIX_AFTER);
+}
+
+#[test]
+fn does_not_run_rule_on_excluded_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noCompareNegZero": { "level": "error", "excludes": ["special/**"] }
+      }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("test.js");
+    fs.insert(file_path.into(), FIX_BEFORE.as_bytes());
+
+    let special_path = Path::new("special/test.js");
+    fs.insert(special_path.into(), FIX_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+                special_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FIX_AFTER);
+    assert_file_contents(&fs, special_path, FIX_BEFORE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_run_rule_on_excluded_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_invalid_rule_files_pattern() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noCompareNegZero": { "level": "error", "includes": ["special/["] }
+      }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("test.js");
+    fs.insert(file_path.into(), FIX_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_invalid_rule_files_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,212 @@
+use bpaf::Args;
+use std::path::Path;
+
+use crate::snap_test::SnapshotPayload;
+use crate::{assert_cli_snapshot, run_cli};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+
+const FIRST_SHARD_REPORT: &str = r#"{"schemaVersion":1,"summary":{"changed":0,"unchanged":2,"errors":1,"warnings":0,"skipped":0,"suggestedFixesSkipped":0,"diagnosticsNotPrinted":0},"diagnostics":[{"category":"lint/suspicious/noDebugger","severity":"error"}],"command":"lint","shard":{"index":1,"count":2}}"#;
+
+const SECOND_SHARD_REPORT: &str = r#"{"schemaVersion":1,"summary":{"changed":0,"unchanged":3,"errors":2,"warnings":1,"skipped":0,"suggestedFixesSkipped":0,"diagnosticsNotPrinted":0},"diagnostics":[{"category":"lint/suspicious/noDebugger","severity":"error"},{"category":"lint/style/useConst","severity":"error"}],"command":"lint","shard":{"index":2,"count":2}}"#;
+
+const FORMAT_REPORT: &str = r#"{"schemaVersion":1,"summary":{"changed":0,"unchanged":1,"errors":0,"warnings":0,"skipped":0,"suggestedFixesSkipped":0,"diagnosticsNotPrinted":0},"diagnostics":[],"command":"format","shard":{"index":2,"count":2}}"#;
+
+#[test]
+fn merge_reports_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("merge-reports"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "merge_reports_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn merge_reports_of_shards() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("shard-1.json").into(),
+        FIRST_SHARD_REPORT.as_bytes(),
+    );
+    fs.insert(
+        Path::new("shard-2.json").into(),
+        SECOND_SHARD_REPORT.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("merge-reports"), "shard-1.json", "shard-2.json"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "merge_reports_of_shards",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn merge_reports_pretty() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("shard-1.json").into(),
+        FIRST_SHARD_REPORT.as_bytes(),
+    );
+    fs.insert(
+        Path::new("shard-2.json").into(),
+        SECOND_SHARD_REPORT.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("merge-reports"),
+                "--pretty",
+                "shard-1.json",
+                "shard-2.json",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "merge_reports_pretty",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn merge_reports_warns_about_missing_shards() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("shard-2.json").into(),
+        SECOND_SHARD_REPORT.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("merge-reports"), "shard-2.json"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "merge_reports_warns_about_missing_shards",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn merge_reports_errors_on_duplicated_shard() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("shard-1.json").into(),
+        FIRST_SHARD_REPORT.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("merge-reports"), "shard-1.json", "shard-1.json"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "merge_reports_errors_on_duplicated_shard",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn merge_reports_errors_on_different_commands() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("shard-1.json").into(),
+        FIRST_SHARD_REPORT.as_bytes(),
+    );
+    fs.insert(Path::new("format.json").into(), FORMAT_REPORT.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("merge-reports"), "shard-1.json", "format.json"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "merge_reports_errors_on_different_commands",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn merge_reports_errors_on_invalid_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("report.json").into(), "not a report".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("merge-reports"), "report.json"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "merge_reports_errors_on_invalid_json",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -330,6 +330,16 @@ fn migrate_eslintrcjson_rule_options() {
                     "selector": "variable",
                     "types": ["boolean"],
                     "format": ["UPPER_CASE"]
+                },
+                {
+                    "selector": "variable",
+                    "filter": "Component$",
+                    "format": ["PascalCase"]
+                },
+                {
+                    "selector": "typeProperty",
+                    "filter": { "regex": "^[a-z]+-[a-z]+$", "match": false },
+                    "format": ["camelCase"]
                 }
             ],
             "unicorn/filename-case": ["error", {
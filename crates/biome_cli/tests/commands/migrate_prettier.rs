@@ -441,3 +441,118 @@ fn prettier_migrate_overrides() {
         result,
     ));
 }
+
+#[test]
+fn prettier_migrate_overrides_exclude_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{}"#;
+    let prettier = r#"{
+        "overrides": [{
+            "files": "src/**/*.jsx",
+            "excludeFiles": ["src/legacy/**"],
+            "options": { "singleAttributePerLine": true, "bracketSpacing": false }
+        }]
+    }"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_overrides_exclude_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prettier_migrate_plugins() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{}"#;
+    let prettier = r#"{
+        "plugins": [
+            "@ianvs/prettier-plugin-sort-imports",
+            "./node_modules/prettier-plugin-tailwindcss/dist/index.mjs",
+            "prettier-plugin-packagejson"
+        ],
+        "tailwindFunctions": ["clsx"],
+        "tailwindAttributes": ["tw"]
+    }"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_plugins",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prettier_migrate_unsupported_options() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{}"#;
+    let prettier = r#"{
+        "quoteProps": "consistent",
+        "experimentalTernaries": true,
+        "requirePragma": true,
+        "overrides": [{
+            "files": "*.svg",
+            "options": { "parser": "html" }
+        }]
+    }"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_unsupported_options",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,178 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn stylelint_migrate() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{
+        "defaultSeverity": "warning",
+        "ignoreFiles": ["vendor/**"],
+        "rules": {
+            "block-no-empty": null,
+            "color-hex-case": "lower",
+            "color-hex-length": "short",
+            "declaration-block-no-duplicate-properties": true,
+            "declaration-block-no-shorthand-property-overrides": [true, { "severity": "error" }],
+            "unit-no-unknown": [true, { "ignoreUnits": ["x"] }]
+        },
+        "overrides": [{
+            "files": ["legacy/**/*.css"],
+            "rules": {
+                "unit-no-unknown": null
+            }
+        }],
+        "unknownField": "ignored"
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint", "--include-nursery"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "stylelint_migrate",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn stylelint_migrate_exclude_nursery() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{
+        "rules": {
+            "unit-no-unknown": true,
+            "color-hex-length": "short"
+        }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new(".stylelintrc").into(), stylelintrc.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "stylelint_migrate_exclude_nursery",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn stylelint_migrate_extends_and_plugins() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let stylelintrc = r#"{
+        "extends": "stylelint-config-standard",
+        "plugins": ["stylelint-scss"],
+        "rules": {
+            "scss/at-rule-no-unknown": true,
+            "property-no-unknown": true
+        }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(
+        Path::new(".stylelintrc.json").into(),
+        stylelintrc.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint", "--include-nursery"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "stylelint_migrate_extends_and_plugins",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn stylelint_migrate_write_packagejson() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+    let packagejson = r#"{
+        "name": "foo",
+        "stylelint": {
+            "rules": {
+                "declaration-block-no-shorthand-property-overrides": true
+            }
+        }
+    }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+    fs.insert(Path::new("package.json").into(), packagejson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint", "--include-nursery", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "stylelint_migrate_write_packagejson",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn stylelint_migrate_no_file() {
+    let biomejson = r#"{ "linter": { "enabled": true } }"#;
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("biome.json").into(), biomejson.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["migrate", "stylelint"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "stylelint_migrate_no_file",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1,12 +1,16 @@
+mod assist;
 mod check;
+mod check_config;
 mod ci;
 mod explain;
 mod format;
 mod init;
 mod lint;
 mod lsp_proxy;
+mod merge_reports;
 mod migrate;
 mod migrate_eslint;
 mod migrate_prettier;
+mod migrate_stylelint;
 mod rage;
 mod version;
@@ -99,29 +99,143 @@ impl Actions {
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 #[doc = r" A list of rules that belong to this group"]
 pub struct Source {
+    #[doc = "Converts a function declaration or a function expression to an arrow function."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_arrow_function_syntax: Option<RuleAssistConfiguration>,
+    #[doc = "Converts an arrow function to a function expression or a function declaration."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_function_syntax: Option<RuleAssistConfiguration>,
+    #[doc = "Generates a JSDoc comment for a function."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_jsdoc_comment: Option<RuleAssistConfiguration>,
+    #[doc = "Sorts the attributes of a JSX element in natural order."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_attributes: Option<RuleAssistConfiguration>,
     #[doc = "Sorts the keys of a JSON object in natural order"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_sorted_keys: Option<RuleAssistConfiguration>,
+    #[doc = "Sorts the members of union types, interfaces, and object types in natural order."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_type_members: Option<RuleAssistConfiguration>,
+    #[doc = "Converts a template literal to a string concatenation."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_string_concatenation: Option<RuleAssistConfiguration>,
+    #[doc = "Converts a numeric enum to a union of string literal types."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_string_literal_union: Option<RuleAssistConfiguration>,
+    #[doc = "Converts a string concatenation to a template literal."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_template_literal: Option<RuleAssistConfiguration>,
 }
 impl Source {
     const GROUP_NAME: &'static str = "source";
-    pub(crate) const GROUP_RULES: &'static [&'static str] = &["useSortedKeys"];
+    pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "useArrowFunctionSyntax",
+        "useFunctionSyntax",
+        "useJsdocComment",
+        "useSortedAttributes",
+        "useSortedKeys",
+        "useSortedTypeMembers",
+        "useStringConcatenation",
+        "useStringLiteralUnion",
+        "useTemplateLiteral",
+    ];
     pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.use_sorted_keys.as_ref() {
+        if let Some(rule) = self.use_arrow_function_syntax.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
+        if let Some(rule) = self.use_function_syntax.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.use_jsdoc_comment.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_attributes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_type_members.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.use_string_concatenation.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.use_string_literal_union.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.use_template_literal.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
         index_set
     }
     pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.use_sorted_keys.as_ref() {
+        if let Some(rule) = self.use_arrow_function_syntax.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
+        if let Some(rule) = self.use_function_syntax.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.use_jsdoc_comment.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_attributes.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_type_members.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.use_string_concatenation.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.use_string_literal_union.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.use_template_literal.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -133,7 +247,15 @@ impl Source {
         rule_name: &str,
     ) -> Option<RuleAssistConfiguration> {
         match rule_name {
+            "useArrowFunctionSyntax" => self.use_arrow_function_syntax.as_ref().copied(),
+            "useFunctionSyntax" => self.use_function_syntax.as_ref().copied(),
+            "useJsdocComment" => self.use_jsdoc_comment.as_ref().copied(),
+            "useSortedAttributes" => self.use_sorted_attributes.as_ref().copied(),
             "useSortedKeys" => self.use_sorted_keys.as_ref().copied(),
+            "useSortedTypeMembers" => self.use_sorted_type_members.as_ref().copied(),
+            "useStringConcatenation" => self.use_string_concatenation.as_ref().copied(),
+            "useStringLiteralUnion" => self.use_string_literal_union.as_ref().copied(),
+            "useTemplateLiteral" => self.use_template_literal.as_ref().copied(),
             _ => None,
         }
     }
@@ -1,11 +1,14 @@
+mod presets;
 #[rustfmt::skip]
 mod rules;
 
 use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use bpaf::Bpaf;
+pub use presets::RulePreset;
 pub use rules::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
 #[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
@@ -16,6 +19,11 @@ pub struct LinterConfiguration {
     #[partial(bpaf(hide))]
     pub enabled: bool,
 
+    /// A preset of rules, such as `"recommended-strict"`, `"pedantic"` or `"formatting-only"`.
+    /// The rules of the preset are layered under `rules`.
+    #[partial(bpaf(hide))]
+    pub preset: Option<RulePreset>,
+
     /// List of rules
     #[partial(bpaf(pure(Default::default()), optional, hide))]
     pub rules: Rules,
@@ -29,6 +37,24 @@ pub struct LinterConfiguration {
     /// match these patterns.
     #[partial(bpaf(hide))]
     pub include: StringSet,
+
+    /// The custom ARIA roles and properties that the accessibility rules should accept
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub aria: AriaConfiguration,
+}
+
+/// The custom ARIA roles and properties, such as the ones used by web components
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct AriaConfiguration {
+    /// The custom roles, mapped to the names of the ARIA roles that they behave like.
+    ///
+    /// For example, `{ "x-toggle": "switch" }` allows `role="x-toggle"`, that has the same requirements as `role="switch"`.
+    pub roles: BTreeMap<String, String>,
+
+    /// The custom `aria-*` properties, that are accepted with any value
+    pub properties: StringSet,
 }
 
 impl LinterConfiguration {
@@ -41,9 +67,11 @@ impl Default for LinterConfiguration {
     fn default() -> Self {
         Self {
             enabled: true,
+            preset: None,
             rules: Default::default(),
             ignore: Default::default(),
             include: Default::default(),
+            aria: Default::default(),
         }
     }
 }
@@ -0,0 +1,176 @@
+use super::{RuleGroup, Rules};
+use crate::analyzer::RulePlainConfiguration;
+use biome_deserialize::Merge;
+use biome_deserialize_macros::{Deserializable, Merge};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// A named bundle of rule severities.
+///
+/// The rules set by a preset are layered under the rules of the configuration,
+/// so every rule of a preset can still be configured individually.
+#[derive(Clone, Copy, Debug, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum RulePreset {
+    /// The recommended rules, and all the rules of the groups `correctness`, `security` and `suspicious`.
+    /// Their diagnostics are errors.
+    #[serde(rename = "recommended-strict")]
+    #[deserializable(rename = "recommended-strict")]
+    RecommendedStrict,
+    /// The rules of `recommended-strict`, and all the rules of the other groups.
+    /// The diagnostics of the other groups are warnings. The rules of the nursery group aren't enabled.
+    #[serde(rename = "pedantic")]
+    #[deserializable(rename = "pedantic")]
+    Pedantic,
+    /// No lint rule, for the projects that only use the formatter and the import sorting.
+    #[serde(rename = "formatting-only")]
+    #[deserializable(rename = "formatting-only")]
+    FormattingOnly,
+}
+
+impl FromStr for RulePreset {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "recommended-strict" => Ok(Self::RecommendedStrict),
+            "pedantic" => Ok(Self::Pedantic),
+            "formatting-only" => Ok(Self::FormattingOnly),
+            _ => Err("Value not supported for RulePreset"),
+        }
+    }
+}
+
+/// A layer of a preset: it sets the level of the rules of some groups.
+#[derive(Debug)]
+struct PresetLayer {
+    groups: &'static [RuleGroup],
+    level: RulePlainConfiguration,
+    /// Only the recommended rules of the groups are set
+    only_recommended: bool,
+}
+
+/// The stable groups
+const STABLE_GROUPS: &[RuleGroup] = &[
+    RuleGroup::A11y,
+    RuleGroup::Complexity,
+    RuleGroup::Correctness,
+    RuleGroup::Performance,
+    RuleGroup::Security,
+    RuleGroup::Style,
+    RuleGroup::Suspicious,
+];
+
+const RECOMMENDED_STRICT_LAYERS: &[PresetLayer] = &[
+    PresetLayer {
+        groups: STABLE_GROUPS,
+        level: RulePlainConfiguration::Error,
+        only_recommended: true,
+    },
+    PresetLayer {
+        groups: &[
+            RuleGroup::Correctness,
+            RuleGroup::Security,
+            RuleGroup::Suspicious,
+        ],
+        level: RulePlainConfiguration::Error,
+        only_recommended: false,
+    },
+];
+
+const PEDANTIC_LAYERS: &[PresetLayer] = &[PresetLayer {
+    groups: &[
+        RuleGroup::A11y,
+        RuleGroup::Complexity,
+        RuleGroup::Performance,
+        RuleGroup::Style,
+    ],
+    level: RulePlainConfiguration::Warn,
+    only_recommended: false,
+}];
+
+impl RulePreset {
+    /// The preset that this preset extends, if any.
+    ///
+    /// The layers of the extended preset are applied before the layers of this preset.
+    const fn extends(self) -> Option<Self> {
+        match self {
+            Self::RecommendedStrict | Self::FormattingOnly => None,
+            Self::Pedantic => Some(Self::RecommendedStrict),
+        }
+    }
+
+    const fn layers(self) -> &'static [PresetLayer] {
+        match self {
+            Self::RecommendedStrict => RECOMMENDED_STRICT_LAYERS,
+            Self::Pedantic => PEDANTIC_LAYERS,
+            Self::FormattingOnly => &[],
+        }
+    }
+
+    /// Returns the rules set by this preset.
+    pub fn to_rules(self) -> Rules {
+        let mut rules = match self.extends() {
+            Some(preset) => preset.to_rules(),
+            None => Rules::default(),
+        };
+        if self == Self::FormattingOnly {
+            rules.recommended = Some(false);
+        }
+        for layer in self.layers() {
+            for group in layer.groups {
+                rules.set_group_rules_level(*group, layer.level, layer.only_recommended);
+            }
+        }
+        rules
+    }
+
+    /// Layers the rules of this preset under `rules`.
+    pub fn apply(self, rules: Rules) -> Rules {
+        let mut result = self.to_rules();
+        result.merge_with(rules);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_diagnostics::category;
+    use biome_diagnostics::Severity;
+
+    #[test]
+    fn pedantic_extends_recommended_strict() {
+        let rules = RulePreset::Pedantic.to_rules();
+        assert_eq!(
+            rules.get_severity_from_code(category!("lint/suspicious/noConsoleLog")),
+            Some(Severity::Error)
+        );
+        assert_eq!(
+            rules.get_severity_from_code(category!("lint/style/useNamingConvention")),
+            Some(Severity::Warning)
+        );
+        assert!(rules.nursery.is_none());
+    }
+
+    #[test]
+    fn configured_rules_override_the_preset() {
+        let mut configured = Rules::default();
+        configured.set_group_rules_level(RuleGroup::Suspicious, RulePlainConfiguration::Off, false);
+        let rules = RulePreset::RecommendedStrict.apply(configured);
+        assert!(!rules
+            .as_enabled_rules()
+            .iter()
+            .any(|filter| filter.group() == "suspicious"));
+        assert!(rules
+            .as_enabled_rules()
+            .iter()
+            .any(|filter| filter.group() == "correctness"));
+    }
+
+    #[test]
+    fn formatting_only_disables_the_rules() {
+        let rules = RulePreset::FormattingOnly.apply(Rules::default());
+        assert!(rules.as_enabled_rules().is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
-use crate::analyzer::{RuleConfiguration, RuleFixConfiguration, RulePlainConfiguration};
+use crate::analyzer::{RuleConfiguration, RuleFiles, RuleFixConfiguration, RulePlainConfiguration};
 use biome_analyze::{options::RuleOptions, RuleFilter};
 use biome_console::markup;
 use biome_deserialize::{DeserializableValidator, DeserializationDiagnostic};
@@ -269,6 +269,172 @@ impl Rules {
         };
         Some(severity)
     }
+    #[doc = r" Returns the glob patterns that restrict the files the rule runs on,"]
+    #[doc = r" if the configuration of the rule sets them."]
+    pub fn get_rule_files(&self, group: RuleGroup, rule_name: &str) -> Option<RuleFiles> {
+        match group {
+            RuleGroup::A11y => self
+                .a11y
+                .as_ref()
+                .and_then(|group| group.get_rule_files(rule_name)),
+            RuleGroup::Complexity => self
+                .complexity
+                .as_ref()
+                .and_then(|group| group.get_rule_files(rule_name)),
+            RuleGroup::Correctness => self
+                .correctness
+                .as_ref()
+                .and_then(|group| group.get_rule_files(rule_name)),
+            RuleGroup::Nursery => self
+                .nursery
+                .as_ref()
+                .and_then(|group| group.get_rule_files(rule_name)),
+            RuleGroup::Performance => self
+                .performance
+                .as_ref()
+                .and_then(|group| group.get_rule_files(rule_name)),
+            RuleGroup::Security => self
+                .security
+                .as_ref()
+                .and_then(|group| group.get_rule_files(rule_name)),
+            RuleGroup::Style => self
+                .style
+                .as_ref()
+                .and_then(|group| group.get_rule_files(rule_name)),
+            RuleGroup::Suspicious => self
+                .suspicious
+                .as_ref()
+                .and_then(|group| group.get_rule_files(rule_name)),
+        }
+    }
+    #[doc = r" Returns the glob patterns of all the rules whose configuration sets them, with the rules."]
+    pub fn all_rule_files(&self) -> Vec<(RuleFilter<'static>, RuleFiles)> {
+        let mut files = Vec::new();
+        if let Some(group) = self.a11y.as_ref() {
+            files.extend(
+                A11y::GROUP_RULES
+                    .iter()
+                    .filter_map(|rule_name| {
+                        let rule_files = group.get_rule_files(rule_name)?;
+                        Some((RuleFilter::Rule(A11y::GROUP_NAME, rule_name), rule_files))
+                    }),
+            );
+        }
+        if let Some(group) = self.complexity.as_ref() {
+            files.extend(
+                Complexity::GROUP_RULES
+                    .iter()
+                    .filter_map(|rule_name| {
+                        let rule_files = group.get_rule_files(rule_name)?;
+                        Some((RuleFilter::Rule(Complexity::GROUP_NAME, rule_name), rule_files))
+                    }),
+            );
+        }
+        if let Some(group) = self.correctness.as_ref() {
+            files.extend(
+                Correctness::GROUP_RULES
+                    .iter()
+                    .filter_map(|rule_name| {
+                        let rule_files = group.get_rule_files(rule_name)?;
+                        Some((RuleFilter::Rule(Correctness::GROUP_NAME, rule_name), rule_files))
+                    }),
+            );
+        }
+        if let Some(group) = self.nursery.as_ref() {
+            files.extend(
+                Nursery::GROUP_RULES
+                    .iter()
+                    .filter_map(|rule_name| {
+                        let rule_files = group.get_rule_files(rule_name)?;
+                        Some((RuleFilter::Rule(Nursery::GROUP_NAME, rule_name), rule_files))
+                    }),
+            );
+        }
+        if let Some(group) = self.performance.as_ref() {
+            files.extend(
+                Performance::GROUP_RULES
+                    .iter()
+                    .filter_map(|rule_name| {
+                        let rule_files = group.get_rule_files(rule_name)?;
+                        Some((RuleFilter::Rule(Performance::GROUP_NAME, rule_name), rule_files))
+                    }),
+            );
+        }
+        if let Some(group) = self.security.as_ref() {
+            files.extend(
+                Security::GROUP_RULES
+                    .iter()
+                    .filter_map(|rule_name| {
+                        let rule_files = group.get_rule_files(rule_name)?;
+                        Some((RuleFilter::Rule(Security::GROUP_NAME, rule_name), rule_files))
+                    }),
+            );
+        }
+        if let Some(group) = self.style.as_ref() {
+            files.extend(
+                Style::GROUP_RULES
+                    .iter()
+                    .filter_map(|rule_name| {
+                        let rule_files = group.get_rule_files(rule_name)?;
+                        Some((RuleFilter::Rule(Style::GROUP_NAME, rule_name), rule_files))
+                    }),
+            );
+        }
+        if let Some(group) = self.suspicious.as_ref() {
+            files.extend(
+                Suspicious::GROUP_RULES
+                    .iter()
+                    .filter_map(|rule_name| {
+                        let rule_files = group.get_rule_files(rule_name)?;
+                        Some((RuleFilter::Rule(Suspicious::GROUP_NAME, rule_name), rule_files))
+                    }),
+            );
+        }
+        files
+    }
+    #[doc = r" Sets the level of the rules of `group`, or only of its recommended rules"]
+    #[doc = r" if `only_recommended` is `true`. The options of the rules are preserved."]
+    pub fn set_group_rules_level(
+        &mut self,
+        group: RuleGroup,
+        level: RulePlainConfiguration,
+        only_recommended: bool,
+    ) {
+        match group {
+            RuleGroup::A11y => self
+                .a11y
+                .get_or_insert_with(Default::default)
+                .set_rules_level(level, only_recommended),
+            RuleGroup::Complexity => self
+                .complexity
+                .get_or_insert_with(Default::default)
+                .set_rules_level(level, only_recommended),
+            RuleGroup::Correctness => self
+                .correctness
+                .get_or_insert_with(Default::default)
+                .set_rules_level(level, only_recommended),
+            RuleGroup::Nursery => self
+                .nursery
+                .get_or_insert_with(Default::default)
+                .set_rules_level(level, only_recommended),
+            RuleGroup::Performance => self
+                .performance
+                .get_or_insert_with(Default::default)
+                .set_rules_level(level, only_recommended),
+            RuleGroup::Security => self
+                .security
+                .get_or_insert_with(Default::default)
+                .set_rules_level(level, only_recommended),
+            RuleGroup::Style => self
+                .style
+                .get_or_insert_with(Default::default)
+                .set_rules_level(level, only_recommended),
+            RuleGroup::Suspicious => self
+                .suspicious
+                .get_or_insert_with(Default::default)
+                .set_rules_level(level, only_recommended),
+        }
+    }
     #[doc = r" Ensure that `recommended` is set to `true` or implied."]
     pub fn set_recommended(&mut self) {
         if self.all != Some(true) && self.recommended == Some(false) {
@@ -1007,6 +1173,105 @@ impl A11y {
         }
         index_set
     }
+    #[doc = r" Sets the level of the rules of this group, or only of its recommended rules"]
+    #[doc = r" if `only_recommended` is `true`. The options of the rules are preserved."]
+    pub(crate) fn set_rules_level(
+        &mut self,
+        level: RulePlainConfiguration,
+        only_recommended: bool,
+    ) {
+        self.no_access_key
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_aria_hidden_on_focusable
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_aria_unsupported_elements
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_autofocus
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_blank_target
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_distracting_elements
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_header_scope
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_interactive_element_to_noninteractive_role
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_noninteractive_element_to_interactive_role
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_noninteractive_tabindex
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_positive_tabindex
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_redundant_alt
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_redundant_roles
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_svg_without_title
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_alt_text
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_anchor_content
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_aria_activedescendant_with_tabindex
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_aria_props_for_role
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_button_type
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_heading_content
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_html_lang
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_iframe_title
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_key_with_click_events
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_key_with_mouse_events
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_media_caption
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_valid_anchor
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_valid_aria_props
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_valid_aria_role
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_valid_aria_values
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_valid_lang
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        if !only_recommended {}
+    }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
     pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
         Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
@@ -1164,6 +1429,71 @@ impl A11y {
             _ => None,
         }
     }
+    pub(crate) fn get_rule_files(&self, rule_name: &str) -> Option<RuleFiles> {
+        match rule_name {
+            "noAccessKey" => self.no_access_key.as_ref().map(|conf| conf.files()),
+            "noAriaHiddenOnFocusable" => self
+                .no_aria_hidden_on_focusable
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noAriaUnsupportedElements" => self
+                .no_aria_unsupported_elements
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noAutofocus" => self.no_autofocus.as_ref().map(|conf| conf.files()),
+            "noBlankTarget" => self.no_blank_target.as_ref().map(|conf| conf.files()),
+            "noDistractingElements" => self
+                .no_distracting_elements
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noHeaderScope" => self.no_header_scope.as_ref().map(|conf| conf.files()),
+            "noInteractiveElementToNoninteractiveRole" => self
+                .no_interactive_element_to_noninteractive_role
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noNoninteractiveElementToInteractiveRole" => self
+                .no_noninteractive_element_to_interactive_role
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noNoninteractiveTabindex" => self
+                .no_noninteractive_tabindex
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noPositiveTabindex" => self.no_positive_tabindex.as_ref().map(|conf| conf.files()),
+            "noRedundantAlt" => self.no_redundant_alt.as_ref().map(|conf| conf.files()),
+            "noRedundantRoles" => self.no_redundant_roles.as_ref().map(|conf| conf.files()),
+            "noSvgWithoutTitle" => self.no_svg_without_title.as_ref().map(|conf| conf.files()),
+            "useAltText" => self.use_alt_text.as_ref().map(|conf| conf.files()),
+            "useAnchorContent" => self.use_anchor_content.as_ref().map(|conf| conf.files()),
+            "useAriaActivedescendantWithTabindex" => self
+                .use_aria_activedescendant_with_tabindex
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useAriaPropsForRole" => self
+                .use_aria_props_for_role
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useButtonType" => self.use_button_type.as_ref().map(|conf| conf.files()),
+            "useHeadingContent" => self.use_heading_content.as_ref().map(|conf| conf.files()),
+            "useHtmlLang" => self.use_html_lang.as_ref().map(|conf| conf.files()),
+            "useIframeTitle" => self.use_iframe_title.as_ref().map(|conf| conf.files()),
+            "useKeyWithClickEvents" => self
+                .use_key_with_click_events
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useKeyWithMouseEvents" => self
+                .use_key_with_mouse_events
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useMediaCaption" => self.use_media_caption.as_ref().map(|conf| conf.files()),
+            "useValidAnchor" => self.use_valid_anchor.as_ref().map(|conf| conf.files()),
+            "useValidAriaProps" => self.use_valid_aria_props.as_ref().map(|conf| conf.files()),
+            "useValidAriaRole" => self.use_valid_aria_role.as_ref().map(|conf| conf.files()),
+            "useValidAriaValues" => self.use_valid_aria_values.as_ref().map(|conf| conf.files()),
+            "useValidLang" => self.use_valid_lang.as_ref().map(|conf| conf.files()),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
 #[deserializable(with_validator)]
@@ -1738,6 +2068,103 @@ impl Complexity {
         }
         index_set
     }
+    #[doc = r" Sets the level of the rules of this group, or only of its recommended rules"]
+    #[doc = r" if `only_recommended` is `true`. The options of the rules are preserved."]
+    pub(crate) fn set_rules_level(
+        &mut self,
+        level: RulePlainConfiguration,
+        only_recommended: bool,
+    ) {
+        self.no_banned_types
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_empty_type_parameters
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_excessive_nested_test_suites
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_extra_boolean_cast
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_for_each
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_multiple_spaces_in_regular_expression_literals
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_static_only_class
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_this_in_static
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_catch
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_constructor
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_empty_export
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_fragments
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_label
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_lone_block_statements
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_rename
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_switch_case
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_ternary
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_this_alias
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_type_constraint
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_with
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_arrow_function
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_flat_map
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_literal_keys
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_optional_chain
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_regex_literals
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_simple_number_keys
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        if !only_recommended {
+            self.no_excessive_cognitive_complexity
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_void
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_simplified_logic_expression
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+        }
+    }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
     pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
         Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
@@ -1891,6 +2318,73 @@ impl Complexity {
             _ => None,
         }
     }
+    pub(crate) fn get_rule_files(&self, rule_name: &str) -> Option<RuleFiles> {
+        match rule_name {
+            "noBannedTypes" => self.no_banned_types.as_ref().map(|conf| conf.files()),
+            "noEmptyTypeParameters" => self
+                .no_empty_type_parameters
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noExcessiveCognitiveComplexity" => self
+                .no_excessive_cognitive_complexity
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noExcessiveNestedTestSuites" => self
+                .no_excessive_nested_test_suites
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noExtraBooleanCast" => self.no_extra_boolean_cast.as_ref().map(|conf| conf.files()),
+            "noForEach" => self.no_for_each.as_ref().map(|conf| conf.files()),
+            "noMultipleSpacesInRegularExpressionLiterals" => self
+                .no_multiple_spaces_in_regular_expression_literals
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noStaticOnlyClass" => self.no_static_only_class.as_ref().map(|conf| conf.files()),
+            "noThisInStatic" => self.no_this_in_static.as_ref().map(|conf| conf.files()),
+            "noUselessCatch" => self.no_useless_catch.as_ref().map(|conf| conf.files()),
+            "noUselessConstructor" => self
+                .no_useless_constructor
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUselessEmptyExport" => self
+                .no_useless_empty_export
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUselessFragments" => self.no_useless_fragments.as_ref().map(|conf| conf.files()),
+            "noUselessLabel" => self.no_useless_label.as_ref().map(|conf| conf.files()),
+            "noUselessLoneBlockStatements" => self
+                .no_useless_lone_block_statements
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUselessRename" => self.no_useless_rename.as_ref().map(|conf| conf.files()),
+            "noUselessSwitchCase" => self
+                .no_useless_switch_case
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUselessTernary" => self.no_useless_ternary.as_ref().map(|conf| conf.files()),
+            "noUselessThisAlias" => self.no_useless_this_alias.as_ref().map(|conf| conf.files()),
+            "noUselessTypeConstraint" => self
+                .no_useless_type_constraint
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noVoid" => self.no_void.as_ref().map(|conf| conf.files()),
+            "noWith" => self.no_with.as_ref().map(|conf| conf.files()),
+            "useArrowFunction" => self.use_arrow_function.as_ref().map(|conf| conf.files()),
+            "useFlatMap" => self.use_flat_map.as_ref().map(|conf| conf.files()),
+            "useLiteralKeys" => self.use_literal_keys.as_ref().map(|conf| conf.files()),
+            "useOptionalChain" => self.use_optional_chain.as_ref().map(|conf| conf.files()),
+            "useRegexLiterals" => self.use_regex_literals.as_ref().map(|conf| conf.files()),
+            "useSimpleNumberKeys" => self
+                .use_simple_number_keys
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useSimplifiedLogicExpression" => self
+                .use_simplified_logic_expression
+                .as_ref()
+                .map(|conf| conf.files()),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
 #[deserializable(with_validator)]
@@ -2655,6 +3149,139 @@ impl Correctness {
         }
         index_set
     }
+    #[doc = r" Sets the level of the rules of this group, or only of its recommended rules"]
+    #[doc = r" if `only_recommended` is `true`. The options of the rules are preserved."]
+    pub(crate) fn set_rules_level(
+        &mut self,
+        level: RulePlainConfiguration,
+        only_recommended: bool,
+    ) {
+        self.no_children_prop
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_const_assign
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_constant_condition
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_constructor_return
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_empty_character_class_in_regex
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_empty_pattern
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_flat_map_identity
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_global_object_calls
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_inner_declarations
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_invalid_constructor_super
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_invalid_new_builtin
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_invalid_use_before_declaration
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_nonoctal_decimal_escape
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_precision_loss
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_render_return_value
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_self_assign
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_setter_return
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_string_case_mismatch
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_switch_declarations
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unnecessary_continue
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unreachable
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unreachable_super
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unsafe_finally
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unsafe_optional_chaining
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unused_labels
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_void_elements_with_children
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_void_type_return
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_exhaustive_dependencies
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_is_nan
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_jsx_key_in_iterable
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_valid_for_direction
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_yield
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        if !only_recommended {
+            self.no_constant_math_min_max_clamp
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_new_symbol
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_nodejs_modules
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_undeclared_variables
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unused_imports
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unused_private_class_members
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unused_variables
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_array_literals
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_hook_at_top_level
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+        }
+    }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
     pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
         Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
@@ -2856,6 +3483,106 @@ impl Correctness {
             _ => None,
         }
     }
+    pub(crate) fn get_rule_files(&self, rule_name: &str) -> Option<RuleFiles> {
+        match rule_name {
+            "noChildrenProp" => self.no_children_prop.as_ref().map(|conf| conf.files()),
+            "noConstAssign" => self.no_const_assign.as_ref().map(|conf| conf.files()),
+            "noConstantCondition" => self.no_constant_condition.as_ref().map(|conf| conf.files()),
+            "noConstantMathMinMaxClamp" => self
+                .no_constant_math_min_max_clamp
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noConstructorReturn" => self.no_constructor_return.as_ref().map(|conf| conf.files()),
+            "noEmptyCharacterClassInRegex" => self
+                .no_empty_character_class_in_regex
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noEmptyPattern" => self.no_empty_pattern.as_ref().map(|conf| conf.files()),
+            "noFlatMapIdentity" => self.no_flat_map_identity.as_ref().map(|conf| conf.files()),
+            "noGlobalObjectCalls" => self
+                .no_global_object_calls
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noInnerDeclarations" => self.no_inner_declarations.as_ref().map(|conf| conf.files()),
+            "noInvalidConstructorSuper" => self
+                .no_invalid_constructor_super
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noInvalidNewBuiltin" => self
+                .no_invalid_new_builtin
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noInvalidUseBeforeDeclaration" => self
+                .no_invalid_use_before_declaration
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noNewSymbol" => self.no_new_symbol.as_ref().map(|conf| conf.files()),
+            "noNodejsModules" => self.no_nodejs_modules.as_ref().map(|conf| conf.files()),
+            "noNonoctalDecimalEscape" => self
+                .no_nonoctal_decimal_escape
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noPrecisionLoss" => self.no_precision_loss.as_ref().map(|conf| conf.files()),
+            "noRenderReturnValue" => self
+                .no_render_return_value
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noSelfAssign" => self.no_self_assign.as_ref().map(|conf| conf.files()),
+            "noSetterReturn" => self.no_setter_return.as_ref().map(|conf| conf.files()),
+            "noStringCaseMismatch" => self
+                .no_string_case_mismatch
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noSwitchDeclarations" => self
+                .no_switch_declarations
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUndeclaredVariables" => self
+                .no_undeclared_variables
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUnnecessaryContinue" => self
+                .no_unnecessary_continue
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUnreachable" => self.no_unreachable.as_ref().map(|conf| conf.files()),
+            "noUnreachableSuper" => self.no_unreachable_super.as_ref().map(|conf| conf.files()),
+            "noUnsafeFinally" => self.no_unsafe_finally.as_ref().map(|conf| conf.files()),
+            "noUnsafeOptionalChaining" => self
+                .no_unsafe_optional_chaining
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUnusedImports" => self.no_unused_imports.as_ref().map(|conf| conf.files()),
+            "noUnusedLabels" => self.no_unused_labels.as_ref().map(|conf| conf.files()),
+            "noUnusedPrivateClassMembers" => self
+                .no_unused_private_class_members
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUnusedVariables" => self.no_unused_variables.as_ref().map(|conf| conf.files()),
+            "noVoidElementsWithChildren" => self
+                .no_void_elements_with_children
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noVoidTypeReturn" => self.no_void_type_return.as_ref().map(|conf| conf.files()),
+            "useArrayLiterals" => self.use_array_literals.as_ref().map(|conf| conf.files()),
+            "useExhaustiveDependencies" => self
+                .use_exhaustive_dependencies
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useHookAtTopLevel" => self.use_hook_at_top_level.as_ref().map(|conf| conf.files()),
+            "useIsNan" => self.use_is_nan.as_ref().map(|conf| conf.files()),
+            "useJsxKeyInIterable" => self
+                .use_jsx_key_in_iterable
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useValidForDirection" => self
+                .use_valid_for_direction
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useYield" => self.use_yield.as_ref().map(|conf| conf.files()),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
 #[deserializable(with_validator)]
@@ -2869,12 +3596,27 @@ pub struct Nursery {
     #[doc = r" It enables ALL rules for this group."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub all: Option<bool>,
+    #[doc = "Disallow await inside loops."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_await_in_loop: Option<RuleFixConfiguration<biome_js_analyze::options::NoAwaitInLoop>>,
+    #[doc = "Disallow the imports that go against the direction of the layers of the architecture."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_circular_domain_dependencies:
+        Option<RuleConfiguration<biome_js_analyze::options::NoCircularDomainDependencies>>,
+    #[doc = "Disallow blocks that only contain comments."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_comment_only_block:
+        Option<RuleConfiguration<biome_css_analyze::options::NoCommentOnlyBlock>>,
     #[doc = "Disallow the use of console."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_console: Option<RuleFixConfiguration<biome_js_analyze::options::NoConsole>>,
     #[doc = "Disallow using a callback in asynchronous tests and hooks."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_done_callback: Option<RuleConfiguration<biome_js_analyze::options::NoDoneCallback>>,
+    #[doc = "Disallow asserting a value to a type through unknown or any."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_double_assertion:
+        Option<RuleConfiguration<biome_js_analyze::options::NoDoubleAssertion>>,
     #[doc = "Disallow duplicate @import rules."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_at_import_rules:
@@ -2887,10 +3629,18 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_font_names:
         Option<RuleConfiguration<biome_css_analyze::options::NoDuplicateFontNames>>,
+    #[doc = "Disallow re-exporting several modules that export the same name."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_imports_across_files:
+        Option<RuleConfiguration<biome_js_analyze::options::NoDuplicateImportsAcrossFiles>>,
     #[doc = "Disallow two keys with the same name inside a JSON object."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_json_keys:
         Option<RuleConfiguration<biome_json_analyze::options::NoDuplicateJsonKeys>>,
+    #[doc = "Disallow the @layer statements that list a layer that is already declared."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_layer_names:
+        Option<RuleConfiguration<biome_css_analyze::options::NoDuplicateLayerNames>>,
     #[doc = "Disallow duplicate selectors within keyframe blocks."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_selectors_keyframe_block:
@@ -2903,20 +3653,36 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_dynamic_namespace_import_access:
         Option<RuleConfiguration<biome_js_analyze::options::NoDynamicNamespaceImportAccess>>,
+    #[doc = "Disallow empty alternatives in regular expression literals."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_empty_alternatives:
+        Option<RuleConfiguration<biome_js_analyze::options::NoEmptyAlternatives>>,
     #[doc = "Disallow CSS empty blocks."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_empty_block: Option<RuleConfiguration<biome_css_analyze::options::NoEmptyBlock>>,
     #[doc = "Disallow variables from evolving into any type through reassignments."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_evolving_types: Option<RuleConfiguration<biome_js_analyze::options::NoEvolvingTypes>>,
+    #[doc = "Disallow using !important more times than allowed in a stylesheet."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_excessive_important:
+        Option<RuleConfiguration<biome_css_analyze::options::NoExcessiveImportant>>,
     #[doc = "Disallow exporting an imported variable."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_exported_imports:
         Option<RuleConfiguration<biome_js_analyze::options::NoExportedImports>>,
+    #[doc = "Disallow spreading props onto JSX elements."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_implicit_spread_props:
+        Option<RuleConfiguration<biome_js_analyze::options::NoImplicitSpreadProps>>,
     #[doc = "Disallow invalid !important within keyframe declarations"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_important_in_keyframe:
         Option<RuleConfiguration<biome_css_analyze::options::NoImportantInKeyframe>>,
+    #[doc = "Disallow the features that can't be used in the size queries of the @container rules."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_container_query_feature:
+        Option<RuleConfiguration<biome_css_analyze::options::NoInvalidContainerQueryFeature>>,
     #[doc = "Disallow non-standard direction values for linear gradient functions."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_invalid_direction_in_linear_gradient:
@@ -2929,14 +3695,37 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_irregular_whitespace:
         Option<RuleConfiguration<biome_css_analyze::options::NoIrregularWhitespace>>,
+    #[doc = "Disallow the literal ids of a JSX tree that don't match: the labels that reference unknown ids, and the duplicate ids."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_jsx_literals_in_attributes_needing_ids:
+        Option<RuleConfiguration<biome_js_analyze::options::NoJsxLiteralsInAttributesNeedingIds>>,
     #[doc = "Enforce that a label element or component has a text label and an associated input."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_label_without_control:
         Option<RuleConfiguration<biome_js_analyze::options::NoLabelWithoutControl>>,
+    #[doc = "Disallow the event listeners, intervals, and subscriptions of an effect that aren't removed by its cleanup function."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_leaked_event_listeners:
+        Option<RuleConfiguration<biome_js_analyze::options::NoLeakedEventListeners>>,
     #[doc = "Checks that the assertion function, for example expect, is placed inside an it() function call."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_misplaced_assertion:
         Option<RuleConfiguration<biome_js_analyze::options::NoMisplacedAssertion>>,
+    #[doc = "Require a fallback in the var() functions of the layout properties."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_missing_var_function_fallback:
+        Option<RuleConfiguration<biome_css_analyze::options::NoMissingVarFunctionFallback>>,
+    #[doc = "Disallow the mutation of the props, the state, and the values memoized by useMemo in React components."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_mutating_props_or_state:
+        Option<RuleConfiguration<biome_js_analyze::options::NoMutatingPropsOrState>>,
+    #[doc = "Disallow non-null assertions after an optional chain."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_non_null_asserted_optional_chain:
+        Option<RuleFixConfiguration<biome_js_analyze::options::NoNonNullAssertedOptionalChain>>,
+    #[doc = "Disallow the use of the __proto__ property."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_proto: Option<RuleFixConfiguration<biome_js_analyze::options::NoProto>>,
     #[doc = "Prevents React-specific JSX properties from being used."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_react_specific_props:
@@ -2949,6 +3738,10 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_shorthand_property_overrides:
         Option<RuleConfiguration<biome_css_analyze::options::NoShorthandPropertyOverrides>>,
+    #[doc = "Disallow the module-level side effects in the packages that declare that they don't have any."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_side_effect_imports_in_libraries:
+        Option<RuleConfiguration<biome_js_analyze::options::NoSideEffectImportsInLibraries>>,
     #[doc = "Enforce that static, visible elements (such as \\<div>) that have click handlers use the valid role attribute."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_static_element_interactions:
@@ -2956,10 +3749,38 @@ pub struct Nursery {
     #[doc = "Enforce the use of String.slice() over String.substr() and String.substring()."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_substr: Option<RuleFixConfiguration<biome_js_analyze::options::NoSubstr>>,
+    #[doc = "Disallow regular expressions that can backtrack exponentially."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_superlinear_backtracking:
+        Option<RuleConfiguration<biome_js_analyze::options::NoSuperlinearBacktracking>>,
+    #[doc = "Disallow catch clauses that silently discard the caught error."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_swallowed_errors:
+        Option<RuleConfiguration<biome_js_analyze::options::NoSwallowedErrors>>,
+    #[doc = "Disallow the untranslated strings displayed to the users in JSX."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_translation_literals:
+        Option<RuleConfiguration<biome_js_analyze::options::NoTranslationLiterals>>,
     #[doc = "Disallow the use of dependencies that aren't specified in the package.json."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_undeclared_dependencies:
         Option<RuleConfiguration<biome_js_analyze::options::NoUndeclaredDependencies>>,
+    #[doc = "Disallow passing async functions to APIs that ignore the promises returned by their callbacks."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unhandled_rejections:
+        Option<RuleConfiguration<biome_js_analyze::options::NoUnhandledRejections>>,
+    #[doc = "Disallow unknown attributes on the native elements."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_attribute:
+        Option<RuleFixConfiguration<biome_js_analyze::options::NoUnknownAttribute>>,
+    #[doc = "Disallow the @container rules that query a container name that is never declared."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_container_name:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnknownContainerName>>,
+    #[doc = "Disallow references to custom properties that are never declared."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_custom_property:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnknownCustomProperty>>,
     #[doc = "Disallow unknown CSS value functions."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_function:
@@ -2968,6 +3789,10 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_media_feature_name:
         Option<RuleConfiguration<biome_css_analyze::options::NoUnknownMediaFeatureName>>,
+    #[doc = "Disallow invalid values of the media features."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_media_feature_value:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnknownMediaFeatureValue>>,
     #[doc = "Disallow unknown properties."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_property:
@@ -2987,10 +3812,26 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unmatchable_anb_selector:
         Option<RuleConfiguration<biome_css_analyze::options::NoUnmatchableAnbSelector>>,
+    #[doc = "Disallow non-null assertions on the values that a condition already checks."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unnecessary_non_null_assertion:
+        Option<RuleFixConfiguration<biome_js_analyze::options::NoUnnecessaryNonNullAssertion>>,
+    #[doc = "Disallow the accesses to the props of the Solid components that lose their reactivity."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_untracked_props_access:
+        Option<RuleConfiguration<biome_js_analyze::options::NoUntrackedPropsAccess>>,
+    #[doc = "Disallow the calls of the Solid signals outside of the tracked scopes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_untracked_signal_calls:
+        Option<RuleConfiguration<biome_js_analyze::options::NoUntrackedSignalCalls>>,
     #[doc = "Disallow unused function parameters."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unused_function_parameters:
         Option<RuleFixConfiguration<biome_js_analyze::options::NoUnusedFunctionParameters>>,
+    #[doc = "Disallow reading the environment variables outside of the modules that validate them."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unvalidated_env_access:
+        Option<RuleConfiguration<biome_js_analyze::options::NoUnvalidatedEnvAccess>>,
     #[doc = "Disallow unnecessary escape sequence in regular expression literals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_useless_escape_in_regex:
@@ -3006,6 +3847,18 @@ pub struct Nursery {
     #[doc = "Disallow use of @value rule in css modules."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_value_at_rule: Option<RuleConfiguration<biome_css_analyze::options::NoValueAtRule>>,
+    #[doc = "Disallow the duplicate keys across the props, the data, the computed properties, and the methods of the Vue components."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_vue_duplicate_keys:
+        Option<RuleConfiguration<biome_js_analyze::options::NoVueDuplicateKeys>>,
+    #[doc = "Disallow the reserved names as props of the Vue components."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_vue_reserved_props:
+        Option<RuleConfiguration<biome_js_analyze::options::NoVueReservedProps>>,
+    #[doc = "Disallow the side effects in the computed properties of Vue."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_vue_side_effects_in_computed:
+        Option<RuleConfiguration<biome_js_analyze::options::NoVueSideEffectsInComputed>>,
     #[doc = "Disallow the use of yoda expressions."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_yoda_expression:
@@ -3014,6 +3867,9 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_adjacent_overload_signatures:
         Option<RuleConfiguration<biome_js_analyze::options::UseAdjacentOverloadSignatures>>,
+    #[doc = "Use at() instead of computing an index from the length to access the last elements."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_at_index: Option<RuleFixConfiguration<biome_js_analyze::options::UseAtIndex>>,
     #[doc = "Enforce the use of new for all builtins, except String, Number, Boolean, Symbol and BigInt."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_consistent_builtin_instantiation:
@@ -3022,10 +3878,26 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_consistent_curly_braces:
         Option<RuleFixConfiguration<biome_js_analyze::options::UseConsistentCurlyBraces>>,
+    #[doc = "Require consistently specifying the type arguments of a generic constructor either on the constructor or on the type annotation."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_generic_constructors:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseConsistentGenericConstructors>>,
     #[doc = "Disallows invalid named grid areas in CSS Grid Layouts."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_consistent_grid_areas:
         Option<RuleConfiguration<biome_css_analyze::options::UseConsistentGridAreas>>,
+    #[doc = "Require the consistent use of the shorthand or the explicit syntax for the properties and the methods of the object literals."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_object_definitions:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseConsistentObjectDefinitions>>,
+    #[doc = "Require consistently using either Record\\<K, V> or index signatures."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_record_type:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseConsistentRecordType>>,
+    #[doc = "Require checking the status of the responses of fetch() before reading their body."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_response_handling:
+        Option<RuleConfiguration<biome_js_analyze::options::UseConsistentResponseHandling>>,
     #[doc = "Use Date.now() to get the number of milliseconds since the Unix Epoch."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_date_now: Option<RuleFixConfiguration<biome_js_analyze::options::UseDateNow>>,
@@ -3037,9 +3909,16 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_deprecated_reason:
         Option<RuleConfiguration<biome_graphql_analyze::options::UseDeprecatedReason>>,
+    #[doc = "Require the caught error to be attached as the cause of the errors thrown in a catch clause."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_error_cause: Option<RuleFixConfiguration<biome_js_analyze::options::UseErrorCause>>,
     #[doc = "Enforce passing a message value when creating a built-in error."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_error_message: Option<RuleConfiguration<biome_js_analyze::options::UseErrorMessage>>,
+    #[doc = "Require the switch statements over an enum or a union of literal types to handle all its members."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_exhaustive_switch_cases:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseExhaustiveSwitchCases>>,
     #[doc = "Enforce explicitly comparing the length, size, byteLength or byteOffset property of a value."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_explicit_length_check:
@@ -3052,6 +3931,9 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_generic_font_names:
         Option<RuleConfiguration<biome_css_analyze::options::UseGenericFontNames>>,
+    #[doc = "Require for...in loops to filter the properties they iterate over."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_guard_for_in: Option<RuleConfiguration<biome_js_analyze::options::UseGuardForIn>>,
     #[doc = "Enforce file extensions for relative imports."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_import_extensions:
@@ -3060,18 +3942,47 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_import_restrictions:
         Option<RuleConfiguration<biome_js_analyze::options::UseImportRestrictions>>,
+    #[doc = "Use includes() instead of comparing the result of indexOf() to check for the presence of a value."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_includes: Option<RuleFixConfiguration<biome_js_analyze::options::UseIncludes>>,
+    #[doc = "Enforce that the @param and @returns tags of JSDoc comments match their function."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_js_doc_params_match:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseJsDocParamsMatch>>,
     #[doc = "Enforce using the digits argument with Number#toFixed()."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_number_to_fixed_digits_argument:
         Option<RuleFixConfiguration<biome_js_analyze::options::UseNumberToFixedDigitsArgument>>,
-    #[doc = "It detects the use of role attributes in JSX elements and suggests using semantic elements instead."]
+    #[doc = "Enforce the grouping of the digits of the long numeric literals with numeric separators."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_numeric_separators:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseNumericSeparators>>,
+    #[doc = "Use the object spread syntax instead of Object.assign() to copy objects into a new object."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_object_spread: Option<RuleFixConfiguration<biome_js_analyze::options::UseObjectSpread>>,
+    #[doc = "Enforce the simplest form of the parts of regular expression literals."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_optimized_regex:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseOptimizedRegex>>,
+    #[doc = "Enforce the reactive use of the signals of Preact."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_preact_signals_correctly:
+        Option<RuleConfiguration<biome_js_analyze::options::UsePreactSignalsCorrectly>>,
+    #[doc = "It detects the use of role attributes in JSX elements and suggests using semantic elements instead."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_semantic_elements:
-        Option<RuleConfiguration<biome_js_analyze::options::UseSemanticElements>>,
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseSemanticElements>>,
     #[doc = "Enforce the sorting of CSS utility classes."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_sorted_classes:
         Option<RuleFixConfiguration<biome_js_analyze::options::UseSortedClasses>>,
+    #[doc = "Enforce sorted keys in the object literals of the configured contexts."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_object_keys:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseSortedObjectKeys>>,
+    #[doc = "Use the spread syntax instead of Array.prototype.slice.call() to convert a value to an array."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_spread: Option<RuleFixConfiguration<biome_js_analyze::options::UseSpread>>,
     #[doc = "Enforce the use of the directive \"use strict\" in script files."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_strict_mode: Option<RuleFixConfiguration<biome_js_analyze::options::UseStrictMode>>,
@@ -3082,7 +3993,7 @@ pub struct Nursery {
     #[doc = "Disallow throwing non-Error values."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_throw_only_error:
-        Option<RuleConfiguration<biome_js_analyze::options::UseThrowOnlyError>>,
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseThrowOnlyError>>,
     #[doc = "Require regex literals to be declared at the top level."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_top_level_regex: Option<RuleConfiguration<biome_js_analyze::options::UseTopLevelRegex>>,
@@ -3094,6 +4005,10 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_valid_autocomplete:
         Option<RuleConfiguration<biome_js_analyze::options::UseValidAutocomplete>>,
+    #[doc = "Ensure that the keys passed to the translation functions exist in the message catalogs."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_valid_i18n_keys:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseValidI18nKeys>>,
 }
 impl DeserializableValidator for Nursery {
     fn validate(
@@ -3112,65 +4027,114 @@ impl DeserializableValidator for Nursery {
 impl Nursery {
     const GROUP_NAME: &'static str = "nursery";
     pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "noAwaitInLoop",
+        "noCircularDomainDependencies",
+        "noCommentOnlyBlock",
         "noConsole",
         "noDoneCallback",
+        "noDoubleAssertion",
         "noDuplicateAtImportRules",
         "noDuplicateElseIf",
         "noDuplicateFontNames",
+        "noDuplicateImportsAcrossFiles",
         "noDuplicateJsonKeys",
+        "noDuplicateLayerNames",
         "noDuplicateSelectorsKeyframeBlock",
         "noDuplicatedFields",
         "noDynamicNamespaceImportAccess",
+        "noEmptyAlternatives",
         "noEmptyBlock",
         "noEvolvingTypes",
+        "noExcessiveImportant",
         "noExportedImports",
+        "noImplicitSpreadProps",
         "noImportantInKeyframe",
+        "noInvalidContainerQueryFeature",
         "noInvalidDirectionInLinearGradient",
         "noInvalidPositionAtImportRule",
         "noIrregularWhitespace",
+        "noJsxLiteralsInAttributesNeedingIds",
         "noLabelWithoutControl",
+        "noLeakedEventListeners",
         "noMisplacedAssertion",
+        "noMissingVarFunctionFallback",
+        "noMutatingPropsOrState",
+        "noNonNullAssertedOptionalChain",
+        "noProto",
         "noReactSpecificProps",
         "noRestrictedImports",
         "noShorthandPropertyOverrides",
+        "noSideEffectImportsInLibraries",
         "noStaticElementInteractions",
         "noSubstr",
+        "noSuperlinearBacktracking",
+        "noSwallowedErrors",
+        "noTranslationLiterals",
         "noUndeclaredDependencies",
+        "noUnhandledRejections",
+        "noUnknownAttribute",
+        "noUnknownContainerName",
+        "noUnknownCustomProperty",
         "noUnknownFunction",
         "noUnknownMediaFeatureName",
+        "noUnknownMediaFeatureValue",
         "noUnknownProperty",
         "noUnknownPseudoClassSelector",
         "noUnknownSelectorPseudoElement",
         "noUnknownUnit",
         "noUnmatchableAnbSelector",
+        "noUnnecessaryNonNullAssertion",
+        "noUntrackedPropsAccess",
+        "noUntrackedSignalCalls",
         "noUnusedFunctionParameters",
+        "noUnvalidatedEnvAccess",
         "noUselessEscapeInRegex",
         "noUselessStringConcat",
         "noUselessUndefinedInitialization",
         "noValueAtRule",
+        "noVueDuplicateKeys",
+        "noVueReservedProps",
+        "noVueSideEffectsInComputed",
         "noYodaExpression",
         "useAdjacentOverloadSignatures",
+        "useAtIndex",
         "useConsistentBuiltinInstantiation",
         "useConsistentCurlyBraces",
+        "useConsistentGenericConstructors",
         "useConsistentGridAreas",
+        "useConsistentObjectDefinitions",
+        "useConsistentRecordType",
+        "useConsistentResponseHandling",
         "useDateNow",
         "useDefaultSwitchClause",
         "useDeprecatedReason",
+        "useErrorCause",
         "useErrorMessage",
+        "useExhaustiveSwitchCases",
         "useExplicitLengthCheck",
         "useFocusableInteractive",
         "useGenericFontNames",
+        "useGuardForIn",
         "useImportExtensions",
         "useImportRestrictions",
+        "useIncludes",
+        "useJsDocParamsMatch",
         "useNumberToFixedDigitsArgument",
+        "useNumericSeparators",
+        "useObjectSpread",
+        "useOptimizedRegex",
+        "usePreactSignalsCorrectly",
         "useSemanticElements",
         "useSortedClasses",
+        "useSortedObjectKeys",
+        "useSpread",
         "useStrictMode",
         "useThrowNewError",
         "useThrowOnlyError",
         "useTopLevelRegex",
         "useTrimStartEnd",
         "useValidAutocomplete",
+        "useValidI18nKeys",
     ];
     const RECOMMENDED_RULES: &'static [&'static str] = &[
         "noDoneCallback",
@@ -3200,31 +4164,31 @@ impl Nursery {
         "useStrictMode",
     ];
     const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]),
     ];
     const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
@@ -3286,6 +4250,55 @@ impl Nursery {
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]),
     ];
     #[doc = r" Retrieves the recommended rules"]
     pub(crate) fn is_recommended_true(&self) -> bool {
@@ -3302,605 +4315,1429 @@ impl Nursery {
     }
     pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.no_console.as_ref() {
+        if let Some(rule) = self.no_await_in_loop.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.no_done_callback.as_ref() {
+        if let Some(rule) = self.no_circular_domain_dependencies.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.no_duplicate_at_import_rules.as_ref() {
+        if let Some(rule) = self.no_comment_only_block.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
+        if let Some(rule) = self.no_console.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_duplicate_font_names.as_ref() {
+        if let Some(rule) = self.no_done_callback.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
+        if let Some(rule) = self.no_double_assertion.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_duplicate_selectors_keyframe_block.as_ref() {
+        if let Some(rule) = self.no_duplicate_at_import_rules.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_duplicated_fields.as_ref() {
+        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
+        if let Some(rule) = self.no_duplicate_font_names.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.no_empty_block.as_ref() {
+        if let Some(rule) = self.no_duplicate_imports_across_files.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_evolving_types.as_ref() {
+        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_exported_imports.as_ref() {
+        if let Some(rule) = self.no_duplicate_layer_names.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_important_in_keyframe.as_ref() {
+        if let Some(rule) = self.no_duplicate_selectors_keyframe_block.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_invalid_direction_in_linear_gradient.as_ref() {
+        if let Some(rule) = self.no_duplicated_fields.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_invalid_position_at_import_rule.as_ref() {
+        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
+        if let Some(rule) = self.no_empty_alternatives.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_label_without_control.as_ref() {
+        if let Some(rule) = self.no_empty_block.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
+        if let Some(rule) = self.no_evolving_types.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_react_specific_props.as_ref() {
+        if let Some(rule) = self.no_excessive_important.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.no_restricted_imports.as_ref() {
+        if let Some(rule) = self.no_exported_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.no_shorthand_property_overrides.as_ref() {
+        if let Some(rule) = self.no_implicit_spread_props.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.no_static_element_interactions.as_ref() {
+        if let Some(rule) = self.no_important_in_keyframe.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.no_substr.as_ref() {
+        if let Some(rule) = self.no_invalid_container_query_feature.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
+        if let Some(rule) = self.no_invalid_direction_in_linear_gradient.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
-        if let Some(rule) = self.no_unknown_function.as_ref() {
+        if let Some(rule) = self.no_invalid_position_at_import_rule.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
             }
         }
-        if let Some(rule) = self.no_unknown_media_feature_name.as_ref() {
+        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
             }
         }
-        if let Some(rule) = self.no_unknown_property.as_ref() {
+        if let Some(rule) = self.no_jsx_literals_in_attributes_needing_ids.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
             }
         }
-        if let Some(rule) = self.no_unknown_pseudo_class_selector.as_ref() {
+        if let Some(rule) = self.no_label_without_control.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
             }
         }
-        if let Some(rule) = self.no_unknown_selector_pseudo_element.as_ref() {
+        if let Some(rule) = self.no_leaked_event_listeners.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
             }
         }
-        if let Some(rule) = self.no_unknown_unit.as_ref() {
+        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
             }
         }
-        if let Some(rule) = self.no_unmatchable_anb_selector.as_ref() {
+        if let Some(rule) = self.no_missing_var_function_fallback.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
             }
         }
-        if let Some(rule) = self.no_unused_function_parameters.as_ref() {
+        if let Some(rule) = self.no_mutating_props_or_state.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
             }
         }
-        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+        if let Some(rule) = self.no_non_null_asserted_optional_chain.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
             }
         }
-        if let Some(rule) = self.no_useless_string_concat.as_ref() {
+        if let Some(rule) = self.no_proto.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined_initialization.as_ref() {
+        if let Some(rule) = self.no_react_specific_props.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
             }
         }
-        if let Some(rule) = self.no_value_at_rule.as_ref() {
+        if let Some(rule) = self.no_restricted_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
             }
         }
-        if let Some(rule) = self.no_yoda_expression.as_ref() {
+        if let Some(rule) = self.no_shorthand_property_overrides.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
             }
         }
-        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
+        if let Some(rule) = self.no_side_effect_imports_in_libraries.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
             }
         }
-        if let Some(rule) = self.use_consistent_builtin_instantiation.as_ref() {
+        if let Some(rule) = self.no_static_element_interactions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
             }
         }
-        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+        if let Some(rule) = self.no_substr.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
             }
         }
-        if let Some(rule) = self.use_consistent_grid_areas.as_ref() {
+        if let Some(rule) = self.no_superlinear_backtracking.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
             }
         }
-        if let Some(rule) = self.use_date_now.as_ref() {
+        if let Some(rule) = self.no_swallowed_errors.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
             }
         }
-        if let Some(rule) = self.use_default_switch_clause.as_ref() {
+        if let Some(rule) = self.no_translation_literals.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
             }
         }
-        if let Some(rule) = self.use_deprecated_reason.as_ref() {
+        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
             }
         }
-        if let Some(rule) = self.use_error_message.as_ref() {
+        if let Some(rule) = self.no_unhandled_rejections.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
             }
         }
-        if let Some(rule) = self.use_explicit_length_check.as_ref() {
+        if let Some(rule) = self.no_unknown_attribute.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
             }
         }
-        if let Some(rule) = self.use_focusable_interactive.as_ref() {
+        if let Some(rule) = self.no_unknown_container_name.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
             }
         }
-        if let Some(rule) = self.use_generic_font_names.as_ref() {
+        if let Some(rule) = self.no_unknown_custom_property.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
             }
         }
-        if let Some(rule) = self.use_import_extensions.as_ref() {
+        if let Some(rule) = self.no_unknown_function.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.no_unknown_media_feature_name.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
             }
         }
-        if let Some(rule) = self.use_number_to_fixed_digits_argument.as_ref() {
+        if let Some(rule) = self.no_unknown_media_feature_value.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
             }
         }
-        if let Some(rule) = self.use_semantic_elements.as_ref() {
+        if let Some(rule) = self.no_unknown_property.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.no_unknown_pseudo_class_selector.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
             }
         }
-        if let Some(rule) = self.use_strict_mode.as_ref() {
+        if let Some(rule) = self.no_unknown_selector_pseudo_element.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
             }
         }
-        if let Some(rule) = self.use_throw_new_error.as_ref() {
+        if let Some(rule) = self.no_unknown_unit.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
             }
         }
-        if let Some(rule) = self.use_throw_only_error.as_ref() {
+        if let Some(rule) = self.no_unmatchable_anb_selector.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
             }
         }
-        if let Some(rule) = self.use_top_level_regex.as_ref() {
+        if let Some(rule) = self.no_unnecessary_non_null_assertion.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
             }
         }
-        if let Some(rule) = self.use_trim_start_end.as_ref() {
+        if let Some(rule) = self.no_untracked_props_access.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]));
             }
         }
-        if let Some(rule) = self.use_valid_autocomplete.as_ref() {
+        if let Some(rule) = self.no_untracked_signal_calls.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]));
             }
         }
-        index_set
-    }
-    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
-        let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.no_console.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+        if let Some(rule) = self.no_unused_function_parameters.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]));
             }
         }
-        if let Some(rule) = self.no_done_callback.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+        if let Some(rule) = self.no_unvalidated_env_access.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]));
             }
         }
-        if let Some(rule) = self.no_duplicate_at_import_rules.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]));
             }
         }
-        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+        if let Some(rule) = self.no_useless_string_concat.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]));
             }
         }
-        if let Some(rule) = self.no_duplicate_font_names.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+        if let Some(rule) = self.no_useless_undefined_initialization.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]));
             }
         }
-        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+        if let Some(rule) = self.no_value_at_rule.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]));
             }
         }
-        if let Some(rule) = self.no_duplicate_selectors_keyframe_block.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+        if let Some(rule) = self.no_vue_duplicate_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]));
             }
         }
-        if let Some(rule) = self.no_duplicated_fields.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+        if let Some(rule) = self.no_vue_reserved_props.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]));
             }
         }
-        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+        if let Some(rule) = self.no_vue_side_effects_in_computed.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]));
             }
         }
-        if let Some(rule) = self.no_empty_block.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+        if let Some(rule) = self.no_yoda_expression.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]));
             }
         }
-        if let Some(rule) = self.no_evolving_types.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]));
             }
         }
-        if let Some(rule) = self.no_exported_imports.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+        if let Some(rule) = self.use_at_index.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]));
             }
         }
-        if let Some(rule) = self.no_important_in_keyframe.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
+        if let Some(rule) = self.use_consistent_builtin_instantiation.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]));
             }
         }
-        if let Some(rule) = self.no_invalid_direction_in_linear_gradient.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]));
             }
         }
-        if let Some(rule) = self.no_invalid_position_at_import_rule.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
+        if let Some(rule) = self.use_consistent_generic_constructors.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]));
             }
         }
-        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
+        if let Some(rule) = self.use_consistent_grid_areas.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]));
             }
         }
-        if let Some(rule) = self.no_label_without_control.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+        if let Some(rule) = self.use_consistent_object_definitions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]));
             }
         }
-        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+        if let Some(rule) = self.use_consistent_record_type.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]));
             }
         }
-        if let Some(rule) = self.no_react_specific_props.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+        if let Some(rule) = self.use_consistent_response_handling.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]));
             }
         }
-        if let Some(rule) = self.no_restricted_imports.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+        if let Some(rule) = self.use_date_now.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]));
             }
         }
-        if let Some(rule) = self.no_shorthand_property_overrides.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+        if let Some(rule) = self.use_default_switch_clause.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]));
             }
         }
-        if let Some(rule) = self.no_static_element_interactions.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+        if let Some(rule) = self.use_deprecated_reason.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]));
             }
         }
-        if let Some(rule) = self.no_substr.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+        if let Some(rule) = self.use_error_cause.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]));
             }
         }
-        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+        if let Some(rule) = self.use_error_message.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]));
             }
         }
-        if let Some(rule) = self.no_unknown_function.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]));
             }
         }
-        if let Some(rule) = self.no_unknown_media_feature_name.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+        if let Some(rule) = self.use_explicit_length_check.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]));
             }
         }
-        if let Some(rule) = self.no_unknown_property.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+        if let Some(rule) = self.use_focusable_interactive.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]));
             }
         }
-        if let Some(rule) = self.no_unknown_pseudo_class_selector.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+        if let Some(rule) = self.use_generic_font_names.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]));
             }
         }
-        if let Some(rule) = self.no_unknown_selector_pseudo_element.as_ref() {
+        if let Some(rule) = self.use_guard_for_in.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]));
+            }
+        }
+        if let Some(rule) = self.use_import_extensions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]));
+            }
+        }
+        if let Some(rule) = self.use_includes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]));
+            }
+        }
+        if let Some(rule) = self.use_js_doc_params_match.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]));
+            }
+        }
+        if let Some(rule) = self.use_number_to_fixed_digits_argument.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]));
+            }
+        }
+        if let Some(rule) = self.use_numeric_separators.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]));
+            }
+        }
+        if let Some(rule) = self.use_object_spread.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]));
+            }
+        }
+        if let Some(rule) = self.use_optimized_regex.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]));
+            }
+        }
+        if let Some(rule) = self.use_preact_signals_correctly.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]));
+            }
+        }
+        if let Some(rule) = self.use_semantic_elements.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_object_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]));
+            }
+        }
+        if let Some(rule) = self.use_spread.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]));
+            }
+        }
+        if let Some(rule) = self.use_strict_mode.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]));
+            }
+        }
+        if let Some(rule) = self.use_throw_new_error.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]));
+            }
+        }
+        if let Some(rule) = self.use_throw_only_error.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]));
+            }
+        }
+        if let Some(rule) = self.use_top_level_regex.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]));
+            }
+        }
+        if let Some(rule) = self.use_trim_start_end.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]));
+            }
+        }
+        if let Some(rule) = self.use_valid_autocomplete.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]));
+            }
+        }
+        if let Some(rule) = self.use_valid_i18n_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]));
+            }
+        }
+        index_set
+    }
+    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.no_await_in_loop.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.no_circular_domain_dependencies.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.no_comment_only_block.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.no_console.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.no_done_callback.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.no_double_assertion.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_at_import_rules.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_font_names.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_imports_across_files.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_layer_names.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_selectors_keyframe_block.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
+            }
+        }
+        if let Some(rule) = self.no_duplicated_fields.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+            }
+        }
+        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
+            }
+        }
+        if let Some(rule) = self.no_empty_alternatives.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
+            }
+        }
+        if let Some(rule) = self.no_empty_block.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+            }
+        }
+        if let Some(rule) = self.no_evolving_types.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+            }
+        }
+        if let Some(rule) = self.no_excessive_important.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+            }
+        }
+        if let Some(rule) = self.no_exported_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+            }
+        }
+        if let Some(rule) = self.no_implicit_spread_props.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+            }
+        }
+        if let Some(rule) = self.no_important_in_keyframe.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+            }
+        }
+        if let Some(rule) = self.no_invalid_container_query_feature.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+            }
+        }
+        if let Some(rule) = self.no_invalid_direction_in_linear_gradient.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+            }
+        }
+        if let Some(rule) = self.no_invalid_position_at_import_rule.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+            }
+        }
+        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+            }
+        }
+        if let Some(rule) = self.no_jsx_literals_in_attributes_needing_ids.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+            }
+        }
+        if let Some(rule) = self.no_label_without_control.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+            }
+        }
+        if let Some(rule) = self.no_leaked_event_listeners.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
             }
         }
-        if let Some(rule) = self.no_unknown_unit.as_ref() {
+        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
             }
         }
-        if let Some(rule) = self.no_unmatchable_anb_selector.as_ref() {
+        if let Some(rule) = self.no_missing_var_function_fallback.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
             }
         }
-        if let Some(rule) = self.no_unused_function_parameters.as_ref() {
+        if let Some(rule) = self.no_mutating_props_or_state.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
             }
         }
-        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+        if let Some(rule) = self.no_non_null_asserted_optional_chain.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
             }
         }
-        if let Some(rule) = self.no_useless_string_concat.as_ref() {
+        if let Some(rule) = self.no_proto.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined_initialization.as_ref() {
+        if let Some(rule) = self.no_react_specific_props.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
             }
         }
-        if let Some(rule) = self.no_value_at_rule.as_ref() {
+        if let Some(rule) = self.no_restricted_imports.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
             }
         }
-        if let Some(rule) = self.no_yoda_expression.as_ref() {
+        if let Some(rule) = self.no_shorthand_property_overrides.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
             }
         }
-        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
+        if let Some(rule) = self.no_side_effect_imports_in_libraries.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
             }
         }
-        if let Some(rule) = self.use_consistent_builtin_instantiation.as_ref() {
+        if let Some(rule) = self.no_static_element_interactions.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
             }
         }
-        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+        if let Some(rule) = self.no_substr.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
             }
         }
-        if let Some(rule) = self.use_consistent_grid_areas.as_ref() {
+        if let Some(rule) = self.no_superlinear_backtracking.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
             }
         }
-        if let Some(rule) = self.use_date_now.as_ref() {
+        if let Some(rule) = self.no_swallowed_errors.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
             }
         }
-        if let Some(rule) = self.use_default_switch_clause.as_ref() {
+        if let Some(rule) = self.no_translation_literals.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
             }
         }
-        if let Some(rule) = self.use_deprecated_reason.as_ref() {
+        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
             }
         }
-        if let Some(rule) = self.use_error_message.as_ref() {
+        if let Some(rule) = self.no_unhandled_rejections.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
             }
         }
-        if let Some(rule) = self.use_explicit_length_check.as_ref() {
+        if let Some(rule) = self.no_unknown_attribute.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
             }
         }
-        if let Some(rule) = self.use_focusable_interactive.as_ref() {
+        if let Some(rule) = self.no_unknown_container_name.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
             }
         }
-        if let Some(rule) = self.use_generic_font_names.as_ref() {
+        if let Some(rule) = self.no_unknown_custom_property.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
             }
         }
-        if let Some(rule) = self.use_import_extensions.as_ref() {
+        if let Some(rule) = self.no_unknown_function.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.no_unknown_media_feature_name.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
             }
         }
-        if let Some(rule) = self.use_number_to_fixed_digits_argument.as_ref() {
+        if let Some(rule) = self.no_unknown_media_feature_value.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
             }
         }
-        if let Some(rule) = self.use_semantic_elements.as_ref() {
+        if let Some(rule) = self.no_unknown_property.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.no_unknown_pseudo_class_selector.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
             }
         }
-        if let Some(rule) = self.use_strict_mode.as_ref() {
+        if let Some(rule) = self.no_unknown_selector_pseudo_element.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
             }
         }
-        if let Some(rule) = self.use_throw_new_error.as_ref() {
+        if let Some(rule) = self.no_unknown_unit.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
             }
         }
-        if let Some(rule) = self.use_throw_only_error.as_ref() {
+        if let Some(rule) = self.no_unmatchable_anb_selector.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
             }
         }
-        if let Some(rule) = self.use_top_level_regex.as_ref() {
+        if let Some(rule) = self.no_unnecessary_non_null_assertion.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
             }
         }
-        if let Some(rule) = self.use_trim_start_end.as_ref() {
+        if let Some(rule) = self.no_untracked_props_access.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]));
             }
         }
-        if let Some(rule) = self.use_valid_autocomplete.as_ref() {
+        if let Some(rule) = self.no_untracked_signal_calls.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]));
             }
         }
-        index_set
-    }
-    #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
-    pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
-        Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
+        if let Some(rule) = self.no_unused_function_parameters.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]));
+            }
+        }
+        if let Some(rule) = self.no_unvalidated_env_access.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]));
+            }
+        }
+        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]));
+            }
+        }
+        if let Some(rule) = self.no_useless_string_concat.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]));
+            }
+        }
+        if let Some(rule) = self.no_useless_undefined_initialization.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]));
+            }
+        }
+        if let Some(rule) = self.no_value_at_rule.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]));
+            }
+        }
+        if let Some(rule) = self.no_vue_duplicate_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]));
+            }
+        }
+        if let Some(rule) = self.no_vue_reserved_props.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]));
+            }
+        }
+        if let Some(rule) = self.no_vue_side_effects_in_computed.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]));
+            }
+        }
+        if let Some(rule) = self.no_yoda_expression.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]));
+            }
+        }
+        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]));
+            }
+        }
+        if let Some(rule) = self.use_at_index.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_builtin_instantiation.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_generic_constructors.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_grid_areas.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_object_definitions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_record_type.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_response_handling.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]));
+            }
+        }
+        if let Some(rule) = self.use_date_now.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]));
+            }
+        }
+        if let Some(rule) = self.use_default_switch_clause.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]));
+            }
+        }
+        if let Some(rule) = self.use_deprecated_reason.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]));
+            }
+        }
+        if let Some(rule) = self.use_error_cause.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]));
+            }
+        }
+        if let Some(rule) = self.use_error_message.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]));
+            }
+        }
+        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]));
+            }
+        }
+        if let Some(rule) = self.use_explicit_length_check.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]));
+            }
+        }
+        if let Some(rule) = self.use_focusable_interactive.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]));
+            }
+        }
+        if let Some(rule) = self.use_generic_font_names.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]));
+            }
+        }
+        if let Some(rule) = self.use_guard_for_in.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]));
+            }
+        }
+        if let Some(rule) = self.use_import_extensions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]));
+            }
+        }
+        if let Some(rule) = self.use_includes.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]));
+            }
+        }
+        if let Some(rule) = self.use_js_doc_params_match.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]));
+            }
+        }
+        if let Some(rule) = self.use_number_to_fixed_digits_argument.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]));
+            }
+        }
+        if let Some(rule) = self.use_numeric_separators.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]));
+            }
+        }
+        if let Some(rule) = self.use_object_spread.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]));
+            }
+        }
+        if let Some(rule) = self.use_optimized_regex.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]));
+            }
+        }
+        if let Some(rule) = self.use_preact_signals_correctly.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]));
+            }
+        }
+        if let Some(rule) = self.use_semantic_elements.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_object_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]));
+            }
+        }
+        if let Some(rule) = self.use_spread.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]));
+            }
+        }
+        if let Some(rule) = self.use_strict_mode.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]));
+            }
+        }
+        if let Some(rule) = self.use_throw_new_error.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]));
+            }
+        }
+        if let Some(rule) = self.use_throw_only_error.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]));
+            }
+        }
+        if let Some(rule) = self.use_top_level_regex.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]));
+            }
+        }
+        if let Some(rule) = self.use_trim_start_end.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]));
+            }
+        }
+        if let Some(rule) = self.use_valid_autocomplete.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]));
+            }
+        }
+        if let Some(rule) = self.use_valid_i18n_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]));
+            }
+        }
+        index_set
+    }
+    #[doc = r" Sets the level of the rules of this group, or only of its recommended rules"]
+    #[doc = r" if `only_recommended` is `true`. The options of the rules are preserved."]
+    pub(crate) fn set_rules_level(
+        &mut self,
+        level: RulePlainConfiguration,
+        only_recommended: bool,
+    ) {
+        self.no_done_callback
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_at_import_rules
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_else_if
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_font_names
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_json_keys
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_selectors_keyframe_block
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicated_fields
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_empty_block
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_important_in_keyframe
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_invalid_direction_in_linear_gradient
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_invalid_position_at_import_rule
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_label_without_control
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_shorthand_property_overrides
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unknown_function
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unknown_property
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unknown_pseudo_class_selector
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unknown_selector_pseudo_element
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unknown_unit
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unmatchable_anb_selector
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_escape_in_regex
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_deprecated_reason
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_focusable_interactive
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_generic_font_names
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_semantic_elements
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_strict_mode
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        if !only_recommended {
+            self.no_await_in_loop
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_circular_domain_dependencies
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_comment_only_block
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_console
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_double_assertion
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_duplicate_imports_across_files
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_duplicate_layer_names
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_dynamic_namespace_import_access
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_empty_alternatives
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_evolving_types
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_excessive_important
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_exported_imports
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_implicit_spread_props
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_invalid_container_query_feature
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_irregular_whitespace
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_jsx_literals_in_attributes_needing_ids
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_leaked_event_listeners
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_misplaced_assertion
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_missing_var_function_fallback
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_mutating_props_or_state
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_non_null_asserted_optional_chain
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_proto
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_react_specific_props
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_restricted_imports
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_side_effect_imports_in_libraries
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_static_element_interactions
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_substr
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_superlinear_backtracking
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_swallowed_errors
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_translation_literals
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_undeclared_dependencies
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unhandled_rejections
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unknown_attribute
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unknown_container_name
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unknown_custom_property
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unknown_media_feature_name
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unknown_media_feature_value
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unnecessary_non_null_assertion
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_untracked_props_access
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_untracked_signal_calls
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unused_function_parameters
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_unvalidated_env_access
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_useless_string_concat
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_useless_undefined_initialization
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_value_at_rule
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_vue_duplicate_keys
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_vue_reserved_props
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_vue_side_effects_in_computed
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_yoda_expression
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_adjacent_overload_signatures
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_at_index
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_consistent_builtin_instantiation
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_consistent_curly_braces
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_consistent_generic_constructors
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_consistent_grid_areas
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_consistent_object_definitions
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_consistent_record_type
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_consistent_response_handling
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_date_now
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_default_switch_clause
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_error_cause
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_error_message
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_exhaustive_switch_cases
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_explicit_length_check
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_guard_for_in
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_import_extensions
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_import_restrictions
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_includes
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_js_doc_params_match
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_number_to_fixed_digits_argument
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_numeric_separators
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_object_spread
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_optimized_regex
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_preact_signals_correctly
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_sorted_classes
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_sorted_object_keys
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_spread
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_throw_new_error
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_throw_only_error
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_top_level_regex
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_trim_start_end
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_valid_autocomplete
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_valid_i18n_keys
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+        }
+    }
+    #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
+    pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
+        Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
     }
     #[doc = r" Checks if, given a rule name, it is marked as recommended"]
     pub(crate) fn is_recommended_rule(rule_name: &str) -> bool {
@@ -3932,242 +5769,875 @@ impl Nursery {
         rule_name: &str,
     ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
         match rule_name {
+            "noAwaitInLoop" => self
+                .no_await_in_loop
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noCircularDomainDependencies" => self
+                .no_circular_domain_dependencies
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noCommentOnlyBlock" => self
+                .no_comment_only_block
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noConsole" => self
+                .no_console
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDoneCallback" => self
+                .no_done_callback
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDoubleAssertion" => self
+                .no_double_assertion
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateAtImportRules" => self
+                .no_duplicate_at_import_rules
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateElseIf" => self
+                .no_duplicate_else_if
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateFontNames" => self
+                .no_duplicate_font_names
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateImportsAcrossFiles" => self
+                .no_duplicate_imports_across_files
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateJsonKeys" => self
+                .no_duplicate_json_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateLayerNames" => self
+                .no_duplicate_layer_names
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateSelectorsKeyframeBlock" => self
+                .no_duplicate_selectors_keyframe_block
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicatedFields" => self
+                .no_duplicated_fields
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDynamicNamespaceImportAccess" => self
+                .no_dynamic_namespace_import_access
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noEmptyAlternatives" => self
+                .no_empty_alternatives
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noEmptyBlock" => self
+                .no_empty_block
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noEvolvingTypes" => self
+                .no_evolving_types
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noExcessiveImportant" => self
+                .no_excessive_important
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noExportedImports" => self
+                .no_exported_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noImplicitSpreadProps" => self
+                .no_implicit_spread_props
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noImportantInKeyframe" => self
+                .no_important_in_keyframe
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidContainerQueryFeature" => self
+                .no_invalid_container_query_feature
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidDirectionInLinearGradient" => self
+                .no_invalid_direction_in_linear_gradient
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidPositionAtImportRule" => self
+                .no_invalid_position_at_import_rule
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noIrregularWhitespace" => self
+                .no_irregular_whitespace
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noJsxLiteralsInAttributesNeedingIds" => self
+                .no_jsx_literals_in_attributes_needing_ids
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noLabelWithoutControl" => self
+                .no_label_without_control
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noLeakedEventListeners" => self
+                .no_leaked_event_listeners
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noMisplacedAssertion" => self
+                .no_misplaced_assertion
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noMissingVarFunctionFallback" => self
+                .no_missing_var_function_fallback
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noMutatingPropsOrState" => self
+                .no_mutating_props_or_state
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noNonNullAssertedOptionalChain" => self
+                .no_non_null_asserted_optional_chain
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noProto" => self
+                .no_proto
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noReactSpecificProps" => self
+                .no_react_specific_props
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noRestrictedImports" => self
+                .no_restricted_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noShorthandPropertyOverrides" => self
+                .no_shorthand_property_overrides
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noSideEffectImportsInLibraries" => self
+                .no_side_effect_imports_in_libraries
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noStaticElementInteractions" => self
+                .no_static_element_interactions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noSubstr" => self
+                .no_substr
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noSuperlinearBacktracking" => self
+                .no_superlinear_backtracking
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noSwallowedErrors" => self
+                .no_swallowed_errors
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noTranslationLiterals" => self
+                .no_translation_literals
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUndeclaredDependencies" => self
+                .no_undeclared_dependencies
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnhandledRejections" => self
+                .no_unhandled_rejections
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownAttribute" => self
+                .no_unknown_attribute
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownContainerName" => self
+                .no_unknown_container_name
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownCustomProperty" => self
+                .no_unknown_custom_property
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownFunction" => self
+                .no_unknown_function
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownMediaFeatureName" => self
+                .no_unknown_media_feature_name
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownMediaFeatureValue" => self
+                .no_unknown_media_feature_value
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownProperty" => self
+                .no_unknown_property
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownPseudoClassSelector" => self
+                .no_unknown_pseudo_class_selector
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownSelectorPseudoElement" => self
+                .no_unknown_selector_pseudo_element
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownUnit" => self
+                .no_unknown_unit
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnmatchableAnbSelector" => self
+                .no_unmatchable_anb_selector
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnnecessaryNonNullAssertion" => self
+                .no_unnecessary_non_null_assertion
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUntrackedPropsAccess" => self
+                .no_untracked_props_access
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUntrackedSignalCalls" => self
+                .no_untracked_signal_calls
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedFunctionParameters" => self
+                .no_unused_function_parameters
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnvalidatedEnvAccess" => self
+                .no_unvalidated_env_access
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUselessEscapeInRegex" => self
+                .no_useless_escape_in_regex
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUselessStringConcat" => self
+                .no_useless_string_concat
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUselessUndefinedInitialization" => self
+                .no_useless_undefined_initialization
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noValueAtRule" => self
+                .no_value_at_rule
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noVueDuplicateKeys" => self
+                .no_vue_duplicate_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noVueReservedProps" => self
+                .no_vue_reserved_props
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noVueSideEffectsInComputed" => self
+                .no_vue_side_effects_in_computed
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noYodaExpression" => self
+                .no_yoda_expression
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useAdjacentOverloadSignatures" => self
+                .use_adjacent_overload_signatures
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useAtIndex" => self
+                .use_at_index
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentBuiltinInstantiation" => self
+                .use_consistent_builtin_instantiation
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentCurlyBraces" => self
+                .use_consistent_curly_braces
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentGenericConstructors" => self
+                .use_consistent_generic_constructors
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentGridAreas" => self
+                .use_consistent_grid_areas
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentObjectDefinitions" => self
+                .use_consistent_object_definitions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentRecordType" => self
+                .use_consistent_record_type
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentResponseHandling" => self
+                .use_consistent_response_handling
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useDateNow" => self
+                .use_date_now
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useDefaultSwitchClause" => self
+                .use_default_switch_clause
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useDeprecatedReason" => self
+                .use_deprecated_reason
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useErrorCause" => self
+                .use_error_cause
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useErrorMessage" => self
+                .use_error_message
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useExhaustiveSwitchCases" => self
+                .use_exhaustive_switch_cases
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useExplicitLengthCheck" => self
+                .use_explicit_length_check
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useFocusableInteractive" => self
+                .use_focusable_interactive
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useGenericFontNames" => self
+                .use_generic_font_names
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useGuardForIn" => self
+                .use_guard_for_in
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useImportExtensions" => self
+                .use_import_extensions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useImportRestrictions" => self
+                .use_import_restrictions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useIncludes" => self
+                .use_includes
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useJsDocParamsMatch" => self
+                .use_js_doc_params_match
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useNumberToFixedDigitsArgument" => self
+                .use_number_to_fixed_digits_argument
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useNumericSeparators" => self
+                .use_numeric_separators
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useObjectSpread" => self
+                .use_object_spread
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useOptimizedRegex" => self
+                .use_optimized_regex
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "usePreactSignalsCorrectly" => self
+                .use_preact_signals_correctly
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSemanticElements" => self
+                .use_semantic_elements
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedClasses" => self
+                .use_sorted_classes
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedObjectKeys" => self
+                .use_sorted_object_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSpread" => self
+                .use_spread
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useStrictMode" => self
+                .use_strict_mode
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useThrowNewError" => self
+                .use_throw_new_error
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useThrowOnlyError" => self
+                .use_throw_only_error
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useTopLevelRegex" => self
+                .use_top_level_regex
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useTrimStartEnd" => self
+                .use_trim_start_end
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useValidAutocomplete" => self
+                .use_valid_autocomplete
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useValidI18nKeys" => self
+                .use_valid_i18n_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            _ => None,
+        }
+    }
+    pub(crate) fn get_rule_files(&self, rule_name: &str) -> Option<RuleFiles> {
+        match rule_name {
+            "noAwaitInLoop" => self
+                .no_await_in_loop
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noCircularDomainDependencies" => self
+                .no_circular_domain_dependencies
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noCommentOnlyBlock" => self
+                .no_comment_only_block
+                .as_ref()
+                .map(|conf| conf.files()),
             "noConsole" => self
                 .no_console
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noDoneCallback" => self
                 .no_done_callback
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noDoubleAssertion" => self
+                .no_double_assertion
+                .as_ref()
+                .map(|conf| conf.files()),
             "noDuplicateAtImportRules" => self
                 .no_duplicate_at_import_rules
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noDuplicateElseIf" => self
                 .no_duplicate_else_if
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noDuplicateFontNames" => self
                 .no_duplicate_font_names
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noDuplicateImportsAcrossFiles" => self
+                .no_duplicate_imports_across_files
+                .as_ref()
+                .map(|conf| conf.files()),
             "noDuplicateJsonKeys" => self
                 .no_duplicate_json_keys
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noDuplicateLayerNames" => self
+                .no_duplicate_layer_names
+                .as_ref()
+                .map(|conf| conf.files()),
             "noDuplicateSelectorsKeyframeBlock" => self
                 .no_duplicate_selectors_keyframe_block
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noDuplicatedFields" => self
                 .no_duplicated_fields
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noDynamicNamespaceImportAccess" => self
                 .no_dynamic_namespace_import_access
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noEmptyAlternatives" => self
+                .no_empty_alternatives
+                .as_ref()
+                .map(|conf| conf.files()),
             "noEmptyBlock" => self
                 .no_empty_block
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noEvolvingTypes" => self
                 .no_evolving_types
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noExcessiveImportant" => self
+                .no_excessive_important
+                .as_ref()
+                .map(|conf| conf.files()),
             "noExportedImports" => self
                 .no_exported_imports
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noImplicitSpreadProps" => self
+                .no_implicit_spread_props
+                .as_ref()
+                .map(|conf| conf.files()),
             "noImportantInKeyframe" => self
                 .no_important_in_keyframe
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noInvalidContainerQueryFeature" => self
+                .no_invalid_container_query_feature
+                .as_ref()
+                .map(|conf| conf.files()),
             "noInvalidDirectionInLinearGradient" => self
                 .no_invalid_direction_in_linear_gradient
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noInvalidPositionAtImportRule" => self
                 .no_invalid_position_at_import_rule
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noIrregularWhitespace" => self
                 .no_irregular_whitespace
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noJsxLiteralsInAttributesNeedingIds" => self
+                .no_jsx_literals_in_attributes_needing_ids
+                .as_ref()
+                .map(|conf| conf.files()),
             "noLabelWithoutControl" => self
                 .no_label_without_control
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noLeakedEventListeners" => self
+                .no_leaked_event_listeners
+                .as_ref()
+                .map(|conf| conf.files()),
             "noMisplacedAssertion" => self
                 .no_misplaced_assertion
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noMissingVarFunctionFallback" => self
+                .no_missing_var_function_fallback
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noMutatingPropsOrState" => self
+                .no_mutating_props_or_state
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noNonNullAssertedOptionalChain" => self
+                .no_non_null_asserted_optional_chain
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noProto" => self
+                .no_proto
+                .as_ref()
+                .map(|conf| conf.files()),
             "noReactSpecificProps" => self
                 .no_react_specific_props
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noRestrictedImports" => self
                 .no_restricted_imports
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noShorthandPropertyOverrides" => self
                 .no_shorthand_property_overrides
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noSideEffectImportsInLibraries" => self
+                .no_side_effect_imports_in_libraries
+                .as_ref()
+                .map(|conf| conf.files()),
             "noStaticElementInteractions" => self
                 .no_static_element_interactions
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noSubstr" => self
                 .no_substr
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noSuperlinearBacktracking" => self
+                .no_superlinear_backtracking
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noSwallowedErrors" => self
+                .no_swallowed_errors
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noTranslationLiterals" => self
+                .no_translation_literals
+                .as_ref()
+                .map(|conf| conf.files()),
             "noUndeclaredDependencies" => self
                 .no_undeclared_dependencies
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noUnhandledRejections" => self
+                .no_unhandled_rejections
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUnknownAttribute" => self
+                .no_unknown_attribute
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUnknownContainerName" => self
+                .no_unknown_container_name
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUnknownCustomProperty" => self
+                .no_unknown_custom_property
+                .as_ref()
+                .map(|conf| conf.files()),
             "noUnknownFunction" => self
                 .no_unknown_function
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noUnknownMediaFeatureName" => self
                 .no_unknown_media_feature_name
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noUnknownMediaFeatureValue" => self
+                .no_unknown_media_feature_value
+                .as_ref()
+                .map(|conf| conf.files()),
             "noUnknownProperty" => self
                 .no_unknown_property
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noUnknownPseudoClassSelector" => self
                 .no_unknown_pseudo_class_selector
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noUnknownSelectorPseudoElement" => self
                 .no_unknown_selector_pseudo_element
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noUnknownUnit" => self
                 .no_unknown_unit
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noUnmatchableAnbSelector" => self
                 .no_unmatchable_anb_selector
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noUnnecessaryNonNullAssertion" => self
+                .no_unnecessary_non_null_assertion
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUntrackedPropsAccess" => self
+                .no_untracked_props_access
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUntrackedSignalCalls" => self
+                .no_untracked_signal_calls
+                .as_ref()
+                .map(|conf| conf.files()),
             "noUnusedFunctionParameters" => self
                 .no_unused_function_parameters
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noUnvalidatedEnvAccess" => self
+                .no_unvalidated_env_access
+                .as_ref()
+                .map(|conf| conf.files()),
             "noUselessEscapeInRegex" => self
                 .no_useless_escape_in_regex
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noUselessStringConcat" => self
                 .no_useless_string_concat
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noUselessUndefinedInitialization" => self
                 .no_useless_undefined_initialization
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "noValueAtRule" => self
                 .no_value_at_rule
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "noVueDuplicateKeys" => self
+                .no_vue_duplicate_keys
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noVueReservedProps" => self
+                .no_vue_reserved_props
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noVueSideEffectsInComputed" => self
+                .no_vue_side_effects_in_computed
+                .as_ref()
+                .map(|conf| conf.files()),
             "noYodaExpression" => self
                 .no_yoda_expression
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useAdjacentOverloadSignatures" => self
                 .use_adjacent_overload_signatures
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "useAtIndex" => self
+                .use_at_index
+                .as_ref()
+                .map(|conf| conf.files()),
             "useConsistentBuiltinInstantiation" => self
                 .use_consistent_builtin_instantiation
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useConsistentCurlyBraces" => self
                 .use_consistent_curly_braces
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "useConsistentGenericConstructors" => self
+                .use_consistent_generic_constructors
+                .as_ref()
+                .map(|conf| conf.files()),
             "useConsistentGridAreas" => self
                 .use_consistent_grid_areas
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "useConsistentObjectDefinitions" => self
+                .use_consistent_object_definitions
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useConsistentRecordType" => self
+                .use_consistent_record_type
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useConsistentResponseHandling" => self
+                .use_consistent_response_handling
+                .as_ref()
+                .map(|conf| conf.files()),
             "useDateNow" => self
                 .use_date_now
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useDefaultSwitchClause" => self
                 .use_default_switch_clause
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useDeprecatedReason" => self
                 .use_deprecated_reason
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "useErrorCause" => self
+                .use_error_cause
+                .as_ref()
+                .map(|conf| conf.files()),
             "useErrorMessage" => self
                 .use_error_message
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "useExhaustiveSwitchCases" => self
+                .use_exhaustive_switch_cases
+                .as_ref()
+                .map(|conf| conf.files()),
             "useExplicitLengthCheck" => self
                 .use_explicit_length_check
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useFocusableInteractive" => self
                 .use_focusable_interactive
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useGenericFontNames" => self
                 .use_generic_font_names
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "useGuardForIn" => self
+                .use_guard_for_in
+                .as_ref()
+                .map(|conf| conf.files()),
             "useImportExtensions" => self
                 .use_import_extensions
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useImportRestrictions" => self
                 .use_import_restrictions
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "useIncludes" => self
+                .use_includes
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useJsDocParamsMatch" => self
+                .use_js_doc_params_match
+                .as_ref()
+                .map(|conf| conf.files()),
             "useNumberToFixedDigitsArgument" => self
                 .use_number_to_fixed_digits_argument
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "useNumericSeparators" => self
+                .use_numeric_separators
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useObjectSpread" => self
+                .use_object_spread
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useOptimizedRegex" => self
+                .use_optimized_regex
+                .as_ref()
+                .map(|conf| conf.files()),
+            "usePreactSignalsCorrectly" => self
+                .use_preact_signals_correctly
+                .as_ref()
+                .map(|conf| conf.files()),
             "useSemanticElements" => self
                 .use_semantic_elements
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useSortedClasses" => self
                 .use_sorted_classes
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "useSortedObjectKeys" => self
+                .use_sorted_object_keys
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useSpread" => self
+                .use_spread
+                .as_ref()
+                .map(|conf| conf.files()),
             "useStrictMode" => self
                 .use_strict_mode
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useThrowNewError" => self
                 .use_throw_new_error
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useThrowOnlyError" => self
                 .use_throw_only_error
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useTopLevelRegex" => self
                 .use_top_level_regex
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useTrimStartEnd" => self
                 .use_trim_start_end
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
             "useValidAutocomplete" => self
                 .use_valid_autocomplete
                 .as_ref()
-                .map(|conf| (conf.level(), conf.get_options())),
+                .map(|conf| conf.files()),
+            "useValidI18nKeys" => self
+                .use_valid_i18n_keys
+                .as_ref()
+                .map(|conf| conf.files()),
             _ => None,
         }
     }
@@ -4196,7 +6666,7 @@ pub struct Performance {
     pub no_delete: Option<RuleFixConfiguration<biome_js_analyze::options::NoDelete>>,
     #[doc = "Avoid re-export all."]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub no_re_export_all: Option<RuleConfiguration<biome_js_analyze::options::NoReExportAll>>,
+    pub no_re_export_all: Option<RuleFixConfiguration<biome_js_analyze::options::NoReExportAll>>,
 }
 impl DeserializableValidator for Performance {
     fn validate(
@@ -4292,6 +6762,28 @@ impl Performance {
         }
         index_set
     }
+    #[doc = r" Sets the level of the rules of this group, or only of its recommended rules"]
+    #[doc = r" if `only_recommended` is `true`. The options of the rules are preserved."]
+    pub(crate) fn set_rules_level(
+        &mut self,
+        level: RulePlainConfiguration,
+        only_recommended: bool,
+    ) {
+        self.no_accumulating_spread
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_delete
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        if !only_recommended {
+            self.no_barrel_file
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_re_export_all
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+        }
+    }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
     pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
         Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
@@ -4345,6 +6837,18 @@ impl Performance {
             _ => None,
         }
     }
+    pub(crate) fn get_rule_files(&self, rule_name: &str) -> Option<RuleFiles> {
+        match rule_name {
+            "noAccumulatingSpread" => self
+                .no_accumulating_spread
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noBarrelFile" => self.no_barrel_file.as_ref().map(|conf| conf.files()),
+            "noDelete" => self.no_delete.as_ref().map(|conf| conf.files()),
+            "noReExportAll" => self.no_re_export_all.as_ref().map(|conf| conf.files()),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
 #[deserializable(with_validator)]
@@ -4457,6 +6961,24 @@ impl Security {
         }
         index_set
     }
+    #[doc = r" Sets the level of the rules of this group, or only of its recommended rules"]
+    #[doc = r" if `only_recommended` is `true`. The options of the rules are preserved."]
+    pub(crate) fn set_rules_level(
+        &mut self,
+        level: RulePlainConfiguration,
+        only_recommended: bool,
+    ) {
+        self.no_dangerously_set_inner_html
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_dangerously_set_inner_html_with_children
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_global_eval
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        if !only_recommended {}
+    }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
     pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
         Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
@@ -4506,6 +7028,20 @@ impl Security {
             _ => None,
         }
     }
+    pub(crate) fn get_rule_files(&self, rule_name: &str) -> Option<RuleFiles> {
+        match rule_name {
+            "noDangerouslySetInnerHtml" => self
+                .no_dangerously_set_inner_html
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noDangerouslySetInnerHtmlWithChildren" => self
+                .no_dangerously_set_inner_html_with_children
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noGlobalEval" => self.no_global_eval.as_ref().map(|conf| conf.files()),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
 #[deserializable(with_validator)]
@@ -4553,7 +7089,7 @@ pub struct Style {
     #[doc = "Disallow reassigning function parameters."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_parameter_assign:
-        Option<RuleConfiguration<biome_js_analyze::options::NoParameterAssign>>,
+        Option<RuleFixConfiguration<biome_js_analyze::options::NoParameterAssign>>,
     #[doc = "Disallow the use of parameter properties in class constructors."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_parameter_properties:
@@ -5288,6 +7824,145 @@ impl Style {
         }
         index_set
     }
+    #[doc = r" Sets the level of the rules of this group, or only of its recommended rules"]
+    #[doc = r" if `only_recommended` is `true`. The options of the rules are preserved."]
+    pub(crate) fn set_rules_level(
+        &mut self,
+        level: RulePlainConfiguration,
+        only_recommended: bool,
+    ) {
+        self.no_arguments
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_comma_operator
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_inferrable_types
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_non_null_assertion
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_parameter_assign
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unused_template_literal
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_useless_else
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_var
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_as_const_assertion
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_const
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_default_parameter_last
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_enum_initializers
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_exponentiation_operator
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_export_type
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_import_type
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_literal_enum_members
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_nodejs_import_protocol
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_number_namespace
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_numeric_literals
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_self_closing_elements
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_shorthand_function_type
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_single_var_declarator
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_template
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_while
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        if !only_recommended {
+            self.no_default_export
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_implicit_boolean
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_namespace
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_namespace_import
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_negation_else
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_parameter_properties
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_restricted_globals
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_shouty_constants
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_block_statements
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_collapsed_else_if
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_consistent_array_type
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_filenaming_convention
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_for_of
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_fragment_syntax
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_naming_convention
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_node_assert_strict
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_shorthand_array_type
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_shorthand_assign
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_single_case_statement
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+        }
+    }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
     pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
         Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
@@ -5497,6 +8172,99 @@ impl Style {
             _ => None,
         }
     }
+    pub(crate) fn get_rule_files(&self, rule_name: &str) -> Option<RuleFiles> {
+        match rule_name {
+            "noArguments" => self.no_arguments.as_ref().map(|conf| conf.files()),
+            "noCommaOperator" => self.no_comma_operator.as_ref().map(|conf| conf.files()),
+            "noDefaultExport" => self.no_default_export.as_ref().map(|conf| conf.files()),
+            "noImplicitBoolean" => self.no_implicit_boolean.as_ref().map(|conf| conf.files()),
+            "noInferrableTypes" => self.no_inferrable_types.as_ref().map(|conf| conf.files()),
+            "noNamespace" => self.no_namespace.as_ref().map(|conf| conf.files()),
+            "noNamespaceImport" => self.no_namespace_import.as_ref().map(|conf| conf.files()),
+            "noNegationElse" => self.no_negation_else.as_ref().map(|conf| conf.files()),
+            "noNonNullAssertion" => self.no_non_null_assertion.as_ref().map(|conf| conf.files()),
+            "noParameterAssign" => self.no_parameter_assign.as_ref().map(|conf| conf.files()),
+            "noParameterProperties" => self
+                .no_parameter_properties
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noRestrictedGlobals" => self.no_restricted_globals.as_ref().map(|conf| conf.files()),
+            "noShoutyConstants" => self.no_shouty_constants.as_ref().map(|conf| conf.files()),
+            "noUnusedTemplateLiteral" => self
+                .no_unused_template_literal
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUselessElse" => self.no_useless_else.as_ref().map(|conf| conf.files()),
+            "noVar" => self.no_var.as_ref().map(|conf| conf.files()),
+            "useAsConstAssertion" => self
+                .use_as_const_assertion
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useBlockStatements" => self.use_block_statements.as_ref().map(|conf| conf.files()),
+            "useCollapsedElseIf" => self.use_collapsed_else_if.as_ref().map(|conf| conf.files()),
+            "useConsistentArrayType" => self
+                .use_consistent_array_type
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useConst" => self.use_const.as_ref().map(|conf| conf.files()),
+            "useDefaultParameterLast" => self
+                .use_default_parameter_last
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useEnumInitializers" => self.use_enum_initializers.as_ref().map(|conf| conf.files()),
+            "useExponentiationOperator" => self
+                .use_exponentiation_operator
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useExportType" => self.use_export_type.as_ref().map(|conf| conf.files()),
+            "useFilenamingConvention" => self
+                .use_filenaming_convention
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useForOf" => self.use_for_of.as_ref().map(|conf| conf.files()),
+            "useFragmentSyntax" => self.use_fragment_syntax.as_ref().map(|conf| conf.files()),
+            "useImportType" => self.use_import_type.as_ref().map(|conf| conf.files()),
+            "useLiteralEnumMembers" => self
+                .use_literal_enum_members
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useNamingConvention" => self.use_naming_convention.as_ref().map(|conf| conf.files()),
+            "useNodeAssertStrict" => self
+                .use_node_assert_strict
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useNodejsImportProtocol" => self
+                .use_nodejs_import_protocol
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useNumberNamespace" => self.use_number_namespace.as_ref().map(|conf| conf.files()),
+            "useNumericLiterals" => self.use_numeric_literals.as_ref().map(|conf| conf.files()),
+            "useSelfClosingElements" => self
+                .use_self_closing_elements
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useShorthandArrayType" => self
+                .use_shorthand_array_type
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useShorthandAssign" => self.use_shorthand_assign.as_ref().map(|conf| conf.files()),
+            "useShorthandFunctionType" => self
+                .use_shorthand_function_type
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useSingleCaseStatement" => self
+                .use_single_case_statement
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useSingleVarDeclarator" => self
+                .use_single_var_declarator
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useTemplate" => self.use_template.as_ref().map(|conf| conf.files()),
+            "useWhile" => self.use_while.as_ref().map(|conf| conf.files()),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
 #[deserializable(with_validator)]
@@ -5647,7 +8415,7 @@ pub struct Suspicious {
     #[doc = "Disallow direct use of Object.prototype builtins."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_prototype_builtins:
-        Option<RuleConfiguration<biome_js_analyze::options::NoPrototypeBuiltins>>,
+        Option<RuleFixConfiguration<biome_js_analyze::options::NoPrototypeBuiltins>>,
     #[doc = "Disallow variable, function, class, and type redeclarations in the same scope."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_redeclare: Option<RuleConfiguration<biome_js_analyze::options::NoRedeclare>>,
@@ -6511,6 +9279,181 @@ impl Suspicious {
         }
         index_set
     }
+    #[doc = r" Sets the level of the rules of this group, or only of its recommended rules"]
+    #[doc = r" if `only_recommended` is `true`. The options of the rules are preserved."]
+    pub(crate) fn set_rules_level(
+        &mut self,
+        level: RulePlainConfiguration,
+        only_recommended: bool,
+    ) {
+        self.no_approximative_numeric_constant
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_array_index_key
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_assign_in_expressions
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_async_promise_executor
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_catch_assign
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_class_assign
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_comment_text
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_compare_neg_zero
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_confusing_labels
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_confusing_void_type
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_const_enum
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_control_characters_in_regex
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_debugger
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_double_equals
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_case
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_class_members
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_jsx_props
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_object_keys
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_parameters
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_duplicate_test_hooks
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_empty_interface
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_explicit_any
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_exports_in_test
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_extra_non_null_assertion
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_fallthrough_switch_clause
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_focused_tests
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_function_assign
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_global_assign
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_global_is_finite
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_global_is_nan
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_implicit_any_let
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_import_assign
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_label_var
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_misleading_character_class
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_misleading_instantiator
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_misrefactored_shorthand_assign
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_prototype_builtins
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_redeclare
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_redundant_use_strict
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_self_compare
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_shadow_restricted_names
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_sparse_array
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_suspicious_semicolon_in_jsx
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_then_property
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unsafe_declaration_merging
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.no_unsafe_negation
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_default_switch_clause_last
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_getter_return
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_is_array
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_namespace_keyword
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        self.use_valid_typeof
+            .get_or_insert_with(Default::default)
+            .set_level(level);
+        if !only_recommended {
+            self.no_console_log
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_empty_block_statements
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.no_skipped_tests
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+            self.use_await
+                .get_or_insert_with(Default::default)
+                .set_level(level);
+        }
+    }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
     pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
         Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
@@ -6768,6 +9711,129 @@ impl Suspicious {
             _ => None,
         }
     }
+    pub(crate) fn get_rule_files(&self, rule_name: &str) -> Option<RuleFiles> {
+        match rule_name {
+            "noApproximativeNumericConstant" => self
+                .no_approximative_numeric_constant
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noArrayIndexKey" => self.no_array_index_key.as_ref().map(|conf| conf.files()),
+            "noAssignInExpressions" => self
+                .no_assign_in_expressions
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noAsyncPromiseExecutor" => self
+                .no_async_promise_executor
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noCatchAssign" => self.no_catch_assign.as_ref().map(|conf| conf.files()),
+            "noClassAssign" => self.no_class_assign.as_ref().map(|conf| conf.files()),
+            "noCommentText" => self.no_comment_text.as_ref().map(|conf| conf.files()),
+            "noCompareNegZero" => self.no_compare_neg_zero.as_ref().map(|conf| conf.files()),
+            "noConfusingLabels" => self.no_confusing_labels.as_ref().map(|conf| conf.files()),
+            "noConfusingVoidType" => self
+                .no_confusing_void_type
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noConsoleLog" => self.no_console_log.as_ref().map(|conf| conf.files()),
+            "noConstEnum" => self.no_const_enum.as_ref().map(|conf| conf.files()),
+            "noControlCharactersInRegex" => self
+                .no_control_characters_in_regex
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noDebugger" => self.no_debugger.as_ref().map(|conf| conf.files()),
+            "noDoubleEquals" => self.no_double_equals.as_ref().map(|conf| conf.files()),
+            "noDuplicateCase" => self.no_duplicate_case.as_ref().map(|conf| conf.files()),
+            "noDuplicateClassMembers" => self
+                .no_duplicate_class_members
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noDuplicateJsxProps" => self
+                .no_duplicate_jsx_props
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noDuplicateObjectKeys" => self
+                .no_duplicate_object_keys
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noDuplicateParameters" => self
+                .no_duplicate_parameters
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noDuplicateTestHooks" => self
+                .no_duplicate_test_hooks
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noEmptyBlockStatements" => self
+                .no_empty_block_statements
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noEmptyInterface" => self.no_empty_interface.as_ref().map(|conf| conf.files()),
+            "noExplicitAny" => self.no_explicit_any.as_ref().map(|conf| conf.files()),
+            "noExportsInTest" => self.no_exports_in_test.as_ref().map(|conf| conf.files()),
+            "noExtraNonNullAssertion" => self
+                .no_extra_non_null_assertion
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noFallthroughSwitchClause" => self
+                .no_fallthrough_switch_clause
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noFocusedTests" => self.no_focused_tests.as_ref().map(|conf| conf.files()),
+            "noFunctionAssign" => self.no_function_assign.as_ref().map(|conf| conf.files()),
+            "noGlobalAssign" => self.no_global_assign.as_ref().map(|conf| conf.files()),
+            "noGlobalIsFinite" => self.no_global_is_finite.as_ref().map(|conf| conf.files()),
+            "noGlobalIsNan" => self.no_global_is_nan.as_ref().map(|conf| conf.files()),
+            "noImplicitAnyLet" => self.no_implicit_any_let.as_ref().map(|conf| conf.files()),
+            "noImportAssign" => self.no_import_assign.as_ref().map(|conf| conf.files()),
+            "noLabelVar" => self.no_label_var.as_ref().map(|conf| conf.files()),
+            "noMisleadingCharacterClass" => self
+                .no_misleading_character_class
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noMisleadingInstantiator" => self
+                .no_misleading_instantiator
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noMisrefactoredShorthandAssign" => self
+                .no_misrefactored_shorthand_assign
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noPrototypeBuiltins" => self.no_prototype_builtins.as_ref().map(|conf| conf.files()),
+            "noRedeclare" => self.no_redeclare.as_ref().map(|conf| conf.files()),
+            "noRedundantUseStrict" => self
+                .no_redundant_use_strict
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noSelfCompare" => self.no_self_compare.as_ref().map(|conf| conf.files()),
+            "noShadowRestrictedNames" => self
+                .no_shadow_restricted_names
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noSkippedTests" => self.no_skipped_tests.as_ref().map(|conf| conf.files()),
+            "noSparseArray" => self.no_sparse_array.as_ref().map(|conf| conf.files()),
+            "noSuspiciousSemicolonInJsx" => self
+                .no_suspicious_semicolon_in_jsx
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noThenProperty" => self.no_then_property.as_ref().map(|conf| conf.files()),
+            "noUnsafeDeclarationMerging" => self
+                .no_unsafe_declaration_merging
+                .as_ref()
+                .map(|conf| conf.files()),
+            "noUnsafeNegation" => self.no_unsafe_negation.as_ref().map(|conf| conf.files()),
+            "useAwait" => self.use_await.as_ref().map(|conf| conf.files()),
+            "useDefaultSwitchClauseLast" => self
+                .use_default_switch_clause_last
+                .as_ref()
+                .map(|conf| conf.files()),
+            "useGetterReturn" => self.use_getter_return.as_ref().map(|conf| conf.files()),
+            "useIsArray" => self.use_is_array.as_ref().map(|conf| conf.files()),
+            "useNamespaceKeyword" => self.use_namespace_keyword.as_ref().map(|conf| conf.files()),
+            "useValidTypeof" => self.use_valid_typeof.as_ref().map(|conf| conf.files()),
+            _ => None,
+        }
+    }
 }
 #[test]
 fn test_order() {
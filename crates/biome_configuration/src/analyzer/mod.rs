@@ -5,7 +5,7 @@ pub use crate::analyzer::linter::*;
 use biome_analyze::options::RuleOptions;
 use biome_analyze::{FixKind, RuleFilter};
 use biome_deserialize::{Deserializable, DeserializableType};
-use biome_deserialize::{DeserializableValue, DeserializationDiagnostic, Merge};
+use biome_deserialize::{DeserializableValue, DeserializationDiagnostic, Merge, StringSet};
 use biome_deserialize_macros::Deserializable;
 use biome_diagnostics::Severity;
 #[cfg(feature = "schema")]
@@ -55,6 +55,15 @@ impl<T: Default> RuleConfiguration<T> {
             Self::WithOptions(options) => options.level = level,
         }
     }
+    pub fn files(&self) -> RuleFiles {
+        match self {
+            Self::Plain(_) => RuleFiles::default(),
+            Self::WithOptions(options) => RuleFiles {
+                includes: options.includes.as_ref(),
+                excludes: options.excludes.as_ref(),
+            },
+        }
+    }
 }
 
 // Rule configuration has a custom [Merge] implementation so that overriding the
@@ -138,6 +147,15 @@ impl<T: Default> RuleFixConfiguration<T> {
             Self::WithOptions(options) => options.level = level,
         }
     }
+    pub fn files(&self) -> RuleFiles {
+        match self {
+            Self::Plain(_) => RuleFiles::default(),
+            Self::WithOptions(options) => RuleFiles {
+                includes: options.includes.as_ref(),
+                excludes: options.excludes.as_ref(),
+            },
+        }
+    }
 }
 
 // Rule configuration has a custom [Merge] implementation so that overriding the
@@ -241,12 +259,27 @@ impl Merge for RuleAssistConfiguration {
     }
 }
 
+/// The glob patterns that restrict the files a rule runs on
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RuleFiles<'a> {
+    /// The rule only runs on the files that match these patterns
+    pub includes: Option<&'a StringSet>,
+    /// The rule doesn't run on the files that match these patterns
+    pub excludes: Option<&'a StringSet>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct RuleWithOptions<T: Default> {
     /// The severity of the emitted diagnostics by the rule
     pub level: RulePlainConfiguration,
+    /// A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub includes: Option<StringSet>,
+    /// A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excludes: Option<StringSet>,
     /// Rule's options
     pub options: T,
 }
@@ -254,6 +287,8 @@ pub struct RuleWithOptions<T: Default> {
 impl<T: Default> Merge for RuleWithOptions<T> {
     fn merge_with(&mut self, other: Self) {
         self.level = other.level;
+        self.includes = other.includes.or(self.includes.take());
+        self.excludes = other.excludes.or(self.excludes.take());
         self.options = other.options;
     }
 }
@@ -267,6 +302,12 @@ pub struct RuleWithFixOptions<T: Default> {
     /// The kind of the code actions emitted by the rule
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fix: Option<FixKind>,
+    /// A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub includes: Option<StringSet>,
+    /// A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excludes: Option<StringSet>,
     /// Rule's options
     pub options: T,
 }
@@ -275,6 +316,8 @@ impl<T: Default> Merge for RuleWithFixOptions<T> {
     fn merge_with(&mut self, other: Self) {
         self.level = other.level;
         self.fix = other.fix.or(self.fix);
+        self.includes = other.includes.or(self.includes.take());
+        self.excludes = other.excludes.or(self.excludes.take());
         self.options = other.options;
     }
 }
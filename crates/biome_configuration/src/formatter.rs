@@ -1,10 +1,12 @@
 use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{
-    AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    AttributePosition, BracketSpacing, CommentsOptions, IndentStyle, IndentWidth, LineEnding,
+    LineWidth,
 };
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::num::NonZeroU32;
 use std::str::FromStr;
 
 /// Generic options applied to all files
@@ -26,6 +28,11 @@ pub struct FormatterConfiguration {
     #[partial(bpaf(hide))]
     pub format_with_errors: bool,
 
+    /// The maximum number of syntax nodes of the files that are formatted. The files above this
+    /// limit aren't formatted, and a diagnostic is emitted. No limit by default.
+    #[partial(bpaf(long("max-complexity"), argument("NUMBER"), optional))]
+    pub max_complexity: Option<NonZeroU32>,
+
     /// The indent style.
     #[partial(bpaf(long("indent-style"), argument("tab|space"), optional))]
     pub indent_style: IndentStyle,
@@ -55,6 +62,10 @@ pub struct FormatterConfiguration {
     #[partial(bpaf(long("bracket-spacing"), argument("true|false"), optional))]
     pub bracket_spacing: BracketSpacing,
 
+    /// How the formatter normalizes the comments. Only applies to the JavaScript files.
+    #[partial(type, bpaf(external(partial_comments_configuration), optional))]
+    pub comments: CommentsConfiguration,
+
     /// A list of Unix shell style patterns. The formatter will ignore files/folders that will
     /// match these patterns.
     #[partial(bpaf(hide))]
@@ -75,6 +86,7 @@ impl PartialFormatterConfiguration {
         FormatterConfiguration {
             enabled: self.enabled.unwrap_or_default(),
             format_with_errors: self.format_with_errors.unwrap_or_default(),
+            max_complexity: self.max_complexity,
             indent_style: self.indent_style.unwrap_or_default(),
             indent_size: self.indent_size.unwrap_or_default(),
             indent_width: self.indent_width.unwrap_or_default(),
@@ -82,6 +94,7 @@ impl PartialFormatterConfiguration {
             line_width: self.line_width.unwrap_or_default(),
             attribute_position: self.attribute_position.unwrap_or_default(),
             bracket_spacing: self.bracket_spacing.unwrap_or_default(),
+            comments: self.comments.clone().map(Into::into).unwrap_or_default(),
             ignore: self.ignore.clone().unwrap_or_default(),
             include: self.include.clone().unwrap_or_default(),
             use_editorconfig: self.use_editorconfig.unwrap_or_default(),
@@ -94,6 +107,7 @@ impl Default for FormatterConfiguration {
         Self {
             enabled: true,
             format_with_errors: false,
+            max_complexity: None,
             indent_size: IndentWidth::default(),
             indent_width: IndentWidth::default(),
             indent_style: IndentStyle::default(),
@@ -101,6 +115,7 @@ impl Default for FormatterConfiguration {
             line_width: LineWidth::default(),
             attribute_position: AttributePosition::default(),
             bracket_spacing: Default::default(),
+            comments: Default::default(),
             ignore: Default::default(),
             include: Default::default(),
             // TODO: Biome 2.0: change to true
@@ -109,6 +124,36 @@ impl Default for FormatterConfiguration {
     }
 }
 
+/// The normalizations of the comments. They are all disabled by default.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct CommentsConfiguration {
+    /// Insert a space after the `//` of the line comments, such as `//comment`. Defaults to false.
+    #[partial(bpaf(long("comments-line-comment-space"), argument("true|false"), optional))]
+    pub line_comment_space: bool,
+
+    /// Start every line of the multiline documentation comments `/** */` with a `*`,
+    /// and put the delimiters of the comments on their own line. Defaults to false.
+    #[partial(bpaf(long("comments-doc-comment-style"), argument("true|false"), optional))]
+    pub doc_comment_style: bool,
+
+    /// Reflow the paragraphs of the multiline block comments to the line width. Defaults to false.
+    #[partial(bpaf(long("comments-reflow"), argument("true|false"), optional))]
+    pub reflow: bool,
+}
+
+impl From<CommentsConfiguration> for CommentsOptions {
+    fn from(configuration: CommentsConfiguration) -> Self {
+        Self {
+            line_comment_space: configuration.line_comment_space,
+            doc_comment_style: configuration.doc_comment_style,
+            reflow: configuration.reflow,
+        }
+    }
+}
+
 /// Required by [Bpaf].
 impl FromStr for FormatterConfiguration {
     type Err = &'static str;
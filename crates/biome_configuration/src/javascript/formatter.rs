@@ -3,7 +3,8 @@ use biome_formatter::{
     AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteStyle,
 };
 use biome_js_formatter::context::{
-    trailing_commas::TrailingCommas, ArrowParentheses, QuoteProperties, Semicolons,
+    trailing_commas::TrailingCommas, ArrowParentheses, EmbeddedLanguageFormatting, QuoteProperties,
+    Semicolons, StringEscapes,
 };
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
@@ -101,6 +102,50 @@ pub struct JavascriptFormatter {
     /// Whether to insert spaces around brackets in object literals. Defaults to true.
     #[partial(bpaf(long("bracket-spacing"), argument("true|false"), optional))]
     pub bracket_spacing: Option<BracketSpacing>,
+
+    /// Whether to vertically align the values of consecutive assignments and object properties. Defaults to false.
+    ///
+    /// This option is experimental: the formatting it produces may change in future versions.
+    #[partial(bpaf(
+        long("experimental-vertical-alignment"),
+        argument("true|false"),
+        optional
+    ))]
+    pub vertical_alignment: bool,
+
+    /// Whether to remove the unnecessary escapes of the string literals, such as `\d`. Defaults to "asNeeded".
+    ///
+    /// With "preserve", the escapes are kept as written. With "normalize", the hexadecimal digits of unicode escapes, such as `\u00e9`, are also written in uppercase.
+    #[partial(bpaf(
+        long("string-escapes"),
+        argument("as-needed|preserve|normalize"),
+        optional
+    ))]
+    pub string_escapes: StringEscapes,
+
+    /// Whether to format the code embedded in the template literals. Defaults to "off".
+    ///
+    /// With "auto", the CSS of the templates tagged with `css`, `keyframes`, `createGlobalStyle`, `injectGlobal`, or `styled`, and of the templates preceded by a `/* css */` comment, is formatted.
+    ///
+    /// Only the CSS is formatted: the templates tagged with `html`, and the templates preceded by a `/* html */` comment, are printed as written.
+    #[partial(bpaf(long("embedded-language-formatting"), argument("off|auto"), optional))]
+    pub embedded_language_formatting: EmbeddedLanguageFormatting,
+
+    /// The maximum number of specifiers of an import or an export that are printed on a single line.
+    ///
+    /// The specifiers are printed one per line when there are more, even if they fit in the line width.
+    #[partial(bpaf(long("max-inline-specifiers"), argument("NUMBER"), optional))]
+    pub max_inline_specifiers: Option<u16>,
+
+    /// Whether to format the markup of the Svelte and Astro files, and not only their scripts. Defaults to false.
+    ///
+    /// This option is experimental: the formatting it produces may change in future versions.
+    #[partial(bpaf(
+        long("experimental-markup-formatting"),
+        argument("true|false"),
+        optional
+    ))]
+    pub markup_formatting: bool,
 }
 
 impl PartialJavascriptFormatter {
@@ -122,6 +167,11 @@ impl PartialJavascriptFormatter {
             line_width: self.line_width,
             quote_style: self.quote_style.unwrap_or_default(),
             attribute_position: self.attribute_position,
+            vertical_alignment: self.vertical_alignment.unwrap_or_default(),
+            string_escapes: self.string_escapes.unwrap_or_default(),
+            embedded_language_formatting: self.embedded_language_formatting.unwrap_or_default(),
+            max_inline_specifiers: self.max_inline_specifiers,
+            markup_formatting: self.markup_formatting.unwrap_or_default(),
         }
     }
 }
@@ -145,6 +195,11 @@ impl Default for JavascriptFormatter {
             line_width: Default::default(),
             quote_style: Default::default(),
             attribute_position: Default::default(),
+            vertical_alignment: Default::default(),
+            string_escapes: Default::default(),
+            embedded_language_formatting: Default::default(),
+            max_inline_specifiers: Default::default(),
+            markup_formatting: Default::default(),
         }
     }
 }
@@ -1,6 +1,6 @@
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
-use biome_json_formatter::context::TrailingCommas;
+use biome_json_formatter::context::{ExponentCase, TrailingCommas};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 
@@ -75,6 +75,31 @@ pub struct JsonFormatter {
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
     #[partial(bpaf(long("json-formatter-trailing-commas"), argument("none|all"), optional))]
     pub trailing_commas: Option<TrailingCommas>,
+
+    /// The maximum width of the arrays of primitive values (strings, numbers, booleans, and `null`)
+    /// that are printed on a single line, even if they exceed the line width. Disabled by default.
+    #[partial(bpaf(
+        long("json-formatter-inline-array-width"),
+        argument("NUMBER"),
+        optional
+    ))]
+    pub inline_array_width: Option<LineWidth>,
+
+    /// The case of the exponent of the numbers: `1e10` or `1E10`. Defaults to "lowercase".
+    #[partial(bpaf(
+        long("json-formatter-exponent-case"),
+        argument("lowercase|uppercase|preserve"),
+        optional
+    ))]
+    pub exponent_case: Option<ExponentCase>,
+
+    /// Whether to remove the `+` sign of the exponent of the numbers: `1e+10` becomes `1e10`. Defaults to true.
+    #[partial(bpaf(
+        long("json-formatter-strip-exponent-plus-sign"),
+        argument("true|false"),
+        optional
+    ))]
+    pub strip_exponent_plus_sign: Option<bool>,
 }
 
 impl PartialJsonFormatter {
@@ -87,6 +112,9 @@ impl PartialJsonFormatter {
             line_ending: self.line_ending,
             line_width: self.line_width,
             trailing_commas: self.trailing_commas,
+            inline_array_width: self.inline_array_width,
+            exponent_case: self.exponent_case,
+            strip_exponent_plus_sign: self.strip_exponent_plus_sign,
         }
     }
 }
@@ -101,6 +129,9 @@ impl Default for JsonFormatter {
             line_ending: Default::default(),
             line_width: Default::default(),
             trailing_commas: Default::default(),
+            inline_array_width: Default::default(),
+            exponent_case: Default::default(),
+            strip_exponent_plus_sign: Default::default(),
         }
     }
 }
@@ -13,6 +13,7 @@ pub mod javascript;
 pub mod json;
 pub mod organize_imports;
 mod overrides;
+pub mod toml;
 pub mod vcs;
 
 use crate::analyzer::assists::{
@@ -28,7 +29,7 @@ use crate::organize_imports::{partial_organize_imports, OrganizeImports, Partial
 use crate::vcs::{partial_vcs_configuration, PartialVcsConfiguration, VcsConfiguration};
 pub use analyzer::{
     partial_linter_configuration, LinterConfiguration, PartialLinterConfiguration,
-    RuleConfiguration, RuleFixConfiguration, RulePlainConfiguration, RuleWithFixOptions,
+    RuleConfiguration, RuleFiles, RuleFixConfiguration, RulePlainConfiguration, RuleWithFixOptions,
     RuleWithOptions, Rules,
 };
 use biome_deserialize::{Deserialized, StringSet};
@@ -40,7 +41,8 @@ pub use css::{
     PartialCssFormatter,
 };
 pub use formatter::{
-    partial_formatter_configuration, FormatterConfiguration, PartialFormatterConfiguration,
+    partial_comments_configuration, partial_formatter_configuration, CommentsConfiguration,
+    FormatterConfiguration, PartialCommentsConfiguration, PartialFormatterConfiguration,
 };
 pub use graphql::{
     partial_graphql_configuration, GraphqlConfiguration, GraphqlFormatter, GraphqlLinter,
@@ -62,6 +64,10 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::num::NonZeroU64;
 use std::path::PathBuf;
+pub use toml::{
+    partial_toml_configuration, PartialTomlConfiguration, PartialTomlFormatter, TomlConfiguration,
+    TomlFormatter,
+};
 use vcs::VcsClientKind;
 
 pub const VERSION: &str = match option_env!("BIOME_VERSION") {
@@ -128,6 +134,10 @@ pub struct Configuration {
     #[partial(type, bpaf(external(partial_graphql_configuration), optional))]
     pub graphql: GraphqlConfiguration,
 
+    /// Specific configuration for the TOML language
+    #[partial(type, bpaf(external(partial_toml_configuration), optional))]
+    pub toml: TomlConfiguration,
+
     /// A list of granular patterns that should be applied only to a sub set of files
     #[partial(bpaf(hide))]
     pub overrides: Overrides,
@@ -287,6 +297,18 @@ impl PartialConfiguration {
             .unwrap_or_default()
     }
 
+    pub fn get_toml_formatter_configuration(&self) -> TomlFormatter {
+        self.toml
+            .as_ref()
+            .map(|f| {
+                f.formatter
+                    .as_ref()
+                    .map(|f| f.get_formatter_configuration())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn is_linter_disabled(&self) -> bool {
         self.linter.as_ref().map_or(false, |f| f.is_disabled())
     }
@@ -21,6 +21,11 @@ pub struct OrganizeImports {
     /// match these patterns.
     #[partial(bpaf(hide))]
     pub include: StringSet,
+
+    /// Whether to merge the type-only imports into the value imports from the same module,
+    /// using inline `type` modifiers. Defaults to `false`.
+    #[partial(bpaf(hide))]
+    pub merge_type_imports: bool,
 }
 
 impl Default for OrganizeImports {
@@ -29,6 +34,7 @@ impl Default for OrganizeImports {
             enabled: true,
             ignore: Default::default(),
             include: Default::default(),
+            merge_type_imports: false,
         }
     }
 }
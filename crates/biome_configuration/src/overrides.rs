@@ -1,17 +1,20 @@
 use super::javascript::PartialJavascriptConfiguration;
 use super::json::PartialJsonConfiguration;
-use super::{PartialCssConfiguration, PartialGraphqlConfiguration};
+use super::{PartialCssConfiguration, PartialGraphqlConfiguration, PartialTomlConfiguration};
+use crate::organize_imports::PartialOrganizeImports;
 use crate::{
     partial_css_configuration, partial_graphql_configuration, partial_javascript_configuration,
-    partial_json_configuration,
+    partial_json_configuration, partial_toml_configuration, PartialConfiguration,
+    PartialFormatterConfiguration, PartialLinterConfiguration,
 };
-use biome_deserialize::StringSet;
+use biome_deserialize::{Merge, StringSet};
 use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::{
     AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth,
 };
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::num::NonZeroU32;
 use std::str::FromStr;
 
 #[derive(
@@ -67,6 +70,11 @@ pub struct OverridePattern {
     #[bpaf(external(partial_graphql_configuration), optional, hide)]
     pub graphql: Option<PartialGraphqlConfiguration>,
 
+    /// Specific configuration for the TOML language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(partial_toml_configuration), optional, hide)]
+    pub toml: Option<PartialTomlConfiguration>,
+
     /// Specific configuration for the Json language
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(override_formatter_configuration), optional, hide)]
@@ -91,6 +99,54 @@ impl FromStr for OverridePattern {
     }
 }
 
+impl OverridePattern {
+    /// Merges the settings of the pattern into `configuration`,
+    /// as they apply to the files that match the pattern.
+    ///
+    /// The `include` and `ignore` fields aren't merged, because they only select the files of the pattern.
+    pub fn merge_into(self, configuration: &mut PartialConfiguration) {
+        configuration.javascript.merge_with(self.javascript);
+        configuration.json.merge_with(self.json);
+        configuration.css.merge_with(self.css);
+        configuration.graphql.merge_with(self.graphql);
+        configuration.toml.merge_with(self.toml);
+        configuration
+            .formatter
+            .merge_with(
+                self.formatter
+                    .map(|formatter| PartialFormatterConfiguration {
+                        enabled: formatter.enabled,
+                        format_with_errors: formatter.format_with_errors,
+                        indent_style: formatter.indent_style,
+                        indent_size: formatter.indent_size,
+                        indent_width: formatter.indent_width,
+                        line_ending: formatter.line_ending,
+                        line_width: formatter.line_width,
+                        attribute_position: formatter.attribute_position,
+                        bracket_spacing: formatter.bracket_spacing,
+                        max_complexity: formatter.max_complexity,
+                        ..Default::default()
+                    }),
+            );
+        configuration
+            .linter
+            .merge_with(self.linter.map(|linter| PartialLinterConfiguration {
+                enabled: linter.enabled,
+                rules: linter.rules,
+                ..Default::default()
+            }));
+        configuration
+            .organize_imports
+            .merge_with(
+                self.organize_imports
+                    .map(|organize_imports| PartialOrganizeImports {
+                        enabled: organize_imports.enabled,
+                        ..Default::default()
+                    }),
+            );
+    }
+}
+
 #[derive(
     Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
 )]
@@ -143,6 +199,12 @@ pub struct OverrideFormatterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("bracket-spacing"), argument("true|false"), optional)]
     pub bracket_spacing: Option<BracketSpacing>,
+
+    /// The maximum number of syntax nodes of the files that are formatted. The files above this
+    /// limit aren't formatted, and a diagnostic is emitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(long("max-complexity"), argument("NUMBER"), optional)]
+    pub max_complexity: Option<NonZeroU32>,
 }
 
 #[derive(
@@ -156,6 +218,11 @@ pub struct OverrideLinterConfiguration {
     #[bpaf(hide)]
     pub enabled: Option<bool>,
 
+    /// A preset of rules, such as `"recommended-strict"`, `"pedantic"` or `"formatting-only"`. The rules of the preset are layered under `rules`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub preset: Option<crate::analyzer::linter::RulePreset>,
+
     /// List of rules
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(pure(crate::analyzer::linter::Rules::default()), optional, hide)]
@@ -0,0 +1,84 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to TOML files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct TomlConfiguration {
+    /// TOML formatter options
+    #[partial(type, bpaf(external(partial_toml_formatter), optional))]
+    pub formatter: TomlFormatter,
+}
+
+/// Options that changes how the TOML formatter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct TomlFormatter {
+    /// Control the formatter for TOML files.
+    #[partial(bpaf(long("toml-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: Option<bool>,
+
+    /// The indent style applied to TOML files.
+    #[partial(bpaf(long("toml-formatter-indent-style"), argument("tab|space"), optional))]
+    pub indent_style: Option<IndentStyle>,
+
+    /// The size of the indentation applied to TOML files. Default to 2.
+    #[partial(bpaf(long("toml-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to TOML files.
+    #[partial(bpaf(long("toml-formatter-line-ending"), argument("lf|crlf|cr"), optional))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to TOML files. Defaults to 80.
+    #[partial(bpaf(long("toml-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+
+    /// Whether to align the `=` of consecutive key-value pairs. Defaults to false.
+    #[partial(bpaf(long("toml-formatter-align-entries"), argument("true|false"), optional))]
+    pub align_entries: Option<bool>,
+}
+
+impl Default for TomlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: Some(false),
+            indent_style: Default::default(),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+            align_entries: Default::default(),
+        }
+    }
+}
+
+impl PartialTomlFormatter {
+    pub fn get_formatter_configuration(&self) -> TomlFormatter {
+        TomlFormatter {
+            enabled: self.enabled,
+            indent_style: self.indent_style,
+            indent_width: self.indent_width,
+            line_ending: self.line_ending,
+            line_width: self.line_width,
+            align_entries: self.align_entries,
+        }
+    }
+}
+
+#[test]
+fn default_toml_formatter() {
+    let toml_configuration = TomlFormatter::default();
+
+    assert_eq!(toml_configuration.enabled, Some(false));
+    assert_eq!(toml_configuration.indent_style, None);
+    assert_eq!(toml_configuration.indent_width, None);
+    assert_eq!(toml_configuration.line_ending, None);
+    assert_eq!(toml_configuration.line_width, None);
+    assert_eq!(toml_configuration.align_entries, None);
+}
@@ -1,5 +1,5 @@
 use std::io;
-use std::io::{IsTerminal, Read, Write};
+use std::io::{BufRead, IsTerminal, Read, Write};
 use std::panic::RefUnwindSafe;
 use termcolor::{ColorChoice, StandardStream};
 use write::Termcolor;
@@ -37,6 +37,11 @@ pub trait Console: Send + Sync + RefUnwindSafe {
 
     /// It reads from a source, and if this source contains something, it's converted into a [String]
     fn read(&mut self) -> Option<String>;
+
+    /// It reads a single line from a source, for instance the answer to a prompt.
+    ///
+    /// It returns [None] when the source is closed.
+    fn read_line(&mut self) -> Option<String>;
 }
 
 /// Extension trait for [Console] providing convenience printing methods
@@ -182,6 +187,16 @@ impl Console for EnvConsole {
             None
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // The prompt printed before doesn't end with a new line
+        let _ = self.out.flush();
+        let mut buffer = String::new();
+        match self.r#in.lock().read_line(&mut buffer) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(buffer),
+        }
+    }
 }
 
 /// Implementation of [Console] storing all printed messages to a memory buffer
@@ -229,4 +244,9 @@ impl Console for BufferConsole {
             Some(self.in_buffer[0].clone())
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // Every prompt consumes the message that answers it
+        (!self.in_buffer.is_empty()).then(|| self.in_buffer.remove(0))
+    }
 }
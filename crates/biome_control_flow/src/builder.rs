@@ -132,6 +132,7 @@ impl<L: Language> FunctionBuilder<L> {
             conditional,
             block,
             finally_fallthrough: false,
+            finally_handlers: 0,
         })
     }
 
@@ -140,6 +141,36 @@ impl<L: Language> FunctionBuilder<L> {
             conditional: false,
             block,
             finally_fallthrough: true,
+            finally_handlers: 0,
+        })
+    }
+
+    /// Insert an unconditional jump towards `block` that leaves the `try`
+    /// statements whose `finally` blocks are `finally_blocks`, from the
+    /// innermost to the outermost: these blocks are executed before the
+    /// control flow resumes at `block`
+    pub fn append_jump_through_finally(
+        &mut self,
+        finally_blocks: &[BlockId],
+        block: BlockId,
+    ) -> InstructionBuilder<L> {
+        // The finally blocks left by the jump are the innermost cleanup
+        // handlers of the current block
+        let cleanup_handlers =
+            &self.result.blocks[self.block_cursor.index as usize].cleanup_handlers;
+        let finally_handlers = cleanup_handlers
+            .iter()
+            .zip(finally_blocks)
+            .take_while(|(handler, finally_block)| handler.target == **finally_block)
+            .count()
+            .try_into()
+            .expect("finally handlers overflow");
+
+        self.append_instruction(InstructionKind::Jump {
+            conditional: false,
+            block,
+            finally_fallthrough: false,
+            finally_handlers,
         })
     }
 }
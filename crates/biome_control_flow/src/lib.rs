@@ -108,6 +108,11 @@ pub enum InstructionKind {
         /// `finally` clause, the target block can be reinterpreted to the next
         /// exception handler instead if the control flow is currently unwinding
         finally_fallthrough: bool,
+        /// Number of `finally` clauses left by this jump, for the `break` and
+        /// `continue` statements inside `try` statements: the first
+        /// `finally_handlers` [BasicBlock::cleanup_handlers] of the block are
+        /// executed before the control flow resumes at `block`
+        finally_handlers: u32,
     },
     /// This instruction causes the control flow to unconditionally abort the
     /// execution of the function, for example is JavaScript this can be
@@ -5112,7 +5112,7 @@ pub const MEDIA_FEATURE_NAMES: [&str; 60] = [
     "prefers-contrast",
     "prefers-reduced-data",
     "prefers-reduced-motion",
-    "prefers-reduded-transparency",
+    "prefers-reduced-transparency",
     "resolution",
     "scan",
     "screen-spanning",
@@ -5124,6 +5124,63 @@ pub const MEDIA_FEATURE_NAMES: [&str; 60] = [
     "width",
 ];
 
+/// The values of the media features whose values are keywords
+pub const MEDIA_FEATURE_KEYWORD_VALUES: [(&str, &[&str]); 26] = [
+    ("any-hover", &["hover", "none"]),
+    ("any-pointer", &["coarse", "fine", "none"]),
+    ("color-gamut", &["p3", "rec2020", "srgb"]),
+    ("device-posture", &["continuous", "folded"]),
+    (
+        "display-mode",
+        &[
+            "browser",
+            "fullscreen",
+            "minimal-ui",
+            "picture-in-picture",
+            "standalone",
+            "window-controls-overlay",
+        ],
+    ),
+    ("dynamic-range", &["high", "standard"]),
+    (
+        "environment-blending",
+        &["additive", "opaque", "subtractive"],
+    ),
+    ("forced-colors", &["active", "none"]),
+    ("hover", &["hover", "none"]),
+    ("inverted-colors", &["inverted", "none"]),
+    ("light-level", &["dim", "normal", "washed"]),
+    ("nav-controls", &["back", "none"]),
+    ("orientation", &["landscape", "portrait"]),
+    (
+        "overflow-block",
+        &["none", "optional-paged", "paged", "scroll"],
+    ),
+    ("overflow-inline", &["none", "scroll"]),
+    ("pointer", &["coarse", "fine", "none"]),
+    ("prefers-color-scheme", &["dark", "light"]),
+    (
+        "prefers-contrast",
+        &["custom", "less", "more", "no-preference"],
+    ),
+    ("prefers-reduced-data", &["no-preference", "reduce"]),
+    ("prefers-reduced-motion", &["no-preference", "reduce"]),
+    ("prefers-reduced-transparency", &["no-preference", "reduce"]),
+    ("scan", &["interlace", "progressive"]),
+    ("scripting", &["enabled", "initial-only", "none"]),
+    ("update", &["fast", "none", "slow"]),
+    ("video-color-gamut", &["p3", "rec2020", "srgb"]),
+    ("video-dynamic-range", &["high", "standard"]),
+];
+
+/// The units of the lengths, in lowercase
+pub const LENGTH_UNITS: [&str; 51] = [
+    "cap", "ch", "cm", "cqb", "cqh", "cqi", "cqmax", "cqmin", "cqw", "dvb", "dvh", "dvi", "dvmax",
+    "dvmin", "dvw", "em", "ex", "ic", "in", "lh", "lvb", "lvh", "lvi", "lvmax", "lvmin", "lvw",
+    "mm", "mozmm", "pc", "pt", "px", "q", "rcap", "rch", "rem", "rex", "ric", "rlh", "rpx", "svb",
+    "svh", "svi", "svmax", "svmin", "svw", "vb", "vh", "vi", "vmax", "vmin", "vw",
+];
+
 pub const SHORTHAND_PROPERTIES: [&str; 57] = [
     "animation",
     "background",
@@ -5440,10 +5497,10 @@ mod tests {
     use super::{
         FUNCTION_KEYWORDS, KNOWN_EDGE_PROPERTIES, KNOWN_EXPLORER_PROPERTIES,
         KNOWN_FIREFOX_PROPERTIES, KNOWN_PROPERTIES, KNOWN_SAFARI_PROPERTIES,
-        KNOWN_SAMSUNG_INTERNET_PROPERTIES, KNOWN_US_BROWSER_PROPERTIES,
-        LONGHAND_SUB_PROPERTIES_OF_SHORTHAND_PROPERTIES, MEDIA_FEATURE_NAMES,
-        RESET_TO_INITIAL_PROPERTIES_BY_BORDER, RESET_TO_INITIAL_PROPERTIES_BY_FONT,
-        SHORTHAND_PROPERTIES,
+        KNOWN_SAMSUNG_INTERNET_PROPERTIES, KNOWN_US_BROWSER_PROPERTIES, LENGTH_UNITS,
+        LONGHAND_SUB_PROPERTIES_OF_SHORTHAND_PROPERTIES, MEDIA_FEATURE_KEYWORD_VALUES,
+        MEDIA_FEATURE_NAMES, RESET_TO_INITIAL_PROPERTIES_BY_BORDER,
+        RESET_TO_INITIAL_PROPERTIES_BY_FONT, SHORTHAND_PROPERTIES,
     };
 
     #[test]
@@ -5516,6 +5573,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_media_feature_keyword_values_order() {
+        for items in MEDIA_FEATURE_KEYWORD_VALUES.windows(2) {
+            assert!(items[0].0 < items[1].0, "{} < {}", items[0].0, items[1].0);
+        }
+        for (_, values) in MEDIA_FEATURE_KEYWORD_VALUES {
+            for items in values.windows(2) {
+                assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_length_units_order() {
+        for items in LENGTH_UNITS.windows(2) {
+            assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+        }
+    }
+
     #[test]
     fn test_shorthand_properties_sorted() {
         let mut sorted = SHORTHAND_PROPERTIES.to_vec();
@@ -7,6 +7,7 @@ mod suppression_action;
 mod utils;
 
 pub use crate::registry::visit_registry;
+pub use crate::services::custom_properties::{declared_custom_properties, ProjectCustomProperties};
 use crate::suppression_action::CssSuppressionAction;
 use biome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
@@ -31,13 +32,21 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<CssLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    custom_properties: ProjectCustomProperties,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<CssLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        custom_properties,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -51,6 +60,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    custom_properties: ProjectCustomProperties,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -69,6 +79,9 @@ where
                     if comment.is_legacy {
                         result.push(Ok(SuppressionKind::Deprecated));
                     }
+                    if let Some(date) = comment.expires {
+                        result.push(Ok(SuppressionKind::Expires(date)));
+                    }
                     comment.categories
                 }
                 Err(err) => {
@@ -99,13 +112,15 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
         return (None, diagnostics);
     }
 
+    services.insert_service(custom_properties);
+
     let mut analyzer = biome_analyze::Analyzer::new(
         METADATA.deref(),
         biome_analyze::InspectMatcher::new(registry, inspect_matcher),
@@ -140,7 +155,7 @@ mod tests {
     use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic, Severity};
     use std::slice;
 
-    use crate::{analyze, AnalysisFilter, ControlFlow};
+    use crate::{analyze, AnalysisFilter, ControlFlow, ProjectCustomProperties};
 
     #[ignore]
     #[test]
@@ -186,6 +201,7 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            ProjectCustomProperties::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
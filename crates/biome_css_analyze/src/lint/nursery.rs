@@ -2,17 +2,25 @@
 
 use biome_analyze::declare_lint_group;
 
+pub mod no_comment_only_block;
 pub mod no_duplicate_at_import_rules;
 pub mod no_duplicate_font_names;
+pub mod no_duplicate_layer_names;
 pub mod no_duplicate_selectors_keyframe_block;
 pub mod no_empty_block;
+pub mod no_excessive_important;
 pub mod no_important_in_keyframe;
+pub mod no_invalid_container_query_feature;
 pub mod no_invalid_direction_in_linear_gradient;
 pub mod no_invalid_position_at_import_rule;
 pub mod no_irregular_whitespace;
+pub mod no_missing_var_function_fallback;
 pub mod no_shorthand_property_overrides;
+pub mod no_unknown_container_name;
+pub mod no_unknown_custom_property;
 pub mod no_unknown_function;
 pub mod no_unknown_media_feature_name;
+pub mod no_unknown_media_feature_value;
 pub mod no_unknown_property;
 pub mod no_unknown_pseudo_class_selector;
 pub mod no_unknown_selector_pseudo_element;
@@ -26,17 +34,25 @@ declare_lint_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_comment_only_block :: NoCommentOnlyBlock ,
             self :: no_duplicate_at_import_rules :: NoDuplicateAtImportRules ,
             self :: no_duplicate_font_names :: NoDuplicateFontNames ,
+            self :: no_duplicate_layer_names :: NoDuplicateLayerNames ,
             self :: no_duplicate_selectors_keyframe_block :: NoDuplicateSelectorsKeyframeBlock ,
             self :: no_empty_block :: NoEmptyBlock ,
+            self :: no_excessive_important :: NoExcessiveImportant ,
             self :: no_important_in_keyframe :: NoImportantInKeyframe ,
+            self :: no_invalid_container_query_feature :: NoInvalidContainerQueryFeature ,
             self :: no_invalid_direction_in_linear_gradient :: NoInvalidDirectionInLinearGradient ,
             self :: no_invalid_position_at_import_rule :: NoInvalidPositionAtImportRule ,
             self :: no_irregular_whitespace :: NoIrregularWhitespace ,
+            self :: no_missing_var_function_fallback :: NoMissingVarFunctionFallback ,
             self :: no_shorthand_property_overrides :: NoShorthandPropertyOverrides ,
+            self :: no_unknown_container_name :: NoUnknownContainerName ,
+            self :: no_unknown_custom_property :: NoUnknownCustomProperty ,
             self :: no_unknown_function :: NoUnknownFunction ,
             self :: no_unknown_media_feature_name :: NoUnknownMediaFeatureName ,
+            self :: no_unknown_media_feature_value :: NoUnknownMediaFeatureValue ,
             self :: no_unknown_property :: NoUnknownProperty ,
             self :: no_unknown_pseudo_class_selector :: NoUnknownPseudoClassSelector ,
             self :: no_unknown_selector_pseudo_element :: NoUnknownSelectorPseudoElement ,
@@ -0,0 +1,98 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::stmt_ext::CssBlockLike;
+use biome_css_syntax::CssSyntaxToken;
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Disallow blocks that only contain comments.
+    ///
+    /// The minifiers remove the comments, so a block that only contains comments becomes an empty block
+    /// once minified. The minifiers then drop the empty rule, or keep an empty block,
+    /// depending on the tool and on the at-rule, so the minified stylesheet can behave differently from its source.
+    ///
+    /// The comments starting with `/*!` are preserved by the minifiers, so they are allowed.
+    ///
+    /// The blocks without any comment are reported by [noEmptyBlock](https://biomejs.dev/linter/rules/no-empty-block).
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a { /* TODO */ }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @media print {
+    ///   /* a { color: black; } */
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a { /*! Preserved by the minifiers */ }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   /* The text color */
+    ///   color: black;
+    /// }
+    /// ```
+    ///
+    pub NoCommentOnlyBlock {
+        version: "next",
+        name: "noCommentOnlyBlock",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoCommentOnlyBlock {
+    type Query = Ast<CssBlockLike>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if !node.is_empty() || node.is_empty_without_comments() {
+            return None;
+        }
+        let l_curly = node.l_curly_token().ok()?;
+        let r_curly = node.r_curly_token().ok()?;
+        let has_preserved_comment =
+            comments(&l_curly, &r_curly).any(|comment| comment.starts_with("/*!"));
+        (!has_preserved_comment).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This block only contains comments, so it becomes empty once minified."
+                },
+            )
+            .note(markup! {
+                "Remove the block, add declarations inside it, or start the comment with "<Emphasis>"/*!"</Emphasis>" to preserve it."
+            }),
+        )
+    }
+}
+
+/// Returns the text of the comments between the curly braces of an empty block
+fn comments<'a>(
+    l_curly: &'a CssSyntaxToken,
+    r_curly: &'a CssSyntaxToken,
+) -> impl Iterator<Item = String> + 'a {
+    l_curly
+        .trailing_trivia()
+        .pieces()
+        .chain(r_curly.leading_trivia().pieces())
+        .filter(|piece| piece.is_comments())
+        .map(|piece| piece.text().to_string())
+}
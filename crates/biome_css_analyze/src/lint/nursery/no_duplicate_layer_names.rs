@@ -0,0 +1,115 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_semantic::model::{CssLayer, CssLayerKind};
+use rustc_hash::FxHashMap;
+
+use crate::services::semantic::SemanticServices;
+
+declare_lint_rule! {
+    /// Disallow the `@layer` statements that list a layer that is already declared.
+    ///
+    /// The order of the cascade layers is set by the first declaration of each layer:
+    /// an `@layer` statement, an `@layer` rule with a block, or an `@import` with `layer()`.
+    /// Listing a layer again in a later `@layer` statement doesn't change its order,
+    /// which is often not what the author expected.
+    ///
+    /// The nested layers are compared by their full name,
+    /// so that `base` nested in `framework` is the layer `framework.base`.
+    /// The `@layer` rules with a block can be repeated to add rules to a layer, they are never reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// @layer reset, base, reset;
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @layer base {
+    ///     a { color: red; }
+    /// }
+    /// @layer reset, base;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @layer reset, base;
+    /// @layer base {
+    ///     a { color: red; }
+    /// }
+    /// @layer base {
+    ///     p { color: blue; }
+    /// }
+    /// ```
+    ///
+    pub NoDuplicateLayerNames {
+        version: "next",
+        name: "noDuplicateLayerNames",
+        language: "css",
+        recommended: false,
+    }
+}
+
+pub struct DuplicateLayer {
+    layer: CssLayer,
+    first_declaration: CssLayer,
+}
+
+impl Rule for NoDuplicateLayerNames {
+    type Query = SemanticServices;
+    type State = DuplicateLayer;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.query();
+        let mut declared: FxHashMap<&str, &CssLayer> = FxHashMap::default();
+        let mut duplicates = Vec::new();
+        for layer in model.layers() {
+            match declared.get(layer.name.as_str()) {
+                Some(first_declaration) if layer.kind == CssLayerKind::Statement => {
+                    duplicates.push(DuplicateLayer {
+                        layer: layer.clone(),
+                        first_declaration: (*first_declaration).clone(),
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    declared.insert(&layer.name, layer);
+                }
+            }
+        }
+        duplicates
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DuplicateLayer {
+            layer,
+            first_declaration,
+        } = state;
+        let name = &layer.name;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                layer.range,
+                markup! {
+                    "The layer "<Emphasis>{name}</Emphasis>" is already declared."
+                },
+            )
+            .detail(
+                first_declaration.range,
+                markup! {
+                    "The layer is first declared here."
+                },
+            )
+            .note(markup! {
+                "The order of the layers is set by their first declaration, so this statement doesn't change it."
+            })
+            .note(markup! {
+                "Remove the layer from this statement, or move the statement before the first declaration of the layer."
+            }),
+        )
+    }
+}
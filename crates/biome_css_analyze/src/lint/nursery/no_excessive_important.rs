@@ -0,0 +1,120 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{CssDeclarationImportant, CssRoot};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{AstNode, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow using `!important` more times than allowed in a stylesheet.
+    ///
+    /// Each `!important` makes the cascade harder to follow, and overriding it requires another `!important`.
+    /// A stylesheet that relies on many of them is usually fighting its own specificity.
+    ///
+    /// The rule reports the first `!important` beyond the allowed count.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a { color: red !important; }
+    /// b { color: red !important; }
+    /// c { color: red !important; }
+    /// d { color: red !important; }
+    /// e { color: red !important; }
+    /// f { color: red !important; }
+    /// g { color: red !important; }
+    /// h { color: red !important; }
+    /// i { color: red !important; }
+    /// j { color: red !important; }
+    /// k { color: red !important; }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a { color: red !important; }
+    /// b { color: blue; }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The option `maxImportant` sets the number of `!important` allowed in a stylesheet. The default is `10`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "maxImportant": 5
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Use [`overrides`](https://biomejs.dev/reference/configuration/#overrides) to set a different threshold for some files,
+    /// such as the stylesheets that override the styles of a third-party library.
+    ///
+    pub NoExcessiveImportant {
+        version: "next",
+        name: "noExcessiveImportant",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noExcessiveImportant`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoExcessiveImportantOptions {
+    /// The number of `!important` allowed in a stylesheet.
+    pub max_important: u16,
+}
+
+impl Default for NoExcessiveImportantOptions {
+    fn default() -> Self {
+        Self { max_important: 10 }
+    }
+}
+
+pub struct ExcessiveImportant {
+    /// The range of the first `!important` beyond the allowed count
+    range: TextRange,
+    count: usize,
+}
+
+impl Rule for NoExcessiveImportant {
+    type Query = Ast<CssRoot>;
+    type State = ExcessiveImportant;
+    type Signals = Option<Self::State>;
+    type Options = NoExcessiveImportantOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let max_important = usize::from(ctx.options().max_important);
+        let mut important = ctx
+            .query()
+            .syntax()
+            .descendants()
+            .filter_map(CssDeclarationImportant::cast);
+        let range = important.nth(max_important)?.range();
+        let count = max_important + 1 + important.count();
+        Some(ExcessiveImportant { range, count })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let max_important = ctx.options().max_important;
+        let count = state.count;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This stylesheet uses "<Emphasis>"!important"</Emphasis>" "{count}" times, but only "{max_important}" are allowed."
+                },
+            )
+            .note(markup! {
+                "Increase the specificity of the selectors, or use cascade layers, instead of "<Emphasis>"!important"</Emphasis>"."
+            }),
+        )
+    }
+}
@@ -0,0 +1,119 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_semantic::model::CssQueryFeature;
+
+use crate::services::semantic::SemanticServices;
+
+declare_lint_rule! {
+    /// Disallow the features that can't be used in the size queries of the `@container` rules.
+    ///
+    /// The size queries of the `@container` rules only support the features that describe the size of the container:
+    /// `width`, `height`, `inline-size`, `block-size`, `aspect-ratio`, and `orientation`,
+    /// as well as their `min-` and `max-` variants.
+    /// The other features, such as the media features `hover` or `prefers-color-scheme`, make the query invalid,
+    /// so its rules are never applied.
+    ///
+    /// The style queries, such as `style(--theme: dark)`, aren't checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// @container (hover: hover) {
+    ///     a { color: red; }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @container card (min-device-width: 400px) {
+    ///     a { color: red; }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @container card (min-inline-size: 400px) and (orientation: landscape) {
+    ///     a { color: red; }
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// @container style(--theme: dark) {
+    ///     a { color: white; }
+    /// }
+    /// ```
+    ///
+    pub NoInvalidContainerQueryFeature {
+        version: "next",
+        name: "noInvalidContainerQueryFeature",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// The features of the container size queries
+const SIZE_FEATURES: [&str; 6] = [
+    "aspect-ratio",
+    "block-size",
+    "height",
+    "inline-size",
+    "orientation",
+    "width",
+];
+
+/// The features of the container size queries that accept the `min-` and `max-` prefixes
+const RANGE_SIZE_FEATURES: [&str; 5] = [
+    "aspect-ratio",
+    "block-size",
+    "height",
+    "inline-size",
+    "width",
+];
+
+impl Rule for NoInvalidContainerQueryFeature {
+    type Query = SemanticServices;
+    type State = CssQueryFeature;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        ctx.query()
+            .container_queries()
+            .iter()
+            .flat_map(|query| query.features.iter())
+            .filter(|feature| !is_size_feature(&feature.name))
+            .cloned()
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.name;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The feature "<Emphasis>{name}</Emphasis>" can't be used in a container query."
+                },
+            )
+            .note(markup! {
+                "The query is invalid, so its rules are never applied."
+            })
+            .note(markup! {
+                "Use a size feature, such as "<Emphasis>"inline-size"</Emphasis>" or "<Emphasis>"aspect-ratio"</Emphasis>", or move this feature to an "<Emphasis>"@media"</Emphasis>" rule."
+            }),
+        )
+    }
+}
+
+fn is_size_feature(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    if SIZE_FEATURES.binary_search(&name.as_str()).is_ok() {
+        return true;
+    }
+    name.strip_prefix("min-")
+        .or_else(|| name.strip_prefix("max-"))
+        .is_some_and(|name| RANGE_SIZE_FEATURES.binary_search(&name).is_ok())
+}
@@ -0,0 +1,167 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{CssFunction, CssGenericProperty};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{AstNode, AstSeparatedList};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Require a fallback in the `var()` functions of the layout properties.
+    ///
+    /// When a custom property isn't defined, a `var()` function without fallback makes
+    /// the whole declaration invalid at computed-value time: the property falls back to its initial
+    /// or inherited value. For the properties that control the layout, such as `display` or `width`,
+    /// this can break the page silently.
+    ///
+    /// The nested `var()` functions are checked too, including the ones used as fallback.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   width: var(--content-width);
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   display: var(--display, var(--fallback-display));
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   width: var(--content-width, 100%);
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   color: var(--text-color);
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The option `properties` replaces the list of the checked properties.
+    /// By default, the properties that control the display, the position, the size, and the spacing of the boxes are checked.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "properties": ["display", "width", "color"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoMissingVarFunctionFallback {
+        version: "next",
+        name: "noMissingVarFunctionFallback",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// The properties checked by default
+const LAYOUT_PROPERTIES: &[&str] = &[
+    "bottom",
+    "display",
+    "flex",
+    "flex-basis",
+    "gap",
+    "grid-template-columns",
+    "grid-template-rows",
+    "height",
+    "inset",
+    "left",
+    "margin",
+    "max-height",
+    "max-width",
+    "min-height",
+    "min-width",
+    "padding",
+    "position",
+    "right",
+    "top",
+    "width",
+];
+
+/// Options for the rule `noMissingVarFunctionFallback`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoMissingVarFunctionFallbackOptions {
+    /// The properties whose `var()` functions must have a fallback.
+    pub properties: Vec<String>,
+}
+
+impl Default for NoMissingVarFunctionFallbackOptions {
+    fn default() -> Self {
+        Self {
+            properties: LAYOUT_PROPERTIES
+                .iter()
+                .map(|property| (*property).to_string())
+                .collect(),
+        }
+    }
+}
+
+impl Rule for NoMissingVarFunctionFallback {
+    type Query = Ast<CssGenericProperty>;
+    type State = CssFunction;
+    type Signals = Vec<Self::State>;
+    type Options = NoMissingVarFunctionFallbackOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let Ok(name) = node.name() else {
+            return vec![];
+        };
+        let name = name.syntax().text_trimmed().to_string();
+        if !ctx
+            .options()
+            .properties
+            .iter()
+            .any(|property| property.eq_ignore_ascii_case(&name))
+        {
+            return vec![];
+        }
+        node.value()
+            .syntax()
+            .descendants()
+            .filter_map(CssFunction::cast)
+            .filter(|function| {
+                function.name().is_ok_and(|name| {
+                    name.value_token()
+                        .is_ok_and(|token| token.text_trimmed().eq_ignore_ascii_case("var"))
+                })
+                // `var(--a,)` has an empty fallback
+                && function.items().separators().next().is_none()
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, function: &Self::State) -> Option<RuleDiagnostic> {
+        let name = ctx.query().name().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                function.range(),
+                markup! {
+                    "This "<Emphasis>"var()"</Emphasis>" function doesn't have a fallback."
+                },
+            )
+            .note(markup! {
+                "When the custom property isn't defined, the property "<Emphasis>{name.syntax().text_trimmed().to_string()}</Emphasis>" falls back to its initial or inherited value, which can break the layout."
+            })
+            .note(markup! {
+                "Add a fallback value after the name of the custom property, such as "<Emphasis>"var(--name, auto)"</Emphasis>"."
+            }),
+        )
+    }
+}
@@ -0,0 +1,91 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_semantic::model::CssContainerName;
+
+use crate::services::semantic::SemanticServices;
+
+declare_lint_rule! {
+    /// Disallow the `@container` rules that query a container name that is never declared.
+    ///
+    /// An `@container` rule with a name only queries the nearest ancestor container with this name.
+    /// When no element declares this name, the query never matches and its rules are never applied:
+    /// this is most likely a typo.
+    ///
+    /// A container name is known if it is declared in the current file,
+    /// with the `container-name` property or the `container` shorthand.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .sidebar {
+    ///     container: sidebar / inline-size;
+    /// }
+    /// @container side-bar (min-width: 400px) {
+    ///     a { color: red; }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .sidebar {
+    ///     container-name: sidebar;
+    ///     container-type: inline-size;
+    /// }
+    /// @container sidebar (min-width: 400px) {
+    ///     a { color: red; }
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// @container (min-width: 400px) {
+    ///     a { color: red; }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownContainerName {
+        version: "next",
+        name: "noUnknownContainerName",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnknownContainerName {
+    type Query = SemanticServices;
+    type State = CssContainerName;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.query();
+        model
+            .container_queries()
+            .iter()
+            .filter_map(|query| query.name.as_ref())
+            .filter(|name| !model.container_names().contains_key(&name.name))
+            .cloned()
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.name;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The container "<Emphasis>{name}</Emphasis>" is never declared."
+                },
+            )
+            .note(markup! {
+                "The query never matches, so its rules are never applied."
+            })
+            .note(markup! {
+                "Declare the container with the "<Emphasis>"container-name"</Emphasis>" property, or fix its name if it is misspelled."
+            }),
+        )
+    }
+}
@@ -0,0 +1,136 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{AnyCssExpression, AnyCssValue, CssDashedIdentifier, CssFunction, CssRoot};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, TextRange};
+
+use crate::services::custom_properties::{declared_custom_properties, CustomProperties};
+
+declare_lint_rule! {
+    /// Disallow references to custom properties that are never declared.
+    ///
+    /// A `var()` function that references an undeclared custom property is replaced by the initial value of the property,
+    /// this is most likely a typo.
+    ///
+    /// A custom property is known if it is declared in the current file,
+    /// or in any CSS file of the project:
+    /// in a rule such as `:root { --brand-color: red; }`, or registered with `@property --brand-color`.
+    /// Only the CSS files processed by Biome are taken into account:
+    /// in an editor, these are the opened files.
+    ///
+    /// A `var()` function with a fallback value, such as `var(--brand-color, red)`, is never reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// :root {
+    ///     --brand-color: red;
+    /// }
+    /// a {
+    ///     color: var(--brand-colour);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// :root {
+    ///     --brand-color: red;
+    /// }
+    /// a {
+    ///     color: var(--brand-color);
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// @property --angle {
+    ///     syntax: "<angle>";
+    ///     inherits: false;
+    ///     initial-value: 0deg;
+    /// }
+    /// a {
+    ///     rotate: var(--angle);
+    ///     color: var(--undeclared, red);
+    /// }
+    /// ```
+    ///
+    pub NoUnknownCustomProperty {
+        version: "next",
+        name: "noUnknownCustomProperty",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnknownCustomProperty {
+    type Query = CustomProperties<CssRoot>;
+    type State = (String, TextRange);
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let project = ctx.project();
+        let declared = declared_custom_properties(root);
+        root.syntax()
+            .descendants()
+            .filter_map(CssFunction::cast)
+            .filter_map(|function| referenced_custom_property(&function))
+            .filter_map(|name| {
+                let text = name.value_token().ok()?.text_trimmed().to_string();
+                (!declared.contains(&text) && !project.contains(&text))
+                    .then(|| (text, name.range()))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, (name, range): &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The custom property "<Emphasis>{name}</Emphasis>" is never declared."
+                },
+            )
+            .note(markup! {
+                "The "<Emphasis>"var()"</Emphasis>" function is replaced by the initial value of the property."
+            })
+            .note(markup! {
+                "Declare the custom property, fix its name if it is misspelled, or provide a fallback value."
+            }),
+        )
+    }
+}
+
+/// Returns the custom property referenced by `function`,
+/// if `function` is a `var()` function without fallback value.
+fn referenced_custom_property(function: &CssFunction) -> Option<CssDashedIdentifier> {
+    if !function
+        .name()
+        .ok()?
+        .value_token()
+        .ok()?
+        .text_trimmed()
+        .eq_ignore_ascii_case("var")
+    {
+        return None;
+    }
+    let mut parameters = function.items().iter();
+    let parameter = parameters.next()?.ok()?;
+    if parameters.next().is_some() {
+        // The fallback value is used when the custom property is not declared
+        return None;
+    }
+    let AnyCssExpression::CssListOfComponentValuesExpression(expression) =
+        parameter.any_css_expression().ok()?
+    else {
+        return None;
+    };
+    let mut values = expression.css_component_value_list().iter();
+    match (values.next()?, values.next()) {
+        (AnyCssValue::CssDashedIdentifier(name), None) => Some(name),
+        _ => None,
+    }
+}
@@ -0,0 +1,319 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDimension, AnyCssQueryFeature, AnyCssQueryFeatureValue, CssIdentifier, CssMediaQueryList,
+};
+use biome_rowan::{AstNode, TextRange};
+
+use crate::utils::{get_media_feature_keyword_values, is_length_unit};
+
+declare_lint_rule! {
+    /// Disallow invalid values of the media features.
+    ///
+    /// A media query whose feature has a misspelled or mistyped value is invalid, so its rules are never applied.
+    /// This rule checks the values of the media features defined in the CSS Specifications:
+    /// - the keywords of the discrete features, such as `dark` for `prefers-color-scheme`;
+    /// - the lengths of `width` and `height`, the resolutions of `resolution`, the ratios of `aspect-ratio`,
+    ///   and the integers of `color` or `monochrome`.
+    ///
+    /// The range syntax, such as `(width <= 600px)` or `(400px <= width <= 700px)`, is checked as well:
+    /// it can only be used with the features that have a range of values, and without the `min-` and `max-` prefixes.
+    ///
+    /// The unknown media features are reported by [noUnknownMediaFeatureName](https://biomejs.dev/linter/rules/no-unknown-media-feature-name),
+    /// and the values computed by functions, such as `calc()` or `env()`, aren't checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// @media (prefers-color-scheme: drak) {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @media (min-width: 50%) {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @media (orientation > landscape) {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @media (min-width >= 600px) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// @media (prefers-color-scheme: dark) and (min-width: 600px) {}
+    /// ```
+    ///
+    /// ```css
+    /// @media (400px <= width <= 700px) and (min-resolution: 2dppx) {}
+    /// ```
+    ///
+    /// ```css
+    /// @media (aspect-ratio: 16 / 9) and (color) {}
+    /// ```
+    ///
+    pub NoUnknownMediaFeatureValue {
+        version: "next",
+        name: "noUnknownMediaFeatureValue",
+        language: "css",
+        recommended: false,
+        sources: &[RuleSource::Stylelint("media-feature-name-value-no-unknown")],
+    }
+}
+
+/// The type of the values of a media feature
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MediaFeatureType {
+    Length,
+    Ratio,
+    Resolution,
+    Integer,
+    /// The integers `0` and `1` of the feature `grid`
+    Boolean,
+    Keyword(&'static [&'static str]),
+}
+
+impl MediaFeatureType {
+    /// Returns the type of the media feature `name`, and whether it accepts the `min-` and `max-` prefixes
+    /// and the range syntax.
+    fn of(name: &str) -> Option<(Self, bool)> {
+        let feature_type = match name {
+            "width" | "height" | "device-width" | "device-height" => Self::Length,
+            "aspect-ratio" | "device-aspect-ratio" => Self::Ratio,
+            "resolution" => Self::Resolution,
+            "color"
+            | "color-index"
+            | "monochrome"
+            | "horizontal-viewport-segments"
+            | "vertical-viewport-segments" => Self::Integer,
+            "grid" => return Some((Self::Boolean, false)),
+            _ => {
+                return get_media_feature_keyword_values(name)
+                    .map(|values| (Self::Keyword(values), false))
+            }
+        };
+        Some((feature_type, true))
+    }
+
+    /// Returns `true` if `value` is valid for this type
+    fn accepts(self, value: &AnyCssQueryFeatureValue) -> bool {
+        match value {
+            // `calc()`, `env()`, or `var()`
+            AnyCssQueryFeatureValue::AnyCssFunction(_) => true,
+            AnyCssQueryFeatureValue::AnyCssDimension(dimension) => match dimension {
+                AnyCssDimension::CssRegularDimension(dimension) => {
+                    let Ok(unit) = dimension.unit_token() else {
+                        return true;
+                    };
+                    let unit = unit.text_trimmed().to_ascii_lowercase();
+                    match self {
+                        Self::Length => is_length_unit(&unit),
+                        Self::Resolution => matches!(unit.as_str(), "dpi" | "dpcm" | "dppx" | "x"),
+                        _ => false,
+                    }
+                }
+                // The unknown units are reported by `noUnknownUnit`
+                AnyCssDimension::CssUnknownDimension(_) => {
+                    matches!(self, Self::Length | Self::Resolution)
+                }
+                AnyCssDimension::CssPercentage(_) => false,
+            },
+            AnyCssQueryFeatureValue::CssNumber(number) => {
+                let Ok(number) = number.value_token() else {
+                    return true;
+                };
+                let number = number.text_trimmed();
+                match self {
+                    // Only the zero length doesn't need a unit
+                    Self::Length => number.parse::<f64>().is_ok_and(|number| number == 0.0),
+                    Self::Ratio => true,
+                    Self::Integer => number.parse::<u32>().is_ok(),
+                    Self::Boolean => matches!(number, "0" | "1"),
+                    Self::Resolution | Self::Keyword(_) => false,
+                }
+            }
+            AnyCssQueryFeatureValue::CssRatio(_) => self == Self::Ratio,
+            AnyCssQueryFeatureValue::CssIdentifier(identifier) => {
+                let Some(value) = identifier_value(identifier) else {
+                    return true;
+                };
+                match self {
+                    Self::Keyword(values) => values.contains(&value.as_str()),
+                    Self::Resolution => value == "infinite",
+                    _ => false,
+                }
+            }
+        }
+    }
+
+    /// Describes the values accepted by this type
+    fn expected(self) -> String {
+        match self {
+            Self::Length => "a length, such as 600px".to_string(),
+            Self::Ratio => "a ratio, such as 16 / 9".to_string(),
+            Self::Resolution => "a resolution, such as 2dppx or 192dpi".to_string(),
+            Self::Integer => "a positive integer".to_string(),
+            Self::Boolean => "0 or 1".to_string(),
+            Self::Keyword(values) => format!("one of the keywords {}", values.join(", ")),
+        }
+    }
+}
+
+pub enum InvalidMediaFeature {
+    /// The value isn't accepted by the feature
+    Value {
+        name: String,
+        feature_type: MediaFeatureType,
+        range: TextRange,
+    },
+    /// A feature that doesn't have a range of values is used in the range syntax
+    Range { name: String, range: TextRange },
+    /// A feature with the `min-` or `max-` prefix is used in the range syntax
+    PrefixedRange {
+        name: String,
+        unprefixed: String,
+        range: TextRange,
+    },
+}
+
+impl Rule for NoUnknownMediaFeatureValue {
+    type Query = Ast<AnyCssQueryFeature>;
+    type State = InvalidMediaFeature;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let feature = ctx.query();
+        // The features of the container queries are checked by `noInvalidContainerQueryFeature`
+        if !feature
+            .syntax()
+            .ancestors()
+            .any(|ancestor| CssMediaQueryList::can_cast(ancestor.kind()))
+        {
+            return Vec::new();
+        }
+        let (name, values, is_range) = match feature {
+            AnyCssQueryFeature::CssQueryFeaturePlain(feature) => {
+                (feature.name(), vec![feature.value()], false)
+            }
+            AnyCssQueryFeature::CssQueryFeatureBoolean(_) => return Vec::new(),
+            AnyCssQueryFeature::CssQueryFeatureRange(feature) => {
+                (feature.left(), vec![feature.right()], true)
+            }
+            AnyCssQueryFeature::CssQueryFeatureReverseRange(feature) => {
+                (feature.right(), vec![feature.left()], true)
+            }
+            AnyCssQueryFeature::CssQueryFeatureRangeInterval(feature) => {
+                (feature.name(), vec![feature.left(), feature.right()], true)
+            }
+        };
+        let Some(name_text) = name.ok().as_ref().and_then(identifier_value) else {
+            return Vec::new();
+        };
+        let (unprefixed, is_prefixed) = match name_text
+            .strip_prefix("min-")
+            .or_else(|| name_text.strip_prefix("max-"))
+        {
+            Some(unprefixed) => (unprefixed, true),
+            None => (name_text.as_str(), false),
+        };
+        // The unknown and the vendor-prefixed features aren't checked
+        let Some((feature_type, has_range)) = MediaFeatureType::of(unprefixed) else {
+            return Vec::new();
+        };
+        if is_prefixed && !has_range {
+            return Vec::new();
+        }
+        if is_range {
+            if !has_range {
+                return vec![InvalidMediaFeature::Range {
+                    name: name_text,
+                    range: feature.range(),
+                }];
+            }
+            if is_prefixed {
+                return vec![InvalidMediaFeature::PrefixedRange {
+                    unprefixed: unprefixed.to_string(),
+                    name: name_text,
+                    range: feature.range(),
+                }];
+            }
+        }
+        values
+            .into_iter()
+            .filter_map(|value| value.ok())
+            .filter(|value| !feature_type.accepts(value))
+            .map(|value| InvalidMediaFeature::Value {
+                name: name_text.clone(),
+                feature_type,
+                range: value.range(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidMediaFeature::Value {
+                name,
+                feature_type,
+                range,
+            } => {
+                let expected = feature_type.expected();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    *range,
+                    markup! {
+                        "This value isn't valid for the media feature "<Emphasis>{name}</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "The feature "<Emphasis>{name}</Emphasis>" expects "{expected}"."
+                })
+            }
+            InvalidMediaFeature::Range { name, range } => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The media feature "<Emphasis>{name}</Emphasis>" can't be used in a range."
+                },
+            )
+            .note(markup! {
+                "Only the features that have a range of values, such as "<Emphasis>"width"</Emphasis>" or "<Emphasis>"resolution"</Emphasis>", can be compared with "<Emphasis>"<"</Emphasis>", "<Emphasis>">"</Emphasis>", or "<Emphasis>"="</Emphasis>"."
+            }),
+            InvalidMediaFeature::PrefixedRange {
+                name,
+                unprefixed,
+                range,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The media feature "<Emphasis>{name}</Emphasis>" can't be used in a range."
+                },
+            )
+            .note(markup! {
+                "The range syntax replaces the "<Emphasis>"min-"</Emphasis>" and "<Emphasis>"max-"</Emphasis>" prefixes: compare "<Emphasis>{unprefixed}</Emphasis>" instead."
+            }),
+        };
+        Some(diagnostic.note(markup! {
+            "The media query is invalid, so its rules are never applied."
+        }))
+    }
+}
+
+/// Returns the lowercase text of `identifier`
+fn identifier_value(identifier: &CssIdentifier) -> Option<String> {
+    Some(
+        identifier
+            .value_token()
+            .ok()?
+            .text_trimmed()
+            .to_ascii_lowercase(),
+    )
+}
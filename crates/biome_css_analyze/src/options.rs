@@ -2,21 +2,31 @@
 
 use crate::lint;
 
+pub type NoCommentOnlyBlock =
+    <lint::nursery::no_comment_only_block::NoCommentOnlyBlock as biome_analyze::Rule>::Options;
 pub type NoDuplicateAtImportRules = < lint :: nursery :: no_duplicate_at_import_rules :: NoDuplicateAtImportRules as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateFontNames =
     <lint::nursery::no_duplicate_font_names::NoDuplicateFontNames as biome_analyze::Rule>::Options;
+pub type NoDuplicateLayerNames = < lint :: nursery :: no_duplicate_layer_names :: NoDuplicateLayerNames as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateSelectorsKeyframeBlock = < lint :: nursery :: no_duplicate_selectors_keyframe_block :: NoDuplicateSelectorsKeyframeBlock as biome_analyze :: Rule > :: Options ;
 pub type NoEmptyBlock =
     <lint::nursery::no_empty_block::NoEmptyBlock as biome_analyze::Rule>::Options;
+pub type NoExcessiveImportant =
+    <lint::nursery::no_excessive_important::NoExcessiveImportant as biome_analyze::Rule>::Options;
 pub type NoImportantInKeyframe = < lint :: nursery :: no_important_in_keyframe :: NoImportantInKeyframe as biome_analyze :: Rule > :: Options ;
+pub type NoInvalidContainerQueryFeature = < lint :: nursery :: no_invalid_container_query_feature :: NoInvalidContainerQueryFeature as biome_analyze :: Rule > :: Options ;
 pub type NoInvalidDirectionInLinearGradient = < lint :: nursery :: no_invalid_direction_in_linear_gradient :: NoInvalidDirectionInLinearGradient as biome_analyze :: Rule > :: Options ;
 pub type NoInvalidPositionAtImportRule = < lint :: nursery :: no_invalid_position_at_import_rule :: NoInvalidPositionAtImportRule as biome_analyze :: Rule > :: Options ;
 pub type NoIrregularWhitespace =
     <lint::nursery::no_irregular_whitespace::NoIrregularWhitespace as biome_analyze::Rule>::Options;
+pub type NoMissingVarFunctionFallback = < lint :: nursery :: no_missing_var_function_fallback :: NoMissingVarFunctionFallback as biome_analyze :: Rule > :: Options ;
 pub type NoShorthandPropertyOverrides = < lint :: nursery :: no_shorthand_property_overrides :: NoShorthandPropertyOverrides as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownContainerName = < lint :: nursery :: no_unknown_container_name :: NoUnknownContainerName as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownCustomProperty = < lint :: nursery :: no_unknown_custom_property :: NoUnknownCustomProperty as biome_analyze :: Rule > :: Options ;
 pub type NoUnknownFunction =
     <lint::nursery::no_unknown_function::NoUnknownFunction as biome_analyze::Rule>::Options;
 pub type NoUnknownMediaFeatureName = < lint :: nursery :: no_unknown_media_feature_name :: NoUnknownMediaFeatureName as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownMediaFeatureValue = < lint :: nursery :: no_unknown_media_feature_value :: NoUnknownMediaFeatureValue as biome_analyze :: Rule > :: Options ;
 pub type NoUnknownProperty =
     <lint::nursery::no_unknown_property::NoUnknownProperty as biome_analyze::Rule>::Options;
 pub type NoUnknownPseudoClassSelector = < lint :: nursery :: no_unknown_pseudo_class_selector :: NoUnknownPseudoClassSelector as biome_analyze :: Rule > :: Options ;
@@ -0,0 +1,115 @@
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssProperty, CssDeclaration, CssLanguage, CssPropertyAtRule, CssRoot,
+    CssSyntaxNode,
+};
+use biome_rowan::AstNode;
+use rustc_hash::FxHashSet;
+use std::sync::Arc;
+
+/// The custom properties declared across the CSS files of the project.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectCustomProperties {
+    names: Arc<FxHashSet<String>>,
+}
+
+impl ProjectCustomProperties {
+    pub fn new(names: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            names: Arc::new(names.into_iter().collect()),
+        }
+    }
+
+    /// Returns `true` if a file of the project declares the custom property `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+}
+
+/// Returns the names of the custom properties declared in `root`.
+///
+/// This includes the custom properties declared in any rule, such as `:root { --color: red; }`,
+/// and the custom properties registered with `@property`.
+pub fn declared_custom_properties(root: &CssRoot) -> FxHashSet<String> {
+    root.syntax()
+        .descendants()
+        .filter_map(|node| {
+            if let Some(declaration) = CssDeclaration::cast_ref(&node) {
+                let AnyCssProperty::CssGenericProperty(property) = declaration.property().ok()?
+                else {
+                    return None;
+                };
+                let AnyCssDeclarationName::CssDashedIdentifier(name) = property.name().ok()? else {
+                    return None;
+                };
+                Some(name.value_token().ok()?.text_trimmed().to_string())
+            } else if let Some(at_property) = CssPropertyAtRule::cast_ref(&node) {
+                let name = at_property.name().ok()?.value_token().ok()?;
+                Some(name.text_trimmed().to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomPropertiesServices {
+    project: ProjectCustomProperties,
+}
+
+impl CustomPropertiesServices {
+    pub fn project(&self) -> &ProjectCustomProperties {
+        &self.project
+    }
+}
+
+impl FromServices for CustomPropertiesServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        let project: &ProjectCustomProperties = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["ProjectCustomProperties"])
+        })?;
+        Ok(Self {
+            project: project.clone(),
+        })
+    }
+}
+
+impl Phase for CustomPropertiesServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the custom properties of the project** to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct CustomProperties<N>(pub N);
+
+impl<N> Queryable for CustomProperties<N>
+where
+    N: AstNode<Language = CssLanguage> + 'static,
+{
+    type Input = CssSyntaxNode;
+    type Output = N;
+
+    type Language = CssLanguage;
+    type Services = CustomPropertiesServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<CssLanguage>, _: &CssRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
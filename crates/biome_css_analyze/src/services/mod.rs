@@ -1 +1,2 @@
+pub mod custom_properties;
 pub mod semantic;
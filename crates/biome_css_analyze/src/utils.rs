@@ -5,10 +5,10 @@ use crate::keywords::{
     FONT_WEIGHT_ABSOLUTE_KEYWORDS, FONT_WEIGHT_NUMERIC_KEYWORDS, FUNCTION_KEYWORDS,
     KNOWN_CHROME_PROPERTIES, KNOWN_EDGE_PROPERTIES, KNOWN_EXPLORER_PROPERTIES,
     KNOWN_FIREFOX_PROPERTIES, KNOWN_PROPERTIES, KNOWN_SAFARI_PROPERTIES,
-    KNOWN_SAMSUNG_INTERNET_PROPERTIES, KNOWN_US_BROWSER_PROPERTIES,
+    KNOWN_SAMSUNG_INTERNET_PROPERTIES, KNOWN_US_BROWSER_PROPERTIES, LENGTH_UNITS,
     LEVEL_ONE_AND_TWO_PSEUDO_ELEMENTS, LINE_HEIGHT_KEYWORDS, LINGUISTIC_PSEUDO_CLASSES,
     LOGICAL_COMBINATIONS_PSEUDO_CLASSES, LONGHAND_SUB_PROPERTIES_OF_SHORTHAND_PROPERTIES,
-    MEDIA_FEATURE_NAMES, OTHER_PSEUDO_CLASSES, OTHER_PSEUDO_ELEMENTS,
+    MEDIA_FEATURE_KEYWORD_VALUES, MEDIA_FEATURE_NAMES, OTHER_PSEUDO_CLASSES, OTHER_PSEUDO_ELEMENTS,
     RESET_TO_INITIAL_PROPERTIES_BY_BORDER, RESET_TO_INITIAL_PROPERTIES_BY_FONT,
     RESOURCE_STATE_PSEUDO_CLASSES, SHADOW_TREE_PSEUDO_ELEMENTS, SHORTHAND_PROPERTIES,
     SYSTEM_FAMILY_NAME_KEYWORDS, VENDOR_PREFIXES, VENDOR_SPECIFIC_PSEUDO_ELEMENTS,
@@ -200,6 +200,19 @@ pub fn is_media_feature_name(prop: &str) -> bool {
     false
 }
 
+/// Returns the keywords accepted by the media feature `name`, if its values are keywords.
+pub fn get_media_feature_keyword_values(name: &str) -> Option<&'static [&'static str]> {
+    MEDIA_FEATURE_KEYWORD_VALUES
+        .binary_search_by(|(feature, _)| (*feature).cmp(name))
+        .ok()
+        .map(|index| MEDIA_FEATURE_KEYWORD_VALUES[index].1)
+}
+
+/// Check if the lowercase `unit` is the unit of a length.
+pub fn is_length_unit(unit: &str) -> bool {
+    LENGTH_UNITS.binary_search(&unit).is_ok()
+}
+
 pub fn get_longhand_sub_properties(shorthand_property: &str) -> &'static [&'static str] {
     if let Ok(index) = SHORTHAND_PROPERTIES.binary_search(&shorthand_property) {
         return LONGHAND_SUB_PROPERTIES_OF_SHORTHAND_PROPERTIES[index];
@@ -1,15 +1,30 @@
 use std::collections::VecDeque;
 
 use biome_css_syntax::{
-    AnyCssSelector, CssDeclarationBlock, CssRelativeSelector, CssSyntaxKind::*,
+    AnyCssQueryFeature, AnyCssScopeRange, AnyCssSelector, CssContainerAtRule,
+    CssDeclarationBlock, CssGenericComponentValueList, CssLayerNameList, CssRelativeSelector,
+    CssScopeAtRule, CssScopeEdge, CssSyntaxKind::*, CssSyntaxNode,
 };
-use biome_rowan::{AstNode, SyntaxNodeCast, TextRange};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, SyntaxNodeCast, TextRange};
 
 use crate::{
-    model::{CssProperty, CssValue},
+    model::{CssContainerName, CssLayerKind, CssProperty, CssQueryFeature, CssValue, Selector},
     semantic_model::model::Specificity,
 };
 
+/// The keywords that can't be used as container names
+const CONTAINER_NAME_KEYWORDS: [&str; 9] = [
+    "none",
+    "and",
+    "not",
+    "or",
+    "initial",
+    "inherit",
+    "revert",
+    "revert-layer",
+    "unset",
+];
+
 #[derive(Debug)]
 pub enum SemanticEvent {
     RuleStart(TextRange),
@@ -34,6 +49,29 @@ pub enum SemanticEvent {
         value: CssValue,
         range: TextRange,
     },
+    /// Indicates the declaration of a cascade layer by an `@layer` rule or an `@import` rule
+    LayerDeclaration {
+        name: String,
+        range: TextRange,
+        kind: CssLayerKind,
+    },
+    /// Indicates the declaration of a container name by the `container-name` or `container` properties
+    ContainerNameDeclaration {
+        name: String,
+        range: TextRange,
+    },
+    /// Indicates the start of an `@container` rule
+    ContainerQuery {
+        name: Option<CssContainerName>,
+        features: Vec<CssQueryFeature>,
+        range: TextRange,
+    },
+    /// Indicates the start of an `@scope` rule
+    ScopeDeclaration {
+        start: Vec<Selector>,
+        end: Vec<Selector>,
+        range: TextRange,
+    },
 }
 
 #[derive(Default, Debug)]
@@ -41,6 +79,8 @@ pub struct SemanticEventExtractor {
     stash: VecDeque<SemanticEvent>,
     current_rule_stack: Vec<TextRange>,
     in_root_selector: bool,
+    /// The full names of the enclosing layers, `None` for the anonymous layers
+    current_layer_stack: Vec<Option<String>>,
 }
 
 impl SemanticEventExtractor {
@@ -52,19 +92,45 @@ impl SemanticEventExtractor {
             //    Example: p { color: red; }
             // 2. Nested selectors
             //    Example: .parent { .child { font-size: 14px; } }
-            // 3. At-rules like media queries
+            // 3. At-rules like media queries, container queries, layers, and scopes
             //    Example: @media (min-width: 600px) { header { padding: 20px; } }
             //
             // Each rule start is pushed onto a stack to maintain parent-child relationships,
             // allowing for proper scoping and inheritance of styles.
-            kind if kind == CSS_QUALIFIED_RULE
-                || kind == CSS_NESTED_QUALIFIED_RULE
-                || kind == CSS_MEDIA_AT_RULE =>
-            {
+            kind if is_rule(kind) => {
                 let range = node.text_range();
                 self.stash.push_back(SemanticEvent::RuleStart(range));
                 self.current_rule_stack.push(range);
+                match kind {
+                    CSS_CONTAINER_AT_RULE => self.process_container_query(node),
+                    CSS_SCOPE_AT_RULE => self.process_scope(node),
+                    CSS_LAYER_DECLARATION => {
+                        // A layer with rules takes a single name, or none when it's anonymous
+                        let name = node
+                            .first_child()
+                            .and_then(|references| references.first_child())
+                            .and_then(CssLayerNameList::cast);
+                        let name = match name {
+                            Some(name) => self.add_layer_event(&name, CssLayerKind::Block),
+                            None => None,
+                        };
+                        self.current_layer_stack.push(name);
+                    }
+                    _ => {}
+                }
+            }
+            CSS_LAYER_REFERENCE_LIST if is_parent_kind(node, CSS_LAYER_REFERENCE) => {
+                for name in node.children().filter_map(CssLayerNameList::cast) {
+                    self.add_layer_event(&name, CssLayerKind::Statement);
+                }
             }
+            CSS_IMPORT_NAMED_LAYER => {
+                if let Some(name) = node.children().find_map(CssLayerNameList::cast) {
+                    self.add_layer_event(&name, CssLayerKind::Import);
+                }
+            }
+            // The selectors of the scoping roots and limits are collected with their `@scope` rule
+            CSS_SELECTOR_LIST if is_parent_kind(node, CSS_SCOPE_EDGE) => {}
             CSS_SELECTOR_LIST => {
                 node.children()
                     .filter_map(AnyCssSelector::cast)
@@ -79,6 +145,12 @@ impl SemanticEventExtractor {
             CSS_DECLARATION => {
                 if let Some(property_name) = node.first_child().and_then(|p| p.first_child()) {
                     if let Some(value) = property_name.next_sibling() {
+                        let name = property_name.text_trimmed().to_string();
+                        if name.eq_ignore_ascii_case("container-name")
+                            || name.eq_ignore_ascii_case("container")
+                        {
+                            self.process_container_names(&value);
+                        }
                         self.stash.push_back(SemanticEvent::PropertyDeclaration {
                             property: CssProperty {
                                 name: property_name.text_trimmed().to_string(),
@@ -162,6 +234,132 @@ impl SemanticEventExtractor {
         }
     }
 
+    /// Adds the container names of the value of a `container-name` or `container` property,
+    /// such as `sidebar` in `container: sidebar / inline-size`.
+    fn process_container_names(&mut self, value: &CssSyntaxNode) {
+        let Some(values) = value.clone().cast::<CssGenericComponentValueList>() else {
+            return;
+        };
+        for value in values.iter() {
+            // The container type follows the `/` of the `container` shorthand
+            let Some(value) = value.as_any_css_value() else {
+                break;
+            };
+            let Some(name) = value.as_css_identifier() else {
+                continue;
+            };
+            let Ok(name_token) = name.value_token() else {
+                continue;
+            };
+            let text = name_token.text_trimmed();
+            if CONTAINER_NAME_KEYWORDS
+                .iter()
+                .any(|keyword| text.eq_ignore_ascii_case(keyword))
+            {
+                continue;
+            }
+            self.stash
+                .push_back(SemanticEvent::ContainerNameDeclaration {
+                    name: text.to_string(),
+                    range: name.range(),
+                });
+        }
+    }
+
+    fn process_container_query(&mut self, node: &CssSyntaxNode) {
+        let Some(container) = CssContainerAtRule::cast_ref(node) else {
+            return;
+        };
+        let name = container.name().and_then(|name| {
+            let name_token = name.value_token().ok()?;
+            Some(CssContainerName {
+                name: name_token.text_trimmed().to_string(),
+                range: name.range(),
+            })
+        });
+        let features = container
+            .query()
+            .map(|query| {
+                query
+                    .syntax()
+                    .descendants()
+                    .filter(|node| {
+                        // The style queries test the values of properties, not size features
+                        !node
+                            .ancestors()
+                            .any(|ancestor| ancestor.kind() == CSS_CONTAINER_STYLE_QUERY_IN_PARENS)
+                    })
+                    .filter_map(AnyCssQueryFeature::cast)
+                    .filter_map(|feature| {
+                        let name = match feature {
+                            AnyCssQueryFeature::CssQueryFeaturePlain(feature) => feature.name(),
+                            AnyCssQueryFeature::CssQueryFeatureBoolean(feature) => feature.name(),
+                            AnyCssQueryFeature::CssQueryFeatureRange(feature) => feature.left(),
+                            AnyCssQueryFeature::CssQueryFeatureReverseRange(feature) => {
+                                feature.right()
+                            }
+                            AnyCssQueryFeature::CssQueryFeatureRangeInterval(feature) => {
+                                feature.name()
+                            }
+                        }
+                        .ok()?;
+                        Some(CssQueryFeature {
+                            name: name.value_token().ok()?.text_trimmed().to_string(),
+                            range: name.range(),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.stash.push_back(SemanticEvent::ContainerQuery {
+            name,
+            features,
+            range: node.text_range(),
+        });
+    }
+
+    fn process_scope(&mut self, node: &CssSyntaxNode) {
+        let Some(scope) = CssScopeAtRule::cast_ref(node) else {
+            return;
+        };
+        let (start, end) = match scope.range() {
+            Some(AnyCssScopeRange::CssScopeRangeStart(range)) => (range.start().ok(), None),
+            Some(AnyCssScopeRange::CssScopeRangeEnd(range)) => (None, range.end().ok()),
+            Some(AnyCssScopeRange::CssScopeRangeInterval(range)) => {
+                (range.start().ok(), range.end().ok())
+            }
+            Some(AnyCssScopeRange::CssBogusScopeRange(_)) | None => (None, None),
+        };
+        self.stash.push_back(SemanticEvent::ScopeDeclaration {
+            start: start.map(scope_edge_selectors).unwrap_or_default(),
+            end: end.map(scope_edge_selectors).unwrap_or_default(),
+            range: node.text_range(),
+        });
+    }
+
+    /// Adds the layer `name`, nested in the current layer, and returns its full name.
+    ///
+    /// The layers nested in an anonymous layer aren't added, because they can't be referenced.
+    fn add_layer_event(&mut self, name: &CssLayerNameList, kind: CssLayerKind) -> Option<String> {
+        let mut full_name = match self.current_layer_stack.last() {
+            Some(Some(parent)) => format!("{parent}."),
+            Some(None) => return None,
+            None => String::new(),
+        };
+        for (index, identifier) in name.iter().enumerate() {
+            if index > 0 {
+                full_name.push('.');
+            }
+            full_name.push_str(identifier.ok()?.value_token().ok()?.text_trimmed());
+        }
+        self.stash.push_back(SemanticEvent::LayerDeclaration {
+            name: full_name.clone(),
+            range: name.range(),
+            kind,
+        });
+        Some(full_name)
+    }
+
     fn add_selector_event(&mut self, name: String, range: TextRange) {
         self.stash.push_back(SemanticEvent::SelectorDeclaration {
             name,
@@ -171,10 +369,10 @@ impl SemanticEventExtractor {
     }
 
     pub fn leave(&mut self, node: &biome_css_syntax::CssSyntaxNode) {
-        if matches!(
-            node.kind(),
-            CSS_QUALIFIED_RULE | CSS_NESTED_QUALIFIED_RULE | CSS_MEDIA_AT_RULE
-        ) {
+        if is_rule(node.kind()) {
+            if node.kind() == CSS_LAYER_DECLARATION {
+                self.current_layer_stack.pop();
+            }
             self.current_rule_stack.pop();
             self.stash.push_back(SemanticEvent::RuleEnd);
             if self.in_root_selector {
@@ -188,3 +386,32 @@ impl SemanticEventExtractor {
         self.stash.pop_front()
     }
 }
+
+/// Returns `true` if the nodes of `kind` start a new CSS rule context
+fn is_rule(kind: biome_css_syntax::CssSyntaxKind) -> bool {
+    matches!(
+        kind,
+        CSS_QUALIFIED_RULE
+            | CSS_NESTED_QUALIFIED_RULE
+            | CSS_MEDIA_AT_RULE
+            | CSS_CONTAINER_AT_RULE
+            | CSS_SCOPE_AT_RULE
+            | CSS_LAYER_DECLARATION
+    )
+}
+
+fn is_parent_kind(node: &CssSyntaxNode, kind: biome_css_syntax::CssSyntaxKind) -> bool {
+    node.parent().is_some_and(|parent| parent.kind() == kind)
+}
+
+fn scope_edge_selectors(edge: CssScopeEdge) -> Vec<Selector> {
+    edge.selectors()
+        .iter()
+        .filter_map(|selector| selector.ok())
+        .map(|selector| Selector {
+            name: selector.text(),
+            range: selector.range(),
+            specificity: Specificity(0, 0, 0),
+        })
+        .collect()
+}
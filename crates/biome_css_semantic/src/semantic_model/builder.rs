@@ -2,7 +2,10 @@ use biome_css_syntax::{CssRoot, CssSyntaxKind, CssSyntaxNode};
 use biome_rowan::TextRange;
 use rustc_hash::FxHashMap;
 
-use super::model::{CssVariable, Declaration, Rule, Selector, SemanticModel, SemanticModelData};
+use super::model::{
+    CssContainerName, CssContainerQuery, CssLayer, CssScope, CssVariable, Declaration, Rule,
+    Selector, SemanticModel, SemanticModelData,
+};
 use crate::events::SemanticEvent;
 
 pub struct SemanticModelBuilder {
@@ -10,6 +13,10 @@ pub struct SemanticModelBuilder {
     node_by_range: FxHashMap<TextRange, CssSyntaxNode>,
     rules: Vec<Rule>,
     global_css_variables: FxHashMap<String, CssVariable>,
+    layers: Vec<CssLayer>,
+    container_names: FxHashMap<String, CssContainerName>,
+    container_queries: Vec<CssContainerQuery>,
+    scopes: Vec<CssScope>,
     current_rule_stack: Vec<Rule>,
     in_root_selector: bool,
 }
@@ -22,6 +29,10 @@ impl SemanticModelBuilder {
             rules: Vec::new(),
             current_rule_stack: Vec::new(),
             global_css_variables: FxHashMap::default(),
+            layers: Vec::new(),
+            container_names: FxHashMap::default(),
+            container_queries: Vec::new(),
+            scopes: Vec::new(),
             in_root_selector: false,
         }
     }
@@ -32,6 +43,10 @@ impl SemanticModelBuilder {
             node_by_range: self.node_by_range,
             rules: self.rules,
             global_css_variables: self.global_css_variables,
+            layers: self.layers,
+            container_names: self.container_names,
+            container_queries: self.container_queries,
+            scopes: self.scopes,
         };
         SemanticModel::new(data)
     }
@@ -126,6 +141,28 @@ impl SemanticModelBuilder {
                     },
                 );
             }
+            SemanticEvent::LayerDeclaration { name, range, kind } => {
+                self.layers.push(CssLayer { name, range, kind });
+            }
+            SemanticEvent::ContainerNameDeclaration { name, range } => {
+                self.container_names
+                    .entry(name.clone())
+                    .or_insert(CssContainerName { name, range });
+            }
+            SemanticEvent::ContainerQuery {
+                name,
+                features,
+                range,
+            } => {
+                self.container_queries.push(CssContainerQuery {
+                    name,
+                    features,
+                    range,
+                });
+            }
+            SemanticEvent::ScopeDeclaration { start, end, range } => {
+                self.scopes.push(CssScope { start, end, range });
+            }
         }
     }
 }
@@ -32,6 +32,7 @@ pub fn semantic_model(root: &CssRoot) -> SemanticModel {
 
 #[cfg(test)]
 mod tests {
+    use super::model::CssLayerKind;
     use biome_css_parser::parse_css;
     use biome_css_parser::CssParserOptions;
 
@@ -153,6 +154,104 @@ mod tests {
         assert!(custom_size);
     }
 
+    #[test]
+    fn test_layers() {
+        let parse = parse_css(
+            r#"@import "theme.css" layer(theme);
+@layer reset, framework;
+@layer framework {
+  @layer base {
+    a { color: red; }
+  }
+}
+@layer {
+  @layer hidden;
+}"#,
+            CssParserOptions::default(),
+        );
+
+        let root = parse.tree();
+        let model = super::semantic_model(&root);
+        let layers = model
+            .layers()
+            .iter()
+            .map(|layer| (layer.name.as_str(), layer.kind))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            layers,
+            [
+                ("theme", CssLayerKind::Import),
+                ("reset", CssLayerKind::Statement),
+                ("framework", CssLayerKind::Statement),
+                ("framework", CssLayerKind::Block),
+                ("framework.base", CssLayerKind::Block),
+            ]
+        );
+        let framework = model.rules().first().unwrap();
+        assert_eq!(framework.children.len(), 1);
+    }
+
+    #[test]
+    fn test_container_queries() {
+        let parse = parse_css(
+            r#".sidebar {
+  container: sidebar / inline-size;
+}
+.card {
+  container-name: card none;
+}
+@container sidebar (min-width: 400px) and (400px < height) {
+  a { color: red; }
+}
+@container style(--theme: dark) {
+  a { color: white; }
+}"#,
+            CssParserOptions::default(),
+        );
+
+        let root = parse.tree();
+        let model = super::semantic_model(&root);
+        let container_names = model.container_names();
+
+        assert_eq!(container_names.len(), 2);
+        assert!(container_names.contains_key("sidebar"));
+        assert!(container_names.contains_key("card"));
+
+        let queries = model.container_queries();
+        assert_eq!(queries.len(), 2);
+        assert_eq!(queries[0].name.as_ref().unwrap().name, "sidebar");
+        let features = queries[0]
+            .features
+            .iter()
+            .map(|feature| feature.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(features, ["min-width", "height"]);
+        assert!(queries[1].name.is_none());
+        assert!(queries[1].features.is_empty());
+    }
+
+    #[test]
+    fn test_scopes() {
+        let parse = parse_css(
+            r#"@scope (.card) to (.content) {
+  img { border: 1px solid; }
+}"#,
+            CssParserOptions::default(),
+        );
+
+        let root = parse.tree();
+        let model = super::semantic_model(&root);
+        let scope = model.scopes().first().unwrap();
+
+        assert_eq!(scope.start[0].name, ".card");
+        assert_eq!(scope.end[0].name, ".content");
+
+        let rule = model.rules().first().unwrap();
+        assert_eq!(rule.selectors.len(), 0);
+        assert_eq!(rule.children.len(), 1);
+    }
+
     #[test]
     fn debug() {
         let parse = parse_css(
@@ -37,6 +37,26 @@ impl SemanticModel {
     pub fn global_css_variables(&self) -> &FxHashMap<String, CssVariable> {
         &self.data.global_css_variables
     }
+
+    /// Returns the layers declared in the CSS document, in the order of their declarations.
+    pub fn layers(&self) -> &[CssLayer] {
+        &self.data.layers
+    }
+
+    /// Returns the container names declared with the `container-name` or `container` properties.
+    pub fn container_names(&self) -> &FxHashMap<String, CssContainerName> {
+        &self.data.container_names
+    }
+
+    /// Returns the queries of the `@container` rules of the CSS document.
+    pub fn container_queries(&self) -> &[CssContainerQuery] {
+        &self.data.container_queries
+    }
+
+    /// Returns the `@scope` rules of the CSS document.
+    pub fn scopes(&self) -> &[CssScope] {
+        &self.data.scopes
+    }
 }
 
 /// Contains the internal data of a `SemanticModel`.
@@ -52,6 +72,14 @@ pub(crate) struct SemanticModelData {
     pub(crate) rules: Vec<Rule>,
     /// Map of CSS variables declared in the `:root` selector or using the @property rule.
     pub(crate) global_css_variables: FxHashMap<String, CssVariable>,
+    /// List of the layers, in the order of their declarations
+    pub(crate) layers: Vec<CssLayer>,
+    /// Map of the container names to their first declaration
+    pub(crate) container_names: FxHashMap<String, CssContainerName>,
+    /// List of the queries of the `@container` rules
+    pub(crate) container_queries: Vec<CssContainerQuery>,
+    /// List of the `@scope` rules
+    pub(crate) scopes: Vec<CssScope>,
 }
 
 /// Represents a CSS rule set, including its selectors, declarations, and nested rules.
@@ -110,6 +138,78 @@ pub struct CssVariable {
     pub range: TextRange,
 }
 
+/// Represents the declaration of a cascade layer.
+///
+/// ```css
+/// @layer reset, base;             /* Statement */
+/// @layer base { }                 /* Block */
+/// @import "theme.css" layer(theme); /* Import */
+/// ```
+#[derive(Debug, Clone)]
+pub struct CssLayer {
+    /// The full name of the layer, such as `framework.base` for the layer `base` nested in the layer `framework`.
+    pub name: String,
+    /// The text range of the name in the source document.
+    pub range: TextRange,
+    pub kind: CssLayerKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CssLayerKind {
+    /// A layer listed in an `@layer` statement, such as `@layer reset, base;`
+    Statement,
+    /// A layer declared with its rules, such as `@layer base { }`
+    Block,
+    /// A layer of an imported style sheet, such as `@import "theme.css" layer(theme);`
+    Import,
+}
+
+/// Represents a container name, either declared by the `container-name` or `container` properties,
+/// or referenced by an `@container` rule.
+#[derive(Debug, Clone)]
+pub struct CssContainerName {
+    pub name: String,
+    pub range: TextRange,
+}
+
+/// Represents the query of an `@container` rule.
+///
+/// ```css
+/// @container sidebar (min-width: 400px) { }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CssContainerQuery {
+    /// The name of the queried container, if any.
+    pub name: Option<CssContainerName>,
+    /// The size features of the query, such as `min-width`.
+    /// The features of the style queries, such as `style(--theme: dark)`, aren't included.
+    pub features: Vec<CssQueryFeature>,
+    /// The text range of the `@container` rule in the source document.
+    pub range: TextRange,
+}
+
+/// Represents the name of a feature in a query, such as `width` in `(width > 400px)`.
+#[derive(Debug, Clone)]
+pub struct CssQueryFeature {
+    pub name: String,
+    pub range: TextRange,
+}
+
+/// Represents an `@scope` rule.
+///
+/// ```css
+/// @scope (.card) to (.content) { }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CssScope {
+    /// The selectors of the scoping roots, such as `.card`.
+    pub start: Vec<Selector>,
+    /// The selectors of the scoping limits, such as `.content`.
+    pub end: Vec<Selector>,
+    /// The text range of the `@scope` rule in the source document.
+    pub range: TextRange,
+}
+
 /// Represents a CSS selector.
 #[derive(Debug, Clone)]
 pub struct Selector {
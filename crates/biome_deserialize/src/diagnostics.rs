@@ -157,10 +157,18 @@ impl DeserializationDiagnostic {
     }
 
     /// Emitted when there's an unknown key, against a set of known ones
+    ///
+    /// When a known key is similar to the unknown key, such as a key with a typo, it is suggested.
     pub fn new_unknown_key(key_name: &str, range: impl AsSpan, allowed_keys: &[&str]) -> Self {
-        Self::new(markup!("Found an unknown key `"<Emphasis>{key_name}</Emphasis>"`." ))
-            .with_range(range)
-            .note_with_list("Known keys:", allowed_keys)
+        let diagnostic =
+            Self::new(markup!("Found an unknown key `"<Emphasis>{key_name}</Emphasis>"`." ))
+                .with_range(range);
+        let diagnostic = match find_similar(key_name, allowed_keys) {
+            Some(similar_key) => diagnostic
+                .with_note(markup!("Did you mean `"<Emphasis>{similar_key}</Emphasis>"`?")),
+            None => diagnostic,
+        };
+        diagnostic.note_with_list("Known keys:", allowed_keys)
     }
 
     /// Emitted when there's an unknown value, against a set of known ones
@@ -235,6 +243,36 @@ impl From<SyntaxError> for DeserializationDiagnostic {
     }
 }
 
+/// Returns the candidate that is the most similar to `name`, if it's similar enough to be a typo of `name`.
+///
+/// The similarity is the case-insensitive edit distance between the names.
+fn find_similar<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(2);
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the Levenshtein distance between `left` and `right`, ignoring the case
+fn edit_distance(left: &str, right: &str) -> usize {
+    let left: Vec<_> = left.chars().flat_map(char::to_lowercase).collect();
+    let right: Vec<_> = right.chars().flat_map(char::to_lowercase).collect();
+    let mut previous_row: Vec<_> = (0..=right.len()).collect();
+    for (i, left_char) in left.iter().enumerate() {
+        let mut row = Vec::with_capacity(right.len() + 1);
+        row.push(i + 1);
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous_row[j] + usize::from(left_char != right_char);
+            row.push(substitution.min(previous_row[j + 1] + 1).min(row[j] + 1));
+        }
+        previous_row = row;
+    }
+    previous_row[right.len()]
+}
+
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 pub struct DeserializationAdvice {
     notes: Vec<(MarkupBuf, Vec<MarkupBuf>)>,
@@ -269,6 +307,15 @@ impl Advices for DeserializationAdvice {
 mod test {
     use super::*;
 
+    #[test]
+    fn test_find_similar() {
+        let candidates = ["hooks", "noDebugger", "strictCase"];
+        assert_eq!(find_similar("hook", &candidates), Some("hooks"));
+        assert_eq!(find_similar("noDebuger", &candidates), Some("noDebugger"));
+        assert_eq!(find_similar("STRICTCASE", &candidates), Some("strictCase"));
+        assert_eq!(find_similar("foo", &candidates), None);
+    }
+
     #[test]
     fn test_visitable_type_fmt() {
         assert_eq!(DeserializableTypes::empty().to_string(), "no value");
@@ -114,69 +114,118 @@ define_categories! {
     "lint/correctness/useValidForDirection": "https://biomejs.dev/linter/rules/use-valid-for-direction",
     "lint/correctness/useYield": "https://biomejs.dev/linter/rules/use-yield",
     "lint/nursery/colorNoInvalidHex": "https://biomejs.dev/linter/rules/color-no-invalid-hex",
+    "lint/nursery/noAwaitInLoop": "https://biomejs.dev/linter/rules/no-await-in-loop",
+    "lint/nursery/noCircularDomainDependencies": "https://biomejs.dev/linter/rules/no-circular-domain-dependencies",
     "lint/nursery/noColorInvalidHex": "https://biomejs.dev/linter/rules/no-color-invalid-hex",
+    "lint/nursery/noCommentOnlyBlock": "https://biomejs.dev/linter/rules/no-comment-only-block",
     "lint/nursery/noConsole": "https://biomejs.dev/linter/rules/no-console",
     "lint/nursery/noDoneCallback": "https://biomejs.dev/linter/rules/no-done-callback",
+    "lint/nursery/noDoubleAssertion": "https://biomejs.dev/linter/rules/no-double-assertion",
     "lint/nursery/noDuplicateAtImportRules": "https://biomejs.dev/linter/rules/no-duplicate-at-import-rules",
     "lint/nursery/noDuplicateElseIf": "https://biomejs.dev/linter/rules/no-duplicate-else-if",
     "lint/nursery/noDuplicateFontNames": "https://biomejs.dev/linter/rules/no-font-family-duplicate-names",
+    "lint/nursery/noDuplicateImportsAcrossFiles": "https://biomejs.dev/linter/rules/no-duplicate-imports-across-files",
     "lint/nursery/noDuplicateJsonKeys": "https://biomejs.dev/linter/rules/no-duplicate-json-keys",
+    "lint/nursery/noDuplicateLayerNames": "https://biomejs.dev/linter/rules/no-duplicate-layer-names",
     "lint/nursery/noDuplicateSelectorsKeyframeBlock": "https://biomejs.dev/linter/rules/no-duplicate-selectors-keyframe-block",
     "lint/nursery/noDuplicatedFields": "https://biomejs.dev/linter/rules/no-duplicated-fields",
     "lint/nursery/noDynamicNamespaceImportAccess": "https://biomejs.dev/linter/rules/no-dynamic-namespace-import-access",
+    "lint/nursery/noEmptyAlternatives": "https://biomejs.dev/linter/rules/no-empty-alternatives",
     "lint/nursery/noEmptyBlock": "https://biomejs.dev/linter/rules/no-empty-block",
     "lint/nursery/noEvolvingTypes": "https://biomejs.dev/linter/rules/no-evolving-types",
+    "lint/nursery/noExcessiveImportant": "https://biomejs.dev/linter/rules/no-excessive-important",
     "lint/nursery/noExportedImports": "https://biomejs.dev/linter/rules/no-exported-imports",
+    "lint/nursery/noImplicitSpreadProps": "https://biomejs.dev/linter/rules/no-implicit-spread-props",
     "lint/nursery/noImportantInKeyframe": "https://biomejs.dev/linter/rules/no-important-in-keyframe",
+    "lint/nursery/noInvalidContainerQueryFeature": "https://biomejs.dev/linter/rules/no-invalid-container-query-feature",
     "lint/nursery/noInvalidDirectionInLinearGradient": "https://biomejs.dev/linter/rules/no-invalid-direction-in-linear-gradient",
     "lint/nursery/noInvalidPositionAtImportRule": "https://biomejs.dev/linter/rules/no-invalid-position-at-import-rule",
     "lint/nursery/noIrregularWhitespace": "https://biomejs.dev/linter/rules/no-irregular-whitespace",
+    "lint/nursery/noJsxLiteralsInAttributesNeedingIds": "https://biomejs.dev/linter/rules/no-jsx-literals-in-attributes-needing-ids",
     "lint/nursery/noLabelWithoutControl": "https://biomejs.dev/linter/rules/no-label-without-control",
+    "lint/nursery/noLeakedEventListeners": "https://biomejs.dev/linter/rules/no-leaked-event-listeners",
     "lint/nursery/noMisplacedAssertion": "https://biomejs.dev/linter/rules/no-misplaced-assertion",
+    "lint/nursery/noMissingVarFunctionFallback": "https://biomejs.dev/linter/rules/no-missing-var-function-fallback",
     "lint/nursery/noMissingGenericFamilyKeyword": "https://biomejs.dev/linter/rules/no-missing-generic-family-keyword",
+    "lint/nursery/noMutatingPropsOrState": "https://biomejs.dev/linter/rules/no-mutating-props-or-state",
+    "lint/nursery/noNonNullAssertedOptionalChain": "https://biomejs.dev/linter/rules/no-non-null-asserted-optional-chain",
+    "lint/nursery/noProto": "https://biomejs.dev/linter/rules/no-proto",
     "lint/nursery/noReactSpecificProps": "https://biomejs.dev/linter/rules/no-react-specific-props",
     "lint/nursery/noRestrictedImports": "https://biomejs.dev/linter/rules/no-restricted-imports",
     "lint/nursery/noShorthandPropertyOverrides": "https://biomejs.dev/linter/rules/no-shorthand-property-overrides",
+    "lint/nursery/noSideEffectImportsInLibraries": "https://biomejs.dev/linter/rules/no-side-effect-imports-in-libraries",
     "lint/nursery/noStaticElementInteractions": "https://biomejs.dev/linter/rules/no-static-element-interactions",
     "lint/nursery/noSubstr": "https://biomejs.dev/linter/rules/no-substr",
+    "lint/nursery/noSuperlinearBacktracking": "https://biomejs.dev/linter/rules/no-superlinear-backtracking",
+    "lint/nursery/noSwallowedErrors": "https://biomejs.dev/linter/rules/no-swallowed-errors",
+    "lint/nursery/noTranslationLiterals": "https://biomejs.dev/linter/rules/no-translation-literals",
     "lint/nursery/noUndeclaredDependencies": "https://biomejs.dev/linter/rules/no-undeclared-dependencies",
+    "lint/nursery/noUnhandledRejections": "https://biomejs.dev/linter/rules/no-unhandled-rejections",
+    "lint/nursery/noUnknownAttribute": "https://biomejs.dev/linter/rules/no-unknown-attribute",
+    "lint/nursery/noUnknownContainerName": "https://biomejs.dev/linter/rules/no-unknown-container-name",
+    "lint/nursery/noUnknownCustomProperty": "https://biomejs.dev/linter/rules/no-unknown-custom-property",
     "lint/nursery/noUnknownFunction": "https://biomejs.dev/linter/rules/no-unknown-function",
     "lint/nursery/noUnknownMediaFeatureName": "https://biomejs.dev/linter/rules/no-unknown-media-feature-name",
+    "lint/nursery/noUnknownMediaFeatureValue": "https://biomejs.dev/linter/rules/no-unknown-media-feature-value",
     "lint/nursery/noUnknownProperty": "https://biomejs.dev/linter/rules/no-unknown-property",
     "lint/nursery/noUnknownPseudoClassSelector": "https://biomejs.dev/linter/rules/no-unknown-pseudo-class-selector",
     "lint/nursery/noUnknownSelectorPseudoElement": "https://biomejs.dev/linter/rules/no-unknown-selector-pseudo-element",
     "lint/nursery/noUnknownUnit": "https://biomejs.dev/linter/rules/no-unknown-unit",
     "lint/nursery/noUnmatchableAnbSelector": "https://biomejs.dev/linter/rules/no-unmatchable-anb-selector",
+    "lint/nursery/noUnnecessaryNonNullAssertion": "https://biomejs.dev/linter/rules/no-unnecessary-non-null-assertion",
+    "lint/nursery/noUntrackedPropsAccess": "https://biomejs.dev/linter/rules/no-untracked-props-access",
+    "lint/nursery/noUntrackedSignalCalls": "https://biomejs.dev/linter/rules/no-untracked-signal-calls",
     "lint/nursery/noUnusedFunctionParameters": "https://biomejs.dev/linter/rules/no-unused-function-parameters",
+    "lint/nursery/noUnvalidatedEnvAccess": "https://biomejs.dev/linter/rules/no-unvalidated-env-access",
     "lint/nursery/noUselessEscapeInRegex": "https://biomejs.dev/linter/rules/no-useless-escape-in-regex",
     "lint/nursery/noUselessStringConcat": "https://biomejs.dev/linter/rules/no-useless-string-concat",
     "lint/nursery/noUselessUndefinedInitialization": "https://biomejs.dev/linter/rules/no-useless-undefined-initialization",
     "lint/nursery/noValueAtRule": "https://biomejs.dev/linter/rules/no-value-at-rule",
+    "lint/nursery/noVueDuplicateKeys": "https://biomejs.dev/linter/rules/no-vue-duplicate-keys",
+    "lint/nursery/noVueReservedProps": "https://biomejs.dev/linter/rules/no-vue-reserved-props",
+    "lint/nursery/noVueSideEffectsInComputed": "https://biomejs.dev/linter/rules/no-vue-side-effects-in-computed",
     "lint/nursery/noYodaExpression": "https://biomejs.dev/linter/rules/no-yoda-expression",
     "lint/nursery/useAdjacentOverloadSignatures": "https://biomejs.dev/linter/rules/use-adjacent-overload-signatures",
+    "lint/nursery/useAtIndex": "https://biomejs.dev/linter/rules/use-at-index",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
     "lint/nursery/useConsistentBuiltinInstantiation": "https://biomejs.dev/linter/rules/use-consistent-new-builtin",
     "lint/nursery/useConsistentCurlyBraces": "https://biomejs.dev/linter/rules/use-consistent-curly-braces",
+    "lint/nursery/useConsistentGenericConstructors": "https://biomejs.dev/linter/rules/use-consistent-generic-constructors",
     "lint/nursery/useConsistentGridAreas": "https://biomejs.dev/linter/rules/use-consistent-grid-areas",
+    "lint/nursery/useConsistentObjectDefinitions": "https://biomejs.dev/linter/rules/use-consistent-object-definitions",
+    "lint/nursery/useConsistentRecordType": "https://biomejs.dev/linter/rules/use-consistent-record-type",
+    "lint/nursery/useConsistentResponseHandling": "https://biomejs.dev/linter/rules/use-consistent-response-handling",
     "lint/nursery/useDateNow": "https://biomejs.dev/linter/rules/use-date-now",
     "lint/nursery/useDefaultSwitchClause": "https://biomejs.dev/linter/rules/use-default-switch-clause",
     "lint/nursery/useDeprecatedReason": "https://biomejs.dev/linter/rules/use-deprecated-reason",
+    "lint/nursery/useErrorCause": "https://biomejs.dev/linter/rules/use-error-cause",
     "lint/nursery/useErrorMessage": "https://biomejs.dev/linter/rules/use-error-message",
+    "lint/nursery/useExhaustiveSwitchCases": "https://biomejs.dev/linter/rules/use-exhaustive-switch-cases",
     "lint/nursery/useExplicitLengthCheck": "https://biomejs.dev/linter/rules/use-explicit-length-check",
     "lint/nursery/useFocusableInteractive": "https://biomejs.dev/linter/rules/use-focusable-interactive",
     "lint/nursery/useGenericFontNames": "https://biomejs.dev/linter/rules/use-generic-font-names",
+    "lint/nursery/useGuardForIn": "https://biomejs.dev/linter/rules/use-guard-for-in",
     "lint/nursery/useImportExtensions": "https://biomejs.dev/linter/rules/use-import-extensions",
     "lint/nursery/useImportRestrictions": "https://biomejs.dev/linter/rules/use-import-restrictions",
+    "lint/nursery/useIncludes": "https://biomejs.dev/linter/rules/use-includes",
+    "lint/nursery/useJsDocParamsMatch": "https://biomejs.dev/linter/rules/use-js-doc-params-match",
     "lint/nursery/useJsxCurlyBraceConvention": "https://biomejs.dev/linter/rules/use-jsx-curly-brace-convention",
     "lint/nursery/useNumberToFixedDigitsArgument": "https://biomejs.dev/linter/rules/use-number-to-fixed-digits-argument",
+    "lint/nursery/useNumericSeparators": "https://biomejs.dev/linter/rules/use-numeric-separators",
+    "lint/nursery/useObjectSpread": "https://biomejs.dev/linter/rules/use-object-spread",
+    "lint/nursery/useOptimizedRegex": "https://biomejs.dev/linter/rules/use-optimized-regex",
+    "lint/nursery/usePreactSignalsCorrectly": "https://biomejs.dev/linter/rules/use-preact-signals-correctly",
     "lint/nursery/useSemanticElements": "https://biomejs.dev/linter/rules/use-semantic-elements",
     "lint/nursery/useSortedClasses": "https://biomejs.dev/linter/rules/use-sorted-classes",
+    "lint/nursery/useSortedObjectKeys": "https://biomejs.dev/linter/rules/use-sorted-object-keys",
+    "lint/nursery/useSpread": "https://biomejs.dev/linter/rules/use-spread",
     "lint/nursery/useStrictMode": "https://biomejs.dev/linter/rules/use-strict-mode",
     "lint/nursery/useThrowNewError": "https://biomejs.dev/linter/rules/use-throw-new-error",
     "lint/nursery/useThrowOnlyError": "https://biomejs.dev/linter/rules/use-throw-only-error",
     "lint/nursery/useTopLevelRegex": "https://biomejs.dev/linter/rules/use-top-level-regex",
     "lint/nursery/useTrimStartEnd": "https://biomejs.dev/linter/rules/use-trim-start-end",
     "lint/nursery/useValidAutocomplete": "https://biomejs.dev/linter/rules/use-valid-autocomplete",
+    "lint/nursery/useValidI18nKeys": "https://biomejs.dev/linter/rules/use-valid-i18n-keys",
     "lint/performance/noAccumulatingSpread": "https://biomejs.dev/linter/rules/no-accumulating-spread",
     "lint/performance/noBarrelFile": "https://biomejs.dev/linter/rules/no-barrel-file",
     "lint/performance/noDelete": "https://biomejs.dev/linter/rules/no-delete",
@@ -284,7 +333,15 @@ define_categories! {
     "lint/suspicious/useValidTypeof": "https://biomejs.dev/linter/rules/use-valid-typeof",
     // end lint rules
     // start assists rules
+    "assists/source/useArrowFunctionSyntax": "https://biomejs.dev/linter/actions/use-arrow-function-syntax",
+    "assists/source/useFunctionSyntax": "https://biomejs.dev/linter/actions/use-function-syntax",
+    "assists/source/useJsdocComment": "https://biomejs.dev/linter/actions/use-jsdoc-comment",
+    "assists/source/useSortedAttributes": "https://biomejs.dev/linter/actions/use-sorted-attributes",
     "assists/source/useSortedKeys": "https://biomejs.dev/linter/actions/use-sorted-keys",
+    "assists/source/useSortedTypeMembers": "https://biomejs.dev/linter/actions/use-sorted-type-members",
+    "assists/source/useStringConcatenation": "https://biomejs.dev/linter/actions/use-string-concatenation",
+    "assists/source/useStringLiteralUnion": "https://biomejs.dev/linter/actions/use-string-literal-union",
+    "assists/source/useTemplateLiteral": "https://biomejs.dev/linter/actions/use-template-literal",
     // end assists rules
     ; // start syntax rules
     "syntax/nursery/noTypeOnlyImportAttributes",
@@ -305,9 +362,11 @@ define_categories! {
     "deserialize",
     "project",
     "search",
+    "plugin",
     "internalError/io",
     "internalError/fs",
     "internalError/panic",
+    "internalError/timeout",
     // parse categories
     "parse",
 
@@ -328,6 +387,7 @@ define_categories! {
     "suppressions/unknownRule",
     "suppressions/unused",
     "suppressions/deprecatedSuppressionComment",
+    "suppressions/expired",
 
     // Used in tests and examples
     "args/fileNotFound",
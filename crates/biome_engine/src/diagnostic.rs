@@ -0,0 +1,52 @@
+use biome_diagnostics::{Diagnostic, PrintDescription};
+use std::ops::Range;
+
+/// A diagnostic of the parser or of the linter, returned by [EngineFile::lint](crate::EngineFile::lint)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LintDiagnostic {
+    /// The category of the diagnostic, such as `lint/suspicious/noDebugger`
+    pub category: Option<String>,
+    /// The severity of the diagnostic
+    pub severity: Severity,
+    /// The message of the diagnostic, without its advices
+    pub message: String,
+    /// The range of the content of the file that the diagnostic is about, in bytes
+    pub range: Option<Range<usize>>,
+}
+
+/// The severity of a [LintDiagnostic]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Severity {
+    /// A suggestion, that doesn't need to be addressed
+    Hint,
+    /// A piece of information about the code
+    Information,
+    /// A problem that doesn't make the code invalid
+    Warning,
+    /// A problem that needs to be fixed
+    Error,
+    /// A problem that stopped the processing of the file
+    Fatal,
+}
+
+impl LintDiagnostic {
+    pub(crate) fn new(diagnostic: &biome_diagnostics::serde::Diagnostic) -> Self {
+        Self {
+            category: diagnostic
+                .category()
+                .map(|category| category.name().to_string()),
+            severity: match diagnostic.severity() {
+                biome_diagnostics::Severity::Hint => Severity::Hint,
+                biome_diagnostics::Severity::Information => Severity::Information,
+                biome_diagnostics::Severity::Warning => Severity::Warning,
+                biome_diagnostics::Severity::Error => Severity::Error,
+                biome_diagnostics::Severity::Fatal => Severity::Fatal,
+            },
+            message: PrintDescription(diagnostic).to_string(),
+            range: diagnostic
+                .location()
+                .span
+                .map(|range| usize::from(range.start())..usize::from(range.end())),
+        }
+    }
+}
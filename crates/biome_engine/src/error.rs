@@ -0,0 +1,58 @@
+use biome_diagnostics::{Diagnostic, Error, PrintDescription};
+use std::fmt::{Debug, Display, Formatter};
+
+/// The error returned by the operations of the engine
+///
+/// It holds the diagnostics that explain the error.
+pub struct EngineError {
+    diagnostics: Vec<Error>,
+}
+
+impl EngineError {
+    pub(crate) fn from_diagnostics(diagnostics: Vec<Error>) -> Self {
+        Self { diagnostics }
+    }
+
+    /// Returns the messages of the diagnostics that explain the error.
+    pub fn messages(&self) -> impl Iterator<Item = String> + '_ {
+        self.diagnostics
+            .iter()
+            .map(|diagnostic| PrintDescription(diagnostic).to_string())
+    }
+}
+
+impl<T> From<T> for EngineError
+where
+    T: Diagnostic + Send + Sync + 'static,
+{
+    fn from(diagnostic: T) -> Self {
+        Self::from_diagnostics(vec![Error::from(diagnostic)])
+    }
+}
+
+impl Debug for EngineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.diagnostics.iter().map(|diagnostic| {
+                (
+                    diagnostic.category().map(|category| category.name()),
+                    PrintDescription(diagnostic).to_string(),
+                )
+            }))
+            .finish()
+    }
+}
+
+impl Display for EngineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, diagnostic) in self.diagnostics.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", PrintDescription(diagnostic))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for EngineError {}
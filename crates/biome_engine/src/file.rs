@@ -0,0 +1,143 @@
+use crate::{Engine, EngineError, LintDiagnostic};
+use biome_analyze::RuleCategoriesBuilder;
+use biome_fs::BiomePath;
+use biome_service::workspace::{
+    FeaturesBuilder, FileFeaturesResult, FixFileMode, FixFileParams, FormatFileParams,
+    GetFileContentParams, PullDiagnosticsParams, SupportsFeatureParams,
+};
+use std::path::Path;
+
+/// A file opened in an [Engine], closed when its last handle is dropped
+pub struct EngineFile<'engine> {
+    engine: &'engine Engine,
+    path: BiomePath,
+}
+
+/// The fixes applied by [EngineFile::fix]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FixMode {
+    /// Applies the safe fixes
+    Safe,
+    /// Applies the safe and the unsafe fixes
+    SafeAndUnsafe,
+}
+
+/// The diagnostics of [EngineFile::lint]
+#[derive(Debug)]
+pub struct LintResult {
+    /// The diagnostics of the parser and the linter
+    pub diagnostics: Vec<LintDiagnostic>,
+    /// The number of diagnostics that are errors
+    pub errors: usize,
+}
+
+impl<'engine> EngineFile<'engine> {
+    pub(crate) fn new(engine: &'engine Engine, path: BiomePath) -> Self {
+        Self { engine, path }
+    }
+
+    /// Returns the path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the current content of the file.
+    pub fn content(&self) -> Result<String, EngineError> {
+        Ok(self
+            .engine
+            .workspace
+            .get_file_content(GetFileContentParams {
+                path: self.path.clone(),
+            })?)
+    }
+
+    /// Replaces the content of the file, shared by all the handles of its path.
+    pub fn update(&mut self, content: impl Into<String>) -> Result<(), EngineError> {
+        self.engine.change_document(&self.path, content.into())
+    }
+
+    /// Returns the formatted content of the file.
+    ///
+    /// Returns `None` if the file can't be formatted,
+    /// because the configuration ignores it or disables the formatter, or because its language isn't supported.
+    pub fn format(&self) -> Result<Option<String>, EngineError> {
+        if !self.features()?.supports_format() {
+            return Ok(None);
+        }
+        let printed = self.engine.workspace.format_file(FormatFileParams {
+            path: self.path.clone(),
+        })?;
+        Ok(Some(printed.into_code()))
+    }
+
+    /// Returns the diagnostics of the parser and of the linter for the file.
+    ///
+    /// Returns `None` if the file can't be linted,
+    /// because the configuration ignores it or disables the linter, or because its language isn't supported.
+    pub fn lint(&self) -> Result<Option<LintResult>, EngineError> {
+        if !self.features()?.supports_lint() {
+            return Ok(None);
+        }
+        let result = self
+            .engine
+            .workspace
+            .pull_diagnostics(PullDiagnosticsParams {
+                path: self.path.clone(),
+                categories: RuleCategoriesBuilder::default()
+                    .with_syntax()
+                    .with_lint()
+                    .build(),
+                max_diagnostics: u64::MAX,
+                only: Vec::new(),
+                skip: Vec::new(),
+                timeout: None,
+                profile: false,
+            })?;
+        Ok(Some(LintResult {
+            diagnostics: result.diagnostics.iter().map(LintDiagnostic::new).collect(),
+            errors: result.errors,
+        }))
+    }
+
+    /// Returns the content of the file with the fixes of the linter applied.
+    ///
+    /// The content of the opened file isn't changed, use [EngineFile::update] to apply the fixes.
+    /// Returns `None` if the file can't be linted.
+    pub fn fix(&self, mode: FixMode) -> Result<Option<String>, EngineError> {
+        if !self.features()?.supports_lint() {
+            return Ok(None);
+        }
+        let result = self.engine.workspace.fix_file(FixFileParams {
+            path: self.path.clone(),
+            fix_file_mode: match mode {
+                FixMode::Safe => FixFileMode::SafeFixes,
+                FixMode::SafeAndUnsafe => FixFileMode::SafeAndUnsafeFixes,
+            },
+            should_format: false,
+            only: Vec::new(),
+            skip: Vec::new(),
+            rule_categories: RuleCategoriesBuilder::default()
+                .with_syntax()
+                .with_lint()
+                .build(),
+            timeout: None,
+        })?;
+        Ok(Some(result.code))
+    }
+
+    fn features(&self) -> Result<FileFeaturesResult, EngineError> {
+        Ok(self.engine.workspace.file_features(SupportsFeatureParams {
+            path: self.path.clone(),
+            features: FeaturesBuilder::new()
+                .with_formatter()
+                .with_linter()
+                .build(),
+        })?)
+    }
+}
+
+impl Drop for EngineFile<'_> {
+    fn drop(&mut self) {
+        self.engine.release_document(&self.path);
+    }
+}
@@ -0,0 +1,16 @@
+use std::path::PathBuf;
+
+/// A file system held in memory, to run an [Engine](crate::Engine) on files that aren't written on the disk
+#[derive(Default)]
+pub struct MemoryFileSystem(biome_fs::MemoryFileSystem);
+
+impl MemoryFileSystem {
+    /// Creates the file at `path`, or replaces its content.
+    pub fn insert(&mut self, path: impl Into<PathBuf>, content: impl Into<Vec<u8>>) {
+        self.0.insert(path.into(), content);
+    }
+
+    pub(crate) fn into_inner(self) -> biome_fs::MemoryFileSystem {
+        self.0
+    }
+}
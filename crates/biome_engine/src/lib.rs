@@ -0,0 +1,230 @@
+//! A stable API to embed the formatter and the linter of Biome in other tools,
+//! such as bundlers and build tools, without going through the CLI.
+//!
+//! The [Engine] owns a workspace and the file system that it reads the configuration
+//! and the files from. The API of this crate follows semantic versioning: its types don't
+//! expose the types of the internal crates of Biome, so that they can keep the same API
+//! when the internals change.
+//!
+//! ## Example
+//!
+//! ```
+//! use biome_engine::{Engine, FixMode, MemoryFileSystem};
+//!
+//! let mut fs = MemoryFileSystem::default();
+//! fs.insert("biome.json", r#"{ "formatter": { "indentStyle": "space" } }"#);
+//! fs.insert("src/index.js", "const answer=42;\ndebugger;\n");
+//!
+//! let engine = Engine::in_memory(fs)?;
+//! engine.load_configuration("")?;
+//!
+//! let file = engine.open_path("src/index.js")?;
+//! assert_eq!(file.format()?.as_deref(), Some("const answer = 42;\ndebugger;\n"));
+//!
+//! let lint = file.lint()?.unwrap();
+//! assert_eq!(lint.errors, 1);
+//!
+//! assert_eq!(file.fix(FixMode::SafeAndUnsafe)?.as_deref(), Some("const answer=42;\n"));
+//! # Ok::<(), biome_engine::EngineError>(())
+//! ```
+
+mod diagnostic;
+mod error;
+mod file;
+mod fs;
+
+pub use crate::diagnostic::{LintDiagnostic, Severity};
+pub use crate::error::EngineError;
+pub use crate::file::{EngineFile, FixMode, LintResult};
+pub use crate::fs::MemoryFileSystem;
+
+use biome_configuration::{ConfigurationPathHint, PartialConfiguration};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_fs::{BiomePath, FileSystem, OsFileSystem};
+use biome_json_parser::JsonParserOptions;
+use biome_service::configuration::{load_configuration, PartialConfigurationExt};
+use biome_service::workspace::{
+    self, ChangeFileParams, CloseFileParams, OpenFileParams, RegisterProjectFolderParams,
+    UpdateSettingsParams,
+};
+use biome_service::{DynRef, Workspace};
+use rustc_hash::FxHashMap;
+use std::collections::hash_map::Entry;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Formats and lints files with the settings of a configuration.
+///
+/// The engine starts with the default configuration, which can be replaced by
+/// [Engine::configure] or [Engine::load_configuration].
+pub struct Engine {
+    workspace: Box<dyn Workspace>,
+    fs: DynRef<'static, dyn FileSystem>,
+    /// The documents opened in the workspace, shared by the [EngineFile] handles of the same path
+    documents: Mutex<FxHashMap<BiomePath, OpenDocument>>,
+}
+
+/// A document opened in the workspace of an [Engine]
+struct OpenDocument {
+    /// The number of [EngineFile] handles of the document. The document is closed when the last one is dropped.
+    handles: usize,
+    /// The version of the last content of the document
+    version: i32,
+}
+
+impl Engine {
+    /// Creates an engine that reads the configuration and the files from the file system of the OS.
+    pub fn new() -> Result<Self, EngineError> {
+        Self::with_file_system(DynRef::Owned(Box::new(OsFileSystem::default())))
+    }
+
+    /// Creates an engine that reads the configuration and the files from `fs`,
+    /// instead of the file system of the OS.
+    pub fn in_memory(fs: MemoryFileSystem) -> Result<Self, EngineError> {
+        Self::with_file_system(DynRef::Owned(Box::new(fs.into_inner())))
+    }
+
+    fn with_file_system(fs: DynRef<'static, dyn FileSystem>) -> Result<Self, EngineError> {
+        let workspace = workspace::server();
+        workspace.register_project_folder(RegisterProjectFolderParams {
+            path: fs.working_directory(),
+            set_as_current_workspace: true,
+        })?;
+        Ok(Self {
+            workspace,
+            fs,
+            documents: Mutex::default(),
+        })
+    }
+
+    /// Replaces the configuration of the engine with `configuration`,
+    /// the content of a `biome.json` or a `biome.jsonc` file.
+    ///
+    /// The `.gitignore` files aren't read: use [Engine::load_configuration]
+    /// to apply the VCS settings of a configuration.
+    pub fn configure(&self, configuration: &str) -> Result<(), EngineError> {
+        let deserialized = deserialize_from_json_str::<PartialConfiguration>(
+            configuration,
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas(),
+            "",
+        );
+        if deserialized.has_errors() {
+            return Err(EngineError::from_diagnostics(
+                deserialized.into_diagnostics(),
+            ));
+        }
+        let configuration = deserialized.into_deserialized().unwrap_or_default();
+        self.workspace.update_settings(UpdateSettingsParams {
+            configuration,
+            vcs_base_path: None,
+            gitignore_matches: Vec::new(),
+            workspace_directory: self.fs.working_directory(),
+        })?;
+        Ok(())
+    }
+
+    /// Loads the configuration file of `directory`, or of its closest ancestor, and applies it.
+    ///
+    /// Returns `false` if no configuration file was found, the engine then uses the default configuration.
+    pub fn load_configuration(&self, directory: impl AsRef<Path>) -> Result<bool, EngineError> {
+        let hint = ConfigurationPathHint::FromWorkspace(directory.as_ref().to_path_buf());
+        let loaded = load_configuration(&self.fs, hint)?;
+        if loaded.has_errors() {
+            return Err(EngineError::from_diagnostics(loaded.diagnostics));
+        }
+        let is_found = loaded.file_path.is_some();
+        let (vcs_base_path, gitignore_matches) = loaded
+            .configuration
+            .retrieve_gitignore_matches(&self.fs, loaded.directory_path())?;
+        self.workspace.update_settings(UpdateSettingsParams {
+            configuration: loaded.configuration,
+            vcs_base_path,
+            gitignore_matches,
+            workspace_directory: loaded
+                .directory_path
+                .or_else(|| self.fs.working_directory()),
+        })?;
+        Ok(is_found)
+    }
+
+    /// Opens a file with the given content.
+    ///
+    /// The path doesn't need to exist, it determines the language of the file
+    /// and the settings that apply to it.
+    ///
+    /// A path has a single content: if the file is already opened, its content is replaced,
+    /// and it stays opened until all its handles are dropped.
+    pub fn open_file(
+        &self,
+        path: impl Into<PathBuf>,
+        content: impl Into<String>,
+    ) -> Result<EngineFile<'_>, EngineError> {
+        let path = BiomePath::new(path.into());
+        let mut documents = self.documents.lock().unwrap();
+        match documents.entry(path.clone()) {
+            Entry::Occupied(mut entry) => {
+                let document = entry.get_mut();
+                self.workspace.change_file(ChangeFileParams {
+                    path: path.clone(),
+                    content: content.into(),
+                    version: document.version + 1,
+                })?;
+                document.version += 1;
+                document.handles += 1;
+            }
+            Entry::Vacant(entry) => {
+                self.workspace.open_file(OpenFileParams {
+                    path: path.clone(),
+                    content: content.into(),
+                    version: 0,
+                    document_file_source: None,
+                })?;
+                entry.insert(OpenDocument {
+                    handles: 1,
+                    version: 0,
+                });
+            }
+        }
+        Ok(EngineFile::new(self, path))
+    }
+
+    /// Opens a file with the content read from the file system of the engine.
+    pub fn open_path(&self, path: impl Into<PathBuf>) -> Result<EngineFile<'_>, EngineError> {
+        let path = path.into();
+        let content = self.fs.read_file_from_path(&path)?;
+        self.open_file(path, content)
+    }
+
+    /// Replaces the content of the document at `path`, shared by all its handles.
+    fn change_document(&self, path: &BiomePath, content: String) -> Result<(), EngineError> {
+        let mut documents = self.documents.lock().unwrap();
+        let Some(document) = documents.get_mut(path) else {
+            return Ok(());
+        };
+        self.workspace.change_file(ChangeFileParams {
+            path: path.clone(),
+            content,
+            version: document.version + 1,
+        })?;
+        document.version += 1;
+        Ok(())
+    }
+
+    /// Releases a handle of the document at `path`, and closes the document if it was the last one.
+    fn release_document(&self, path: &BiomePath) {
+        let mut documents = self.documents.lock().unwrap();
+        let Entry::Occupied(mut entry) = documents.entry(path.clone()) else {
+            return;
+        };
+        entry.get_mut().handles -= 1;
+        if entry.get().handles == 0 {
+            entry.remove();
+            // The file can only fail to close if it's already closed
+            self.workspace
+                .close_file(CloseFileParams { path: path.clone() })
+                .ok();
+        }
+    }
+}
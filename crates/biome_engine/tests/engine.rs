@@ -0,0 +1,143 @@
+use biome_engine::{Engine, FixMode, MemoryFileSystem, Severity};
+
+#[test]
+fn format_with_default_configuration() {
+    let engine = Engine::in_memory(MemoryFileSystem::default()).unwrap();
+    let file = engine.open_file("index.ts", "const a : number=1").unwrap();
+
+    assert_eq!(
+        file.format().unwrap().as_deref(),
+        Some("const a: number = 1;\n")
+    );
+}
+
+#[test]
+fn format_with_configuration() {
+    let engine = Engine::in_memory(MemoryFileSystem::default()).unwrap();
+    engine
+        .configure(r#"{ "formatter": { "lineWidth": 20 } }"#)
+        .unwrap();
+    let file = engine
+        .open_file("index.js", "call(firstArgument, secondArgument)")
+        .unwrap();
+
+    assert_eq!(
+        file.format().unwrap().as_deref(),
+        Some("call(\n\tfirstArgument,\n\tsecondArgument,\n);\n")
+    );
+}
+
+#[test]
+fn load_configuration_from_file_system() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "project/biome.json",
+        r#"{ "formatter": { "indentStyle": "space" }, "files": { "ignore": ["dist"] } }"#,
+    );
+    fs.insert("project/src/index.js", "if (a) {b}");
+    fs.insert("project/dist/index.js", "if (a) {b}");
+    let engine = Engine::in_memory(fs).unwrap();
+
+    assert!(engine.load_configuration("project").unwrap());
+
+    let file = engine.open_path("project/src/index.js").unwrap();
+    assert_eq!(
+        file.format().unwrap().as_deref(),
+        Some("if (a) {\n  b;\n}\n")
+    );
+
+    let ignored = engine.open_path("project/dist/index.js").unwrap();
+    assert_eq!(ignored.format().unwrap(), None);
+    assert!(ignored.lint().unwrap().is_none());
+}
+
+#[test]
+fn load_missing_configuration() {
+    let engine = Engine::in_memory(MemoryFileSystem::default()).unwrap();
+
+    assert!(!engine.load_configuration("project").unwrap());
+}
+
+#[test]
+fn load_invalid_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("biome.json", r#"{ "formatter": { "indentStyle": 1 } }"#);
+    let engine = Engine::in_memory(fs).unwrap();
+
+    let error = engine.load_configuration("").unwrap_err();
+    assert!(error.messages().next().is_some());
+}
+
+#[test]
+fn configure_with_invalid_configuration() {
+    let engine = Engine::in_memory(MemoryFileSystem::default()).unwrap();
+
+    let error = engine
+        .configure(r#"{ "formatter": { "indentStyle": 1 } }"#)
+        .unwrap_err();
+    assert!(error.messages().next().is_some());
+}
+
+#[test]
+fn lint_and_fix() {
+    let engine = Engine::in_memory(MemoryFileSystem::default()).unwrap();
+    let mut file = engine
+        .open_file("index.js", "let a = 1;\ndebugger;\n")
+        .unwrap();
+
+    let result = file.lint().unwrap().unwrap();
+    assert_eq!(result.errors, 2);
+    assert_eq!(result.diagnostics.len(), 2);
+    let category = Some("lint/suspicious/noDebugger");
+    let debugger = result
+        .diagnostics
+        .iter()
+        .find(|diagnostic| diagnostic.category.as_deref() == category)
+        .unwrap();
+    assert_eq!(debugger.severity, Severity::Error);
+    assert_eq!(debugger.range, Some(11..20));
+
+    assert_eq!(
+        file.fix(FixMode::Safe).unwrap().as_deref(),
+        Some("const a = 1;\ndebugger;\n")
+    );
+    let fixed = file.fix(FixMode::SafeAndUnsafe).unwrap().unwrap();
+    assert_eq!(fixed, "const a = 1;\n");
+
+    file.update(fixed).unwrap();
+    assert_eq!(file.content().unwrap(), "const a = 1;\n");
+    assert_eq!(file.lint().unwrap().unwrap().errors, 0);
+}
+
+#[test]
+fn unsupported_language() {
+    let engine = Engine::in_memory(MemoryFileSystem::default()).unwrap();
+    let file = engine.open_file("README.md", "# Title").unwrap();
+
+    assert_eq!(file.format().unwrap(), None);
+    assert!(file.lint().unwrap().is_none());
+}
+
+#[test]
+fn open_missing_path() {
+    let engine = Engine::in_memory(MemoryFileSystem::default()).unwrap();
+
+    assert!(engine.open_path("missing.js").is_err());
+}
+
+#[test]
+fn share_the_files_of_the_same_path() {
+    let engine = Engine::in_memory(MemoryFileSystem::default()).unwrap();
+    let mut file = engine.open_file("index.js", "let a = 1").unwrap();
+    let other = engine.open_file("index.js", "let b = 2").unwrap();
+
+    assert_eq!(file.content().unwrap(), "let b = 2");
+
+    file.update("let c = 3").unwrap();
+    assert_eq!(other.content().unwrap(), "let c = 3");
+
+    // The file stays opened while a handle of its path is alive
+    drop(file);
+    assert_eq!(other.content().unwrap(), "let c = 3");
+    assert_eq!(other.format().unwrap().as_deref(), Some("let c = 3;\n"));
+}
@@ -633,6 +633,29 @@ impl FromStr for AttributePosition {
     }
 }
 
+/// The normalizations that the formatter applies to the comments. They are all disabled by default.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CommentsOptions {
+    /// Whether to insert a space after the `//` of the line comments
+    pub line_comment_space: bool,
+    /// Whether to start every line of the multiline documentation comments `/** */` with a `*`
+    pub doc_comment_style: bool,
+    /// Whether to reflow the text of the multiline block comments to the line width
+    pub reflow: bool,
+}
+
+impl std::fmt::Display for CommentsOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(
+            f,
+            "line comment space: {}, doc comment style: {}, reflow: {}",
+            self.line_comment_space,
+            self.doc_comment_style,
+            self.reflow
+        )
+    }
+}
+
 /// Context object storing data relevant when formatting an object.
 pub trait FormatContext {
     type Options: FormatOptions;
@@ -54,8 +54,12 @@ struct FormatNumberLiteralExponent {
     first_digit_index: Option<NonZeroUsize>,
     first_non_zero_index: Option<NonZeroUsize>,
 }
+/// Removes the unnecessary characters of a number: the leading `+` and zeros of the exponent,
+/// the trailing zeros of the decimal part, and the exponents equal to zero.
+/// The text is lowercased, and a `0` is added before a leading dot.
+///
 // Regex-free version of https://github.com/prettier/prettier/blob/ca246afacee8e6d5db508dae01730c9523bbff1d/src/common/util.js#L341-L356
-fn format_trimmed_number(text: &str) -> Cow<str> {
+pub fn format_trimmed_number(text: &str) -> Cow<str> {
     use FormatNumberLiteralState::*;
 
     let text = text.to_ascii_lowercase_cow();
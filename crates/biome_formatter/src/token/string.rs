@@ -173,6 +173,53 @@ pub fn normalize_string(
     }
 }
 
+/// Writes the hexadecimal digits of the escape sequences `\xHH`, `\uHHHH`, and `\u{H..}` in uppercase.
+///
+/// The function allocates a new string only if at least one digit is changed.
+///
+/// ```
+/// use biome_formatter::token::string::uppercase_hex_escapes;
+/// assert_eq!(uppercase_hex_escapes("\\xe9 \\u00e9 \\u{1f600} \\\\u00e9"), "\\xE9 \\u00E9 \\u{1F600} \\\\u00e9");
+/// ```
+pub fn uppercase_hex_escapes(raw_content: &str) -> Cow<str> {
+    let bytes = raw_content.as_bytes();
+    let mut uppercased: Option<Vec<u8>> = None;
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] != b'\\' {
+            index += 1;
+            continue;
+        }
+        let Some(escaped) = bytes.get(index + 1) else {
+            break;
+        };
+        index += 2;
+        let max_digits = match escaped {
+            b'x' => 2,
+            b'u' if bytes.get(index) == Some(&b'{') => {
+                index += 1;
+                usize::MAX
+            }
+            b'u' => 4,
+            _ => continue,
+        };
+        let mut digits = 0;
+        while digits < max_digits && index < bytes.len() && bytes[index].is_ascii_hexdigit() {
+            if bytes[index].is_ascii_lowercase() {
+                uppercased.get_or_insert_with(|| bytes.to_vec())[index] =
+                    bytes[index].to_ascii_uppercase();
+            }
+            index += 1;
+            digits += 1;
+        }
+    }
+    match uppercased {
+        // Only ASCII characters are changed, the string is still valid UTF-8
+        Some(uppercased) => Cow::Owned(String::from_utf8(uppercased).unwrap()),
+        None => Cow::Borrowed(raw_content),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,4 +269,16 @@ mod tests {
         assert_eq!(normalize_string("\\'", Quote::Single, false), "\\'");
         assert_eq!(normalize_string("\\\"", Quote::Single, false), "\"");
     }
+
+    #[test]
+    fn uppercase_escapes() {
+        assert_eq!(uppercase_hex_escapes("abc"), "abc");
+        assert_eq!(uppercase_hex_escapes("\\xff"), "\\xFF");
+        assert_eq!(uppercase_hex_escapes("\\u00ff"), "\\u00FF");
+        assert_eq!(uppercase_hex_escapes("\\u{1f600}"), "\\u{1F600}");
+        assert_eq!(uppercase_hex_escapes("\\u00ffab"), "\\u00FFab");
+        assert_eq!(uppercase_hex_escapes("\\\\u00ff"), "\\\\u00ff");
+        assert_eq!(uppercase_hex_escapes("\\nff"), "\\nff");
+        assert_eq!(uppercase_hex_escapes("é\\xe9"), "é\\xE9");
+    }
 }
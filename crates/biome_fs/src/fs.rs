@@ -70,6 +70,9 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Checks if the given path is a symlink
     fn path_is_symlink(&self, path: &Path) -> bool;
 
+    /// Returns the paths of the entries of the directory at the given path, in no particular order
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+
     /// This method accepts a directory path (`search_dir`) and a list of filenames (`file_names`),
     /// It looks for the files in the specified directory in the order they appear in the list.
     /// If a file is not found in the initial directory, the search may continue into the parent
@@ -185,6 +188,14 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
 
     fn get_staged_files(&self) -> io::Result<Vec<String>>;
 
+    /// Returns the content of the file at `path` in the git index, which is the content that will be committed
+    fn get_staged_content(&self, path: &Path) -> io::Result<String>;
+
+    /// Replaces the content of the files in the git index, without modifying the working tree.
+    ///
+    /// The index is updated at once, so that the changes of several files don't compete for its lock.
+    fn set_staged_contents(&self, contents: &[(PathBuf, Vec<u8>)]) -> io::Result<()>;
+
     fn resolve_configuration(
         &self,
         specifier: &str,
@@ -340,6 +351,12 @@ pub trait TraversalContext: Sync {
 
     /// Returns the paths that should be handled
     fn evaluated_paths(&self) -> FxHashSet<EvaluatedPath>;
+
+    /// Returns `true` if the traversal should stop: the directories that weren't
+    /// visited yet aren't read, and the files that weren't scheduled yet aren't handled
+    fn should_stop(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Eq, Clone)]
@@ -428,6 +445,10 @@ where
         T::path_is_symlink(self, path)
     }
 
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        T::read_dir(self, path)
+    }
+
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
         T::get_changed_files(self, base)
     }
@@ -436,6 +457,14 @@ where
         T::get_staged_files(self)
     }
 
+    fn get_staged_content(&self, path: &Path) -> io::Result<String> {
+        T::get_staged_content(self, path)
+    }
+
+    fn set_staged_contents(&self, contents: &[(PathBuf, Vec<u8>)]) -> io::Result<()> {
+        T::set_staged_contents(self, contents)
+    }
+
     fn resolve_configuration(
         &self,
         specifier: &str,
@@ -1,9 +1,9 @@
 use oxc_resolver::{Resolution, ResolveError};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::hash_map::{Entry, IntoIter};
 use std::io;
 use std::panic::{AssertUnwindSafe, RefUnwindSafe};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::str;
 use std::sync::Arc;
 
@@ -30,6 +30,8 @@ pub struct MemoryFileSystem {
     allow_write: bool,
     on_get_staged_files: OnGetChangedFiles,
     on_get_changed_files: OnGetChangedFiles,
+    /// The content of the files in the git index
+    staged_files: AssertUnwindSafe<RwLock<FxHashMap<PathBuf, Vec<u8>>>>,
 }
 
 impl Default for MemoryFileSystem {
@@ -44,6 +46,7 @@ impl Default for MemoryFileSystem {
             on_get_changed_files: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
                 Vec::new,
             )))))),
+            staged_files: Default::default(),
         }
     }
 }
@@ -121,6 +124,18 @@ impl MemoryFileSystem {
     ) {
         self.on_get_staged_files = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
     }
+
+    /// Create or update the content of a file in the git index
+    pub fn insert_staged(&mut self, path: PathBuf, content: impl Into<Vec<u8>>) {
+        self.staged_files.0.get_mut().insert(path, content.into());
+    }
+
+    /// Returns the content of a file in the git index
+    pub fn staged_content(&self, path: &Path) -> Option<String> {
+        let staged_files = self.staged_files.0.read();
+        let content = staged_files.get(path)?;
+        Some(String::from_utf8_lossy(content).into_owned())
+    }
 }
 
 impl FileSystem for MemoryFileSystem {
@@ -209,6 +224,20 @@ impl FileSystem for MemoryFileSystem {
         false
     }
 
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        // The memory file system only stores files: the directories are the parents of their paths
+        let files = self.files.0.read();
+        let mut entries = FxHashSet::default();
+        for file in files.keys() {
+            if let Ok(relative) = file.strip_prefix(path) {
+                if let Some(Component::Normal(name)) = relative.components().next() {
+                    entries.insert(path.join(name));
+                }
+            }
+        }
+        Ok(entries.into_iter().collect())
+    }
+
     fn get_changed_files(&self, _base: &str) -> io::Result<Vec<String>> {
         let cb_arc = self.on_get_changed_files.as_ref().unwrap().clone();
 
@@ -229,6 +258,42 @@ impl FileSystem for MemoryFileSystem {
         Ok(cb())
     }
 
+    fn get_staged_content(&self, path: &Path) -> io::Result<String> {
+        let staged_files = self.staged_files.0.read();
+        let content = staged_files.get(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("the file {} isn't in the git index", path.display()),
+            )
+        })?;
+        str::from_utf8(content)
+            .map(str::to_string)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    fn set_staged_contents(&self, contents: &[(PathBuf, Vec<u8>)]) -> io::Result<()> {
+        if !self.allow_write {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "cannot acquire write access to file in read-only filesystem",
+            ));
+        }
+        let mut staged_files = self.staged_files.0.write();
+        if let Some((path, _)) = contents
+            .iter()
+            .find(|(path, _)| !staged_files.contains_key(path))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("the file {} isn't in the git index", path.display()),
+            ));
+        }
+        for (path, content) in contents {
+            staged_files.insert(path.clone(), content.clone());
+        }
+        Ok(())
+    }
+
     fn resolve_configuration(
         &self,
         _specifier: &str,
@@ -295,6 +360,9 @@ impl<'scope> TraversalScope<'scope> for MemoryTraversalScope<'scope> {
         {
             let files = &self.fs.files.0.read();
             for path in files.keys() {
+                if ctx.should_stop() {
+                    break;
+                }
                 let should_process_file = if base.starts_with(".") || base.starts_with("./") {
                     // we simulate absolute paths, so we can correctly strips out the base path from the path
                     let absolute_base = PathBuf::from("/").join(&base);
@@ -11,7 +11,7 @@ use rayon::{scope, Scope};
 use std::ffi::OsStr;
 use std::fs::{DirEntry, FileType};
 use std::panic::AssertUnwindSafe;
-use std::process::Command;
+use std::process::{Command, Stdio};
 use std::{
     env, fs,
     io::{self, ErrorKind as IoErrorKind, Read, Seek, Write},
@@ -91,6 +91,10 @@ impl FileSystem for OsFileSystem {
         path.is_symlink()
     }
 
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        fs::read_dir(path)?.map(|entry| Ok(entry?.path())).collect()
+    }
+
     fn resolve_configuration(
         &self,
         specifier: &str,
@@ -138,6 +142,119 @@ impl FileSystem for OsFileSystem {
             .map(|l| l.to_string())
             .collect())
     }
+
+    fn get_staged_content(&self, path: &Path) -> io::Result<String> {
+        let StagedEntry { object, .. } = staged_entry(path)?;
+        let content = run_git(Command::new("git").arg("cat-file").arg("blob").arg(object))?;
+        String::from_utf8(content).map_err(|error| io::Error::new(IoErrorKind::InvalidData, error))
+    }
+
+    fn set_staged_contents(&self, contents: &[(PathBuf, Vec<u8>)]) -> io::Result<()> {
+        if contents.is_empty() {
+            return Ok(());
+        }
+        // The objects are written first, then all the entries are updated by a single command,
+        // which takes the lock of the index once
+        let mut index_info = Vec::new();
+        for (path, content) in contents {
+            let StagedEntry {
+                mode, full_path, ..
+            } = staged_entry(path)?;
+            let object = git_with_stdin(
+                Command::new("git")
+                    .arg("hash-object")
+                    .arg("-w")
+                    .arg("--stdin"),
+                content,
+            )?;
+            // The entries have the format `<mode> <object>\t<path>`, where the path is relative to the root of the repository
+            write!(
+                index_info,
+                "{mode} {}\t{full_path}\0",
+                String::from_utf8_lossy(&object).trim()
+            )?;
+        }
+        git_with_stdin(
+            Command::new("git")
+                .arg("update-index")
+                .arg("-z")
+                .arg("--index-info"),
+            &index_info,
+        )?;
+        Ok(())
+    }
+}
+
+/// An entry of the git index
+struct StagedEntry {
+    mode: String,
+    object: String,
+    /// The path of the file, relative to the root of the repository
+    full_path: String,
+}
+
+/// Returns the entry of the file at `path` in the git index
+fn staged_entry(path: &Path) -> io::Result<StagedEntry> {
+    let output = run_git(
+        Command::new("git")
+            .arg("ls-files")
+            .arg("--stage")
+            .arg("--full-name")
+            .arg("-z")
+            .arg("--")
+            .arg(path),
+    )?;
+    // The entry has the format `<mode> <object> <stage>\t<path>\0`
+    let entry = String::from_utf8_lossy(&output);
+    let entry = entry.split('\0').next().unwrap_or_default();
+    let (fields, full_path) = entry.split_once('\t').unwrap_or_default();
+    let mut fields = fields.split_whitespace();
+    match (fields.next(), fields.next()) {
+        (Some(mode), Some(object)) if !full_path.is_empty() => Ok(StagedEntry {
+            mode: mode.to_string(),
+            object: object.to_string(),
+            full_path: full_path.to_string(),
+        }),
+        _ => Err(io::Error::new(
+            IoErrorKind::NotFound,
+            format!("the file {} isn't in the git index", path.display()),
+        )),
+    }
+}
+
+/// Runs the git `command` with `input` as its standard input, and returns its standard output
+fn git_with_stdin(command: &mut Command, input: &[u8]) -> io::Result<Vec<u8>> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input)?;
+    }
+    let output = child.wait_with_output()?;
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(git_error(&output.stderr))
+    }
+}
+
+/// Runs the git `command`, and returns its standard output
+fn run_git(command: &mut Command) -> io::Result<Vec<u8>> {
+    let output = command.output()?;
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(git_error(&output.stderr))
+    }
+}
+
+fn git_error(stderr: &[u8]) -> io::Error {
+    io::Error::new(
+        IoErrorKind::Other,
+        String::from_utf8_lossy(stderr).trim().to_string(),
+    )
 }
 
 struct OsFile {
@@ -244,6 +361,9 @@ fn handle_dir<'scope>(
     };
 
     for entry in iter {
+        if ctx.should_stop() {
+            return;
+        }
         match entry {
             Ok(entry) => handle_dir_entry(scope, ctx, entry, origin_path.clone()),
             Err(err) => {
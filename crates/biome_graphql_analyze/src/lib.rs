@@ -66,6 +66,9 @@ where
                     if comment.is_legacy {
                         result.push(Ok(SuppressionKind::Deprecated));
                     }
+                    if let Some(date) = comment.expires {
+                        result.push(Ok(SuppressionKind::Expires(date)));
+                    }
                     comment.categories
                 }
                 Err(err) => {
@@ -0,0 +1,16 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_lint_group;
+
+pub mod use_alt_text;
+pub mod use_valid_aria_props;
+
+declare_lint_group! {
+    pub A11y {
+        name : "a11y" ,
+        rules : [
+            self :: use_alt_text :: UseAltText ,
+            self :: use_valid_aria_props :: UseValidAriaProps ,
+        ]
+     }
+}
@@ -0,0 +1,95 @@
+use crate::markup::{HtmlMarkupAttribute, HtmlMarkupElement};
+use crate::HtmlRuleAction;
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
+use biome_aria::a11y::invalid_aria_attributes;
+use biome_aria::AriaProperties;
+use biome_console::markup;
+use biome_html_syntax::AnyHtmlElement;
+use biome_rowan::{AstNode, BatchMutationExt};
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Ensures that ARIA properties `aria-*` are all valid.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <input class="" aria-labell="" />
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <div aria-lorem="foobar"></div>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <input class="" aria-label="Name" />
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    pub UseValidAriaProps {
+        version: "next",
+        name: "useValidAriaProps",
+        language: "html",
+        recommended: true,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseValidAriaProps {
+    type Query = Ast<AnyHtmlElement>;
+    type State = (HtmlMarkupElement, HtmlMarkupAttribute);
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(element) = HtmlMarkupElement::cast(ctx.query()) else {
+            return Vec::new();
+        };
+        let Some(properties) = ctx.get_service::<Arc<AriaProperties>>() else {
+            return Vec::new();
+        };
+        invalid_aria_attributes(&element, properties)
+            .into_iter()
+            .map(|attribute| (element.clone(), attribute))
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (element, attribute) = state;
+        let attribute_name = attribute.0.name().ok()?.value_token().ok()?;
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            element.range(),
+            markup! {
+                "The element contains invalid ARIA attribute(s)"
+            },
+        ).detail(
+            attribute.0.range(),
+            markup! {
+                <Emphasis>{attribute_name.text_trimmed()}</Emphasis>" is not a valid ARIA attribute."
+            },
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<HtmlRuleAction> {
+        let (_, attribute) = state;
+        let mut mutation = ctx.root().begin();
+
+        mutation.remove_node(attribute.0.clone());
+
+        Some(HtmlRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Remove the invalid "<Emphasis>"aria-*"</Emphasis>" attribute.
+                Check the list of all "<Hyperlink href="https://developer.mozilla.org/en-US/docs/web/Accessibility/ARIA/Attributes#aria_attribute_types">"valid"</Hyperlink>" aria-* attributes." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
@@ -0,0 +1,16 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_lint_group;
+
+pub mod no_label_without_control;
+pub mod no_unknown_attribute;
+
+declare_lint_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_label_without_control :: NoLabelWithoutControl ,
+            self :: no_unknown_attribute :: NoUnknownAttribute ,
+        ]
+     }
+}
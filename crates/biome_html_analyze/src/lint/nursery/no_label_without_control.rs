@@ -0,0 +1,88 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_aria::a11y::{label_association, LabelAssociation, LabelAssociationOptions};
+use biome_console::markup;
+use biome_html_syntax::AnyHtmlElement;
+use biome_rowan::AstNode;
+
+use crate::markup::HtmlMarkupElement;
+
+declare_lint_rule! {
+    /// Enforce that a label element has a text label and an associated input.
+    ///
+    /// An "input" is considered one of the following elements: `input`, `meter`, `output`, `progress`, `select` or `textarea`.
+    ///
+    /// There are two supported ways to associate a label with an input:
+    /// - Wrapping an input in a label element.
+    /// - Adding a `for` attribute to a label and assigning it a DOM ID string associated with an input on the page.
+    ///
+    /// This rule checks that any `label` element meets two conditions:
+    /// - The label has text content, or an `aria-label`, `aria-labelledby` or `alt` attribute.
+    /// - The label is associated with an input.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <label for="js_id"></label>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <label>A label</label>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <label for="js_id" aria-label="A label"></label>
+    /// ```
+    ///
+    /// ```html
+    /// <label>A label<input /></label>
+    /// ```
+    ///
+    pub NoLabelWithoutControl {
+        version: "next",
+        name: "noLabelWithoutControl",
+        language: "html",
+        recommended: true,
+    }
+}
+
+impl Rule for NoLabelWithoutControl {
+    type Query = Ast<AnyHtmlElement>;
+    type State = (HtmlMarkupElement, LabelAssociation);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = HtmlMarkupElement::cast(ctx.query())?;
+        let association = label_association(&element, LabelAssociationOptions::default())?;
+        (!association.is_valid()).then_some((element, association))
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (_, association) = state;
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().range(),
+            markup! {
+                "A form label must be associated with an input."
+            },
+        );
+
+        if !association.has_text_content {
+            diagnostic = diagnostic.note(
+                markup! { "Consider adding an accessible text content to the label element." },
+            );
+        }
+
+        if !association.has_control_association {
+            diagnostic = diagnostic.note(
+                markup! { "Consider adding a `for` attribute to the label element or moving the input element to inside the label element." },
+            );
+        }
+
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,101 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_aria::attributes::{unknown_attributes, AttributeNaming, UnknownAttributeKind};
+use biome_aria::markup::MarkupElement;
+use biome_console::markup;
+use biome_html_syntax::AnyHtmlElement;
+use biome_rowan::AstNode;
+
+use crate::markup::{HtmlMarkupAttribute, HtmlMarkupElement};
+
+declare_lint_rule! {
+    /// Disallow unknown attributes on the HTML elements.
+    ///
+    /// The attributes are checked against the HTML specification.
+    /// The rule reports the attributes that don't exist, the attributes spelled with their name in
+    /// a JSX framework, such as `className`, and the attributes of other elements, such as `href` on a `div`.
+    ///
+    /// The `data-*` and `aria-*` attributes, the namespaced attributes such as `xml:lang`,
+    /// and the event handlers are accepted.
+    /// The custom elements, and the SVG and MathML elements, aren't checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <div className="container"></div>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <div href="/home"></div>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <div class="container" data-id="1" aria-hidden="true" onclick="toggle()"></div>
+    /// ```
+    ///
+    pub NoUnknownAttribute {
+        version: "next",
+        name: "noUnknownAttribute",
+        language: "html",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnknownAttribute {
+    type Query = Ast<AnyHtmlElement>;
+    type State = (HtmlMarkupAttribute, UnknownAttributeKind);
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(element) = HtmlMarkupElement::cast(ctx.query()) else {
+            return Vec::new();
+        };
+        unknown_attributes(&element, AttributeNaming::Html, &[])
+    }
+
+    fn diagnostic(
+        ctx: &RuleContext<Self>,
+        (attribute, kind): &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        let name = attribute.0.name().ok()?.value_token().ok()?;
+        let name = name.text_trimmed();
+        let element = HtmlMarkupElement::cast(ctx.query())?.name()?;
+        let diagnostic = match kind {
+            UnknownAttributeKind::Unknown => RuleDiagnostic::new(
+                rule_category!(),
+                attribute.0.range(),
+                markup! {
+                    <Emphasis>{name}</Emphasis>" isn't an attribute of the "<Emphasis>{element}</Emphasis>" element."
+                },
+            )
+            .note(markup! {
+                "Check the spelling of the attribute."
+            }),
+            UnknownAttributeKind::Misnamed { expected } => RuleDiagnostic::new(
+                rule_category!(),
+                attribute.0.range(),
+                markup! {
+                    "The attribute "<Emphasis>{name}</Emphasis>" is spelled "<Emphasis>{expected}</Emphasis>" in HTML."
+                },
+            )
+            .note(markup! {
+                "The element doesn't receive an attribute that is spelled differently."
+            }),
+            UnknownAttributeKind::NotOnElement { elements } => RuleDiagnostic::new(
+                rule_category!(),
+                attribute.0.range(),
+                markup! {
+                    <Emphasis>{name}</Emphasis>" isn't an attribute of the "<Emphasis>{element}</Emphasis>" element."
+                },
+            )
+            .note(markup! {
+                "It's only an attribute of the following elements: "{elements.join(", ")}"."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,142 @@
+//! Implementation of the markup abstraction of [biome_aria] for the HTML elements.
+
+use biome_aria::markup::{MarkupAttribute, MarkupAttributeValue, MarkupElement};
+use biome_html_syntax::{
+    inner_string_text, AnyHtmlElement, HtmlAttribute, HtmlAttributeList, HtmlContent, HtmlElement,
+    HtmlName, HtmlSelfClosingElement, HtmlSyntaxNode,
+};
+use biome_rowan::{AstNode, TextRange};
+
+/// An HTML element, such as `<img alt="Biome" />` or `<label>Name</label>`
+#[derive(Clone, Debug)]
+pub enum HtmlMarkupElement {
+    Element(HtmlElement),
+    SelfClosingElement(HtmlSelfClosingElement),
+}
+
+/// An attribute of an [HtmlMarkupElement], such as `alt="Biome"`
+#[derive(Clone, Debug)]
+pub struct HtmlMarkupAttribute(pub(crate) HtmlAttribute);
+
+impl HtmlMarkupElement {
+    /// Returns the element if `element` isn't text content or a bogus element
+    pub(crate) fn cast(element: &AnyHtmlElement) -> Option<Self> {
+        match element {
+            AnyHtmlElement::HtmlElement(element) => Some(Self::Element(element.clone())),
+            AnyHtmlElement::HtmlSelfClosingElement(element) => {
+                Some(Self::SelfClosingElement(element.clone()))
+            }
+            AnyHtmlElement::HtmlContent(_) | AnyHtmlElement::HtmlBogusElement(_) => None,
+        }
+    }
+
+    /// The range of the opening element, or of the self-closing element
+    pub(crate) fn range(&self) -> TextRange {
+        match self {
+            Self::Element(element) => element.opening_element().map_or_else(
+                |_| element.range(),
+                |opening_element| opening_element.range(),
+            ),
+            Self::SelfClosingElement(element) => element.range(),
+        }
+    }
+
+    fn syntax(&self) -> &HtmlSyntaxNode {
+        match self {
+            Self::Element(element) => element.syntax(),
+            Self::SelfClosingElement(element) => element.syntax(),
+        }
+    }
+
+    fn name_node(&self) -> Option<HtmlName> {
+        match self {
+            Self::Element(element) => element.opening_element().ok()?.name().ok(),
+            Self::SelfClosingElement(element) => element.name().ok(),
+        }
+    }
+
+    fn attribute_list(&self) -> Option<HtmlAttributeList> {
+        match self {
+            Self::Element(element) => Some(element.opening_element().ok()?.attributes()),
+            Self::SelfClosingElement(element) => Some(element.attributes()),
+        }
+    }
+}
+
+impl MarkupAttribute for HtmlMarkupAttribute {
+    fn name(&self) -> Option<String> {
+        // The names of the attributes aren't case-sensitive
+        let name = self.0.name().ok()?.value_token().ok()?;
+        Some(name.text_trimmed().to_ascii_lowercase())
+    }
+
+    fn value(&self) -> MarkupAttributeValue {
+        let Some(initializer) = self.0.initializer() else {
+            return MarkupAttributeValue::Missing;
+        };
+        match initializer.value().and_then(|value| value.value_token()) {
+            Ok(token) => MarkupAttributeValue::Text(inner_string_text(&token).to_string()),
+            Err(_) => MarkupAttributeValue::Missing,
+        }
+    }
+}
+
+impl MarkupElement for HtmlMarkupElement {
+    type Attribute = HtmlMarkupAttribute;
+
+    fn name(&self) -> Option<String> {
+        // The names of the elements aren't case-sensitive
+        let name = self.name_node()?.value_token().ok()?;
+        Some(name.text_trimmed().to_ascii_lowercase())
+    }
+
+    fn attributes(&self) -> Vec<Self::Attribute> {
+        self.attribute_list()
+            .into_iter()
+            .flatten()
+            .filter_map(|attribute| attribute.as_html_attribute().cloned())
+            .map(HtmlMarkupAttribute)
+            .collect()
+    }
+
+    fn is_custom_component(&self) -> bool {
+        false
+    }
+
+    fn has_accessible_child(&self) -> bool {
+        let Self::Element(element) = self else {
+            return false;
+        };
+        element.children().into_iter().any(|child| match child {
+            AnyHtmlElement::HtmlContent(content) => content
+                .value_token()
+                .is_ok_and(|token| !token.text_trimmed().trim().is_empty()),
+            AnyHtmlElement::HtmlBogusElement(_) => true,
+            child => Self::cast(&child).is_some_and(|child| {
+                !child
+                    .find_attribute_by_name("aria-hidden")
+                    .is_some_and(|attribute| attribute.value().as_text() == Some("true"))
+            }),
+        })
+    }
+
+    fn has_text_content(&self) -> bool {
+        self.syntax()
+            .descendants()
+            .filter_map(HtmlContent::cast)
+            .any(|content| {
+                content
+                    .value_token()
+                    .is_ok_and(|token| !token.text_trimmed().trim().is_empty())
+            })
+    }
+
+    fn descendants(&self) -> Vec<Self> {
+        self.syntax()
+            .descendants()
+            .skip(1)
+            .filter_map(AnyHtmlElement::cast)
+            .filter_map(|element| Self::cast(&element))
+            .collect()
+    }
+}
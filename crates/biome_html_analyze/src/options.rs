@@ -0,0 +1,10 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+
+pub type NoLabelWithoutControl = < lint :: nursery :: no_label_without_control :: NoLabelWithoutControl as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownAttribute =
+    <lint::nursery::no_unknown_attribute::NoUnknownAttribute as biome_analyze::Rule>::Options;
+pub type UseAltText = <lint::a11y::use_alt_text::UseAltText as biome_analyze::Rule>::Options;
+pub type UseValidAriaProps =
+    <lint::a11y::use_valid_aria_props::UseValidAriaProps as biome_analyze::Rule>::Options;
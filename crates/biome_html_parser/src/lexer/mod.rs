@@ -74,7 +74,7 @@ impl<'src> HtmlLexer<'src> {
     fn consume_element_list_token(&mut self, current: u8) -> HtmlSyntaxKind {
         debug_assert!(!self.is_eof());
         match current {
-            b'<' => self.consume_byte(T![<]),
+            b'<' => self.consume_l_angle(),
             _ => {
                 while let Some(chr) = self.current_byte() {
                     match chr {
@@ -135,7 +135,7 @@ impl<'src> HtmlLexer<'src> {
         self.advance_byte_or_char(first);
 
         while let Some(byte) = self.current_byte() {
-            if is_identifier_byte(byte) {
+            if is_identifier_continue_byte(byte) {
                 if len < BUFFER_SIZE {
                     buffer[len] = byte;
                     len += 1;
@@ -427,6 +427,12 @@ fn is_identifier_byte(byte: u8) -> bool {
     byte.is_ascii_alphanumeric()
 }
 
+/// Returns `true` if `byte` can follow the first byte of the name of an element or an attribute,
+/// such as the dash of `aria-label` or `custom-element`
+fn is_identifier_continue_byte(byte: u8) -> bool {
+    is_identifier_byte(byte) || matches!(byte, b'-' | b'_' | b'.' | b':')
+}
+
 #[derive(Copy, Clone, Debug)]
 enum LexStringState {
     /// String that contains an invalid escape sequence
@@ -190,3 +190,21 @@ fn element_with_attributes() {
         R_ANGLE: 1,
     }
 }
+
+#[test]
+fn element_with_dashed_attributes() {
+    assert_lex! {
+        "<custom-element aria-label='joy' data-id='1'>",
+        L_ANGLE: 1,
+        HTML_LITERAL: 14,
+        WHITESPACE: 1,
+        HTML_LITERAL: 10,
+        EQ: 1,
+        HTML_STRING_LITERAL: 5,
+        WHITESPACE: 1,
+        HTML_LITERAL: 7,
+        EQ: 1,
+        HTML_STRING_LITERAL: 3,
+        R_ANGLE: 1,
+    }
+}
@@ -3,12 +3,30 @@
 use biome_analyze::declare_assists_group;
 
 pub mod organize_imports;
+pub mod use_arrow_function_syntax;
+pub mod use_function_syntax;
+pub mod use_jsdoc_comment;
+pub mod use_sorted_attributes;
+pub mod use_sorted_keys;
+pub mod use_sorted_type_members;
+pub mod use_string_concatenation;
+pub mod use_string_literal_union;
+pub mod use_template_literal;
 
 declare_assists_group! {
     pub Source {
         name : "source" ,
         rules : [
             self :: organize_imports :: OrganizeImports ,
+            self :: use_arrow_function_syntax :: UseArrowFunctionSyntax ,
+            self :: use_function_syntax :: UseFunctionSyntax ,
+            self :: use_jsdoc_comment :: UseJsdocComment ,
+            self :: use_sorted_attributes :: UseSortedAttributes ,
+            self :: use_sorted_keys :: UseSortedKeys ,
+            self :: use_sorted_type_members :: UseSortedTypeMembers ,
+            self :: use_string_concatenation :: UseStringConcatenation ,
+            self :: use_string_literal_union :: UseStringLiteralUnion ,
+            self :: use_template_literal :: UseTemplateLiteral ,
         ]
      }
 }
@@ -11,7 +11,7 @@ use biome_js_syntax::{
 };
 use biome_rowan::{
     chain_trivia_pieces, AstNode, AstNodeExt, AstNodeList, AstSeparatedList, BatchMutationExt,
-    SyntaxTriviaPiece, TokenText, TriviaPiece,
+    Direction, SyntaxTriviaPiece, TokenText, TriviaPiece,
 };
 
 use crate::JsRuleAction;
@@ -40,6 +40,25 @@ declare_source_rule! {
     /// import { Popup } from '@ui/Popup';
     /// import { createConnection } from '@server/database';
     /// ```
+    ///
+    /// The named imports from the same module are merged into a single import,
+    /// and the duplicated specifiers are removed.
+    /// The comments attached to a merged import are moved before the resulting import.
+    ///
+    /// ```js
+    /// import { useEffect } from 'react';
+    /// // used by the form
+    /// import { useRef, useEffect } from 'react';
+    /// ```
+    ///
+    /// Type-only imports are merged with the other type-only imports from the same module.
+    /// When the `organizeImports.mergeTypeImports` option is enabled,
+    /// they are also merged with the value imports, using inline `type` modifiers.
+    ///
+    /// ```ts
+    /// import type { FC } from 'react';
+    /// import { useRef } from 'react';
+    /// ```
     pub OrganizeImports {
         version: "1.0.0",
         name: "organizeImports",
@@ -53,7 +72,7 @@ impl Rule for OrganizeImports {
     type Query = Ast<JsModule>;
     type State = ImportGroups;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = OrganizeImportsOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let root = ctx.query();
@@ -124,9 +143,13 @@ impl Rule for OrganizeImports {
             groups.push(ImportGroup { first_node, nodes });
         }
 
+        for group in &mut groups {
+            group.merge_imports(ctx.options());
+        }
+
         groups
             .iter()
-            .any(|group| !group.is_sorted())
+            .any(|group| !group.is_sorted() || group.has_merged_imports())
             .then_some(ImportGroups { groups })
     }
 
@@ -193,7 +216,6 @@ impl Rule for OrganizeImports {
             let nodes_iter = next_group
                 .nodes
                 .values()
-                // TODO: Try to merge nodes from the same source
                 .flat_map(|nodes| nodes.iter())
                 .enumerate();
 
@@ -205,6 +227,13 @@ impl Rule for OrganizeImports {
                         .unwrap_or_else(|| panic!("mising node {item_slot} {node_index}"));
                 }
 
+                // The specifiers of a merged import are emitted by the import it was merged into.
+                // A merged import is never the first import of its source, so it's never
+                // the first node of the group either.
+                if import_node.is_merged {
+                    continue;
+                }
+
                 let first_token = import_node.node.import_token().ok()?;
                 let mut node = import_node.build_sorted_node();
 
@@ -239,6 +268,7 @@ impl Rule for OrganizeImports {
                     ));
                 }
 
+                let node = import_node.prepend_merged_comments(node)?;
                 new_list.push(AnyJsModuleItem::JsImport(node));
             }
 
@@ -268,6 +298,16 @@ impl Rule for OrganizeImports {
     }
 }
 
+/// Options for the action `organizeImports`.
+#[derive(Clone, Debug, Default)]
+pub struct OrganizeImportsOptions {
+    /// Whether to merge the type-only imports into the value imports from the same module,
+    /// using inline `type` modifiers.
+    pub merge_type_imports: bool,
+}
+
+type ImportSpecifiers = BTreeMap<ImportKey, (AnyJsNamedImportSpecifier, Option<JsSyntaxToken>)>;
+
 #[derive(Debug)]
 pub struct ImportGroups {
     /// The list of all the import groups in the file
@@ -302,6 +342,39 @@ impl ImportGroup {
                 is_sorted
             })
     }
+
+    /// Returns true if some imports of the group were merged into other imports
+    fn has_merged_imports(&self) -> bool {
+        self.nodes
+            .values()
+            .flat_map(|nodes| nodes.iter())
+            .any(|import_node| import_node.is_merged)
+    }
+
+    /// Merges the named imports of each source into the first named import of this source
+    fn merge_imports(&mut self, options: &OrganizeImportsOptions) {
+        for nodes in self.nodes.values_mut() {
+            let mut value_target = None;
+            let mut type_target = None;
+            for index in 0..nodes.len() {
+                let Some(is_type_only) = nodes[index].mergeable_kind() else {
+                    continue;
+                };
+                let target = if is_type_only && !options.merge_type_imports {
+                    &mut type_target
+                } else {
+                    &mut value_target
+                };
+                match *target {
+                    None => *target = Some(index),
+                    Some(target_index) => {
+                        let (previous_nodes, next_nodes) = nodes.split_at_mut(index);
+                        previous_nodes[target_index].merge(&mut next_nodes[0]);
+                    }
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -312,7 +385,14 @@ struct ImportNode {
     separator_count: usize,
     /// Map storing all the named import specifiers and their associated trailing separator,
     /// sorted in natural order
-    specifiers: BTreeMap<ImportKey, (AnyJsNamedImportSpecifier, Option<JsSyntaxToken>)>,
+    specifiers: ImportSpecifiers,
+    /// Whether this import was merged into a previous import from the same source, and should be removed
+    is_merged: bool,
+    /// The imports that were merged into this one
+    merged_imports: Vec<JsImport>,
+    /// Whether the `type` keyword of this import should be removed,
+    /// because value imports were merged into this type-only import
+    removes_type_token: bool,
 }
 
 impl From<JsImport> for ImportNode {
@@ -344,6 +424,9 @@ impl From<JsImport> for ImportNode {
             node,
             separator_count,
             specifiers: specifiers.unwrap_or_default(),
+            is_merged: false,
+            merged_imports: Vec::new(),
+            removes_type_token: false,
         }
     }
 }
@@ -363,6 +446,123 @@ impl ImportNode {
         })
     }
 
+    /// Returns whether this import is type-only, if it can be merged with the other imports of its source
+    fn mergeable_kind(&self) -> Option<bool> {
+        let AnyJsImportClause::JsImportNamedClause(import_named_clause) =
+            self.node.import_clause().ok()?
+        else {
+            return None;
+        };
+        // Imports with different attributes can't be merged
+        if import_named_clause.assertion().is_some() {
+            return None;
+        }
+        // Bogus specifiers, and specifiers that import the same name twice, are not collected
+        let specifiers = import_named_clause.named_specifiers().ok()?.specifiers();
+        let has_all_specifiers =
+            !self.merged_imports.is_empty() || specifiers.len() == self.specifiers.len();
+        has_all_specifiers
+            .then(|| import_named_clause.type_token().is_some() && !self.removes_type_token)
+    }
+
+    /// Moves the specifiers of `other` into this import, and marks `other` as merged.
+    ///
+    /// Nothing is merged if a specifier of `other` imports the same name as a different specifier of this import.
+    fn merge(&mut self, other: &mut ImportNode) {
+        let (Some(is_type_only), Some(other_is_type_only)) =
+            (self.mergeable_kind(), other.mergeable_kind())
+        else {
+            return;
+        };
+        let with_type_modifiers = |specifiers: &ImportSpecifiers| {
+            specifiers
+                .iter()
+                .map(|(key, (node, separator))| {
+                    let node = with_type_modifier(node)?;
+                    Some((key.clone(), (node, separator.clone())))
+                })
+                .collect::<Option<BTreeMap<_, _>>>()
+        };
+        // A type-only import that absorbs value specifiers becomes a value import with inline types
+        let specifiers = if is_type_only && !other_is_type_only {
+            with_type_modifiers(&self.specifiers)
+        } else {
+            Some(self.specifiers.clone())
+        };
+        let other_specifiers = if !is_type_only && other_is_type_only {
+            with_type_modifiers(&other.specifiers)
+        } else {
+            Some(other.specifiers.clone())
+        };
+        let (Some(specifiers), Some(other_specifiers)) = (specifiers, other_specifiers) else {
+            return;
+        };
+        let has_conflicts = other_specifiers.iter().any(|(key, (node, _))| {
+            specifiers.get(key).is_some_and(|(specifier, _)| {
+                specifier.syntax().text_trimmed() != node.syntax().text_trimmed()
+            })
+        });
+        if has_conflicts {
+            return;
+        }
+
+        let has_trailing_separator =
+            !specifiers.is_empty() && self.separator_count >= specifiers.len();
+        self.specifiers = specifiers;
+        for (key, specifier) in other_specifiers {
+            // Duplicated specifiers are removed
+            self.specifiers.entry(key).or_insert(specifier);
+        }
+        self.separator_count = if has_trailing_separator {
+            self.specifiers.len()
+        } else {
+            self.specifiers.len().saturating_sub(1)
+        };
+        self.removes_type_token |= is_type_only && !other_is_type_only;
+        self.merged_imports.push(other.node.clone());
+        other.is_merged = true;
+    }
+
+    /// Moves the comments of the imports merged into this import before `import`
+    fn prepend_merged_comments(&self, import: JsImport) -> Option<JsImport> {
+        let mut comments = Vec::new();
+        for merged_import in &self.merged_imports {
+            // The comments of the specifiers are moved with the specifiers
+            let specifiers_range = match merged_import.import_clause() {
+                Ok(AnyJsImportClause::JsImportNamedClause(import_named_clause)) => {
+                    import_named_clause
+                        .named_specifiers()
+                        .ok()
+                        .map(|specifiers| specifiers.specifiers().syntax().text_range())
+                }
+                _ => None,
+            };
+            for token in merged_import.syntax().descendants_tokens(Direction::Next) {
+                if specifiers_range.is_some_and(|range| range.contains_range(token.text_range())) {
+                    continue;
+                }
+                let trivia = token
+                    .leading_trivia()
+                    .pieces()
+                    .chain(token.trailing_trivia().pieces());
+                for piece in trivia.filter(|piece| piece.is_comments()) {
+                    comments.push((piece.kind(), piece.text().to_string()));
+                }
+            }
+        }
+        if comments.is_empty() {
+            return Some(import);
+        }
+
+        let import_token = import.import_token().ok()?;
+        let leading_trivia: Vec<_> = leading_trivia_iter(&import_token)
+            .chain(comments.iter().flat_map(|(kind, text)| {
+                [(*kind, text.as_str()), (TriviaPieceKind::Newline, "\n")]
+            }))
+            .collect();
+        Some(import.with_import_token(import_token.with_leading_trivia(leading_trivia)))
+    }
+
     /// Build a clone of the original node this import node was created from with its import specifiers sorted
     fn build_sorted_node(&self) -> JsImport {
         let import = self.node.clone().detach();
@@ -505,9 +705,34 @@ impl ImportNode {
             }
         }
 
-        import
-            .replace_node_discard_trivia(old_specifiers, new_specifiers)
-            .expect("old_specifiers should be a child of import")
+        let type_token = import_named_clause
+            .type_token()
+            .filter(|_| !self.removes_type_token);
+        let import_named_clause = import_named_clause
+            .with_named_specifiers(new_specifiers)
+            .with_type_token(type_token);
+        import.with_import_clause(import_named_clause.into())
+    }
+}
+
+/// Returns a clone of `specifier` with a `type` modifier
+fn with_type_modifier(specifier: &AnyJsNamedImportSpecifier) -> Option<AnyJsNamedImportSpecifier> {
+    let specifier = specifier.clone().detach();
+    let first_token = specifier.syntax().first_token()?;
+    // The leading trivia of the specifier is moved to the `type` modifier
+    let type_token = make::token(T![type])
+        .with_leading_trivia_pieces(first_token.leading_trivia().pieces())
+        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+    let specifier = specifier
+        .replace_token_discard_trivia(first_token.clone(), first_token.with_leading_trivia([]))?;
+    match specifier {
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+            Some(specifier.with_type_token(Some(type_token)).into())
+        }
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            Some(specifier.with_type_token(Some(type_token)).into())
+        }
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => None,
     }
 }
 
@@ -573,7 +798,7 @@ fn leading_trivia_iter(
     })
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct ImportKey(TokenText);
 
 impl Ord for ImportKey {
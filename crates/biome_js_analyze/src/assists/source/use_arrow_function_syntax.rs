@@ -0,0 +1,317 @@
+use crate::lint::complexity::use_arrow_function::{
+    needs_parentheses, to_arrow_body, to_arrow_type_parameters,
+};
+use crate::services::semantic::Semantic;
+use crate::utils::function::uses_function_context;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsDeclarationClause, AnyJsExpression, AnyJsStatement,
+    JsArrowFunctionExpression, JsExport, JsFileSource, JsFunctionBody, JsFunctionDeclaration,
+    JsFunctionExpression, JsInitializerClause, JsParameters, JsStaticMemberExpression,
+    JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, JsVariableDeclarator, TsReturnTypeAnnotation,
+    TsTypeParameters, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstSeparatedList, BatchMutationExt, TriviaPieceKind,
+};
+
+declare_source_rule! {
+    /// Converts a function declaration or a function expression to an arrow function.
+    ///
+    /// The action is provided for the functions that intersect the selection,
+    /// under the `refactor.rewrite` code action kind.
+    ///
+    /// An arrow function doesn't bind its own `this`, `super`, `new.target`, and `arguments`,
+    /// so the action isn't provided for the functions that use them.
+    /// The action isn't provided for the generators, the named function expressions, and the functions used as constructors either.
+    /// A function is considered used as a constructor if it is called with `new`, extended by a class, its `prototype` is accessed,
+    /// or one of its properties is assigned.
+    ///
+    /// A function declaration is converted to a `const` declaration, which isn't hoisted:
+    /// the action is only provided if the function isn't used before its declaration, and isn't reassigned.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// function add(a, b) {
+    ///     return a + b;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// promise.then(function (value) {
+    ///     return value * 2;
+    /// });
+    /// ```
+    pub UseArrowFunctionSyntax {
+        version: "next",
+        name: "useArrowFunctionSyntax",
+        language: "js",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsConvertibleFunction = JsFunctionDeclaration | JsFunctionExpression
+}
+
+impl Rule for UseArrowFunctionSyntax {
+    type Query = Semantic<AnyJsConvertibleFunction>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let function = ctx.query();
+        let model = ctx.model();
+        let (star_token, parameters) = match function {
+            AnyJsConvertibleFunction::JsFunctionDeclaration(declaration) => {
+                if !can_be_a_const(declaration, model) {
+                    return None;
+                }
+                (declaration.star_token(), declaration.parameters().ok()?)
+            }
+            AnyJsConvertibleFunction::JsFunctionExpression(expression) => {
+                if expression.id().is_some()
+                    || is_constructor(expression.syntax())
+                    || is_bound_to_object(expression, model)
+                {
+                    return None;
+                }
+                (expression.star_token(), expression.parameters().ok()?)
+            }
+        };
+        if star_token.is_some() {
+            return None;
+        }
+        let has_this_parameter = parameters
+            .items()
+            .iter()
+            .next()
+            .and_then(|parameter| parameter.ok())
+            .is_some_and(|parameter| parameter.as_ts_this_parameter().is_some());
+        if has_this_parameter || uses_function_context(function.syntax(), model) {
+            return None;
+        }
+        Some(())
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let source_type = ctx.source_type::<JsFileSource>();
+        let mut mutation = ctx.root().begin();
+        match ctx.query() {
+            AnyJsConvertibleFunction::JsFunctionDeclaration(declaration) => {
+                let arrow_function = to_arrow_function(
+                    declaration
+                        .async_token()
+                        // The leading trivia of the declaration is moved to the `const` keyword
+                        .map(|token| token.with_leading_trivia_pieces([])),
+                    declaration.type_parameters(),
+                    declaration.parameters().ok()?,
+                    declaration.return_type_annotation(),
+                    declaration.body().ok()?.trim_trailing_trivia()?,
+                    source_type,
+                );
+                let variable_declaration = make::js_variable_declaration(
+                    make::token(T![const])
+                        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    make::js_variable_declarator_list(
+                        [
+                            make::js_variable_declarator(AnyJsBindingPattern::AnyJsBinding(
+                                declaration.id().ok()?.trim_trivia()?,
+                            ))
+                            .with_initializer(make::js_initializer_clause(
+                                make::token_decorated_with_space(T![=]),
+                                arrow_function.into(),
+                            ))
+                            .build(),
+                        ],
+                        [],
+                    ),
+                )
+                .build();
+                if declaration.parent::<JsExport>().is_some() {
+                    let clause = make::js_variable_declaration_clause(variable_declaration)
+                        .with_semicolon_token(make::token(T![;]))
+                        .build();
+                    mutation.replace_node(
+                        AnyJsDeclarationClause::from(declaration.clone()),
+                        clause.into(),
+                    );
+                } else {
+                    let statement = make::js_variable_statement(variable_declaration)
+                        .with_semicolon_token(make::token(T![;]))
+                        .build();
+                    mutation
+                        .replace_node(AnyJsStatement::from(declaration.clone()), statement.into());
+                }
+            }
+            AnyJsConvertibleFunction::JsFunctionExpression(expression) => {
+                let arrow_function = to_arrow_function(
+                    expression.async_token(),
+                    expression.type_parameters(),
+                    expression.parameters().ok()?,
+                    expression.return_type_annotation(),
+                    expression.body().ok()?,
+                    source_type,
+                );
+                let arrow_function = if needs_parentheses(expression) {
+                    AnyJsExpression::from(make::parenthesized(
+                        arrow_function.trim_trailing_trivia()?,
+                    ))
+                } else {
+                    AnyJsExpression::from(arrow_function)
+                };
+                mutation.replace_node(AnyJsExpression::from(expression.clone()), arrow_function);
+            }
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Rewrite),
+            Applicability::MaybeIncorrect,
+            markup! { "Convert to an arrow function." },
+            mutation,
+        ))
+    }
+}
+
+fn to_arrow_function(
+    async_token: Option<JsSyntaxToken>,
+    type_parameters: Option<TsTypeParameters>,
+    parameters: JsParameters,
+    return_type_annotation: Option<TsReturnTypeAnnotation>,
+    body: JsFunctionBody,
+    source_type: &JsFileSource,
+) -> JsArrowFunctionExpression {
+    let mut arrow_function_builder = make::js_arrow_function_expression(
+        parameters.into(),
+        make::token(T![=>]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        to_arrow_body(body),
+    );
+    if let Some(async_token) = async_token {
+        arrow_function_builder = arrow_function_builder.with_async_token(async_token);
+    }
+    if let Some(type_parameters) = type_parameters {
+        arrow_function_builder = arrow_function_builder
+            .with_type_parameters(to_arrow_type_parameters(type_parameters, source_type));
+    }
+    if let Some(return_type_annotation) = return_type_annotation {
+        arrow_function_builder =
+            arrow_function_builder.with_return_type_annotation(return_type_annotation);
+    }
+    arrow_function_builder.build()
+}
+
+/// Returns `true` if `declaration` can be replaced by a `const` declaration.
+fn can_be_a_const(declaration: &JsFunctionDeclaration, model: &SemanticModel) -> bool {
+    let Ok(AnyJsBinding::JsIdentifierBinding(id)) = declaration.id() else {
+        return false;
+    };
+    // The declarations of a block of a sloppy-mode `if` have a specific semantics
+    let statement = match declaration.syntax().parent() {
+        Some(parent)
+            if matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_STATEMENT_LIST | JsSyntaxKind::JS_MODULE_ITEM_LIST
+            ) =>
+        {
+            declaration.syntax().clone()
+        }
+        Some(parent) if parent.kind() == JsSyntaxKind::JS_EXPORT => parent,
+        _ => return false,
+    };
+    if has_overloads(&statement) {
+        return false;
+    }
+    let binding = model.as_binding(&id);
+    if binding.all_writes().next().is_some() {
+        return false;
+    }
+    let range = declaration.range();
+    binding.all_references().all(|reference| {
+        let position = reference.range_start();
+        // A `const` can't be used before its declaration, except in the function itself
+        (position >= range.end() || range.contains(position))
+            && !is_used_as_object(reference.syntax())
+    })
+}
+
+/// Returns `true` if `expression` is assigned to a variable that is used as a constructor or an object.
+fn is_bound_to_object(expression: &JsFunctionExpression, model: &SemanticModel) -> bool {
+    let Some(declarator) = expression
+        .parent::<JsInitializerClause>()
+        .and_then(|initializer| initializer.parent::<JsVariableDeclarator>())
+    else {
+        return false;
+    };
+    let Ok(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(id))) =
+        declarator.id()
+    else {
+        return false;
+    };
+    model
+        .as_binding(&id)
+        .all_references()
+        .any(|reference| is_used_as_object(reference.syntax()))
+}
+
+/// Returns `true` if the function referenced by `reference` is used as a constructor or an object:
+/// it is called with `new`, extended by a class, its `prototype` is accessed, or one of its properties is assigned.
+fn is_used_as_object(reference: &JsSyntaxNode) -> bool {
+    let Some(mut object) = reference.parent() else {
+        return false;
+    };
+    if is_constructor(&object) {
+        return true;
+    }
+    while let Some(parent) = object
+        .parent()
+        .filter(|parent| parent.kind() == JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+    {
+        object = parent;
+    }
+    let Some(member) = object.parent() else {
+        return false;
+    };
+    if member.first_child().as_ref() != Some(&object) {
+        return false;
+    }
+    match member.kind() {
+        JsSyntaxKind::JS_STATIC_MEMBER_ASSIGNMENT | JsSyntaxKind::JS_COMPUTED_MEMBER_ASSIGNMENT => {
+            true
+        }
+        JsSyntaxKind::JS_STATIC_MEMBER_EXPRESSION => JsStaticMemberExpression::cast(member)
+            .and_then(|member| member.member().ok())
+            .is_some_and(|name| name.syntax().text_trimmed() == "prototype"),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the statement that declares a function is preceded by overload signatures.
+fn has_overloads(statement: &JsSyntaxNode) -> bool {
+    statement.prev_sibling().is_some_and(|sibling| {
+        sibling.kind() == JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+            || sibling.first_child().is_some_and(|clause| {
+                clause.kind() == JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+            })
+    })
+}
+
+/// Returns `true` if the function `node` is called with `new` or is extended by a class.
+fn is_constructor(node: &JsSyntaxNode) -> bool {
+    node.ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+        .is_some_and(|ancestor| {
+            matches!(
+                ancestor.kind(),
+                JsSyntaxKind::JS_NEW_EXPRESSION | JsSyntaxKind::JS_EXTENDS_CLAUSE
+            )
+        })
+}
@@ -0,0 +1,231 @@
+use crate::services::semantic::Semantic;
+use crate::utils::function::uses_function_context;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern, AnyJsDeclarationClause,
+    AnyJsExpression, AnyJsFormalParameter, AnyJsFunctionBody, AnyJsParameter, AnyJsStatement,
+    JsArrowFunctionExpression, JsExpressionStatement, JsFunctionBody, JsInitializerClause,
+    JsParameters, JsVariableDeclaration, JsVariableDeclarationClause, JsVariableDeclarator,
+    JsVariableDeclaratorList, JsVariableStatement, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstSeparatedList, BatchMutationExt, TriviaPieceKind,
+};
+
+declare_source_rule! {
+    /// Converts an arrow function to a function expression or a function declaration.
+    ///
+    /// The action is provided for the arrow functions that intersect the selection,
+    /// under the `refactor.rewrite` code action kind.
+    ///
+    /// An arrow function uses the `this`, `super`, `new.target`, and `arguments` of its parent,
+    /// so the action isn't provided for the arrow functions that use them.
+    ///
+    /// An arrow function that initializes a `const` declaration with a single variable is converted to a function declaration.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const add = (a, b) => a + b;
+    /// ```
+    ///
+    /// ```js
+    /// promise.then((value) => {
+    ///     return value * 2;
+    /// });
+    /// ```
+    pub UseFunctionSyntax {
+        version: "next",
+        name: "useFunctionSyntax",
+        language: "js",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsConstStatement = JsVariableStatement | JsVariableDeclarationClause
+}
+
+impl Rule for UseFunctionSyntax {
+    type Query = Semantic<JsArrowFunctionExpression>;
+    /// The `const` statement that can be replaced by a function declaration, if any
+    type State = Option<AnyJsConstStatement>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let arrow_function = ctx.query();
+        // A function expression can't start an expression statement
+        if arrow_function.parent::<JsExpressionStatement>().is_some()
+            || uses_function_context(arrow_function.syntax(), ctx.model())
+        {
+            return None;
+        }
+        Some(const_statement(arrow_function))
+    }
+
+    fn action(ctx: &RuleContext<Self>, statement: &Self::State) -> Option<JsRuleAction> {
+        let arrow_function = ctx.query();
+        let parameters = to_parameters(arrow_function.parameters().ok()?)?;
+        let body = to_function_body(arrow_function.body().ok()?)?;
+        let mut mutation = ctx.root().begin();
+        if let Some(statement) = statement {
+            let declarator = arrow_function
+                .parent::<JsInitializerClause>()?
+                .parent::<JsVariableDeclarator>()?;
+            let AnyJsBindingPattern::AnyJsBinding(id) = declarator.id().ok()? else {
+                return None;
+            };
+            let mut builder = make::js_function_declaration(
+                make::token(T![function])
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                id.trim_trivia()?,
+                parameters,
+                body,
+            );
+            if let Some(async_token) = arrow_function.async_token() {
+                builder = builder.with_async_token(async_token);
+            }
+            if let Some(type_parameters) = arrow_function.type_parameters() {
+                builder = builder.with_type_parameters(type_parameters);
+            }
+            if let Some(return_type_annotation) = arrow_function.return_type_annotation() {
+                builder = builder.with_return_type_annotation(return_type_annotation);
+            }
+            let declaration = builder.build();
+            match statement {
+                AnyJsConstStatement::JsVariableStatement(statement) => {
+                    mutation
+                        .replace_node(AnyJsStatement::from(statement.clone()), declaration.into());
+                }
+                AnyJsConstStatement::JsVariableDeclarationClause(clause) => {
+                    mutation.replace_node(
+                        AnyJsDeclarationClause::from(clause.clone()),
+                        declaration.into(),
+                    );
+                }
+            }
+        } else {
+            let mut builder = make::js_function_expression(
+                make::token(T![function])
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                parameters,
+                body,
+            );
+            if let Some(async_token) = arrow_function.async_token() {
+                builder = builder.with_async_token(async_token);
+            }
+            if let Some(type_parameters) = arrow_function.type_parameters() {
+                builder = builder.with_type_parameters(type_parameters);
+            }
+            if let Some(return_type_annotation) = arrow_function.return_type_annotation() {
+                builder = builder.with_return_type_annotation(return_type_annotation);
+            }
+            mutation.replace_node(
+                AnyJsExpression::from(arrow_function.clone()),
+                builder.build().into(),
+            );
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Rewrite),
+            Applicability::MaybeIncorrect,
+            if statement.is_some() {
+                markup! { "Convert to a function declaration." }
+            } else {
+                markup! { "Convert to a function expression." }
+            },
+            mutation,
+        ))
+    }
+}
+
+/// Returns the statement of `const name = () => {}`, if `arrow_function` is the initializer of
+/// a `const` declaration with a single variable.
+fn const_statement(arrow_function: &JsArrowFunctionExpression) -> Option<AnyJsConstStatement> {
+    let declarator = arrow_function
+        .parent::<JsInitializerClause>()?
+        .parent::<JsVariableDeclarator>()?;
+    if declarator.variable_annotation().is_some()
+        || !matches!(
+            declarator.id(),
+            Ok(AnyJsBindingPattern::AnyJsBinding(
+                AnyJsBinding::JsIdentifierBinding(_)
+            ))
+        )
+    {
+        return None;
+    }
+    let declarators = declarator.parent::<JsVariableDeclaratorList>()?;
+    if declarators.len() != 1 {
+        return None;
+    }
+    let declaration = declarators.parent::<JsVariableDeclaration>()?;
+    if !declaration.is_const() || declaration.await_token().is_some() {
+        return None;
+    }
+    declaration.parent::<AnyJsConstStatement>()
+}
+
+/// Returns the parameters of a function from the parameters of an arrow function.
+fn to_parameters(parameters: AnyJsArrowFunctionParameters) -> Option<JsParameters> {
+    match parameters {
+        AnyJsArrowFunctionParameters::JsParameters(parameters) => Some(parameters),
+        // `a => a` has a single parameter without parentheses
+        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+            let parameter = make::js_formal_parameter(
+                make::js_decorator_list([]),
+                AnyJsBindingPattern::AnyJsBinding(binding.trim_trailing_trivia()?),
+            )
+            .build();
+            Some(make::js_parameters(
+                make::token(T!['(']),
+                make::js_parameter_list(
+                    [AnyJsParameter::AnyJsFormalParameter(
+                        AnyJsFormalParameter::JsFormalParameter(parameter),
+                    )],
+                    [],
+                ),
+                make::token(T![')']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            ))
+        }
+    }
+}
+
+/// Returns the body of a function from the body of an arrow function.
+fn to_function_body(body: AnyJsFunctionBody) -> Option<JsFunctionBody> {
+    let expression = match body {
+        AnyJsFunctionBody::JsFunctionBody(body) => return Some(body),
+        AnyJsFunctionBody::AnyJsExpression(expression) => expression,
+    };
+    // `() => ({ ... })` and `() => (first, second)` don't need their parentheses in a return statement
+    let expression = match expression {
+        AnyJsExpression::JsParenthesizedExpression(parenthesized)
+            if matches!(
+                parenthesized.expression(),
+                Ok(AnyJsExpression::JsObjectExpression(_)
+                    | AnyJsExpression::JsSequenceExpression(_))
+            ) =>
+        {
+            parenthesized.expression().ok()?
+        }
+        expression => expression,
+    };
+    let return_statement = make::js_return_statement(
+        make::token(T![return]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+    )
+    .with_argument(expression.trim_trailing_trivia()?)
+    .with_semicolon_token(make::token(T![;]))
+    .build();
+    Some(make::js_function_body(
+        make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        make::js_directive_list([]),
+        make::js_statement_list([AnyJsStatement::from(return_statement)]),
+        make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+    ))
+}
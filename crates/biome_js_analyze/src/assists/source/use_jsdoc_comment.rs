@@ -0,0 +1,221 @@
+use crate::utils::jsdoc::{jsdoc_comment, returns_value, AnyJsDocumentableFunction};
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Ast, Rule, SourceActionKind,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern, AnyJsFormalParameter,
+    AnyJsFunctionBody, AnyJsParameter, AnyTsReturnType, AnyTsType, JsSyntaxNode, JsSyntaxToken,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, TriviaPieceKind};
+use std::borrow::Cow;
+
+declare_source_rule! {
+    /// Generates a JSDoc comment for a function.
+    ///
+    /// The action is provided for the functions that intersect the selection and don't have a JSDoc comment yet,
+    /// under the `source.generateJsdoc` code action kind.
+    /// It applies to the function declarations, the default exported functions,
+    /// and the functions and arrow functions that initialize a variable, either exported or not.
+    ///
+    /// The comment contains a `@template` tag for every type parameter,
+    /// a `@param` tag for every parameter, and a `@returns` tag if the function returns a value.
+    /// The types of the tags are filled from the type annotations of the function, when they are present.
+    /// The destructured parameters are named after their position, such as `param0`.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// export function greet<T extends string>(name: T, punctuation = "!"): string {
+    ///     return `Hello, ${name}${punctuation}`;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// export const add = (a, b) => a + b;
+    /// ```
+    pub UseJsdocComment {
+        version: "next",
+        name: "useJsdocComment",
+        language: "js",
+        recommended: false,
+    }
+}
+
+impl Rule for UseJsdocComment {
+    type Query = Ast<AnyJsDocumentableFunction>;
+    /// The first token of the documented statement, which receives the comment
+    type State = JsSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let token = ctx.query().documented_node()?.first_token()?;
+        jsdoc_comment(&token).is_none().then_some(token)
+    }
+
+    fn action(ctx: &RuleContext<Self>, token: &Self::State) -> Option<JsRuleAction> {
+        let function = ctx.query();
+        let mut tags = Vec::new();
+        if let Some(type_parameters) = function.type_parameters() {
+            for type_parameter in type_parameters.items() {
+                let type_parameter = type_parameter.ok()?;
+                let name = type_parameter.name().ok()?.ident_token().ok()?;
+                let name = name.text_trimmed();
+                tags.push(match type_parameter.constraint() {
+                    Some(constraint) => {
+                        let constraint = type_text(constraint.ty().ok()?.syntax());
+                        format!("@template {{{constraint}}} {name}")
+                    }
+                    None => format!("@template {name}"),
+                });
+            }
+        }
+        match function.parameters()? {
+            AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+                tags.push(format!("@param {}", binding_name(&binding, 0)?));
+            }
+            AnyJsArrowFunctionParameters::JsParameters(parameters) => {
+                for (index, parameter) in parameters.items().iter().enumerate() {
+                    if let Some(tag) = parameter_tag(&parameter.ok()?, index) {
+                        tags.push(tag);
+                    }
+                }
+            }
+        }
+        if let Some(tag) = return_tag(function) {
+            tags.push(tag);
+        }
+
+        // The comment is indented like the documented statement
+        let mut trivia: Vec<_> = token
+            .leading_trivia()
+            .pieces()
+            .map(|piece| (piece.kind(), piece.text().to_string()))
+            .collect();
+        let line_start = trivia
+            .iter()
+            .rposition(|(kind, _)| *kind == TriviaPieceKind::Newline)
+            .map_or(0, |position| position + 1);
+        let indentation: String = trivia[line_start..]
+            .iter()
+            .filter(|(kind, _)| *kind == TriviaPieceKind::Whitespace)
+            .map(|(_, text)| text.as_str())
+            .collect();
+        let mut comment = format!("/**\n{indentation} *");
+        for tag in tags {
+            comment.push_str(&format!("\n{indentation} * {tag}"));
+        }
+        comment.push_str(&format!("\n{indentation} */"));
+        trivia.push((TriviaPieceKind::MultiLineComment, comment));
+        trivia.push((TriviaPieceKind::Newline, "\n".to_string()));
+        if !indentation.is_empty() {
+            trivia.push((TriviaPieceKind::Whitespace, indentation));
+        }
+        let new_token = token.with_leading_trivia(
+            trivia
+                .iter()
+                .map(|(kind, text)| (*kind, text.as_str()))
+                .collect::<Vec<_>>(),
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_discard_trivia(token.clone(), new_token);
+        Some(JsRuleAction::new(
+            ActionCategory::Source(SourceActionKind::Other(Cow::Borrowed("generateJsdoc"))),
+            Applicability::Always,
+            markup! { "Generate a JSDoc comment." },
+            mutation,
+        ))
+    }
+}
+
+/// Returns the `@param` tag of a parameter, or `None` for the `this` parameter.
+fn parameter_tag(parameter: &AnyJsParameter, index: usize) -> Option<String> {
+    let (name, type_annotation) = match parameter {
+        AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(
+            parameter,
+        )) => {
+            let name = pattern_name(&parameter.binding().ok()?, index)?;
+            let name = match parameter.initializer() {
+                Some(initializer) => {
+                    let default = type_text(initializer.expression().ok()?.syntax());
+                    format!("[{name}={default}]")
+                }
+                None if parameter.question_mark_token().is_some() => format!("[{name}]"),
+                None => name,
+            };
+            let type_annotation = parameter
+                .type_annotation()
+                .and_then(|annotation| annotation.ty().ok())
+                .map(|ty| type_text(ty.syntax()));
+            (name, type_annotation)
+        }
+        AnyJsParameter::JsRestParameter(parameter) => {
+            let name = pattern_name(&parameter.binding().ok()?, index)?;
+            // `...args: string[]` is documented as `{...string}`
+            let type_annotation = parameter
+                .type_annotation()
+                .and_then(|annotation| annotation.ty().ok())
+                .map(|ty| match ty {
+                    AnyTsType::TsArrayType(array) => match array.element_type() {
+                        Ok(element) => format!("...{}", type_text(element.syntax())),
+                        Err(_) => type_text(array.syntax()),
+                    },
+                    ty => type_text(ty.syntax()),
+                });
+            (name, type_annotation)
+        }
+        AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsBogusParameter(_))
+        | AnyJsParameter::TsThisParameter(_) => return None,
+    };
+    Some(match type_annotation {
+        Some(ty) => format!("@param {{{ty}}} {name}"),
+        None => format!("@param {name}"),
+    })
+}
+
+/// Returns the `@returns` tag of a function, or `None` if it doesn't return a value.
+fn return_tag(function: &AnyJsDocumentableFunction) -> Option<String> {
+    match function.return_type_annotation() {
+        Some(annotation) => match annotation.ty().ok()? {
+            AnyTsReturnType::AnyTsType(AnyTsType::TsVoidType(_))
+            | AnyTsReturnType::TsAssertsReturnType(_) => None,
+            ty => Some(format!("@returns {{{}}}", type_text(ty.syntax()))),
+        },
+        None => match function.body()? {
+            AnyJsFunctionBody::AnyJsExpression(_) => Some("@returns".to_string()),
+            AnyJsFunctionBody::JsFunctionBody(body) => {
+                returns_value(body.syntax()).then(|| "@returns".to_string())
+            }
+        },
+    }
+}
+
+/// Returns the name of a parameter, or `param{index}` if the parameter is destructured.
+fn pattern_name(pattern: &AnyJsBindingPattern, index: usize) -> Option<String> {
+    match pattern {
+        AnyJsBindingPattern::AnyJsBinding(binding) => binding_name(binding, index),
+        _ => Some(format!("param{index}")),
+    }
+}
+
+fn binding_name(binding: &AnyJsBinding, index: usize) -> Option<String> {
+    match binding {
+        AnyJsBinding::JsIdentifierBinding(binding) => {
+            Some(binding.name_token().ok()?.text_trimmed().to_string())
+        }
+        _ => Some(format!("param{index}")),
+    }
+}
+
+/// Returns the source of a type or an expression on a single line.
+fn type_text(node: &JsSyntaxNode) -> String {
+    node.text_trimmed()
+        .to_string()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
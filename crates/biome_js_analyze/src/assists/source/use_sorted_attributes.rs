@@ -0,0 +1,75 @@
+use crate::utils::sort::{sort_run, unsorted_runs, SortableRun, SORT_MEMBERS_CATEGORY};
+use crate::JsRuleAction;
+use biome_analyze::{context::RuleContext, declare_source_rule, Ast, Rule};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{AnyJsxAttribute, AnyJsxAttributeName, JsxAttributeList};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt};
+
+declare_source_rule! {
+    /// Sorts the attributes of a JSX element in natural order.
+    ///
+    /// The action is provided for the elements that intersect the selection,
+    /// under the `source.sortMembers` code action kind.
+    ///
+    /// Spread attributes are never moved, because they override the attributes that precede them:
+    /// only the attributes between them are sorted.
+    /// Sorting the attributes changes the order in which their values are evaluated,
+    /// this is why the action is unsafe.
+    ///
+    /// ## Examples
+    ///
+    /// ```jsx
+    /// <Hello lastName="Smith" firstName="John" {...props} age={42} />;
+    /// ```
+    pub UseSortedAttributes {
+        version: "next",
+        name: "useSortedAttributes",
+        language: "jsx",
+        recommended: false,
+    }
+}
+
+impl Rule for UseSortedAttributes {
+    type Query = Ast<JsxAttributeList>;
+    type State = Vec<SortableRun<AnyJsxAttribute>>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let attributes = ctx.query().iter().map(|attribute| {
+            let key = attribute_key(&attribute);
+            (attribute, key)
+        });
+        let runs = unsorted_runs(attributes);
+        (!runs.is_empty()).then_some(runs)
+    }
+
+    fn action(ctx: &RuleContext<Self>, runs: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        for run in runs {
+            sort_run(&mut mutation, run)?;
+        }
+        Some(JsRuleAction::new(
+            SORT_MEMBERS_CATEGORY,
+            Applicability::MaybeIncorrect,
+            markup! { "Sort the attributes of this element." },
+            mutation,
+        ))
+    }
+}
+
+/// Returns the name of `attribute`, or `None` if the attribute can't be moved.
+fn attribute_key(attribute: &AnyJsxAttribute) -> Option<String> {
+    let AnyJsxAttribute::JsxAttribute(attribute) = attribute else {
+        return None;
+    };
+    match attribute.name().ok()? {
+        AnyJsxAttributeName::JsxName(name) => {
+            Some(name.value_token().ok()?.text_trimmed().to_string())
+        }
+        name @ AnyJsxAttributeName::JsxNamespaceName(_) => {
+            Some(name.syntax().text_trimmed().to_string())
+        }
+    }
+}
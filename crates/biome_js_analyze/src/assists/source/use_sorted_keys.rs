@@ -0,0 +1,90 @@
+use crate::utils::sort::{sort_run, unsorted_runs, SortableRun, SORT_MEMBERS_CATEGORY};
+use crate::JsRuleAction;
+use biome_analyze::{context::RuleContext, declare_source_rule, Ast, Rule};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{AnyJsObjectMember, AnyJsObjectMemberName, JsObjectMemberList};
+use biome_rowan::{AstSeparatedList, BatchMutationExt};
+
+declare_source_rule! {
+    /// Sorts the properties of an object literal in natural order.
+    ///
+    /// The action is provided for the objects that intersect the selection,
+    /// under the `source.sortMembers` code action kind.
+    ///
+    /// Spread elements and properties with computed names are never moved:
+    /// only the properties between them are sorted.
+    /// Sorting the properties changes the order in which their values are evaluated,
+    /// this is why the action is unsafe.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const object = {
+    ///     zed: 1,
+    ///     alpha: 2,
+    ///     ...rest,
+    ///     get beta() {
+    ///         return 3;
+    ///     },
+    ///     [computed]: 4,
+    /// };
+    /// ```
+    pub UseSortedKeys {
+        version: "next",
+        name: "useSortedKeys",
+        language: "js",
+        recommended: false,
+    }
+}
+
+impl Rule for UseSortedKeys {
+    type Query = Ast<JsObjectMemberList>;
+    type State = Vec<SortableRun<AnyJsObjectMember>>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let members = ctx.query().iter().filter_map(|member| {
+            let member = member.ok()?;
+            let key = member_key(&member);
+            Some((member, key))
+        });
+        let runs = unsorted_runs(members);
+        (!runs.is_empty()).then_some(runs)
+    }
+
+    fn action(ctx: &RuleContext<Self>, runs: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        for run in runs {
+            sort_run(&mut mutation, run)?;
+        }
+        Some(JsRuleAction::new(
+            SORT_MEMBERS_CATEGORY,
+            Applicability::MaybeIncorrect,
+            markup! { "Sort the properties of this object." },
+            mutation,
+        ))
+    }
+}
+
+/// Returns the static name of `member`, or `None` if the member can't be moved.
+fn member_key(member: &AnyJsObjectMember) -> Option<String> {
+    let name = match member {
+        AnyJsObjectMember::JsPropertyObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsMethodObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsGetterObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsSetterObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsShorthandPropertyObjectMember(member) => {
+            let name = member.name().ok()?.value_token().ok()?;
+            return Some(name.text_trimmed().to_string());
+        }
+        AnyJsObjectMember::JsSpread(_) | AnyJsObjectMember::JsBogusMember(_) => return None,
+    };
+    match name {
+        AnyJsObjectMemberName::JsLiteralMemberName(name) => Some(name.name().ok()?.to_string()),
+        // Computed names may have side effects
+        AnyJsObjectMemberName::JsComputedMemberName(_)
+        | AnyJsObjectMemberName::JsMetavariable(_) => None,
+    }
+}
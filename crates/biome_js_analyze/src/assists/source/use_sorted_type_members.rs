@@ -0,0 +1,143 @@
+use crate::utils::sort::{sort_run, unsorted_runs, SortableRun, SORT_MEMBERS_CATEGORY};
+use crate::JsRuleAction;
+use biome_analyze::{context::RuleContext, declare_source_rule, Ast, Rule};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{
+    AnyJsObjectMemberName, AnyTsType, AnyTsTypeMember, TsInterfaceDeclaration, TsObjectType,
+    TsTypeMemberList, TsUnionType,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, AstSeparatedList, BatchMutationExt};
+
+declare_source_rule! {
+    /// Sorts the members of union types, interfaces, and object types in natural order.
+    ///
+    /// The action is provided for the types that intersect the selection,
+    /// under the `source.sortMembers` code action kind.
+    ///
+    /// The members of a union type are sorted by their source text.
+    /// The properties, methods, getters, and setters of interfaces and object types are sorted by their name.
+    /// Call signatures, construct signatures, index signatures, and members with computed names are never moved:
+    /// only the members between them are sorted.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// type Direction = "up" | "down" | "left" | "right";
+    ///
+    /// interface Person {
+    ///     name: string;
+    ///     age: number;
+    ///     (): void;
+    ///     greet(): void;
+    ///     address: string;
+    /// }
+    /// ```
+    pub UseSortedTypeMembers {
+        version: "next",
+        name: "useSortedTypeMembers",
+        language: "ts",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyTsTypeWithMembers = TsUnionType | TsInterfaceDeclaration | TsObjectType
+}
+
+pub enum UnsortedTypeMembers {
+    Union(Vec<SortableRun<AnyTsType>>),
+    Members(Vec<SortableRun<AnyTsTypeMember>>),
+}
+
+impl Rule for UseSortedTypeMembers {
+    type Query = Ast<AnyTsTypeWithMembers>;
+    type State = UnsortedTypeMembers;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let list = match ctx.query() {
+            AnyTsTypeWithMembers::TsUnionType(ty) => {
+                let types = ty.types().iter().filter_map(|ty| {
+                    let ty = ty.ok()?;
+                    let key = ty.syntax().text_trimmed().to_string();
+                    Some((ty, Some(key)))
+                });
+                let runs = unsorted_runs(types);
+                return (!runs.is_empty()).then_some(UnsortedTypeMembers::Union(runs));
+            }
+            AnyTsTypeWithMembers::TsInterfaceDeclaration(declaration) => declaration.members(),
+            AnyTsTypeWithMembers::TsObjectType(ty) => ty.members(),
+        };
+        let runs = unsorted_members(&list);
+        (!runs.is_empty()).then_some(UnsortedTypeMembers::Members(runs))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let message = match state {
+            UnsortedTypeMembers::Union(runs) => {
+                for run in runs {
+                    sort_run(&mut mutation, run)?;
+                }
+                markup! { "Sort the members of this union type." }
+            }
+            UnsortedTypeMembers::Members(runs) => {
+                for run in runs {
+                    sort_run(&mut mutation, run)?;
+                }
+                markup! { "Sort the members of this type." }
+            }
+        };
+        Some(JsRuleAction::new(
+            SORT_MEMBERS_CATEGORY,
+            Applicability::Always,
+            message,
+            mutation,
+        ))
+    }
+}
+
+/// Returns the runs of `list` that aren't sorted.
+fn unsorted_members(list: &TsTypeMemberList) -> Vec<SortableRun<AnyTsTypeMember>> {
+    // When the members are on the same line, a member without separator can't be moved before another one
+    let is_multiline = list
+        .iter()
+        .all(|member| member.syntax().has_leading_newline());
+    let members = list.iter().map(|member| {
+        let key = member_key(&member, is_multiline);
+        (member, key)
+    });
+    unsorted_runs(members)
+}
+
+/// Returns the static name of `member`, or `None` if the member can't be moved.
+fn member_key(member: &AnyTsTypeMember, is_multiline: bool) -> Option<String> {
+    let (name, separator) = match member {
+        AnyTsTypeMember::TsPropertySignatureTypeMember(member) => {
+            (member.name().ok()?, member.separator_token())
+        }
+        AnyTsTypeMember::TsMethodSignatureTypeMember(member) => {
+            (member.name().ok()?, member.separator_token())
+        }
+        AnyTsTypeMember::TsGetterSignatureTypeMember(member) => {
+            (member.name().ok()?, member.separator_token())
+        }
+        AnyTsTypeMember::TsSetterSignatureTypeMember(member) => {
+            (member.name().ok()?, member.separator_token())
+        }
+        AnyTsTypeMember::JsBogusMember(_)
+        | AnyTsTypeMember::TsCallSignatureTypeMember(_)
+        | AnyTsTypeMember::TsConstructSignatureTypeMember(_)
+        | AnyTsTypeMember::TsIndexSignatureTypeMember(_) => return None,
+    };
+    if separator.is_none() && !is_multiline {
+        return None;
+    }
+    match name {
+        AnyJsObjectMemberName::JsLiteralMemberName(name) => Some(name.name().ok()?.to_string()),
+        AnyJsObjectMemberName::JsComputedMemberName(_)
+        | AnyJsObjectMemberName::JsMetavariable(_) => None,
+    }
+}
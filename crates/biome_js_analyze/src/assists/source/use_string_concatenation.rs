@@ -0,0 +1,105 @@
+use crate::utils::template::concatenation_from_template_expression;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Ast, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsTemplateElement, JsBinaryOperator, JsTemplateExpression,
+    OperatorPrecedence,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_source_rule! {
+    /// Converts a template literal to a string concatenation.
+    ///
+    /// The action is provided for the template literals that intersect the selection,
+    /// under the `refactor.rewrite` code action kind.
+    /// The template literal must contain at least one expression, and must not be tagged.
+    ///
+    /// A template literal converts its expressions to strings, while the operator `+` converts them to primitives first:
+    /// the result can differ for the objects whose `valueOf()` method doesn't return the same value as their `toString()` method.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const greeting = `Hello, ${name}!`;
+    /// ```
+    ///
+    /// ```js
+    /// const total = `${count + 1} items`;
+    /// ```
+    pub UseStringConcatenation {
+        version: "next",
+        name: "useStringConcatenation",
+        language: "js",
+        recommended: false,
+    }
+}
+
+impl Rule for UseStringConcatenation {
+    type Query = Ast<JsTemplateExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let template = ctx.query();
+        let has_expressions = template
+            .elements()
+            .into_iter()
+            .any(|element| matches!(element, AnyJsTemplateElement::JsTemplateElement(_)));
+        (template.tag().is_none() && has_expressions).then_some(())
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let template = ctx.query();
+        let concatenation = concatenation_from_template_expression(template)?;
+        let concatenation = if needs_parentheses(template) {
+            AnyJsExpression::from(make::parenthesized(concatenation))
+        } else {
+            concatenation
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(template.clone()), concatenation);
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Rewrite),
+            Applicability::MaybeIncorrect,
+            markup! { "Convert to a string concatenation." },
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if the concatenation that replaces `template` needs parentheses,
+/// such as `("a" + b).length` for `` `a${b}`.length ``.
+fn needs_parentheses(template: &JsTemplateExpression) -> bool {
+    let Some(parent) = template.syntax().parent().and_then(AnyJsExpression::cast) else {
+        return false;
+    };
+    match parent {
+        AnyJsExpression::JsBinaryExpression(binary) => {
+            let Ok(operator) = binary.operator() else {
+                return true;
+            };
+            // `a + `${b}`` keeps the order of the operations with `a + ("" + b)`
+            operator.precedence() > OperatorPrecedence::Additive
+                || (matches!(operator, JsBinaryOperator::Plus | JsBinaryOperator::Minus)
+                    && binary
+                        .right()
+                        .is_ok_and(|right| right.syntax() == template.syntax()))
+        }
+        AnyJsExpression::JsParenthesizedExpression(_)
+        | AnyJsExpression::JsSequenceExpression(_)
+        | AnyJsExpression::JsConditionalExpression(_)
+        | AnyJsExpression::JsAssignmentExpression(_)
+        | AnyJsExpression::JsYieldExpression(_)
+        | AnyJsExpression::JsArrowFunctionExpression(_)
+        | AnyJsExpression::JsLogicalExpression(_)
+        | AnyJsExpression::JsInExpression(_)
+        | AnyJsExpression::JsInstanceofExpression(_) => false,
+        _ => true,
+    }
+}
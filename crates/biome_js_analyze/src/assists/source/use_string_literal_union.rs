@@ -0,0 +1,178 @@
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsExpression, AnyJsLiteralExpression, AnyJsStatement, AnyTsEnumMemberName,
+    AnyTsIdentifierBinding, AnyTsType, JsSyntaxKind, JsSyntaxToken, TsEnumDeclaration,
+    TsEnumMember, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, TriviaPieceKind};
+
+declare_source_rule! {
+    /// Converts a numeric enum to a union of string literal types.
+    ///
+    /// The action is provided for the _TypeScript_ enums that intersect the selection,
+    /// under the `refactor.rewrite` code action kind.
+    /// The union contains the names of the members, so the values become strings.
+    ///
+    /// The enum can't be used as a value anymore:
+    /// the action is only provided for the enums whose members are never accessed,
+    /// and that aren't exported or declared in an ambient context.
+    /// The members must not have an initializer, or a numeric literal as initializer.
+    ///
+    /// The lint rule [useEnumInitializers](https://biomejs.dev/linter/rules/use-enum-initializers)
+    /// reports the members without an initializer, and initializes them with their implicit values.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// enum Direction {
+    ///     Up,
+    ///     Down,
+    /// }
+    /// function move(direction: Direction) {}
+    /// ```
+    ///
+    /// ```ts
+    /// const enum Status {
+    ///     Open = 1,
+    ///     Closed,
+    /// }
+    /// let status: Status;
+    /// ```
+    pub UseStringLiteralUnion {
+        version: "next",
+        name: "useStringLiteralUnion",
+        language: "ts",
+        recommended: false,
+    }
+}
+
+impl Rule for UseStringLiteralUnion {
+    type Query = Semantic<TsEnumDeclaration>;
+    type State = Vec<AnyTsType>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let declaration = ctx.query();
+        // The members of an ambient enum without an initializer have an opaque value
+        if declaration.is_ambient() || !is_type_only(declaration, ctx.model()) {
+            return None;
+        }
+        let is_double_quote = ctx.as_preferred_quote().is_double();
+        let literals = declaration
+            .members()
+            .iter()
+            .map(|member| to_literal_type(&member.ok()?, is_double_quote))
+            .collect::<Option<Vec<_>>>()?;
+        (!literals.is_empty()).then_some(literals)
+    }
+
+    fn action(ctx: &RuleContext<Self>, literals: &Self::State) -> Option<JsRuleAction> {
+        let declaration = ctx.query();
+        let ty = match literals.as_slice() {
+            [literal] => literal.clone(),
+            _ => AnyTsType::from(
+                make::ts_union_type(make::ts_union_type_variant_list(
+                    literals.iter().cloned(),
+                    (1..literals.len()).map(|_| make::token_decorated_with_space(T![|])),
+                ))
+                .build(),
+            ),
+        };
+        let name = declaration
+            .id()
+            .ok()?
+            .as_js_identifier_binding()?
+            .name_token()
+            .ok()?;
+        let alias = make::ts_type_alias_declaration(
+            make::token(T![type]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            AnyTsIdentifierBinding::from(make::ts_identifier_binding(make::ident(
+                name.text_trimmed(),
+            ))),
+            make::token_decorated_with_space(T![=]),
+            ty,
+        )
+        .with_semicolon_token(make::token(T![;]))
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsStatement::from(declaration.clone()),
+            AnyJsStatement::from(alias),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Rewrite),
+            Applicability::MaybeIncorrect,
+            markup! { "Convert to a union of string literal types." },
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `declaration` is a statement that is only referenced as a type,
+/// so that it can be replaced by a type alias.
+fn is_type_only(declaration: &TsEnumDeclaration, model: &SemanticModel) -> bool {
+    if !declaration.syntax().parent().is_some_and(|parent| {
+        matches!(
+            parent.kind(),
+            JsSyntaxKind::JS_STATEMENT_LIST | JsSyntaxKind::JS_MODULE_ITEM_LIST
+        )
+    }) {
+        return false;
+    }
+    let Ok(AnyJsBinding::JsIdentifierBinding(id)) = declaration.id() else {
+        return false;
+    };
+    if model.is_exported(&id) {
+        return false;
+    }
+    model.as_binding(&id).all_references().all(|reference| {
+        // `Direction` is a type reference, but `Direction.Up` and `typeof Direction` aren't
+        reference
+            .syntax()
+            .parent()
+            .is_some_and(|parent| parent.kind() == JsSyntaxKind::TS_REFERENCE_TYPE)
+    })
+}
+
+/// Returns the string literal type of the name of `member`, if it is a numeric member.
+fn to_literal_type(member: &TsEnumMember, is_double_quote: bool) -> Option<AnyTsType> {
+    if let Some(initializer) = member.initializer() {
+        if !matches!(
+            initializer.expression().ok()?.omit_parentheses(),
+            AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsNumberLiteralExpression(_)
+            )
+        ) {
+            return None;
+        }
+    }
+    let name = member.name().ok()?;
+    if let AnyTsEnumMemberName::TsLiteralEnumMemberName(name) = &name {
+        let token = name.value().ok()?;
+        if token.kind() == JsSyntaxKind::JS_STRING_LITERAL {
+            return Some(make::ts_string_literal_type(without_trivia(&token)).into());
+        }
+    }
+    let name = name.name()?;
+    let literal = if is_double_quote {
+        make::js_string_literal(name.text())
+    } else {
+        make::js_string_literal_single_quotes(name.text())
+    };
+    Some(make::ts_string_literal_type(literal).into())
+}
+
+fn without_trivia(token: &JsSyntaxToken) -> JsSyntaxToken {
+    token
+        .with_leading_trivia_pieces([])
+        .with_trailing_trivia_pieces([])
+}
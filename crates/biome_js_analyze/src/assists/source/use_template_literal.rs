@@ -0,0 +1,70 @@
+use crate::utils::template::{
+    is_nested_in_concatenation, is_string_concatenation, template_expression_from_binary_expression,
+};
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Ast, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{AnyJsExpression, JsBinaryExpression};
+use biome_rowan::BatchMutationExt;
+
+declare_source_rule! {
+    /// Converts a string concatenation to a template literal.
+    ///
+    /// The action is provided for the concatenations with the operator `+` that intersect the selection,
+    /// under the `refactor.rewrite` code action kind.
+    /// The concatenation must contain a string literal or a template literal.
+    ///
+    /// The lint rule [useTemplate](https://biomejs.dev/linter/rules/use-template) reports the same concatenations,
+    /// the action makes the conversion available on demand when the rule isn't enabled.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const greeting = "Hello, " + name + "!";
+    /// ```
+    ///
+    /// ```js
+    /// const path = root + "/" + file;
+    /// ```
+    pub UseTemplateLiteral {
+        version: "next",
+        name: "useTemplateLiteral",
+        language: "js",
+        recommended: false,
+    }
+}
+
+impl Rule for UseTemplateLiteral {
+    type Query = Ast<JsBinaryExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        // The whole concatenation is converted
+        if is_nested_in_concatenation(node) {
+            return None;
+        }
+        is_string_concatenation(node).then_some(())
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let template = template_expression_from_binary_expression(node)?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::from(node.clone()),
+            AnyJsExpression::from(template),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Rewrite),
+            Applicability::MaybeIncorrect,
+            markup! { "Convert to a template literal." },
+            mutation,
+        ))
+    }
+}
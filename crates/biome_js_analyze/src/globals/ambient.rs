@@ -0,0 +1,83 @@
+//! The globals declared by the TypeScript declaration files of a project
+
+use crate::services::module_exports::{declared_names, declared_variable_names};
+use biome_js_syntax::{
+    AnyJsDeclarationClause, AnyJsModuleItem, AnyJsRoot, AnyJsStatement, JsModuleItemList,
+    JsSyntaxNode, TsDeclareStatement,
+};
+use biome_rowan::{AstNode, AstNodeList};
+use rustc_hash::FxHashSet;
+
+/// Returns the globals declared by the declaration file `root`.
+///
+/// A declaration file without imports and exports is a global declaration file:
+/// all its declarations are globals, such as `declare var VERSION: string;` or `interface Env {}`.
+/// The other declaration files are modules: only the declarations of their `declare global { ... }`
+/// blocks are globals.
+pub fn declared_ambient_globals(root: &AnyJsRoot) -> FxHashSet<String> {
+    let mut globals = FxHashSet::default();
+    match root {
+        AnyJsRoot::JsModule(module) => {
+            let is_module = module.items().iter().any(|item| {
+                matches!(
+                    item,
+                    AnyJsModuleItem::JsExport(_) | AnyJsModuleItem::JsImport(_)
+                ) || matches!(
+                    item,
+                    AnyJsModuleItem::AnyJsStatement(AnyJsStatement::TsImportEqualsDeclaration(_))
+                )
+            });
+            collect_items(&module.items(), !is_module, &mut globals);
+        }
+        AnyJsRoot::JsScript(script) => {
+            for statement in script.statements() {
+                collect_declaration(statement.syntax(), true, &mut globals);
+            }
+        }
+        AnyJsRoot::JsExpressionSnipped(_) => {}
+    }
+    globals
+}
+
+/// Collects the globals declared by `items`.
+///
+/// The declarations themselves are globals if `is_global` is `true`,
+/// otherwise only the declarations of the `declare global` blocks are collected.
+fn collect_items(items: &JsModuleItemList, is_global: bool, globals: &mut FxHashSet<String>) {
+    for item in items {
+        collect_declaration(item.syntax(), is_global, globals);
+    }
+}
+
+fn collect_declaration(node: &JsSyntaxNode, is_global: bool, globals: &mut FxHashSet<String>) {
+    // `declare var a: string;` and `declare global { ... }`
+    let declaration = match TsDeclareStatement::cast_ref(node) {
+        Some(statement) => statement.declaration().ok(),
+        None => AnyJsDeclarationClause::cast_ref(node),
+    };
+    match declaration {
+        Some(AnyJsDeclarationClause::TsGlobalDeclaration(declaration)) => {
+            if let Ok(body) = declaration.body() {
+                collect_items(&body.items(), true, globals);
+            }
+        }
+        // The declarations of `declare module "name" { ... }` belong to the module
+        Some(
+            AnyJsDeclarationClause::TsExternalModuleDeclaration(_)
+            | AnyJsDeclarationClause::TsImportEqualsDeclaration(_),
+        ) => {}
+        Some(declaration) if is_global => globals.extend(declared_names(&declaration)),
+        Some(_) => {}
+        None if is_global => {
+            let Some(AnyJsStatement::JsVariableStatement(statement)) =
+                AnyJsStatement::cast_ref(node)
+            else {
+                return;
+            };
+            if let Ok(declaration) = statement.declaration() {
+                globals.extend(declared_variable_names(&declaration));
+            }
+        }
+        None => {}
+    }
+}
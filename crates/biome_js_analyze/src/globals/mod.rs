@@ -14,3 +14,6 @@ pub use typescript::is_web_global as is_ts_web_global;
 
 pub mod module;
 pub use module::is_node_builtin_module;
+
+pub mod ambient;
+pub use ambient::declared_ambient_globals;
@@ -22,12 +22,19 @@ pub mod options;
 mod react;
 mod registry;
 mod services;
+mod solid;
 mod suppression_action;
 mod syntax;
 pub mod utils;
+mod vue;
 
 pub use crate::registry::visit_registry;
 pub use crate::services::control_flow::ControlFlowGraph;
+pub use crate::services::manifest::ManifestServices;
+pub use crate::services::module_exports::{
+    collect_exported_names, collect_module_details, collect_module_exports, ExportedName,
+    ModuleDetails, ModuleExports, ProjectModuleExports,
+};
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
@@ -43,6 +50,7 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
 /// Additionally, this function takes a `inspect_matcher` function that can be
 /// used to inspect the "query matches" emitted by the analyzer before they are
 /// processed by the lint rules registry
+#[allow(clippy::too_many_arguments)]
 pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
@@ -50,6 +58,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    module_exports: ProjectModuleExports,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -68,6 +77,9 @@ where
                     if comment.is_legacy {
                         result.push(Ok(SuppressionKind::Deprecated));
                     }
+                    if let Some(date) = comment.expires {
+                        result.push(Ok(SuppressionKind::Expires(date)));
+                    }
                     comment.categories
                 }
                 Err(err) => {
@@ -121,9 +133,14 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
-    services.insert_service(Arc::new(AriaRoles));
-    services.insert_service(Arc::new(AriaProperties));
+    services.insert_service(Arc::new(AriaRoles::with_custom_roles(
+        options.configuration.aria_roles.iter().cloned(),
+    )));
+    services.insert_service(Arc::new(AriaProperties::with_custom_properties(
+        options.configuration.aria_properties.iter().cloned(),
+    )));
     services.insert_service(Arc::new(manifest));
+    services.insert_service(module_exports);
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -145,6 +162,7 @@ pub fn analyze<'a, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    module_exports: ProjectModuleExports,
     emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -158,6 +176,7 @@ where
         options,
         source_type,
         manifest,
+        module_exports,
         emit_signal,
     )
 }
@@ -178,7 +197,7 @@ mod tests {
 
     use crate::lint::correctness::use_exhaustive_dependencies::{Hook, HooksOptions};
     use crate::react::hooks::StableHookResult;
-    use crate::{analyze, AnalysisFilter, ControlFlow};
+    use crate::{analyze, AnalysisFilter, ControlFlow, ProjectModuleExports};
 
     #[ignore]
     #[test]
@@ -225,6 +244,7 @@ mod tests {
                 dependencies,
                 ..Default::default()
             }),
+            ProjectModuleExports::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -317,6 +337,7 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            ProjectModuleExports::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let span = diag.get_span();
@@ -378,6 +399,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn suppression_expiration() {
+        const SOURCE: &str = "
+            // biome-ignore lint/suspicious/noDoubleEquals: expired [expires:2000-01-01]
+            a == b;
+            // biome-ignore lint/suspicious/noDoubleEquals: not expired [expires:2999-12-31]
+            a == b;
+            // biome-ignore lint/suspicious/noDoubleEquals: invalid date [expires:2000-02-30]
+            a == b;
+        ";
+
+        let parsed = parse(
+            SOURCE,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+
+        let mut lint_ranges: Vec<TextRange> = Vec::new();
+        let mut parse_ranges: Vec<TextRange> = Vec::new();
+        let mut expired_ranges: Vec<TextRange> = Vec::new();
+
+        let options = AnalyzerOptions::default();
+        analyze(
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            JsFileSource::js_module(),
+            None,
+            ProjectModuleExports::default(),
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    let span = diag.get_span();
+                    let code = diag.category().unwrap();
+                    if code == category!("lint/suspicious/noDoubleEquals") {
+                        lint_ranges.push(span.unwrap());
+                    }
+
+                    if code == category!("suppressions/parse") {
+                        parse_ranges.push(span.unwrap());
+                    }
+
+                    if code == category!("suppressions/expired") {
+                        assert_eq!(diag.severity(), Severity::Error);
+                        expired_ranges.push(span.unwrap());
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+        assert_eq!(
+            lint_ranges.as_slice(),
+            &[TextRange::new(TextSize::from(331), TextSize::from(333))]
+        );
+
+        assert_eq!(
+            parse_ranges.as_slice(),
+            &[TextRange::new(TextSize::from(305), TextSize::from(315))]
+        );
+
+        assert_eq!(
+            expired_ranges.as_slice(),
+            &[TextRange::new(TextSize::from(13), TextSize::from(89))]
+        );
+    }
+
     #[test]
     fn suppression_syntax() {
         const SOURCE: &str = "
@@ -403,6 +490,7 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            ProjectModuleExports::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let code = diag.category().unwrap();
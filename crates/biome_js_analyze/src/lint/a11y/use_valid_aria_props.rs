@@ -1,11 +1,13 @@
 use crate::services::aria::Aria;
+use crate::utils::markup::JsxMarkupElement;
 use crate::JsRuleAction;
 use biome_analyze::context::RuleContext;
 use biome_analyze::{declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource};
+use biome_aria::a11y::invalid_aria_attributes;
 use biome_console::markup;
 use biome_js_syntax::jsx_ext::AnyJsxElement;
 use biome_js_syntax::JsxAttribute;
-use biome_rowan::{AstNode, AstNodeList, BatchMutationExt};
+use biome_rowan::{AstNode, BatchMutationExt};
 
 declare_lint_rule! {
     /// Ensures that ARIA properties `aria-*` are all valid.
@@ -41,34 +43,11 @@ impl Rule for UseValidAriaProps {
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
-        let node = ctx.query();
-        let aria_properties = ctx.aria_properties();
-
-        // check attributes that belong only to HTML elements
-        if node.is_element() {
-            let attributes: Vec<_> = node
-                .attributes()
-                .iter()
-                .filter_map(|attribute| {
-                    let attribute = attribute.as_jsx_attribute()?;
-                    let attribute_name =
-                        attribute.name().ok()?.as_jsx_name()?.value_token().ok()?;
-                    if attribute_name.text_trimmed().starts_with("aria-")
-                        && aria_properties
-                            .get_property(attribute_name.text_trimmed())
-                            .is_none()
-                    {
-                        Some(attribute.clone())
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            attributes
-        } else {
-            vec![]
-        }
+        let element = JsxMarkupElement(ctx.query().clone());
+        invalid_aria_attributes(&element, ctx.aria_properties())
+            .into_iter()
+            .map(|attribute| attribute.0)
+            .collect()
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, attribute: &Self::State) -> Option<RuleDiagnostic> {
@@ -11,7 +11,8 @@ use biome_js_syntax::{
     JsFunctionBody, JsFunctionDeclaration, JsFunctionExportDefaultDeclaration,
     JsFunctionExpression, JsGetterClassMember, JsGetterObjectMember, JsLanguage,
     JsMethodClassMember, JsMethodObjectMember, JsModule, JsScript, JsSetterClassMember,
-    JsSetterObjectMember, JsStaticInitializationBlockClassMember, JsSyntaxKind, T,
+    JsSetterObjectMember, JsStaticInitializationBlockClassMember, JsSyntaxKind, TsTypeParameters,
+    T,
 };
 use biome_rowan::{
     declare_node_union, AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, Language,
@@ -154,24 +155,9 @@ impl Rule for UseArrowFunction {
             arrow_function_builder = arrow_function_builder.with_async_token(async_token);
         }
         if let Some(type_parameters) = function_expression.type_parameters() {
-            let mut type_parameters_iter =
-                type_parameters.items().iter().filter_map(|item| item.ok());
-            let type_parameter = type_parameters_iter.next();
-            // Keep a trailing comma when there is a single type parameter in arrow functions and JSX is enabled
-            // Or the parser will treat it as a JSX tag and fail to parse it.
-            let type_parameters = if type_parameter.is_some()
-                && type_parameters_iter.next().is_none()
-                && ctx.source_type::<JsFileSource>().is_jsx()
-            {
-                make::ts_type_parameters(
-                    make::token(T![<]),
-                    make::ts_type_parameter_list(type_parameter, Some(make::token(T![,]))),
-                    make::token(T![>]),
-                )
-            } else {
-                type_parameters
-            };
-            arrow_function_builder = arrow_function_builder.with_type_parameters(type_parameters);
+            arrow_function_builder = arrow_function_builder.with_type_parameters(
+                to_arrow_type_parameters(type_parameters, ctx.source_type::<JsFileSource>()),
+            );
         }
         if let Some(return_type_annotation) = function_expression.return_type_annotation() {
             arrow_function_builder =
@@ -197,8 +183,28 @@ impl Rule for UseArrowFunction {
     }
 }
 
+/// Returns the type parameters of a function, changed to be valid in an arrow function.
+pub(crate) fn to_arrow_type_parameters(
+    type_parameters: TsTypeParameters,
+    source_type: &JsFileSource,
+) -> TsTypeParameters {
+    let mut type_parameters_iter = type_parameters.items().iter().filter_map(|item| item.ok());
+    let type_parameter = type_parameters_iter.next();
+    // Keep a trailing comma when there is a single type parameter in arrow functions and JSX is enabled
+    // Or the parser will treat it as a JSX tag and fail to parse it.
+    if type_parameter.is_some() && type_parameters_iter.next().is_none() && source_type.is_jsx() {
+        make::ts_type_parameters(
+            make::token(T![<]),
+            make::ts_type_parameter_list(type_parameter, Some(make::token(T![,]))),
+            make::token(T![>]),
+        )
+    } else {
+        type_parameters
+    }
+}
+
 /// Returns `true` if `function_expr` needs parenthesis when turned into an arrow function.
-fn needs_parentheses(function_expression: &JsFunctionExpression) -> bool {
+pub(crate) fn needs_parentheses(function_expression: &JsFunctionExpression) -> bool {
     function_expression.syntax().parent().is_some_and(|parent| {
         // Copied from the implementation of `NeedsParentheses` for `JsArrowFunctionExpression`
         // in the `biome_js_formatter` crate.
@@ -334,7 +340,7 @@ impl Visitor for AnyThisScopeVisitor {
 }
 
 /// Get a minimal arrow function body from a regular function body.
-fn to_arrow_body(body: JsFunctionBody) -> AnyJsFunctionBody {
+pub(crate) fn to_arrow_body(body: JsFunctionBody) -> AnyJsFunctionBody {
     let body_statements = body.statements();
     // () => { ... }
     let early_result = AnyJsFunctionBody::from(body);
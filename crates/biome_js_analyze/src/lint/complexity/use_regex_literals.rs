@@ -12,7 +12,11 @@ use biome_js_syntax::{
 };
 use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxError, TokenText};
 
-use crate::{services::semantic::Semantic, JsRuleAction};
+use crate::{
+    services::semantic::Semantic,
+    utils::constant::{evaluate, ConstantValue},
+    JsRuleAction,
+};
 
 declare_lint_rule! {
     /// Enforce the use of the regular expression literals instead of the RegExp constructor if possible.
@@ -84,7 +88,7 @@ impl Rule for UseRegexLiterals {
 
         let flags = match args.next() {
             Some(flags) => {
-                let flags = create_flags(flags)?;
+                let flags = create_flags(flags, model)?;
                 Some(flags)
             }
             None => None,
@@ -208,9 +212,20 @@ fn parse_node(node: &JsNewOrCallExpression) -> Option<(AnyJsExpression, JsCallAr
     }
 }
 
-fn create_flags(flags: Result<AnyJsCallArgument, SyntaxError>) -> Option<String> {
+fn create_flags(
+    flags: Result<AnyJsCallArgument, SyntaxError>,
+    model: &SemanticModel,
+) -> Option<String> {
     let flags = flags.ok()?;
-    let flags = extract_literal_string(flags)?;
+    // The flags can be computed, such as `"g" + "i"`, or written in a raw string, such as String.raw`g`
+    let flags = match evaluate(flags.as_any_js_expression()?, Some(model)) {
+        Some(ConstantValue::String(flags)) => flags,
+        _ => extract_literal_string(flags)?,
+    };
+    // The flags are written as is in the regular expression literal
+    if !flags.chars().all(|flag| flag.is_ascii_alphabetic()) {
+        return None;
+    }
     // u flag (Unicode mode) and v flag (unicodeSets mode) cannot be combined.
     if flags == "uv" || flags == "vu" {
         return None;
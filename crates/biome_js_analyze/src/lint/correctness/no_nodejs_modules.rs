@@ -69,11 +69,7 @@ impl Rule for NoNodejsModules {
         let module_name_text = inner_string_text(&module_name);
         let module_name_text = module_name_text.text();
         // Ignore dependencies
-        if ctx.is_dependency(module_name_text)
-            || ctx.is_dev_dependency(module_name_text)
-            || ctx.is_peer_dependency(module_name_text)
-            || ctx.is_optional_dependency(module_name_text)
-        {
+        if ctx.is_declared_dependency(module_name_text) {
             return None;
         }
         is_node_builtin_module(module_name_text).then_some(module_name.text_trimmed_range())
@@ -13,6 +13,19 @@ declare_lint_rule! {
     ///
     /// If you need to allow-list some global bindings, you can use the [`javascript.globals`](/reference/configuration/#javascriptglobals) configuration.
     ///
+    /// Globals can also be declared for a single file using a `biome-globals` comment directive,
+    /// followed by a list of names separated by commas or whitespaces:
+    ///
+    /// ```js
+    /// /* biome-globals myGlobal, anotherGlobal */
+    /// myGlobal;
+    /// ```
+    ///
+    /// The globals declared by the TypeScript declaration files of the project are also recognized:
+    /// the declarations of a `.d.ts` file without imports and exports,
+    /// and the declarations of the `declare global` blocks of the other `.d.ts` files.
+    /// The declaration files must be part of the linted files.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -5,15 +5,18 @@ use biome_control_flow::{
     builder::{BlockId, ROOT_BLOCK_ID},
     ExceptionHandler, ExceptionHandlerKind, Instruction, InstructionKind,
 };
+use biome_deserialize_macros::Deserializable;
 use biome_js_syntax::{
-    JsBlockStatement, JsCaseClause, JsDefaultClause, JsDoWhileStatement, JsForInStatement,
-    JsForOfStatement, JsForStatement, JsFunctionBody, JsIfStatement, JsLabeledStatement,
-    JsLanguage, JsReturnStatement, JsSwitchStatement, JsSyntaxElement, JsSyntaxKind, JsSyntaxNode,
-    JsTryFinallyStatement, JsTryStatement, JsVariableStatement, JsWhileStatement, TextRange,
+    AnyJsExpression, JsBlockStatement, JsCaseClause, JsDefaultClause, JsDoWhileStatement,
+    JsExpressionStatement, JsForInStatement, JsForOfStatement, JsForStatement, JsFunctionBody,
+    JsIfStatement, JsLabeledStatement, JsLanguage, JsReturnStatement, JsSwitchStatement,
+    JsSyntaxElement, JsSyntaxKind, JsSyntaxNode, JsTryFinallyStatement, JsTryStatement,
+    JsVariableStatement, JsWhileStatement, TextRange,
 };
 use biome_rowan::{declare_node_union, AstNode};
 use roaring::bitmap::RoaringBitmap;
 use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
 
 use crate::services::control_flow::{ControlFlowGraph, JsControlFlowGraph};
 
@@ -47,6 +50,42 @@ declare_lint_rule! {
     ///     }
     /// }
     /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function example() {
+    ///     for (const item of items) {
+    ///         try {
+    ///             break;
+    ///         } finally {
+    ///             // The finally clause is executed when the loop is left
+    ///             cleanup();
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `neverReturningFunctions`
+    ///
+    /// The functions that never return to their caller, such as the functions that stop the process.
+    /// The code after a call to one of these functions is unreachable.
+    /// The functions are matched by their name, or by their path for the methods of an object, such as `process.exit`.
+    /// They are matched whether the name is bound to a declaration of the module or not.
+    ///
+    /// Default: `[]`
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "neverReturningFunctions": ["process.exit", "fail"]
+    ///     }
+    /// }
+    /// ```
+    ///
     pub NoUnreachable {
         version: "1.0.0",
         name: "noUnreachable",
@@ -56,21 +95,31 @@ declare_lint_rule! {
     }
 }
 
+/// Options for the rule `noUnreachable`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUnreachableOptions {
+    /// The functions that never return to their caller, such as `process.exit`.
+    pub never_returning_functions: Vec<String>,
+}
+
 impl Rule for NoUnreachable {
     type Query = ControlFlowGraph;
     type State = UnreachableRange;
     type Signals = UnreachableRanges;
-    type Options = ();
+    type Options = NoUnreachableOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let mut signals = UnreachableRanges::new();
 
         let cfg = ctx.query();
+        let never_returning_functions = &ctx.options().never_returning_functions;
 
         if exceeds_complexity_threshold(cfg) {
-            analyze_simple(cfg, &mut signals)
+            analyze_simple(cfg, never_returning_functions, &mut signals)
         } else {
-            analyze_fine(cfg, &mut signals)
+            analyze_fine(cfg, never_returning_functions, &mut signals)
         }
 
         signals
@@ -259,7 +308,11 @@ fn exceeds_complexity_threshold(cfg: &JsControlFlowGraph) -> bool {
 /// Perform a simple reachability analysis, does not attempt to determine a
 /// terminator instruction for unreachable ranges allowing blocks to be visited
 /// at most once and ensuring the algorithm finishes in a bounded time
-fn analyze_simple(cfg: &JsControlFlowGraph, signals: &mut UnreachableRanges) {
+fn analyze_simple(
+    cfg: &JsControlFlowGraph,
+    never_returning_functions: &[String],
+    signals: &mut UnreachableRanges,
+) {
     // Perform a simple reachability analysis on the control flow graph by
     // traversing the function starting at the entry point
     let mut reachable_blocks = RoaringBitmap::new();
@@ -304,12 +357,30 @@ fn analyze_simple(cfg: &JsControlFlowGraph, signals: &mut UnreachableRanges) {
             }
 
             match inst.kind {
-                InstructionKind::Statement => {}
+                InstructionKind::Statement => {
+                    if is_never_returning_call(inst, never_returning_functions) {
+                        has_terminator = true;
+                    }
+                }
                 InstructionKind::Jump {
                     conditional,
-                    block,
+                    block: jump_block,
                     finally_fallthrough,
+                    finally_handlers,
                 } => {
+                    // A `break` or `continue` statement leaving `try`
+                    // statements executes their finally clauses first
+                    let finally_handlers = block
+                        .cleanup_handlers
+                        .get(..finally_handlers as usize)
+                        .and_then(<[_]>::split_first);
+
+                    if let Some((handler, handlers)) = finally_handlers {
+                        if reachable_blocks.insert(handler.target.index()) {
+                            queue.push_back((handler.target, Some(handlers)));
+                        }
+                    }
+
                     if finally_fallthrough && handlers.is_some() {
                         // Jump towards the corresponding block if there are pending exception
                         // handlers, otherwise return from the function
@@ -322,9 +393,9 @@ fn analyze_simple(cfg: &JsControlFlowGraph, signals: &mut UnreachableRanges) {
                         }
                     }
 
-                    if reachable_blocks.insert(block.index()) {
+                    if reachable_blocks.insert(jump_block.index()) {
                         // Insert an edge if this jump is reachable
-                        queue.push_back((block, handlers));
+                        queue.push_back((jump_block, handlers));
                     }
 
                     // Jump is a terminator instruction if it's unconditional
@@ -365,9 +436,13 @@ fn analyze_simple(cfg: &JsControlFlowGraph, signals: &mut UnreachableRanges) {
 /// the reachability of each block and instruction but also find one or more
 /// "terminator instructions" for each unreachable range of code that cause it
 /// to be impossible to reach
-fn analyze_fine(cfg: &JsControlFlowGraph, signals: &mut UnreachableRanges) {
+fn analyze_fine(
+    cfg: &JsControlFlowGraph,
+    never_returning_functions: &[String],
+    signals: &mut UnreachableRanges,
+) {
     // Traverse the CFG and calculate block / instruction reachability
-    let block_paths = traverse_cfg(cfg, signals);
+    let block_paths = traverse_cfg(cfg, never_returning_functions, signals);
 
     // Detect unreachable blocks using the result of the above traversal
     'blocks: for (block_id, block) in cfg.block_id_iter() {
@@ -425,12 +500,17 @@ struct PathState<'cfg> {
     /// encountered
     terminator: Option<Option<PathTerminator>>,
     exception_handlers: Option<&'cfg [ExceptionHandler]>,
+    /// Target of the `break` or `continue` statement whose finally clauses
+    /// are executed by this path: the control flow resumes there once all the
+    /// `exception_handlers` have been executed
+    resume_block: Option<BlockId>,
 }
 
 /// Perform a simple reachability analysis on the control flow graph by
 /// traversing the function starting at the entry points
 fn traverse_cfg(
     cfg: &JsControlFlowGraph,
+    never_returning_functions: &[String],
     signals: &mut UnreachableRanges,
 ) -> FxHashMap<BlockId, Vec<Option<Option<PathTerminator>>>> {
     let mut queue = VecDeque::new();
@@ -440,6 +520,7 @@ fn traverse_cfg(
         visited: RoaringBitmap::new(),
         terminator: None,
         exception_handlers: None,
+        resume_block: None,
     });
 
     // This maps holds a list of "path state", the active terminator
@@ -478,6 +559,7 @@ fn traverse_cfg(
                             visited: path.visited.clone(),
                             terminator: path.terminator,
                             exception_handlers: find_catch_handlers(handlers),
+                            resume_block: None,
                         });
                     }
                 }
@@ -491,13 +573,31 @@ fn traverse_cfg(
             }
 
             match inst.kind {
-                InstructionKind::Statement => {}
+                InstructionKind::Statement => {
+                    if path.terminator.is_none()
+                        && is_never_returning_call(inst, never_returning_functions)
+                    {
+                        path.terminator = Some(inst.node.as_ref().map(|node| PathTerminator {
+                            kind: node.kind(),
+                            range: node.text_trimmed_range(),
+                        }));
+                        has_direct_terminator = true;
+                    }
+                }
                 InstructionKind::Jump {
                     conditional,
-                    block,
+                    block: jump_block,
                     finally_fallthrough,
+                    finally_handlers,
                 } => {
-                    handle_jump(&mut queue, &path, block, finally_fallthrough);
+                    handle_jump(
+                        &mut queue,
+                        &path,
+                        &block.cleanup_handlers,
+                        jump_block,
+                        finally_fallthrough,
+                        finally_handlers,
+                    );
 
                     // Jump is a terminator instruction if it's unconditional
                     if path.terminator.is_none() && !conditional {
@@ -546,6 +646,64 @@ fn has_side_effects(inst: &Instruction<JsLanguage>) -> bool {
     }
 }
 
+/// Returns `true` if `inst` calls one of the `never_returning_functions`, such
+/// as `process.exit()`
+fn is_never_returning_call(
+    inst: &Instruction<JsLanguage>,
+    never_returning_functions: &[String],
+) -> bool {
+    let Some(node) = inst.node.as_ref().and_then(|node| node.as_node()) else {
+        return false;
+    };
+
+    // The statement instructions are emitted for the expression statements and
+    // the initializers of the variable declarations
+    let expression = match JsExpressionStatement::cast_ref(node) {
+        Some(statement) => statement.expression().ok(),
+        None => AnyJsExpression::cast_ref(node),
+    };
+    let Some(AnyJsExpression::JsCallExpression(call)) =
+        expression.map(|expression| expression.omit_parentheses())
+    else {
+        return false;
+    };
+
+    if call.is_optional_chain() {
+        return false;
+    }
+
+    call.callee().is_ok_and(|callee| {
+        never_returning_functions
+            .iter()
+            .any(|name| is_function_name(&callee, name))
+    })
+}
+
+/// Returns `true` if `expression` references the function `name`, which is
+/// either an identifier or a path such as `process.exit`
+fn is_function_name(expression: &AnyJsExpression, name: &str) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .is_ok_and(|identifier| identifier.has_name(name)),
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let Some((object_name, member_name)) = name.rsplit_once('.') else {
+                return false;
+            };
+
+            member
+                .member()
+                .ok()
+                .and_then(|member| member.as_js_name()?.value_token().ok())
+                .is_some_and(|member| member.text_trimmed() == member_name)
+                && member
+                    .object()
+                    .is_ok_and(|object| is_function_name(&object, object_name))
+        }
+        _ => false,
+    }
+}
+
 /// Returns the list of all `finally` exception handlers up to and including
 /// the first `catch` handler to be executed when an exception is thrown
 fn find_catch_handlers(handlers: &[ExceptionHandler]) -> Option<&[ExceptionHandler]> {
@@ -565,14 +723,23 @@ fn find_catch_handlers(handlers: &[ExceptionHandler]) -> Option<&[ExceptionHandl
 fn handle_jump<'cfg>(
     queue: &mut VecDeque<PathState<'cfg>>,
     path: &PathState<'cfg>,
+    cleanup_handlers: &'cfg [ExceptionHandler],
     block: BlockId,
     finally_fallthrough: bool,
+    finally_handlers: u32,
 ) {
+    // The finally clauses of the `try` statements left by a `break` or
+    // `continue` statement, executed before jumping to its target
+    let finally_handlers = cleanup_handlers
+        .get(..finally_handlers as usize)
+        .and_then(<[_]>::split_first);
+
     // If this jump is exiting a finally clause and this path is visiting
     // an exception handlers chain
     if finally_fallthrough && path.exception_handlers.is_some() {
         // Jump towards the corresponding block if there are pending exception
-        // handlers, otherwise return from the function
+        // handlers, otherwise resume at the target of the `break` or
+        // `continue` statement, or return from the function
         let handlers = path.exception_handlers.and_then(<[_]>::split_first);
 
         if let Some((handler, handlers)) = handlers {
@@ -582,9 +749,30 @@ fn handle_jump<'cfg>(
                     visited: path.visited.clone(),
                     terminator: path.terminator,
                     exception_handlers: Some(handlers),
+                    resume_block: path.resume_block,
+                });
+            }
+        } else if let Some(resume_block) = path.resume_block {
+            if !path.visited.contains(resume_block.index()) {
+                queue.push_back(PathState {
+                    next_block: resume_block,
+                    visited: path.visited.clone(),
+                    terminator: path.terminator,
+                    exception_handlers: None,
+                    resume_block: None,
                 });
             }
         }
+    } else if let Some((handler, handlers)) = finally_handlers {
+        if !path.visited.contains(handler.target.index()) {
+            queue.push_back(PathState {
+                next_block: handler.target,
+                visited: path.visited.clone(),
+                terminator: path.terminator,
+                exception_handlers: Some(handlers),
+                resume_block: Some(block),
+            });
+        }
     } else if !path.visited.contains(block.index()) {
         // Push the jump target block to the queue if it hasn't
         // been visited yet in this path
@@ -593,6 +781,7 @@ fn handle_jump<'cfg>(
             visited: path.visited.clone(),
             terminator: path.terminator,
             exception_handlers: path.exception_handlers,
+            resume_block: path.resume_block,
         });
     }
 }
@@ -611,6 +800,7 @@ fn handle_return<'cfg>(
                 visited: path.visited.clone(),
                 terminator: path.terminator,
                 exception_handlers: Some(handlers),
+                resume_block: None,
             });
         }
     }
@@ -30,6 +30,9 @@ declare_lint_rule! {
     /// If you want to report unused imports,
     /// enable [noUnusedImports](https://biomejs.dev/linter/rules/no-unused-imports/).
     ///
+    /// A top-level variable isn't reported if its name is listed in a `biome-globals` comment directive,
+    /// because the file declares it as a global that is used by other files.
+    ///
     /// From `v1.9.0`, the rule won't check unused function parameters any more.
     /// If you want to report unused function parameters,
     /// enable [noUnusedFunctionParameters](https://biomejs.dev/linter/rules/no-unused-function-parameters/).
@@ -317,6 +320,13 @@ impl Rule for NoUnusedVariables {
             return None;
         }
 
+        // A top-level binding listed in a `biome-globals` directive is a global that the file defines for other files.
+        if model.is_directive_global(binding.name_token().ok()?.text_trimmed())
+            && model.scope(binding.syntax()) == model.global_scope()
+        {
+            return None;
+        }
+
         // We need to check if all uses of this binding are somehow recursive or unused
         let declaration = binding.declaration()?;
         let declaration = declaration.syntax();
@@ -6,15 +6,22 @@ use biome_console::markup;
 use biome_js_semantic::SemanticModel;
 use biome_js_syntax::{
     AnyJsExpression, AnyJsFunctionBody, AnyJsMemberExpression, AnyJsObjectMember, AnyJsxAttribute,
-    AnyJsxChild, JsArrayExpression, JsCallExpression, JsFunctionBody, JsObjectExpression,
-    JsxAttributeList, JsxExpressionChild, JsxTagExpression,
+    AnyJsxChild, JsArrayExpression, JsCallExpression, JsFunctionBody, JsFunctionDeclaration,
+    JsIdentifierExpression, JsObjectExpression, JsVariableDeclarator, JsxAttributeList,
+    JsxExpressionChild, JsxTagExpression,
 };
 use biome_rowan::{declare_node_union, AstNode, AstNodeList, AstSeparatedList, TextRange};
+use rustc_hash::FxHashSet;
 
 declare_lint_rule! {
     /// Disallow missing key props in iterators/collection literals.
     ///
     /// Warn if an element that likely requires a key prop--namely, one present in an array literal or an arrow function expression.
+    ///
+    /// The elements are followed through the local variables that hold them,
+    /// and the callbacks of the iterators can be referenced by name,
+    /// as long as the variables and the functions are declared in the same file.
+    ///
     /// Check out React documentation for [explanation on the why does React need keys.](https://react.dev/learn/rendering-lists#why-does-react-need-keys)
     ///
     /// ## Examples
@@ -27,6 +34,10 @@ declare_lint_rule! {
     /// ```jsx,expect_diagnostic
     /// data.map((x) => <Hello>{x}</Hello>);
     /// ```
+    /// ```jsx,expect_diagnostic
+    /// const renderItem = (x) => <Hello>{x}</Hello>;
+    /// data.map(renderItem);
+    /// ```
     ///
     /// ### Valid
     ///
@@ -63,12 +74,16 @@ impl Rule for UseJsxKeyInIterable {
         let node = ctx.query();
         let model = ctx.model();
 
-        match node {
+        let mut ranges = match node {
             UseJsxKeyInIterableQuery::JsArrayExpression(node) => handle_collections(node, model),
             UseJsxKeyInIterableQuery::JsCallExpression(node) => {
                 handle_iterators(node, model).unwrap_or_default()
             }
-        }
+        };
+        // An element can be reached through several variables
+        let mut seen = FxHashSet::default();
+        ranges.retain(|range| seen.insert(*range));
+        ranges
     }
 
     fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
@@ -158,7 +173,43 @@ fn handle_iterators(node: &JsCallExpression, model: &SemanticModel) -> Option<Ve
         .as_any_js_expression()?;
 
     let is_inside_jsx = node.parent::<JsxExpressionChild>().is_some();
+    handle_callback(callback_argument, model, is_inside_jsx)
+}
+
+/// Handle the callback of an iterator, written inline or referenced by name
+///
+/// Examples
+///
+/// ```jsx
+/// function renderItem(x) {
+///     return <h1>{x}</h1>;
+/// }
+/// data.map(renderItem)
+/// ```
+fn handle_callback(
+    callback_argument: &AnyJsExpression,
+    model: &SemanticModel,
+    is_inside_jsx: bool,
+) -> Option<Vec<TextRange>> {
     match callback_argument {
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let binding = model.binding(&identifier.name().ok()?)?;
+            if let Some(declaration) = binding.tree().parent::<JsFunctionDeclaration>() {
+                let body = declaration.body().ok()?;
+                return Some(handle_function_body(&body, model, is_inside_jsx));
+            }
+            if binding.all_writes().next().is_some() {
+                return None;
+            }
+            let declarator = binding.tree().parent::<JsVariableDeclarator>()?;
+            let initializer = declarator.initializer()?.expression().ok()?;
+            let initializer = unwrap_parenthesis(initializer)?;
+            if matches!(initializer, AnyJsExpression::JsIdentifierExpression(_)) {
+                // Don't follow the aliases of the functions
+                return None;
+            }
+            handle_callback(&initializer, model, is_inside_jsx)
+        }
         AnyJsExpression::JsFunctionExpression(callback) => {
             let body = callback.body().ok()?;
             Some(handle_function_body(&body, model, is_inside_jsx))
@@ -242,6 +293,11 @@ fn handle_potential_react_component(
 ) -> Option<Vec<TextRange>> {
     let node = unwrap_parenthesis(node)?;
 
+    if let AnyJsExpression::JsIdentifierExpression(node) = node {
+        let value = resolve_local_value(&node, model)?;
+        return handle_potential_react_component(value, model, is_inside_jsx);
+    }
+
     if let AnyJsExpression::JsConditionalExpression(node) = node {
         let consequent =
             handle_potential_react_component(node.consequent().ok()?, model, is_inside_jsx);
@@ -322,9 +378,19 @@ fn handle_jsx_child(node: &AnyJsxChild, model: &SemanticModel) -> Option<Vec<Tex
                 let has_any_tags = node.children().iter().any(|child| match &child {
                     AnyJsxChild::JsxElement(_) | AnyJsxChild::JsxSelfClosingElement(_) => true,
                     // HACK: don't flag the entire fragment if there's a conditional expression
-                    AnyJsxChild::JsxExpressionChild(node) => node
-                        .expression()
-                        .map_or(false, |n| n.as_js_conditional_expression().is_some()),
+                    AnyJsxChild::JsxExpressionChild(node) => {
+                        node.expression().map_or(false, |n| match n {
+                            AnyJsExpression::JsConditionalExpression(_) => true,
+                            AnyJsExpression::JsIdentifierExpression(n) => {
+                                resolve_local_value(&n, model)
+                                    .and_then(unwrap_parenthesis)
+                                    .is_some_and(|value| {
+                                        JsxTagExpression::can_cast(value.syntax().kind())
+                                    })
+                            }
+                            _ => false,
+                        })
+                    }
                     _ => false,
                 });
 
@@ -414,6 +480,26 @@ fn has_key_prop(props: &JsObjectExpression) -> bool {
     })
 }
 
+/// Returns the initializer of the local variable referenced by `node`.
+///
+/// Returns `None` if the variable is reassigned, or if it's declared after the reference,
+/// so that the variables that reference each other aren't followed endlessly.
+fn resolve_local_value(
+    node: &JsIdentifierExpression,
+    model: &SemanticModel,
+) -> Option<AnyJsExpression> {
+    let reference = node.name().ok()?;
+    let binding = model.binding(&reference)?;
+    if binding.all_writes().next().is_some() {
+        return None;
+    }
+    let declarator = binding.tree().parent::<JsVariableDeclarator>()?;
+    if declarator.range().end() > reference.range().start() {
+        return None;
+    }
+    declarator.initializer()?.expression().ok()
+}
+
 // unwrap parenthesized expression
 fn unwrap_parenthesis(expr: AnyJsExpression) -> Option<AnyJsExpression> {
     let mut inner_expr = expr;
@@ -2,87 +2,169 @@
 
 use biome_analyze::declare_lint_group;
 
+pub mod no_await_in_loop;
+pub mod no_circular_domain_dependencies;
 pub mod no_console;
 pub mod no_done_callback;
+pub mod no_double_assertion;
 pub mod no_duplicate_else_if;
+pub mod no_duplicate_imports_across_files;
 pub mod no_dynamic_namespace_import_access;
+pub mod no_empty_alternatives;
 pub mod no_evolving_types;
 pub mod no_exported_imports;
+pub mod no_implicit_spread_props;
 pub mod no_irregular_whitespace;
+pub mod no_jsx_literals_in_attributes_needing_ids;
 pub mod no_label_without_control;
+pub mod no_leaked_event_listeners;
 pub mod no_misplaced_assertion;
+pub mod no_mutating_props_or_state;
+pub mod no_non_null_asserted_optional_chain;
+pub mod no_proto;
 pub mod no_react_specific_props;
 pub mod no_restricted_imports;
+pub mod no_side_effect_imports_in_libraries;
 pub mod no_static_element_interactions;
 pub mod no_substr;
+pub mod no_superlinear_backtracking;
+pub mod no_swallowed_errors;
+pub mod no_translation_literals;
 pub mod no_undeclared_dependencies;
+pub mod no_unhandled_rejections;
+pub mod no_unknown_attribute;
+pub mod no_unnecessary_non_null_assertion;
+pub mod no_untracked_props_access;
+pub mod no_untracked_signal_calls;
 pub mod no_unused_function_parameters;
+pub mod no_unvalidated_env_access;
 pub mod no_useless_escape_in_regex;
 pub mod no_useless_string_concat;
 pub mod no_useless_undefined_initialization;
+pub mod no_vue_duplicate_keys;
+pub mod no_vue_reserved_props;
+pub mod no_vue_side_effects_in_computed;
 pub mod no_yoda_expression;
 pub mod use_adjacent_overload_signatures;
+pub mod use_at_index;
 pub mod use_consistent_builtin_instantiation;
 pub mod use_consistent_curly_braces;
+pub mod use_consistent_generic_constructors;
+pub mod use_consistent_object_definitions;
+pub mod use_consistent_record_type;
+pub mod use_consistent_response_handling;
 pub mod use_date_now;
 pub mod use_default_switch_clause;
+pub mod use_error_cause;
 pub mod use_error_message;
+pub mod use_exhaustive_switch_cases;
 pub mod use_explicit_length_check;
 pub mod use_focusable_interactive;
+pub mod use_guard_for_in;
 pub mod use_import_extensions;
 pub mod use_import_restrictions;
+pub mod use_includes;
+pub mod use_js_doc_params_match;
 pub mod use_number_to_fixed_digits_argument;
+pub mod use_numeric_separators;
+pub mod use_object_spread;
+pub mod use_optimized_regex;
+pub mod use_preact_signals_correctly;
 pub mod use_semantic_elements;
 pub mod use_sorted_classes;
+pub mod use_sorted_object_keys;
+pub mod use_spread;
 pub mod use_strict_mode;
 pub mod use_throw_new_error;
 pub mod use_throw_only_error;
 pub mod use_top_level_regex;
 pub mod use_trim_start_end;
 pub mod use_valid_autocomplete;
+pub mod use_valid_i18n_keys;
 
 declare_lint_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_await_in_loop :: NoAwaitInLoop ,
+            self :: no_circular_domain_dependencies :: NoCircularDomainDependencies ,
             self :: no_console :: NoConsole ,
             self :: no_done_callback :: NoDoneCallback ,
+            self :: no_double_assertion :: NoDoubleAssertion ,
             self :: no_duplicate_else_if :: NoDuplicateElseIf ,
+            self :: no_duplicate_imports_across_files :: NoDuplicateImportsAcrossFiles ,
             self :: no_dynamic_namespace_import_access :: NoDynamicNamespaceImportAccess ,
+            self :: no_empty_alternatives :: NoEmptyAlternatives ,
             self :: no_evolving_types :: NoEvolvingTypes ,
             self :: no_exported_imports :: NoExportedImports ,
+            self :: no_implicit_spread_props :: NoImplicitSpreadProps ,
             self :: no_irregular_whitespace :: NoIrregularWhitespace ,
+            self :: no_jsx_literals_in_attributes_needing_ids :: NoJsxLiteralsInAttributesNeedingIds ,
             self :: no_label_without_control :: NoLabelWithoutControl ,
+            self :: no_leaked_event_listeners :: NoLeakedEventListeners ,
             self :: no_misplaced_assertion :: NoMisplacedAssertion ,
+            self :: no_mutating_props_or_state :: NoMutatingPropsOrState ,
+            self :: no_non_null_asserted_optional_chain :: NoNonNullAssertedOptionalChain ,
+            self :: no_proto :: NoProto ,
             self :: no_react_specific_props :: NoReactSpecificProps ,
             self :: no_restricted_imports :: NoRestrictedImports ,
+            self :: no_side_effect_imports_in_libraries :: NoSideEffectImportsInLibraries ,
             self :: no_static_element_interactions :: NoStaticElementInteractions ,
             self :: no_substr :: NoSubstr ,
+            self :: no_superlinear_backtracking :: NoSuperlinearBacktracking ,
+            self :: no_swallowed_errors :: NoSwallowedErrors ,
+            self :: no_translation_literals :: NoTranslationLiterals ,
             self :: no_undeclared_dependencies :: NoUndeclaredDependencies ,
+            self :: no_unhandled_rejections :: NoUnhandledRejections ,
+            self :: no_unknown_attribute :: NoUnknownAttribute ,
+            self :: no_unnecessary_non_null_assertion :: NoUnnecessaryNonNullAssertion ,
+            self :: no_untracked_props_access :: NoUntrackedPropsAccess ,
+            self :: no_untracked_signal_calls :: NoUntrackedSignalCalls ,
             self :: no_unused_function_parameters :: NoUnusedFunctionParameters ,
+            self :: no_unvalidated_env_access :: NoUnvalidatedEnvAccess ,
             self :: no_useless_escape_in_regex :: NoUselessEscapeInRegex ,
             self :: no_useless_string_concat :: NoUselessStringConcat ,
             self :: no_useless_undefined_initialization :: NoUselessUndefinedInitialization ,
+            self :: no_vue_duplicate_keys :: NoVueDuplicateKeys ,
+            self :: no_vue_reserved_props :: NoVueReservedProps ,
+            self :: no_vue_side_effects_in_computed :: NoVueSideEffectsInComputed ,
             self :: no_yoda_expression :: NoYodaExpression ,
             self :: use_adjacent_overload_signatures :: UseAdjacentOverloadSignatures ,
+            self :: use_at_index :: UseAtIndex ,
             self :: use_consistent_builtin_instantiation :: UseConsistentBuiltinInstantiation ,
             self :: use_consistent_curly_braces :: UseConsistentCurlyBraces ,
+            self :: use_consistent_generic_constructors :: UseConsistentGenericConstructors ,
+            self :: use_consistent_object_definitions :: UseConsistentObjectDefinitions ,
+            self :: use_consistent_record_type :: UseConsistentRecordType ,
+            self :: use_consistent_response_handling :: UseConsistentResponseHandling ,
             self :: use_date_now :: UseDateNow ,
             self :: use_default_switch_clause :: UseDefaultSwitchClause ,
+            self :: use_error_cause :: UseErrorCause ,
             self :: use_error_message :: UseErrorMessage ,
+            self :: use_exhaustive_switch_cases :: UseExhaustiveSwitchCases ,
             self :: use_explicit_length_check :: UseExplicitLengthCheck ,
             self :: use_focusable_interactive :: UseFocusableInteractive ,
+            self :: use_guard_for_in :: UseGuardForIn ,
             self :: use_import_extensions :: UseImportExtensions ,
             self :: use_import_restrictions :: UseImportRestrictions ,
+            self :: use_includes :: UseIncludes ,
+            self :: use_js_doc_params_match :: UseJsDocParamsMatch ,
             self :: use_number_to_fixed_digits_argument :: UseNumberToFixedDigitsArgument ,
+            self :: use_numeric_separators :: UseNumericSeparators ,
+            self :: use_object_spread :: UseObjectSpread ,
+            self :: use_optimized_regex :: UseOptimizedRegex ,
+            self :: use_preact_signals_correctly :: UsePreactSignalsCorrectly ,
             self :: use_semantic_elements :: UseSemanticElements ,
             self :: use_sorted_classes :: UseSortedClasses ,
+            self :: use_sorted_object_keys :: UseSortedObjectKeys ,
+            self :: use_spread :: UseSpread ,
             self :: use_strict_mode :: UseStrictMode ,
             self :: use_throw_new_error :: UseThrowNewError ,
             self :: use_throw_only_error :: UseThrowOnlyError ,
             self :: use_top_level_regex :: UseTopLevelRegex ,
             self :: use_trim_start_end :: UseTrimStartEnd ,
             self :: use_valid_autocomplete :: UseValidAutocomplete ,
+            self :: use_valid_i18n_keys :: UseValidI18nKeys ,
         ]
      }
 }
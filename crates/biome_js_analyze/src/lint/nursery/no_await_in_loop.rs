@@ -0,0 +1,390 @@
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsAssignment, AnyJsCallArgument, AnyJsExpression,
+    AnyJsForInOrOfInitializer, AnyJsFormalParameter, AnyJsFunctionBody, AnyJsMemberExpression,
+    AnyJsParameter, AnyJsStatement, JsAwaitExpression, JsCallExpression, JsForOfStatement,
+    JsForStatement, JsIdentifierAssignment, JsSyntaxKind, JsSyntaxNode, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, BatchMutationExt, TextRange, TriviaPieceKind, WalkEvent,
+};
+
+declare_lint_rule! {
+    /// Disallow `await` inside loops.
+    ///
+    /// An `await` inside a loop waits for each iteration to complete before starting the next one,
+    /// so the asynchronous operations run one after the other.
+    /// When they are independent, starting all of them and awaiting them together with `Promise.all()` is faster.
+    ///
+    /// The rule reports the `await` expressions and the `for await` loops in the body and in the condition of a loop.
+    /// The `await` of a function nested in the loop, and the `await` in the body of a `for await` loop, are ignored.
+    /// Sometimes the iterations depend on each other, for example when retrying an operation or when paginating results,
+    /// then the diagnostic can be suppressed.
+    ///
+    /// The code action rewrites a `for...of` loop to `await Promise.all(items.map(async (item) => { ... }))`.
+    /// It's only provided when, according to simple heuristics, the order of the iterations doesn't matter:
+    /// the body doesn't contain `break`, `continue`, `return`, or `yield`,
+    /// and doesn't assign or mutate with methods like `push` the variables declared outside of the loop.
+    /// The action is unsafe, because the operations of the iterations can still depend on each other,
+    /// and because only arrays have a `map` method.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function fetchAll(urls) {
+    ///     for (const url of urls) {
+    ///         await fetch(url);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function poll() {
+    ///     while (!(await isReady())) {}
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function fetchAll(urls) {
+    ///     await Promise.all(urls.map((url) => fetch(url)));
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// async function readAll(stream) {
+    ///     for await (const chunk of stream) {
+    ///         await write(chunk);
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoAwaitInLoop {
+        version: "next",
+        name: "noAwaitInLoop",
+        language: "js",
+        sources: &[RuleSource::Eslint("no-await-in-loop")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsAwait = JsAwaitExpression | JsForOfStatement
+}
+
+impl Rule for NoAwaitInLoop {
+    type Query = Semantic<AnyJsAwait>;
+    /// The loop that contains the `await`
+    type State = JsSyntaxNode;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if let AnyJsAwait::JsForOfStatement(for_of) = node {
+            for_of.await_token()?;
+        }
+        let mut child = node.syntax().clone();
+        for parent in node.syntax().ancestors().skip(1) {
+            let is_looped = match parent.kind() {
+                kind if is_function(kind) => return None,
+                JsSyntaxKind::JS_FOR_OF_STATEMENT => {
+                    let for_of = JsForOfStatement::unwrap_cast(parent.clone());
+                    // The iterations of a `for await` loop are awaited on purpose
+                    if for_of.await_token().is_some() {
+                        return None;
+                    }
+                    for_of.body().is_ok_and(|body| body.syntax() == &child)
+                }
+                JsSyntaxKind::JS_FOR_IN_STATEMENT => {
+                    parent.last_child().is_some_and(|body| body == child)
+                }
+                // The initializer runs once
+                JsSyntaxKind::JS_FOR_STATEMENT => JsForStatement::unwrap_cast(parent.clone())
+                    .initializer()
+                    .map_or(true, |initializer| initializer.syntax() != &child),
+                JsSyntaxKind::JS_WHILE_STATEMENT | JsSyntaxKind::JS_DO_WHILE_STATEMENT => true,
+                _ => false,
+            };
+            if is_looped {
+                return Some(parent);
+            }
+            child = parent;
+        }
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let range = match ctx.query() {
+            AnyJsAwait::JsAwaitExpression(expression) => expression.range(),
+            AnyJsAwait::JsForOfStatement(for_of) => TextRange::new(
+                for_of.for_token().ok()?.text_trimmed_range().start(),
+                for_of.await_token()?.text_trimmed_range().end(),
+            ),
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Avoid "<Emphasis>"await"</Emphasis>" inside a loop."
+                },
+            )
+            .note(markup! {
+                "Each iteration waits for the previous one to complete, so the asynchronous operations run one after the other."
+            })
+            .note(markup! {
+                "If the iterations are independent, start all the operations and await them together with "<Emphasis>"Promise.all()"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, loop_statement: &Self::State) -> Option<JsRuleAction> {
+        let for_of = JsForOfStatement::cast_ref(loop_statement)?;
+        let AnyJsForInOrOfInitializer::JsForVariableDeclaration(declaration) =
+            for_of.initializer().ok()?
+        else {
+            return None;
+        };
+        if declaration.kind_token().ok()?.kind() == T![var]
+            || !is_parallelizable(&for_of, ctx.model())
+        {
+            return None;
+        }
+        let binding = declaration.declarator().ok()?.id().ok()?;
+        let body = match for_of.body().ok()? {
+            AnyJsStatement::JsBlockStatement(block) => make::js_function_body(
+                block.l_curly_token().ok()?,
+                make::js_directive_list([]),
+                block.statements(),
+                block.r_curly_token().ok()?,
+            ),
+            statement => make::js_function_body(
+                make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_directive_list([]),
+                make::js_statement_list([statement.trim_trivia()?]),
+                make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            ),
+        };
+        let parameters = make::js_parameters(
+            make::token(T!['(']),
+            make::js_parameter_list(
+                [AnyJsParameter::AnyJsFormalParameter(
+                    AnyJsFormalParameter::JsFormalParameter(
+                        make::js_formal_parameter(
+                            make::js_decorator_list([]),
+                            binding.trim_trivia()?,
+                        )
+                        .build(),
+                    ),
+                )],
+                [],
+            ),
+            make::token(T![')']),
+        );
+        let callback = make::js_arrow_function_expression(
+            AnyJsArrowFunctionParameters::from(parameters),
+            make::token_decorated_with_space(T![=>]),
+            AnyJsFunctionBody::from(body),
+        )
+        .with_async_token(
+            make::token(T![async]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        )
+        .build();
+        let iterable = match for_of.expression().ok()?.trim_trivia()? {
+            iterable @ (AnyJsExpression::JsIdentifierExpression(_)
+            | AnyJsExpression::JsStaticMemberExpression(_)
+            | AnyJsExpression::JsComputedMemberExpression(_)
+            | AnyJsExpression::JsCallExpression(_)
+            | AnyJsExpression::JsArrayExpression(_)
+            | AnyJsExpression::JsParenthesizedExpression(_)) => iterable,
+            iterable => make::parenthesized(iterable).into(),
+        };
+        let promises = make::js_call_expression(
+            make::js_static_member_expression(
+                iterable,
+                make::token(T![.]),
+                make::js_name(make::ident("map")).into(),
+            )
+            .into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(
+                    [AnyJsCallArgument::AnyJsExpression(callback.into())],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let promise_all = make::js_call_expression(
+            make::js_static_member_expression(
+                make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                    "Promise",
+                )))
+                .into(),
+                make::token(T![.]),
+                make::js_name(make::ident("all")).into(),
+            )
+            .into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(
+                    [AnyJsCallArgument::AnyJsExpression(promises.into())],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let statement = make::js_expression_statement(
+            make::js_await_expression(
+                make::token(T![await]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                promise_all.into(),
+            )
+            .into(),
+        )
+        .with_semicolon_token(make::token(T![;]))
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsStatement::from(for_of),
+            AnyJsStatement::from(statement),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Run the iterations in parallel with "<Emphasis>"Promise.all()"</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// The methods that change the order of the elements of a collection.
+const MUTATING_METHODS: [&str; 9] = [
+    "add", "pop", "push", "reverse", "set", "shift", "sort", "splice", "unshift",
+];
+
+/// Returns `true` if the iterations of `for_of` seem independent of their order.
+fn is_parallelizable(for_of: &JsForOfStatement, model: &SemanticModel) -> bool {
+    let Ok(body) = for_of.body() else {
+        return false;
+    };
+    let loop_range = for_of.range();
+    let is_local = |object: Option<AnyJsExpression>| {
+        let Some(AnyJsExpression::JsIdentifierExpression(identifier)) = object.map(root_object)
+        else {
+            return false;
+        };
+        identifier
+            .name()
+            .ok()
+            .and_then(|reference| model.binding(&reference))
+            .is_some_and(|binding| loop_range.contains_range(binding.syntax().text_trimmed_range()))
+    };
+    for event in body.syntax().preorder() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        match node.kind() {
+            JsSyntaxKind::JS_BREAK_STATEMENT
+            | JsSyntaxKind::JS_CONTINUE_STATEMENT
+            | JsSyntaxKind::JS_RETURN_STATEMENT
+            | JsSyntaxKind::JS_YIELD_EXPRESSION => {
+                // These statements of a nested function don't affect the loop
+                if !node
+                    .ancestors()
+                    .take_while(|ancestor| ancestor != body.syntax())
+                    .any(|ancestor| is_function(ancestor.kind()))
+                {
+                    return false;
+                }
+            }
+            JsSyntaxKind::JS_IDENTIFIER_ASSIGNMENT => {
+                let assignment = JsIdentifierAssignment::unwrap_cast(node);
+                if !model.binding(&assignment).is_some_and(|binding| {
+                    loop_range.contains_range(binding.syntax().text_trimmed_range())
+                }) {
+                    return false;
+                }
+            }
+            kind if AnyJsAssignment::can_cast(kind) => {
+                let assignment = AnyJsAssignment::unwrap_cast(node);
+                let object = match &assignment {
+                    AnyJsAssignment::JsStaticMemberAssignment(member) => member.object().ok(),
+                    AnyJsAssignment::JsComputedMemberAssignment(member) => member.object().ok(),
+                    _ => continue,
+                };
+                if !is_local(object) {
+                    return false;
+                }
+            }
+            JsSyntaxKind::JS_CALL_EXPRESSION => {
+                let call = JsCallExpression::unwrap_cast(node);
+                let Some(callee) = call
+                    .callee()
+                    .ok()
+                    .and_then(|callee| AnyJsMemberExpression::cast(callee.into_syntax()))
+                else {
+                    continue;
+                };
+                if callee
+                    .member_name()
+                    .is_some_and(|name| MUTATING_METHODS.contains(&name.text()))
+                    && !is_local(callee.object().ok())
+                {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+    true
+}
+
+/// Returns the object at the root of a chain of member accesses, such as `a` in `a.b[c].d`.
+fn root_object(mut expression: AnyJsExpression) -> AnyJsExpression {
+    loop {
+        expression = match expression.omit_parentheses() {
+            AnyJsExpression::JsStaticMemberExpression(member) => match member.object() {
+                Ok(object) => object,
+                Err(_) => return member.into(),
+            },
+            AnyJsExpression::JsComputedMemberExpression(member) => match member.object() {
+                Ok(object) => object,
+                Err(_) => return member.into(),
+            },
+            expression => return expression,
+        };
+    }
+}
+
+fn is_function(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+            | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+            | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+            | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+    )
+}
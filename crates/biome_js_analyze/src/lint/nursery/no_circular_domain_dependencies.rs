@@ -0,0 +1,263 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{AnyJsExportClause, AnyJsModuleItem, JsModule};
+use biome_rowan::{AstNode, TextRange};
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+use crate::services::module_exports::{
+    import_specifier, normalize_path, ProjectExports, ProjectModuleExports,
+};
+use crate::utils::glob::matches_path_suffix;
+
+declare_lint_rule! {
+    /// Disallow the imports that go against the direction of the layers of the architecture.
+    ///
+    /// In a layered architecture, such as `ui -> domain -> data`, a layer can depend on the layers
+    /// below it, but the layers below must not depend on the layers above them.
+    /// Otherwise, the layers depend on each other, and can't be changed, tested, or reused separately.
+    ///
+    /// The layers are configured with the `layers` option, from the top layer to the bottom layer.
+    /// A module of a layer can import the modules of its own layer and of the layers after it.
+    /// The rule reports the imports of the modules of the layers before it.
+    ///
+    /// The modules that don't belong to any layer, such as shared utilities, are transparent:
+    /// an import of such a module is reported when the module imports, directly or not,
+    /// a module of a layer that the importer can't depend on.
+    /// The diagnostic shows the path of the dependency.
+    ///
+    /// Only the relative specifiers, such as `./button`, are resolved,
+    /// and only the files processed by Biome are taken into account:
+    /// in an editor, these are the opened files.
+    ///
+    /// ## Examples
+    ///
+    /// With the options of the [Options](#options) section:
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,ignore
+    /// // src/domain/user.ts
+    /// import { Avatar } from "../ui/avatar";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts,ignore
+    /// // src/domain/user.ts
+    /// import { fetchUser } from "../data/users";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `layers`
+    ///
+    /// The layers of the architecture, from the top layer to the bottom layer.
+    /// Each layer has a name, and the globs of the paths of its modules.
+    /// The globs are matched against the end of the paths, so that `src/ui/**` matches the modules
+    /// of the `src/ui` directory wherever the project is.
+    /// A module belongs to the first layer that matches its path.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "layers": [
+    ///             { "name": "ui", "include": ["src/ui/**"] },
+    ///             { "name": "domain", "include": ["src/domain/**"] },
+    ///             { "name": "data", "include": ["src/data/**"] }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoCircularDomainDependencies {
+        version: "next",
+        name: "noCircularDomainDependencies",
+        language: "js",
+        recommended: false,
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoCircularDomainDependenciesOptions {
+    /// The layers of the architecture, from the top layer to the bottom layer.
+    /// A layer can only depend on the layers after it.
+    pub layers: Vec<Layer>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Layer {
+    /// The name of the layer, displayed in the diagnostics.
+    pub name: String,
+    /// The globs of the paths of the modules of the layer.
+    pub include: Vec<String>,
+}
+
+impl NoCircularDomainDependenciesOptions {
+    /// Returns the index of the layer of the module at `path`
+    fn layer_of(&self, path: &Path) -> Option<usize> {
+        self.layers.iter().position(|layer| {
+            layer
+                .include
+                .iter()
+                .any(|glob| matches_path_suffix(glob, path))
+        })
+    }
+}
+
+/// An import that makes a layer depend on a layer above it
+pub struct LayerViolation {
+    /// The range of the specifier of the import
+    range: TextRange,
+    /// The index of the layer that the import leads to
+    layer: usize,
+    /// The modules from the imported module to the module of the forbidden layer
+    path: Vec<PathBuf>,
+}
+
+impl Rule for NoCircularDomainDependencies {
+    type Query = ProjectExports<JsModule>;
+    type State = LayerViolation;
+    type Signals = Vec<Self::State>;
+    type Options = NoCircularDomainDependenciesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let module = ctx.query();
+        let options = ctx.options();
+        let file_path = normalize_path(ctx.file_path());
+        let Some(own_layer) = options.layer_of(&file_path) else {
+            return Vec::new();
+        };
+        let project = ctx.project();
+
+        let mut violations = Vec::new();
+        for item in module.items() {
+            let Some(specifier) = import_specifier(&item) else {
+                continue;
+            };
+            let Some(resolved) = project.resolve(&file_path, &specifier) else {
+                continue;
+            };
+            let Some((layer, path)) = forbidden_dependency(project, options, own_layer, resolved)
+            else {
+                continue;
+            };
+            let range = source_range(&item).unwrap_or_else(|| item.range());
+            violations.push(LayerViolation { range, layer, path });
+        }
+        violations
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let options = ctx.options();
+        let file_path = normalize_path(ctx.file_path());
+        let own_layer = &options.layers[options.layer_of(&file_path)?].name;
+        let layer = &options.layers.get(state.layer)?.name;
+        let order = options
+            .layers
+            .iter()
+            .map(|layer| layer.name.as_str())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        let directory = file_path.parent()?;
+        let dependency_path = std::iter::once(display_path(directory, &file_path))
+            .chain(state.path.iter().map(|path| display_path(directory, path)))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This import makes the layer "<Emphasis>{own_layer}</Emphasis>" depend on the layer "<Emphasis>{layer}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The layers are ordered as "<Emphasis>{order}</Emphasis>": a layer can only depend on the layers after it, otherwise the layers depend on each other."
+            })
+            .note(markup! {
+                "The dependency path is: "<Emphasis>{dependency_path}</Emphasis>
+            })
+            .note(markup! {
+                "Move the shared code to a lower layer, or invert the dependency."
+            }),
+        )
+    }
+}
+
+/// Returns the layer above `own_layer` that the module at `imported` leads to, with the path of the dependency.
+///
+/// The imports of the modules that belong to a layer aren't followed,
+/// because these modules are checked by themselves.
+fn forbidden_dependency(
+    project: &ProjectModuleExports,
+    options: &NoCircularDomainDependenciesOptions,
+    own_layer: usize,
+    imported: &Path,
+) -> Option<(usize, Vec<PathBuf>)> {
+    let mut visited = FxHashSet::default();
+    let mut queue = VecDeque::from([vec![imported.to_path_buf()]]);
+    // Breadth-first, so that the shortest path is reported
+    while let Some(path) = queue.pop_front() {
+        let module = path.last()?;
+        if !visited.insert(module.clone()) {
+            continue;
+        }
+        match options.layer_of(module) {
+            Some(layer) if layer < own_layer => return Some((layer, path)),
+            Some(_) => {}
+            None => {
+                for dependency in project.dependencies(module) {
+                    let mut next = path.clone();
+                    next.push(dependency.to_path_buf());
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns the range of the specifier of the import or re-export `item`
+fn source_range(item: &AnyJsModuleItem) -> Option<TextRange> {
+    match item {
+        AnyJsModuleItem::JsImport(import) => {
+            Some(import.import_clause().ok()?.source().ok()?.range())
+        }
+        AnyJsModuleItem::JsExport(export) => match export.export_clause().ok()? {
+            AnyJsExportClause::JsExportFromClause(clause) => Some(clause.source().ok()?.range()),
+            AnyJsExportClause::JsExportNamedFromClause(clause) => {
+                Some(clause.source().ok()?.range())
+            }
+            _ => None,
+        },
+        AnyJsModuleItem::AnyJsStatement(_) => None,
+    }
+}
+
+/// Returns `path` relative to `directory`, such as `../ui/button.ts`
+fn display_path(directory: &Path, path: &Path) -> String {
+    let mut base = directory.to_path_buf();
+    let mut prefix = PathBuf::new();
+    loop {
+        if let Ok(relative) = path.strip_prefix(&base) {
+            return if prefix.as_os_str().is_empty() {
+                relative.display().to_string()
+            } else {
+                prefix.join(relative).display().to_string()
+            };
+        }
+        if !base.pop() {
+            return path.display().to_string();
+        }
+        prefix.push("..");
+    }
+}
@@ -0,0 +1,142 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{AnyJsExpression, AnyTsType, TsAsExpression, TsTypeAssertionExpression};
+use biome_rowan::{declare_node_union, AstNode};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow asserting a value to a type through `unknown` or `any`.
+    ///
+    /// TypeScript rejects the assertions between unrelated types, such as `"text" as number`.
+    /// Asserting the value to `unknown` or `any` first, as in `"text" as unknown as number`, silences this check:
+    /// the code compiles, but the value may not have the asserted type at runtime.
+    ///
+    /// A type guard, or a fix of the type of the value, keeps the code checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// const user = response as unknown as User;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const user = response as any as User;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const user = <User><unknown>response;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// const user = response as User;
+    /// ```
+    ///
+    /// ```ts
+    /// const value = response as unknown;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `allowUnknown` to allow the double assertions through `unknown`,
+    /// and to only report the ones through `any`:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowUnknown": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoDoubleAssertion {
+        version: "next",
+        name: "noDoubleAssertion",
+        language: "ts",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noDoubleAssertion`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoDoubleAssertionOptions {
+    /// Allow the double assertions through `unknown`, such as `value as unknown as T`.
+    pub allow_unknown: bool,
+}
+
+declare_node_union! {
+    pub AnyTsTypeAssertion = TsAsExpression | TsTypeAssertionExpression
+}
+
+impl AnyTsTypeAssertion {
+    fn expression(&self) -> Option<AnyJsExpression> {
+        match self {
+            Self::TsAsExpression(assertion) => assertion.expression().ok(),
+            Self::TsTypeAssertionExpression(assertion) => assertion.expression().ok(),
+        }
+    }
+
+    fn ty(&self) -> Option<AnyTsType> {
+        match self {
+            Self::TsAsExpression(assertion) => assertion.ty().ok(),
+            Self::TsTypeAssertionExpression(assertion) => assertion.ty().ok(),
+        }
+    }
+}
+
+pub struct DoubleAssertion {
+    /// The type the value is asserted to
+    target: String,
+    /// The type of the inner assertion, either `unknown` or `any`
+    intermediate: String,
+}
+
+impl Rule for NoDoubleAssertion {
+    type Query = Ast<AnyTsTypeAssertion>;
+    type State = DoubleAssertion;
+    type Signals = Option<Self::State>;
+    type Options = NoDoubleAssertionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let inner = AnyTsTypeAssertion::cast(node.expression()?.omit_parentheses().into_syntax())?;
+        let intermediate = match inner.ty()? {
+            AnyTsType::TsUnknownType(_) if !ctx.options().allow_unknown => "unknown",
+            AnyTsType::TsAnyType(_) => "any",
+            _ => return None,
+        };
+        Some(DoubleAssertion {
+            target: node.ty()?.syntax().text_trimmed().to_string(),
+            intermediate: intermediate.to_string(),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DoubleAssertion {
+            target,
+            intermediate,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This value is asserted to "<Emphasis>{target}</Emphasis>" through "<Emphasis>{intermediate}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The assertion to "<Emphasis>{intermediate}</Emphasis>" silences the check of TypeScript, so the value may not be a "<Emphasis>{target}</Emphasis>" at runtime."
+            })
+            .note(markup! {
+                "Narrow the type of the value with a type guard, or fix the type of the value."
+            }),
+        )
+    }
+}
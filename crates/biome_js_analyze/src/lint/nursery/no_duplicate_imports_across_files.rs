@@ -0,0 +1,163 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsModuleItem, AnyJsRoot, JsModule};
+use biome_rowan::{AstNode, TextRange};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::services::module_exports::{
+    collect_module_exports, normalize_path, star_re_export_specifier, ProjectExports,
+};
+
+declare_lint_rule! {
+    /// Disallow re-exporting several modules that export the same name.
+    ///
+    /// When a module, such as a barrel file, re-exports several modules with `export * from`,
+    /// a name exported by more than one of these modules is ambiguous:
+    /// JavaScript silently drops it from the exports of the module,
+    /// and the editors can't decide which module to auto-import it from.
+    ///
+    /// A name is only reported when the re-exported modules export different bindings,
+    /// and when the module doesn't export the name explicitly.
+    /// The default exports are ignored, because `export * from` doesn't re-export them.
+    ///
+    /// Only the relative specifiers, such as `./button`, are resolved,
+    /// and only the files processed by Biome are taken into account:
+    /// in an editor, these are the opened files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,ignore
+    /// // button.ts
+    /// export const Button = () => {};
+    ///
+    /// // icon-button.ts
+    /// export const Button = () => {};
+    ///
+    /// // index.ts
+    /// export * from "./button";
+    /// export * from "./icon-button";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts,ignore
+    /// // index.ts
+    /// export * from "./button";
+    /// export * from "./icon-button";
+    /// export { Button } from "./button";
+    /// ```
+    ///
+    pub NoDuplicateImportsAcrossFiles {
+        version: "next",
+        name: "noDuplicateImportsAcrossFiles",
+        language: "js",
+        recommended: false,
+    }
+}
+
+impl Rule for NoDuplicateImportsAcrossFiles {
+    type Query = ProjectExports<JsModule>;
+    type State = AmbiguousExport;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let module = ctx.query();
+        let project = ctx.project();
+        let file_path = normalize_path(ctx.file_path());
+        let own_exports = collect_module_exports(&AnyJsRoot::from(module.clone()));
+
+        // Sorted by name, so that the diagnostics are emitted in a stable order
+        let mut sources_by_name: BTreeMap<String, Vec<ReExportSource>> = BTreeMap::new();
+        for item in module.items() {
+            let AnyJsModuleItem::JsExport(export) = item else {
+                continue;
+            };
+            let Some(specifier) = star_re_export_specifier(&export) else {
+                continue;
+            };
+            let Some(resolved) = project.resolve(&file_path, &specifier) else {
+                continue;
+            };
+            for (name, origin) in project.star_exported_names(resolved) {
+                if own_exports.contains(&name) {
+                    continue;
+                }
+                let sources = sources_by_name.entry(name).or_default();
+                // The same binding can be re-exported through several modules
+                if sources.iter().all(|source| source.origin != origin) {
+                    sources.push(ReExportSource {
+                        range: export.range(),
+                        specifier: specifier.clone(),
+                        origin,
+                    });
+                }
+            }
+        }
+
+        sources_by_name
+            .into_iter()
+            .filter(|(_, sources)| sources.len() > 1)
+            .map(|(name, sources)| AmbiguousExport { name, sources })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.name;
+        let first_source = state.sources.first()?;
+        let directory = normalize_path(ctx.file_path().parent()?);
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            first_source.range,
+            markup! {
+                "The name "<Emphasis>{name}</Emphasis>" is exported by several modules re-exported by this module."
+            },
+        );
+        for source in &state.sources {
+            let path = display_path(&directory, &source.origin);
+            diagnostic = diagnostic.detail(
+                source.range,
+                markup! {
+                    <Emphasis>{path}</Emphasis>" exports "<Emphasis>{name}</Emphasis>" through this re-export."
+                },
+            );
+        }
+        let specifier = &first_source.specifier;
+        Some(
+            diagnostic
+                .note(markup! {
+                    "JavaScript doesn't export the ambiguous names of "<Emphasis>"export *"</Emphasis>", and the editors can't decide which module to auto-import "<Emphasis>{name}</Emphasis>" from."
+                })
+                .note(markup! {
+                    "Export "<Emphasis>{name}</Emphasis>" explicitly, for example with "<Emphasis>"export { "{name}" } from \""{specifier}"\""</Emphasis>", or rename it in the other modules."
+                }),
+        )
+    }
+}
+
+/// A name exported by several of the modules re-exported with `export * from`
+pub struct AmbiguousExport {
+    name: String,
+    sources: Vec<ReExportSource>,
+}
+
+/// A module that provides an ambiguous name
+pub struct ReExportSource {
+    /// The range of the `export * from` statement that re-exports the name
+    range: TextRange,
+    /// The specifier of the `export * from` statement
+    specifier: String,
+    /// The path of the module that exports the name
+    origin: PathBuf,
+}
+
+/// Returns `path` relative to `directory` if `path` is inside `directory`
+fn display_path(directory: &Path, path: &Path) -> String {
+    path.strip_prefix(directory)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
@@ -0,0 +1,104 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::JsRegexLiteralExpression;
+use biome_rowan::{TextRange, TextSize};
+
+use crate::utils::regex_ast::{parse_regex, RegexFlags};
+
+declare_lint_rule! {
+    /// Disallow empty alternatives in regular expression literals.
+    ///
+    /// An empty alternative matches the empty string,
+    /// so the group or the pattern that contains it always matches.
+    /// An empty alternative is often the result of a typing mistake, such as a doubled or a trailing `|`.
+    /// If the group is meant to be optional, use the `?` quantifier instead.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /a||b/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /(jpg|png|)$/;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /a|b/;
+    /// ```
+    ///
+    /// ```js
+    /// /(jpg|png)?$/;
+    /// ```
+    ///
+    pub NoEmptyAlternatives {
+        version: "next",
+        name: "noEmptyAlternatives",
+        language: "js",
+        sources: &[RuleSource::EslintRegexp("no-empty-alternative")],
+        recommended: false,
+    }
+}
+
+impl Rule for NoEmptyAlternatives {
+    type Query = Ast<JsRegexLiteralExpression>;
+    /// The range of the `|` next to the empty alternative, relative to the pattern.
+    type State = TextRange;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut separators = Vec::new();
+        let Ok((pattern, flags)) = ctx.query().decompose() else {
+            return separators;
+        };
+        let Some(disjunction) = parse_regex(pattern.text(), RegexFlags::from_text(flags.text()))
+        else {
+            return separators;
+        };
+        disjunction.for_each_disjunction(&mut |disjunction| {
+            if disjunction.alternatives.len() < 2 {
+                return;
+            }
+            for (index, alternative) in disjunction.alternatives.iter().enumerate() {
+                if alternative.terms.is_empty() {
+                    // The first alternative is followed by a `|`, the others are preceded by a `|`
+                    let start = if index == 0 {
+                        alternative.range.start()
+                    } else {
+                        alternative.range.start() - TextSize::from(1)
+                    };
+                    separators.push(TextRange::at(start, TextSize::from(1)));
+                }
+            }
+        });
+        separators
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, separator: &Self::State) -> Option<RuleDiagnostic> {
+        let regex_token = ctx.query().value_token().ok()?;
+        // Add 1 to skip the opening `/`
+        let pattern_start = regex_token.text_trimmed_range().start() + TextSize::from(1);
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *separator + pattern_start,
+                markup! {
+                    "This "<Emphasis>"|"</Emphasis>" separates an empty alternative."
+                },
+            )
+            .note(markup! {
+                "An empty alternative matches the empty string, so the group or the pattern that contains it always matches."
+            })
+            .note(markup! {
+                "Remove the empty alternative, and use the "<Emphasis>"?"</Emphasis>" quantifier if the group is optional."
+            }),
+        )
+    }
+}
@@ -0,0 +1,132 @@
+use crate::services::manifest::{Manifest, ManifestServices};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::jsx_ext::AnyJsxElement;
+use biome_js_syntax::{AnyJsExpression, JsxSpreadAttribute};
+use biome_rowan::AstNode;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow spreading props onto JSX elements.
+    ///
+    /// Spreading an object of props, such as `{...props}`, passes every property of the object to the element.
+    /// On DOM elements, the properties that aren't valid HTML attributes end up in the rendered markup,
+    /// or trigger warnings of React about unknown attributes.
+    /// Passing the props explicitly makes it clear which attributes the element receives.
+    ///
+    /// The spread of an object literal, such as `{...{ id: "main" }}`, is explicit and is allowed.
+    ///
+    /// The rule only applies to projects that declare `react` in the dependencies,
+    /// the development dependencies or the peer dependencies of their `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,ignore
+    /// <div {...props} />
+    /// ```
+    ///
+    /// ```jsx,ignore
+    /// <Button {...props}>Submit</Button>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx,ignore
+    /// <div id={props.id} className={props.className} />
+    /// ```
+    ///
+    /// ```jsx,ignore
+    /// <div {...{ id: "main" }} />
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `ignoreComponents` to allow spreading props onto custom components,
+    /// and to only report the spreads onto DOM elements:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignoreComponents": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoImplicitSpreadProps {
+        version: "next",
+        name: "noImplicitSpreadProps",
+        language: "jsx",
+        sources: &[RuleSource::EslintReact("jsx-props-no-spreading")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noImplicitSpreadProps`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoImplicitSpreadPropsOptions {
+    /// Allow spreading props onto custom components, such as `<Button {...props} />`.
+    pub ignore_components: bool,
+}
+
+impl Rule for NoImplicitSpreadProps {
+    type Query = Manifest<JsxSpreadAttribute>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = NoImplicitSpreadPropsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !depends_on_react(ctx) {
+            return None;
+        }
+        let spread = ctx.query();
+        if matches!(
+            spread.argument().ok()?.omit_parentheses(),
+            AnyJsExpression::JsObjectExpression(_)
+        ) {
+            return None;
+        }
+        let element = spread
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find_map(AnyJsxElement::cast)?;
+        if ctx.options().ignore_components && element.is_custom_component() {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Avoid spreading props onto JSX elements."
+                },
+            )
+            .note(markup! {
+                "The element receives every property of the object, including the ones that aren't valid attributes."
+            })
+            .note(markup! {
+                "Pass the props explicitly instead."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if the `package.json` of the project declares `react`
+fn depends_on_react(manifest: &ManifestServices) -> bool {
+    manifest.is_dependency("react")
+        || manifest.is_dev_dependency("react")
+        || manifest.is_peer_dependency("react")
+}
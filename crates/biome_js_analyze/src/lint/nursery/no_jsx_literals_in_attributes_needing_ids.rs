@@ -0,0 +1,184 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::jsx_ext::AnyJsxElement;
+use biome_js_syntax::{AnyJsxAttribute, JsxTagExpression};
+use biome_rowan::{AstNode, TextRange};
+use rustc_hash::FxHashMap;
+use std::collections::hash_map::Entry;
+
+declare_lint_rule! {
+    /// Disallow the literal ids of a JSX tree that don't match: the labels that reference unknown ids, and the duplicate ids.
+    ///
+    /// A label is associated with its control through its `htmlFor` attribute (`for` in HTML), which references the `id` of the control.
+    /// A label that references an id that doesn't exist isn't associated with any control.
+    /// An id declared several times makes the association ambiguous: the browsers only use the first element with this id.
+    ///
+    /// The rule checks the literal values of the `id`, `htmlFor` and `for` attributes of a JSX tree, and reports:
+    /// - the labels that reference an id that isn't declared in the tree;
+    /// - the ids that are declared several times in the tree.
+    ///
+    /// The dynamic values, such as `id={inputId}`, are ignored.
+    /// The labels aren't reported when the tree contains a dynamic id, a spread attribute,
+    /// or a component without an `id` attribute, because they may declare the referenced id.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <form>
+    ///     <label htmlFor="email">Email</label>
+    ///     <input id="mail" type="email" />
+    /// </form>;
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <form>
+    ///     <input id="name" />
+    ///     <input id="name" />
+    /// </form>;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <form>
+    ///     <label htmlFor="email">Email</label>
+    ///     <input id="email" type="email" />
+    /// </form>;
+    /// ```
+    ///
+    /// ```jsx
+    /// <form>
+    ///     <label htmlFor="email">Email</label>
+    ///     <EmailInput />
+    /// </form>;
+    /// ```
+    ///
+    pub NoJsxLiteralsInAttributesNeedingIds {
+        version: "next",
+        name: "noJsxLiteralsInAttributesNeedingIds",
+        language: "jsx",
+        recommended: false,
+    }
+}
+
+pub enum InvalidId {
+    /// A label references an id that isn't declared in the tree
+    UnknownTarget { id: String, range: TextRange },
+    /// An id is declared several times in the tree
+    Duplicate {
+        id: String,
+        range: TextRange,
+        first_declaration: TextRange,
+    },
+}
+
+impl Rule for NoJsxLiteralsInAttributesNeedingIds {
+    type Query = Ast<JsxTagExpression>;
+    type State = InvalidId;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let tree = ctx.query();
+        // The nested trees, such as `{visible && <input id="name" />}`, are checked with the tree that contains them
+        if tree
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .any(|ancestor| JsxTagExpression::can_cast(ancestor.kind()))
+        {
+            return Vec::new();
+        }
+        let mut signals = Vec::new();
+        let mut ids = FxHashMap::default();
+        let mut labels = Vec::new();
+        // `true` if the tree may declare ids that aren't known statically
+        let mut has_unknown_ids = false;
+        for element in tree.syntax().descendants().filter_map(AnyJsxElement::cast) {
+            has_unknown_ids |= element
+                .attributes()
+                .iter()
+                .any(|attribute| matches!(attribute, AnyJsxAttribute::JsxSpreadAttribute(_)));
+            match element.find_attribute_by_name("id") {
+                Some(attribute) => match attribute.as_static_value() {
+                    Some(value) if !value.text().is_empty() => {
+                        let range = value.range();
+                        match ids.entry(value.text().to_string()) {
+                            Entry::Occupied(entry) => signals.push(InvalidId::Duplicate {
+                                id: entry.key().clone(),
+                                range,
+                                first_declaration: *entry.get(),
+                            }),
+                            Entry::Vacant(entry) => {
+                                entry.insert(range);
+                            }
+                        }
+                    }
+                    _ => has_unknown_ids = true,
+                },
+                None => has_unknown_ids |= element.is_custom_component(),
+            }
+            for name in ["htmlFor", "for"] {
+                if let Some(value) = element
+                    .find_attribute_by_name(name)
+                    .and_then(|attribute| attribute.as_static_value())
+                {
+                    labels.push((value.text().to_string(), value.range()));
+                }
+            }
+        }
+        if !has_unknown_ids {
+            signals.extend(
+                labels
+                    .into_iter()
+                    .filter(|(id, _)| !ids.contains_key(id))
+                    .map(|(id, range)| InvalidId::UnknownTarget { id, range }),
+            );
+        }
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidId::UnknownTarget { id, range } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The label references the id "<Emphasis>{id}</Emphasis>", which isn't declared in this JSX tree."
+                },
+            )
+            .note(markup! {
+                "The label isn't associated with any control."
+            })
+            .note(markup! {
+                "Declare the id on the control that the label describes, or reference the id of an existing control."
+            }),
+            InvalidId::Duplicate {
+                id,
+                range,
+                first_declaration,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The id "<Emphasis>{id}</Emphasis>" is declared several times in this JSX tree."
+                },
+            )
+            .detail(
+                first_declaration,
+                markup! {
+                    "The id is first declared here."
+                },
+            )
+            .note(markup! {
+                "The labels and the ARIA attributes that reference this id only use the first element with this id."
+            })
+            .note(markup! {
+                "Give a unique id to each element."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
@@ -1,15 +1,16 @@
 use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
 };
+use biome_aria::a11y::{label_association, LabelAssociation, LabelAssociationOptions};
 use biome_console::markup;
 use biome_deserialize_macros::Deserializable;
-use biome_js_syntax::{
-    AnyJsxAttribute, AnyJsxAttributeValue, AnyJsxTag, JsxAttribute, JsxAttributeList, JsxName,
-    JsxReferenceIdentifier, JsxText,
-};
+use biome_js_syntax::jsx_ext::AnyJsxElement;
+use biome_js_syntax::AnyJsxTag;
 use biome_rowan::AstNode;
 use serde::{Deserialize, Serialize};
 
+use crate::utils::markup::JsxMarkupElement;
+
 declare_lint_rule! {
     /// Enforce that a label element or component has a text label and an associated input.
     ///
@@ -105,49 +106,28 @@ pub struct NoLabelWithoutControlOptions {
     pub label_components: Vec<String>,
 }
 
-pub struct NoLabelWithoutControlState {
-    pub has_text_content: bool,
-    pub has_control_association: bool,
-}
-
-const DEFAULT_LABEL_ATTRIBUTES: &[&str; 2] = &["aria-label", "alt"];
-const DEFAULT_LABEL_COMPONENTS: &[&str; 1] = &["label"];
-const DEFAULT_INPUT_COMPONENTS: &[&str; 6] =
-    &["input", "meter", "output", "progress", "select", "textarea"];
-
 impl Rule for NoLabelWithoutControl {
     type Query = Ast<AnyJsxTag>;
-    type State = NoLabelWithoutControlState;
+    type State = LabelAssociation;
     type Signals = Option<Self::State>;
     type Options = NoLabelWithoutControlOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
-        let node = ctx.query();
         let options = ctx.options();
-        let label_attributes = &options.label_attributes;
-        let label_components = &options.label_components;
-        let input_components = &options.input_components;
-
-        let element_name = get_element_name(node)?;
-        let is_allowed_element = label_components.contains(&element_name)
-            || DEFAULT_LABEL_COMPONENTS.contains(&element_name.as_str());
-
-        if !is_allowed_element {
-            return None;
-        }
-
-        let has_text_content = has_accessible_label(node, label_attributes);
-        let has_control_association =
-            has_for_attribute(node)? || has_nested_control(node, input_components);
-
-        if has_text_content && has_control_association {
-            return None;
-        }
-
-        Some(NoLabelWithoutControlState {
-            has_text_content,
-            has_control_association,
-        })
+        let element = match ctx.query() {
+            AnyJsxTag::JsxElement(element) => element.opening_element().ok()?.into(),
+            AnyJsxTag::JsxSelfClosingElement(element) => AnyJsxElement::from(element.clone()),
+            AnyJsxTag::JsxFragment(_) => return None,
+        };
+        let association = label_association(
+            &JsxMarkupElement(element),
+            LabelAssociationOptions {
+                input_components: &options.input_components,
+                label_attributes: &options.label_attributes,
+                label_components: &options.label_components,
+            },
+        )?;
+        (!association.is_valid()).then_some(association)
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
@@ -175,101 +155,3 @@ impl Rule for NoLabelWithoutControl {
         Some(diagnostic)
     }
 }
-
-/// Returns the `JsxAttributeList` of the passed `AnyJsxTag`
-fn get_element_attributes(jsx_tag: &AnyJsxTag) -> Option<JsxAttributeList> {
-    match jsx_tag {
-        AnyJsxTag::JsxElement(element) => Some(element.opening_element().ok()?.attributes()),
-        AnyJsxTag::JsxSelfClosingElement(element) => Some(element.attributes()),
-        _ => None,
-    }
-}
-
-/// Returns the element name of a `AnyJsxTag`
-fn get_element_name(jsx_tag: &AnyJsxTag) -> Option<String> {
-    match jsx_tag {
-        AnyJsxTag::JsxElement(element) => Some(element.opening_element().ok()?.name().ok()?.text()),
-        AnyJsxTag::JsxSelfClosingElement(element) => Some(element.name().ok()?.text()),
-        _ => None,
-    }
-}
-
-/// Returns whether the passed `AnyJsxTag` have a `for` or `htmlFor` attribute
-fn has_for_attribute(jsx_tag: &AnyJsxTag) -> Option<bool> {
-    let for_attributes = &["for", "htmlFor"];
-    let attributes = get_element_attributes(jsx_tag)?;
-
-    Some(attributes.into_iter().any(|attribute| {
-        match attribute {
-            AnyJsxAttribute::JsxAttribute(jsx_attribute) => jsx_attribute
-                .name()
-                .is_ok_and(|jsx_name| for_attributes.contains(&jsx_name.text().as_str())),
-            _ => false,
-        }
-    }))
-}
-
-/// Returns whether the passed `AnyJsxTag` have a child that is considered an input component
-/// according to the passed `input_components` parameter
-fn has_nested_control(jsx_tag: &AnyJsxTag, input_components: &[String]) -> bool {
-    jsx_tag
-        .syntax()
-        .descendants()
-        .any(|descendant| match JsxName::try_cast(descendant) {
-            Ok(jsx_name) => {
-                let attribute_name = jsx_name.text();
-                input_components.contains(&attribute_name)
-                    || DEFAULT_INPUT_COMPONENTS.contains(&attribute_name.as_str())
-            }
-            Err(descendant) => {
-                if let Some(jsx_reference_identifier) = JsxReferenceIdentifier::cast(descendant) {
-                    let attribute_name = jsx_reference_identifier.text();
-                    input_components.contains(&attribute_name)
-                        || DEFAULT_INPUT_COMPONENTS.contains(&attribute_name.as_str())
-                } else {
-                    false
-                }
-            }
-        })
-}
-
-/// Returns whether the passed `AnyJsxTag` meets one of the following conditions:
-/// - Has a label attribute that corresponds to the `label_attributes` parameter
-/// - Has an `aria-labelledby` attribute
-/// - Has a child `JsxText` node
-fn has_accessible_label(jsx_tag: &AnyJsxTag, label_attributes: &[String]) -> bool {
-    let mut has_text = false;
-    let mut has_accessible_attribute = false;
-
-    for descendant in jsx_tag.syntax().descendants() {
-        has_text = has_text || JsxText::can_cast(descendant.kind());
-
-        if let Some(jsx_attribute) = JsxAttribute::cast(descendant) {
-            if let (Some(jsx_name), Some(jsx_attribute_value)) = (
-                jsx_attribute.name().ok(),
-                jsx_attribute
-                    .initializer()
-                    .and_then(|initializer| initializer.value().ok()),
-            ) {
-                let attribute_name = jsx_name.text();
-                let has_label_attribute = label_attributes.contains(&attribute_name)
-                    || DEFAULT_LABEL_ATTRIBUTES.contains(&attribute_name.as_str());
-                let is_aria_labelledby_attribute = jsx_name.text() == "aria-labelledby";
-                let has_value = has_jsx_attribute_value(&jsx_attribute_value);
-
-                if has_value && (is_aria_labelledby_attribute || has_label_attribute) {
-                    has_accessible_attribute = true
-                }
-            }
-        }
-    }
-
-    has_accessible_attribute || has_text
-}
-
-/// Returns whether the passed `jsx_attribute_value` has a valid value inside it
-fn has_jsx_attribute_value(jsx_attribute_value: &AnyJsxAttributeValue) -> bool {
-    jsx_attribute_value
-        .as_static_value()
-        .is_some_and(|static_value| !static_value.text().trim().is_empty())
-}
@@ -0,0 +1,496 @@
+use crate::react::{is_react_call_api, ReactLibrary};
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsFunctionBody, AnyJsMemberExpression,
+    AnyJsObjectMember, JsAssignmentExpression, JsCallExpression, JsFunctionDeclaration,
+    JsInitializerClause, JsReturnStatement, JsSyntaxKind, JsSyntaxNode, JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange, WalkEvent};
+
+declare_lint_rule! {
+    /// Disallow the event listeners, intervals, and subscriptions of an effect that aren't removed by its cleanup function.
+    ///
+    /// A React effect runs each time its dependencies change, and its cleanup function runs before the next execution and when the component unmounts.
+    /// An event listener, an interval, or a subscription started by an effect must be stopped by its cleanup function,
+    /// otherwise they pile up and keep a reference to a component that doesn't exist anymore.
+    ///
+    /// The rule checks the calls of `addEventListener`, `setInterval`, and `subscribe` in `useEffect`, `useLayoutEffect`, and `useInsertionEffect`:
+    /// - an event listener is removed by a call of `removeEventListener` with the same target, event, and handler,
+    ///   or when it is attached to the `signal` of an `AbortController`;
+    /// - an interval is cleared by a call of `clearInterval` with the variable that stores its id;
+    /// - a subscription is cancelled by a call of the variable that stores it, or of one of its methods.
+    ///
+    /// The calls are matched by their text, so a listener removed through another variable isn't recognized.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { useEffect } from "react";
+    ///
+    /// function Component() {
+    ///     useEffect(() => {
+    ///         window.addEventListener("resize", onResize);
+    ///     }, []);
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { useEffect } from "react";
+    ///
+    /// function Component() {
+    ///     useEffect(() => {
+    ///         const id = setInterval(tick, 1000);
+    ///         return () => {};
+    ///     }, []);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// import { useEffect } from "react";
+    ///
+    /// function Component() {
+    ///     useEffect(() => {
+    ///         window.addEventListener("resize", onResize);
+    ///         return () => window.removeEventListener("resize", onResize);
+    ///     }, []);
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// import { useEffect } from "react";
+    ///
+    /// function Component() {
+    ///     useEffect(() => {
+    ///         const subscription = source.subscribe(onChange);
+    ///         return () => subscription.unsubscribe();
+    ///     }, []);
+    /// }
+    /// ```
+    ///
+    pub NoLeakedEventListeners {
+        version: "next",
+        name: "noLeakedEventListeners",
+        language: "jsx",
+        recommended: false,
+    }
+}
+
+const EFFECT_HOOKS: [&str; 3] = ["useEffect", "useInsertionEffect", "useLayoutEffect"];
+
+pub struct Leak {
+    /// The range of the call that starts the listener, the interval, or the subscription
+    range: TextRange,
+    kind: LeakKind,
+}
+
+enum LeakKind {
+    EventListener { has_inline_handler: bool },
+    Interval,
+    Subscription,
+}
+
+/// A call of an effect that must be stopped by its cleanup function.
+enum Start {
+    /// `target.addEventListener(event, handler)`
+    EventListener {
+        target: String,
+        event: String,
+        handler: String,
+        has_inline_handler: bool,
+    },
+    /// `setInterval(callback)`, with the text of the variable that stores its id
+    Interval { id: Option<String> },
+    /// `source.subscribe(callback)`, with the text of the variable that stores the subscription
+    Subscription { id: Option<String> },
+}
+
+/// The calls of the cleanup functions of an effect.
+#[derive(Default)]
+struct Stops {
+    /// The target, the event, and the handler of the calls of `removeEventListener`
+    removed_listeners: Vec<(String, String, String)>,
+    /// The arguments of the calls of `clearInterval`
+    cleared_intervals: Vec<String>,
+    /// The callees of all the calls, and the objects of the callees that are member expressions
+    callees: Vec<String>,
+}
+
+impl Rule for NoLeakedEventListeners {
+    type Query = Semantic<JsCallExpression>;
+    type State = Leak;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let model = ctx.model();
+        let Ok(callee) = call.callee() else {
+            return Vec::new();
+        };
+        if !EFFECT_HOOKS
+            .iter()
+            .any(|hook| is_react_call_api(&callee, model, ReactLibrary::React, hook))
+        {
+            return Vec::new();
+        }
+        let Some(body) = effect_body(call) else {
+            return Vec::new();
+        };
+        let stops = Stops::from_effect(&body, model);
+        starts(&body)
+            .into_iter()
+            .filter_map(|(range, start)| {
+                let kind = match start {
+                    Start::EventListener {
+                        target,
+                        event,
+                        handler,
+                        has_inline_handler,
+                    } => {
+                        if !has_inline_handler
+                            && stops.removed_listeners.iter().any(|removed| {
+                                removed.0 == target && removed.1 == event && removed.2 == handler
+                            })
+                        {
+                            return None;
+                        }
+                        LeakKind::EventListener { has_inline_handler }
+                    }
+                    Start::Interval { id } => {
+                        if id.is_some_and(|id| stops.cleared_intervals.contains(&id)) {
+                            return None;
+                        }
+                        LeakKind::Interval
+                    }
+                    Start::Subscription { id } => {
+                        if id.is_some_and(|id| stops.callees.contains(&id)) {
+                            return None;
+                        }
+                        LeakKind::Subscription
+                    }
+                };
+                Some(Leak { range, kind })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, leak: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match leak.kind {
+            LeakKind::EventListener { has_inline_handler } => {
+                let diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    leak.range,
+                    markup! {
+                        "This event listener isn't removed by the cleanup function of the effect."
+                    },
+                );
+                if has_inline_handler {
+                    diagnostic.note(markup! {
+                        "The handler is a new function each time the effect runs, so it can't be removed. Store it in a variable, and pass this variable to "<Emphasis>"removeEventListener"</Emphasis>" in the cleanup function."
+                    })
+                } else {
+                    diagnostic.note(markup! {
+                        "Return a cleanup function that calls "<Emphasis>"removeEventListener"</Emphasis>" with the same target, event, and handler. Otherwise, a new listener is added each time the effect runs."
+                    })
+                }
+            }
+            LeakKind::Interval => RuleDiagnostic::new(
+                rule_category!(),
+                leak.range,
+                markup! {
+                    "This interval isn't cleared by the cleanup function of the effect."
+                },
+            )
+            .note(markup! {
+                "Store its id in a variable, and return a cleanup function that passes this variable to "<Emphasis>"clearInterval"</Emphasis>". Otherwise, a new interval is started each time the effect runs."
+            }),
+            LeakKind::Subscription => RuleDiagnostic::new(
+                rule_category!(),
+                leak.range,
+                markup! {
+                    "This subscription isn't cancelled by the cleanup function of the effect."
+                },
+            )
+            .note(markup! {
+                "Store the subscription in a variable, and return a cleanup function that cancels it. Otherwise, a new subscription is started each time the effect runs."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the body of the function passed to the effect `call`.
+fn effect_body(call: &JsCallExpression) -> Option<AnyJsFunctionBody> {
+    let [Some(AnyJsCallArgument::AnyJsExpression(callback))] =
+        call.arguments().ok()?.get_arguments_by_index([0])
+    else {
+        return None;
+    };
+    match callback.omit_parentheses() {
+        AnyJsExpression::JsArrowFunctionExpression(arrow) => arrow.body().ok(),
+        AnyJsExpression::JsFunctionExpression(function) => {
+            Some(AnyJsFunctionBody::JsFunctionBody(function.body().ok()?))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the body of the cleanup function returned by `statement`.
+///
+/// A cleanup function is returned inline, or is a function declared in the effect and returned by its name.
+fn cleanup_function(statement: &JsReturnStatement, model: &SemanticModel) -> Option<JsSyntaxNode> {
+    match statement.argument()?.omit_parentheses() {
+        AnyJsExpression::JsArrowFunctionExpression(arrow) => Some(arrow.body().ok()?.into_syntax()),
+        AnyJsExpression::JsFunctionExpression(function) => {
+            Some(function.body().ok()?.into_syntax())
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let binding = model.binding(&identifier.name().ok()?)?;
+            let declaration = binding.syntax().parent()?;
+            if let Some(function) = JsFunctionDeclaration::cast_ref(&declaration) {
+                return Some(function.body().ok()?.into_syntax());
+            }
+            let initializer = JsVariableDeclarator::cast(declaration)?
+                .initializer()?
+                .expression()
+                .ok()?;
+            match initializer.omit_parentheses() {
+                AnyJsExpression::JsArrowFunctionExpression(arrow) => {
+                    Some(arrow.body().ok()?.into_syntax())
+                }
+                AnyJsExpression::JsFunctionExpression(function) => {
+                    Some(function.body().ok()?.into_syntax())
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the calls of the effect `body` that start a listener, an interval, or a subscription.
+fn starts(body: &AnyJsFunctionBody) -> Vec<(TextRange, Start)> {
+    effect_nodes(body.syntax())
+        .filter_map(JsCallExpression::cast)
+        .filter_map(|call| {
+            let start = to_start(&call)?;
+            // `() => source.subscribe(callback)` and `return source.subscribe(callback)`
+            // return the function that cancels the subscription
+            if matches!(start, Start::Subscription { .. }) {
+                let parent = call.syntax().parent()?;
+                if parent.kind() == JsSyntaxKind::JS_RETURN_STATEMENT
+                    || parent.kind() == JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+                {
+                    return None;
+                }
+            }
+            Some((call.range(), start))
+        })
+        .collect()
+}
+
+fn to_start(call: &JsCallExpression) -> Option<Start> {
+    let callee = call.callee().ok()?.omit_parentheses();
+    if is_global_function(&callee, "setInterval") {
+        return Some(Start::Interval {
+            id: stored_in(call),
+        });
+    }
+    let member = AnyJsMemberExpression::cast(callee.into_syntax())?;
+    match member.member_name()?.text() {
+        "addEventListener" => {
+            let [Some(event), Some(handler), options] =
+                call.arguments().ok()?.get_arguments_by_index([0, 1, 2])
+            else {
+                return None;
+            };
+            let event = event.as_any_js_expression()?.clone();
+            let handler = handler.as_any_js_expression()?.clone().omit_parentheses();
+            if options.is_some_and(|options| has_signal(&options)) {
+                return None;
+            }
+            let event = event.as_static_value().map_or_else(
+                || event.syntax().text_trimmed().to_string(),
+                |value| value.text().to_string(),
+            );
+            Some(Start::EventListener {
+                target: member.object().ok()?.syntax().text_trimmed().to_string(),
+                event,
+                has_inline_handler: matches!(
+                    handler,
+                    AnyJsExpression::JsArrowFunctionExpression(_)
+                        | AnyJsExpression::JsFunctionExpression(_)
+                ),
+                handler: handler.syntax().text_trimmed().to_string(),
+            })
+        }
+        "subscribe" => Some(Start::Subscription {
+            id: stored_in(call),
+        }),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the options of `addEventListener` have a `signal` property.
+fn has_signal(options: &AnyJsCallArgument) -> bool {
+    let Some(AnyJsExpression::JsObjectExpression(object)) = options.as_any_js_expression() else {
+        return false;
+    };
+    object
+        .members()
+        .iter()
+        .flatten()
+        .any(|member| match member {
+            AnyJsObjectMember::JsPropertyObjectMember(property) => property
+                .name()
+                .ok()
+                .and_then(|name| name.name())
+                .is_some_and(|name| name.text() == "signal"),
+            AnyJsObjectMember::JsShorthandPropertyObjectMember(property) => property
+                .name()
+                .is_ok_and(|name| name.syntax().text_trimmed() == "signal"),
+            _ => false,
+        })
+}
+
+/// Returns the text of the variable or the assignment target that stores the result of `call`.
+fn stored_in(call: &JsCallExpression) -> Option<String> {
+    let parent = call.syntax().parent()?;
+    if let Some(initializer) = JsInitializerClause::cast_ref(&parent) {
+        let declarator = initializer.parent::<JsVariableDeclarator>()?;
+        return Some(declarator.id().ok()?.syntax().text_trimmed().to_string());
+    }
+    let assignment = JsAssignmentExpression::cast(parent)?;
+    Some(assignment.left().ok()?.syntax().text_trimmed().to_string())
+}
+
+/// Returns `true` if `callee` is `name`, `window.name`, or `globalThis.name`.
+fn is_global_function(callee: &AnyJsExpression, name: &str) -> bool {
+    match callee {
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .is_ok_and(|reference| reference.syntax().text_trimmed() == name),
+        _ => AnyJsMemberExpression::cast_ref(callee.syntax()).is_some_and(|member| {
+            member
+                .member_name()
+                .is_some_and(|member| member.text() == name)
+                && member.object().is_ok_and(|object| {
+                    matches!(
+                        object.syntax().text_trimmed().to_string().as_str(),
+                        "window" | "globalThis"
+                    )
+                })
+        }),
+    }
+}
+
+impl Stops {
+    fn from_effect(body: &AnyJsFunctionBody, model: &SemanticModel) -> Self {
+        let mut stops = Self::default();
+        let AnyJsFunctionBody::JsFunctionBody(body) = body else {
+            return stops;
+        };
+        for statement in effect_nodes(body.syntax()).filter_map(JsReturnStatement::cast) {
+            if let Some(cleanup) = cleanup_function(&statement, model) {
+                stops.collect(&cleanup);
+            } else if let Some(argument) = statement.argument() {
+                // `return unsubscribe` returns the function that cancels a subscription
+                stops.callees.push(
+                    argument
+                        .omit_parentheses()
+                        .syntax()
+                        .text_trimmed()
+                        .to_string(),
+                );
+            }
+        }
+        stops
+    }
+
+    fn collect(&mut self, cleanup: &JsSyntaxNode) {
+        for call in cleanup.descendants().filter_map(JsCallExpression::cast) {
+            let Ok(callee) = call.callee() else {
+                continue;
+            };
+            let callee = callee.omit_parentheses();
+            let Ok(arguments) = call.arguments() else {
+                continue;
+            };
+            if is_global_function(&callee, "clearInterval") {
+                if let [Some(AnyJsCallArgument::AnyJsExpression(id))] =
+                    arguments.get_arguments_by_index([0])
+                {
+                    self.cleared_intervals
+                        .push(id.omit_parentheses().syntax().text_trimmed().to_string());
+                }
+                continue;
+            }
+            self.callees
+                .push(callee.syntax().text_trimmed().to_string());
+            let Some(member) = AnyJsMemberExpression::cast_ref(callee.syntax()) else {
+                continue;
+            };
+            let Ok(object) = member.object() else {
+                continue;
+            };
+            let target = object.syntax().text_trimmed().to_string();
+            if member
+                .member_name()
+                .is_some_and(|name| name.text() == "removeEventListener")
+            {
+                if let [Some(AnyJsCallArgument::AnyJsExpression(event)), Some(AnyJsCallArgument::AnyJsExpression(handler))] =
+                    arguments.get_arguments_by_index([0, 1])
+                {
+                    let event = event.as_static_value().map_or_else(
+                        || event.syntax().text_trimmed().to_string(),
+                        |value| value.text().to_string(),
+                    );
+                    let handler = handler
+                        .omit_parentheses()
+                        .syntax()
+                        .text_trimmed()
+                        .to_string();
+                    self.removed_listeners
+                        .push((target.clone(), event, handler));
+                }
+            }
+            self.callees.push(target);
+        }
+    }
+}
+
+/// Returns the nodes of `root` that are executed by the effect itself,
+/// without the nested functions, which include the cleanup functions.
+fn effect_nodes(root: &JsSyntaxNode) -> impl Iterator<Item = JsSyntaxNode> {
+    let root = root.clone();
+    let mut preorder = root.preorder();
+    std::iter::from_fn(move || loop {
+        let WalkEvent::Enter(node) = preorder.next()? else {
+            continue;
+        };
+        if node != root
+            && matches!(
+                node.kind(),
+                JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+                    | JsSyntaxKind::JS_FUNCTION_DECLARATION
+                    | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+                    | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                    | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                    | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+                    | JsSyntaxKind::JS_CLASS_DECLARATION
+                    | JsSyntaxKind::JS_CLASS_EXPRESSION
+            )
+        {
+            preorder.skip_subtree();
+            continue;
+        }
+        return Some(node);
+    })
+}
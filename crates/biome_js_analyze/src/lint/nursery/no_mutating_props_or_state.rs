@@ -0,0 +1,375 @@
+use crate::react::hooks::is_react_component;
+use crate::react::{is_react_call_api, ReactLibrary};
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::{Binding, SemanticModel};
+use biome_js_syntax::{
+    global_identifier, AnyJsExpression, AnyJsFunction, JsArrowFunctionExpression, JsCallExpression,
+    JsComputedMemberAssignment, JsFormalParameter, JsParameters, JsReferenceIdentifier,
+    JsStaticMemberAssignment, JsSyntaxKind, JsSyntaxNode, JsUnaryExpression, JsUnaryOperator,
+    JsVariableDeclarator,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, TextRange};
+
+declare_lint_rule! {
+    /// Disallow the mutation of the props, the state, and the values memoized by useMemo in React components.
+    ///
+    /// React expects the props, the state, and the values returned by `useMemo` to be immutable:
+    /// - mutating the props changes a value owned by the parent component, without rendering it again;
+    /// - mutating the state doesn't render the component again, the setter returned by `useState` must be called with a new value;
+    /// - mutating a memoized value changes the value reused by the next renders.
+    ///
+    /// The [React Compiler](https://react.dev/learn/react-compiler) relies on these values being immutable,
+    /// and doesn't optimize the components that mutate them.
+    ///
+    /// The rule reports the assignments to the properties of these values, their deletion,
+    /// the calls of `Object.assign()` that copy into them, and the calls of the methods that mutate an array, such as `push()` or `sort()`.
+    ///
+    /// The props are the first parameter of a component, a function whose name starts with a capital letter.
+    /// The state is the first element returned by `useState` or `useReducer`.
+    /// The rule follows the variables initialized with these values, one of their properties, or a destructuring of them,
+    /// such as `const { items } = props` or `const list = memoized.list`.
+    /// The variables that are reassigned aren't followed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// function Profile(props) {
+    ///     props.name = props.name.trim();
+    ///     return <p>{props.name}</p>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { useState } from "react";
+    ///
+    /// function TodoList() {
+    ///     const [todos, setTodos] = useState([]);
+    ///     const add = (todo) => todos.push(todo);
+    ///     return <List items={todos} onAdd={add} />;
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { useMemo } from "react";
+    ///
+    /// function Table({ rows }) {
+    ///     const sorted = useMemo(() => rows.filter(Boolean), [rows]);
+    ///     const view = sorted;
+    ///     view.sort(compare);
+    ///     return <Rows rows={view} />;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// function Profile(props) {
+    ///     const name = props.name.trim();
+    ///     return <p>{name}</p>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// import { useState } from "react";
+    ///
+    /// function TodoList() {
+    ///     const [todos, setTodos] = useState([]);
+    ///     const add = (todo) => setTodos([...todos, todo]);
+    ///     return <List items={todos} onAdd={add} />;
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// import { useMemo } from "react";
+    ///
+    /// function Table({ rows }) {
+    ///     const sorted = useMemo(() => rows.filter(Boolean).sort(compare), [rows]);
+    ///     return <Rows rows={sorted} />;
+    /// }
+    /// ```
+    ///
+    pub NoMutatingPropsOrState {
+        version: "next",
+        name: "noMutatingPropsOrState",
+        language: "jsx",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsMutation = JsStaticMemberAssignment | JsComputedMemberAssignment | JsUnaryExpression | JsCallExpression
+}
+
+/// The kind of the values that mustn't be mutated
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImmutableValueKind {
+    /// The props of a component
+    Props,
+    /// The state returned by `useState` or `useReducer`
+    State,
+    /// The value returned by `useMemo`
+    Memo,
+}
+
+pub struct MutatedValue {
+    kind: ImmutableValueKind,
+    /// The range of the binding that holds the value in the component
+    declaration: TextRange,
+}
+
+/// The methods of arrays that mutate the array
+const MUTATING_METHODS: [&str; 9] = [
+    "copyWithin",
+    "fill",
+    "pop",
+    "push",
+    "reverse",
+    "shift",
+    "sort",
+    "splice",
+    "unshift",
+];
+
+/// The maximum number of variables followed from a mutated variable to the value it holds
+const MAX_ALIASES: usize = 16;
+
+impl Rule for NoMutatingPropsOrState {
+    type Query = Semantic<AnyJsMutation>;
+    type State = MutatedValue;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mutation = ctx.query();
+        let model = ctx.model();
+        let object = mutated_object(mutation, model)?;
+        let reference = root_reference(&object)?;
+        let binding = model.binding(&reference)?;
+        immutable_value(binding, model)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let range = ctx.query().range();
+        let diagnostic = match state.kind {
+            ImmutableValueKind::Props => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This mutates the props of the component."
+                },
+            )
+            .detail(state.declaration, markup! {
+                "The props are declared here."
+            })
+            .note(markup! {
+                "The props are owned by the parent component, which isn't rendered again when they are mutated."
+            })
+            .note(markup! {
+                "Pass a callback in the props to let the parent component update the value instead."
+            }),
+            ImmutableValueKind::State => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This mutates the state of the component."
+                },
+            )
+            .detail(state.declaration, markup! {
+                "The state is declared here."
+            })
+            .note(markup! {
+                "The component isn't rendered again when the state is mutated."
+            })
+            .note(markup! {
+                "Call the setter of the state with a new value instead."
+            }),
+            ImmutableValueKind::Memo => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This mutates a value memoized by "<Emphasis>"useMemo"</Emphasis>"."
+                },
+            )
+            .detail(state.declaration, markup! {
+                "The value is memoized here."
+            })
+            .note(markup! {
+                "The memoized value is reused by the next renders, which see the mutation."
+            })
+            .note(markup! {
+                "Compute the value in the callback of "<Emphasis>"useMemo"</Emphasis>", or mutate a copy of it instead."
+            }),
+        };
+        Some(diagnostic.note(markup! {
+            "The React Compiler relies on these values being immutable, and doesn't optimize the components that mutate them."
+        }))
+    }
+}
+
+/// Returns the object mutated by `mutation`, if any
+fn mutated_object(mutation: &AnyJsMutation, model: &SemanticModel) -> Option<AnyJsExpression> {
+    match mutation {
+        AnyJsMutation::JsStaticMemberAssignment(assignment) => assignment.object().ok(),
+        AnyJsMutation::JsComputedMemberAssignment(assignment) => assignment.object().ok(),
+        // `delete object.property`
+        AnyJsMutation::JsUnaryExpression(unary) => {
+            if unary.operator().ok()? != JsUnaryOperator::Delete {
+                return None;
+            }
+            match unary.argument().ok()?.omit_parentheses() {
+                AnyJsExpression::JsStaticMemberExpression(member) => member.object().ok(),
+                AnyJsExpression::JsComputedMemberExpression(member) => member.object().ok(),
+                _ => None,
+            }
+        }
+        // `array.push(item)` and `Object.assign(object, source)`
+        AnyJsMutation::JsCallExpression(call) => {
+            let callee = call.callee().ok()?.omit_parentheses();
+            let callee = callee.as_js_static_member_expression()?;
+            let method = callee.member().ok()?;
+            let method = method.as_js_name()?.value_token().ok()?;
+            let method = method.text_trimmed();
+            if MUTATING_METHODS.contains(&method) {
+                return callee.object().ok();
+            }
+            if method != "assign" {
+                return None;
+            }
+            let (reference, name) = global_identifier(&callee.object().ok()?.omit_parentheses())?;
+            if name.text() != "Object" || model.binding(&reference).is_some() {
+                return None;
+            }
+            call.arguments()
+                .ok()?
+                .args()
+                .first()?
+                .ok()?
+                .as_any_js_expression()
+                .cloned()
+        }
+    }
+}
+
+/// Returns the variable at the root of a chain of properties, such as `object` in `object.a[b]`
+fn root_reference(expression: &AnyJsExpression) -> Option<JsReferenceIdentifier> {
+    let mut expression = expression.clone().omit_parentheses();
+    loop {
+        expression = match expression {
+            AnyJsExpression::JsIdentifierExpression(identifier) => return identifier.name().ok(),
+            AnyJsExpression::JsStaticMemberExpression(member) => {
+                member.object().ok()?.omit_parentheses()
+            }
+            AnyJsExpression::JsComputedMemberExpression(member) => {
+                member.object().ok()?.omit_parentheses()
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Returns the immutable value held by `binding`,
+/// following the variables initialized with another variable, one of its properties, or a destructuring of it
+fn immutable_value(mut binding: Binding, model: &SemanticModel) -> Option<MutatedValue> {
+    for _ in 0..MAX_ALIASES {
+        // A reassigned variable can hold another value
+        if binding.all_writes().next().is_some() {
+            return None;
+        }
+        let identifier = binding.syntax();
+        let owner = binding_pattern_owner(identifier)?;
+        let declaration = identifier.text_range();
+        if let Some(parameter) = JsFormalParameter::cast_ref(&owner) {
+            // The props are the first parameter of the component
+            if parameter.syntax().prev_sibling().is_some() {
+                return None;
+            }
+            let function = parameter
+                .syntax()
+                .grand_parent()
+                .and_then(JsParameters::cast)?
+                .syntax()
+                .parent()
+                .and_then(AnyJsFunction::cast)?;
+            return is_component(&function).then_some(MutatedValue {
+                kind: ImmutableValueKind::Props,
+                declaration,
+            });
+        }
+        if let Some(arrow) = JsArrowFunctionExpression::cast_ref(&owner) {
+            return is_component(&AnyJsFunction::from(arrow)).then_some(MutatedValue {
+                kind: ImmutableValueKind::Props,
+                declaration,
+            });
+        }
+        let declarator = JsVariableDeclarator::cast(owner)?;
+        let initializer = declarator
+            .initializer()?
+            .expression()
+            .ok()?
+            .omit_parentheses();
+        if let AnyJsExpression::JsCallExpression(call) = initializer {
+            let callee = call.callee().ok()?;
+            if is_react_call_api(&callee, model, ReactLibrary::React, "useMemo") {
+                return Some(MutatedValue {
+                    kind: ImmutableValueKind::Memo,
+                    declaration,
+                });
+            }
+            if !is_react_call_api(&callee, model, ReactLibrary::React, "useState")
+                && !is_react_call_api(&callee, model, ReactLibrary::React, "useReducer")
+            {
+                return None;
+            }
+            // The state is the first element of `const [state, setState] = useState()`
+            let pattern = declarator.id().ok()?;
+            let state = pattern
+                .as_js_array_binding_pattern()?
+                .elements()
+                .first()?
+                .ok()?;
+            return identifier
+                .ancestors()
+                .any(|ancestor| ancestor == *state.syntax())
+                .then_some(MutatedValue {
+                    kind: ImmutableValueKind::State,
+                    declaration,
+                });
+        }
+        let reference = root_reference(&initializer)?;
+        binding = model.binding(&reference)?;
+    }
+    None
+}
+
+/// Returns the node that declares the binding pattern containing `identifier`:
+/// a variable declarator, a formal parameter, or the single parameter of an arrow function.
+///
+/// The rest elements of the patterns, such as `...rest`, create a new object or array,
+/// and don't have an owner.
+fn binding_pattern_owner(identifier: &JsSyntaxNode) -> Option<JsSyntaxNode> {
+    identifier.ancestors().skip(1).find(|ancestor| {
+        !matches!(
+            ancestor.kind(),
+            JsSyntaxKind::JS_ARRAY_BINDING_PATTERN
+                | JsSyntaxKind::JS_ARRAY_BINDING_PATTERN_ELEMENT
+                | JsSyntaxKind::JS_ARRAY_BINDING_PATTERN_ELEMENT_LIST
+                | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN
+                | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_PROPERTY
+                | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_PROPERTY_LIST
+                | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_SHORTHAND_PROPERTY
+        )
+    })
+}
+
+/// Returns `true` if `function` is a component, whose name starts with a capital letter
+fn is_component(function: &AnyJsFunction) -> bool {
+    let binding = function.binding().or_else(|| function.id().ok().flatten());
+    binding
+        .as_ref()
+        .and_then(|binding| binding.as_js_identifier_binding()?.name_token().ok())
+        .is_some_and(|name| is_react_component(name.text_trimmed()))
+}
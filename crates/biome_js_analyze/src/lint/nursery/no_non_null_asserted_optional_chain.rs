@@ -0,0 +1,110 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsOptionalChainExpression, JsSyntaxKind, TsNonNullAssertionExpression,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Disallow non-null assertions after an optional chain.
+    ///
+    /// An optional chain, such as `foo?.bar`, evaluates to `undefined` when `foo` is `null` or `undefined`.
+    /// Asserting with `!` that its result isn't `null` or `undefined` contradicts the optional chain:
+    /// either the chain is unnecessary, or the assertion is wrong and the code may get `undefined` at runtime.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// foo?.bar!;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// foo?.bar()!;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// (foo?.bar)!.baz;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// foo?.bar;
+    /// foo!.bar;
+    /// foo?.bar!.baz;
+    /// ```
+    ///
+    pub NoNonNullAssertedOptionalChain {
+        version: "next",
+        name: "noNonNullAssertedOptionalChain",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("no-non-null-asserted-optional-chain")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoNonNullAssertedOptionalChain {
+    type Query = Ast<TsNonNullAssertionExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let expression = node.expression().ok()?;
+        let is_parenthesized = matches!(expression, AnyJsExpression::JsParenthesizedExpression(_));
+        let chain =
+            AnyJsOptionalChainExpression::cast(expression.omit_parentheses().into_syntax())?;
+        if !chain.is_optional_chain() {
+            return None;
+        }
+        // `foo?.bar!.baz` is still short-circuited by `?.`: only the assertions that end the chain are reported
+        let continues_chain = node.syntax().parent().is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_STATIC_MEMBER_EXPRESSION
+                    | JsSyntaxKind::JS_COMPUTED_MEMBER_EXPRESSION
+                    | JsSyntaxKind::JS_CALL_EXPRESSION
+            ) && parent.first_child().as_ref() == Some(node.syntax())
+        });
+        (is_parenthesized || !continues_chain).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "This optional chain is asserted to not be "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "An optional chain evaluates to "<Emphasis>"undefined"</Emphasis>" when the value before "<Emphasis>"?."</Emphasis>" is "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>", so the assertion may be wrong at runtime."
+            })
+            .note(markup! {
+                "Remove the assertion and handle "<Emphasis>"undefined"</Emphasis>", or replace "<Emphasis>"?."</Emphasis>" with "<Emphasis>"."</Emphasis>" if the value can't be "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        mutation.remove_token(node.excl_token().ok()?);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Remove the non-null assertion." }.to_owned(),
+            mutation,
+        ))
+    }
+}
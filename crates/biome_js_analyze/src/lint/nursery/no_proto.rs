@@ -0,0 +1,242 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_syntax::static_value::StaticValue;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsMemberExpression, JsAssignmentExpression,
+    JsAssignmentOperator, JsCallExpression, JsComputedMemberAssignment, JsComputedMemberExpression,
+    JsExpressionStatement, JsNewExpression, JsStaticMemberAssignment, JsStaticMemberExpression,
+    JsSyntaxNode, JsTemplateExpression, JsUnaryExpression, JsUnaryOperator, TriviaPieceKind, T,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Disallow the use of the `__proto__` property.
+    ///
+    /// The `__proto__` accessor is deprecated, and is not available on objects that don't inherit from `Object.prototype`,
+    /// such as the objects created with `Object.create(null)`.
+    /// Use `Object.getPrototypeOf()` and `Object.setPrototypeOf()` instead.
+    ///
+    /// The `__proto__` key of object literals, such as `{ __proto__: null }`, is standard and is allowed.
+    ///
+    /// The fix replaces the reads of `__proto__` with `Object.getPrototypeOf()`,
+    /// and the assignment statements with `Object.setPrototypeOf()`.
+    /// It is unsafe: unlike the assignment, `Object.setPrototypeOf()` throws when the prototype is neither an object nor `null`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const prototype = object.__proto__;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// object["__proto__"] = prototype;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const prototype = Object.getPrototypeOf(object);
+    /// Object.setPrototypeOf(object, prototype);
+    /// const dictionary = { __proto__: null };
+    /// ```
+    ///
+    pub NoProto {
+        version: "next",
+        name: "noProto",
+        language: "js",
+        sources: &[RuleSource::Eslint("no-proto")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsProtoAccess = JsStaticMemberExpression
+        | JsComputedMemberExpression
+        | JsStaticMemberAssignment
+        | JsComputedMemberAssignment
+}
+
+impl AnyJsProtoAccess {
+    /// Returns the object whose property is accessed
+    fn object(&self) -> Option<AnyJsExpression> {
+        match self {
+            Self::JsStaticMemberExpression(member) => member.object().ok(),
+            Self::JsComputedMemberExpression(member) => member.object().ok(),
+            Self::JsStaticMemberAssignment(member) => member.object().ok(),
+            Self::JsComputedMemberAssignment(member) => member.object().ok(),
+        }
+    }
+
+    /// Returns `true` if the accessed property is `__proto__`
+    fn is_proto(&self) -> bool {
+        match self {
+            Self::JsStaticMemberExpression(member) => {
+                AnyJsMemberExpression::from(member.clone()).member_name()
+            }
+            Self::JsComputedMemberExpression(member) => {
+                AnyJsMemberExpression::from(member.clone()).member_name()
+            }
+            Self::JsStaticMemberAssignment(member) => member
+                .member()
+                .ok()
+                .and_then(|member| member.as_js_name()?.value_token().ok())
+                .map(StaticValue::String),
+            Self::JsComputedMemberAssignment(member) => member
+                .member()
+                .ok()
+                .and_then(|member| member.omit_parentheses().as_static_value()),
+        }
+        .is_some_and(|name| name.as_string_constant() == Some("__proto__"))
+    }
+}
+
+pub enum ProtoFix {
+    /// Replaces the member expression with `Object.getPrototypeOf(object)`
+    GetPrototypeOf(AnyJsExpression),
+    /// Replaces the assignment with `Object.setPrototypeOf(object, prototype)`
+    SetPrototypeOf(JsAssignmentExpression),
+}
+
+impl Rule for NoProto {
+    type Query = Ast<AnyJsProtoAccess>;
+    type State = Option<ProtoFix>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        node.is_proto().then(|| proto_fix(node))
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Avoid the use of the "<Emphasis>"__proto__"</Emphasis>" property."
+                },
+            )
+            .note(markup! {
+                "The "<Emphasis>"__proto__"</Emphasis>" accessor is deprecated, and is missing from the objects that don't inherit from "<Emphasis>"Object.prototype"</Emphasis>"."
+            })
+            .note(markup! {
+                "Use "<Emphasis>"Object.getPrototypeOf()"</Emphasis>" or "<Emphasis>"Object.setPrototypeOf()"</Emphasis>" instead."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let object = ctx.query().object()?.trim_trivia()?;
+        let mut mutation = ctx.root().begin();
+        let method = match state.as_ref()? {
+            ProtoFix::GetPrototypeOf(member) => {
+                let call = make_object_call("getPrototypeOf", [object]);
+                mutation.replace_node(member.clone(), call.into());
+                "getPrototypeOf"
+            }
+            ProtoFix::SetPrototypeOf(assignment) => {
+                let prototype = assignment.right().ok()?.trim_trivia()?;
+                let call = make_object_call("setPrototypeOf", [object, prototype]);
+                mutation.replace_node(AnyJsExpression::from(assignment.clone()), call.into());
+                "setPrototypeOf"
+            }
+        };
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>"Object."{method}"()"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the fix of `node`, if it can be rewritten without changing the evaluation of the surrounding code
+fn proto_fix(node: &AnyJsProtoAccess) -> Option<ProtoFix> {
+    if matches!(node.object()?, AnyJsExpression::JsSuperExpression(_)) {
+        return None;
+    }
+    let parent = node.syntax().parent()?;
+    match node {
+        AnyJsProtoAccess::JsStaticMemberExpression(_)
+        | AnyJsProtoAccess::JsComputedMemberExpression(_) => {
+            let member = AnyJsMemberExpression::cast_ref(node.syntax())?;
+            let is_optional_chain = match &member {
+                AnyJsMemberExpression::JsStaticMemberExpression(member) => {
+                    member.is_optional_chain()
+                }
+                AnyJsMemberExpression::JsComputedMemberExpression(member) => {
+                    member.is_optional_chain()
+                }
+            };
+            if is_optional_chain || is_callee_or_deleted(node.syntax(), &parent) {
+                return None;
+            }
+            Some(ProtoFix::GetPrototypeOf(AnyJsExpression::cast(
+                member.into_syntax(),
+            )?))
+        }
+        AnyJsProtoAccess::JsStaticMemberAssignment(_)
+        | AnyJsProtoAccess::JsComputedMemberAssignment(_) => {
+            // Only the assignment statements are fixed: `Object.setPrototypeOf()` returns the object
+            let assignment = JsAssignmentExpression::cast(parent)?;
+            if assignment.operator().ok()? != JsAssignmentOperator::Assign
+                || !JsExpressionStatement::can_cast(assignment.syntax().parent()?.kind())
+            {
+                return None;
+            }
+            Some(ProtoFix::SetPrototypeOf(assignment))
+        }
+    }
+}
+
+/// Returns `true` if `node` is called, instantiated, used as a template tag, or deleted by `parent`
+fn is_callee_or_deleted(node: &JsSyntaxNode, parent: &JsSyntaxNode) -> bool {
+    if let Some(call) = JsCallExpression::cast_ref(parent) {
+        call.callee().is_ok_and(|callee| callee.syntax() == node)
+    } else if let Some(new) = JsNewExpression::cast_ref(parent) {
+        new.callee().is_ok_and(|callee| callee.syntax() == node)
+    } else if let Some(template) = JsTemplateExpression::cast_ref(parent) {
+        template.tag().is_some_and(|tag| tag.syntax() == node)
+    } else if let Some(unary) = JsUnaryExpression::cast_ref(parent) {
+        unary.operator() == Ok(JsUnaryOperator::Delete)
+    } else {
+        false
+    }
+}
+
+/// Builds `Object.<method>(<arguments>)`
+fn make_object_call(
+    method: &str,
+    arguments: impl IntoIterator<Item = AnyJsExpression>,
+) -> JsCallExpression {
+    let callee = make::js_static_member_expression(
+        make::js_identifier_expression(make::js_reference_identifier(make::ident("Object"))).into(),
+        make::token(T![.]),
+        make::js_name(make::ident(method)).into(),
+    );
+    let arguments: Vec<_> = arguments
+        .into_iter()
+        .map(AnyJsCallArgument::AnyJsExpression)
+        .collect();
+    let separators = (1..arguments.len())
+        .map(|_| make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]));
+    make::js_call_expression(
+        callee.into(),
+        make::js_call_arguments(
+            make::token(T!['(']),
+            make::js_call_argument_list(arguments, separators),
+            make::token(T![')']),
+        ),
+    )
+    .build()
+}
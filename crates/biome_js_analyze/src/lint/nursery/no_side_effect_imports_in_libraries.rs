@@ -0,0 +1,243 @@
+use crate::services::manifest::Manifest;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding, AnyJsExpression, AnyJsImportClause,
+    AnyJsModuleItem, AnyJsStatement, JsClassDeclaration, JsFunctionDeclaration,
+    JsIdentifierBinding, JsImport, JsModule, JsSyntaxNode, JsVariableDeclarationClause,
+    JsVariableStatement, TsEnumDeclaration,
+};
+use biome_rowan::{AstNode, TextRange};
+use rustc_hash::FxHashSet;
+
+declare_lint_rule! {
+    /// Disallow the module-level side effects in the packages that declare that they don't have any.
+    ///
+    /// A library declares with `"sideEffects": false` in its `package.json` that importing its modules doesn't do anything by itself.
+    /// The bundlers rely on it to remove the modules of which no export is used, which is known as tree-shaking.
+    /// The code that runs when such a module is imported may thus never run in the applications that use the library.
+    ///
+    /// The rule reports the following statements at the top level of the modules, when the nearest `package.json` has `"sideEffects": false`:
+    /// - the imports that are only run for their side effects, such as `import "./polyfill.js"`;
+    /// - the calls, such as `register()`;
+    /// - the mutations of the globals, such as `window.app = {}`.
+    ///   The variables that aren't declared or imported by the module are considered as globals.
+    ///
+    /// The packages that declare their modules with side effects in a list, such as `"sideEffects": ["*.css"]`, aren't checked.
+    ///
+    /// ## Examples
+    ///
+    /// With the following `package.json`:
+    ///
+    /// ```json,ignore
+    /// { "name": "my-library", "sideEffects": false }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// import "./polyfill.js";
+    /// ```
+    ///
+    /// ```js,ignore
+    /// customElements.define("my-element", MyElement);
+    /// ```
+    ///
+    /// ```js,ignore
+    /// Array.prototype.last = function () { return this[this.length - 1]; };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// export function register() {
+    ///     customElements.define("my-element", MyElement);
+    /// }
+    /// ```
+    ///
+    /// ```js,ignore
+    /// function Button() {}
+    /// Button.displayName = "Button";
+    /// ```
+    ///
+    pub NoSideEffectImportsInLibraries {
+        version: "next",
+        name: "noSideEffectImportsInLibraries",
+        language: "js",
+        recommended: false,
+    }
+}
+
+pub enum SideEffect {
+    /// An import that only runs the imported module
+    Import(TextRange),
+    /// A call, or a `new` expression, whose result isn't used
+    Call(TextRange),
+    /// An assignment, or an update, of a global or of one of its members
+    GlobalMutation { range: TextRange, global: String },
+}
+
+impl Rule for NoSideEffectImportsInLibraries {
+    type Query = Manifest<JsModule>;
+    type State = SideEffect;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.is_side_effect_free() {
+            return Vec::new();
+        }
+        let module = ctx.query();
+        let bindings = top_level_bindings(module);
+        module
+            .items()
+            .into_iter()
+            .filter_map(|item| match item {
+                AnyJsModuleItem::JsImport(import) => match import.import_clause().ok()? {
+                    AnyJsImportClause::JsImportBareClause(_) => {
+                        Some(SideEffect::Import(import.range()))
+                    }
+                    _ => None,
+                },
+                AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsExpressionStatement(
+                    statement,
+                )) => side_effect(statement.expression().ok()?, &bindings),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            SideEffect::Import(range) => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This import runs the imported module, but the package declares that its modules don't have side effects."
+                },
+            ),
+            SideEffect::Call(range) => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This call runs when the module is imported, but the package declares that its modules don't have side effects."
+                },
+            ),
+            SideEffect::GlobalMutation { range, global } => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This assignment mutates the global "<Emphasis>{global}</Emphasis>" when the module is imported, but the package declares that its modules don't have side effects."
+                },
+            ),
+        };
+        Some(
+            diagnostic
+                .note(markup! {
+                    "The bundlers remove the modules of which no export is used, so this code may never run."
+                })
+                .note(markup! {
+                    "Move this code into an exported function, or list this module in the "<Emphasis>"sideEffects"</Emphasis>" field of the "<Emphasis>"package.json"</Emphasis>"."
+                }),
+        )
+    }
+}
+
+/// Returns the side effect of the expression of a top-level statement, if any
+fn side_effect(expression: AnyJsExpression, bindings: &FxHashSet<String>) -> Option<SideEffect> {
+    let expression = match expression.omit_parentheses() {
+        AnyJsExpression::JsAwaitExpression(expression) => {
+            expression.argument().ok()?.omit_parentheses()
+        }
+        expression => expression,
+    };
+    let assignment = match &expression {
+        AnyJsExpression::JsCallExpression(_) | AnyJsExpression::JsNewExpression(_) => {
+            return Some(SideEffect::Call(expression.range()));
+        }
+        AnyJsExpression::JsAssignmentExpression(expression) => match expression.left().ok()? {
+            AnyJsAssignmentPattern::AnyJsAssignment(assignment) => assignment,
+            _ => return None,
+        },
+        AnyJsExpression::JsPreUpdateExpression(expression) => expression.operand().ok()?,
+        AnyJsExpression::JsPostUpdateExpression(expression) => expression.operand().ok()?,
+        _ => return None,
+    };
+    let global = assignment_root(assignment)?;
+    (!bindings.contains(&global)).then(|| SideEffect::GlobalMutation {
+        range: expression.range(),
+        global,
+    })
+}
+
+/// Returns the name of the variable that is assigned, or of which a member is assigned,
+/// such as `Array` in `Array.prototype.last = last`
+fn assignment_root(assignment: AnyJsAssignment) -> Option<String> {
+    let mut object = match assignment {
+        AnyJsAssignment::JsIdentifierAssignment(assignment) => {
+            return Some(assignment.name_token().ok()?.text_trimmed().to_string());
+        }
+        AnyJsAssignment::JsStaticMemberAssignment(assignment) => assignment.object().ok()?,
+        AnyJsAssignment::JsComputedMemberAssignment(assignment) => assignment.object().ok()?,
+        AnyJsAssignment::JsParenthesizedAssignment(assignment) => {
+            return assignment_root(assignment.assignment().ok()?);
+        }
+        _ => return None,
+    };
+    loop {
+        object = match object.omit_parentheses() {
+            AnyJsExpression::JsIdentifierExpression(expression) => {
+                let name = expression.name().ok()?.value_token().ok()?;
+                return Some(name.text_trimmed().to_string());
+            }
+            AnyJsExpression::JsStaticMemberExpression(expression) => expression.object().ok()?,
+            AnyJsExpression::JsComputedMemberExpression(expression) => expression.object().ok()?,
+            _ => return None,
+        };
+    }
+}
+
+/// Returns the names of the variables, functions, classes, enums, and imports declared at the top level of `module`
+fn top_level_bindings(module: &JsModule) -> FxHashSet<String> {
+    let mut bindings = FxHashSet::default();
+    for item in module.items() {
+        let node = match item {
+            AnyJsModuleItem::AnyJsStatement(statement) => statement.into_syntax(),
+            AnyJsModuleItem::JsExport(export) => match export.export_clause() {
+                Ok(clause) => clause.into_syntax(),
+                Err(_) => continue,
+            },
+            AnyJsModuleItem::JsImport(import) => import.into_syntax(),
+        };
+        if JsImport::can_cast(node.kind())
+            || JsVariableStatement::can_cast(node.kind())
+            || JsVariableDeclarationClause::can_cast(node.kind())
+        {
+            bindings.extend(
+                node.descendants()
+                    .filter_map(JsIdentifierBinding::cast)
+                    .filter_map(|binding| binding.name_token().ok())
+                    .map(|name| name.text_trimmed().to_string()),
+            );
+        } else if let Some(id) = declaration_id(node) {
+            if let Some(name) = id
+                .as_js_identifier_binding()
+                .and_then(|binding| binding.name_token().ok())
+            {
+                bindings.insert(name.text_trimmed().to_string());
+            }
+        }
+    }
+    bindings
+}
+
+/// Returns the identifier of a function, class, or enum declaration
+fn declaration_id(node: JsSyntaxNode) -> Option<AnyJsBinding> {
+    if let Some(declaration) = JsFunctionDeclaration::cast_ref(&node) {
+        declaration.id().ok()
+    } else if let Some(declaration) = JsClassDeclaration::cast_ref(&node) {
+        declaration.id().ok()
+    } else {
+        TsEnumDeclaration::cast(node)?.id().ok()
+    }
+}
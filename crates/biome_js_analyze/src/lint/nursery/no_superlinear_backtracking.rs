@@ -0,0 +1,218 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::JsRegexLiteralExpression;
+use biome_rowan::{TextRange, TextSize};
+
+use crate::utils::regex_ast::{parse_regex, GroupKind, RegexFlags, Term, TermKind};
+
+declare_lint_rule! {
+    /// Disallow regular expressions that can backtrack exponentially.
+    ///
+    /// When a regular expression fails to match, the JavaScript engine tries all the ways to split the input between its quantifiers.
+    /// If a repeated group can match the same characters in several ways, the number of tries grows exponentially with the length of the input.
+    /// A short input that doesn't match can then freeze the program.
+    /// This is known as a _Regular expression Denial of Service_ (ReDoS).
+    ///
+    /// The rule uses heuristics to report the repeated groups with an unbounded quantifier that contain:
+    ///
+    /// - another unbounded quantifier that can match a whole iteration of the group, such as `(a+)+`;
+    /// - two alternatives that can match the same character, such as `(\w|\d)*`;
+    /// - two adjacent unbounded quantifiers that can match the same characters, such as `(\w+\d+)*`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /^(a+)+$/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /^(\w|\d)*$/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /^(\w+\d+)*$/;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /^a+$/;
+    /// ```
+    ///
+    /// ```js
+    /// /^(\d+,)*$/;
+    /// ```
+    ///
+    /// ```js
+    /// /^(\s|\S)*$/;
+    /// ```
+    ///
+    pub NoSuperlinearBacktracking {
+        version: "next",
+        name: "noSuperlinearBacktracking",
+        language: "js",
+        sources: &[RuleSource::EslintRegexp("no-super-linear-backtracking")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+impl Rule for NoSuperlinearBacktracking {
+    type Query = Ast<JsRegexLiteralExpression>;
+    type State = Backtracking;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut signals = Vec::new();
+        let Ok((pattern, flags)) = ctx.query().decompose() else {
+            return signals;
+        };
+        let flags = RegexFlags::from_text(flags.text());
+        let Some(disjunction) = parse_regex(pattern.text(), flags) else {
+            return signals;
+        };
+        disjunction.for_each_term(&mut |term| {
+            let TermKind::Quantified(repeated, quantifier) = &term.kind else {
+                return;
+            };
+            let TermKind::Group(group) = &repeated.kind else {
+                return;
+            };
+            if quantifier.max.is_some() || group.kind == GroupKind::Lookaround {
+                return;
+            }
+            let alternatives = &group.disjunction.alternatives;
+            for alternative in alternatives {
+                let terms = &alternative.terms;
+                let unbounded: Vec<_> = terms.iter().filter(|term| is_unbounded(term)).collect();
+                if let [nested] = unbounded.as_slice() {
+                    let others_can_match_empty = terms
+                        .iter()
+                        .filter(|term| !std::ptr::eq(*term, *nested))
+                        .all(Term::can_match_empty);
+                    if others_can_match_empty {
+                        signals.push(Backtracking {
+                            range: nested.range,
+                            kind: BacktrackingKind::NestedQuantifier,
+                        });
+                        continue;
+                    }
+                }
+                for pair in terms.windows(2) {
+                    if is_unbounded(&pair[0])
+                        && is_unbounded(&pair[1])
+                        && overlap(&pair[0], &pair[1], flags)
+                    {
+                        signals.push(Backtracking {
+                            range: pair[0].range.cover(pair[1].range),
+                            kind: BacktrackingKind::AdjacentQuantifiers,
+                        });
+                    }
+                }
+            }
+            let single_terms: Vec<_> = alternatives
+                .iter()
+                .filter_map(|alternative| match alternative.terms.as_slice() {
+                    [term] => term.char_set(flags),
+                    _ => None,
+                })
+                .collect();
+            let has_overlapping_alternatives = single_terms.iter().enumerate().any(|(i, set)| {
+                single_terms[i + 1..]
+                    .iter()
+                    .any(|other| set.intersects(other))
+            });
+            if has_overlapping_alternatives {
+                signals.push(Backtracking {
+                    range: repeated.range,
+                    kind: BacktrackingKind::OverlappingAlternatives,
+                });
+            }
+        });
+        signals
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let regex_token = ctx.query().value_token().ok()?;
+        // Add 1 to skip the opening `/`
+        let pattern_start = regex_token.text_trimmed_range().start() + TextSize::from(1);
+        let range = state.range + pattern_start;
+        let diagnostic = match state.kind {
+            BacktrackingKind::NestedQuantifier => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This quantifier is nested in a quantifier that can repeat the same characters."
+                },
+            ),
+            BacktrackingKind::OverlappingAlternatives => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The alternatives of this repeated group can match the same characters."
+                },
+            ),
+            BacktrackingKind::AdjacentQuantifiers => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "These adjacent quantifiers of a repeated group can match the same characters."
+                },
+            ),
+        };
+        let suggestion = match state.kind {
+            BacktrackingKind::NestedQuantifier => {
+                "Remove one of the quantifiers, or make every iteration of the group end with a distinct character."
+            }
+            BacktrackingKind::OverlappingAlternatives => {
+                "Make the alternatives match distinct characters, for example by merging them in a single character class."
+            }
+            BacktrackingKind::AdjacentQuantifiers => {
+                "Make the adjacent quantifiers match distinct characters."
+            }
+        };
+        Some(
+            diagnostic
+                .note(markup! {
+                    "When the input doesn't match, the number of ways to match that are tried can grow exponentially with the length of the input, and freeze the program."
+                })
+                .note(markup! { {suggestion} }),
+        )
+    }
+}
+
+pub struct Backtracking {
+    /// The range of the terms, relative to the pattern.
+    range: TextRange,
+    kind: BacktrackingKind,
+}
+
+pub enum BacktrackingKind {
+    /// `(a+)+`
+    NestedQuantifier,
+    /// `(a|a)+`
+    OverlappingAlternatives,
+    /// `(a+a+)+`
+    AdjacentQuantifiers,
+}
+
+fn is_unbounded(term: &Term) -> bool {
+    matches!(&term.kind, TermKind::Quantified(_, quantifier) if quantifier.max.is_none())
+}
+
+/// Returns `true` if the repeated characters of two quantified terms can be the same.
+fn overlap(left: &Term, right: &Term, flags: RegexFlags) -> bool {
+    let (TermKind::Quantified(left, _), TermKind::Quantified(right, _)) = (&left.kind, &right.kind)
+    else {
+        return false;
+    };
+    match (left.char_set(flags), right.char_set(flags)) {
+        (Some(left), Some(right)) => left.intersects(&right),
+        _ => false,
+    }
+}
@@ -0,0 +1,157 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::ReferencesExtensions;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, JsCatchClause, JsSyntaxKind, JsSyntaxNode,
+};
+use biome_rowan::{AstNode, WalkEvent};
+
+use crate::services::{control_flow::AnyJsControlFlowRoot, semantic::Semantic};
+
+declare_lint_rule! {
+    /// Disallow `catch` clauses that silently discard the caught error.
+    ///
+    /// A `catch` clause that neither uses the caught error nor does anything with the failure
+    /// hides the errors, which makes the bugs hard to find.
+    ///
+    /// A `catch` clause is reported when the caught error isn't referenced,
+    /// and when its block doesn't throw, return, call a function, or assign a value.
+    /// The nested functions of the block aren't taken into account.
+    ///
+    /// A `catch` clause that contains a comment is considered intentional and isn't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// try {
+    ///     load();
+    /// } catch (error) {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// for (const file of files) {
+    ///     try {
+    ///         load(file);
+    ///     } catch {
+    ///         continue;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// try {
+    ///     load();
+    /// } catch (error) {
+    ///     console.error(error);
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// function loadConfiguration() {
+    ///     try {
+    ///         return load();
+    ///     } catch {
+    ///         return DEFAULT_CONFIGURATION;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// try {
+    ///     load();
+    /// } catch {
+    ///     // The configuration is optional.
+    /// }
+    /// ```
+    ///
+    pub NoSwallowedErrors {
+        version: "next",
+        name: "noSwallowedErrors",
+        language: "js",
+        recommended: false,
+    }
+}
+
+impl Rule for NoSwallowedErrors {
+    type Query = Semantic<JsCatchClause>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let body = node.body().ok()?;
+        if body.syntax().has_comments_descendants() {
+            return None;
+        }
+        if let Some(declaration) = node.declaration() {
+            match declaration.binding().ok()? {
+                AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) => {
+                    if binding.all_references(ctx.model()).next().is_some() {
+                        return None;
+                    }
+                }
+                // A destructured error is used by the destructuring itself
+                _ => return None,
+            }
+        }
+        (!handles_failure(body.syntax())).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let catch_range = node.catch_token().ok()?.text_trimmed_range();
+        // The block of the clause can be long
+        let range = node.declaration().map_or(catch_range, |declaration| {
+            catch_range.cover(declaration.range())
+        });
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This "<Emphasis>"catch"</Emphasis>" clause silently discards the caught error."
+                },
+            )
+            .note(markup! {
+                "Swallowed errors hide the failures, which makes the bugs hard to find."
+            })
+            .note(markup! {
+                "Handle the error, rethrow it, or add a comment explaining why it can be ignored."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `body` contains a statement or an expression that can handle a failure,
+/// outside of its nested functions.
+fn handles_failure(body: &JsSyntaxNode) -> bool {
+    let mut iter = body.preorder();
+    while let Some(event) = iter.next() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        if AnyJsControlFlowRoot::can_cast(node.kind()) {
+            iter.skip_subtree();
+            continue;
+        }
+        if matches!(
+            node.kind(),
+            JsSyntaxKind::JS_THROW_STATEMENT
+                | JsSyntaxKind::JS_RETURN_STATEMENT
+                | JsSyntaxKind::JS_CALL_EXPRESSION
+                | JsSyntaxKind::JS_NEW_EXPRESSION
+                | JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION
+                | JsSyntaxKind::JS_PRE_UPDATE_EXPRESSION
+                | JsSyntaxKind::JS_POST_UPDATE_EXPRESSION
+                | JsSyntaxKind::JS_YIELD_EXPRESSION
+        ) {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,221 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsxAttributeValue, JsxAttribute, JsxExpressionChild, JsxText,
+};
+use biome_rowan::{declare_node_union, AstNode, TextRange, TextSize};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow the untranslated strings displayed to the users in JSX.
+    ///
+    /// In an application translated in several languages, the text displayed to the users
+    /// is usually passed through a translation function, such as `t("home.title")`.
+    /// A string written directly in the JSX is displayed as is, in every language.
+    ///
+    /// The rule reports:
+    /// - the JSX text, such as `<h1>Welcome</h1>`;
+    /// - the strings of the JSX expressions, such as `<h1>{"Welcome"}</h1>`;
+    /// - the strings of the attributes of the `attributes` option, such as `<input placeholder="Search" />`.
+    ///
+    /// The strings without letters, such as punctuation, numbers, or emojis,
+    /// and the strings shorter than the `minLength` option, aren't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <h1>Welcome</h1>;
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <input placeholder="Search" />;
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <button aria-label={"Close"} />;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <h1>{t("home.welcome")}</h1>;
+    /// ```
+    ///
+    /// ```jsx
+    /// <input placeholder={t("search.placeholder")} className="search" />;
+    /// ```
+    ///
+    /// ```jsx
+    /// <p>{count} / {total}</p>;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `attributes`
+    ///
+    /// The attributes whose values are displayed to the users.
+    ///
+    /// Default: `["aria-label", "placeholder", "title"]`
+    ///
+    /// ### `allowedStrings`
+    ///
+    /// The strings that don't need a translation, such as the name of the product.
+    /// The strings are compared to the text without its leading and trailing whitespace.
+    ///
+    /// ### `minLength`
+    ///
+    /// The strings with fewer characters than `minLength`, such as `x` in a close button, aren't reported.
+    ///
+    /// Default: `2`
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "attributes": ["aria-label", "alt", "placeholder", "title"],
+    ///         "allowedStrings": ["Biome", "GitHub"],
+    ///         "minLength": 3
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoTranslationLiterals {
+        version: "next",
+        name: "noTranslationLiterals",
+        language: "jsx",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noTranslationLiterals`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoTranslationLiteralsOptions {
+    /// The attributes whose values are displayed to the users.
+    pub attributes: Vec<String>,
+    /// The strings that don't need a translation, such as the name of the product.
+    pub allowed_strings: Vec<String>,
+    /// The strings with fewer characters than this length aren't reported.
+    pub min_length: u8,
+}
+
+impl Default for NoTranslationLiteralsOptions {
+    fn default() -> Self {
+        Self {
+            attributes: vec![
+                "aria-label".to_string(),
+                "placeholder".to_string(),
+                "title".to_string(),
+            ],
+            allowed_strings: Vec::new(),
+            min_length: 2,
+        }
+    }
+}
+
+declare_node_union! {
+    pub AnyJsxLiteral = JsxText | JsxExpressionChild | JsxAttribute
+}
+
+pub struct UntranslatedLiteral {
+    /// The range of the text, without its leading and trailing whitespace
+    range: TextRange,
+    /// The name of the attribute, if the string is the value of an attribute
+    attribute: Option<String>,
+}
+
+impl Rule for NoTranslationLiterals {
+    type Query = Ast<AnyJsxLiteral>;
+    type State = UntranslatedLiteral;
+    type Signals = Option<Self::State>;
+    type Options = NoTranslationLiteralsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let (text, range, attribute) = match ctx.query() {
+            AnyJsxLiteral::JsxText(text) => {
+                let token = text.value_token().ok()?;
+                (
+                    token.text_trimmed().to_string(),
+                    token.text_trimmed_range(),
+                    None,
+                )
+            }
+            AnyJsxLiteral::JsxExpressionChild(child) => {
+                let (text, range) = string_expression(&child.expression()?)?;
+                (text, range, None)
+            }
+            AnyJsxLiteral::JsxAttribute(attribute) => {
+                let name = attribute.name().ok()?.syntax().text_trimmed().to_string();
+                if !options.attributes.contains(&name) {
+                    return None;
+                }
+                let (text, range) = match attribute.initializer()?.value().ok()? {
+                    AnyJsxAttributeValue::JsxString(string) => (
+                        string.inner_string_text().ok()?.to_string(),
+                        inner_range(string.range()),
+                    ),
+                    AnyJsxAttributeValue::JsxExpressionAttributeValue(value) => {
+                        string_expression(&value.expression().ok()?)?
+                    }
+                    AnyJsxAttributeValue::AnyJsxTag(_) => return None,
+                };
+                (text, range, Some(name))
+            }
+        };
+        let trimmed = text.trim();
+        let is_translatable = trimmed.chars().any(char::is_alphabetic)
+            && trimmed.chars().count() >= usize::from(options.min_length)
+            && !options
+                .allowed_strings
+                .iter()
+                .any(|allowed| allowed == trimmed);
+        if !is_translatable {
+            return None;
+        }
+        // Report the text without the whitespace around it, that usually spans several lines
+        let leading = TextSize::of(&text[..text.len() - text.trim_start().len()]);
+        let range = TextRange::at(range.start() + leading, TextSize::of(trimmed));
+        Some(UntranslatedLiteral { range, attribute })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let title = match &state.attribute {
+            Some(attribute) => markup! {
+                "The value of the attribute "<Emphasis>{attribute}</Emphasis>" is displayed to the users without being translated."
+            }
+            .to_owned(),
+            None => markup! {
+                "This text is displayed to the users without being translated."
+            }
+            .to_owned(),
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), state.range, title).note(markup! {
+                "Pass the text through a translation function, such as "<Emphasis>"t(\"home.title\")"</Emphasis>", so that it can be displayed in the language of the users."
+            }),
+        )
+    }
+}
+
+/// Returns the text and the range of the text of `expression`, if it is a string or a template without expressions
+fn string_expression(expression: &AnyJsExpression) -> Option<(String, TextRange)> {
+    let value = expression.as_static_value()?;
+    let text = value.as_string_constant()?.to_string();
+    let range = match expression {
+        // The range of a string literal includes its quotes
+        AnyJsExpression::AnyJsLiteralExpression(_) => inner_range(value.range()),
+        _ => value.range(),
+    };
+    Some((text, range))
+}
+
+/// Returns the range of a string without its quotes
+fn inner_range(range: TextRange) -> TextRange {
+    let quote = TextSize::from(1);
+    TextRange::new(range.start() + quote, range.end() - quote)
+}
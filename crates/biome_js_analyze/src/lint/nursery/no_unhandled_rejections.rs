@@ -0,0 +1,266 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, global_identifier, AnyJsCallArgument, AnyJsExpression,
+    AnyJsMemberExpression, JsCallExpression,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow passing async functions to APIs that ignore the promises returned by their callbacks.
+    ///
+    /// Timers such as `setTimeout` and event targets such as `addEventListener` call their callback
+    /// and discard its return value.
+    /// When the callback is an async function, a rejection of the returned promise is never handled:
+    /// the error is swallowed in the best case, and crashes the process in _Node.js_.
+    ///
+    /// The rule reports async functions, and references to async functions declared in the same file,
+    /// that are passed to:
+    ///
+    /// - `setTimeout`, `setInterval`, `setImmediate`, `queueMicrotask`,
+    ///   `requestAnimationFrame`, `requestIdleCallback`, and `process.nextTick`;
+    /// - the `addEventListener` method of any object.
+    ///
+    /// Functions that wrap an async callback, such as `debounce(async () => {})`, usually forward its promise
+    /// and are reported too.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// setTimeout(async () => {
+    ///     await fetchData();
+    /// }, 1000);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function onClick() {
+    ///     await save();
+    /// }
+    /// button.addEventListener("click", onClick);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// setTimeout(() => {
+    ///     fetchData().catch(console.error);
+    /// }, 1000);
+    /// ```
+    ///
+    /// ```js
+    /// button.addEventListener("click", () => {
+    ///     void save().catch(reportError);
+    /// });
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides the option `safeWrappers`, a list of names of functions that handle the rejections
+    /// of the async callbacks they wrap.
+    /// The callbacks wrapped by these functions are never reported.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "safeWrappers": ["catchErrors"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,ignore
+    /// setTimeout(catchErrors(async () => {
+    ///     await fetchData();
+    /// }));
+    /// ```
+    ///
+    pub NoUnhandledRejections {
+        version: "next",
+        name: "noUnhandledRejections",
+        language: "js",
+        recommended: false,
+    }
+}
+
+/// Global functions that ignore the return value of their first argument.
+const CALLBACK_GLOBALS: &[&str] = &[
+    "queueMicrotask",
+    "requestAnimationFrame",
+    "requestIdleCallback",
+    "setImmediate",
+    "setInterval",
+    "setTimeout",
+];
+
+impl Rule for NoUnhandledRejections {
+    type Query = Semantic<JsCallExpression>;
+    type State = UnhandledRejection;
+    type Signals = Option<Self::State>;
+    type Options = UnhandledRejectionsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let model = ctx.model();
+        let callee = call.callee().ok()?.omit_parentheses();
+        let (api, index) = callback_api(&callee, model)?;
+        let [Some(AnyJsCallArgument::AnyJsExpression(callback))] =
+            call.arguments().ok()?.get_arguments_by_index([index])
+        else {
+            return None;
+        };
+        let function = find_async_function(&callback, model, ctx.options())?;
+        Some(UnhandledRejection {
+            api,
+            callback_range: callback.range(),
+            function_range: function,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let api = &state.api;
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.callback_range,
+            markup! {
+                "This async function is passed to "<Emphasis>{api}</Emphasis>", which ignores the promise it returns."
+            },
+        );
+        if !state.callback_range.contains_range(state.function_range) {
+            diagnostic = diagnostic.detail(
+                state.function_range,
+                markup! { "The async function is declared here." },
+            );
+        }
+        Some(
+            diagnostic
+                .note(markup! {
+                    "If the promise is rejected, the rejection is never handled."
+                })
+                .note(markup! {
+                    "Handle the errors inside the function with a "<Emphasis>"try...catch"</Emphasis>" statement, or call the async function from a synchronous callback that handles its rejection with "<Emphasis>".catch()"</Emphasis>"."
+                }),
+        )
+    }
+}
+
+pub struct UnhandledRejection {
+    /// Name of the API that receives the callback
+    api: String,
+    /// Range of the callback argument
+    callback_range: TextRange,
+    /// Range of the async function
+    function_range: TextRange,
+}
+
+/// Options for the rule `noUnhandledRejections`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UnhandledRejectionsOptions {
+    /// Names of the functions that handle the rejections of the async callbacks they wrap.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub safe_wrappers: Vec<String>,
+}
+
+/// Returns the name of the API called by `callee` and the index of its callback argument,
+/// if the API ignores the return value of this callback.
+fn callback_api(callee: &AnyJsExpression, model: &SemanticModel) -> Option<(String, usize)> {
+    if let Some(member) = AnyJsMemberExpression::cast_ref(callee.syntax()) {
+        let member_name = member.member_name()?;
+        match member_name.text() {
+            "addEventListener" => return Some(("addEventListener".to_string(), 1)),
+            "nextTick" => {
+                let object = member.object().ok()?.omit_parentheses();
+                let (reference, name) = global_identifier(&object)?;
+                if name.text() == "process" && model.binding(&reference).is_none() {
+                    return Some(("process.nextTick".to_string(), 0));
+                }
+                return None;
+            }
+            _ => {}
+        }
+    }
+    let (reference, name) = global_identifier(callee)?;
+    let name = name.text();
+    (CALLBACK_GLOBALS.contains(&name) && model.binding(&reference).is_none())
+        .then(|| (name.to_string(), 0))
+}
+
+/// Returns the range of the async function that `expression` evaluates to, if any.
+fn find_async_function(
+    expression: &AnyJsExpression,
+    model: &SemanticModel,
+    options: &UnhandledRejectionsOptions,
+) -> Option<TextRange> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsArrowFunctionExpression(function) => {
+            function.async_token().map(|_| function.range())
+        }
+        AnyJsExpression::JsFunctionExpression(function) => {
+            function.async_token().map(|_| function.range())
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let reference = identifier.name().ok()?;
+            let declaration = model.binding(&reference)?.tree().declaration()?;
+            match declaration {
+                AnyJsBindingDeclaration::JsFunctionDeclaration(function) => {
+                    function.async_token().map(|_| function.range())
+                }
+                AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+                    // The value of other variables may be reassigned
+                    if !declarator.declaration()?.is_const() {
+                        return None;
+                    }
+                    let initializer = declarator.initializer()?.expression().ok()?;
+                    let function = match initializer.omit_parentheses() {
+                        function @ (AnyJsExpression::JsArrowFunctionExpression(_)
+                        | AnyJsExpression::JsFunctionExpression(_)) => function,
+                        _ => return None,
+                    };
+                    find_async_function(&function, model, options)
+                }
+                _ => None,
+            }
+        }
+        AnyJsExpression::JsCallExpression(call) => {
+            let callee = call.callee().ok()?.omit_parentheses();
+            // `asyncFunction.bind(this)`
+            if let Some(member) = AnyJsMemberExpression::cast_ref(callee.syntax()) {
+                if member.member_name()?.text() == "bind" {
+                    let object = member.object().ok()?;
+                    return find_async_function(&object, model, options);
+                }
+            }
+            if let Some(name) = callee.get_callee_member_name() {
+                if options
+                    .safe_wrappers
+                    .iter()
+                    .any(|wrapper| wrapper == name.text_trimmed())
+                {
+                    return None;
+                }
+            }
+            // A wrapper such as `debounce(async () => {})` forwards the promise of its callback
+            call.arguments()
+                .ok()?
+                .args()
+                .iter()
+                .filter_map(|argument| argument.ok()?.as_any_js_expression().cloned())
+                .find_map(|argument| match argument.omit_parentheses() {
+                    argument @ (AnyJsExpression::JsArrowFunctionExpression(_)
+                    | AnyJsExpression::JsFunctionExpression(_)
+                    | AnyJsExpression::JsIdentifierExpression(_)) => {
+                        find_async_function(&argument, model, options)
+                    }
+                    _ => None,
+                })
+        }
+        _ => None,
+    }
+}
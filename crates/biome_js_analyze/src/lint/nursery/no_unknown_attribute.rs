@@ -0,0 +1,217 @@
+use crate::utils::markup::JsxMarkupElement;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_aria::attributes::{unknown_attributes, AttributeNaming, UnknownAttributeKind};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make::{jsx_ident, jsx_name};
+use biome_js_syntax::jsx_ext::AnyJsxElement;
+use biome_js_syntax::{AnyJsxAttributeName, JsxAttribute};
+use biome_rowan::{AstNode, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow unknown attributes on the native elements.
+    ///
+    /// The attributes of the HTML elements are checked against the HTML specification,
+    /// with the names that the framework gives to them:
+    /// React uses the names of the properties of the DOM elements, such as `className` and `tabIndex`,
+    /// while Solid uses the HTML names, such as `class` and `tabindex`.
+    /// The rule reports the attributes that don't exist, the attributes spelled with the name of
+    /// another framework or with the wrong case, and the attributes of other elements, such as `href` on a `div`.
+    ///
+    /// The `data-*` and `aria-*` attributes, the namespaced attributes such as `xlink:href`,
+    /// the props of the framework such as `key` and `ref`, and the event handlers are accepted.
+    /// The custom elements, the components, and the SVG and MathML elements aren't checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <div class="container" />
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <iframe src="/video" allowfullscreen />
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <div href="/home" />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <div className="container" data-testid="container" aria-hidden="true" onClick={toggle} />
+    /// ```
+    ///
+    /// ```jsx
+    /// <Button class="primary" />
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `framework`
+    ///
+    /// The framework that renders the elements, which determines the names of the attributes.
+    /// It's one of:
+    ///
+    /// - `"react"`, the default: the names of the properties of the DOM elements, such as `className`;
+    /// - `"preact"`: both the HTML names and the React names;
+    /// - `"solid"`: the HTML names, such as `class`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "framework": "solid"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### `ignore`
+    ///
+    /// The names of the attributes that are accepted, such as the attributes added by a library.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignore": ["css"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownAttribute {
+        version: "next",
+        name: "noUnknownAttribute",
+        language: "jsx",
+        sources: &[RuleSource::EslintReact("no-unknown-property")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoUnknownAttributeOptions {
+    /// The framework that renders the elements.
+    pub framework: JsxFramework,
+    /// The names of the attributes that are accepted.
+    pub ignore: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum JsxFramework {
+    /// The attributes are named after the properties of the DOM elements, such as `className`
+    #[default]
+    React,
+    /// The attributes have either their HTML name or their React name
+    Preact,
+    /// The attributes have their HTML name, such as `class`
+    Solid,
+}
+
+impl JsxFramework {
+    const fn naming(self) -> AttributeNaming {
+        match self {
+            Self::React => AttributeNaming::React,
+            Self::Preact => AttributeNaming::Preact,
+            Self::Solid => AttributeNaming::Solid,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::React => "React",
+            Self::Preact => "Preact",
+            Self::Solid => "Solid",
+        }
+    }
+}
+
+impl Rule for NoUnknownAttribute {
+    type Query = Ast<AnyJsxElement>;
+    type State = (JsxAttribute, UnknownAttributeKind);
+    type Signals = Vec<Self::State>;
+    type Options = NoUnknownAttributeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let element = JsxMarkupElement(ctx.query().clone());
+        unknown_attributes(&element, options.framework.naming(), &options.ignore)
+            .into_iter()
+            .map(|(attribute, kind)| (attribute.0, kind))
+            .collect()
+    }
+
+    fn diagnostic(
+        ctx: &RuleContext<Self>,
+        (attribute, kind): &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        let name = attribute.name().ok()?.text();
+        let element = ctx.query().name().ok()?.text();
+        let diagnostic = match kind {
+            UnknownAttributeKind::Unknown => RuleDiagnostic::new(
+                rule_category!(),
+                attribute.range(),
+                markup! {
+                    <Emphasis>{name}</Emphasis>" isn't an attribute of the "<Emphasis>{element}</Emphasis>" element."
+                },
+            )
+            .note(markup! {
+                "Check the spelling of the attribute, or add it to the "<Emphasis>"ignore"</Emphasis>" option if a library handles it."
+            }),
+            UnknownAttributeKind::Misnamed { expected } => {
+                let framework = ctx.options().framework.name();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    attribute.range(),
+                    markup! {
+                        "The attribute "<Emphasis>{name}</Emphasis>" is spelled "<Emphasis>{expected}</Emphasis>" in "{framework}"."
+                    },
+                )
+                .note(markup! {
+                    "The element doesn't receive an attribute that is spelled differently."
+                })
+            }
+            UnknownAttributeKind::NotOnElement { elements } => RuleDiagnostic::new(
+                rule_category!(),
+                attribute.range(),
+                markup! {
+                    <Emphasis>{name}</Emphasis>" isn't an attribute of the "<Emphasis>{element}</Emphasis>" element."
+                },
+            )
+            .note(markup! {
+                "It's only an attribute of the following elements: "{elements.join(", ")}"."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, (attribute, kind): &Self::State) -> Option<JsRuleAction> {
+        let UnknownAttributeKind::Misnamed { expected } = kind else {
+            return None;
+        };
+        let mut mutation = ctx.root().begin();
+        let name = attribute.name().ok()?;
+        mutation.replace_node(
+            name,
+            AnyJsxAttributeName::JsxName(jsx_name(jsx_ident(expected))),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Rename the attribute to "<Emphasis>{expected}</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
@@ -0,0 +1,339 @@
+use crate::services::control_flow::AnyJsControlFlowRoot;
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsMemberExpression, JsBinaryExpression, JsBinaryOperator,
+    JsConditionalExpression, JsElseClause, JsIfStatement, JsLogicalExpression, JsLogicalOperator,
+    JsSyntaxNode, JsUnaryOperator, TextRange, TsNonNullAssertionExpression,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Disallow non-null assertions on the values that a condition already checks.
+    ///
+    /// TypeScript narrows the type of a value in the branches of a condition that checks it:
+    /// in `if (user) { ... }`, `user` can't be `null` or `undefined`.
+    /// A non-null assertion with `!` on such a value does nothing, and hides the assertions that are actually needed.
+    ///
+    /// The rule reports the assertions of a variable, or of a property such as `user.address`, when they are:
+    /// - in the consequent of an `if` statement or of a conditional expression whose test checks the value,
+    ///   such as `if (user) { user!.name }` or `user != null ? user!.name : ""`;
+    /// - in the `else` branch, or the alternate, when the test checks that the value is missing,
+    ///   such as `if (!user) { ... } else { user!.name }`;
+    /// - on the right of `&&` when the left checks the value, such as `user && user!.name`,
+    ///   or on the right of `||` when the left checks that the value is missing, such as `!user || user!.name`.
+    ///
+    /// The assertions in a nested function, or after an assignment of the variable in the branch, aren't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// function getName(user: User | undefined) {
+    ///     if (user) {
+    ///         return user!.name;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const name = user && user!.name;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const city = user.address != null ? user.address!.city : "";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// function getName(user: User | undefined) {
+    ///     if (user) {
+    ///         return user.name;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// if (user) {
+    ///     items.forEach(() => user!.add());
+    /// }
+    /// ```
+    ///
+    pub NoUnnecessaryNonNullAssertion {
+        version: "next",
+        name: "noUnnecessaryNonNullAssertion",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("no-unnecessary-type-assertion")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for NoUnnecessaryNonNullAssertion {
+    type Query = Semantic<TsNonNullAssertionExpression>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let model = ctx.model();
+        let target = node.expression().ok()?.omit_parentheses();
+        if !is_reference(&target) {
+            return None;
+        }
+        let mut child = node.syntax().clone();
+        for ancestor in node.syntax().ancestors().skip(1) {
+            if AnyJsControlFlowRoot::can_cast(ancestor.kind()) {
+                return None;
+            }
+            let checking_test = match narrowing_test(&ancestor, &child) {
+                Some(Narrowing::Truthy(test)) if is_checked_by(&test, &target, model) => Some(test),
+                Some(Narrowing::Falsy(test)) if is_negated_by(&test, &target, model) => Some(test),
+                _ => None,
+            };
+            if let Some(test) = checking_test {
+                return (!is_reassigned_in(&target, &ancestor, model)).then(|| test.range());
+            }
+            child = ancestor;
+        }
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, condition: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This non-null assertion is unnecessary."
+                },
+            )
+            .detail(
+                condition,
+                markup! {
+                    "This condition already checks that the value isn't "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "TypeScript narrows the type of the value in this branch, so the assertion does nothing."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        mutation.remove_token(node.excl_token().ok()?);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Remove the non-null assertion." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// The test of a condition, in the branch where it's truthy or falsy
+enum Narrowing {
+    Truthy(AnyJsExpression),
+    Falsy(AnyJsExpression),
+}
+
+/// Returns the test of `ancestor` if it's a condition of which `child` is a branch
+fn narrowing_test(ancestor: &JsSyntaxNode, child: &JsSyntaxNode) -> Option<Narrowing> {
+    if let Some(statement) = JsIfStatement::cast_ref(ancestor) {
+        let test = statement.test().ok()?;
+        if statement.consequent().ok()?.syntax() == child {
+            return Some(Narrowing::Truthy(test));
+        }
+        return JsElseClause::can_cast(child.kind()).then_some(Narrowing::Falsy(test));
+    }
+    if let Some(expression) = JsConditionalExpression::cast_ref(ancestor) {
+        let test = expression.test().ok()?;
+        if expression.consequent().ok()?.syntax() == child {
+            return Some(Narrowing::Truthy(test));
+        }
+        return (expression.alternate().ok()?.syntax() == child).then_some(Narrowing::Falsy(test));
+    }
+    let expression = JsLogicalExpression::cast_ref(ancestor)?;
+    if expression.right().ok()?.syntax() != child {
+        return None;
+    }
+    let left = expression.left().ok()?;
+    match expression.operator().ok()? {
+        JsLogicalOperator::LogicalAnd => Some(Narrowing::Truthy(left)),
+        JsLogicalOperator::LogicalOr => Some(Narrowing::Falsy(left)),
+        JsLogicalOperator::NullishCoalescing => None,
+    }
+}
+
+/// Returns `true` if `test` is truthy only when `target` isn't `null` or `undefined`,
+/// such as `user`, `user != null`, `user.address`, or `user && user.isActive`
+fn is_checked_by(test: &AnyJsExpression, target: &AnyJsExpression, model: &SemanticModel) -> bool {
+    match test.clone().omit_parentheses() {
+        AnyJsExpression::JsLogicalExpression(expression) => {
+            expression.operator() == Ok(JsLogicalOperator::LogicalAnd)
+                && (expression
+                    .left()
+                    .is_ok_and(|left| is_checked_by(&left, target, model))
+                    || expression
+                        .right()
+                        .is_ok_and(|right| is_checked_by(&right, target, model)))
+        }
+        AnyJsExpression::JsBinaryExpression(expression) => {
+            expression.operator() == Ok(JsBinaryOperator::Inequality)
+                && is_compared_to_nullish(&expression, target, model)
+        }
+        // `user.address` is truthy only if `user` isn't `null` or `undefined`
+        mut test => loop {
+            if is_same_reference(&test, target, model) {
+                return true;
+            }
+            match AnyJsMemberExpression::cast(test.into_syntax())
+                .and_then(|member| member.object().ok())
+            {
+                Some(object) => test = object.omit_parentheses(),
+                None => return false,
+            }
+        },
+    }
+}
+
+/// Returns `true` if `test` is falsy only when `target` isn't `null` or `undefined`,
+/// such as `!user`, `user == null`, or `!user || user.isBanned`
+fn is_negated_by(test: &AnyJsExpression, target: &AnyJsExpression, model: &SemanticModel) -> bool {
+    match test.clone().omit_parentheses() {
+        AnyJsExpression::JsUnaryExpression(expression) => {
+            expression.operator() == Ok(JsUnaryOperator::LogicalNot)
+                && expression
+                    .argument()
+                    .is_ok_and(|argument| is_checked_by(&argument, target, model))
+        }
+        AnyJsExpression::JsLogicalExpression(expression) => {
+            expression.operator() == Ok(JsLogicalOperator::LogicalOr)
+                && (expression
+                    .left()
+                    .is_ok_and(|left| is_negated_by(&left, target, model))
+                    || expression
+                        .right()
+                        .is_ok_and(|right| is_negated_by(&right, target, model)))
+        }
+        AnyJsExpression::JsBinaryExpression(expression) => {
+            expression.operator() == Ok(JsBinaryOperator::Equality)
+                && is_compared_to_nullish(&expression, target, model)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if one side of a loose comparison is `target`, and the other is `null` or `undefined`
+fn is_compared_to_nullish(
+    comparison: &JsBinaryExpression,
+    target: &AnyJsExpression,
+    model: &SemanticModel,
+) -> bool {
+    let (Ok(left), Ok(right)) = (comparison.left(), comparison.right()) else {
+        return false;
+    };
+    let is_nullish = |expression: &AnyJsExpression| {
+        expression
+            .clone()
+            .omit_parentheses()
+            .as_static_value()
+            .is_some_and(|value| value.is_null_or_undefined())
+    };
+    (is_nullish(&right) && is_same_reference(&left, target, model))
+        || (is_nullish(&left) && is_same_reference(&right, target, model))
+}
+
+/// Returns `true` if the narrowing of TypeScript applies to `expression`:
+/// a variable, `this`, or a chain of properties such as `user.address.city`
+fn is_reference(expression: &AnyJsExpression) -> bool {
+    match expression {
+        AnyJsExpression::JsIdentifierExpression(_) | AnyJsExpression::JsThisExpression(_) => true,
+        AnyJsExpression::JsStaticMemberExpression(member) => member
+            .object()
+            .is_ok_and(|object| is_reference(&object.omit_parentheses())),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `left` and `right` are the same variable, or the same chain of properties
+fn is_same_reference(
+    left: &AnyJsExpression,
+    right: &AnyJsExpression,
+    model: &SemanticModel,
+) -> bool {
+    match (
+        left.clone().omit_parentheses(),
+        right.clone().omit_parentheses(),
+    ) {
+        (
+            AnyJsExpression::JsIdentifierExpression(left),
+            AnyJsExpression::JsIdentifierExpression(right),
+        ) => {
+            let (Ok(left), Ok(right)) = (left.name(), right.name()) else {
+                return false;
+            };
+            let (Ok(left_name), Ok(right_name)) = (left.value_token(), right.value_token()) else {
+                return false;
+            };
+            left_name.text_trimmed() == right_name.text_trimmed()
+                && model.binding(&left).map(|binding| binding.syntax().clone())
+                    == model
+                        .binding(&right)
+                        .map(|binding| binding.syntax().clone())
+        }
+        (AnyJsExpression::JsThisExpression(_), AnyJsExpression::JsThisExpression(_)) => true,
+        (
+            AnyJsExpression::JsStaticMemberExpression(left),
+            AnyJsExpression::JsStaticMemberExpression(right),
+        ) => {
+            let (Ok(left_member), Ok(right_member)) = (left.member(), right.member()) else {
+                return false;
+            };
+            let (Ok(left_object), Ok(right_object)) = (left.object(), right.object()) else {
+                return false;
+            };
+            left_member.syntax().text_trimmed() == right_member.syntax().text_trimmed()
+                && is_same_reference(&left_object, &right_object, model)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if the variable at the root of `target` is assigned in `condition`
+fn is_reassigned_in(
+    target: &AnyJsExpression,
+    condition: &JsSyntaxNode,
+    model: &SemanticModel,
+) -> bool {
+    let mut root = target.clone();
+    while let AnyJsExpression::JsStaticMemberExpression(member) = &root {
+        match member.object() {
+            Ok(object) => root = object.omit_parentheses(),
+            Err(_) => return true,
+        }
+    }
+    let Some(binding) = root
+        .as_js_identifier_expression()
+        .and_then(|expression| expression.name().ok())
+        .and_then(|name| model.binding(&name))
+    else {
+        return false;
+    };
+    let range = condition.text_trimmed_range();
+    binding
+        .all_writes()
+        .any(|write| range.contains_range(write.syntax().text_trimmed_range()))
+}
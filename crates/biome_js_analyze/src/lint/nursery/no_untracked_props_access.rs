@@ -0,0 +1,169 @@
+use crate::services::semantic::Semantic;
+use crate::solid::{is_component, is_solid_project, props_parameter, untracked_component};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_semantic::ReferencesExtensions;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsFunction, JsIdentifierExpression, JsInitializerClause,
+    JsStaticMemberExpression, JsVariableDeclarator,
+};
+use biome_project::PackageJson;
+use biome_rowan::{AstNode, TextRange};
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow the accesses to the props of the Solid components that lose their reactivity.
+    ///
+    /// [Solid](https://www.solidjs.com/) tracks the props of a component through the accesses to the properties of the props object.
+    /// The body of a component runs once: the values read there, or destructured from the props,
+    /// don't follow the changes of the props.
+    ///
+    /// The rule reports:
+    /// - the destructuring of the props, in the parameters or in the body of a component;
+    /// - the reads of a prop in the body of a component, outside of JSX and of the functions declared by the component.
+    ///
+    /// The components are the functions whose name starts with a capital letter.
+    /// The rule only applies to projects that declare `solid-js` in the dependencies of their `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,ignore
+    /// function Greeting({ name }) {
+    ///     return <p>Hello {name}</p>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx,ignore
+    /// function Greeting(props) {
+    ///     const name = props.name;
+    ///     return <p>Hello {name}</p>;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx,ignore
+    /// function Greeting(props) {
+    ///     return <p>Hello {props.name}</p>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx,ignore
+    /// import { createMemo } from "solid-js";
+    ///
+    /// function Greeting(props) {
+    ///     const greeting = createMemo(() => `Hello ${props.name}`);
+    ///     return <p>{greeting()}</p>;
+    /// }
+    /// ```
+    ///
+    pub NoUntrackedPropsAccess {
+        version: "next",
+        name: "noUntrackedPropsAccess",
+        language: "jsx",
+        sources: &[RuleSource::EslintSolid("no-destructure")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub enum UntrackedPropsAccess {
+    /// The props are destructured
+    Destructuring(TextRange),
+    /// A prop is read in the body of the component
+    Read { prop: String, range: TextRange },
+}
+
+impl Rule for NoUntrackedPropsAccess {
+    type Query = Semantic<AnyJsFunction>;
+    type State = UntrackedPropsAccess;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let component = ctx.query();
+        if !is_component(component) {
+            return Vec::new();
+        }
+        let manifest = ctx
+            .get_service::<Arc<Option<PackageJson>>>()
+            .and_then(|manifest| manifest.as_ref().as_ref());
+        if !is_solid_project(manifest) {
+            return Vec::new();
+        }
+        let props = match props_parameter(component) {
+            Some(AnyJsBindingPattern::JsObjectBindingPattern(pattern)) => {
+                return vec![UntrackedPropsAccess::Destructuring(pattern.range())];
+            }
+            Some(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(props))) => {
+                props
+            }
+            _ => return Vec::new(),
+        };
+        props
+            .all_reads(ctx.model())
+            .filter_map(|reference| {
+                let expression = reference
+                    .syntax()
+                    .parent()
+                    .and_then(JsIdentifierExpression::cast)?;
+                if untracked_component(expression.syntax()).as_ref() != Some(component) {
+                    return None;
+                }
+                let parent = expression.syntax().parent()?;
+                // `const { name } = props`
+                if let Some(initializer) = JsInitializerClause::cast_ref(&parent) {
+                    return match initializer.parent::<JsVariableDeclarator>()?.id().ok()? {
+                        AnyJsBindingPattern::JsObjectBindingPattern(pattern) => {
+                            Some(UntrackedPropsAccess::Destructuring(pattern.range()))
+                        }
+                        _ => None,
+                    };
+                }
+                // `props.name`
+                let member = JsStaticMemberExpression::cast(parent)?;
+                let prop = member.member().ok()?.value_token().ok()?;
+                Some(UntrackedPropsAccess::Read {
+                    prop: prop.text_trimmed().to_string(),
+                    range: member.range(),
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            UntrackedPropsAccess::Destructuring(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The props of the component are destructured."
+                },
+            )
+            .note(markup! {
+                "The destructured values are read once when the component is created, and don't follow the changes of the props."
+            })
+            .note(markup! {
+                "Access the props through the props object instead, such as "<Emphasis>"props.name"</Emphasis>", or separate them with "<Emphasis>"splitProps"</Emphasis>"."
+            }),
+            UntrackedPropsAccess::Read { prop, range } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The prop "<Emphasis>{prop}</Emphasis>" is read outside of a tracked scope."
+                },
+            )
+            .note(markup! {
+                "The body of a component runs once, so the value doesn't follow the changes of the prop."
+            })
+            .note(markup! {
+                "Read the prop in JSX, or in a function such as "<Emphasis>"createMemo"</Emphasis>" instead."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::services::semantic::Semantic;
+use crate::solid::{is_signal_getter, is_solid_project, untracked_component};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::JsCallExpression;
+use biome_project::PackageJson;
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow the calls of the Solid signals outside of the tracked scopes.
+    ///
+    /// Calling the getter of a [Solid](https://www.solidjs.com/) signal, or of a memo, reads its value.
+    /// The body of a component runs once: the values read there don't follow the changes of the signal.
+    /// Solid tracks the signals read in JSX, and in the functions passed to `createMemo` or `createEffect`.
+    ///
+    /// The rule reports the calls of the signals created by `createSignal` and `createMemo`
+    /// in the body of a component, outside of JSX and of the functions declared by the component.
+    ///
+    /// The components are the functions whose name starts with a capital letter.
+    /// The rule only applies to projects that declare `solid-js` in the dependencies of their `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,ignore
+    /// import { createSignal } from "solid-js";
+    ///
+    /// function Counter() {
+    ///     const [count, setCount] = createSignal(0);
+    ///     const double = count() * 2;
+    ///     return <p>{double}</p>;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx,ignore
+    /// import { createMemo, createSignal } from "solid-js";
+    ///
+    /// function Counter() {
+    ///     const [count, setCount] = createSignal(0);
+    ///     const double = createMemo(() => count() * 2);
+    ///     return <button onClick={() => setCount(count() + 1)}>{double()}</button>;
+    /// }
+    /// ```
+    ///
+    pub NoUntrackedSignalCalls {
+        version: "next",
+        name: "noUntrackedSignalCalls",
+        language: "jsx",
+        sources: &[RuleSource::EslintSolid("reactivity")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+impl Rule for NoUntrackedSignalCalls {
+    type Query = Semantic<JsCallExpression>;
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let callee = call.callee().ok()?.omit_parentheses();
+        let reference = callee.as_js_reference_identifier()?;
+        let manifest = ctx
+            .get_service::<Arc<Option<PackageJson>>>()
+            .and_then(|manifest| manifest.as_ref().as_ref());
+        if !is_solid_project(manifest) {
+            return None;
+        }
+        let binding = ctx.model().binding(&reference)?;
+        if !is_signal_getter(&binding, ctx.model()) {
+            return None;
+        }
+        untracked_component(call.syntax())?;
+        Some(reference.name().ok()?.text().to_string())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, signal: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The signal "<Emphasis>{signal}</Emphasis>" is called outside of a tracked scope."
+                },
+            )
+            .note(markup! {
+                "The body of a component runs once, so the value doesn't follow the changes of the signal."
+            })
+            .note(markup! {
+                "Call the signal in JSX, or in a function such as "<Emphasis>"createMemo"</Emphasis>" or "<Emphasis>"createEffect"</Emphasis>" instead."
+            }),
+        )
+    }
+}
@@ -0,0 +1,242 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    global_identifier, AnyJsBinding, AnyJsBindingPattern, AnyJsExpression, AnyJsMemberExpression,
+    AnyJsObjectBindingPatternMember, JsInitializerClause, JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, TextRange};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::services::module_exports::normalize_path;
+use crate::services::semantic::Semantic;
+use crate::utils::glob::matches_path_suffix;
+
+declare_lint_rule! {
+    /// Disallow reading the environment variables outside of the modules that validate them.
+    ///
+    /// The environment variables are strings that may be missing or malformed.
+    /// When they are read across the codebase, each module has to check them again,
+    /// and a missing variable is only noticed when the code that reads it runs.
+    /// Reading them in a single module, which parses and validates them when the application starts,
+    /// gives the rest of the code typed values and reports the invalid configurations early.
+    ///
+    /// The rule reports the reads of `process.env` and `import.meta.env`,
+    /// such as `process.env.API_URL`, `import.meta.env["API_URL"]` or `const { API_URL } = process.env`,
+    /// outside of the modules configured with the `validationModules` option.
+    /// Without this option, all the reads are reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// fetch(process.env.API_URL);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const { API_URL } = import.meta.env;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { env } from "./env";
+    ///
+    /// fetch(env.API_URL);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `validationModules`
+    ///
+    /// The globs of the paths of the modules that read and validate the environment variables.
+    /// The globs are matched against the end of the paths, so that `src/env.ts` matches this module
+    /// wherever the project is.
+    ///
+    /// ### `allow`
+    ///
+    /// The names of the environment variables that can be read everywhere, such as `NODE_ENV`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "validationModules": ["src/env.ts"],
+    ///         "allow": ["NODE_ENV"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoUnvalidatedEnvAccess {
+        version: "next",
+        name: "noUnvalidatedEnvAccess",
+        language: "js",
+        recommended: false,
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUnvalidatedEnvAccessOptions {
+    /// The globs of the paths of the modules that can read the environment variables.
+    pub validation_modules: Vec<String>,
+    /// The names of the environment variables that can be read in all the modules.
+    pub allow: Vec<String>,
+}
+
+/// A read of an environment variable
+pub struct EnvRead {
+    /// The name of the variable, or `None` if it isn't known statically
+    name: Option<String>,
+    range: TextRange,
+}
+
+impl Rule for NoUnvalidatedEnvAccess {
+    type Query = Semantic<AnyJsMemberExpression>;
+    type State = EnvRead;
+    type Signals = Vec<Self::State>;
+    type Options = NoUnvalidatedEnvAccessOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let member = ctx.query();
+        let model = ctx.model();
+        let options = ctx.options();
+        let reads = if member
+            .object()
+            .is_ok_and(|object| is_env_object(&object, model))
+        {
+            // `process.env.API_URL`
+            vec![EnvRead {
+                name: member.member_name().map(|name| name.text().to_string()),
+                range: member.range(),
+            }]
+        } else if is_env_object(&AnyJsExpression::from(member.clone()), model) {
+            // `const { API_URL } = process.env`
+            destructured_reads(member)
+        } else {
+            return Vec::new();
+        };
+        if reads.is_empty() {
+            return reads;
+        }
+        let file_path = normalize_path(ctx.file_path());
+        if options
+            .validation_modules
+            .iter()
+            .any(|glob| matches_path_suffix(glob, &file_path))
+        {
+            return Vec::new();
+        }
+        reads
+            .into_iter()
+            .filter(|read| {
+                read.name
+                    .as_ref()
+                    .map_or(true, |name| !options.allow.contains(name))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match &state.name {
+            Some(name) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The environment variable "<Emphasis>{name}</Emphasis>" is read outside of the validation modules."
+                },
+            ),
+            None => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The environment variables are read dynamically outside of the validation modules."
+                },
+            ),
+        };
+        Some(
+            diagnostic
+                .note(markup! {
+                    "The environment variables may be missing or malformed, and each read has to check them again."
+                })
+                .note(markup! {
+                    "Read the variable in a module that validates the environment, and import the validated value."
+                }),
+        )
+    }
+}
+
+/// Returns `true` if `expression` is `process.env` or `import.meta.env`
+fn is_env_object(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    let Some(member) = AnyJsMemberExpression::cast_ref(expression.omit_parentheses().syntax())
+    else {
+        return false;
+    };
+    if !member
+        .member_name()
+        .is_some_and(|name| name.text() == "env")
+    {
+        return false;
+    }
+    match member.object() {
+        Ok(AnyJsExpression::JsImportMetaExpression(_)) => true,
+        Ok(object) => {
+            global_identifier(&object.omit_parentheses()).is_some_and(|(reference, name)| {
+                name.text() == "process" && model.binding(&reference).is_none()
+            })
+        }
+        Err(_) => false,
+    }
+}
+
+/// Returns the variables read by the object pattern that `env` initializes, if any
+fn destructured_reads(env: &AnyJsMemberExpression) -> Vec<EnvRead> {
+    let Some(pattern) = env
+        .syntax()
+        .parent()
+        .and_then(JsInitializerClause::cast)
+        .and_then(|initializer| initializer.parent::<JsVariableDeclarator>())
+        .and_then(|declarator| declarator.id().ok())
+    else {
+        return Vec::new();
+    };
+    let AnyJsBindingPattern::JsObjectBindingPattern(pattern) = pattern else {
+        return Vec::new();
+    };
+    pattern
+        .properties()
+        .iter()
+        .filter_map(|property| {
+            let property = property.ok()?;
+            let name = match &property {
+                AnyJsObjectBindingPatternMember::JsObjectBindingPatternProperty(property) => {
+                    property
+                        .member()
+                        .ok()?
+                        .name()
+                        .map(|name| name.text().to_string())
+                }
+                AnyJsObjectBindingPatternMember::JsObjectBindingPatternShorthandProperty(
+                    property,
+                ) => match property.identifier().ok()? {
+                    AnyJsBinding::JsIdentifierBinding(binding) => {
+                        Some(binding.name_token().ok()?.text_trimmed().to_string())
+                    }
+                    _ => return None,
+                },
+                // `...rest` reads all the variables
+                AnyJsObjectBindingPatternMember::JsObjectBindingPatternRest(_) => None,
+                _ => return None,
+            };
+            Some(EnvRead {
+                name,
+                range: property.range(),
+            })
+        })
+        .collect()
+}
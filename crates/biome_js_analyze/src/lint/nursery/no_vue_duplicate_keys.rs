@@ -0,0 +1,211 @@
+use crate::services::semantic::Semantic;
+use crate::vue::{
+    component_options, is_define_props_call, object_keys, object_member_key,
+    option_returned_object, props_keys, AnyVuePropsDeclaration, VueKey,
+};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::{binding_ext::AnyJsIdentifierBinding, AnyJsExpression, JsFileSource};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use rustc_hash::FxHashMap;
+
+declare_lint_rule! {
+    /// Disallow the duplicate keys across the props, the data, the computed properties, and the methods of the Vue components.
+    ///
+    /// Vue exposes the props, the data, the computed properties, the methods,
+    /// and the values returned by `setup` on the same instance of the component.
+    /// When two of them share a name, only one of them is accessible.
+    ///
+    /// In `<script setup>`, the rule reports the variables of the script that have the name of a prop declared with `defineProps`,
+    /// because the template can only access one of them.
+    ///
+    /// The rule only applies to Vue files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```vue,ignore
+    /// <script>
+    /// export default {
+    ///     props: ["title"],
+    ///     computed: {
+    ///         title() {
+    ///             return "Home";
+    ///         },
+    ///     },
+    /// };
+    /// </script>
+    /// ```
+    ///
+    /// ```vue,ignore
+    /// <script setup>
+    /// import { ref } from "vue";
+    ///
+    /// defineProps(["count"]);
+    /// const count = ref(0);
+    /// </script>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```vue,ignore
+    /// <script>
+    /// export default {
+    ///     props: ["title"],
+    ///     computed: {
+    ///         pageTitle() {
+    ///             return `${this.title} - Home`;
+    ///         },
+    ///     },
+    /// };
+    /// </script>
+    /// ```
+    ///
+    pub NoVueDuplicateKeys {
+        version: "next",
+        name: "noVueDuplicateKeys",
+        language: "js",
+        sources: &[RuleSource::EslintVueJs("no-dupe-keys")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// The options of a component whose keys are exposed on its instance
+const KEYED_OPTIONS: [&str; 5] = ["props", "computed", "data", "methods", "setup"];
+
+pub struct DuplicateKey {
+    name: String,
+    range: TextRange,
+    /// The range of the first declaration of the key
+    first_range: TextRange,
+    /// `true` if the key is a variable of `<script setup>` that has the name of a prop
+    is_script_setup_variable: bool,
+}
+
+impl Rule for NoVueDuplicateKeys {
+    type Query = Semantic<AnyVuePropsDeclaration>;
+    type State = DuplicateKey;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx
+            .source_type::<JsFileSource>()
+            .as_embedding_kind()
+            .is_vue()
+        {
+            return Vec::new();
+        }
+        match ctx.query() {
+            AnyVuePropsDeclaration::JsCallExpression(call) if is_define_props_call(call) => {
+                let props = ctx.query().props();
+                ctx.model()
+                    .global_scope()
+                    .bindings()
+                    .filter_map(|binding| {
+                        let AnyJsIdentifierBinding::JsIdentifierBinding(binding) = binding.tree()
+                        else {
+                            return None;
+                        };
+                        let name = binding.name_token().ok()?;
+                        let prop = props.iter().find(|prop| prop.name == name.text_trimmed())?;
+                        Some(DuplicateKey {
+                            name: prop.name.clone(),
+                            range: name.text_trimmed_range(),
+                            first_range: prop.range,
+                            is_script_setup_variable: true,
+                        })
+                    })
+                    .collect()
+            }
+            AnyVuePropsDeclaration::JsExportDefaultExpressionClause(export) => {
+                let Some(options) = component_options(export) else {
+                    return Vec::new();
+                };
+                let mut first_ranges = FxHashMap::default();
+                let mut duplicates = Vec::new();
+                for option in options.members().iter().flatten() {
+                    let Some(option_key) = object_member_key(&option) else {
+                        continue;
+                    };
+                    let keys = match option_key.name.as_str() {
+                        "props" => option
+                            .as_js_property_object_member()
+                            .and_then(|property| property.value().ok())
+                            .map_or_else(Vec::new, |value| props_keys(&value)),
+                        "computed" | "methods" => option
+                            .as_js_property_object_member()
+                            .and_then(|property| property.value().ok())
+                            .and_then(|value| match value.omit_parentheses() {
+                                AnyJsExpression::JsObjectExpression(object) => {
+                                    Some(object_keys(&object))
+                                }
+                                _ => None,
+                            })
+                            .unwrap_or_default(),
+                        name if KEYED_OPTIONS.contains(&name) => option_returned_object(&option)
+                            .map_or_else(Vec::new, |object| object_keys(&object)),
+                        _ => continue,
+                    };
+                    for VueKey { name, range } in keys {
+                        if let Some(first_range) = first_ranges.get(&name) {
+                            duplicates.push(DuplicateKey {
+                                name,
+                                range,
+                                first_range: *first_range,
+                                is_script_setup_variable: false,
+                            });
+                        } else {
+                            first_ranges.insert(name, range);
+                        }
+                    }
+                }
+                duplicates
+            }
+            AnyVuePropsDeclaration::JsCallExpression(_) => Vec::new(),
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DuplicateKey {
+            name,
+            range,
+            first_range,
+            is_script_setup_variable,
+        } = state;
+        let diagnostic = if *is_script_setup_variable {
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The variable "<Emphasis>{name}</Emphasis>" has the name of a prop of the component."
+                },
+            )
+            .detail(first_range, markup! {
+                "The prop is declared here."
+            })
+            .note(markup! {
+                "The template can only access one of them. Rename the variable."
+            })
+        } else {
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The key "<Emphasis>{name}</Emphasis>" is declared several times in the component."
+                },
+            )
+            .detail(first_range, markup! {
+                "The key is first declared here."
+            })
+            .note(markup! {
+                "The props, the data, the computed properties, and the methods share the instance of the component, so only one of them is accessible."
+            })
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::vue::{AnyVuePropsDeclaration, VueKey};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::JsFileSource;
+
+declare_lint_rule! {
+    /// Disallow the reserved names as props of the Vue components.
+    ///
+    /// Vue uses the attributes `key` and `ref` itself: a prop with one of these names
+    /// never receives the value passed by the parent component.
+    ///
+    /// The rule checks the props declared with `defineProps`, and the `props` option
+    /// of the component exported by `export default`. It only applies to Vue files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```vue,ignore
+    /// <script setup>
+    /// defineProps(["key", "title"]);
+    /// </script>
+    /// ```
+    ///
+    /// ```vue,ignore
+    /// <script>
+    /// export default {
+    ///     props: {
+    ///         ref: String,
+    ///     },
+    /// };
+    /// </script>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```vue,ignore
+    /// <script setup>
+    /// defineProps(["itemKey", "title"]);
+    /// </script>
+    /// ```
+    ///
+    pub NoVueReservedProps {
+        version: "next",
+        name: "noVueReservedProps",
+        language: "js",
+        sources: &[RuleSource::EslintVueJs("no-reserved-props")],
+        recommended: false,
+    }
+}
+
+/// The names of the attributes that Vue doesn't pass to the components as props
+const RESERVED_PROPS: [&str; 2] = ["key", "ref"];
+
+impl Rule for NoVueReservedProps {
+    type Query = Ast<AnyVuePropsDeclaration>;
+    type State = VueKey;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx
+            .source_type::<JsFileSource>()
+            .as_embedding_kind()
+            .is_vue()
+        {
+            return Vec::new();
+        }
+        ctx.query()
+            .props()
+            .into_iter()
+            .filter(|prop| RESERVED_PROPS.contains(&prop.name.as_str()))
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, prop: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                prop.range,
+                markup! {
+                    "The name "<Emphasis>{prop.name}</Emphasis>" is reserved by Vue and can't be used as a prop."
+                },
+            )
+            .note(markup! {
+                "Vue uses the attribute "<Emphasis>{prop.name}</Emphasis>" itself, so the prop never receives the value passed by the parent component."
+            })
+            .note(markup! {
+                "Rename the prop."
+            }),
+        )
+    }
+}
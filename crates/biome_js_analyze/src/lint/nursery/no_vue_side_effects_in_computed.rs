@@ -0,0 +1,303 @@
+use crate::services::semantic::Semantic;
+use crate::vue::{component_options, is_vue_import, object_member_key};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_semantic::{Binding, SemanticModel};
+use biome_js_syntax::{
+    AnyFunctionLike, AnyJsAssignment, AnyJsAssignmentPattern, AnyJsExpression, AnyJsObjectMember,
+    JsAssignmentExpression, JsCallArgumentList, JsCallArguments, JsCallExpression,
+    JsExportDefaultExpressionClause, JsFileSource, JsObjectExpression, JsObjectMemberList,
+    JsPostUpdateExpression, JsPreUpdateExpression, JsPropertyObjectMember, JsSyntaxNode,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, TextRange};
+
+declare_lint_rule! {
+    /// Disallow the side effects in the computed properties of Vue.
+    ///
+    /// Vue caches the value of a computed property, and evaluates it again only when one of its dependencies changes.
+    /// A computed property that changes the state of the component, or mutates an array,
+    /// runs these side effects at unpredictable times, or not at all.
+    ///
+    /// The rule reports the assignments and the mutating array methods, such as `push` or `sort`,
+    /// that change a value declared outside the getter of a computed property:
+    /// the getters passed to `computed` imported from `vue`, and the members of the `computed` option of a component.
+    ///
+    /// The rule only applies to Vue files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```vue,ignore
+    /// <script setup>
+    /// import { computed, ref } from "vue";
+    ///
+    /// const items = ref([3, 1, 2]);
+    /// const sortedItems = computed(() => items.value.sort());
+    /// </script>
+    /// ```
+    ///
+    /// ```vue,ignore
+    /// <script>
+    /// export default {
+    ///     computed: {
+    ///         fullName() {
+    ///             this.firstName = "John";
+    ///             return `${this.firstName} ${this.lastName}`;
+    ///         },
+    ///     },
+    /// };
+    /// </script>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```vue,ignore
+    /// <script setup>
+    /// import { computed, ref } from "vue";
+    ///
+    /// const items = ref([3, 1, 2]);
+    /// const sortedItems = computed(() => [...items.value].sort());
+    /// </script>
+    /// ```
+    ///
+    pub NoVueSideEffectsInComputed {
+        version: "next",
+        name: "noVueSideEffectsInComputed",
+        language: "js",
+        sources: &[RuleSource::EslintVueJs("no-side-effects-in-computed-properties")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsMutation = JsAssignmentExpression | JsPostUpdateExpression | JsPreUpdateExpression | JsCallExpression
+}
+
+/// The methods that mutate the array they are called on
+const MUTATING_ARRAY_METHODS: [&str; 9] = [
+    "copyWithin",
+    "fill",
+    "pop",
+    "push",
+    "reverse",
+    "shift",
+    "sort",
+    "splice",
+    "unshift",
+];
+
+impl Rule for NoVueSideEffectsInComputed {
+    type Query = Semantic<AnyJsMutation>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx
+            .source_type::<JsFileSource>()
+            .as_embedding_kind()
+            .is_vue()
+        {
+            return None;
+        }
+        let mutation = ctx.query();
+        let model = ctx.model();
+        // Only the statements of the getter itself run when the value is computed
+        let getter = mutation
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find_map(AnyFunctionLike::cast)?;
+        if !is_computed_getter(&getter, model) {
+            return None;
+        }
+        let getter_range = getter.range();
+        let is_side_effect = match mutation {
+            AnyJsMutation::JsAssignmentExpression(assignment) => match assignment.left().ok()? {
+                AnyJsAssignmentPattern::AnyJsAssignment(target) => {
+                    is_outer_assignment(&target, model, getter_range)
+                }
+                _ => false,
+            },
+            AnyJsMutation::JsPostUpdateExpression(update) => {
+                is_outer_assignment(&update.operand().ok()?, model, getter_range)
+            }
+            AnyJsMutation::JsPreUpdateExpression(update) => {
+                is_outer_assignment(&update.operand().ok()?, model, getter_range)
+            }
+            AnyJsMutation::JsCallExpression(call) => {
+                let callee = call.callee().ok()?.omit_parentheses();
+                let callee = callee.as_js_static_member_expression()?;
+                let method = callee.member().ok()?.value_token().ok()?;
+                MUTATING_ARRAY_METHODS.contains(&method.text_trimmed())
+                    && is_outer_value(&callee.object().ok()?, model, getter_range)
+            }
+        };
+        is_side_effect.then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Unexpected side effect in a computed property."
+                },
+            )
+            .note(markup! {
+                "Vue evaluates a computed property only when one of its dependencies changes, so the side effect runs at unpredictable times."
+            })
+            .note(markup! {
+                "Compute the value without changing the state, or move the side effect to a watcher or a method."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `target` is, or is a property of, a value declared outside the getter
+fn is_outer_assignment(target: &AnyJsAssignment, model: &SemanticModel, getter: TextRange) -> bool {
+    match target {
+        AnyJsAssignment::JsIdentifierAssignment(identifier) => {
+            is_outer_binding(model.binding(identifier), getter)
+        }
+        AnyJsAssignment::JsStaticMemberAssignment(member) => member
+            .object()
+            .is_ok_and(|object| is_outer_value(&object, model, getter)),
+        AnyJsAssignment::JsComputedMemberAssignment(member) => member
+            .object()
+            .is_ok_and(|object| is_outer_value(&object, model, getter)),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `expression` is `this`, a variable declared outside the getter, or one of their properties
+fn is_outer_value(expression: &AnyJsExpression, model: &SemanticModel, getter: TextRange) -> bool {
+    let mut expression = expression.clone();
+    loop {
+        let object = match expression.omit_parentheses() {
+            AnyJsExpression::JsStaticMemberExpression(member) => member.object(),
+            AnyJsExpression::JsComputedMemberExpression(member) => member.object(),
+            AnyJsExpression::JsThisExpression(_) => return true,
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                return identifier
+                    .name()
+                    .is_ok_and(|reference| is_outer_binding(model.binding(&reference), getter))
+            }
+            _ => return false,
+        };
+        let Ok(object) = object else {
+            return false;
+        };
+        expression = object;
+    }
+}
+
+/// Returns `true` if `binding` is a global variable, or a variable declared outside the getter
+fn is_outer_binding(binding: Option<Binding>, getter: TextRange) -> bool {
+    binding.map_or(true, |binding| {
+        !getter.contains_range(binding.syntax().text_trimmed_range())
+    })
+}
+
+/// Returns `true` if `function` is the getter of a computed property
+fn is_computed_getter(function: &AnyFunctionLike, model: &SemanticModel) -> bool {
+    match function {
+        AnyFunctionLike::AnyJsFunction(function) => {
+            // `computed(() => count.value * 2)`
+            is_computed_call_argument(function.syntax(), model)
+                // `computed: { double: function () { return this.count * 2 } }`
+                || function
+                    .parent::<JsPropertyObjectMember>()
+                    .is_some_and(|property| is_computed_member(&property.into(), model))
+        }
+        AnyFunctionLike::JsMethodObjectMember(method) => {
+            is_computed_member(&method.clone().into(), model)
+        }
+        AnyFunctionLike::JsMethodClassMember(_) => false,
+    }
+}
+
+/// Returns `true` if `member` is a member of the `computed` option of a component,
+/// or the `get` member of the object that declares a computed property with a getter and a setter
+fn is_computed_member(member: &AnyJsObjectMember, model: &SemanticModel) -> bool {
+    let Some(object) = parent_object(member.syntax()) else {
+        return false;
+    };
+    if is_computed_option(&object) {
+        return true;
+    }
+    if !object_member_key(member).is_some_and(|key| key.name == "get") {
+        return false;
+    }
+    // `computed({ get() { ... }, set(value) { ... } })`
+    is_computed_call_argument(object.syntax(), model)
+        // `computed: { double: { get() { ... }, set(value) { ... } } }`
+        || object
+            .parent::<JsPropertyObjectMember>()
+            .and_then(|property| parent_object(property.syntax()))
+            .is_some_and(|object| is_computed_option(&object))
+}
+
+/// Returns `true` if `object` is the value of the `computed` option of the component exported by `export default`
+fn is_computed_option(object: &JsObjectExpression) -> bool {
+    let Some(property) = object.parent::<JsPropertyObjectMember>() else {
+        return false;
+    };
+    let is_computed = property
+        .name()
+        .ok()
+        .and_then(|name| name.name())
+        .is_some_and(|name| name.text() == "computed");
+    if !is_computed {
+        return false;
+    }
+    let Some(options) = parent_object(property.syntax()) else {
+        return false;
+    };
+    options
+        .syntax()
+        .ancestors()
+        .find_map(JsExportDefaultExpressionClause::cast)
+        .and_then(|export| component_options(&export))
+        .is_some_and(|component| component == options)
+}
+
+/// Returns the object that contains the member `member`
+fn parent_object(member: &JsSyntaxNode) -> Option<JsObjectExpression> {
+    member
+        .parent()
+        .and_then(JsObjectMemberList::cast)?
+        .parent::<JsObjectExpression>()
+}
+
+/// Returns `true` if `node` is the first argument of a call to `computed` imported from `vue`
+fn is_computed_call_argument(node: &JsSyntaxNode, model: &SemanticModel) -> bool {
+    let Some(call) = node
+        .parent()
+        .and_then(JsCallArgumentList::cast)
+        .and_then(|arguments| arguments.parent::<JsCallArguments>())
+        .and_then(|arguments| arguments.parent::<JsCallExpression>())
+    else {
+        return false;
+    };
+    let is_first_argument = call.arguments().is_ok_and(|arguments| {
+        arguments
+            .args()
+            .iter()
+            .next()
+            .and_then(|argument| argument.ok())
+            .is_some_and(|argument| argument.syntax() == node)
+    });
+    is_first_argument
+        && call
+            .callee()
+            .ok()
+            .and_then(|callee| callee.omit_parentheses().as_js_reference_identifier())
+            .and_then(|reference| model.binding(&reference))
+            .is_some_and(|binding| is_vue_import(&binding, "computed"))
+}
@@ -0,0 +1,183 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, JsBinaryOperator, JsComputedMemberExpression,
+    JsUnaryOperator, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+use crate::utils::is_node_equal;
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Use `at()` instead of computing an index from the length to access the last elements.
+    ///
+    /// `array.at(-1)` reads the last element of an array or a string without repeating the object,
+    /// which `array[array.length - 1]` requires.
+    ///
+    /// The rule reports the accesses such as `object[object.length - 2]`,
+    /// when `object` is an identifier, `this`, or a chain of properties of them.
+    /// The assignments, such as `array[array.length - 1] = value`, and the method calls aren't reported.
+    ///
+    /// The fix is unsafe: the objects that aren't arrays, strings or typed arrays, such as `arguments` or a `NodeList`,
+    /// don't have an `at()` method.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const last = array[array.length - 1];
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const penultimate = this.items[this.items.length - 2];
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const last = array.at(-1);
+    /// ```
+    ///
+    /// ```js
+    /// array[array.length - 1] = value;
+    /// ```
+    ///
+    /// ```js
+    /// const last = other[array.length - 1];
+    /// ```
+    ///
+    pub UseAtIndex {
+        version: "next",
+        name: "useAtIndex",
+        language: "js",
+        sources: &[RuleSource::EslintUnicorn("prefer-at")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseAtIndex {
+    type Query = Ast<JsComputedMemberExpression>;
+    type State = AnyJsExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let member = ctx.query();
+        // `object?.[object.length - 1]` is `undefined` when `object` is nullish,
+        // while `object.length` throws
+        if member.is_optional_chain() {
+            return None;
+        }
+        // `object[object.length - 1]()` calls the method with `object` as `this`,
+        // and `delete object[object.length - 1]` removes the element
+        match AnyJsExpression::cast(member.syntax().parent()?) {
+            Some(
+                AnyJsExpression::JsCallExpression(_) | AnyJsExpression::JsTemplateExpression(_),
+            ) => return None,
+            Some(AnyJsExpression::JsUnaryExpression(unary))
+                if unary.operator().ok()? == JsUnaryOperator::Delete =>
+            {
+                return None
+            }
+            _ => {}
+        }
+        let object = member.object().ok()?;
+        if !is_simple_reference(&object) {
+            return None;
+        }
+        let index = member.member().ok()?.omit_parentheses();
+        let index = index.as_js_binary_expression()?;
+        if index.operator().ok()? != JsBinaryOperator::Minus {
+            return None;
+        }
+        let length = index.left().ok()?.omit_parentheses();
+        let length = length.as_js_static_member_expression()?;
+        if length.is_optional_chain()
+            || length
+                .member()
+                .ok()?
+                .as_js_name()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                != "length"
+            || !is_node_equal(length.object().ok()?.syntax(), object.syntax())
+        {
+            return None;
+        }
+        let offset = index.right().ok()?.omit_parentheses();
+        let literal = offset
+            .as_any_js_literal_expression()?
+            .as_js_number_literal_expression()?;
+        let value = literal.as_number()?;
+        (value >= 1.0 && value.fract() == 0.0).then_some(offset)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use "<Emphasis>"at()"</Emphasis>" instead of computing the index from the length."
+                },
+            )
+            .note(markup! {
+                "A negative index passed to "<Emphasis>"at()"</Emphasis>" counts back from the end, without repeating the object."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, offset: &Self::State) -> Option<JsRuleAction> {
+        let member = ctx.query();
+        let object = member.object().ok()?;
+        let index = make::js_unary_expression(make::token(T![-]), offset.clone().trim_trivia()?);
+        let at = make::js_call_expression(
+            make::js_static_member_expression(
+                object.trim_trivia()?,
+                make::token(T![.]),
+                make::js_name(make::ident("at")).into(),
+            )
+            .into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list([AnyJsCallArgument::AnyJsExpression(index.into())], []),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::from(member.clone()),
+            AnyJsExpression::from(at),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>"at()"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `expression` is an identifier, `this`, or a chain of static members of them,
+/// which can be evaluated twice without side effects
+fn is_simple_reference(expression: &AnyJsExpression) -> bool {
+    match expression {
+        AnyJsExpression::JsIdentifierExpression(_) | AnyJsExpression::JsThisExpression(_) => true,
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            !member.is_optional_chain()
+                && member
+                    .object()
+                    .is_ok_and(|object| is_simple_reference(&object))
+        }
+        _ => false,
+    }
+}
@@ -3,12 +3,19 @@ use biome_analyze::{
     RuleSource, RuleSourceKind,
 };
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
 use biome_js_factory::make;
 use biome_js_syntax::{
     AnyJsExpression, AnyJsLiteralExpression, AnyJsxAttributeValue, AnyJsxChild, JsSyntaxKind,
-    JsSyntaxToken, JsxAttributeInitializerClause, JsxChildList, JsxExpressionAttributeValue, T,
+    JsSyntaxToken, JsxAttributeInitializerClause, JsxChildList, JsxExpressionAttributeValue,
+    JsxString, JsxText, T,
 };
-use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, TextRange, TriviaPiece};
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, BatchMutationExt, TextRange, TextSize, TriviaPiece,
+};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use crate::JsRuleAction;
 
@@ -18,6 +25,10 @@ declare_lint_rule! {
     /// For situations where JSX expressions are unnecessary, please refer to [the React doc](https://facebook.github.io/react/docs/jsx-in-depth.html) and [this page about JSX gotchas](https://github.com/facebook/react/blob/v15.4.0-rc.3/docs/docs/02.3-jsx-gotchas.md#html-entities).
     ///
     /// This rule will check for and warn about unnecessary curly braces in both JSX props and children.
+    /// With the options, it can require the curly braces around the strings instead.
+    ///
+    /// The strings that can't be written in both styles are ignored,
+    /// such as the strings with escape sequences or HTML entities.
     ///
     /// ## Examples
     ///
@@ -44,6 +55,32 @@ declare_lint_rule! {
     /// </>
     /// ```
     ///
+    /// ## Options
+    ///
+    /// Each option sets the style of the strings in a position:
+    /// - `"never"` (default): the strings aren't wrapped in curly braces, such as `foo="bar"` and `<Foo>Hello</Foo>`;
+    /// - `"always"`: the strings are wrapped in curly braces, such as `foo={"bar"}` and `<Foo>{"Hello"}</Foo>`;
+    /// - `"ignore"`: both styles are allowed.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "props": "always",
+    ///         "children": "never"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### `props`
+    ///
+    /// The style of the string values of the JSX attributes.
+    ///
+    /// ### `children`
+    ///
+    /// The style of the text of the JSX children.
+    /// With `"always"`, the text that spans several lines is ignored, because JSX collapses its line breaks.
+    ///
     pub UseConsistentCurlyBraces {
         version: "next",
         name: "useConsistentCurlyBraces",
@@ -51,10 +88,34 @@ declare_lint_rule! {
         recommended: false,
         sources: &[RuleSource::EslintReact("jsx-curly-brace-presence")],
         source_kind: RuleSourceKind::Inspired,
-        fix_kind: FixKind::Unsafe,
+        fix_kind: FixKind::Safe,
     }
 }
 
+/// Options for the rule `useConsistentCurlyBraces`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct ConsistentCurlyBracesOptions {
+    /// The style of the string values of the JSX attributes
+    pub props: CurlyBracesPresence,
+    /// The style of the text of the JSX children
+    pub children: CurlyBracesPresence,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum CurlyBracesPresence {
+    /// The strings are wrapped in curly braces: `foo={"bar"}`, `<Foo>{"Hello"}</Foo>`
+    Always,
+    /// The strings aren't wrapped in curly braces: `foo="bar"`, `<Foo>Hello</Foo>`
+    #[default]
+    Never,
+    /// Both styles are allowed
+    Ignore,
+}
+
 declare_node_union! {
     pub AnyJsxCurlyQuery = JsxAttributeInitializerClause | AnyJsxChild
 }
@@ -66,6 +127,9 @@ impl AnyJsxCurlyQuery {
             AnyJsxCurlyQuery::JsxAttributeInitializerClause(node) => {
                 node.value().map(|value| value.range())
             }
+            AnyJsxCurlyQuery::AnyJsxChild(AnyJsxChild::JsxText(text)) => {
+                text.value_token().map(|token| trimmed_text_range(&token))
+            }
             AnyJsxCurlyQuery::AnyJsxChild(_) => Ok(self.range()),
         }
         .unwrap_or(self.range())
@@ -84,16 +148,19 @@ impl Rule for UseConsistentCurlyBraces {
     type Query = Ast<AnyJsxCurlyQuery>;
     type State = CurlyBraceResolution;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = ConsistentCurlyBracesOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let query = ctx.query();
+        let options = ctx.options();
         let has_curly_braces = has_curly_braces(query);
         match query {
             AnyJsxCurlyQuery::JsxAttributeInitializerClause(attr) => {
-                handle_attr_init_clause(attr, has_curly_braces)
+                handle_attr_init_clause(attr, has_curly_braces, options.props)
+            }
+            AnyJsxCurlyQuery::AnyJsxChild(child) => {
+                handle_jsx_child(child, has_curly_braces, options.children)
             }
-            AnyJsxCurlyQuery::AnyJsxChild(child) => handle_jsx_child(child, has_curly_braces),
         }
     }
 
@@ -102,15 +169,21 @@ impl Rule for UseConsistentCurlyBraces {
         let source_range = node.source_range();
 
         let diag = match (state, node) {
-            (CurlyBraceResolution::AddBraces, AnyJsxCurlyQuery::JsxAttributeInitializerClause(_)) => RuleDiagnostic::new(
+            (CurlyBraceResolution::AddBraces, AnyJsxCurlyQuery::JsxAttributeInitializerClause(attr)) => RuleDiagnostic::new(
                 rule_category!(),
                 source_range,
                 markup! {
                     "Should have curly braces around expression."
                 },
             )
-            .note(markup! {
-                "JSX attribute value should be wrapped in curly braces. This will make the JSX attribute value more readable."
+            .note(if matches!(attr.value(), Ok(AnyJsxAttributeValue::JsxString(_))) {
+                markup! {
+                    "The "<Emphasis>"props"</Emphasis>" option requires the string values of the JSX attributes to be wrapped in curly braces."
+                }
+            } else {
+                markup! {
+                    "JSX attribute value should be wrapped in curly braces. This will make the JSX attribute value more readable."
+                }
             }),
             (CurlyBraceResolution::AddBraces, AnyJsxCurlyQuery::AnyJsxChild(_)) => RuleDiagnostic::new(
                 rule_category!(),
@@ -174,11 +247,19 @@ impl Rule for UseConsistentCurlyBraces {
                             Ok(AnyJsxAttributeValue::JsxExpressionAttributeValue(node))
                         }
                         AnyJsxAttributeValue::JsxString(node) => {
+                            // The quotes are kept, JSX strings and string literals use the same ones
+                            let value_token = node.value_token()?;
+                            let string_literal = JsSyntaxToken::new_detached(
+                                JsSyntaxKind::JS_STRING_LITERAL,
+                                value_token.text_trimmed(),
+                                [],
+                                [],
+                            );
                             let value = make::jsx_expression_attribute_value(
                                 make::token(T!['{']),
                                 AnyJsExpression::AnyJsLiteralExpression(
                                     AnyJsLiteralExpression::JsStringLiteralExpression(
-                                        make::js_string_literal_expression(node.value_token()?),
+                                        make::js_string_literal_expression(string_literal),
                                     ),
                                 ),
                                 make::token(T!['}']),
@@ -192,9 +273,46 @@ impl Rule for UseConsistentCurlyBraces {
                     make::jsx_attribute_initializer_clause(make::token(T![=]), value),
                 );
             }
-            (CurlyBraceResolution::AddBraces, AnyJsxCurlyQuery::AnyJsxChild(_)) => {
-                // this should never get hit
-                return None;
+            (CurlyBraceResolution::AddBraces, AnyJsxCurlyQuery::AnyJsxChild(node)) => {
+                let AnyJsxChild::JsxText(text) = node else {
+                    return None;
+                };
+                let value_token = text.value_token().ok()?;
+                let content = value_token.text_trimmed();
+                let trimmed = content.trim();
+                let leading = &content[..content.len() - content.trim_start().len()];
+                let trailing = &content[content.trim_end().len()..];
+
+                // The whitespace around the text stays out of the curly braces, so that JSX handles it as before
+                let mut children = vec![];
+                if !leading.is_empty() {
+                    children.push(jsx_text_child(leading));
+                }
+                let string_literal = if trimmed.contains('"') {
+                    make::js_string_literal_single_quotes(trimmed)
+                } else {
+                    make::js_string_literal(trimmed)
+                };
+                children.push(AnyJsxChild::JsxExpressionChild(
+                    make::jsx_expression_child(make::token(T!['{']), make::token(T!['}']))
+                        .with_expression(AnyJsExpression::AnyJsLiteralExpression(
+                            AnyJsLiteralExpression::JsStringLiteralExpression(
+                                make::js_string_literal_expression(string_literal),
+                            ),
+                        ))
+                        .build(),
+                ));
+                if !trailing.is_empty() {
+                    children.push(jsx_text_child(trailing));
+                }
+
+                let child_list = node.parent::<JsxChildList>()?;
+                mutation.replace_element_discard_trivia(
+                    child_list.clone().into_syntax().into(),
+                    replace_child(&child_list, node, children)
+                        .into_syntax()
+                        .into(),
+                );
             }
             (
                 CurlyBraceResolution::RemoveBraces,
@@ -216,7 +334,13 @@ impl Rule for UseConsistentCurlyBraces {
                         None
                     }
                 })?;
-                let jsx_string = make::jsx_string(str_literal.value_token().ok()?);
+                // The quotes are kept, JSX strings and string literals use the same ones
+                let jsx_string = make::jsx_string(JsSyntaxToken::new_detached(
+                    JsSyntaxKind::JSX_STRING_LITERAL,
+                    str_literal.value_token().ok()?.text_trimmed(),
+                    [],
+                    [],
+                ));
                 let value = AnyJsxAttributeValue::JsxString(jsx_string);
                 mutation.replace_node(
                     node.clone(),
@@ -254,30 +378,19 @@ impl Rule for UseConsistentCurlyBraces {
                     let leading_comments_expr = build_comment_expression_child(&leading_comments);
                     let trailing_comments_expr = build_comment_expression_child(&trailing_comments);
 
-                    let text = &str_literal.value_token().ok()?.token_text_trimmed();
-                    // trim the quotes off of the string literal
-                    let text_trimmed = text.clone().slice(TextRange::new(
-                        1.into(),
-                        text.len().checked_sub(1.into()).unwrap_or(text.len()),
-                    ));
-                    let jsx_text =
-                        AnyJsxChild::JsxText(make::jsx_text(JsSyntaxToken::new_detached(
-                            JsSyntaxKind::JS_STRING_LITERAL,
-                            &format!("{text_trimmed}"),
-                            [],
-                            [],
-                        )));
+                    let text = str_literal.inner_string_text().ok()?;
+                    let jsx_text = jsx_text_child(text.text());
 
                     let child_list = node.parent::<JsxChildList>()?;
                     let mut children = vec![];
                     if let Some(leading_comments_expr) = leading_comments_expr {
                         children.push(leading_comments_expr);
                     }
-                    children.push(jsx_text.clone());
+                    children.push(jsx_text);
                     if let Some(trailing_comments_expr) = trailing_comments_expr {
                         children.push(trailing_comments_expr);
                     }
-                    let new_child_list = make::jsx_child_list(children);
+                    let new_child_list = replace_child(&child_list, node, children);
 
                     mutation.replace_element_discard_trivia(
                         child_list.clone().into_syntax().into(),
@@ -327,16 +440,47 @@ fn build_comment_expression_child(
     ))
 }
 
+fn jsx_text_child(text: &str) -> AnyJsxChild {
+    AnyJsxChild::JsxText(make::jsx_text(JsSyntaxToken::new_detached(
+        JsSyntaxKind::JSX_TEXT_LITERAL,
+        text,
+        [],
+        [],
+    )))
+}
+
+/// Returns the list `child_list` where `child` is replaced with `replacement`, so that its siblings are kept
+fn replace_child(
+    child_list: &JsxChildList,
+    child: &AnyJsxChild,
+    replacement: Vec<AnyJsxChild>,
+) -> JsxChildList {
+    let mut children = vec![];
+    let mut replacement = Some(replacement);
+    for sibling in child_list.iter() {
+        if &sibling == child {
+            children.extend(replacement.take().into_iter().flatten());
+        } else {
+            children.push(sibling);
+        }
+    }
+    make::jsx_child_list(children)
+}
+
 fn handle_attr_init_clause(
     attr: &JsxAttributeInitializerClause,
     has_curly_braces: bool,
+    presence: CurlyBracesPresence,
 ) -> Option<CurlyBraceResolution> {
     let node = attr.value().ok()?;
 
     match node {
         AnyJsxAttributeValue::AnyJsxTag(_) => Some(CurlyBraceResolution::AddBraces),
         AnyJsxAttributeValue::JsxExpressionAttributeValue(node) => {
-            if has_curly_braces && contains_string_literal(&node) {
+            if has_curly_braces
+                && presence == CurlyBracesPresence::Never
+                && contains_string_literal(&node, can_be_jsx_string)
+            {
                 Some(CurlyBraceResolution::RemoveBraces)
             } else if !has_curly_braces && contains_jsx_tag(&node) {
                 Some(CurlyBraceResolution::AddBraces)
@@ -344,25 +488,32 @@ fn handle_attr_init_clause(
                 None
             }
         }
-        AnyJsxAttributeValue::JsxString(_) => None,
+        AnyJsxAttributeValue::JsxString(node) => (presence == CurlyBracesPresence::Always
+            && can_be_string_literal(&node))
+        .then_some(CurlyBraceResolution::AddBraces),
     }
 }
 
-fn handle_jsx_child(child: &AnyJsxChild, has_curly_braces: bool) -> Option<CurlyBraceResolution> {
+fn handle_jsx_child(
+    child: &AnyJsxChild,
+    has_curly_braces: bool,
+    presence: CurlyBracesPresence,
+) -> Option<CurlyBraceResolution> {
     match child {
-        AnyJsxChild::JsxExpressionChild(child) => child
-            .expression()
-            .as_ref()
-            .and_then(|node| node.as_any_js_literal_expression())
-            .and_then(|node| node.as_js_string_literal_expression())
-            .and({
-                if has_curly_braces {
-                    Some(CurlyBraceResolution::RemoveBraces)
-                } else {
-                    None
-                }
-            }),
-        AnyJsxChild::JsxText(_) => None,
+        AnyJsxChild::JsxExpressionChild(child) => {
+            let is_text = child
+                .expression()
+                .as_ref()
+                .and_then(|node| node.as_any_js_literal_expression())
+                .and_then(|node| node.as_js_string_literal_expression())
+                .and_then(|node| node.inner_string_text().ok())
+                .is_some_and(|text| can_be_jsx_text(text.text()));
+            (is_text && has_curly_braces && presence == CurlyBracesPresence::Never)
+                .then_some(CurlyBraceResolution::RemoveBraces)
+        }
+        AnyJsxChild::JsxText(text) => (presence == CurlyBracesPresence::Always
+            && can_be_string_child(text))
+        .then_some(CurlyBraceResolution::AddBraces),
         _ => None,
     }
 }
@@ -382,17 +533,19 @@ fn has_curly_braces(node: &AnyJsxCurlyQuery) -> bool {
     }
 }
 
-fn contains_string_literal(node: &JsxExpressionAttributeValue) -> bool {
+fn contains_string_literal(
+    node: &JsxExpressionAttributeValue,
+    predicate: impl Fn(&str) -> bool,
+) -> bool {
     node.expression()
-        .map(|expr| {
-            matches!(
-                expr,
-                AnyJsExpression::AnyJsLiteralExpression(
-                    AnyJsLiteralExpression::JsStringLiteralExpression(_)
-                )
-            )
+        .ok()
+        .and_then(|expr| {
+            expr.as_any_js_literal_expression()?
+                .as_js_string_literal_expression()?
+                .inner_string_text()
+                .ok()
         })
-        .unwrap_or_default()
+        .is_some_and(|text| predicate(text.text()))
 }
 
 fn contains_jsx_tag(node: &JsxExpressionAttributeValue) -> bool {
@@ -400,3 +553,51 @@ fn contains_jsx_tag(node: &JsxExpressionAttributeValue) -> bool {
         .map(|expr| matches!(expr, AnyJsExpression::JsxTagExpression(_)))
         .unwrap_or_default()
 }
+
+/// Returns `true` if the content of a string literal means the same in a JSX string.
+///
+/// The JSX strings don't have escape sequences, and decode the HTML entities.
+fn can_be_jsx_string(text: &str) -> bool {
+    !text.contains(['\\', '&'])
+}
+
+/// Returns `true` if the content of a string literal means the same as JSX text.
+///
+/// JSX text can't contain curly braces and tags, and JSX trims the whitespace around its lines.
+fn can_be_jsx_text(text: &str) -> bool {
+    !text.is_empty() && text.trim() == text && !text.contains(['\\', '&', '{', '}', '<', '>'])
+}
+
+/// Returns `true` if the JSX string `node` means the same in a string literal
+fn can_be_string_literal(node: &JsxString) -> bool {
+    node.inner_string_text()
+        .is_ok_and(|text| !text.text().contains(['\\', '&', '\n', '\r']))
+}
+
+/// Returns `true` if the JSX text `node` can be written as a string literal in curly braces.
+///
+/// The text that spans several lines is left as is, because JSX collapses its line breaks.
+fn can_be_string_child(node: &JsxText) -> bool {
+    let Ok(value_token) = node.value_token() else {
+        return false;
+    };
+    let text = value_token.text_trimmed().trim();
+    !text.is_empty()
+        && !text.contains(['\\', '&', '\n', '\r'])
+        && !(text.contains('"') && text.contains('\''))
+}
+
+/// Returns the range of the JSX text `token` without the whitespace around it
+fn trimmed_text_range(token: &JsSyntaxToken) -> TextRange {
+    let text = token.text_trimmed();
+    let start = text.len() - text.trim_start().len();
+    let end = text.trim_end().len();
+    let range = token.text_trimmed_range();
+    if start >= end {
+        return range;
+    }
+    TextRange::new(
+        range.start() + TextSize::from(start as u32),
+        range.start() + TextSize::from(end as u32),
+    )
+}
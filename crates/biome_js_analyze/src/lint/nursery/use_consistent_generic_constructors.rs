@@ -0,0 +1,336 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyTsName, AnyTsPropertyAnnotation, AnyTsType, AnyTsVariableAnnotation,
+    JsFormalParameter, JsInitializerClause, JsNewExpression, JsPropertyClassMember, JsSyntaxTrivia,
+    JsVariableDeclarator, TsTypeAnnotation, TsTypeArguments, T,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, TriviaPieceKind};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Require consistently specifying the type arguments of a generic constructor either on the constructor or on the type annotation.
+    ///
+    /// When a variable, a class property, or a parameter is initialized with a generic class instance,
+    /// the type arguments can be written on the constructor call, `new Map<string, number>()`,
+    /// or on the type annotation, `Map<string, number>`.
+    /// Writing them on both sides is redundant,
+    /// and using the same style consistently across your codebase makes these declarations easier to read.
+    ///
+    /// The rule only checks the constructor calls whose callee has the same name as the type of the annotation.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// const map: Map<string, number> = new Map();
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Cache {
+    ///     entries: Set<string> = new Set();
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// const map = new Map<string, number>();
+    /// const set: Set<string> = new Set<string>();
+    /// const values: Iterable<string> = new Set();
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides an option to specify where the type arguments are written.
+    ///
+    /// Default: "constructor"
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "style": "constructor"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Style
+    ///
+    /// By default, the type arguments of the type annotation are moved to the constructor call,
+    /// and the type annotation is removed.
+    /// If the option is set to "typeAnnotation", the type arguments of the constructor call are moved to a type annotation
+    /// when the declaration doesn't have one.
+    ///
+    pub UseConsistentGenericConstructors {
+        version: "next",
+        name: "useConsistentGenericConstructors",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("consistent-generic-constructors")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyTsGenericConstructorDeclaration = JsVariableDeclarator | JsPropertyClassMember | JsFormalParameter
+}
+
+impl Rule for UseConsistentGenericConstructors {
+    type Query = Ast<AnyTsGenericConstructorDeclaration>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ConsistentGenericConstructorsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let (annotation, expression) = node.annotation_and_constructor()?;
+        match (ctx.options().style, annotation) {
+            (ConsistentGenericConstructorsStyle::Constructor, Some(annotation)) => {
+                if expression.type_arguments().is_some() || expression.arguments().is_none() {
+                    return None;
+                }
+                let ty = annotation.ty().ok()?;
+                let ty = ty.as_ts_reference_type()?;
+                ty.type_arguments()?;
+                let name = ty.name().ok()?;
+                let callee = expression.callee().ok()?;
+                (name.syntax().text_trimmed() == callee.syntax().text_trimmed()).then_some(())
+            }
+            (ConsistentGenericConstructorsStyle::TypeAnnotation, None) => {
+                expression.type_arguments()?;
+                matches!(
+                    expression.callee().ok()?,
+                    AnyJsExpression::JsIdentifierExpression(_)
+                )
+                .then_some(())
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let title = match ctx.options().style {
+            ConsistentGenericConstructorsStyle::Constructor => markup! {
+                "The type arguments should be specified on the "<Emphasis>"constructor"</Emphasis>"."
+            },
+            ConsistentGenericConstructorsStyle::TypeAnnotation => markup! {
+                "The type arguments should be specified on the "<Emphasis>"type annotation"</Emphasis>"."
+            },
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), node.range(), title).note(markup! {
+                "Using a single style for the type arguments of generic constructors makes the code easier to read."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let (annotation, expression) = node.annotation_and_constructor()?;
+        let mut mutation = ctx.root().begin();
+        let message = match annotation {
+            Some(annotation) => {
+                let ty = annotation.ty().ok()?;
+                let arguments = ty.as_ts_reference_type()?.type_arguments()?;
+                // The whitespace that follows the annotation now follows the name
+                let trailing_trivia = annotation.syntax().last_trailing_trivia()?;
+                let new_node = node
+                    .clone()
+                    .with_annotation(None)?
+                    .append_trivia_to_name(trailing_trivia)?
+                    .with_constructor(
+                        expression.with_type_arguments(Some(arguments.trim_trivia()?)),
+                    )?;
+                mutation.replace_node(node.clone(), new_node);
+                markup! { "Move the type arguments to the constructor." }.to_owned()
+            }
+            None => {
+                let arguments = expression.type_arguments()?;
+                let callee = expression.callee().ok()?;
+                let callee = callee.as_js_identifier_expression()?.name().ok()?;
+                let name = callee.value_token().ok()?;
+                let annotation = make_type_annotation(name.text_trimmed(), arguments)?;
+                let new_node = node
+                    .clone()
+                    .move_name_trailing_trivia_to(annotation)?
+                    .with_constructor(expression.with_type_arguments(None))?;
+                mutation.replace_node(node.clone(), new_node);
+                markup! { "Move the type arguments to the type annotation." }.to_owned()
+            }
+        };
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            message,
+            mutation,
+        ))
+    }
+}
+
+impl AnyTsGenericConstructorDeclaration {
+    /// Returns the type annotation and the constructor call that initializes the declaration.
+    ///
+    /// Returns `None` if the declaration isn't initialized with a constructor call,
+    /// or if it has a definite or an optional annotation.
+    fn annotation_and_constructor(&self) -> Option<(Option<TsTypeAnnotation>, JsNewExpression)> {
+        let (annotation, initializer) = match self {
+            Self::JsVariableDeclarator(declarator) => {
+                let annotation = match declarator.variable_annotation() {
+                    Some(AnyTsVariableAnnotation::TsTypeAnnotation(annotation)) => Some(annotation),
+                    Some(_) => return None,
+                    None => None,
+                };
+                (annotation, declarator.initializer()?)
+            }
+            Self::JsPropertyClassMember(member) => {
+                let annotation = match member.property_annotation() {
+                    Some(AnyTsPropertyAnnotation::TsTypeAnnotation(annotation)) => Some(annotation),
+                    Some(_) => return None,
+                    None => None,
+                };
+                (annotation, member.value()?)
+            }
+            Self::JsFormalParameter(parameter) => {
+                if parameter.question_mark_token().is_some() {
+                    return None;
+                }
+                (parameter.type_annotation(), parameter.initializer()?)
+            }
+        };
+        let expression = initializer.expression().ok()?;
+        Some((annotation, expression.as_js_new_expression()?.clone()))
+    }
+
+    fn initializer(&self) -> Option<JsInitializerClause> {
+        match self {
+            Self::JsVariableDeclarator(declarator) => declarator.initializer(),
+            Self::JsPropertyClassMember(member) => member.value(),
+            Self::JsFormalParameter(parameter) => parameter.initializer(),
+        }
+    }
+
+    /// Replaces the constructor call of the initializer by `expression`.
+    fn with_constructor(self, expression: JsNewExpression) -> Option<Self> {
+        let initializer = self
+            .initializer()?
+            .with_expression(AnyJsExpression::from(expression));
+        Some(match self {
+            Self::JsVariableDeclarator(declarator) => {
+                Self::from(declarator.with_initializer(Some(initializer)))
+            }
+            Self::JsPropertyClassMember(member) => Self::from(member.with_value(Some(initializer))),
+            Self::JsFormalParameter(parameter) => {
+                Self::from(parameter.with_initializer(Some(initializer)))
+            }
+        })
+    }
+
+    fn with_annotation(self, annotation: Option<TsTypeAnnotation>) -> Option<Self> {
+        Some(match self {
+            Self::JsVariableDeclarator(declarator) => {
+                Self::from(declarator.with_variable_annotation(
+                    annotation.map(AnyTsVariableAnnotation::TsTypeAnnotation),
+                ))
+            }
+            Self::JsPropertyClassMember(member) => Self::from(member.with_property_annotation(
+                annotation.map(AnyTsPropertyAnnotation::TsTypeAnnotation),
+            )),
+            Self::JsFormalParameter(parameter) => {
+                Self::from(parameter.with_type_annotation(annotation))
+            }
+        })
+    }
+
+    /// Appends `trivia` to the binding or the name of the declaration.
+    fn append_trivia_to_name(self, trivia: JsSyntaxTrivia) -> Option<Self> {
+        Some(match self {
+            Self::JsVariableDeclarator(declarator) => {
+                let id = declarator
+                    .id()
+                    .ok()?
+                    .append_trivia_pieces(trivia.pieces())?;
+                Self::from(declarator.with_id(id))
+            }
+            Self::JsPropertyClassMember(member) => {
+                let name = member.name().ok()?.append_trivia_pieces(trivia.pieces())?;
+                Self::from(member.with_name(name))
+            }
+            Self::JsFormalParameter(parameter) => {
+                let binding = parameter
+                    .binding()
+                    .ok()?
+                    .append_trivia_pieces(trivia.pieces())?;
+                Self::from(parameter.with_binding(binding))
+            }
+        })
+    }
+
+    /// Adds `annotation` after the binding or the name of the declaration,
+    /// and moves the trailing trivia of the name after the annotation.
+    fn move_name_trailing_trivia_to(self, annotation: TsTypeAnnotation) -> Option<Self> {
+        let name = match &self {
+            Self::JsVariableDeclarator(declarator) => declarator.id().ok()?.into_syntax(),
+            Self::JsPropertyClassMember(member) => member.name().ok()?.into_syntax(),
+            Self::JsFormalParameter(parameter) => parameter.binding().ok()?.into_syntax(),
+        };
+        let trailing_trivia = name.last_trailing_trivia()?;
+        let annotation = annotation.append_trivia_pieces(trailing_trivia.pieces())?;
+        let node = match self {
+            Self::JsVariableDeclarator(declarator) => {
+                let id = declarator.id().ok()?.trim_trailing_trivia()?;
+                Self::from(declarator.with_id(id))
+            }
+            Self::JsPropertyClassMember(member) => {
+                let name = member.name().ok()?.trim_trailing_trivia()?;
+                Self::from(member.with_name(name))
+            }
+            Self::JsFormalParameter(parameter) => {
+                let binding = parameter.binding().ok()?.trim_trailing_trivia()?;
+                Self::from(parameter.with_binding(binding))
+            }
+        };
+        node.with_annotation(Some(annotation))
+    }
+}
+
+/// Returns the type annotation `: Name<Arguments>`.
+fn make_type_annotation(name: &str, arguments: TsTypeArguments) -> Option<TsTypeAnnotation> {
+    let ty = make::ts_reference_type(AnyTsName::JsReferenceIdentifier(
+        make::js_reference_identifier(make::ident(name)),
+    ))
+    .with_type_arguments(arguments.trim_trivia()?)
+    .build();
+    Some(make::ts_type_annotation(
+        make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        AnyTsType::from(ty),
+    ))
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConsistentGenericConstructorsOptions {
+    pub style: ConsistentGenericConstructorsStyle,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ConsistentGenericConstructorsStyle {
+    /// `const map = new Map<Key, Value>()`
+    #[default]
+    Constructor,
+    /// `const map: Map<Key, Value> = new Map()`
+    TypeAnnotation,
+}
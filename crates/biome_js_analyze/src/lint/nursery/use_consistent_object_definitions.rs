@@ -0,0 +1,331 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsObjectMember, AnyJsObjectMemberName, JsFunctionExpression,
+    JsMethodObjectMember, JsPropertyObjectMember, JsShorthandPropertyObjectMember, JsSyntaxKind,
+    TriviaPieceKind, T,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Require the consistent use of the shorthand or the explicit syntax for the properties and the methods of the object literals.
+    ///
+    /// The properties whose value is a variable with the same name, such as `{ name: name }`,
+    /// can be written with the shorthand syntax `{ name }`,
+    /// and the properties whose value is a function, such as `{ run: function () {} }`,
+    /// can be written with the method syntax `{ run() {} }`.
+    ///
+    /// By default, the rule reports the properties and the methods that can use the shorthand syntax.
+    /// With the option `syntax` set to `"explicit"`, it reports the properties and the methods that use the shorthand syntax instead.
+    ///
+    /// The named function expressions, such as `{ run: function run() {} }`, aren't reported because their name can be referenced in their body,
+    /// and the arrow functions aren't reported because they don't bind `this`.
+    /// The methods that use `super` aren't reported with the explicit syntax, because `super` can only be used in a method.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const user = { name: name };
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const handlers = {
+    ///     click: function (event) {},
+    /// };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const user = { name, age: 42 };
+    /// ```
+    ///
+    /// ```js
+    /// const handlers = {
+    ///     click(event) {},
+    ///     focus: () => {},
+    ///     blur: function blur() {},
+    /// };
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `syntax`
+    ///
+    /// The syntax of the properties and the methods:
+    /// - `"shorthand"`: `{ name }` and `{ run() {} }`;
+    /// - `"explicit"`: `{ name: name }` and `{ run: function () {} }`.
+    ///
+    /// Default: `"shorthand"`
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "syntax": "explicit"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseConsistentObjectDefinitions {
+        version: "next",
+        name: "useConsistentObjectDefinitions",
+        language: "js",
+        recommended: false,
+        sources: &[RuleSource::Eslint("object-shorthand")],
+        source_kind: RuleSourceKind::Inspired,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// Options for the rule `useConsistentObjectDefinitions`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseConsistentObjectDefinitionsOptions {
+    /// The syntax of the properties and the methods of the object literals
+    pub syntax: ObjectPropertySyntax,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ObjectPropertySyntax {
+    /// `{ name }` and `{ run() {} }`
+    #[default]
+    Shorthand,
+    /// `{ name: name }` and `{ run: function () {} }`
+    Explicit,
+}
+
+declare_node_union! {
+    pub AnyJsObjectDefinition = JsPropertyObjectMember | JsShorthandPropertyObjectMember | JsMethodObjectMember
+}
+
+impl Rule for UseConsistentObjectDefinitions {
+    type Query = Ast<AnyJsObjectDefinition>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = UseConsistentObjectDefinitionsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let member = ctx.query();
+        match (ctx.options().syntax, member) {
+            (
+                ObjectPropertySyntax::Shorthand,
+                AnyJsObjectDefinition::JsPropertyObjectMember(property),
+            ) => {
+                let name = property.name().ok()?;
+                // `{ __proto__: __proto__ }` sets the prototype of the object,
+                // while `{ __proto__ }` defines a property
+                if is_proto(&name) {
+                    return None;
+                }
+                match property.value().ok()? {
+                    AnyJsExpression::JsIdentifierExpression(identifier) => {
+                        let name = name.as_js_literal_member_name()?.name().ok()?;
+                        identifier.name().ok()?.has_name(name.text()).then_some(())
+                    }
+                    AnyJsExpression::JsFunctionExpression(function) => {
+                        function.id().is_none().then_some(())
+                    }
+                    _ => None,
+                }
+            }
+            (
+                ObjectPropertySyntax::Explicit,
+                AnyJsObjectDefinition::JsShorthandPropertyObjectMember(shorthand),
+            ) => (!shorthand.name().ok()?.has_name("__proto__")).then_some(()),
+            (
+                ObjectPropertySyntax::Explicit,
+                AnyJsObjectDefinition::JsMethodObjectMember(method),
+            ) => {
+                if is_proto(&method.name().ok()?) {
+                    return None;
+                }
+                let uses_super = method
+                    .body()
+                    .ok()?
+                    .syntax()
+                    .descendants()
+                    .any(|node| node.kind() == JsSyntaxKind::JS_SUPER_EXPRESSION);
+                (!uses_super).then_some(())
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let member = ctx.query();
+        let diagnostic = match member {
+            AnyJsObjectDefinition::JsPropertyObjectMember(property) => {
+                if matches!(
+                    property.value().ok()?,
+                    AnyJsExpression::JsFunctionExpression(_)
+                ) {
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        member.range(),
+                        markup! {
+                            "This function can be defined with the method syntax."
+                        },
+                    )
+                } else {
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        member.range(),
+                        markup! {
+                            "This property can be defined with the shorthand syntax."
+                        },
+                    )
+                }
+            }
+            AnyJsObjectDefinition::JsShorthandPropertyObjectMember(_) => RuleDiagnostic::new(
+                rule_category!(),
+                member.range(),
+                markup! {
+                    "This property is defined with the shorthand syntax."
+                },
+            ),
+            AnyJsObjectDefinition::JsMethodObjectMember(_) => RuleDiagnostic::new(
+                rule_category!(),
+                member.range(),
+                markup! {
+                    "This method is defined with the shorthand syntax."
+                },
+            ),
+        };
+        let syntax = match ctx.options().syntax {
+            ObjectPropertySyntax::Shorthand => "shorthand",
+            ObjectPropertySyntax::Explicit => "explicit",
+        };
+        Some(diagnostic.note(markup! {
+            "The properties and the methods of the object literals are defined with the "{syntax}" syntax in this project."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let member = ctx.query();
+        let replacement = match member {
+            AnyJsObjectDefinition::JsPropertyObjectMember(property) => {
+                match property.value().ok()? {
+                    AnyJsExpression::JsIdentifierExpression(identifier) => AnyJsObjectMember::from(
+                        make::js_shorthand_property_object_member(identifier.name().ok()?),
+                    ),
+                    AnyJsExpression::JsFunctionExpression(function) => {
+                        AnyJsObjectMember::from(to_method(property.name().ok()?, &function)?)
+                    }
+                    _ => return None,
+                }
+            }
+            AnyJsObjectDefinition::JsShorthandPropertyObjectMember(shorthand) => {
+                let name = shorthand.name().ok()?;
+                let token = name.value_token().ok()?;
+                AnyJsObjectMember::from(make::js_property_object_member(
+                    make::js_literal_member_name(make::ident(token.text_trimmed())).into(),
+                    make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    make::js_identifier_expression(name.trim_leading_trivia()?).into(),
+                ))
+            }
+            AnyJsObjectDefinition::JsMethodObjectMember(method) => {
+                AnyJsObjectMember::from(to_property(method)?)
+            }
+        };
+        // The comments before the member stay before it
+        let replacement = replacement
+            .with_leading_trivia_pieces(member.syntax().first_leading_trivia()?.pieces())?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsObjectMember::unwrap_cast(member.syntax().clone()),
+            replacement,
+        );
+        let message = match ctx.options().syntax {
+            ObjectPropertySyntax::Shorthand => markup! { "Use the shorthand syntax." },
+            ObjectPropertySyntax::Explicit => markup! { "Use the explicit syntax." },
+        };
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            message.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `name` is `__proto__`, which has a special meaning in the object literals
+fn is_proto(name: &AnyJsObjectMemberName) -> bool {
+    name.as_js_literal_member_name()
+        .and_then(|name| name.name().ok())
+        .is_some_and(|name| name.text() == "__proto__")
+}
+
+/// Converts `name: function () {}` to `name() {}`
+fn to_method(
+    name: AnyJsObjectMemberName,
+    function: &JsFunctionExpression,
+) -> Option<JsMethodObjectMember> {
+    let mut method = make::js_method_object_member(
+        name.trim_leading_trivia()?,
+        function.parameters().ok()?,
+        function.body().ok()?,
+    );
+    if function.async_token().is_some() {
+        method = method.with_async_token(
+            make::token(T![async]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        );
+    }
+    if function.star_token().is_some() {
+        method = method.with_star_token(make::token(T![*]));
+    }
+    if let Some(type_parameters) = function.type_parameters() {
+        method = method.with_type_parameters(type_parameters);
+    }
+    if let Some(return_type_annotation) = function.return_type_annotation() {
+        method = method.with_return_type_annotation(return_type_annotation);
+    }
+    Some(method.build())
+}
+
+/// Converts `name() {}` to `name: function () {}`
+fn to_property(method: &JsMethodObjectMember) -> Option<JsPropertyObjectMember> {
+    let space = [(TriviaPieceKind::Whitespace, " ")];
+    let function_token = if method.star_token().is_some() {
+        make::token(T![function])
+    } else {
+        make::token(T![function]).with_trailing_trivia(space)
+    };
+    let mut function = make::js_function_expression(
+        function_token,
+        method.parameters().ok()?,
+        method.body().ok()?,
+    );
+    if method.async_token().is_some() {
+        function = function.with_async_token(make::token(T![async]).with_trailing_trivia(space));
+    }
+    if method.star_token().is_some() {
+        function = function.with_star_token(make::token(T![*]).with_trailing_trivia(space));
+    }
+    if let Some(type_parameters) = method.type_parameters() {
+        function = function.with_type_parameters(type_parameters);
+    }
+    if let Some(return_type_annotation) = method.return_type_annotation() {
+        function = function.with_return_type_annotation(return_type_annotation);
+    }
+    Some(make::js_property_object_member(
+        method.name().ok()?.trim_leading_trivia()?,
+        make::token(T![:]).with_trailing_trivia(space),
+        AnyJsExpression::from(function.build()),
+    ))
+}
@@ -0,0 +1,339 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyTsName, AnyTsType, AnyTsTypeMember, JsSyntaxKind, JsSyntaxToken, TriviaPieceKind,
+    TsIndexSignatureTypeMember, TsInterfaceDeclaration, TsObjectType, TsReferenceType,
+    TsTypeAliasDeclaration, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstSeparatedList, BatchMutationExt, TextRange, TriviaPiece,
+};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Require consistently using either `Record<K, V>` or index signatures.
+    ///
+    /// _TypeScript_ provides two equivalent ways to define an object type with arbitrary keys:
+    /// the `Record<K, V>` utility type and an object type with a single index signature such as `{ [key: K]: V }`.
+    /// Using the same style consistently across your codebase makes these types easier to read.
+    ///
+    /// Object types and interfaces that reference themselves in their index signature are ignored,
+    /// because `Record` doesn't support circular references.
+    ///
+    /// Interfaces are reported, but no fix is provided for them:
+    /// turning an interface into a type alias disables declaration merging.
+    ///
+    /// The rule only checks the style of the indexed object types.
+    /// The `T[]` and `Array<T>` styles are checked by [useConsistentArrayType](https://biomejs.dev/linter/rules/use-consistent-array-type),
+    /// and the type arguments of the generic constructors are checked by [useConsistentGenericConstructors](https://biomejs.dev/linter/rules/use-consistent-generic-constructors).
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// let invalid: { [key: string]: number };
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// type Dictionary = { readonly [key: string]: unknown };
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// interface Dictionary {
+    ///     [key: string]: unknown;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// let valid: Record<string, number>;
+    /// type Tree = { [key: string]: Tree };
+    /// type WithProperties = { [key: string]: number; length: number };
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides an option to specify which syntax is enforced.
+    ///
+    /// Default: "record"
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "syntax": "record"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Syntax
+    ///
+    /// By default, object types with a single index signature are converted to `Record<K, V>`,
+    /// and a `readonly` index signature is converted to `Readonly<Record<K, V>>`.
+    /// If the option is set to "indexSignature", `Record<K, V>` is converted to `{ [key: K]: V }`
+    /// when `K` is `string`, `number`, or `symbol`.
+    ///
+    pub UseConsistentRecordType {
+        version: "next",
+        name: "useConsistentRecordType",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("consistent-indexed-object-style")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyTsIndexedObjectType = TsObjectType | TsInterfaceDeclaration | TsReferenceType
+}
+
+impl Rule for UseConsistentRecordType {
+    type Query = Ast<AnyTsIndexedObjectType>;
+    type State = IndexedObjectStyleIssue;
+    type Signals = Option<Self::State>;
+    type Options = ConsistentRecordTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+        match (node, options.syntax) {
+            (AnyTsIndexedObjectType::TsObjectType(ty), ConsistentRecordType::Record) => {
+                let member = single_index_signature(ty.members().into_iter())?;
+                if let Some(alias) = ty.parent::<TsTypeAliasDeclaration>() {
+                    let name = alias.binding_identifier().ok()?;
+                    let name = name.as_ts_identifier_binding()?.name_token().ok()?;
+                    if references_name(&member, name.text_trimmed()) {
+                        return None;
+                    }
+                }
+                Some(IndexedObjectStyleIssue::IndexSignature {
+                    record: index_signature_to_record(&member)?,
+                })
+            }
+            (
+                AnyTsIndexedObjectType::TsInterfaceDeclaration(decl),
+                ConsistentRecordType::Record,
+            ) => {
+                if decl.extends_clause().is_some() {
+                    return None;
+                }
+                let member = single_index_signature(decl.members().into_iter())?;
+                let name = decl.id().ok()?;
+                let name = name.as_ts_identifier_binding()?.name_token().ok()?;
+                if references_name(&member, name.text_trimmed()) {
+                    return None;
+                }
+                Some(IndexedObjectStyleIssue::Interface)
+            }
+            (AnyTsIndexedObjectType::TsReferenceType(ty), ConsistentRecordType::IndexSignature) => {
+                Some(IndexedObjectStyleIssue::Record {
+                    object: record_to_index_signature(ty)?,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let (range, title) = match (node, state) {
+            (AnyTsIndexedObjectType::TsInterfaceDeclaration(decl), _) => (
+                interface_range(decl)?,
+                markup! {
+                    "Use a "<Emphasis>"Record<K, V>"</Emphasis>" type instead of an interface with a single index signature."
+                },
+            ),
+            (_, IndexedObjectStyleIssue::Record { .. }) => (
+                node.range(),
+                markup! {
+                    "Use an "<Emphasis>"index signature"</Emphasis>" instead of "<Emphasis>"Record<K, V>"</Emphasis>"."
+                },
+            ),
+            _ => (
+                node.range(),
+                markup! {
+                    "Use "<Emphasis>"Record<K, V>"</Emphasis>" instead of an "<Emphasis>"index signature"</Emphasis>"."
+                },
+            ),
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), range, title).note(markup! {
+                "Using a single style for indexed object types makes the code easier to read."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        let message = match (node, state) {
+            (
+                AnyTsIndexedObjectType::TsObjectType(ty),
+                IndexedObjectStyleIssue::IndexSignature { record },
+            ) => {
+                mutation.replace_node(AnyTsType::from(ty.clone()), record.clone());
+                markup! { "Use "<Emphasis>"Record<K, V>"</Emphasis>"." }.to_owned()
+            }
+            (
+                AnyTsIndexedObjectType::TsReferenceType(ty),
+                IndexedObjectStyleIssue::Record { object },
+            ) => {
+                mutation.replace_node(AnyTsType::from(ty.clone()), object.clone());
+                markup! { "Use an "<Emphasis>"index signature"</Emphasis>"." }.to_owned()
+            }
+            _ => return None,
+        };
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            message,
+            mutation,
+        ))
+    }
+}
+
+pub enum IndexedObjectStyleIssue {
+    /// An object type with a single index signature, and its `Record` equivalent.
+    IndexSignature { record: AnyTsType },
+    /// An interface with a single index signature.
+    Interface,
+    /// A `Record` type, and its index signature equivalent.
+    Record { object: AnyTsType },
+}
+
+/// Returns the member of `members` if it is the only member and an index signature.
+fn single_index_signature(
+    mut members: impl Iterator<Item = AnyTsTypeMember>,
+) -> Option<TsIndexSignatureTypeMember> {
+    let first = members.next()?;
+    if members.next().is_some() {
+        return None;
+    }
+    first.as_ts_index_signature_type_member().cloned()
+}
+
+/// Returns `true` if the value type of `member` references the type named `name`.
+fn references_name(member: &TsIndexSignatureTypeMember, name: &str) -> bool {
+    member.syntax().descendants().any(|node| {
+        TsReferenceType::cast(node)
+            .and_then(|ty| ty.name().ok())
+            .and_then(|name| name.as_js_reference_identifier()?.value_token().ok())
+            .is_some_and(|token| token.text_trimmed() == name)
+    })
+}
+
+/// Returns the range from the start of the interface to the end of its name.
+fn interface_range(decl: &TsInterfaceDeclaration) -> Option<TextRange> {
+    let start = decl.interface_token().ok()?.text_trimmed_range().start();
+    let end = decl.id().ok()?.range().end();
+    Some(TextRange::new(start, end))
+}
+
+/// Turns `{ [key: K]: V }` into `Record<K, V>`, and `{ readonly [key: K]: V }` into `Readonly<Record<K, V>>`.
+fn index_signature_to_record(member: &TsIndexSignatureTypeMember) -> Option<AnyTsType> {
+    let key_type = member
+        .parameter()
+        .ok()?
+        .type_annotation()
+        .ok()?
+        .ty()
+        .ok()?
+        .trim_trivia()?;
+    let value_type = member.type_annotation().ok()?.ty().ok()?.trim_trivia()?;
+    let record = make_generic_type("Record", [key_type, value_type]);
+    if member.readonly_token().is_some() {
+        Some(make_generic_type("Readonly", [record]))
+    } else {
+        Some(record)
+    }
+}
+
+/// Turns `Record<K, V>` into `{ [key: K]: V }` if `K` is a valid index signature parameter type.
+fn record_to_index_signature(ty: &TsReferenceType) -> Option<AnyTsType> {
+    let name = ty.name().ok()?;
+    let name = name.as_js_reference_identifier()?.value_token().ok()?;
+    if name.text_trimmed() != "Record" {
+        return None;
+    }
+    let arguments = ty.type_arguments()?.ts_type_argument_list();
+    if arguments.len() != 2 {
+        return None;
+    }
+    let mut arguments = arguments.iter();
+    let key_type = arguments.next()?.ok()?;
+    let value_type = arguments.next()?.ok()?;
+    if !matches!(
+        key_type,
+        AnyTsType::TsStringType(_) | AnyTsType::TsNumberType(_) | AnyTsType::TsSymbolType(_)
+    ) {
+        return None;
+    }
+    let member = make::ts_index_signature_type_member(
+        make::token(T!['[']),
+        make::ts_index_signature_parameter(
+            make::js_identifier_binding(make::ident("key")),
+            make::ts_type_annotation(
+                make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                key_type.trim_trivia()?,
+            ),
+        ),
+        make::token(T![']']),
+        make::ts_type_annotation(
+            make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            value_type.trim_trivia()?,
+        ),
+    )
+    .build();
+    let object = make::ts_object_type(
+        make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        make::ts_type_member_list([AnyTsTypeMember::from(member)]),
+        make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+    );
+    Some(AnyTsType::from(object))
+}
+
+fn make_generic_type(name: &str, arguments: impl IntoIterator<Item = AnyTsType>) -> AnyTsType {
+    let arguments = arguments.into_iter().collect::<Vec<_>>();
+    let separators = (1..arguments.len()).map(|_| {
+        JsSyntaxToken::new_detached(JsSyntaxKind::COMMA, ", ", [], [TriviaPiece::whitespace(1)])
+    });
+    AnyTsType::TsReferenceType(
+        make::ts_reference_type(AnyTsName::JsReferenceIdentifier(
+            make::js_reference_identifier(make::ident(name)),
+        ))
+        .with_type_arguments(make::ts_type_arguments(
+            make::token(T![<]),
+            make::ts_type_argument_list(arguments, separators),
+            make::token(T![>]),
+        ))
+        .build(),
+    )
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConsistentRecordTypeOptions {
+    pub syntax: ConsistentRecordType,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ConsistentRecordType {
+    /// `Record<Key, Value>`
+    #[default]
+    Record,
+    /// `{ [key: Key]: Value }`
+    IndexSignature,
+}
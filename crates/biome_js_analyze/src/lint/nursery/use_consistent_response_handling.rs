@@ -0,0 +1,234 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::{
+    global_identifier, AnyJsExpression, AnyJsMemberExpression, JsCallExpression, JsSyntaxKind,
+    JsSyntaxNode,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+
+declare_lint_rule! {
+    /// Require checking the status of the responses of `fetch()` before reading their body.
+    ///
+    /// Unlike most HTTP clients, `fetch()` doesn't reject the responses with an error status, such as `404` or `500`.
+    /// Reading the body of such a response with `.json()` or `.text()` treats the error page as if the request had succeeded,
+    /// which usually fails later with a confusing error, or silently displays wrong data.
+    ///
+    /// The rule reports the calls of `.json()`, `.text()`, and the other methods that read the body of a response,
+    /// when the response is:
+    /// - a variable initialized with `await fetch()`, such as `const res = await fetch(url)`;
+    /// - the parameter of the callback of `fetch().then()`, such as `fetch(url).then((res) => res.json())`;
+    /// - an awaited `fetch()`, such as `(await fetch(url)).json()`.
+    ///
+    /// The response is checked when `res.ok` or `res.status` is read anywhere in its scope.
+    /// A response that is passed to a function, returned, or assigned is assumed to be checked elsewhere.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function getUser(id) {
+    ///     const res = await fetch(`/api/users/${id}`);
+    ///     return res.json();
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// fetch("/api/users").then((res) => res.json());
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function getUser(id) {
+    ///     const res = await fetch(`/api/users/${id}`);
+    ///     if (!res.ok) {
+    ///         throw new Error(`Request failed with status ${res.status}`);
+    ///     }
+    ///     return res.json();
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// async function getUser(id) {
+    ///     const res = await fetch(`/api/users/${id}`);
+    ///     assertOk(res);
+    ///     return res.json();
+    /// }
+    /// ```
+    ///
+    pub UseConsistentResponseHandling {
+        version: "next",
+        name: "useConsistentResponseHandling",
+        language: "js",
+        recommended: false,
+    }
+}
+
+/// The methods of `Response` that read its body
+const BODY_METHODS: [&str; 6] = ["arrayBuffer", "blob", "bytes", "formData", "json", "text"];
+
+/// The properties of `Response` that tell whether the request succeeded
+const STATUS_PROPERTIES: [&str; 2] = ["ok", "status"];
+
+pub struct UncheckedResponse {
+    /// The range of the call that reads the body
+    range: TextRange,
+    method: String,
+}
+
+impl Rule for UseConsistentResponseHandling {
+    type Query = Semantic<JsCallExpression>;
+    type State = UncheckedResponse;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let model = ctx.model();
+        if !call.arguments().ok()?.args().is_empty() {
+            return None;
+        }
+        let callee =
+            AnyJsMemberExpression::cast(call.callee().ok()?.omit_parentheses().into_syntax())?;
+        let method = callee.member_name()?;
+        if !BODY_METHODS.contains(&method.text()) {
+            return None;
+        }
+        let is_unchecked = match callee.object().ok()?.omit_parentheses() {
+            // `(await fetch(url)).json()`
+            AnyJsExpression::JsAwaitExpression(expression) => {
+                is_fetch_call(&expression.argument().ok()?, model)
+            }
+            AnyJsExpression::JsIdentifierExpression(expression) => {
+                let binding = model.binding(&expression.name().ok()?)?;
+                is_response_declaration(&binding.tree().declaration()?, model)
+                    && !binding
+                        .all_references()
+                        .any(|reference| is_checked_by(reference.syntax()))
+            }
+            _ => false,
+        };
+        is_unchecked.then(|| UncheckedResponse {
+            range: call.range(),
+            method: method.text().to_string(),
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let method = &state.method;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The body of this response is read with "<Emphasis>"."{method}"()"</Emphasis>" without checking its status."
+                },
+            )
+            .note(markup! {
+                <Emphasis>"fetch()"</Emphasis>" doesn't reject the responses with an error status, such as 404 or 500, so their body is read as if the request had succeeded."
+            })
+            .note(markup! {
+                "Check "<Emphasis>"ok"</Emphasis>" or "<Emphasis>"status"</Emphasis>" before reading the body of the response."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `declaration` declares a response of `fetch()`:
+/// a variable initialized with `await fetch()`, or the first parameter of the callback of `fetch().then()`
+fn is_response_declaration(declaration: &AnyJsBindingDeclaration, model: &SemanticModel) -> bool {
+    match declaration {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => declarator
+            .initializer()
+            .and_then(|initializer| initializer.expression().ok())
+            .is_some_and(|expression| match expression.omit_parentheses() {
+                AnyJsExpression::JsAwaitExpression(expression) => expression
+                    .argument()
+                    .is_ok_and(|argument| is_fetch_call(&argument, model)),
+                _ => false,
+            }),
+        // `res => res.json()`
+        AnyJsBindingDeclaration::JsArrowFunctionExpression(function) => {
+            is_fetch_then_callback(function.syntax(), model)
+        }
+        // `(res) => res.json()` or `function (res) { return res.json(); }`
+        AnyJsBindingDeclaration::JsFormalParameter(parameter) => {
+            let Some(list) = parameter.syntax().parent() else {
+                return false;
+            };
+            let is_first_parameter = list
+                .first_child()
+                .is_some_and(|first| &first == parameter.syntax());
+            is_first_parameter
+                && list
+                    .grand_parent()
+                    .is_some_and(|function| is_fetch_then_callback(&function, model))
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `function` is the first argument of `fetch().then()`
+fn is_fetch_then_callback(function: &JsSyntaxNode, model: &SemanticModel) -> bool {
+    let Some(arguments) = function.parent() else {
+        return false;
+    };
+    if arguments.kind() != JsSyntaxKind::JS_CALL_ARGUMENT_LIST
+        || arguments.first_child().as_ref() != Some(function)
+    {
+        return false;
+    }
+    let Some(call) = arguments.grand_parent().and_then(JsCallExpression::cast) else {
+        return false;
+    };
+    call.callee()
+        .ok()
+        .and_then(|callee| AnyJsMemberExpression::cast(callee.omit_parentheses().into_syntax()))
+        .filter(|callee| {
+            callee
+                .member_name()
+                .is_some_and(|name| name.text() == "then")
+        })
+        .and_then(|callee| callee.object().ok())
+        .is_some_and(|object| is_fetch_call(&object, model))
+}
+
+/// Returns `true` if `expression` is a call of the global `fetch()`
+fn is_fetch_call(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    let AnyJsExpression::JsCallExpression(call) = expression.clone().omit_parentheses() else {
+        return false;
+    };
+    call.callee()
+        .ok()
+        .and_then(|callee| global_identifier(&callee.omit_parentheses()))
+        .is_some_and(|(reference, name)| {
+            name.text() == "fetch" && model.binding(&reference).is_none()
+        })
+}
+
+/// Returns `true` if the reference to a response reads its status,
+/// or if the response escapes to code that may check it, such as `assertOk(res)`
+fn is_checked_by(reference: &JsSyntaxNode) -> bool {
+    let Some(expression) = reference.parent() else {
+        return false;
+    };
+    let Some(parent) = expression.parent() else {
+        return false;
+    };
+    match AnyJsMemberExpression::cast(parent) {
+        Some(member)
+            if member
+                .object()
+                .is_ok_and(|object| object.syntax() == &expression) =>
+        {
+            member
+                .member_name()
+                .is_some_and(|name| STATUS_PROPERTIES.contains(&name.text()))
+        }
+        _ => true,
+    }
+}
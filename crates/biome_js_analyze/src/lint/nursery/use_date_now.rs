@@ -1,15 +1,17 @@
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
     RuleSource,
 };
 use biome_console::markup;
 use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
 use biome_js_syntax::{
-    AnyJsExpression, JsAssignmentOperator, JsBinaryOperator, JsCallExpression, JsNewExpression,
-    JsNewOrCallExpression, JsSyntaxKind, JsSyntaxNode, JsUnaryOperator, T,
+    global_identifier, AnyJsExpression, JsAssignmentOperator, JsBinaryOperator, JsCallExpression,
+    JsNewExpression, JsNewOrCallExpression, JsSyntaxKind, JsSyntaxNode, JsUnaryOperator, T,
 };
 use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
 
+use crate::services::semantic::Semantic;
 use crate::JsRuleAction;
 
 use super::use_explicit_length_check::does_node_needs_space_before_child;
@@ -20,6 +22,9 @@ declare_lint_rule! {
     /// `Date.now()` is more readable than `new Date().getTime()` and its variants,
     /// it also avoids unnecessary instantiation of `Date` object.
     ///
+    /// The rule ignores the code where `Date` or `Number` don't refer to the global objects,
+    /// such as a local variable named `Date`.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -52,6 +57,10 @@ declare_lint_rule! {
     /// ```js
     /// const foo = Date.now() * 2;
     /// ```
+    /// ```js
+    /// import { Date } from "./date.js";
+    /// const foo = new Date().getTime();
+    /// ```
     ///
     pub UseDateNow {
         version: "1.8.0",
@@ -59,22 +68,25 @@ declare_lint_rule! {
         language: "js",
         sources: &[RuleSource::EslintUnicorn("prefer-date-now")],
         recommended: false,
-        fix_kind: FixKind::Unsafe,
+        fix_kind: FixKind::Safe,
     }
 }
 
 impl Rule for UseDateNow {
-    type Query = Ast<JsNewOrCallExpression>;
+    type Query = Semantic<JsNewOrCallExpression>;
     type State = (AnyJsExpression, UseDateNowIssueKind);
     type Signals = Option<Self::State>;
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let expr = ctx.query();
+        let model = ctx.model();
 
         match expr {
-            JsNewOrCallExpression::JsCallExpression(call_expr) => get_date_method_issue(call_expr),
-            JsNewOrCallExpression::JsNewExpression(expr) => get_new_date_issue(expr),
+            JsNewOrCallExpression::JsCallExpression(call_expr) => {
+                get_date_method_issue(call_expr, model)
+            }
+            JsNewOrCallExpression::JsNewExpression(expr) => get_new_date_issue(expr, model),
         }
     }
 
@@ -152,6 +164,7 @@ pub enum UseDateNowIssueKind {
 
 fn get_date_method_issue(
     call_expr: &JsCallExpression,
+    model: &SemanticModel,
 ) -> Option<(AnyJsExpression, UseDateNowIssueKind)> {
     let callee = call_expr.callee().ok()?.omit_parentheses();
 
@@ -170,13 +183,9 @@ fn get_date_method_issue(
         .omit_parentheses();
 
     let new_expr = object.as_js_new_expression()?;
-    let object_name = new_expr
-        .callee()
-        .ok()?
-        .get_callee_object_name()?
-        .token_text_trimmed();
 
-    if object_name != "Date" || new_expr.arguments()?.args().len() > 0 {
+    if !is_global(&new_expr.callee().ok()?, "Date", model) || new_expr.arguments()?.args().len() > 0
+    {
         return None;
     }
 
@@ -186,12 +195,11 @@ fn get_date_method_issue(
     ))
 }
 
-fn get_new_date_issue(expr: &JsNewExpression) -> Option<(AnyJsExpression, UseDateNowIssueKind)> {
-    let callee = expr.callee().ok()?.omit_parentheses();
-
-    if callee.get_callee_member_name()?.token_text_trimmed() != "Date"
-        || expr.arguments()?.args().len() > 0
-    {
+fn get_new_date_issue(
+    expr: &JsNewExpression,
+    model: &SemanticModel,
+) -> Option<(AnyJsExpression, UseDateNowIssueKind)> {
+    if !is_global(&expr.callee().ok()?, "Date", model) || expr.arguments()?.args().len() > 0 {
         return None;
     }
 
@@ -249,26 +257,36 @@ fn get_new_date_issue(expr: &JsNewExpression) -> Option<(AnyJsExpression, UseDat
                 return None;
             }
 
-            let call_name = call_expr
-                .callee()
-                .ok()?
-                .omit_parentheses()
-                .get_callee_member_name()?
-                .token_text_trimmed();
+            let callee = call_expr.callee().ok()?;
 
-            if call_name == "Number" || call_name == "BigInt" {
+            if is_global(&callee, "Number", model) {
                 return Some((
                     AnyJsExpression::cast(call_expr.into_syntax())?,
                     UseDateNowIssueKind::ReplaceNumberConstructor,
                 ));
             }
 
+            // `BigInt(Date.now())` keeps the type of `BigInt(new Date())`
+            if is_global(&callee, "BigInt", model) {
+                return Some((
+                    AnyJsExpression::cast_ref(expr.syntax())?,
+                    UseDateNowIssueKind::ReplaceConstructor,
+                ));
+            }
+
             None
         }
         _ => None,
     }
 }
 
+/// Returns `true` if `expr` references the global object `name`, such as `Date` or `globalThis.Date`
+fn is_global(expr: &AnyJsExpression, name: &str, model: &SemanticModel) -> bool {
+    global_identifier(&expr.clone().omit_parentheses()).is_some_and(|(reference, global_name)| {
+        global_name.text() == name && model.binding(&reference).is_none()
+    })
+}
+
 fn get_parent_without_parenthesis(node: &JsSyntaxNode) -> Option<AnyJsExpression> {
     node.ancestors()
         .skip(1)
@@ -0,0 +1,319 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    global_identifier, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument, AnyJsExpression,
+    AnyJsObjectMember, AnyJsObjectMemberName, JsCatchClause, JsIdentifierBinding, JsLanguage,
+    JsNewOrCallExpression, JsObjectExpression, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    JsThrowStatement, T,
+};
+use biome_rowan::{
+    AstNode, AstSeparatedElement, AstSeparatedList, BatchMutationExt, TriviaPieceKind,
+};
+
+use crate::{
+    services::{control_flow::AnyJsControlFlowRoot, semantic::Semantic},
+    JsRuleAction,
+};
+
+declare_lint_rule! {
+    /// Require the caught error to be attached as the `cause` of the errors thrown in a `catch` clause.
+    ///
+    /// When an error is caught and a new error is thrown in its place,
+    /// the message and the stack trace of the original error are lost,
+    /// unless the original error is passed as the `cause` option of the new error.
+    ///
+    /// The rule only checks the built-in error constructors, such as `Error` or `TypeError`,
+    /// that are thrown directly in a `catch` clause that binds the caught error.
+    /// An error that is passed options which aren't an object literal isn't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// try {
+    ///     load();
+    /// } catch (error) {
+    ///     throw new Error("Failed to load the configuration.");
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// try {
+    ///     load();
+    /// } catch (error) {
+    ///     throw new TypeError("Invalid configuration.", { details: "..." });
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// try {
+    ///     load();
+    /// } catch (error) {
+    ///     throw new Error("Failed to load the configuration.", { cause: error });
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// try {
+    ///     load();
+    /// } catch (cause) {
+    ///     throw new Error("Failed to load the configuration.", { cause });
+    /// }
+    /// ```
+    ///
+    pub UseErrorCause {
+        version: "next",
+        name: "useErrorCause",
+        language: "js",
+        sources: &[RuleSource::Eslint("preserve-caught-error")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseErrorCause {
+    type Query = Semantic<JsThrowStatement>;
+    type State = MissingErrorCause;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let catch_binding = enclosing_catch_binding(node)?;
+        let error =
+            JsNewOrCallExpression::cast(node.argument().ok()?.omit_parentheses().into_syntax())?;
+        let (reference, name) = global_identifier(&error.callee().ok()?.omit_parentheses())?;
+        let options_index = error_options_index(name.text())?;
+        if ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+
+        let arguments = error.arguments()?.args();
+        let mut options = None;
+        for (index, argument) in arguments.iter().enumerate() {
+            let argument = argument.ok()?;
+            // The options can be anywhere after a spread argument
+            if matches!(argument, AnyJsCallArgument::JsSpread(_)) {
+                return None;
+            }
+            if index == options_index {
+                let AnyJsCallArgument::AnyJsExpression(argument) = argument else {
+                    return None;
+                };
+                let object = JsObjectExpression::cast(argument.omit_parentheses().into_syntax())?;
+                if !can_lack_cause(&object) {
+                    return None;
+                }
+                options = Some(object);
+            }
+        }
+
+        Some(MissingErrorCause {
+            catch_binding,
+            error,
+            options_index,
+            options,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.error.range(),
+                markup! {
+                    "The caught error isn't attached as the "<Emphasis>"cause"</Emphasis>" of the thrown error."
+                },
+            )
+            .detail(
+                state.catch_binding.range(),
+                markup! { "The error is caught here." },
+            )
+            .note(markup! {
+                "Without a "<Emphasis>"cause"</Emphasis>", the message and the stack trace of the caught error are lost when "<Emphasis>"throw"</Emphasis>" is reached."
+            })
+            .note(markup! {
+                "Pass the caught error as the "<Emphasis>"cause"</Emphasis>" option of the thrown error."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let binding_name = state.catch_binding.name_token().ok()?;
+        let binding_name = binding_name.text_trimmed();
+
+        match &state.options {
+            Some(options) => {
+                let members = options.members();
+                if members.is_empty() {
+                    mutation.replace_node(options.clone(), make_cause_object(binding_name));
+                } else {
+                    let (items, separators) =
+                        append_element(members.elements(), make_cause_member(binding_name))?;
+                    mutation.replace_node(members, make::js_object_member_list(items, separators));
+                }
+            }
+            None => {
+                let arguments = state.error.arguments()?.args();
+                // The fix doesn't make up the missing arguments, such as the message of the error
+                if arguments.len() != state.options_index {
+                    return None;
+                }
+                let (items, separators) = append_element(
+                    arguments.elements(),
+                    AnyJsCallArgument::AnyJsExpression(AnyJsExpression::JsObjectExpression(
+                        make_cause_object(binding_name),
+                    )),
+                )?;
+                mutation.replace_node(arguments, make::js_call_argument_list(items, separators));
+            }
+        }
+
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Attach the caught error as the "<Emphasis>"cause"</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// An error thrown in a `catch` clause without the caught error as its `cause`
+pub struct MissingErrorCause {
+    /// The binding of the caught error
+    catch_binding: JsIdentifierBinding,
+    /// The thrown error
+    error: JsNewOrCallExpression,
+    /// The position of the options in the arguments of the error
+    options_index: usize,
+    /// The options passed to the error, if any
+    options: Option<JsObjectExpression>,
+}
+
+/// Returns the binding of the error caught by the `catch` clause that `node` is directly in.
+///
+/// The `throw` statements of a nested `try` block or of a nested function don't rethrow the caught error.
+pub(crate) fn enclosing_catch_binding(node: &JsThrowStatement) -> Option<JsIdentifierBinding> {
+    let mut previous: Option<JsSyntaxNode> = None;
+    for ancestor in node.syntax().ancestors() {
+        if let Some(catch_clause) = JsCatchClause::cast_ref(&ancestor) {
+            let binding = catch_clause.declaration()?.binding().ok()?;
+            let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) =
+                binding
+            else {
+                return None;
+            };
+            return Some(binding);
+        }
+        if AnyJsControlFlowRoot::can_cast(ancestor.kind()) {
+            return None;
+        }
+        if matches!(
+            ancestor.kind(),
+            JsSyntaxKind::JS_TRY_STATEMENT | JsSyntaxKind::JS_TRY_FINALLY_STATEMENT
+        ) && previous.as_ref().is_some_and(|previous| {
+            previous.kind() == JsSyntaxKind::JS_BLOCK_STATEMENT
+                && ancestor.first_child().as_ref() == Some(previous)
+        }) {
+            return None;
+        }
+        previous = Some(ancestor);
+    }
+    None
+}
+
+/// Returns the position of the options in the arguments of the built-in error constructor `name`
+fn error_options_index(name: &str) -> Option<usize> {
+    match name {
+        "AggregateError" => Some(2),
+        "Error" | "EvalError" | "RangeError" | "ReferenceError" | "SyntaxError" | "TypeError"
+        | "URIError" => Some(1),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the options `object` doesn't set `cause`, and can't set it through a spread
+fn can_lack_cause(object: &JsObjectExpression) -> bool {
+    object.members().iter().all(|member| {
+        let name = match member {
+            Ok(AnyJsObjectMember::JsPropertyObjectMember(member)) => member.name(),
+            Ok(AnyJsObjectMember::JsMethodObjectMember(member)) => member.name(),
+            Ok(AnyJsObjectMember::JsGetterObjectMember(member)) => member.name(),
+            Ok(AnyJsObjectMember::JsSetterObjectMember(member)) => member.name(),
+            Ok(AnyJsObjectMember::JsShorthandPropertyObjectMember(member)) => {
+                return member
+                    .name()
+                    .and_then(|name| name.value_token())
+                    .is_ok_and(|name| name.text_trimmed() != "cause");
+            }
+            _ => return false,
+        };
+        // A computed name that isn't a literal can be `cause`
+        name.ok()
+            .and_then(|name| name.name())
+            .is_some_and(|name| name.text() != "cause")
+    })
+}
+
+fn make_cause_member(binding_name: &str) -> AnyJsObjectMember {
+    if binding_name == "cause" {
+        return AnyJsObjectMember::JsShorthandPropertyObjectMember(
+            make::js_shorthand_property_object_member(make::js_reference_identifier(make::ident(
+                "cause",
+            ))),
+        );
+    }
+    AnyJsObjectMember::JsPropertyObjectMember(make::js_property_object_member(
+        AnyJsObjectMemberName::JsLiteralMemberName(make::js_literal_member_name(make::ident(
+            "cause",
+        ))),
+        make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        AnyJsExpression::JsIdentifierExpression(make::js_identifier_expression(
+            make::js_reference_identifier(make::ident(binding_name)),
+        )),
+    ))
+}
+
+/// Returns the options `{ cause: binding_name }`
+pub(crate) fn make_cause_object(binding_name: &str) -> JsObjectExpression {
+    make::js_object_expression(
+        make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        make::js_object_member_list([make_cause_member(binding_name)], []),
+        make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+    )
+}
+
+/// Returns the items and the separators of a list made of `elements` followed by `item`
+fn append_element<N: AstNode<Language = JsLanguage>>(
+    elements: impl Iterator<Item = AstSeparatedElement<JsLanguage, N>>,
+    item: N,
+) -> Option<(Vec<N>, Vec<JsSyntaxToken>)> {
+    let mut items = Vec::new();
+    let mut separators = Vec::new();
+    for element in elements {
+        items.push(element.node().ok()?.clone());
+        if let Some(separator) = element.trailing_separator().ok()? {
+            separators.push(separator.clone());
+        }
+    }
+    let mut item = item;
+    if separators.len() < items.len() {
+        // The trivia before the closing delimiter moves after the new item
+        let last = items.pop()?;
+        let trailing_trivia = last.syntax().last_trailing_trivia()?.pieces();
+        item = item.with_trailing_trivia_pieces(trailing_trivia)?;
+        items.push(last.with_trailing_trivia_pieces([])?);
+        separators
+            .push(make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]));
+    }
+    items.push(item);
+    Some((items, separators))
+}
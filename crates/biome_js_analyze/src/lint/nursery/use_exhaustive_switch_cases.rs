@@ -0,0 +1,434 @@
+use crate::services::module_exports::{
+    declared_type_members, literal_type_members, ProjectModuleExports, TypeMember,
+};
+use crate::services::semantic::Semantic;
+use crate::utils::constant::{evaluate, number_to_js_string, unescape, ConstantValue};
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::numbers::parse_js_number;
+use biome_js_syntax::{
+    AnyJsBindingPattern, AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression,
+    AnyJsMemberExpression, AnyJsName, AnyJsStatement, AnyJsSwitchClause, AnyTsName, AnyTsType,
+    JsImport, JsSwitchStatement, JsSyntaxNode, JsSyntaxToken, JsThrowStatement, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt, TextRange, TriviaPieceKind};
+use biome_unicode_table::is_js_ident;
+
+declare_lint_rule! {
+    /// Require the `switch` statements over an enum or a union of literal types to handle all its members.
+    ///
+    /// When a member is added to an enum, or to a union of literal types, the `switch` statements that handle each member must be updated.
+    /// A `switch` statement that misses a member silently does nothing for it.
+    ///
+    /// The rule checks the `switch` statements without a `default` clause,
+    /// of which the discriminant is a variable or a parameter with a type annotation.
+    /// The type must be an enum or an alias of a union of string and number literal types, such as `type Kind = "a" | "b"`,
+    /// declared in the same module or imported from another module of the project,
+    /// or an inline union of literal types.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// function toHex(color: Color) {
+    ///     switch (color) {
+    ///         case Color.Red:
+    ///             return "#f00";
+    ///         case Color.Green:
+    ///             return "#0f0";
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function move(direction: "up" | "down") {
+    ///     switch (direction) {
+    ///         case "up":
+    ///             y--;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// enum Color { Red, Green, Blue }
+    ///
+    /// function toHex(color: Color) {
+    ///     switch (color) {
+    ///         case Color.Red:
+    ///             return "#f00";
+    ///         case Color.Green:
+    ///             return "#0f0";
+    ///         case Color.Blue:
+    ///             return "#00f";
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function move(direction: "up" | "down") {
+    ///     switch (direction) {
+    ///         case "up":
+    ///             y--;
+    ///             break;
+    ///         default:
+    ///             y++;
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseExhaustiveSwitchCases {
+        version: "next",
+        name: "useExhaustiveSwitchCases",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("switch-exhaustiveness-check")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub struct MissingCases {
+    /// The local name of the enum, if the discriminant is an enum
+    enum_name: Option<String>,
+    /// The members that aren't handled, in the order of their declaration
+    members: Vec<TypeMember>,
+}
+
+impl Rule for UseExhaustiveSwitchCases {
+    type Query = Semantic<JsSwitchStatement>;
+    type State = MissingCases;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let switch = ctx.query();
+        let cases = switch.cases();
+        if cases
+            .iter()
+            .any(|clause| matches!(clause, AnyJsSwitchClause::JsDefaultClause(_)))
+        {
+            return None;
+        }
+        let ty = discriminant_type(ctx.model(), &switch.discriminant().ok()?)?;
+        let (enum_name, members) = type_members(ctx, &ty)?;
+        let handled = cases
+            .iter()
+            .filter_map(|clause| clause.as_js_case_clause()?.test().ok())
+            .filter_map(|test| case_member(&test, enum_name.as_deref(), ctx.model()))
+            .collect::<Vec<_>>();
+        let members = members
+            .into_iter()
+            .filter(|member| !handled.contains(&normalize_member(member)))
+            .collect::<Vec<_>>();
+        (!members.is_empty()).then_some(MissingCases { enum_name, members })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let switch = ctx.query();
+        let range = TextRange::new(
+            switch.switch_token().ok()?.text_trimmed_range().start(),
+            switch.r_paren_token().ok()?.text_trimmed_range().end(),
+        );
+        let cases = state
+            .members
+            .iter()
+            .map(|member| case_text(member, state.enum_name.as_deref()))
+            .collect::<Vec<_>>();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This "<Emphasis>"switch"</Emphasis>" statement doesn't handle all the members of the type of its discriminant."
+                },
+            )
+            .note(markup! {
+                "Nothing happens when the discriminant is one of the missing members."
+            })
+            .footer_list(markup! { "Missing cases:" }, &cases),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let switch = ctx.query();
+        let cases = switch.cases();
+        // The new clauses are indented like the last clause
+        let indentation = cases
+            .iter()
+            .last()
+            .and_then(|clause| clause.syntax().first_token())
+            .map(|token| {
+                token
+                    .leading_trivia()
+                    .pieces()
+                    .filter(|piece| piece.is_newline() || piece.is_whitespace())
+                    .collect::<Vec<_>>()
+            });
+        let is_double_quote = ctx.as_preferred_quote().is_double();
+        let mut clauses = cases.iter().collect::<Vec<_>>();
+        for member in &state.members {
+            let case_token =
+                match &indentation {
+                    Some(indentation) => make::token(T![case])
+                        .with_leading_trivia_pieces(indentation.iter().cloned()),
+                    None => make::token(T![case])
+                        .with_leading_trivia([(TriviaPieceKind::Newline, "\n")]),
+                };
+            let test = case_test(member, state.enum_name.as_deref(), is_double_quote)?;
+            let clause = make::js_case_clause(
+                case_token.with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                test,
+                make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_statement_list([AnyJsStatement::from(not_implemented_error(
+                    is_double_quote,
+                ))]),
+            );
+            clauses.push(clause.into());
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(cases, make::js_switch_case_list(clauses));
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Add the missing cases." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the type annotation of the discriminant, if it's a variable or a parameter declared with a type annotation
+fn discriminant_type(model: &SemanticModel, discriminant: &AnyJsExpression) -> Option<AnyTsType> {
+    let AnyJsExpression::JsIdentifierExpression(identifier) =
+        discriminant.clone().omit_parentheses()
+    else {
+        return None;
+    };
+    let binding = model.binding(&identifier.name().ok()?)?;
+    let binding = binding.tree();
+    let annotation = match binding.declaration()? {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            if !is_binding(&declarator.id().ok()?, binding.syntax()) {
+                return None;
+            }
+            declarator.variable_annotation()?.type_annotation().ok()??
+        }
+        AnyJsBindingDeclaration::JsFormalParameter(parameter) => {
+            if !is_binding(&parameter.binding().ok()?, binding.syntax()) {
+                return None;
+            }
+            parameter.type_annotation()?
+        }
+        _ => return None,
+    };
+    annotation.ty().ok()
+}
+
+/// Returns `true` if `pattern` is the binding itself, rather than a destructuring pattern that contains it
+fn is_binding(pattern: &AnyJsBindingPattern, binding: &JsSyntaxNode) -> bool {
+    matches!(pattern, AnyJsBindingPattern::AnyJsBinding(_)) && pattern.syntax() == binding
+}
+
+/// Returns the local name of the enum, if `ty` is an enum, and the members of `ty`
+fn type_members(
+    ctx: &RuleContext<UseExhaustiveSwitchCases>,
+    ty: &AnyTsType,
+) -> Option<(Option<String>, Vec<TypeMember>)> {
+    let AnyTsType::TsReferenceType(reference) = ty else {
+        return Some((None, literal_type_members(ty)?));
+    };
+    if reference.type_arguments().is_some() {
+        return None;
+    }
+    let AnyTsName::JsReferenceIdentifier(name) = reference.name().ok()? else {
+        return None;
+    };
+    let local_name = name.value_token().ok()?.text_trimmed().to_string();
+    let binding = ctx.model().binding(&name)?;
+    let declaration = binding.tree().declaration()?;
+    let members = match declaration {
+        AnyJsBindingDeclaration::TsEnumDeclaration(_)
+        | AnyJsBindingDeclaration::TsTypeAliasDeclaration(_) => {
+            declared_type_members(declaration.syntax())?.1
+        }
+        AnyJsBindingDeclaration::JsNamedImportSpecifier(specifier) => {
+            let imported_name = specifier.name().ok()?.inner_string_text().ok()?;
+            imported_type_members(ctx, specifier.syntax(), imported_name.text())?
+        }
+        AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(specifier) => {
+            imported_type_members(ctx, specifier.syntax(), &local_name)?
+        }
+        _ => return None,
+    };
+    let is_enum = members
+        .iter()
+        .any(|member| matches!(member, TypeMember::EnumMember(_)));
+    Some((is_enum.then_some(local_name), members))
+}
+
+/// Returns the members of the type `name` exported by the module imported by the import of `specifier`
+fn imported_type_members(
+    ctx: &RuleContext<UseExhaustiveSwitchCases>,
+    specifier: &JsSyntaxNode,
+    name: &str,
+) -> Option<Vec<TypeMember>> {
+    let import = specifier.ancestors().find_map(JsImport::cast)?;
+    let source = import.source_text().ok()?;
+    let project = ctx.get_service::<ProjectModuleExports>()?;
+    let path = project.resolve(ctx.file_path(), source.text())?;
+    Some(project.details(path)?.type_members(name)?.to_vec())
+}
+
+/// Returns the member matched by the test of a `case` clause, such as `Red` in `case Color.Red:`
+fn case_member(
+    test: &AnyJsExpression,
+    enum_name: Option<&str>,
+    model: &SemanticModel,
+) -> Option<TypeMember> {
+    if let Some(enum_name) = enum_name {
+        let member = AnyJsMemberExpression::cast(test.clone().omit_parentheses().into_syntax())?;
+        let AnyJsExpression::JsIdentifierExpression(object) = member.object().ok()? else {
+            return None;
+        };
+        if object.name().ok()?.value_token().ok()?.text_trimmed() != enum_name {
+            return None;
+        }
+        return Some(TypeMember::EnumMember(
+            member.member_name()?.text().to_string(),
+        ));
+    }
+    match evaluate(test, Some(model))? {
+        ConstantValue::String(value) => Some(TypeMember::String(value)),
+        value @ ConstantValue::Number(_) => Some(TypeMember::Number(value.to_js_string())),
+        _ => None,
+    }
+}
+
+/// Returns `member` as the value of a `case` clause that matches it,
+/// such as `16` for the member `0x10` of a union of literal types
+fn normalize_member(member: &TypeMember) -> TypeMember {
+    match member {
+        TypeMember::String(value) => {
+            TypeMember::String(unescape(value).unwrap_or_else(|| value.clone()))
+        }
+        TypeMember::Number(value) => {
+            let number = match value.strip_prefix('-') {
+                Some(value) => parse_js_number(value).map(|number| -number),
+                None => parse_js_number(value),
+            };
+            number.map_or_else(
+                || member.clone(),
+                |number| TypeMember::Number(number_to_js_string(number)),
+            )
+        }
+        TypeMember::EnumMember(_) => member.clone(),
+    }
+}
+
+/// Returns the test of the `case` clause that handles `member`, as displayed in the diagnostic
+fn case_text(member: &TypeMember, enum_name: Option<&str>) -> String {
+    match member {
+        TypeMember::EnumMember(name) if is_js_ident(name) => {
+            format!("{}.{name}", enum_name.unwrap_or_default())
+        }
+        TypeMember::EnumMember(name) => format!("{}[\"{name}\"]", enum_name.unwrap_or_default()),
+        TypeMember::String(value) => format!("\"{value}\""),
+        TypeMember::Number(value) => value.clone(),
+    }
+}
+
+/// Returns the test of the `case` clause that handles `member`, such as `Color.Red`
+fn case_test(
+    member: &TypeMember,
+    enum_name: Option<&str>,
+    is_double_quote: bool,
+) -> Option<AnyJsExpression> {
+    let test = match member {
+        TypeMember::EnumMember(name) => {
+            let object = AnyJsExpression::from(make::js_identifier_expression(
+                make::js_reference_identifier(make::ident(enum_name?)),
+            ));
+            if is_js_ident(name) {
+                make::js_static_member_expression(
+                    object,
+                    make::token(T![.]),
+                    AnyJsName::from(make::js_name(make::ident(name))),
+                )
+                .into()
+            } else {
+                make::js_computed_member_expression(
+                    object,
+                    make::token(T!['[']),
+                    string_literal(name, is_double_quote),
+                    make::token(T![']']),
+                )
+                .build()
+                .into()
+            }
+        }
+        TypeMember::String(value) => string_literal(value, is_double_quote),
+        TypeMember::Number(value) => match value.strip_prefix('-') {
+            Some(value) => make::js_unary_expression(
+                make::token(T![-]),
+                number_literal(make::js_number_literal(value)),
+            )
+            .into(),
+            None => number_literal(make::js_number_literal(value)),
+        },
+    };
+    Some(test)
+}
+
+fn string_literal(value: &str, is_double_quote: bool) -> AnyJsExpression {
+    let token = if is_double_quote {
+        make::js_string_literal(value)
+    } else {
+        make::js_string_literal_single_quotes(value)
+    };
+    AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
+        make::js_string_literal_expression(token),
+    ))
+}
+
+fn number_literal(token: JsSyntaxToken) -> AnyJsExpression {
+    AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsNumberLiteralExpression(
+        make::js_number_literal_expression(token),
+    ))
+}
+
+/// Returns `throw new Error("Not implemented yet");`
+fn not_implemented_error(is_double_quote: bool) -> JsThrowStatement {
+    let error = make::js_new_expression(
+        make::token(T![new]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        make::js_identifier_expression(make::js_reference_identifier(make::ident("Error"))).into(),
+    )
+    .with_arguments(make::js_call_arguments(
+        make::token(T!['(']),
+        make::js_call_argument_list(
+            [AnyJsCallArgument::from(string_literal(
+                "Not implemented yet",
+                is_double_quote,
+            ))],
+            [],
+        ),
+        make::token(T![')']),
+    ))
+    .build();
+    make::js_throw_statement(
+        make::token(T![throw]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        error.into(),
+    )
+    .with_semicolon_token(make::token(T![;]))
+    .build()
+}
@@ -0,0 +1,134 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsStatement, JsForInStatement};
+use biome_rowan::{AstNodeList, TextRange};
+
+declare_lint_rule! {
+    /// Require `for...in` loops to filter the properties they iterate over.
+    ///
+    /// A `for...in` loop iterates over the enumerable properties of an object,
+    /// including the properties inherited from its prototype chain.
+    /// Unless the body of the loop filters these properties with an `if` statement,
+    /// it may process properties that were added to `Object.prototype`, or to the prototype of the object.
+    ///
+    /// The body of the loop is guarded if it is a single `if` statement,
+    /// or if it starts with an `if` statement that skips the current property with `continue`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// for (const key in object) {
+    ///     process(object[key]);
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// for (const key in object) {
+    ///     if (Object.hasOwn(object, key)) {
+    ///         process(object[key]);
+    ///     }
+    ///     log(key);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// for (const key in object) {
+    ///     if (Object.hasOwn(object, key)) {
+    ///         process(object[key]);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// for (const key in object) {
+    ///     if (!Object.hasOwn(object, key)) {
+    ///         continue;
+    ///     }
+    ///     process(object[key]);
+    /// }
+    /// ```
+    ///
+    pub UseGuardForIn {
+        version: "next",
+        name: "useGuardForIn",
+        language: "js",
+        sources: &[RuleSource::Eslint("guard-for-in")],
+        recommended: false,
+    }
+}
+
+impl Rule for UseGuardForIn {
+    type Query = Ast<JsForInStatement>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let for_in = ctx.query();
+        if is_guarded(&for_in.body().ok()?) {
+            return None;
+        }
+        // Report the head of the loop: `for (... in ...)`
+        let start = for_in.for_token().ok()?.text_trimmed_range().start();
+        let end = for_in.r_paren_token().ok()?.text_trimmed_range().end();
+        Some(TextRange::new(start, end))
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The body of this "<Emphasis>"for...in"</Emphasis>" loop doesn't filter the properties of the object."
+                },
+            )
+            .note(markup! {
+                "The loop also iterates over the enumerable properties inherited from the prototype chain."
+            })
+            .note(markup! {
+                "Wrap the body in an "<Emphasis>"if"</Emphasis>" statement, such as "<Emphasis>"if (Object.hasOwn(object, key))"</Emphasis>", or skip the unwanted properties with "<Emphasis>"continue"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `body` filters the iterated properties with an `if` statement
+fn is_guarded(body: &AnyJsStatement) -> bool {
+    match body {
+        AnyJsStatement::JsEmptyStatement(_) | AnyJsStatement::JsIfStatement(_) => true,
+        AnyJsStatement::JsBlockStatement(block) => {
+            let statements = block.statements();
+            let Some(AnyJsStatement::JsIfStatement(first)) = statements.first() else {
+                return statements.is_empty();
+            };
+            statements.len() == 1
+                || first
+                    .consequent()
+                    .is_ok_and(|consequent| is_continue(&consequent))
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `statement` is `continue`, or a block that only contains `continue`
+fn is_continue(statement: &AnyJsStatement) -> bool {
+    match statement {
+        AnyJsStatement::JsContinueStatement(_) => true,
+        AnyJsStatement::JsBlockStatement(block) => {
+            let statements = block.statements();
+            statements.len() == 1
+                && matches!(
+                    statements.first(),
+                    Some(AnyJsStatement::JsContinueStatement(_))
+                )
+        }
+        _ => false,
+    }
+}
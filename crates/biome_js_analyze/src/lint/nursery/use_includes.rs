@@ -0,0 +1,212 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, JsBinaryExpression, JsBinaryOperator,
+    JsCallExpression, JsUnaryOperator, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Use `includes()` instead of comparing the result of `indexOf()` to check for the presence of a value.
+    ///
+    /// `includes()` states the intent of the check, and stops as soon as the value is found.
+    /// The rule reports the comparisons of `indexOf()` to `-1`, such as `array.indexOf(value) !== -1`,
+    /// and to `0`, such as `array.indexOf(value) >= 0`.
+    ///
+    /// The fix is unsafe: unlike `indexOf()`, `includes()` finds `NaN` in arrays,
+    /// and the object may have an `indexOf()` method but no `includes()` method.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// if (array.indexOf(value) !== -1) {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// if (text.indexOf("biome") === -1) {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const found = array.indexOf(value) >= 0;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// if (array.includes(value)) {}
+    /// ```
+    ///
+    /// ```js
+    /// const index = array.indexOf(value);
+    /// const isFirst = array.indexOf(value) === 0;
+    /// ```
+    ///
+    pub UseIncludes {
+        version: "next",
+        name: "useIncludes",
+        language: "js",
+        sources: &[
+            RuleSource::EslintUnicorn("prefer-includes"),
+            RuleSource::EslintTypeScript("prefer-includes"),
+        ],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub struct IndexOfComparison {
+    /// The call to `indexOf()`
+    call: JsCallExpression,
+    /// Whether the comparison checks for the absence of the value
+    is_negated: bool,
+}
+
+impl Rule for UseIncludes {
+    type Query = Ast<JsBinaryExpression>;
+    type State = IndexOfComparison;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let binary = ctx.query();
+        let left = binary.left().ok()?.omit_parentheses();
+        let right = binary.right().ok()?.omit_parentheses();
+        let operator = binary.operator().ok()?;
+        // Normalize the comparison, so that the call to `indexOf()` is on the left
+        let (call, operator, value) = match (as_index_of_call(&left), as_index_of_call(&right)) {
+            (Some(call), None) => (call, operator, as_integer(&right)?),
+            (None, Some(call)) => (call, mirror(operator), as_integer(&left)?),
+            _ => return None,
+        };
+        let is_negated = match (operator, value) {
+            (
+                JsBinaryOperator::StrictInequality
+                | JsBinaryOperator::Inequality
+                | JsBinaryOperator::GreaterThan,
+                -1,
+            )
+            | (JsBinaryOperator::GreaterThanOrEqual, 0) => false,
+            (
+                JsBinaryOperator::StrictEquality
+                | JsBinaryOperator::Equality
+                | JsBinaryOperator::LessThanOrEqual,
+                -1,
+            )
+            | (JsBinaryOperator::LessThan, 0) => true,
+            _ => return None,
+        };
+        Some(IndexOfComparison { call, is_negated })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let method = if state.is_negated {
+            "!includes()"
+        } else {
+            "includes()"
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use "<Emphasis>{method}</Emphasis>" instead of comparing the result of "<Emphasis>"indexOf()"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                <Emphasis>"includes()"</Emphasis>" states the intent of the check, and stops as soon as the value is found."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let IndexOfComparison { call, is_negated } = state;
+        let member = call
+            .callee()
+            .ok()?
+            .omit_parentheses()
+            .as_js_static_member_expression()?
+            .clone();
+        let member = member.with_member(make::js_name(make::ident("includes")).into());
+        let includes = AnyJsExpression::from(call.clone().with_callee(member.into()));
+        let replacement = if *is_negated {
+            make::js_unary_expression(make::token(T![!]), includes.trim_trivia()?).into()
+        } else {
+            includes.trim_trivia()?
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(ctx.query().clone()), replacement);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>"includes()"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `expression` if it's a call such as `object.indexOf(value)` or `object.indexOf(value, fromIndex)`
+fn as_index_of_call(expression: &AnyJsExpression) -> Option<JsCallExpression> {
+    let call = expression.as_js_call_expression()?;
+    // `object?.indexOf(value) !== -1` is `true` when `object` is nullish
+    if call.is_optional_chain() {
+        return None;
+    }
+    let callee = call.callee().ok()?.omit_parentheses();
+    let member = callee.as_js_static_member_expression()?;
+    if member
+        .member()
+        .ok()?
+        .as_js_name()?
+        .value_token()
+        .ok()?
+        .text_trimmed()
+        != "indexOf"
+        || matches!(member.object().ok()?, AnyJsExpression::JsSuperExpression(_))
+    {
+        return None;
+    }
+    let arguments = call.arguments().ok()?.args();
+    let has_valid_arguments = matches!(arguments.len(), 1 | 2)
+        && arguments.iter().all(|argument| {
+            argument.is_ok_and(|argument| argument.as_any_js_expression().is_some())
+        });
+    has_valid_arguments.then(|| call.clone())
+}
+
+/// Returns the value of `expression` if it's `-1` or `0`
+fn as_integer(expression: &AnyJsExpression) -> Option<i8> {
+    match expression {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(literal),
+        ) => (literal.as_number()? == 0.0).then_some(0),
+        AnyJsExpression::JsUnaryExpression(unary)
+            if unary.operator().ok()? == JsUnaryOperator::Minus =>
+        {
+            let argument = unary.argument().ok()?.omit_parentheses();
+            let literal = argument
+                .as_any_js_literal_expression()?
+                .as_js_number_literal_expression()?;
+            (literal.as_number()? == 1.0).then_some(-1)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the operator of the comparison with swapped operands
+const fn mirror(operator: JsBinaryOperator) -> JsBinaryOperator {
+    match operator {
+        JsBinaryOperator::LessThan => JsBinaryOperator::GreaterThan,
+        JsBinaryOperator::LessThanOrEqual => JsBinaryOperator::GreaterThanOrEqual,
+        JsBinaryOperator::GreaterThan => JsBinaryOperator::LessThan,
+        JsBinaryOperator::GreaterThanOrEqual => JsBinaryOperator::LessThanOrEqual,
+        operator => operator,
+    }
+}
@@ -0,0 +1,322 @@
+use crate::utils::jsdoc::{jsdoc_comment, returns_value, AnyJsDocumentableFunction, JsDoc};
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern, AnyJsFormalParameter,
+    AnyJsFunctionBody, AnyJsParameter, AnyTsReturnType, AnyTsType,
+};
+use biome_rowan::{AstNode, BatchMutationExt, TextRange};
+
+declare_lint_rule! {
+    /// Enforce that the `@param` and `@returns` tags of JSDoc comments match their function.
+    ///
+    /// When a function changes, its JSDoc comment can be left behind,
+    /// and document parameters that don't exist anymore, or a value that the function doesn't return.
+    /// The rule reports:
+    ///
+    /// - the `@param` tags whose name isn't the name of a parameter of the function;
+    /// - the `@returns` tags of the functions that don't return a value.
+    ///
+    /// A `@param` tag is expected to document the parameter at the same position.
+    /// When this parameter isn't documented by another tag, the fix renames the tag,
+    /// otherwise the fix removes the tag.
+    /// The tag of a destructured parameter can have any name,
+    /// and the tags that document the properties of a parameter, such as `@param options.verbose`, aren't checked.
+    ///
+    /// A function without a return type annotation doesn't return a value if it has no `return` statement with a value.
+    /// The `async` functions, the generators, and the `@returns` tags of type `void`, `undefined`, or `never` are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @param {string} name
+    ///  */
+    /// function greet(username) {
+    ///     console.log(`Hello, ${username}`);
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @param {string} name
+    ///  * @param {string} punctuation
+    ///  */
+    /// function greet(name) {
+    ///     console.log(`Hello, ${name}`);
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /**
+    ///  * @returns {string}
+    ///  */
+    /// function greet() {
+    ///     console.log("Hello");
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /**
+    ///  * @param {string} name
+    ///  * @returns {string}
+    ///  */
+    /// function greet(name) {
+    ///     return `Hello, ${name}`;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// /**
+    ///  * @param {object} options
+    ///  * @param {boolean} options.verbose
+    ///  */
+    /// function configure({ verbose }) {}
+    /// ```
+    ///
+    pub UseJsDocParamsMatch {
+        version: "next",
+        name: "useJsDocParamsMatch",
+        language: "js",
+        sources: &[
+            RuleSource::EslintJsDoc("check-param-names"),
+            RuleSource::EslintJsDoc("require-returns-check"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseJsDocParamsMatch {
+    type Query = Ast<AnyJsDocumentableFunction>;
+    type State = StaleTag;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut stale_tags = Vec::new();
+        let function = ctx.query();
+        let Some(comment) = function
+            .documented_node()
+            .and_then(|node| jsdoc_comment(&node.first_token()?))
+        else {
+            return stale_tags;
+        };
+        let Some(jsdoc) = JsDoc::parse(comment.text()) else {
+            return stale_tags;
+        };
+        let Some(parameters) = parameter_names(function) else {
+            return stale_tags;
+        };
+
+        // The tags of the properties of a parameter, such as `options.verbose`, are ignored
+        let parameter_tags: Vec<_> = jsdoc
+            .tags
+            .iter()
+            .filter_map(|tag| Some((tag, tag.parameter.as_ref()?)))
+            .filter(|(_, parameter)| !parameter.name.contains('.'))
+            .collect();
+        for (position, (tag, documented)) in parameter_tags.iter().enumerate() {
+            if parameters
+                .iter()
+                .any(|name| name.as_deref() == Some(documented.name.as_str()))
+            {
+                continue;
+            }
+            let kind = match parameters.get(position) {
+                // A destructured parameter can be documented under any name
+                Some(None) => continue,
+                Some(Some(parameter))
+                    if parameter_tags
+                        .iter()
+                        .all(|(_, other)| &other.name != parameter) =>
+                {
+                    StaleTagKind::RenamedParameter {
+                        documented: documented.name.clone(),
+                        parameter: parameter.clone(),
+                    }
+                }
+                _ => StaleTagKind::UnknownParameter {
+                    documented: documented.name.clone(),
+                },
+            };
+            stale_tags.push(StaleTag {
+                range: tag.range,
+                highlight: documented.range,
+                kind,
+            });
+        }
+
+        if returns_nothing(function) {
+            for tag in &jsdoc.tags {
+                let documents_nothing = matches!(
+                    tag.type_expression.as_deref(),
+                    Some("void" | "undefined" | "never")
+                );
+                if tag.is_returns() && !documents_nothing {
+                    stale_tags.push(StaleTag {
+                        range: tag.range,
+                        highlight: tag.name_range,
+                        kind: StaleTagKind::Returns,
+                    });
+                }
+            }
+        }
+        stale_tags
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let comment = jsdoc_comment(&ctx.query().documented_node()?.first_token()?)?;
+        let range = state.highlight + comment.text_range().start();
+        let diagnostic = match &state.kind {
+            StaleTagKind::RenamedParameter {
+                documented,
+                parameter,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This "<Emphasis>"@param"</Emphasis>" tag documents "<Emphasis>{documented}</Emphasis>", but the parameter at this position is "<Emphasis>{parameter}</Emphasis>"."
+                },
+            ),
+            StaleTagKind::UnknownParameter { documented } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This "<Emphasis>"@param"</Emphasis>" tag documents "<Emphasis>{documented}</Emphasis>", which isn't a parameter of the function."
+                },
+            ),
+            StaleTagKind::Returns => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This "<Emphasis>"@returns"</Emphasis>" tag documents a return value, but the function doesn't return a value."
+                },
+            ),
+        };
+        Some(diagnostic.note(markup! {
+            "A documentation that doesn't match the function misleads its readers."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let token = ctx.query().documented_node()?.first_token()?;
+        let comment = jsdoc_comment(&token)?;
+        let mut text = comment.text().to_string();
+        let message = match &state.kind {
+            StaleTagKind::RenamedParameter { parameter, .. } => {
+                text.replace_range(
+                    usize::from(state.highlight.start())..usize::from(state.highlight.end()),
+                    parameter,
+                );
+                markup! { "Rename the tag to "<Emphasis>{parameter}</Emphasis>"." }.to_owned()
+            }
+            StaleTagKind::UnknownParameter { .. } | StaleTagKind::Returns => {
+                text.replace_range(
+                    usize::from(state.range.start())..usize::from(state.range.end()),
+                    "",
+                );
+                markup! { "Remove the tag." }.to_owned()
+            }
+        };
+        let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+        let trivia: Vec<_> = pieces
+            .iter()
+            .map(|piece| {
+                if piece.text_range() == comment.text_range() {
+                    (piece.kind(), text.as_str())
+                } else {
+                    (piece.kind(), piece.text())
+                }
+            })
+            .collect();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_discard_trivia(token.clone(), token.with_leading_trivia(trivia));
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            message,
+            mutation,
+        ))
+    }
+}
+
+pub struct StaleTag {
+    /// The range of the tag, relative to the comment
+    range: TextRange,
+    /// The range of the reported part of the tag, relative to the comment
+    highlight: TextRange,
+    kind: StaleTagKind,
+}
+
+pub enum StaleTagKind {
+    /// `@param` tag of the parameter at its position, under another name
+    RenamedParameter {
+        documented: String,
+        parameter: String,
+    },
+    /// `@param` tag that doesn't match any parameter
+    UnknownParameter { documented: String },
+    /// `@returns` tag of a function that doesn't return a value
+    Returns,
+}
+
+/// Returns the names of the parameters of `function`, with `None` for the destructured parameters.
+///
+/// The `this` parameter isn't part of the list.
+fn parameter_names(function: &AnyJsDocumentableFunction) -> Option<Vec<Option<String>>> {
+    let parameters = match function.parameters()? {
+        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+            return Some(vec![binding_name(&binding)]);
+        }
+        AnyJsArrowFunctionParameters::JsParameters(parameters) => parameters,
+    };
+    let mut names = Vec::new();
+    for parameter in parameters.items() {
+        let binding = match parameter.ok()? {
+            AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(
+                parameter,
+            )) => parameter.binding().ok(),
+            AnyJsParameter::JsRestParameter(parameter) => parameter.binding().ok(),
+            AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsBogusParameter(_)) => None,
+            AnyJsParameter::TsThisParameter(_) => continue,
+        };
+        names.push(match binding {
+            Some(AnyJsBindingPattern::AnyJsBinding(binding)) => binding_name(&binding),
+            _ => None,
+        });
+    }
+    Some(names)
+}
+
+fn binding_name(binding: &AnyJsBinding) -> Option<String> {
+    let binding = binding.as_js_identifier_binding()?;
+    Some(binding.name_token().ok()?.text_trimmed().to_string())
+}
+
+/// Returns `true` if `function` doesn't return a value.
+fn returns_nothing(function: &AnyJsDocumentableFunction) -> bool {
+    match function.return_type_annotation() {
+        Some(annotation) => matches!(
+            annotation.ty(),
+            Ok(AnyTsReturnType::AnyTsType(AnyTsType::TsVoidType(_)))
+        ),
+        None => {
+            !function.is_async()
+                && !function.is_generator()
+                && matches!(
+                    function.body(),
+                    Some(AnyJsFunctionBody::JsFunctionBody(body)) if !returns_value(body.syntax())
+                )
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{JsBigintLiteralExpression, JsNumberLiteralExpression, JsSyntaxToken};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, SyntaxResult};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU8;
+
+declare_lint_rule! {
+    /// Enforce the grouping of the digits of the long numeric literals with numeric separators.
+    ///
+    /// The numeric separators (`_`) split the digits of a number into groups, such as `1_000_000`,
+    /// which makes the long numbers easier to read.
+    ///
+    /// The rule reports the numeric literals whose digits reach a minimum count and aren't grouped,
+    /// or are grouped in an inconsistent way.
+    /// The integer part and the exponent of the decimal numbers are grouped from the right,
+    /// and their fractional part from the left.
+    /// The parts that have fewer digits than the minimum are left as written.
+    ///
+    /// The legacy octal literals, such as `0777`, are ignored because they can't contain numeric separators.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const budget = 1000000;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const budget = 10_00_000;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const mask = 0xFFFFFF;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const budget = 1_000_000;
+    /// const price = 9.99;
+    /// const mask = 0xFF_FF_FF;
+    /// const flags = 0b1010_0001;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Each option sets the style of a base: `number` for the decimal numbers,
+    /// `hexadecimal`, `binary` and `octal`.
+    /// A style has two settings:
+    /// - `minimumDigits`: the digits are grouped when a part of the number has at least this count of digits;
+    /// - `groupLength`: the count of digits of each group.
+    ///
+    /// The default styles are:
+    ///
+    /// | Base          | `minimumDigits` | `groupLength` |
+    /// |---------------|-----------------|---------------|
+    /// | `number`      | 5               | 3             |
+    /// | `hexadecimal` | 0               | 2             |
+    /// | `binary`      | 0               | 4             |
+    /// | `octal`       | 0               | 4             |
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "number": {
+    ///             "minimumDigits": 7
+    ///         },
+    ///         "hexadecimal": {
+    ///             "groupLength": 4
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseNumericSeparators {
+        version: "next",
+        name: "useNumericSeparators",
+        language: "js",
+        recommended: false,
+        sources: &[RuleSource::EslintUnicorn("numeric-separators-style")],
+        source_kind: RuleSourceKind::Inspired,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// Options for the rule `useNumericSeparators`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NumericSeparatorsOptions {
+    /// The style of the decimal numbers
+    pub number: NumericSeparatorsStyle,
+    /// The style of the hexadecimal numbers, such as `0xFF_FF`
+    pub hexadecimal: NumericSeparatorsStyle,
+    /// The style of the binary numbers, such as `0b1010_0001`
+    pub binary: NumericSeparatorsStyle,
+    /// The style of the octal numbers, such as `0o7777_7777`
+    pub octal: NumericSeparatorsStyle,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NumericSeparatorsStyle {
+    /// The minimum count of digits of a part of the number to group its digits.
+    /// Defaults to 5 for the decimal numbers, and 0 for the other bases.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_digits: Option<u8>,
+    /// The count of digits of each group.
+    /// Defaults to 3 for the decimal numbers, 2 for the hexadecimal numbers, and 4 for the binary and octal numbers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_length: Option<NonZeroU8>,
+}
+
+impl NumericSeparatorsStyle {
+    /// Returns the minimum count of digits and the length of the groups,
+    /// falling back to `default_minimum_digits` and `default_group_length`
+    fn resolve(&self, default_minimum_digits: u8, default_group_length: u8) -> (usize, usize) {
+        (
+            self.minimum_digits.unwrap_or(default_minimum_digits) as usize,
+            self.group_length
+                .map_or(default_group_length, NonZeroU8::get) as usize,
+        )
+    }
+}
+
+declare_node_union! {
+    pub AnyJsNumericLiteral = JsNumberLiteralExpression | JsBigintLiteralExpression
+}
+
+impl AnyJsNumericLiteral {
+    fn value_token(&self) -> SyntaxResult<JsSyntaxToken> {
+        match self {
+            AnyJsNumericLiteral::JsNumberLiteralExpression(literal) => literal.value_token(),
+            AnyJsNumericLiteral::JsBigintLiteralExpression(literal) => literal.value_token(),
+        }
+    }
+}
+
+impl Rule for UseNumericSeparators {
+    type Query = Ast<AnyJsNumericLiteral>;
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = NumericSeparatorsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let token = ctx.query().value_token().ok()?;
+        let text = token.text_trimmed();
+        let expected = format_numeric_literal(text, ctx.options())?;
+        (expected != text).then_some(expected)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The digits of this number aren't grouped consistently with numeric separators."
+                },
+            )
+            .note(markup! {
+                "Grouping the digits makes the long numbers easier to read."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, expected: &Self::State) -> Option<JsRuleAction> {
+        let token = ctx.query().value_token().ok()?;
+        let new_token = JsSyntaxToken::new_detached(token.kind(), expected, [], []);
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token(token, new_token);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>{expected}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `text` with its digits grouped according to `options`,
+/// or `None` if the literal can't contain numeric separators
+fn format_numeric_literal(text: &str, options: &NumericSeparatorsOptions) -> Option<String> {
+    let (number, suffix) = match text.strip_suffix('n') {
+        Some(number) => (number, "n"),
+        None => (text, ""),
+    };
+    let mut result = String::with_capacity(text.len() + 4);
+    let mut chars = number.chars();
+    match (chars.next(), chars.next()) {
+        (Some('0'), Some(prefix @ ('x' | 'X' | 'b' | 'B' | 'o' | 'O'))) => {
+            let (minimum_digits, group_length) = match prefix {
+                'x' | 'X' => options.hexadecimal.resolve(0, 2),
+                'b' | 'B' => options.binary.resolve(0, 4),
+                _ => options.octal.resolve(0, 4),
+            };
+            result.push_str(&number[..2]);
+            group_from_end(&mut result, &number[2..], minimum_digits, group_length);
+        }
+        // Legacy octal literals, such as `0777`, and the decimal literals with a leading zero, such as `089`
+        (Some('0'), Some(next)) if next.is_ascii_digit() || next == '_' => return None,
+        _ => {
+            let (minimum_digits, group_length) = options.number.resolve(5, 3);
+            let (mantissa, exponent) = match number.find(['e', 'E']) {
+                Some(index) => (&number[..index], Some(&number[index..])),
+                None => (number, None),
+            };
+            let (integer, fraction) = match mantissa.split_once('.') {
+                Some((integer, fraction)) => (integer, Some(fraction)),
+                None => (mantissa, None),
+            };
+            group_from_end(&mut result, integer, minimum_digits, group_length);
+            if let Some(fraction) = fraction {
+                result.push('.');
+                group_from_start(&mut result, fraction, minimum_digits, group_length);
+            }
+            if let Some(exponent) = exponent {
+                // `e`, followed by the optional sign
+                let digits_start = exponent
+                    .find(|c: char| c.is_ascii_digit() || c == '_')
+                    .unwrap_or(exponent.len());
+                result.push_str(&exponent[..digits_start]);
+                group_from_end(
+                    &mut result,
+                    &exponent[digits_start..],
+                    minimum_digits,
+                    group_length,
+                );
+            }
+        }
+    }
+    result.push_str(suffix);
+    Some(result)
+}
+
+/// Pushes the digits of `text` to `result`, grouped from the right, such as the integer part of a number
+fn group_from_end(result: &mut String, text: &str, minimum_digits: usize, group_length: usize) {
+    let digits: Vec<char> = text.chars().filter(|&c| c != '_').collect();
+    if digits.len() < minimum_digits.max(1) {
+        result.push_str(text);
+        return;
+    }
+    for (index, digit) in digits.iter().enumerate() {
+        if index > 0 && (digits.len() - index) % group_length == 0 {
+            result.push('_');
+        }
+        result.push(*digit);
+    }
+}
+
+/// Pushes the digits of `text` to `result`, grouped from the left, such as the fractional part of a number
+fn group_from_start(result: &mut String, text: &str, minimum_digits: usize, group_length: usize) {
+    let digits: Vec<char> = text.chars().filter(|&c| c != '_').collect();
+    if digits.len() < minimum_digits.max(1) {
+        result.push_str(text);
+        return;
+    }
+    for (index, digit) in digits.iter().enumerate() {
+        if index > 0 && index % group_length == 0 {
+            result.push('_');
+        }
+        result.push(*digit);
+    }
+}
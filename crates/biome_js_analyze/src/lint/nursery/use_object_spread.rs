@@ -0,0 +1,201 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    global_identifier, AnyJsCallArgument, AnyJsExpression, AnyJsObjectMember, JsCallExpression,
+    JsExpressionStatement, JsObjectExpression, TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Use the object spread syntax instead of `Object.assign()` to copy objects into a new object.
+    ///
+    /// `Object.assign({}, a, b)` creates a new object and copies the properties of `a` and `b` into it.
+    /// The spread syntax `{ ...a, ...b }` does the same, and is shorter and clearer.
+    ///
+    /// The rule only reports the calls whose first argument is an object literal, which is the object that is created.
+    /// The calls that assign to an existing object, such as `Object.assign(target, source)`, mutate it and aren't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const merged = Object.assign({}, defaults, options);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const user = Object.assign({ id: 1 }, profile);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const merged = { ...defaults, ...options };
+    /// ```
+    ///
+    /// ```js
+    /// Object.assign(target, source);
+    /// ```
+    ///
+    /// ```js
+    /// const merged = Object.assign({}, ...sources);
+    /// ```
+    ///
+    pub UseObjectSpread {
+        version: "next",
+        name: "useObjectSpread",
+        language: "js",
+        sources: &[RuleSource::Eslint("prefer-object-spread")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for UseObjectSpread {
+    type Query = Semantic<JsCallExpression>;
+    type State = JsObjectExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        if call.is_optional_chain() {
+            return None;
+        }
+        let callee = call.callee().ok()?.omit_parentheses();
+        let callee = callee.as_js_static_member_expression()?;
+        if callee
+            .member()
+            .ok()?
+            .as_js_name()?
+            .value_token()
+            .ok()?
+            .text_trimmed()
+            != "assign"
+        {
+            return None;
+        }
+        let (reference, name) = global_identifier(&callee.object().ok()?.omit_parentheses())?;
+        if name.text() != "Object" || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        let mut arguments = call.arguments().ok()?.args().iter();
+        let AnyJsCallArgument::AnyJsExpression(AnyJsExpression::JsObjectExpression(target)) =
+            arguments.next()?.ok()?
+        else {
+            return None;
+        };
+        // The accessors of the target are called by `Object.assign()`, but replaced by the spread syntax
+        let has_accessors = target.members().iter().any(|member| {
+            matches!(
+                member,
+                Ok(AnyJsObjectMember::JsGetterObjectMember(_)
+                    | AnyJsObjectMember::JsSetterObjectMember(_))
+            )
+        });
+        // `...sources` spreads an array of objects, which can't be spread into an object
+        let has_spread_arguments = arguments
+            .any(|argument| !matches!(argument, Ok(AnyJsCallArgument::AnyJsExpression(_))));
+        (!has_accessors && !has_spread_arguments).then_some(target)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use the object spread syntax instead of "<Emphasis>"Object.assign()"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The spread syntax creates the same object, and is shorter and clearer."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, target: &Self::State) -> Option<JsRuleAction> {
+        let call = ctx.query();
+        let members = target.members();
+        let mut items = Vec::new();
+        let mut separators = Vec::new();
+        for element in members.elements() {
+            items.push(element.node().ok()?.clone());
+            if let Some(separator) = element.trailing_separator().ok()? {
+                separators.push(separator.clone());
+            }
+        }
+        let mut spreads = Vec::new();
+        for argument in call.arguments().ok()?.args().iter().skip(1) {
+            let argument = argument.ok()?;
+            let argument = argument.as_any_js_expression()?.clone().trim_trivia()?;
+            spreads.push(AnyJsObjectMember::from(make::js_spread(
+                make::token(T![...]),
+                argument,
+            )));
+        }
+        // The trailing separator stays after the last spread
+        let trailing_separator = if separators.len() == items.len() && !spreads.is_empty() {
+            separators.pop()
+        } else {
+            None
+        };
+        if trailing_separator.is_none() && !items.is_empty() && !spreads.is_empty() {
+            // The trivia before the closing delimiter moves after the last spread
+            let last = items.pop()?;
+            let trailing_trivia = last.syntax().last_trailing_trivia()?.pieces();
+            let last_spread = spreads
+                .pop()?
+                .with_trailing_trivia_pieces(trailing_trivia)?;
+            spreads.push(last_spread);
+            items.push(last.with_trailing_trivia_pieces([])?);
+        }
+        for spread in spreads {
+            if separators.len() < items.len() {
+                separators.push(
+                    make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                );
+            }
+            items.push(spread);
+        }
+        separators.extend(trailing_separator);
+        let object = if members.is_empty() {
+            make::js_object_expression(
+                make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_object_member_list(items, separators),
+                make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            )
+        } else {
+            make::js_object_expression(
+                target.l_curly_token().ok()?,
+                make::js_object_member_list(items, separators),
+                target.r_curly_token().ok()?,
+            )
+        };
+        // An object at the start of a statement or of the body of an arrow function would be parsed as a block
+        let parent = call.syntax().parent()?;
+        let replacement = if JsExpressionStatement::can_cast(parent.kind())
+            || AnyJsExpression::cast(parent)
+                .is_some_and(|parent| parent.as_js_arrow_function_expression().is_some())
+        {
+            make::parenthesized(object).into()
+        } else {
+            AnyJsExpression::from(object)
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(call.clone()), replacement);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use the spread syntax instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
@@ -0,0 +1,365 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::{JsRegexLiteralExpression, JsSyntaxKind, JsSyntaxToken};
+use biome_rowan::{BatchMutationExt, TextRange, TextSize};
+
+use crate::utils::regex_ast::{
+    parse_regex, CharSet, CharacterClass, ClassItemKind, GroupKind, RegexFlags, TermKind,
+};
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Enforce the simplest form of the parts of regular expression literals.
+    ///
+    /// Regular expressions are easier to read when they use the shortest equivalent syntax.
+    /// The rule reports:
+    ///
+    /// - the character classes equivalent to `\d` or `\w`, such as `[0-9]` and `[a-zA-Z0-9_]`;
+    /// - the character classes of a single character or escape, such as `[a]` and `[^\s]`;
+    /// - the quantifiers written with braces that have a shorter form, such as `{0,}`, `{1,}`, `{0,1}`, `{1}`, and `{2,2}`;
+    /// - the non-capturing groups that can be removed, such as `(?:ab)` and `(?:a)+`.
+    ///
+    /// The fixes don't change the strings matched by the regular expression.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /[0-9]+/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /a{1,}/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /(?:a)+/;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /\d+/;
+    /// ```
+    ///
+    /// ```js
+    /// /(?:ab)+/;
+    /// ```
+    ///
+    pub UseOptimizedRegex {
+        version: "next",
+        name: "useOptimizedRegex",
+        language: "js",
+        sources: &[
+            RuleSource::EslintRegexp("prefer-d"),
+            RuleSource::EslintRegexp("prefer-w"),
+            RuleSource::EslintRegexp("no-useless-character-class"),
+            RuleSource::EslintRegexp("prefer-plus-quantifier"),
+            RuleSource::EslintRegexp("prefer-star-quantifier"),
+            RuleSource::EslintRegexp("prefer-question-quantifier"),
+            RuleSource::EslintRegexp("no-useless-two-nums-quantifier"),
+            RuleSource::EslintRegexp("no-useless-non-capturing-group"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for UseOptimizedRegex {
+    type Query = Ast<JsRegexLiteralExpression>;
+    type State = Simplification;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut simplifications = Vec::new();
+        let Ok((pattern, flags)) = ctx.query().decompose() else {
+            return simplifications;
+        };
+        let pattern = pattern.text();
+        let flags = RegexFlags::from_text(flags.text());
+        let Some(disjunction) = parse_regex(pattern, flags) else {
+            return simplifications;
+        };
+        let source = |range: TextRange| &pattern[range];
+        // The repeated groups are simplified with their quantifier
+        let mut quantified_groups = Vec::new();
+        disjunction.for_each_term(&mut |term| match &term.kind {
+            TermKind::Class(class) => {
+                if let Some(replacement) = simplify_class(class, source(term.range), pattern, flags)
+                {
+                    simplifications.push(Simplification {
+                        range: term.range,
+                        replacement,
+                        kind: SimplificationKind::CharacterClass,
+                    });
+                }
+            }
+            TermKind::Quantified(repeated, quantifier) => {
+                if let Some(replacement) = simplify_quantifier(
+                    quantifier.min,
+                    quantifier.max,
+                    quantifier.greedy,
+                    source(quantifier.range),
+                ) {
+                    let is_removed_after_backreference = replacement.is_empty()
+                        && matches!(repeated.kind, TermKind::Backreference)
+                        && starts_with_digit(&pattern[quantifier.range.end().into()..]);
+                    if !is_removed_after_backreference {
+                        let kind = if replacement.is_empty() {
+                            SimplificationKind::UselessQuantifier
+                        } else {
+                            SimplificationKind::Quantifier
+                        };
+                        simplifications.push(Simplification {
+                            range: quantifier.range,
+                            replacement,
+                            kind,
+                        });
+                    }
+                }
+                if let TermKind::Group(group) = &repeated.kind {
+                    quantified_groups.push(repeated.range);
+                    if let (GroupKind::NonCapturing, [alternative]) =
+                        (group.kind, group.disjunction.alternatives.as_slice())
+                    {
+                        if let [content] = alternative.terms.as_slice() {
+                            let content_text = source(content.range);
+                            if !matches!(
+                                content.kind,
+                                TermKind::Quantified(..) | TermKind::Assertion
+                            ) && !starts_with_digit(content_text)
+                            {
+                                simplifications.push(Simplification {
+                                    range: repeated.range,
+                                    replacement: content_text.to_string(),
+                                    kind: SimplificationKind::Group,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+            TermKind::Group(group) => {
+                let [alternative] = group.disjunction.alternatives.as_slice() else {
+                    return;
+                };
+                if group.kind != GroupKind::NonCapturing || quantified_groups.contains(&term.range)
+                {
+                    return;
+                }
+                let content = source(alternative.range);
+                let following = &pattern[term.range.end().into()..];
+                let can_join_following = if content.is_empty() {
+                    // `\1(?:)0` must not become `\10`
+                    following.is_empty() || following.starts_with(['|', ')'])
+                } else {
+                    !matches!(
+                        alternative.terms.last().map(|term| &term.kind),
+                        Some(TermKind::Backreference)
+                    ) || !starts_with_digit(following)
+                };
+                // `a{(?:2)}` and `a{2(?:,)}` must not become quantifiers
+                if can_join_following
+                    && !starts_with_digit(content)
+                    && !content.starts_with(['{', '}', ','])
+                {
+                    simplifications.push(Simplification {
+                        range: term.range,
+                        replacement: content.to_string(),
+                        kind: SimplificationKind::Group,
+                    });
+                }
+            }
+            _ => {}
+        });
+        simplifications
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let regex_token = ctx.query().value_token().ok()?;
+        // Add 1 to skip the opening `/`
+        let pattern_start = regex_token.text_trimmed_range().start() + TextSize::from(1);
+        let range = state.range + pattern_start;
+        let message = match state.kind {
+            SimplificationKind::CharacterClass => markup! {
+                "This character class can be simplified."
+            },
+            SimplificationKind::Quantifier => markup! {
+                "This quantifier can be simplified."
+            },
+            SimplificationKind::UselessQuantifier => markup! {
+                "This quantifier is useless."
+            },
+            SimplificationKind::Group => markup! {
+                "This non-capturing group is unnecessary."
+            },
+        };
+        Some(RuleDiagnostic::new(rule_category!(), range, message))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let regex_token = ctx.query().value_token().ok()?;
+        let regex_text = regex_token.text_trimmed();
+        // Add 1 to skip the opening `/`
+        let start = usize::from(state.range.start()) + 1;
+        let end = usize::from(state.range.end()) + 1;
+        let new_regex = JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_REGEX_LITERAL,
+            &format!(
+                "{}{}{}",
+                &regex_text[..start],
+                state.replacement,
+                &regex_text[end..]
+            ),
+            [],
+            [],
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token(regex_token, new_regex);
+        let replacement = &state.replacement;
+        let message = match state.kind {
+            SimplificationKind::CharacterClass | SimplificationKind::Quantifier => markup! {
+                "Use "<Emphasis>{replacement}</Emphasis>" instead."
+            }
+            .to_owned(),
+            SimplificationKind::UselessQuantifier => markup! {
+                "Remove the quantifier."
+            }
+            .to_owned(),
+            SimplificationKind::Group => markup! {
+                "Remove the group."
+            }
+            .to_owned(),
+        };
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            message,
+            mutation,
+        ))
+    }
+}
+
+pub struct Simplification {
+    /// The range of the simplified part, relative to the pattern.
+    range: TextRange,
+    replacement: String,
+    kind: SimplificationKind,
+}
+
+pub enum SimplificationKind {
+    /// `[0-9]` or `[a]`
+    CharacterClass,
+    /// `{1,}`
+    Quantifier,
+    /// `{1}`
+    UselessQuantifier,
+    /// `(?:a)`
+    Group,
+}
+
+/// Characters that must be escaped outside a character class.
+const SYNTAX_CHARACTERS: &str = r"^$\.*+?()[]{}|/";
+
+fn starts_with_digit(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Returns the shortest equivalent of a class, or `None` if the class is already the shortest.
+fn simplify_class(
+    class: &CharacterClass,
+    class_text: &str,
+    pattern: &str,
+    flags: RegexFlags,
+) -> Option<String> {
+    if let [item] = class.items.as_slice() {
+        let item_text = &pattern[item.range];
+        match item.kind {
+            ClassItemKind::Character(c) if !class.negated => {
+                let Some(escaped) = item_text.strip_prefix('\\') else {
+                    let c = char::from_u32(c)?;
+                    if c.is_ascii_digit() {
+                        // `\1[0]` must not become `\10`
+                        return None;
+                    }
+                    return Some(if SYNTAX_CHARACTERS.contains(c) {
+                        format!("\\{c}")
+                    } else {
+                        c.to_string()
+                    });
+                };
+                // `\b` and `\-` have other meanings outside a class
+                let keeps_meaning = escaped
+                    .starts_with(|c: char| SYNTAX_CHARACTERS.contains(c) || "tnvfrxu".contains(c))
+                    || (escaped.starts_with('c') && escaped.len() == 2);
+                return keeps_meaning.then(|| item_text.to_string());
+            }
+            ClassItemKind::Escape(_) => {
+                if !class.negated {
+                    return Some(item_text.to_string());
+                }
+                if flags.unicode_sets && flags.ignore_case {
+                    return None;
+                }
+                // `[^\d]` is `\D`
+                let mut negated = item_text.to_string();
+                let letter = negated[1..2].to_string();
+                let swapped_letter = if letter == letter.to_lowercase() {
+                    letter.to_uppercase()
+                } else {
+                    letter.to_lowercase()
+                };
+                negated.replace_range(1..2, &swapped_letter);
+                return Some(negated);
+            }
+            ClassItemKind::Range(..) => {}
+            _ => return None,
+        }
+    }
+    let set = class.unnegated_char_set()?;
+    let escape = if set == CharSet::digit() {
+        'd'
+    } else if set == CharSet::word() && !(flags.ignore_case && flags.unicode) {
+        // With the `i` and `u` flags, `\w` also matches `ſ` and the Kelvin sign
+        'w'
+    } else {
+        return None;
+    };
+    let escape = if class.negated {
+        escape.to_ascii_uppercase()
+    } else {
+        escape
+    };
+    let replacement = format!("\\{escape}");
+    (replacement != class_text).then_some(replacement)
+}
+
+/// Returns the shortest equivalent of a quantifier written with braces.
+fn simplify_quantifier(
+    min: u32,
+    max: Option<u32>,
+    greedy: bool,
+    quantifier_text: &str,
+) -> Option<String> {
+    if !quantifier_text.starts_with('{') {
+        return None;
+    }
+    let lazy = if greedy { "" } else { "?" };
+    Some(match (min, max) {
+        (0, None) => format!("*{lazy}"),
+        (1, None) => format!("+{lazy}"),
+        (0, Some(1)) => format!("?{lazy}"),
+        // `a{1}?` matches exactly one `a`, like `a`
+        (1, Some(1)) => String::new(),
+        (min, Some(max)) if min == max && quantifier_text.contains(',') => {
+            format!("{{{min}}}{lazy}")
+        }
+        _ => return None,
+    })
+}
@@ -0,0 +1,275 @@
+use crate::react::hooks::{is_react_component, is_react_hook};
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_semantic::{Binding, SemanticModel};
+use biome_js_syntax::{
+    AnyFunctionLike, AnyJsBinding, AnyJsExpression, AnyJsFunction, AnyJsName,
+    AnyJsNamedImportSpecifier, JsIdentifierBinding, JsImport, JsStaticMemberAssignment,
+    JsStaticMemberExpression, JsVariableDeclarator,
+};
+use biome_project::PackageJson;
+use biome_rowan::{declare_node_union, AstNode, SyntaxResult, TextRange};
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Enforce the reactive use of the signals of Preact.
+    ///
+    /// The signals of [Preact](https://preactjs.com/guide/v10/signals/) hold a value in their `value` property.
+    /// Reading `value` in a component, a computed signal, or an effect subscribes to the changes of the signal.
+    ///
+    /// The rule reports:
+    /// - the reads of `value` at the top level of a module, that run once when the module is loaded
+    ///   and don't follow the changes of the signal;
+    /// - the writes of `value` during the render of a component or a hook,
+    ///   that trigger a new render and can loop forever.
+    ///
+    /// The signals are the variables initialized with `signal`, `computed`, `useSignal`, or `useComputed`,
+    /// imported from a package of Preact Signals, such as `@preact/signals`.
+    ///
+    /// The rule only applies to projects that declare a package of Preact Signals,
+    /// such as `@preact/signals` or `@preact/signals-react`, in the dependencies of their `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// import { signal } from "@preact/signals";
+    ///
+    /// const count = signal(0);
+    /// const double = count.value * 2;
+    /// ```
+    ///
+    /// ```jsx,ignore
+    /// import { useSignal } from "@preact/signals";
+    ///
+    /// function Counter() {
+    ///     const count = useSignal(0);
+    ///     count.value++;
+    ///     return <p>{count}</p>;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// import { computed, signal } from "@preact/signals";
+    ///
+    /// const count = signal(0);
+    /// const double = computed(() => count.value * 2);
+    /// ```
+    ///
+    /// ```jsx,ignore
+    /// import { useSignal } from "@preact/signals";
+    ///
+    /// function Counter() {
+    ///     const count = useSignal(0);
+    ///     return <button onClick={() => count.value++}>{count}</button>;
+    /// }
+    /// ```
+    ///
+    pub UsePreactSignalsCorrectly {
+        version: "next",
+        name: "usePreactSignalsCorrectly",
+        language: "js",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnySignalValueAccess = JsStaticMemberExpression | JsStaticMemberAssignment
+}
+
+impl AnySignalValueAccess {
+    fn object(&self) -> SyntaxResult<AnyJsExpression> {
+        match self {
+            Self::JsStaticMemberExpression(expression) => expression.object(),
+            Self::JsStaticMemberAssignment(assignment) => assignment.object(),
+        }
+    }
+
+    fn member(&self) -> SyntaxResult<AnyJsName> {
+        match self {
+            Self::JsStaticMemberExpression(expression) => expression.member(),
+            Self::JsStaticMemberAssignment(assignment) => assignment.member(),
+        }
+    }
+}
+
+pub enum SignalMisuse {
+    /// The value of the signal is read at the top level of the module
+    ReadOutsideReactiveContext { signal: String, range: TextRange },
+    /// The value of the signal is written during the render of a component or a hook
+    WriteDuringRender { signal: String, range: TextRange },
+}
+
+/// The functions of Preact Signals that create a signal
+const SIGNAL_FACTORIES: [&str; 4] = ["computed", "signal", "useComputed", "useSignal"];
+
+impl Rule for UsePreactSignalsCorrectly {
+    type Query = Semantic<AnySignalValueAccess>;
+    type State = SignalMisuse;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let access = ctx.query();
+        if access.member().ok()?.value_token().ok()?.text_trimmed() != "value" {
+            return None;
+        }
+        if !depends_on_preact_signals(ctx) {
+            return None;
+        }
+        let object = access.object().ok()?.omit_parentheses();
+        let reference = object.as_js_reference_identifier()?;
+        let binding = ctx.model().binding(&reference)?;
+        if !is_signal(&binding, ctx.model()) {
+            return None;
+        }
+        let signal = reference.name().ok()?.text().to_string();
+        let range = access.range();
+        let enclosing_function = access
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find_map(AnyFunctionLike::cast);
+        match (access, enclosing_function) {
+            (AnySignalValueAccess::JsStaticMemberExpression(_), None) => {
+                Some(SignalMisuse::ReadOutsideReactiveContext { signal, range })
+            }
+            (
+                AnySignalValueAccess::JsStaticMemberAssignment(_),
+                Some(AnyFunctionLike::AnyJsFunction(function)),
+            ) if is_component_or_hook(&function) => {
+                Some(SignalMisuse::WriteDuringRender { signal, range })
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            SignalMisuse::ReadOutsideReactiveContext { signal, range } => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The value of the signal "<Emphasis>{signal}</Emphasis>" is read at the top level of the module."
+                },
+            )
+            .note(markup! {
+                "The value is read once when the module is loaded, and doesn't follow the changes of the signal."
+            })
+            .note(markup! {
+                "Read the value in a component, a computed signal, or an effect instead, such as "<Emphasis>"computed(() => "{signal}".value)"</Emphasis>"."
+            }),
+            SignalMisuse::WriteDuringRender { signal, range } => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The value of the signal "<Emphasis>{signal}</Emphasis>" is written during the render."
+                },
+            )
+            .note(markup! {
+                "Writing a signal during the render triggers a new render, which can loop forever."
+            })
+            .note(markup! {
+                "Write the signal in an event handler or an effect instead."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns `true` if the `package.json` of the project declares a package of Preact Signals,
+/// such as `@preact/signals` or `@preact/signals-react`
+fn depends_on_preact_signals(ctx: &RuleContext<UsePreactSignalsCorrectly>) -> bool {
+    let Some(Some(manifest)) = ctx
+        .get_service::<Arc<Option<PackageJson>>>()
+        .map(|manifest| manifest.as_ref())
+    else {
+        return false;
+    };
+    [
+        &manifest.dependencies,
+        &manifest.dev_dependencies,
+        &manifest.peer_dependencies,
+    ]
+    .into_iter()
+    .any(|dependencies| {
+        dependencies
+            .names()
+            .any(|name| name.starts_with("@preact/signals"))
+    })
+}
+
+/// Returns `true` if `binding` is a variable initialized by a call to a function of Preact Signals
+/// that creates a signal, such as `const count = signal(0)`
+fn is_signal(binding: &Binding, model: &SemanticModel) -> bool {
+    let Some(declarator) = binding
+        .syntax()
+        .parent()
+        .and_then(JsVariableDeclarator::cast)
+    else {
+        return false;
+    };
+    let Some(callee) = declarator
+        .initializer()
+        .and_then(|initializer| initializer.expression().ok())
+        .and_then(|expression| {
+            expression
+                .omit_parentheses()
+                .as_js_call_expression()?
+                .callee()
+                .ok()
+        })
+    else {
+        return false;
+    };
+    callee
+        .omit_parentheses()
+        .as_js_reference_identifier()
+        .and_then(|reference| model.binding(&reference))
+        .is_some_and(|factory| is_signal_factory_import(&factory))
+}
+
+/// Returns `true` if `binding` is a function imported from a package of Preact Signals that creates a signal
+fn is_signal_factory_import(binding: &Binding) -> bool {
+    let Some(identifier) = JsIdentifierBinding::cast_ref(binding.syntax()) else {
+        return false;
+    };
+    let Some(specifier) = identifier.parent::<AnyJsNamedImportSpecifier>() else {
+        return false;
+    };
+    let imported_name = match &specifier {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => specifier
+            .name()
+            .ok()
+            .and_then(|name| name.value().ok())
+            .map(|name| name.text_trimmed().to_string()),
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => identifier
+            .name_token()
+            .ok()
+            .map(|name| name.text_trimmed().to_string()),
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => None,
+    };
+    if !imported_name.is_some_and(|name| SIGNAL_FACTORIES.contains(&name.as_str())) {
+        return false;
+    }
+    specifier
+        .syntax()
+        .ancestors()
+        .find_map(|ancestor| JsImport::cast(ancestor)?.source_text().ok())
+        .is_some_and(|source| source.text().starts_with("@preact/signals"))
+}
+
+/// Returns `true` if `function` is a component or a hook, whose body runs during the render
+fn is_component_or_hook(function: &AnyJsFunction) -> bool {
+    let Some(AnyJsBinding::JsIdentifierBinding(binding)) = function.binding() else {
+        return false;
+    };
+    binding.name_token().is_ok_and(|name| {
+        let name = name.text_trimmed();
+        is_react_component(name) || is_react_hook(name)
+    })
+}
@@ -1,10 +1,20 @@
-use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
-use biome_aria::AriaRoles;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
 use biome_console::markup;
-use biome_js_syntax::{JsxAttribute, JsxOpeningElement};
-use biome_rowan::TextRange;
+use biome_js_factory::make::{
+    jsx_attribute, jsx_attribute_initializer_clause, jsx_attribute_list, jsx_ident, jsx_name,
+    jsx_string, jsx_string_literal, token,
+};
+use biome_js_syntax::{
+    AnyJsxAttribute, AnyJsxAttributeName, AnyJsxAttributeValue, JsxAttribute, JsxElement,
+    JsxOpeningElement, T,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, TextRange, TriviaPieceKind};
 
 use crate::services::aria::Aria;
+use crate::JsRuleAction;
 
 declare_lint_rule! {
     /// It detects the use of `role` attributes in JSX elements and suggests using semantic elements instead.
@@ -24,6 +34,16 @@ declare_lint_rule! {
     /// <div role="img">
     /// ```
     ///
+    /// When the role has an equivalent element that doesn't require other attributes,
+    /// the rule suggests to replace the element.
+    /// For example, `<div role="navigation"></div>` can be rewritten to `<nav></nav>`,
+    /// and `<div role="heading" aria-level="3"></div>` can be rewritten to `<h3></h3>`.
+    /// The fix is unsafe because the styles and the scripts that target the original element no longer apply.
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <div role="button" onClick={submit}>Submit</div>
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```jsx
@@ -40,6 +60,7 @@ declare_lint_rule! {
         language: "jsx",
         sources: &[RuleSource::EslintJsxA11y("prefer-tag-over-role")],
         recommended: true,
+        fix_kind: FixKind::Unsafe,
     }
 }
 
@@ -73,12 +94,12 @@ impl Rule for UseSemanticElements {
         None
     }
 
-    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
         let role_attribute = state;
 
         let static_value = role_attribute.as_static_value()?;
         let role_value = static_value.as_string_constant()?;
-        let candidate = AriaRoles.get_elements_by_role(role_value);
+        let candidate = ctx.aria_roles().get_elements_by_role(role_value);
 
         let mut result_elements: Vec<&str> = vec![];
         let mut result_attributes: Vec<(&str, &str)> = vec![];
@@ -132,4 +153,132 @@ impl Rule for UseSemanticElements {
             }),
         )
     }
+
+    fn action(ctx: &RuleContext<Self>, role_attribute: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let element_name = node.name().ok()?.as_jsx_name()?.value_token().ok()?;
+        let role = role_attribute.as_static_value()?;
+        let role = role.as_string_constant()?.trim();
+        let attributes = node.attributes();
+        let (replacement, removed_attribute) = if role == "heading" {
+            // The default level of a heading is 2
+            let level = match node.find_attribute_by_name("aria-level").ok()? {
+                Some(level) => {
+                    let level = level.as_static_value()?;
+                    let level = level.as_string_constant()?.trim();
+                    let level = ["1", "2", "3", "4", "5", "6"]
+                        .into_iter()
+                        .position(|valid_level| valid_level == level)?;
+                    HEADING_ELEMENTS[level]
+                }
+                None => HEADING_ELEMENTS[1],
+            };
+            (SemanticElement::new(level), Some("aria-level"))
+        } else {
+            (SemanticElement::from_role(role)?, None)
+        };
+        if element_name.text_trimmed() == replacement.name {
+            return None;
+        }
+
+        let mut new_attributes = Vec::with_capacity(attributes.len());
+        for attribute in attributes.iter() {
+            // The attributes of a spread could conflict with the new element
+            let AnyJsxAttribute::JsxAttribute(jsx_attribute) = &attribute else {
+                return None;
+            };
+            let name = jsx_attribute.name().ok()?;
+            let name = name.as_jsx_name()?.value_token().ok()?;
+            let name = name.text_trimmed();
+            if replacement
+                .attributes
+                .iter()
+                .any(|(attribute_name, _)| *attribute_name == name)
+            {
+                return None;
+            }
+            if jsx_attribute != role_attribute && Some(name) != removed_attribute {
+                new_attributes.push(attribute);
+            }
+        }
+        for (attribute_name, attribute_value) in replacement.attributes {
+            let new_attribute = jsx_attribute(AnyJsxAttributeName::JsxName(jsx_name(
+                jsx_ident(attribute_name).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            )))
+            .with_initializer(jsx_attribute_initializer_clause(
+                token(T![=]),
+                AnyJsxAttributeValue::JsxString(jsx_string(jsx_string_literal(attribute_value))),
+            ))
+            .build();
+            new_attributes.push(AnyJsxAttribute::JsxAttribute(new_attribute));
+        }
+
+        let mut mutation = ctx.root().begin();
+        if let Some(closing_element) = node
+            .parent::<JsxElement>()
+            .and_then(|element| element.closing_element().ok())
+        {
+            let closing_name = closing_element.name().ok()?;
+            let closing_name = closing_name.as_jsx_name()?.value_token().ok()?;
+            mutation.replace_token_transfer_trivia(closing_name, jsx_ident(replacement.name));
+        }
+        if new_attributes.is_empty() {
+            // Remove the whitespace that separated the name from the attributes
+            let new_name = jsx_ident(replacement.name)
+                .with_leading_trivia_pieces(element_name.leading_trivia().pieces());
+            mutation.replace_token_discard_trivia(element_name, new_name);
+        } else {
+            mutation.replace_token_transfer_trivia(element_name, jsx_ident(replacement.name));
+        }
+        mutation.replace_node(attributes, jsx_attribute_list(new_attributes));
+
+        let new_element = replacement.name;
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use a "<Emphasis>"<"{new_element}">"</Emphasis>" element instead." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+const HEADING_ELEMENTS: [&str; 6] = ["h1", "h2", "h3", "h4", "h5", "h6"];
+
+/// An element that can replace an element with an ARIA role.
+struct SemanticElement {
+    name: &'static str,
+    /// Attributes that the element requires to keep the behavior of the role.
+    attributes: &'static [(&'static str, &'static str)],
+}
+
+impl SemanticElement {
+    const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            attributes: &[],
+        }
+    }
+
+    /// Returns the element that has the same semantics as `role`,
+    /// if the replacement doesn't require the element to hold extra information such as a `href` or an `alt`.
+    fn from_role(role: &str) -> Option<Self> {
+        Some(match role {
+            "article" => Self::new("article"),
+            "banner" => Self::new("header"),
+            // A `<button>` submits its form by default
+            "button" => Self {
+                name: "button",
+                attributes: &[("type", "button")],
+            },
+            "complementary" => Self::new("aside"),
+            "contentinfo" => Self::new("footer"),
+            "figure" => Self::new("figure"),
+            "list" => Self::new("ul"),
+            "listitem" => Self::new("li"),
+            "main" => Self::new("main"),
+            "navigation" => Self::new("nav"),
+            _ => return None,
+        })
+    }
 }
@@ -0,0 +1,358 @@
+use crate::utils::regex::RestrictedRegex;
+use crate::utils::sort::{sort_run, unsorted_runs, SortableRun};
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsArrayElement, AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBindingPattern,
+    AnyJsClassMemberName, AnyJsExpression, AnyJsObjectMember, AnyJsObjectMemberName,
+    AnyJsTemplateElement, JsAssignmentExpression, JsObjectExpression, JsPropertyClassMember,
+    JsPropertyObjectMember, JsSyntaxKind, JsUnaryOperator, JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce sorted keys in the object literals of the configured contexts.
+    ///
+    /// This rule is the lint counterpart of the `useSortedKeys` assist: it reports the objects
+    /// whose properties aren't in natural order, but only in the contexts where the order matters
+    /// to the project, such as the objects of configuration or the lookup tables.
+    ///
+    /// The contexts are selected with the `identifiers` option: an object is checked when it's
+    /// assigned to a variable, a property, or a class property whose name matches one of the regular
+    /// expressions, or when it's nested in such an object.
+    /// When the option is empty, all the objects are checked.
+    /// The rule can be restricted to some files with the `overrides` of the configuration.
+    ///
+    /// Spread elements and properties with computed names are never moved:
+    /// only the properties between them are sorted.
+    /// The comments of the properties move with them.
+    ///
+    /// The fix is safe because it's only provided when the values of the properties don't have
+    /// side effects, so that the order in which they're evaluated doesn't matter.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const object = {
+    ///     zed: 1,
+    ///     alpha: 2,
+    /// };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const object = {
+    ///     alpha: 2,
+    ///     ...rest,
+    ///     zed: 1,
+    /// };
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `identifiers`
+    ///
+    /// The regular expressions that select the names of the checked objects.
+    /// The regular expressions are matched against the whole name.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "identifiers": [".*(CONFIG|MAP)"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// With these options, only the first object is checked:
+    ///
+    /// ```js,ignore
+    /// const ROUTE_MAP = {
+    ///     home: "/",
+    ///     about: "/about",
+    /// };
+    ///
+    /// const routes = {
+    ///     home: "/",
+    ///     about: "/about",
+    /// };
+    /// ```
+    ///
+    pub UseSortedObjectKeys {
+        version: "next",
+        name: "useSortedObjectKeys",
+        language: "js",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseSortedObjectKeysOptions {
+    /// The regular expressions that select the names of the checked objects.
+    /// All the objects are checked when it's empty.
+    pub identifiers: Vec<RestrictedRegex>,
+}
+
+pub struct UnsortedObject {
+    runs: Vec<SortableRun<AnyJsObjectMember>>,
+    /// The name that selected the object
+    context: Option<String>,
+}
+
+impl Rule for UseSortedObjectKeys {
+    type Query = Ast<JsObjectExpression>;
+    type State = UnsortedObject;
+    type Signals = Option<Self::State>;
+    type Options = UseSortedObjectKeysOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let object = ctx.query();
+        let identifiers = &ctx.options().identifiers;
+        let context = if identifiers.is_empty() {
+            None
+        } else {
+            let name = context_names(object)
+                .into_iter()
+                .find(|name| identifiers.iter().any(|regex| regex.is_match(name)))?;
+            Some(name)
+        };
+        let members = object.members().iter().filter_map(|member| {
+            let member = member.ok()?;
+            let key = member_key(&member);
+            Some((member, key))
+        });
+        let runs = unsorted_runs(members);
+        (!runs.is_empty()).then_some(UnsortedObject { runs, context })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let run = state.runs.first()?;
+        let range = TextRange::new(run.first()?.0.range().start(), run.last()?.0.range().end());
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            range,
+            markup! {
+                "The properties of this object aren't sorted."
+            },
+        );
+        let diagnostic = match &state.context {
+            Some(context) => diagnostic.note(markup! {
+                "The properties of "<Emphasis>{context}</Emphasis>" must be sorted in natural order."
+            }),
+            None => diagnostic.note(markup! {
+                "The properties of the objects must be sorted in natural order."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        // Reordering the values of the properties mustn't reorder their side effects
+        let is_pure = state
+            .runs
+            .iter()
+            .flatten()
+            .all(|(member, _)| is_pure_member(member));
+        if !is_pure {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        for run in &state.runs {
+            sort_run(&mut mutation, run)?;
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Sort the properties of this object." },
+            mutation,
+        ))
+    }
+}
+
+/// Returns the names that `object` is assigned to, from the closest to the farthest.
+///
+/// The names of the enclosing objects are included, so that the nested objects are checked with them.
+fn context_names(object: &JsObjectExpression) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut node = object.syntax().clone();
+    while let Some(parent) = node.parent() {
+        match parent.kind() {
+            JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION
+            | JsSyntaxKind::TS_AS_EXPRESSION
+            | JsSyntaxKind::TS_SATISFIES_EXPRESSION
+            | JsSyntaxKind::TS_NON_NULL_ASSERTION_EXPRESSION
+            | JsSyntaxKind::JS_ARRAY_ELEMENT_LIST
+            | JsSyntaxKind::JS_ARRAY_EXPRESSION
+            | JsSyntaxKind::JS_OBJECT_MEMBER_LIST
+            | JsSyntaxKind::JS_OBJECT_EXPRESSION
+            | JsSyntaxKind::JS_INITIALIZER_CLAUSE => {}
+            JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER => {
+                let name = JsPropertyObjectMember::unwrap_cast(parent.clone())
+                    .name()
+                    .ok()
+                    .and_then(|name| name.name());
+                names.extend(name.map(|name| name.to_string()));
+            }
+            JsSyntaxKind::JS_VARIABLE_DECLARATOR => {
+                let name = JsVariableDeclarator::unwrap_cast(parent).id().ok();
+                if let Some(AnyJsBindingPattern::AnyJsBinding(binding)) = name {
+                    if let Some(binding) = binding.as_js_identifier_binding() {
+                        names.extend(
+                            binding
+                                .name_token()
+                                .ok()
+                                .map(|token| token.text_trimmed().to_string()),
+                        );
+                    }
+                }
+                break;
+            }
+            JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION => {
+                let left = JsAssignmentExpression::unwrap_cast(parent).left().ok();
+                if let Some(AnyJsAssignmentPattern::AnyJsAssignment(assignment)) = left {
+                    names.extend(assignment_name(&assignment));
+                }
+                break;
+            }
+            JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER => {
+                let name = JsPropertyClassMember::unwrap_cast(parent).name().ok();
+                if let Some(AnyJsClassMemberName::JsLiteralMemberName(name)) = name {
+                    names.extend(name.name().ok().map(|name| name.to_string()));
+                }
+                break;
+            }
+            _ => break,
+        }
+        node = parent;
+    }
+    names
+}
+
+fn assignment_name(assignment: &AnyJsAssignment) -> Option<String> {
+    match assignment {
+        AnyJsAssignment::JsIdentifierAssignment(assignment) => {
+            Some(assignment.name_token().ok()?.text_trimmed().to_string())
+        }
+        AnyJsAssignment::JsStaticMemberAssignment(assignment) => Some(
+            assignment
+                .member()
+                .ok()?
+                .as_js_name()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                .to_string(),
+        ),
+        AnyJsAssignment::JsComputedMemberAssignment(assignment) => {
+            let member = assignment.member().ok()?;
+            let member = member.as_any_js_literal_expression()?;
+            Some(member.as_static_value()?.text().to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the static name of `member`, or `None` if the member can't be moved.
+fn member_key(member: &AnyJsObjectMember) -> Option<String> {
+    let name = match member {
+        AnyJsObjectMember::JsPropertyObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsMethodObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsGetterObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsSetterObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsShorthandPropertyObjectMember(member) => {
+            let name = member.name().ok()?.value_token().ok()?;
+            return Some(name.text_trimmed().to_string());
+        }
+        AnyJsObjectMember::JsSpread(_) | AnyJsObjectMember::JsBogusMember(_) => return None,
+    };
+    match name {
+        AnyJsObjectMemberName::JsLiteralMemberName(name) => Some(name.name().ok()?.to_string()),
+        // Computed names may have side effects
+        AnyJsObjectMemberName::JsComputedMemberName(_)
+        | AnyJsObjectMemberName::JsMetavariable(_) => None,
+    }
+}
+
+/// Returns `true` if evaluating `member` doesn't have side effects.
+fn is_pure_member(member: &AnyJsObjectMember) -> bool {
+    match member {
+        AnyJsObjectMember::JsPropertyObjectMember(member) => {
+            member.value().is_ok_and(|value| is_pure_expression(&value))
+        }
+        // The methods and the accessors are only defined
+        AnyJsObjectMember::JsMethodObjectMember(_)
+        | AnyJsObjectMember::JsGetterObjectMember(_)
+        | AnyJsObjectMember::JsSetterObjectMember(_)
+        | AnyJsObjectMember::JsShorthandPropertyObjectMember(_) => true,
+        AnyJsObjectMember::JsSpread(_) | AnyJsObjectMember::JsBogusMember(_) => false,
+    }
+}
+
+fn is_pure_expression(expression: &AnyJsExpression) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(_)
+        | AnyJsExpression::JsIdentifierExpression(_)
+        | AnyJsExpression::JsFunctionExpression(_)
+        | AnyJsExpression::JsArrowFunctionExpression(_) => true,
+        // The substitutions of the objects call their `toString` method
+        AnyJsExpression::JsTemplateExpression(template) => {
+            template.tag().is_none()
+                && template.elements().iter().all(|element| match element {
+                    AnyJsTemplateElement::JsTemplateChunkElement(_) => true,
+                    AnyJsTemplateElement::JsTemplateElement(element) => {
+                        matches!(
+                            element.expression(),
+                            Ok(AnyJsExpression::AnyJsLiteralExpression(_))
+                        )
+                    }
+                })
+        }
+        // The numeric operators call the `valueOf` method of the objects
+        AnyJsExpression::JsUnaryExpression(expression) => {
+            let Ok(argument) = expression.argument() else {
+                return false;
+            };
+            match expression.operator() {
+                Ok(
+                    JsUnaryOperator::LogicalNot | JsUnaryOperator::Typeof | JsUnaryOperator::Void,
+                ) => is_pure_expression(&argument),
+                Ok(
+                    JsUnaryOperator::Plus | JsUnaryOperator::Minus | JsUnaryOperator::BitwiseNot,
+                ) => {
+                    matches!(argument, AnyJsExpression::AnyJsLiteralExpression(_))
+                }
+                _ => false,
+            }
+        }
+        AnyJsExpression::JsArrayExpression(array) => {
+            array.elements().iter().all(|element| match element {
+                Ok(AnyJsArrayElement::AnyJsExpression(expression)) => {
+                    is_pure_expression(&expression)
+                }
+                Ok(AnyJsArrayElement::JsArrayHole(_)) => true,
+                _ => false,
+            })
+        }
+        AnyJsExpression::JsObjectExpression(object) => object.members().iter().all(|member| {
+            member.is_ok_and(|member| member_key(&member).is_some() && is_pure_member(&member))
+        }),
+        AnyJsExpression::TsAsExpression(expression) => expression
+            .expression()
+            .is_ok_and(|expression| is_pure_expression(&expression)),
+        AnyJsExpression::TsSatisfiesExpression(expression) => expression
+            .expression()
+            .is_ok_and(|expression| is_pure_expression(&expression)),
+        _ => false,
+    }
+}
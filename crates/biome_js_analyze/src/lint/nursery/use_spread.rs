@@ -0,0 +1,163 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    global_identifier, AnyJsArrayElement, AnyJsExpression, JsCallExpression,
+    JsStaticMemberExpression, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, TokenText};
+
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Use the spread syntax instead of `Array.prototype.slice.call()` to convert a value to an array.
+    ///
+    /// Before the spread syntax, `Array.prototype.slice.call(arguments)` and `[].slice.call(arguments)`
+    /// were the common ways to copy an array-like object, such as `arguments` or a `NodeList`, into an array.
+    /// The spread syntax, such as `[...arguments]`, is shorter and clearer.
+    ///
+    /// The rule only reports the calls that pass a single argument to `call()`.
+    ///
+    /// The fix is unsafe: the spread syntax requires an iterable object,
+    /// while `slice()` also accepts the array-like objects that aren't iterable, such as `{ length: 0 }`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function f() {
+    ///     const args = Array.prototype.slice.call(arguments);
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const elements = [].slice.call(document.querySelectorAll("div"));
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function f() {
+    ///     const args = [...arguments];
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// const rest = Array.prototype.slice.call(arguments, 1);
+    /// ```
+    ///
+    pub UseSpread {
+        version: "next",
+        name: "useSpread",
+        language: "js",
+        sources: &[RuleSource::EslintUnicorn("prefer-spread")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseSpread {
+    type Query = Semantic<JsCallExpression>;
+    type State = AnyJsExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        if call.is_optional_chain() {
+            return None;
+        }
+        let callee = call.callee().ok()?.omit_parentheses();
+        let call_member = callee.as_js_static_member_expression()?;
+        if member_name(call_member)? != "call" {
+            return None;
+        }
+        let slice_object = call_member.object().ok()?.omit_parentheses();
+        let slice_member = slice_object.as_js_static_member_expression()?;
+        if member_name(slice_member)? != "slice"
+            || !is_array_prototype(&slice_member.object().ok()?, ctx.model())
+        {
+            return None;
+        }
+        let arguments = call.arguments().ok()?.args();
+        if arguments.len() != 1 {
+            return None;
+        }
+        arguments.first()?.ok()?.as_any_js_expression().cloned()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use the spread syntax instead of "<Emphasis>"slice.call()"</Emphasis>" to convert the value to an array."
+                },
+            )
+            .note(markup! {
+                "The spread syntax, such as "<Emphasis>"[...arguments]"</Emphasis>", is shorter and clearer."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, argument: &Self::State) -> Option<JsRuleAction> {
+        let call = ctx.query();
+        let array = make::js_array_expression(
+            make::token(T!['[']),
+            make::js_array_element_list(
+                [AnyJsArrayElement::JsSpread(make::js_spread(
+                    make::token(T![...]),
+                    argument.clone().trim_trivia()?,
+                ))],
+                [],
+            ),
+            make::token(T![']']),
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(call.clone()), array.into());
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use the spread syntax instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+fn member_name(member: &JsStaticMemberExpression) -> Option<TokenText> {
+    Some(
+        member
+            .member()
+            .ok()?
+            .as_js_name()?
+            .value_token()
+            .ok()?
+            .token_text_trimmed(),
+    )
+}
+
+/// Returns `true` if `expression` is `Array.prototype` or an empty array, such as `[]`
+fn is_array_prototype(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsArrayExpression(array) => array.elements().is_empty(),
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            member_name(&member).is_some_and(|name| name == "prototype")
+                && member.object().is_ok_and(|object| {
+                    global_identifier(&object.omit_parentheses()).is_some_and(
+                        |(reference, name)| {
+                            name.text() == "Array" && model.binding(&reference).is_none()
+                        },
+                    )
+                })
+        }
+        _ => false,
+    }
+}
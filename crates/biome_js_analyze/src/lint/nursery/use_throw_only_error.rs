@@ -1,12 +1,17 @@
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
 };
 use biome_console::markup;
+use biome_js_factory::make;
 use biome_js_syntax::{
-    AnyJsExpression, AnyJsLiteralExpression, JsLogicalExpression, JsObjectExpression, JsSyntaxKind,
-    JsThrowStatement,
+    AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression, JsBinaryOperator,
+    JsLogicalExpression, JsObjectExpression, JsSyntaxKind, JsThrowStatement, T,
 };
-use biome_rowan::AstNode;
+use biome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
+
+use crate::lint::nursery::use_error_cause::{enclosing_catch_binding, make_cause_object};
+use crate::JsRuleAction;
 
 declare_lint_rule! {
     /// Disallow throwing non-`Error` values.
@@ -43,6 +48,9 @@ declare_lint_rule! {
     /// throw new CustomError();
     /// ```
     ///
+    /// The fix wraps the thrown strings in an `Error`.
+    /// In a `catch` clause, the caught error is attached as the `cause` of the new `Error`.
+    ///
     /// ## Caveats
     ///
     /// This rule only covers cases where throwing the value can be known statically.
@@ -56,6 +64,7 @@ declare_lint_rule! {
         sources: &[RuleSource::Eslint("no-throw-literal"), RuleSource::EslintTypeScript("only-throw-error")],
         source_kind: RuleSourceKind::Inspired,
         recommended: false,
+        fix_kind: FixKind::Unsafe,
     }
 }
 
@@ -85,6 +94,79 @@ impl Rule for UseThrowOnlyError {
             "While Javascript supports throwing any value, handling non-"<Emphasis>"Error"</Emphasis>" values is confusing."
         }))
     }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let argument = node.argument().ok()?;
+        if !is_string_value(&argument.clone().omit_parentheses()) {
+            return None;
+        }
+        let leading_trivia = argument.syntax().first_leading_trivia()?.pieces();
+        let message = argument.clone().with_leading_trivia_pieces([])?;
+
+        let mut items = vec![AnyJsCallArgument::AnyJsExpression(message)];
+        let mut separators = Vec::new();
+        let catch_binding = enclosing_catch_binding(node);
+        if let Some(catch_binding) = &catch_binding {
+            items.push(AnyJsCallArgument::AnyJsExpression(
+                AnyJsExpression::JsObjectExpression(make_cause_object(
+                    catch_binding.name_token().ok()?.text_trimmed(),
+                )),
+            ));
+            separators.push(
+                make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            );
+        }
+        let error = make::js_new_expression(
+            make::token(T![new])
+                .with_leading_trivia_pieces(leading_trivia)
+                .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            AnyJsExpression::JsIdentifierExpression(make::js_identifier_expression(
+                make::js_reference_identifier(make::ident("Error")),
+            )),
+        )
+        .with_arguments(make::js_call_arguments(
+            make::token(T!['(']),
+            make::js_call_argument_list(items, separators),
+            make::token(T![')']),
+        ))
+        .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(argument, AnyJsExpression::JsNewExpression(error));
+
+        let message = if catch_binding.is_some() {
+            markup! { "Throw an "<Emphasis>"Error"</Emphasis>" with this message, and the caught error as its "<Emphasis>"cause"</Emphasis>"." }
+        } else {
+            markup! { "Throw an "<Emphasis>"Error"</Emphasis>" with this message." }
+        };
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            message.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `expr` is a string, which can be the message of an `Error`
+fn is_string_value(expr: &AnyJsExpression) -> bool {
+    match expr {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(_),
+        ) => true,
+        AnyJsExpression::JsTemplateExpression(template) => template.tag().is_none(),
+        AnyJsExpression::JsBinaryExpression(binary) => {
+            binary.operator() == Ok(JsBinaryOperator::Plus)
+                && (binary
+                    .left()
+                    .is_ok_and(|left| is_string_value(&left.omit_parentheses()))
+                    || binary
+                        .right()
+                        .is_ok_and(|right| is_string_value(&right.omit_parentheses())))
+        }
+        _ => false,
+    }
 }
 
 fn is_invalid_throw_value(any_expr: &AnyJsExpression) -> Option<bool> {
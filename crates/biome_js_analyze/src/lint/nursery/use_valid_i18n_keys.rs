@@ -0,0 +1,302 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{JsCallExpression, JsImport};
+use biome_json_analyze::DocumentKeys;
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, TextRange};
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::services::module_exports::{
+    normalize_path, string_call, ProjectExports, ProjectModuleExports, StringCall,
+};
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Ensure that the keys passed to the translation functions exist in the message catalogs.
+    ///
+    /// The translation functions, such as `t("home.title")`, look up their key in JSON message catalogs.
+    /// A key that is missing from a catalog, because of a typo or because the message wasn't translated yet,
+    /// usually displays the key itself to the users.
+    ///
+    /// The rule reports the string keys passed to the functions of the `functions` option
+    /// that are missing from one of the catalogs of the `catalogs` option.
+    /// The keys of the nested objects of a catalog are joined with a `.`,
+    /// so that `{ "home": { "title": "Home" } }` declares the key `home.title`.
+    /// The keys that aren't strings, such as `t(key)`, aren't checked.
+    ///
+    /// The rule also reports the keys of a catalog that aren't passed to a translation function by any module of the project.
+    /// These keys are reported on the import of the catalog, such as `import en from "./locales/en.json"`.
+    ///
+    /// The code action adds a missing key, with an empty message, to the catalogs that don't have it.
+    /// It edits the catalogs and not the module, so it's only applied by the editors, and not by `--write`.
+    ///
+    /// Only the files processed by Biome are taken into account:
+    /// in an editor, these are the opened files.
+    ///
+    /// ## Examples
+    ///
+    /// With the options of the [Options](#options) section, and the catalog `locales/en.json`:
+    ///
+    /// ```json,ignore
+    /// { "home": { "title": "Home" } }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// i18n.t("home.titel");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// i18n.t("home.title");
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `functions`
+    ///
+    /// The translation functions, such as `t` or `i18n.t`.
+    /// Their first argument is the key of the message.
+    ///
+    /// Default: `["t"]`
+    ///
+    /// ### `catalogs`
+    ///
+    /// The paths of the JSON message catalogs, such as `locales/en.json`.
+    /// The paths are matched against the end of the paths of the JSON files,
+    /// so that `locales/en.json` matches the catalog wherever the project is.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "functions": ["i18n.t"],
+    ///         "catalogs": ["locales/en.json", "locales/fr.json"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseValidI18nKeys {
+        version: "next",
+        name: "useValidI18nKeys",
+        language: "js",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseValidI18nKeysOptions {
+    /// The translation functions, such as `t` or `i18n.t`. Their first argument is the key of the message.
+    pub functions: Vec<String>,
+    /// The paths of the JSON message catalogs, such as `locales/en.json`.
+    pub catalogs: Vec<String>,
+}
+
+impl Default for UseValidI18nKeysOptions {
+    fn default() -> Self {
+        Self {
+            functions: vec!["t".to_string()],
+            catalogs: Vec::new(),
+        }
+    }
+}
+
+declare_node_union! {
+    pub AnyTranslationNode = JsCallExpression | JsImport
+}
+
+pub enum TranslationKeysState {
+    /// A key that is missing from some catalogs
+    MissingKey {
+        /// The range of the key
+        range: TextRange,
+        key: String,
+        /// The catalogs, as written in the options, that don't have the key
+        catalogs: Vec<String>,
+    },
+    /// The keys of an imported catalog that aren't used by the project
+    UnusedKeys {
+        /// The range of the specifier of the import
+        range: TextRange,
+        /// The catalog, as written in the options
+        catalog: String,
+        keys: Vec<String>,
+    },
+}
+
+impl Rule for UseValidI18nKeys {
+    type Query = ProjectExports<AnyTranslationNode>;
+    type State = TranslationKeysState;
+    type Signals = Option<Self::State>;
+    type Options = UseValidI18nKeysOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let project = ctx.project();
+        match ctx.query() {
+            AnyTranslationNode::JsCallExpression(call) => {
+                let StringCall {
+                    callee,
+                    argument,
+                    range,
+                } = string_call(call)?;
+                if !options.functions.contains(&callee) {
+                    return None;
+                }
+                // The catalogs that aren't indexed can't be checked
+                let catalogs = options
+                    .catalogs
+                    .iter()
+                    .filter(|catalog| {
+                        find_catalog(project, catalog)
+                            .is_some_and(|(_, keys)| !keys.contains(&argument))
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (!catalogs.is_empty()).then_some(TranslationKeysState::MissingKey {
+                    range,
+                    key: argument,
+                    catalogs,
+                })
+            }
+            AnyTranslationNode::JsImport(import) => {
+                let specifier = import.source_text().ok()?;
+                let file_path = normalize_path(ctx.file_path());
+                let imported = project.resolve_json_document(&file_path, &specifier)?;
+                let (catalog, keys) = options.catalogs.iter().find_map(|catalog| {
+                    let (path, keys) = find_catalog(project, catalog)?;
+                    (path == imported).then_some((catalog, keys))
+                })?;
+                let used_keys = used_keys(ctx, project, &file_path);
+                let mut keys = keys
+                    .iter()
+                    .filter(|key| !used_keys.contains(*key))
+                    .map(str::to_string)
+                    .collect::<Vec<_>>();
+                if keys.is_empty() {
+                    return None;
+                }
+                keys.sort_unstable();
+                Some(TranslationKeysState::UnusedKeys {
+                    range: import.import_clause().ok()?.source().ok()?.range(),
+                    catalog: catalog.clone(),
+                    keys,
+                })
+            }
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        match state {
+            TranslationKeysState::MissingKey {
+                range,
+                key,
+                catalogs,
+            } => {
+                let catalogs = catalogs.join(", ");
+                Some(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        *range,
+                        markup! {
+                            "The translation key "<Emphasis>{key}</Emphasis>" is missing from "<Emphasis>{catalogs}</Emphasis>"."
+                        },
+                    )
+                    .note(markup! {
+                        "The key is usually displayed instead of the message to the users."
+                    })
+                    .note(markup! {
+                        "Fix the key, or add the message to the catalogs."
+                    }),
+                )
+            }
+            TranslationKeysState::UnusedKeys {
+                range,
+                catalog,
+                keys,
+            } => Some(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    *range,
+                    markup! {
+                        "The catalog "<Emphasis>{catalog}</Emphasis>" has keys that aren't used by any module."
+                    },
+                )
+                .note(markup! {
+                    "Remove the unused messages from the catalog, they are no longer displayed."
+                })
+                .footer_list(markup! { "Unused keys:" }, keys),
+            ),
+        }
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let TranslationKeysState::MissingKey { key, catalogs, .. } = state else {
+            return None;
+        };
+        let project = ctx.project();
+        let mut action = JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Add the key to the catalogs." }.to_owned(),
+            ctx.root().begin(),
+        );
+        for catalog in catalogs {
+            let (path, keys) = find_catalog(project, catalog)?;
+            action = action.with_file_edit(path, keys.insert_key(key)?);
+        }
+        Some(action)
+    }
+}
+
+/// Returns the catalog at the path `catalog`, as written in the options, among the JSON documents of the project
+fn find_catalog<'a>(
+    project: &'a ProjectModuleExports,
+    catalog: &str,
+) -> Option<(&'a Path, &'a DocumentKeys)> {
+    let catalog = normalize_path(Path::new(catalog));
+    project
+        .json_documents()
+        .find(|(path, _)| path.ends_with(&catalog))
+}
+
+/// Returns the keys passed to the translation functions by the modules of the project
+///
+/// The keys of the module at `file_path` are collected from its syntax,
+/// because its index can be older than the analyzed code.
+fn used_keys(
+    ctx: &RuleContext<UseValidI18nKeys>,
+    project: &ProjectModuleExports,
+    file_path: &Path,
+) -> FxHashSet<String> {
+    let functions = &ctx.options().functions;
+    let mut keys = project
+        .module_details()
+        .filter(|(path, _)| *path != file_path)
+        .flat_map(|(_, details)| {
+            functions
+                .iter()
+                .flat_map(|function| details.string_arguments(function))
+        })
+        .map(str::to_string)
+        .collect::<FxHashSet<_>>();
+    keys.extend(
+        ctx.root()
+            .syntax()
+            .descendants()
+            .filter_map(JsCallExpression::cast)
+            .filter_map(|call| string_call(&call))
+            .filter(|call| functions.contains(&call.callee))
+            .map(|call| call.argument),
+    );
+    keys
+}
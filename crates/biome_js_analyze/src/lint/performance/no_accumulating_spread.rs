@@ -1,13 +1,16 @@
 use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
 use biome_console::markup;
-use biome_js_semantic::SemanticModel;
+use biome_js_semantic::{Binding, SemanticModel};
 use biome_js_syntax::{
-    AnyJsFunction, AnyJsMemberExpression, JsCallArgumentList, JsCallArguments, JsCallExpression,
-    JsFormalParameter, JsParameterList, JsParameters, JsSpread,
+    AnyJsCallArgument, AnyJsExpression, AnyJsFunction, AnyJsMemberExpression, JsArrayElementList,
+    JsArrayExpression, JsArrowFunctionExpression, JsAssignmentExpression, JsAssignmentOperator,
+    JsCallArgumentList, JsCallArguments, JsCallExpression, JsFormalParameter, JsInitializerClause,
+    JsObjectExpression, JsObjectMemberList, JsParameterList, JsParameters, JsSpread, JsSyntaxKind,
+    JsSyntaxNode, JsVariableDeclaration, JsVariableDeclarator, JsVariableDeclaratorList,
 };
 use biome_rowan::{AstNode, AstSeparatedList};
 
-use crate::services::semantic::Semantic;
+use crate::services::{control_flow::AnyJsControlFlowRoot, semantic::Semantic};
 
 declare_lint_rule! {
     /// Disallow the use of spread (`...`) syntax on accumulators.
@@ -17,6 +20,10 @@ declare_lint_rule! {
     /// Spread syntax should be avoided on accumulators (like those in `.reduce`)
     /// because it causes a time complexity of `O(n^2)` instead of `O(n)`.
     ///
+    /// The rule also reports the accumulators of loops, which are reassigned with a spread of themselves.
+    /// The rule follows the aliases of the accumulators, the callbacks passed to `.reduce` by name,
+    /// and the helper functions that return a spread of their parameter.
+    ///
     /// Source: https://prateeksurana.me/blog/why-using-object-spread-with-reduce-bad-idea/
     ///
     /// ## Examples
@@ -38,6 +45,21 @@ declare_lint_rule! {
     /// a.reduce((acc, val) => ({...acc, [val]: val}), {});
     /// ```
     ///
+    /// ```js,expect_diagnostic
+    /// let acc = {};
+    /// for (const [key, value] of entries) {
+    ///     acc = {...acc, [key]: value};
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const append = (list, item) => [...list, item];
+    /// let acc = [];
+    /// for (const item of items) {
+    ///     acc = append(acc, item);
+    /// }
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```js
@@ -45,6 +67,13 @@ declare_lint_rule! {
     /// a.reduce((acc, val) => {acc.push(val); return acc}, []);
     /// ```
     ///
+    /// ```js
+    /// let acc = {};
+    /// for (const [key, value] of entries) {
+    ///     acc[key] = value;
+    /// }
+    /// ```
+    ///
     pub NoAccumulatingSpread {
         version: "1.0.0",
         name: "noAccumulatingSpread",
@@ -63,7 +92,7 @@ impl Rule for NoAccumulatingSpread {
         let node = ctx.query();
         let model = ctx.model();
 
-        is_known_accumulator(node, model)?.then_some(())
+        is_accumulating_spread(node, model)?.then_some(())
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
@@ -86,21 +115,75 @@ impl Rule for NoAccumulatingSpread {
     }
 }
 
-fn is_known_accumulator(node: &JsSpread, model: &SemanticModel) -> Option<bool> {
+/// Maximum number of helper functions followed from a spread to the accumulation of its result
+const MAX_HELPER_DEPTH: u8 = 3;
+
+/// Returns `true` if `node` spreads an accumulator.
+fn is_accumulating_spread(node: &JsSpread, model: &SemanticModel) -> Option<bool> {
     let reference = node
         .argument()
         .ok()?
         .as_js_identifier_expression()?
         .name()
         .ok()?;
-    let parameter = model
-        .binding(&reference)
-        .and_then(|declaration| declaration.syntax().parent())
-        .and_then(JsFormalParameter::cast)?;
-    let function = parameter
-        .parent::<JsParameterList>()
-        .and_then(|list| list.parent::<JsParameters>())
-        .and_then(|parameters| parameters.parent::<AnyJsFunction>())?;
+    let binding = resolve_alias(model.binding(&reference)?, model);
+    if is_reduce_accumulator(&binding, model).is_some() {
+        return Some(true);
+    }
+    // The array or the object built by the spread
+    let accumulation = node
+        .parent::<JsArrayElementList>()
+        .and_then(|list| list.parent::<JsArrayExpression>())
+        .map(|array| array.into_syntax())
+        .or_else(|| {
+            node.parent::<JsObjectMemberList>()
+                .and_then(|list| list.parent::<JsObjectExpression>())
+                .map(|object| object.into_syntax())
+        })?;
+    Some(is_accumulated(&accumulation, &binding, model, 0))
+}
+
+/// Returns the binding that `binding` is an alias of, such as `acc` in `const result = acc;`.
+fn resolve_alias(binding: Binding, model: &SemanticModel) -> Binding {
+    let mut binding = binding;
+    // Aliases of aliases are unlikely
+    for _ in 0..MAX_HELPER_DEPTH {
+        let Some(aliased) = binding
+            .syntax()
+            .parent()
+            .and_then(JsVariableDeclarator::cast)
+            .filter(|declarator| {
+                declarator
+                    .parent::<JsVariableDeclaratorList>()
+                    .and_then(|list| list.parent::<JsVariableDeclaration>())
+                    .is_some_and(|declaration| declaration.is_const())
+            })
+            .and_then(|declarator| declarator.initializer()?.expression().ok())
+            .and_then(|initializer| identifier_binding(initializer, model))
+        else {
+            break;
+        };
+        binding = aliased;
+    }
+    binding
+}
+
+/// Returns the binding referenced by `expression`, if it is an identifier.
+fn identifier_binding(expression: AnyJsExpression, model: &SemanticModel) -> Option<Binding> {
+    let reference = expression
+        .omit_parentheses()
+        .as_js_identifier_expression()?
+        .name()
+        .ok()?;
+    model.binding(&reference)
+}
+
+/// Returns the callback of `.reduce` or `.reduceRight` that has `binding` as accumulator.
+fn is_reduce_accumulator(binding: &Binding, model: &SemanticModel) -> Option<AnyJsFunction> {
+    let (function, index) = parameter_function(binding)?;
+    if index != 0 {
+        return None;
+    }
 
     // Known accumulators need at least 2 arguments and no more than 4. (accumulator, value, index, array)
     let param_count = function
@@ -114,27 +197,208 @@ fn is_known_accumulator(node: &JsSpread, model: &SemanticModel) -> Option<bool>
         return None;
     }
 
-    let call_expression = function
-        .parent::<JsCallArgumentList>()
+    // The callback is either passed directly to the call, or through a reference
+    if is_reduce_callback(function.syntax()) {
+        return Some(function);
+    }
+    function_binding(&function, model)?
+        .all_reads()
+        .filter_map(|reference| reference.syntax().parent())
+        .any(|reference| is_reduce_callback(&reference))
+        .then_some(function)
+}
+
+/// Returns `true` if `node` is an argument of a call to `.reduce` or `.reduceRight`.
+fn is_reduce_callback(node: &JsSyntaxNode) -> bool {
+    let Some(call_expression) = node
+        .parent()
+        .and_then(JsCallArgumentList::cast)
         .and_then(|arguments| arguments.parent::<JsCallArguments>())
-        .and_then(|arguments| arguments.parent::<JsCallExpression>())?;
+        .and_then(|arguments| arguments.parent::<JsCallExpression>())
+    else {
+        return false;
+    };
 
     // The accumulator function should be a part of a call expression. This call expression should
     // have no more than 2 arguments. (callback, initialValue)
-    let arg_count = call_expression.arguments().ok()?.args().iter().count();
-    if arg_count > 2 {
-        return None;
+    let Ok(arguments) = call_expression.arguments() else {
+        return false;
+    };
+    if arguments.args().iter().count() > 2 {
+        return false;
     }
 
-    let callee = call_expression.callee().ok()?;
-    let member_expression = AnyJsMemberExpression::cast(callee.into_syntax())?;
-
     // We only care about `.reduce` and `.reduceRight`.
-    let member_name = member_expression.member_name()?;
-    if !matches!(member_name.text(), "reduce" | "reduceRight") {
+    call_expression
+        .callee()
+        .ok()
+        .and_then(|callee| AnyJsMemberExpression::cast(callee.into_syntax()))
+        .and_then(|member_expression| member_expression.member_name())
+        .is_some_and(|member_name| matches!(member_name.text(), "reduce" | "reduceRight"))
+}
+
+/// Returns `true` if the value `node`, computed from `binding`,
+/// becomes the next value of the accumulator `binding`.
+///
+/// This is the case when the value is:
+/// - returned by the `.reduce` callback of the accumulator `binding`;
+/// - assigned to `binding` in a loop, such as `acc = [...acc, item]`;
+/// - returned by a helper function called with the accumulator,
+///   such as `acc = append(acc, item)` in a loop.
+fn is_accumulated(
+    node: &JsSyntaxNode,
+    binding: &Binding,
+    model: &SemanticModel,
+    depth: u8,
+) -> bool {
+    let mut value = node.clone();
+    while let Some(parent) = value
+        .parent()
+        .filter(|parent| parent.kind() == JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+    {
+        value = parent;
+    }
+
+    if let Some(assignment) = value
+        .parent()
+        .and_then(JsAssignmentExpression::cast)
+        .filter(|assignment| {
+            assignment
+                .right()
+                .is_ok_and(|right| right.syntax() == &value)
+                && assignment
+                    .operator()
+                    .is_ok_and(|operator| operator == JsAssignmentOperator::Assign)
+        })
+    {
+        return assignment
+            .left()
+            .ok()
+            .and_then(|left| {
+                let left = left.as_any_js_assignment()?.as_js_identifier_assignment()?;
+                model.binding(left)
+            })
+            .is_some_and(|assigned| {
+                assigned.syntax() == binding.syntax() && is_in_loop(assignment.syntax(), binding)
+            });
+    }
+
+    let Some(function) = returning_function(&value) else {
+        return false;
+    };
+    if is_reduce_accumulator(binding, model).is_some_and(|callback| callback == function) {
+        return true;
+    }
+    if depth >= MAX_HELPER_DEPTH {
+        return false;
+    }
+    let Some((helper, index)) =
+        parameter_function(binding).filter(|(helper, _)| helper == &function)
+    else {
+        return false;
+    };
+    let Some(helper_binding) = function_binding(&helper, model) else {
+        return false;
+    };
+    helper_binding.all_reads().any(|reference| {
+        let Some(call) = reference
+            .syntax()
+            .parent()
+            .filter(|callee| callee.kind() == JsSyntaxKind::JS_IDENTIFIER_EXPRESSION)
+            .and_then(|callee| callee.parent())
+            .and_then(JsCallExpression::cast)
+        else {
+            return false;
+        };
+        call.arguments()
+            .ok()
+            .and_then(|arguments| arguments.args().iter().nth(index)?.ok())
+            .and_then(|argument| match argument {
+                AnyJsCallArgument::AnyJsExpression(argument) => identifier_binding(argument, model),
+                AnyJsCallArgument::JsSpread(_) => None,
+            })
+            .is_some_and(|accumulator| {
+                is_accumulated(
+                    call.syntax(),
+                    &resolve_alias(accumulator, model),
+                    model,
+                    depth + 1,
+                )
+            })
+    })
+}
+
+/// Returns the function that `binding` is a parameter of, and the position of the parameter.
+fn parameter_function(binding: &Binding) -> Option<(AnyJsFunction, usize)> {
+    let parameter = binding
+        .syntax()
+        .parent()
+        .and_then(JsFormalParameter::cast)?;
+    let parameters = parameter
+        .parent::<JsParameterList>()
+        .and_then(|list| list.parent::<JsParameters>())?;
+    let index = parameters
+        .items()
+        .iter()
+        .position(|item| item.is_ok_and(|item| item.syntax() == parameter.syntax()))?;
+    Some((parameters.parent::<AnyJsFunction>()?, index))
+}
+
+/// Returns the binding of the name of `function`,
+/// such as `f` in `function f() {}` or in `const f = () => {}`.
+fn function_binding(function: &AnyJsFunction, model: &SemanticModel) -> Option<Binding> {
+    let binding = match function {
+        AnyJsFunction::JsFunctionDeclaration(declaration) => declaration.id().ok()?,
+        AnyJsFunction::JsFunctionExpression(_) | AnyJsFunction::JsArrowFunctionExpression(_) => {
+            function
+                .parent::<JsInitializerClause>()
+                .and_then(|initializer| initializer.parent::<JsVariableDeclarator>())?
+                .id()
+                .ok()?
+                .as_any_js_binding()?
+                .clone()
+        }
+        _ => return None,
+    };
+    Some(model.as_binding(binding.as_js_identifier_binding()?))
+}
+
+/// Returns the function that returns the value `node`.
+fn returning_function(node: &JsSyntaxNode) -> Option<AnyJsFunction> {
+    let parent = node.parent()?;
+    if let Some(arrow) = JsArrowFunctionExpression::cast_ref(&parent) {
+        return arrow
+            .body()
+            .is_ok_and(|body| body.syntax() == node)
+            .then(|| AnyJsFunction::JsArrowFunctionExpression(arrow));
+    }
+    if parent.kind() != JsSyntaxKind::JS_RETURN_STATEMENT {
         return None;
     }
+    let root = parent
+        .ancestors()
+        .find(|ancestor| AnyJsControlFlowRoot::can_cast(ancestor.kind()))?;
+    AnyJsFunction::cast(root)
+}
 
-    // Finally check that the spread references the first parameter.
-    Some(parameter.syntax().index() == 0)
+/// Returns `true` if `node` is in a loop that doesn't declare `binding`.
+fn is_in_loop(node: &JsSyntaxNode, binding: &Binding) -> bool {
+    let declaration_start = binding.syntax().text_trimmed_range().start();
+    for ancestor in node.ancestors() {
+        if AnyJsControlFlowRoot::can_cast(ancestor.kind()) {
+            return false;
+        }
+        if matches!(
+            ancestor.kind(),
+            JsSyntaxKind::JS_FOR_STATEMENT
+                | JsSyntaxKind::JS_FOR_IN_STATEMENT
+                | JsSyntaxKind::JS_FOR_OF_STATEMENT
+                | JsSyntaxKind::JS_WHILE_STATEMENT
+                | JsSyntaxKind::JS_DO_WHILE_STATEMENT
+        ) && !ancestor.text_trimmed_range().contains(declaration_start)
+        {
+            return true;
+        }
+    }
+    false
 }
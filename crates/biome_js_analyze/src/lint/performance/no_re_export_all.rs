@@ -1,9 +1,14 @@
+use crate::services::module_exports::ProjectModuleExports;
+use crate::{collect_module_exports, JsRuleAction};
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
 };
 use biome_console::markup;
-use biome_js_syntax::JsExportFromClause;
-use biome_rowan::AstNode;
+use biome_js_factory::make;
+use biome_js_syntax::{AnyJsExportClause, JsExportFromClause, T};
+use biome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
+use biome_unicode_table::is_js_ident;
 
 declare_lint_rule! {
     /// Avoid re-export all.
@@ -12,6 +17,9 @@ declare_lint_rule! {
     /// This structure results in the unnecessary loading of many modules, significantly impacting performance in large-scale applications.
     /// Additionally, it complicates the codebase, making it difficult to navigate and understand the project's dependency graph.
     ///
+    /// When the re-exported module is part of the project, the fix replaces `export * from` with the names that this module exports,
+    /// such as `export { a, b } from "./mod.js"`.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -42,6 +50,7 @@ declare_lint_rule! {
         recommended: false,
         sources: &[RuleSource::EslintBarrelFiles("avoid-re-export-all")],
         source_kind: RuleSourceKind::SameLogic,
+        fix_kind: FixKind::Unsafe,
     }
 }
 
@@ -69,4 +78,68 @@ impl Rule for NoReExportAll {
             }),
         )
     }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let clause = ctx.query();
+        // A namespace re-export can't be expanded to the names of the module
+        if clause.export_as().is_some() {
+            return None;
+        }
+        let source = clause.source().ok()?;
+        let specifier = source.as_js_module_source()?.inner_string_text().ok()?;
+        let project = ctx.get_service::<ProjectModuleExports>()?;
+        let path = project.resolve(ctx.file_path(), specifier.text())?;
+        // The names that the module exports itself shadow the re-exported names
+        let local_names = collect_module_exports(ctx.root());
+        let mut names = project
+            .star_exported_names(path)
+            .into_keys()
+            .filter(|name| !local_names.contains(name))
+            .collect::<Vec<_>>();
+        if names.is_empty() {
+            return None;
+        }
+        names.sort_unstable();
+
+        let specifiers = names.iter().map(|name| {
+            let token = if is_js_ident(name) {
+                make::ident(name)
+            } else {
+                make::js_string_literal(name)
+            };
+            make::js_export_named_from_specifier(make::js_literal_export_name(token)).build()
+        });
+        let separators = (1..names.len())
+            .map(|_| make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]));
+        let star_token = clause.star_token().ok()?;
+        let mut named_clause = make::js_export_named_from_clause(
+            make::token(T!['{'])
+                .with_leading_trivia_pieces(star_token.leading_trivia().pieces())
+                .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            make::js_export_named_from_specifier_list(specifiers, separators),
+            make::token(T!['}'])
+                .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")])
+                .with_trailing_trivia_pieces(star_token.trailing_trivia().pieces()),
+            clause.from_token().ok()?,
+            source,
+        );
+        if let Some(assertion) = clause.assertion() {
+            named_clause = named_clause.with_assertion(assertion);
+        }
+        if let Some(semicolon_token) = clause.semicolon_token() {
+            named_clause = named_clause.with_semicolon_token(semicolon_token);
+        }
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExportClause::from(clause.clone()),
+            AnyJsExportClause::from(named_clause.build()),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Replace with the names that the module exports." }.to_owned(),
+            mutation,
+        ))
+    }
 }
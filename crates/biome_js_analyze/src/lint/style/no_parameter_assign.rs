@@ -1,9 +1,23 @@
 use crate::services::semantic::Semantic;
-use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
 use biome_console::markup;
-use biome_js_semantic::{AllBindingWriteReferencesIter, Reference, ReferencesExtensions};
-use biome_js_syntax::{AnyJsBinding, AnyJsBindingPattern, AnyJsFormalParameter, AnyJsParameter};
-use biome_rowan::AstNode;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
+use biome_js_semantic::{Reference, ReferencesExtensions};
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsExpression, AnyJsFormalParameter, AnyJsParameter,
+    AnyJsStatement, JsComputedMemberAssignment, JsComputedMemberExpression, JsFunctionBody,
+    JsIdentifierBinding, JsStaticMemberAssignment, JsStaticMemberExpression, JsSyntaxKind,
+    JsSyntaxNode, JsUnaryExpression, JsUnaryOperator, TextRange, T,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, Direction, TriviaPieceKind};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 declare_lint_rule! {
     /// Disallow reassigning `function` parameters.
@@ -12,8 +26,13 @@ declare_lint_rule! {
     /// as modifying parameters will also mutate the `arguments` object.
     /// It is often unintended and indicative of a programmer error.
     ///
-    /// In contrast to the _ESLint_ rule, this rule cannot be configured to report
-    /// assignments to a property of a parameter.
+    /// The rule can also report the modifications of the properties of the parameters
+    /// with the `props` option.
+    ///
+    /// The fix renames the parameter and declares a local variable with the original name,
+    /// initialized with the parameter.
+    /// It's only available when the parameter is only used in the body of the function,
+    /// and the function doesn't use `arguments`.
     ///
     /// ## Examples
     ///
@@ -53,63 +72,292 @@ declare_lint_rule! {
     /// }
     /// ```
     ///
+    /// ## Options
+    ///
+    /// ### `props`
+    ///
+    /// When `true`, the rule also reports the modifications of the properties of the parameters,
+    /// such as `param.prop = 0`, `param.prop++` or `delete param.prop`.
+    /// The callers of a function rarely expect it to mutate the objects they pass.
+    /// Defaults to `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "props": true
+    ///     }
+    /// }
+    /// ```
+    ///
     pub NoParameterAssign {
         version: "1.0.0",
         name: "noParameterAssign",
         language: "js",
         sources: &[RuleSource::Eslint("no-param-reassign")],
         recommended: true,
+        fix_kind: FixKind::Unsafe,
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoParameterAssignOptions {
+    /// Whether to report the modifications of the properties of the parameters.
+    pub props: bool,
+}
+
+/// A modification of a parameter
+pub enum ParameterAssign {
+    /// The parameter is reassigned
+    Reassign(Reference),
+    /// A property of the parameter is modified, the range is the one of the modification
+    Property(TextRange),
+}
+
 impl Rule for NoParameterAssign {
     type Query = Semantic<AnyJsParameter>;
-    type State = Reference;
-    type Signals = AllBindingWriteReferencesIter;
-    type Options = ();
+    type State = ParameterAssign;
+    type Signals = Vec<Self::State>;
+    type Options = NoParameterAssignOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let param = ctx.query();
         let model = ctx.model();
-        if let Some(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding))) =
-            binding_of(param)
-        {
-            return binding.all_writes(model);
+        let Some(binding) = identifier_binding_of(param) else {
+            return Vec::new();
+        };
+        let mut signals: Vec<_> = binding
+            .all_writes(model)
+            .map(ParameterAssign::Reassign)
+            .collect();
+        if ctx.options().props {
+            signals.extend(
+                binding
+                    .all_reads(model)
+                    .filter_map(|reference| property_modification(reference.syntax()))
+                    .map(ParameterAssign::Property),
+            );
         }
-        // Empty iterator that conforms to `AllBindingWriteReferencesIter` type.
-        std::iter::successors(None, |_| None)
+        signals
     }
 
-    fn diagnostic(ctx: &RuleContext<Self>, reference: &Self::State) -> Option<RuleDiagnostic> {
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
         let param = ctx.query();
-        Some(
-            RuleDiagnostic::new(
+        let diagnostic = match state {
+            ParameterAssign::Reassign(reference) => RuleDiagnostic::new(
                 rule_category!(),
                 reference.syntax().text_trimmed_range(),
                 markup! {
                     "Reassigning a "<Emphasis>"function parameter"</Emphasis>" is confusing."
                 },
-            )
-            .detail(
-                param.syntax().text_trimmed_range(),
+            ),
+            ParameterAssign::Property(range) => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
                 markup! {
-                    "The "<Emphasis>"parameter"</Emphasis>" is declared here:"
+                    "Modifying a property of a "<Emphasis>"function parameter"</Emphasis>" is confusing."
                 },
-            )
-            .note(markup! {
+            ),
+        };
+        let note = match state {
+            ParameterAssign::Reassign(_) => markup! {
                 "Use a local variable instead."
-            }),
+            },
+            ParameterAssign::Property(_) => markup! {
+                "The callers may not expect the function to modify the objects they pass. Modify a copy of the object instead."
+            },
+        };
+        Some(
+            diagnostic
+                .detail(
+                    param.syntax().text_trimmed_range(),
+                    markup! {
+                        "The "<Emphasis>"parameter"</Emphasis>" is declared here:"
+                    },
+                )
+                .note(note),
         )
     }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        if !matches!(state, ParameterAssign::Reassign(_)) {
+            return None;
+        }
+        let param = ctx.query();
+        let model = ctx.model();
+        let binding = identifier_binding_of(param)?;
+        let name_token = binding.name_token().ok()?;
+        let name = name_token.text_trimmed();
+        let body = function_body_of(param)?;
+        let function = body.syntax().parent()?;
+        let copy_name = format!("{name}Arg");
+        // The parameter must only be used in the body,
+        // and the function must not use `arguments` that the parameter is synchronized with.
+        let body_range = body.range();
+        if !binding
+            .all_references(model)
+            .all(|reference| body_range.contains_range(reference.syntax().text_trimmed_range()))
+            || !can_declare_copy(&function, &binding, name, &copy_name)
+        {
+            return None;
+        }
+        let statements = body.statements();
+        let first_statement = statements.first()?;
+        let first_token = first_statement.syntax().first_token()?;
+        // The local variable is declared on its own line if the first statement is,
+        // or on the same line otherwise.
+        let pieces: Vec<_> = first_token.leading_trivia().pieces().collect();
+        let indentation: Vec<_> = match pieces.iter().rposition(|piece| piece.is_newline()) {
+            Some(index) => pieces[index..]
+                .iter()
+                .take_while(|piece| piece.is_newline() || piece.is_whitespace())
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        };
+        let semicolon = if indentation.is_empty() {
+            make::token(T![;]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+        } else {
+            make::token(T![;])
+        };
+        let declaration = make::js_variable_statement(
+            make::js_variable_declaration(
+                make::token(T![let])
+                    .with_leading_trivia_pieces(indentation)
+                    .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_variable_declarator_list(
+                    [
+                        make::js_variable_declarator(AnyJsBindingPattern::AnyJsBinding(
+                            AnyJsBinding::JsIdentifierBinding(make::js_identifier_binding(
+                                make::ident(name),
+                            )),
+                        ))
+                        .with_initializer(make::js_initializer_clause(
+                            make::token_decorated_with_space(T![=]),
+                            AnyJsExpression::JsIdentifierExpression(
+                                make::js_identifier_expression(make::js_reference_identifier(
+                                    make::ident(&copy_name),
+                                )),
+                            ),
+                        ))
+                        .build(),
+                    ],
+                    [],
+                ),
+            )
+            .build(),
+        )
+        .with_semicolon_token(semicolon)
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(name_token.clone(), make::ident(&copy_name));
+        mutation.replace_node(
+            statements.clone(),
+            make::js_statement_list(
+                std::iter::once(AnyJsStatement::JsVariableStatement(declaration))
+                    .chain(statements.iter()),
+            ),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Copy the parameter to a local variable." }.to_owned(),
+            mutation,
+        ))
+    }
 }
 
-fn binding_of(param: &AnyJsParameter) -> Option<AnyJsBindingPattern> {
-    match param {
+fn identifier_binding_of(param: &AnyJsParameter) -> Option<JsIdentifierBinding> {
+    let binding = match param {
         AnyJsParameter::AnyJsFormalParameter(formal_param) => match &formal_param {
             AnyJsFormalParameter::JsBogusParameter(_) => None,
             AnyJsFormalParameter::JsFormalParameter(param) => param.binding().ok(),
         },
         AnyJsParameter::JsRestParameter(param) => param.binding().ok(),
         AnyJsParameter::TsThisParameter(_) => None,
+    };
+    match binding? {
+        AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) => {
+            Some(binding)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the range of the modification of a property of the object referenced by `reference`,
+/// such as `param.prop = 0`, `param.prop.nested++` or `delete param[prop]`.
+fn property_modification(reference: &JsSyntaxNode) -> Option<TextRange> {
+    let mut object = reference.parent()?;
+    let mut is_member = false;
+    loop {
+        let parent = object.parent()?;
+        let is_object_of = |member_object: Option<AnyJsExpression>| {
+            member_object.is_some_and(|member_object| member_object.syntax() == &object)
+        };
+        match parent.kind() {
+            JsSyntaxKind::JS_STATIC_MEMBER_EXPRESSION
+                if is_object_of(JsStaticMemberExpression::cast_ref(&parent)?.object().ok()) => {}
+            JsSyntaxKind::JS_COMPUTED_MEMBER_EXPRESSION
+                if is_object_of(JsComputedMemberExpression::cast_ref(&parent)?.object().ok()) => {}
+            JsSyntaxKind::JS_STATIC_MEMBER_ASSIGNMENT
+                if is_object_of(JsStaticMemberAssignment::cast_ref(&parent)?.object().ok()) =>
+            {
+                return Some(parent.text_trimmed_range());
+            }
+            JsSyntaxKind::JS_COMPUTED_MEMBER_ASSIGNMENT
+                if is_object_of(JsComputedMemberAssignment::cast_ref(&parent)?.object().ok()) =>
+            {
+                return Some(parent.text_trimmed_range());
+            }
+            JsSyntaxKind::JS_UNARY_EXPRESSION
+                if is_member
+                    && JsUnaryExpression::cast_ref(&parent)?
+                        .operator()
+                        .is_ok_and(|operator| operator == JsUnaryOperator::Delete) =>
+            {
+                return Some(parent.text_trimmed_range());
+            }
+            _ => return None,
+        }
+        object = parent;
+        is_member = true;
     }
 }
+
+/// Returns the body of the function that declares `param`, if it has one
+fn function_body_of(param: &AnyJsParameter) -> Option<JsFunctionBody> {
+    let parent = param.syntax().parent()?;
+    // The parameters of the setters aren't in a list
+    let function = if matches!(
+        parent.kind(),
+        JsSyntaxKind::JS_PARAMETER_LIST | JsSyntaxKind::JS_CONSTRUCTOR_PARAMETER_LIST
+    ) {
+        parent.parent()?.parent()?
+    } else {
+        parent
+    };
+    function.children().find_map(JsFunctionBody::cast)
+}
+
+/// Returns `true` if a local variable `name` initialized with `copy_name` can be declared
+/// in the body of `function`: `copy_name` must be unused,
+/// `name` must not be declared by another binding, and `arguments` must not be used.
+fn can_declare_copy(
+    function: &JsSyntaxNode,
+    param: &JsIdentifierBinding,
+    name: &str,
+    copy_name: &str,
+) -> bool {
+    !function.descendants_tokens(Direction::Next).any(|token| {
+        let text = token.text_trimmed();
+        text == copy_name
+            || text == "arguments"
+            || (text == name
+                && token
+                    .parent()
+                    .is_some_and(|parent| parent.kind() == JsSyntaxKind::JS_IDENTIFIER_BINDING)
+                && token.parent().as_ref() != Some(param.syntax()))
+    })
+}
@@ -1,4 +1,6 @@
+use crate::services::module_exports::normalize_path;
 use crate::services::semantic::SemanticServices;
+use crate::utils::glob::matches_path_suffix;
 use biome_analyze::{
     context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
 };
@@ -8,7 +10,7 @@ use biome_rowan::TextRange;
 use biome_string_case::{Case, Cases};
 use rustc_hash::FxHashSet;
 use serde::{Deserialize, Serialize};
-use std::{hash::Hash, str::FromStr};
+use std::{hash::Hash, path::Path, str::FromStr};
 
 use biome_deserialize::{DeserializableValue, DeserializationDiagnostic};
 #[cfg(feature = "schemars")]
@@ -102,6 +104,28 @@ declare_lint_rule! {
     /// You can enforce a stricter convention by setting `filenameCases` option.
     /// `filenameCases` accepts an array of cases among the following cases: [`camelCase`], [`kebab-case`], [`PascalCase`], [`snake_case`], and `export`.
     ///
+    /// ### conventions
+    ///
+    /// The `conventions` option sets the allowed cases of the files of some directories,
+    /// such as the components in [`PascalCase`] and the utilities in [`kebab-case`].
+    /// Each convention has the globs of the paths of its files in `include`, and the allowed cases in `filenameCases`.
+    /// The globs are matched against the end of the paths, so that `components/**` matches the files
+    /// of all the `components` directories of the project.
+    ///
+    /// A file uses the first convention that matches its path, and the `filenameCases` option otherwise.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "conventions": [
+    ///             { "include": ["components/**"], "filenameCases": ["PascalCase"] },
+    ///             { "include": ["utils/**"], "filenameCases": ["kebab-case"] }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
     /// [case]: https://en.wikipedia.org/wiki/Naming_convention_(programming)#Examples_of_multiple-word_identifier_formats
     /// [`camelCase`]: https://en.wikipedia.org/wiki/Camel_case
     /// [`kebab-case`]: https://en.wikipedia.org/wiki/Letter_case#Kebab_case
@@ -191,7 +215,8 @@ impl Rule for UseFilenamingConvention {
             return None;
         }
         // Check filename case
-        let allowed_cases = options.filename_cases.cases();
+        let (filename_cases, _) = options.filename_cases_of(ctx.file_path());
+        let allowed_cases = filename_cases.cases();
         if !allowed_cases.is_empty() {
             let trimmed_name = name.trim_matches('_');
             let case = Case::identify(trimmed_name, options.strict_case);
@@ -199,7 +224,7 @@ impl Rule for UseFilenamingConvention {
                 return None;
             }
         }
-        if options.filename_cases.0.contains(&FilenameCase::Export) {
+        if filename_cases.0.contains(&FilenameCase::Export) {
             // If no exported binding has the file name, then reports the filename
             let model = ctx.model();
             model
@@ -230,9 +255,10 @@ impl Rule for UseFilenamingConvention {
                 }))
             },
             FileNamingConventionState::Filename => {
-                let allowed_cases = options.filename_cases.cases();
+                let (filename_cases, convention) = options.filename_cases_of(ctx.file_path());
+                let allowed_cases = filename_cases.cases();
                 let allowed_case_names = allowed_cases.into_iter().map(|case| case.to_string());
-                let allowed_case_names = if options.filename_cases.0.contains(&FilenameCase::Export) {
+                let allowed_case_names = if filename_cases.0.contains(&FilenameCase::Export) {
                     allowed_case_names
                         .chain(["equal to the name of an export".to_string()])
                         .collect::<SmallVec<[_; 4]>>()
@@ -258,7 +284,7 @@ impl Rule for UseFilenamingConvention {
                 } else {
                     markup! {""}.to_owned()
                 };
-                if options.strict_case && options.filename_cases.0.contains(&FilenameCase::Camel) {
+                if options.strict_case && filename_cases.0.contains(&FilenameCase::Camel) {
                     let case_type = Case::identify(trimmed_name, false);
                     let case_strict = Case::identify(trimmed_name, true);
                     if case_type == Case::Camel && case_strict == Case::Unknown {
@@ -281,13 +307,21 @@ impl Rule for UseFilenamingConvention {
                     .into_iter()
                     .collect::<SmallVec<[_; 3]>>()
                     .join("\n");
-                Some(RuleDiagnostic::new(
+                let diagnostic = RuleDiagnostic::new(
                     rule_category!(),
                     None as Option<TextRange>,
                     markup! {
                         "The filename"{trimmed_info}" should be in "<Emphasis>{allowed_case_names}</Emphasis>"."
                     },
-                ).note(markup! {
+                );
+                let diagnostic = if let Some(include) = convention {
+                    diagnostic.note(markup! {
+                        "This convention applies to the files that match "<Emphasis>{include}</Emphasis>"."
+                    })
+                } else {
+                    diagnostic
+                };
+                Some(diagnostic.note(markup! {
                     "The filename could be renamed to one of the following names:\n"{suggested_filenames}
                 }))
             },
@@ -331,6 +365,41 @@ pub struct FilenamingConventionOptions {
     /// Allowed cases for file names.
     #[serde(default, skip_serializing_if = "is_default_filename_cases")]
     pub filename_cases: FilenameCases,
+
+    /// Allowed cases for the names of the files that match some globs.
+    /// A file uses the first convention that matches its path.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conventions: Vec<FilenamingConvention>,
+}
+
+impl FilenamingConventionOptions {
+    /// Returns the allowed cases of the file at `path`,
+    /// and the glob of the convention that applies to it, if any
+    fn filename_cases_of(&self, path: &Path) -> (&FilenameCases, Option<&str>) {
+        let path = normalize_path(path);
+        self.conventions
+            .iter()
+            .find_map(|convention| {
+                let include = convention
+                    .include
+                    .iter()
+                    .find(|glob| matches_path_suffix(glob, &path))?;
+                Some((&convention.filename_cases, Some(include.as_str())))
+            })
+            .unwrap_or((&self.filename_cases, None))
+    }
+}
+
+/// Allowed cases for the names of the files that match some globs.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FilenamingConvention {
+    /// The globs of the paths of the files that use this convention.
+    pub include: Vec<String>,
+
+    /// Allowed cases for the names of these files.
+    pub filename_cases: FilenameCases,
 }
 
 const fn enabled() -> bool {
@@ -355,6 +424,7 @@ impl Default for FilenamingConventionOptions {
             strict_case: true,
             require_ascii: false,
             filename_cases: FilenameCases::default(),
+            conventions: Vec::new(),
         }
     }
 }
@@ -421,6 +421,30 @@ declare_lint_rule! {
     ///   - `any`: anywhere (default value if the scope is unset)
     ///   - `global`: the global scope (also includes the namespace scopes)
     ///
+    /// A convention can further restrict the declarations it applies to with `filter`:
+    /// a regular expression that the name of the declaration must match.
+    /// The filter is always checked against the name of the declaration,
+    /// even if a previous convention forwarded a part of the name.
+    ///
+    /// In the following example, we require variables ending with `Component` to be in [`PascalCase`]:
+    ///
+    /// ```json5
+    /// {
+    ///     // ...
+    ///     "options": {
+    ///         "conventions": [
+    ///             {
+    ///                 "selector": {
+    ///                     "kind": "variable"
+    ///                 },
+    ///                 "filter": ".+Component",
+    ///                 "formats": ["PascalCase"]
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
     /// For each declaration,
     /// the `conventions` array is traversed until a selector selects the declaration.
     /// The requirements of the convention are so verified on the declaration.
@@ -594,7 +618,7 @@ declare_lint_rule! {
     ///
     /// ### Regular expression syntax
     ///
-    /// The `match` option takes a regular expression that supports the following syntaxes:
+    /// The `match` and `filter` options take a regular expression that supports the following syntaxes:
     ///
     /// - Greedy quantifiers `*`, `?`, `+`, `{n}`, `{n,m}`, `{n,}`, `{m}`
     /// - Non-greedy quantifiers `*?`, `??`, `+?`, `{n}?`, `{n,m}?`, `{n,}?`, `{m}?`
@@ -653,12 +677,16 @@ impl Rule for UseNamingConvention {
             });
         }
         let node_selector = Selector::from_name(node)?;
+        // The filters are checked against the name of the declaration, not against a capture
+        let unfiltered_name = name;
         let mut is_not_trimmed = true;
-        for convention in options
-            .conventions
-            .iter()
-            .filter(|convention| node_selector.contains(convention.selector))
-        {
+        for convention in options.conventions.iter().filter(|convention| {
+            node_selector.contains(convention.selector)
+                && convention
+                    .filter
+                    .as_ref()
+                    .map_or(true, |filter| filter.is_match(unfiltered_name))
+        }) {
             if let Some(matching) = &convention.matching {
                 let Some(capture) = matching.captures(name) else {
                     return Some(State {
@@ -993,6 +1021,11 @@ pub struct Convention {
     #[serde(default, skip_serializing_if = "is_default")]
     pub selector: Selector,
 
+    /// Regular expression that the name of a declaration must match
+    /// for the convention to apply
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<RestrictedRegex>,
+
     /// Regular expression to enforce
     #[serde(default, rename = "match", skip_serializing_if = "Option::is_none")]
     pub matching: Option<RestrictedRegex>,
@@ -1408,28 +1441,33 @@ impl Selector {
         match kind {
             Kind::TypeProperty if self.modifiers.contains(Modifier::Readonly) => Convention {
                 selector: Selector::with_modifiers(self.kind, Modifier::Readonly),
+                filter: None,
                 matching: None,
                 formats: Formats(Case::Camel | Case::Constant),
             },
             Kind::TypeGetter => Convention {
                 selector: kind.into(),
+                filter: None,
                 matching: None,
                 formats: Formats(Case::Camel | Case::Constant),
             },
             Kind::Function if Scope::Global.contains(self.scope) => Convention {
                 selector: Selector::with_scope(kind, Scope::Global),
+                filter: None,
                 matching: None,
                 formats: Formats(Case::Camel | Case::Pascal | Case::Upper),
             },
             Kind::Variable | Kind::Const | Kind::Var if Scope::Global.contains(self.scope) => {
                 Convention {
                     selector: Selector::with_scope(kind, Scope::Global),
+                    filter: None,
                     matching: None,
                     formats: Formats(Case::Camel | Case::Pascal | Case::Constant),
                 }
             }
             Kind::Any | Kind::ExportAlias | Kind::ImportAlias => Convention {
                 selector: kind.into(),
+                filter: None,
                 matching: None,
                 formats: Formats(Case::Camel | Case::Pascal | Case::Constant),
             },
@@ -1438,6 +1476,7 @@ impl Selector {
             {
                 Convention {
                     selector: Selector::with_modifiers(kind, Modifier::Static),
+                    filter: None,
                     matching: None,
                     formats: Formats(Case::Camel | Case::Constant),
                 }
@@ -1460,6 +1499,7 @@ impl Selector {
             | Kind::TypeSetter
             | Kind::Using => Convention {
                 selector: kind.into(),
+                filter: None,
                 matching: None,
                 formats: Formats(Case::Camel.into()),
             },
@@ -1470,16 +1510,19 @@ impl Selector {
             | Kind::TypeAlias
             | Kind::TypeParameter => Convention {
                 selector: kind.into(),
+                filter: None,
                 matching: None,
                 formats: Formats(Case::Pascal.into()),
             },
             Kind::EnumMember => Convention {
                 selector: kind.into(),
+                filter: None,
                 matching: None,
                 formats: Formats(Case::from(options.enum_member_case).into()),
             },
             Kind::Variable | Kind::Const | Kind::Var | Kind::Let => Convention {
                 selector: kind.into(),
+                filter: None,
                 matching: None,
                 formats: Formats(Case::Camel | Case::Pascal),
             },
@@ -1490,6 +1533,7 @@ impl Selector {
             | Kind::NamespaceLike
             | Kind::FunctionParameter => Convention {
                 selector: kind.into(),
+                filter: None,
                 matching: None,
                 formats: Formats(Case::Camel | Case::Pascal),
             },
@@ -71,11 +71,7 @@ impl Rule for UseNodejsImportProtocol {
         }
         let module_name = node.module_name_token()?;
         let module_name_trimmed = inner_string_text(&module_name);
-        if ctx.is_dependency(&module_name_trimmed)
-            || ctx.is_dev_dependency(&module_name_trimmed)
-            || ctx.is_peer_dependency(&module_name_trimmed)
-            || ctx.is_optional_dependency(&module_name_trimmed)
-        {
+        if ctx.is_declared_dependency(&module_name_trimmed) {
             return None;
         }
         is_node_module_without_protocol(&module_name_trimmed).then_some(module_name)
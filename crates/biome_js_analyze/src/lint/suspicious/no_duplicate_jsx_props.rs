@@ -1,14 +1,24 @@
+use crate::services::semantic::Semantic;
 use biome_analyze::context::RuleContext;
-use biome_analyze::{declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_analyze::{declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
 use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
 use biome_js_syntax::jsx_ext::AnyJsxElement;
-use biome_js_syntax::{AnyJsxAttribute, JsxAttribute};
-use biome_rowan::AstNode;
-use rustc_hash::FxHashMap;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsObjectMember, AnyJsxAttribute, JsObjectExpression, JsxAttribute,
+    JsxSpreadAttribute,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 declare_lint_rule! {
     /// Prevents JSX properties to be assigned multiple times.
     ///
+    /// The properties assigned by a spread attribute are also checked,
+    /// when the spread object is an object literal,
+    /// or a `const` variable initialized with an object literal.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -21,6 +31,11 @@ declare_lint_rule! {
     /// <label xml:lang="en-US" xml:lang="en-US"></label>
     /// ```
     ///
+    /// ```jsx,expect_diagnostic
+    /// const defaults = { name: "John" };
+    /// <Hello {...defaults} name="John" />
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```jsx
@@ -30,6 +45,10 @@ declare_lint_rule! {
     /// ```jsx
     /// <label xml:lang="en-US" lang="en-US"></label>
     /// ```
+    ///
+    /// ```jsx
+    /// <Hello {...props} name="John" />
+    /// ```
  pub NoDuplicateJsxProps {
         version: "1.0.0",
         name: "noDuplicateJsxProps",
@@ -40,22 +59,42 @@ declare_lint_rule! {
 }
 
 impl Rule for NoDuplicateJsxProps {
-    type Query = Ast<AnyJsxElement>;
-    type State = (String, Vec<JsxAttribute>);
-    type Signals = FxHashMap<String, Vec<JsxAttribute>>;
+    type Query = Semantic<AnyJsxElement>;
+    type State = (String, Vec<DefinedProp>);
+    type Signals = FxHashMap<String, Vec<DefinedProp>>;
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
 
-        let mut defined_attributes: FxHashMap<String, Vec<JsxAttribute>> = FxHashMap::default();
+        let mut defined_attributes: FxHashMap<String, Vec<DefinedProp>> = FxHashMap::default();
         for attribute in node.attributes() {
-            if let AnyJsxAttribute::JsxAttribute(attr) = attribute {
-                if let Ok(name) = attr.name() {
-                    defined_attributes
-                        .entry(name.text())
-                        .or_default()
-                        .push(attr);
+            match attribute {
+                AnyJsxAttribute::JsxAttribute(attr) => {
+                    if let Ok(name) = attr.name() {
+                        defined_attributes
+                            .entry(name.text())
+                            .or_default()
+                            .push(DefinedProp::Attribute(attr));
+                    }
+                }
+                AnyJsxAttribute::JsxSpreadAttribute(spread) => {
+                    let Some(object) = spread_object(&spread, ctx.model()) else {
+                        continue;
+                    };
+                    let mut spread_names = FxHashSet::default();
+                    for member in object.members().iter().flatten() {
+                        if let Some(name) = member_name(&member) {
+                            if spread_names.insert(name.clone()) {
+                                defined_attributes.entry(name).or_default().push(
+                                    DefinedProp::Spread {
+                                        spread: spread.clone(),
+                                        member,
+                                    },
+                                );
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -67,19 +106,105 @@ impl Rule for NoDuplicateJsxProps {
     fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
         let mut attributes = state.1.iter();
 
+        let first = attributes.next()?;
         let mut diagnostic = RuleDiagnostic::new(
             rule_category!(),
-            attributes.next()?.syntax().text_trimmed_range(),
+            first.range(),
             markup!("This JSX property is assigned multiple times."),
         );
+        diagnostic = first.add_member_detail(diagnostic);
 
         for attr in attributes {
-            diagnostic = diagnostic.detail(
-                attr.syntax().text_trimmed_range(),
-                "This attribute is assigned again here.",
-            )
+            diagnostic = match attr {
+                DefinedProp::Attribute(_) => {
+                    diagnostic.detail(attr.range(), "This attribute is assigned again here.")
+                }
+                DefinedProp::Spread { .. } => diagnostic.detail(
+                    attr.range(),
+                    "This spread attribute assigns the property again here.",
+                ),
+            };
+            diagnostic = attr.add_member_detail(diagnostic);
         }
 
         Some(diagnostic)
     }
 }
+
+/// A JSX property, assigned by an attribute or a spread attribute
+pub enum DefinedProp {
+    Attribute(JsxAttribute),
+    Spread {
+        spread: JsxSpreadAttribute,
+        /// The member of the spread object that defines the property
+        member: AnyJsObjectMember,
+    },
+}
+
+impl DefinedProp {
+    fn range(&self) -> TextRange {
+        match self {
+            Self::Attribute(attribute) => attribute.syntax().text_trimmed_range(),
+            Self::Spread { spread, .. } => spread.syntax().text_trimmed_range(),
+        }
+    }
+
+    /// Points to the member of a spread object that is defined outside of the element.
+    fn add_member_detail(&self, diagnostic: RuleDiagnostic) -> RuleDiagnostic {
+        match self {
+            Self::Spread { spread, member }
+                if !spread
+                    .syntax()
+                    .text_trimmed_range()
+                    .contains_range(member.range()) =>
+            {
+                diagnostic.detail(
+                    member.range(),
+                    "The spread object defines the property here.",
+                )
+            }
+            _ => diagnostic,
+        }
+    }
+}
+
+/// Returns the object literal spread by `spread`, either written inline,
+/// or as the initializer of a `const` variable.
+fn spread_object(spread: &JsxSpreadAttribute, model: &SemanticModel) -> Option<JsObjectExpression> {
+    let expression = match spread.argument().ok()?.omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let reference = identifier.name().ok()?;
+            let binding = model.binding(&reference)?;
+            let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) =
+                binding.tree().declaration()?
+            else {
+                return None;
+            };
+            if !declarator.declaration()?.is_const() {
+                return None;
+            }
+            declarator.initializer()?.expression().ok()?
+        }
+        expression => expression,
+    };
+    match expression.omit_parentheses() {
+        AnyJsExpression::JsObjectExpression(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// Returns the static name of an object member, or `None` if it's a spread or a computed name.
+fn member_name(member: &AnyJsObjectMember) -> Option<String> {
+    let name = match member {
+        AnyJsObjectMember::JsPropertyObjectMember(member) => member.name(),
+        AnyJsObjectMember::JsMethodObjectMember(member) => member.name(),
+        AnyJsObjectMember::JsGetterObjectMember(member) => member.name(),
+        AnyJsObjectMember::JsSetterObjectMember(member) => member.name(),
+        AnyJsObjectMember::JsShorthandPropertyObjectMember(member) => {
+            let name = member.name().ok()?.value_token().ok()?;
+            return Some(name.text_trimmed().to_string());
+        }
+        _ => return None,
+    };
+    Some(name.ok()?.name()?.text().to_string())
+}
@@ -4,9 +4,11 @@ use biome_analyze::{
     RuleSource, RuleSourceKind,
 };
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
 use biome_js_factory::make;
-use biome_js_syntax::{JsCallExpression, TextRange};
+use biome_js_syntax::{AnyJsExpression, JsCallExpression, TextRange};
 use biome_rowan::{AstNode, BatchMutationExt, NodeOrToken};
+use serde::{Deserialize, Serialize};
 
 declare_lint_rule! {
     /// Disallow focused tests.
@@ -31,6 +33,31 @@ declare_lint_rule! {
     /// ```js
     /// test("foo", () => {});
     /// ```
+    ///
+    /// ## Options
+    ///
+    /// Codebases that wrap the functions of their test framework can declare the names of their wrappers.
+    /// A name can be a chain of names, such as `feature.scenario`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "testFunctions": ["testCase", "feature.scenario"],
+    ///         "focusedFunctions": ["fscenario"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### testFunctions
+    ///
+    /// The functions that declare a test or a test suite.
+    /// Their `only` variant, such as `testCase.only("foo", () => {})`, is reported.
+    ///
+    /// ### focusedFunctions
+    ///
+    /// The functions that declare a focused test, such as `fscenario("foo", () => {})`.
+    ///
     pub NoFocusedTests {
         version: "1.6.0",
         name: "noFocusedTests",
@@ -49,11 +76,16 @@ impl Rule for NoFocusedTests {
     type Query = Ast<JsCallExpression>;
     type State = TextRange;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = NoFocusedTestsOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
         let callee = node.callee().ok()?;
+        let options = ctx.options();
+
+        if let Some(range) = find_custom_focus(&callee, options) {
+            return Some(range);
+        }
 
         if node.is_test_call_expression().ok()? {
             let callee = node.callee().ok()?;
@@ -65,19 +97,18 @@ impl Rule for NoFocusedTests {
                 }
             }
         } else if let Some(expression) = callee.as_js_computed_member_expression() {
-            let value_token = expression
-                .as_fields()
-                .object
-                .ok()?
-                .as_js_identifier_expression()?
-                .name()
-                .ok()?
-                .value_token()
-                .ok()?;
+            let object = expression.object().ok()?;
+            let is_test_function = match object.as_js_identifier_expression() {
+                Some(identifier) => {
+                    let value_token = identifier.name().ok()?.value_token().ok()?;
+                    CALEE_NAMES.contains(&value_token.text_trimmed())
+                }
+                None => false,
+            } || options.is_test_function(&object);
 
             if expression.l_brack_token().is_ok()
                 && expression.r_brack_token().is_ok()
-                && CALEE_NAMES.contains(&value_token.text_trimmed())
+                && is_test_function
             {
                 if let Some(literal) = expression.member().ok()?.as_any_js_literal_expression() {
                     if literal.as_js_string_literal_expression().is_some()
@@ -131,7 +162,8 @@ impl Rule for NoFocusedTests {
                     replaced_function = make::js_reference_identifier(make::ident("it"));
                     mutation.replace_element(function_name.into(), replaced_function.into());
                 }
-                _ => {}
+                // The name of the unfocused variant of a custom focused function is unknown
+                _ => return None,
             };
         } else if let Some(expression) = callee.as_js_computed_member_expression() {
             let l_brack = expression.l_brack_token().ok()?;
@@ -151,3 +183,44 @@ impl Rule for NoFocusedTests {
         ))
     }
 }
+
+/// Options for the rule `noFocusedTests`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoFocusedTestsOptions {
+    /// Additional functions that declare a test or a test suite, such as `testCase` or `feature.scenario`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub test_functions: Vec<String>,
+    /// Additional functions that declare a focused test, such as `fscenario` or `scenario.only`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub focused_functions: Vec<String>,
+}
+
+impl NoFocusedTestsOptions {
+    fn is_test_function(&self, expression: &AnyJsExpression) -> bool {
+        self.test_functions
+            .iter()
+            .any(|path| expression.matches_names_path(path))
+    }
+}
+
+/// Returns the range of the focus of `callee`,
+/// if `callee` is one of the focused functions or the `only` variant of one of the test functions of `options`.
+fn find_custom_focus(
+    callee: &AnyJsExpression,
+    options: &NoFocusedTestsOptions,
+) -> Option<TextRange> {
+    if options
+        .focused_functions
+        .iter()
+        .any(|path| callee.matches_names_path(path))
+    {
+        return Some(callee.syntax().text_trimmed_range());
+    }
+    let member_expression = callee.as_js_static_member_expression()?;
+    let member = member_expression.member().ok()?;
+    let member = member.as_js_name()?.value_token().ok()?;
+    (member.text_trimmed() == "only" && options.is_test_function(&member_expression.object().ok()?))
+        .then(|| member.text_trimmed_range())
+}
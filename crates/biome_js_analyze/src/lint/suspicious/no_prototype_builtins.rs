@@ -1,9 +1,16 @@
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
 };
 use biome_console::markup;
-use biome_js_syntax::{AnyJsMemberExpression, JsCallExpression, TextRange};
-use biome_rowan::AstNode;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsMemberExpression, JsCallExpression, TextRange,
+    TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+use crate::JsRuleAction;
 
 declare_lint_rule! {
     /// Disallow direct use of `Object.prototype` builtins.
@@ -17,6 +24,9 @@ declare_lint_rule! {
     /// For example, `foo.isPrototypeOf(bar)` should be replaced with `Object.prototype.isPrototypeOf.call(foo, "bar")`
     /// As for the `hasOwn` method, `foo.hasOwn("bar")` should be replaced with `Object.hasOwn(foo, "bar")`.
     ///
+    /// The calls to `hasOwnProperty` are fixed by using `Object.hasOwn()`.
+    /// The fix is unsafe because it changes the result of the call when the object overrides `hasOwnProperty`.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -47,6 +57,7 @@ declare_lint_rule! {
         language: "js",
         sources: &[RuleSource::Eslint("no-prototype-builtins")],
         recommended: true,
+        fix_kind: FixKind::Unsafe,
     }
 }
 
@@ -97,6 +108,70 @@ impl Rule for NoPrototypeBuiltins {
             Some(diag)
         }
     }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        if state.prototype_builtins_method_name != "hasOwnProperty" {
+            return None;
+        }
+        let call_expr = ctx.query();
+        let callee = call_expr.callee().ok()?.omit_parentheses();
+        let member_expr = AnyJsMemberExpression::cast(callee.into_syntax())?;
+        if call_expr.is_optional_chain() || is_optional_member(&member_expr) {
+            return None;
+        }
+        let object = member_expr.object().ok()?;
+        if matches!(object, AnyJsExpression::JsSuperExpression(_)) {
+            return None;
+        }
+        let mut args = call_expr.arguments().ok()?.args().iter();
+        let Some(Ok(AnyJsCallArgument::AnyJsExpression(property))) = args.next() else {
+            return None;
+        };
+        if args.next().is_some() {
+            return None;
+        }
+
+        let callee = make::js_static_member_expression(
+            make::js_identifier_expression(make::js_reference_identifier(make::ident("Object")))
+                .into(),
+            make::token(T![.]),
+            make::js_name(make::ident("hasOwn")).into(),
+        );
+        let new_call_expr =
+            make::js_call_expression(
+                callee.into(),
+                make::js_call_arguments(
+                    make::token(T!['(']),
+                    make::js_call_argument_list(
+                        [
+                            AnyJsCallArgument::AnyJsExpression(object.trim_trivia()?),
+                            AnyJsCallArgument::AnyJsExpression(property.trim_trivia()?),
+                        ],
+                        [make::token(T![,])
+                            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])],
+                    ),
+                    make::token(T![')']),
+                ),
+            )
+            .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(call_expr.clone(), new_call_expr);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>"Object.hasOwn()"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `member_expr` is part of an optional chain
+fn is_optional_member(member_expr: &AnyJsMemberExpression) -> bool {
+    match member_expr {
+        AnyJsMemberExpression::JsStaticMemberExpression(member) => member.is_optional_chain(),
+        AnyJsMemberExpression::JsComputedMemberExpression(member) => member.is_optional_chain(),
+    }
 }
 
 /// Chekcks if the `Object.prototype` builtins called directly.
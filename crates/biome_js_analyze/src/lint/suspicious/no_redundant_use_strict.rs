@@ -4,7 +4,8 @@ use biome_analyze::{
 };
 use biome_console::markup;
 use biome_js_syntax::{
-    AnyJsClass, JsDirective, JsDirectiveList, JsFunctionBody, JsModule, JsScript,
+    AnyJsClass, JsDirective, JsDirectiveList, JsExpressionStatement, JsFileSource, JsFunctionBody,
+    JsModule, JsScript,
 };
 
 use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
@@ -23,6 +24,12 @@ declare_lint_rule! {
  ///
  /// Instead, `.cjs` files are considered "scripts" and the directive `"use strict"` is accepted and advised.
  ///
+ /// The directive is also redundant inside a class, or inside a function that is already in strict mode.
+ ///
+ /// A `"use strict"` that follows other statements isn't a directive, and doesn't enable strict mode.
+ /// This happens when a bundler adds a banner before the directive of the file, for example.
+ /// The rule reports these strings too.
+ ///
  /// Note that the leading trivia, e.g., comments or newlines preceding
  /// the redundant `"use strict"` will also be removed. So that comment
  /// directives won't be transferred to a wrong place.
@@ -65,6 +72,11 @@ declare_lint_rule! {
  /// };
  ///
  /// ```
+ /// ```cjs,expect_diagnostic
+ /// /*! my-library v1.0.0 */
+ /// var VERSION = "1.0.0";
+ /// "use strict";
+ /// ```
  /// ### Valid
  /// ```cjs
  /// function foo() {
@@ -90,48 +102,68 @@ declare_lint_rule! {
     }
 }
 
-declare_node_union! { AnyNodeWithDirectives = JsFunctionBody | JsScript }
+declare_node_union! { AnyNodeWithDirectives = JsFunctionBody | JsScript | JsModule }
 impl AnyNodeWithDirectives {
     fn directives(&self) -> JsDirectiveList {
         match self {
             AnyNodeWithDirectives::JsFunctionBody(node) => node.directives(),
             AnyNodeWithDirectives::JsScript(script) => script.directives(),
+            AnyNodeWithDirectives::JsModule(module) => module.directives(),
         }
     }
 }
 declare_node_union! { pub AnyJsStrictModeNode = AnyJsClass| JsModule | JsDirective  }
 
+declare_node_union! { pub AnyJsUseStrict = JsDirective | JsExpressionStatement }
+
+pub enum RedundantUseStrict {
+    /// The code is already in strict mode because of this node
+    InStrictMode(AnyJsStrictModeNode),
+    /// The string follows other statements, so it isn't a directive and has no effect
+    NotDirective,
+}
+
 impl Rule for NoRedundantUseStrict {
-    type Query = Ast<JsDirective>;
-    type State = AnyJsStrictModeNode;
+    type Query = Ast<AnyJsUseStrict>;
+    type State = RedundantUseStrict;
     type Signals = Option<Self::State>;
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
-        let node = ctx.query();
+        let node = match ctx.query() {
+            AnyJsUseStrict::JsDirective(directive) => directive,
+            AnyJsUseStrict::JsExpressionStatement(statement) => {
+                return is_misplaced_use_strict(statement)
+                    .then_some(RedundantUseStrict::NotDirective);
+            }
+        };
         if node.inner_string_text().ok()? != "use strict" {
             return None;
         }
         let mut outer_most: Option<AnyJsStrictModeNode> = None;
-        let root = ctx.root();
-        match root {
-            biome_js_syntax::AnyJsRoot::JsModule(js_module) => outer_most = Some(js_module.into()),
-            _ => {
-                for n in node.syntax().ancestors() {
-                    match AnyNodeWithDirectives::try_cast(n) {
-                        Ok(parent) => {
-                            for directive in parent.directives() {
-                                let directive_text = directive.inner_string_text().ok()?;
-                                if directive_text == "use strict" {
-                                    outer_most = Some(directive.into());
-                                    break; // continue with next parent
-                                }
+        // The file source tells if the file is a module, which includes the `.js` files
+        // of the projects whose `package.json` defines the type `module`
+        if ctx.source_type::<JsFileSource>().is_module() {
+            outer_most = ctx
+                .root()
+                .as_js_module()
+                .cloned()
+                .map(AnyJsStrictModeNode::from);
+        } else {
+            for n in node.syntax().ancestors() {
+                match AnyNodeWithDirectives::try_cast(n) {
+                    Ok(parent) => {
+                        for directive in parent.directives() {
+                            let directive_text = directive.inner_string_text().ok()?;
+                            if directive_text == "use strict" {
+                                outer_most = Some(directive.into());
+                                break; // continue with next parent
                             }
                         }
-                        Err(n) => {
-                            if let Some(module_or_class) = AnyJsClass::cast(n) {
-                                outer_most = Some(module_or_class.into());
-                            }
+                    }
+                    Err(n) => {
+                        if let Some(module_or_class) = AnyJsClass::cast(n) {
+                            outer_most = Some(module_or_class.into());
                         }
                     }
                 }
@@ -143,12 +175,29 @@ impl Rule for NoRedundantUseStrict {
             if outer_most.syntax() == node.syntax() {
                 return None;
             }
-            return Some(outer_most);
+            return Some(RedundantUseStrict::InStrictMode(outer_most));
         }
 
         None
     }
     fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let state = match state {
+            RedundantUseStrict::InStrictMode(state) => state,
+            RedundantUseStrict::NotDirective => {
+                return Some(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        ctx.query().range(),
+                        markup! {
+                            "This "<Emphasis>"use strict"</Emphasis>" isn't a directive, and has no effect."
+                        },
+                    )
+                    .note(markup! {
+                        "A directive must precede the other statements of the file or of the function. This one follows other statements, such as the banner added by a bundler, so it doesn't enable strict mode."
+                    }),
+                );
+            }
+        };
         let mut diag = RuleDiagnostic::new(
             rule_category!(),
             ctx.query().range(),
@@ -174,18 +223,46 @@ impl Rule for NoRedundantUseStrict {
         Some(diag)
     }
 
-    fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<JsRuleAction> {
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
         let node = ctx.query();
         let mut mutation = ctx.root().begin();
         // This will also remove the trivia of the node
         // which is intended
         mutation.remove_node(node.clone());
+        let message = match state {
+            RedundantUseStrict::InStrictMode(_) => {
+                markup! { "Remove the redundant "<Emphasis>"use strict"</Emphasis>" directive." }
+            }
+            RedundantUseStrict::NotDirective => {
+                markup! { "Remove the ineffective "<Emphasis>"use strict"</Emphasis>"." }
+            }
+        };
         Some(JsRuleAction::new(
             ActionCategory::QuickFix,
             ctx.metadata().applicability(),
-            markup! { "Remove the redundant "<Emphasis>"use strict"</Emphasis>" directive." }
-                .to_owned(),
+            message.to_owned(),
             mutation,
         ))
     }
 }
+
+/// Returns `true` if `statement` is a `"use strict"` string that follows other statements
+/// at the start of a file or of a function, where it was meant to be a directive
+fn is_misplaced_use_strict(statement: &JsExpressionStatement) -> bool {
+    let is_use_strict = statement
+        .expression()
+        .ok()
+        .and_then(|expression| {
+            expression
+                .as_any_js_literal_expression()?
+                .as_js_string_literal_expression()?
+                .inner_string_text()
+                .ok()
+        })
+        .is_some_and(|text| text == "use strict");
+    is_use_strict
+        && statement
+            .syntax()
+            .grand_parent()
+            .is_some_and(|node| AnyNodeWithDirectives::can_cast(node.kind()))
+}
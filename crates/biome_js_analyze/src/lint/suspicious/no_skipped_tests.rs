@@ -3,9 +3,11 @@ use biome_analyze::{
     RuleSource, RuleSourceKind,
 };
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
 use biome_js_factory::make;
-use biome_js_syntax::JsCallExpression;
-use biome_rowan::{BatchMutationExt, TextRange};
+use biome_js_syntax::{AnyJsExpression, JsCallExpression};
+use biome_rowan::{AstNode, BatchMutationExt, TextRange};
+use serde::{Deserialize, Serialize};
 
 use crate::JsRuleAction;
 
@@ -33,6 +35,30 @@ declare_lint_rule! {
     /// test("test", () => {});
     /// ```
     ///
+    /// ## Options
+    ///
+    /// Codebases that wrap the functions of their test framework can declare the names of their wrappers.
+    /// A name can be a chain of names, such as `feature.scenario`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "testFunctions": ["testCase", "feature.scenario"],
+    ///         "skippedFunctions": ["xscenario"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### testFunctions
+    ///
+    /// The functions that declare a test or a test suite.
+    /// Their `skip` variant, such as `testCase.skip("test", () => {})`, is reported.
+    ///
+    /// ### skippedFunctions
+    ///
+    /// The functions that declare a disabled test, such as `xscenario("test", () => {})`.
+    ///
     pub NoSkippedTests {
         version: "1.6.0",
         name: "noSkippedTests",
@@ -50,11 +76,15 @@ impl Rule for NoSkippedTests {
     type Query = Ast<JsCallExpression>;
     type State = TextRange;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = NoSkippedTestsOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
 
+        if let Some(range) = find_custom_skip(&node.callee().ok()?, ctx.options()) {
+            return Some(range);
+        }
+
         if node.is_test_call_expression().ok()? {
             let callee = node.callee().ok()?;
             if callee.contains_a_test_pattern().ok()? {
@@ -111,7 +141,8 @@ impl Rule for NoSkippedTests {
                 replaced_function = make::js_reference_identifier(make::ident("test"));
                 mutation.replace_element(function_name.into(), replaced_function.into());
             }
-            _ => {}
+            // The name of the enabled variant of a custom skipped function is unknown
+            _ => return None,
         };
 
         Some(JsRuleAction::new(
@@ -122,3 +153,41 @@ impl Rule for NoSkippedTests {
         ))
     }
 }
+
+/// Options for the rule `noSkippedTests`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoSkippedTestsOptions {
+    /// Additional functions that declare a test or a test suite, such as `testCase` or `feature.scenario`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub test_functions: Vec<String>,
+    /// Additional functions that declare a disabled test, such as `xscenario` or `scenario.skip`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skipped_functions: Vec<String>,
+}
+
+/// Returns the range of the function that disables the test,
+/// if `callee` is one of the skipped functions or the `skip` variant of one of the test functions of `options`.
+fn find_custom_skip(
+    callee: &AnyJsExpression,
+    options: &NoSkippedTestsOptions,
+) -> Option<TextRange> {
+    if options
+        .skipped_functions
+        .iter()
+        .any(|path| callee.matches_names_path(path))
+    {
+        return Some(callee.syntax().text_trimmed_range());
+    }
+    let member_expression = callee.as_js_static_member_expression()?;
+    let member = member_expression.member().ok()?;
+    let member = member.as_js_name()?.value_token().ok()?;
+    let object = member_expression.object().ok()?;
+    (member.text_trimmed() == "skip"
+        && options
+            .test_functions
+            .iter()
+            .any(|path| object.matches_names_path(path)))
+    .then(|| member.text_trimmed_range())
+}
@@ -1,20 +1,27 @@
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
     RuleSource,
 };
 use biome_console::markup;
 use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
 use biome_js_syntax::{
-    AnyJsExpression, AnyJsLiteralExpression, JsBinaryExpression, JsBinaryExpressionFields,
-    JsBinaryOperator, JsUnaryOperator, TextRange,
+    AnyJsExpression, AnyJsLiteralExpression, AnyTsType, JsBinaryExpression,
+    JsBinaryExpressionFields, JsBinaryOperator, JsFormalParameter, JsSyntaxKind, JsUnaryOperator,
+    JsVariableDeclarator, TextRange, TsTypeAnnotation,
 };
-use biome_rowan::{AstNode, BatchMutationExt};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
 
-use crate::JsRuleAction;
+use crate::{services::semantic::Semantic, JsRuleAction};
 
 declare_lint_rule! {
     /// This rule verifies the result of `typeof $expr` unary expressions is being compared to valid values, either string literals containing valid type names or other `typeof` expressions
     ///
+    /// In TypeScript, the rule also reports the comparisons that are impossible given the declared type
+    /// of a variable or a parameter, such as `typeof x === "number"` where `x` is declared as a `string`.
+    /// Only the types that Biome can resolve without type inference are taken into account:
+    /// keywords, literal types, arrays, tuples, functions, and unions of them.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -55,6 +62,12 @@ declare_lint_rule! {
     /// typeof foo == -5
     /// ```
     ///
+    /// ```ts,expect_diagnostic
+    /// function f(x: string | undefined) {
+    ///     return typeof x === "number";
+    /// }
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```js
@@ -68,6 +81,12 @@ declare_lint_rule! {
     /// ```js
     /// typeof bar === typeof qux
     /// ```
+    ///
+    /// ```ts
+    /// function f(x: string | number) {
+    ///     return typeof x === "number";
+    /// }
+    /// ```
     pub UseValidTypeof {
         version: "1.0.0",
         name: "useValidTypeof",
@@ -79,7 +98,7 @@ declare_lint_rule! {
 }
 
 impl Rule for UseValidTypeof {
-    type Query = Ast<JsBinaryExpression>;
+    type Query = Semantic<JsBinaryExpression>;
     type State = (TypeofError, Option<(AnyJsExpression, JsTypeName)>);
     type Signals = Option<Self::State>;
     type Options = ();
@@ -130,8 +149,27 @@ impl Rule for UseValidTypeof {
                         .trim_end_matches(['"', '\''])
                         .to_lowercase();
 
-                    if JsTypeName::from_str(&literal).is_some() {
-                        return None;
+                    if let Some(type_name) = JsTypeName::from_str(&literal) {
+                        let argument = unary.argument().ok()?.omit_parentheses();
+                        let (name, annotation, is_optional) =
+                            declared_type(ctx.model(), &argument)?;
+                        let annotation = annotation.ty().ok()?;
+                        let possible_types = possible_typeof_results(&annotation)?;
+                        // An optional parameter can also be `undefined`
+                        if possible_types.contains(&type_name)
+                            || (is_optional && type_name == JsTypeName::Undefined)
+                        {
+                            return None;
+                        }
+                        return Some((
+                            TypeofError::ImpossibleLiteral {
+                                range,
+                                name,
+                                type_name,
+                                annotation_range: annotation.range(),
+                            },
+                            None,
+                        ));
                     }
 
                     // Try to fix the casing of the literal eg. "String" -> "string"
@@ -218,6 +256,24 @@ impl Rule for UseValidTypeof {
                     .note("not a string literal")
                     .description(format!("{TITLE}: this expression is not a string literal",))
             }
+            TypeofError::ImpossibleLiteral {
+                range,
+                name,
+                type_name,
+                annotation_range,
+            } => {
+                let type_name = type_name.as_str();
+                RuleDiagnostic::new(rule_category!(), range, TITLE)
+                    .detail(
+                        annotation_range,
+                        markup! {
+                            "The declared type of "<Emphasis>{name}</Emphasis>" is never of type "<Emphasis>"\""{type_name}"\""</Emphasis>"."
+                        },
+                    )
+                    .description(format!(
+                        "{TITLE}: the declared type of `{name}` is never of type \"{type_name}\""
+                    ))
+            }
         })
     }
 
@@ -249,8 +305,16 @@ impl Rule for UseValidTypeof {
 pub enum TypeofError {
     InvalidLiteral(TextRange, String),
     InvalidExpression(TextRange),
+    /// The type name is valid, but the declared type of the compared variable excludes it
+    ImpossibleLiteral {
+        range: TextRange,
+        name: String,
+        type_name: JsTypeName,
+        annotation_range: TextRange,
+    },
 }
 
+#[derive(Clone, Copy, Eq, PartialEq)]
 pub enum JsTypeName {
     Undefined,
     Object,
@@ -292,3 +356,92 @@ impl JsTypeName {
         }
     }
 }
+
+/// Returns the name and the type annotation of the variable or the parameter referenced by `expression`,
+/// and whether the parameter is optional
+fn declared_type(
+    model: &SemanticModel,
+    expression: &AnyJsExpression,
+) -> Option<(String, TsTypeAnnotation, bool)> {
+    let reference = expression.as_js_identifier_expression()?.name().ok()?;
+    let binding = model.binding(&reference)?;
+    let declaration = binding.syntax().parent()?;
+    let (annotation, is_optional) = match declaration.kind() {
+        JsSyntaxKind::JS_VARIABLE_DECLARATOR => (
+            JsVariableDeclarator::cast(declaration)?
+                .variable_annotation()?
+                .type_annotation()
+                .ok()??,
+            false,
+        ),
+        JsSyntaxKind::JS_FORMAL_PARAMETER => {
+            let parameter = JsFormalParameter::cast(declaration)?;
+            (
+                parameter.type_annotation()?,
+                parameter.question_mark_token().is_some(),
+            )
+        }
+        _ => return None,
+    };
+    Some((
+        reference.name().ok()?.text().to_string(),
+        annotation,
+        is_optional,
+    ))
+}
+
+/// Returns the results of `typeof` that are possible for a value of type `ty`.
+///
+/// Returns [None] when the type can't be resolved without type inference,
+/// e.g. type references, object types and generics.
+fn possible_typeof_results(ty: &AnyTsType) -> Option<Vec<JsTypeName>> {
+    let mut results = Vec::new();
+    collect_typeof_results(ty, &mut results)?;
+    Some(results)
+}
+
+fn collect_typeof_results(ty: &AnyTsType, results: &mut Vec<JsTypeName>) -> Option<()> {
+    match ty {
+        AnyTsType::TsStringType(_)
+        | AnyTsType::TsStringLiteralType(_)
+        | AnyTsType::TsTemplateLiteralType(_) => results.push(JsTypeName::String),
+        AnyTsType::TsNumberType(_) | AnyTsType::TsNumberLiteralType(_) => {
+            results.push(JsTypeName::Number)
+        }
+        AnyTsType::TsBooleanType(_) | AnyTsType::TsBooleanLiteralType(_) => {
+            results.push(JsTypeName::Boolean)
+        }
+        AnyTsType::TsBigintType(_) | AnyTsType::TsBigintLiteralType(_) => {
+            results.push(JsTypeName::BigInt)
+        }
+        AnyTsType::TsSymbolType(_) => results.push(JsTypeName::Symbol),
+        AnyTsType::TsUndefinedType(_) | AnyTsType::TsVoidType(_) => {
+            results.push(JsTypeName::Undefined)
+        }
+        // `typeof null` is `"object"`
+        AnyTsType::TsNullLiteralType(_) | AnyTsType::TsArrayType(_) | AnyTsType::TsTupleType(_) => {
+            results.push(JsTypeName::Object)
+        }
+        AnyTsType::TsFunctionType(_) | AnyTsType::TsConstructorType(_) => {
+            results.push(JsTypeName::Function)
+        }
+        // The functions are also of type `object`
+        AnyTsType::TsNonPrimitiveType(_) => {
+            results.push(JsTypeName::Object);
+            results.push(JsTypeName::Function);
+        }
+        AnyTsType::TsParenthesizedType(ty) => collect_typeof_results(&ty.ty().ok()?, results)?,
+        AnyTsType::TsTypeOperatorType(ty) => match ty.operator_token().ok()?.text_trimmed() {
+            "readonly" => collect_typeof_results(&ty.ty().ok()?, results)?,
+            "unique" => results.push(JsTypeName::Symbol),
+            _ => return None,
+        },
+        AnyTsType::TsUnionType(ty) => {
+            for ty in ty.types().iter() {
+                collect_typeof_results(&ty.ok()?, results)?;
+            }
+        }
+        _ => return None,
+    }
+    Some(())
+}
@@ -14,6 +14,8 @@ pub type NoArrayIndexKey =
 pub type NoAssignInExpressions = < lint :: suspicious :: no_assign_in_expressions :: NoAssignInExpressions as biome_analyze :: Rule > :: Options ;
 pub type NoAsyncPromiseExecutor = < lint :: suspicious :: no_async_promise_executor :: NoAsyncPromiseExecutor as biome_analyze :: Rule > :: Options ;
 pub type NoAutofocus = <lint::a11y::no_autofocus::NoAutofocus as biome_analyze::Rule>::Options;
+pub type NoAwaitInLoop =
+    <lint::nursery::no_await_in_loop::NoAwaitInLoop as biome_analyze::Rule>::Options;
 pub type NoBannedTypes =
     <lint::complexity::no_banned_types::NoBannedTypes as biome_analyze::Rule>::Options;
 pub type NoBarrelFile =
@@ -24,6 +26,7 @@ pub type NoCatchAssign =
     <lint::suspicious::no_catch_assign::NoCatchAssign as biome_analyze::Rule>::Options;
 pub type NoChildrenProp =
     <lint::correctness::no_children_prop::NoChildrenProp as biome_analyze::Rule>::Options;
+pub type NoCircularDomainDependencies = < lint :: nursery :: no_circular_domain_dependencies :: NoCircularDomainDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoClassAssign =
     <lint::suspicious::no_class_assign::NoClassAssign as biome_analyze::Rule>::Options;
 pub type NoCommaOperator =
@@ -59,6 +62,8 @@ pub type NoDistractingElements =
     <lint::a11y::no_distracting_elements::NoDistractingElements as biome_analyze::Rule>::Options;
 pub type NoDoneCallback =
     <lint::nursery::no_done_callback::NoDoneCallback as biome_analyze::Rule>::Options;
+pub type NoDoubleAssertion =
+    <lint::nursery::no_double_assertion::NoDoubleAssertion as biome_analyze::Rule>::Options;
 pub type NoDoubleEquals =
     <lint::suspicious::no_double_equals::NoDoubleEquals as biome_analyze::Rule>::Options;
 pub type NoDuplicateCase =
@@ -66,12 +71,15 @@ pub type NoDuplicateCase =
 pub type NoDuplicateClassMembers = < lint :: suspicious :: no_duplicate_class_members :: NoDuplicateClassMembers as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateElseIf =
     <lint::nursery::no_duplicate_else_if::NoDuplicateElseIf as biome_analyze::Rule>::Options;
+pub type NoDuplicateImportsAcrossFiles = < lint :: nursery :: no_duplicate_imports_across_files :: NoDuplicateImportsAcrossFiles as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateJsxProps =
     <lint::suspicious::no_duplicate_jsx_props::NoDuplicateJsxProps as biome_analyze::Rule>::Options;
 pub type NoDuplicateObjectKeys = < lint :: suspicious :: no_duplicate_object_keys :: NoDuplicateObjectKeys as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateParameters = < lint :: suspicious :: no_duplicate_parameters :: NoDuplicateParameters as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateTestHooks = < lint :: suspicious :: no_duplicate_test_hooks :: NoDuplicateTestHooks as biome_analyze :: Rule > :: Options ;
 pub type NoDynamicNamespaceImportAccess = < lint :: nursery :: no_dynamic_namespace_import_access :: NoDynamicNamespaceImportAccess as biome_analyze :: Rule > :: Options ;
+pub type NoEmptyAlternatives =
+    <lint::nursery::no_empty_alternatives::NoEmptyAlternatives as biome_analyze::Rule>::Options;
 pub type NoEmptyBlockStatements = < lint :: suspicious :: no_empty_block_statements :: NoEmptyBlockStatements as biome_analyze :: Rule > :: Options ;
 pub type NoEmptyCharacterClassInRegex = < lint :: correctness :: no_empty_character_class_in_regex :: NoEmptyCharacterClassInRegex as biome_analyze :: Rule > :: Options ;
 pub type NoEmptyInterface =
@@ -115,6 +123,7 @@ pub type NoImplicitAnyLet =
     <lint::suspicious::no_implicit_any_let::NoImplicitAnyLet as biome_analyze::Rule>::Options;
 pub type NoImplicitBoolean =
     <lint::style::no_implicit_boolean::NoImplicitBoolean as biome_analyze::Rule>::Options;
+pub type NoImplicitSpreadProps = < lint :: nursery :: no_implicit_spread_props :: NoImplicitSpreadProps as biome_analyze :: Rule > :: Options ;
 pub type NoImportAssign =
     <lint::suspicious::no_import_assign::NoImportAssign as biome_analyze::Rule>::Options;
 pub type NoInferrableTypes =
@@ -127,14 +136,17 @@ pub type NoInvalidNewBuiltin = < lint :: correctness :: no_invalid_new_builtin :
 pub type NoInvalidUseBeforeDeclaration = < lint :: correctness :: no_invalid_use_before_declaration :: NoInvalidUseBeforeDeclaration as biome_analyze :: Rule > :: Options ;
 pub type NoIrregularWhitespace =
     <lint::nursery::no_irregular_whitespace::NoIrregularWhitespace as biome_analyze::Rule>::Options;
+pub type NoJsxLiteralsInAttributesNeedingIds = < lint :: nursery :: no_jsx_literals_in_attributes_needing_ids :: NoJsxLiteralsInAttributesNeedingIds as biome_analyze :: Rule > :: Options ;
 pub type NoLabelVar = <lint::suspicious::no_label_var::NoLabelVar as biome_analyze::Rule>::Options;
 pub type NoLabelWithoutControl = < lint :: nursery :: no_label_without_control :: NoLabelWithoutControl as biome_analyze :: Rule > :: Options ;
+pub type NoLeakedEventListeners = < lint :: nursery :: no_leaked_event_listeners :: NoLeakedEventListeners as biome_analyze :: Rule > :: Options ;
 pub type NoMisleadingCharacterClass = < lint :: suspicious :: no_misleading_character_class :: NoMisleadingCharacterClass as biome_analyze :: Rule > :: Options ;
 pub type NoMisleadingInstantiator = < lint :: suspicious :: no_misleading_instantiator :: NoMisleadingInstantiator as biome_analyze :: Rule > :: Options ;
 pub type NoMisplacedAssertion =
     <lint::nursery::no_misplaced_assertion::NoMisplacedAssertion as biome_analyze::Rule>::Options;
 pub type NoMisrefactoredShorthandAssign = < lint :: suspicious :: no_misrefactored_shorthand_assign :: NoMisrefactoredShorthandAssign as biome_analyze :: Rule > :: Options ;
 pub type NoMultipleSpacesInRegularExpressionLiterals = < lint :: complexity :: no_multiple_spaces_in_regular_expression_literals :: NoMultipleSpacesInRegularExpressionLiterals as biome_analyze :: Rule > :: Options ;
+pub type NoMutatingPropsOrState = < lint :: nursery :: no_mutating_props_or_state :: NoMutatingPropsOrState as biome_analyze :: Rule > :: Options ;
 pub type NoNamespace = <lint::style::no_namespace::NoNamespace as biome_analyze::Rule>::Options;
 pub type NoNamespaceImport =
     <lint::style::no_namespace_import::NoNamespaceImport as biome_analyze::Rule>::Options;
@@ -144,6 +156,7 @@ pub type NoNewSymbol =
     <lint::correctness::no_new_symbol::NoNewSymbol as biome_analyze::Rule>::Options;
 pub type NoNodejsModules =
     <lint::correctness::no_nodejs_modules::NoNodejsModules as biome_analyze::Rule>::Options;
+pub type NoNonNullAssertedOptionalChain = < lint :: nursery :: no_non_null_asserted_optional_chain :: NoNonNullAssertedOptionalChain as biome_analyze :: Rule > :: Options ;
 pub type NoNonNullAssertion =
     <lint::style::no_non_null_assertion::NoNonNullAssertion as biome_analyze::Rule>::Options;
 pub type NoNoninteractiveElementToInteractiveRole = < lint :: a11y :: no_noninteractive_element_to_interactive_role :: NoNoninteractiveElementToInteractiveRole as biome_analyze :: Rule > :: Options ;
@@ -157,6 +170,7 @@ pub type NoPositiveTabindex =
     <lint::a11y::no_positive_tabindex::NoPositiveTabindex as biome_analyze::Rule>::Options;
 pub type NoPrecisionLoss =
     <lint::correctness::no_precision_loss::NoPrecisionLoss as biome_analyze::Rule>::Options;
+pub type NoProto = <lint::nursery::no_proto::NoProto as biome_analyze::Rule>::Options;
 pub type NoPrototypeBuiltins =
     <lint::suspicious::no_prototype_builtins::NoPrototypeBuiltins as biome_analyze::Rule>::Options;
 pub type NoReExportAll =
@@ -184,6 +198,7 @@ pub type NoSetterReturn =
 pub type NoShadowRestrictedNames = < lint :: suspicious :: no_shadow_restricted_names :: NoShadowRestrictedNames as biome_analyze :: Rule > :: Options ;
 pub type NoShoutyConstants =
     <lint::style::no_shouty_constants::NoShoutyConstants as biome_analyze::Rule>::Options;
+pub type NoSideEffectImportsInLibraries = < lint :: nursery :: no_side_effect_imports_in_libraries :: NoSideEffectImportsInLibraries as biome_analyze :: Rule > :: Options ;
 pub type NoSkippedTests =
     <lint::suspicious::no_skipped_tests::NoSkippedTests as biome_analyze::Rule>::Options;
 pub type NoSparseArray =
@@ -193,17 +208,27 @@ pub type NoStaticOnlyClass =
     <lint::complexity::no_static_only_class::NoStaticOnlyClass as biome_analyze::Rule>::Options;
 pub type NoStringCaseMismatch = < lint :: correctness :: no_string_case_mismatch :: NoStringCaseMismatch as biome_analyze :: Rule > :: Options ;
 pub type NoSubstr = <lint::nursery::no_substr::NoSubstr as biome_analyze::Rule>::Options;
+pub type NoSuperlinearBacktracking = < lint :: nursery :: no_superlinear_backtracking :: NoSuperlinearBacktracking as biome_analyze :: Rule > :: Options ;
 pub type NoSuspiciousSemicolonInJsx = < lint :: suspicious :: no_suspicious_semicolon_in_jsx :: NoSuspiciousSemicolonInJsx as biome_analyze :: Rule > :: Options ;
 pub type NoSvgWithoutTitle =
     <lint::a11y::no_svg_without_title::NoSvgWithoutTitle as biome_analyze::Rule>::Options;
+pub type NoSwallowedErrors =
+    <lint::nursery::no_swallowed_errors::NoSwallowedErrors as biome_analyze::Rule>::Options;
 pub type NoSwitchDeclarations = < lint :: correctness :: no_switch_declarations :: NoSwitchDeclarations as biome_analyze :: Rule > :: Options ;
 pub type NoThenProperty =
     <lint::suspicious::no_then_property::NoThenProperty as biome_analyze::Rule>::Options;
 pub type NoThisInStatic =
     <lint::complexity::no_this_in_static::NoThisInStatic as biome_analyze::Rule>::Options;
+pub type NoTranslationLiterals =
+    <lint::nursery::no_translation_literals::NoTranslationLiterals as biome_analyze::Rule>::Options;
 pub type NoUndeclaredDependencies = < lint :: nursery :: no_undeclared_dependencies :: NoUndeclaredDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoUndeclaredVariables = < lint :: correctness :: no_undeclared_variables :: NoUndeclaredVariables as biome_analyze :: Rule > :: Options ;
+pub type NoUnhandledRejections =
+    <lint::nursery::no_unhandled_rejections::NoUnhandledRejections as biome_analyze::Rule>::Options;
+pub type NoUnknownAttribute =
+    <lint::nursery::no_unknown_attribute::NoUnknownAttribute as biome_analyze::Rule>::Options;
 pub type NoUnnecessaryContinue = < lint :: correctness :: no_unnecessary_continue :: NoUnnecessaryContinue as biome_analyze :: Rule > :: Options ;
+pub type NoUnnecessaryNonNullAssertion = < lint :: nursery :: no_unnecessary_non_null_assertion :: NoUnnecessaryNonNullAssertion as biome_analyze :: Rule > :: Options ;
 pub type NoUnreachable =
     <lint::correctness::no_unreachable::NoUnreachable as biome_analyze::Rule>::Options;
 pub type NoUnreachableSuper =
@@ -214,6 +239,8 @@ pub type NoUnsafeFinally =
 pub type NoUnsafeNegation =
     <lint::suspicious::no_unsafe_negation::NoUnsafeNegation as biome_analyze::Rule>::Options;
 pub type NoUnsafeOptionalChaining = < lint :: correctness :: no_unsafe_optional_chaining :: NoUnsafeOptionalChaining as biome_analyze :: Rule > :: Options ;
+pub type NoUntrackedPropsAccess = < lint :: nursery :: no_untracked_props_access :: NoUntrackedPropsAccess as biome_analyze :: Rule > :: Options ;
+pub type NoUntrackedSignalCalls = < lint :: nursery :: no_untracked_signal_calls :: NoUntrackedSignalCalls as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedFunctionParameters = < lint :: nursery :: no_unused_function_parameters :: NoUnusedFunctionParameters as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedImports =
     <lint::correctness::no_unused_imports::NoUnusedImports as biome_analyze::Rule>::Options;
@@ -223,6 +250,7 @@ pub type NoUnusedPrivateClassMembers = < lint :: correctness :: no_unused_privat
 pub type NoUnusedTemplateLiteral = < lint :: style :: no_unused_template_literal :: NoUnusedTemplateLiteral as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedVariables =
     <lint::correctness::no_unused_variables::NoUnusedVariables as biome_analyze::Rule>::Options;
+pub type NoUnvalidatedEnvAccess = < lint :: nursery :: no_unvalidated_env_access :: NoUnvalidatedEnvAccess as biome_analyze :: Rule > :: Options ;
 pub type NoUselessCatch =
     <lint::complexity::no_useless_catch::NoUselessCatch as biome_analyze::Rule>::Options;
 pub type NoUselessConstructor = < lint :: complexity :: no_useless_constructor :: NoUselessConstructor as biome_analyze :: Rule > :: Options ;
@@ -251,6 +279,11 @@ pub type NoVoid = <lint::complexity::no_void::NoVoid as biome_analyze::Rule>::Op
 pub type NoVoidElementsWithChildren = < lint :: correctness :: no_void_elements_with_children :: NoVoidElementsWithChildren as biome_analyze :: Rule > :: Options ;
 pub type NoVoidTypeReturn =
     <lint::correctness::no_void_type_return::NoVoidTypeReturn as biome_analyze::Rule>::Options;
+pub type NoVueDuplicateKeys =
+    <lint::nursery::no_vue_duplicate_keys::NoVueDuplicateKeys as biome_analyze::Rule>::Options;
+pub type NoVueReservedProps =
+    <lint::nursery::no_vue_reserved_props::NoVueReservedProps as biome_analyze::Rule>::Options;
+pub type NoVueSideEffectsInComputed = < lint :: nursery :: no_vue_side_effects_in_computed :: NoVueSideEffectsInComputed as biome_analyze :: Rule > :: Options ;
 pub type NoWith = <lint::complexity::no_with::NoWith as biome_analyze::Rule>::Options;
 pub type NoYodaExpression =
     <lint::nursery::no_yoda_expression::NoYodaExpression as biome_analyze::Rule>::Options;
@@ -267,8 +300,10 @@ pub type UseArrayLiterals =
     <lint::correctness::use_array_literals::UseArrayLiterals as biome_analyze::Rule>::Options;
 pub type UseArrowFunction =
     <lint::complexity::use_arrow_function::UseArrowFunction as biome_analyze::Rule>::Options;
+pub type UseArrowFunctionSyntax = < assists :: source :: use_arrow_function_syntax :: UseArrowFunctionSyntax as biome_analyze :: Rule > :: Options ;
 pub type UseAsConstAssertion =
     <lint::style::use_as_const_assertion::UseAsConstAssertion as biome_analyze::Rule>::Options;
+pub type UseAtIndex = <lint::nursery::use_at_index::UseAtIndex as biome_analyze::Rule>::Options;
 pub type UseAwait = <lint::suspicious::use_await::UseAwait as biome_analyze::Rule>::Options;
 pub type UseBlockStatements =
     <lint::style::use_block_statements::UseBlockStatements as biome_analyze::Rule>::Options;
@@ -279,6 +314,10 @@ pub type UseCollapsedElseIf =
 pub type UseConsistentArrayType = < lint :: style :: use_consistent_array_type :: UseConsistentArrayType as biome_analyze :: Rule > :: Options ;
 pub type UseConsistentBuiltinInstantiation = < lint :: nursery :: use_consistent_builtin_instantiation :: UseConsistentBuiltinInstantiation as biome_analyze :: Rule > :: Options ;
 pub type UseConsistentCurlyBraces = < lint :: nursery :: use_consistent_curly_braces :: UseConsistentCurlyBraces as biome_analyze :: Rule > :: Options ;
+pub type UseConsistentGenericConstructors = < lint :: nursery :: use_consistent_generic_constructors :: UseConsistentGenericConstructors as biome_analyze :: Rule > :: Options ;
+pub type UseConsistentObjectDefinitions = < lint :: nursery :: use_consistent_object_definitions :: UseConsistentObjectDefinitions as biome_analyze :: Rule > :: Options ;
+pub type UseConsistentRecordType = < lint :: nursery :: use_consistent_record_type :: UseConsistentRecordType as biome_analyze :: Rule > :: Options ;
+pub type UseConsistentResponseHandling = < lint :: nursery :: use_consistent_response_handling :: UseConsistentResponseHandling as biome_analyze :: Rule > :: Options ;
 pub type UseConst = <lint::style::use_const::UseConst as biome_analyze::Rule>::Options;
 pub type UseDateNow = <lint::nursery::use_date_now::UseDateNow as biome_analyze::Rule>::Options;
 pub type UseDefaultParameterLast = < lint :: style :: use_default_parameter_last :: UseDefaultParameterLast as biome_analyze :: Rule > :: Options ;
@@ -286,9 +325,12 @@ pub type UseDefaultSwitchClause = < lint :: nursery :: use_default_switch_clause
 pub type UseDefaultSwitchClauseLast = < lint :: suspicious :: use_default_switch_clause_last :: UseDefaultSwitchClauseLast as biome_analyze :: Rule > :: Options ;
 pub type UseEnumInitializers =
     <lint::style::use_enum_initializers::UseEnumInitializers as biome_analyze::Rule>::Options;
+pub type UseErrorCause =
+    <lint::nursery::use_error_cause::UseErrorCause as biome_analyze::Rule>::Options;
 pub type UseErrorMessage =
     <lint::nursery::use_error_message::UseErrorMessage as biome_analyze::Rule>::Options;
 pub type UseExhaustiveDependencies = < lint :: correctness :: use_exhaustive_dependencies :: UseExhaustiveDependencies as biome_analyze :: Rule > :: Options ;
+pub type UseExhaustiveSwitchCases = < lint :: nursery :: use_exhaustive_switch_cases :: UseExhaustiveSwitchCases as biome_analyze :: Rule > :: Options ;
 pub type UseExplicitLengthCheck = < lint :: nursery :: use_explicit_length_check :: UseExplicitLengthCheck as biome_analyze :: Rule > :: Options ;
 pub type UseExponentiationOperator = < lint :: style :: use_exponentiation_operator :: UseExponentiationOperator as biome_analyze :: Rule > :: Options ;
 pub type UseExportType =
@@ -299,8 +341,12 @@ pub type UseFocusableInteractive = < lint :: nursery :: use_focusable_interactiv
 pub type UseForOf = <lint::style::use_for_of::UseForOf as biome_analyze::Rule>::Options;
 pub type UseFragmentSyntax =
     <lint::style::use_fragment_syntax::UseFragmentSyntax as biome_analyze::Rule>::Options;
+pub type UseFunctionSyntax =
+    <assists::source::use_function_syntax::UseFunctionSyntax as biome_analyze::Rule>::Options;
 pub type UseGetterReturn =
     <lint::suspicious::use_getter_return::UseGetterReturn as biome_analyze::Rule>::Options;
+pub type UseGuardForIn =
+    <lint::nursery::use_guard_for_in::UseGuardForIn as biome_analyze::Rule>::Options;
 pub type UseHeadingContent =
     <lint::a11y::use_heading_content::UseHeadingContent as biome_analyze::Rule>::Options;
 pub type UseHookAtTopLevel =
@@ -314,8 +360,13 @@ pub type UseImportRestrictions =
     <lint::nursery::use_import_restrictions::UseImportRestrictions as biome_analyze::Rule>::Options;
 pub type UseImportType =
     <lint::style::use_import_type::UseImportType as biome_analyze::Rule>::Options;
+pub type UseIncludes = <lint::nursery::use_includes::UseIncludes as biome_analyze::Rule>::Options;
 pub type UseIsArray = <lint::suspicious::use_is_array::UseIsArray as biome_analyze::Rule>::Options;
 pub type UseIsNan = <lint::correctness::use_is_nan::UseIsNan as biome_analyze::Rule>::Options;
+pub type UseJsDocParamsMatch =
+    <lint::nursery::use_js_doc_params_match::UseJsDocParamsMatch as biome_analyze::Rule>::Options;
+pub type UseJsdocComment =
+    <assists::source::use_jsdoc_comment::UseJsdocComment as biome_analyze::Rule>::Options;
 pub type UseJsxKeyInIterable = < lint :: correctness :: use_jsx_key_in_iterable :: UseJsxKeyInIterable as biome_analyze :: Rule > :: Options ;
 pub type UseKeyWithClickEvents =
     <lint::a11y::use_key_with_click_events::UseKeyWithClickEvents as biome_analyze::Rule>::Options;
@@ -339,8 +390,15 @@ pub type UseNumberNamespace =
 pub type UseNumberToFixedDigitsArgument = < lint :: nursery :: use_number_to_fixed_digits_argument :: UseNumberToFixedDigitsArgument as biome_analyze :: Rule > :: Options ;
 pub type UseNumericLiterals =
     <lint::style::use_numeric_literals::UseNumericLiterals as biome_analyze::Rule>::Options;
+pub type UseNumericSeparators =
+    <lint::nursery::use_numeric_separators::UseNumericSeparators as biome_analyze::Rule>::Options;
+pub type UseObjectSpread =
+    <lint::nursery::use_object_spread::UseObjectSpread as biome_analyze::Rule>::Options;
+pub type UseOptimizedRegex =
+    <lint::nursery::use_optimized_regex::UseOptimizedRegex as biome_analyze::Rule>::Options;
 pub type UseOptionalChain =
     <lint::complexity::use_optional_chain::UseOptionalChain as biome_analyze::Rule>::Options;
+pub type UsePreactSignalsCorrectly = < lint :: nursery :: use_preact_signals_correctly :: UsePreactSignalsCorrectly as biome_analyze :: Rule > :: Options ;
 pub type UseRegexLiterals =
     <lint::complexity::use_regex_literals::UseRegexLiterals as biome_analyze::Rule>::Options;
 pub type UseSelfClosingElements = < lint :: style :: use_self_closing_elements :: UseSelfClosingElements as biome_analyze :: Rule > :: Options ;
@@ -356,11 +414,23 @@ pub type UseSimpleNumberKeys =
 pub type UseSimplifiedLogicExpression = < lint :: complexity :: use_simplified_logic_expression :: UseSimplifiedLogicExpression as biome_analyze :: Rule > :: Options ;
 pub type UseSingleCaseStatement = < lint :: style :: use_single_case_statement :: UseSingleCaseStatement as biome_analyze :: Rule > :: Options ;
 pub type UseSingleVarDeclarator = < lint :: style :: use_single_var_declarator :: UseSingleVarDeclarator as biome_analyze :: Rule > :: Options ;
+pub type UseSortedAttributes =
+    <assists::source::use_sorted_attributes::UseSortedAttributes as biome_analyze::Rule>::Options;
 pub type UseSortedClasses =
     <lint::nursery::use_sorted_classes::UseSortedClasses as biome_analyze::Rule>::Options;
+pub type UseSortedKeys =
+    <assists::source::use_sorted_keys::UseSortedKeys as biome_analyze::Rule>::Options;
+pub type UseSortedObjectKeys =
+    <lint::nursery::use_sorted_object_keys::UseSortedObjectKeys as biome_analyze::Rule>::Options;
+pub type UseSortedTypeMembers = < assists :: source :: use_sorted_type_members :: UseSortedTypeMembers as biome_analyze :: Rule > :: Options ;
+pub type UseSpread = <lint::nursery::use_spread::UseSpread as biome_analyze::Rule>::Options;
 pub type UseStrictMode =
     <lint::nursery::use_strict_mode::UseStrictMode as biome_analyze::Rule>::Options;
+pub type UseStringConcatenation = < assists :: source :: use_string_concatenation :: UseStringConcatenation as biome_analyze :: Rule > :: Options ;
+pub type UseStringLiteralUnion = < assists :: source :: use_string_literal_union :: UseStringLiteralUnion as biome_analyze :: Rule > :: Options ;
 pub type UseTemplate = <lint::style::use_template::UseTemplate as biome_analyze::Rule>::Options;
+pub type UseTemplateLiteral =
+    <assists::source::use_template_literal::UseTemplateLiteral as biome_analyze::Rule>::Options;
 pub type UseThrowNewError =
     <lint::nursery::use_throw_new_error::UseThrowNewError as biome_analyze::Rule>::Options;
 pub type UseThrowOnlyError =
@@ -380,6 +450,8 @@ pub type UseValidAriaValues =
 pub type UseValidAutocomplete =
     <lint::nursery::use_valid_autocomplete::UseValidAutocomplete as biome_analyze::Rule>::Options;
 pub type UseValidForDirection = < lint :: correctness :: use_valid_for_direction :: UseValidForDirection as biome_analyze :: Rule > :: Options ;
+pub type UseValidI18nKeys =
+    <lint::nursery::use_valid_i18n_keys::UseValidI18nKeys as biome_analyze::Rule>::Options;
 pub type UseValidLang = <lint::a11y::use_valid_lang::UseValidLang as biome_analyze::Rule>::Options;
 pub type UseValidTypeof =
     <lint::suspicious::use_valid_typeof::UseValidTypeof as biome_analyze::Rule>::Options;
@@ -66,12 +66,9 @@ impl ReactCallWithDependencyResult {
             .as_ref()
             .and_then(|node| AnyJsFunctionExpression::try_from(node.clone()).ok())
             .map(|function_expression| {
-                let closure = function_expression.closure(model);
-                let range = closure.closure_range();
-                closure
-                    .descendents()
-                    .flat_map(|closure| closure.all_captures())
-                    .filter(move |capture| !range.contains(capture.declaration_range().start()))
+                function_expression
+                    .closure(model)
+                    .all_captures_including_descendents()
             })
             .into_iter()
             .flatten()
@@ -193,8 +193,8 @@ mod tests {
             ),
         ]);
         let services = AriaServices {
-            roles: Arc::new(AriaRoles {}),
-            properties: Arc::new(AriaProperties {}),
+            roles: Arc::new(AriaRoles::default()),
+            properties: Arc::new(AriaProperties::default()),
         };
 
         let attribute_name_to_values = services.extract_attributes(&attribute_list).unwrap();
@@ -1,4 +1,3 @@
-mod block;
 mod break_stmt;
 mod continue_stmt;
 mod do_while;
@@ -6,6 +5,7 @@ mod for_in;
 mod for_of;
 mod for_stmt;
 mod if_stmt;
+mod labeled;
 mod return_stmt;
 mod statement;
 mod switch_stmt;
@@ -14,7 +14,6 @@ mod try_catch;
 mod variable;
 mod while_stmt;
 
-pub(super) use block::*;
 pub(super) use break_stmt::*;
 pub(super) use continue_stmt::*;
 pub(super) use do_while::*;
@@ -22,6 +21,7 @@ pub(super) use for_in::*;
 pub(super) use for_of::*;
 pub(super) use for_stmt::*;
 pub(super) use if_stmt::*;
+pub(super) use labeled::*;
 pub(super) use return_stmt::*;
 pub(super) use statement::*;
 pub(super) use switch_stmt::*;
@@ -5,8 +5,8 @@ use biome_rowan::{AstNode, SyntaxError, SyntaxResult};
 
 use crate::services::control_flow::{
     nodes::{
-        BlockVisitor, DoWhileVisitor, ForInVisitor, ForOfVisitor, ForVisitor, SwitchVisitor,
-        WhileVisitor,
+        DoWhileVisitor, FinallyBlocks, ForInVisitor, ForOfVisitor, ForVisitor, LabeledVisitor,
+        SwitchVisitor, WhileVisitor,
     },
     visitor::{FunctionVisitor, NodeVisitor, StatementStack, VisitorAdapter},
     FunctionBuilder,
@@ -23,6 +23,7 @@ impl NodeVisitor for BreakVisitor {
         state: StatementStack,
     ) -> SyntaxResult<Self> {
         let label = node.label_token();
+        let mut finally_blocks = FinallyBlocks::default();
 
         let break_block = state
             .stack
@@ -30,46 +31,51 @@ impl NodeVisitor for BreakVisitor {
             .rev()
             .take_while(|(type_id, _)| *type_id != TypeId::of::<VisitorAdapter<FunctionVisitor>>())
             .find_map(|(type_id, index)| {
-                let (block_label, block) = if let Some(visitor) =
-                    state.try_downcast::<ForVisitor>(*type_id, *index)
-                {
-                    (visitor.label.as_ref(), visitor.break_block)
-                } else if let Some(visitor) = state.try_downcast::<ForInVisitor>(*type_id, *index) {
-                    (visitor.label.as_ref(), visitor.break_block)
-                } else if let Some(visitor) = state.try_downcast::<ForOfVisitor>(*type_id, *index) {
-                    (visitor.label.as_ref(), visitor.break_block)
-                } else if let Some(visitor) = state.try_downcast::<WhileVisitor>(*type_id, *index) {
-                    (visitor.label.as_ref(), visitor.break_block)
-                } else if let Some(visitor) = state.try_downcast::<DoWhileVisitor>(*type_id, *index)
-                {
-                    (visitor.label.as_ref(), visitor.break_block)
-                } else if let Some(visitor) = state.try_downcast::<SwitchVisitor>(*type_id, *index)
-                {
-                    (visitor.label.as_ref(), visitor.break_block)
-                } else if let Some(visitor) = state.try_downcast::<BlockVisitor>(*type_id, *index) {
-                    let (label, block) = visitor.break_block.as_ref()?;
-                    (Some(label), *block)
-                } else {
+                if finally_blocks.visit(&state, *type_id, *index) {
                     return None;
-                };
+                }
 
-                match (block_label, &label) {
-                    (Some(a), Some(b)) => {
-                        if a.text_trimmed() == b.text_trimmed() {
-                            Some(block)
+                match &label {
+                    // A labeled break jumps to the end of the statement with
+                    // the same label, which can be any statement
+                    Some(label) => {
+                        let visitor = state.try_downcast::<LabeledVisitor>(*type_id, *index)?;
+                        (visitor.label.text_trimmed() == label.text_trimmed())
+                            .then_some(visitor.break_block)
+                    }
+                    // An unlabeled break jumps to the end of the innermost
+                    // loop or switch statement
+                    None => {
+                        if let Some(visitor) = state.try_downcast::<ForVisitor>(*type_id, *index) {
+                            Some(visitor.break_block)
+                        } else if let Some(visitor) =
+                            state.try_downcast::<ForInVisitor>(*type_id, *index)
+                        {
+                            Some(visitor.break_block)
+                        } else if let Some(visitor) =
+                            state.try_downcast::<ForOfVisitor>(*type_id, *index)
+                        {
+                            Some(visitor.break_block)
+                        } else if let Some(visitor) =
+                            state.try_downcast::<WhileVisitor>(*type_id, *index)
+                        {
+                            Some(visitor.break_block)
+                        } else if let Some(visitor) =
+                            state.try_downcast::<DoWhileVisitor>(*type_id, *index)
+                        {
+                            Some(visitor.break_block)
                         } else {
-                            None
+                            state
+                                .try_downcast::<SwitchVisitor>(*type_id, *index)
+                                .map(|visitor| visitor.break_block)
                         }
                     }
-
-                    (None, None) => Some(block),
-                    _ => None,
                 }
             })
             .ok_or(SyntaxError::MissingRequiredChild)?;
 
         builder
-            .append_jump(false, break_block)
+            .append_jump_through_finally(finally_blocks.blocks(), break_block)
             .with_node(node.into_syntax());
 
         Ok(Self)
@@ -4,7 +4,10 @@ use biome_js_syntax::JsContinueStatement;
 use biome_rowan::{AstNode, SyntaxError, SyntaxResult};
 
 use crate::services::control_flow::{
-    nodes::{DoWhileVisitor, ForInVisitor, ForOfVisitor, ForVisitor, WhileVisitor},
+    nodes::{
+        DoWhileVisitor, FinallyBlocks, ForInVisitor, ForOfVisitor, ForVisitor, LabeledVisitor,
+        WhileVisitor,
+    },
     visitor::{FunctionVisitor, NodeVisitor, StatementStack, VisitorAdapter},
     FunctionBuilder,
 };
@@ -20,6 +23,11 @@ impl NodeVisitor for ContinueVisitor {
         state: StatementStack,
     ) -> SyntaxResult<Self> {
         let label = node.label_token();
+        let mut finally_blocks = FinallyBlocks::default();
+
+        // Continue block of the loop directly below the visited labels, a
+        // labeled continue jumps to it if one of these labels matches
+        let mut labeled_loop_block = None;
 
         let continue_block = state
             .stack
@@ -27,40 +35,44 @@ impl NodeVisitor for ContinueVisitor {
             .rev()
             .take_while(|(type_id, _)| *type_id != TypeId::of::<VisitorAdapter<FunctionVisitor>>())
             .find_map(|(type_id, index)| {
-                let (block_label, block) = if let Some(visitor) =
-                    state.try_downcast::<ForVisitor>(*type_id, *index)
-                {
-                    (visitor.label.as_ref(), visitor.continue_block)
+                if let Some(visitor) = state.try_downcast::<LabeledVisitor>(*type_id, *index) {
+                    return match &label {
+                        Some(label) if visitor.label.text_trimmed() == label.text_trimmed() => {
+                            labeled_loop_block
+                        }
+                        // A loop can have several labels
+                        _ => None,
+                    };
+                }
+
+                let loop_block = if finally_blocks.visit(&state, *type_id, *index) {
+                    None
+                } else if let Some(visitor) = state.try_downcast::<ForVisitor>(*type_id, *index) {
+                    Some(visitor.continue_block)
                 } else if let Some(visitor) = state.try_downcast::<ForInVisitor>(*type_id, *index) {
-                    (visitor.label.as_ref(), visitor.continue_block)
+                    Some(visitor.continue_block)
                 } else if let Some(visitor) = state.try_downcast::<ForOfVisitor>(*type_id, *index) {
-                    (visitor.label.as_ref(), visitor.continue_block)
+                    Some(visitor.continue_block)
                 } else if let Some(visitor) = state.try_downcast::<WhileVisitor>(*type_id, *index) {
-                    (visitor.label.as_ref(), visitor.continue_block)
-                } else if let Some(visitor) = state.try_downcast::<DoWhileVisitor>(*type_id, *index)
-                {
-                    (visitor.label.as_ref(), visitor.continue_block)
+                    Some(visitor.continue_block)
                 } else {
-                    return None;
+                    state
+                        .try_downcast::<DoWhileVisitor>(*type_id, *index)
+                        .map(|visitor| visitor.continue_block)
                 };
 
-                match (block_label, &label) {
-                    (Some(a), Some(b)) => {
-                        if a.text_trimmed() == b.text_trimmed() {
-                            Some(block)
-                        } else {
-                            None
-                        }
-                    }
-
-                    (None, None) => Some(block),
-                    _ => None,
+                // An unlabeled continue jumps to the innermost loop
+                if label.is_none() {
+                    return loop_block;
                 }
+
+                labeled_loop_block = loop_block;
+                None
             })
             .ok_or(SyntaxError::MissingRequiredChild)?;
 
         builder
-            .append_jump(false, continue_block)
+            .append_jump_through_finally(finally_blocks.blocks(), continue_block)
             .with_node(node.into_syntax());
 
         Ok(Self)
@@ -1,5 +1,5 @@
 use biome_control_flow::builder::BlockId;
-use biome_js_syntax::{JsDoWhileStatement, JsLabeledStatement, JsSyntaxToken};
+use biome_js_syntax::JsDoWhileStatement;
 use biome_rowan::{AstNode, SyntaxResult};
 
 use crate::services::control_flow::{
@@ -8,9 +8,8 @@ use crate::services::control_flow::{
 };
 
 pub(in crate::services::control_flow) struct DoWhileVisitor {
-    // `label`, `continue_block` and `break_block` are used by the
+    // `continue_block` and `break_block` are used by the
     // `ContinueVisitor` and `BreakVisitor`
-    pub(super) label: Option<JsSyntaxToken>,
     pub(super) continue_block: BlockId,
     pub(super) break_block: BlockId,
     body_block: BlockId,
@@ -20,7 +19,7 @@ impl NodeVisitor for DoWhileVisitor {
     type Node = JsDoWhileStatement;
 
     fn enter(
-        node: Self::Node,
+        _: Self::Node,
         builder: &mut FunctionBuilder,
         _: StatementStack,
     ) -> SyntaxResult<Self> {
@@ -32,15 +31,10 @@ impl NodeVisitor for DoWhileVisitor {
         let continue_block = builder.append_block();
         let break_block = builder.append_block();
 
-        let label = node
-            .parent::<JsLabeledStatement>()
-            .and_then(|label| label.label_token().ok());
-
         // Fill the body block
         builder.set_cursor(body_block);
 
         Ok(Self {
-            label,
             continue_block,
             break_block,
             body_block,
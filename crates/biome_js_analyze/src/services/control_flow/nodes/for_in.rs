@@ -1,5 +1,5 @@
 use biome_control_flow::builder::BlockId;
-use biome_js_syntax::{JsForInStatement, JsLabeledStatement, JsSyntaxToken};
+use biome_js_syntax::JsForInStatement;
 use biome_rowan::{AstNode, SyntaxResult};
 
 use crate::services::control_flow::{
@@ -8,9 +8,8 @@ use crate::services::control_flow::{
 };
 
 pub(in crate::services::control_flow) struct ForInVisitor {
-    // `label`, `continue_block` and `break_block` are used by the
+    // `continue_block` and `break_block` are used by the
     // `ContinueVisitor` and `BreakVisitor`
-    pub(super) label: Option<JsSyntaxToken>,
     pub(super) continue_block: BlockId,
     pub(super) break_block: BlockId,
 }
@@ -36,14 +35,9 @@ impl NodeVisitor for ForInVisitor {
 
         builder.append_jump(false, break_block);
 
-        let label = node
-            .parent::<JsLabeledStatement>()
-            .and_then(|label| label.label_token().ok());
-
         builder.set_cursor(loop_block);
 
         Ok(Self {
-            label,
             continue_block,
             break_block,
         })
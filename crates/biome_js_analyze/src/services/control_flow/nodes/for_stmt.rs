@@ -1,5 +1,5 @@
 use biome_control_flow::builder::BlockId;
-use biome_js_syntax::{JsForStatement, JsLabeledStatement, JsSyntaxToken};
+use biome_js_syntax::JsForStatement;
 use biome_rowan::{AstNode, SyntaxResult};
 
 use crate::services::control_flow::{
@@ -8,9 +8,8 @@ use crate::services::control_flow::{
 };
 
 pub(in crate::services::control_flow) struct ForVisitor {
-    // `label`, `continue_block` and `break_block` are used by the
+    // `continue_block` and `break_block` are used by the
     // `ContinueVisitor` and `BreakVisitor`
-    pub(super) label: Option<JsSyntaxToken>,
     pub(super) continue_block: BlockId,
     pub(super) break_block: BlockId,
     cond_block: BlockId,
@@ -53,12 +52,7 @@ impl NodeVisitor for ForVisitor {
         let loop_block = builder.append_block();
         builder.set_cursor(loop_block);
 
-        let label = node
-            .parent::<JsLabeledStatement>()
-            .and_then(|label| label.label_token().ok());
-
         Ok(Self {
-            label,
             continue_block,
             break_block,
             cond_block,
@@ -0,0 +1,43 @@
+use biome_control_flow::builder::BlockId;
+use biome_js_syntax::{JsLabeledStatement, JsSyntaxToken};
+use biome_rowan::SyntaxResult;
+
+use crate::services::control_flow::{
+    visitor::{NodeVisitor, StatementStack},
+    FunctionBuilder,
+};
+
+pub(in crate::services::control_flow) struct LabeledVisitor {
+    // `label` and `break_block` are used by the `BreakVisitor`, and `label`
+    // is used by the `ContinueVisitor` to find the labeled loop
+    pub(super) label: JsSyntaxToken,
+    pub(super) break_block: BlockId,
+}
+
+impl NodeVisitor for LabeledVisitor {
+    type Node = JsLabeledStatement;
+
+    fn enter(
+        node: Self::Node,
+        builder: &mut FunctionBuilder,
+        _: StatementStack,
+    ) -> SyntaxResult<Self> {
+        let label = node.label_token()?;
+        let break_block = builder.append_block();
+
+        Ok(Self { label, break_block })
+    }
+
+    fn exit(
+        self,
+        _: Self::Node,
+        builder: &mut FunctionBuilder,
+        _: StatementStack,
+    ) -> SyntaxResult<()> {
+        // Implicit jump from the end of the labeled statement to the break block
+        builder.append_jump(false, self.break_block);
+        builder.set_cursor(self.break_block);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use biome_control_flow::builder::BlockId;
-use biome_js_syntax::{AnyJsSwitchClause, JsLabeledStatement, JsSwitchStatement, JsSyntaxToken};
+use biome_js_syntax::{AnyJsSwitchClause, JsSwitchStatement, JsSyntaxToken};
 use biome_rowan::{AstNode, SyntaxResult};
 
 use crate::services::control_flow::{
@@ -9,8 +9,7 @@ use crate::services::control_flow::{
 
 pub(in crate::services::control_flow) struct SwitchVisitor {
     entry_block: BlockId,
-    // `label` and `break_block` are used by the `BreakVisitor`
-    pub(super) label: Option<JsSyntaxToken>,
+    // `break_block` is used by the `BreakVisitor`
     pub(super) break_block: BlockId,
     /// Flag used by the [CaseVisitor] to check if it's the first case clause
     /// in a switch statement (used to implement fallthrough)
@@ -32,13 +31,8 @@ impl NodeVisitor for SwitchVisitor {
         let entry_block = builder.cursor();
         let break_block = builder.append_block();
 
-        let label = node
-            .parent::<JsLabeledStatement>()
-            .and_then(|label| label.label_token().ok());
-
         Ok(Self {
             entry_block,
-            label,
             break_block,
             is_first_case_clause: true,
             default_block: None,
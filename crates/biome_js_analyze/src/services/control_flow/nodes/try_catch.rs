@@ -1,3 +1,5 @@
+use std::any::TypeId;
+
 use biome_control_flow::{builder::BlockId, ExceptionHandlerKind};
 use biome_js_syntax::{AnyJsTryStatement, JsCatchClause, JsFinallyClause};
 use biome_rowan::SyntaxResult;
@@ -150,3 +152,43 @@ impl NodeVisitor for FinallyVisitor {
         Ok(())
     }
 }
+
+/// Collects the `finally` blocks a `break` or `continue` statement goes
+/// through, while visiting the statement stack from the jump towards its target
+#[derive(Default)]
+pub(in crate::services::control_flow) struct FinallyBlocks {
+    blocks: Vec<BlockId>,
+    in_finally_clause: bool,
+}
+
+impl FinallyBlocks {
+    /// Returns `true` if the visitor at `index` belongs to a try statement,
+    /// collecting its finally block if the jump leaves the try statement from
+    /// its `try` block or its catch clause
+    pub(super) fn visit(&mut self, state: &StatementStack, type_id: TypeId, index: usize) -> bool {
+        if state
+            .try_downcast::<FinallyVisitor>(type_id, index)
+            .is_some()
+        {
+            // A jump out of a finally clause doesn't go through it again
+            self.in_finally_clause = true;
+            return true;
+        }
+
+        if let Some(try_stmt) = state.try_downcast::<TryVisitor>(type_id, index) {
+            if !self.in_finally_clause {
+                self.blocks.extend(try_stmt.finally_block);
+            }
+
+            self.in_finally_clause = false;
+            return true;
+        }
+
+        false
+    }
+
+    /// Returns the collected finally blocks, from the innermost to the outermost
+    pub(super) fn blocks(&self) -> &[BlockId] {
+        &self.blocks
+    }
+}
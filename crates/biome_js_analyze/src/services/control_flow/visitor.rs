@@ -107,7 +107,7 @@ macro_rules! declare_visitor {
 declare_visitor! {
     ControlFlowVisitor {
         statement: StatementVisitor,
-        block: BlockVisitor,
+        labeled: LabeledVisitor,
         try_stmt: TryVisitor,
         catch: CatchVisitor,
         finally: FinallyVisitor,
@@ -3,7 +3,7 @@ use biome_analyze::{
     RuleKey, ServiceBag, SyntaxVisitor,
 };
 use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode};
-use biome_project::PackageJson;
+use biome_project::{PackageJson, PackageType, SideEffects};
 use biome_rowan::AstNode;
 use std::sync::Arc;
 
@@ -13,36 +13,66 @@ pub struct ManifestServices {
 }
 
 impl ManifestServices {
-    pub(crate) fn is_dependency(&self, specifier: &str) -> bool {
-        self.manifest
-            .as_ref()
-            .as_ref()
-            .map(|pkg| pkg.dependencies.contains(specifier))
-            .unwrap_or_default()
-    }
-
-    pub(crate) fn is_dev_dependency(&self, specifier: &str) -> bool {
-        self.manifest
-            .as_ref()
-            .as_ref()
-            .map(|pkg| pkg.dev_dependencies.contains(specifier))
-            .unwrap_or_default()
-    }
-
-    pub(crate) fn is_peer_dependency(&self, specifier: &str) -> bool {
-        self.manifest
-            .as_ref()
-            .as_ref()
-            .map(|pkg| pkg.peer_dependencies.contains(specifier))
-            .unwrap_or_default()
-    }
-
-    pub(crate) fn is_optional_dependency(&self, specifier: &str) -> bool {
-        self.manifest
-            .as_ref()
-            .as_ref()
-            .map(|pkg| pkg.optional_dependencies.contains(specifier))
-            .unwrap_or_default()
+    /// Returns the nearest `package.json` of the file, if any
+    fn package_json(&self) -> Option<&PackageJson> {
+        self.manifest.as_ref().as_ref()
+    }
+
+    pub fn is_dependency(&self, specifier: &str) -> bool {
+        self.package_json()
+            .is_some_and(|pkg| pkg.dependencies.contains(specifier))
+    }
+
+    pub fn is_dev_dependency(&self, specifier: &str) -> bool {
+        self.package_json()
+            .is_some_and(|pkg| pkg.dev_dependencies.contains(specifier))
+    }
+
+    pub fn is_peer_dependency(&self, specifier: &str) -> bool {
+        self.package_json()
+            .is_some_and(|pkg| pkg.peer_dependencies.contains(specifier))
+    }
+
+    pub fn is_optional_dependency(&self, specifier: &str) -> bool {
+        self.package_json()
+            .is_some_and(|pkg| pkg.optional_dependencies.contains(specifier))
+    }
+
+    /// Returns `true` if `specifier` is declared in any kind of dependencies of the `package.json`
+    pub fn is_declared_dependency(&self, specifier: &str) -> bool {
+        self.is_dependency(specifier)
+            || self.is_dev_dependency(specifier)
+            || self.is_peer_dependency(specifier)
+            || self.is_optional_dependency(specifier)
+    }
+
+    /// Returns the `type` field of the `package.json`.
+    ///
+    /// Returns `None` if there is no `package.json`, or if it doesn't declare a type.
+    pub fn package_type(&self) -> Option<&PackageType> {
+        self.package_json()?.r#type.as_ref()
+    }
+
+    /// Returns the version range of the runtime `name` in the `engines` field of the `package.json`
+    pub fn engine(&self, name: &str) -> Option<&str> {
+        self.package_json()?.engines.get(name)
+    }
+
+    /// Returns the version range of Node.js in the `engines` field of the `package.json`
+    pub fn node_engine(&self) -> Option<&str> {
+        self.package_json()?.engines.node()
+    }
+
+    /// Returns the queries of the `browserslist` field of the `package.json`
+    pub fn browserslist(&self) -> Option<&[String]> {
+        Some(self.package_json()?.browserslist.as_ref()?.queries())
+    }
+
+    /// Returns `true` if the `package.json` declares that none of the modules of the package
+    /// have side effects, with `"sideEffects": false`
+    pub fn is_side_effect_free(&self) -> bool {
+        self.package_json()
+            .is_some_and(|pkg| pkg.side_effects == Some(SideEffects::Bool(false)))
     }
 }
 
@@ -0,0 +1,739 @@
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsDeclarationClause, AnyJsExportClause, AnyJsExportNamedSpecifier,
+    AnyJsExpression, AnyJsModuleItem, AnyJsRoot, AnyTsType, JsCallExpression, JsExport,
+    JsExportFromClause, JsIdentifierBinding, JsLanguage, JsModule, JsSyntaxNode,
+    JsVariableDeclaration, TsEnumDeclaration, TsIdentifierBinding, TsTypeAliasDeclaration,
+};
+use biome_json_analyze::DocumentKeys;
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+/// The extensions tried, in order, when a specifier doesn't point to an indexed module.
+const RESOLVED_EXTENSIONS: &[&str] =
+    &["ts", "tsx", "d.ts", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// The names exported by a JavaScript module, and the modules it depends on.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ModuleExports {
+    /// The names exported by the module itself, including the named re-exports
+    /// such as `export { a } from "./a"` and `export * as ns from "./ns"`.
+    names: FxHashSet<String>,
+    /// The specifiers of the re-exported modules, such as `./a` in `export * from "./a"`.
+    star_re_exports: Vec<String>,
+    /// The specifiers of the modules imported or re-exported by the module,
+    /// such as `./a` in `import { a } from "./a"` and `export { b } from "./b"`.
+    #[serde(default)]
+    imports: Vec<String>,
+}
+
+/// The members of the types exported by a JavaScript module, and the strings it passes to functions.
+///
+/// They are indexed apart from the [ModuleExports], only for the rules that need them.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ModuleDetails {
+    /// The strings passed as first argument to the functions called by the module, by function,
+    /// such as `home.title` for `i18n.t` in `i18n.t("home.title")`.
+    string_arguments: FxHashMap<String, FxHashSet<String>>,
+    /// The members of the enums and of the unions of literal types exported by the module, by type,
+    /// such as `Red` and `Green` for `Color` in `export enum Color { Red, Green }`.
+    type_members: FxHashMap<String, Vec<TypeMember>>,
+}
+
+/// A value of a type that a `switch` statement can match.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum TypeMember {
+    /// A member of an enum, such as `Red` in `enum Color { Red }`
+    EnumMember(String),
+    /// A string literal type, such as `a` in `type Kind = "a" | "b"`
+    String(String),
+    /// A number literal type, such as `-1` in `type Direction = -1 | 1`
+    Number(String),
+}
+
+impl ModuleExports {
+    /// Returns `true` if the module itself exports `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    /// The specifiers of the modules re-exported with `export * from`.
+    pub fn star_re_exports(&self) -> &[String] {
+        &self.star_re_exports
+    }
+
+    /// The specifiers of the modules imported or re-exported by the module.
+    pub fn imports(&self) -> &[String] {
+        &self.imports
+    }
+}
+
+impl ModuleDetails {
+    /// The strings passed as first argument to `callee`, such as `i18n.t`, by the module.
+    pub fn string_arguments(&self, callee: &str) -> impl Iterator<Item = &str> {
+        self.string_arguments
+            .get(callee)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// The members of the type `name` exported by the module, if it's an enum or a union of literal types.
+    pub fn type_members(&self, name: &str) -> Option<&[TypeMember]> {
+        self.type_members.get(name).map(Vec::as_slice)
+    }
+}
+
+/// Returns the names exported by `root`, the modules it re-exports with `export * from`,
+/// and the modules it imports.
+pub fn collect_module_exports(root: &AnyJsRoot) -> ModuleExports {
+    let mut exports = ModuleExports::default();
+    let AnyJsRoot::JsModule(module) = root else {
+        return exports;
+    };
+    for item in module.items() {
+        if let Some(specifier) = import_specifier(&item) {
+            exports.imports.push(specifier);
+        }
+        let AnyJsModuleItem::JsExport(export) = item else {
+            continue;
+        };
+        if let Some(specifier) = star_re_export_specifier(&export) {
+            exports.star_re_exports.push(specifier);
+        } else {
+            exports.names.extend(exported_names(&export));
+        }
+    }
+    exports
+}
+
+/// Returns the members of the types exported by `root`, and the strings it passes to functions.
+pub fn collect_module_details(root: &AnyJsRoot) -> ModuleDetails {
+    let mut details = ModuleDetails::default();
+    for call in root
+        .syntax()
+        .descendants()
+        .filter_map(JsCallExpression::cast)
+    {
+        if let Some(StringCall {
+            callee, argument, ..
+        }) = string_call(&call)
+        {
+            details
+                .string_arguments
+                .entry(callee)
+                .or_default()
+                .insert(argument);
+        }
+    }
+    let AnyJsRoot::JsModule(module) = root else {
+        return details;
+    };
+    for item in module.items() {
+        let AnyJsModuleItem::JsExport(export) = item else {
+            continue;
+        };
+        let declaration = match export.export_clause() {
+            Ok(AnyJsExportClause::AnyJsDeclarationClause(declaration)) => declaration,
+            Ok(AnyJsExportClause::TsExportDeclareClause(clause)) => match clause.declaration() {
+                Ok(declaration) => declaration,
+                Err(_) => continue,
+            },
+            _ => continue,
+        };
+        if let Some((name, members)) = declared_type_members(declaration.syntax()) {
+            details.type_members.insert(name, members);
+        }
+    }
+    details
+}
+
+/// A name exported by a module.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportedName {
+    pub name: String,
+    /// Whether the name is exported as a type only,
+    /// such as an interface, a type alias, or `T` in `export { type T }`
+    pub is_type: bool,
+}
+
+/// Returns the names exported by `module`, in order, excluding the default export
+/// and the names of the modules re-exported with `export * from`.
+pub fn collect_exported_names(module: &JsModule) -> Vec<ExportedName> {
+    let mut names: Vec<ExportedName> = Vec::new();
+    for item in module.items() {
+        let AnyJsModuleItem::JsExport(export) = item else {
+            continue;
+        };
+        for name in exported_names_with_kind(&export) {
+            if name.name != "default" && names.iter().all(|other| other.name != name.name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+fn is_type_declaration(declaration: &AnyJsDeclarationClause) -> bool {
+    matches!(
+        declaration,
+        AnyJsDeclarationClause::TsInterfaceDeclaration(_)
+            | AnyJsDeclarationClause::TsTypeAliasDeclaration(_)
+    )
+}
+
+/// Returns the specifier of `export`, if `export` is an `export * from` statement.
+///
+/// `export * as ns from "./ns"` isn't a star re-export: it exports the name `ns`.
+pub fn star_re_export_specifier(export: &JsExport) -> Option<String> {
+    let AnyJsExportClause::JsExportFromClause(clause) = export.export_clause().ok()? else {
+        return None;
+    };
+    if clause.export_as().is_some() {
+        return None;
+    }
+    source_specifier(&clause)
+}
+
+fn source_specifier(clause: &JsExportFromClause) -> Option<String> {
+    let source = clause.source().ok()?;
+    let source = source.as_js_module_source()?;
+    Some(source.inner_string_text().ok()?.to_string())
+}
+
+/// Returns the specifier of the module imported by `item`,
+/// if `item` is an `import` statement or an `export ... from` statement.
+pub fn import_specifier(item: &AnyJsModuleItem) -> Option<String> {
+    let source = match item {
+        AnyJsModuleItem::JsImport(import) => {
+            return Some(import.source_text().ok()?.to_string());
+        }
+        AnyJsModuleItem::JsExport(export) => match export.export_clause().ok()? {
+            AnyJsExportClause::JsExportFromClause(clause) => clause.source().ok()?,
+            AnyJsExportClause::JsExportNamedFromClause(clause) => clause.source().ok()?,
+            _ => return None,
+        },
+        AnyJsModuleItem::AnyJsStatement(_) => return None,
+    };
+    Some(
+        source
+            .as_js_module_source()?
+            .inner_string_text()
+            .ok()?
+            .to_string(),
+    )
+}
+
+/// A call of a function whose first argument is a string, such as `i18n.t("home.title")`.
+pub(crate) struct StringCall {
+    /// The name of the function, such as `i18n.t`
+    pub(crate) callee: String,
+    /// The value of the first argument, such as `home.title`
+    pub(crate) argument: String,
+    /// The range of the first argument
+    pub(crate) range: TextRange,
+}
+
+/// Returns the name of the function called by `call` and its first argument,
+/// if the function is a name or a chain of static members, and the argument is a string.
+pub(crate) fn string_call(call: &JsCallExpression) -> Option<StringCall> {
+    let callee = callee_name(&call.callee().ok()?)?;
+    let [Some(AnyJsCallArgument::AnyJsExpression(argument))] =
+        call.arguments().ok()?.get_arguments_by_index([0])
+    else {
+        return None;
+    };
+    let value = argument.as_static_value()?;
+    Some(StringCall {
+        callee,
+        argument: value.as_string_constant()?.to_string(),
+        range: argument.range(),
+    })
+}
+
+fn callee_name(callee: &AnyJsExpression) -> Option<String> {
+    match callee.clone().omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => Some(
+            identifier
+                .name()
+                .ok()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                .to_string(),
+        ),
+        AnyJsExpression::JsThisExpression(_) => Some("this".to_string()),
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let object = callee_name(&member.object().ok()?)?;
+            let member = member.member().ok()?.value_token().ok()?;
+            Some(format!("{object}.{}", member.text_trimmed()))
+        }
+        _ => None,
+    }
+}
+
+fn exported_names(export: &JsExport) -> Vec<String> {
+    exported_names_with_kind(export)
+        .into_iter()
+        .map(|name| name.name)
+        .collect()
+}
+
+/// Returns the names exported by `export`, including `default`, and whether they are exported as types only.
+fn exported_names_with_kind(export: &JsExport) -> Vec<ExportedName> {
+    let Ok(clause) = export.export_clause() else {
+        return Vec::new();
+    };
+    let declared = |declaration: &AnyJsDeclarationClause| {
+        let is_type = is_type_declaration(declaration);
+        declared_names(declaration)
+            .into_iter()
+            .map(|name| ExportedName { name, is_type })
+            .collect::<Vec<_>>()
+    };
+    match clause {
+        AnyJsExportClause::AnyJsDeclarationClause(declaration) => declared(&declaration),
+        AnyJsExportClause::TsExportDeclareClause(clause) => clause
+            .declaration()
+            .map(|declaration| declared(&declaration))
+            .unwrap_or_default(),
+        AnyJsExportClause::JsExportDefaultDeclarationClause(_)
+        | AnyJsExportClause::JsExportDefaultExpressionClause(_) => vec![ExportedName {
+            name: "default".to_string(),
+            is_type: false,
+        }],
+        AnyJsExportClause::JsExportFromClause(clause) => clause
+            .export_as()
+            .and_then(|export_as| export_as.exported_name().ok())
+            .and_then(|name| name.inner_string_text().ok())
+            .map(|name| {
+                vec![ExportedName {
+                    name: name.to_string(),
+                    is_type: clause.type_token().is_some(),
+                }]
+            })
+            .unwrap_or_default(),
+        AnyJsExportClause::JsExportNamedClause(clause) => {
+            let is_type_clause = clause.type_token().is_some();
+            clause
+                .specifiers()
+                .iter()
+                .filter_map(|specifier| {
+                    let (name, type_token) = match specifier.ok()? {
+                        AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => (
+                            specifier
+                                .name()
+                                .ok()?
+                                .value_token()
+                                .ok()?
+                                .token_text_trimmed(),
+                            specifier.type_token(),
+                        ),
+                        AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => (
+                            specifier.exported_name().ok()?.inner_string_text().ok()?,
+                            specifier.type_token(),
+                        ),
+                    };
+                    Some(ExportedName {
+                        name: name.to_string(),
+                        is_type: is_type_clause || type_token.is_some(),
+                    })
+                })
+                .collect()
+        }
+        AnyJsExportClause::JsExportNamedFromClause(clause) => {
+            let is_type_clause = clause.type_token().is_some();
+            clause
+                .specifiers()
+                .iter()
+                .filter_map(|specifier| {
+                    let specifier = specifier.ok()?;
+                    let name = match specifier.export_as() {
+                        Some(export_as) => export_as.exported_name().ok()?,
+                        None => specifier.source_name().ok()?,
+                    };
+                    Some(ExportedName {
+                        name: name.inner_string_text().ok()?.to_string(),
+                        is_type: is_type_clause || specifier.type_token().is_some(),
+                    })
+                })
+                .collect()
+        }
+        AnyJsExportClause::TsExportAsNamespaceClause(_)
+        | AnyJsExportClause::TsExportAssignmentClause(_) => Vec::new(),
+    }
+}
+
+/// Returns the names bound by `declaration`.
+pub(crate) fn declared_names(declaration: &AnyJsDeclarationClause) -> Vec<String> {
+    if let AnyJsDeclarationClause::JsVariableDeclarationClause(clause) = declaration {
+        let Ok(declaration) = clause.declaration() else {
+            return Vec::new();
+        };
+        return declared_variable_names(&declaration);
+    }
+    // The name of the other declarations, such as classes and type aliases, is a direct child
+    declaration
+        .syntax()
+        .children()
+        .find_map(|child| {
+            let name = match JsIdentifierBinding::cast_ref(&child) {
+                Some(binding) => binding.name_token().ok()?,
+                None => TsIdentifierBinding::cast(child)?.name_token().ok()?,
+            };
+            Some(name.text_trimmed().to_string())
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Returns the names bound by the declarators of `declaration`, including the destructured names.
+pub(crate) fn declared_variable_names(declaration: &JsVariableDeclaration) -> Vec<String> {
+    declaration
+        .declarators()
+        .iter()
+        .filter_map(|declarator| declarator.ok()?.id().ok())
+        .flat_map(|id| {
+            id.syntax()
+                .descendants()
+                .filter_map(JsIdentifierBinding::cast)
+                .filter_map(|binding| Some(binding.name_token().ok()?.text_trimmed().to_string()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Returns the name and the members of `node`,
+/// if it's an enum or the alias of a union of string and number literal types, such as `type Kind = "a" | "b"`.
+pub(crate) fn declared_type_members(node: &JsSyntaxNode) -> Option<(String, Vec<TypeMember>)> {
+    if let Some(declaration) = TsEnumDeclaration::cast_ref(node) {
+        let id = declaration.id().ok()?;
+        let name = id.as_js_identifier_binding()?.name_token().ok()?;
+        let members = declaration
+            .members()
+            .iter()
+            .map(|member| {
+                let name = member.ok()?.name().ok()?.name()?;
+                Some(TypeMember::EnumMember(name.to_string()))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        return Some((name.text_trimmed().to_string(), members));
+    }
+    let alias = TsTypeAliasDeclaration::cast_ref(node)?;
+    if alias.type_parameters().is_some() {
+        return None;
+    }
+    let id = alias.binding_identifier().ok()?;
+    let name = id.as_ts_identifier_binding()?.name_token().ok()?;
+    let members = literal_type_members(&alias.ty().ok()?)?;
+    Some((name.text_trimmed().to_string(), members))
+}
+
+/// Returns the members of `ty`, if it's a string or number literal type, or a union of such types.
+pub(crate) fn literal_type_members(ty: &AnyTsType) -> Option<Vec<TypeMember>> {
+    match ty {
+        AnyTsType::TsUnionType(union) => union
+            .types()
+            .iter()
+            .map(|ty| literal_type_member(&ty.ok()?))
+            .collect(),
+        ty => Some(vec![literal_type_member(ty)?]),
+    }
+}
+
+fn literal_type_member(ty: &AnyTsType) -> Option<TypeMember> {
+    match ty {
+        AnyTsType::TsStringLiteralType(ty) => {
+            Some(TypeMember::String(ty.inner_string_text().ok()?.to_string()))
+        }
+        AnyTsType::TsNumberLiteralType(ty) => {
+            let sign = if ty.minus_token().is_some() { "-" } else { "" };
+            let value = ty.literal_token().ok()?;
+            let value = value.text_trimmed();
+            Some(TypeMember::Number(format!("{sign}{value}")))
+        }
+        AnyTsType::TsParenthesizedType(ty) => literal_type_member(&ty.ty().ok()?),
+        _ => None,
+    }
+}
+
+/// The exports and the details of the JavaScript modules of the project, and the keys of its JSON documents,
+/// indexed by path.
+///
+/// The exports and the details of each module are shared with the index they come from,
+/// so that they aren't copied when the index changes.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectModuleExports {
+    modules: Arc<FxHashMap<PathBuf, Arc<ModuleExports>>>,
+    module_details: Arc<FxHashMap<PathBuf, Arc<ModuleDetails>>>,
+    json_documents: Arc<FxHashMap<PathBuf, Arc<DocumentKeys>>>,
+}
+
+impl ProjectModuleExports {
+    pub fn new<E: Into<Arc<ModuleExports>>>(
+        modules: impl IntoIterator<Item = (PathBuf, E)>,
+    ) -> Self {
+        Self {
+            modules: Arc::new(
+                modules
+                    .into_iter()
+                    .map(|(path, exports)| (normalize_path(&path), exports.into()))
+                    .collect(),
+            ),
+            module_details: Arc::default(),
+            json_documents: Arc::default(),
+        }
+    }
+
+    /// Sets the details of the JavaScript modules of the project,
+    /// the members of the types they export and the strings they pass to functions.
+    pub fn with_module_details<D: Into<Arc<ModuleDetails>>>(
+        mut self,
+        details: impl IntoIterator<Item = (PathBuf, D)>,
+    ) -> Self {
+        self.module_details = Arc::new(
+            details
+                .into_iter()
+                .map(|(path, details)| (normalize_path(&path), details.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Sets the keys of the JSON documents of the project, such as `home.title` in `{ "home": { "title": "Home" } }`.
+    pub fn with_json_documents<K: Into<Arc<DocumentKeys>>>(
+        mut self,
+        documents: impl IntoIterator<Item = (PathBuf, K)>,
+    ) -> Self {
+        self.json_documents = Arc::new(
+            documents
+                .into_iter()
+                .map(|(path, keys)| (normalize_path(&path), keys.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Returns the indexed modules, with their exports.
+    pub fn modules(&self) -> impl Iterator<Item = (&Path, &ModuleExports)> {
+        self.modules
+            .iter()
+            .map(|(path, exports)| (path.as_path(), exports.as_ref()))
+    }
+
+    /// Returns the exports of the indexed module at `path`.
+    pub fn module(&self, path: &Path) -> Option<&ModuleExports> {
+        self.modules.get(&normalize_path(path)).map(Arc::as_ref)
+    }
+
+    /// Returns the modules whose details are indexed, with their details.
+    pub fn module_details(&self) -> impl Iterator<Item = (&Path, &ModuleDetails)> {
+        self.module_details
+            .iter()
+            .map(|(path, details)| (path.as_path(), details.as_ref()))
+    }
+
+    /// Returns the details of the indexed module at `path`.
+    pub fn details(&self, path: &Path) -> Option<&ModuleDetails> {
+        self.module_details
+            .get(&normalize_path(path))
+            .map(Arc::as_ref)
+    }
+
+    /// Returns the indexed JSON documents, with their keys.
+    pub fn json_documents(&self) -> impl Iterator<Item = (&Path, &DocumentKeys)> {
+        self.json_documents
+            .iter()
+            .map(|(path, keys)| (path.as_path(), keys.as_ref()))
+    }
+
+    /// Returns the path of the indexed JSON document that `specifier` points to,
+    /// when `specifier` is imported by the module at `importer`, such as `./en.json`.
+    pub fn resolve_json_document(&self, importer: &Path, specifier: &str) -> Option<&Path> {
+        if !(specifier.starts_with("./") || specifier.starts_with("../")) {
+            return None;
+        }
+        let path = normalize_path(&importer.parent()?.join(specifier));
+        self.json_documents
+            .get_key_value(&path)
+            .map(|(path, _)| path.as_path())
+    }
+
+    /// Returns the path of the indexed module that `specifier` points to,
+    /// when `specifier` is imported by the module at `importer`.
+    ///
+    /// Only the relative specifiers, such as `./a` or `../a.js`, are resolved.
+    /// The extension of the module can be omitted, and a directory resolves to its `index` module.
+    pub fn resolve(&self, importer: &Path, specifier: &str) -> Option<&Path> {
+        if !(specifier.starts_with("./")
+            || specifier.starts_with("../")
+            || matches!(specifier, "." | ".."))
+        {
+            return None;
+        }
+        let base = normalize_path(&importer.parent()?.join(specifier));
+        let mut candidates = vec![base.clone()];
+        // TypeScript resolves `./a.js` to `./a.ts`
+        if let Some(stem) = base.to_str().and_then(|base| {
+            base.strip_suffix(".js")
+                .or_else(|| base.strip_suffix(".jsx"))
+        }) {
+            candidates
+                .extend(["ts", "tsx", "d.ts"].map(|ext| PathBuf::from(format!("{stem}.{ext}"))));
+        }
+        for extension in RESOLVED_EXTENSIONS {
+            let mut candidate = base.clone().into_os_string();
+            candidate.push(".");
+            candidate.push(extension);
+            candidates.push(candidate.into());
+        }
+        for extension in RESOLVED_EXTENSIONS {
+            candidates.push(base.join(format!("index.{extension}")));
+        }
+        candidates
+            .into_iter()
+            .find_map(|candidate| self.modules.get_key_value(&candidate))
+            .map(|(path, _)| path.as_path())
+    }
+
+    /// Returns the indexed modules that the module at `path` imports or re-exports.
+    pub fn dependencies(&self, path: &Path) -> Vec<&Path> {
+        let Some(exports) = self.modules.get(&normalize_path(path)) else {
+            return Vec::new();
+        };
+        exports
+            .imports
+            .iter()
+            .filter_map(|specifier| self.resolve(path, specifier))
+            .collect()
+    }
+
+    /// Returns the names that the module at `path` exports to a module that re-exports it with `export * from`,
+    /// with the path of the module that exports each name.
+    ///
+    /// The default export isn't re-exported by `export * from`.
+    pub fn star_exported_names(&self, path: &Path) -> FxHashMap<String, PathBuf> {
+        let mut names = FxHashMap::default();
+        let mut visited = FxHashSet::default();
+        self.collect_star_exported_names(&normalize_path(path), &mut names, &mut visited);
+        names
+    }
+
+    fn collect_star_exported_names(
+        &self,
+        path: &Path,
+        names: &mut FxHashMap<String, PathBuf>,
+        visited: &mut FxHashSet<PathBuf>,
+    ) {
+        if !visited.insert(path.to_path_buf()) {
+            return;
+        }
+        let Some(exports) = self.modules.get(path) else {
+            return;
+        };
+        for name in &exports.names {
+            if name != "default" {
+                names.insert(name.clone(), path.to_path_buf());
+            }
+        }
+        for specifier in &exports.star_re_exports {
+            let Some(resolved) = self.resolve(path, specifier) else {
+                continue;
+            };
+            let mut re_exported = FxHashMap::default();
+            self.collect_star_exported_names(resolved, &mut re_exported, visited);
+            for (name, origin) in re_exported {
+                // The names exported by the module itself shadow the re-exported names
+                if !exports.names.contains(&name) {
+                    names.entry(name).or_insert(origin);
+                }
+            }
+        }
+    }
+}
+
+/// Removes the `.` components of `path`, and resolves its `..` components lexically.
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) {
+                    normalized.pop();
+                } else {
+                    normalized.push(component);
+                }
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[derive(Debug, Clone)]
+pub struct ProjectExportsServices {
+    project: ProjectModuleExports,
+}
+
+impl ProjectExportsServices {
+    pub fn project(&self) -> &ProjectModuleExports {
+        &self.project
+    }
+}
+
+impl FromServices for ProjectExportsServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        let project: &ProjectModuleExports = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["ProjectModuleExports"])
+        })?;
+        Ok(Self {
+            project: project.clone(),
+        })
+    }
+}
+
+impl Phase for ProjectExportsServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the exports of the modules of the project** to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct ProjectExports<N>(pub N);
+
+impl<N> Queryable for ProjectExports<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Input = JsSyntaxNode;
+    type Output = N;
+
+    type Language = JsLanguage;
+    type Services = ProjectExportsServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, _: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
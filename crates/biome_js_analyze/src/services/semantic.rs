@@ -2,7 +2,9 @@ use biome_analyze::{
     AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, QueryMatch,
     Queryable, RuleKey, ServiceBag, SyntaxVisitor, Visitor, VisitorContext, VisitorFinishContext,
 };
-use biome_js_semantic::{SemanticEventExtractor, SemanticModel, SemanticModelBuilder};
+use biome_js_semantic::{
+    globals_from_directives, SemanticEventExtractor, SemanticModel, SemanticModelBuilder,
+};
 use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode, TextRange, WalkEvent};
 use biome_rowan::AstNode;
 
@@ -93,9 +95,13 @@ pub struct SemanticModelBuilderVisitor {
 
 impl SemanticModelBuilderVisitor {
     pub(crate) fn new(root: &AnyJsRoot) -> Self {
+        let mut builder = SemanticModelBuilder::new(root.clone());
+        for global in globals_from_directives(root) {
+            builder.push_directive_global(global);
+        }
         Self {
             extractor: SemanticEventExtractor::default(),
-            builder: SemanticModelBuilder::new(root.clone()),
+            builder,
         }
     }
 }
@@ -0,0 +1,155 @@
+//! A series of AST utilities to work with the Solid library
+
+use biome_js_semantic::{Binding, SemanticModel};
+use biome_js_syntax::{
+    AnyFunctionLike, AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern,
+    AnyJsFormalParameter, AnyJsFunction, AnyJsNamedImportSpecifier, AnyJsParameter,
+    JsArrayBindingPattern, JsArrayBindingPatternElement, JsArrayBindingPatternElementList,
+    JsGetterObjectMember, JsIdentifierBinding, JsImport, JsSyntaxNode, JsVariableDeclarator,
+    JsxExpressionAttributeValue, JsxExpressionChild, JsxSpreadAttribute, JsxSpreadChild,
+};
+use biome_project::PackageJson;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// Returns `true` if the `package.json` of the project declares `solid-js` in its dependencies
+pub(crate) fn is_solid_project(manifest: Option<&PackageJson>) -> bool {
+    manifest.is_some_and(|manifest| {
+        [
+            &manifest.dependencies,
+            &manifest.dev_dependencies,
+            &manifest.peer_dependencies,
+        ]
+        .into_iter()
+        .any(|dependencies| dependencies.names().any(|name| name == "solid-js"))
+    })
+}
+
+/// Returns `true` if `function` is a component: its name starts with a capital letter
+pub(crate) fn is_component(function: &AnyJsFunction) -> bool {
+    let Some(AnyJsBinding::JsIdentifierBinding(binding)) = function.binding() else {
+        return false;
+    };
+    binding.name_token().is_ok_and(|name| {
+        name.text_trimmed()
+            .chars()
+            .next()
+            .is_some_and(char::is_uppercase)
+    })
+}
+
+/// Returns the binding of the props of `component`: its first parameter
+pub(crate) fn props_parameter(component: &AnyJsFunction) -> Option<AnyJsBindingPattern> {
+    match component.parameters().ok()? {
+        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+            Some(AnyJsBindingPattern::AnyJsBinding(binding))
+        }
+        AnyJsArrowFunctionParameters::JsParameters(parameters) => {
+            match parameters.items().first()?.ok()? {
+                AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(
+                    parameter,
+                )) => parameter.binding().ok(),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Returns the component that runs `node` when it's created.
+///
+/// Solid runs the body of a component once, outside of any tracked scope.
+/// The expressions of JSX and the nested functions run later, in the tracked scopes
+/// that Solid creates for them, so the function returns `None` for the nodes they contain.
+pub(crate) fn untracked_component(node: &JsSyntaxNode) -> Option<AnyJsFunction> {
+    for ancestor in node.ancestors().skip(1) {
+        if JsxExpressionChild::can_cast(ancestor.kind())
+            || JsxExpressionAttributeValue::can_cast(ancestor.kind())
+            || JsxSpreadAttribute::can_cast(ancestor.kind())
+            || JsxSpreadChild::can_cast(ancestor.kind())
+            || JsGetterObjectMember::can_cast(ancestor.kind())
+        {
+            return None;
+        }
+        if let Some(function) = AnyFunctionLike::cast(ancestor) {
+            return match function {
+                AnyFunctionLike::AnyJsFunction(function) if is_component(&function) => {
+                    Some(function)
+                }
+                _ => None,
+            };
+        }
+    }
+    None
+}
+
+/// Returns `true` if `binding` reads a reactive value when it's called:
+/// the getter of a signal, such as `count` in `const [count, setCount] = createSignal(0)`,
+/// or a memo, such as `double` in `const double = createMemo(() => count() * 2)`
+pub(crate) fn is_signal_getter(binding: &Binding, model: &SemanticModel) -> bool {
+    let Some(identifier) = JsIdentifierBinding::cast_ref(binding.syntax()) else {
+        return false;
+    };
+    let (declarator, factory) = match identifier.parent::<JsArrayBindingPatternElement>() {
+        Some(element) => {
+            let Some(elements) = element
+                .syntax()
+                .parent()
+                .and_then(JsArrayBindingPatternElementList::cast)
+            else {
+                return false;
+            };
+            let is_first_element = elements
+                .first()
+                .and_then(|first| first.ok())
+                .is_some_and(|first| first.syntax() == element.syntax());
+            if !is_first_element {
+                return false;
+            }
+            let declarator = elements
+                .parent::<JsArrayBindingPattern>()
+                .and_then(|pattern| pattern.parent::<JsVariableDeclarator>());
+            (declarator, "createSignal")
+        }
+        None => (identifier.parent::<JsVariableDeclarator>(), "createMemo"),
+    };
+    declarator
+        .and_then(|declarator| declarator.initializer()?.expression().ok())
+        .and_then(|expression| {
+            expression
+                .omit_parentheses()
+                .as_js_call_expression()?
+                .callee()
+                .ok()
+        })
+        .and_then(|callee| {
+            let reference = callee.omit_parentheses().as_js_reference_identifier()?;
+            model.binding(&reference)
+        })
+        .is_some_and(|binding| is_solid_import(&binding, factory))
+}
+
+/// Returns `true` if `binding` is the function `name` imported from `solid-js`
+fn is_solid_import(binding: &Binding, name: &str) -> bool {
+    let Some(identifier) = JsIdentifierBinding::cast_ref(binding.syntax()) else {
+        return false;
+    };
+    let Some(specifier) = identifier.parent::<AnyJsNamedImportSpecifier>() else {
+        return false;
+    };
+    let imported_name = match &specifier {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            specifier.name().ok().and_then(|name| name.value().ok())
+        }
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => {
+            identifier.name_token().ok()
+        }
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => None,
+    };
+    if !imported_name.is_some_and(|imported_name| imported_name.text_trimmed() == name) {
+        return false;
+    }
+    specifier
+        .syntax()
+        .ancestors()
+        .find_map(|ancestor| JsImport::cast(ancestor)?.source_text().ok())
+        .is_some_and(|source| source.text() == "solid-js")
+}
@@ -3,8 +3,16 @@ use biome_rowan::{AstNode, Direction, WalkEvent};
 use std::iter;
 
 pub mod batch;
+pub(crate) mod constant;
+pub(crate) mod function;
+pub(crate) mod glob;
+pub(crate) mod jsdoc;
+pub(crate) mod markup;
 pub mod regex;
+pub(crate) mod regex_ast;
 pub mod rename;
+pub(crate) mod sort;
+pub(crate) mod template;
 #[cfg(test)]
 pub mod tests;
 
@@ -0,0 +1,560 @@
+//! Evaluation of the expressions whose value is known statically.
+//!
+//! The rules that need the value of an expression, such as the pattern passed to `new RegExp()`
+//! or the test of a `case` clause, can use [evaluate] instead of matching the literals themselves:
+//! the evaluation also handles the concatenations, the template literals without dynamic parts,
+//! the arithmetic operations, and, with a semantic model, the `const` variables.
+
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsExpression, AnyJsLiteralExpression,
+    AnyJsTemplateElement, JsBinaryOperator, JsLogicalOperator, JsReferenceIdentifier,
+    JsUnaryOperator,
+};
+use biome_rowan::AstNode;
+
+/// The maximum count of `const` variables followed to evaluate an expression
+const MAX_RESOLVED_VARIABLES: u8 = 16;
+
+/// The value of an expression known statically
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ConstantValue {
+    String(String),
+    Number(f64),
+    Boolean(bool),
+    Null,
+    Undefined,
+}
+
+impl ConstantValue {
+    /// Returns the value converted to a string, as `String(value)` does
+    pub(crate) fn to_js_string(&self) -> String {
+        match self {
+            ConstantValue::String(value) => value.clone(),
+            ConstantValue::Number(value) => number_to_js_string(*value),
+            ConstantValue::Boolean(value) => value.to_string(),
+            ConstantValue::Null => "null".to_string(),
+            ConstantValue::Undefined => "undefined".to_string(),
+        }
+    }
+
+    /// Returns the value converted to a number, as `Number(value)` does
+    pub(crate) fn to_number(&self) -> f64 {
+        match self {
+            ConstantValue::String(value) => {
+                let value = value.trim();
+                if value.is_empty() {
+                    0.0
+                } else {
+                    string_to_number(value).unwrap_or(f64::NAN)
+                }
+            }
+            ConstantValue::Number(value) => *value,
+            ConstantValue::Boolean(value) => f64::from(u8::from(*value)),
+            ConstantValue::Null => 0.0,
+            ConstantValue::Undefined => f64::NAN,
+        }
+    }
+
+    /// Returns `true` if the value is truthy
+    pub(crate) fn is_truthy(&self) -> bool {
+        match self {
+            ConstantValue::String(value) => !value.is_empty(),
+            ConstantValue::Number(value) => *value != 0.0 && !value.is_nan(),
+            ConstantValue::Boolean(value) => *value,
+            ConstantValue::Null | ConstantValue::Undefined => false,
+        }
+    }
+
+    /// Returns the result of `typeof value`
+    fn type_of(&self) -> &'static str {
+        match self {
+            ConstantValue::String(_) => "string",
+            ConstantValue::Number(_) => "number",
+            ConstantValue::Boolean(_) => "boolean",
+            ConstantValue::Null => "object",
+            ConstantValue::Undefined => "undefined",
+        }
+    }
+}
+
+/// Returns the value of `expression`, if it's known statically.
+///
+/// With a semantic model, the references to the `const` variables are replaced with the value of their initializer,
+/// and the references to the globals `undefined`, `NaN` and `Infinity` with their value.
+/// Without a semantic model, the identifiers aren't resolved.
+pub(crate) fn evaluate(
+    expression: &AnyJsExpression,
+    model: Option<&SemanticModel>,
+) -> Option<ConstantValue> {
+    Evaluator {
+        model,
+        resolved_variables: 0,
+    }
+    .evaluate(expression)
+}
+
+struct Evaluator<'a> {
+    model: Option<&'a SemanticModel>,
+    /// The count of `const` variables followed so far, to stop on the circular references
+    resolved_variables: u8,
+}
+
+impl Evaluator<'_> {
+    fn evaluate(&mut self, expression: &AnyJsExpression) -> Option<ConstantValue> {
+        match expression {
+            AnyJsExpression::AnyJsLiteralExpression(literal) => match literal {
+                AnyJsLiteralExpression::JsStringLiteralExpression(string) => {
+                    let text = string.inner_string_text().ok()?;
+                    Some(ConstantValue::String(unescape(text.text())?))
+                }
+                AnyJsLiteralExpression::JsNumberLiteralExpression(number) => {
+                    Some(ConstantValue::Number(number.as_number()?))
+                }
+                AnyJsLiteralExpression::JsBooleanLiteralExpression(boolean) => Some(
+                    ConstantValue::Boolean(boolean.value_token().ok()?.text_trimmed() == "true"),
+                ),
+                AnyJsLiteralExpression::JsNullLiteralExpression(_) => Some(ConstantValue::Null),
+                AnyJsLiteralExpression::JsBigintLiteralExpression(_)
+                | AnyJsLiteralExpression::JsRegexLiteralExpression(_) => None,
+            },
+            AnyJsExpression::JsParenthesizedExpression(expression) => {
+                self.evaluate(&expression.expression().ok()?)
+            }
+            AnyJsExpression::JsTemplateExpression(template) => {
+                if template.tag().is_some() {
+                    return None;
+                }
+                let mut result = String::new();
+                for element in template.elements() {
+                    match element {
+                        AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                            let chunk = chunk.template_chunk_token().ok()?;
+                            result.push_str(&unescape(chunk.text_trimmed())?);
+                        }
+                        AnyJsTemplateElement::JsTemplateElement(element) => {
+                            let value = self.evaluate(&element.expression().ok()?)?;
+                            result.push_str(&value.to_js_string());
+                        }
+                    }
+                }
+                Some(ConstantValue::String(result))
+            }
+            AnyJsExpression::JsUnaryExpression(unary) => {
+                let argument = self.evaluate(&unary.argument().ok()?)?;
+                let value = match unary.operator().ok()? {
+                    JsUnaryOperator::Plus => ConstantValue::Number(argument.to_number()),
+                    JsUnaryOperator::Minus => ConstantValue::Number(-argument.to_number()),
+                    JsUnaryOperator::BitwiseNot => {
+                        ConstantValue::Number(f64::from(!to_int32(argument.to_number())))
+                    }
+                    JsUnaryOperator::LogicalNot => ConstantValue::Boolean(!argument.is_truthy()),
+                    JsUnaryOperator::Typeof => {
+                        ConstantValue::String(argument.type_of().to_string())
+                    }
+                    JsUnaryOperator::Void => ConstantValue::Undefined,
+                    JsUnaryOperator::Delete => return None,
+                };
+                Some(value)
+            }
+            AnyJsExpression::JsBinaryExpression(binary) => {
+                let left = self.evaluate(&binary.left().ok()?)?;
+                let right = self.evaluate(&binary.right().ok()?)?;
+                evaluate_binary(binary.operator().ok()?, &left, &right)
+            }
+            AnyJsExpression::JsLogicalExpression(logical) => {
+                let left = self.evaluate(&logical.left().ok()?)?;
+                let returns_left = match logical.operator().ok()? {
+                    JsLogicalOperator::NullishCoalescing => {
+                        !matches!(left, ConstantValue::Null | ConstantValue::Undefined)
+                    }
+                    JsLogicalOperator::LogicalOr => left.is_truthy(),
+                    JsLogicalOperator::LogicalAnd => !left.is_truthy(),
+                };
+                if returns_left {
+                    Some(left)
+                } else {
+                    self.evaluate(&logical.right().ok()?)
+                }
+            }
+            AnyJsExpression::JsConditionalExpression(conditional) => {
+                if self.evaluate(&conditional.test().ok()?)?.is_truthy() {
+                    self.evaluate(&conditional.consequent().ok()?)
+                } else {
+                    self.evaluate(&conditional.alternate().ok()?)
+                }
+            }
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                self.resolve(&identifier.name().ok()?)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the variable referenced by `reference`, if it's a `const` variable
+    /// initialized with a constant expression, or one of the globals `undefined`, `NaN` and `Infinity`
+    fn resolve(&mut self, reference: &JsReferenceIdentifier) -> Option<ConstantValue> {
+        let model = self.model?;
+        let Some(binding) = model.binding(reference) else {
+            let name = reference.value_token().ok()?;
+            return match name.text_trimmed() {
+                "undefined" => Some(ConstantValue::Undefined),
+                "NaN" => Some(ConstantValue::Number(f64::NAN)),
+                "Infinity" => Some(ConstantValue::Number(f64::INFINITY)),
+                _ => None,
+            };
+        };
+        if self.resolved_variables >= MAX_RESOLVED_VARIABLES {
+            return None;
+        }
+        self.resolved_variables += 1;
+        let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) =
+            binding.tree().declaration()?
+        else {
+            return None;
+        };
+        if !declarator.declaration()?.is_const()
+            || !matches!(
+                declarator.id().ok()?,
+                AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(_))
+            )
+        {
+            return None;
+        }
+        let initializer = declarator.initializer()?.expression().ok()?;
+        // The variables declared in the initializer of the declaration can't be read before it
+        if initializer
+            .syntax()
+            .text_trimmed_range()
+            .contains_range(reference.syntax().text_trimmed_range())
+        {
+            return None;
+        }
+        self.evaluate(&initializer)
+    }
+}
+
+fn evaluate_binary(
+    operator: JsBinaryOperator,
+    left: &ConstantValue,
+    right: &ConstantValue,
+) -> Option<ConstantValue> {
+    let value = match operator {
+        JsBinaryOperator::Plus => match (left, right) {
+            (ConstantValue::String(_), _) | (_, ConstantValue::String(_)) => {
+                ConstantValue::String(left.to_js_string() + &right.to_js_string())
+            }
+            _ => ConstantValue::Number(left.to_number() + right.to_number()),
+        },
+        JsBinaryOperator::Minus => ConstantValue::Number(left.to_number() - right.to_number()),
+        JsBinaryOperator::Times => ConstantValue::Number(left.to_number() * right.to_number()),
+        JsBinaryOperator::Divide => ConstantValue::Number(left.to_number() / right.to_number()),
+        JsBinaryOperator::Remainder => ConstantValue::Number(left.to_number() % right.to_number()),
+        JsBinaryOperator::Exponent => {
+            let (base, exponent) = (left.to_number(), right.to_number());
+            // `1 ** NaN` is `NaN` in JavaScript, but `1` with `powf`
+            if exponent.is_nan() {
+                ConstantValue::Number(f64::NAN)
+            } else {
+                ConstantValue::Number(base.powf(exponent))
+            }
+        }
+        JsBinaryOperator::LeftShift => ConstantValue::Number(f64::from(
+            to_int32(left.to_number()).wrapping_shl(to_uint32(right.to_number())),
+        )),
+        JsBinaryOperator::RightShift => ConstantValue::Number(f64::from(
+            to_int32(left.to_number()).wrapping_shr(to_uint32(right.to_number())),
+        )),
+        JsBinaryOperator::UnsignedRightShift => ConstantValue::Number(f64::from(
+            to_uint32(left.to_number()).wrapping_shr(to_uint32(right.to_number())),
+        )),
+        JsBinaryOperator::BitwiseAnd => ConstantValue::Number(f64::from(
+            to_int32(left.to_number()) & to_int32(right.to_number()),
+        )),
+        JsBinaryOperator::BitwiseOr => ConstantValue::Number(f64::from(
+            to_int32(left.to_number()) | to_int32(right.to_number()),
+        )),
+        JsBinaryOperator::BitwiseXor => ConstantValue::Number(f64::from(
+            to_int32(left.to_number()) ^ to_int32(right.to_number()),
+        )),
+        JsBinaryOperator::StrictEquality => ConstantValue::Boolean(left == right),
+        JsBinaryOperator::StrictInequality => ConstantValue::Boolean(left != right),
+        // The loose equality and the comparisons depend on conversions that aren't worth reproducing
+        JsBinaryOperator::Equality
+        | JsBinaryOperator::Inequality
+        | JsBinaryOperator::LessThan
+        | JsBinaryOperator::GreaterThan
+        | JsBinaryOperator::LessThanOrEqual
+        | JsBinaryOperator::GreaterThanOrEqual => return None,
+    };
+    Some(value)
+}
+
+/// Returns the value of the escape sequences of the text of a string literal or of a template chunk,
+/// or `None` if it contains a legacy octal escape sequence
+pub(crate) fn unescape(text: &str) -> Option<String> {
+    if !text.contains('\\') {
+        return Some(text.to_string());
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            'r' => result.push('\r'),
+            'b' => result.push('\u{8}'),
+            'f' => result.push('\u{c}'),
+            'v' => result.push('\u{b}'),
+            '0' if !chars.peek().is_some_and(char::is_ascii_digit) => result.push('\0'),
+            '0'..='9' => return None,
+            'x' => {
+                let digits: String = chars.by_ref().take(2).collect();
+                result.push(char::from_u32(u32::from_str_radix(&digits, 16).ok()?)?);
+            }
+            'u' => {
+                let code = if chars.peek() == Some(&'{') {
+                    chars.next();
+                    let digits: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                    u32::from_str_radix(&digits, 16).ok()?
+                } else {
+                    let digits: String = chars.by_ref().take(4).collect();
+                    u32::from_str_radix(&digits, 16).ok()?
+                };
+                // The lone surrogates can't be represented in a Rust string
+                result.push(char::from_u32(code)?);
+            }
+            // Line continuations
+            '\r' => {
+                chars.next_if_eq(&'\n');
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            c => result.push(c),
+        }
+    }
+    Some(result)
+}
+
+/// Returns the number represented by `text`, as `Number(text)` does,
+/// or `None` if `text` isn't a valid number
+fn string_to_number(text: &str) -> Option<f64> {
+    match text {
+        "Infinity" | "+Infinity" => return Some(f64::INFINITY),
+        "-Infinity" => return Some(f64::NEG_INFINITY),
+        _ => {}
+    }
+    let mut chars = text.chars();
+    if let (Some('0'), Some(prefix @ ('x' | 'X' | 'o' | 'O' | 'b' | 'B'))) =
+        (chars.next(), chars.next())
+    {
+        let radix = match prefix {
+            'x' | 'X' => 16,
+            'o' | 'O' => 8,
+            _ => 2,
+        };
+        return u64::from_str_radix(chars.as_str(), radix)
+            .ok()
+            .map(|value| value as f64);
+    }
+    // Rust accepts `inf`, `nan` and the separators that JavaScript rejects
+    if !text
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+    {
+        return None;
+    }
+    text.parse().ok()
+}
+
+/// Returns the string representation of `value`, as `String(value)` does
+pub(crate) fn number_to_js_string(value: f64) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    if value.is_infinite() {
+        return if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    }
+    let sign = if value < 0.0 { "-" } else { "" };
+    // The shortest representation of the number, such as `1.2345e3`
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let digits = mantissa.replace('.', "");
+    let exponent: i32 = exponent.parse().unwrap_or_default();
+    // The position of the decimal point relative to the digits
+    let point = exponent + 1;
+    let digit_count = digits.len() as i32;
+    let text = if digit_count <= point && point <= 21 {
+        format!("{digits}{}", "0".repeat((point - digit_count) as usize))
+    } else if 0 < point && point <= 21 {
+        let (integer, fraction) = digits.split_at(point as usize);
+        format!("{integer}.{fraction}")
+    } else if -6 < point && point <= 0 {
+        format!("0.{}{digits}", "0".repeat((-point) as usize))
+    } else {
+        let (first, rest) = digits.split_at(1);
+        let rest = if rest.is_empty() {
+            String::new()
+        } else {
+            format!(".{rest}")
+        };
+        let exponent_sign = if exponent < 0 { "-" } else { "+" };
+        format!("{first}{rest}e{exponent_sign}{}", exponent.abs())
+    };
+    format!("{sign}{text}")
+}
+
+/// Returns `value` converted to a signed 32-bit integer, as the bitwise operators do
+fn to_int32(value: f64) -> i32 {
+    to_uint32(value) as i32
+}
+
+/// Returns `value` converted to an unsigned 32-bit integer, as the `>>>` operator does
+fn to_uint32(value: f64) -> u32 {
+    if !value.is_finite() {
+        return 0;
+    }
+    value.trunc().rem_euclid(4_294_967_296.0) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_js_parser::JsParserOptions;
+    use biome_js_semantic::{semantic_model, SemanticModelOptions};
+    use biome_js_syntax::{JsFileSource, JsVariableDeclarator};
+
+    /// Returns the value of the initializer of the last variable declared in `source`
+    fn evaluate_source(source: &str) -> Option<ConstantValue> {
+        let r = biome_js_parser::parse(
+            source,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+        let declarator = r
+            .syntax()
+            .descendants()
+            .filter_map(JsVariableDeclarator::cast)
+            .last()?;
+        evaluate(&declarator.initializer()?.expression().ok()?, Some(&model))
+    }
+
+    fn string(value: &str) -> Option<ConstantValue> {
+        Some(ConstantValue::String(value.to_string()))
+    }
+
+    #[test]
+    fn evaluates_literals() {
+        assert_eq!(evaluate_source(r#"const a = "a\tb";"#), string("a\tb"));
+        assert_eq!(
+            evaluate_source(r#"const a = '\x41B\u{43}';"#),
+            string("ABC")
+        );
+        assert_eq!(
+            evaluate_source("const a = 0x10;"),
+            Some(ConstantValue::Number(16.0))
+        );
+        assert_eq!(
+            evaluate_source("const a = true;"),
+            Some(ConstantValue::Boolean(true))
+        );
+        assert_eq!(
+            evaluate_source("const a = null;"),
+            Some(ConstantValue::Null)
+        );
+        assert_eq!(evaluate_source("const a = 10n;"), None);
+        assert_eq!(evaluate_source(r#"const a = "\07";"#), None);
+    }
+
+    #[test]
+    fn evaluates_concatenations() {
+        assert_eq!(evaluate_source(r#"const a = "a" + "b";"#), string("ab"));
+        assert_eq!(evaluate_source(r#"const a = "a" + 1 + 2;"#), string("a12"));
+        assert_eq!(evaluate_source(r#"const a = 1 + 2 + "a";"#), string("3a"));
+        assert_eq!(evaluate_source(r#"const a = "a" + null;"#), string("anull"));
+        assert_eq!(evaluate_source("const a = `a${1 + 1}b`;"), string("a2b"));
+        assert_eq!(evaluate_source("const a = `a${b}`;"), None);
+        assert_eq!(evaluate_source("const a = tag`a`;"), None);
+    }
+
+    #[test]
+    fn evaluates_numeric_operations() {
+        assert_eq!(
+            evaluate_source("const a = (1 + 2) * 3 - 4 / 2;"),
+            Some(ConstantValue::Number(7.0))
+        );
+        assert_eq!(
+            evaluate_source("const a = 2 ** 10 % 1000;"),
+            Some(ConstantValue::Number(24.0))
+        );
+        assert_eq!(
+            evaluate_source("const a = -1 >>> 28;"),
+            Some(ConstantValue::Number(15.0))
+        );
+        assert_eq!(
+            evaluate_source("const a = 1 << 31;"),
+            Some(ConstantValue::Number(-2147483648.0))
+        );
+        assert_eq!(
+            evaluate_source("const a = ~5 | 0xF0 & 3;"),
+            Some(ConstantValue::Number(-6.0))
+        );
+        assert_eq!(
+            evaluate_source(r#"const a = "3" * "4";"#),
+            Some(ConstantValue::Number(12.0))
+        );
+        assert_eq!(evaluate_source("const a = typeof 1;"), string("number"));
+        assert_eq!(evaluate_source(r#"const a = "" || 0 || "b";"#), string("b"));
+        assert_eq!(
+            evaluate_source("const a = null ?? 1 ? `x` : `y`;"),
+            string("x")
+        );
+    }
+
+    #[test]
+    fn resolves_const_variables() {
+        assert_eq!(
+            evaluate_source("const prefix = 'data-'; const a = prefix + 'id';"),
+            string("data-id")
+        );
+        assert_eq!(
+            evaluate_source("let prefix = 'data-'; const a = prefix + 'id';"),
+            None
+        );
+        assert_eq!(
+            evaluate_source("const { prefix } = options; const a = prefix;"),
+            None
+        );
+        assert_eq!(evaluate_source("const a = a + 1;"), None);
+        assert_eq!(
+            evaluate_source("const a = undefined;"),
+            Some(ConstantValue::Undefined)
+        );
+        assert_eq!(
+            evaluate_source("const undefined = 1; const a = undefined;"),
+            Some(ConstantValue::Number(1.0))
+        );
+    }
+
+    #[test]
+    fn converts_numbers_to_strings() {
+        assert_eq!(number_to_js_string(1.0), "1");
+        assert_eq!(number_to_js_string(-1.5), "-1.5");
+        assert_eq!(number_to_js_string(0.1), "0.1");
+        assert_eq!(number_to_js_string(1e21), "1e+21");
+        assert_eq!(number_to_js_string(123e18), "123000000000000000000");
+        assert_eq!(number_to_js_string(1e-7), "1e-7");
+        assert_eq!(number_to_js_string(0.000001), "0.000001");
+        assert_eq!(number_to_js_string(1.5e-10), "1.5e-10");
+        assert_eq!(number_to_js_string(f64::NAN), "NaN");
+        assert_eq!(number_to_js_string(-0.0), "0");
+    }
+}
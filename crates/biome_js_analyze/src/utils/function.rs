@@ -0,0 +1,60 @@
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{JsReferenceIdentifier, JsSyntaxKind, JsSyntaxNode};
+use biome_rowan::{AstNode, WalkEvent};
+
+/// Returns `true` if `node` uses the context bound by a function:
+/// `this`, `super`, `new.target`, or the implicit `arguments` object.
+///
+/// The nested functions and class members bind their own context, so they aren't traversed.
+/// The nested arrow functions are traversed, because they inherit the context of their parent.
+pub(crate) fn uses_function_context(node: &JsSyntaxNode, model: &SemanticModel) -> bool {
+    let mut preorder = node.preorder();
+    while let Some(event) = preorder.next() {
+        let WalkEvent::Enter(descendant) = event else {
+            continue;
+        };
+        if &descendant != node && binds_function_context(descendant.kind()) {
+            preorder.skip_subtree();
+            continue;
+        }
+        match descendant.kind() {
+            JsSyntaxKind::JS_THIS_EXPRESSION
+            | JsSyntaxKind::JS_SUPER_EXPRESSION
+            | JsSyntaxKind::JS_NEW_TARGET_EXPRESSION => return true,
+            JsSyntaxKind::JS_REFERENCE_IDENTIFIER => {
+                let Some(reference) = JsReferenceIdentifier::cast(descendant) else {
+                    continue;
+                };
+                // A reference to `arguments` without declaration is the implicit `arguments` object
+                if reference
+                    .value_token()
+                    .is_ok_and(|name| name.text_trimmed() == "arguments")
+                    && model.binding(&reference).is_none()
+                {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Returns `true` if the nodes of this kind bind their own `this`, `super`, `new.target`, and `arguments`.
+fn binds_function_context(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+            | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+            | JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER
+            | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+            | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+            | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+    )
+}
@@ -0,0 +1,45 @@
+use std::path::{Component, Path};
+
+/// Returns `true` if `glob` matches the last components of `path`.
+///
+/// `*` matches any sequence of characters in a component, `?` matches a character,
+/// and `**` matches any number of components.
+pub(crate) fn matches_path_suffix(glob: &str, path: &Path) -> bool {
+    let glob = glob.trim_start_matches("./");
+    let patterns = glob.split('/').collect::<Vec<_>>();
+    let components = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    (0..components.len()).any(|start| matches_components(&patterns, &components[start..]))
+}
+
+fn matches_components(patterns: &[&str], components: &[&str]) -> bool {
+    match patterns.split_first() {
+        None => components.is_empty(),
+        Some((&"**", rest)) => {
+            (0..=components.len()).any(|skipped| matches_components(rest, &components[skipped..]))
+        }
+        Some((pattern, rest)) => match components.split_first() {
+            Some((component, components)) => {
+                matches_component(pattern.as_bytes(), component.as_bytes())
+                    && matches_components(rest, components)
+            }
+            None => false,
+        },
+    }
+}
+
+fn matches_component(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => {
+            (0..=name.len()).any(|skipped| matches_component(rest, &name[skipped..]))
+        }
+        Some((b'?', rest)) => !name.is_empty() && matches_component(rest, &name[1..]),
+        Some((byte, rest)) => name.first() == Some(byte) && matches_component(rest, &name[1..]),
+    }
+}
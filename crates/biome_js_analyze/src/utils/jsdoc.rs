@@ -0,0 +1,372 @@
+//! Helpers shared by the rules and the assists that read or write JSDoc comments.
+
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsFunctionBody, JsArrowFunctionExpression, JsExport,
+    JsExportDefaultDeclarationClause, JsExportDefaultExpressionClause, JsFunctionDeclaration,
+    JsFunctionExportDefaultDeclaration, JsFunctionExpression, JsInitializerClause, JsLanguage,
+    JsParameters, JsReturnStatement, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    JsVariableDeclaration, JsVariableDeclarationClause, JsVariableDeclarator,
+    JsVariableDeclaratorList, JsVariableStatement, TsReturnTypeAnnotation, TsTypeParameters,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstSeparatedList, SyntaxNodeCast, SyntaxTriviaPiece, TextRange,
+    TextSize, WalkEvent,
+};
+
+/// A parsed JSDoc comment.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub(crate) struct JsDoc {
+    pub(crate) tags: Vec<JsDocTag>,
+}
+
+/// A block tag of a JSDoc comment, such as `@param {string} name The name.`
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct JsDocTag {
+    /// The name of the tag, without the `@`
+    pub(crate) name: String,
+    /// The range of the name and the `@`, relative to the comment
+    pub(crate) name_range: TextRange,
+    /// The range of the tag and its description, relative to the comment.
+    ///
+    /// When the tag starts a line, the range covers its whole lines,
+    /// so that removing the tag doesn't leave an empty line.
+    pub(crate) range: TextRange,
+    /// The type of the tag, without its braces
+    pub(crate) type_expression: Option<String>,
+    /// The parameter documented by a `@param` tag
+    pub(crate) parameter: Option<JsDocParameter>,
+}
+
+/// The name of the parameter documented by a `@param` tag.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct JsDocParameter {
+    pub(crate) name: String,
+    /// The range of the name, relative to the comment.
+    ///
+    /// The brackets and the default value of an optional parameter, as in `[name="value"]`,
+    /// aren't part of the range.
+    pub(crate) range: TextRange,
+}
+
+impl JsDoc {
+    /// Parses the text of a `/** */` comment, or returns `None` if the comment isn't a JSDoc comment.
+    pub(crate) fn parse(comment: &str) -> Option<Self> {
+        let content = comment.strip_prefix("/**")?.strip_suffix("*/")?;
+        let content_start = comment.len() - content.len() - 2;
+        let content_end = content_start + content.len();
+        // The lines of the content, with their offsets in the comment
+        let mut lines = Vec::new();
+        let mut line_start = content_start;
+        for line in content.split('\n') {
+            lines.push((line_start, line));
+            line_start += line.len() + 1;
+        }
+        // The closing `*/` is usually on its own line, after the tags
+        let closing_line_start = match lines.as_slice() {
+            [_, .., (start, line)] if line.trim().is_empty() => *start,
+            _ => content_end,
+        };
+
+        // The lines that start a tag, and the offset of their `@`
+        let mut tag_starts = Vec::new();
+        for (index, (line_start, line)) in lines.iter().enumerate() {
+            let mut text = line.trim_start();
+            // The lines after the first one usually start with a `*`
+            if index > 0 {
+                text = text.strip_prefix('*').unwrap_or(text).trim_start();
+            }
+            if text
+                .strip_prefix('@')
+                .is_some_and(|name| name.starts_with(|c: char| c.is_ascii_alphabetic()))
+            {
+                tag_starts.push((index, line_start + line.len() - text.len()));
+            }
+        }
+
+        let tags = tag_starts
+            .iter()
+            .enumerate()
+            .map(|(position, &(index, at))| {
+                let (line_start, line) = lines[index];
+                let start = if index == 0 { at } else { line_start };
+                let end = match tag_starts.get(position + 1) {
+                    Some(&(next_index, _)) => lines[next_index].0,
+                    None => closing_line_start,
+                };
+                let line_end = (line_start + line.len()).min(end);
+                JsDocTag::parse(&comment[at..line_end], at, range(start, end))
+            })
+            .collect();
+        Some(Self { tags })
+    }
+}
+
+impl JsDocTag {
+    /// Parses the first line of a tag, which starts at `offset` in the comment.
+    fn parse(line: &str, offset: usize, tag_range: TextRange) -> Self {
+        let line = line.trim_end();
+        let name_end = line[1..]
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .map_or(line.len(), |end| end + 1);
+        let name = line[1..name_end].to_string();
+        let mut position = skip_whitespace(line, name_end);
+
+        let mut type_expression = None;
+        if line[position..].starts_with('{') {
+            let mut depth = 0;
+            let type_end = line[position..].find(|c| {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            });
+            if let Some(type_end) = type_end {
+                let type_end = position + type_end;
+                type_expression = Some(line[position + 1..type_end].trim().to_string());
+                position = skip_whitespace(line, type_end + 1);
+            }
+        }
+
+        let parameter = if matches!(name.as_str(), "param" | "arg" | "argument") {
+            let (name_start, name_end) = match line[position..].strip_prefix('[') {
+                // `[name]` and `[name=value]` are optional parameters
+                Some(optional) => {
+                    let start = skip_whitespace(line, position + 1);
+                    let end = optional
+                        .find([']', '='])
+                        .map_or(line.len(), |end| position + 1 + end);
+                    (start, start + line[start..end].trim_end().len())
+                }
+                None => (
+                    position,
+                    line[position..]
+                        .find(char::is_whitespace)
+                        .map_or(line.len(), |end| position + end),
+                ),
+            };
+            (name_start < name_end).then(|| JsDocParameter {
+                name: line[name_start..name_end].to_string(),
+                range: range(offset + name_start, offset + name_end),
+            })
+        } else {
+            None
+        };
+
+        Self {
+            name,
+            name_range: range(offset, offset + name_end),
+            range: tag_range,
+            type_expression,
+            parameter,
+        }
+    }
+
+    /// Returns `true` if the tag documents the return value: `@returns` or its alias `@return`.
+    pub(crate) fn is_returns(&self) -> bool {
+        matches!(self.name.as_str(), "returns" | "return")
+    }
+}
+
+fn skip_whitespace(text: &str, position: usize) -> usize {
+    position + text[position..].len() - text[position..].trim_start().len()
+}
+
+fn range(start: usize, end: usize) -> TextRange {
+    TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32))
+}
+
+/// Returns the JSDoc comment of a declaration that starts with `token`:
+/// the last `/** */` comment before the token.
+pub(crate) fn jsdoc_comment(token: &JsSyntaxToken) -> Option<SyntaxTriviaPiece<JsLanguage>> {
+    token
+        .leading_trivia()
+        .pieces()
+        .filter(|piece| {
+            piece.is_comments() && piece.text().starts_with("/**") && piece.text() != "/**/"
+        })
+        .last()
+}
+
+declare_node_union! {
+    pub AnyJsDocumentableFunction =
+        JsFunctionDeclaration
+        | JsFunctionExportDefaultDeclaration
+        | JsFunctionExpression
+        | JsArrowFunctionExpression
+}
+
+impl AnyJsDocumentableFunction {
+    /// Returns the statement that the comment documents, or `None` if the function isn't
+    /// declared by a statement.
+    pub(crate) fn documented_node(&self) -> Option<JsSyntaxNode> {
+        let statement = match self {
+            Self::JsFunctionDeclaration(declaration) => declaration.syntax().clone(),
+            Self::JsFunctionExportDefaultDeclaration(declaration) => {
+                return Some(
+                    declaration
+                        .parent::<JsExportDefaultDeclarationClause>()?
+                        .parent::<JsExport>()?
+                        .into_syntax(),
+                );
+            }
+            Self::JsFunctionExpression(_) | Self::JsArrowFunctionExpression(_) => {
+                if let Some(clause) = self.parent::<JsExportDefaultExpressionClause>() {
+                    return Some(clause.parent::<JsExport>()?.into_syntax());
+                }
+                let declarators = self
+                    .parent::<JsInitializerClause>()?
+                    .parent::<JsVariableDeclarator>()?
+                    .parent::<JsVariableDeclaratorList>()?;
+                if declarators.len() != 1 {
+                    return None;
+                }
+                let declaration = declarators.parent::<JsVariableDeclaration>()?;
+                if let Some(statement) = declaration.parent::<JsVariableStatement>() {
+                    return Some(statement.into_syntax());
+                }
+                declaration
+                    .parent::<JsVariableDeclarationClause>()?
+                    .into_syntax()
+            }
+        };
+        // `export function f() {}` is documented before `export`
+        match statement.parent() {
+            Some(parent) if JsExport::can_cast(parent.kind()) => Some(parent),
+            _ => Some(statement),
+        }
+    }
+
+    pub(crate) fn is_async(&self) -> bool {
+        match self {
+            Self::JsFunctionDeclaration(function) => function.async_token().is_some(),
+            Self::JsFunctionExportDefaultDeclaration(function) => function.async_token().is_some(),
+            Self::JsFunctionExpression(function) => function.async_token().is_some(),
+            Self::JsArrowFunctionExpression(function) => function.async_token().is_some(),
+        }
+    }
+
+    pub(crate) fn is_generator(&self) -> bool {
+        match self {
+            Self::JsFunctionDeclaration(function) => function.star_token().is_some(),
+            Self::JsFunctionExportDefaultDeclaration(function) => function.star_token().is_some(),
+            Self::JsFunctionExpression(function) => function.star_token().is_some(),
+            Self::JsArrowFunctionExpression(_) => false,
+        }
+    }
+
+    pub(crate) fn type_parameters(&self) -> Option<TsTypeParameters> {
+        match self {
+            Self::JsFunctionDeclaration(function) => function.type_parameters(),
+            Self::JsFunctionExportDefaultDeclaration(function) => function.type_parameters(),
+            Self::JsFunctionExpression(function) => function.type_parameters(),
+            Self::JsArrowFunctionExpression(function) => function.type_parameters(),
+        }
+    }
+
+    pub(crate) fn parameters(&self) -> Option<AnyJsArrowFunctionParameters> {
+        let parameters: JsParameters = match self {
+            Self::JsFunctionDeclaration(function) => function.parameters().ok()?,
+            Self::JsFunctionExportDefaultDeclaration(function) => function.parameters().ok()?,
+            Self::JsFunctionExpression(function) => function.parameters().ok()?,
+            Self::JsArrowFunctionExpression(function) => return function.parameters().ok(),
+        };
+        Some(parameters.into())
+    }
+
+    pub(crate) fn return_type_annotation(&self) -> Option<TsReturnTypeAnnotation> {
+        match self {
+            Self::JsFunctionDeclaration(function) => function.return_type_annotation(),
+            Self::JsFunctionExportDefaultDeclaration(function) => function.return_type_annotation(),
+            Self::JsFunctionExpression(function) => function.return_type_annotation(),
+            Self::JsArrowFunctionExpression(function) => function.return_type_annotation(),
+        }
+    }
+
+    pub(crate) fn body(&self) -> Option<AnyJsFunctionBody> {
+        match self {
+            Self::JsFunctionDeclaration(function) => Some(function.body().ok()?.into()),
+            Self::JsFunctionExportDefaultDeclaration(function) => {
+                Some(function.body().ok()?.into())
+            }
+            Self::JsFunctionExpression(function) => Some(function.body().ok()?.into()),
+            Self::JsArrowFunctionExpression(function) => function.body().ok(),
+        }
+    }
+}
+
+/// Returns `true` if the body of a function contains a `return` statement with a value.
+///
+/// The nested functions and classes aren't traversed.
+pub(crate) fn returns_value(body: &JsSyntaxNode) -> bool {
+    let mut preorder = body.preorder();
+    while let Some(event) = preorder.next() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        if AnyJsDocumentableFunction::can_cast(node.kind())
+            || matches!(
+                node.kind(),
+                JsSyntaxKind::JS_CLASS_DECLARATION
+                    | JsSyntaxKind::JS_CLASS_EXPRESSION
+                    | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                    | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                    | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+            )
+        {
+            preorder.skip_subtree();
+            continue;
+        }
+        if let Some(statement) = node.cast::<JsReturnStatement>() {
+            if statement.argument().is_some() {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tags() {
+        let comment = "/**\n * Greets.\n * @param {string} [name=\"you\"] The name,\n *   of the person.\n * @returns {string}\n */";
+        let jsdoc = JsDoc::parse(comment).unwrap();
+        assert_eq!(jsdoc.tags.len(), 2);
+
+        let param = &jsdoc.tags[0];
+        assert_eq!(param.name, "param");
+        assert_eq!(param.type_expression.as_deref(), Some("string"));
+        let parameter = param.parameter.as_ref().unwrap();
+        assert_eq!(parameter.name, "name");
+        assert_eq!(&comment[parameter.range], "name");
+        assert_eq!(
+            &comment[param.range],
+            " * @param {string} [name=\"you\"] The name,\n *   of the person.\n"
+        );
+
+        let returns = &jsdoc.tags[1];
+        assert!(returns.is_returns());
+        assert_eq!(&comment[returns.name_range], "@returns");
+        assert_eq!(&comment[returns.range], " * @returns {string}\n");
+    }
+
+    #[test]
+    fn parse_single_line() {
+        let comment = "/** @param {{ a: string }} options */";
+        let jsdoc = JsDoc::parse(comment).unwrap();
+        let tag = &jsdoc.tags[0];
+        assert_eq!(tag.type_expression.as_deref(), Some("{ a: string }"));
+        assert_eq!(tag.parameter.as_ref().unwrap().name, "options");
+        assert_eq!(&comment[tag.range], "@param {{ a: string }} options ");
+    }
+
+    #[test]
+    fn parse_without_jsdoc() {
+        assert_eq!(JsDoc::parse("/* @param name */"), None);
+        assert_eq!(JsDoc::parse("/** Description. */"), Some(JsDoc::default()));
+    }
+}
@@ -0,0 +1,103 @@
+//! Implementation of the markup abstraction of [biome_aria] for the JSX elements.
+
+use biome_aria::markup::{MarkupAttribute, MarkupAttributeValue, MarkupElement};
+use biome_js_syntax::jsx_ext::AnyJsxElement;
+use biome_js_syntax::static_value::StaticValue;
+use biome_js_syntax::{JsxAttribute, JsxElement, JsxText};
+use biome_rowan::AstNode;
+
+/// A JSX element, such as `<img alt="Biome" />` or the opening element of `<label>Name</label>`
+#[derive(Clone, Debug)]
+pub(crate) struct JsxMarkupElement(pub(crate) AnyJsxElement);
+
+/// An attribute of a [JsxMarkupElement], such as `alt="Biome"`
+#[derive(Clone, Debug)]
+pub(crate) struct JsxMarkupAttribute(pub(crate) JsxAttribute);
+
+impl JsxMarkupElement {
+    /// The element that contains the children of the opening element
+    fn parent_element(&self) -> Option<JsxElement> {
+        match &self.0 {
+            AnyJsxElement::JsxOpeningElement(element) => element.parent::<JsxElement>(),
+            AnyJsxElement::JsxSelfClosingElement(_) => None,
+        }
+    }
+}
+
+impl MarkupAttribute for JsxMarkupAttribute {
+    fn name(&self) -> Option<String> {
+        Some(self.0.name().ok()?.text())
+    }
+
+    fn value(&self) -> MarkupAttributeValue {
+        if self.0.initializer().is_none() {
+            return MarkupAttributeValue::Missing;
+        }
+        match self.0.as_static_value() {
+            None => MarkupAttributeValue::Dynamic,
+            Some(StaticValue::Null(_) | StaticValue::Undefined(_)) => MarkupAttributeValue::Nullish,
+            Some(StaticValue::Boolean(token)) => {
+                MarkupAttributeValue::Boolean(token.text_trimmed() == "true")
+            }
+            Some(value) => MarkupAttributeValue::Text(value.text().to_string()),
+        }
+    }
+}
+
+impl MarkupElement for JsxMarkupElement {
+    type Attribute = JsxMarkupAttribute;
+
+    fn name(&self) -> Option<String> {
+        Some(self.0.name().ok()?.text())
+    }
+
+    fn attributes(&self) -> Vec<Self::Attribute> {
+        self.0
+            .attributes()
+            .into_iter()
+            .filter_map(|attribute| attribute.as_jsx_attribute().cloned())
+            .map(JsxMarkupAttribute)
+            .collect()
+    }
+
+    fn find_attribute_by_name(&self, name: &str) -> Option<Self::Attribute> {
+        self.0.find_attribute_by_name(name).map(JsxMarkupAttribute)
+    }
+
+    fn is_custom_component(&self) -> bool {
+        self.0.is_custom_component()
+    }
+
+    fn has_trailing_spread_prop(&self, attribute: &Self::Attribute) -> bool {
+        self.0.has_trailing_spread_prop(&attribute.0)
+    }
+
+    fn has_accessible_child(&self) -> bool {
+        match &self.0 {
+            AnyJsxElement::JsxOpeningElement(element) => element.has_accessible_child(),
+            AnyJsxElement::JsxSelfClosingElement(_) => false,
+        }
+    }
+
+    fn has_text_content(&self) -> bool {
+        self.parent_element().is_some_and(|element| {
+            element
+                .syntax()
+                .descendants()
+                .any(|descendant| JsxText::can_cast(descendant.kind()))
+        })
+    }
+
+    fn descendants(&self) -> Vec<Self> {
+        let Some(element) = self.parent_element() else {
+            return Vec::new();
+        };
+        element
+            .syntax()
+            .descendants()
+            .filter(|descendant| descendant != self.0.syntax())
+            .filter_map(AnyJsxElement::cast)
+            .map(JsxMarkupElement)
+            .collect()
+    }
+}
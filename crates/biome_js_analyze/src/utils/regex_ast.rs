@@ -0,0 +1,813 @@
+//! A syntax tree of the patterns of the regular expression literals.
+//!
+//! The simplest rules scan the bytes of a pattern.
+//! The rules that need its structure, such as the alternatives, the groups, or the quantifiers,
+//! parse the pattern with [parse_regex] and analyze the returned tree.
+
+use biome_rowan::{TextRange, TextSize};
+
+/// The flags that change how a pattern is parsed or matched.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct RegexFlags {
+    /// The flag `i`
+    pub(crate) ignore_case: bool,
+    /// The flag `s`
+    pub(crate) dot_all: bool,
+    /// The flag `u` or the flag `v`
+    pub(crate) unicode: bool,
+    /// The flag `v`
+    pub(crate) unicode_sets: bool,
+}
+
+impl RegexFlags {
+    pub(crate) fn from_text(flags: &str) -> Self {
+        Self {
+            ignore_case: flags.contains('i'),
+            dot_all: flags.contains('s'),
+            unicode: flags.contains('u') || flags.contains('v'),
+            unicode_sets: flags.contains('v'),
+        }
+    }
+}
+
+/// Alternatives separated by `|`.
+#[derive(Debug)]
+pub(crate) struct Disjunction {
+    pub(crate) alternatives: Vec<Alternative>,
+}
+
+/// A sequence of terms.
+#[derive(Debug)]
+pub(crate) struct Alternative {
+    pub(crate) range: TextRange,
+    pub(crate) terms: Vec<Term>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Term {
+    pub(crate) range: TextRange,
+    pub(crate) kind: TermKind,
+}
+
+#[derive(Debug)]
+pub(crate) enum TermKind {
+    /// A literal or an escaped character, such as `a` or `\n`
+    Character(u32),
+    /// `.`
+    Any,
+    /// `\d`, `\w`, `\s`, their negations, and the Unicode properties `\p{...}`
+    Escape(ClassEscape),
+    /// `[...]`
+    Class(CharacterClass),
+    /// `\1` or `\k<name>`
+    Backreference,
+    /// `^`, `$`, `\b`, or `\B`
+    Assertion,
+    /// `(...)`, `(?:...)`, `(?i:...)`, or a lookaround
+    Group(Group),
+    /// A term followed by a quantifier
+    Quantified(Box<Term>, Quantifier),
+}
+
+#[derive(Debug)]
+pub(crate) struct Group {
+    pub(crate) kind: GroupKind,
+    pub(crate) disjunction: Disjunction,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum GroupKind {
+    /// `(...)` or `(?<name>...)`
+    Capturing,
+    /// `(?:...)`
+    NonCapturing,
+    /// `(?i:...)`, `(?-i:...)`, ...
+    Modifiers,
+    /// `(?=...)`, `(?!...)`, `(?<=...)`, or `(?<!...)`
+    Lookaround,
+}
+
+/// `*`, `+`, `?`, `{n}`, `{n,}`, or `{n,m}`, optionally followed by `?`.
+#[derive(Debug)]
+pub(crate) struct Quantifier {
+    pub(crate) range: TextRange,
+    pub(crate) min: u32,
+    /// `None` if the quantifier is unbounded
+    pub(crate) max: Option<u32>,
+    pub(crate) greedy: bool,
+}
+
+#[derive(Debug)]
+pub(crate) struct CharacterClass {
+    pub(crate) negated: bool,
+    pub(crate) items: Vec<ClassItem>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ClassItem {
+    pub(crate) range: TextRange,
+    pub(crate) kind: ClassItemKind,
+}
+
+#[derive(Debug)]
+pub(crate) enum ClassItemKind {
+    Character(u32),
+    Range(u32, u32),
+    Escape(ClassEscape),
+    /// A nested class, a string disjunction `\q{...}`, or a set operator of the `v` flag
+    Other,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct ClassEscape {
+    pub(crate) kind: ClassEscapeKind,
+    pub(crate) negated: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ClassEscapeKind {
+    /// `\d`
+    Digit,
+    /// `\w`
+    Word,
+    /// `\s`
+    Space,
+    /// `\p{...}`
+    Property,
+}
+
+/// Parses `pattern`, the text between the slashes of a regular expression literal.
+///
+/// Returns `None` if the pattern is invalid.
+/// The ranges of the tree are relative to the start of `pattern`.
+pub(crate) fn parse_regex(pattern: &str, flags: RegexFlags) -> Option<Disjunction> {
+    let mut parser = Parser {
+        source: pattern,
+        position: 0,
+        flags,
+    };
+    let disjunction = parser.parse_disjunction()?;
+    (parser.position == pattern.len()).then_some(disjunction)
+}
+
+impl Disjunction {
+    /// Calls `f` on all the terms of the disjunction, including the nested ones, parents first.
+    pub(crate) fn for_each_term(&self, f: &mut impl FnMut(&Term)) {
+        for alternative in &self.alternatives {
+            for term in &alternative.terms {
+                term.for_each_term(f);
+            }
+        }
+    }
+
+    /// Calls `f` on this disjunction and on the disjunctions of all the nested groups.
+    pub(crate) fn for_each_disjunction(&self, f: &mut impl FnMut(&Disjunction)) {
+        f(self);
+        self.for_each_term(&mut |term| {
+            if let TermKind::Group(group) = &term.kind {
+                f(&group.disjunction);
+            }
+        });
+    }
+
+    /// Returns `true` if one of the alternatives can match the empty string.
+    pub(crate) fn can_match_empty(&self) -> bool {
+        self.alternatives
+            .iter()
+            .any(|alternative| alternative.terms.iter().all(Term::can_match_empty))
+    }
+}
+
+impl Term {
+    fn for_each_term(&self, f: &mut impl FnMut(&Term)) {
+        f(self);
+        match &self.kind {
+            TermKind::Quantified(term, _) => term.for_each_term(f),
+            TermKind::Group(group) => group.disjunction.for_each_term(f),
+            _ => {}
+        }
+    }
+
+    /// Returns `true` if the term can match the empty string.
+    pub(crate) fn can_match_empty(&self) -> bool {
+        match &self.kind {
+            TermKind::Character(_)
+            | TermKind::Any
+            | TermKind::Escape(_)
+            | TermKind::Class(_)
+            | TermKind::Backreference => false,
+            TermKind::Assertion => true,
+            TermKind::Group(group) => {
+                group.kind == GroupKind::Lookaround || group.disjunction.can_match_empty()
+            }
+            TermKind::Quantified(term, quantifier) => quantifier.min == 0 || term.can_match_empty(),
+        }
+    }
+
+    /// Returns the characters matched by a term that matches a single character,
+    /// or `None` if the term can match something else or if the characters are unknown.
+    pub(crate) fn char_set(&self, flags: RegexFlags) -> Option<CharSet> {
+        let set = match &self.kind {
+            TermKind::Character(c) => CharSet::from_ranges([(*c, *c)]),
+            TermKind::Any if flags.dot_all => CharSet::all(),
+            TermKind::Any => CharSet::line_terminator().negate(),
+            TermKind::Escape(escape) => escape.char_set()?,
+            TermKind::Class(class) => {
+                let set = class.unnegated_char_set()?;
+                let set = if flags.ignore_case {
+                    set.with_ascii_case_variants()
+                } else {
+                    set
+                };
+                if class.negated {
+                    set.negate()
+                } else {
+                    set
+                }
+            }
+            _ => return None,
+        };
+        Some(
+            if flags.ignore_case && matches!(self.kind, TermKind::Character(_)) {
+                set.with_ascii_case_variants()
+            } else {
+                set
+            },
+        )
+    }
+}
+
+impl CharacterClass {
+    /// Returns the characters matched by the items of the class, ignoring the negation.
+    pub(crate) fn unnegated_char_set(&self) -> Option<CharSet> {
+        let mut set = CharSet::default();
+        for item in &self.items {
+            let item_set = match item.kind {
+                ClassItemKind::Character(c) => CharSet::from_ranges([(c, c)]),
+                ClassItemKind::Range(from, to) => CharSet::from_ranges([(from, to)]),
+                ClassItemKind::Escape(escape) => escape.char_set()?,
+                ClassItemKind::Other => return None,
+            };
+            set = set.union(&item_set);
+        }
+        Some(set)
+    }
+}
+
+impl ClassEscape {
+    /// Returns the characters matched by the escape, or `None` for a Unicode property.
+    pub(crate) fn char_set(&self) -> Option<CharSet> {
+        let set = match self.kind {
+            ClassEscapeKind::Digit => CharSet::digit(),
+            ClassEscapeKind::Word => CharSet::word(),
+            ClassEscapeKind::Space => CharSet::space(),
+            ClassEscapeKind::Property => return None,
+        };
+        Some(if self.negated { set.negate() } else { set })
+    }
+}
+
+const MAX_CODE_POINT: u32 = 0x0010_FFFF;
+
+/// A set of characters, stored as sorted and disjoint ranges of code points.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct CharSet(Vec<(u32, u32)>);
+
+impl CharSet {
+    fn from_ranges(ranges: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut ranges: Vec<_> = ranges.into_iter().filter(|(from, to)| from <= to).collect();
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (from, to) in ranges {
+            match merged.last_mut() {
+                Some((_, last_to)) if from <= last_to.saturating_add(1) => {
+                    *last_to = (*last_to).max(to);
+                }
+                _ => merged.push((from, to)),
+            }
+        }
+        Self(merged)
+    }
+
+    pub(crate) fn all() -> Self {
+        Self(vec![(0, MAX_CODE_POINT)])
+    }
+
+    /// `\d`
+    pub(crate) fn digit() -> Self {
+        Self::from_ranges([('0' as u32, '9' as u32)])
+    }
+
+    /// `\w`
+    pub(crate) fn word() -> Self {
+        Self::from_ranges([
+            ('0' as u32, '9' as u32),
+            ('A' as u32, 'Z' as u32),
+            ('_' as u32, '_' as u32),
+            ('a' as u32, 'z' as u32),
+        ])
+    }
+
+    /// `\s`
+    pub(crate) fn space() -> Self {
+        Self::from_ranges([
+            (0x09, 0x0D),
+            (0x20, 0x20),
+            (0xA0, 0xA0),
+            (0x1680, 0x1680),
+            (0x2000, 0x200A),
+            (0x2028, 0x2029),
+            (0x202F, 0x202F),
+            (0x205F, 0x205F),
+            (0x3000, 0x3000),
+            (0xFEFF, 0xFEFF),
+        ])
+    }
+
+    fn line_terminator() -> Self {
+        Self::from_ranges([(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)])
+    }
+
+    pub(crate) fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.0.iter().chain(&other.0).copied())
+    }
+
+    pub(crate) fn negate(&self) -> Self {
+        let mut ranges = Vec::with_capacity(self.0.len() + 1);
+        let mut next = 0;
+        for &(from, to) in &self.0 {
+            if from > next {
+                ranges.push((next, from - 1));
+            }
+            next = to + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            ranges.push((next, MAX_CODE_POINT));
+        }
+        Self(ranges)
+    }
+
+    pub(crate) fn intersects(&self, other: &Self) -> bool {
+        let (mut left, mut right) = (self.0.iter().peekable(), other.0.iter().peekable());
+        while let (Some(&&(left_from, left_to)), Some(&&(right_from, right_to))) =
+            (left.peek(), right.peek())
+        {
+            if left_from <= right_to && right_from <= left_to {
+                return true;
+            }
+            if left_to < right_to {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        false
+    }
+
+    /// Adds the other case of the ASCII letters of the set.
+    fn with_ascii_case_variants(&self) -> Self {
+        let case_variants = self.0.iter().flat_map(|&(from, to)| {
+            [('a' as u32, 'z' as u32), ('A' as u32, 'Z' as u32)]
+                .into_iter()
+                .filter_map(move |(letter_from, letter_to)| {
+                    let (from, to) = (from.max(letter_from), to.min(letter_to));
+                    // The lowercase and uppercase letters are 32 code points apart
+                    (from <= to).then_some((from ^ 0x20, to ^ 0x20))
+                })
+        });
+        Self::from_ranges(self.0.iter().copied().chain(case_variants))
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+    flags: RegexFlags,
+}
+
+/// A character escape or a character class escape.
+enum Escape {
+    Character(u32),
+    Class(ClassEscape),
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.source[self.position..].chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let is_present = self.peek() == Some(c);
+        if is_present {
+            self.position += c.len_utf8();
+        }
+        is_present
+    }
+
+    fn range_from(&self, start: usize) -> TextRange {
+        TextRange::new(
+            TextSize::from(start as u32),
+            TextSize::from(self.position as u32),
+        )
+    }
+
+    fn parse_disjunction(&mut self) -> Option<Disjunction> {
+        let mut alternatives = vec![self.parse_alternative()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_alternative()?);
+        }
+        Some(Disjunction { alternatives })
+    }
+
+    fn parse_alternative(&mut self) -> Option<Alternative> {
+        let start = self.position;
+        let mut terms = Vec::new();
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            terms.push(self.parse_term()?);
+        }
+        Some(Alternative {
+            range: self.range_from(start),
+            terms,
+        })
+    }
+
+    fn parse_term(&mut self) -> Option<Term> {
+        let start = self.position;
+        let kind = match self.bump()? {
+            '^' | '$' => {
+                return Some(Term {
+                    range: self.range_from(start),
+                    kind: TermKind::Assertion,
+                })
+            }
+            '\\' if matches!(self.peek(), Some('b' | 'B')) => {
+                self.bump();
+                return Some(Term {
+                    range: self.range_from(start),
+                    kind: TermKind::Assertion,
+                });
+            }
+            '\\' => self.parse_atom_escape()?,
+            '.' => TermKind::Any,
+            '(' => TermKind::Group(self.parse_group()?),
+            '[' => TermKind::Class(self.parse_class()?),
+            '*' | '+' | '?' => return None,
+            '{' => {
+                // Without the `u` flag, a brace that doesn't start a quantifier is a character
+                self.position = start;
+                if self.flags.unicode || self.parse_quantifier().is_some() {
+                    return None;
+                }
+                self.position = start + 1;
+                TermKind::Character('{' as u32)
+            }
+            ']' | '}' if self.flags.unicode => return None,
+            c => TermKind::Character(c as u32),
+        };
+        let atom = Term {
+            range: self.range_from(start),
+            kind,
+        };
+        Some(match self.parse_quantifier() {
+            Some(quantifier) => Term {
+                range: self.range_from(start),
+                kind: TermKind::Quantified(Box::new(atom), quantifier),
+            },
+            None => atom,
+        })
+    }
+
+    fn parse_quantifier(&mut self) -> Option<Quantifier> {
+        let start = self.position;
+        let (min, max) = match self.peek()? {
+            '*' => (0, None),
+            '+' => (1, None),
+            '?' => (0, Some(1)),
+            '{' => {
+                self.bump();
+                let bounds = self.parse_quantifier_bounds();
+                if bounds.is_none() {
+                    self.position = start;
+                }
+                return bounds.map(|(min, max)| {
+                    let greedy = !self.eat('?');
+                    Quantifier {
+                        range: self.range_from(start),
+                        min,
+                        max,
+                        greedy,
+                    }
+                });
+            }
+            _ => return None,
+        };
+        self.bump();
+        let greedy = !self.eat('?');
+        Some(Quantifier {
+            range: self.range_from(start),
+            min,
+            max,
+            greedy,
+        })
+    }
+
+    /// Parses `n}`, `n,}`, or `n,m}`.
+    fn parse_quantifier_bounds(&mut self) -> Option<(u32, Option<u32>)> {
+        let min = self.parse_number()?;
+        let max = if self.eat(',') {
+            if self.peek() == Some('}') {
+                None
+            } else {
+                Some(self.parse_number()?)
+            }
+        } else {
+            Some(min)
+        };
+        self.eat('}').then_some((min, max))
+    }
+
+    fn parse_number(&mut self) -> Option<u32> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+        (self.position > start).then(|| {
+            self.source[start..self.position]
+                .parse()
+                .unwrap_or(u32::MAX)
+        })
+    }
+
+    /// Parses a group after `(`.
+    fn parse_group(&mut self) -> Option<Group> {
+        let kind = if self.eat('?') {
+            match self.bump()? {
+                ':' => GroupKind::NonCapturing,
+                '=' | '!' => GroupKind::Lookaround,
+                '<' if self.eat('=') || self.eat('!') => GroupKind::Lookaround,
+                '<' => {
+                    self.parse_group_name()?;
+                    GroupKind::Capturing
+                }
+                'i' | 'm' | 's' | '-' => {
+                    while matches!(self.peek(), Some('i' | 'm' | 's' | '-')) {
+                        self.bump();
+                    }
+                    if !self.eat(':') {
+                        return None;
+                    }
+                    GroupKind::Modifiers
+                }
+                _ => return None,
+            }
+        } else {
+            GroupKind::Capturing
+        };
+        let disjunction = self.parse_disjunction()?;
+        self.eat(')').then_some(Group { kind, disjunction })
+    }
+
+    /// Parses a group name after `<`.
+    fn parse_group_name(&mut self) -> Option<()> {
+        while self.bump()? != '>' {}
+        Some(())
+    }
+
+    /// Parses an escape after `\`, outside a character class.
+    fn parse_atom_escape(&mut self) -> Option<TermKind> {
+        match self.peek()? {
+            '1'..='9' => {
+                self.parse_number();
+                Some(TermKind::Backreference)
+            }
+            'k' if self.peek_nth(1) == Some('<') => {
+                self.position += 2;
+                self.parse_group_name()?;
+                Some(TermKind::Backreference)
+            }
+            _ => Some(match self.parse_escape(false)? {
+                Escape::Character(c) => TermKind::Character(c),
+                Escape::Class(escape) => TermKind::Escape(escape),
+            }),
+        }
+    }
+
+    /// Parses a character escape or a character class escape after `\`.
+    fn parse_escape(&mut self, is_in_class: bool) -> Option<Escape> {
+        let c = self.bump()?;
+        let class_escape = |kind, negated| Some(Escape::Class(ClassEscape { kind, negated }));
+        match c {
+            'd' | 'D' => class_escape(ClassEscapeKind::Digit, c == 'D'),
+            'w' | 'W' => class_escape(ClassEscapeKind::Word, c == 'W'),
+            's' | 'S' => class_escape(ClassEscapeKind::Space, c == 'S'),
+            'p' | 'P' if self.flags.unicode => {
+                if !self.eat('{') {
+                    return None;
+                }
+                while self.bump()? != '}' {}
+                class_escape(ClassEscapeKind::Property, c == 'P')
+            }
+            'b' if is_in_class => Some(Escape::Character(0x08)),
+            't' => Some(Escape::Character(0x09)),
+            'n' => Some(Escape::Character(0x0A)),
+            'v' => Some(Escape::Character(0x0B)),
+            'f' => Some(Escape::Character(0x0C)),
+            'r' => Some(Escape::Character(0x0D)),
+            'c' if self.peek().is_some_and(|c| c.is_ascii_alphabetic()) => {
+                let letter = self.bump()?;
+                Some(Escape::Character(letter as u32 % 32))
+            }
+            'x' => self
+                .parse_hex_digits(2)
+                .or_else(|| (!self.flags.unicode).then_some('x' as u32))
+                .map(Escape::Character),
+            'u' => self.parse_unicode_escape().map(Escape::Character),
+            '0'..='7' if c == '0' || is_in_class => {
+                // Legacy octal escapes
+                let mut value = c.to_digit(8)?;
+                while value < 32 && self.peek().is_some_and(|c| c.is_digit(8)) {
+                    value = value * 8 + self.bump()?.to_digit(8)?;
+                }
+                Some(Escape::Character(value))
+            }
+            c => Some(Escape::Character(c as u32)),
+        }
+    }
+
+    /// Parses `hhhh` or `{h...}` after `\u`.
+    fn parse_unicode_escape(&mut self) -> Option<u32> {
+        if self.flags.unicode && self.eat('{') {
+            let start = self.position;
+            while self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+                self.bump();
+            }
+            let value = u32::from_str_radix(&self.source[start..self.position], 16).ok()?;
+            return self.eat('}').then_some(value);
+        }
+        self.parse_hex_digits(4)
+            .or_else(|| (!self.flags.unicode).then_some('u' as u32))
+    }
+
+    fn parse_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.source.get(self.position..self.position + count)?;
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.position += count;
+        u32::from_str_radix(digits, 16).ok()
+    }
+
+    /// Parses a character class after `[`.
+    fn parse_class(&mut self) -> Option<CharacterClass> {
+        let negated = self.eat('^');
+        let mut items = Vec::new();
+        loop {
+            let start = self.position;
+            let kind = match self.peek()? {
+                ']' => {
+                    self.bump();
+                    break;
+                }
+                '[' if self.flags.unicode_sets => {
+                    self.bump();
+                    self.parse_class()?;
+                    ClassItemKind::Other
+                }
+                '&' | '-' if self.flags.unicode_sets && self.peek_nth(1) == self.peek() => {
+                    self.position += 2;
+                    ClassItemKind::Other
+                }
+                '\\' if self.flags.unicode_sets && self.peek_nth(1) == Some('q') => {
+                    while self.bump()? != '}' {}
+                    ClassItemKind::Other
+                }
+                _ => self.parse_class_range()?,
+            };
+            items.push(ClassItem {
+                range: self.range_from(start),
+                kind,
+            });
+        }
+        Some(CharacterClass { negated, items })
+    }
+
+    /// Parses a class atom, or a range of two class atoms.
+    fn parse_class_range(&mut self) -> Option<ClassItemKind> {
+        let from = self.parse_class_atom()?;
+        let ClassItemKind::Character(from) = from else {
+            return Some(from);
+        };
+        if self.peek() != Some('-') || matches!(self.peek_nth(1), None | Some(']')) {
+            return Some(ClassItemKind::Character(from));
+        }
+        let dash = self.position;
+        self.bump();
+        match self.parse_class_atom()? {
+            ClassItemKind::Character(to) => Some(ClassItemKind::Range(from, to)),
+            _ if self.flags.unicode => None,
+            _ => {
+                // Without the `u` flag, `[a-\d]` matches `a`, `-`, or a digit
+                self.position = dash;
+                Some(ClassItemKind::Character(from))
+            }
+        }
+    }
+
+    fn parse_class_atom(&mut self) -> Option<ClassItemKind> {
+        match self.bump()? {
+            '\\' => Some(match self.parse_escape(true)? {
+                Escape::Character(c) => ClassItemKind::Character(c),
+                Escape::Class(escape) => ClassItemKind::Escape(escape),
+            }),
+            c => Some(ClassItemKind::Character(c as u32)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(pattern: &str) -> Disjunction {
+        parse_regex(pattern, RegexFlags::default()).expect("the pattern should be valid")
+    }
+
+    #[test]
+    fn parse_alternatives() {
+        let disjunction = parse("a|(b|)|");
+        assert_eq!(disjunction.alternatives.len(), 3);
+        assert!(disjunction.alternatives[2].terms.is_empty());
+        let mut disjunctions = 0;
+        disjunction.for_each_disjunction(&mut |_| disjunctions += 1);
+        assert_eq!(disjunctions, 2);
+    }
+
+    #[test]
+    fn parse_quantifiers() {
+        let disjunction = parse("a{2,}?b{3}c*{");
+        let terms = &disjunction.alternatives[0].terms;
+        let TermKind::Quantified(_, quantifier) = &terms[0].kind else {
+            panic!("the first term should be quantified");
+        };
+        assert_eq!(
+            (quantifier.min, quantifier.max, quantifier.greedy),
+            (2, None, false)
+        );
+        let TermKind::Quantified(_, quantifier) = &terms[1].kind else {
+            panic!("the second term should be quantified");
+        };
+        assert_eq!((quantifier.min, quantifier.max), (3, Some(3)));
+        assert!(matches!(terms[3].kind, TermKind::Character(c) if c == '{' as u32));
+        assert!(parse_regex("a{", RegexFlags::from_text("u")).is_none());
+        assert!(parse_regex("a**", RegexFlags::default()).is_none());
+    }
+
+    #[test]
+    fn parse_classes() {
+        let disjunction = parse(r"[^a-z\d\-]");
+        let TermKind::Class(class) = &disjunction.alternatives[0].terms[0].kind else {
+            panic!("the term should be a class");
+        };
+        assert!(class.negated);
+        assert!(
+            matches!(class.items[0].kind, ClassItemKind::Range(from, to) if from == 'a' as u32 && to == 'z' as u32)
+        );
+        assert!(matches!(class.items[2].kind, ClassItemKind::Character(c) if c == '-' as u32));
+        assert_eq!(class.items[2].range, TextRange::new(7.into(), 9.into()));
+    }
+
+    #[test]
+    fn char_sets() {
+        let flags = RegexFlags::default();
+        let digits = &parse("[0-9]").alternatives[0].terms[0];
+        assert_eq!(digits.char_set(flags), Some(CharSet::digit()));
+        let word = &parse("[_a-zA-Z0-9]").alternatives[0].terms[0];
+        assert_eq!(word.char_set(flags), Some(CharSet::word()));
+        let not_space = &parse(r"\S").alternatives[0].terms[0];
+        assert!(not_space
+            .char_set(flags)
+            .unwrap()
+            .intersects(&CharSet::digit()));
+        assert!(!not_space
+            .char_set(flags)
+            .unwrap()
+            .intersects(&CharSet::space()));
+        let letter = &parse("a").alternatives[0].terms[0];
+        let upper = CharSet::from_ranges([('A' as u32, 'A' as u32)]);
+        assert!(!letter.char_set(flags).unwrap().intersects(&upper));
+        assert!(letter
+            .char_set(RegexFlags::from_text("i"))
+            .unwrap()
+            .intersects(&upper));
+    }
+}
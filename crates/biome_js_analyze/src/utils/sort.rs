@@ -0,0 +1,99 @@
+//! Helpers shared by the assists that sort the members of a list.
+
+use biome_analyze::{ActionCategory, SourceActionKind};
+use biome_js_syntax::JsLanguage;
+use biome_rowan::{syntax::SyntaxTrivia, AstNode, BatchMutation, SyntaxElement};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// The category of the sort actions: `source.sortMembers.biome`
+pub(crate) const SORT_MEMBERS_CATEGORY: ActionCategory =
+    ActionCategory::Source(SourceActionKind::Other(Cow::Borrowed("sortMembers")));
+
+/// Consecutive members of a list that can be reordered, alongside their sort keys.
+pub(crate) type SortableRun<N> = Vec<(N, String)>;
+
+/// Splits `members` into runs of members that can be reordered and returns the runs that aren't sorted.
+///
+/// A member without a key can't be moved: it ends the current run.
+pub(crate) fn unsorted_runs<N: AstNode<Language = JsLanguage>>(
+    members: impl IntoIterator<Item = (N, Option<String>)>,
+) -> Vec<SortableRun<N>> {
+    let mut runs = vec![Vec::new()];
+    for (member, key) in members {
+        match key {
+            Some(key) => runs.last_mut().unwrap().push((member, key)),
+            None => runs.push(Vec::new()),
+        }
+    }
+    runs.retain(|run| !is_sorted(run) && can_move_trivia(run));
+    runs
+}
+
+/// Returns `true` if the keys of `run` are in natural order.
+fn is_sorted<N>(run: &SortableRun<N>) -> bool {
+    run.windows(2)
+        .all(|pair| compare_keys(&pair[0].1, &pair[1].1) != Ordering::Greater)
+}
+
+fn compare_keys(a: &str, b: &str) -> Ordering {
+    natord::compare(a, b)
+}
+
+/// Returns `false` if the comments that follow a member are attached to a separator:
+/// they would stay in place while the member moves.
+fn can_move_trivia<N: AstNode<Language = JsLanguage>>(run: &SortableRun<N>) -> bool {
+    run.iter().all(
+        |(member, _)| match member.syntax().next_sibling_or_token() {
+            Some(SyntaxElement::Token(separator)) => !separator.has_trailing_comments(),
+            _ => true,
+        },
+    )
+}
+
+/// Pushes the changes that sort `run` to `mutation`.
+///
+/// The members keep their comments, while the whitespaces stay in place
+/// so that the layout of the list is preserved.
+pub(crate) fn sort_run<N: AstNode<Language = JsLanguage>>(
+    mutation: &mut BatchMutation<JsLanguage>,
+    run: &SortableRun<N>,
+) -> Option<()> {
+    let mut sorted: Vec<_> = run.iter().collect();
+    // The sort is stable: members with the same key keep their relative order
+    sorted.sort_by(|(_, a), (_, b)| compare_keys(a, b));
+
+    for ((slot, _), (member, _)) in run.iter().zip(sorted) {
+        if slot.syntax() == member.syntax() {
+            continue;
+        }
+        let slot_leading = slot.syntax().first_leading_trivia()?;
+        let slot_trailing = slot.syntax().last_trailing_trivia()?;
+        let member_leading = member.syntax().first_leading_trivia()?;
+        let member_trailing = member.syntax().last_trailing_trivia()?;
+
+        let leading = if has_comments(&slot_leading) || has_comments(&member_leading) {
+            member_leading
+        } else {
+            slot_leading
+        };
+        let trailing = if has_comments(&member_trailing) {
+            member_trailing
+        } else {
+            slot_trailing
+        };
+        let new_member = member
+            .clone()
+            .with_leading_trivia_pieces(leading.pieces())?
+            .with_trailing_trivia_pieces(trailing.pieces())?;
+        mutation.replace_element_discard_trivia(
+            slot.syntax().clone().into(),
+            new_member.into_syntax().into(),
+        );
+    }
+    Some(())
+}
+
+fn has_comments(trivia: &SyntaxTrivia<JsLanguage>) -> bool {
+    trivia.pieces().any(|piece| piece.is_comments())
+}
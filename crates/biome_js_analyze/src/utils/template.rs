@@ -0,0 +1,332 @@
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, AnyJsTemplateElement, JsBinaryExpression,
+    JsBinaryOperator, JsParenthesizedExpression, JsStringLiteralExpression, JsSyntaxKind,
+    JsSyntaxToken, JsTemplateElementList, JsTemplateExpression, OperatorPrecedence, T,
+};
+use biome_rowan::{AstNode, WalkEvent};
+
+/// Returns `true` if `node` is an operand of a binary expression with the operator `+`,
+/// in which case the whole concatenation is converted instead of `node`.
+pub(crate) fn is_nested_in_concatenation(node: &JsBinaryExpression) -> bool {
+    node.syntax()
+        .ancestors()
+        .skip(1) // skip node
+        .find(|x| !JsParenthesizedExpression::can_cast(x.kind()))
+        .and_then(JsBinaryExpression::cast)
+        .is_some_and(|parent| parent.operator() == Ok(JsBinaryOperator::Plus))
+}
+
+/// Returns `true` if `node` is a concatenation with the operator `+` that contains
+/// a string literal or an untagged template literal.
+///
+/// Unlike [can_be_template_literal], the concatenations of string literals are accepted.
+pub(crate) fn is_string_concatenation(node: &JsBinaryExpression) -> bool {
+    if node.operator() != Ok(JsBinaryOperator::Plus) {
+        return false;
+    }
+    let mut iter = node.syntax().preorder();
+    while let Some(walk) = iter.next() {
+        let WalkEvent::Enter(node) = walk else {
+            continue;
+        };
+        match AnyJsExpression::cast(node) {
+            Some(AnyJsExpression::JsParenthesizedExpression(_)) => continue,
+            Some(AnyJsExpression::JsBinaryExpression(binary))
+                if binary.operator() == Ok(JsBinaryOperator::Plus) =>
+            {
+                continue
+            }
+            Some(AnyJsExpression::JsTemplateExpression(template)) if template.tag().is_none() => {
+                return true
+            }
+            Some(AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsStringLiteralExpression(_),
+            )) => return true,
+            _ => iter.skip_subtree(),
+        }
+    }
+    false
+}
+
+/// Returns true if `node` can be converted to a template literal.
+///
+/// This is the case, if:
+///
+/// - the binary expression contains the `+` operator,
+/// - the binary expression contains a string-like literal and a non-string-like or one of them and
+///   an interpolated template literal
+///
+/// String-like literals are string literals and untagged template literals.
+pub(crate) fn can_be_template_literal(node: &JsBinaryExpression) -> Option<bool> {
+    let mut iter = node.syntax().preorder();
+    let mut has_constant_string_constituent = false;
+    let mut has_interpolated_string_constituent = false;
+    let mut has_non_constant_string_constituent = false;
+    while let Some(walk) = iter.next() {
+        if let WalkEvent::Enter(node) = walk {
+            let expression = AnyJsExpression::cast(node)?;
+            match &expression {
+                AnyJsExpression::JsParenthesizedExpression(_) => continue,
+                AnyJsExpression::JsBinaryExpression(binary)
+                    if binary.operator() == Ok(JsBinaryOperator::Plus) =>
+                {
+                    continue
+                }
+                AnyJsExpression::JsTemplateExpression(template) if template.is_constant() => {
+                    has_constant_string_constituent = true;
+                }
+                AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+                    has_interpolated_string_constituent = true;
+                }
+                AnyJsExpression::AnyJsLiteralExpression(
+                    AnyJsLiteralExpression::JsStringLiteralExpression(_),
+                ) => {
+                    has_constant_string_constituent = true;
+                }
+                _ => {
+                    has_non_constant_string_constituent = true;
+                }
+            }
+            if (has_constant_string_constituent
+                && (has_non_constant_string_constituent || has_interpolated_string_constituent))
+                || (has_interpolated_string_constituent && has_non_constant_string_constituent)
+            {
+                return Some(true);
+            }
+            iter.skip_subtree();
+        }
+    }
+    Some(false)
+}
+
+pub(crate) fn template_expression_from_binary_expression(
+    node: &JsBinaryExpression,
+) -> Option<JsTemplateExpression> {
+    // For each nested binary expression (with operator `+`), we keep track of the last left expressions
+    // and whether it evaluates to a string.
+    // Once we see a string/template literal, we insert all of `left_expressions_stack` in
+    // `template_elements` and the seen string/template literal.
+    // Any subsequent expression is directly inserted in `template_elements` if its parent
+    // evaluates to a string.
+    let mut template_elements = vec![];
+    let mut left_expressions_stack = vec![];
+    let mut binary_evaluates_to_string_stack = vec![];
+
+    let mut iter = node.syntax().preorder();
+    while let Some(walk) = iter.next() {
+        match walk {
+            WalkEvent::Enter(node) => match AnyJsExpression::cast(node)? {
+                AnyJsExpression::JsParenthesizedExpression(_) => {}
+                AnyJsExpression::JsBinaryExpression(ref binary)
+                    if binary.operator() == Ok(JsBinaryOperator::Plus) =>
+                {
+                    left_expressions_stack.push(vec![]);
+                    binary_evaluates_to_string_stack.push(false);
+                }
+                _ => iter.skip_subtree(),
+            },
+            WalkEvent::Leave(node) => {
+                let expression = AnyJsExpression::cast(node)?;
+                match &expression {
+                    // Skip parenthesized expressions, because they would be added twice to
+                    // `left_expressions_stack` or `template_elements` (see the last match arm):
+                    // First the contained expression and then the parenthesized expression itself.
+                    AnyJsExpression::JsParenthesizedExpression(_) => continue,
+                    AnyJsExpression::JsBinaryExpression(binary)
+                        if binary.operator() == Ok(JsBinaryOperator::Plus) =>
+                    {
+                        left_expressions_stack.pop()?;
+
+                        if binary_evaluates_to_string_stack.pop()? {
+                            if let Some(parent_evaluates_to_string) =
+                                binary_evaluates_to_string_stack.last_mut()
+                            {
+                                *parent_evaluates_to_string = true;
+                            }
+                        } else if !template_elements.is_empty()
+                            && *binary_evaluates_to_string_stack.last().unwrap_or(&false)
+                        {
+                            template_elements.push(template_element_from(expression)?)
+                        } else if let Some(left_expressions) = left_expressions_stack.last_mut() {
+                            left_expressions.push(expression)
+                        }
+                    }
+                    AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+                        *binary_evaluates_to_string_stack.last_mut()? = true;
+
+                        for left_expression in
+                            left_expressions_stack.iter_mut().flat_map(|v| v.drain(..))
+                        {
+                            template_elements.push(template_element_from(left_expression)?)
+                        }
+                        flatten_template_element_list(&mut template_elements, template.elements())?;
+                    }
+                    AnyJsExpression::AnyJsLiteralExpression(
+                        AnyJsLiteralExpression::JsStringLiteralExpression(string_literal),
+                    ) => {
+                        *binary_evaluates_to_string_stack.last_mut()? = true;
+
+                        for left_expression in
+                            left_expressions_stack.iter_mut().flat_map(|v| v.drain(..))
+                        {
+                            template_elements.push(template_element_from(left_expression)?)
+                        }
+                        template_elements.push(template_chunk_from(string_literal)?);
+                    }
+                    _expression => {
+                        if !template_elements.is_empty()
+                            && *binary_evaluates_to_string_stack.last()?
+                        {
+                            template_elements.push(template_element_from(expression)?)
+                        } else {
+                            left_expressions_stack.last_mut()?.push(expression)
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Some(
+        make::js_template_expression(
+            make::token(T!['`']),
+            make::js_template_element_list(template_elements),
+            make::token(T!['`']),
+        )
+        .build(),
+    )
+}
+
+fn template_chunk_from(string_literal: &JsStringLiteralExpression) -> Option<AnyJsTemplateElement> {
+    let text = string_literal.inner_string_text().ok()?;
+    Some(AnyJsTemplateElement::from(make::js_template_chunk_element(
+        make::js_template_chunk(text.text()),
+    )))
+}
+
+fn template_element_from(expr: AnyJsExpression) -> Option<AnyJsTemplateElement> {
+    Some(AnyJsTemplateElement::from(make::js_template_element(
+        JsSyntaxToken::new_detached(JsSyntaxKind::DOLLAR_CURLY, "${", [], []),
+        expr.with_leading_trivia_pieces([])?
+            .with_trailing_trivia_pieces([])?,
+        make::token(T!['}']),
+    )))
+}
+
+/// Flatten a [JsTemplateElementList] of [JsTemplate] which could possibly be recursive, into a `Vec<JsAnyTemplateElement>`
+/// ## Example
+/// flatten
+/// ```js
+/// `${1 + 2 + `${a}test` }bar`
+/// ```
+/// into
+/// `[1, 2, a, "test", "bar"]`
+fn flatten_template_element_list(
+    result: &mut Vec<AnyJsTemplateElement>,
+    list: JsTemplateElementList,
+) -> Option<()> {
+    for element in list {
+        match element {
+            AnyJsTemplateElement::JsTemplateChunkElement(_) => result.push(element),
+            AnyJsTemplateElement::JsTemplateElement(ref ele) => {
+                let expr = ele.expression().ok()?;
+                match expr {
+                    AnyJsExpression::JsTemplateExpression(template) => {
+                        flatten_template_element_list(result, template.elements())?;
+                    }
+                    _ => {
+                        result.push(element);
+                    }
+                }
+            }
+        }
+    }
+    Some(())
+}
+
+/// Converts the template literal `template` to a concatenation of strings and expressions,
+/// such as `"a" + x + "b"` for `` `a${x}b` ``.
+///
+/// The concatenation starts with an empty string when it would otherwise add the first
+/// expressions together, such as `"" + a + b` for `` `${a}${b}` ``.
+pub(crate) fn concatenation_from_template_expression(
+    template: &JsTemplateExpression,
+) -> Option<AnyJsExpression> {
+    let mut operands = Vec::new();
+    for element in template.elements() {
+        match element {
+            AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                let chunk = chunk.template_chunk_token().ok()?;
+                operands.push(AnyJsExpression::from(make::js_string_literal_expression(
+                    make::js_string_literal(&string_text_from_template_chunk(chunk.text_trimmed())),
+                )));
+            }
+            AnyJsTemplateElement::JsTemplateElement(element) => {
+                let expression = element.expression().ok()?.trim_trivia()?;
+                // `${a + b}` and `${() => a}` need parentheses in the concatenation
+                let expression = if expression.precedence().ok()? <= OperatorPrecedence::Additive
+                    || matches!(expression, AnyJsExpression::JsArrowFunctionExpression(_))
+                {
+                    AnyJsExpression::from(make::parenthesized(expression))
+                } else {
+                    expression
+                };
+                operands.push(expression);
+            }
+        }
+    }
+    let is_string = |operand: Option<&AnyJsExpression>| {
+        matches!(
+            operand,
+            Some(AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsStringLiteralExpression(_)
+            ))
+        )
+    };
+    // `a + b` adds the numbers together, while `"" + a + b` concatenates them
+    if !is_string(operands.first()) && !is_string(operands.get(1)) {
+        operands.insert(
+            0,
+            make::js_string_literal_expression(make::js_string_literal("")).into(),
+        );
+    }
+    let mut operands = operands.into_iter();
+    let first = operands.next()?;
+    Some(operands.fold(first, |left, right| {
+        make::js_binary_expression(left, make::token_decorated_with_space(T![+]), right).into()
+    }))
+}
+
+/// Returns the text of a string literal between double quotes from the raw text of a template chunk.
+///
+/// The escape sequences specific to the template literals are unescaped,
+/// and the line breaks are escaped.
+fn string_text_from_template_chunk(chunk: &str) -> String {
+    let mut text = String::with_capacity(chunk.len());
+    let mut chars = chunk.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped @ ('`' | '$')) => text.push(escaped),
+                Some(escaped) => {
+                    text.push('\\');
+                    text.push(escaped);
+                    // A line continuation can end with `\r\n`
+                    if escaped == '\r' {
+                        text.extend(chars.next_if_eq(&'\n'));
+                    }
+                }
+                None => text.push('\\'),
+            },
+            '"' => text.push_str("\\\""),
+            // The line breaks of a template literal are normalized to `\n`
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                text.push_str("\\n");
+            }
+            '\n' => text.push_str("\\n"),
+            _ => text.push(c),
+        }
+    }
+    text
+}
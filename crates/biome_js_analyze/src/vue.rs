@@ -0,0 +1,245 @@
+//! A series of AST utilities to work with the Vue library
+
+use biome_js_semantic::Binding;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsFunctionBody, AnyJsNamedImportSpecifier, AnyJsObjectMember,
+    AnyJsObjectMemberName, AnyJsStatement, AnyTsType, AnyTsTypeMember, JsCallExpression,
+    JsExportDefaultExpressionClause, JsFunctionBody, JsIdentifierBinding, JsImport,
+    JsObjectExpression,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, AstSeparatedList, TextRange};
+
+/// A key declared by a component, such as the name of a prop or of a computed property
+#[derive(Debug)]
+pub(crate) struct VueKey {
+    pub(crate) name: String,
+    pub(crate) range: TextRange,
+}
+
+declare_node_union! {
+    /// A declaration of the props of a component: a call to `defineProps`,
+    /// or the options of the component exported by `export default`
+    pub AnyVuePropsDeclaration = JsCallExpression | JsExportDefaultExpressionClause
+}
+
+impl AnyVuePropsDeclaration {
+    /// Returns the props declared by the component
+    pub(crate) fn props(&self) -> Vec<VueKey> {
+        match self {
+            Self::JsCallExpression(call) if is_define_props_call(call) => define_props_keys(call),
+            Self::JsExportDefaultExpressionClause(export) => component_options(export)
+                .and_then(|options| component_option(&options, "props"))
+                .and_then(|option| option.as_js_property_object_member()?.value().ok())
+                .map_or_else(Vec::new, |value| props_keys(&value)),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Returns `true` if `call` calls the compiler macro `defineProps` of `<script setup>`,
+/// which is available without import
+pub(crate) fn is_define_props_call(call: &JsCallExpression) -> bool {
+    call.callee().ok().is_some_and(|callee| {
+        callee
+            .omit_parentheses()
+            .as_js_reference_identifier()
+            .is_some_and(|reference| reference.has_name("defineProps"))
+    })
+}
+
+/// Returns the props declared by a call to `defineProps`, such as
+/// `defineProps(["title"])`, `defineProps({ title: String })`, or `defineProps<{ title: string }>()`
+pub(crate) fn define_props_keys(call: &JsCallExpression) -> Vec<VueKey> {
+    if let Some(type_arguments) = call.type_arguments() {
+        let Some(Ok(AnyTsType::TsObjectType(object))) =
+            type_arguments.ts_type_argument_list().iter().next()
+        else {
+            return Vec::new();
+        };
+        return object
+            .members()
+            .iter()
+            .filter_map(|member| match member {
+                AnyTsTypeMember::TsPropertySignatureTypeMember(property) => {
+                    member_name_key(&property.name().ok()?)
+                }
+                _ => None,
+            })
+            .collect();
+    }
+    let Ok(arguments) = call.arguments() else {
+        return Vec::new();
+    };
+    let [Some(argument)] = arguments.get_arguments_by_index([0]) else {
+        return Vec::new();
+    };
+    argument
+        .as_any_js_expression()
+        .map_or_else(Vec::new, props_keys)
+}
+
+/// Returns the props declared by the value of the `props` option, or by the argument of `defineProps`:
+/// an array of strings, such as `["title"]`, or an object, such as `{ title: String }`
+pub(crate) fn props_keys(expression: &AnyJsExpression) -> Vec<VueKey> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsArrayExpression(array) => array
+            .elements()
+            .iter()
+            .filter_map(|element| {
+                let element = element.ok()?;
+                let element = element.as_any_js_expression()?;
+                let name = element.as_static_value()?.as_string_constant()?.to_string();
+                Some(VueKey {
+                    name,
+                    range: element.range(),
+                })
+            })
+            .collect(),
+        AnyJsExpression::JsObjectExpression(object) => object_keys(&object),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the keys of the members of `object` whose name is static
+pub(crate) fn object_keys(object: &JsObjectExpression) -> Vec<VueKey> {
+    object
+        .members()
+        .iter()
+        .filter_map(|member| object_member_key(&member.ok()?))
+        .collect()
+}
+
+/// Returns the key of `member` if its name is static
+pub(crate) fn object_member_key(member: &AnyJsObjectMember) -> Option<VueKey> {
+    let name = match member {
+        AnyJsObjectMember::JsPropertyObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsMethodObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsGetterObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsSetterObjectMember(member) => member.name().ok()?,
+        AnyJsObjectMember::JsShorthandPropertyObjectMember(member) => {
+            let name = member.name().ok()?.value_token().ok()?;
+            return Some(VueKey {
+                name: name.text_trimmed().to_string(),
+                range: name.text_trimmed_range(),
+            });
+        }
+        AnyJsObjectMember::JsSpread(_) | AnyJsObjectMember::JsBogusMember(_) => return None,
+    };
+    member_name_key(&name)
+}
+
+fn member_name_key(name: &AnyJsObjectMemberName) -> Option<VueKey> {
+    match name {
+        AnyJsObjectMemberName::JsLiteralMemberName(literal) => Some(VueKey {
+            name: literal.name().ok()?.to_string(),
+            range: literal.range(),
+        }),
+        AnyJsObjectMemberName::JsComputedMemberName(_)
+        | AnyJsObjectMemberName::JsMetavariable(_) => None,
+    }
+}
+
+/// Returns the options of the component declared by `export`,
+/// such as `export default { props: ["title"] }` or `export default defineComponent({ props: ["title"] })`
+pub(crate) fn component_options(
+    export: &JsExportDefaultExpressionClause,
+) -> Option<JsObjectExpression> {
+    match export.expression().ok()?.omit_parentheses() {
+        AnyJsExpression::JsObjectExpression(object) => Some(object),
+        AnyJsExpression::JsCallExpression(call) => {
+            let callee = call.callee().ok()?.omit_parentheses();
+            if !callee
+                .as_js_reference_identifier()?
+                .has_name("defineComponent")
+            {
+                return None;
+            }
+            let [argument] = call.arguments().ok()?.get_arguments_by_index([0]);
+            match argument?.as_any_js_expression()?.clone().omit_parentheses() {
+                AnyJsExpression::JsObjectExpression(object) => Some(object),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the option `name` of the options of a component, such as `computed` or `methods`
+pub(crate) fn component_option(
+    options: &JsObjectExpression,
+    name: &str,
+) -> Option<AnyJsObjectMember> {
+    options.members().iter().find_map(|member| {
+        let member = member.ok()?;
+        (object_member_key(&member)?.name == name).then_some(member)
+    })
+}
+
+/// Returns the object returned by an option declared as a function, such as `data() { return { count: 0 } }`
+/// or `setup: () => ({ count })`, or the value of the option if it's an object, such as `data: { count: 0 }`
+pub(crate) fn option_returned_object(option: &AnyJsObjectMember) -> Option<JsObjectExpression> {
+    match option {
+        AnyJsObjectMember::JsMethodObjectMember(method) => returned_object(&method.body().ok()?),
+        AnyJsObjectMember::JsPropertyObjectMember(property) => {
+            match property.value().ok()?.omit_parentheses() {
+                AnyJsExpression::JsObjectExpression(object) => Some(object),
+                AnyJsExpression::JsArrowFunctionExpression(arrow) => match arrow.body().ok()? {
+                    AnyJsFunctionBody::AnyJsExpression(expression) => {
+                        match expression.omit_parentheses() {
+                            AnyJsExpression::JsObjectExpression(object) => Some(object),
+                            _ => None,
+                        }
+                    }
+                    AnyJsFunctionBody::JsFunctionBody(body) => returned_object(&body),
+                },
+                AnyJsExpression::JsFunctionExpression(function) => {
+                    returned_object(&function.body().ok()?)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the object returned by the first `return` statement of `body`
+fn returned_object(body: &JsFunctionBody) -> Option<JsObjectExpression> {
+    body.statements()
+        .iter()
+        .find_map(|statement| match statement {
+            AnyJsStatement::JsReturnStatement(statement) => {
+                match statement.argument()?.omit_parentheses() {
+                    AnyJsExpression::JsObjectExpression(object) => Some(object),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+}
+
+/// Returns `true` if `binding` is the function `name` imported from `vue`
+pub(crate) fn is_vue_import(binding: &Binding, name: &str) -> bool {
+    let Some(identifier) = JsIdentifierBinding::cast_ref(binding.syntax()) else {
+        return false;
+    };
+    let Some(specifier) = identifier.parent::<AnyJsNamedImportSpecifier>() else {
+        return false;
+    };
+    let imported_name = match &specifier {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            specifier.name().ok().and_then(|name| name.value().ok())
+        }
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => {
+            identifier.name_token().ok()
+        }
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => None,
+    };
+    if !imported_name.is_some_and(|imported_name| imported_name.text_trimmed() == name) {
+        return false;
+    }
+    specifier
+        .syntax()
+        .ancestors()
+        .find_map(|ancestor| JsImport::cast(ancestor)?.source_text().ok())
+        .is_some_and(|source| source.text() == "vue")
+}
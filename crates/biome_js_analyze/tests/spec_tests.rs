@@ -1,8 +1,11 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
+use biome_js_analyze::{collect_module_details, collect_module_exports, ProjectModuleExports};
 use biome_js_parser::{parse, JsParserOptions};
-use biome_js_syntax::{JsFileSource, JsLanguage, ModuleKind};
+use biome_js_syntax::{AnyJsRoot, JsFileSource, JsLanguage, ModuleKind};
+use biome_json_analyze::document_keys;
+use biome_json_parser::{parse_json, JsonParserOptions};
 use biome_project::PackageType;
 use biome_rowan::AstNode;
 use biome_test_utils::{
@@ -10,10 +13,11 @@ use biome_test_utils::{
     has_bogus_nodes_or_empty_slots, load_manifest, parse_test_path, register_leak_checker,
     scripts_from_json, write_analyzer_snapshot, CheckActionType,
 };
+use std::fs::{read_dir, read_to_string};
 use std::ops::Deref;
-use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
+use std::{ffi::OsStr, path::Path, slice};
 
-tests_macros::gen_tests! {"tests/specs/**/*.{cjs,js,jsx,tsx,ts,json,jsonc,svelte}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/specs/**/*.{cjs,js,jsx,tsx,ts,json,jsonc,svelte,vue}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{cjs,js,jsx,tsx,ts,json,jsonc,svelte}", crate::run_suppression_test, "module"}
 
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
@@ -91,6 +95,48 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     }
 }
 
+/// Returns the exports and the details of the other modules of the directory of `input_file`,
+/// and the keys of its JSON documents, if `root` imports or re-exports modules.
+///
+/// The directory of a test stands for its project.
+fn project_module_exports(
+    input_file: &Path,
+    root: &AnyJsRoot,
+    parser_options: JsParserOptions,
+) -> ProjectModuleExports {
+    if collect_module_exports(root).imports().is_empty() {
+        return ProjectModuleExports::default();
+    }
+    let mut modules = Vec::new();
+    let mut module_details = Vec::new();
+    let mut json_documents = Vec::new();
+    let entries = read_dir(input_file.parent().unwrap()).unwrap();
+    for entry in entries {
+        let path = entry.unwrap().path();
+        if path == input_file {
+            continue;
+        }
+        if path.extension() == Some(OsStr::new("json")) {
+            let code = read_to_string(&path)
+                .unwrap_or_else(|err| panic!("failed to read {path:?}: {err:?}"));
+            let parsed = parse_json(&code, JsonParserOptions::default());
+            json_documents.push((path, document_keys(&parsed.tree())));
+            continue;
+        }
+        let Ok(source_type) = JsFileSource::try_from(path.as_path()) else {
+            continue;
+        };
+        let code =
+            read_to_string(&path).unwrap_or_else(|err| panic!("failed to read {path:?}: {err:?}"));
+        let parsed = parse(&code, source_type, parser_options.clone());
+        module_details.push((path.clone(), collect_module_details(&parsed.tree())));
+        modules.push((path, collect_module_exports(&parsed.tree())));
+    }
+    ProjectModuleExports::new(modules)
+        .with_module_details(module_details)
+        .with_json_documents(json_documents)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn analyze_and_snap(
     snapshot: &mut String,
@@ -118,9 +164,16 @@ pub(crate) fn analyze_and_snap(
     let root = parsed.tree();
 
     let options = create_analyzer_options(input_file, &mut diagnostics);
+    let module_exports = project_module_exports(input_file, &root, parser_options.clone());
 
-    let (_, errors) =
-        biome_js_analyze::analyze(&root, filter, &options, source_type, manifest, |event| {
+    let (_, errors) = biome_js_analyze::analyze(
+        &root,
+        filter,
+        &options,
+        source_type,
+        manifest,
+        module_exports,
+        |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions() {
                     if check_action_type.is_suppression() {
@@ -142,7 +195,12 @@ pub(crate) fn analyze_and_snap(
                             &action,
                             parser_options.clone(),
                         );
-                        diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                        // The edits of the other files can't be printed by the diagnostic
+                        if action.file_edits.is_empty() {
+                            diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                        } else {
+                            code_fixes.push(code_fix_to_string(input_code, action));
+                        }
                     }
                 }
 
@@ -176,7 +234,8 @@ pub(crate) fn analyze_and_snap(
             }
 
             ControlFlow::<Never>::Continue(())
-        });
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
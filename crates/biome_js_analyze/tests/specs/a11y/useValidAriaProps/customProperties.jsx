@@ -0,0 +1,2 @@
+var a = <div aria-x-state="open" />;
+var a = <div aria-x-other="open" />;
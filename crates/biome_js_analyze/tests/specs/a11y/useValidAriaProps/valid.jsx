@@ -6,3 +6,5 @@ var a = <div fooaria-foobar="true"></div>;
 var a = <div fooaria-hidden="true"></div>;
 var a = <input type="text" aria-errormessage="foobar" />;
 var a = <div type="text" aria-atomic="true" />;
+var a = <div aria-description="Description" aria-braillelabel="Label" />;
+var a = <td aria-colindextext="B" aria-rowindextext="2" />;
@@ -0,0 +1,4 @@
+<>
+  <div role="x-toggle" aria-checked="true" />
+  <div role="x-other" />
+</>
@@ -11,4 +11,8 @@
   <div role="switch row" />
   <div />
   <div role="tabpanel row" />
+  <div role="comment" />
+  <div role="suggestion" />
+  <div role="image" />
+  <del role="deletion" />
 </>
\ No newline at end of file
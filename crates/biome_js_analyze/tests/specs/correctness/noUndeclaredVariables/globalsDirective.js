@@ -0,0 +1,8 @@
+/* biome-globals myGlobal, anotherGlobal */
+// biome-globals lineGlobal
+myGlobal;
+anotherGlobal();
+lineGlobal.property;
+
+// Not declared by a directive
+undeclaredGlobal;
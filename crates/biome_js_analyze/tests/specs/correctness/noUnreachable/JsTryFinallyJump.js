@@ -0,0 +1,60 @@
+function JsTryFinallyJump1() {
+    while (true) {
+        try {
+            break;
+        } finally {
+            finallyClause();
+        }
+    }
+
+    afterLoop();
+}
+
+function JsTryFinallyJump2() {
+    outer: for (const item of items) {
+        try {
+            for (const child of item.children) {
+                try {
+                    continue outer;
+                } finally {
+                    innerFinallyClause();
+                }
+            }
+        } finally {
+            outerFinallyClause();
+        }
+    }
+}
+
+function JsTryFinallyJump3() {
+    first: second: while (true) {
+        try {
+            continue first;
+        } finally {
+            finallyClause();
+        }
+    }
+}
+
+function JsTryFinallyJump4() {
+    label: if (value) {
+        try {
+            break label;
+        } finally {
+            finallyClause();
+        }
+    }
+
+    afterIf();
+}
+
+function JsTryFinallyJump5() {
+    while (true) {
+        try {
+            break;
+            afterBreak();
+        } finally {
+            finallyClause();
+        }
+    }
+}
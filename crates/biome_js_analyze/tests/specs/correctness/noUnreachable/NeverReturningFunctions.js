@@ -0,0 +1,19 @@
+/* should not generate diagnostics */
+// No function is considered as never returning by default
+function NeverReturningFunctions1() {
+    process.exit(1);
+    afterExit();
+}
+
+function NeverReturningFunctions2() {
+    if (failed) {
+        process.exit(1);
+    }
+
+    afterIf();
+}
+
+function NeverReturningFunctions3() {
+    exit(1);
+    afterOtherFunction();
+}
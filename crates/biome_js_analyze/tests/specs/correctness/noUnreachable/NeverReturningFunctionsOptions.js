@@ -0,0 +1,14 @@
+function NeverReturningFunctionsOptions1() {
+    fail("message");
+    afterFail();
+}
+
+function NeverReturningFunctionsOptions2() {
+    logger.fatal("message");
+    afterFatal();
+}
+
+function NeverReturningFunctionsOptions3() {
+    process.exit(1);
+    afterExit();
+}
@@ -0,0 +1,10 @@
+/* biome-globals definedGlobal, nestedName */
+var definedGlobal = 1;
+
+function f() {
+	// Only top-level bindings define a global
+	const nestedName = 0;
+}
+f();
+
+var unusedVariable = 2;
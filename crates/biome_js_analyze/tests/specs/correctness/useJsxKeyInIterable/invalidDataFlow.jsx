@@ -0,0 +1,24 @@
+function renderItem(item) {
+	return <li>{item}</li>;
+}
+
+items.map(renderItem);
+
+const renderRow = (row) => <tr>{row}</tr>;
+
+rows.map(renderRow);
+
+const row = <Row />;
+
+rows.map(() => row);
+
+const renderOption = function (option) {
+	return <option>{option}</option>;
+};
+
+Array.from(options, renderOption);
+
+items.map((item) => {
+	const label = <span>{item}</span>;
+	return <>{label}</>;
+});
@@ -0,0 +1,20 @@
+/* should not generate diagnostics */
+
+function renderItem(item) {
+	return <li key={item.id}>{item.name}</li>;
+}
+
+items.map(renderItem);
+
+const renderRow = (row) => <tr key={row.id}>{row.name}</tr>;
+
+rows.map(renderRow);
+
+items.map((item) => {
+	const label = <span key={item.id}>{item.name}</span>;
+	return <>{label}</>;
+});
+
+let row = <Row />;
+row = <Row key="row" />;
+rows.map(() => row);
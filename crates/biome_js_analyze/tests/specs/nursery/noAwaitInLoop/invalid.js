@@ -0,0 +1,78 @@
+async function forOf(urls) {
+	for (const url of urls) {
+		await fetch(url);
+	}
+}
+
+async function forOfSingleStatement(files) {
+	for (const file of files) await upload(file);
+}
+
+async function forOfDestructuring(entries) {
+	for (const [key, value] of Object.entries(entries)) {
+		const response = await save(key, value);
+		log(response.status);
+	}
+}
+
+async function forOfLocalMutation(users) {
+	for (const user of users) {
+		const record = {};
+		record.profile = await loadProfile(user);
+		record.items = [];
+		record.items.push(await loadItems(user));
+	}
+}
+
+async function forIn(object) {
+	for (const key in object) {
+		await process(object[key]);
+	}
+}
+
+async function forLoop() {
+	for (let i = 0; i < 10; i++) {
+		await step(i);
+	}
+}
+
+async function forTest() {
+	for (let i = 0; await hasMore(i); i++) {}
+}
+
+async function whileLoop() {
+	while (!(await isReady())) {}
+}
+
+async function doWhile() {
+	do {
+		await tick();
+	} while (running);
+}
+
+async function nestedForAwait(sources) {
+	for (const source of sources) {
+		for await (const chunk of source) {
+			write(chunk);
+		}
+	}
+}
+
+async function orderDependent(pages) {
+	const results = [];
+	for (const page of pages) {
+		results.push(await fetchPage(page));
+	}
+	let total = 0;
+	for (const page of pages) {
+		total += await countPage(page);
+	}
+	for (const page of pages) {
+		if (await isLast(page)) {
+			break;
+		}
+	}
+	for (var page of pages) {
+		await fetchPage(page);
+	}
+}
@@ -0,0 +1,30 @@
+async function parallel(urls) {
+	await Promise.all(urls.map((url) => fetch(url)));
+}
+
+async function nestedFunction(urls) {
+	for (const url of urls) {
+		queue.push(async () => {
+			await fetch(url);
+		});
+	}
+}
+
+async function forAwait(stream) {
+	for await (const chunk of stream) {
+		await write(chunk);
+	}
+}
+
+async function initializers(items) {
+	for (let i = await start(); i < 10; i++) {}
+	for (const item of await load(items)) {}
+	for (const key in await loadObject()) {}
+}
+
+async function afterLoop(items) {
+	for (const item of items) {
+		prepare(item);
+	}
+	await flush();
+}
@@ -0,0 +1,2 @@
+import { getUserName } from "./domainUser";
+import type { Button } from "./uiButton";
@@ -0,0 +1,3 @@
+import { Button } from "./uiButton";
+import { formatAction } from "./sharedFormat";
+export { Button as UserButton } from "./uiButton";
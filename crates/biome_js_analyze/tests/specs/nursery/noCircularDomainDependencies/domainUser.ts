@@ -0,0 +1,3 @@
+import { fetchUser } from "./dataUsers";
+
+export const getUserName = (id: string) => fetchUser(id).name;
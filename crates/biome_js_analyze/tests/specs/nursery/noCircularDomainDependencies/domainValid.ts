@@ -0,0 +1,3 @@
+import { fetchUser } from "./dataUsers";
+import { getUserName } from "./domainUser";
+import { useState } from "react";
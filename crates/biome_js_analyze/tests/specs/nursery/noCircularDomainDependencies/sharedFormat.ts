@@ -0,0 +1,3 @@
+import { Button } from "./uiButton";
+
+export const formatAction = (label: string) => Button(label.trim());
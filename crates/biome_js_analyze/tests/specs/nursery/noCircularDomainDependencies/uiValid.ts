@@ -0,0 +1,3 @@
+import { Button } from "./uiButton";
+import { getUserName } from "./domainUser";
+import { fetchUser } from "./dataUsers";
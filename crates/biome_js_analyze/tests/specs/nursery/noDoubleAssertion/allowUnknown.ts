@@ -0,0 +1,2 @@
+const a = value as unknown as User;
+const b = value as any as User;
@@ -0,0 +1,5 @@
+const a = value as unknown as User;
+const b = value as any as User;
+const c = <User><unknown>value;
+const d = (value as unknown) as User;
+const e = <User>(value as any);
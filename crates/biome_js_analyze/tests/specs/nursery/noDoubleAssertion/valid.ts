@@ -0,0 +1,5 @@
+const a = value as User;
+const b = value as unknown;
+const c = <User>value;
+const d = value as const;
+const e = (value as Partial<User>) as User;
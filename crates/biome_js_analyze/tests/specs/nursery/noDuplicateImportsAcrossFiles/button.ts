@@ -0,0 +1,3 @@
+export const Button = () => {};
+export type Size = "small" | "large";
+export default Button;
@@ -0,0 +1,4 @@
+export function Button() {}
+export type Size = number;
+export const IconButton = () => {};
+export default IconButton;
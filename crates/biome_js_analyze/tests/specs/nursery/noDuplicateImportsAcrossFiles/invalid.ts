@@ -0,0 +1,4 @@
+export * from "./button";
+export * from "./iconButton";
+export * from "./link";
+export * from "./palette.js";
@@ -0,0 +1,2 @@
+export const Link = () => {};
+export * from "./theme";
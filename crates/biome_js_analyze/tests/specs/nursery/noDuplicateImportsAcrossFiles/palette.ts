@@ -0,0 +1,5 @@
+export const color = "blue";
+export enum Shade {
+	Light,
+	Dark,
+}
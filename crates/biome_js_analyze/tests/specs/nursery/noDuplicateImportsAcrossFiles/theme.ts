@@ -0,0 +1,2 @@
+export const color = "red";
+export const { spacing, radius } = { spacing: 4, radius: 2 };
@@ -0,0 +1,12 @@
+/* should not generate diagnostics */
+export * from "./button";
+export * from "./iconButton";
+// The explicit exports pick one of the modules
+export { Button, type Size } from "./button";
+// `link` re-exports the same `color` binding as `theme`
+export * from "./link";
+export * from "./theme";
+export * as palette from "./palette";
+// The modules that aren't indexed are ignored
+export * from "./unknown";
+export * from "react";
@@ -0,0 +1,7 @@
+/a||b/;
+/|a/;
+/a|/;
+/(jpg|png|)$/;
+/(?:|a|b)c/;
+/x(?<name>a|)/u;
+/[|](a||)/;
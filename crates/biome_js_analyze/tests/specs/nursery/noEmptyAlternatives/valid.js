@@ -0,0 +1,8 @@
+/a|b/;
+/(jpg|png)?$/;
+/()/;
+/[|]/;
+/a\|/;
+/(?:)/;
+// Invalid patterns are ignored
+/(a|/;
@@ -0,0 +1,5 @@
+<Button {...props} />;
+
+<Foo.Bar {...props} />;
+
+<div {...props} />;
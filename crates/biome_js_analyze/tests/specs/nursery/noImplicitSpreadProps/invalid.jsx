@@ -0,0 +1,11 @@
+<div {...props} />;
+
+<div id="main" {...props}>content</div>;
+
+<input {...(props)} />;
+
+<Button {...props} />;
+
+<Foo.Bar {...props} />;
+
+<div {...props.attributes} {...rest} />;
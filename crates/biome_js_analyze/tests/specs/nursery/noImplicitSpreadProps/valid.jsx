@@ -0,0 +1,7 @@
+<div id={props.id} className={props.className} />;
+
+<div {...{ id: "main" }} />;
+
+<Button onClick={onClick}>Submit</Button>;
+
+<Button {...({ type: "submit" })} />;
@@ -0,0 +1,3 @@
+<div {...props} />;
+
+<Button {...props} />;
@@ -0,0 +1,19 @@
+function SignupForm() {
+	return (
+		<form>
+			<label htmlFor="email">Email</label>
+			<input id="mail" type="email" />
+			<label for="password">Password</label>
+			<input id="password-input" type="password" />
+		</form>
+	);
+}
+
+function Fields({ visible }) {
+	return (
+		<div>
+			<input id="name" />
+			{visible && <input id="name" />}
+		</div>
+	);
+}
@@ -0,0 +1,38 @@
+function SignupForm() {
+	return (
+		<form>
+			<label htmlFor="email">Email</label>
+			<input id="email" type="email" />
+		</form>
+	);
+}
+
+function ComponentForm() {
+	return (
+		<form>
+			<label htmlFor="email">Email</label>
+			<EmailInput />
+		</form>
+	);
+}
+
+function DynamicForm({ inputId }) {
+	return (
+		<form>
+			<label htmlFor="email">Email</label>
+			<input id={inputId} />
+		</form>
+	);
+}
+
+function SpreadForm(props) {
+	return (
+		<form>
+			<label htmlFor="email">Email</label>
+			<input {...props} />
+		</form>
+	);
+}
+
+const first = <input id="name" />;
+const second = <input id="name" />;
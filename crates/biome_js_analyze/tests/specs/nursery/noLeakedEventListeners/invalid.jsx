@@ -0,0 +1,56 @@
+import React, { useEffect, useLayoutEffect } from "react";
+
+function MissingCleanup() {
+	useEffect(() => {
+		window.addEventListener("resize", onResize);
+	}, []);
+}
+
+function DifferentHandler() {
+	useEffect(() => {
+		window.addEventListener("resize", onResize);
+		return () => window.removeEventListener("resize", onScroll);
+	}, []);
+}
+
+function DifferentTarget() {
+	useLayoutEffect(() => {
+		window.addEventListener("keydown", onKeyDown);
+		return () => document.removeEventListener("keydown", onKeyDown);
+	}, []);
+}
+
+function InlineHandler() {
+	React.useEffect(() => {
+		window.addEventListener("resize", () => setWidth(window.innerWidth));
+		return () => window.removeEventListener("resize", () => setWidth(window.innerWidth));
+	}, []);
+}
+
+function IntervalNotStored() {
+	useEffect(() => {
+		setInterval(tick, 1000);
+	}, []);
+}
+
+function IntervalNotCleared() {
+	useEffect(() => {
+		const id = setInterval(tick, 1000);
+		return () => clearInterval(other);
+	}, []);
+}
+
+function SubscriptionNotCancelled() {
+	useEffect(() => {
+		const subscription = source.subscribe(onChange);
+		return () => {};
+	}, []);
+}
+
+function Conditional() {
+	useEffect(() => {
+		if (enabled) {
+			element.addEventListener("click", onClick);
+		}
+	}, [enabled]);
+}
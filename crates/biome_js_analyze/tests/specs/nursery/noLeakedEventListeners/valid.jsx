@@ -0,0 +1,80 @@
+import React, { useEffect, useLayoutEffect } from "react";
+
+function RemovedListener() {
+	useEffect(() => {
+		window.addEventListener("resize", onResize);
+		return () => window.removeEventListener("resize", onResize);
+	}, []);
+}
+
+function RemovedInBlock() {
+	useLayoutEffect(() => {
+		const handler = () => setWidth(window.innerWidth);
+		window.addEventListener("resize", handler, { passive: true });
+		return () => {
+			window.removeEventListener("resize", handler);
+		};
+	}, []);
+}
+
+function NamedCleanup() {
+	React.useEffect(function () {
+		document.addEventListener("keydown", onKeyDown);
+		function cleanup() {
+			document.removeEventListener("keydown", onKeyDown);
+		}
+		return cleanup;
+	}, []);
+}
+
+function AbortSignal() {
+	useEffect(() => {
+		const controller = new AbortController();
+		window.addEventListener("resize", onResize, { signal: controller.signal });
+		return () => controller.abort();
+	}, []);
+}
+
+function ClearedInterval() {
+	useEffect(() => {
+		const id = setInterval(tick, 1000);
+		return () => clearInterval(id);
+	}, []);
+}
+
+function AssignedInterval() {
+	useEffect(() => {
+		timer.current = window.setInterval(tick, 1000);
+		return () => window.clearInterval(timer.current);
+	}, []);
+}
+
+function CancelledSubscription() {
+	useEffect(() => {
+		const subscription = source.subscribe(onChange);
+		return () => subscription.unsubscribe();
+	}, []);
+}
+
+function ReturnedSubscription() {
+	useEffect(() => store.subscribe(onChange), []);
+	useEffect(() => {
+		return store.subscribe(onChange);
+	}, []);
+	useEffect(() => {
+		const unsubscribe = store.subscribe(onChange);
+		return unsubscribe;
+	}, []);
+}
+
+function NestedFunction() {
+	useEffect(() => {
+		button.onclick = () => window.addEventListener("resize", onResize);
+	}, []);
+}
+
+function NotAnEffect() {
+	useCallback(() => {
+		window.addEventListener("resize", onResize);
+	}, []);
+}
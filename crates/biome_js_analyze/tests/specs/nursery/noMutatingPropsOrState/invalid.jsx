@@ -0,0 +1,60 @@
+import { useMemo, useReducer, useState } from "react";
+import * as React from "react";
+
+function Profile(props) {
+	props.name = "Anonymous";
+	return <p>{props.name}</p>;
+}
+
+const Counter = ({ count }) => {
+	count.value++;
+	return <p>{count.value}</p>;
+};
+
+const Title = props => {
+	delete props.title;
+	return <h1>{props.children}</h1>;
+};
+
+function List({ items }) {
+	items.push(null);
+	return <ul>{items}</ul>;
+}
+
+function Form(props) {
+	const values = props.values;
+	Object.assign(values, { submitted: true });
+	return <form />;
+}
+
+function TodoList() {
+	const [todos, setTodos] = useState([]);
+	const add = (todo) => todos.push(todo);
+	return <Todos items={todos} onAdd={add} />;
+}
+
+function Settings() {
+	const [settings] = React.useState({ theme: "dark" });
+	settings.theme = "light";
+	return <Theme name={settings.theme} />;
+}
+
+function Store() {
+	const [{ entries }, dispatch] = useReducer(reducer, initialState);
+	entries[0] = null;
+	return <Entries items={entries} />;
+}
+
+function Table({ rows }) {
+	const sorted = useMemo(() => rows.filter(Boolean), [rows]);
+	const view = sorted;
+	view.sort(compare);
+	return <Rows rows={view} />;
+}
+
+function Chart({ data }) {
+	const { points } = useMemo(() => compute(data), [data]);
+	const first = points[0];
+	first.x += 1;
+	return <Points items={points} />;
+}
@@ -0,0 +1,66 @@
+import { useMemo, useState } from "react";
+
+function Profile(props) {
+	const name = props.name.trim();
+	return <p>{name}</p>;
+}
+
+function TodoList() {
+	const [todos, setTodos] = useState([]);
+	const add = (todo) => setTodos([...todos, todo]);
+	return <Todos items={todos} onAdd={add} />;
+}
+
+function Table({ rows }) {
+	const sorted = useMemo(() => rows.filter(Boolean).sort(compare), [rows]);
+	const copy = sorted.slice();
+	copy.reverse();
+	return <Rows rows={copy} />;
+}
+
+// The rest elements are new objects
+function Link({ href, ...rest }) {
+	rest.className = "link";
+	return <a href={href} {...rest} />;
+}
+
+// The reassigned variables can hold another value
+function Editor(props) {
+	let options = props.options;
+	options = { ...options };
+	options.readOnly = true;
+	return <Code options={options} />;
+}
+
+// The second parameter isn't the props
+function Field(props, ref) {
+	ref.current = null;
+	return <input />;
+}
+
+// The functions that aren't components
+function update(props) {
+	props.updated = true;
+}
+
+// The state setter isn't the state
+function Toggle() {
+	const [on, setOn] = useState(false);
+	setOn.displayName = "setOn";
+	return <button onClick={() => setOn(!on)} />;
+}
+
+// `useMemo` that isn't imported from React
+function Chart({ data, useMemo }) {
+	const points = useMemo(() => compute(data));
+	points.push(null);
+	return <Points items={points} />;
+}
+
+function Local() {
+	const items = [];
+	items.push(1);
+	const object = {};
+	object.key = "value";
+	return <List items={items} />;
+}
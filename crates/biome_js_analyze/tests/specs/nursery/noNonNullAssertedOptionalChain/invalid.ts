@@ -0,0 +1,6 @@
+foo?.bar!;
+foo?.bar()!;
+foo?.[key]!;
+foo?.bar.baz!;
+(foo?.bar)!.baz;
+(foo?.bar)!();
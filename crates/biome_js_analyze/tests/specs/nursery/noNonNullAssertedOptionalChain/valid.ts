@@ -0,0 +1,6 @@
+foo?.bar;
+foo.bar!;
+foo!.bar;
+foo?.bar!.baz;
+foo?.bar!();
+foo?.bar ?? baz;
@@ -0,0 +1,12 @@
+const a = object.__proto__;
+const b = object["__proto__"];
+const c = (object).__proto__.constructor;
+object.__proto__ = prototype;
+object["__proto__"] = prototype;
+const d = (object.__proto__ = prototype);
+object.__proto__ ||= prototype;
+object?.__proto__;
+object.__proto__();
+new object.__proto__();
+delete object.__proto__;
+super.__proto__;
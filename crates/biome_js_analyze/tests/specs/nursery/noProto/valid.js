@@ -0,0 +1,7 @@
+const a = Object.getPrototypeOf(object);
+Object.setPrototypeOf(object, prototype);
+const dictionary = { __proto__: null };
+const b = object.proto;
+const c = object[__proto__];
+const d = object["__proto"];
+class E { __proto__() {} }
@@ -0,0 +1,9 @@
+import "./polyfill.js";
+import { Button } from "./button.js";
+customElements.define("my-element", Button);
+new Worker("./worker.js");
+await init();
+window.app = {};
+Array.prototype.last = last;
+counter++;
+Button.displayName = "Button";
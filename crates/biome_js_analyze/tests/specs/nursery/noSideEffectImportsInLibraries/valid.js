@@ -0,0 +1,10 @@
+import { register } from "./register.js";
+export function define() {
+	customElements.define("my-element", Button);
+}
+function Button() {}
+Button.displayName = "Button";
+let count = 0;
+count++;
+export const cache = new Map();
+export default register();
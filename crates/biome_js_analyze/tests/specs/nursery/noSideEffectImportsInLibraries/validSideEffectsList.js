@@ -0,0 +1,3 @@
+import "./style.css";
+customElements.define("my-element", Button);
+window.app = {};
@@ -0,0 +1,9 @@
+/^(a+)+$/;
+/^(a*)*b$/;
+/(\w+\s?)*$/;
+/^(?:\d+)+x/;
+/^(\w|\d)*$/;
+/^(.|\s)*$/;
+/^(a|A)+$/i;
+/^(\w+\d+)*$/;
+/((a+)+)+/;
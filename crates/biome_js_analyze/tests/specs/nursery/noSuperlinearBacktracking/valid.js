@@ -0,0 +1,10 @@
+/^a+$/;
+/^(\d+,)*$/;
+/^(\s|\S)*$/;
+/^(a|b)+$/;
+/^(a|A)+$/;
+/^(a+)?$/;
+/^(a+){2}$/;
+/^(\w+\s)*$/;
+/^(?=a+)+b/;
+/^(\d+[a-z]+)*$/;
@@ -0,0 +1,27 @@
+try {
+	load();
+} catch (error) {}
+
+try {
+	load();
+} catch {}
+
+for (const file of files) {
+	try {
+		load(file);
+	} catch (error) {
+		continue;
+	}
+}
+
+try {
+	load();
+} catch (error) {
+	const retry = () => load();
+}
+
+try {
+	load();
+} catch (error) {
+	let failed;
+}
@@ -0,0 +1,67 @@
+try {
+	load();
+} catch (error) {
+	console.error(error);
+}
+
+try {
+	load();
+} catch (error) {
+	report(error);
+}
+
+function loadConfiguration() {
+	try {
+		return load();
+	} catch {
+		return DEFAULT_CONFIGURATION;
+	}
+}
+
+try {
+	load();
+} catch {
+	throw new Error("Failed to load.");
+}
+
+try {
+	load();
+} catch {
+	failed = true;
+}
+
+try {
+	load();
+} catch {
+	failures++;
+}
+
+try {
+	load();
+} catch {
+	// The configuration is optional.
+}
+
+function f() {
+	try {
+		load();
+	} catch (error) {
+		if (error instanceof TypeError) {
+			return;
+		}
+	}
+}
+
+try {
+	load();
+} catch ({ message }) {
+	log(message);
+}
+
+try {
+	load();
+} catch (error) {
+	setTimeout(() => {
+		throw error;
+	});
+}
@@ -0,0 +1,9 @@
+<h1>Welcome</h1>;
+<p>
+    Hello, world
+</p>;
+<p>{"Hello"}</p>;
+<p>{`Hello`}</p>;
+<input placeholder="Search" />;
+<button aria-label={"Close"} />;
+<abbr title='Frequently asked questions'>FAQ</abbr>;
@@ -0,0 +1,5 @@
+<p>Biome</p>;
+<p>Yes</p>;
+<p>Hello</p>;
+<img alt="Logo" />;
+<input placeholder="Search" />;
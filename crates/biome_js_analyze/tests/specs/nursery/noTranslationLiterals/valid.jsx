@@ -0,0 +1,10 @@
+<h1>{t("home.welcome")}</h1>;
+<p>{count} / {total}</p>;
+<button>x</button>;
+<span>🎉</span>;
+<input className="search" type="text" />;
+<input placeholder={t("search.placeholder")} />;
+<p>{`Hello ${name}`}</p>;
+<p>
+    {t("text")}
+</p>;
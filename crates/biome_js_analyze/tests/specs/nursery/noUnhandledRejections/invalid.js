@@ -0,0 +1,25 @@
+setTimeout(async () => {
+	await fetchData();
+}, 1000);
+
+setInterval(async function () {
+	await poll();
+});
+
+window.setTimeout(async () => {});
+globalThis.queueMicrotask(async () => {});
+requestAnimationFrame((async () => {}));
+process.nextTick(async () => {});
+
+async function onClick() {
+	await save();
+}
+button.addEventListener("click", onClick);
+document.addEventListener("load", onClick.bind(document));
+
+const onKeyDown = async (event) => {
+	await handle(event);
+};
+window.addEventListener("keydown", onKeyDown);
+
+element.addEventListener("scroll", debounce(async () => {}, 100));
@@ -0,0 +1,26 @@
+setTimeout(() => {
+	fetchData().catch(console.error);
+}, 1000);
+
+setTimeout(syncFunction);
+
+button.addEventListener("click", () => {
+	void save().catch(reportError);
+});
+
+// Reassignable variables are ignored
+let handler = async () => {};
+button.addEventListener("click", handler);
+
+// The event type is the first argument
+button.addEventListener(async () => {});
+
+// Local functions that shadow the globals are ignored
+function setTimeout(callback) {
+	return callback();
+}
+setTimeout(async () => {});
+
+// Other APIs handle the returned promises
+promise.then(async () => {});
+array.map(async () => {});
@@ -0,0 +1,4 @@
+setTimeout(catchErrors(async () => {
+	await fetchData();
+}));
+button.addEventListener("click", utils.catchErrors(async () => {}));
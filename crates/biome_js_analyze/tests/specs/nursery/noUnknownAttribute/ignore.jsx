@@ -0,0 +1,2 @@
+<div css={styles} />;
+<div tw="flex" />;
@@ -0,0 +1,9 @@
+<div class="container" />;
+<label for="name">Name</label>;
+<iframe src="/video" allowfullscreen />;
+<div tabindex="0" />;
+<button onclick={submit}>Submit</button>;
+<div href="/home" />;
+<input type="text" colour="red" />;
+<td colSpan={2} rowspan={3} />;
+<div onClik={toggle} />;
@@ -0,0 +1,4 @@
+<div class="container" className="container" onclick={toggle} onClick={toggle} />;
+<label for="name" htmlFor="name">Name</label>;
+<div tabindex={0} tabIndex={0} />;
+<div onclik={toggle} />;
@@ -0,0 +1,4 @@
+<div class="container" classList={{ active: true }} on:click={toggle} onClick={toggle} />;
+<label for="name">Name</label>;
+<div className="container" />;
+<div tabIndex={0} />;
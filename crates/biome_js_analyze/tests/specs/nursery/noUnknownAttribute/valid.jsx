@@ -0,0 +1,14 @@
+<div className="container" id="main" style={{ color: "red" }} />;
+<label htmlFor="name">Name</label>;
+<iframe src="/video" allowFullScreen />;
+<div tabIndex={0} data-testid="container" aria-hidden="true" />;
+<button type="submit" onClick={submit} onPointerDown={press}>Submit</button>;
+<a href="/home" target="_blank" rel="noreferrer">Home</a>;
+<input type="text" defaultValue="Biome" maxLength={10} />;
+<ul key="list" ref={ref}>{children}</ul>;
+<div dangerouslySetInnerHTML={{ __html: html }} suppressHydrationWarning />;
+<svg viewBox="0 0 10 10"><path d="M0 0" strokeWidth={2} /></svg>;
+<my-element someProperty="value" />;
+<Button class="primary" unknown />;
+<div xml:lang="en" {...props} />;
+<meta property="og:title" content="Biome" />;
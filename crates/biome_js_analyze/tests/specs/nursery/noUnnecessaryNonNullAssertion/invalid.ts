@@ -0,0 +1,24 @@
+function getName(user: User | undefined) {
+  if (user) {
+    return user!.name;
+  }
+}
+
+function getCity(user: User) {
+  if (user.address != null) {
+    return user.address!.city;
+  }
+}
+
+function getEmail(user: User | null) {
+  if (!user) {
+    return "";
+  } else {
+    return user!.email;
+  }
+}
+
+const name = user && user!.name;
+const isAdmin = !user || user!.isAdmin;
+const city = user.address ? user.address!.city : "";
+const street = user?.address ? user!.address.street : "";
@@ -0,0 +1,30 @@
+function getName(user: User | undefined) {
+  if (user) {
+    return user.name;
+  }
+  return user!.name;
+}
+
+function update(user: User | undefined) {
+  if (user) {
+    items.forEach(() => user!.add());
+  }
+}
+
+function reassign(user: User | undefined) {
+  if (user) {
+    user = find();
+    return user!.name;
+  }
+}
+
+function shadow(user: User | undefined) {
+  if (user) {
+    const user = find();
+    return user!.name;
+  }
+}
+
+const isAdmin = user || user!.isAdmin;
+const city = user.address === null ? "" : user.address!.city;
+const name = other ? user!.name : "";
@@ -0,0 +1,14 @@
+function Greeting({ name }) {
+	return <p>Hello {name}</p>;
+}
+
+const Title = (props) => {
+	const { text } = props;
+	const level = props.level;
+	return <h1 aria-level={level}>{text}</h1>;
+};
+
+export default function Card(props) {
+	console.log(props.title);
+	return <div>{props.children}</div>;
+}
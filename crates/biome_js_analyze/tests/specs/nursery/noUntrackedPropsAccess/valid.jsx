@@ -0,0 +1,24 @@
+/* should not generate diagnostics */
+import { createMemo, splitProps } from "solid-js";
+
+function Greeting(props) {
+	return <p>Hello {props.name}</p>;
+}
+
+function Title(props) {
+	const level = createMemo(() => props.level);
+	return (
+		<h1 aria-level={level()} onClick={() => console.log(props.text)}>
+			{props.text}
+		</h1>
+	);
+}
+
+function Button(props) {
+	const [local, others] = splitProps(props, ["label"]);
+	return <button {...others}>{local.label}</button>;
+}
+
+function formatName({ first, last }) {
+	return `${first} ${last}`;
+}
@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+function Greeting({ name }) {
+	return <p>Hello {name}</p>;
+}
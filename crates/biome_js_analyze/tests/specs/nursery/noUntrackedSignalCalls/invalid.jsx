@@ -0,0 +1,9 @@
+import { createMemo, createSignal } from "solid-js";
+
+function Counter() {
+	const [count, setCount] = createSignal(0);
+	const double = createMemo(() => count() * 2);
+	const label = `Count: ${count()}`;
+	console.log(double());
+	return <p>{label}</p>;
+}
@@ -0,0 +1,12 @@
+/* should not generate diagnostics */
+import { createEffect, createMemo, createSignal } from "solid-js";
+
+const [theme] = createSignal("light");
+const initialTheme = theme();
+
+function Counter() {
+	const [count, setCount] = createSignal(0);
+	const double = createMemo(() => count() * 2);
+	createEffect(() => console.log(count()));
+	return <button onClick={() => setCount(count() + 1)}>{double()}</button>;
+}
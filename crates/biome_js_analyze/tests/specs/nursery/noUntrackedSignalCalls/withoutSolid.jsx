@@ -0,0 +1,8 @@
+/* should not generate diagnostics */
+import { createSignal } from "solid-js";
+
+function Counter() {
+	const [count] = createSignal(0);
+	const label = `Count: ${count()}`;
+	return <p>{label}</p>;
+}
@@ -0,0 +1,3 @@
+if (process.env.NODE_ENV === "production") {
+}
+const { NODE_ENV, API_URL } = process.env;
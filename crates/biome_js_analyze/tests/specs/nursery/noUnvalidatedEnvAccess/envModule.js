@@ -0,0 +1,4 @@
+export const env = {
+	apiUrl: process.env.API_URL,
+	mode: import.meta.env.MODE,
+};
@@ -0,0 +1,7 @@
+fetch(process.env.API_URL);
+const port = process.env["PORT"];
+const value = process.env[name];
+const mode = import.meta.env.MODE;
+const host = globalThis.process.env.HOST;
+const debug = (process.env).DEBUG;
+const { DATABASE_URL, SECRET: secret, ...rest } = process.env;
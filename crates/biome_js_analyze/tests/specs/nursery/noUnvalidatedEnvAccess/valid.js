@@ -0,0 +1,10 @@
+import { env } from "./env";
+
+fetch(env.API_URL);
+
+function read(process) {
+	return process.env.API_URL;
+}
+
+const keys = Object.keys(process.env);
+const url = import.meta.url;
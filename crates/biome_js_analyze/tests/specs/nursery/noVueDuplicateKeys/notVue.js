@@ -0,0 +1,9 @@
+/* should not generate diagnostics */
+export default {
+	props: ["title"],
+	computed: {
+		title() {
+			return "Home";
+		},
+	},
+};
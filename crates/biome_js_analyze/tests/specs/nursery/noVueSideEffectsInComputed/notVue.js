@@ -0,0 +1,5 @@
+/* should not generate diagnostics */
+import { computed, ref } from "vue";
+
+const items = ref([3, 1, 2]);
+const sortedItems = computed(() => items.value.sort());
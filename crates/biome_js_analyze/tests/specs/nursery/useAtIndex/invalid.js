@@ -0,0 +1,7 @@
+const last = array[array.length - 1];
+const penultimate = array[array.length - 2];
+const char = string[string.length - 1];
+const item = this.items[this.items.length - 1];
+const nested = foo.bar.baz[foo.bar.baz.length - 3];
+const parenthesized = array[(array.length - 1)];
+console.log(array[array.length - 1]);
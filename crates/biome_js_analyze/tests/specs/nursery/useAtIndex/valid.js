@@ -0,0 +1,12 @@
+const last = array.at(-1);
+const first = array[0];
+const other = foo[bar.length - 1];
+const computed = array[array.length - n];
+const fraction = array[array.length - 1.5];
+const zero = array[array.length - 0];
+const plus = array[array.length + 1];
+const call = getArray()[getArray().length - 1];
+const optional = array?.[array.length - 1];
+array[array.length - 1] = value;
+array[array.length - 1]();
+delete array[array.length - 1];
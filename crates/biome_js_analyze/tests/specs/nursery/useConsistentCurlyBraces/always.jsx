@@ -0,0 +1,7 @@
+<>
+<Foo foo="bar" />
+
+<Foo>Hello world</Foo>
+
+<Foo>Hello {name}</Foo>
+</>
@@ -0,0 +1,13 @@
+/* should not generate diagnostics */
+
+<>
+<Foo foo={"bar"} />
+<Foo>{"Hello world"}</Foo>
+<Foo>
+	Hello
+	world
+</Foo>
+<Foo foo="&amp;" />
+<Foo>Tom &amp; Jerry</Foo>
+<Foo>It's "fine"</Foo>
+</>
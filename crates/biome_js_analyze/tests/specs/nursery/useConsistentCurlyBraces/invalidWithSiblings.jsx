@@ -0,0 +1 @@
+<Foo>Hello {'world'}</Foo>;
@@ -0,0 +1,6 @@
+const map: Map<string, number> = new Map();
+let set: Set<string> = new Set(["a"]);
+class Cache {
+	entries: Map<string, Date> = new Map();
+}
+function f(items: Array<number> = new Array()) {}
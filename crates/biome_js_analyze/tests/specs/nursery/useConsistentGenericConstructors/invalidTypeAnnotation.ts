@@ -0,0 +1,6 @@
+const map = new Map<string, number>();
+let set = new Set<string>(["a"]);
+class Cache {
+	entries = new Map<string, Date>();
+}
+function f(items = new Array<number>()) {}
@@ -0,0 +1,11 @@
+const a = new Map<string, number>();
+const b: Map<string, number> = new Map<string, number>();
+const c: ReadonlyMap<string, number> = new Map();
+const d: Map<string, number> = createMap();
+const e = new Map();
+let f: Set<string>;
+class G {
+	h?: Set<string> = new Set();
+	i = new Set<string>();
+}
+function j(k = new Set<string>()) {}
@@ -0,0 +1,4 @@
+const a: Map<string, number> = new Map();
+const b: Map<string, number> = new Map<string, number>();
+const c = new Map();
+const d = new foo.Bar<string>();
@@ -0,0 +1,5 @@
+const user = { name: name };
+const quoted = { "name": name };
+const handlers = { click: function (event) {} };
+const asyncHandlers = { load: async function () {} };
+const generators = { items: function* () {} };
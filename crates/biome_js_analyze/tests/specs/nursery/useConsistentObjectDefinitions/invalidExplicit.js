@@ -0,0 +1,4 @@
+const user = { name };
+const handlers = { click(event) {} };
+const asyncHandlers = { async load() {} };
+const generators = { *items() {} };
@@ -0,0 +1,6 @@
+const user = { name, age: 42 };
+const handlers = { click(event) {}, focus: () => {}, blur: function blur() {} };
+const renamed = { name: firstName };
+const proto = { __proto__: __proto__ };
+const computed = { [name]: name };
+const accessors = { get name() { return name; } };
@@ -0,0 +1,4 @@
+const user = { name: name };
+const handlers = { click: function (event) {} };
+const proto = { __proto__ };
+const withSuper = { method() { return super.method(); } };
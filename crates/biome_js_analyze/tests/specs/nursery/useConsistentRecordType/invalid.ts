@@ -0,0 +1,12 @@
+let a: { [key: string]: number };
+let b: { readonly [key: string]: number };
+let c: Array<{ [key: number]: string }>;
+type D = { [key: string]: unknown };
+type E<T> = { [key: string]: T };
+function f(param: { [index: symbol]: boolean }) {}
+interface G {
+	[key: string]: number;
+}
+export interface H<T> {
+	[key: string]: T;
+}
@@ -0,0 +1,3 @@
+let a: Record<string, number>;
+let b: Record<number, Array<string>>;
+let c: Promise<Record<symbol, unknown>>;
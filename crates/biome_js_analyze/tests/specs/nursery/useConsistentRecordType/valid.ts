@@ -0,0 +1,17 @@
+let a: Record<string, number>;
+let b: Readonly<Record<string, number>>;
+let c: { [key: string]: number; length: number };
+let d: {};
+type Tree = { [key: string]: Tree };
+type Node = { [key: string]: Node[] };
+interface Leaf {
+	[key: string]: Leaf;
+}
+interface WithProperties {
+	[key: string]: number;
+	length: number;
+}
+interface Extended extends Base {
+	[key: string]: number;
+}
+let e: { [K in Keys]: number };
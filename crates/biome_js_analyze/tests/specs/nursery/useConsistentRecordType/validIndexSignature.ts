@@ -0,0 +1,7 @@
+let a: { [key: string]: number };
+let b: Record<"a" | "b", number>;
+let c: Record<Keys, number>;
+let d: Record<string>;
+interface E {
+	[key: string]: number;
+}
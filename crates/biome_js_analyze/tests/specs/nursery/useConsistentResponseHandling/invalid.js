@@ -0,0 +1,20 @@
+async function getUser(id) {
+	const res = await fetch(`/api/users/${id}`);
+	return res.json();
+}
+
+async function getText() {
+	return (await fetch("/api/text")).text();
+}
+
+fetch("/api/users").then((res) => res.json());
+
+fetch("/api/users").then(function (response) {
+	return response.blob();
+});
+
+async function getStatus() {
+	const res = await window.fetch("/api/status");
+	console.log(res.headers.get("content-type"));
+	return await res.json();
+}
@@ -0,0 +1,30 @@
+async function getUser(id) {
+	const res = await fetch(`/api/users/${id}`);
+	if (!res.ok) {
+		throw new Error(`Request failed with status ${res.status}`);
+	}
+	return res.json();
+}
+
+async function getUserOrNull(id) {
+	const res = await fetch(`/api/users/${id}`);
+	return res.status === 404 ? null : res.json();
+}
+
+async function getCheckedUser(id) {
+	const res = await fetch(`/api/users/${id}`);
+	assertOk(res);
+	return res.json();
+}
+
+fetch("/api/users").then((res) => (res.ok ? res.json() : null));
+
+async function getWithClient() {
+	const res = await request("/api/users");
+	return res.json();
+}
+
+async function getWithLocalFetch(fetch) {
+	const res = await fetch("/api/users");
+	return res.json();
+}
@@ -39,4 +39,7 @@ function foo() {
 	return -new Date();
 }
 await +new Date();
-typeof+new Date();
\ No newline at end of file
+typeof+new Date();
+// Global objects accessed through `globalThis` and `window`
+const ts14 = new globalThis.Date().getTime();
+const ts15 = window.Number(new Date());
@@ -62,3 +62,15 @@ new Date() + new Date();
 foo = new Date() | 0;
 foo &= new Date();
 foo = new Date() >> 0;
+
+// `Date` and `Number` don't refer to the global objects
+function shadowed(Date, Number) {
+	const ts = new Date().getTime();
+	const ts1 = +new Date();
+}
+import { Number as LocalNumber } from "./number.js";
+{
+	const Number = LocalNumber;
+	Number(new Date());
+}
+new window.Moment().getTime();
@@ -0,0 +1,59 @@
+try {
+	load();
+} catch (error) {
+	throw new Error("Failed to load.");
+}
+
+try {
+	load();
+} catch (error) {
+	throw Error("Failed to load.");
+}
+
+try {
+	load();
+} catch (cause) {
+	throw new TypeError("Failed to load.");
+}
+
+try {
+	load();
+} catch (error) {
+	throw new RangeError("Failed to load.", {});
+}
+
+try {
+	load();
+} catch (error) {
+	throw new Error("Failed to load.", { details: "..." });
+}
+
+try {
+	load();
+} catch (error) {
+	throw new AggregateError([error], "Failed to load.");
+}
+
+try {
+	load();
+} catch (error) {
+	if (isFatal(error)) {
+		throw new globalThis.Error(`Failed to load: ${error.message}`);
+	}
+}
+
+try {
+	load();
+} catch (error) {
+	throw new Error();
+}
+
+try {
+	load();
+} catch (error) {
+	try {
+		recover();
+	} finally {
+		throw new Error("Failed to recover.");
+	}
+}
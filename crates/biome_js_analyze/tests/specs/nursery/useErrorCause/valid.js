@@ -0,0 +1,73 @@
+try {
+	load();
+} catch (error) {
+	throw new Error("Failed to load.", { cause: error });
+}
+
+try {
+	load();
+} catch (cause) {
+	throw new Error("Failed to load.", { cause });
+}
+
+try {
+	load();
+} catch (error) {
+	throw new Error("Failed to load.", options);
+}
+
+try {
+	load();
+} catch (error) {
+	throw new Error("Failed to load.", { ...options });
+}
+
+try {
+	load();
+} catch (error) {
+	throw error;
+}
+
+try {
+	load();
+} catch {
+	throw new Error("Failed to load.");
+}
+
+try {
+	load();
+} catch ({ message }) {
+	throw new Error(message);
+}
+
+try {
+	load();
+} catch (error) {
+	const fail = () => {
+		throw new Error("Failed to load.");
+	};
+}
+
+try {
+	load();
+} catch (error) {
+	try {
+		throw new Error("Failed to recover.");
+	} catch {}
+}
+
+try {
+	load();
+} catch (error) {
+	throw new CustomError("Failed to load.");
+}
+
+function f(Error) {
+	try {
+		load();
+	} catch (error) {
+		throw new Error("Failed to load.");
+	}
+}
+
+throw new Error("Failed to load.");
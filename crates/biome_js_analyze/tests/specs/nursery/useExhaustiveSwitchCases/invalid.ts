@@ -0,0 +1,28 @@
+enum Color {
+	Red,
+	Green,
+	Blue,
+}
+
+function toHex(color: Color) {
+	switch (color) {
+		case Color.Red:
+			return "#f00";
+	}
+}
+
+type Direction = "up" | "down" | -1;
+
+function move(direction: Direction) {
+	switch (direction) {
+		case "up":
+			return 1;
+	}
+}
+
+function scale(factor: 1 | 2) {
+	switch (factor) {
+		case 1:
+			return factor;
+	}
+}
@@ -0,0 +1,12 @@
+import { Status, Kind } from "./types";
+
+function label(status: Status, kind: Kind) {
+	switch (status) {
+		case Status.Active:
+			return "active";
+	}
+	switch (kind) {
+		case "file":
+			return "file";
+	}
+}
@@ -0,0 +1,6 @@
+export enum Status {
+	Active,
+	Archived,
+}
+
+export type Kind = "file" | "folder";
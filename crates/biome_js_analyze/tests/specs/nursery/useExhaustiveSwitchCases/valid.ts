@@ -0,0 +1,59 @@
+/* should not generate diagnostics */
+enum Color {
+	Red,
+	Green,
+}
+
+function toHex(color: Color) {
+	switch (color) {
+		case Color.Red:
+			return "#f00";
+		case Color.Green:
+			return "#0f0";
+	}
+}
+
+function move(direction: "up" | "down") {
+	switch (direction) {
+		case "up":
+			return 1;
+		default:
+			return 0;
+	}
+}
+
+function untyped(kind) {
+	switch (kind) {
+		case "a":
+			return 1;
+	}
+}
+
+function destructured({ kind }: { kind: "a" | "b" }) {
+	switch (kind) {
+		case "a":
+			return 1;
+	}
+}
+
+function wide(kind: string) {
+	switch (kind) {
+		case "a":
+			return 1;
+	}
+}
+
+type Size = "small" | "large" | 0x10;
+
+const LARGE = "large";
+
+function computed(size: Size) {
+	switch (size) {
+		case `small`:
+			return 1;
+		case LARGE:
+			return 2;
+		case 8 * 2:
+			return 3;
+	}
+}
@@ -0,0 +1,34 @@
+for (const key in object) {
+	process(object[key]);
+}
+
+for (const key in object) process(object[key]);
+
+for (const key in object) {
+	if (Object.hasOwn(object, key)) {
+		process(object[key]);
+	}
+	log(key);
+}
+
+for (const key in object) {
+	if (!Object.hasOwn(object, key)) {
+		log(key);
+	}
+	process(object[key]);
+}
+
+for (const key in object) {
+	if (!Object.hasOwn(object, key)) {
+		continue;
+		log(key);
+	}
+	process(object[key]);
+}
+
+for (const key in object) {
+	process(object[key]);
+	if (Object.hasOwn(object, key)) {
+		continue;
+	}
+}
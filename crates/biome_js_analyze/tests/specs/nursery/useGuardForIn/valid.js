@@ -0,0 +1,27 @@
+for (const key in object);
+
+for (const key in object) {}
+
+for (const key in object) if (Object.hasOwn(object, key)) process(object[key]);
+
+for (const key in object) {
+	if (Object.hasOwn(object, key)) {
+		process(object[key]);
+	}
+}
+
+for (const key in object) {
+	if (!Object.hasOwn(object, key)) continue;
+	process(object[key]);
+}
+
+for (const key in object) {
+	if (!Object.hasOwn(object, key)) {
+		continue;
+	}
+	process(object[key]);
+}
+
+for (const value of list) {
+	process(value);
+}
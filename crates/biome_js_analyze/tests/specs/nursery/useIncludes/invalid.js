@@ -0,0 +1,15 @@
+array.indexOf(value) !== -1;
+array.indexOf(value) != -1;
+array.indexOf(value) > -1;
+array.indexOf(value) >= 0;
+array.indexOf(value) === -1;
+array.indexOf(value) == -1;
+array.indexOf(value) < 0;
+array.indexOf(value) <= -1;
+-1 !== array.indexOf(value);
+0 <= array.indexOf(value);
+0 > array.indexOf(value);
+text.indexOf("biome", 1) !== -1;
+(array.indexOf(value)) !== (-1);
+if (!(list.items.indexOf(item) === -1)) {}
+const found = /* before */ array.indexOf(value) !== -1 /* after */;
@@ -0,0 +1,20 @@
+array.includes(value);
+!array.includes(value);
+array.indexOf(value) === 0;
+array.indexOf(value) !== 0;
+array.indexOf(value) > 0;
+array.indexOf(value) >= -1;
+array.indexOf(value) === -2;
+array.lastIndexOf(value) !== -1;
+array.indexOf() !== -1;
+array.indexOf(...values) !== -1;
+array.indexOf(a, b, c) !== -1;
+array?.indexOf(value) !== -1;
+array["indexOf"](value) !== -1;
+indexOf(value) !== -1;
+array.indexOf(value) !== array.indexOf(other);
+class A extends B {
+	has(value) {
+		return super.indexOf(value) !== -1;
+	}
+}
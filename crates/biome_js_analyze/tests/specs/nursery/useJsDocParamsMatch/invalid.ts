@@ -0,0 +1,39 @@
+/**
+ * Greets someone.
+ * @param {string} name The name of the person.
+ */
+function renamed(username) {
+	console.log(`Hello, ${username}`);
+}
+
+/**
+ * @param {string} name
+ * @param {string} punctuation The punctuation,
+ *   which ends the greeting.
+ */
+function removed(name) {
+	console.log(`Hello, ${name}`);
+}
+
+/**
+ * Logs a message.
+ * @returns {string} Nothing, actually.
+ */
+export default function () {
+	console.log("message");
+}
+
+/** @returns {number} */
+const empty = function () {
+	const inner = () => {
+		return 1;
+	};
+};
+
+/**
+ * @param {number} count
+ * @return the count
+ */
+function withVoidType(value): void {
+	console.log(value);
+}
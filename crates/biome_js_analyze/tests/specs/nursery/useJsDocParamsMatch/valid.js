@@ -0,0 +1,57 @@
+/**
+ * @param {string} name
+ * @param {string} [punctuation="!"]
+ * @returns {string}
+ */
+function greet(name, punctuation = "!") {
+	return `Hello, ${name}${punctuation}`;
+}
+
+/**
+ * @param {object} options
+ * @param {boolean} options.verbose
+ * @param {number[]} values
+ */
+export function configure({ verbose }, ...values) {}
+
+/**
+ * @param count
+ */
+const increment = count => count + 1;
+
+/**
+ * @returns {Promise<void>}
+ */
+async function load() {
+	await fetch("/");
+}
+
+/**
+ * @returns {Generator<number>}
+ */
+function* generate() {
+	yield 1;
+}
+
+/**
+ * @returns {never}
+ */
+function fail() {
+	throw new Error("failure");
+}
+
+/*
+ * @param {string} notJsdoc
+ */
+function notDocumented(name) {}
+
+/**
+ * @param {string} name
+ */
+function undocumentedParameters(name, other) {}
+
+/**
+ * @param {string} first
+ * @param {string} [second="b"]
+ */
+export const reordered = (second, first) => first + second;
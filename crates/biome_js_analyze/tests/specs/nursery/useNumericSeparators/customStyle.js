@@ -0,0 +1,4 @@
+const short = 123456;
+const long = 1234567;
+const hexadecimal = 0xABCDEF;
+const binary = 0b1010;
@@ -0,0 +1,11 @@
+const integer = 1234567;
+const regrouped = 12_34_567;
+const decimal = 1234567.1234567;
+const fraction = .1234567;
+const exponent = 2e-12345;
+const hexadecimal = 0xABCDEF;
+const uppercase = 0XabcdeF;
+const binary = 0b10101010;
+const octal = 0o12345670;
+const bigint = 1234567n;
+const partial = 0xAB_CDEFn;
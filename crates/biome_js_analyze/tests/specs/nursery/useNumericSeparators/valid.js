@@ -0,0 +1,12 @@
+const short = 1234;
+const grouped = 1_234_567;
+const decimal = 1_234.567_8;
+const exponent = 1e10;
+const fraction = 0.1234;
+const legacyOctal = 0777;
+const leadingZero = 089;
+const hexadecimal = 0xFF_FF;
+const binary = 0b1010_0001;
+const octal = 0o7_7777;
+const bigint = 12_345n;
+const zero = 0;
@@ -0,0 +1,8 @@
+const merged = Object.assign({}, defaults, options);
+const copy = Object.assign({}, source);
+const user = Object.assign({ id: 1 }, profile);
+const withTrailingComma = Object.assign({ id: 1, }, profile);
+const nested = Object.assign({}, { a: 1 }, b.c);
+foo(Object.assign({}, a));
+Object.assign({}, a);
+const arrow = () => Object.assign({}, a);
@@ -0,0 +1,11 @@
+const merged = { ...defaults, ...options };
+Object.assign(target, source);
+Object.assign(this, source);
+const spread = Object.assign({}, ...sources);
+const accessors = Object.assign({ get a() { return 1; } }, source);
+const optional = Object?.assign({}, a);
+const notObject = Foo.assign({}, a);
+Object.assign();
+function local(Object) {
+	return Object.assign({}, a);
+}
@@ -0,0 +1,18 @@
+/[0-9]+/;
+/[^0123456789]/;
+/[a-zA-Z0-9_]/;
+/[^_0-9a-zA-Z]/i;
+/[a]/;
+/[.]/;
+/[\d]/;
+/[^\s]/;
+/[^\p{L}]/u;
+/a{0,}/;
+/a{1,}?/;
+/a{0,1}/;
+/a{1}/;
+/a{2,2}/;
+/(?:ab)c/;
+/(?:a)+/;
+/(?:[ab])*/;
+/x(?:)/;
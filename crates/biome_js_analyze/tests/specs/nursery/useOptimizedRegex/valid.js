@@ -0,0 +1,18 @@
+/\d+/;
+/[0-8]/;
+/[0-9a]/;
+/[a-zA-Z0-9_]/iu;
+/[^a]/;
+/[\b]/;
+/[\-]/;
+/\1[0]/;
+/a*/;
+/a{2}/;
+/a{2,3}/;
+/(?:ab)+/;
+/(?:a|b)/;
+/(?i:a)/;
+/(a)/;
+/\1(?:0)/;
+/(?:)0/;
+/a{(?:2)}/;
@@ -0,0 +1,19 @@
+import { signal, useSignal } from "@preact/signals";
+
+const count = signal(0);
+const double = count.value * 2;
+
+function Counter() {
+	const clicks = useSignal(0);
+	clicks.value++;
+	return <p>{clicks}</p>;
+}
+
+const Reset = () => {
+	count.value = 0;
+	return null;
+};
+
+function useReset() {
+	count.value = 0;
+}
@@ -0,0 +1,25 @@
+import { signal, computed, effect, useSignal } from "@preact/signals";
+import { ref } from "vue";
+
+const count = signal(0);
+const double = computed(() => count.value * 2);
+effect(() => console.log(count.value));
+
+function Counter() {
+	const clicks = useSignal(0);
+	const label = clicks.value > 0 ? "Clicked" : "Click";
+	return <button onClick={() => clicks.value++}>{label}</button>;
+}
+
+function increment() {
+	count.value++;
+}
+
+count.value = 1;
+
+const other = ref(0);
+const value = other.value;
+
+function Component() {
+	other.value = 1;
+}
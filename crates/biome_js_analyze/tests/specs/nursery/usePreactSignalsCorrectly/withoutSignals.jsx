@@ -0,0 +1,4 @@
+import { signal } from "@preact/signals";
+
+const count = signal(0);
+const double = count.value * 2;
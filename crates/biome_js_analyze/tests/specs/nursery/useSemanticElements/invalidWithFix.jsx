@@ -0,0 +1,12 @@
+<>
+    <div role="button" onClick={submit}>Submit</div>
+    <span className="nav" role="navigation"><a href="/">Home</a></span>
+    <div role="heading">Title</div>
+    <div role="heading" aria-level="4">Subtitle</div>
+    <div role="list"><div role="listitem">Item</div></div>
+    <div role="banner" id="top"></div>
+    <div role="complementary"></div>
+    <div role="main"></div>
+    <div role="figure" id="chart" className="chart"></div>
+    <div id="article" role="article" className="post"></div>
+</>
@@ -0,0 +1,8 @@
+<>
+    <div role="button" type="submit"></div>
+    <div role="button" {...props}></div>
+    <div role="heading" aria-level={level}></div>
+    <div role="heading" aria-level="7"></div>
+    <div role="link"></div>
+    <div role="img"></div>
+</>
@@ -0,0 +1,47 @@
+const ROUTE_MAP = {
+	home: "/",
+	about: "/about",
+};
+
+const routes = {
+	home: "/",
+	about: "/about",
+};
+
+const APP_CONFIG = {
+	server: {
+		port: 8080,
+		host: "localhost",
+	},
+	client: [{ b: 1, a: 2 }],
+};
+
+settings.THEME_CONFIG = {
+	dark: true,
+	contrast: "high",
+};
+
+class Store {
+	static EVENT_MAP = {
+		update: "onUpdate",
+		change: "onChange",
+	};
+}
+
+const object = {
+	LOCALE_MAP: {
+		fr: "French",
+		en: "English",
+	},
+	other: {
+		b: 1,
+		a: 2,
+	},
+};
+
+function create() {
+	return {
+		b: 1,
+		a: 2,
+	};
+}
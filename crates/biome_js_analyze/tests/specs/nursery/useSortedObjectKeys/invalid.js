@@ -0,0 +1,26 @@
+const object = {
+	zed: 1,
+	alpha: 2,
+};
+
+const comments = {
+	// comment of b
+	b: 1,
+	/* comment of c */ c: 3,
+	a: 2,
+};
+
+const barriers = {
+	b: 1,
+	a: 2,
+	...rest,
+	[computed]: 3,
+	d() {},
+	c,
+};
+
+// The values have side effects: there's no fix
+const calls = {
+	b: first(),
+	a: second(),
+};
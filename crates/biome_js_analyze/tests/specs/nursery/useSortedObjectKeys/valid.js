@@ -0,0 +1,15 @@
+const object = {
+	alpha: 2,
+	zed: 1,
+};
+
+const barriers = {
+	b: 1,
+	...rest,
+	a: 2,
+};
+
+const natural = {
+	item2: 1,
+	item10: 2,
+};
@@ -0,0 +1,8 @@
+function f() {
+	const args = Array.prototype.slice.call(arguments);
+	const args1 = [].slice.call(arguments);
+	const args2 = (Array.prototype).slice.call(arguments);
+}
+const elements = Array.prototype.slice.call(document.querySelectorAll("div"));
+const list = globalThis.Array.prototype.slice.call(nodes);
+const copy = /* before */ [].slice.call(array) /* after */;
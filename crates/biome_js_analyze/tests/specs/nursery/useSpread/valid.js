@@ -0,0 +1,14 @@
+function f() {
+	const args = [...arguments];
+	const rest = Array.prototype.slice.call(arguments, 1);
+	const empty = Array.prototype.slice.call();
+	const spread = Array.prototype.slice.call(...arguments);
+}
+[1].slice.call(arguments);
+Array.prototype.slice.apply(arguments);
+Array.prototype.map.call(arguments, String);
+Array.prototype.slice?.call(arguments);
+Object.prototype.slice.call(arguments);
+function g(Array) {
+	return Array.prototype.slice.call(arguments);
+}
@@ -21,4 +21,16 @@ throw `${foo}`;
 
 // False positives while valid, not a good practice.
 throw "literal" && new Error();
-throw new Error() || "literal";
\ No newline at end of file
+throw new Error() || "literal";
+
+try {
+	load();
+} catch (error) {
+	throw "Failed to load: " + error.message;
+}
+
+try {
+	load();
+} catch (cause) {
+	throw `Failed to load.`;
+}
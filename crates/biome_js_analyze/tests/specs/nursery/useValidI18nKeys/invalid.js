@@ -0,0 +1,5 @@
+import en from "./en.json";
+
+t("home.titel");
+t("home.subtitle");
+i18n.t("errors.unknown");
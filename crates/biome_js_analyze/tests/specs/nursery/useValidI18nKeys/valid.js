@@ -0,0 +1,7 @@
+/* should not generate diagnostics */
+import { render } from "./render";
+
+render(t("home.title"));
+render(i18n.t(`errors.notFound`));
+t(key);
+translate("home.unknown");
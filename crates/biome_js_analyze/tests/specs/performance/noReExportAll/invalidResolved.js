@@ -0,0 +1,2 @@
+export * from "./mod.js";
+export const local = 1;
@@ -0,0 +1,4 @@
+export const a = 1;
+export function b() {}
+export default 2;
+export const local = 3;
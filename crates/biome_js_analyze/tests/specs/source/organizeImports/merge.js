@@ -0,0 +1,11 @@
+import { useEffect } from 'react';
+import { b } from 'b';
+// used by the form
+import { useRef, useEffect } from 'react';
+import { a as c } from 'a';
+import { a as d } from 'a';
+import { x } from 'x' with { type: 'json' };
+import { x as y } from 'x';
+import * as z from 'z';
+import { z1 } from 'z';
+import { useState, useEffect } from 'react'; // state
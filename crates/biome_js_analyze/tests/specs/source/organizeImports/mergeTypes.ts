@@ -0,0 +1,4 @@
+import type { FC } from 'react';
+import { useRef } from 'react';
+import type { ReactNode } from 'react';
+import { type Ref, useState } from 'react';
@@ -0,0 +1,15 @@
+// Adds two numbers
+function add(a, b) {
+	return a + b;
+}
+
+export async function load(url) {
+	const response = await fetch(url);
+	return response.json();
+}
+
+function factorial(n) {
+	return n <= 1 ? 1 : n * factorial(n - 1);
+}
+
+add(1, 2);
@@ -0,0 +1,9 @@
+promise.then(function (value) {
+	return value * 2;
+});
+
+const callback = async function (event) {
+	await handle(event);
+};
+
+const typeOf = typeof function () {};
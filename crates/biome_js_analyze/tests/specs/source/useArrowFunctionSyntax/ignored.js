@@ -0,0 +1,44 @@
+const usesThis = function () {
+	return this.value;
+};
+
+const usesArguments = function () {
+	return arguments.length;
+};
+
+const usesNewTarget = function () {
+	return new.target;
+};
+
+const arrowUsesThis = function () {
+	return () => this.value;
+};
+
+const generator = function* () {
+	yield 1;
+};
+
+const named = function named() {};
+
+hoisted();
+function hoisted() {}
+
+function reassigned() {}
+reassigned = null;
+
+function Constructor() {}
+new Constructor();
+
+const constructed = new (function () {})();
+
+function WithPrototype() {}
+WithPrototype.prototype.value = 1;
+
+function WithProperty() {}
+WithProperty.displayName = "WithProperty";
+
+const ConstructedExpression = function () {};
+new ConstructedExpression();
+
+const expressionWithPrototype = function () {};
+expressionWithPrototype.prototype = {};
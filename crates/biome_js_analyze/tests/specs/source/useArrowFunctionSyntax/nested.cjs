@@ -0,0 +1,6 @@
+const outer = function () {
+	const arguments = [];
+	return function () {
+		return this.value + arguments.length;
+	};
+};
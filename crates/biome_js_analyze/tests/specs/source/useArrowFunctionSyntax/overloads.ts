@@ -0,0 +1,11 @@
+function parse(value: string): number;
+function parse(value: number): number;
+function parse(value: string | number): number {
+	return Number(value);
+}
+
+function identity<T>(value: T): T {
+	return value;
+}
+
+const withThis = function (this: Window) {};
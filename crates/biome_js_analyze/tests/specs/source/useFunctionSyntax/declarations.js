@@ -0,0 +1,10 @@
+const add = (a, b) => a + b;
+
+export const load = async (url) => {
+	const response = await fetch(url);
+	return response.json();
+};
+
+const double = value => value * 2;
+
+const point = () => ({ x: 0, y: 0 });
@@ -0,0 +1,8 @@
+promise.then((value) => {
+	return value * 2;
+});
+
+let mutable = () => {};
+
+const first = () => {},
+	second = () => {};
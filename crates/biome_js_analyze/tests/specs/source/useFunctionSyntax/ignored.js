@@ -0,0 +1,13 @@
+const usesThis = () => this.value;
+
+const usesArguments = () => arguments.length;
+
+class Component {
+	handle = () => this.update();
+
+	render() {
+		return () => super.render();
+	}
+}
+
+const nestedArrow = () => () => this.value;
@@ -0,0 +1,8 @@
+const outer = () => function () {
+	return this.value;
+};
+
+const withArguments = (...args) => {
+	const arguments = args;
+	return arguments.length;
+};
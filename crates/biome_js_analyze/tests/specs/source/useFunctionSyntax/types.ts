@@ -0,0 +1,3 @@
+const identity = <T,>(value: T): T => value;
+
+const typed: () => void = () => {};
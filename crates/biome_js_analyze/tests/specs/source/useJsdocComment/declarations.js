@@ -0,0 +1,28 @@
+export function add(a, b) {
+	return a + b;
+}
+
+function log(message, ...rest) {
+	console.log(message, ...rest);
+}
+
+export default function ({ name }, [first], options = {}) {
+	const inner = () => {
+		return name;
+	};
+}
+
+export const double = (value) => value * 2;
+
+const parse = function (text) {
+	if (!text) {
+		return;
+	}
+	return JSON.parse(text);
+};
+
+if (enabled) {
+	function nested(a) {
+		return a;
+	}
+}
@@ -0,0 +1,15 @@
+/**
+ * Already documented.
+ */
+export function documented() {}
+
+/** Already documented. */
+const alsoDocumented = () => {};
+
+let first = () => {}, second = () => {};
+
+callback(() => {});
+
+const object = {
+	method: function () {},
+};
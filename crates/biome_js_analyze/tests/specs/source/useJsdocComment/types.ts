@@ -0,0 +1,20 @@
+export function greet<T extends string, U>(name: T, suffix?: U, punctuation = "!"): string {
+	return `Hello, ${name}${punctuation}`;
+}
+
+export const join = (separator: string, ...parts: Array<string>): string => parts.join(separator);
+
+function collect(this: Window, ...values: number[]): void {}
+
+function isString(value: unknown): value is string {
+	return typeof value === "string";
+}
+
+function assertString(value: unknown): asserts value is string {}
+
+export function configure(options: {
+	verbose: boolean;
+	level: number;
+}): Promise<void> {
+	return Promise.resolve();
+}
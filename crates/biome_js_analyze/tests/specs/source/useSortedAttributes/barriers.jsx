@@ -0,0 +1 @@
+<Hello b="b" a="a" {...props} d="d" c="c" />;
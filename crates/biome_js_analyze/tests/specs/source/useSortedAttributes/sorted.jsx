@@ -0,0 +1 @@
+<Hello a="a" b="b" {...props} a="a" />;
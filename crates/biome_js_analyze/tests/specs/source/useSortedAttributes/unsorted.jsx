@@ -0,0 +1,8 @@
+<Hello lastName="Smith" firstName="John" />;
+
+<Hello
+	lastName="Smith"
+	xlink:href="#"
+	firstName="John"
+	age={42}
+/>;
@@ -0,0 +1,10 @@
+const object = {
+	d: 1,
+	c: 2,
+	...rest,
+	b: 3,
+	a: 4,
+	[computed]: 5,
+	f: 6,
+	e: 7,
+};
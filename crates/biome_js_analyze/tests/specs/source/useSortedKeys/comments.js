@@ -0,0 +1,12 @@
+const object = {
+	// comment of b
+	b: 1,
+	/* comment of c */ c: 3,
+	a: 2,
+};
+
+// The comment is attached to the comma: the properties are not sorted
+const trailing = {
+	b: 1, // comment of b
+	a: 2,
+};
@@ -0,0 +1,8 @@
+const object = {
+	a: 1,
+	b: 2,
+	...rest,
+	a: 3,
+};
+
+const empty = {};
@@ -0,0 +1,13 @@
+const object = {
+	zed: 1,
+	alpha: 2,
+	"quoted": 3,
+	method() {},
+	get beta() {
+		return 4;
+	},
+	a10,
+	a2,
+};
+
+const inline = { b: 1, a: 2, c: 3 };
@@ -0,0 +1,14 @@
+interface Person {
+	name: string;
+	age: number;
+	(): void;
+	greet(): void;
+	address: string;
+}
+
+type Point = { y: number; x: number };
+
+type Multiline = {
+	b: string
+	a: string
+};
@@ -0,0 +1,8 @@
+type Direction = "down" | "left" | "right" | "up";
+
+interface Person {
+	age: number;
+	name: string;
+	[key: string]: unknown;
+	address: string;
+}
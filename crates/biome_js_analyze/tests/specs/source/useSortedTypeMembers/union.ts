@@ -0,0 +1,6 @@
+type Direction = "up" | "down" | "left" | "right";
+
+type Leading =
+	| B
+	| A
+	| C;
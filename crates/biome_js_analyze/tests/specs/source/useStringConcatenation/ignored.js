@@ -0,0 +1,3 @@
+const constant = `no expressions`;
+
+const tagged = html`<p>${content}</p>`;
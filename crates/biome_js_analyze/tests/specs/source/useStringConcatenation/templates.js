@@ -0,0 +1,9 @@
+const greeting = `Hello, ${name}!`;
+
+const total = `${count + 1} items`;
+
+const pair = `${first}${second}`;
+
+const quoted = `Say "${word}" with \`backticks\``;
+
+const length = `a${b}`.length;
@@ -0,0 +1,12 @@
+enum Direction {
+	Up,
+	Down,
+}
+function move(direction: Direction) {}
+
+const enum Status {
+	Open = 1,
+	"in-progress",
+	Closed = 3,
+}
+type Statuses = Status[];
@@ -0,0 +1,25 @@
+enum Accessed {
+	A,
+	B,
+}
+Accessed.A;
+
+export enum Exported {
+	A,
+}
+
+enum Mixed {
+	A,
+	B = "b",
+}
+let mixed: Mixed;
+
+declare enum Ambient {
+	A,
+}
+let ambient: Ambient;
+
+enum Queried {
+	A,
+}
+type Keys = keyof typeof Queried;
@@ -0,0 +1,7 @@
+const greeting = "Hello, " + name + "!";
+
+const path = root + "/" + file;
+
+const message = "Total: " + (count + 1);
+
+const joined = "a" + "b";
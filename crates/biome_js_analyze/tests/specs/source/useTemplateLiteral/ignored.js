@@ -0,0 +1,5 @@
+const sum = a + b;
+
+const difference = "a" - b;
+
+const tagged = tag`a` + b;
@@ -0,0 +1,10 @@
+function fixable(value) {
+	"use strict";
+	value = value.trim();
+	return value;
+}
+
+const arrow = (count) => {
+	count += 1;
+	return count;
+};
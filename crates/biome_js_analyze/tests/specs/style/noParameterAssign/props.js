@@ -0,0 +1,14 @@
+function setProps(a) {
+	a.b = 0;
+	a.b.c++;
+	delete a[b];
+	[a.b] = [];
+	for (a.b of arr);
+}
+
+function readProps(a) {
+	a.b;
+	foo(a.b).c = 0;
+	data[a.b] = 0;
+	a.b.push(0);
+}
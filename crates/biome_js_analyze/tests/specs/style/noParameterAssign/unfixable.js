@@ -0,0 +1,15 @@
+function defaultValue(a, b = a) {
+	a = 0;
+}
+
+function usesArguments(a) {
+	a = 0;
+	return arguments;
+}
+
+const arrow = (a) => (a = 0);
+
+function conflict(a) {
+	a = 0;
+	const aArg = 1;
+}
@@ -0,0 +1,3 @@
+export const buttonComponent = () => {};
+
+export function _specialValue() {}
@@ -0,0 +1,7 @@
+export const ButtonComponent = () => {};
+
+export const button = () => {};
+
+export const Button = () => {};
+
+export function _SPECIAL_VALUE() {}
@@ -7,4 +7,15 @@
 //Case with two duplicates
 <Hello name="John" name="John" lastname="Doe" lastname="Doe" />;
 
-<label xml:lang="en-US" xml:lang="en-US"></label>
+<label xml:lang="en-US" xml:lang="en-US"></label>;
+
+//Duplicate property assigned by a spread of an object literal
+<Hello {...{ name: "John" }} name="John" />;
+
+//Duplicate property assigned by a spread of a local constant
+const defaults = { name: "John", "aria-label": "Hello" };
+<Hello aria-label="Hi" {...defaults} name="John" />;
+
+//Duplicate property assigned by two spreads
+const greeting = { greeting: "Hi" };
+<Hello {...greeting} {...({ greeting() {} })} />;
@@ -5,3 +5,18 @@
 
 //Case insensitive case
 <Hello name="John" Name="John" />;
+
+//Spread of an object that isn't known
+<Hello {...props} name="John" />;
+
+//Spread of a variable that can be reassigned
+let mutable = { name: "John" };
+<Hello {...mutable} name="John" />;
+
+//Spread of a property with a computed name
+const key = "name";
+<Hello {...{ [key]: "John", ...others }} name="John" />;
+
+//Spread of a constant that isn't an object literal
+const created = createProps({ name: "John" });
+<Hello {...created} name="John" />;
@@ -0,0 +1,6 @@
+testCase.only("foo", () => {});
+feature.scenario.only("foo", () => {});
+testCase["only"]("foo", () => {});
+fscenario("foo", () => {});
+scenario.only("foo", () => {});
+testCase.only(() => {});
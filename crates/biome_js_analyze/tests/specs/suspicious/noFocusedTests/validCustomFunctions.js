@@ -0,0 +1,5 @@
+testCase("foo", () => {});
+feature.scenario("foo", () => {});
+scenario.only("foo", () => {});
+other.testCase.only("foo", () => {});
+testCase.skip("foo", () => {});
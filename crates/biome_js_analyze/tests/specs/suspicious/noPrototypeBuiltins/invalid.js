@@ -8,4 +8,7 @@ foo[`isPrototypeOf`]("bar").baz;
 foo?.hasOwnProperty(bar);
 (foo?.hasOwnProperty)("bar");
 foo?.["hasOwnProperty"]("bar");
-(foo?.[`hasOwnProperty`])("bar");
\ No newline at end of file
+(foo?.[`hasOwnProperty`])("bar");
+foo.hasOwnProperty(...bar);
+(foo /* object */).hasOwnProperty(bar); // comment
+class A extends B { m() { return super.hasOwnProperty("bar"); } }
@@ -0,0 +1,8 @@
+/*! my-library v1.0.0 | MIT License */
+var VERSION = "1.0.0";
+"use strict";
+
+function test() {
+	setup();
+	"use strict";
+}
@@ -4,3 +4,6 @@ function foo() {
 function bar() {
 	"use strict";
 }
+if (condition) {
+	"use strict";
+}
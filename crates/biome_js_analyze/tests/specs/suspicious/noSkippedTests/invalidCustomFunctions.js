@@ -0,0 +1,5 @@
+testCase.skip("test", () => {});
+feature.scenario.skip("test", () => {});
+xscenario("test", () => {});
+scenario.skip("test", () => {});
+testCase.skip(() => {});
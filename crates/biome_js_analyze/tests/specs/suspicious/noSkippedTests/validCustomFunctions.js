@@ -0,0 +1,5 @@
+testCase("test", () => {});
+feature.scenario("test", () => {});
+scenario.skip("test", () => {});
+other.testCase.skip("test", () => {});
+testCase.only("test", () => {});
@@ -0,0 +1,21 @@
+function isNumber(x: string) {
+	return typeof x === "number";
+}
+
+function isUndefined(x: string | number) {
+	return "undefined" === typeof x;
+}
+
+function isString(x: readonly number[]) {
+	return typeof (x) !== "string";
+}
+
+let value: "a" | "b" | null = null;
+typeof value === "boolean";
+
+const callback: () => void = () => {};
+typeof callback == "object";
+
+function isFunction(x?: bigint | (string)) {
+	return typeof x === "function";
+}
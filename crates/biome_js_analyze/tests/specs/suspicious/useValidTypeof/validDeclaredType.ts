@@ -0,0 +1,38 @@
+function isNumber(x: string | number) {
+	return typeof x === "number";
+}
+
+function isObject(x: string | null) {
+	return typeof x === "object";
+}
+
+function isUndefined(x?: string) {
+	return typeof x === "undefined";
+}
+
+function isFunction(x: object) {
+	return typeof x === "function";
+}
+
+// The type references aren't resolved
+type Value = string | number;
+function isValue(x: Value) {
+	return typeof x === "number";
+}
+
+function isGeneric<T>(x: T) {
+	return typeof x === "number";
+}
+
+function isUnknown(x: unknown) {
+	return typeof x === "number";
+}
+
+function isObjectType(x: { a: string }) {
+	return typeof x === "function";
+}
+
+// Destructured bindings aren't resolved
+function isDestructured({ x }: { x: string }) {
+	return typeof x === "number";
+}
@@ -17,11 +17,15 @@ use biome_js_syntax::{
     JsLanguage, JsNamedImportSpecifiers, JsParameters, JsSyntaxKind, JsSyntaxNode,
     JsVariableDeclarator, JsWhileStatement, TsInterfaceDeclaration, TsMappedType,
 };
-use biome_rowan::{AstNode, SyntaxNodeOptionExt, SyntaxTriviaPieceComments, TextLen};
+use biome_rowan::{AstNode, SyntaxNodeOptionExt, SyntaxTriviaPieceComments, TextLen, TextSize};
 use biome_suppression::parse_suppression_comment;
 
 pub type JsComments = Comments<JsLanguage>;
 
+/// Formats a comment, and normalizes it according to the `formatter.comments` options.
+///
+/// Despite its name, this rule formats the leading, the trailing, and the dangling comments:
+/// it's the [CstFormatContext::CommentRule](biome_formatter::CstFormatContext::CommentRule) of [JsFormatContext].
 #[derive(Default)]
 pub struct FormatJsLeadingComment;
 
@@ -33,6 +37,31 @@ impl FormatRule<SourceComment<JsLanguage>> for FormatJsLeadingComment {
         comment: &SourceComment<JsLanguage>,
         f: &mut Formatter<Self::Context>,
     ) -> FormatResult<()> {
+        let options = f.options().comments();
+        let comment_text = comment.piece().text();
+        if options.line_comment_space {
+            if let Some(spaced) = space_line_comment(comment_text) {
+                let source_offset = comment.piece().text_range().start();
+                return write!(f, [dynamic_text(&spaced, source_offset)]);
+            }
+        }
+        if comment.piece().has_newline() && (options.doc_comment_style || options.reflow) {
+            let is_alignable = is_alignable_comment(comment.piece());
+            let is_doc_comment = options.doc_comment_style && comment_text.starts_with("/**");
+            if let Some(block) = BlockComment::parse(comment_text, is_alignable || is_doc_comment) {
+                if is_doc_comment || options.reflow {
+                    return write!(
+                        f,
+                        [FormatBlockComment {
+                            block,
+                            reflow: options.reflow,
+                            source_offset: comment.piece().text_range().start(),
+                        }]
+                    );
+                }
+            }
+        }
+
         if is_alignable_comment(comment.piece()) {
             let mut source_offset = comment.piece().text_range().start();
 
@@ -70,6 +99,189 @@ impl FormatRule<SourceComment<JsLanguage>> for FormatJsLeadingComment {
     }
 }
 
+/// Returns the text of a line comment with a space after `//`, or `None` if it doesn't need one
+///
+/// The triple-slash directives such as `/// <reference />` and the comments such as `//# sourceMappingURL=`
+/// and `//#region` are left as they are.
+fn space_line_comment(text: &str) -> Option<String> {
+    let content = text.strip_prefix("//")?;
+    let first = content.chars().next()?;
+    if first.is_whitespace() || matches!(first, '/' | '#') {
+        return None;
+    }
+    Some(std::format!("// {content}"))
+}
+
+/// The lines of a multiline block comment, without its delimiters and the `*` that start its lines
+struct BlockComment<'a> {
+    /// `/**` for a documentation comment, `/*` otherwise
+    opener: &'static str,
+    lines: Vec<&'a str>,
+}
+
+impl<'a> BlockComment<'a> {
+    /// Splits `text` in lines.
+    ///
+    /// Returns `None` for the comments decorated with `*`, such as `/*****`, and for the legal comments `/*!`,
+    /// and if `allow_undecorated_lines` is `false` and a line doesn't start with `*`.
+    fn parse(text: &'a str, allow_undecorated_lines: bool) -> Option<Self> {
+        if text.starts_with("/***") || text.starts_with("/*!") || text.ends_with("**/") {
+            return None;
+        }
+        let (opener, content) = match text.strip_prefix("/**") {
+            Some(content) => ("/**", content),
+            None => ("/*", text.strip_prefix("/*")?),
+        };
+        let content = content.strip_suffix("*/")?;
+
+        let mut raw_lines = content.lines();
+        let first_line = raw_lines.next()?.trim();
+        let mut lines = Vec::new();
+        // The lines that don't start with `*`, which lose their common indentation
+        let mut undecorated_lines = Vec::new();
+        if !first_line.is_empty() {
+            lines.push(first_line);
+        }
+        let raw_lines: Vec<_> = raw_lines.collect();
+        let last_index = raw_lines.len().saturating_sub(1);
+        for (index, line) in raw_lines.into_iter().enumerate() {
+            if index == last_index && line.trim().is_empty() {
+                break;
+            }
+            let trimmed = line.trim_start();
+            if let Some(rest) = trimmed.strip_prefix('*') {
+                lines.push(rest.strip_prefix(' ').unwrap_or(rest).trim_end());
+            } else if allow_undecorated_lines {
+                undecorated_lines.push(lines.len());
+                lines.push(line.trim_end());
+            } else {
+                return None;
+            }
+        }
+
+        let indentation = undecorated_lines
+            .iter()
+            .map(|index| lines[*index])
+            .filter(|line| !line.is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or_default();
+        for index in undecorated_lines {
+            let line = lines[index];
+            lines[index] = line.get(indentation..).unwrap_or(line.trim_start());
+        }
+
+        (!lines.is_empty()).then_some(Self { opener, lines })
+    }
+
+    /// Groups the lines in paragraphs that can be reflowed, and in lines that are kept as they are.
+    ///
+    /// The empty lines, the indented lines, the code blocks, the JSDoc tags, the headings, the quotes
+    /// and the tables are kept. A list item starts a new paragraph.
+    fn paragraphs(&self) -> Vec<CommentParagraph<'a>> {
+        let mut paragraphs = Vec::new();
+        let mut words: Vec<&str> = Vec::new();
+        let mut is_code_block = false;
+        for line in &self.lines {
+            let is_fence = line.trim_start().starts_with("```");
+            let is_kept = is_code_block
+                || is_fence
+                || line.is_empty()
+                || line.starts_with(char::is_whitespace)
+                || line.starts_with(['@', '#', '>', '|']);
+            if (is_kept || is_list_item(line)) && !words.is_empty() {
+                paragraphs.push(CommentParagraph::Reflowed(std::mem::take(&mut words)));
+            }
+            if is_fence {
+                is_code_block = !is_code_block;
+            }
+            if is_kept {
+                paragraphs.push(CommentParagraph::Kept(line));
+            } else {
+                words.extend(line.split_whitespace());
+            }
+        }
+        if !words.is_empty() {
+            paragraphs.push(CommentParagraph::Reflowed(words));
+        }
+        paragraphs
+    }
+}
+
+enum CommentParagraph<'a> {
+    /// The words of a paragraph, which are filled up to the line width
+    Reflowed(Vec<&'a str>),
+    /// A line that is kept as it is
+    Kept(&'a str),
+}
+
+/// Returns `true` if `line` starts with the marker of a Markdown list item, such as `- ` or `1. `
+fn is_list_item(line: &str) -> bool {
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    let rest = &line[digits..];
+    if digits > 0 {
+        rest.starts_with(". ") || rest.starts_with(") ")
+    } else {
+        rest.starts_with("- ") || rest.starts_with("* ") || rest.starts_with("+ ")
+    }
+}
+
+/// Formats a multiline block comment with its delimiters on their own lines, and a `*` at the start of every line
+struct FormatBlockComment<'a> {
+    block: BlockComment<'a>,
+    reflow: bool,
+    source_offset: TextSize,
+}
+
+impl Format<JsFormatContext> for FormatBlockComment<'_> {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        let source_offset = self.source_offset;
+        let format_kept = |line: &str, f: &mut Formatter<JsFormatContext>| {
+            if line.is_empty() {
+                write!(f, [hard_line_break(), text(" *")])
+            } else {
+                write!(
+                    f,
+                    [
+                        hard_line_break(),
+                        text(" * "),
+                        dynamic_text(line, source_offset)
+                    ]
+                )
+            }
+        };
+
+        write!(f, [text(self.block.opener)])?;
+        if self.reflow {
+            // In a filled paragraph, the words that go to the next line are preceded by ` * `
+            let separator = format_with(|f| {
+                write!(
+                    f,
+                    [soft_line_break_or_space(), if_group_breaks(&text(" * "))]
+                )
+            });
+            for paragraph in self.block.paragraphs() {
+                match paragraph {
+                    CommentParagraph::Reflowed(words) => {
+                        write!(f, [hard_line_break(), text(" * ")])?;
+                        let mut fill = f.fill();
+                        for word in words {
+                            fill.entry(&separator, &dynamic_text(word, source_offset));
+                        }
+                        fill.finish()?;
+                    }
+                    CommentParagraph::Kept(line) => format_kept(line, f)?,
+                }
+            }
+        } else {
+            for line in &self.block.lines {
+                format_kept(line, f)?;
+            }
+        }
+        write!(f, [hard_line_break(), text(" */")])
+    }
+}
+
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
 pub struct JsCommentStyle;
 
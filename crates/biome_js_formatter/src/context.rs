@@ -4,10 +4,13 @@ use crate::comments::{FormatJsLeadingComment, JsCommentStyle, JsComments};
 use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::printer::PrinterOptions;
 use biome_formatter::{
-    AttributePosition, BracketSpacing, CstFormatContext, FormatContext, FormatElement,
-    FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteStyle, TransformSourceMap,
+    AttributePosition, BracketSpacing, CommentsOptions, CstFormatContext, FormatContext,
+    FormatElement, FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteStyle,
+    TransformSourceMap,
 };
 use biome_js_syntax::{AnyJsFunctionBody, JsFileSource, JsLanguage};
+use biome_text_size::TextRange;
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::rc::Rc;
@@ -43,6 +46,14 @@ pub struct JsFormatContext {
     /// This should be rare enough for us not to care about it.
     cached_function_body: Option<(AnyJsFunctionBody, FormatElement)>,
 
+    /// Stores the padding of the nodes that are vertically aligned with their siblings.
+    ///
+    /// The padding of all the nodes of an aligned run is computed at once, when the first node of the run
+    /// gets formatted. Caching it avoids walking the siblings of every node of the run again.
+    ///
+    /// See [JsFormatOptions::vertical_alignment].
+    vertical_alignment_paddings: HashMap<TextRange, usize>,
+
     source_map: Option<TransformSourceMap>,
 }
 
@@ -52,6 +63,7 @@ impl JsFormatContext {
             options,
             comments: Rc::new(comments),
             cached_function_body: None,
+            vertical_alignment_paddings: HashMap::new(),
             source_map: None,
         }
     }
@@ -86,6 +98,23 @@ impl JsFormatContext {
         self.cached_function_body = Some((body.clone(), formatted))
     }
 
+    /// Returns the cached vertical alignment padding of the node with the given range, if any.
+    ///
+    /// See [JsFormatContext::vertical_alignment_paddings] for more in depth documentation.
+    pub(crate) fn get_vertical_alignment_padding(&self, range: TextRange) -> Option<usize> {
+        self.vertical_alignment_paddings.get(&range).copied()
+    }
+
+    /// Caches the vertical alignment padding of the nodes of an aligned run.
+    ///
+    /// See [JsFormatContext::vertical_alignment_paddings] for more in depth documentation.
+    pub(crate) fn set_vertical_alignment_paddings(
+        &mut self,
+        paddings: impl IntoIterator<Item = (TextRange, usize)>,
+    ) {
+        self.vertical_alignment_paddings.extend(paddings)
+    }
+
     pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
         self.source_map = source_map;
         self
@@ -172,6 +201,24 @@ pub struct JsFormatOptions {
 
     /// Attribute position style. By default auto.
     attribute_position: AttributePosition,
+
+    /// Whether to vertically align the values of consecutive assignments and object properties. Defaults to false.
+    ///
+    /// This option is experimental: the formatting it produces may change in future versions.
+    vertical_alignment: VerticalAlignment,
+
+    /// Whether to remove the unnecessary escapes of the string literals. Defaults to as-needed.
+    string_escapes: StringEscapes,
+
+    /// How to normalize the comments. All the normalizations are disabled by default.
+    comments: CommentsOptions,
+
+    /// Whether to format the code embedded in the template literals, such as CSS. Defaults to off.
+    embedded_language_formatting: EmbeddedLanguageFormatting,
+
+    /// The maximum number of specifiers of an import or an export that are printed on a single line.
+    /// The specifiers are printed one per line when there are more, even if they fit in the line width. Unset by default.
+    max_inline_specifiers: Option<u16>,
 }
 
 impl JsFormatOptions {
@@ -191,6 +238,11 @@ impl JsFormatOptions {
             bracket_spacing: BracketSpacing::default(),
             bracket_same_line: BracketSameLine::default(),
             attribute_position: AttributePosition::default(),
+            vertical_alignment: VerticalAlignment::default(),
+            string_escapes: StringEscapes::default(),
+            comments: CommentsOptions::default(),
+            embedded_language_formatting: EmbeddedLanguageFormatting::default(),
+            max_inline_specifiers: None,
         }
     }
 
@@ -259,6 +311,34 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    pub fn with_string_escapes(mut self, string_escapes: StringEscapes) -> Self {
+        self.string_escapes = string_escapes;
+        self
+    }
+
+    pub fn with_comments(mut self, comments: CommentsOptions) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    pub fn with_embedded_language_formatting(
+        mut self,
+        embedded_language_formatting: EmbeddedLanguageFormatting,
+    ) -> Self {
+        self.embedded_language_formatting = embedded_language_formatting;
+        self
+    }
+
+    pub fn with_max_inline_specifiers(mut self, max_inline_specifiers: Option<u16>) -> Self {
+        self.max_inline_specifiers = max_inline_specifiers;
+        self
+    }
+
     pub fn set_arrow_parentheses(&mut self, arrow_parentheses: ArrowParentheses) {
         self.arrow_parentheses = arrow_parentheses;
     }
@@ -310,6 +390,29 @@ impl JsFormatOptions {
         self.semicolons = semicolons;
     }
 
+    pub fn set_vertical_alignment(&mut self, vertical_alignment: VerticalAlignment) {
+        self.vertical_alignment = vertical_alignment;
+    }
+
+    pub fn set_string_escapes(&mut self, string_escapes: StringEscapes) {
+        self.string_escapes = string_escapes;
+    }
+
+    pub fn set_comments(&mut self, comments: CommentsOptions) {
+        self.comments = comments;
+    }
+
+    pub fn set_embedded_language_formatting(
+        &mut self,
+        embedded_language_formatting: EmbeddedLanguageFormatting,
+    ) {
+        self.embedded_language_formatting = embedded_language_formatting;
+    }
+
+    pub fn set_max_inline_specifiers(&mut self, max_inline_specifiers: Option<u16>) {
+        self.max_inline_specifiers = max_inline_specifiers;
+    }
+
     pub fn arrow_parentheses(&self) -> ArrowParentheses {
         self.arrow_parentheses
     }
@@ -353,6 +456,32 @@ impl JsFormatOptions {
     pub fn attribute_position(&self) -> AttributePosition {
         self.attribute_position
     }
+
+    pub fn vertical_alignment(&self) -> VerticalAlignment {
+        self.vertical_alignment
+    }
+
+    pub fn string_escapes(&self) -> StringEscapes {
+        self.string_escapes
+    }
+
+    pub fn comments(&self) -> CommentsOptions {
+        self.comments
+    }
+
+    pub fn embedded_language_formatting(&self) -> EmbeddedLanguageFormatting {
+        self.embedded_language_formatting
+    }
+
+    pub fn max_inline_specifiers(&self) -> Option<u16> {
+        self.max_inline_specifiers
+    }
+
+    /// Returns `true` if a list of `count` specifiers of an import or an export must be printed one per line
+    pub fn should_expand_specifiers(&self, count: usize) -> bool {
+        self.max_inline_specifiers
+            .is_some_and(|max| count > usize::from(max))
+    }
 }
 
 impl FormatOptions for JsFormatOptions {
@@ -399,7 +528,21 @@ impl fmt::Display for JsFormatOptions {
         writeln!(f, "Arrow parentheses: {}", self.arrow_parentheses)?;
         writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())?;
         writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
-        writeln!(f, "Attribute Position: {}", self.attribute_position)
+        writeln!(f, "Attribute Position: {}", self.attribute_position)?;
+        writeln!(f, "Vertical alignment: {}", self.vertical_alignment.value())?;
+        writeln!(f, "String escapes: {}", self.string_escapes)?;
+        writeln!(f, "Comments: {}", self.comments)?;
+        writeln!(
+            f,
+            "Embedded language formatting: {}",
+            self.embedded_language_formatting
+        )?;
+        match self.max_inline_specifiers {
+            Some(max_inline_specifiers) => {
+                writeln!(f, "Max inline specifiers: {max_inline_specifiers}")
+            }
+            None => writeln!(f, "Max inline specifiers: unset"),
+        }
     }
 }
 
@@ -437,6 +580,97 @@ impl fmt::Display for QuoteProperties {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum EmbeddedLanguageFormatting {
+    /// Print the embedded code as written
+    #[default]
+    Off,
+    /// Format the embedded code when its language is recognized, such as the CSS of ``css`...` ``
+    Auto,
+}
+
+impl EmbeddedLanguageFormatting {
+    pub const fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+}
+
+impl FromStr for EmbeddedLanguageFormatting {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" | "Off" => Ok(Self::Off),
+            "auto" | "Auto" => Ok(Self::Auto),
+            _ => Err("Value not supported for EmbeddedLanguageFormatting. Supported values are 'off' and 'auto'."),
+        }
+    }
+}
+
+impl fmt::Display for EmbeddedLanguageFormatting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmbeddedLanguageFormatting::Off => write!(f, "Off"),
+            EmbeddedLanguageFormatting::Auto => write!(f, "Auto"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum StringEscapes {
+    /// Remove the unnecessary escapes, such as `\d`
+    #[default]
+    AsNeeded,
+    /// Keep the escapes as written
+    Preserve,
+    /// Remove the unnecessary escapes, and write the hexadecimal digits of the
+    /// unicode escapes, such as `\u00e9`, in uppercase
+    Normalize,
+}
+
+impl StringEscapes {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_normalize(&self) -> bool {
+        matches!(self, Self::Normalize)
+    }
+}
+
+impl FromStr for StringEscapes {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "as-needed" | "AsNeeded" => Ok(Self::AsNeeded),
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "normalize" | "Normalize" => Ok(Self::Normalize),
+            _ => Err("Value not supported for StringEscapes. Supported values are 'as-needed', 'preserve' and 'normalize'."),
+        }
+    }
+}
+
+impl fmt::Display for StringEscapes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StringEscapes::AsNeeded => write!(f, "As needed"),
+            StringEscapes::Preserve => write!(f, "Preserve"),
+            StringEscapes::Normalize => write!(f, "Normalize"),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -544,3 +778,24 @@ impl From<bool> for BracketSameLine {
         Self(value)
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct VerticalAlignment(bool);
+
+impl VerticalAlignment {
+    /// Return the boolean value for this [VerticalAlignment]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for VerticalAlignment {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::js::lists::template_element_list::FormatJsTemplateElementListOptions;
 use crate::prelude::*;
+use crate::utils::embedded_css::is_embedded_css;
 
 use biome_formatter::write;
 use biome_js_syntax::parentheses::NeedsParentheses;
@@ -68,8 +69,11 @@ impl AnyJsTemplate {
         match self {
             AnyJsTemplate::JsTemplateExpression(template) => {
                 let is_test_each_pattern = template.is_test_each_pattern();
+                let is_embedded_css = f.options().embedded_language_formatting().is_auto()
+                    && is_embedded_css(template);
                 let options = FormatJsTemplateElementListOptions {
                     is_test_each_pattern,
+                    is_embedded_css,
                 };
 
                 write!(f, [template.elements().format().with_options(options)])
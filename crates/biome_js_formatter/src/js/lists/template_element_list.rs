@@ -2,6 +2,7 @@ use crate::context::TabWidth;
 use crate::js::auxiliary::template_chunk_element::AnyTemplateChunkElement;
 use crate::js::auxiliary::template_element::{AnyTemplateElement, TemplateElementOptions};
 use crate::prelude::*;
+use crate::utils::embedded_css::EmbeddedCss;
 use crate::utils::test_each_template::EachTemplateTable;
 use biome_formatter::FormatRuleWithOptions;
 use biome_js_syntax::{
@@ -31,6 +32,13 @@ impl FormatRule<JsTemplateElementList> for FormatJsTemplateElementList {
     fn fmt(&self, node: &JsTemplateElementList, f: &mut JsFormatter) -> FormatResult<()> {
         if self.options.is_test_each_pattern {
             EachTemplateTable::from(node, f)?.fmt(f)
+        } else if let Some(css) = self
+            .options
+            .is_embedded_css
+            .then(|| EmbeddedCss::from(node, f))
+            .flatten()
+        {
+            css.fmt(f)
         } else {
             AnyTemplateElementList::JsTemplateElementList(node.clone()).fmt(f)
         }
@@ -40,6 +48,8 @@ impl FormatRule<JsTemplateElementList> for FormatJsTemplateElementList {
 #[derive(Debug, Copy, Clone, Default)]
 pub(crate) struct FormatJsTemplateElementListOptions {
     pub(crate) is_test_each_pattern: bool,
+    /// Whether to format the elements as CSS, see [is_embedded_css](crate::utils::embedded_css::is_embedded_css)
+    pub(crate) is_embedded_css: bool,
 }
 
 pub(crate) enum AnyTemplateElementList {
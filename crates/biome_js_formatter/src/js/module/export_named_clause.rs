@@ -32,12 +32,14 @@ impl FormatNodeRule<JsExportNamedClause> for FormatJsExportNamedClause {
             )?;
         } else {
             let should_insert_space_around_brackets = f.options().bracket_spacing().value();
+            let should_expand = f.options().should_expand_specifiers(specifiers.len());
             write!(
                 f,
                 [group(&soft_block_indent_with_maybe_space(
                     &specifiers.format(),
                     should_insert_space_around_brackets
-                ),)]
+                ),)
+                .should_expand(should_expand)]
             )?;
         }
 
@@ -22,6 +22,7 @@ impl FormatNodeRule<JsExportNamedFromClause> for FormatJsExportNamedFromClause {
             semicolon_token,
         } = node.as_fields();
         let should_insert_space_around_brackets = f.options().bracket_spacing().value();
+        let should_expand = f.options().should_expand_specifiers(specifiers.len());
 
         if let Some(type_token) = &type_token {
             write!(f, [type_token.format(), space()])?;
@@ -33,7 +34,10 @@ impl FormatNodeRule<JsExportNamedFromClause> for FormatJsExportNamedFromClause {
             Some(AstSeparatedElement {
                 node: Ok(node),
                 trailing_separator: Ok(separator),
-            }) if specifiers.len() == 1 && !f.comments().has_comments(node.syntax()) => {
+            }) if specifiers.len() == 1
+                && !should_expand
+                && !f.comments().has_comments(node.syntax()) =>
+            {
                 write!(
                     f,
                     [
@@ -49,7 +53,7 @@ impl FormatNodeRule<JsExportNamedFromClause> for FormatJsExportNamedFromClause {
                 write!(f, [maybe_space(should_insert_space_around_brackets)])?;
             }
             _ => {
-                if specifiers.syntax().has_leading_newline() {
+                if should_expand || specifiers.syntax().has_leading_newline() {
                     write!(f, [block_indent(&specifiers.format()),])?;
                 } else {
                     write!(
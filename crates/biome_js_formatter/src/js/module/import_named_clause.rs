@@ -38,6 +38,7 @@ impl FormatNodeRule<JsImportNamedClause> for FormatJsImportNamedClause {
         // 2. length of `JsNamedImportSpecifiers` at least is one
         // 3. Surrounding of the only `JsNamedImportSpecifiers` should not have any comments
         if specifiers.len() == 1
+            && !f.options().should_expand_specifiers(1)
             && !f
                 .context()
                 .comments()
@@ -4,6 +4,7 @@ use crate::prelude::*;
 use crate::ts::bindings::type_parameters::FormatTsTypeParametersOptions;
 use crate::utils::member_chain::is_member_call_chain;
 use crate::utils::object::write_member_name;
+use crate::utils::vertical_alignment::{AnyJsAlignable, FormatAlignmentPadding};
 use crate::utils::{FormatLiteralStringToken, StringLiteralParentKind};
 use biome_formatter::{format_args, write, CstFormatContext, FormatOptions, VecBuffer};
 use biome_js_syntax::binary_like_expression::AnyJsBinaryLikeExpression;
@@ -12,12 +13,12 @@ use biome_js_syntax::{
     AnyJsExpression, AnyJsFunctionBody, AnyJsObjectAssignmentPatternMember,
     AnyJsObjectBindingPatternMember, AnyJsObjectMemberName, AnyJsTemplateElement,
     AnyTsIdentifierBinding, AnyTsType, AnyTsVariableAnnotation, JsAssignmentExpression,
-    JsInitializerClause, JsLiteralMemberName, JsObjectAssignmentPattern,
+    JsExpressionStatement, JsInitializerClause, JsLiteralMemberName, JsObjectAssignmentPattern,
     JsObjectAssignmentPatternProperty, JsObjectBindingPattern, JsPropertyClassMember,
     JsPropertyClassMemberFields, JsPropertyObjectMember, JsSyntaxKind, JsVariableDeclarator,
-    TsInitializedPropertySignatureClassMember, TsInitializedPropertySignatureClassMemberFields,
-    TsPropertySignatureClassMember, TsPropertySignatureClassMemberFields, TsTypeAliasDeclaration,
-    TsTypeArguments, TsUnionType,
+    JsVariableStatement, TsInitializedPropertySignatureClassMember,
+    TsInitializedPropertySignatureClassMemberFields, TsPropertySignatureClassMember,
+    TsPropertySignatureClassMemberFields, TsTypeAliasDeclaration, TsTypeArguments, TsUnionType,
 };
 use biome_js_syntax::{AnyJsLiteralExpression, JsUnaryExpression};
 use biome_rowan::{declare_node_union, AstNode, SyntaxNodeOptionExt, SyntaxResult};
@@ -407,6 +408,10 @@ impl AnyJsAssignmentLike {
             AnyJsAssignmentLike::JsAssignmentExpression(assignment) => {
                 let left = assignment.left()?;
                 write!(f, [&left.format()])?;
+                if let Some(statement) = assignment.parent::<JsExpressionStatement>() {
+                    let padding = AnyJsAlignable::from(statement).padding(f);
+                    write!(f, [FormatAlignmentPadding(padding)])?;
+                }
                 Ok(false)
             }
             AnyJsAssignmentLike::JsObjectAssignmentPatternProperty(property) => {
@@ -429,6 +434,15 @@ impl AnyJsAssignmentLike {
                 let variable_annotation = variable_declarator.variable_annotation();
 
                 write!(f, [id.format(), variable_annotation.format()])?;
+                if let Some(statement) = variable_declarator
+                    .syntax()
+                    .grand_parent()
+                    .and_then(|declaration| declaration.parent())
+                    .and_then(JsVariableStatement::cast)
+                {
+                    let padding = AnyJsAlignable::from(statement).padding(f);
+                    write!(f, [FormatAlignmentPadding(padding)])?;
+                }
                 Ok(false)
             }
             AnyJsAssignmentLike::TsTypeAliasDeclaration(type_alias_declaration) => {
@@ -1070,6 +1084,16 @@ impl Format<JsFormatContext> for AnyJsAssignmentLike {
             let left = format_once(|f| f.write_elements(formatted_left));
             let right = format_with(|f| self.write_right(f, layout));
 
+            // The values of object members are aligned after the colon, but only when they
+            // are printed on the same line as the colon
+            let padding = match self {
+                AnyJsAssignmentLike::JsPropertyObjectMember(property) => {
+                    AnyJsAlignable::from(property.clone()).padding(f)
+                }
+                _ => 0,
+            };
+            let padding = FormatAlignmentPadding(padding);
+
             let inner_content = format_with(|f| {
                 if matches!(
                     &layout,
@@ -1094,20 +1118,27 @@ impl Format<JsFormatContext> for AnyJsAssignmentLike {
                             [
                                 group(&indent(&soft_line_break_or_space()))
                                     .with_group_id(Some(group_id)),
+                                if_group_fits_on_line(&padding).with_group_id(Some(group_id)),
                                 line_suffix_boundary(),
                                 indent_if_group_breaks(&right, group_id)
                             ]
                         ]
                     }
                     AssignmentLikeLayout::BreakAfterOperator => {
-                        write![f, [group(&soft_line_indent_or_space(&right))]]
+                        write![
+                            f,
+                            [group(&soft_line_indent_or_space(&format_args![
+                                if_group_fits_on_line(&padding),
+                                right
+                            ]))]
+                        ]
                     }
                     AssignmentLikeLayout::NeverBreakAfterOperator => {
-                        write![f, [space(), right]]
+                        write![f, [space(), padding, right]]
                     }
 
                     AssignmentLikeLayout::BreakLeftHandSide => {
-                        write![f, [space(), group(&right)]]
+                        write![f, [space(), padding, group(&right)]]
                     }
 
                     AssignmentLikeLayout::Chain => {
@@ -1122,7 +1153,7 @@ impl Format<JsFormatContext> for AnyJsAssignmentLike {
                     }
 
                     AssignmentLikeLayout::ChainTailArrowFunction => {
-                        write!(f, [space(), right])
+                        write!(f, [space(), padding, right])
                     }
                     AssignmentLikeLayout::SuppressedInitializer => {
                         self.write_suppressed_initializer(f)
@@ -0,0 +1,207 @@
+use crate::js::auxiliary::template_element::TemplateElementOptions;
+use crate::js::lists::template_element_list::TemplateElementIndention;
+use crate::prelude::*;
+use biome_css_formatter::context::CssFormatOptions;
+use biome_css_parser::{parse_css, CssParserOptions};
+use biome_formatter::printer::Printer;
+use biome_formatter::{
+    write, CstFormatContext, FormatOptions, IndentStyle, RemoveSoftLinesBuffer, VecBuffer,
+};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsTemplateElement, JsTemplateElementList, JsTemplateExpression,
+};
+
+/// The prefix of the identifiers that replace the `${}` elements in the embedded CSS
+const PLACEHOLDER: &str = "biome-placeholder-";
+
+/// The tags of the templates that contain CSS, such as ``css`color: red;` ``
+const CSS_TAGS: [&str; 4] = ["css", "createGlobalStyle", "injectGlobal", "keyframes"];
+
+/// Returns `true` if the template contains CSS, either because of its tag or because it's preceded by a `/* css */` comment.
+///
+/// ```javascript
+/// css`color: red;`;
+/// styled.div`color: red;`;
+/// styled(Button).attrs({ type: "button" })`color: red;`;
+/// /* css */ `color: red;`;
+/// ```
+pub(crate) fn is_embedded_css(template: &JsTemplateExpression) -> bool {
+    match template.tag() {
+        Some(AnyJsExpression::JsIdentifierExpression(identifier)) => identifier
+            .name()
+            .and_then(|name| name.value_token())
+            .is_ok_and(|name| CSS_TAGS.contains(&name.text_trimmed())),
+        Some(tag) => is_styled(tag),
+        None => template.l_tick_token().is_ok_and(|l_tick| {
+            l_tick
+                .leading_trivia()
+                .pieces()
+                .filter_map(|piece| piece.as_comments())
+                .last()
+                .is_some_and(|comment| {
+                    comment
+                        .text()
+                        .strip_prefix("/*")
+                        .and_then(|text| text.strip_suffix("*/"))
+                        .is_some_and(|text| text.trim().eq_ignore_ascii_case("css"))
+                })
+        }),
+    }
+}
+
+/// Returns `true` if the tag is a `styled` component factory, such as `styled.div` or `styled(Button).attrs({})`
+fn is_styled(tag: AnyJsExpression) -> bool {
+    match tag {
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .and_then(|name| name.value_token())
+            .is_ok_and(|name| name.text_trimmed() == "styled"),
+        AnyJsExpression::JsStaticMemberExpression(member) => member.object().is_ok_and(is_styled),
+        AnyJsExpression::JsCallExpression(call) => call.callee().is_ok_and(is_styled),
+        _ => false,
+    }
+}
+
+/// The CSS of a template, formatted with the `${}` elements replaced by placeholders.
+///
+/// The template is printed as written when its CSS doesn't parse, for example when an element is a whole declaration without a semicolon.
+/// It's also printed as written when it contains escapes: the CSS is formatted from the raw text of the template,
+/// in which `\\` is a single backslash once the template is evaluated.
+#[derive(Debug)]
+pub(crate) struct EmbeddedCss {
+    elements: JsTemplateElementList,
+    /// The formatted lines of the CSS, without indentation
+    lines: Vec<String>,
+}
+
+impl EmbeddedCss {
+    pub(crate) fn from(elements: &JsTemplateElementList, f: &JsFormatter) -> Option<Self> {
+        let mut source = String::new();
+        let mut placeholders = 0;
+        for element in elements {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    let chunk = chunk.template_chunk_token().ok()?;
+                    if chunk.text_trimmed().contains('\\') {
+                        return None;
+                    }
+                    source.push_str(chunk.text_trimmed());
+                }
+                AnyJsTemplateElement::JsTemplateElement(_) => {
+                    source.push_str(&std::format!("{PLACEHOLDER}{placeholders}"));
+                    placeholders += 1;
+                }
+            }
+        }
+        if source.trim().is_empty() || source.matches(PLACEHOLDER).count() != placeholders {
+            return None;
+        }
+
+        // The declarations are wrapped in a rule, so that the templates of the styled components parse
+        let parsed = parse_css(
+            &std::format!("{PLACEHOLDER}root {{\n{source}\n}}\n"),
+            CssParserOptions::default(),
+        );
+        if parsed.has_errors() {
+            return None;
+        }
+
+        let options = f.options();
+        let css_options = CssFormatOptions::default()
+            .with_indent_style(options.indent_style())
+            .with_indent_width(options.indent_width())
+            .with_line_width(options.line_width())
+            .with_quote_style(options.quote_style());
+        let formatted = biome_css_formatter::format_node(css_options, &parsed.syntax()).ok()?;
+        let printed = formatted.print().ok()?;
+
+        let indent = match options.indent_style() {
+            IndentStyle::Tab => "\t".to_string(),
+            IndentStyle::Space => " ".repeat(options.indent_width().value() as usize),
+        };
+        let code = printed.as_code().trim_end();
+        let mut lines = code.lines();
+        lines.next()?;
+        let mut lines = lines.collect::<Vec<_>>();
+        if lines.pop()? != "}" {
+            return None;
+        }
+        let lines = lines
+            .into_iter()
+            .map(|line| {
+                line.strip_prefix(indent.as_str())
+                    .unwrap_or(line)
+                    .to_string()
+            })
+            .collect();
+
+        Some(Self {
+            elements: elements.clone(),
+            lines,
+        })
+    }
+}
+
+impl Format<JsFormatContext> for EmbeddedCss {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        let mut elements = Vec::new();
+        for element in &self.elements {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    // The chunks can't have comments: the CSS replaces their text
+                    f.context()
+                        .comments()
+                        .mark_suppression_checked(chunk.syntax());
+
+                    write!(f, [format_removed(&chunk.template_chunk_token()?)])?;
+                }
+                AnyJsTemplateElement::JsTemplateElement(element) => {
+                    let mut vec_buffer = VecBuffer::new(f.state_mut());
+
+                    // The elements are printed on a single line when possible, like in the `test.each` tables
+                    let mut buffer = RemoveSoftLinesBuffer::new(&mut vec_buffer);
+
+                    let options = TemplateElementOptions {
+                        after_new_line: false,
+                        indention: TemplateElementIndention::default(),
+                    };
+                    write!(buffer, [element.format().with_options(options)])?;
+
+                    let root = Document::from(vec_buffer.into_vec());
+                    let print_options = f.options().as_print_options();
+                    let printed = Printer::new(print_options).print(&root)?;
+                    elements.push(printed.into_code());
+                }
+            }
+        }
+
+        let start = self.elements.syntax().text_range().start();
+        let content = format_with(|f| {
+            let mut is_after_empty_line = false;
+            for (index, line) in self.lines.iter().enumerate() {
+                if line.is_empty() {
+                    is_after_empty_line = true;
+                    continue;
+                }
+                if index > 0 {
+                    if is_after_empty_line {
+                        write!(f, [empty_line()])?;
+                    } else {
+                        write!(f, [hard_line_break()])?;
+                    }
+                }
+                is_after_empty_line = false;
+
+                // The placeholders are replaced from the last one, so that `biome-placeholder-1` doesn't replace the start of `biome-placeholder-10`
+                let mut line = line.clone();
+                for (index, element) in elements.iter().enumerate().rev() {
+                    line = line.replace(&std::format!("{PLACEHOLDER}{index}"), element);
+                }
+                write!(f, [dynamic_text(&line, start)])?;
+            }
+            Ok(())
+        });
+
+        write!(f, [block_indent(&content)])
+    }
+}
@@ -1,6 +1,7 @@
 pub(crate) mod array;
 mod assignment_like;
 mod conditional;
+pub(crate) mod embedded_css;
 mod format_binary_like_expression;
 pub mod string_utils;
 
@@ -17,6 +18,7 @@ mod object_pattern_like;
 mod quickcheck_utils;
 pub(crate) mod test_each_template;
 mod typescript;
+pub(crate) mod vertical_alignment;
 
 use crate::context::trailing_commas::FormatTrailingCommas;
 use crate::context::Semicolons;
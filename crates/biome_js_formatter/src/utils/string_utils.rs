@@ -1,6 +1,6 @@
-use crate::context::{JsFormatOptions, QuoteProperties};
+use crate::context::{JsFormatOptions, QuoteProperties, StringEscapes};
 use crate::prelude::*;
-use biome_formatter::token::string::normalize_string;
+use biome_formatter::token::string::{normalize_string, uppercase_hex_escapes};
 use biome_formatter::QuoteStyle;
 use biome_js_syntax::JsSyntaxKind::{JSX_STRING_LITERAL, JS_STRING_LITERAL};
 use biome_js_syntax::{JsFileSource, JsSyntaxToken};
@@ -53,8 +53,12 @@ impl<'token> FormatLiteralStringToken<'token> {
         };
         let chosen_quote_properties = options.quote_properties();
 
-        let mut string_cleaner =
-            LiteralStringNormaliser::new(self, chosen_quote_style, chosen_quote_properties);
+        let mut string_cleaner = LiteralStringNormaliser::new(
+            self,
+            chosen_quote_style,
+            chosen_quote_properties,
+            options.string_escapes(),
+        );
 
         let content = string_cleaner.normalise_text(options.source_type().into());
         let normalized_text_width = content.width();
@@ -193,6 +197,8 @@ struct LiteralStringNormaliser<'token> {
     chosen_quote_style: QuoteStyle,
     /// When properties in objects are quoted that was set inside the configuration
     chosen_quote_properties: QuoteProperties,
+    /// Whether the unnecessary escapes are removed that was set inside the configuration
+    string_escapes: StringEscapes,
 }
 
 /// Convenience enum to map [biome_js_syntax::JsFileSource] by just reading
@@ -218,11 +224,13 @@ impl<'token> LiteralStringNormaliser<'token> {
         token: &'token FormatLiteralStringToken<'_>,
         chosen_quote_style: QuoteStyle,
         chosen_quote_properties: QuoteProperties,
+        string_escapes: StringEscapes,
     ) -> Self {
         Self {
             token,
             chosen_quote_style,
             chosen_quote_properties,
+            string_escapes,
         }
     }
 
@@ -334,12 +342,21 @@ impl<'token> LiteralStringNormaliser<'token> {
     }
 
     fn normalize_string(&self, string_information: &StringInformation) -> Cow<'token, str> {
-        let is_escape_preserved = self.token.token.kind() == JSX_STRING_LITERAL;
-        normalize_string(
+        // Backslashes don't start escape sequences in JSX strings
+        let is_jsx = self.token.token.kind() == JSX_STRING_LITERAL;
+        let is_escape_preserved = is_jsx || self.string_escapes.is_preserve();
+        let normalized = normalize_string(
             self.raw_content(),
             string_information.preferred_quote.into(),
             is_escape_preserved,
-        )
+        );
+        if is_jsx || !self.string_escapes.is_normalize() {
+            return normalized;
+        }
+        match normalized {
+            Cow::Borrowed(normalized) => uppercase_hex_escapes(normalized),
+            Cow::Owned(normalized) => Cow::Owned(uppercase_hex_escapes(&normalized).into_owned()),
+        }
     }
 
     /// Returns the string without its quotes.
@@ -448,8 +465,12 @@ mod tests {
     ) {
         let token = generate_syntax_token(input);
         let string_token = as_token.into_token(&token);
-        let mut string_cleaner =
-            LiteralStringNormaliser::new(&string_token, quote, quote_properties);
+        let mut string_cleaner = LiteralStringNormaliser::new(
+            &string_token,
+            quote,
+            quote_properties,
+            StringEscapes::default(),
+        );
         let content = string_cleaner.normalise_text(source);
         assert_eq!(content, Cow::Borrowed(input))
     }
@@ -464,8 +485,12 @@ mod tests {
     ) {
         let token = generate_syntax_token(input);
         let string_token = as_token.into_token(&token);
-        let mut string_cleaner =
-            LiteralStringNormaliser::new(&string_token, quote, quote_properties);
+        let mut string_cleaner = LiteralStringNormaliser::new(
+            &string_token,
+            quote,
+            quote_properties,
+            StringEscapes::default(),
+        );
         let content = string_cleaner.normalise_text(source);
         let owned: Cow<str> = Cow::Owned(output.to_string());
         assert_eq!(content, owned)
@@ -0,0 +1,214 @@
+use crate::context::JsFormatOptions;
+use crate::prelude::*;
+use crate::utils::{FormatLiteralStringToken, StringLiteralParentKind};
+use biome_formatter::write;
+use biome_js_syntax::{
+    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern, AnyJsExpression,
+    AnyJsObjectMemberName, JsAssignmentOperator, JsExpressionStatement, JsObjectExpression,
+    JsPropertyObjectMember, JsSyntaxKind, JsSyntaxNode, JsVariableStatement, T,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, Direction};
+use unicode_width::UnicodeWidthStr;
+
+declare_node_union! {
+    /// A node whose value can be vertically aligned with the values of its siblings.
+    ///
+    /// ```javascript
+    /// const a      = 1;
+    /// const abcdef = 2;
+    ///
+    /// a.b     = 1;
+    /// a.bcdef = 2;
+    ///
+    /// const object = {
+    ///     a:      1,
+    ///     abcdef: 2,
+    /// };
+    /// ```
+    pub(crate) AnyJsAlignable = JsVariableStatement | JsExpressionStatement | JsPropertyObjectMember
+}
+
+impl AnyJsAlignable {
+    /// Returns the width of the part of the node that precedes the aligned operator or value,
+    /// or `None` if the node doesn't take part in the alignment.
+    ///
+    /// Only simple nodes that fit on a single line are aligned: the widths are computed from the source
+    /// and are thus only reliable if the formatter prints the left side as is.
+    fn key_width(&self, options: &JsFormatOptions) -> Option<usize> {
+        let syntax = self.syntax();
+        if syntax.text_trimmed().contains_char('\n') || has_inner_comments(syntax) {
+            return None;
+        }
+
+        match self {
+            AnyJsAlignable::JsVariableStatement(statement) => {
+                let declaration = statement.declaration().ok()?;
+                if declaration.await_token().is_some() {
+                    return None;
+                }
+                let declarators = declaration.declarators();
+                if declarators.len() != 1 {
+                    return None;
+                }
+                let declarator = declarators.first()?.ok()?;
+                if declarator.variable_annotation().is_some() || declarator.initializer().is_none()
+                {
+                    return None;
+                }
+                let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) =
+                    declarator.id().ok()?
+                else {
+                    return None;
+                };
+                let kind = declaration.kind().ok()?;
+                let name = binding.name_token().ok()?;
+                Some(kind.text_trimmed().width() + 1 + name.text_trimmed().width())
+            }
+            AnyJsAlignable::JsExpressionStatement(statement) => {
+                let AnyJsExpression::JsAssignmentExpression(assignment) =
+                    statement.expression().ok()?
+                else {
+                    return None;
+                };
+                if assignment.operator().ok()? != JsAssignmentOperator::Assign {
+                    return None;
+                }
+                let left = match assignment.left().ok()? {
+                    AnyJsAssignmentPattern::AnyJsAssignment(
+                        left @ (AnyJsAssignment::JsIdentifierAssignment(_)
+                        | AnyJsAssignment::JsStaticMemberAssignment(_)),
+                    ) => left,
+                    _ => return None,
+                };
+                // Only accept plain member chains such as `this.a.b`, whose formatting can't change
+                let mut width = 0;
+                for token in left.syntax().descendants_tokens(Direction::Next) {
+                    if !matches!(token.kind(), JsSyntaxKind::IDENT | T![this] | T![.] | T![#]) {
+                        return None;
+                    }
+                    width += token.text_trimmed().width();
+                }
+                Some(width)
+            }
+            AnyJsAlignable::JsPropertyObjectMember(member) => {
+                // Members of objects that may be printed on a single line can't be aligned
+                let object = member
+                    .syntax()
+                    .grand_parent()
+                    .and_then(JsObjectExpression::cast)?;
+                if !object.members().syntax().has_leading_newline() {
+                    return None;
+                }
+                let AnyJsObjectMemberName::JsLiteralMemberName(name) = member.name().ok()? else {
+                    return None;
+                };
+                let name = name.value().ok()?;
+                match name.kind() {
+                    JsSyntaxKind::IDENT => Some(name.text_trimmed().width()),
+                    // The quotes of the name may be removed or normalized
+                    JsSyntaxKind::JS_STRING_LITERAL => Some(
+                        FormatLiteralStringToken::new(&name, StringLiteralParentKind::Member)
+                            .clean_text(options)
+                            .width(),
+                    ),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Returns the number of spaces to insert after the key of this node so that its value lines up
+    /// with the values of its siblings.
+    pub(crate) fn padding(&self, f: &mut JsFormatter) -> usize {
+        if !f.options().vertical_alignment().value() {
+            return 0;
+        }
+
+        let range = self.range();
+        if let Some(padding) = f.context().get_vertical_alignment_padding(range) {
+            return padding;
+        }
+
+        let run = self.aligned_run(f.options());
+        let max_width = run.iter().map(|(_, width)| *width).max().unwrap_or(0);
+        f.context_mut().set_vertical_alignment_paddings(
+            run.iter()
+                .map(|(node, width)| (node.range(), max_width - width)),
+        );
+        f.context()
+            .get_vertical_alignment_padding(range)
+            .unwrap_or(0)
+    }
+
+    /// Returns the consecutive siblings of the same kind that are aligned together with this node,
+    /// alongside their key widths.
+    ///
+    /// A run ends at a node that can't be aligned or that is preceded by an empty line.
+    fn aligned_run(&self, options: &JsFormatOptions) -> Vec<(AnyJsAlignable, usize)> {
+        let Some(width) = self.key_width(options) else {
+            return vec![(self.clone(), 0)];
+        };
+
+        let kind = self.syntax().kind();
+        let alignable_sibling = |node: JsSyntaxNode| {
+            if node.kind() != kind {
+                return None;
+            }
+            let node = AnyJsAlignable::unwrap_cast(node);
+            let width = node.key_width(options)?;
+            Some((node, width))
+        };
+
+        let mut preceding = Vec::new();
+        let mut current = self.syntax().clone();
+        for sibling in self.syntax().siblings(Direction::Prev).skip(1) {
+            if get_lines_before(&current) > 1 {
+                break;
+            }
+            let Some(entry) = alignable_sibling(sibling.clone()) else {
+                break;
+            };
+            preceding.push(entry);
+            current = sibling;
+        }
+
+        let mut run: Vec<_> = preceding.into_iter().rev().collect();
+        run.push((self.clone(), width));
+
+        for sibling in self.syntax().siblings(Direction::Next).skip(1) {
+            if get_lines_before(&sibling) > 1 {
+                break;
+            }
+            let Some(entry) = alignable_sibling(sibling) else {
+                break;
+            };
+            run.push(entry);
+        }
+
+        run
+    }
+}
+
+/// Returns `true` if `node` has comments other than the leading comments of its first token
+/// and the trailing comments of its last token.
+fn has_inner_comments(node: &JsSyntaxNode) -> bool {
+    let first = node.first_token();
+    let last = node.last_token();
+    node.descendants_tokens(Direction::Next).any(|token| {
+        (Some(&token) != first.as_ref() && token.has_leading_comments())
+            || (Some(&token) != last.as_ref() && token.has_trailing_comments())
+    })
+}
+
+/// Formats the spaces that vertically align a node with its siblings.
+pub(crate) struct FormatAlignmentPadding(pub(crate) usize);
+
+impl Format<JsFormatContext> for FormatAlignmentPadding {
+    fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        // Spaces can't be used here because consecutive spaces collapse into a single one
+        for _ in 0..self.0 {
+            write!(f, [text(" ")])?;
+        }
+        Ok(())
+    }
+}
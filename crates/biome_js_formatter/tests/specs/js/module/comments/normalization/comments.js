@@ -0,0 +1,55 @@
+//A line comment without a space
+// A line comment with a space
+/// <reference path="./types.d.ts" />
+//# sourceMappingURL=index.js.map
+//#region
+//
+
+/** A documentation comment
+    that doesn't start its lines with a star */
+function documented() {}
+
+/**
+ * A documentation comment whose paragraph is long enough to be reflowed to the line width of the formatter.
+ *
+ * A second paragraph, split
+ * on short lines.
+ *
+ * - A list item
+ * - Another list item that is long enough to be reflowed to the line width of the formatter.
+ *
+ * ```js
+ * const kept = "code blocks are kept as they are, even when they are longer than the line width";
+ * ```
+ *
+ * @param {string} name A tag with a description that is longer than the line width of the formatter.
+ * @returns {string}
+ */
+function reflowed(name) {
+	/*
+	 * An indented block comment that is long enough to be reflowed to the line width of the formatter.
+	 */
+	return name;
+}
+
+/** A single line documentation comment stays on one line. */
+const single = 1;
+
+const trailing = 1; //A trailing line comment
+
+function dangling() {
+	//A dangling line comment
+}
+
+function danglingDocumentation() {
+	/** A dangling documentation comment
+	    without stars */
+}
+
+/*!
+ * A legal comment that is long enough to be reflowed to the line width of the formatter, but is kept.
+ */
+
+/*****************************************
+ * A decorated comment is kept as it is. *
+ *****************************************/
@@ -0,0 +1,10 @@
+import { a } from "a";
+import { a, b } from "ab";
+import { a, b, c } from "abc";
+import Default, { a, b, c } from "abc";
+import { a as x, b as y, c as z } from "abc";
+
+export { d, e };
+export { d, e, f };
+export { a } from "a";
+export { a, b, c } from "abc";
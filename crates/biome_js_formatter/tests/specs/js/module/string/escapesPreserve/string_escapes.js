@@ -0,0 +1,5 @@
+const unnecessary = "unnecessary \d \a escapes";
+const quotes = 'unnecessary \d escapes and \' quote';
+const unicode = "unicode é \u{1f600} \xe9 \\u00e9 escapes";
+const object = { "\d": "\d" };
+<div title="\d é" />;
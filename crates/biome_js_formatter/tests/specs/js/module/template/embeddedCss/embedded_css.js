@@ -0,0 +1,27 @@
+const Button = styled.button`
+color:${(props) => props.color};
+  padding : 4px   8px;
+&:hover{opacity:0.8}
+`;
+
+const title = css`font-size: ${size}px;`;
+
+const globalStyle = /* css */ `
+body{margin:0}
+`;
+
+// The mixins without a semicolon aren't valid CSS, so the template is printed as written
+const mixin = css`
+  ${truncate}
+  color: red;
+`;
+
+// The escapes of the raw text aren't CSS escapes, so the template is printed as written
+const quote = css`
+&::before{content:"\\201C"}
+`;
+
+const notCss = html`<div>${content}</div>`;
+
+// Only the CSS is formatted
+const notCssEither = /* html */ `<p>${text}</p>`;
@@ -0,0 +1,59 @@
+const a = 1;
+const abc = 2;
+let abcdef = 3;
+var ab = "a";
+
+const separated = 1;
+const byEmptyLine = 2;
+
+const first = 1;
+// comment
+const second = 2; // trailing
+
+const x = 1, y = 2;
+const notAligned = 3;
+
+const multiline = {
+	a: 1,
+};
+const afterMultiline = 2;
+
+a = 1;
+abc.def = 2;
+this.#private = 3;
+a += 4;
+b = 5;
+
+const object = {
+	a: 1,
+	abc: "abc",
+	abcdef: true,
+	method() {},
+	key: value,
+	"quoted": 1,
+	longerKey: 2,
+
+	afterEmptyLine: 1,
+	b: 2,
+	shorthand,
+	c: 3,
+	nested: {
+		x: 1,
+		xyz: 2,
+	},
+	d: 4,
+	ef: () => {},
+};
+
+const inline = { a: 1, abcdef: 2 };
+
+const long = {
+	a: "a very long string that doesn't fit on the line with the other values",
+	abcdefghijklmnop: "another very long string that won't fit on the same line",
+};
+
+function f() {
+	let i = 0;
+	let count = 10;
+	i = count;
+}
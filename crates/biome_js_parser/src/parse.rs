@@ -129,9 +129,18 @@ fn parse_common(
     text: &str,
     source_type: JsFileSource,
     options: JsParserOptions,
+) -> (Vec<Event<JsSyntaxKind>>, Vec<ParseDiagnostic>, Vec<Trivia>) {
+    parse_common_with(text, source_type, options, syntax::program::parse)
+}
+
+fn parse_common_with(
+    text: &str,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+    parse_root: fn(&mut JsParser) -> CompletedMarker,
 ) -> (Vec<Event<JsSyntaxKind>>, Vec<ParseDiagnostic>, Vec<Trivia>) {
     let mut parser = JsParser::new(text, source_type, options);
-    syntax::program::parse(&mut parser);
+    parse_root(&mut parser);
 
     let (events, trivia, errors) = parser.finish();
 
@@ -249,6 +258,41 @@ pub fn parse(text: &str, source_type: JsFileSource, options: JsParserOptions) ->
     parse_js_with_cache(text, source_type, options, &mut cache)
 }
 
+/// Parses the provided string as a single expression, using the provided syntax features.
+///
+/// The root of the tree is a [biome_js_syntax::JsExpressionSnipped]. This is used to parse the expressions embedded
+/// in other languages, such as the values of the attributes of a Vue template.
+///
+/// ### Examples
+///
+/// ```
+/// use biome_js_parser::{JsParserOptions, parse_expression};
+/// use biome_js_syntax::JsFileSource;
+/// let parsed = parse_expression("handler($event)", JsFileSource::js_module(), JsParserOptions::default());
+/// assert_eq!(parsed.diagnostics().len(), 0);
+///
+/// // The statements aren't expressions
+/// let parsed = parse_expression("a(); b()", JsFileSource::js_module(), JsParserOptions::default());
+/// assert!(parsed.has_errors());
+/// ```
+pub fn parse_expression(
+    text: &str,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+) -> Parse<AnyJsRoot> {
+    let mut cache = NodeCache::default();
+    let (events, errors, tokens) = parse_common_with(
+        text,
+        source_type,
+        options,
+        syntax::program::parse_expression_snipped,
+    );
+    let mut tree_sink = JsLosslessTreeSink::with_cache(text, &tokens, &mut cache);
+    biome_parser::event::process(&mut tree_sink, events, errors);
+    let (green, parse_errors) = tree_sink.finish();
+    Parse::new(green, parse_errors)
+}
+
 /// Parses the provided string as a EcmaScript program using the provided syntax features and node cache.
 ///
 /// ### Examples
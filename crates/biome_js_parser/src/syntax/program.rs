@@ -1,5 +1,7 @@
 //! Top level functions for parsing a script or module, also includes module specific items.
 
+use super::expr::{parse_expression, ExpressionContext};
+use super::js_parse_error::expected_expression;
 use super::module::parse_module_body;
 use super::stmt::parse_statements;
 use crate::prelude::*;
@@ -36,3 +38,21 @@ pub(crate) fn parse(p: &mut JsParser) -> CompletedMarker {
 
     result
 }
+
+/// Parses a single expression that spans the whole source, such as the value of an attribute of a Vue template
+pub(crate) fn parse_expression_snipped(p: &mut JsParser) -> CompletedMarker {
+    let m = p.start();
+
+    parse_expression(p, ExpressionContext::default()).or_add_diagnostic(p, expected_expression);
+
+    if !p.at(EOF) {
+        let unexpected = p.start();
+        while !p.at(EOF) {
+            p.bump_any();
+        }
+        let unexpected = unexpected.complete(p, JS_BOGUS);
+        p.error(p.err_builder("Expected the end of the expression", unexpected.range(p)));
+    }
+
+    m.complete(p, JS_EXPRESSION_SNIPPED)
+}
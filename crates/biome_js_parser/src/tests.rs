@@ -1,5 +1,8 @@
 use crate::test_utils::has_bogus_nodes_or_empty_slots;
-use crate::{parse, parse_module, test_utils::assert_errors_are_absent, JsParserOptions, Parse};
+use crate::{
+    parse, parse_expression, parse_module, test_utils::assert_errors_are_absent, JsParserOptions,
+    Parse,
+};
 use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::markup;
 use biome_diagnostics::DiagnosticExt;
@@ -325,6 +328,35 @@ fn parser_regexp_after_operator() {
     assert_no_errors(r#"a!==/a/"#);
 }
 
+#[test]
+fn parser_expression_snipped() {
+    let parse = parse_expression(
+        "handler($event, (a) => a + 1)",
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+    );
+    assert_errors_are_absent(&parse, Path::new("parser_expression_snipped"));
+    assert_eq!(parse.syntax().kind(), JsSyntaxKind::JS_EXPRESSION_SNIPPED);
+    assert!(!has_bogus_nodes_or_empty_slots(&parse.syntax()));
+
+    let parse = parse_expression(
+        "count++; other()",
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+    );
+    assert!(parse.has_errors());
+
+    let parse = parse_expression("", JsFileSource::js_module(), JsParserOptions::default());
+    assert!(parse.has_errors());
+
+    let parse = parse_expression(
+        "value as string",
+        JsFileSource::ts(),
+        JsParserOptions::default(),
+    );
+    assert_errors_are_absent(&parse, Path::new("parser_expression_snipped"));
+}
+
 #[test]
 pub fn node_contains_trailing_comments() {
     let text = "true && (3 - 2 == 0) // comment";
@@ -1,6 +1,7 @@
 mod binding;
 mod builder;
 mod closure;
+mod directives;
 mod globals;
 mod import;
 mod is_constant;
@@ -30,6 +31,7 @@ use std::{
 pub use binding::*;
 pub use builder::*;
 
+pub use directives::*;
 pub use globals::*;
 pub use import::*;
 pub use is_constant::*;
@@ -55,6 +57,9 @@ pub fn semantic_model(root: &AnyJsRoot, options: SemanticModelOptions) -> Semant
     for global in globals {
         builder.push_global(global);
     }
+    for global in globals_from_directives(root) {
+        builder.push_directive_global(global);
+    }
 
     let root = root.syntax();
     for node in root.preorder() {
@@ -16,6 +16,8 @@ pub struct SemanticModelBuilder {
     scope_node_by_range: FxHashMap<TextRange, JsSyntaxNode>,
     globals: Vec<SemanticModelGlobalBindingData>,
     globals_by_name: FxHashMap<String, Option<u32>>,
+    /// Globals declared by a `biome-globals` comment directive
+    directive_globals: FxHashSet<String>,
     scopes: Vec<SemanticModelScopeData>,
     scope_range_by_start: FxHashMap<TextSize, BTreeSet<Interval<u32, ScopeId>>>,
     scope_hoisted_to_by_range: FxHashMap<TextSize, ScopeId>,
@@ -36,6 +38,7 @@ impl SemanticModelBuilder {
             scope_node_by_range: FxHashMap::default(),
             globals: vec![],
             globals_by_name: FxHashMap::default(),
+            directive_globals: FxHashSet::default(),
             scopes: vec![],
             scope_range_by_start: FxHashMap::default(),
             scope_hoisted_to_by_range: FxHashMap::default(),
@@ -122,6 +125,16 @@ impl SemanticModelBuilder {
         self.globals_by_name.insert(name.into(), None);
     }
 
+    /// Registers a global declared by a `biome-globals` comment directive of the file.
+    ///
+    /// See [globals_from_directives].
+    #[inline]
+    pub fn push_directive_global(&mut self, name: impl Into<String>) {
+        let name = name.into();
+        self.globals_by_name.insert(name.clone(), None);
+        self.directive_globals.insert(name);
+    }
+
     #[inline]
     pub fn push_event(&mut self, e: SemanticEvent) {
         use SemanticEvent::*;
@@ -334,6 +347,7 @@ impl SemanticModelBuilder {
             exported: self.exported,
             unresolved_references: self.unresolved_references,
             globals: self.globals,
+            directive_globals: self.directive_globals,
         };
         SemanticModel::new(data)
     }
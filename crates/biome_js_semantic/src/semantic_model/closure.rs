@@ -84,6 +84,7 @@ pub struct Capture {
     ty: CaptureType,
     node: JsSyntaxNode,
     binding_id: BindingId,
+    reference_id: ReferenceId,
 }
 
 impl Capture {
@@ -105,6 +106,14 @@ impl Capture {
         }
     }
 
+    /// Returns the reference of this capture
+    pub fn reference(&self) -> Reference {
+        Reference {
+            data: self.data.clone(),
+            id: self.reference_id,
+        }
+    }
+
     /// Returns the trimmed text range of the declaration of this capture.
     /// This is equivalent, but faster, to:
     ///
@@ -121,6 +130,8 @@ pub struct AllCapturesIter {
     closure_range: TextRange,
     scopes: Vec<ScopeId>,
     references: Vec<ReferenceId>,
+    /// Whether the references of the descendent closures are visited
+    include_descendents: bool,
 }
 
 impl Iterator for AllCapturesIter {
@@ -128,16 +139,17 @@ impl Iterator for AllCapturesIter {
 
     fn next(&mut self) -> Option<Self::Item> {
         'references: loop {
-            while let Some(reference) = self.references.pop() {
-                let binding_id = reference.binding_id();
+            while let Some(reference_id) = self.references.pop() {
+                let binding_id = reference_id.binding_id();
                 let binding = &self.data.binding(binding_id);
                 if !self.closure_range.contains(binding.range.start()) {
-                    let reference = &binding.references[reference.index()];
+                    let reference = &binding.references[reference_id.index()];
                     return Some(Capture {
                         data: self.data.clone(),
                         node: self.data.binding_node_by_start[&reference.range_start].clone(), // TODO change node to store the range
                         ty: CaptureType::ByReference,
                         binding_id,
+                        reference_id,
                     });
                 }
             }
@@ -145,7 +157,7 @@ impl Iterator for AllCapturesIter {
             'scopes: while let Some(scope_id) = self.scopes.pop() {
                 let scope = &self.data.scopes[scope_id.index()];
 
-                if scope.is_closure {
+                if scope.is_closure && !self.include_descendents {
                     continue 'scopes;
                 }
                 self.references.clear();
@@ -263,6 +275,27 @@ impl Closure {
     /// assert!(model.closure(function_f).all_captures(), &["a"]);
     /// ```
     pub fn all_captures(&self) -> impl Iterator<Item = Capture> {
+        self.captures_iter(false)
+    }
+
+    /// Return all [Capture] of this closure and of its descendent closures,
+    /// whose binding is declared outside of this closure.
+    ///
+    /// ```rust,ignore
+    /// let inner_function = "let a, b;
+    /// function f(c) {
+    ///     console.log(a);
+    ///     function g() {
+    ///         console.log(b, c);
+    ///     }
+    /// }";
+    /// assert!(model.closure(function_f).all_captures_including_descendents(), &["a", "b"]);
+    /// ```
+    pub fn all_captures_including_descendents(&self) -> impl Iterator<Item = Capture> {
+        self.captures_iter(true)
+    }
+
+    fn captures_iter(&self, include_descendents: bool) -> AllCapturesIter {
         let scope = &self.data.scopes[self.scope_id.index()];
 
         let scopes = scope.children.clone();
@@ -275,9 +308,53 @@ impl Closure {
             closure_range: self.closure_range(),
             scopes,
             references,
+            include_descendents,
         }
     }
 
+    /// Return the bindings of the enclosing scopes that this closure or its descendent closures capture.
+    /// Every binding is returned once, in the order of the declarations.
+    ///
+    /// ```rust,ignore
+    /// let inner_function = "let a, b;
+    /// function f(c) {
+    ///     console.log(a, a);
+    ///     return () => b;
+    /// }";
+    /// assert!(model.closure(function_f).captured_bindings(), &["a", "b"]);
+    /// ```
+    pub fn captured_bindings(&self) -> impl Iterator<Item = Binding> {
+        let mut binding_ids = self
+            .all_captures_including_descendents()
+            .map(|capture| capture.binding_id)
+            .collect::<Vec<_>>();
+        binding_ids.sort_unstable_by_key(|binding_id| self.data.binding(*binding_id).range.start());
+        binding_ids.dedup();
+        let data = self.data.clone();
+        binding_ids.into_iter().map(move |id| Binding {
+            data: data.clone(),
+            id,
+        })
+    }
+
+    /// Returns `true` if this closure or one of its descendent closures captures `binding`.
+    ///
+    /// ```rust,ignore
+    /// let inner_function = "for (let i = 0; i < 10; i++) {
+    ///     setTimeout(() => console.log(i));
+    /// }";
+    /// assert!(model.closure(arrow_function).captures(binding_i));
+    /// ```
+    pub fn captures(&self, binding: &Binding) -> bool {
+        let closure_range = self.closure_range();
+        let binding = self.data.binding(binding.id);
+        !closure_range.contains(binding.range.start())
+            && binding
+                .references
+                .iter()
+                .any(|reference| closure_range.contains(reference.range_start))
+    }
+
     /// Return all immediate children closures of this closure.
     ///
     /// ```rust,ignore
@@ -346,29 +423,7 @@ mod test {
         let r = biome_js_parser::parse(code, JsFileSource::tsx(), JsParserOptions::default());
         let model = semantic_model(&r.tree(), SemanticModelOptions::default());
 
-        let closure = if name != "ARROWFUNCTION" {
-            let node = r
-                .syntax()
-                .descendants()
-                .filter(|x| x.text_trimmed() == name)
-                .last()
-                .unwrap();
-            let node = node
-                .parent()
-                .and_then(|node| AnyHasClosureNode::from_node(&node))
-                .unwrap();
-            model.closure(&node)
-        } else {
-            let node = r
-                .syntax()
-                .descendants()
-                .filter(|x| x.kind() == JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION)
-                .last()
-                .unwrap()
-                .cast::<JsArrowFunctionExpression>()
-                .unwrap();
-            model.closure(&node)
-        };
+        let closure = get_closure(&model, &r.syntax(), name);
 
         let expected_captures: BTreeSet<String> =
             captures.iter().map(|x| (*x).to_string()).collect();
@@ -385,13 +440,9 @@ mod test {
         assert_eq!(intersection_count, all_captures.len());
     }
 
-    fn get_closure_children(code: &str, name: &str) -> Vec<Closure> {
-        let r = biome_js_parser::parse(code, JsFileSource::tsx(), JsParserOptions::default());
-        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
-
-        let closure = if name != "ARROWFUNCTION" {
-            let node = r
-                .syntax()
+    fn get_closure(model: &SemanticModel, root: &JsSyntaxNode, name: &str) -> Closure {
+        if name != "ARROWFUNCTION" {
+            let node = root
                 .descendants()
                 .filter(|x| x.text_trimmed() == name)
                 .last()
@@ -402,8 +453,7 @@ mod test {
                 .unwrap();
             model.closure(&node)
         } else {
-            let node = r
-                .syntax()
+            let node = root
                 .descendants()
                 .filter(|x| x.kind() == JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION)
                 .last()
@@ -411,9 +461,31 @@ mod test {
                 .cast::<JsArrowFunctionExpression>()
                 .unwrap();
             model.closure(&node)
-        };
+        }
+    }
 
-        closure.children().collect()
+    fn get_closure_children(code: &str, name: &str) -> Vec<Closure> {
+        let r = biome_js_parser::parse(code, JsFileSource::tsx(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+        get_closure(&model, &r.syntax(), name).children().collect()
+    }
+
+    fn assert_captured_bindings(code: &str, name: &str, bindings: &[&str]) {
+        let r = biome_js_parser::parse(code, JsFileSource::tsx(), JsParserOptions::default());
+        let model = semantic_model(&r.tree(), SemanticModelOptions::default());
+        let closure = get_closure(&model, &r.syntax(), name);
+
+        let captured_bindings: Vec<String> = closure
+            .captured_bindings()
+            .map(|binding| binding.syntax().text_trimmed().to_string())
+            .collect();
+        assert_eq!(captured_bindings, bindings);
+
+        for binding in model.all_bindings() {
+            let is_captured =
+                bindings.contains(&binding.syntax().text_trimmed().to_string().as_str());
+            assert_eq!(closure.captures(&binding), is_captured);
+        }
     }
 
     #[test]
@@ -479,4 +551,33 @@ mod test {
         assert_closure(object_callables, "getValue", &["a"]);
         assert_closure(object_callables, "setValue", &["a"]);
     }
+
+    #[test]
+    pub fn ok_semantic_model_closure_captured_bindings() {
+        assert_captured_bindings("let a; function f() { let b; b = 1; }", "f", &[]);
+
+        let duplicated_captures = "let a, b; function f(c) { console.log(b, a, b, c); }";
+        assert_captured_bindings(duplicated_captures, "f", &["a", "b"]);
+
+        let inner_function = "let a, b;
+        function f(c) {
+            console.log(a);
+            function g() {
+                console.log(b, c);
+            }
+        }";
+        assert_captured_bindings(inner_function, "f", &["a", "b"]);
+        assert_captured_bindings(inner_function, "g", &["b", "c"]);
+
+        let loop_variable = "for (let i = 0; i < 10; i++) {
+            setTimeout(() => console.log(i));
+        }";
+        assert_captured_bindings(loop_variable, "ARROWFUNCTION", &["i"]);
+
+        let writes = "let a;
+        const f = () => {
+            a = 1;
+        }";
+        assert_captured_bindings(writes, "ARROWFUNCTION", &["a"]);
+    }
 }
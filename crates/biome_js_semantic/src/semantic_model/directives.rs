@@ -0,0 +1,82 @@
+use biome_js_syntax::{AnyJsRoot, JsLanguage};
+use biome_rowan::{AstNode, Direction, SyntaxTriviaPiece};
+
+/// Name of the comment directive that declares globals for a single file.
+///
+/// ```js
+/// /* biome-globals myGlobal, anotherGlobal */
+/// ```
+const GLOBALS_DIRECTIVE: &str = "biome-globals";
+
+/// Returns the names declared by all the `biome-globals` comment directives of `root`.
+pub fn globals_from_directives(root: &AnyJsRoot) -> Vec<String> {
+    let mut globals = Vec::new();
+    for token in root.syntax().descendants_tokens(Direction::Next) {
+        let pieces = token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces());
+        for piece in pieces {
+            globals.extend(globals_from_comment(&piece));
+        }
+    }
+    globals
+}
+
+fn globals_from_comment(piece: &SyntaxTriviaPiece<JsLanguage>) -> Vec<String> {
+    let Some(comment) = piece.as_comments() else {
+        return Vec::new();
+    };
+    parse_globals_directive(comment.text())
+        .into_iter()
+        .flat_map(|names| names.split(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Returns the list of names of a `biome-globals` directive, if `comment` is such a directive.
+fn parse_globals_directive(comment: &str) -> Option<&str> {
+    let content = if let Some(content) = comment.strip_prefix("//") {
+        content
+    } else {
+        comment.strip_prefix("/*")?.strip_suffix("*/")?
+    };
+    let names = content.trim_start().strip_prefix(GLOBALS_DIRECTIVE)?;
+    // Reject directives such as `biome-globalsfoo`
+    if names.is_empty() || names.starts_with(char::is_whitespace) {
+        Some(names)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+
+    fn globals(code: &str) -> Vec<String> {
+        let parsed = parse(code, JsFileSource::js_module(), JsParserOptions::default());
+        globals_from_directives(&parsed.tree())
+    }
+
+    #[test]
+    fn parses_block_and_line_directives() {
+        assert_eq!(
+            globals("/* biome-globals a, b */\n// biome-globals c\nlet x;"),
+            ["a", "b", "c"]
+        );
+        assert_eq!(globals("/*biome-globals  a,b,,c */"), ["a", "b", "c"]);
+        assert_eq!(globals("f(/* biome-globals a */);"), ["a"]);
+    }
+
+    #[test]
+    fn ignores_other_comments() {
+        assert!(globals("/* biome-globalsa */").is_empty());
+        assert!(globals("/* some biome-globals a */").is_empty());
+        assert!(globals("/** @biome-globals a */").is_empty());
+        assert!(globals("const s = '/* biome-globals a */';").is_empty());
+    }
+}
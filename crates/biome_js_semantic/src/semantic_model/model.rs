@@ -89,6 +89,8 @@ pub(crate) struct SemanticModelData {
     pub(crate) unresolved_references: Vec<SemanticModelUnresolvedReference>,
     /// All globals references
     pub(crate) globals: Vec<SemanticModelGlobalBindingData>,
+    /// Globals declared by a `biome-globals` comment directive
+    pub(crate) directive_globals: FxHashSet<String>,
 }
 
 impl SemanticModelData {
@@ -373,6 +375,11 @@ impl SemanticModel {
         self.data.has_exports()
     }
 
+    /// Returns `true` if `name` is declared as a global by a `biome-globals` comment directive of the file.
+    pub fn is_directive_global(&self, name: &str) -> bool {
+        self.data.directive_globals.contains(name)
+    }
+
     /// Returns if the node is imported or is a reference to a binding
     /// that is imported.
     ///
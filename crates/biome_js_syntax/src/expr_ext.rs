@@ -1161,6 +1161,48 @@ impl AnyJsExpression {
             None => None,
         }
     }
+
+    /// Checks whether the current expression is a chain of names that matches the dotted `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```javascript
+    /// scenario.only -> matches `scenario.only`, doesn't match `only`
+    /// ```
+    pub fn matches_names_path(&self, path: &str) -> bool {
+        let mut segments = path.rsplit('.');
+        let mut current = self.clone();
+        loop {
+            let Some(segment) = segments.next() else {
+                return false;
+            };
+            match current {
+                AnyJsExpression::JsIdentifierExpression(identifier) => {
+                    return segments.next().is_none()
+                        && identifier
+                            .name()
+                            .and_then(|reference| reference.value_token())
+                            .is_ok_and(|value| value.text_trimmed() == segment);
+                }
+                AnyJsExpression::JsStaticMemberExpression(member_expression) => {
+                    let Ok(AnyJsName::JsName(name)) = member_expression.member() else {
+                        return false;
+                    };
+                    if !name
+                        .value_token()
+                        .is_ok_and(|value| value.text_trimmed() == segment)
+                    {
+                        return false;
+                    }
+                    let Ok(object) = member_expression.object() else {
+                        return false;
+                    };
+                    current = object;
+                }
+                _ => return false,
+            }
+        }
+    }
 }
 
 /// Iterator that returns the callee names in "top down order".
@@ -2067,6 +2109,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn matches_names_path() {
+        let call_expression = extract_call_expression("scenario.only();");
+        let callee = call_expression.callee().unwrap();
+        assert!(callee.matches_names_path("scenario.only"));
+        assert!(!callee.matches_names_path("only"));
+        assert!(!callee.matches_names_path("scenario"));
+        assert!(!callee.matches_names_path("feature.scenario.only"));
+
+        let call_expression = extract_call_expression("testCase();");
+        assert!(call_expression
+            .callee()
+            .unwrap()
+            .matches_names_path("testCase"));
+    }
+
     #[test]
     fn matches_simple_each() {
         let template = extract_template("describe.each``");
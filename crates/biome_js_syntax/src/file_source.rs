@@ -117,6 +117,7 @@ pub enum EmbeddingKind {
     Astro,
     Vue,
     Svelte,
+    Mdx,
     #[default]
     None,
 }
@@ -131,6 +132,9 @@ impl EmbeddingKind {
     pub const fn is_svelte(&self) -> bool {
         matches!(self, EmbeddingKind::Svelte)
     }
+    pub const fn is_mdx(&self) -> bool {
+        matches!(self, EmbeddingKind::Mdx)
+    }
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -142,7 +146,7 @@ pub struct JsFileSource {
     variant: LanguageVariant,
     module_kind: ModuleKind,
     version: LanguageVersion,
-    /// Used to mark if the source is being used for an Astro, Svelte or Vue file
+    /// Used to mark if the source is being used for an Astro, Svelte, Vue or MDX file
     embedding_kind: EmbeddingKind,
 }
 
@@ -208,6 +212,11 @@ impl JsFileSource {
         Self::js_module().with_embedding_kind(EmbeddingKind::Svelte)
     }
 
+    /// MDX file definition
+    pub fn mdx() -> Self {
+        Self::jsx().with_embedding_kind(EmbeddingKind::Mdx)
+    }
+
     pub const fn with_module_kind(mut self, kind: ModuleKind) -> Self {
         self.module_kind = kind;
         self
@@ -312,6 +321,8 @@ impl JsFileSource {
             "vue" => Ok(Self::vue()),
             // TODO: Remove once we have full support of svelte files
             "svelte" => Ok(Self::svelte()),
+            // TODO: Remove once we have full support of mdx files
+            "mdx" => Ok(Self::mdx()),
             _ => Err(FileSourceError::UnknownExtension(
                 Default::default(),
                 extension.into(),
@@ -347,6 +358,8 @@ impl JsFileSource {
             "vue" => Ok(Self::vue()),
             // TODO: Remove once we have full support of svelte files
             "svelte" => Ok(Self::svelte()),
+            // TODO: Remove once we have full support of mdx files
+            "mdx" => Ok(Self::mdx()),
             _ => Err(FileSourceError::UnknownLanguageId(language_id.into())),
         }
     }
@@ -0,0 +1,153 @@
+use biome_json_syntax::{AnyJsonValue, JsonObjectValue, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList, TextSize};
+use biome_text_edit::TextEdit;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The keys of the values of a JSON document, and where a key can be added to its objects.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DocumentKeys {
+    keys: FxHashSet<String>,
+    /// The text of the document, that the edits adding a key are relative to
+    text: String,
+    /// The objects of the document, by key, the root object having the empty key
+    objects: FxHashMap<String, ObjectStart>,
+}
+
+/// Where a member can be added to an object of a JSON document.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ObjectStart {
+    /// The offset right after the `{` of the object
+    offset: TextSize,
+    /// The text between the `{` and the first member of the object, such as `\n\t`,
+    /// if the object has members
+    separator: Option<String>,
+}
+
+impl DocumentKeys {
+    /// Returns `true` if the document has a value for `key`.
+    pub fn contains(&self, key: &str) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Returns the keys of the values of the document.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.keys.iter().map(String::as_str)
+    }
+
+    /// Returns the edit of the document that adds `key`, with an empty string as value.
+    ///
+    /// The key is added as the first member of the deepest object that matches its prefix,
+    /// such as `title` in the `home` object for `home.title`, and the objects that are missing are added with it.
+    /// When the document has no nested objects, the key is added as is to its root object.
+    ///
+    /// Returns `None` if the key can't be added, because the document already has it,
+    /// because a prefix of the key has a value that isn't an object, or because the key would need to be escaped.
+    pub fn insert_key(&self, key: &str) -> Option<TextEdit> {
+        if key.is_empty()
+            || key.contains(['"', '\\'])
+            || self.keys.contains(key)
+            || self.objects.contains_key(key)
+        {
+            return None;
+        }
+        let (object, member) = if self.objects.len() == 1 {
+            (self.objects.get("")?, format!("\"{key}\": \"\""))
+        } else {
+            let mut object_key = "";
+            for (index, _) in key.match_indices('.') {
+                let prefix = &key[..index];
+                if self.keys.contains(prefix) {
+                    return None;
+                }
+                if !self.objects.contains_key(prefix) {
+                    break;
+                }
+                object_key = prefix;
+            }
+            let name = if object_key.is_empty() {
+                key
+            } else {
+                &key[object_key.len() + 1..]
+            };
+            let mut segments = name.split('.');
+            let first = segments.next()?;
+            let value = segments.rev().fold(String::from("\"\""), |value, segment| {
+                format!("{{ \"{segment}\": {value} }}")
+            });
+            (
+                self.objects.get(object_key)?,
+                format!("\"{first}\": {value}"),
+            )
+        };
+
+        let offset = usize::from(object.offset);
+        let mut builder = TextEdit::builder();
+        builder.equal(&self.text[..offset]);
+        match &object.separator {
+            Some(separator) => builder.insert(&format!("{separator}{member},")),
+            None => builder.insert(&member),
+        }
+        builder.equal(&self.text[offset..]);
+        Some(builder.finish())
+    }
+}
+
+/// Returns the keys of the values of the document, such as `home.title` in `{ "home": { "title": "Home" } }`.
+///
+/// The keys of the nested objects are joined with a `.`.
+/// Only the keys of the values that aren't objects are returned.
+pub fn document_keys(root: &JsonRoot) -> DocumentKeys {
+    let mut keys = DocumentKeys {
+        text: root.syntax().to_string(),
+        ..DocumentKeys::default()
+    };
+    if let Ok(value) = root.value() {
+        collect_keys(&value, "", &mut keys);
+    }
+    keys
+}
+
+fn collect_keys(value: &AnyJsonValue, prefix: &str, keys: &mut DocumentKeys) {
+    let AnyJsonValue::JsonObjectValue(object) = value else {
+        if !prefix.is_empty() {
+            keys.keys.insert(prefix.to_string());
+        }
+        return;
+    };
+    if let Some(start) = object_start(object, &keys.text) {
+        keys.objects.insert(prefix.to_string(), start);
+    }
+    for member in object.json_member_list().iter().flatten() {
+        let (Ok(name), Ok(value)) = (member.name(), member.value()) else {
+            continue;
+        };
+        let Ok(name) = name.inner_string_text() else {
+            continue;
+        };
+        let key = if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{prefix}.{name}")
+        };
+        collect_keys(&value, &key, keys);
+    }
+}
+
+fn object_start(object: &JsonObjectValue, text: &str) -> Option<ObjectStart> {
+    let offset = object.l_curly_token().ok()?.text_trimmed_range().end();
+    let separator = match object.json_member_list().first() {
+        Some(member) => {
+            let start = member.ok()?.syntax().text_trimmed_range().start();
+            let between = &text[usize::from(offset)..usize::from(start)];
+            // Only the indentation of the line of the member is kept, and not the comments before it
+            Some(match between.rfind('\n') {
+                Some(index) if between[..index].ends_with('\r') => between[index - 1..].to_string(),
+                Some(index) => between[index..].to_string(),
+                None if between.ends_with(char::is_whitespace) => String::from(" "),
+                None => String::new(),
+            })
+        }
+        None => None,
+    };
+    Some(ObjectStart { offset, separator })
+}
@@ -1,4 +1,5 @@
 mod assists;
+mod document_keys;
 mod lint;
 
 pub mod options;
@@ -6,6 +7,7 @@ mod registry;
 mod suppression_action;
 pub mod utils;
 
+pub use crate::document_keys::{document_keys, DocumentKeys};
 pub use crate::registry::visit_registry;
 use crate::suppression_action::JsonSuppressionAction;
 use biome_analyze::{
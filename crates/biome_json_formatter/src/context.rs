@@ -67,6 +67,13 @@ pub struct JsonFormatOptions {
     attribute_position: AttributePosition,
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
     trailing_commas: TrailingCommas,
+    /// The maximum width of the arrays of primitive values that are printed on a single line,
+    /// even if they exceed the line width. Disabled by default.
+    inline_array_width: Option<LineWidth>,
+    /// The case of the exponent of the numbers. Defaults to "lowercase".
+    exponent_case: ExponentCase,
+    /// Whether to keep the `+` sign of the exponent of the numbers. Defaults to `false`.
+    keep_exponent_plus_sign: bool,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
@@ -104,6 +111,45 @@ impl fmt::Display for TrailingCommas {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ExponentCase {
+    #[default]
+    /// The exponent is printed as `e`
+    Lowercase,
+    /// The exponent is printed as `E`
+    Uppercase,
+    /// The exponent is printed as written
+    Preserve,
+}
+
+impl FromStr for ExponentCase {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lowercase" => Ok(Self::Lowercase),
+            "uppercase" => Ok(Self::Uppercase),
+            "preserve" => Ok(Self::Preserve),
+            _ => Err("Value not supported for ExponentCase"),
+        }
+    }
+}
+
+impl fmt::Display for ExponentCase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExponentCase::Lowercase => std::write!(f, "Lowercase"),
+            ExponentCase::Uppercase => std::write!(f, "Uppercase"),
+            ExponentCase::Preserve => std::write!(f, "Preserve"),
+        }
+    }
+}
+
 impl JsonFormatOptions {
     pub fn new() -> Self {
         Self {
@@ -136,6 +182,21 @@ impl JsonFormatOptions {
         self
     }
 
+    pub fn with_inline_array_width(mut self, inline_array_width: Option<LineWidth>) -> Self {
+        self.inline_array_width = inline_array_width;
+        self
+    }
+
+    pub fn with_exponent_case(mut self, exponent_case: ExponentCase) -> Self {
+        self.exponent_case = exponent_case;
+        self
+    }
+
+    pub fn with_strip_exponent_plus_sign(mut self, strip_exponent_plus_sign: bool) -> Self {
+        self.keep_exponent_plus_sign = !strip_exponent_plus_sign;
+        self
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -156,6 +217,30 @@ impl JsonFormatOptions {
         self.trailing_commas = trailing_commas;
     }
 
+    pub fn set_inline_array_width(&mut self, inline_array_width: Option<LineWidth>) {
+        self.inline_array_width = inline_array_width;
+    }
+
+    pub fn set_exponent_case(&mut self, exponent_case: ExponentCase) {
+        self.exponent_case = exponent_case;
+    }
+
+    pub fn set_strip_exponent_plus_sign(&mut self, strip_exponent_plus_sign: bool) {
+        self.keep_exponent_plus_sign = !strip_exponent_plus_sign;
+    }
+
+    pub fn inline_array_width(&self) -> Option<LineWidth> {
+        self.inline_array_width
+    }
+
+    pub fn exponent_case(&self) -> ExponentCase {
+        self.exponent_case
+    }
+
+    pub fn strip_exponent_plus_sign(&self) -> bool {
+        !self.keep_exponent_plus_sign
+    }
+
     pub(crate) fn to_trailing_separator(&self) -> TrailingSeparator {
         match self.trailing_commas {
             TrailingCommas::None => TrailingSeparator::Omit,
@@ -200,6 +285,16 @@ impl fmt::Display for JsonFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.value())?;
-        writeln!(f, "Trailing commas: {}", self.trailing_commas)
+        writeln!(f, "Trailing commas: {}", self.trailing_commas)?;
+        match self.inline_array_width {
+            Some(width) => writeln!(f, "Inline array width: {}", width.value())?,
+            None => writeln!(f, "Inline array width: disabled")?,
+        }
+        writeln!(f, "Exponent case: {}", self.exponent_case)?;
+        writeln!(
+            f,
+            "Strip exponent plus sign: {}",
+            self.strip_exponent_plus_sign()
+        )
     }
 }
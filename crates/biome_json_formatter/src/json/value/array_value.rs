@@ -1,6 +1,8 @@
 use crate::prelude::*;
+use crate::separated::FormatAstSeparatedListExtension;
+use biome_formatter::separated::TrailingSeparator;
 use biome_formatter::{format_args, write};
-use biome_json_syntax::{JsonArrayValue, JsonArrayValueFields};
+use biome_json_syntax::{AnyJsonValue, JsonArrayElementList, JsonArrayValue, JsonArrayValueFields};
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsonArrayValue;
@@ -14,6 +16,25 @@ impl FormatNodeRule<JsonArrayValue> for FormatJsonArrayValue {
 
         let should_expand = f.comments().has_dangling_comments(node.syntax());
 
+        if let Some(inline_array_width) = f.options().inline_array_width() {
+            if !node.syntax().has_comments_descendants()
+                && fits_inline(&elements, inline_array_width.value())
+            {
+                return write!(
+                    f,
+                    [
+                        l_brack_token.format(),
+                        format_with(|f| {
+                            f.join_with(space())
+                                .entries(elements.format_separated(",", TrailingSeparator::Omit))
+                                .finish()
+                        }),
+                        r_brack_token.format()
+                    ]
+                );
+            }
+        }
+
         write!(
             f,
             [
@@ -34,3 +55,25 @@ impl FormatNodeRule<JsonArrayValue> for FormatJsonArrayValue {
         Ok(())
     }
 }
+
+/// Returns `true` if `elements` are primitive values, and if they fit in `width` when they are
+/// printed on a single line, brackets included.
+fn fits_inline(elements: &JsonArrayElementList, width: u16) -> bool {
+    if elements.is_empty() {
+        return false;
+    }
+    let mut inline_width = 2 + 2 * (elements.len() - 1);
+    for element in elements.iter() {
+        let Ok(
+            element @ (AnyJsonValue::JsonBooleanValue(_)
+            | AnyJsonValue::JsonNullValue(_)
+            | AnyJsonValue::JsonNumberValue(_)
+            | AnyJsonValue::JsonStringValue(_)),
+        ) = element
+        else {
+            return false;
+        };
+        inline_width += usize::from(element.syntax().text_trimmed().len());
+    }
+    inline_width <= usize::from(width)
+}
@@ -1,12 +1,50 @@
+use crate::context::ExponentCase;
 use crate::prelude::*;
-use biome_formatter::token::number::format_number_token;
+use biome_formatter::token::number::{format_number_token, format_trimmed_number};
 use biome_json_syntax::JsonNumberValue;
+use std::borrow::Cow;
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsonNumberValue;
 
 impl FormatNodeRule<JsonNumberValue> for FormatJsonNumberValue {
     fn fmt_fields(&self, node: &JsonNumberValue, f: &mut JsonFormatter) -> FormatResult<()> {
-        format_number_token(&node.value_token()?).fmt(f)
+        let token = node.value_token()?;
+        let exponent_case = f.options().exponent_case();
+        let strip_exponent_plus_sign = f.options().strip_exponent_plus_sign();
+        if exponent_case == ExponentCase::Lowercase && strip_exponent_plus_sign {
+            return format_number_token(&token).fmt(f);
+        }
+
+        let original = token.text_trimmed();
+        let cleaned = format_trimmed_number(original);
+        // The cleaned number is lowercased and has no `+` sign in its exponent
+        let text = match (cleaned.find('e'), original.find(['e', 'E'])) {
+            (Some(index), Some(original_index)) => {
+                let exponent = match exponent_case {
+                    ExponentCase::Lowercase => "e",
+                    ExponentCase::Uppercase => "E",
+                    ExponentCase::Preserve => &original[original_index..=original_index],
+                };
+                let sign = if !strip_exponent_plus_sign
+                    && original[original_index + 1..].starts_with('+')
+                {
+                    "+"
+                } else {
+                    ""
+                };
+                Cow::Owned(std::format!(
+                    "{}{exponent}{sign}{}",
+                    &cleaned[..index],
+                    &cleaned[index + 1..]
+                ))
+            }
+            _ => cleaned,
+        };
+        format_replaced(
+            &token,
+            &syntax_token_cow_slice(text, &token, token.text_trimmed_range().start()),
+        )
+        .fmt(f)
     }
 }
@@ -56,7 +56,8 @@ pub(crate) async fn handle_lsp_error<T>(
             // diagnostics that shouldn't raise an hard error, but send a message to the user
             WorkspaceError::FormatWithErrorsDisabled(_)
             | WorkspaceError::FileIgnored(_)
-            | WorkspaceError::FileTooLarge(_) => {
+            | WorkspaceError::FileTooLarge(_)
+            | WorkspaceError::FileTooComplex(_) => {
                 let message = format!("{err}");
                 client.log_message(MessageType::WARNING, message).await;
                 Ok(None)
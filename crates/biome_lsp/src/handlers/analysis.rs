@@ -8,15 +8,18 @@ use biome_analyze::{ActionCategory, RuleCategoriesBuilder, SourceActionKind};
 use biome_diagnostics::Applicability;
 use biome_fs::BiomePath;
 use biome_rowan::{TextRange, TextSize};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{
+    AstroFileHandler, DocumentFileSource, SvelteFileHandler, VueFileHandler,
+};
 use biome_service::workspace::{
-    FeaturesBuilder, FixFileMode, FixFileParams, GetFileContentParams, PullActionsParams,
-    SupportsFeatureParams,
+    CloseFileParams, FeaturesBuilder, FixFileMode, FixFileParams, GenerateBarrelFileParams,
+    GetFileContentParams, OpenFileParams, PullActionsParams, SupportsFeatureParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::ops::Sub;
+use std::path::Path;
 use tower_lsp::lsp_types::{
     self as lsp, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
 };
@@ -24,6 +27,9 @@ use tracing::{debug, info, trace};
 
 const FIX_ALL_CATEGORY: ActionCategory = ActionCategory::Source(SourceActionKind::FixAll);
 
+const GENERATE_BARREL_FILE_CATEGORY: ActionCategory =
+    ActionCategory::Source(SourceActionKind::Other(Cow::Borrowed("generateBarrelFile")));
+
 fn fix_all_kind() -> CodeActionKind {
     match FIX_ALL_CATEGORY.to_str() {
         Cow::Borrowed(kind) => CodeActionKind::from(kind),
@@ -147,6 +153,19 @@ pub(crate) fn code_actions(
         None
     };
 
+    // Generate an additional code action to update the barrel file of the directory
+    // when the document is an `index` file
+    let generate_barrel_file = if file_features.supports_assists()
+        && (filters.is_empty()
+            || filters
+                .iter()
+                .any(|filter| GENERATE_BARREL_FILE_CATEGORY.matches(filter)))
+    {
+        generate_barrel_file(session, &url, &biome_path, &doc.line_index, &content)?
+    } else {
+        None
+    };
+
     let mut has_fixes = false;
 
     let mut actions: Vec<_> = result
@@ -170,7 +189,9 @@ pub(crate) fn code_actions(
             }
 
             // Filter out the refactor.* actions when assists are disabled
-            if action.category.matches("source") && !file_features.supports_assists() {
+            if (action.category.matches("source") || action.category.matches("refactor"))
+                && !file_features.supports_assists()
+            {
                 return None;
             }
             // Remove actions that do not match the categories requested by the
@@ -194,6 +215,7 @@ pub(crate) fn code_actions(
                 &diagnostics,
                 action,
                 offset,
+                |path| file_line_index(session, path),
             )
             .ok()?;
 
@@ -202,6 +224,7 @@ pub(crate) fn code_actions(
         })
         .rev()
         .chain(fix_all)
+        .chain(generate_barrel_file)
         .collect();
 
     // If any actions is marked as fixing a diagnostic, hide other actions
@@ -221,6 +244,22 @@ pub(crate) fn code_actions(
     Ok(Some(actions))
 }
 
+/// Returns the URL and the line index of the file at `path`, edited by a fix
+/// that spans several files
+///
+/// The files that aren't open in the editor are read from the disk.
+fn file_line_index(session: &Session, path: &Path) -> Option<(lsp::Url, LineIndex)> {
+    let url = lsp::Url::from_file_path(path).ok()?;
+    let line_index = match session.document(&url) {
+        Ok(doc) => doc.line_index,
+        Err(_) => {
+            let content = session.fs.read_file_from_path(&path.to_path_buf()).ok()?;
+            LineIndex::new(&content)
+        }
+    };
+    Some((url, line_index))
+}
+
 /// Generate a "fix all" code action for the given document
 #[tracing::instrument(level = "debug", skip(session), err)]
 fn fix_all(
@@ -249,6 +288,7 @@ fn fix_all(
             .with_lint()
             .with_action()
             .build(),
+        timeout: None,
     })?;
 
     if fixed.actions.is_empty() {
@@ -330,3 +370,102 @@ fn fix_all(
         data: None,
     })))
 }
+
+/// Generate a code action that updates the barrel file at `biome_path`,
+/// so that it re-exports the names exported by the other modules of its directory
+///
+/// The modules that aren't open in the editor are read from the disk.
+#[tracing::instrument(level = "debug", skip(session, line_index, content), err)]
+fn generate_barrel_file(
+    session: &Session,
+    url: &lsp::Url,
+    biome_path: &BiomePath,
+    line_index: &LineIndex,
+    content: &str,
+) -> Result<Option<CodeActionOrCommand>, LspError> {
+    if !biome_path.file_stem().is_some_and(|stem| stem == "index") {
+        return Ok(None);
+    }
+    let Some(Ok(entries)) = biome_path
+        .parent()
+        .map(|directory| session.fs.read_dir(directory))
+    else {
+        return Ok(None);
+    };
+
+    let mut modules = Vec::new();
+    let mut opened = Vec::new();
+    for path in entries {
+        if !matches!(
+            DocumentFileSource::from_path(&path),
+            DocumentFileSource::Js(_)
+        ) || !session.fs.path_is_file(&path)
+        {
+            continue;
+        }
+        let path = BiomePath::new(path);
+        let is_open = session
+            .workspace
+            .get_file_content(GetFileContentParams { path: path.clone() })
+            .is_ok();
+        if !is_open {
+            let Ok(content) = session.fs.read_file_from_path(&path) else {
+                continue;
+            };
+            session.workspace.open_file(OpenFileParams {
+                path: path.clone(),
+                content,
+                version: 0,
+                document_file_source: None,
+            })?;
+            opened.push(path.clone());
+        }
+        modules.push(path);
+    }
+
+    let result = session
+        .workspace
+        .generate_barrel_file(GenerateBarrelFileParams {
+            path: biome_path.clone(),
+            modules,
+            exclude: Vec::new(),
+        });
+    for path in opened {
+        session.workspace.close_file(CloseFileParams { path })?;
+    }
+    let new_text = result?.content;
+    if new_text == content {
+        return Ok(None);
+    }
+
+    let mut changes = HashMap::new();
+    changes.insert(
+        url.clone(),
+        vec![lsp::TextEdit {
+            range: lsp::Range {
+                start: lsp::Position::new(0, 0),
+                end: lsp::Position::new(line_index.len(), 0),
+            },
+            new_text,
+        }],
+    );
+
+    let edit = lsp::WorkspaceEdit {
+        changes: Some(changes),
+        document_changes: None,
+        change_annotations: None,
+    };
+
+    Ok(Some(CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Generate the barrel file of the directory"),
+        kind: Some(CodeActionKind::from(
+            GENERATE_BARREL_FILE_CATEGORY.to_str().into_owned(),
+        )),
+        diagnostics: None,
+        edit: Some(edit),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    })))
+}
@@ -35,26 +35,10 @@ pub(crate) fn format(
             path: biome_path.clone(),
         })?;
 
-        let mut output = printed.into_code();
-        let file_extension = biome_path.extension().and_then(|s| s.to_str());
-        let input = session.workspace.get_file_content(GetFileContentParams {
-            path: biome_path.clone(),
-        })?;
+        let output = printed.into_code();
         if output.is_empty() {
             return Ok(None);
         }
-        match file_extension {
-            Some("astro") => {
-                output = AstroFileHandler::output(input.as_str(), output.as_str());
-            }
-            Some("vue") => {
-                output = VueFileHandler::output(input.as_str(), output.as_str());
-            }
-            Some("svelte") => {
-                output = SvelteFileHandler::output(input.as_str(), output.as_str());
-            }
-            _ => {}
-        }
 
         let num_lines: u32 = doc.line_index.len();
 
@@ -0,0 +1,300 @@
+use crate::converters::line_index::LineIndex;
+use crate::converters::{from_proto, to_proto};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_fs::BiomePath;
+use biome_rowan::TextRange;
+use biome_service::workspace::{
+    self, CallHierarchyCallsParams, CallHierarchyItemKind, DocumentHighlightKind,
+    DocumentHighlightsParams, GetFileContentParams, PrepareCallHierarchyParams,
+    SelectionRangesParams,
+};
+use biome_service::WorkspaceError;
+use rustc_hash::FxHashMap;
+use tower_lsp::lsp_types::{
+    self, CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams,
+    DocumentHighlight, DocumentHighlightParams, SelectionRange, SelectionRangeParams, SymbolKind,
+    Url,
+};
+use tracing::trace;
+
+/// Handler for `textDocument/documentHighlight` LSP request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_highlight(
+    session: &Session,
+    params: DocumentHighlightParams,
+) -> Result<Option<Vec<DocumentHighlight>>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    trace!("Highlighting...");
+
+    let result = match session
+        .workspace
+        .document_highlights(DocumentHighlightsParams {
+            path: biome_path,
+            symbol_at: offset,
+        }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    if result.highlights.is_empty() {
+        return Ok(None);
+    }
+
+    let highlights = result
+        .highlights
+        .into_iter()
+        .map(|highlight| {
+            Ok(DocumentHighlight {
+                range: to_proto::range(&doc.line_index, highlight.range, position_encoding)?,
+                kind: Some(match highlight.kind {
+                    DocumentHighlightKind::Read => lsp_types::DocumentHighlightKind::READ,
+                    DocumentHighlightKind::Write => lsp_types::DocumentHighlightKind::WRITE,
+                }),
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(Some(highlights))
+}
+
+/// Handler for `textDocument/selectionRange` LSP request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn selection_range(
+    session: &Session,
+    params: SelectionRangeParams,
+) -> Result<Option<Vec<SelectionRange>>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let positions = params
+        .positions
+        .iter()
+        .map(|position| {
+            from_proto::offset(&doc.line_index, *position, position_encoding).with_context(|| {
+                format!("failed to access position {position:?} in document {url}")
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    let result = match session.workspace.selection_ranges(SelectionRangesParams {
+        path: biome_path,
+        positions,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let selection_ranges = result
+        .ranges
+        .into_iter()
+        .zip(params.positions)
+        .map(|(ranges, position)| {
+            // Each range is the parent of the previous one
+            let mut selection_range: Option<SelectionRange> = None;
+            for range in ranges.into_iter().rev() {
+                selection_range = Some(SelectionRange {
+                    range: to_proto::range(&doc.line_index, range, position_encoding)?,
+                    parent: selection_range.map(Box::new),
+                });
+            }
+            // The response must contain a range for each position
+            Ok(selection_range.unwrap_or(SelectionRange {
+                range: lsp_types::Range::new(position, position),
+                parent: None,
+            }))
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(Some(selection_ranges))
+}
+
+/// Handler for `textDocument/prepareCallHierarchy` LSP request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn prepare_call_hierarchy(
+    session: &Session,
+    params: CallHierarchyPrepareParams,
+) -> Result<Option<Vec<CallHierarchyItem>>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let offset = from_proto::offset(&doc.line_index, position, session.position_encoding())
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    let result = match session
+        .workspace
+        .prepare_call_hierarchy(PrepareCallHierarchyParams {
+            path: biome_path,
+            symbol_at: offset,
+        }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    if result.items.is_empty() {
+        return Ok(None);
+    }
+
+    let mut documents = CallHierarchyDocuments::new(session);
+    let items = result
+        .items
+        .into_iter()
+        .map(|item| documents.to_proto(item))
+        .collect::<anyhow::Result<_>>()?;
+    Ok(Some(items))
+}
+
+/// Handler for `callHierarchy/incomingCalls` LSP request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn incoming_calls(
+    session: &Session,
+    params: CallHierarchyIncomingCallsParams,
+) -> Result<Option<Vec<CallHierarchyIncomingCall>>, LspError> {
+    let Some(item) = from_proto_item(&params.item) else {
+        return Ok(None);
+    };
+    let result = match session
+        .workspace
+        .incoming_calls(CallHierarchyCallsParams { item })
+    {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut documents = CallHierarchyDocuments::new(session);
+    let calls = result
+        .calls
+        .into_iter()
+        .map(|call| {
+            let from_ranges = documents.ranges(&call.from.path, &call.from_ranges)?;
+            Ok(CallHierarchyIncomingCall {
+                from: documents.to_proto(call.from)?,
+                from_ranges,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+    Ok(Some(calls))
+}
+
+/// Handler for `callHierarchy/outgoingCalls` LSP request
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn outgoing_calls(
+    session: &Session,
+    params: CallHierarchyOutgoingCallsParams,
+) -> Result<Option<Vec<CallHierarchyOutgoingCall>>, LspError> {
+    let Some(item) = from_proto_item(&params.item) else {
+        return Ok(None);
+    };
+    let path = item.path.clone();
+    let result = match session
+        .workspace
+        .outgoing_calls(CallHierarchyCallsParams { item })
+    {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut documents = CallHierarchyDocuments::new(session);
+    let calls = result
+        .calls
+        .into_iter()
+        .map(|call| {
+            let from_ranges = documents.ranges(&path, &call.from_ranges)?;
+            Ok(CallHierarchyOutgoingCall {
+                to: documents.to_proto(call.to)?,
+                from_ranges,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+    Ok(Some(calls))
+}
+
+/// Returns the item of the workspace that the client sends back in the `data` of an item
+fn from_proto_item(item: &CallHierarchyItem) -> Option<workspace::CallHierarchyItem> {
+    serde_json::from_value(item.data.clone()?).ok()
+}
+
+/// The line indexes of the documents of the items of a call hierarchy,
+/// which aren't necessarily opened by the client
+struct CallHierarchyDocuments<'a> {
+    session: &'a Session,
+    line_indexes: FxHashMap<Url, LineIndex>,
+}
+
+impl<'a> CallHierarchyDocuments<'a> {
+    fn new(session: &'a Session) -> Self {
+        Self {
+            session,
+            line_indexes: FxHashMap::default(),
+        }
+    }
+
+    fn line_index(&mut self, path: &BiomePath) -> anyhow::Result<(Url, &LineIndex)> {
+        let url = Url::from_file_path(path.as_path())
+            .map_err(|_| anyhow::anyhow!("failed to convert {} to a URL", path.display()))?;
+        if !self.line_indexes.contains_key(&url) {
+            let line_index = match self.session.document(&url) {
+                Ok(doc) => doc.line_index,
+                Err(_) => {
+                    let content = self
+                        .session
+                        .workspace
+                        .get_file_content(GetFileContentParams { path: path.clone() })?;
+                    LineIndex::new(&content)
+                }
+            };
+            self.line_indexes.insert(url.clone(), line_index);
+        }
+        let line_index = &self.line_indexes[&url];
+        Ok((url, line_index))
+    }
+
+    fn ranges(
+        &mut self,
+        path: &BiomePath,
+        ranges: &[TextRange],
+    ) -> anyhow::Result<Vec<lsp_types::Range>> {
+        let position_encoding = self.session.position_encoding();
+        let (_, line_index) = self.line_index(path)?;
+        ranges
+            .iter()
+            .map(|range| to_proto::range(line_index, *range, position_encoding))
+            .collect()
+    }
+
+    fn to_proto(
+        &mut self,
+        item: workspace::CallHierarchyItem,
+    ) -> anyhow::Result<CallHierarchyItem> {
+        let position_encoding = self.session.position_encoding();
+        let (uri, line_index) = self.line_index(&item.path)?;
+        Ok(CallHierarchyItem {
+            name: item.name.clone(),
+            kind: match item.kind {
+                CallHierarchyItemKind::Function => SymbolKind::FUNCTION,
+                CallHierarchyItemKind::Module => SymbolKind::MODULE,
+            },
+            tags: None,
+            detail: None,
+            uri,
+            range: to_proto::range(line_index, item.range, position_encoding)?,
+            selection_range: to_proto::range(line_index, item.selection_range, position_encoding)?,
+            // The item is sent back by the client in the requests for its calls
+            data: Some(serde_json::to_value(&item)?),
+        })
+    }
+}
@@ -287,6 +287,8 @@ impl LanguageServer for LSPServer {
             self.session.load_workspace_settings(),
             self.session.load_manifest()
         );
+        // The settings tell whether a rule needs the module graph
+        self.session.load_module_graph();
 
         let msg = format!("Server initialized with PID: {}", std::process::id());
         self.session
@@ -301,6 +303,7 @@ impl LanguageServer for LSPServer {
     }
 
     async fn shutdown(&self) -> LspResult<()> {
+        self.session.save_module_graph();
         Ok(())
     }
 
@@ -330,10 +333,10 @@ impl LanguageServer for LSPServer {
                                 || ConfigName::file_names()
                                     .contains(&&*possible_rome_json.display().to_string())
                             {
-                                self.session.load_workspace_settings().await;
+                                let diff = self.session.load_workspace_settings().await;
                                 self.session.load_manifest().await;
                                 self.setup_capabilities().await;
-                                self.session.update_all_diagnostics().await;
+                                self.session.update_diagnostics_affected_by(&diff).await;
                                 // for now we are only interested to the configuration file,
                                 // so it's OK to exist the loop
                                 break;
@@ -447,6 +450,57 @@ impl LanguageServer for LSPServer {
         self.map_op_error(result).await
     }
 
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> LspResult<Option<Vec<DocumentHighlight>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::document_highlight(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> LspResult<Option<Vec<SelectionRange>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::selection_range(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> LspResult<Option<Vec<CallHierarchyItem>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::prepare_call_hierarchy(&self.session, params)
+                .map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> LspResult<Option<Vec<CallHierarchyIncomingCall>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::incoming_calls(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> LspResult<Option<Vec<CallHierarchyOutgoingCall>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::outgoing_calls(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
     async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
         biome_diagnostics::panic::catch_unwind(move || {
             let rename_enabled = self
@@ -605,6 +659,10 @@ impl ServerFactory {
         // "shutdown" is not part of the Workspace API
         builder = builder.custom_method("biome/shutdown", |server: &LSPServer, (): ()| {
             info!("Sending shutdown signal");
+            // The clients aren't notified of the shutdown of the daemon
+            for session in server.sessions.lock().unwrap().values() {
+                session.save_module_graph();
+            }
             server.session.broadcast_shutdown();
             ready(Ok(Some(())))
         });
@@ -628,11 +686,19 @@ impl ServerFactory {
         workspace_method!(builder, pull_diagnostics);
         workspace_method!(builder, pull_actions);
         workspace_method!(builder, format_file);
+        workspace_method!(builder, verify_format_file);
         workspace_method!(builder, format_range);
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, document_highlights);
+        workspace_method!(builder, selection_ranges);
+        workspace_method!(builder, prepare_call_hierarchy);
+        workspace_method!(builder, incoming_calls);
+        workspace_method!(builder, outgoing_calls);
+        workspace_method!(builder, generate_barrel_file);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, project_indexes);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
@@ -6,7 +6,7 @@ use crate::extension_settings::CONFIGURATION_SECTION;
 use crate::utils;
 use anyhow::Result;
 use biome_analyze::RuleCategoriesBuilder;
-use biome_configuration::ConfigurationPathHint;
+use biome_configuration::{ConfigurationPathHint, PartialConfiguration};
 use biome_console::markup;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDescription};
 use biome_fs::{BiomePath, FileSystem};
@@ -14,17 +14,22 @@ use biome_service::configuration::{
     load_configuration, LoadedConfiguration, PartialConfigurationExt,
 };
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::settings_diff::SettingsDiff;
 use biome_service::workspace::{
     FeaturesBuilder, GetFileContentParams, OpenProjectParams, PullDiagnosticsParams,
     RegisterProjectFolderParams, SupportsFeatureParams, UpdateProjectParams,
 };
-use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
+use biome_service::workspace::{
+    LoadModuleGraphParams, RageEntry, RageParams, RageResult, SaveModuleGraphParams,
+    UpdateSettingsParams,
+};
 use biome_service::Workspace;
 use biome_service::{DynRef, WorkspaceError};
 use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
 use rustc_hash::FxHashMap;
 use serde_json::Value;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::PathBuf;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::{AtomicBool, AtomicU8};
@@ -68,6 +73,10 @@ pub(crate) struct Session {
 
     configuration_status: AtomicU8,
 
+    /// The configurations applied to the workspace, by directory of the configuration file.
+    /// They are compared to the reloaded configurations to refresh only what changed.
+    configurations: RwLock<FxHashMap<Option<PathBuf>, PartialConfiguration>>,
+
     /// A flag to notify a message to the user when the configuration is broken, and the LSP attempts
     /// to update the diagnostics
     notified_broken_configuration: AtomicBool,
@@ -173,6 +182,7 @@ impl Session {
             initialize_params: OnceCell::default(),
             workspace,
             configuration_status: AtomicU8::new(ConfigurationStatus::Missing as u8),
+            configurations: Default::default(),
             documents,
             extension_settings: config,
             fs,
@@ -333,6 +343,8 @@ impl Session {
                 max_diagnostics: u64::MAX,
                 only: Vec::new(),
                 skip: Vec::new(),
+                timeout: None,
+                profile: false,
             })?;
 
             tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
@@ -393,6 +405,30 @@ impl Session {
         }
     }
 
+    /// Updates diagnostics for the [`Document`]s affected by the changes of the settings
+    pub(crate) async fn update_diagnostics_affected_by(&self, diff: &SettingsDiff) {
+        if !diff.affects_diagnostics() {
+            return;
+        }
+        let mut futures: FuturesUnordered<_> = self
+            .documents
+            .read()
+            .unwrap()
+            .keys()
+            .filter(|url| {
+                url.to_file_path()
+                    .is_ok_and(|path| diff.affects_path(&path))
+            })
+            .map(|url| self.update_diagnostics(url.clone()))
+            .collect();
+
+        while let Some(result) = futures.next().await {
+            if let Err(e) = result {
+                error!("Error while updating diagnostics: {}", e);
+            }
+        }
+    }
+
     /// True if the client supports dynamic registration of "workspace/didChangeConfiguration" requests
     pub(crate) fn can_register_did_change_configuration(&self) -> bool {
         self.initialize_params
@@ -432,13 +468,19 @@ impl Session {
     }
 
     /// This function attempts to read the `biome.json` configuration file from
-    /// the root URI and update the workspace settings accordingly
+    /// the root URI and update the workspace settings accordingly.
+    ///
+    /// Returns what changed compared to the settings loaded previously.
     #[tracing::instrument(level = "trace", skip(self))]
-    pub(crate) async fn load_workspace_settings(&self) {
+    pub(crate) async fn load_workspace_settings(&self) -> SettingsDiff {
+        let previous_status = self.configuration_status.load(Ordering::Relaxed);
+        let mut diff = SettingsDiff::default();
         // Providing a custom configuration path will not allow to support workspaces
         if let Some(config_path) = &self.config_path {
             let base_path = ConfigurationPathHint::FromUser(config_path.clone());
-            let status = self.load_biome_configuration_file(base_path).await;
+            let status = self
+                .load_biome_configuration_file(base_path, &mut diff)
+                .await;
             self.set_configuration_status(status);
         } else if let Some(folders) = self.get_workspace_folders() {
             info!("Detected workspace folder.");
@@ -449,9 +491,10 @@ impl Session {
                 match base_path {
                     Ok(base_path) => {
                         let status = self
-                            .load_biome_configuration_file(ConfigurationPathHint::FromWorkspace(
-                                base_path,
-                            ))
+                            .load_biome_configuration_file(
+                                ConfigurationPathHint::FromWorkspace(base_path),
+                                &mut diff,
+                            )
                             .await;
                         self.set_configuration_status(status);
                     }
@@ -468,14 +511,23 @@ impl Session {
                 None => ConfigurationPathHint::default(),
                 Some(path) => ConfigurationPathHint::FromLsp(path),
             };
-            let status = self.load_biome_configuration_file(base_path).await;
+            let status = self
+                .load_biome_configuration_file(base_path, &mut diff)
+                .await;
             self.set_configuration_status(status);
         }
+        // The status decides which diagnostics are computed
+        if self.configuration_status.load(Ordering::Relaxed) != previous_status {
+            diff.merge_with(SettingsDiff::all());
+        }
+        diff
     }
 
+    /// Loads the configuration file, and adds the changes of the settings to `diff`.
     async fn load_biome_configuration_file(
         &self,
         base_path: ConfigurationPathHint,
+        diff: &mut SettingsDiff,
     ) -> ConfigurationStatus {
         match load_configuration(&self.fs, base_path.clone()) {
             Ok(loaded_configuration) => {
@@ -493,7 +545,20 @@ impl Session {
                         ..
                     } = loaded_configuration;
                     info!("Configuration loaded successfully from disk.");
+                    let configuration_diff = self
+                        .configurations
+                        .read()
+                        .unwrap()
+                        .get(&configuration_path)
+                        .map_or_else(SettingsDiff::all, |previous| {
+                            SettingsDiff::new(previous, &configuration)
+                        });
+                    if configuration_diff.is_empty() {
+                        info!("The configuration didn't change.");
+                        return ConfigurationStatus::Loaded;
+                    }
                     info!("Update workspace settings.");
+                    diff.merge_with(configuration_diff);
                     let fs = &self.fs;
 
                     let result =
@@ -527,7 +592,7 @@ impl Session {
                             }
                             let result = self.workspace.update_settings(UpdateSettingsParams {
                                 workspace_directory: fs.working_directory(),
-                                configuration,
+                                configuration: configuration.clone(),
                                 vcs_base_path,
                                 gitignore_matches,
                             });
@@ -537,6 +602,10 @@ impl Session {
                                 self.client.log_message(MessageType::ERROR, &error).await;
                                 ConfigurationStatus::Error
                             } else {
+                                self.configurations
+                                    .write()
+                                    .unwrap()
+                                    .insert(configuration_path, configuration);
                                 ConfigurationStatus::Loaded
                             }
                         }
@@ -623,6 +692,46 @@ impl Session {
         }
     }
 
+    /// Returns the path of the snapshot of the module graph of the project of this session.
+    ///
+    /// The snapshots are stored in the cache directory, and named after the root of their project.
+    fn module_graph_snapshot_path(&self) -> Option<PathBuf> {
+        let base_path = self.base_path()?;
+        let mut hasher = DefaultHasher::new();
+        base_path.hash(&mut hasher);
+        Some(
+            biome_fs::ensure_cache_dir()
+                .join("module-graph")
+                .join(format!("{:016x}.json", hasher.finish())),
+        )
+    }
+
+    /// Saves the module graph of the workspace, so that the next session of the project can start from it
+    pub(crate) fn save_module_graph(&self) {
+        let Some(path) = self.module_graph_snapshot_path() else {
+            return;
+        };
+        if let Err(error) = self
+            .workspace
+            .save_module_graph(SaveModuleGraphParams { path })
+        {
+            error!("Failed to save the module graph: {error}");
+        }
+    }
+
+    /// Loads the module graph saved by a previous session of the project
+    pub(crate) fn load_module_graph(&self) {
+        let Some(path) = self.module_graph_snapshot_path() else {
+            return;
+        };
+        if let Err(error) = self
+            .workspace
+            .load_module_graph(LoadModuleGraphParams { path })
+        {
+            error!("Failed to load the module graph: {error}");
+        }
+    }
+
     /// Broadcast a shutdown signal to all active connections
     pub(crate) fn broadcast_shutdown(&self) {
         self.cancellation.notify_one();
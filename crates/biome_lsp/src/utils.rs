@@ -17,6 +17,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 use std::ops::{Add, Range};
+use std::path::Path;
 use std::{io, mem};
 use tower_lsp::jsonrpc::Error as LspError;
 use tower_lsp::lsp_types;
@@ -98,6 +99,7 @@ pub(crate) fn code_fix_to_lsp(
     diagnostics: &[lsp::Diagnostic],
     action: CodeAction,
     offset: Option<u32>,
+    file_line_index: impl Fn(&Path) -> Option<(Url, LineIndex)>,
 ) -> Result<lsp::CodeAction> {
     // Mark diagnostics emitted by the same rule as resolved by this action
     let diagnostics: Vec<_> = action
@@ -147,6 +149,14 @@ pub(crate) fn code_fix_to_lsp(
 
     changes.insert(url.clone(), edits);
 
+    // The edits of the other files, when the fix spans several files
+    for file_edit in action.file_edits {
+        let (file_url, file_line_index) = file_line_index(&file_edit.path)
+            .with_context(|| format!("failed to access the file {}", file_edit.path.display()))?;
+        let edits = text_edit(&file_line_index, file_edit.edit, position_encoding, None)?;
+        changes.entry(file_url).or_default().extend(edits);
+    }
+
     let edit = lsp::WorkspaceEdit {
         changes: Some(changes),
         document_changes: None,
@@ -393,7 +403,13 @@ mod tests {
     use super::apply_document_changes;
     use crate::converters::line_index::LineIndex;
     use crate::converters::{PositionEncoding, WideEncoding};
+    use biome_analyze::{ActionCategory, FileEdit};
+    use biome_console::markup;
+    use biome_diagnostics::{Applicability, CodeSuggestion};
+    use biome_rowan::{TextRange, TextSize};
+    use biome_service::workspace::CodeAction;
     use biome_text_edit::TextEdit;
+    use std::path::{Path, PathBuf};
     use tower_lsp::lsp_types as lsp;
     use tower_lsp::lsp_types::{Position, Range, TextDocumentContentChangeEvent};
 
@@ -524,4 +540,52 @@ line 7 new";
 
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_code_fix_with_file_edits() {
+        const MAIN: &str = "export const foo = 1;";
+        const OTHER: &str = "import { foo } from \"./main\";";
+
+        let main_url = lsp::Url::parse("file:///project/main.ts").unwrap();
+        let other_url = lsp::Url::parse("file:///project/other.ts").unwrap();
+        let action = CodeAction {
+            category: ActionCategory::QuickFix,
+            rule_name: None,
+            suggestion: CodeSuggestion {
+                span: TextRange::new(TextSize::from(13), TextSize::from(16)),
+                applicability: Applicability::MaybeIncorrect,
+                msg: markup! { "Rename foo to bar." }.to_owned(),
+                suggestion: TextEdit::from_unicode_words(MAIN, "export const bar = 1;"),
+                labels: Vec::new(),
+            },
+            file_edits: vec![FileEdit {
+                path: PathBuf::from("/project/other.ts"),
+                edit: TextEdit::from_unicode_words(OTHER, "import { bar } from \"./main\";"),
+            }],
+        };
+
+        let code_action = super::code_fix_to_lsp(
+            &main_url,
+            &LineIndex::new(MAIN),
+            PositionEncoding::Utf8,
+            &[],
+            action,
+            None,
+            |path| {
+                assert_eq!(path, Path::new("/project/other.ts"));
+                Some((other_url.clone(), LineIndex::new(OTHER)))
+            },
+        )
+        .unwrap();
+
+        let changes = code_action.edit.unwrap().changes.unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[&other_url].as_slice(),
+            &[lsp::TextEdit {
+                range: lsp::Range::new(lsp::Position::new(0, 9), lsp::Position::new(0, 12)),
+                new_text: String::from("bar"),
+            }]
+        );
+    }
 }
@@ -33,18 +33,26 @@ use tower_lsp::lsp_types::DocumentFormattingParams;
 use tower_lsp::lsp_types::FormattingOptions;
 use tower_lsp::lsp_types::InitializeResult;
 use tower_lsp::lsp_types::InitializedParams;
+use tower_lsp::lsp_types::PartialResultParams;
 use tower_lsp::lsp_types::Position;
 use tower_lsp::lsp_types::PublishDiagnosticsParams;
 use tower_lsp::lsp_types::Range;
 use tower_lsp::lsp_types::TextDocumentContentChangeEvent;
 use tower_lsp::lsp_types::TextDocumentIdentifier;
 use tower_lsp::lsp_types::TextDocumentItem;
+use tower_lsp::lsp_types::TextDocumentPositionParams;
 use tower_lsp::lsp_types::TextEdit;
 use tower_lsp::lsp_types::VersionedTextDocumentIdentifier;
 use tower_lsp::lsp_types::WorkDoneProgressParams;
+use tower_lsp::lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams,
+};
 use tower_lsp::lsp_types::{ClientCapabilities, CodeDescription, Url};
 use tower_lsp::lsp_types::{DidChangeConfigurationParams, DidChangeTextDocumentParams};
 use tower_lsp::lsp_types::{DidCloseTextDocumentParams, WorkspaceFolder};
+use tower_lsp::lsp_types::{DocumentHighlight, DocumentHighlightKind, DocumentHighlightParams};
+use tower_lsp::lsp_types::{SelectionRange, SelectionRangeParams};
 use tower_lsp::LspService;
 use tower_lsp::{jsonrpc::Request, lsp_types::InitializeParams};
 
@@ -1733,6 +1741,155 @@ if(a === -0) {}
     Ok(())
 }
 
+#[tokio::test]
+async fn pull_refactor_code_actions() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+        "assists": {
+            "enabled": true,
+            "actions": { "source": { "useArrowFunctionSyntax": "on" } }
+        }
+    }"#;
+
+    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_document("function add(a, b) {\n    return a + b;\n}\n")
+        .await?;
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            lsp::CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 9,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 12,
+                    },
+                },
+                context: lsp::CodeActionContext {
+                    only: Some(vec![lsp::CodeActionKind::REFACTOR]),
+                    ..Default::default()
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let mut changes = HashMap::default();
+    changes.insert(
+        url!("document.js"),
+        vec![
+            TextEdit {
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 0,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 8,
+                    },
+                },
+                new_text: String::from("const"),
+            },
+            TextEdit {
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 12,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 12,
+                    },
+                },
+                new_text: String::from(" = "),
+            },
+            TextEdit {
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 19,
+                    },
+                    end: Position {
+                        line: 1,
+                        character: 10,
+                    },
+                },
+                new_text: String::from("=>"),
+            },
+            TextEdit {
+                range: Range {
+                    start: Position {
+                        line: 1,
+                        character: 17,
+                    },
+                    end: Position {
+                        line: 2,
+                        character: 1,
+                    },
+                },
+                new_text: String::new(),
+            },
+        ],
+    );
+
+    let expected_code_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Convert to an arrow function."),
+        kind: Some(lsp::CodeActionKind::new(
+            "refactor.rewrite.biome.source.useArrowFunctionSyntax",
+        )),
+        diagnostics: None,
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(res, vec![expected_code_action]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn does_not_pull_action_for_disabled_rule_in_override_issue_2782() -> Result<()> {
     let factory = ServerFactory::default();
@@ -2117,6 +2274,239 @@ isSpreadAssignment;
     Ok(())
 }
 
+#[tokio::test]
+async fn document_highlights() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("let count = 0;\ncount += 1;\nconsole.log(count);\n")
+        .await?;
+
+    let highlights: Vec<DocumentHighlight> = server
+        .request(
+            "textDocument/documentHighlight",
+            "document_highlight",
+            DocumentHighlightParams {
+                text_document_position_params: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: url!("document.js"),
+                    },
+                    position: Position {
+                        line: 2,
+                        character: 14,
+                    },
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("document highlight returned None")?;
+
+    let highlight = |line, character, kind| DocumentHighlight {
+        range: Range {
+            start: Position { line, character },
+            end: Position {
+                line,
+                character: character + 5,
+            },
+        },
+        kind: Some(kind),
+    };
+    assert_eq!(
+        highlights,
+        vec![
+            highlight(0, 4, DocumentHighlightKind::WRITE),
+            highlight(1, 0, DocumentHighlightKind::WRITE),
+            highlight(2, 12, DocumentHighlightKind::READ),
+        ]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn selection_ranges() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("foo(bar + 1);\n").await?;
+
+    let selection_ranges: Vec<SelectionRange> = server
+        .request(
+            "textDocument/selectionRange",
+            "selection_range",
+            SelectionRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                positions: vec![Position {
+                    line: 0,
+                    character: 5,
+                }],
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("selection range returned None")?;
+
+    let [selection_range] = selection_ranges.as_slice() else {
+        panic!("expected a single selection range, got {selection_ranges:?}");
+    };
+    let mut ranges = Vec::new();
+    let mut current = Some(selection_range);
+    while let Some(selection_range) = current {
+        let Range { start, end } = selection_range.range;
+        ranges.push((start.character, end.character));
+        current = selection_range.parent.as_deref();
+    }
+    // `bar`, `bar + 1`, `(bar + 1)`, `foo(bar + 1)`, `foo(bar + 1);`, then the whole module
+    assert_eq!(
+        ranges[..5],
+        [(4, 7), (4, 11), (3, 12), (0, 12), (0, 13)],
+        "unexpected ranges {ranges:?}"
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn call_hierarchy() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("function greet() {\n\tformat();\n}\nconst format = () => {};\ngreet();\n")
+        .await?;
+
+    let items: Vec<CallHierarchyItem> = server
+        .request(
+            "textDocument/prepareCallHierarchy",
+            "prepare_call_hierarchy",
+            CallHierarchyPrepareParams {
+                text_document_position_params: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: url!("document.js"),
+                    },
+                    position: Position {
+                        line: 1,
+                        character: 2,
+                    },
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("prepare call hierarchy returned None")?;
+
+    let [format] = items.as_slice() else {
+        panic!("expected a single item, got {items:?}");
+    };
+    assert_eq!(format.name, "format");
+    assert_eq!(format.selection_range.start, Position::new(3, 6));
+
+    let incoming_calls: Vec<CallHierarchyIncomingCall> = server
+        .request(
+            "callHierarchy/incomingCalls",
+            "incoming_calls",
+            CallHierarchyIncomingCallsParams {
+                item: format.clone(),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("incoming calls returned None")?;
+
+    let [incoming_call] = incoming_calls.as_slice() else {
+        panic!("expected a single incoming call, got {incoming_calls:?}");
+    };
+    assert_eq!(incoming_call.from.name, "greet");
+    assert_eq!(
+        incoming_call.from_ranges,
+        vec![Range::new(Position::new(1, 1), Position::new(1, 7))]
+    );
+
+    let outgoing_calls: Vec<CallHierarchyOutgoingCall> = server
+        .request(
+            "callHierarchy/outgoingCalls",
+            "outgoing_calls",
+            CallHierarchyOutgoingCallsParams {
+                item: incoming_call.from.clone(),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("outgoing calls returned None")?;
+
+    let [outgoing_call] = outgoing_calls.as_slice() else {
+        panic!("expected a single outgoing call, got {outgoing_calls:?}");
+    };
+    assert_eq!(outgoing_call.to.name, "format");
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn format_with_syntax_errors() -> Result<()> {
     let factory = ServerFactory::default();
@@ -695,4 +695,8 @@ impl AnyParse {
     pub fn has_errors(&self) -> bool {
         self.diagnostics.iter().any(|diag| diag.is_error())
     }
+    /// Returns the number of syntax nodes of the parsed tree
+    pub fn node_count(&self) -> usize {
+        self.root.node_count()
+    }
 }
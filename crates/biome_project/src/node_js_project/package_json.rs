@@ -19,6 +19,9 @@ pub struct PackageJson {
     pub optional_dependencies: Dependencies,
     pub license: Option<(String, TextRange)>,
     pub r#type: Option<PackageType>,
+    pub engines: Engines,
+    pub browserslist: Option<Browserslist>,
+    pub side_effects: Option<SideEffects>,
 }
 
 impl Manifest for PackageJson {
@@ -41,11 +44,56 @@ impl Dependencies {
         self.0.contains_key(specifier)
     }
 
+    /// Returns the names of the dependencies
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
     pub fn add(&mut self, dependency: impl Into<String>, version: impl Into<Version>) {
         self.0.insert(dependency.into(), version.into());
     }
 }
 
+/// The versions of the runtimes supported by the package, such as `"node": ">=18"`
+#[derive(Debug, Default, Clone, biome_deserialize_macros::Deserializable)]
+pub struct Engines(FxHashMap<String, String>);
+
+impl Engines {
+    /// Returns the version range of the runtime `name`
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    /// Returns the version range of Node.js
+    pub fn node(&self) -> Option<&str> {
+        self.get("node")
+    }
+}
+
+/// The queries of the browsers targeted by the package, such as `"> 0.5%, not dead"`.
+///
+/// When the queries are declared by environment, only the queries of the `production`
+/// environment are kept, because it is the default environment of Browserslist.
+#[derive(Debug, Default, Clone)]
+pub struct Browserslist(Vec<String>);
+
+impl Browserslist {
+    pub fn queries(&self) -> &[String] {
+        &self.0
+    }
+}
+
+/// The modules of the package that have side effects, according to the `sideEffects` field.
+///
+/// The bundlers remove the modules that don't have side effects when none of their exports are used.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SideEffects {
+    /// `true` if all the modules have side effects, `false` if none of them have
+    Bool(bool),
+    /// The globs of the modules that have side effects, such as `"*.css"`
+    Files(Vec<String>),
+}
+
 #[derive(Debug, Clone)]
 pub enum Version {
     SemVer(node_semver::Version),
@@ -136,6 +184,21 @@ impl DeserializationVisitor for PackageJsonVisitor {
                 "type" => {
                     result.r#type = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "engines" => {
+                    if let Some(engines) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.engines = engines;
+                    }
+                }
+                "browserslist" => {
+                    result.browserslist =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "sideEffects" => {
+                    result.side_effects =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 _ => {
                     // each package can add their own field, so we should ignore any extraneous key
                     // and only deserialize the ones that Biome deems important
@@ -160,6 +223,112 @@ impl Deserializable for Version {
     }
 }
 
+impl Deserializable for Browserslist {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(BrowserslistVisitor, name, diagnostics)
+    }
+}
+
+struct BrowserslistVisitor;
+impl DeserializationVisitor for BrowserslistVisitor {
+    type Output = Browserslist;
+
+    const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::STR
+        .union(DeserializableTypes::ARRAY)
+        .union(DeserializableTypes::MAP);
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(Browserslist(vec![value.text().to_string()]))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let queries = items
+            .flatten()
+            .filter_map(|item| String::deserialize(&item, name, diagnostics))
+            .collect();
+        Some(Browserslist(queries))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Browserslist::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if key_text.text() == "production" {
+                if let Some(queries) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                    result = queries;
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+impl Deserializable for SideEffects {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(SideEffectsVisitor, name, diagnostics)
+    }
+}
+
+struct SideEffectsVisitor;
+impl DeserializationVisitor for SideEffectsVisitor {
+    type Output = SideEffects;
+
+    const EXPECTED_TYPE: DeserializableTypes =
+        DeserializableTypes::BOOL.union(DeserializableTypes::ARRAY);
+
+    fn visit_bool(
+        self,
+        value: bool,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(SideEffects::Bool(value))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let files = items
+            .flatten()
+            .filter_map(|item| String::deserialize(&item, name, diagnostics))
+            .collect();
+        Some(SideEffects::Files(files))
+    }
+}
+
 #[derive(Debug, Default, Clone, Eq, PartialEq, biome_deserialize_macros::Deserializable)]
 pub enum PackageType {
     #[default]
@@ -830,6 +830,13 @@ impl SendNode {
             None
         }
     }
+
+    /// Returns the number of nodes in the tree rooted at this node, including itself
+    pub fn node_count(&self) -> usize {
+        cursor::SyntaxNode::new_root(self.green.clone())
+            .descendants()
+            .count()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1,7 +1,8 @@
 use crate::matcher::Pattern;
 use crate::settings::Settings;
 use crate::{DynRef, WorkspaceError};
-use biome_analyze::AnalyzerRules;
+use biome_analyze::options::RuleOptions;
+use biome_analyze::{AnalyzerRules, RuleKey};
 use biome_configuration::diagnostics::{CantLoadExtendFile, EditorConfigDiagnostic};
 use biome_configuration::VERSION;
 use biome_configuration::{
@@ -11,10 +12,12 @@ use biome_configuration::{
 use biome_console::markup;
 use biome_css_analyze::METADATA as css_lint_metadata;
 use biome_deserialize::json::deserialize_from_json_str;
-use biome_deserialize::{Deserialized, Merge};
+use biome_deserialize::{Deserialized, Merge, StringSet};
 use biome_diagnostics::{DiagnosticExt, Error, Severity};
 use biome_fs::{AutoSearchResult, ConfigName, FileSystem, OpenOptions};
 use biome_graphql_analyze::METADATA as graphql_lint_metadata;
+use biome_html_analyze::METADATA as html_lint_metadata;
+use biome_js_analyze::options::OrganizeImports as OrganizeImportsOptions;
 use biome_js_analyze::METADATA as js_lint_metadata;
 use biome_json_analyze::METADATA as json_lint_metadata;
 use biome_json_formatter::context::JsonFormatOptions;
@@ -117,6 +120,7 @@ impl LoadedConfiguration {
         Ok(Self {
             configuration: match partial_configuration {
                 Some(mut partial_configuration) => {
+                    partial_configuration.interpolate_environment_variables(&mut diagnostics);
                     partial_configuration.apply_extends(
                         fs,
                         &configuration_file_path,
@@ -374,7 +378,17 @@ pub fn to_analyzer_rules(settings: &Settings, path: &Path) -> AnalyzerRules {
         push_to_analyzer_rules(rules, css_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_rules(rules, json_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_rules(rules, graphql_lint_metadata.deref(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, html_lint_metadata.deref(), &mut analyzer_rules);
     }
+    analyzer_rules.push_rule(
+        RuleKey::new("source", "organizeImports"),
+        RuleOptions::new(
+            OrganizeImportsOptions {
+                merge_type_imports: settings.organize_imports.merge_type_imports,
+            },
+            None,
+        ),
+    );
 
     overrides.override_analyzer_rules(path, analyzer_rules)
 }
@@ -397,6 +411,8 @@ pub trait PartialConfigurationExt {
 
     fn migrate_deprecated_fields(&mut self);
 
+    fn interpolate_environment_variables(&mut self, diagnostics: &mut Vec<Error>);
+
     fn retrieve_gitignore_matches(
         &self,
         file_system: &DynRef<'_, dyn FileSystem>,
@@ -426,7 +442,11 @@ impl PartialConfigurationExt for PartialConfiguration {
         let (configurations, errors): (Vec<_>, Vec<_>) = deserialized
             .into_iter()
             .map(|d| d.consume())
-            .map(|(config, diagnostics)| (config.unwrap_or_default(), diagnostics))
+            .map(|(config, mut diagnostics)| {
+                let mut config = config.unwrap_or_default();
+                config.interpolate_environment_variables(&mut diagnostics);
+                (config, diagnostics)
+            })
             .unzip();
 
         let extended_configuration = configurations.into_iter().reduce(
@@ -564,6 +584,69 @@ impl PartialConfigurationExt for PartialConfiguration {
         }
     }
 
+    /// Replaces the references to environment variables, such as `${HOME}`, in the paths
+    /// and in the globals of the configuration.
+    ///
+    /// A diagnostic is emitted for each variable that isn't set.
+    fn interpolate_environment_variables(&mut self, diagnostics: &mut Vec<Error>) {
+        let mut interpolate = |value: &mut String| {
+            match interpolate_environment_variables(value, |name| std::env::var(name).ok()) {
+                Ok(interpolated) => *value = interpolated,
+                Err(name) => diagnostics.push(
+                    BiomeDiagnostic::invalid_configuration(markup! {
+                        "The environment variable "<Emphasis>{name}</Emphasis>" used in the configuration isn't set."
+                    })
+                    .into(),
+                ),
+            }
+        };
+        let mut interpolate_set = |set: &mut Option<StringSet>| {
+            if let Some(set) = set.as_mut() {
+                *set = std::mem::take(set)
+                    .into_iter()
+                    .map(|mut value| {
+                        interpolate(&mut value);
+                        value
+                    })
+                    .collect();
+            }
+        };
+
+        interpolate_set(&mut self.extends);
+        if let Some(files) = self.files.as_mut() {
+            interpolate_set(&mut files.include);
+            interpolate_set(&mut files.ignore);
+        }
+        if let Some(formatter) = self.formatter.as_mut() {
+            interpolate_set(&mut formatter.include);
+            interpolate_set(&mut formatter.ignore);
+        }
+        if let Some(linter) = self.linter.as_mut() {
+            interpolate_set(&mut linter.include);
+            interpolate_set(&mut linter.ignore);
+        }
+        if let Some(organize_imports) = self.organize_imports.as_mut() {
+            interpolate_set(&mut organize_imports.include);
+            interpolate_set(&mut organize_imports.ignore);
+        }
+        if let Some(assists) = self.assists.as_mut() {
+            interpolate_set(&mut assists.include);
+            interpolate_set(&mut assists.ignore);
+        }
+        if let Some(javascript) = self.javascript.as_mut() {
+            interpolate_set(&mut javascript.globals);
+        }
+        if let Some(overrides) = self.overrides.as_mut() {
+            for pattern in overrides.0.iter_mut() {
+                interpolate_set(&mut pattern.include);
+                interpolate_set(&mut pattern.ignore);
+            }
+        }
+        if let Some(root) = self.vcs.as_mut().and_then(|vcs| vcs.root.as_mut()) {
+            interpolate(root);
+        }
+    }
+
     /// This function checks if the VCS integration is enabled, and if so, it will attempts to resolve the
     /// VCS root directory and the `.gitignore` file.
     ///
@@ -607,3 +690,90 @@ impl PartialConfigurationExt for PartialConfiguration {
         Ok((None, vec![]))
     }
 }
+
+/// Replaces the references to environment variables in `value`, such as `${HOME}`,
+/// with the values returned by `lookup`.
+///
+/// The text that doesn't reference a valid variable name, such as `${}`, is kept as is.
+///
+/// ## Errors
+///
+/// Returns the name of the first variable that `lookup` can't resolve.
+fn interpolate_environment_variables(
+    value: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(length) = rest[start + 2..].find('}') else {
+            break;
+        };
+        let name = &rest[start + 2..start + 2 + length];
+        let end = start + 3 + length;
+        result.push_str(&rest[..start]);
+        if is_environment_variable_name(name) {
+            result.push_str(&lookup(name).ok_or_else(|| name.to_string())?);
+        } else {
+            result.push_str(&rest[start..end]);
+        }
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+fn is_environment_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::interpolate_environment_variables;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "HOME" => Some("/home/biome".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn interpolates_environment_variables() {
+        assert_eq!(
+            interpolate_environment_variables("${HOME}/src/**", lookup),
+            Ok("/home/biome/src/**".to_string())
+        );
+        assert_eq!(
+            interpolate_environment_variables("a${EMPTY}b${HOME}", lookup),
+            Ok("ab/home/biome".to_string())
+        );
+        assert_eq!(
+            interpolate_environment_variables("src/**", lookup),
+            Ok("src/**".to_string())
+        );
+    }
+
+    #[test]
+    fn keeps_invalid_references() {
+        for value in ["${}", "${1A}", "${HOME", "$HOME", "${a-b}/${"] {
+            assert_eq!(
+                interpolate_environment_variables(value, lookup),
+                Ok(value.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn reports_unset_environment_variables() {
+        assert_eq!(
+            interpolate_environment_variables("${HOME}/${UNSET}", lookup),
+            Err("UNSET".to_string())
+        );
+    }
+}
@@ -54,6 +54,8 @@ pub enum WorkspaceError {
     FileIgnored(FileIgnored),
     /// Emitted when a file could not be parsed because it's larger than the size limit
     FileTooLarge(FileTooLarge),
+    /// Emitted when a file isn't formatted because it has more syntax nodes than the complexity limit
+    FileTooComplex(FileTooComplex),
     /// Diagnostics emitted when querying the file system
     FileSystem(FileSystemDiagnostic),
     /// Raised when there's an issue around the VCS integration
@@ -81,6 +83,14 @@ impl WorkspaceError {
         Self::FileTooLarge(FileTooLarge { path, size, limit })
     }
 
+    pub fn file_too_complex(path: String, complexity: usize, limit: usize) -> Self {
+        Self::FileTooComplex(FileTooComplex {
+            path,
+            complexity,
+            limit,
+        })
+    }
+
     pub fn file_ignored(path: String) -> Self {
         Self::FileIgnored(FileIgnored { path })
     }
@@ -277,6 +287,21 @@ impl Diagnostic for FileTooLarge {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "format",
+    message(
+        message("The file "{self.path}" has "{self.complexity}" syntax nodes, which exceeds the formatter complexity limit of "{self.limit}". The file wasn't formatted. Use the `formatter.maxComplexity` configuration to change this limit."),
+        description = "The file {path} has {complexity} syntax nodes, which exceeds the formatter complexity limit of {limit}. The file wasn't formatted. Use the `formatter.maxComplexity` configuration to change this limit."
+    )
+)]
+pub struct FileTooComplex {
+    #[location(resource)]
+    path: String,
+    complexity: usize,
+    limit: usize,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SourceFileNotSupported {
     file_source: DocumentFileSource,
@@ -538,8 +563,8 @@ impl Advices for ProtectedFileAdvice {
 #[cfg(test)]
 mod test {
     use crate::diagnostics::{
-        CantReadDirectory, CantReadFile, DirtyWorkspace, FileIgnored, FileTooLarge, NotFound,
-        SourceFileNotSupported,
+        CantReadDirectory, CantReadFile, DirtyWorkspace, FileIgnored, FileTooComplex, FileTooLarge,
+        NotFound, SourceFileNotSupported,
     };
     use crate::file_handlers::DocumentFileSource;
     use crate::{TransportError, WorkspaceError};
@@ -643,6 +668,19 @@ mod test {
         )
     }
 
+    #[test]
+    fn file_too_complex() {
+        snap_diagnostic(
+            "file_too_complex",
+            WorkspaceError::FileTooComplex(FileTooComplex {
+                path: "example.js".to_string(),
+                complexity: 500,
+                limit: 100,
+            })
+            .with_file_path("example.js"),
+        )
+    }
+
     #[test]
     fn transport_channel_closed() {
         snap_diagnostic(
@@ -42,6 +42,7 @@ impl LintRulesVisitor {
         biome_graphql_analyze::visit_registry(&mut visitor);
         biome_css_analyze::visit_registry(&mut visitor);
         biome_json_analyze::visit_registry(&mut visitor);
+        biome_html_analyze::visit_registry(&mut visitor);
         biome_js_analyze::visit_registry(&mut visitor);
 
         visitor
@@ -10,13 +10,16 @@ use crate::workspace::{
 use crate::WorkspaceError;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
+use biome_js_formatter::context::JsFormatOptions;
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
-use biome_js_syntax::{JsFileSource, TextRange, TextSize};
+use biome_js_syntax::{JsFileSource, JsLanguage, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
-use regex::{Matches, Regex, RegexBuilder};
+use regex::{Regex, RegexBuilder};
 use std::sync::LazyLock;
 
+use super::frontmatter::Frontmatter;
+use super::markup::{format_markup, MarkupKind};
 use super::SearchCapabilities;
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -34,35 +37,38 @@ impl AstroFileHandler {
     ///
     /// If the frontmatter doesn't exist, an empty string is returned.
     pub fn input(text: &str) -> &str {
-        let mut matches = Self::matches(text);
-        match (matches.next(), matches.next()) {
-            (Some(start), Some(end)) => &text[start.end()..end.start()],
-            _ => "",
-        }
+        Frontmatter::parse(text).map_or("", |frontmatter| frontmatter.content(text))
     }
 
-    /// Returns the start byte offset of the Astro fence
+    /// Returns the start byte offset of the code contained in the frontmatter
     pub fn start(input: &str) -> Option<u32> {
-        ASTRO_FENCE.find_iter(input).next().map(|m| m.end() as u32)
-    }
-
-    fn matches(input: &str) -> Matches {
-        ASTRO_FENCE.find_iter(input)
+        Frontmatter::parse(input).map(|frontmatter| frontmatter.content_range().start as u32)
     }
 
     /// It takes the original content of an Astro file, and new output of an Astro file. The output is only the content contained inside the
-    /// Astro fences. The function replaces `output` inside those fences.
+    /// Astro fences. The function replaces `output` inside those fences, and keeps the rest of the file byte for byte.
     pub fn output(input: &str, output: &str) -> String {
-        let mut matches = Self::matches(input);
-        if let (Some(start), Some(end)) = (matches.next(), matches.next()) {
-            format!(
-                "{}{}{}",
-                &input[..start.end() + 1],
-                output.trim_start(),
-                &input[end.start()..]
-            )
-        } else {
-            input.to_string()
+        match Frontmatter::parse(input) {
+            Some(frontmatter) => frontmatter.replace_content(input, output.trim_start()),
+            None => input.to_string(),
+        }
+    }
+
+    /// It formats the markup that follows the frontmatter of an Astro file: the elements are indented
+    /// according to their nesting, the attributes are normalized, and the expressions are formatted.
+    ///
+    /// The content of the `<script>` and `<style>` elements is left untouched.
+    /// The markup is returned as is when its tags aren't balanced.
+    pub fn format_markup(input: &str, options: &JsFormatOptions) -> String {
+        let markup_start = Frontmatter::parse(input).map_or(0, |frontmatter| frontmatter.end());
+        match format_markup(
+            &input[markup_start..],
+            MarkupKind::Astro,
+            JsFileSource::tsx(),
+            options,
+        ) {
+            Some(markup) => format!("{}{}", &input[..markup_start], markup),
+            None => input.to_string(),
         }
     }
 }
@@ -70,7 +76,10 @@ impl AstroFileHandler {
 impl ExtensionHandler for AstroFileHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                selection_ranges: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: None,
                 debug_control_flow: None,
@@ -80,6 +89,8 @@ impl ExtensionHandler for AstroFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                document_highlights: None,
+                call_hierarchy: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -87,6 +98,8 @@ impl ExtensionHandler for AstroFileHandler {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                compare_syntax: None,
+                format_embedding: Some(format_embedding),
             },
             // TODO: We should be able to search JS portions already
             search: SearchCapabilities { search: None },
@@ -126,6 +139,32 @@ fn format(
 ) -> Result<Printed, WorkspaceError> {
     javascript::format(biome_path, document_file_source, parse, settings)
 }
+
+fn format_embedding(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    content: &str,
+    printed: Printed,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let content = if printed.as_code().is_empty() {
+        content.to_string()
+    } else {
+        AstroFileHandler::output(content, printed.as_code())
+    };
+    // The formatting of the markup is experimental, and it's opt-in
+    let markup_formatting = settings
+        .settings()
+        .is_some_and(|settings| settings.markup_formatting_enabled(biome_path));
+    let code = if markup_formatting {
+        let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+        AstroFileHandler::format_markup(&content, &options)
+    } else {
+        content
+    };
+    Ok(Printed::new(code, None, vec![], vec![]))
+}
+
 pub(crate) fn format_range(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
@@ -158,6 +197,11 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     javascript::fix_all(params)
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+fn organize_imports(
+    path: &BiomePath,
+    file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    javascript::organize_imports(path, file_source, parse, settings)
 }
@@ -0,0 +1,161 @@
+use std::ops::Range;
+
+/// The frontmatter of a document: a block at the start of the document, delimited by two `---` lines.
+///
+/// ```text
+/// ---
+/// const title = "Home";
+/// ---
+/// <h1>{title}</h1>
+/// ```
+///
+/// The frontmatter is the script of an Astro component, and the YAML metadata of a Markdown document.
+/// Only the blank lines can precede the opening fence, the `---` lines of the rest of the document aren't fences.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct Frontmatter {
+    /// The byte range of the content between the fences.
+    /// It starts after the line break of the opening fence, and ends at the start of the line of the closing fence.
+    content: Range<usize>,
+    /// The byte offset of the end of the closing fence, where the rest of the document starts
+    end: usize,
+}
+
+impl Frontmatter {
+    /// Returns the frontmatter at the start of `text`, if any
+    pub(crate) fn parse(text: &str) -> Option<Self> {
+        let mut lines = Lines::new(text);
+        let opening = lines.find(|line| !line.text.trim().is_empty())?;
+        if !is_fence(opening.text) || opening.line_break_end == opening.end {
+            return None;
+        }
+        let closing = lines.find(|line| is_fence(line.text))?;
+        Some(Self {
+            content: opening.line_break_end..closing.start,
+            end: closing.end,
+        })
+    }
+
+    /// Returns the byte range of the content between the fences
+    pub(crate) fn content_range(&self) -> Range<usize> {
+        self.content.clone()
+    }
+
+    /// Returns the content between the fences
+    pub(crate) fn content<'a>(&self, text: &'a str) -> &'a str {
+        &text[self.content_range()]
+    }
+
+    /// Returns the byte offset of the end of the closing fence, where the rest of the document starts
+    pub(crate) fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Replaces the content between the fences with `content`, and keeps the rest of `text` byte for byte
+    pub(crate) fn replace_content(&self, text: &str, content: &str) -> String {
+        format!(
+            "{}{}{}",
+            &text[..self.content.start],
+            content,
+            &text[self.content.end..]
+        )
+    }
+}
+
+/// Returns `true` if `line` is a fence of the frontmatter: `---`, optionally followed by spaces
+fn is_fence(line: &str) -> bool {
+    line.strip_prefix("---")
+        .is_some_and(|rest| rest.chars().all(|c| matches!(c, ' ' | '\t')))
+}
+
+/// A line of a document, with the byte offsets of its text and of its line break
+struct Line<'a> {
+    /// The text of the line, without its line break
+    text: &'a str,
+    start: usize,
+    /// The end of the text, where the line break starts
+    end: usize,
+    /// The end of the line break, which is equal to `end` on the last line
+    line_break_end: usize,
+}
+
+/// The lines of a document, whose line breaks are `\n` or `\r\n`
+struct Lines<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Lines<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, offset: 0 }
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.text.len() {
+            return None;
+        }
+        let start = self.offset;
+        let rest = &self.text[start..];
+        let (end, line_break_end) = match rest.find('\n') {
+            Some(index) if rest[..index].ends_with('\r') => (start + index - 1, start + index + 1),
+            Some(index) => (start + index, start + index + 1),
+            None => (self.text.len(), self.text.len()),
+        };
+        self.offset = line_break_end;
+        Some(Line {
+            text: &self.text[start..end],
+            start,
+            end,
+            line_break_end,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Frontmatter;
+
+    #[test]
+    fn parses_the_frontmatter_at_the_start() {
+        let text = "---\ntitle: Home\n---\n# Home\n";
+        let frontmatter = Frontmatter::parse(text).unwrap();
+        assert_eq!(frontmatter.content(text), "title: Home\n");
+        assert_eq!(&text[frontmatter.end()..], "\n# Home\n");
+    }
+
+    #[test]
+    fn parses_the_frontmatter_with_carriage_returns() {
+        let text = "---\r\ntitle: Home\r\n---\r\n# Home";
+        let frontmatter = Frontmatter::parse(text).unwrap();
+        assert_eq!(frontmatter.content(text), "title: Home\r\n");
+        assert_eq!(&text[frontmatter.end()..], "\r\n# Home");
+    }
+
+    #[test]
+    fn parses_the_empty_frontmatter() {
+        let text = "---\n---";
+        let frontmatter = Frontmatter::parse(text).unwrap();
+        assert_eq!(frontmatter.content(text), "");
+        assert_eq!(frontmatter.end(), text.len());
+    }
+
+    #[test]
+    fn ignores_the_fences_after_the_start() {
+        assert_eq!(Frontmatter::parse("<hr />\n---\ntext\n---\n"), None);
+        assert_eq!(Frontmatter::parse("---\ntitle: Home\n"), None);
+        assert_eq!(Frontmatter::parse("----\ntitle: Home\n----\n"), None);
+    }
+
+    #[test]
+    fn keeps_the_rest_of_the_document() {
+        let text = "\n---  \r\ntitle:   Home\n---\n\n# Home  \r\n";
+        let frontmatter = Frontmatter::parse(text).unwrap();
+        assert_eq!(
+            frontmatter.replace_content(text, "title: Home\n"),
+            "\n---  \r\ntitle: Home\n---\n\n# Home  \r\n"
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
-    ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, SearchCapabilities,
+    compare_formatted_syntax, is_diagnostic_error, selection_ranges, AnalyzerVisitorBuilder,
+    CodeActionsParams, DocumentFileSource, ExtensionHandler, FixAllParams, LintParams, LintResults,
+    ParseResult, SearchCapabilities,
 };
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
@@ -145,6 +146,8 @@ impl ServiceLanguage for GraphqlLanguage {
         AnalyzerOptions {
             configuration: AnalyzerConfiguration::default(),
             file_path: path.to_path_buf(),
+            deadline: None,
+            profiler: None,
         }
     }
 }
@@ -155,7 +158,10 @@ pub(crate) struct GraphqlFileHandler;
 impl ExtensionHandler for GraphqlFileHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                selection_ranges: Some(selection_ranges::<GraphqlLanguage>),
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -165,6 +171,8 @@ impl ExtensionHandler for GraphqlFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                document_highlights: None,
+                call_hierarchy: None,
                 fix_all: Some(fix_all),
                 organize_imports: None,
             },
@@ -172,6 +180,8 @@ impl ExtensionHandler for GraphqlFileHandler {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                compare_syntax: Some(compare_formatted_syntax::<GraphqlLanguage>),
+                format_embedding: None,
             },
             search: SearchCapabilities { search: None },
         }
@@ -292,8 +302,10 @@ fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting GraphQL file", path =? params.path, language =? params.language).in_scope(
         move || {
             let workspace_settings = &params.workspace;
-            let analyzer_options = workspace_settings
+            let mut analyzer_options = workspace_settings
                 .analyzer_options::<GraphqlLanguage>(params.path, &params.language);
+            analyzer_options.deadline = params.deadline;
+            analyzer_options.profiler = params.profiler.clone();
             let tree = params.parse.tree();
 
             let has_only_filter = !params.only.is_empty();
@@ -363,7 +375,7 @@ fn lint(params: LintParams) -> LintResults {
 
                     if diagnostic_count <= params.max_diagnostics {
                         for action in signal.actions() {
-                            if !action.is_suppression() {
+                            if !action.is_suppression() && action.file_edits.is_empty() {
                                 diagnostic = diagnostic.add_code_suggestion(action.into());
                             }
                         }
@@ -402,6 +414,9 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        css_custom_properties: _,
+        module_exports: _,
+        ambient_globals: _,
         language,
         only,
         skip,
@@ -446,6 +461,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                             .rule_name
                             .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
                         suggestion: item.suggestion,
+                        file_edits: item.file_edits,
                     }
                 }));
 
@@ -491,9 +507,10 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let mut actions = Vec::new();
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = params
+    let mut analyzer_options = params
         .workspace
         .analyzer_options::<GraphqlLanguage>(params.biome_path, &params.document_file_source);
+    analyzer_options.deadline = params.deadline;
     loop {
         let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
             let current_diagnostic = signal.diagnostic();
@@ -505,8 +522,9 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             }
 
             for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
+                // suppression actions should not be part of the fixes (safe or suggested),
+                // and the edits of the other files are only applied by the editors
+                if action.is_suppression() || !action.file_edits.is_empty() {
                     continue;
                 }
 
@@ -540,6 +558,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                 if let (root, Some((range, _))) =
                     action.mutation.commit_with_text_range_and_edit(true)
                 {
+                    let fix_action = FixAction::new(action.rule_name, tree.syntax(), &root, range);
                     tree = match GraphqlRoot::cast(root) {
                         Some(tree) => tree,
                         None => {
@@ -552,12 +571,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                             ));
                         }
                     };
-                    actions.push(FixAction {
-                        rule_name: action
-                            .rule_name
-                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
-                        range,
-                    });
+                    actions.push(fix_action);
                 }
             }
             None => {
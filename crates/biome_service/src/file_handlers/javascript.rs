@@ -1,13 +1,18 @@
 use super::{
-    search, AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
-    ExtensionHandler, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, SearchCapabilities,
+    compare_formatted_syntax_with, search, selection_ranges, AnalyzerCapabilities,
+    AnalyzerVisitorBuilder, CallHierarchy, CallHierarchyTarget, CallTarget, CodeActionsParams,
+    DebugCapabilities, ExtensionHandler, FormatterCapabilities, FormatterChanges, ImportedFunction,
+    LintParams, LintResults, OutgoingCalls, ParseResult, ParserCapabilities, SearchCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
 use crate::file_handlers::{is_diagnostic_error, FixAllParams};
 use crate::settings::{LinterSettings, OverrideSettings, Settings};
-use crate::workspace::{DocumentFileSource, OrganizeImportsResult};
+use crate::workspace::{
+    CallHierarchyItem, CallHierarchyItemKind, DocumentFileSource, DocumentHighlight,
+    DocumentHighlightKind, DocumentHighlightsResult, IncomingCall, OrganizeImportsResult,
+    OutgoingCall, SyntaxTreeDifference,
+};
 use crate::{
     settings::{
         FormatSettings, LanguageListSettings, LanguageSettings, ServiceLanguage,
@@ -32,16 +37,25 @@ use biome_formatter::{
 };
 use biome_fs::BiomePath;
 use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
-use biome_js_analyze::{analyze, analyze_with_inspect_matcher, ControlFlowGraph};
+use biome_js_analyze::{
+    analyze, analyze_with_inspect_matcher, collect_module_exports, ControlFlowGraph,
+    ProjectModuleExports,
+};
 use biome_js_formatter::context::trailing_commas::TrailingCommas;
 use biome_js_formatter::context::{
-    ArrowParentheses, BracketSameLine, JsFormatOptions, QuoteProperties, Semicolons,
+    ArrowParentheses, BracketSameLine, EmbeddedLanguageFormatting, JsFormatOptions,
+    QuoteProperties, Semicolons, StringEscapes, VerticalAlignment,
 };
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
 use biome_js_semantic::{semantic_model, SemanticModelOptions};
 use biome_js_syntax::{
-    AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
+    AnyJsExpression, AnyJsRoot, JsArrowFunctionExpression, JsCallExpression, JsFileSource,
+    JsFunctionDeclaration, JsFunctionExportDefaultDeclaration, JsFunctionExpression,
+    JsIdentifierAssignment, JsIdentifierBinding, JsImport, JsLanguage, JsNamedImportSpecifier,
+    JsReferenceIdentifier, JsShorthandNamedImportSpecifier, JsSyntaxKind, JsSyntaxNode,
+    JsVariableDeclarator, JsxReferenceIdentifier, TextRange, TextSize, TokenAtOffset,
+    TsIdentifierBinding,
 };
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache};
@@ -49,6 +63,7 @@ use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::fmt::Debug;
+use std::iter;
 use tracing::{debug, debug_span, error, info, trace, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -68,6 +83,11 @@ pub struct JsFormatterSettings {
     pub indent_style: Option<IndentStyle>,
     pub enabled: Option<bool>,
     pub attribute_position: Option<AttributePosition>,
+    pub vertical_alignment: Option<VerticalAlignment>,
+    pub string_escapes: Option<StringEscapes>,
+    pub embedded_language_formatting: Option<EmbeddedLanguageFormatting>,
+    pub max_inline_specifiers: Option<u16>,
+    pub markup_formatting: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -177,7 +197,20 @@ impl ServiceLanguage for JsLanguage {
                 .and_then(|l| l.attribute_position)
                 .or(global.and_then(|g| g.attribute_position))
                 .unwrap_or_default(),
-        );
+        )
+        .with_vertical_alignment(
+            language
+                .and_then(|l| l.vertical_alignment)
+                .unwrap_or_default(),
+        )
+        .with_string_escapes(language.and_then(|l| l.string_escapes).unwrap_or_default())
+        .with_embedded_language_formatting(
+            language
+                .and_then(|l| l.embedded_language_formatting)
+                .unwrap_or_default(),
+        )
+        .with_max_inline_specifiers(language.and_then(|l| l.max_inline_specifiers))
+        .with_comments(global.and_then(|g| g.comments).unwrap_or_default());
 
         if let Some(overrides) = overrides {
             overrides.override_js_format_options(path, options)
@@ -260,11 +293,26 @@ impl ServiceLanguage for JsLanguage {
             globals,
             preferred_quote,
             jsx_runtime,
+            aria_roles: global
+                .map(|g| {
+                    g.linter
+                        .aria
+                        .roles
+                        .iter()
+                        .map(|(role, standard_role)| (role.clone(), standard_role.clone()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            aria_properties: global
+                .map(|g| g.linter.aria.properties.iter().cloned().collect())
+                .unwrap_or_default(),
         };
 
         AnalyzerOptions {
             configuration,
             file_path: path.to_path_buf(),
+            deadline: None,
+            profiler: None,
         }
     }
 }
@@ -275,7 +323,10 @@ pub(crate) struct JsFileHandler;
 impl ExtensionHandler for JsFileHandler {
     fn capabilities(&self) -> super::Capabilities {
         super::Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                selection_ranges: Some(selection_ranges::<JsLanguage>),
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: Some(debug_control_flow),
@@ -286,12 +337,21 @@ impl ExtensionHandler for JsFileHandler {
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
                 rename: Some(rename),
+                document_highlights: Some(document_highlights),
+                call_hierarchy: Some(CallHierarchy {
+                    prepare: prepare_call_hierarchy,
+                    exported_function,
+                    incoming_calls,
+                    outgoing_calls,
+                }),
                 organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                compare_syntax: Some(compare_formatted_syntax),
+                format_embedding: None,
             },
             search: SearchCapabilities {
                 search: Some(search),
@@ -300,6 +360,33 @@ impl ExtensionHandler for JsFileHandler {
     }
 }
 
+/// Compares the syntax of the formatted code, knowing that the formatter sorts the modifiers of the classes and of the types
+fn compare_formatted_syntax(
+    original: &AnyParse,
+    formatted: &AnyParse,
+) -> Option<SyntaxTreeDifference> {
+    compare_formatted_syntax_with::<JsLanguage>(
+        original,
+        formatted,
+        &FormatterChanges {
+            is_sorted_modifier_list: |kind| {
+                matches!(
+                    kind,
+                    JsSyntaxKind::JS_CONSTRUCTOR_MODIFIER_LIST
+                        | JsSyntaxKind::JS_METHOD_MODIFIER_LIST
+                        | JsSyntaxKind::JS_PROPERTY_MODIFIER_LIST
+                        | JsSyntaxKind::TS_INDEX_SIGNATURE_MODIFIER_LIST
+                        | JsSyntaxKind::TS_METHOD_SIGNATURE_MODIFIER_LIST
+                        | JsSyntaxKind::TS_PROPERTY_PARAMETER_MODIFIER_LIST
+                        | JsSyntaxKind::TS_PROPERTY_SIGNATURE_MODIFIER_LIST
+                        | JsSyntaxKind::TS_TYPE_PARAMETER_MODIFIER_LIST
+                )
+            },
+            ..Default::default()
+        },
+    )
+}
+
 fn parse(
     biome_path: &BiomePath,
     file_source: DocumentFileSource,
@@ -380,6 +467,7 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
         &options,
         JsFileSource::default(),
         None,
+        ProjectModuleExports::default(),
         |_| ControlFlow::<Never>::Continue(()),
     );
 
@@ -416,9 +504,15 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 };
             };
             let tree = params.parse.tree();
-            let analyzer_options = &params
+            let mut analyzer_options = params
                 .workspace
                 .analyzer_options::<JsLanguage>(params.path, &params.language);
+            analyzer_options.deadline = params.deadline;
+            analyzer_options.profiler = params.profiler.clone();
+            analyzer_options
+                .configuration
+                .globals
+                .extend(params.ambient_globals);
 
             let rules = params
                 .workspace
@@ -454,9 +548,10 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
             let (_, analyze_diagnostics) = analyze(
                 &tree,
                 filter,
-                analyzer_options,
+                &analyzer_options,
                 file_source,
                 params.manifest,
+                params.module_exports,
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
@@ -488,7 +583,7 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
 
                         if diagnostic_count <= params.max_diagnostics {
                             for action in signal.actions() {
-                                if !action.is_suppression() {
+                                if !action.is_suppression() && action.file_edits.is_empty() {
                                     diagnostic = diagnostic.add_code_suggestion(action.into());
                                 }
                             }
@@ -527,6 +622,9 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest,
+        css_custom_properties: _,
+        module_exports,
+        ambient_globals,
         language,
         only,
         skip,
@@ -534,7 +632,11 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
     debug_span!("Code actions JavaScript", range =? range, path =? path).in_scope(move || {
         let tree = parse.tree();
         trace_span!("Parsed file", tree =? tree).in_scope(move || {
-            let analyzer_options = workspace.analyzer_options::<JsLanguage>(path, &language);
+            let mut analyzer_options = workspace.analyzer_options::<JsLanguage>(path, &language);
+            analyzer_options
+                .configuration
+                .globals
+                .extend(ambient_globals);
             let mut actions = Vec::new();
             let (enabled_rules, disabled_rules) =
                 AnalyzerVisitorBuilder::new(params.workspace.settings())
@@ -568,6 +670,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 &analyzer_options,
                 source_type,
                 manifest,
+                module_exports,
                 |signal| {
                     actions.extend(signal.actions().into_code_action_iter().map(|item| {
                         CodeAction {
@@ -576,6 +679,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                                 .rule_name
                                 .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
                             suggestion: item.suggestion,
+                            file_edits: item.file_edits,
                         }
                     }));
 
@@ -627,9 +731,14 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let mut actions = Vec::new();
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = params
+    let mut analyzer_options = params
         .workspace
         .analyzer_options::<JsLanguage>(params.biome_path, &params.document_file_source);
+    analyzer_options.deadline = params.deadline;
+    analyzer_options
+        .configuration
+        .globals
+        .extend(params.ambient_globals);
     loop {
         let (action, _) = analyze(
             &tree,
@@ -637,6 +746,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             &analyzer_options,
             file_source,
             params.manifest.clone(),
+            params.module_exports.clone(),
             |signal| {
                 let current_diagnostic = signal.diagnostic();
 
@@ -647,8 +757,9 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                 }
 
                 for action in signal.actions() {
-                    // suppression actions should not be part of the fixes (safe or suggested)
-                    if action.is_suppression() {
+                    // suppression actions should not be part of the fixes (safe or suggested),
+                    // and the edits of the other files are only applied by the editors
+                    if action.is_suppression() || !action.file_edits.is_empty() {
                         continue;
                     }
 
@@ -683,6 +794,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                 if let (root, Some((range, _))) =
                     action.mutation.commit_with_text_range_and_edit(true)
                 {
+                    let fix_action = FixAction::new(action.rule_name, tree.syntax(), &root, range);
                     tree = match AnyJsRoot::cast(root) {
                         Some(tree) => tree,
                         None => {
@@ -695,12 +807,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                             ));
                         }
                     };
-                    actions.push(FixAction {
-                        rule_name: action
-                            .rule_name
-                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
-                        range,
-                    });
+                    actions.push(fix_action);
                 }
             }
             None => {
@@ -845,8 +952,324 @@ fn rename(
     }
 }
 
-pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+fn document_highlights(parse: AnyParse, symbol_at: TextSize) -> DocumentHighlightsResult {
+    let root: AnyJsRoot = parse.tree();
+    let Some(node) = root
+        .syntax()
+        .token_at_offset(symbol_at)
+        .find(|token| token.text_trimmed_range().contains_inclusive(symbol_at))
+        .and_then(|token| token.parent())
+    else {
+        return DocumentHighlightsResult::default();
+    };
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let binding = if let Some(binding) = JsIdentifierBinding::cast_ref(&node) {
+        model.as_binding(&binding)
+    } else if let Some(binding) = TsIdentifierBinding::cast_ref(&node) {
+        model.as_binding(&binding)
+    } else if let Some(reference) = JsReferenceIdentifier::cast_ref(&node) {
+        match model.binding(&reference) {
+            Some(binding) => binding,
+            None => return DocumentHighlightsResult::default(),
+        }
+    } else if let Some(reference) = JsIdentifierAssignment::cast_ref(&node) {
+        match model.binding(&reference) {
+            Some(binding) => binding,
+            None => return DocumentHighlightsResult::default(),
+        }
+    } else if let Some(reference) = JsxReferenceIdentifier::cast_ref(&node) {
+        match model.binding(&reference) {
+            Some(binding) => binding,
+            None => return DocumentHighlightsResult::default(),
+        }
+    } else {
+        return DocumentHighlightsResult::default();
+    };
+
+    let declaration = DocumentHighlight {
+        range: binding.syntax().text_trimmed_range(),
+        kind: DocumentHighlightKind::Write,
+    };
+    let mut highlights: Vec<_> = iter::once(declaration)
+        .chain(binding.all_references().map(|reference| DocumentHighlight {
+            range: reference.syntax().text_trimmed_range(),
+            kind: if reference.is_write() {
+                DocumentHighlightKind::Write
+            } else {
+                DocumentHighlightKind::Read
+            },
+        }))
+        .collect();
+    highlights.sort_by_key(|highlight| highlight.range.start());
+    DocumentHighlightsResult { highlights }
+}
+
+fn prepare_call_hierarchy(
+    path: &BiomePath,
+    parse: AnyParse,
+    symbol_at: TextSize,
+) -> Option<CallHierarchyTarget> {
+    let root: AnyJsRoot = parse.tree();
+    let node = root
+        .syntax()
+        .token_at_offset(symbol_at)
+        .find(|token| token.text_trimmed_range().contains_inclusive(symbol_at))
+        .and_then(|token| token.parent())?;
+    let binding = match JsReferenceIdentifier::cast_ref(&node) {
+        Some(reference) => {
+            let model = semantic_model(&root, SemanticModelOptions::default());
+            JsIdentifierBinding::cast(model.binding(&reference)?.syntax().clone())?
+        }
+        None => JsIdentifierBinding::cast(node)?,
+    };
+    if let Some(function) = imported_function(&binding) {
+        return Some(CallHierarchyTarget::Imported(function));
+    }
+    let function = named_function(&binding)?;
+    function_item(path, &function).map(CallHierarchyTarget::Item)
+}
+
+fn exported_function(path: &BiomePath, parse: AnyParse, name: &str) -> Option<CallHierarchyItem> {
+    let root: AnyJsRoot = parse.tree();
+    if !collect_module_exports(&root).contains(name) {
+        return None;
+    }
+    root.syntax()
+        .descendants()
+        .filter_map(JsIdentifierBinding::cast)
+        .filter(|binding| {
+            binding
+                .name_token()
+                .is_ok_and(|token| token.text_trimmed() == name)
+        })
+        .filter_map(|binding| named_function(&binding))
+        .find(|function| enclosing_function(function).is_none())
+        .and_then(|function| function_item(path, &function))
+}
+
+fn incoming_calls(path: &BiomePath, parse: AnyParse, target: CallTarget) -> Vec<IncomingCall> {
+    let root: AnyJsRoot = parse.tree();
+    let bindings = match target {
+        CallTarget::Item(item) => item_binding(&root, item).into_iter().collect::<Vec<_>>(),
+        CallTarget::Imported {
+            name,
+            is_target_module,
+        } => root
+            .syntax()
+            .descendants()
+            .filter_map(JsIdentifierBinding::cast)
+            .filter(|binding| {
+                imported_function(binding).is_some_and(|function| {
+                    function.name == name && is_target_module(&function.specifier)
+                })
+            })
+            .collect(),
+    };
+    if bindings.is_empty() {
+        return Vec::new();
+    }
+
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    // The calls are grouped by the function that makes them
+    let mut calls: Vec<IncomingCall> = Vec::new();
+    for binding in bindings {
+        for reference in model.as_binding(&binding).all_reads() {
+            if reference.as_call().is_none() {
+                continue;
+            }
+            let from = match enclosing_function(reference.syntax()) {
+                Some(function) => match function_item(path, &function) {
+                    Some(item) => item,
+                    None => continue,
+                },
+                None => module_item(path, &root),
+            };
+            let range = reference.syntax().text_trimmed_range();
+            match calls.iter_mut().find(|call| call.from == from) {
+                Some(call) => call.from_ranges.push(range),
+                None => calls.push(IncomingCall {
+                    from,
+                    from_ranges: vec![range],
+                }),
+            }
+        }
+    }
+    calls
+}
+
+fn outgoing_calls(path: &BiomePath, parse: AnyParse, item: &CallHierarchyItem) -> OutgoingCalls {
+    let root: AnyJsRoot = parse.tree();
+    let function = match item.kind {
+        CallHierarchyItemKind::Function => {
+            match item_binding(&root, item).and_then(|binding| named_function(&binding)) {
+                Some(function) => Some(function),
+                None => return OutgoingCalls::default(),
+            }
+        }
+        CallHierarchyItemKind::Module => None,
+    };
+    let container = function.clone().unwrap_or_else(|| root.syntax().clone());
+
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let mut outgoing = OutgoingCalls::default();
+    for call in container.descendants().filter_map(JsCallExpression::cast) {
+        // The calls made by the nested functions are the outgoing calls of these functions
+        if enclosing_function(call.syntax()) != function {
+            continue;
+        }
+        let Ok(AnyJsExpression::JsIdentifierExpression(callee)) =
+            call.callee().map(|callee| callee.omit_parentheses())
+        else {
+            continue;
+        };
+        let Some(binding) = callee
+            .name()
+            .ok()
+            .and_then(|reference| model.binding(&reference))
+            .and_then(|binding| JsIdentifierBinding::cast(binding.syntax().clone()))
+        else {
+            continue;
+        };
+        let range = callee.range();
+        if let Some(imported) = imported_function(&binding) {
+            match outgoing
+                .imported
+                .iter_mut()
+                .find(|(function, _)| *function == imported)
+            {
+                Some((_, ranges)) => ranges.push(range),
+                None => outgoing.imported.push((imported, vec![range])),
+            }
+        } else if let Some(to) =
+            named_function(&binding).and_then(|function| function_item(path, &function))
+        {
+            match outgoing.calls.iter_mut().find(|call| call.to == to) {
+                Some(call) => call.from_ranges.push(range),
+                None => outgoing.calls.push(OutgoingCall {
+                    to,
+                    from_ranges: vec![range],
+                }),
+            }
+        }
+    }
+    outgoing
+}
+
+/// Returns the binding of the name of the function of `item`, if the document still declares it
+fn item_binding(root: &AnyJsRoot, item: &CallHierarchyItem) -> Option<JsIdentifierBinding> {
+    let binding = root
+        .syntax()
+        .token_at_offset(item.selection_range.start())
+        .right_biased()?
+        .parent()
+        .and_then(JsIdentifierBinding::cast)?;
+    (binding.range() == item.selection_range).then_some(binding)
+}
+
+/// Returns the function that `binding` names, such as `a` in `function a() {}` or in `const a = () => {}`
+fn named_function(binding: &JsIdentifierBinding) -> Option<JsSyntaxNode> {
+    let parent = binding.syntax().parent()?;
+    if JsFunctionDeclaration::can_cast(parent.kind())
+        || JsFunctionExportDefaultDeclaration::can_cast(parent.kind())
+    {
+        return Some(parent);
+    }
+    let expression = JsVariableDeclarator::cast(parent)?
+        .initializer()?
+        .expression()
+        .ok()?;
+    matches!(
+        expression,
+        AnyJsExpression::JsArrowFunctionExpression(_) | AnyJsExpression::JsFunctionExpression(_)
+    )
+    .then(|| expression.into_syntax())
+}
+
+/// Returns the binding of the name of `function`, if it's a function declaration or a function assigned to a variable
+fn function_name(function: &JsSyntaxNode) -> Option<JsIdentifierBinding> {
+    let id = if let Some(declaration) = JsFunctionDeclaration::cast_ref(function) {
+        declaration.id().ok()?
+    } else if let Some(declaration) = JsFunctionExportDefaultDeclaration::cast_ref(function) {
+        declaration.id()?
+    } else if JsArrowFunctionExpression::can_cast(function.kind())
+        || JsFunctionExpression::can_cast(function.kind())
+    {
+        // The parent of the function is the initializer of the declarator
+        let declarator = JsVariableDeclarator::cast(function.parent()?.parent()?)?;
+        declarator.id().ok()?.as_any_js_binding()?.clone()
+    } else {
+        return None;
+    };
+    id.as_js_identifier_binding().cloned()
+}
+
+/// Returns the named function that contains `node`, or `None` if `node` is at the top level of the module.
+///
+/// The anonymous functions, such as the callbacks, belong to the function that contains them.
+fn enclosing_function(node: &JsSyntaxNode) -> Option<JsSyntaxNode> {
+    node.ancestors()
+        .skip(1)
+        .find(|ancestor| function_name(ancestor).is_some())
+}
+
+fn function_item(path: &BiomePath, function: &JsSyntaxNode) -> Option<CallHierarchyItem> {
+    let name = function_name(function)?;
+    // The declaration of a function assigned to a variable is its declarator
+    let range = match name.syntax().parent() {
+        Some(declarator) if JsVariableDeclarator::can_cast(declarator.kind()) => {
+            declarator.text_trimmed_range()
+        }
+        _ => function.text_trimmed_range(),
+    };
+    Some(CallHierarchyItem {
+        path: path.clone(),
+        name: name.name_token().ok()?.text_trimmed().to_string(),
+        kind: CallHierarchyItemKind::Function,
+        range,
+        selection_range: name.range(),
+    })
+}
+
+/// Returns the item of the calls made at the top level of the module
+fn module_item(path: &BiomePath, root: &AnyJsRoot) -> CallHierarchyItem {
+    let range = root.syntax().text_trimmed_range();
+    CallHierarchyItem {
+        path: path.clone(),
+        name: path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        kind: CallHierarchyItemKind::Module,
+        range,
+        selection_range: TextRange::empty(range.start()),
+    }
+}
+
+/// Returns the function that `binding` imports, such as `a` in `import { a as b } from "./a.js"`
+fn imported_function(binding: &JsIdentifierBinding) -> Option<ImportedFunction> {
+    let specifier = binding.syntax().parent()?;
+    let name = if let Some(specifier) = JsNamedImportSpecifier::cast_ref(&specifier) {
+        specifier.name().ok()?.inner_string_text().ok()?.to_string()
+    } else if JsShorthandNamedImportSpecifier::can_cast(specifier.kind()) {
+        binding.name_token().ok()?.text_trimmed().to_string()
+    } else {
+        return None;
+    };
+    let import = specifier.ancestors().find_map(JsImport::cast)?;
+    Some(ImportedFunction {
+        specifier: import.source_text().ok()?.to_string(),
+        name,
+    })
+}
+
+pub(crate) fn organize_imports(
+    path: &BiomePath,
+    file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
+    let analyzer_options = settings.analyzer_options::<JsLanguage>(path, file_source);
 
     let filter = AnalysisFilter {
         enabled_rules: Some(&[RuleFilter::Rule("source", "organizeImports")]),
@@ -857,9 +1280,10 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
     let (action, _) = analyze(
         &tree,
         filter,
-        &AnalyzerOptions::default(),
+        &analyzer_options,
         JsFileSource::default(),
         None,
+        ProjectModuleExports::default(),
         |signal| {
             for action in signal.actions() {
                 if action.is_suppression() {
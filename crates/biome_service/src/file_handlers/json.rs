@@ -2,8 +2,8 @@ use std::borrow::Cow;
 use std::ffi::OsStr;
 
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
-    ExtensionHandler, ParseResult, SearchCapabilities,
+    compare_formatted_syntax, is_diagnostic_error, selection_ranges, AnalyzerVisitorBuilder,
+    CodeActionsParams, DocumentFileSource, ExtensionHandler, ParseResult, SearchCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -31,7 +31,7 @@ use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Seve
 use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
 use biome_fs::{BiomePath, ConfigName, ROME_JSON};
 use biome_json_analyze::analyze;
-use biome_json_formatter::context::{JsonFormatOptions, TrailingCommas};
+use biome_json_formatter::context::{ExponentCase, JsonFormatOptions, TrailingCommas};
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
@@ -48,6 +48,9 @@ pub struct JsonFormatterSettings {
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub trailing_commas: Option<TrailingCommas>,
+    pub inline_array_width: Option<LineWidth>,
+    pub exponent_case: Option<ExponentCase>,
+    pub strip_exponent_plus_sign: Option<bool>,
     pub enabled: Option<bool>,
 }
 
@@ -114,7 +117,14 @@ impl ServiceLanguage for JsonLanguage {
             .with_indent_style(indent_style)
             .with_indent_width(indent_width)
             .with_line_width(line_width)
-            .with_trailing_commas(trailing_commas);
+            .with_trailing_commas(trailing_commas)
+            .with_inline_array_width(language.and_then(|l| l.inline_array_width))
+            .with_exponent_case(language.and_then(|l| l.exponent_case).unwrap_or_default())
+            .with_strip_exponent_plus_sign(
+                language
+                    .and_then(|l| l.strip_exponent_plus_sign)
+                    .unwrap_or(true),
+            );
 
         if let Some(overrides) = overrides {
             overrides.to_override_json_format_options(path, options)
@@ -138,10 +148,14 @@ impl ServiceLanguage for JsonLanguage {
             globals: vec![],
             preferred_quote: PreferredQuote::Double,
             jsx_runtime: Default::default(),
+            aria_roles: Vec::new(),
+            aria_properties: Vec::new(),
         };
         AnalyzerOptions {
             configuration,
             file_path: path.to_path_buf(),
+            deadline: None,
+            profiler: None,
         }
     }
 }
@@ -152,7 +166,10 @@ pub(crate) struct JsonFileHandler;
 impl ExtensionHandler for JsonFileHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                selection_ranges: Some(selection_ranges::<JsonLanguage>),
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -162,6 +179,8 @@ impl ExtensionHandler for JsonFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                document_highlights: None,
+                call_hierarchy: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -169,6 +188,8 @@ impl ExtensionHandler for JsonFileHandler {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                compare_syntax: Some(compare_formatted_syntax::<JsonLanguage>),
+                format_embedding: None,
             },
             search: SearchCapabilities { search: None },
         }
@@ -319,9 +340,11 @@ fn lint(params: LintParams) -> LintResults {
             };
             let root: JsonRoot = params.parse.tree();
 
-            let analyzer_options = &params
+            let mut analyzer_options = params
                 .workspace
                 .analyzer_options::<JsonLanguage>(params.path, &params.language);
+            analyzer_options.deadline = params.deadline;
+            analyzer_options.profiler = params.profiler.clone();
 
             let has_only_filter = !params.only.is_empty();
             let rules = params
@@ -374,7 +397,7 @@ fn lint(params: LintParams) -> LintResults {
             let skipped_diagnostics = diagnostic_count - diagnostics.len() as u32;
 
             let (_, analyze_diagnostics) =
-                analyze(&root, filter, analyzer_options, file_source, |signal| {
+                analyze(&root, filter, &analyzer_options, file_source, |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
                             && diagnostic.category() == Some(category!("suppressions/unused"))
@@ -405,7 +428,7 @@ fn lint(params: LintParams) -> LintResults {
 
                         if diagnostic_count <= params.max_diagnostics {
                             for action in signal.actions() {
-                                if !action.is_suppression() {
+                                if !action.is_suppression() && action.file_edits.is_empty() {
                                     diagnostic = diagnostic.add_code_suggestion(action.into());
                                 }
                             }
@@ -441,6 +464,9 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        css_custom_properties: _,
+        module_exports: _,
+        ambient_globals: _,
         language,
         skip,
         only,
@@ -484,6 +510,7 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                             .rule_name
                             .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
                         suggestion: item.suggestion,
+                        file_edits: item.file_edits,
                     }
                 }));
 
@@ -537,9 +564,10 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     let mut actions = Vec::new();
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = params
+    let mut analyzer_options = params
         .workspace
         .analyzer_options::<JsonLanguage>(params.biome_path, &params.document_file_source);
+    analyzer_options.deadline = params.deadline;
     loop {
         let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
             let current_diagnostic = signal.diagnostic();
@@ -551,8 +579,9 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
             }
 
             for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
+                // suppression actions should not be part of the fixes (safe or suggested),
+                // and the edits of the other files are only applied by the editors
+                if action.is_suppression() || !action.file_edits.is_empty() {
                     continue;
                 }
 
@@ -586,6 +615,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                 if let (root, Some((range, _))) =
                     action.mutation.commit_with_text_range_and_edit(true)
                 {
+                    let fix_action = FixAction::new(action.rule_name, tree.syntax(), &root, range);
                     tree = match JsonRoot::cast(root) {
                         Some(tree) => tree,
                         None => {
@@ -598,12 +628,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                             ));
                         }
                     };
-                    actions.push(FixAction {
-                        rule_name: action
-                            .rule_name
-                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
-                        range,
-                    });
+                    actions.push(fix_action);
                 }
             }
             None => {
@@ -631,7 +656,12 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     }
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+fn organize_imports(
+    _path: &BiomePath,
+    _file_source: &DocumentFileSource,
+    parse: AnyParse,
+    _settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
     Ok(OrganizeImportsResult {
         code: parse.syntax::<JsonLanguage>().to_string(),
     })
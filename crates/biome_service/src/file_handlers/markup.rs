@@ -0,0 +1,618 @@
+//! Formatting of the markup of the Svelte and Astro files.
+//!
+//! The markup is formatted without a syntax tree: the formatter keeps the line breaks of the original content,
+//! indents the lines according to the nesting of the elements and of the blocks, normalizes the attributes of the tags,
+//! and formats the expression tags, such as `{count + 1}`, with the JavaScript formatter.
+//!
+//! The content of the `<script>`, `<style>`, `<pre>` and `<textarea>` elements is left untouched,
+//! as well as the comments. The markup isn't formatted when its tags aren't balanced.
+//!
+//! The formatting of the markup is experimental, and it's only enabled by the `markupFormatting` option
+//! of the JavaScript formatter.
+
+use biome_formatter::{FormatOptions, IndentStyle, LineEnding, LineWidth};
+use biome_js_formatter::context::JsFormatOptions;
+use biome_js_formatter::format_node;
+use biome_js_parser::{parse_expression, JsParserOptions};
+use biome_js_syntax::JsFileSource;
+
+/// The flavor of the markup
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum MarkupKind {
+    /// The markup of a Svelte component, which supports the blocks, such as `{#if condition}`,
+    /// and the shorthands of the directives, such as `bind:value`
+    Svelte,
+    /// The markup of an Astro component, whose expressions can contain JSX
+    Astro,
+}
+
+/// The elements whose content is kept as is
+const RAW_ELEMENTS: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+/// The elements that can't have children, and don't need a closing tag
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Formats `markup`.
+///
+/// `file_source` is used to parse the expressions of the markup.
+/// Returns `None` if the markup can't be formatted, such as when its tags aren't balanced.
+pub(crate) fn format_markup(
+    markup: &str,
+    kind: MarkupKind,
+    file_source: JsFileSource,
+    options: &JsFormatOptions,
+) -> Option<String> {
+    let (items, trailing) = tokenize(markup)?;
+    let mut printer = MarkupPrinter {
+        output: String::with_capacity(markup.len()),
+        newline: if markup.contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        },
+        stack: Vec::new(),
+        kind,
+        file_source,
+        options,
+    };
+    for item in &items {
+        printer.print_item(item)?;
+    }
+    if !printer.stack.is_empty() {
+        return None;
+    }
+    if trailing.contains('\n') {
+        printer.output.push_str(printer.newline);
+    }
+    Some(printer.output)
+}
+
+/// A token of the markup, with the whitespace that precedes it
+#[derive(Debug)]
+struct MarkupItem<'a> {
+    leading_whitespace: &'a str,
+    token: MarkupToken<'a>,
+}
+
+#[derive(Debug)]
+enum MarkupToken<'a> {
+    /// An opening tag, such as `<div class="card">`, or a self-closing tag, such as `<Icon />`
+    OpeningTag {
+        name: &'a str,
+        attributes: Vec<MarkupAttribute<'a>>,
+        self_closing: bool,
+        /// `true` if the tag spans several lines
+        multiline: bool,
+    },
+    /// A closing tag, such as `</div>`
+    ClosingTag(&'a str),
+    /// The content of an expression tag, such as `count + 1` in `{count + 1}`
+    ExpressionTag(&'a str),
+    /// A comment, a doctype, or an element whose content is raw, such as `<style>`
+    Verbatim(&'a str),
+    Text(&'a str),
+}
+
+#[derive(Debug)]
+struct MarkupAttribute<'a> {
+    /// The name of the attribute, which is empty for the attributes written as an expression,
+    /// such as `{value}` or `{...props}`
+    name: &'a str,
+    value: Option<MarkupAttributeValue<'a>>,
+}
+
+#[derive(Debug)]
+enum MarkupAttributeValue<'a> {
+    /// A quoted value, with its quotes
+    Quoted(&'a str),
+    /// An expression, without its braces
+    Expression(&'a str),
+    Unquoted(&'a str),
+}
+
+/// Splits `markup` into tokens. Returns the tokens and the whitespace that ends the markup,
+/// or `None` if a tag or an expression isn't closed.
+fn tokenize(markup: &str) -> Option<(Vec<MarkupItem>, &str)> {
+    let bytes = markup.as_bytes();
+    let mut items = Vec::new();
+    let mut index = 0;
+    loop {
+        let whitespace_start = index;
+        while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+            index += 1;
+        }
+        let leading_whitespace = &markup[whitespace_start..index];
+        if index == bytes.len() {
+            return Some((items, leading_whitespace));
+        }
+        let rest = &markup[index..];
+        let (token, end) = if rest.starts_with("<!--") {
+            let end = index + rest.find("-->")? + "-->".len();
+            (MarkupToken::Verbatim(&markup[index..end]), end)
+        } else if rest.starts_with("<!") {
+            let end = index + rest.find('>')? + 1;
+            (MarkupToken::Verbatim(&markup[index..end]), end)
+        } else if rest.starts_with("</") {
+            let end = index + rest.find('>')?;
+            let name = markup[index + "</".len()..end].trim();
+            (MarkupToken::ClosingTag(name), end + 1)
+        } else if is_tag_start(bytes, index) {
+            let (token, end) = tokenize_opening_tag(markup, index)?;
+            match token {
+                MarkupToken::OpeningTag {
+                    name,
+                    self_closing: false,
+                    ..
+                } if RAW_ELEMENTS.contains(&name.to_ascii_lowercase().as_str()) => {
+                    let closing_tag = format!("</{name}");
+                    let closing_start = end + markup[end..].find(&closing_tag)?;
+                    let closing_end = closing_start + markup[closing_start..].find('>')? + 1;
+                    (
+                        MarkupToken::Verbatim(&markup[index..closing_end]),
+                        closing_end,
+                    )
+                }
+                token => (token, end),
+            }
+        } else if bytes[index] == b'{' {
+            let end = closing_brace(markup, index)?;
+            (MarkupToken::ExpressionTag(&markup[index + 1..end]), end + 1)
+        } else {
+            let mut end = index + 1;
+            while end < bytes.len()
+                && bytes[end] != b'{'
+                && !(bytes[end] == b'<'
+                    && (is_tag_start(bytes, end)
+                        || bytes
+                            .get(end + 1)
+                            .is_some_and(|byte| matches!(byte, b'/' | b'!'))))
+            {
+                end += 1;
+            }
+            // The whitespace that follows the text precedes the next token
+            let text = markup[index..end].trim_end();
+            (MarkupToken::Text(text), index + text.len())
+        };
+        items.push(MarkupItem {
+            leading_whitespace,
+            token,
+        });
+        index = end;
+    }
+}
+
+/// Returns `true` if the byte at `index` starts an opening tag, such as `<div` or the fragment `<>`
+fn is_tag_start(bytes: &[u8], index: usize) -> bool {
+    bytes[index] == b'<'
+        && bytes
+            .get(index + 1)
+            .is_some_and(|byte| byte.is_ascii_alphabetic() || *byte == b'>')
+}
+
+/// Tokenizes the opening tag that starts at `start`, and returns the index of the byte that follows it
+fn tokenize_opening_tag(markup: &str, start: usize) -> Option<(MarkupToken, usize)> {
+    let bytes = markup.as_bytes();
+    let mut index = start + 1;
+    while index < bytes.len() && !is_attribute_delimiter(bytes, index) {
+        index += 1;
+    }
+    let name = &markup[start + 1..index];
+    let mut attributes = Vec::new();
+    let mut multiline = false;
+    loop {
+        while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+            multiline |= bytes[index] == b'\n';
+            index += 1;
+        }
+        match bytes.get(index)? {
+            b'>' => {
+                let token = MarkupToken::OpeningTag {
+                    name,
+                    attributes,
+                    self_closing: false,
+                    multiline,
+                };
+                return Some((token, index + 1));
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'>') => {
+                let token = MarkupToken::OpeningTag {
+                    name,
+                    attributes,
+                    self_closing: true,
+                    multiline,
+                };
+                return Some((token, index + 2));
+            }
+            b'{' => {
+                // `{value}` or `{...props}`
+                let end = closing_brace(markup, index)?;
+                attributes.push(MarkupAttribute {
+                    name: "",
+                    value: Some(MarkupAttributeValue::Expression(&markup[index + 1..end])),
+                });
+                index = end + 1;
+                continue;
+            }
+            _ => {}
+        }
+        let name_start = index;
+        while index < bytes.len() && !is_attribute_delimiter(bytes, index) && bytes[index] != b'=' {
+            index += 1;
+        }
+        if name_start == index {
+            // A stray `/` or `=`
+            return None;
+        }
+        let name = &markup[name_start..index];
+        let mut value_start = index;
+        while value_start < bytes.len() && bytes[value_start].is_ascii_whitespace() {
+            value_start += 1;
+        }
+        if bytes.get(value_start) != Some(&b'=') {
+            attributes.push(MarkupAttribute { name, value: None });
+            continue;
+        }
+        value_start += 1;
+        while value_start < bytes.len() && bytes[value_start].is_ascii_whitespace() {
+            value_start += 1;
+        }
+        let (value, end) = match bytes.get(value_start)? {
+            &quote @ (b'"' | b'\'') => {
+                let end = value_start + 1 + markup[value_start + 1..].find(quote as char)? + 1;
+                (MarkupAttributeValue::Quoted(&markup[value_start..end]), end)
+            }
+            b'{' => {
+                let end = closing_brace(markup, value_start)?;
+                (
+                    MarkupAttributeValue::Expression(&markup[value_start + 1..end]),
+                    end + 1,
+                )
+            }
+            _ => {
+                let mut end = value_start;
+                while end < bytes.len() && !is_attribute_delimiter(bytes, end) {
+                    end += 1;
+                }
+                (
+                    MarkupAttributeValue::Unquoted(&markup[value_start..end]),
+                    end,
+                )
+            }
+        };
+        attributes.push(MarkupAttribute {
+            name,
+            value: Some(value),
+        });
+        index = end;
+    }
+}
+
+/// Returns `true` if the byte at `index` ends the name of a tag or an attribute
+fn is_attribute_delimiter(bytes: &[u8], index: usize) -> bool {
+    match bytes[index] {
+        b'>' => true,
+        b'/' => bytes.get(index + 1) == Some(&b'>'),
+        byte => byte.is_ascii_whitespace(),
+    }
+}
+
+/// Returns the index of the `}` that closes the `{` at `start`.
+///
+/// The braces contained in the strings and in the template literals of the expression are ignored.
+fn closing_brace(markup: &str, start: usize) -> Option<usize> {
+    let bytes = markup.as_bytes();
+    let mut depth = 0usize;
+    let mut index = start;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            quote @ (b'"' | b'\'') => {
+                index += 1;
+                while index < bytes.len() && bytes[index] != quote {
+                    if bytes[index] == b'\\' {
+                        index += 1;
+                    }
+                    index += 1;
+                }
+            }
+            b'`' => {
+                index += 1;
+                while index < bytes.len() && bytes[index] != b'`' {
+                    match bytes[index] {
+                        b'\\' => index += 1,
+                        b'$' if bytes.get(index + 1) == Some(&b'{') => {
+                            index = closing_brace(markup, index + 1)?;
+                        }
+                        _ => {}
+                    }
+                    index += 1;
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+struct MarkupPrinter<'a> {
+    output: String,
+    newline: &'static str,
+    /// The names of the open elements, and the keywords of the open blocks, such as `if` for `{#if condition}`
+    stack: Vec<String>,
+    kind: MarkupKind,
+    file_source: JsFileSource,
+    options: &'a JsFormatOptions,
+}
+
+impl MarkupPrinter<'_> {
+    /// Prints `item`. Returns `None` if it doesn't close the last open element or block.
+    fn print_item(&mut self, item: &MarkupItem) -> Option<()> {
+        // The tags that close an element or a block are indented like their opening tag,
+        // and the branches of a block, such as `{:else}`, like the block itself
+        let depth = match &item.token {
+            MarkupToken::ClosingTag(name) => {
+                if self.stack.pop()? != *name {
+                    return None;
+                }
+                self.stack.len()
+            }
+            MarkupToken::ExpressionTag(content) if self.kind == MarkupKind::Svelte => {
+                let content = content.trim_start();
+                if let Some(block) = content.strip_prefix('/') {
+                    if self.stack.pop()? != block.trim() {
+                        return None;
+                    }
+                    self.stack.len()
+                } else if content.starts_with(':') {
+                    self.stack.len().checked_sub(1)?
+                } else {
+                    self.stack.len()
+                }
+            }
+            _ => self.stack.len(),
+        };
+
+        let line_breaks = item.leading_whitespace.matches('\n').count();
+        if line_breaks > 0 {
+            self.output.push_str(self.newline);
+            // The empty lines are collapsed into one
+            if line_breaks > 1 {
+                self.output.push_str(self.newline);
+            }
+            self.push_indentation(depth);
+        } else if !item.leading_whitespace.is_empty() && !self.output.is_empty() {
+            self.output.push(' ');
+        }
+
+        match &item.token {
+            MarkupToken::OpeningTag {
+                name,
+                attributes,
+                self_closing,
+                multiline,
+            } => {
+                self.print_opening_tag(name, attributes, *self_closing, *multiline, depth);
+                if !*self_closing && !VOID_ELEMENTS.contains(&name.to_ascii_lowercase().as_str()) {
+                    self.stack.push(name.to_string());
+                }
+            }
+            MarkupToken::ClosingTag(name) => {
+                self.output.push_str("</");
+                self.output.push_str(name);
+                self.output.push('>');
+            }
+            MarkupToken::ExpressionTag(content) => {
+                let formatted = self.format_expression_tag(content);
+                self.output.push_str(&formatted);
+                if self.kind == MarkupKind::Svelte {
+                    if let Some(block) = content.trim_start().strip_prefix('#') {
+                        let keyword = block.split_whitespace().next().unwrap_or_default();
+                        self.stack.push(keyword.to_string());
+                    }
+                }
+            }
+            MarkupToken::Verbatim(content) => self.output.push_str(content),
+            MarkupToken::Text(text) => {
+                let mut is_empty_line = false;
+                for (index, line) in text.lines().map(str::trim).enumerate() {
+                    if index > 0 {
+                        if line.is_empty() {
+                            is_empty_line = true;
+                            continue;
+                        }
+                        self.output.push_str(self.newline);
+                        if is_empty_line {
+                            self.output.push_str(self.newline);
+                            is_empty_line = false;
+                        }
+                        self.push_indentation(depth);
+                    }
+                    self.output.push_str(line);
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn print_opening_tag(
+        &mut self,
+        name: &str,
+        attributes: &[MarkupAttribute],
+        self_closing: bool,
+        multiline: bool,
+        depth: usize,
+    ) {
+        self.output.push('<');
+        self.output.push_str(name);
+        // The attributes of a tag that spans several lines are printed on their own line
+        let multiline = multiline && !attributes.is_empty();
+        for attribute in attributes {
+            if multiline {
+                self.output.push_str(self.newline);
+                self.push_indentation(depth + 1);
+            } else {
+                self.output.push(' ');
+            }
+            let attribute = self.format_attribute(attribute);
+            self.output.push_str(&attribute);
+        }
+        if multiline && !self.options.bracket_same_line().value() {
+            self.output.push_str(self.newline);
+            self.push_indentation(depth);
+        } else if self_closing {
+            self.output.push(' ');
+        }
+        self.output.push_str(if self_closing { "/>" } else { ">" });
+    }
+
+    fn format_attribute(&self, attribute: &MarkupAttribute) -> String {
+        let MarkupAttribute { name, value } = attribute;
+        let expression = match value {
+            None => return name.to_string(),
+            Some(MarkupAttributeValue::Unquoted(value)) => return format!("{name}={value}"),
+            Some(MarkupAttributeValue::Quoted(value)) => {
+                // Svelte accepts the quoted expressions, such as `on:click="{handler}"`
+                let inner = &value[1..value.len() - 1];
+                match inner
+                    .trim()
+                    .strip_prefix('{')
+                    .and_then(|inner| inner.strip_suffix('}'))
+                {
+                    Some(expression)
+                        if self.kind == MarkupKind::Svelte
+                            && closing_brace(inner.trim(), 0) == Some(inner.trim().len() - 1) =>
+                    {
+                        expression
+                    }
+                    _ => return format!("{name}={value}"),
+                }
+            }
+            Some(MarkupAttributeValue::Expression(expression)) => expression,
+        };
+        if name.is_empty() {
+            return format!("{{{}}}", self.format_expression(expression, 1));
+        }
+        let formatted = self.format_expression(expression, name.len() + "={".len());
+        // `bind:value={value}` is shortened to `bind:value`
+        if self.kind == MarkupKind::Svelte {
+            if let Some(("bind" | "class", directive)) = name.split_once(':') {
+                if directive == formatted {
+                    return name.to_string();
+                }
+            }
+        }
+        format!("{name}={{{formatted}}}")
+    }
+
+    /// Formats the content of an expression tag, and returns the tag with its braces
+    fn format_expression_tag(&self, content: &str) -> String {
+        let trimmed = content.trim();
+        if self.kind == MarkupKind::Svelte {
+            // The blocks whose keyword is followed by an expression, such as `{#if condition}` or `{@html content}`
+            for keyword in ["#if", "#key", "#await", ":else if", "@html"] {
+                if let Some(expression) = trimmed.strip_prefix(keyword) {
+                    if expression.starts_with(char::is_whitespace) {
+                        let expression =
+                            self.format_expression(expression, "{".len() + keyword.len() + 1);
+                        return format!("{{{keyword} {expression}}}");
+                    }
+                }
+            }
+            if trimmed.starts_with(['#', ':', '/', '@']) {
+                return format!("{{{trimmed}}}");
+            }
+        }
+        let expression = self.format_expression(content, "{".len());
+        format!("{{{expression}}}")
+    }
+
+    /// Formats `expression` with the JavaScript formatter. `offset` is the number of columns
+    /// between the current column and the start of the expression.
+    ///
+    /// The expression is returned as is if it isn't valid.
+    fn format_expression(&self, expression: &str, offset: usize) -> String {
+        let trimmed = expression.trim();
+        if trimmed.is_empty() {
+            return String::new();
+        }
+        if let Some(argument) = trimmed.strip_prefix("...") {
+            return format!(
+                "...{}",
+                self.format_expression(argument, offset + "...".len())
+            );
+        }
+        let parse = parse_expression(trimmed, self.file_source, JsParserOptions::default());
+        if parse.has_errors() {
+            return expression.to_string();
+        }
+        let line_start = self
+            .output
+            .rfind('\n')
+            .map_or(0, |index| index + "\n".len());
+        let line = &self.output[line_start..];
+        let indentation = &line[..line.len() - line.trim_start().len()];
+        let column = line.chars().count() + offset;
+        let line_width = self
+            .options
+            .line_width()
+            .value()
+            .saturating_sub(column as u16);
+        let Ok(line_width) = LineWidth::try_from(line_width.max(LineWidth::MIN)) else {
+            return expression.to_string();
+        };
+        // The lines that follow the first one are indented by the formatter, which leaves
+        // the content of the strings and of the template literals untouched
+        let level = match self.options.indent_style() {
+            IndentStyle::Tab => indentation.matches('\t').count(),
+            IndentStyle::Space => {
+                indentation.len() / usize::from(self.options.indent_width().value().max(1))
+            }
+        };
+        let Ok(level) = u16::try_from(level) else {
+            return expression.to_string();
+        };
+        let line_ending = if self.newline == "\r\n" {
+            LineEnding::Crlf
+        } else {
+            LineEnding::Lf
+        };
+        let options = self
+            .options
+            .clone()
+            .with_line_width(line_width)
+            .with_line_ending(line_ending);
+        match format_node(options, &parse.syntax())
+            .ok()
+            .and_then(|formatted| formatted.print_with_indent(level).ok())
+        {
+            Some(printed) => printed.as_code().trim_end().to_string(),
+            None => expression.to_string(),
+        }
+    }
+
+    fn push_indentation(&mut self, depth: usize) {
+        match self.options.indent_style() {
+            IndentStyle::Tab => {
+                for _ in 0..depth {
+                    self.output.push('\t');
+                }
+            }
+            IndentStyle::Space => {
+                let width = usize::from(self.options.indent_width().value());
+                for _ in 0..depth * width {
+                    self.output.push(' ');
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,530 @@
+//! Support of the MDX documents, which mix Markdown with the `import` and `export` statements of JavaScript and JSX.
+//!
+//! The document is split into blocks without a syntax tree of the Markdown:
+//! a paragraph that starts with `import` or `export` is a block of statements,
+//! and a paragraph that starts with a tag and contains only JSX is a block of JSX.
+//! The frontmatter and the fenced code blocks are left untouched.
+//!
+//! The linter analyzes a JavaScript module in which the Markdown is replaced with spaces,
+//! so the ranges of its diagnostics are the ranges of the document.
+
+use crate::file_handlers::{
+    javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
+    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
+    ParserCapabilities,
+};
+use crate::settings::{Settings, WorkspaceSettingsHandle};
+use crate::workspace::{DocumentFileSource, FixFileResult, PullActionsResult};
+use crate::WorkspaceError;
+use biome_formatter::Printed;
+use biome_fs::BiomePath;
+use biome_js_formatter::context::JsFormatOptions;
+use biome_js_formatter::format_node;
+use biome_js_parser::{parse, parse_js_with_cache, JsParserOptions, Parse};
+use biome_js_syntax::{AnyJsRoot, JsFileSource, JsLanguage};
+use biome_parser::AnyParse;
+use biome_rowan::NodeCache;
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use std::ops::Range;
+
+use super::frontmatter::Frontmatter;
+use super::SearchCapabilities;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct MdxFileHandler;
+
+impl MdxFileHandler {
+    /// It extracts the JavaScript code of an MDX document: its `import` and `export` statements, and its blocks of JSX.
+    ///
+    /// The rest of the document is replaced with spaces, and the line breaks are kept,
+    /// so that the code has the same position in the returned string and in the document.
+    pub fn input(text: &str) -> String {
+        let mut code = text.as_bytes().to_vec();
+        let blocks = MdxBlock::parse(text);
+        for block in blocks.iter().filter(|block| !block.kind.is_code()) {
+            for byte in &mut code[block.range.clone()] {
+                if !matches!(byte, b'\n' | b'\r') {
+                    *byte = b' ';
+                }
+            }
+        }
+        // The statements of two blocks are separated by a semicolon,
+        // which is put on the blank line that follows a block
+        for (block, next) in blocks.iter().zip(blocks.iter().skip(1)) {
+            if block.kind.is_code()
+                && next.kind == MdxBlockKind::Blank
+                && !text[block.range.clone()].trim_end().ends_with(';')
+            {
+                code[next.range.start] = b';';
+            }
+        }
+        // The Markdown is replaced with ASCII spaces, the code is kept as is
+        String::from_utf8(code).unwrap_or_default()
+    }
+
+    /// It takes the original content of an MDX document, and the code returned by [MdxFileHandler::input] after it was changed,
+    /// for example by the fixes of the linter. The function applies the changes of the code to the document,
+    /// and keeps the Markdown as is.
+    pub fn output(input: &str, output: &str) -> String {
+        let code = Self::input(input);
+        if code == output {
+            return input.to_string();
+        }
+        let code_ranges: Vec<_> = MdxBlock::parse(input)
+            .into_iter()
+            .filter(|block| block.kind.is_code())
+            .map(|block| block.range)
+            .collect();
+        let diff = TextEdit::from_unicode_words(&code, output);
+        let mut result = Vec::with_capacity(output.len());
+        let mut position = 0;
+        for op in &diff {
+            match op {
+                CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                    let end = position + usize::from(range.len());
+                    result.extend_from_slice(&input.as_bytes()[position..end]);
+                    position = end;
+                }
+                CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                    result.extend_from_slice(diff.get_text(*range).as_bytes());
+                }
+                CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                    let end = position + usize::from(range.len());
+                    // Only the code can be removed, the Markdown is kept
+                    for index in position..end {
+                        if !code_ranges.iter().any(|range| range.contains(&index)) {
+                            result.push(input.as_bytes()[index]);
+                        }
+                    }
+                    position = end;
+                }
+                CompressedOp::EqualLines { line_count } => {
+                    let length: usize = code[position..]
+                        .split_inclusive('\n')
+                        .take(line_count.get() as usize + 1)
+                        .map(str::len)
+                        .sum();
+                    result.extend_from_slice(&input.as_bytes()[position..position + length]);
+                    position += length;
+                }
+            }
+        }
+        String::from_utf8(result).unwrap_or_else(|_| input.to_string())
+    }
+
+    /// It formats an MDX document: the blocks of statements and of JSX are formatted with the JavaScript formatter,
+    /// the consecutive blank lines are collapsed, and the headings and the trailing whitespace of the Markdown are normalized.
+    ///
+    /// The frontmatter, the fenced and indented code blocks, and the blocks that contain syntax errors are left untouched.
+    pub fn format(input: &str, options: &JsFormatOptions) -> String {
+        let newline = if input.contains("\r\n") { "\r\n" } else { "\n" };
+        let blocks = MdxBlock::parse(input);
+        let mut output = String::with_capacity(input.len());
+        let mut blank_lines = 0;
+        for block in &blocks {
+            let text = &input[block.range.clone()];
+            if block.kind == MdxBlockKind::Blank {
+                blank_lines += 1;
+                continue;
+            }
+            if !output.is_empty() && blank_lines > 0 {
+                // The blank lines of an indented code block are kept
+                let count = if block.kind == MdxBlockKind::Markdown && is_indented_code(text) {
+                    blank_lines
+                } else {
+                    1
+                };
+                for _ in 0..count {
+                    output.push_str(newline);
+                }
+            }
+            blank_lines = 0;
+            match block.kind {
+                MdxBlockKind::Frontmatter | MdxBlockKind::Code => {
+                    output.push_str(text);
+                    if !text.ends_with('\n') {
+                        output.push_str(newline);
+                    }
+                }
+                MdxBlockKind::Esm | MdxBlockKind::Jsx => {
+                    let code = text.trim_end();
+                    let formatted = format_code(code, block.kind, options);
+                    let formatted = formatted.as_deref().unwrap_or(code);
+                    for line in formatted.lines() {
+                        output.push_str(line);
+                        output.push_str(newline);
+                    }
+                }
+                MdxBlockKind::Markdown => {
+                    if is_indented_code(text) {
+                        output.push_str(text.trim_end_matches(['\n', '\r']));
+                    } else {
+                        output.push_str(&format_markdown_line(text));
+                    }
+                    output.push_str(newline);
+                }
+                MdxBlockKind::Blank => {}
+            }
+        }
+        output
+    }
+}
+
+/// The kind of a block of an MDX document
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum MdxBlockKind {
+    /// The frontmatter at the start of the document
+    Frontmatter,
+    /// A fenced code block, such as ```` ```js ````
+    Code,
+    /// A paragraph of `import` and `export` statements
+    Esm,
+    /// A paragraph that contains only JSX, such as `<Chart data={data} />`
+    Jsx,
+    /// A line of Markdown
+    Markdown,
+    /// A line that contains only whitespace
+    Blank,
+}
+
+impl MdxBlockKind {
+    /// Returns `true` if the block contains JavaScript code
+    fn is_code(self) -> bool {
+        matches!(self, Self::Esm | Self::Jsx)
+    }
+}
+
+/// A block of an MDX document, which spans whole lines, including their line breaks
+#[derive(Debug, Eq, PartialEq)]
+struct MdxBlock {
+    kind: MdxBlockKind,
+    range: Range<usize>,
+}
+
+impl MdxBlock {
+    /// Splits `text` into blocks, which cover the whole document
+    fn parse(text: &str) -> Vec<Self> {
+        let mut blocks = Vec::new();
+        let mut start = 0;
+        if let Some(frontmatter) = Frontmatter::parse(text) {
+            start = text[frontmatter.end()..]
+                .find('\n')
+                .map_or(text.len(), |index| frontmatter.end() + index + 1);
+            blocks.push(Self {
+                kind: MdxBlockKind::Frontmatter,
+                range: 0..start,
+            });
+        }
+        let mut lines = Vec::new();
+        for line in text[start..].split_inclusive('\n') {
+            lines.push(start..start + line.len());
+            start += line.len();
+        }
+        let mut index = 0;
+        while index < lines.len() {
+            let line = text[lines[index].clone()].trim_end_matches(['\n', '\r']);
+            if line.trim().is_empty() {
+                blocks.push(Self {
+                    kind: MdxBlockKind::Blank,
+                    range: lines[index].clone(),
+                });
+                index += 1;
+                continue;
+            }
+            if let Some(fence) = opening_code_fence(line) {
+                // The code block ends with its closing fence, or with the document
+                let end = lines[index + 1..]
+                    .iter()
+                    .position(|range| is_closing_code_fence(&text[range.clone()], fence))
+                    .map_or(lines.len(), |position| index + position + 2);
+                blocks.push(Self {
+                    kind: MdxBlockKind::Code,
+                    range: lines[index].start..lines[end - 1].end,
+                });
+                index = end;
+                continue;
+            }
+            let kind = if is_esm(line) {
+                Some(MdxBlockKind::Esm)
+            } else if is_jsx(line) {
+                Some(MdxBlockKind::Jsx)
+            } else {
+                None
+            };
+            if let Some(kind) = kind {
+                // The paragraph ends with a blank line
+                let end = lines[index..]
+                    .iter()
+                    .position(|range| text[range.clone()].trim().is_empty())
+                    .map_or(lines.len(), |position| index + position);
+                let range = lines[index].start..lines[end - 1].end;
+                let code = &text[range.clone()];
+                if kind == MdxBlockKind::Esm || !parse_code(code).has_errors() {
+                    blocks.push(Self { kind, range });
+                    index = end;
+                    continue;
+                }
+            }
+            blocks.push(Self {
+                kind: MdxBlockKind::Markdown,
+                range: lines[index].clone(),
+            });
+            index += 1;
+        }
+        blocks
+    }
+}
+
+/// Returns the fence of `line` if it opens a fenced code block, such as ```` ``` ```` or `~~~`
+fn opening_code_fence(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let fence_char = trimmed.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let length = trimmed.len() - trimmed.trim_start_matches(fence_char).len();
+    (length >= 3).then(|| &trimmed[..length])
+}
+
+/// Returns `true` if `line` closes the fenced code block opened by `fence`
+fn is_closing_code_fence(line: &str, fence: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.starts_with(fence) && trimmed.chars().all(|c| fence.starts_with(c))
+}
+
+/// Returns `true` if `line` starts an `import` or an `export` statement
+fn is_esm(line: &str) -> bool {
+    ["import", "export"].iter().any(|keyword| {
+        line.strip_prefix(keyword)
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|c| c.is_whitespace() || matches!(c, '{' | '*'))
+    })
+}
+
+/// Returns `true` if `line` starts with a tag, such as `<Chart` or the fragment `<>`
+fn is_jsx(line: &str) -> bool {
+    line.strip_prefix('<')
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '>')
+}
+
+/// Returns `true` if `line` belongs to an indented code block
+fn is_indented_code(line: &str) -> bool {
+    line.starts_with('\t') || line.starts_with("    ")
+}
+
+fn parse_code(code: &str) -> Parse<AnyJsRoot> {
+    parse(code, JsFileSource::jsx(), JsParserOptions::default())
+}
+
+/// Formats the code of a block with the JavaScript formatter.
+///
+/// Returns `None` if the code contains syntax errors.
+fn format_code(code: &str, kind: MdxBlockKind, options: &JsFormatOptions) -> Option<String> {
+    let parse = parse_code(code);
+    if parse.has_errors() {
+        return None;
+    }
+    let printed = format_node(options.clone(), &parse.syntax())
+        .ok()?
+        .print()
+        .ok()?;
+    let formatted = printed.as_code().trim_end();
+    // The JSX is written without the semicolon of its statement
+    let formatted = if kind == MdxBlockKind::Jsx {
+        formatted.trim_start_matches(';').trim_end_matches(';')
+    } else {
+        formatted
+    };
+    Some(formatted.to_string())
+}
+
+/// Normalizes a line of Markdown: the trailing whitespace is removed, unless it's a hard line break,
+/// and the text of the headings is separated from their `#` by a single space
+fn format_markdown_line(line: &str) -> String {
+    let line = line.trim_end_matches(['\n', '\r']);
+    let content = line.trim_end();
+    // Two trailing spaces are a hard line break
+    let line = if line.ends_with("  ") && !line.trim().starts_with('#') {
+        line
+    } else {
+        content
+    };
+    let level = line.len() - line.trim_start_matches('#').len();
+    if (1..=6).contains(&level) {
+        let text = &line[level..];
+        if text.starts_with([' ', '\t']) {
+            return format!("{} {}", &line[..level], text.trim());
+        }
+    }
+    line.to_string()
+}
+
+impl ExtensionHandler for MdxFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            parser: ParserCapabilities {
+                parse: Some(parse_mdx),
+                selection_ranges: None,
+            },
+            debug: DebugCapabilities {
+                debug_syntax_tree: None,
+                debug_control_flow: None,
+                debug_formatter_ir: None,
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                code_actions: Some(code_actions),
+                rename: None,
+                document_highlights: None,
+                call_hierarchy: None,
+                fix_all: Some(fix_all),
+                organize_imports: None,
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: None,
+                format_on_type: None,
+                compare_syntax: None,
+                format_embedding: Some(format_embedding),
+            },
+            search: SearchCapabilities { search: None },
+        }
+    }
+}
+
+fn parse_mdx(
+    _rome_path: &BiomePath,
+    _file_source: DocumentFileSource,
+    text: &str,
+    _settings: Option<&Settings>,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let code = MdxFileHandler::input(text);
+    let parse = parse_js_with_cache(
+        &code,
+        JsFileSource::mdx(),
+        JsParserOptions::default(),
+        cache,
+    );
+
+    ParseResult {
+        any_parse: parse.into(),
+        language: Some(JsFileSource::mdx().into()),
+    }
+}
+
+/// The code is formatted with the rest of the document by [format_embedding],
+/// because the blocks of code are formatted separately
+fn format(
+    _biome_path: &BiomePath,
+    _document_file_source: &DocumentFileSource,
+    _parse: AnyParse,
+    _settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    Ok(Printed::new(String::new(), None, vec![], vec![]))
+}
+
+fn format_embedding(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    content: &str,
+    _printed: Printed,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+    let code = MdxFileHandler::format(content, &options);
+    Ok(Printed::new(code, None, vec![], vec![]))
+}
+
+pub(crate) fn lint(params: LintParams) -> LintResults {
+    javascript::lint(params)
+}
+
+/// The code actions are relative to the code returned by [MdxFileHandler::input], which has the positions of the document,
+/// but its whitespace can be Markdown in the document.
+/// The actions that delete a line break or only whitespace are left out, so that they don't remove Markdown.
+pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let mut result = javascript::code_actions(params);
+    result.actions.retain(|action| {
+        let edit = &action.suggestion.suggestion;
+        edit.iter().all(|op| match op {
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                let text = edit.get_text(*range);
+                !text.contains('\n') && !text.trim().is_empty()
+            }
+            _ => true,
+        })
+    });
+    result
+}
+
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    // Formatting the code would move the Markdown
+    javascript::fix_all(FixAllParams {
+        should_format: false,
+        ..params
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MdxFileHandler;
+    use biome_js_formatter::context::JsFormatOptions;
+    use biome_js_syntax::JsFileSource;
+
+    const DOCUMENT: &str = r#"---
+title: Charts
+---
+import { Chart } from "./chart.js";
+
+# Charts
+
+<Chart data={[1, 2]} />
+
+The chart of `data`.
+"#;
+
+    #[test]
+    fn input_keeps_the_positions_of_the_code() {
+        let code = MdxFileHandler::input(DOCUMENT);
+        assert_eq!(code.len(), DOCUMENT.len());
+        let import = DOCUMENT.find("import").unwrap();
+        assert_eq!(&code[import..import + 6], "import");
+        let chart = DOCUMENT.find("<Chart").unwrap();
+        assert_eq!(&code[chart..chart + 6], "<Chart");
+        assert!(!code.contains("title"));
+        assert!(!code.contains("# Charts"));
+    }
+
+    #[test]
+    fn output_applies_the_changes_of_the_code() {
+        let code = MdxFileHandler::input(DOCUMENT);
+        let fixed = code.replace("import { Chart }", "import { Chart, Legend }");
+        assert_eq!(
+            MdxFileHandler::output(DOCUMENT, &fixed),
+            DOCUMENT.replace("import { Chart }", "import { Chart, Legend }")
+        );
+    }
+
+    #[test]
+    fn output_keeps_the_markdown_when_the_code_is_removed() {
+        let code = MdxFileHandler::input(DOCUMENT);
+        let import = "import { Chart } from \"./chart.js\";";
+        let fixed = code.replace(import, "");
+        assert_eq!(
+            MdxFileHandler::output(DOCUMENT, &fixed),
+            DOCUMENT.replace(import, "")
+        );
+    }
+
+    #[test]
+    fn format_keeps_the_indented_code_blocks() {
+        let document = "# Example\n\n    let a = 1;  \n    let b = 2;\t\n\nThe end.  \n";
+        let options = JsFormatOptions::new(JsFileSource::mdx());
+        assert_eq!(
+            MdxFileHandler::format(document, &options),
+            "# Example\n\n    let a = 1;  \n    let b = 2;\t\n\nThe end.  \n"
+        );
+    }
+}
@@ -5,45 +5,61 @@ use self::{
 use crate::diagnostics::{QueryDiagnostic, SearchError};
 pub use crate::file_handlers::astro::{AstroFileHandler, ASTRO_FENCE};
 use crate::file_handlers::graphql::GraphqlFileHandler;
+use crate::file_handlers::html::HtmlFileHandler;
+pub use crate::file_handlers::mdx::MdxFileHandler;
 pub use crate::file_handlers::svelte::{SvelteFileHandler, SVELTE_FENCE};
+use crate::file_handlers::toml::TomlFileHandler;
 pub use crate::file_handlers::vue::{VueFileHandler, VUE_FENCE};
-use crate::settings::Settings;
-use crate::workspace::{FixFileMode, OrganizeImportsResult};
+use crate::settings::{is_rule_enabled_for_path, Settings};
+use crate::workspace::{
+    CallHierarchyItem, DocumentHighlightsResult, FixFileMode, IncomingCall, OrganizeImportsResult,
+    OutgoingCall, SelectionRangesResult, SyntaxTreeDifference,
+};
 use crate::{
     settings::WorkspaceSettingsHandle,
     workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
     WorkspaceError,
 };
 use biome_analyze::{
-    AnalyzerDiagnostic, GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategories,
-    RuleCategory, RuleFilter, RuleGroup,
+    profiling::RuleProfiler, AnalyzerDiagnostic, GroupCategory, Queryable, RegistryVisitor, Rule,
+    RuleCategories, RuleCategory, RuleFilter, RuleGroup,
 };
 use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::Rules;
 use biome_console::fmt::Formatter;
 use biome_console::markup;
+use biome_css_analyze::ProjectCustomProperties;
 use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_diagnostics::{Diagnostic, Severity};
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
 use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
 use biome_grit_patterns::{GritQuery, GritQueryResult, GritTargetFile};
+use biome_html_syntax::HtmlFileSource;
+use biome_js_analyze::ProjectModuleExports;
 use biome_js_parser::{parse, JsParserOptions};
 use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, Language, TextRange, TextSize};
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
 use biome_parser::AnyParse;
 use biome_project::PackageJson;
-use biome_rowan::{FileSourceError, NodeCache};
+use biome_rowan::{Direction, FileSourceError, NodeCache, SyntaxKind, TokenAtOffset};
+use biome_toml_syntax::TomlFileSource;
 pub use javascript::JsFormatterSettings;
 use std::ffi::OsStr;
 use std::path::Path;
+use std::time::Instant;
 
 mod astro;
 mod css;
+mod frontmatter;
 mod graphql;
+mod html;
 mod javascript;
 mod json;
+mod markup;
+mod mdx;
 mod svelte;
+mod toml;
 mod unknown;
 mod vue;
 
@@ -56,6 +72,8 @@ pub enum DocumentFileSource {
     Json(JsonFileSource),
     Css(CssFileSource),
     Graphql(GraphqlFileSource),
+    Toml(TomlFileSource),
+    Html(HtmlFileSource),
     #[default]
     Unknown,
 }
@@ -84,6 +102,18 @@ impl From<GraphqlFileSource> for DocumentFileSource {
     }
 }
 
+impl From<TomlFileSource> for DocumentFileSource {
+    fn from(value: TomlFileSource) -> Self {
+        Self::Toml(value)
+    }
+}
+
+impl From<HtmlFileSource> for DocumentFileSource {
+    fn from(value: HtmlFileSource) -> Self {
+        Self::Html(value)
+    }
+}
+
 impl From<&Path> for DocumentFileSource {
     fn from(path: &Path) -> Self {
         Self::from_path(path)
@@ -104,6 +134,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_well_known(file_name) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = TomlFileSource::try_from_well_known(file_name) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = HtmlFileSource::try_from_well_known(file_name) {
+            return Ok(file_source.into());
+        }
 
         Err(FileSourceError::UnknownFileName(file_name.into()))
     }
@@ -127,6 +163,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = TomlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = HtmlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownExtension(
             Default::default(),
             extension.into(),
@@ -152,6 +194,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = TomlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = HtmlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownLanguageId(language_id.into()))
     }
 
@@ -259,6 +307,10 @@ impl DocumentFileSource {
         matches!(self, DocumentFileSource::Css(_))
     }
 
+    pub const fn is_toml_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Toml(_))
+    }
+
     pub fn to_js_file_source(&self) -> Option<JsFileSource> {
         match self {
             DocumentFileSource::Js(file_source) => Some(*file_source),
@@ -280,6 +332,13 @@ impl DocumentFileSource {
         }
     }
 
+    pub fn to_toml_file_source(&self) -> Option<TomlFileSource> {
+        match self {
+            DocumentFileSource::Toml(toml) => Some(*toml),
+            _ => None,
+        }
+    }
+
     pub fn to_css_file_source(&self) -> Option<CssFileSource> {
         match self {
             DocumentFileSource::Css(css) => Some(*css),
@@ -287,6 +346,13 @@ impl DocumentFileSource {
         }
     }
 
+    pub fn to_html_file_source(&self) -> Option<HtmlFileSource> {
+        match self {
+            DocumentFileSource::Html(html) => Some(*html),
+            _ => None,
+        }
+    }
+
     pub fn can_parse(path: &Path, content: &str) -> bool {
         let file_source = DocumentFileSource::from(path);
         match file_source {
@@ -294,10 +360,13 @@ impl DocumentFileSource {
                 EmbeddingKind::Astro => ASTRO_FENCE.is_match(content),
                 EmbeddingKind::Vue => VUE_FENCE.is_match(content),
                 EmbeddingKind::Svelte => SVELTE_FENCE.is_match(content),
+                EmbeddingKind::Mdx => true,
                 EmbeddingKind::None => true,
             },
             DocumentFileSource::Json(_) | DocumentFileSource::Css(_) => true,
             DocumentFileSource::Graphql(_) => true,
+            DocumentFileSource::Toml(_) => true,
+            DocumentFileSource::Html(_) => true,
             DocumentFileSource::Unknown => false,
         }
     }
@@ -329,6 +398,8 @@ impl biome_console::fmt::Display for DocumentFileSource {
             }
             DocumentFileSource::Css(_) => fmt.write_markup(markup! { "CSS" }),
             DocumentFileSource::Graphql(_) => fmt.write_markup(markup! { "GraphQL" }),
+            DocumentFileSource::Toml(_) => fmt.write_markup(markup! { "TOML" }),
+            DocumentFileSource::Html(_) => fmt.write_markup(markup! { "HTML" }),
             DocumentFileSource::Unknown => fmt.write_markup(markup! { "Unknown" }),
         }
     }
@@ -342,10 +413,16 @@ pub struct FixAllParams<'a> {
     pub(crate) should_format: bool,
     pub(crate) biome_path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) css_custom_properties: ProjectCustomProperties,
+    pub(crate) module_exports: ProjectModuleExports,
+    /// The globals declared by the TypeScript declaration files of the project
+    pub(crate) ambient_globals: Vec<String>,
     pub(crate) document_file_source: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) rule_categories: RuleCategories,
+    /// When set, the analysis stops once this instant is reached
+    pub(crate) deadline: Option<Instant>,
 }
 
 #[derive(Default)]
@@ -371,6 +448,45 @@ type Parse =
 pub struct ParserCapabilities {
     /// Parse a file
     pub(crate) parse: Option<Parse>,
+    /// Computes the ranges of the syntax elements that contain the given positions
+    pub(crate) selection_ranges: Option<SelectionRanges>,
+}
+
+type SelectionRanges = fn(AnyParse, &[TextSize]) -> SelectionRangesResult;
+
+/// Returns the ranges of the syntax elements of `parse` that contain each position,
+/// from the innermost token to the root
+pub(crate) fn selection_ranges<L: biome_rowan::Language + 'static>(
+    parse: AnyParse,
+    positions: &[TextSize],
+) -> SelectionRangesResult {
+    let root = parse.syntax::<L>();
+    let ranges = positions
+        .iter()
+        .map(|&position| {
+            // Prefer the token that starts at the position, unless the position is in its leading trivia
+            let token = match root.token_at_offset(position) {
+                TokenAtOffset::None => return Vec::new(),
+                TokenAtOffset::Single(token) => token,
+                TokenAtOffset::Between(left, right) => {
+                    if right.text_trimmed_range().contains_inclusive(position) {
+                        right
+                    } else {
+                        left
+                    }
+                }
+            };
+            let mut ranges = vec![token.text_trimmed_range()];
+            for node in token.ancestors() {
+                let range = node.text_trimmed_range();
+                if !range.is_empty() && ranges.last() != Some(&range) {
+                    ranges.push(range);
+                }
+            }
+            ranges
+        })
+        .collect();
+    SelectionRangesResult { ranges }
 }
 
 type DebugSyntaxTree = fn(&BiomePath, AnyParse) -> GetSyntaxTreeResult;
@@ -403,6 +519,14 @@ pub(crate) struct LintParams<'a> {
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) categories: RuleCategories,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) css_custom_properties: ProjectCustomProperties,
+    pub(crate) module_exports: ProjectModuleExports,
+    /// The globals declared by the TypeScript declaration files of the project
+    pub(crate) ambient_globals: Vec<String>,
+    /// When set, the analysis stops once this instant is reached
+    pub(crate) deadline: Option<Instant>,
+    /// When set, the time spent running each rule is recorded by this profiler
+    pub(crate) profiler: Option<RuleProfiler>,
 }
 
 pub(crate) struct LintResults {
@@ -417,6 +541,10 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
     pub(crate) path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) css_custom_properties: ProjectCustomProperties,
+    pub(crate) module_exports: ProjectModuleExports,
+    /// The globals declared by the TypeScript declaration files of the project
+    pub(crate) ambient_globals: Vec<String>,
     pub(crate) language: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
@@ -426,7 +554,13 @@ type Lint = fn(LintParams) -> LintResults;
 type CodeActions = fn(CodeActionsParams) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
 type Rename = fn(&BiomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
-type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
+type DocumentHighlights = fn(AnyParse, TextSize) -> DocumentHighlightsResult;
+type OrganizeImports = fn(
+    &BiomePath,
+    &DocumentFileSource,
+    AnyParse,
+    WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError>;
 
 #[derive(Default)]
 pub struct AnalyzerCapabilities {
@@ -438,10 +572,65 @@ pub struct AnalyzerCapabilities {
     pub(crate) fix_all: Option<FixAll>,
     /// It renames a binding inside a file
     pub(crate) rename: Option<Rename>,
+    /// It finds the declaration and the references of a binding inside a file
+    pub(crate) document_highlights: Option<DocumentHighlights>,
+    /// It finds the calls between the functions of a file
+    pub(crate) call_hierarchy: Option<CallHierarchy>,
     /// It organizes imports
     pub(crate) organize_imports: Option<OrganizeImports>,
 }
 
+/// The functions that build the call hierarchy of a file.
+///
+/// The calls to the functions of the other files are resolved by the workspace,
+/// with the exports of the project.
+#[derive(Clone, Copy)]
+pub(crate) struct CallHierarchy {
+    /// It finds the function at a position
+    pub(crate) prepare: fn(&BiomePath, AnyParse, TextSize) -> Option<CallHierarchyTarget>,
+    /// It finds the function exported with a name
+    pub(crate) exported_function: fn(&BiomePath, AnyParse, &str) -> Option<CallHierarchyItem>,
+    /// It finds the calls to a function
+    pub(crate) incoming_calls: fn(&BiomePath, AnyParse, CallTarget) -> Vec<IncomingCall>,
+    /// It finds the calls made by the function of an item
+    pub(crate) outgoing_calls: fn(&BiomePath, AnyParse, &CallHierarchyItem) -> OutgoingCalls,
+}
+
+/// A function found in a file, which may be declared in another file
+pub(crate) enum CallHierarchyTarget {
+    Item(CallHierarchyItem),
+    Imported(ImportedFunction),
+}
+
+/// A function imported from another module, such as `a` in `import { a } from "./a.js"`
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub(crate) struct ImportedFunction {
+    /// The specifier of the import, such as `./a.js`
+    pub(crate) specifier: String,
+    /// The name exported by the imported module
+    pub(crate) name: String,
+}
+
+/// The function of which the calls are searched
+pub(crate) enum CallTarget<'a> {
+    /// The function of an item of the same file
+    Item(&'a CallHierarchyItem),
+    /// The function exported with `name` by the module of which the specifiers match `is_target_module`
+    Imported {
+        name: &'a str,
+        is_target_module: &'a dyn Fn(&str) -> bool,
+    },
+}
+
+/// The calls made by a function
+#[derive(Default)]
+pub(crate) struct OutgoingCalls {
+    /// The calls to the functions of the same file
+    pub(crate) calls: Vec<OutgoingCall>,
+    /// The calls to the imported functions, with the ranges of their callees
+    pub(crate) imported: Vec<(ImportedFunction, Vec<TextRange>)>,
+}
+
 type Format = fn(
     &BiomePath,
     &DocumentFileSource,
@@ -462,6 +651,13 @@ type FormatOnType = fn(
     WorkspaceSettingsHandle,
     TextSize,
 ) -> Result<Printed, WorkspaceError>;
+type FormatEmbedding = fn(
+    &BiomePath,
+    &DocumentFileSource,
+    &str,
+    Printed,
+    WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError>;
 
 #[derive(Default)]
 pub(crate) struct FormatterCapabilities {
@@ -471,6 +667,352 @@ pub(crate) struct FormatterCapabilities {
     pub(crate) format_range: Option<FormatRange>,
     /// It formats a file while typing
     pub(crate) format_on_type: Option<FormatOnType>,
+    /// It compares the syntax tree of a file with the syntax tree of its formatted content
+    pub(crate) compare_syntax: Option<CompareSyntax>,
+    /// It formats the content of a file that embeds a script, given the content of the file
+    /// and its formatted script. It returns the formatted content of the whole file
+    pub(crate) format_embedding: Option<FormatEmbedding>,
+}
+
+type CompareSyntax = fn(&AnyParse, &AnyParse) -> Option<SyntaxTreeDifference>;
+
+/// Returns the first difference between the tokens of `original` and the tokens of `formatted`,
+/// ignoring the changes that the formatter is allowed to make.
+///
+/// The tokens are compared by kind and by trimmed text. The tokens of the keywords and of the punctuation
+/// are only compared by kind, because the formatter can change the case of the CSS keywords.
+/// A string is compared to a token of another kind by content, because the formatter can remove the quotes of the property names.
+///
+/// The formatter can add or remove parentheses, semicolons, commas and leading separators of unions,
+/// add or remove the quotes of strings and property names, change the whitespace of the texts of JSX and of the block strings,
+/// print the numbers, the escape sequences and the flags of regular expressions in their canonical form,
+/// remove the empty import attributes and add or remove the `{" "}` of JSX.
+/// The comments are compared separately, because the formatter can move them.
+pub(crate) fn compare_formatted_syntax<L: biome_rowan::Language + 'static>(
+    original: &AnyParse,
+    formatted: &AnyParse,
+) -> Option<SyntaxTreeDifference> {
+    compare_formatted_syntax_with::<L>(original, formatted, &FormatterChanges::default())
+}
+
+/// The changes that the formatter of a language makes, in addition to the ones allowed by [compare_formatted_syntax]
+pub(crate) struct FormatterChanges<L: biome_rowan::Language> {
+    /// Returns `true` for the lists of modifiers that the formatter sorts
+    pub(crate) is_sorted_modifier_list: fn(L::Kind) -> bool,
+    /// Returns `true` for the tokens that the formatter prints in lowercase
+    pub(crate) is_lowercased: fn(&biome_rowan::SyntaxToken<L>) -> bool,
+}
+
+impl<L: biome_rowan::Language> Default for FormatterChanges<L> {
+    fn default() -> Self {
+        Self {
+            is_sorted_modifier_list: |_| false,
+            is_lowercased: |_| false,
+        }
+    }
+}
+
+/// Like [compare_formatted_syntax], for the languages of which the formatter makes more changes.
+///
+/// The modifiers of the sorted lists are compared regardless of their order,
+/// and the lowercased tokens are compared regardless of their case.
+pub(crate) fn compare_formatted_syntax_with<L: biome_rowan::Language + 'static>(
+    original: &AnyParse,
+    formatted: &AnyParse,
+    changes: &FormatterChanges<L>,
+) -> Option<SyntaxTreeDifference> {
+    let (original_tokens, mut original_comments) =
+        comparable_tokens(&original.syntax::<L>(), changes);
+    let (formatted_tokens, mut formatted_comments) =
+        comparable_tokens(&formatted.syntax::<L>(), changes);
+
+    let difference = original_tokens
+        .iter()
+        .map(Some)
+        .chain(std::iter::repeat(None))
+        .zip(
+            formatted_tokens
+                .iter()
+                .map(Some)
+                .chain(std::iter::repeat(None)),
+        )
+        .take(original_tokens.len().max(formatted_tokens.len()))
+        .find(|(original, formatted)| match (original, formatted) {
+            (Some(original), Some(formatted)) => {
+                original.text != formatted.text
+                    || (original.kind != formatted.kind
+                        && !(original.is_string || formatted.is_string))
+            }
+            _ => true,
+        });
+    if let Some((original, formatted)) = difference {
+        return Some(SyntaxTreeDifference {
+            original_range: original.map(|token| token.range),
+            formatted_range: formatted.map(|token| token.range),
+        });
+    }
+
+    original_comments.sort_by(|a, b| a.text.cmp(&b.text));
+    formatted_comments.sort_by(|a, b| a.text.cmp(&b.text));
+    let mut formatted_comments = formatted_comments.iter().peekable();
+    for comment in &original_comments {
+        match formatted_comments.peek() {
+            Some(formatted) if formatted.text == comment.text => {
+                formatted_comments.next();
+            }
+            Some(formatted) if formatted.text < comment.text => {
+                return Some(SyntaxTreeDifference {
+                    original_range: None,
+                    formatted_range: Some(formatted.range),
+                });
+            }
+            _ => {
+                return Some(SyntaxTreeDifference {
+                    original_range: Some(comment.range),
+                    formatted_range: None,
+                });
+            }
+        }
+    }
+    formatted_comments
+        .next()
+        .map(|formatted| SyntaxTreeDifference {
+            original_range: None,
+            formatted_range: Some(formatted.range),
+        })
+}
+
+/// A token, or a word of the text of a token, with its normalized text
+struct ComparableToken<K> {
+    kind: K,
+    text: String,
+    range: TextRange,
+    /// Whether the token is a string, of which the text is the unquoted content
+    is_string: bool,
+}
+
+/// A comment, with its text without whitespace
+struct ComparableComment {
+    text: String,
+    range: TextRange,
+}
+
+/// Returns the normalized tokens of `root`, and its comments
+fn comparable_tokens<L: biome_rowan::Language>(
+    root: &biome_rowan::SyntaxNode<L>,
+    changes: &FormatterChanges<L>,
+) -> (Vec<ComparableToken<L::Kind>>, Vec<ComparableComment>) {
+    let mut tokens: Vec<ComparableToken<L::Kind>> = Vec::new();
+    let mut comments = Vec::new();
+    // The start of the modifiers that the formatter can reorder, and their list
+    let mut modifiers: Option<(usize, biome_rowan::SyntaxNode<L>)> = None;
+    for token in root.descendants_tokens(Direction::Next) {
+        for piece in token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces())
+            .filter(|piece| piece.is_comments())
+        {
+            let range = piece.text_range();
+            // The formatter can join line comments, and reindent the lines of a block comment
+            let comments_text = match piece.text().strip_prefix("//") {
+                Some(text) => text.split("//").map(|text| format!("//{text}")).collect(),
+                None => vec![piece.text().to_string()],
+            };
+            comments.extend(comments_text.into_iter().map(|text| ComparableComment {
+                text: text.split_whitespace().collect(),
+                range,
+            }));
+        }
+
+        let kind = token.kind();
+        let text = token.text_trimmed();
+        let range = token.text_trimmed_range();
+
+        // A modifier is the only token of a node of a list of modifiers, such as `static` in `static async f() {}`
+        let modifier_list = token
+            .parent()
+            .filter(|parent| parent.first_token().as_ref() == parent.last_token().as_ref())
+            .and_then(|parent| parent.parent())
+            .filter(|list| (changes.is_sorted_modifier_list)(list.kind()));
+        match (&modifiers, modifier_list) {
+            (Some((_, list)), Some(modifier_list)) if *list == modifier_list => {}
+            (_, modifier_list) => {
+                if let Some((start, _)) = modifiers.take() {
+                    tokens[start..].sort_by(|a, b| a.text.cmp(&b.text));
+                }
+                modifiers = modifier_list.map(|list| (tokens.len(), list));
+            }
+        }
+
+        if matches!(text, "|" | "&")
+            && token
+                .next_sibling_or_token()
+                .is_some_and(|next| next.kind().is_list())
+        {
+            // The leading separator of a union
+            continue;
+        }
+        if matches!(text, "(" | ")" | ";" | ",") {
+            continue;
+        }
+        if text == "}" {
+            match tokens.as_slice() {
+                // `{" "}` in JSX
+                [.., open, string]
+                    if open.text == "{" && string.is_string && string.text.trim().is_empty() =>
+                {
+                    tokens.truncate(tokens.len() - 2);
+                    continue;
+                }
+                // The empty import attributes
+                [.., keyword, open]
+                    if open.text == "{" && matches!(keyword.text.as_str(), "with" | "assert") =>
+                {
+                    tokens.truncate(tokens.len() - 2);
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(fixed_text) = kind.to_string() {
+            // The text of the keywords and of the punctuation is fixed by their kind
+            tokens.push(ComparableToken {
+                kind,
+                text: fixed_text.to_string(),
+                range,
+                is_string: false,
+            });
+        } else if let Some(content) = unquoted(text) {
+            let content = match content
+                .strip_prefix("\"\"")
+                .and_then(|content| content.strip_suffix("\"\""))
+            {
+                // The formatter can reindent the lines of a block string
+                Some(content) => content.split_whitespace().collect::<Vec<_>>().join(" "),
+                None => unescaped(content),
+            };
+            tokens.push(ComparableToken {
+                kind,
+                text: content,
+                range,
+                is_string: true,
+            });
+        } else if let Some(regex) = regex_with_sorted_flags(text) {
+            tokens.push(ComparableToken {
+                kind,
+                text: regex,
+                range,
+                is_string: false,
+            });
+        } else {
+            let text = if (changes.is_lowercased)(&token) {
+                text.to_lowercase()
+            } else {
+                text.to_string()
+            };
+            // The formatter can change the whitespace of the texts of JSX and of the templates
+            tokens.extend(split_words(&text).map(|word| ComparableToken {
+                kind,
+                text: normalized_number(word).unwrap_or_else(|| word.to_string()),
+                range,
+                is_string: false,
+            }));
+        }
+    }
+    if let Some((start, _)) = modifiers {
+        tokens[start..].sort_by(|a, b| a.text.cmp(&b.text));
+    }
+    (tokens, comments)
+}
+
+/// Splits `text` at the whitespace, and around the `|` that separate the columns of the tables of `each` templates
+fn split_words(text: &str) -> impl Iterator<Item = &str> {
+    text.split_whitespace()
+        .flat_map(|word| {
+            word.split_inclusive('|')
+                .flat_map(|word| match word.strip_suffix('|') {
+                    Some(word) if !word.is_empty() => [Some(word), Some("|")],
+                    Some(_) => [Some("|"), None],
+                    None => [Some(word), None],
+                })
+        })
+        .flatten()
+}
+
+/// Returns the canonical form of `word`, if it's a number
+fn normalized_number(word: &str) -> Option<String> {
+    let is_number = match word.trim_start_matches(['+', '-']).as_bytes() {
+        [first, ..] if first.is_ascii_digit() => true,
+        [b'.', second, ..] => second.is_ascii_digit(),
+        _ => false,
+    };
+    if !is_number {
+        return None;
+    }
+    let word = word.to_lowercase();
+    Some(
+        word.replace('_', "")
+            .parse::<f64>()
+            .map_or(word, |number| number.to_string()),
+    )
+}
+
+fn unquoted(text: &str) -> Option<&str> {
+    ['"', '\''].into_iter().find_map(|quote| {
+        text.strip_prefix(quote)
+            .and_then(|text| text.strip_suffix(quote))
+    })
+}
+
+/// Removes the backslash of the escape sequences that are equivalent to the escaped character
+fn unescaped(content: &str) -> String {
+    let mut unescaped = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            unescaped.push(char);
+            continue;
+        }
+        match chars.next() {
+            Some(
+                escaped @ ('\\'
+                | 'n'
+                | 'r'
+                | 't'
+                | 'b'
+                | 'f'
+                | 'v'
+                | 'x'
+                | 'u'
+                | '0'..='7'
+                | '\n'
+                | '\r'),
+            ) => {
+                unescaped.push('\\');
+                unescaped.push(escaped);
+            }
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Returns `text` with its flags sorted, if `text` is a regular expression literal
+fn regex_with_sorted_flags(text: &str) -> Option<String> {
+    let end = text.rfind('/')?;
+    if !text.starts_with('/') || text.starts_with("//") || text.starts_with("/*") || end == 0 {
+        return None;
+    }
+    let (pattern, flags) = text.split_at(end + 1);
+    let mut flags: Vec<_> = flags.chars().collect();
+    flags.sort_unstable();
+    Some(format!(
+        "{pattern}{}",
+        flags.into_iter().collect::<String>()
+    ))
 }
 
 type Search = fn(
@@ -504,8 +1046,11 @@ pub(crate) struct Features {
     astro: AstroFileHandler,
     vue: VueFileHandler,
     svelte: SvelteFileHandler,
+    mdx: MdxFileHandler,
     unknown: UnknownFileHandler,
     graphql: GraphqlFileHandler,
+    toml: TomlFileHandler,
+    html: HtmlFileHandler,
 }
 
 impl Features {
@@ -517,7 +1062,10 @@ impl Features {
             astro: AstroFileHandler {},
             vue: VueFileHandler {},
             svelte: SvelteFileHandler {},
+            mdx: MdxFileHandler {},
             graphql: GraphqlFileHandler {},
+            toml: TomlFileHandler {},
+            html: HtmlFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
     }
@@ -533,11 +1081,14 @@ impl Features {
                 EmbeddingKind::Astro => self.astro.capabilities(),
                 EmbeddingKind::Vue => self.vue.capabilities(),
                 EmbeddingKind::Svelte => self.svelte.capabilities(),
+                EmbeddingKind::Mdx => self.mdx.capabilities(),
                 EmbeddingKind::None => self.js.capabilities(),
             },
             DocumentFileSource::Json(_) => self.json.capabilities(),
             DocumentFileSource::Css(_) => self.css.capabilities(),
             DocumentFileSource::Graphql(_) => self.graphql.capabilities(),
+            DocumentFileSource::Toml(_) => self.toml.capabilities(),
+            DocumentFileSource::Html(_) => self.html.capabilities(),
             DocumentFileSource::Unknown => self.unknown.capabilities(),
         }
     }
@@ -768,12 +1319,17 @@ impl<'a, 'b> LintVisitor<'a, 'b> {
         let has_only_filter = !self.only.is_empty();
         let enabled_rules = if !has_only_filter {
             self.settings
-                .and_then(|settings| settings.as_linter_rules(self.path))
-                .as_ref()
-                .map(|rules| rules.as_enabled_rules())
+                .and_then(|settings| {
+                    let rules = settings.as_linter_rules(self.path)?;
+                    Some(
+                        rules
+                            .as_enabled_rules()
+                            .into_iter()
+                            .filter(|filter| is_rule_enabled_for_path(settings, filter, self.path))
+                            .collect::<Vec<_>>(),
+                    )
+                })
                 .unwrap_or_default()
-                .into_iter()
-                .collect::<Vec<_>>()
         } else {
             vec![]
         };
@@ -1113,6 +1669,7 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             biome_css_analyze::visit_registry(&mut syntax);
             biome_json_analyze::visit_registry(&mut syntax);
             biome_graphql_analyze::visit_registry(&mut syntax);
+            biome_html_analyze::visit_registry(&mut syntax);
             enabled_rules.extend(syntax.enabled_rules);
         }
 
@@ -1121,6 +1678,7 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             biome_css_analyze::visit_registry(&mut lint);
             biome_json_analyze::visit_registry(&mut lint);
             biome_graphql_analyze::visit_registry(&mut lint);
+            biome_html_analyze::visit_registry(&mut lint);
             let (linter_enabled_rules, linter_disabled_rules) = lint.finish();
             enabled_rules.extend(linter_enabled_rules);
             disabled_rules.extend(linter_disabled_rules);
@@ -1131,6 +1689,7 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             biome_css_analyze::visit_registry(&mut assists);
             biome_json_analyze::visit_registry(&mut assists);
             biome_graphql_analyze::visit_registry(&mut assists);
+            biome_html_analyze::visit_registry(&mut assists);
             let (assists_enabled_rules, assists_disabled_rules) = assists.finish();
             enabled_rules.extend(assists_enabled_rules);
             disabled_rules.extend(assists_disabled_rules);
@@ -1154,3 +1713,33 @@ fn test_vue_script_lang() {
     assert!(parse_lang_from_script_opening_tag(VUE_SETUP_JS_SCRIPT_OPENING_TAG).is_javascript());
     assert!(parse_lang_from_script_opening_tag(VUE_SETUP_TS_SCRIPT_OPENING_TAG).is_typescript());
 }
+
+#[test]
+fn test_compare_formatted_syntax() {
+    let compare = |original: &str, formatted: &str| {
+        let original = parse(original, JsFileSource::ts(), JsParserOptions::default());
+        let formatted = parse(formatted, JsFileSource::ts(), JsParserOptions::default());
+        compare_formatted_syntax::<JsLanguage>(&original.into(), &formatted.into())
+    };
+
+    assert_eq!(compare("a ( 'b' ,c )", "a(\"b\", c);\n"), None);
+    assert_eq!(compare("({ 'a': 0XA })", "({ a: 0xa });\n"), None);
+    assert_eq!(
+        compare("type A = | 'a' | 'b'", "type A = \"a\" | \"b\";\n"),
+        None
+    );
+    assert_eq!(
+        compare("a(b, c)", "a(c, b);\n"),
+        Some(SyntaxTreeDifference {
+            original_range: Some(TextRange::new(2.into(), 3.into())),
+            formatted_range: Some(TextRange::new(2.into(), 3.into())),
+        })
+    );
+    assert_eq!(
+        compare("a.b", "a;\n"),
+        Some(SyntaxTreeDifference {
+            original_range: Some(TextRange::new(1.into(), 2.into())),
+            formatted_range: None,
+        })
+    );
+}
@@ -10,14 +10,16 @@ use crate::workspace::{
 use crate::WorkspaceError;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
+use biome_js_formatter::context::JsFormatOptions;
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
-use biome_js_syntax::{EmbeddingKind, JsFileSource, Language, TextRange, TextSize};
+use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, Language, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
 use regex::{Match, Regex};
 use std::sync::LazyLock;
 use tracing::debug;
 
+use super::markup::{format_markup, MarkupKind};
 use super::{parse_lang_from_script_opening_tag, SearchCapabilities};
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -80,12 +82,25 @@ impl SvelteFileHandler {
             })
             .map_or(JsFileSource::js_module(), |fs| fs)
     }
+
+    /// It formats the markup of a Svelte file: the elements are indented according to their nesting,
+    /// the attributes and the blocks are normalized, and the expressions are formatted.
+    ///
+    /// The content of the `<script>` and `<style>` blocks is left untouched.
+    /// The markup is returned as is when its tags aren't balanced.
+    pub fn format_markup(input: &str, options: &JsFormatOptions) -> String {
+        format_markup(input, MarkupKind::Svelte, Self::file_source(input), options)
+            .unwrap_or_else(|| input.to_string())
+    }
 }
 
 impl ExtensionHandler for SvelteFileHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                selection_ranges: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: None,
                 debug_control_flow: None,
@@ -95,6 +110,8 @@ impl ExtensionHandler for SvelteFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                document_highlights: None,
+                call_hierarchy: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -102,6 +119,8 @@ impl ExtensionHandler for SvelteFileHandler {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                compare_syntax: None,
+                format_embedding: Some(format_embedding),
             },
             // TODO: We should be able to search JS portions already
             search: SearchCapabilities { search: None },
@@ -138,6 +157,32 @@ fn format(
 ) -> Result<Printed, WorkspaceError> {
     javascript::format(biome_path, document_file_source, parse, settings)
 }
+
+fn format_embedding(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    content: &str,
+    printed: Printed,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let content = if printed.as_code().is_empty() {
+        content.to_string()
+    } else {
+        SvelteFileHandler::output(content, printed.as_code())
+    };
+    // The formatting of the markup is experimental, and it's opt-in
+    let markup_formatting = settings
+        .settings()
+        .is_some_and(|settings| settings.markup_formatting_enabled(biome_path));
+    let code = if markup_formatting {
+        let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+        SvelteFileHandler::format_markup(&content, &options)
+    } else {
+        content
+    };
+    Ok(Printed::new(code, None, vec![], vec![]))
+}
+
 pub(crate) fn format_range(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
@@ -170,6 +215,11 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     javascript::fix_all(params)
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+fn organize_imports(
+    path: &BiomePath,
+    file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    javascript::organize_imports(path, file_source, parse, settings)
 }
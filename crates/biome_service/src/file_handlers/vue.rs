@@ -8,13 +8,16 @@ use crate::workspace::{
     DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
-use biome_formatter::Printed;
+use biome_formatter::{FormatOptions, LineWidth, Printed, QuoteStyle};
 use biome_fs::BiomePath;
-use biome_js_parser::{parse_js_with_cache, JsParserOptions};
-use biome_js_syntax::{EmbeddingKind, JsFileSource, Language, TextRange, TextSize};
+use biome_js_formatter::context::JsFormatOptions;
+use biome_js_formatter::format_node;
+use biome_js_parser::{parse_expression, parse_js_with_cache, JsParserOptions};
+use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, Language, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
 use regex::{Match, Regex};
+use std::ops::Range;
 use std::sync::LazyLock;
 use tracing::debug;
 
@@ -28,6 +31,11 @@ pub static VUE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r#"(?ixs)(?<opening><script(?:\s.*?)?>)\r?\n(?<script>(?U:.*))</script>"#).unwrap()
 });
 
+/// The root `<template>` block of a Vue file. The nested `<template>` tags are part of it.
+pub static VUE_TEMPLATE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)<template(?:\s[^>]*)?>(?<template>.*)</template>"#).unwrap()
+});
+
 impl VueFileHandler {
     /// It extracts the JavaScript/TypeScript code contained in the script block of a Vue file
     ///
@@ -80,12 +88,229 @@ impl VueFileHandler {
             })
             .map_or(JsFileSource::js_module(), |fs| fs)
     }
+
+    /// It formats the expressions contained in the attributes of the `<template>` block of a Vue file,
+    /// such as `:prop="expression"` and `@event="handler($event)"`.
+    ///
+    /// The expressions are printed with the quotes that aren't used by the attribute,
+    /// and the line width accounts for the column where the value of the attribute starts.
+    /// The attributes that contain an invalid expression are left untouched.
+    pub fn format_template(
+        input: &str,
+        file_source: JsFileSource,
+        options: &JsFormatOptions,
+    ) -> String {
+        let Some(template) = VUE_TEMPLATE_FENCE
+            .captures(input)
+            .and_then(|captures| captures.name("template"))
+        else {
+            return input.to_string();
+        };
+        let mut output = input.to_string();
+        // The edits are applied from the end, so that the ranges of the previous attributes stay valid
+        for attribute in template_attributes(template.as_str(), template.start())
+            .into_iter()
+            .rev()
+        {
+            if !is_expression_attribute(&input[attribute.name.clone()]) {
+                continue;
+            }
+            if let Some(formatted) = format_attribute_value(input, &attribute, file_source, options)
+            {
+                output.replace_range(attribute.value, &formatted);
+            }
+        }
+        output
+    }
+}
+
+/// An attribute of a tag of a template, whose value is quoted
+#[derive(Debug)]
+struct TemplateAttribute {
+    /// The byte range of the name of the attribute
+    name: Range<usize>,
+    /// The byte range of the value of the attribute, without its quotes
+    value: Range<usize>,
+    /// The quote that wraps the value of the attribute
+    quote: u8,
+}
+
+/// Returns the quoted attributes of the tags contained in `template`, ignoring the comments.
+///
+/// The ranges of the attributes are offset by `offset`.
+fn template_attributes(template: &str, offset: usize) -> Vec<TemplateAttribute> {
+    let bytes = template.as_bytes();
+    let mut attributes = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index..].starts_with(b"<!--") {
+            index = template[index..]
+                .find("-->")
+                .map_or(bytes.len(), |end| index + end + "-->".len());
+            continue;
+        }
+        if bytes[index] != b'<' || !bytes.get(index + 1).is_some_and(u8::is_ascii_alphabetic) {
+            index += 1;
+            continue;
+        }
+        // Skip the name of the tag
+        while index < bytes.len() && !is_tag_delimiter(bytes, index) {
+            index += 1;
+        }
+        loop {
+            while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+                index += 1;
+            }
+            match bytes.get(index) {
+                None => break,
+                Some(b'>') => {
+                    index += 1;
+                    break;
+                }
+                Some(b'/') if bytes.get(index + 1) == Some(&b'>') => {
+                    index += 2;
+                    break;
+                }
+                _ => {}
+            }
+            let name_start = index;
+            while index < bytes.len() && !is_tag_delimiter(bytes, index) && bytes[index] != b'=' {
+                index += 1;
+            }
+            if name_start == index {
+                // A stray character, such as a `/` or a `=` without name
+                index += 1;
+                continue;
+            }
+            let name = (offset + name_start)..(offset + index);
+            let mut value_start = index;
+            while value_start < bytes.len() && bytes[value_start].is_ascii_whitespace() {
+                value_start += 1;
+            }
+            // An attribute without value
+            if bytes.get(value_start) != Some(&b'=') {
+                continue;
+            }
+            value_start += 1;
+            while value_start < bytes.len() && bytes[value_start].is_ascii_whitespace() {
+                value_start += 1;
+            }
+            index = value_start;
+            match bytes.get(value_start) {
+                Some(&quote @ (b'"' | b'\'')) => {
+                    let value_end = bytes[value_start + 1..]
+                        .iter()
+                        .position(|byte| *byte == quote)
+                        .map_or(bytes.len(), |end| value_start + 1 + end);
+                    if value_end < bytes.len() {
+                        attributes.push(TemplateAttribute {
+                            name,
+                            value: (offset + value_start + 1)..(offset + value_end),
+                            quote,
+                        });
+                    }
+                    index = value_end + 1;
+                }
+                // An unquoted value
+                _ => {
+                    while index < bytes.len() && !is_tag_delimiter(bytes, index) {
+                        index += 1;
+                    }
+                }
+            }
+        }
+    }
+    attributes
+}
+
+/// Returns `true` if the byte at `index` ends a tag name or an attribute
+fn is_tag_delimiter(bytes: &[u8], index: usize) -> bool {
+    match bytes[index] {
+        b'>' => true,
+        b'/' => bytes.get(index + 1) == Some(&b'>'),
+        byte => byte.is_ascii_whitespace(),
+    }
+}
+
+/// Returns `true` if the value of the attribute `name` is a JavaScript expression.
+///
+/// The Vue bindings (`:prop`), events (`@event`) and directives (`v-if`) are supported,
+/// as well as the Angular-style bindings (`[prop]`) and events (`(event)`).
+/// `v-for` and `v-slot` are excluded, because their values aren't expressions.
+fn is_expression_attribute(name: &str) -> bool {
+    match name.as_bytes().first() {
+        Some(b':' | b'@') => name.len() > 1,
+        Some(b'[') => name.ends_with(']'),
+        Some(b'(') => name.ends_with(')'),
+        _ => name.strip_prefix("v-").is_some_and(|directive| {
+            let directive = directive.split([':', '.']).next().unwrap_or_default();
+            !directive.is_empty() && !matches!(directive, "for" | "slot")
+        }),
+    }
+}
+
+/// Formats the value of `attribute` as a JavaScript expression.
+///
+/// Returns `None` if the value isn't a valid expression, or if its formatted content
+/// can't be wrapped by the quotes of the attribute.
+fn format_attribute_value(
+    input: &str,
+    attribute: &TemplateAttribute,
+    file_source: JsFileSource,
+    options: &JsFormatOptions,
+) -> Option<String> {
+    let value = input[attribute.value.clone()].trim();
+    if value.is_empty() {
+        return None;
+    }
+    let parse = parse_expression(value, file_source, JsParserOptions::default());
+    if parse.has_errors() {
+        return None;
+    }
+    let line_start = input[..attribute.name.start]
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+    let indentation = input[line_start..attribute.name.start]
+        .split(|c: char| !c.is_whitespace())
+        .next()
+        .unwrap_or_default();
+    let column = input[line_start..attribute.value.start].chars().count();
+    let line_width = options.line_width().value().saturating_sub(column as u16);
+    let line_width = LineWidth::try_from(line_width.max(LineWidth::MIN)).ok()?;
+    let quote_style = if attribute.quote == b'"' {
+        QuoteStyle::Single
+    } else {
+        QuoteStyle::Double
+    };
+    let options = options
+        .clone()
+        .with_line_width(line_width)
+        .with_quote_style(quote_style);
+    let printed = format_node(options, &parse.syntax()).ok()?.print().ok()?;
+    let code = printed.as_code().trim_end();
+    if code.as_bytes().contains(&attribute.quote) {
+        return None;
+    }
+    let mut formatted = String::with_capacity(code.len());
+    for (index, line) in code.lines().enumerate() {
+        if index > 0 {
+            formatted.push('\n');
+            if !line.is_empty() {
+                formatted.push_str(indentation);
+            }
+        }
+        formatted.push_str(line);
+    }
+    Some(formatted)
 }
 
 impl ExtensionHandler for VueFileHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                selection_ranges: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: None,
                 debug_control_flow: None,
@@ -95,6 +320,8 @@ impl ExtensionHandler for VueFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                document_highlights: None,
+                call_hierarchy: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -102,6 +329,8 @@ impl ExtensionHandler for VueFileHandler {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                compare_syntax: None,
+                format_embedding: Some(format_embedding),
             },
             // TODO: We should be able to search JS portions already
             search: SearchCapabilities { search: None },
@@ -139,6 +368,24 @@ fn format(
     javascript::format(biome_path, document_file_source, parse, settings)
 }
 
+fn format_embedding(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    content: &str,
+    printed: Printed,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let content = if printed.as_code().is_empty() {
+        content.to_string()
+    } else {
+        VueFileHandler::output(content, printed.as_code())
+    };
+    let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+    let code =
+        VueFileHandler::format_template(&content, VueFileHandler::file_source(&content), &options);
+    Ok(Printed::new(code, None, vec![], vec![]))
+}
+
 pub(crate) fn format_range(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
@@ -171,6 +418,11 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     javascript::fix_all(params)
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+fn organize_imports(
+    path: &BiomePath,
+    file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    javascript::organize_imports(path, file_source, parse, settings)
 }
@@ -8,6 +8,7 @@ pub mod file_handlers;
 
 pub mod matcher;
 pub mod settings;
+pub mod settings_diff;
 pub mod workspace;
 
 pub mod configuration;
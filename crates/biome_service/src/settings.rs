@@ -1,7 +1,8 @@
 use crate::workspace::{DocumentFileSource, ProjectKey, WorkspaceData};
 use crate::{Matcher, WorkspaceError};
-use biome_analyze::{AnalyzerOptions, AnalyzerRules};
+use biome_analyze::{AnalyzerOptions, AnalyzerRules, RuleFilter};
 use biome_configuration::analyzer::assists::AssistsConfiguration;
+use biome_configuration::analyzer::AriaConfiguration;
 use biome_configuration::diagnostics::InvalidIgnorePattern;
 use biome_configuration::javascript::JsxRuntime;
 use biome_configuration::organize_imports::OrganizeImports;
@@ -11,6 +12,7 @@ use biome_configuration::{
     OverrideFormatterConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, Overrides, PartialConfiguration, PartialCssConfiguration,
     PartialGraphqlConfiguration, PartialJavascriptConfiguration, PartialJsonConfiguration,
+    PartialTomlConfiguration,
 };
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_parser::CssParserOptions;
@@ -18,7 +20,8 @@ use biome_css_syntax::CssLanguage;
 use biome_deserialize::{Merge, StringSet};
 use biome_diagnostics::Category;
 use biome_formatter::{
-    AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    AttributePosition, BracketSpacing, CommentsOptions, IndentStyle, IndentWidth, LineEnding,
+    LineWidth,
 };
 use biome_fs::BiomePath;
 use biome_graphql_formatter::context::GraphqlFormatOptions;
@@ -29,6 +32,8 @@ use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
+use biome_toml_formatter::context::TomlFormatOptions;
+use biome_toml_syntax::TomlLanguage;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use indexmap::IndexSet;
 use rustc_hash::FxHashMap;
@@ -37,7 +42,7 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::RwLockWriteGuard;
 use std::{
-    num::NonZeroU64,
+    num::{NonZeroU32, NonZeroU64},
     sync::{RwLock, RwLockReadGuard},
 };
 use tracing::trace;
@@ -240,6 +245,10 @@ impl Settings {
         if let Some(graphql) = configuration.graphql {
             self.languages.graphql = graphql.into();
         }
+        // toml settings
+        if let Some(toml) = configuration.toml {
+            self.languages.toml = toml.into();
+        }
 
         // NOTE: keep this last. Computing the overrides require reading the settings computed by the parent settings.
         if let Some(overrides) = configuration.overrides {
@@ -255,12 +264,27 @@ impl Settings {
         &self.formatter
     }
 
+    /// The maximum number of syntax nodes of the files that are formatted, taking overrides into account
+    pub fn max_complexity(&self, path: &Path) -> Option<NonZeroU32> {
+        self.override_settings
+            .max_complexity(path)
+            .or(self.formatter.max_complexity)
+    }
+
     /// Whether the formatter is disabled for JavaScript files
     pub fn javascript_formatter_disabled(&self) -> bool {
         let enabled = self.languages.javascript.formatter.enabled.as_ref();
         enabled == Some(&false)
     }
 
+    /// Whether the markup of the Svelte and Astro files is formatted for `path`, taking overrides into account
+    pub fn markup_formatting_enabled(&self, path: &Path) -> bool {
+        self.override_settings
+            .markup_formatting_enabled(path)
+            .or(self.languages.javascript.formatter.markup_formatting)
+            .unwrap_or_default()
+    }
+
     /// Whether the formatter is disabled for JSON files
     pub fn json_formatter_disabled(&self) -> bool {
         let enabled = self.languages.json.formatter.enabled.as_ref();
@@ -273,6 +297,14 @@ impl Settings {
         enabled == Some(&false)
     }
 
+    /// Whether the formatter is disabled for TOML files
+    ///
+    /// The TOML formatter is opt-in, so it's disabled when `toml.formatter.enabled` isn't configured.
+    pub fn toml_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.toml.formatter.enabled.as_ref();
+        enabled != Some(&true)
+    }
+
     /// Whether the linter is disabled for CSS files
     pub fn javascript_linter_disabled(&self) -> bool {
         let enabled = self.languages.javascript.linter.enabled.as_ref();
@@ -380,12 +412,15 @@ pub struct FormatSettings {
     /// Stores whether formatting should be allowed to proceed if a given file
     /// has syntax errors
     pub format_with_errors: bool,
+    /// The maximum number of syntax nodes of the files that are formatted
+    pub max_complexity: Option<NonZeroU32>,
     pub indent_style: Option<IndentStyle>,
     pub indent_width: Option<IndentWidth>,
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
     pub attribute_position: Option<AttributePosition>,
     pub bracket_spacing: Option<BracketSpacing>,
+    pub comments: Option<CommentsOptions>,
     /// List of ignore paths/files
     pub ignored_files: Matcher,
     /// List of included paths/files
@@ -397,12 +432,14 @@ impl Default for FormatSettings {
         Self {
             enabled: true,
             format_with_errors: false,
+            max_complexity: None,
             indent_style: Some(IndentStyle::default()),
             indent_width: Some(IndentWidth::default()),
             line_ending: Some(LineEnding::default()),
             line_width: Some(LineWidth::default()),
             attribute_position: Some(AttributePosition::default()),
             bracket_spacing: Some(BracketSpacing::default()),
+            comments: Some(CommentsOptions::default()),
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
         }
@@ -423,6 +460,8 @@ pub struct OverrideFormatSettings {
     pub line_width: Option<LineWidth>,
     pub bracket_spacing: Option<BracketSpacing>,
     pub attribute_position: Option<AttributePosition>,
+    /// The maximum number of syntax nodes of the files that are formatted
+    pub max_complexity: Option<NonZeroU32>,
 }
 
 /// Linter settings for the entire workspace
@@ -439,6 +478,12 @@ pub struct LinterSettings {
 
     /// List of included paths/files to match
     pub included_files: Matcher,
+
+    /// The custom ARIA roles and properties
+    pub aria: AriaConfiguration,
+
+    /// The compiled `includes` and `excludes` of the rules
+    pub rule_files: RuleFileMatchers,
 }
 
 impl Default for LinterSettings {
@@ -448,6 +493,8 @@ impl Default for LinterSettings {
             rules: Some(biome_configuration::analyzer::linter::Rules::default()),
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
+            aria: AriaConfiguration::default(),
+            rule_files: RuleFileMatchers::default(),
         }
     }
 }
@@ -460,6 +507,52 @@ pub struct OverrideLinterSettings {
 
     /// List of rules
     pub rules: Option<biome_configuration::analyzer::linter::Rules>,
+
+    /// The compiled `includes` and `excludes` of the rules
+    pub rule_files: RuleFileMatchers,
+}
+
+/// The matchers of the `includes` and the `excludes` of the rules, compiled once when the settings are built
+#[derive(Debug, Default)]
+pub struct RuleFileMatchers(FxHashMap<RuleFilter<'static>, RuleFileMatcher>);
+
+/// The matchers of the `includes` and the `excludes` of a rule configuration
+#[derive(Debug)]
+struct RuleFileMatcher {
+    includes: Option<Matcher>,
+    excludes: Option<Matcher>,
+}
+
+impl RuleFileMatchers {
+    /// Compiles the `includes` and the `excludes` of the rules.
+    ///
+    /// It returns [WorkspaceError] if one of the patterns is invalid.
+    pub fn new(
+        working_directory: Option<PathBuf>,
+        rules: Option<&biome_configuration::analyzer::linter::Rules>,
+    ) -> Result<Self, WorkspaceError> {
+        let mut matchers = Self::default();
+        for (rule, files) in rules
+            .map(|rules| rules.all_rule_files())
+            .unwrap_or_default()
+        {
+            let compile = |patterns| to_matcher(working_directory.clone(), Some(patterns));
+            matchers.0.insert(
+                rule,
+                RuleFileMatcher {
+                    includes: files.includes.map(compile).transpose()?,
+                    excludes: files.excludes.map(compile).transpose()?,
+                },
+            );
+        }
+        Ok(matchers)
+    }
+
+    fn get(&self, rule: &RuleFilter) -> Option<&RuleFileMatcher> {
+        // The map is covariant in its keys, so that it can be looked up with the filters of any lifetime
+        let matchers: &FxHashMap<RuleFilter, RuleFileMatcher> = &self.0;
+        matchers.get(rule)
+    }
 }
 
 /// Linter settings for the entire workspace
@@ -473,6 +566,9 @@ pub struct OrganizeImportsSettings {
 
     /// List of ignored paths/files to match
     pub included_files: Matcher,
+
+    /// Whether to merge the type-only imports into the value imports
+    pub merge_type_imports: bool,
 }
 
 impl Default for OrganizeImportsSettings {
@@ -481,6 +577,7 @@ impl Default for OrganizeImportsSettings {
             enabled: true,
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
+            merge_type_imports: false,
         }
     }
 }
@@ -536,6 +633,7 @@ pub struct LanguageListSettings {
     pub json: LanguageSettings<JsonLanguage>,
     pub css: LanguageSettings<CssLanguage>,
     pub graphql: LanguageSettings<GraphqlLanguage>,
+    pub toml: LanguageSettings<TomlLanguage>,
 }
 
 impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
@@ -550,6 +648,12 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
         language_setting.formatter.semicolons = Some(formatter.semicolons);
         language_setting.formatter.arrow_parentheses = Some(formatter.arrow_parentheses);
         language_setting.formatter.bracket_same_line = Some(formatter.bracket_same_line.into());
+        language_setting.formatter.vertical_alignment = Some(formatter.vertical_alignment.into());
+        language_setting.formatter.string_escapes = Some(formatter.string_escapes);
+        language_setting.formatter.embedded_language_formatting =
+            Some(formatter.embedded_language_formatting);
+        language_setting.formatter.max_inline_specifiers = formatter.max_inline_specifiers;
+        language_setting.formatter.markup_formatting = Some(formatter.markup_formatting);
         language_setting.formatter.enabled = Some(formatter.enabled);
         language_setting.formatter.line_width = formatter.line_width;
         language_setting.formatter.bracket_spacing = formatter.bracket_spacing;
@@ -577,6 +681,10 @@ impl From<PartialJsonConfiguration> for LanguageSettings<JsonLanguage> {
         }
         if let Some(formatter) = json.formatter {
             language_setting.formatter.trailing_commas = formatter.trailing_commas;
+            language_setting.formatter.inline_array_width = formatter.inline_array_width;
+            language_setting.formatter.exponent_case = formatter.exponent_case;
+            language_setting.formatter.strip_exponent_plus_sign =
+                formatter.strip_exponent_plus_sign;
             language_setting.formatter.enabled = formatter.enabled;
             language_setting.formatter.line_width = formatter.line_width;
             language_setting.formatter.indent_width = formatter.indent_width.map(Into::into);
@@ -640,6 +748,24 @@ impl From<PartialGraphqlConfiguration> for LanguageSettings<GraphqlLanguage> {
     }
 }
 
+impl From<PartialTomlConfiguration> for LanguageSettings<TomlLanguage> {
+    fn from(toml: PartialTomlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<TomlLanguage> = LanguageSettings::default();
+
+        if let Some(formatter) = toml.formatter {
+            // TODO: change RHS to `formatter.enabled` when toml formatting is enabled by default
+            language_setting.formatter.enabled = Some(formatter.enabled.unwrap_or_default());
+            language_setting.formatter.indent_width = formatter.indent_width;
+            language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
+            language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
+            language_setting.formatter.align_entries = formatter.align_entries;
+        }
+
+        language_setting
+    }
+}
+
 pub trait ServiceLanguage: biome_rowan::Language {
     /// Formatter settings type for this language
     type FormatterSettings: Default;
@@ -977,6 +1103,20 @@ impl OverrideSettings {
         options
     }
 
+    /// It scans the current override rules and return the formatting options that of the first override is matched
+    pub fn to_override_toml_format_options(
+        &self,
+        path: &Path,
+        mut options: TomlFormatOptions,
+    ) -> TomlFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.include.matches_path(path) && !pattern.exclude.matches_path(path) {
+                pattern.apply_overrides_to_toml_format_options(&mut options);
+            }
+        }
+        options
+    }
+
     pub fn to_override_js_parser_options(
         &self,
         path: &Path,
@@ -1046,6 +1186,11 @@ impl OverrideSettings {
                         biome_graphql_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_rules(
+                        rules,
+                        biome_html_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
             }
         }
@@ -1065,6 +1210,16 @@ impl OverrideSettings {
         })
     }
 
+    /// Scans the overrides and returns the formatter complexity limit of the last override that sets one for `path`
+    pub fn max_complexity(&self, path: &Path) -> Option<NonZeroU32> {
+        // Reverse the traversal as only the last override takes effect
+        self.patterns.iter().rev().find_map(|pattern| {
+            let max_complexity = pattern.formatter.max_complexity?;
+            (pattern.include.matches_path(path) && !pattern.exclude.matches_path(path))
+                .then_some(max_complexity)
+        })
+    }
+
     /// Scans the overrides and checks if there's an override that disable the linter for `path`
     pub fn linter_disabled(&self, path: &Path) -> Option<bool> {
         // Reverse the traversal as only the last override takes effect
@@ -1078,6 +1233,17 @@ impl OverrideSettings {
         })
     }
 
+    /// Scans the overrides and checks if there's an override that enables or disables the formatting
+    /// of the markup of the Svelte and Astro files for `path`
+    pub fn markup_formatting_enabled(&self, path: &Path) -> Option<bool> {
+        // Reverse the traversal as only the last override takes effect
+        self.patterns.iter().rev().find_map(|pattern| {
+            let markup_formatting = pattern.languages.javascript.formatter.markup_formatting?;
+            (pattern.include.matches_path(path) && !pattern.exclude.matches_path(path))
+                .then_some(markup_formatting)
+        })
+    }
+
     /// Scans the overrides and checks if there's an override that disable the organize imports for `path`
     pub fn organize_imports_disabled(&self, path: &Path) -> Option<bool> {
         // Reverse the traversal as only the last override takes effect
@@ -1128,6 +1294,7 @@ pub struct OverrideSettingPattern {
     pub(crate) cached_json_format_options: RwLock<Option<JsonFormatOptions>>,
     pub(crate) cached_css_format_options: RwLock<Option<CssFormatOptions>>,
     pub(crate) cached_graphql_format_options: RwLock<Option<GraphqlFormatOptions>>,
+    pub(crate) cached_toml_format_options: RwLock<Option<TomlFormatOptions>>,
     pub(crate) cached_js_parser_options: RwLock<Option<JsParserOptions>>,
     pub(crate) _cached_json_parser_options: RwLock<Option<JsonParserOptions>>,
     pub(crate) cached_css_parser_options: RwLock<Option<CssParserOptions>>,
@@ -1179,6 +1346,18 @@ impl OverrideSettingPattern {
         if let Some(bracket_same_line) = js_formatter.bracket_same_line {
             options.set_bracket_same_line(bracket_same_line);
         }
+        if let Some(vertical_alignment) = js_formatter.vertical_alignment {
+            options.set_vertical_alignment(vertical_alignment);
+        }
+        if let Some(string_escapes) = js_formatter.string_escapes {
+            options.set_string_escapes(string_escapes);
+        }
+        if let Some(embedded_language_formatting) = js_formatter.embedded_language_formatting {
+            options.set_embedded_language_formatting(embedded_language_formatting);
+        }
+        if let Some(max_inline_specifiers) = js_formatter.max_inline_specifiers {
+            options.set_max_inline_specifiers(Some(max_inline_specifiers));
+        }
         if let Some(attribute_position) = js_formatter
             .attribute_position
             .or(formatter.attribute_position)
@@ -1218,6 +1397,15 @@ impl OverrideSettingPattern {
         if let Some(trailing_commas) = json_formatter.trailing_commas {
             options.set_trailing_commas(trailing_commas);
         }
+        if let Some(inline_array_width) = json_formatter.inline_array_width {
+            options.set_inline_array_width(Some(inline_array_width));
+        }
+        if let Some(exponent_case) = json_formatter.exponent_case {
+            options.set_exponent_case(exponent_case);
+        }
+        if let Some(strip_exponent_plus_sign) = json_formatter.strip_exponent_plus_sign {
+            options.set_strip_exponent_plus_sign(strip_exponent_plus_sign);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_json_format_options.write() {
             let options = options.clone();
@@ -1297,6 +1485,39 @@ impl OverrideSettingPattern {
         }
     }
 
+    fn apply_overrides_to_toml_format_options(&self, options: &mut TomlFormatOptions) {
+        if let Ok(readonly_cache) = self.cached_toml_format_options.read() {
+            if let Some(cached_options) = readonly_cache.as_ref() {
+                *options = cached_options.clone();
+                return;
+            }
+        }
+
+        let toml_formatter = &self.languages.toml.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_style) = toml_formatter.indent_style.or(formatter.indent_style) {
+            options.set_indent_style(indent_style);
+        }
+        if let Some(indent_width) = toml_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = toml_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = toml_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+        if let Some(align_entries) = toml_formatter.align_entries {
+            options.set_align_entries(align_entries);
+        }
+
+        if let Ok(mut writeonly_cache) = self.cached_toml_format_options.write() {
+            let options = options.clone();
+            let _ = writeonly_cache.insert(options);
+        }
+    }
+
     fn apply_overrides_to_js_parser_options(&self, options: &mut JsParserOptions) {
         if let Ok(readonly_cache) = self.cached_js_parser_options.read() {
             if let Some(cached_options) = readonly_cache.as_ref() {
@@ -1382,6 +1603,34 @@ pub fn to_matcher(
     Ok(matcher)
 }
 
+/// Returns `true` if the rule of `filter` runs on `path`, according to the `includes` and the `excludes`
+/// of its configuration.
+///
+/// Like the other options of the rule, the patterns of an override that matches `path` replace the ones
+/// of the base configuration and of the previous overrides.
+pub fn is_rule_enabled_for_path(settings: &Settings, filter: &RuleFilter, path: &Path) -> bool {
+    let mut includes = None;
+    let mut excludes = None;
+    let matchers = std::iter::once(&settings.linter.rule_files).chain(
+        settings
+            .override_settings
+            .patterns
+            .iter()
+            .filter(|pattern| {
+                pattern.include.matches_path(path) && !pattern.exclude.matches_path(path)
+            })
+            .map(|pattern| &pattern.linter.rule_files),
+    );
+    for matchers in matchers {
+        if let Some(matcher) = matchers.get(filter) {
+            includes = matcher.includes.as_ref().or(includes);
+            excludes = matcher.excludes.as_ref().or(excludes);
+        }
+    }
+    includes.map_or(true, |includes| includes.matches_path(path))
+        && !excludes.is_some_and(|excludes| excludes.matches_path(path))
+}
+
 fn to_git_ignore(path: PathBuf, matches: &[String]) -> Result<Gitignore, WorkspaceError> {
     let mut gitignore_builder = GitignoreBuilder::new(path.clone());
 
@@ -1412,6 +1661,7 @@ pub fn to_organize_imports_settings(
         enabled: organize_imports.enabled,
         ignored_files: to_matcher(working_directory.clone(), Some(&organize_imports.ignore))?,
         included_files: to_matcher(working_directory, Some(&organize_imports.include))?,
+        merge_type_imports: organize_imports.merge_type_imports,
     })
 }
 
@@ -1425,6 +1675,7 @@ impl TryFrom<OverrideOrganizeImportsConfiguration> for OrganizeImportsSettings {
             enabled: organize_imports.enabled.unwrap_or_default(),
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
+            merge_type_imports: false,
         })
     }
 }
@@ -1449,14 +1700,13 @@ pub fn to_override_settings(
                 line_width: formatter.line_width,
                 bracket_spacing: formatter.bracket_spacing,
                 attribute_position: formatter.attribute_position,
+                max_complexity: formatter.max_complexity,
             })
             .unwrap_or_default();
         let linter = pattern
             .linter
-            .map(|linter| OverrideLinterSettings {
-                enabled: linter.enabled,
-                rules: linter.rules,
-            })
+            .map(|linter| to_override_linter_settings(working_directory.clone(), linter))
+            .transpose()?
             .unwrap_or_default();
         let organize_imports = OverrideOrganizeImportsSettings {
             enabled: pattern
@@ -1469,6 +1719,7 @@ pub fn to_override_settings(
         let json = pattern.json.take().unwrap_or_default();
         let css = pattern.css.take().unwrap_or_default();
         let graphql = pattern.graphql.take().unwrap_or_default();
+        let toml = pattern.toml.take().unwrap_or_default();
         languages.javascript =
             to_javascript_language_settings(javascript, &current_settings.languages.javascript);
 
@@ -1476,6 +1727,7 @@ pub fn to_override_settings(
         languages.css = to_css_language_settings(css, &current_settings.languages.css);
         languages.graphql =
             to_graphql_language_settings(graphql, &current_settings.languages.graphql);
+        languages.toml = to_toml_language_settings(toml, &current_settings.languages.toml);
 
         let pattern_setting = OverrideSettingPattern {
             include: to_matcher(working_directory.clone(), pattern.include.as_ref())?,
@@ -1508,6 +1760,12 @@ fn to_javascript_language_settings(
     language_setting.formatter.arrow_parentheses = formatter.arrow_parentheses;
     language_setting.formatter.bracket_spacing = formatter.bracket_spacing;
     language_setting.formatter.bracket_same_line = formatter.bracket_same_line.map(Into::into);
+    language_setting.formatter.vertical_alignment = formatter.vertical_alignment.map(Into::into);
+    language_setting.formatter.string_escapes = formatter.string_escapes;
+    language_setting.formatter.embedded_language_formatting =
+        formatter.embedded_language_formatting;
+    language_setting.formatter.max_inline_specifiers = formatter.max_inline_specifiers;
+    language_setting.formatter.markup_formatting = formatter.markup_formatting;
     language_setting.formatter.enabled = formatter.enabled;
     language_setting.formatter.line_width = formatter.line_width;
     language_setting.formatter.line_ending = formatter.line_ending;
@@ -1551,6 +1809,9 @@ fn to_json_language_settings(
         .or(formatter.indent_size.map(Into::into));
     language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
     language_setting.formatter.trailing_commas = formatter.trailing_commas;
+    language_setting.formatter.inline_array_width = formatter.inline_array_width;
+    language_setting.formatter.exponent_case = formatter.exponent_case;
+    language_setting.formatter.strip_exponent_plus_sign = formatter.strip_exponent_plus_sign;
 
     let parser = conf.parser.take().unwrap_or_default();
     let parent_parser = &parent_settings.parser;
@@ -1605,6 +1866,23 @@ fn to_graphql_language_settings(
     language_setting
 }
 
+fn to_toml_language_settings(
+    mut conf: PartialTomlConfiguration,
+    _parent_settings: &LanguageSettings<TomlLanguage>,
+) -> LanguageSettings<TomlLanguage> {
+    let mut language_setting: LanguageSettings<TomlLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter.enabled = formatter.enabled;
+    language_setting.formatter.line_width = formatter.line_width;
+    language_setting.formatter.line_ending = formatter.line_ending;
+    language_setting.formatter.indent_width = formatter.indent_width.map(Into::into);
+    language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
+    language_setting.formatter.align_entries = formatter.align_entries;
+
+    language_setting
+}
+
 pub fn to_format_settings(
     working_directory: Option<PathBuf>,
     conf: FormatterConfiguration,
@@ -1619,8 +1897,10 @@ pub fn to_format_settings(
         line_ending: Some(conf.line_ending),
         line_width: Some(conf.line_width),
         format_with_errors: conf.format_with_errors,
+        max_complexity: conf.max_complexity,
         attribute_position: Some(conf.attribute_position),
         bracket_spacing: Some(conf.bracket_spacing),
+        comments: Some(conf.comments.into()),
         ignored_files: to_matcher(working_directory.clone(), Some(&conf.ignore))?,
         included_files: to_matcher(working_directory, Some(&conf.include))?,
     })
@@ -1649,7 +1929,9 @@ impl TryFrom<OverrideFormatterConfiguration> for FormatSettings {
             line_width: conf.line_width,
             attribute_position: Some(AttributePosition::default()),
             bracket_spacing: Some(BracketSpacing::default()),
+            comments: Some(CommentsOptions::default()),
             format_with_errors: conf.format_with_errors.unwrap_or_default(),
+            max_complexity: conf.max_complexity,
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
         })
@@ -1660,25 +1942,33 @@ pub fn to_linter_settings(
     working_directory: Option<PathBuf>,
     conf: LinterConfiguration,
 ) -> Result<LinterSettings, WorkspaceError> {
+    let rules = match conf.preset {
+        Some(preset) => preset.apply(conf.rules),
+        None => conf.rules,
+    };
     Ok(LinterSettings {
         enabled: conf.enabled,
-        rules: Some(conf.rules),
+        rule_files: RuleFileMatchers::new(working_directory.clone(), Some(&rules))?,
+        rules: Some(rules),
         ignored_files: to_matcher(working_directory.clone(), Some(&conf.ignore))?,
         included_files: to_matcher(working_directory.clone(), Some(&conf.include))?,
+        aria: conf.aria,
     })
 }
 
-impl TryFrom<OverrideLinterConfiguration> for LinterSettings {
-    type Error = WorkspaceError;
-
-    fn try_from(conf: OverrideLinterConfiguration) -> Result<Self, Self::Error> {
-        Ok(Self {
-            enabled: conf.enabled.unwrap_or_default(),
-            rules: conf.rules,
-            ignored_files: Matcher::empty(),
-            included_files: Matcher::empty(),
-        })
-    }
+pub fn to_override_linter_settings(
+    working_directory: Option<PathBuf>,
+    conf: OverrideLinterConfiguration,
+) -> Result<OverrideLinterSettings, WorkspaceError> {
+    let rules = match conf.preset {
+        Some(preset) => Some(preset.apply(conf.rules.unwrap_or_default())),
+        None => conf.rules,
+    };
+    Ok(OverrideLinterSettings {
+        enabled: conf.enabled,
+        rule_files: RuleFileMatchers::new(working_directory, rules.as_ref())?,
+        rules,
+    })
 }
 
 pub fn to_assists_settings(
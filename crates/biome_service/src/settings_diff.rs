@@ -0,0 +1,274 @@
+//! Computes what changed between two versions of a configuration.
+//!
+//! A client that reloads the configuration, such as the language server,
+//! can use the difference to refresh only what depends on the changed settings.
+
+use crate::workspace::DocumentFileSource;
+use biome_configuration::PartialConfiguration;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// The difference between two configurations.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct SettingsDiff {
+    /// Whether a setting that can change the diagnostics of any file changed:
+    /// the processed files, the VCS integration, the parsers, the overrides,
+    /// or the `recommended` and `all` presets of the rules.
+    all_diagnostics: bool,
+    /// The lint rules whose configuration changed, as `group/ruleName`.
+    rules: BTreeSet<String>,
+    /// Whether a formatter setting changed.
+    formatter: bool,
+}
+
+impl SettingsDiff {
+    /// Returns the difference between the `previous` and the `next` configurations.
+    ///
+    /// ```
+    /// use biome_configuration::PartialConfiguration;
+    /// use biome_deserialize::json::deserialize_from_json_str;
+    /// use biome_json_parser::JsonParserOptions;
+    /// use biome_service::settings_diff::SettingsDiff;
+    ///
+    /// let parse = |source| {
+    ///     deserialize_from_json_str::<PartialConfiguration>(source, JsonParserOptions::default(), "")
+    ///         .into_deserialized()
+    ///         .unwrap()
+    /// };
+    /// let previous = parse(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#);
+    /// let next = parse(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#);
+    ///
+    /// let diff = SettingsDiff::new(&previous, &next);
+    /// assert_eq!(diff.changed_rules().collect::<Vec<_>>(), ["suspicious/noDebugger"]);
+    /// assert!(!diff.affects_formatter());
+    /// ```
+    pub fn new(previous: &PartialConfiguration, next: &PartialConfiguration) -> Self {
+        let (Ok(previous), Ok(next)) = (serde_json::to_value(previous), serde_json::to_value(next))
+        else {
+            return Self::all();
+        };
+        let mut diff = Self::default();
+        let empty = Map::new();
+        let previous = previous.as_object().unwrap_or(&empty);
+        let next = next.as_object().unwrap_or(&empty);
+        for key in keys(previous, next) {
+            let (previous, next) = (previous.get(key), next.get(key));
+            if previous == next {
+                continue;
+            }
+            match key {
+                // These settings are resolved when the configuration is loaded
+                "$schema" | "extends" => {}
+                "formatter" => diff.formatter = true,
+                "linter" => diff.diff_linter(previous, next),
+                "javascript" | "json" | "css" | "graphql" | "toml" => {
+                    diff.diff_language(previous, next)
+                }
+                _ => {
+                    diff.all_diagnostics = true;
+                    // The overrides and the processed files also apply to the formatter
+                    diff.formatter = true;
+                }
+            }
+        }
+        diff
+    }
+
+    /// Returns a difference where everything changed.
+    pub fn all() -> Self {
+        Self {
+            all_diagnostics: true,
+            rules: BTreeSet::new(),
+            formatter: true,
+        }
+    }
+
+    /// Returns `true` if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        !self.affects_diagnostics() && !self.formatter
+    }
+
+    /// Returns `true` if the diagnostics of a file can be different.
+    pub fn affects_diagnostics(&self) -> bool {
+        self.all_diagnostics || !self.rules.is_empty()
+    }
+
+    /// Returns `true` if the formatting of a file can be different.
+    pub fn affects_formatter(&self) -> bool {
+        self.formatter
+    }
+
+    /// Returns the lint rules whose configuration changed, as `group/ruleName`.
+    pub fn changed_rules(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().map(String::as_str)
+    }
+
+    /// Returns `true` if the diagnostics of the file at `path` can be different.
+    ///
+    /// When only some rules changed, the files are affected if one of the rules applies to their language.
+    pub fn affects_path(&self, path: &Path) -> bool {
+        if self.all_diagnostics {
+            return true;
+        }
+        let metadata = match DocumentFileSource::from_path(path) {
+            DocumentFileSource::Js(_) => &*biome_js_analyze::METADATA,
+            DocumentFileSource::Json(_) => &*biome_json_analyze::METADATA,
+            DocumentFileSource::Css(_) => &*biome_css_analyze::METADATA,
+            DocumentFileSource::Graphql(_) => &*biome_graphql_analyze::METADATA,
+            DocumentFileSource::Html(_) => &*biome_html_analyze::METADATA,
+            DocumentFileSource::Toml(_) | DocumentFileSource::Unknown => return false,
+        };
+        self.rules.iter().any(|rule| {
+            rule.split_once('/')
+                .is_some_and(|(group, rule)| metadata.find_rule(group, rule).is_some())
+        })
+    }
+
+    /// Adds the changes of `other` to this difference.
+    pub fn merge_with(&mut self, other: Self) {
+        self.all_diagnostics |= other.all_diagnostics;
+        self.formatter |= other.formatter;
+        self.rules.extend(other.rules);
+    }
+
+    fn diff_linter(&mut self, previous: Option<&Value>, next: Option<&Value>) {
+        let empty = Map::new();
+        let previous = previous.and_then(Value::as_object).unwrap_or(&empty);
+        let next = next.and_then(Value::as_object).unwrap_or(&empty);
+        for key in keys(previous, next) {
+            let (previous, next) = (previous.get(key), next.get(key));
+            if previous == next {
+                continue;
+            }
+            if key == "rules" {
+                self.diff_rules(previous, next);
+            } else {
+                self.all_diagnostics = true;
+            }
+        }
+    }
+
+    fn diff_rules(&mut self, previous: Option<&Value>, next: Option<&Value>) {
+        let empty = Map::new();
+        let previous = previous.and_then(Value::as_object).unwrap_or(&empty);
+        let next = next.and_then(Value::as_object).unwrap_or(&empty);
+        for group in keys(previous, next) {
+            let (previous, next) = (previous.get(group), next.get(group));
+            if previous == next {
+                continue;
+            }
+            let (Some(previous_rules), Some(next_rules)) = (
+                previous.map_or(Some(&empty), Value::as_object),
+                next.map_or(Some(&empty), Value::as_object),
+            ) else {
+                // `recommended` and `all` enable or disable many rules
+                self.all_diagnostics = true;
+                return;
+            };
+            for rule in keys(previous_rules, next_rules) {
+                if previous_rules.get(rule) == next_rules.get(rule) {
+                    continue;
+                }
+                if matches!(rule, "recommended" | "all") {
+                    self.all_diagnostics = true;
+                    return;
+                }
+                self.rules.insert(format!("{group}/{rule}"));
+            }
+        }
+    }
+
+    fn diff_language(&mut self, previous: Option<&Value>, next: Option<&Value>) {
+        let empty = Map::new();
+        let previous = previous.and_then(Value::as_object).unwrap_or(&empty);
+        let next = next.and_then(Value::as_object).unwrap_or(&empty);
+        for key in keys(previous, next) {
+            if previous.get(key) == next.get(key) {
+                continue;
+            }
+            if key == "formatter" {
+                self.formatter = true;
+            } else {
+                self.all_diagnostics = true;
+            }
+        }
+    }
+}
+
+/// Returns the keys of both objects.
+fn keys<'a>(previous: &'a Map<String, Value>, next: &'a Map<String, Value>) -> BTreeSet<&'a str> {
+    previous
+        .keys()
+        .chain(next.keys())
+        .map(String::as_str)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SettingsDiff;
+    use biome_configuration::PartialConfiguration;
+    use biome_deserialize::json::deserialize_from_json_str;
+    use biome_json_parser::JsonParserOptions;
+    use std::path::Path;
+
+    fn parse(source: &str) -> PartialConfiguration {
+        deserialize_from_json_str::<PartialConfiguration>(source, JsonParserOptions::default(), "")
+            .into_deserialized()
+            .unwrap()
+    }
+
+    #[test]
+    fn same_configuration() {
+        let configuration = parse(r#"{ "linter": { "rules": { "recommended": true } } }"#);
+        assert!(SettingsDiff::new(&configuration, &configuration).is_empty());
+    }
+
+    #[test]
+    fn changed_rules() {
+        let diff = SettingsDiff::new(
+            &parse(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#),
+            &parse(
+                r#"{ "linter": { "rules": { "a11y": { "noBlankTarget": "error" }, "suspicious": { "noDebugger": "off" } } } }"#,
+            ),
+        );
+        assert_eq!(
+            diff.changed_rules().collect::<Vec<_>>(),
+            ["a11y/noBlankTarget"]
+        );
+        assert!(diff.affects_path(Path::new("component.jsx")));
+        assert!(!diff.affects_path(Path::new("style.css")));
+    }
+
+    #[test]
+    fn changed_presets() {
+        let diff = SettingsDiff::new(
+            &parse(r#"{ "linter": { "rules": { "style": { "recommended": true } } } }"#),
+            &parse(r#"{ "linter": { "rules": { "style": { "recommended": false } } } }"#),
+        );
+        assert!(diff.affects_path(Path::new("style.css")));
+    }
+
+    #[test]
+    fn changed_formatter() {
+        let diff = SettingsDiff::new(
+            &parse(r#"{ "formatter": { "indentStyle": "tab" } }"#),
+            &parse(
+                r#"{ "formatter": { "indentStyle": "space" }, "css": { "formatter": { "quoteStyle": "single" } } }"#,
+            ),
+        );
+        assert!(diff.affects_formatter());
+        assert!(!diff.affects_diagnostics());
+    }
+
+    #[test]
+    fn changed_files() {
+        let diff = SettingsDiff::new(
+            &parse(r#"{ "files": { "ignore": ["dist"] } }"#),
+            &parse(r#"{ "files": { "ignore": ["build"] } }"#),
+        );
+        assert!(diff.affects_formatter());
+        assert!(diff.affects_path(Path::new("data.json")));
+    }
+}
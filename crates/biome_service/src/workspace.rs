@@ -56,8 +56,9 @@ use crate::file_handlers::Capabilities;
 pub use crate::file_handlers::DocumentFileSource;
 use crate::settings::Settings;
 use crate::{Deserialize, Serialize, WorkspaceError};
-use biome_analyze::ActionCategory;
+use biome_analyze::profiling::RuleMeasurement;
 pub use biome_analyze::RuleCategories;
+use biome_analyze::{ActionCategory, FileEdit};
 use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::PartialConfiguration;
 use biome_console::{markup, Markup, MarkupBuf};
@@ -65,6 +66,7 @@ use biome_diagnostics::CodeSuggestion;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
 use biome_js_syntax::{TextRange, TextSize};
+use biome_rowan::{Language, SyntaxNode};
 use biome_text_edit::TextEdit;
 use enumflags2::{bitflags, BitFlags};
 #[cfg(feature = "schema")]
@@ -73,10 +75,13 @@ use slotmap::{new_key_type, DenseSlotMap};
 use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc};
 use tracing::debug;
 
+mod barrel_file;
 mod client;
+mod module_graph;
 mod server;
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -176,6 +181,8 @@ impl FileFeaturesResult {
                 !settings.formatter().enabled || settings.json_formatter_disabled()
             } else if file_source.is_css_like() {
                 !settings.formatter().enabled || settings.css_formatter_disabled()
+            } else if file_source.is_toml_like() {
+                !settings.formatter().enabled || settings.toml_formatter_disabled()
             } else {
                 !settings.formatter().enabled
             };
@@ -556,6 +563,12 @@ pub struct CloseFileParams {
     pub path: BiomePath,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ForgetFileParams {
+    pub path: BiomePath,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PullDiagnosticsParams {
@@ -564,6 +577,12 @@ pub struct PullDiagnosticsParams {
     pub max_diagnostics: u64,
     pub only: Vec<RuleSelector>,
     pub skip: Vec<RuleSelector>,
+    /// When set, the analysis of the file is stopped after this duration,
+    /// and a diagnostic is emitted instead
+    pub timeout: Option<Duration>,
+    /// When `true`, the time spent running each rule on the file is returned in
+    /// [PullDiagnosticsResult::rule_timings]
+    pub profile: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -572,6 +591,31 @@ pub struct PullDiagnosticsResult {
     pub diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
     pub errors: usize,
     pub skipped_diagnostics: u64,
+    /// The time spent running each rule on the file, when [PullDiagnosticsParams::profile] is `true`
+    pub rule_timings: Vec<RuleTiming>,
+}
+
+/// The time spent running a rule on a file
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleTiming {
+    pub group: String,
+    pub rule: String,
+    /// The total time spent running the rule
+    pub total: Duration,
+    /// The number of nodes the rule was run on
+    pub count: u64,
+}
+
+impl From<RuleMeasurement> for RuleTiming {
+    fn from(measurement: RuleMeasurement) -> Self {
+        Self {
+            group: measurement.rule.group().to_string(),
+            rule: measurement.rule.rule_name().to_string(),
+            total: measurement.total,
+            count: measurement.count,
+        }
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -595,6 +639,9 @@ pub struct CodeAction {
     pub category: ActionCategory,
     pub rule_name: Option<(Cow<'static, str>, Cow<'static, str>)>,
     pub suggestion: CodeSuggestion,
+    /// The edits of the other files of the project, when the action spans several files
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_edits: Vec<FileEdit>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -603,6 +650,48 @@ pub struct FormatFileParams {
     pub path: BiomePath,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifyFormatFileParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VerifyFormatFileResult {
+    /// The content of the file after formatting it once
+    pub formatted: String,
+    /// The issue found while checking the stability of the formatted content, if any
+    pub issue: Option<FormatStabilityIssue>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FormatStabilityIssue {
+    /// The formatted content can't be parsed
+    SyntaxErrors {
+        diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
+    },
+    /// Formatting the formatted content changes it again
+    NotIdempotent {
+        /// The content of the file after formatting it twice
+        reformatted: String,
+    },
+    /// The syntax tree of the formatted content differs from the syntax tree of the original content
+    SyntaxTreeChanged(SyntaxTreeDifference),
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SyntaxTreeDifference {
+    /// The range of the first element of the original content that differs,
+    /// or `None` if the original content ends before the formatted content
+    pub original_range: Option<TextRange>,
+    /// The range of the first element of the formatted content that differs,
+    /// or `None` if the formatted content ends before the original content
+    pub formatted_range: Option<TextRange>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FormatRangeParams {
@@ -636,6 +725,8 @@ pub struct FixFileParams {
     pub only: Vec<RuleSelector>,
     pub skip: Vec<RuleSelector>,
     pub rule_categories: RuleCategories,
+    /// When set, the analysis of the file is stopped after this duration
+    pub timeout: Option<Duration>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -660,6 +751,32 @@ pub struct FixAction {
     pub rule_name: Option<(Cow<'static, str>, Cow<'static, str>)>,
     /// Source range at which this action was applied
     pub range: TextRange,
+    /// Source code replaced by this action
+    pub before: String,
+    /// Source code inserted by this action
+    pub after: String,
+}
+
+impl FixAction {
+    /// Creates the action of the rule `rule_name` that replaced the `range` of `old_root`, resulting in `new_root`
+    pub fn new<L: Language>(
+        rule_name: Option<(&'static str, &'static str)>,
+        old_root: &SyntaxNode<L>,
+        new_root: &SyntaxNode<L>,
+        range: TextRange,
+    ) -> Self {
+        // The text after the range is the same in both trees
+        let new_end = range.end() + new_root.text_range().len() - old_root.text_range().len();
+        Self {
+            rule_name: rule_name.map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+            range,
+            before: old_root.text().slice(range).to_string(),
+            after: new_root
+                .text()
+                .slice(TextRange::new(range.start(), new_end))
+                .to_string(),
+        }
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -679,6 +796,141 @@ pub struct RenameResult {
     pub indels: TextEdit,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentHighlightsParams {
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentHighlightsResult {
+    /// The declaration and the references of the symbol, in the order they appear in the file
+    pub highlights: Vec<DocumentHighlight>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentHighlight {
+    pub range: TextRange,
+    pub kind: DocumentHighlightKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DocumentHighlightKind {
+    /// The symbol is read
+    Read,
+    /// The symbol is declared or written
+    Write,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SelectionRangesParams {
+    pub path: BiomePath,
+    pub positions: Vec<TextSize>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SelectionRangesResult {
+    /// For each position, the ranges of the syntax elements that contain it,
+    /// from the innermost to the outermost
+    pub ranges: Vec<Vec<TextRange>>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PrepareCallHierarchyParams {
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PrepareCallHierarchyResult {
+    /// The function at the position, if any
+    pub items: Vec<CallHierarchyItem>,
+}
+
+/// A function, or a module for the calls made at its top level
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CallHierarchyItem {
+    pub path: BiomePath,
+    pub name: String,
+    pub kind: CallHierarchyItemKind,
+    /// The range of the whole declaration
+    pub range: TextRange,
+    /// The range of the name of the function
+    pub selection_range: TextRange,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CallHierarchyItemKind {
+    Function,
+    Module,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CallHierarchyCallsParams {
+    /// An item returned by `prepare_call_hierarchy`, or by a previous request for the calls
+    pub item: CallHierarchyItem,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IncomingCallsResult {
+    pub calls: Vec<IncomingCall>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IncomingCall {
+    /// The function that makes the calls
+    pub from: CallHierarchyItem,
+    /// The ranges of the callees of the calls, in the document of `from`
+    pub from_ranges: Vec<TextRange>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OutgoingCallsResult {
+    pub calls: Vec<OutgoingCall>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OutgoingCall {
+    /// The function that is called
+    pub to: CallHierarchyItem,
+    /// The ranges of the callees of the calls, in the document of the item of the request
+    pub from_ranges: Vec<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GenerateBarrelFileParams {
+    /// The path of the barrel file, such as `src/components/index.ts`
+    pub path: BiomePath,
+    /// The modules of the directory of the barrel file.
+    /// The modules that aren't open in the workspace are ignored.
+    pub modules: Vec<BiomePath>,
+    /// The glob patterns of the file names of the modules that aren't re-exported
+    pub exclude: Vec<String>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GenerateBarrelFileResult {
+    /// The new content of the barrel file
+    pub content: String,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Default, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ServerInfo {
@@ -773,6 +1025,37 @@ pub struct DropPatternParams {
     pub pattern: PatternId,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SaveModuleGraphParams {
+    /// The path of the snapshot file
+    pub path: PathBuf,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LoadModuleGraphParams {
+    /// The path of the snapshot file
+    pub path: PathBuf,
+}
+
+#[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProjectIndexesParams {}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProjectIndexesResult {
+    /// Whether the custom properties declared by the CSS documents are indexed
+    pub css_custom_properties: bool,
+    /// Whether the names exported and the modules imported by the JavaScript documents are indexed
+    pub module_exports: bool,
+    /// Whether the keys of the JSON documents are indexed
+    pub json_document_keys: bool,
+    /// Whether the globals declared by the TypeScript declaration files are indexed
+    pub ambient_globals: bool,
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PatternId(String);
@@ -889,6 +1172,10 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Remove a file from the workspace
     fn close_file(&self, params: CloseFileParams) -> Result<(), WorkspaceError>;
 
+    /// Removes a file that was deleted from the indexes of the project,
+    /// which keep the information of the closed files, such as the names exported by a module
+    fn forget_file(&self, params: ForgetFileParams) -> Result<(), WorkspaceError>;
+
     /// Retrieves the list of diagnostics associated to a file
     fn pull_diagnostics(
         &self,
@@ -903,6 +1190,13 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// and returns the resulting source code
     fn format_file(&self, params: FormatFileParams) -> Result<Printed, WorkspaceError>;
 
+    /// Formats the given file twice, and checks that the formatted content
+    /// can be parsed, is stable, and has the same syntax tree as the original content
+    fn verify_format_file(
+        &self,
+        params: VerifyFormatFileParams,
+    ) -> Result<VerifyFormatFileResult, WorkspaceError>;
+
     /// Runs a range of an open document through the formatter
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError>;
 
@@ -916,6 +1210,45 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Return the content of the file after renaming a symbol
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Return the ranges of the declaration and the references of the symbol at a given position
+    fn document_highlights(
+        &self,
+        params: DocumentHighlightsParams,
+    ) -> Result<DocumentHighlightsResult, WorkspaceError>;
+
+    /// Return the ranges of the syntax elements that contain the given positions
+    fn selection_ranges(
+        &self,
+        params: SelectionRangesParams,
+    ) -> Result<SelectionRangesResult, WorkspaceError>;
+
+    /// Return the function at a given position, to start a call hierarchy from it
+    fn prepare_call_hierarchy(
+        &self,
+        params: PrepareCallHierarchyParams,
+    ) -> Result<PrepareCallHierarchyResult, WorkspaceError>;
+
+    /// Return the functions that call the function of an item.
+    ///
+    /// The calls from the other documents are only found when the exports of the project are indexed.
+    fn incoming_calls(
+        &self,
+        params: CallHierarchyCallsParams,
+    ) -> Result<IncomingCallsResult, WorkspaceError>;
+
+    /// Return the functions that the function of an item calls
+    fn outgoing_calls(
+        &self,
+        params: CallHierarchyCallsParams,
+    ) -> Result<OutgoingCallsResult, WorkspaceError>;
+
+    /// Generates the content of a barrel file: a module, such as `index.ts`,
+    /// that re-exports the names exported by the other modules of its directory
+    fn generate_barrel_file(
+        &self,
+        params: GenerateBarrelFileParams,
+    ) -> Result<GenerateBarrelFileResult, WorkspaceError>;
+
     /// Returns debug information about this workspace.
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError>;
 
@@ -933,6 +1266,28 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Used to indicate a client no longer needs a specific pattern.
     fn drop_pattern(&self, params: DropPatternParams) -> Result<(), WorkspaceError>;
 
+    /// Writes a snapshot of the module graph of the workspace, the names exported by its modules,
+    /// so that a workspace created later can start from it with [`Self::load_module_graph()`].
+    ///
+    /// The snapshot is a cache: it isn't written if it can't be serialized.
+    fn save_module_graph(&self, params: SaveModuleGraphParams) -> Result<(), WorkspaceError>;
+
+    /// Loads a snapshot written by [`Self::save_module_graph()`].
+    ///
+    /// The modules that were modified since the snapshot was written, and the modules
+    /// already indexed by the workspace, are ignored. A missing or invalid snapshot is ignored.
+    fn load_module_graph(&self, params: LoadModuleGraphParams) -> Result<(), WorkspaceError>;
+
+    /// Returns the information that the workspace indexes across the documents of the project,
+    /// according to the rules enabled in its settings.
+    ///
+    /// Clients that process a whole project open the indexed documents before the others,
+    /// so that the rules know the information of all the documents.
+    fn project_indexes(
+        &self,
+        params: ProjectIndexesParams,
+    ) -> Result<ProjectIndexesResult, WorkspaceError>;
+
     /// Returns information about the server this workspace is connected to or `None` if the workspace isn't connected to a server.
     fn server_info(&self) -> Option<&ServerInfo>;
 
@@ -1010,6 +1365,8 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         max_diagnostics: u32,
         only: Vec<RuleSelector>,
         skip: Vec<RuleSelector>,
+        timeout: Option<Duration>,
+        profile: bool,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         self.workspace.pull_diagnostics(PullDiagnosticsParams {
             path: self.path.clone(),
@@ -1017,6 +1374,8 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             max_diagnostics: max_diagnostics.into(),
             only,
             skip,
+            timeout,
+            profile,
         })
     }
 
@@ -1040,6 +1399,12 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         })
     }
 
+    pub fn verify_format_file(&self) -> Result<VerifyFormatFileResult, WorkspaceError> {
+        self.workspace.verify_format_file(VerifyFormatFileParams {
+            path: self.path.clone(),
+        })
+    }
+
     pub fn format_range(&self, range: TextRange) -> Result<Printed, WorkspaceError> {
         self.workspace.format_range(FormatRangeParams {
             path: self.path.clone(),
@@ -1061,6 +1426,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         rule_categories: RuleCategories,
         only: Vec<RuleSelector>,
         skip: Vec<RuleSelector>,
+        timeout: Option<Duration>,
     ) -> Result<FixFileResult, WorkspaceError> {
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
@@ -1069,6 +1435,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             only,
             skip,
             rule_categories,
+            timeout,
         })
     }
 
@@ -0,0 +1,78 @@
+use biome_js_analyze::ExportedName;
+use biome_js_syntax::{AnyJsExportClause, AnyJsModuleItem, AnyJsModuleSource, JsModule};
+use biome_rowan::AstNode;
+use std::fmt::Write;
+use std::path::Path;
+
+/// A module re-exported by a barrel file
+pub(super) struct BarrelModule {
+    /// The specifier of the module in the barrel file, such as `./button`
+    pub(super) specifier: String,
+    pub(super) names: Vec<ExportedName>,
+}
+
+/// Returns the specifier of the module at `path` in the barrel file of its directory,
+/// such as `./button` for `button.tsx`
+pub(super) fn module_specifier(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    Some(format!("./{stem}"))
+}
+
+/// Returns the content of a barrel file that re-exports `modules`.
+///
+/// The items of the current barrel file that don't re-export a module of its directory,
+/// such as a default export, are kept before the re-exports.
+pub(super) fn barrel_file_content(barrel: Option<&JsModule>, modules: &[BarrelModule]) -> String {
+    let mut content = String::new();
+    if let Some(barrel) = barrel {
+        for item in barrel.items() {
+            if !is_sibling_re_export(&item) {
+                content.push_str(item.syntax().to_string().trim());
+                content.push('\n');
+            }
+        }
+    }
+    if !content.is_empty() && !modules.is_empty() {
+        content.push('\n');
+    }
+    for BarrelModule { specifier, names } in modules {
+        let are_all_types = names.iter().all(|name| name.is_type);
+        let names = names
+            .iter()
+            .map(|name| match (name.is_type, are_all_types) {
+                (true, false) => format!("type {}", name.name),
+                _ => name.name.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let keyword = if are_all_types {
+            "export type"
+        } else {
+            "export"
+        };
+        let _ = writeln!(content, "{keyword} {{ {names} }} from \"{specifier}\";");
+    }
+    content
+}
+
+/// Returns `true` if `item` re-exports a module of the directory of the barrel file,
+/// such as `export { Button } from "./button"`
+fn is_sibling_re_export(item: &AnyJsModuleItem) -> bool {
+    let AnyJsModuleItem::JsExport(export) = item else {
+        return false;
+    };
+    let source = match export.export_clause() {
+        Ok(AnyJsExportClause::JsExportFromClause(clause)) => clause.source(),
+        Ok(AnyJsExportClause::JsExportNamedFromClause(clause)) => clause.source(),
+        _ => return false,
+    };
+    let Ok(AnyJsModuleSource::JsModuleSource(source)) = source else {
+        return false;
+    };
+    source.inner_string_text().is_ok_and(|specifier| {
+        specifier
+            .text()
+            .strip_prefix("./")
+            .is_some_and(|name| !name.is_empty() && !name.contains('/'))
+    })
+}
@@ -1,7 +1,8 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OpenProjectParams,
-    OrganizeImportsParams, OrganizeImportsResult, ProjectKey, RageParams, RageResult,
-    RegisterProjectFolderParams, ServerInfo, UnregisterProjectFolderParams, UpdateProjectParams,
+    DocumentHighlightsParams, DocumentHighlightsResult, FileFeaturesResult, GetFileContentParams,
+    IsPathIgnoredParams, OpenProjectParams, OrganizeImportsParams, OrganizeImportsResult,
+    ProjectKey, RageParams, RageResult, RegisterProjectFolderParams, ServerInfo,
+    UnregisterProjectFolderParams, UpdateProjectParams,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -13,11 +14,14 @@ use std::{
 };
 
 use super::{
-    ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
+    CallHierarchyCallsParams, ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult,
+    FormatFileParams, FormatOnTypeParams, FormatRangeParams, GenerateBarrelFileParams,
+    GenerateBarrelFileResult, GetControlFlowGraphParams, GetFormatterIRParams, GetSyntaxTreeParams,
+    GetSyntaxTreeResult, IncomingCallsResult, OpenFileParams, OutgoingCallsResult,
+    PrepareCallHierarchyParams, PrepareCallHierarchyResult, PullActionsParams, PullActionsResult,
     PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult, SearchPatternParams,
-    SearchResults, SupportsFeatureParams, UpdateSettingsParams,
+    SearchResults, SelectionRangesParams, SelectionRangesResult, SupportsFeatureParams,
+    UpdateSettingsParams, VerifyFormatFileParams, VerifyFormatFileResult,
 };
 
 pub struct WorkspaceClient<T> {
@@ -170,6 +174,10 @@ where
         self.request("biome/close_file", params)
     }
 
+    fn forget_file(&self, params: super::ForgetFileParams) -> Result<(), WorkspaceError> {
+        self.request("biome/forget_file", params)
+    }
+
     fn pull_diagnostics(
         &self,
         params: PullDiagnosticsParams,
@@ -185,6 +193,13 @@ where
         self.request("biome/format_file", params)
     }
 
+    fn verify_format_file(
+        &self,
+        params: VerifyFormatFileParams,
+    ) -> Result<VerifyFormatFileResult, WorkspaceError> {
+        self.request("biome/verify_format_file", params)
+    }
+
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError> {
         self.request("biome/format_range", params)
     }
@@ -201,6 +216,48 @@ where
         self.request("biome/rename", params)
     }
 
+    fn document_highlights(
+        &self,
+        params: DocumentHighlightsParams,
+    ) -> Result<DocumentHighlightsResult, WorkspaceError> {
+        self.request("biome/document_highlights", params)
+    }
+
+    fn selection_ranges(
+        &self,
+        params: SelectionRangesParams,
+    ) -> Result<SelectionRangesResult, WorkspaceError> {
+        self.request("biome/selection_ranges", params)
+    }
+
+    fn prepare_call_hierarchy(
+        &self,
+        params: PrepareCallHierarchyParams,
+    ) -> Result<PrepareCallHierarchyResult, WorkspaceError> {
+        self.request("biome/prepare_call_hierarchy", params)
+    }
+
+    fn incoming_calls(
+        &self,
+        params: CallHierarchyCallsParams,
+    ) -> Result<IncomingCallsResult, WorkspaceError> {
+        self.request("biome/incoming_calls", params)
+    }
+
+    fn outgoing_calls(
+        &self,
+        params: CallHierarchyCallsParams,
+    ) -> Result<OutgoingCallsResult, WorkspaceError> {
+        self.request("biome/outgoing_calls", params)
+    }
+
+    fn generate_barrel_file(
+        &self,
+        params: GenerateBarrelFileParams,
+    ) -> Result<GenerateBarrelFileResult, WorkspaceError> {
+        self.request("biome/generate_barrel_file", params)
+    }
+
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError> {
         self.request("biome/rage", params)
     }
@@ -220,6 +277,27 @@ where
         self.request("biome/drop_pattern", params)
     }
 
+    fn save_module_graph(
+        &self,
+        params: super::SaveModuleGraphParams,
+    ) -> Result<(), WorkspaceError> {
+        self.request("biome/save_module_graph", params)
+    }
+
+    fn load_module_graph(
+        &self,
+        params: super::LoadModuleGraphParams,
+    ) -> Result<(), WorkspaceError> {
+        self.request("biome/load_module_graph", params)
+    }
+
+    fn project_indexes(
+        &self,
+        params: super::ProjectIndexesParams,
+    ) -> Result<super::ProjectIndexesResult, WorkspaceError> {
+        self.request("biome/project_indexes", params)
+    }
+
     fn server_info(&self) -> Option<&ServerInfo> {
         self.server_info.as_ref()
     }
@@ -0,0 +1,132 @@
+use biome_configuration::VERSION;
+use biome_js_analyze::{ModuleDetails, ModuleExports};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tracing::{info, warn};
+use xxhash_rust::xxh3::xxh3_64;
+
+/// A snapshot of the module graph of a workspace, written to the disk
+/// so that the next workspace doesn't have to index the project again.
+#[derive(Debug, Deserialize, Serialize)]
+struct ModuleGraphSnapshot {
+    /// The version of Biome that wrote the snapshot.
+    /// The snapshots written by other versions are ignored, because the indexed data can differ.
+    version: String,
+    modules: Vec<SnapshotModule>,
+}
+
+/// A module of a [ModuleGraphSnapshot]
+#[derive(Debug, Deserialize, Serialize)]
+pub(super) struct SnapshotModule {
+    pub(super) path: PathBuf,
+    /// The size and the modification time of the file when the snapshot was written
+    pub(super) stamp: FileStamp,
+    /// The hash of the content the exports were collected from
+    pub(super) content_hash: u64,
+    pub(super) exports: ModuleExports,
+    /// The details of the module, if they were indexed
+    #[serde(default)]
+    pub(super) details: Option<ModuleDetails>,
+}
+
+impl SnapshotModule {
+    /// Returns `true` if the content of the file of the module is the content the exports were collected from
+    ///
+    /// The file isn't read when its size and its modification time didn't change.
+    /// Its content is only hashed when it has the same size but was modified,
+    /// for example when it was checked out again.
+    pub(super) fn is_fresh(&self) -> bool {
+        match FileStamp::of(&self.path) {
+            Some(stamp) if stamp == self.stamp => true,
+            Some(stamp) if stamp.len == self.stamp.len => {
+                file_content_hash(&self.path) == Some(self.content_hash)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The size and the modification time of a file
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(super) struct FileStamp {
+    len: u64,
+    modified: SystemTime,
+}
+
+impl FileStamp {
+    /// Returns the stamp of the file at `path`, if its metadata can be read
+    pub(super) fn of(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        Some(Self {
+            len: metadata.len(),
+            modified: metadata.modified().ok()?,
+        })
+    }
+}
+
+/// Returns the hash of `content`.
+///
+/// The hash is written to the snapshots, so it must be the same across the processes and the platforms,
+/// which isn't guaranteed by the hashers of the standard library.
+pub(super) fn content_hash(content: &str) -> u64 {
+    xxh3_64(content.as_bytes())
+}
+
+/// Returns the hash of the content of the file at `path`
+pub(super) fn file_content_hash(path: &Path) -> Option<u64> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|content| content_hash(&content))
+}
+
+/// Writes the snapshot of `modules` at `path`. The failures are logged.
+pub(super) fn write_snapshot(path: &Path, modules: Vec<SnapshotModule>) {
+    let snapshot = ModuleGraphSnapshot {
+        version: VERSION.to_string(),
+        modules,
+    };
+    let content = match serde_json::to_string(&snapshot) {
+        Ok(content) => content,
+        Err(error) => {
+            warn!("The module graph couldn't be serialized: {error}");
+            return;
+        }
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    match fs::write(path, content) {
+        Ok(()) => info!(
+            "Saved the module graph of {} modules to {}",
+            snapshot.modules.len(),
+            path.display()
+        ),
+        Err(error) => warn!(
+            "The module graph couldn't be saved to {}: {error}",
+            path.display()
+        ),
+    }
+}
+
+/// Reads the modules of the snapshot written at `path`.
+///
+/// An empty list is returned if the snapshot doesn't exist, is invalid,
+/// or was written by another version of Biome.
+pub(super) fn read_snapshot(path: &Path) -> Vec<SnapshotModule> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return vec![];
+    };
+    match serde_json::from_str::<ModuleGraphSnapshot>(&content) {
+        Ok(snapshot) if snapshot.version == VERSION => snapshot.modules,
+        Ok(_) => vec![],
+        Err(error) => {
+            warn!(
+                "The module graph saved at {} is invalid: {error}",
+                path.display()
+            );
+            vec![]
+        }
+    }
+}
@@ -1,17 +1,30 @@
+use super::barrel_file::{barrel_file_content, module_specifier, BarrelModule};
+use super::module_graph::{
+    content_hash, file_content_hash, read_snapshot, write_snapshot, FileStamp, SnapshotModule,
+};
 use super::{
-    ChangeFileParams, CloseFileParams, FeatureKind, FeatureName, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, OpenProjectParams,
-    ParsePatternParams, ParsePatternResult, PatternId, ProjectKey, PullActionsParams,
-    PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult, RegisterProjectFolderParams,
-    RenameResult, SearchPatternParams, SearchResults, SupportsFeatureParams,
+    ChangeFileParams, CloseFileParams, FeatureKind, FeatureName, FixFileResult, ForgetFileParams,
+    FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
+    GetFormatterIRParams, GetSyntaxTreeParams, GetSyntaxTreeResult, LoadModuleGraphParams,
+    OpenFileParams, OpenProjectParams, ParsePatternParams, ParsePatternResult, PatternId,
+    ProjectIndexesParams, ProjectIndexesResult, ProjectKey, PullActionsParams, PullActionsResult,
+    PullDiagnosticsParams, PullDiagnosticsResult, RegisterProjectFolderParams, RenameResult,
+    RuleTiming, SaveModuleGraphParams, SearchPatternParams, SearchResults, SupportsFeatureParams,
     UnregisterProjectFolderParams, UpdateProjectParams, UpdateSettingsParams,
 };
 use crate::diagnostics::{InvalidPattern, SearchError};
 use crate::file_handlers::{
-    Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams, ParseResult,
+    CallHierarchyTarget, CallTarget, Capabilities, CodeActionsParams, DocumentFileSource,
+    FixAllParams, ImportedFunction, LintParams, OutgoingCalls, ParseResult,
+};
+use crate::settings::{to_matcher, Settings, WorkspaceSettings, WorkspaceSettingsHandleMut};
+use crate::workspace::{
+    CallHierarchyCallsParams, CallHierarchyItem, CallHierarchyItemKind, DocumentHighlightsParams,
+    DocumentHighlightsResult, FormatStabilityIssue, GenerateBarrelFileParams,
+    GenerateBarrelFileResult, IncomingCallsResult, OutgoingCall, OutgoingCallsResult,
+    PrepareCallHierarchyParams, PrepareCallHierarchyResult, SelectionRangesParams,
+    SelectionRangesResult, VerifyFormatFileParams, VerifyFormatFileResult,
 };
-use crate::settings::{WorkspaceSettings, WorkspaceSettingsHandleMut};
 use crate::workspace::{
     FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
     OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
@@ -19,14 +32,23 @@ use crate::workspace::{
 use crate::{
     file_handlers::Features, settings::WorkspaceSettingsHandle, Workspace, WorkspaceError,
 };
+use biome_analyze::profiling::RuleProfiler;
+use biome_analyze::RuleFilter;
 use biome_configuration::DEFAULT_FILE_SIZE_LIMIT;
+use biome_css_analyze::{declared_custom_properties, ProjectCustomProperties};
 use biome_diagnostics::{
     serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
 };
 use biome_formatter::Printed;
 use biome_fs::{BiomePath, ConfigName};
 use biome_grit_patterns::GritQuery;
-use biome_js_syntax::ModuleKind;
+use biome_js_analyze::globals::declared_ambient_globals;
+use biome_js_analyze::{
+    collect_exported_names, collect_module_details, collect_module_exports, ModuleDetails,
+    ModuleExports, ProjectModuleExports,
+};
+use biome_js_syntax::{AnyJsRoot, JsModule, ModuleKind};
+use biome_json_analyze::{document_keys, DocumentKeys};
 use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
 use biome_json_syntax::JsonFileSource;
 use biome_parser::AnyParse;
@@ -34,10 +56,13 @@ use biome_project::{NodeJsProject, PackageType};
 use biome_rowan::NodeCache;
 use dashmap::{mapref::entry::Entry, DashMap};
 use indexmap::IndexSet;
+use rustc_hash::FxHashSet;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{debug, info, info_span, trace};
 
@@ -60,6 +85,35 @@ pub(super) struct WorkspaceServer {
     file_sources: RwLock<IndexSet<DocumentFileSource>>,
     /// Stores patterns to search for.
     patterns: DashMap<PatternId, GritQuery>,
+    /// Stores the custom properties declared in the CSS documents.
+    ///
+    /// The entries are kept when a document is closed:
+    /// other CSS documents can still reference the custom properties it declares.
+    css_custom_properties: DashMap<BiomePath, FxHashSet<String>>,
+    /// The custom properties of [Self::css_custom_properties], merged once and shared by the analyzers
+    /// until a CSS document changes
+    merged_css_custom_properties: RwLock<Option<ProjectCustomProperties>>,
+    /// Stores the names exported by the JavaScript documents.
+    ///
+    /// The entries are kept when a document is closed, for the same reason as [Self::css_custom_properties].
+    js_module_exports: DashMap<BiomePath, IndexedModule>,
+    /// Stores the members of the types exported by the JavaScript documents, and the strings they pass to functions.
+    ///
+    /// The entries are kept when a document is closed, for the same reason as [Self::css_custom_properties].
+    js_module_details: DashMap<BiomePath, Arc<ModuleDetails>>,
+    /// Stores the globals declared by the TypeScript declaration files.
+    ///
+    /// The entries are kept when a document is closed, for the same reason as [Self::css_custom_properties].
+    js_ambient_globals: DashMap<BiomePath, FxHashSet<String>>,
+    /// Stores the keys of the JSON documents, such as `home.title` in `{ "home": { "title": "Home" } }`.
+    ///
+    /// The keys come with the text of the document, so that the fixes can add the missing keys to it.
+    ///
+    /// The entries are kept when a document is closed, for the same reason as [Self::css_custom_properties].
+    json_document_keys: DashMap<BiomePath, Arc<DocumentKeys>>,
+    /// The exports of [Self::js_module_exports], the details of [Self::js_module_details]
+    /// and the keys of [Self::json_document_keys], merged once and shared by the analyzers until one of them changes
+    merged_module_exports: RwLock<Option<ProjectModuleExports>>,
 }
 
 /// The `Workspace` object is long-lived, so we want it to be able to cross
@@ -70,6 +124,15 @@ pub(super) struct WorkspaceServer {
 /// could lead too hard to debug issues)
 impl RefUnwindSafe for WorkspaceServer {}
 
+/// The names exported by a JavaScript document
+#[derive(Debug)]
+struct IndexedModule {
+    /// The hash of the content the exports were collected from,
+    /// so that only the exports of the content saved on the disk are written to the snapshots
+    content_hash: u64,
+    exports: Arc<ModuleExports>,
+}
+
 #[derive(Debug)]
 pub(crate) struct Document {
     pub(crate) content: String,
@@ -97,6 +160,13 @@ impl WorkspaceServer {
             current_manifest_path: RwLock::default(),
             file_sources: RwLock::default(),
             patterns: Default::default(),
+            css_custom_properties: DashMap::default(),
+            merged_css_custom_properties: RwLock::default(),
+            js_module_exports: DashMap::default(),
+            js_module_details: DashMap::default(),
+            js_ambient_globals: DashMap::default(),
+            json_document_keys: DashMap::default(),
+            merged_module_exports: RwLock::default(),
         }
     }
 
@@ -126,6 +196,278 @@ impl WorkspaceServer {
             .unwrap_or(DocumentFileSource::from_path(path))
     }
 
+    /// Stores the custom properties declared in the document at `path`, if it is a CSS document
+    ///
+    /// The documents are only indexed when a rule that needs the custom properties of the project is enabled,
+    /// so that the other documents aren't parsed ahead of time.
+    fn index_css_custom_properties(&self, path: &BiomePath) {
+        if !matches!(self.get_file_source(path), DocumentFileSource::Css(_))
+            || !self.is_custom_properties_rule_enabled()
+        {
+            return;
+        }
+        // A document that can't be parsed doesn't declare any custom property
+        match self.get_parse(path.clone()) {
+            Ok(parse) => {
+                let names = declared_custom_properties(&parse.tree());
+                self.css_custom_properties.insert(path.clone(), names);
+            }
+            Err(_) => {
+                self.css_custom_properties.remove(path);
+            }
+        }
+        *self.merged_css_custom_properties.write().unwrap() = None;
+    }
+
+    /// Returns `true` if a rule that needs the custom properties of the CSS documents is enabled
+    fn is_custom_properties_rule_enabled(&self) -> bool {
+        self.is_rule_enabled(RuleFilter::Rule("nursery", "noUnknownCustomProperty"))
+    }
+
+    /// Returns the custom properties declared across the CSS documents of the workspace
+    fn get_css_custom_properties(&self) -> ProjectCustomProperties {
+        if let Some(properties) = self.merged_css_custom_properties.read().unwrap().as_ref() {
+            return properties.clone();
+        }
+        let mut merged = self.merged_css_custom_properties.write().unwrap();
+        merged
+            .get_or_insert_with(|| {
+                ProjectCustomProperties::new(
+                    self.css_custom_properties
+                        .iter()
+                        .flat_map(|entry| entry.value().clone()),
+                )
+            })
+            .clone()
+    }
+
+    /// Stores the names exported by the document at `path`, if it is a JavaScript document
+    ///
+    /// The documents are only indexed when a rule that needs the exports of the project is enabled,
+    /// so that the other documents aren't parsed ahead of time.
+    fn index_js_module_exports(&self, path: &BiomePath) {
+        if !matches!(self.get_file_source(path), DocumentFileSource::Js(_))
+            || !self.is_module_graph_rule_enabled()
+        {
+            return;
+        }
+        let Ok(parse) = self.get_parse(path.clone()) else {
+            if self.js_module_exports.remove(path).is_some() {
+                self.invalidate_module_exports();
+            }
+            return;
+        };
+        let content_hash = match self.documents.get(path) {
+            Some(document) => content_hash(&document.content),
+            None => return,
+        };
+        let exports = collect_module_exports(&parse.tree());
+        // A document is opened again when it's processed, usually without changes
+        if let Some(mut indexed) = self.js_module_exports.get_mut(path) {
+            if *indexed.exports == exports {
+                indexed.content_hash = content_hash;
+                return;
+            }
+        }
+        self.js_module_exports.insert(
+            path.clone(),
+            IndexedModule {
+                content_hash,
+                exports: Arc::new(exports),
+            },
+        );
+        self.invalidate_module_exports();
+    }
+
+    /// Stores the members of the types exported by the document at `path`, and the strings it passes to functions,
+    /// if it is a JavaScript document
+    ///
+    /// The details are indexed apart from the exports, only when a rule that needs them is enabled.
+    fn index_js_module_details(&self, path: &BiomePath) {
+        if !matches!(self.get_file_source(path), DocumentFileSource::Js(_))
+            || !self.is_module_details_rule_enabled()
+        {
+            return;
+        }
+        let Ok(parse) = self.get_parse(path.clone()) else {
+            if self.js_module_details.remove(path).is_some() {
+                self.invalidate_module_exports();
+            }
+            return;
+        };
+        let details = collect_module_details(&parse.tree());
+        if self
+            .js_module_details
+            .get(path)
+            .is_some_and(|indexed| **indexed == details)
+        {
+            return;
+        }
+        self.js_module_details
+            .insert(path.clone(), Arc::new(details));
+        self.invalidate_module_exports();
+    }
+
+    /// Drops the merged exports, so that they are merged again with the changes of the indexes
+    fn invalidate_module_exports(&self) {
+        *self.merged_module_exports.write().unwrap() = None;
+    }
+
+    /// Returns the tree of the document at `path`, if it is an open ES module
+    fn get_js_module(&self, path: &BiomePath) -> Option<JsModule> {
+        let parse = self.get_parse(path.clone()).ok()?;
+        match parse.tree::<AnyJsRoot>() {
+            AnyJsRoot::JsModule(module) => Some(module),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if a rule that needs the exports and the imports of the JavaScript documents is enabled
+    fn is_module_graph_rule_enabled(&self) -> bool {
+        const RULES: [RuleFilter; 5] = [
+            RuleFilter::Rule("nursery", "noCircularDomainDependencies"),
+            RuleFilter::Rule("nursery", "noDuplicateImportsAcrossFiles"),
+            RuleFilter::Rule("nursery", "useExhaustiveSwitchCases"),
+            RuleFilter::Rule("nursery", "useValidI18nKeys"),
+            RuleFilter::Rule("performance", "noReExportAll"),
+        ];
+        RULES.into_iter().any(|rule| self.is_rule_enabled(rule))
+    }
+
+    /// Returns `true` if a rule that needs the details of the JavaScript documents,
+    /// the members of their exported types and the strings they pass to functions, is enabled
+    fn is_module_details_rule_enabled(&self) -> bool {
+        self.is_rule_enabled(RuleFilter::Rule("nursery", "useExhaustiveSwitchCases"))
+            || self.is_rule_enabled(RuleFilter::Rule("nursery", "useValidI18nKeys"))
+    }
+
+    /// Returns `true` if `rule` is enabled, in the configuration or in one of its overrides
+    fn is_rule_enabled(&self, rule: RuleFilter<'static>) -> bool {
+        let workspace = self.workspace();
+        let Some(settings) = workspace.settings() else {
+            return false;
+        };
+        settings.linter.enabled
+            && settings
+                .linter
+                .rules
+                .iter()
+                .chain(
+                    settings
+                        .override_settings
+                        .patterns
+                        .iter()
+                        .filter_map(|pattern| pattern.linter.rules.as_ref()),
+                )
+                .any(|rules| rules.as_enabled_rules().contains(&rule))
+    }
+
+    /// Returns the names exported by the JavaScript documents of the workspace, their details,
+    /// and the keys of its JSON documents
+    fn get_js_module_exports(&self) -> ProjectModuleExports {
+        if let Some(exports) = self.merged_module_exports.read().unwrap().as_ref() {
+            return exports.clone();
+        }
+        let mut merged = self.merged_module_exports.write().unwrap();
+        merged
+            .get_or_insert_with(|| {
+                ProjectModuleExports::new(
+                    self.js_module_exports
+                        .iter()
+                        .map(|entry| (entry.key().to_path_buf(), entry.exports.clone())),
+                )
+                .with_module_details(
+                    self.js_module_details
+                        .iter()
+                        .map(|entry| (entry.key().to_path_buf(), entry.value().clone())),
+                )
+                .with_json_documents(
+                    self.json_document_keys
+                        .iter()
+                        .map(|entry| (entry.key().to_path_buf(), entry.value().clone())),
+                )
+            })
+            .clone()
+    }
+
+    /// Returns the function that the module at `importer` imports,
+    /// if the imported module is indexed and opened in the workspace
+    fn resolve_imported_function(
+        &self,
+        project: &ProjectModuleExports,
+        importer: &Path,
+        function: &ImportedFunction,
+    ) -> Option<CallHierarchyItem> {
+        let path = BiomePath::new(project.resolve(importer, &function.specifier)?);
+        let call_hierarchy = self.get_file_capabilities(&path).analyzer.call_hierarchy?;
+        let parse = self.get_parse(path.clone()).ok()?;
+        (call_hierarchy.exported_function)(&path, parse, &function.name)
+    }
+
+    /// Stores the keys of the document at `path`, if it is a JSON document
+    ///
+    /// Like the exports, the keys are only indexed when `useValidI18nKeys` is enabled,
+    /// because they are the message keys of the catalogs of this rule.
+    fn index_json_document_keys(&self, path: &BiomePath) {
+        if !matches!(self.get_file_source(path), DocumentFileSource::Json(_))
+            || !self.is_json_document_keys_rule_enabled()
+        {
+            return;
+        }
+        let Ok(parse) = self.get_parse(path.clone()) else {
+            if self.json_document_keys.remove(path).is_some() {
+                self.invalidate_module_exports();
+            }
+            return;
+        };
+        let keys = document_keys(&parse.tree());
+        if self
+            .json_document_keys
+            .get(path)
+            .is_some_and(|indexed| **indexed == keys)
+        {
+            return;
+        }
+        self.json_document_keys.insert(path.clone(), Arc::new(keys));
+        self.invalidate_module_exports();
+    }
+
+    /// Returns `true` if the rule that checks the keys of the JSON documents is enabled
+    fn is_json_document_keys_rule_enabled(&self) -> bool {
+        self.is_rule_enabled(RuleFilter::Rule("nursery", "useValidI18nKeys"))
+    }
+
+    /// Stores the globals declared by the document at `path`, if it is a TypeScript declaration file
+    ///
+    /// Like the exports, the globals are only indexed when `noUndeclaredVariables` is enabled.
+    fn index_js_ambient_globals(&self, path: &BiomePath) {
+        let DocumentFileSource::Js(file_source) = self.get_file_source(path) else {
+            return;
+        };
+        if !file_source.language().is_definition_file() || !self.is_ambient_globals_rule_enabled() {
+            return;
+        }
+        let Ok(parse) = self.get_parse(path.clone()) else {
+            self.js_ambient_globals.remove(path);
+            return;
+        };
+        let globals = declared_ambient_globals(&parse.tree());
+        self.js_ambient_globals.insert(path.clone(), globals);
+    }
+
+    /// Returns `true` if the rule that needs the globals declared by the TypeScript declaration files is enabled
+    fn is_ambient_globals_rule_enabled(&self) -> bool {
+        self.is_rule_enabled(RuleFilter::Rule("correctness", "noUndeclaredVariables"))
+    }
+
+    /// Returns the globals declared by the TypeScript declaration files of the workspace
+    fn get_js_ambient_globals(&self) -> Vec<String> {
+        self.js_ambient_globals
+            .iter()
+            .flat_map(|entry| entry.value().iter().cloned().collect::<Vec<_>>())
+            .collect()
+    }
+
     /// Return an error factory function for unsupported features at a given path
     fn build_capability_error<'a>(
         &'a self,
@@ -489,6 +831,11 @@ impl Workspace for WorkspaceServer {
         if let Some(project_key) = self.path_belongs_to_current_workspace(&params.path) {
             self.set_current_project(project_key);
         }
+        self.index_css_custom_properties(&params.path);
+        self.index_js_module_exports(&params.path);
+        self.index_js_module_details(&params.path);
+        self.index_js_ambient_globals(&params.path);
+        self.index_json_document_keys(&params.path);
 
         Ok(())
     }
@@ -626,6 +973,11 @@ impl Workspace for WorkspaceServer {
         }
 
         self.syntax.remove(&params.path);
+        self.index_css_custom_properties(&params.path);
+        self.index_js_module_exports(&params.path);
+        self.index_js_module_details(&params.path);
+        self.index_js_ambient_globals(&params.path);
+        self.index_json_document_keys(&params.path);
         Ok(())
     }
 
@@ -639,12 +991,29 @@ impl Workspace for WorkspaceServer {
         Ok(())
     }
 
+    fn forget_file(&self, params: ForgetFileParams) -> Result<(), WorkspaceError> {
+        let path = &params.path;
+        if self.css_custom_properties.remove(path).is_some() {
+            *self.merged_css_custom_properties.write().unwrap() = None;
+        }
+        let removed_module = self.js_module_exports.remove(path).is_some();
+        let removed_details = self.js_module_details.remove(path).is_some();
+        let removed_document = self.json_document_keys.remove(path).is_some();
+        if removed_module || removed_details || removed_document {
+            self.invalidate_module_exports();
+        }
+        self.js_ambient_globals.remove(path);
+        Ok(())
+    }
+
     /// Retrieves the list of diagnostics associated with a file
     #[tracing::instrument(level = "trace", skip(self))]
     fn pull_diagnostics(
         &self,
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
+        let deadline = params.timeout.map(|timeout| Instant::now() + timeout);
+        let profiler = params.profile.then(RuleProfiler::default);
         let parse = self.get_parse(params.path.clone())?;
         let manifest = self.get_current_manifest()?.map(|pr| pr.manifest);
         let (diagnostics, errors, skipped_diagnostics) =
@@ -660,6 +1029,11 @@ impl Workspace for WorkspaceServer {
                         language: self.get_file_source(&params.path),
                         categories: params.categories,
                         manifest,
+                        css_custom_properties: self.get_css_custom_properties(),
+                        module_exports: self.get_js_module_exports(),
+                        ambient_globals: self.get_js_ambient_globals(),
+                        deadline,
+                        profiler: profiler.clone(),
                     });
 
                     (
@@ -689,6 +1063,15 @@ impl Workspace for WorkspaceServer {
                 .collect(),
             errors,
             skipped_diagnostics: skipped_diagnostics.into(),
+            rule_timings: profiler
+                .map(|profiler| {
+                    profiler
+                        .take_measurements()
+                        .into_iter()
+                        .map(RuleTiming::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
         })
     }
 
@@ -712,6 +1095,9 @@ impl Workspace for WorkspaceServer {
             workspace: &workspace,
             path: &params.path,
             manifest,
+            css_custom_properties: self.get_css_custom_properties(),
+            module_exports: self.get_js_module_exports(),
+            ambient_globals: self.get_js_ambient_globals(),
             language,
             only: params.only,
             skip: params.skip,
@@ -734,9 +1120,104 @@ impl Workspace for WorkspaceServer {
             if !settings.formatter().format_with_errors && parse.has_errors() {
                 return Err(WorkspaceError::format_with_errors_disabled());
             }
+            check_format_complexity(&params.path, &parse, settings)?;
         }
         let document_file_source = self.get_file_source(&params.path);
-        format(&params.path, &document_file_source, parse, workspace)
+        let printed = format(&params.path, &document_file_source, parse, workspace)?;
+        match capabilities.formatter.format_embedding {
+            Some(format_embedding) => {
+                let content = self
+                    .documents
+                    .get(&params.path)
+                    .map(|document| document.content.clone())
+                    .ok_or_else(WorkspaceError::not_found)?;
+                format_embedding(
+                    &params.path,
+                    &document_file_source,
+                    &content,
+                    printed,
+                    self.workspace(),
+                )
+            }
+            None => Ok(printed),
+        }
+    }
+
+    fn verify_format_file(
+        &self,
+        params: VerifyFormatFileParams,
+    ) -> Result<VerifyFormatFileResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let (Some(parse), Some(format), Some(compare_syntax)) = (
+            capabilities.parser.parse,
+            capabilities.formatter.format,
+            capabilities.formatter.compare_syntax,
+        ) else {
+            return Err(self.build_capability_error(&params.path)());
+        };
+        let workspace = self.workspace();
+        let settings = workspace.settings();
+        let original = self.get_parse(params.path.clone())?;
+
+        if let Some(settings) = settings {
+            if !settings.formatter().format_with_errors && original.has_errors() {
+                return Err(WorkspaceError::format_with_errors_disabled());
+            }
+            check_format_complexity(&params.path, &original, settings)?;
+        }
+        let document_file_source = self.get_file_source(&params.path);
+        let formatted = format(
+            &params.path,
+            &document_file_source,
+            original.clone(),
+            self.workspace(),
+        )?
+        .into_code();
+        // The content of a file with syntax errors isn't expected to be stable
+        if original.has_errors() {
+            return Ok(VerifyFormatFileResult {
+                formatted,
+                issue: None,
+            });
+        }
+
+        let reparsed = parse(
+            &params.path,
+            document_file_source,
+            &formatted,
+            settings,
+            &mut NodeCache::default(),
+        )
+        .any_parse;
+        if reparsed.has_errors() {
+            let diagnostics = reparsed
+                .into_diagnostics()
+                .into_iter()
+                .map(|diag| {
+                    let diag = diag.with_file_path(params.path.as_path().display().to_string());
+                    SerdeDiagnostic::new(diag)
+                })
+                .collect();
+            return Ok(VerifyFormatFileResult {
+                formatted,
+                issue: Some(FormatStabilityIssue::SyntaxErrors { diagnostics }),
+            });
+        }
+
+        let reformatted = format(
+            &params.path,
+            &document_file_source,
+            reparsed.clone(),
+            self.workspace(),
+        )?
+        .into_code();
+        let issue = if reformatted != formatted {
+            Some(FormatStabilityIssue::NotIdempotent { reformatted })
+        } else {
+            compare_syntax(&original, &reparsed).map(FormatStabilityIssue::SyntaxTreeChanged)
+        };
+
+        Ok(VerifyFormatFileResult { formatted, issue })
     }
 
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError> {
@@ -753,6 +1234,7 @@ impl Workspace for WorkspaceServer {
             if !settings.formatter().format_with_errors && parse.has_errors() {
                 return Err(WorkspaceError::format_with_errors_disabled());
             }
+            check_format_complexity(&params.path, &parse, settings)?;
         }
         let document_file_source = self.get_file_source(&params.path);
         format_range(
@@ -778,6 +1260,7 @@ impl Workspace for WorkspaceServer {
             if !settings.formatter().format_with_errors && parse.has_errors() {
                 return Err(WorkspaceError::format_with_errors_disabled());
             }
+            check_format_complexity(&params.path, &parse, settings)?;
         }
         let document_file_source = self.get_file_source(&params.path);
 
@@ -791,6 +1274,7 @@ impl Workspace for WorkspaceServer {
     }
 
     fn fix_file(&self, params: super::FixFileParams) -> Result<FixFileResult, WorkspaceError> {
+        let deadline = params.timeout.map(|timeout| Instant::now() + timeout);
         let capabilities = self.get_file_capabilities(&params.path);
 
         let fix_all = capabilities
@@ -810,10 +1294,14 @@ impl Workspace for WorkspaceServer {
             should_format: params.should_format,
             biome_path: &params.path,
             manifest,
+            css_custom_properties: self.get_css_custom_properties(),
+            module_exports: self.get_js_module_exports(),
+            ambient_globals: self.get_js_ambient_globals(),
             document_file_source: language,
             only: params.only,
             skip: params.skip,
             rule_categories: params.rule_categories,
+            deadline,
         })
     }
 
@@ -830,6 +1318,177 @@ impl Workspace for WorkspaceServer {
         Ok(result)
     }
 
+    fn document_highlights(
+        &self,
+        params: DocumentHighlightsParams,
+    ) -> Result<DocumentHighlightsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let document_highlights = capabilities
+            .analyzer
+            .document_highlights
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(document_highlights(parse, params.symbol_at))
+    }
+
+    fn selection_ranges(
+        &self,
+        params: SelectionRangesParams,
+    ) -> Result<SelectionRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let selection_ranges = capabilities
+            .parser
+            .selection_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(selection_ranges(parse, &params.positions))
+    }
+
+    fn prepare_call_hierarchy(
+        &self,
+        params: PrepareCallHierarchyParams,
+    ) -> Result<PrepareCallHierarchyResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let call_hierarchy = capabilities
+            .analyzer
+            .call_hierarchy
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let item = match (call_hierarchy.prepare)(&params.path, parse, params.symbol_at) {
+            Some(CallHierarchyTarget::Item(item)) => Some(item),
+            Some(CallHierarchyTarget::Imported(function)) => self.resolve_imported_function(
+                &self.get_js_module_exports(),
+                &params.path,
+                &function,
+            ),
+            None => None,
+        };
+        Ok(PrepareCallHierarchyResult {
+            items: item.into_iter().collect(),
+        })
+    }
+
+    fn incoming_calls(
+        &self,
+        params: CallHierarchyCallsParams,
+    ) -> Result<IncomingCallsResult, WorkspaceError> {
+        let item = params.item;
+        let capabilities = self.get_file_capabilities(&item.path);
+        let call_hierarchy = capabilities
+            .analyzer
+            .call_hierarchy
+            .ok_or_else(self.build_capability_error(&item.path))?;
+
+        let parse = self.get_parse(item.path.clone())?;
+        let mut calls = (call_hierarchy.incoming_calls)(&item.path, parse, CallTarget::Item(&item));
+        if item.kind == CallHierarchyItemKind::Module {
+            return Ok(IncomingCallsResult { calls });
+        }
+
+        // The other modules are only known when the exports of the project are indexed
+        let project = self.get_js_module_exports();
+        for (path, exports) in project.modules() {
+            let is_target_module =
+                |specifier: &str| project.resolve(path, specifier) == Some(item.path.as_path());
+            if !exports
+                .imports()
+                .iter()
+                .any(|specifier| is_target_module(specifier))
+            {
+                continue;
+            }
+            let path = BiomePath::new(path);
+            let Some(call_hierarchy) = self.get_file_capabilities(&path).analyzer.call_hierarchy
+            else {
+                continue;
+            };
+            let Ok(parse) = self.get_parse(path.clone()) else {
+                continue;
+            };
+            calls.extend((call_hierarchy.incoming_calls)(
+                &path,
+                parse,
+                CallTarget::Imported {
+                    name: &item.name,
+                    is_target_module: &is_target_module,
+                },
+            ));
+        }
+        Ok(IncomingCallsResult { calls })
+    }
+
+    fn outgoing_calls(
+        &self,
+        params: CallHierarchyCallsParams,
+    ) -> Result<OutgoingCallsResult, WorkspaceError> {
+        let item = params.item;
+        let capabilities = self.get_file_capabilities(&item.path);
+        let call_hierarchy = capabilities
+            .analyzer
+            .call_hierarchy
+            .ok_or_else(self.build_capability_error(&item.path))?;
+
+        let parse = self.get_parse(item.path.clone())?;
+        let OutgoingCalls {
+            mut calls,
+            imported,
+        } = (call_hierarchy.outgoing_calls)(&item.path, parse, &item);
+        if !imported.is_empty() {
+            let project = self.get_js_module_exports();
+            calls.extend(imported.into_iter().filter_map(|(function, from_ranges)| {
+                let to = self.resolve_imported_function(&project, &item.path, &function)?;
+                Some(OutgoingCall { to, from_ranges })
+            }));
+        }
+        Ok(OutgoingCallsResult { calls })
+    }
+
+    fn generate_barrel_file(
+        &self,
+        params: GenerateBarrelFileParams,
+    ) -> Result<GenerateBarrelFileResult, WorkspaceError> {
+        let exclude = to_matcher(None, Some(&params.exclude.into_iter().collect()))?;
+        let barrel_stem = params.path.file_stem();
+        let mut modules: Vec<_> = params
+            .modules
+            .into_iter()
+            .filter(|path| {
+                // `index.ts` doesn't re-export `index.js`, nor itself
+                path.file_stem() != barrel_stem
+                    && path
+                        .file_name()
+                        .is_some_and(|name| !exclude.matches_path(Path::new(name)))
+            })
+            .filter_map(|path| {
+                let DocumentFileSource::Js(file_source) = self.get_file_source(&path) else {
+                    return None;
+                };
+                if file_source.language().is_definition_file() {
+                    return None;
+                }
+                let module = self.get_js_module(&path)?;
+                let names = collect_exported_names(&module);
+                if names.is_empty() {
+                    return None;
+                }
+                Some(BarrelModule {
+                    specifier: module_specifier(&path)?,
+                    names,
+                })
+            })
+            .collect();
+        modules.sort_by(|a, b| a.specifier.cmp(&b.specifier));
+        modules.dedup_by(|a, b| a.specifier == b.specifier);
+
+        let barrel = self.get_js_module(&params.path);
+        Ok(GenerateBarrelFileResult {
+            content: barrel_file_content(barrel.as_ref(), &modules),
+        })
+    }
+
     fn rage(&self, _: RageParams) -> Result<RageResult, WorkspaceError> {
         let entries = vec![
             RageEntry::section("Workspace"),
@@ -889,6 +1548,76 @@ impl Workspace for WorkspaceServer {
         Ok(())
     }
 
+    fn save_module_graph(&self, params: SaveModuleGraphParams) -> Result<(), WorkspaceError> {
+        // Only the exports of the content saved on the disk can be validated by the next workspace,
+        // not the exports of the unsaved documents of an editor
+        let modules = self
+            .js_module_exports
+            .iter()
+            .filter_map(|entry| {
+                // The stamp is read first: if the file changes in between,
+                // the next workspace compares the hash of its content
+                let stamp = FileStamp::of(entry.key())?;
+                (file_content_hash(entry.key()) == Some(entry.content_hash))
+                    .then_some((entry, stamp))
+            })
+            .map(|(entry, stamp)| SnapshotModule {
+                path: entry.key().to_path_buf(),
+                stamp,
+                content_hash: entry.content_hash,
+                exports: ModuleExports::clone(&entry.exports),
+                details: self
+                    .js_module_details
+                    .get(entry.key())
+                    .map(|details| ModuleDetails::clone(&details)),
+            })
+            .collect::<Vec<_>>();
+        if !modules.is_empty() {
+            write_snapshot(&params.path, modules);
+        }
+        Ok(())
+    }
+
+    fn load_module_graph(&self, params: LoadModuleGraphParams) -> Result<(), WorkspaceError> {
+        if !self.is_module_graph_rule_enabled() {
+            return Ok(());
+        }
+        for module in read_snapshot(&params.path) {
+            let path = BiomePath::new(&module.path);
+            // The modules indexed by this workspace are more recent than the snapshot
+            if self.js_module_exports.contains_key(&path) || !module.is_fresh() {
+                continue;
+            }
+            if let Some(details) = module.details {
+                if self.is_module_details_rule_enabled() {
+                    self.js_module_details
+                        .insert(path.clone(), Arc::new(details));
+                }
+            }
+            self.js_module_exports.insert(
+                path,
+                IndexedModule {
+                    content_hash: module.content_hash,
+                    exports: Arc::new(module.exports),
+                },
+            );
+        }
+        self.invalidate_module_exports();
+        Ok(())
+    }
+
+    fn project_indexes(
+        &self,
+        _params: ProjectIndexesParams,
+    ) -> Result<ProjectIndexesResult, WorkspaceError> {
+        Ok(ProjectIndexesResult {
+            css_custom_properties: self.is_custom_properties_rule_enabled(),
+            module_exports: self.is_module_graph_rule_enabled(),
+            json_document_keys: self.is_json_document_keys_rule_enabled(),
+            ambient_globals: self.is_ambient_globals_rule_enabled(),
+        })
+    }
+
     fn server_info(&self) -> Option<&ServerInfo> {
         None
     }
@@ -903,8 +1632,10 @@ impl Workspace for WorkspaceServer {
             .organize_imports
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let parse = self.get_parse(params.path)?;
-        let result = organize_imports(parse)?;
+        let parse = self.get_parse(params.path.clone())?;
+        let document_file_source = self.get_file_source(&params.path);
+        let result =
+            organize_imports(&params.path, &document_file_source, parse, self.workspace())?;
 
         Ok(result)
     }
@@ -916,6 +1647,29 @@ fn is_dir(path: &Path) -> bool {
     path.is_dir() || (path.is_symlink() && fs::read_link(path).is_ok_and(|path| path.is_dir()))
 }
 
+/// Returns an error if the file has more syntax nodes than the `formatter.maxComplexity` limit
+/// that applies to `path`, so that the formatter doesn't spend most of its time on a few
+/// generated or minified files.
+fn check_format_complexity(
+    path: &BiomePath,
+    parse: &AnyParse,
+    settings: &Settings,
+) -> Result<(), WorkspaceError> {
+    let Some(limit) = settings.max_complexity(path) else {
+        return Ok(());
+    };
+    let limit = limit.get() as usize;
+    let complexity = parse.node_count();
+    if complexity > limit {
+        return Err(WorkspaceError::file_too_complex(
+            path.display().to_string(),
+            complexity,
+            limit,
+        ));
+    }
+    Ok(())
+}
+
 /// Generates a pattern ID that we can use as "handle" for referencing
 /// previously parsed search queries.
 fn make_search_pattern_id() -> PatternId {
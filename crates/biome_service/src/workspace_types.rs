@@ -56,6 +56,32 @@ fn instance_type<'a>(
         // If the instance type is an object, generate a TS object type with the corresponding properties
         InstanceType::Object => {
             let object = schema.object.as_deref().unwrap();
+            // If the object is a map, generate a `Record<string, T>` type with the type of its values
+            if let (true, Some(values)) = (
+                object.properties.is_empty(),
+                object.additional_properties.as_deref(),
+            ) {
+                let (ts_type, optional, _) = schema_type(queue, root_schema, values);
+                assert!(!optional, "optional nested types are not supported");
+
+                return AnyTsType::from(
+                    make::ts_reference_type(AnyTsName::from(make::js_reference_identifier(
+                        make::ident("Record"),
+                    )))
+                    .with_type_arguments(make::ts_type_arguments(
+                        make::token(T![<]),
+                        make::ts_type_argument_list(
+                            [
+                                AnyTsType::from(make::ts_string_type(make::token(T![string]))),
+                                ts_type,
+                            ],
+                            [make::token(T![,])],
+                        ),
+                        make::token(T![>]),
+                    ))
+                    .build(),
+                );
+            }
             AnyTsType::from(make::ts_object_type(
                 make::token(T!['{']),
                 make::ts_type_member_list(object.properties.iter().map(|(property, schema)| {
@@ -451,7 +477,7 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 20] {
+pub fn methods() -> [WorkspaceMethod; 27] {
     [
         WorkspaceMethod::of::<SupportsFeatureParams, SupportsFeatureResult>("file_features"),
         workspace_method!(update_settings),
@@ -469,9 +495,16 @@ pub fn methods() -> [WorkspaceMethod; 20] {
         workspace_method!(pull_diagnostics),
         workspace_method!(pull_actions),
         workspace_method!(format_file),
+        workspace_method!(verify_format_file),
         workspace_method!(format_range),
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
         workspace_method!(rename),
+        workspace_method!(document_highlights),
+        workspace_method!(selection_ranges),
+        workspace_method!(prepare_call_hierarchy),
+        workspace_method!(incoming_calls),
+        workspace_method!(outgoing_calls),
+        workspace_method!(generate_barrel_file),
     ]
 }
@@ -6,9 +6,13 @@ mod test {
     use biome_js_syntax::{JsFileSource, TextSize};
     use biome_service::file_handlers::DocumentFileSource;
     use biome_service::workspace::{
-        server, FileGuard, OpenFileParams, RegisterProjectFolderParams,
+        server, FileGuard, FormatStabilityIssue, LoadModuleGraphParams, OpenFileParams,
+        RegisterProjectFolderParams, SaveModuleGraphParams, UpdateSettingsParams,
     };
     use biome_service::Workspace;
+    use std::fs;
+    use std::path::Path;
+    use std::time::{Duration, SystemTime};
     fn create_server() -> Box<dyn Workspace> {
         let workspace = server();
         workspace
@@ -230,9 +234,153 @@ type User {
                 "useDeprecatedReason",
             )],
             vec![],
+            None,
+            false,
         );
         assert!(result.is_ok());
         let diagnostics = result.unwrap().diagnostics;
         assert_eq!(diagnostics.len(), 1)
     }
+
+    #[test]
+    fn verify_format_file_accepts_the_changes_of_the_formatter() {
+        let workspace = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.tsx"),
+                content: r#"const a = { 'b': .50, "c": [1,2,], };
+type C = | A | B;
+const d = <div>some <b>long</b> text that breaks {' '} the line of the element</div>;
+// 1
+// 2
+"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let result = file.verify_format_file().unwrap();
+        assert!(result.issue.is_none(), "{:?}", result.issue);
+    }
+
+    #[test]
+    fn verify_format_file_reports_unstable_formatting() {
+        let workspace = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: "Math // 0\n    .pow( // 1\n    a)\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let result = file.verify_format_file().unwrap();
+        assert!(matches!(
+            result.issue,
+            Some(FormatStabilityIssue::NotIdempotent { .. })
+        ));
+    }
+
+    fn create_server_with_module_graph() -> Box<dyn Workspace> {
+        let workspace = create_server();
+        let configuration = biome_deserialize::json::deserialize_from_json_str(
+            r#"{ "linter": { "rules": { "nursery": { "noDuplicateImportsAcrossFiles": "error" } } } }"#,
+            biome_json_parser::JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration,
+                vcs_base_path: None,
+                gitignore_matches: vec![],
+                workspace_directory: None,
+            })
+            .unwrap();
+        workspace
+    }
+
+    fn open_module(workspace: &dyn Workspace, path: &Path) {
+        workspace
+            .open_file(OpenFileParams {
+                path: BiomePath::new(path),
+                content: fs::read_to_string(path).unwrap(),
+                version: 0,
+                document_file_source: None,
+            })
+            .unwrap();
+    }
+
+    /// Returns the number of ambiguous re-exports of `index`
+    fn count_ambiguous_re_exports(workspace: &dyn Workspace, index: &Path) -> usize {
+        let file = FileGuard::open(
+            workspace,
+            OpenFileParams {
+                path: BiomePath::new(index),
+                content: "export * from \"./button\";\nexport * from \"./iconButton\";\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        file.pull_diagnostics(RuleCategories::all(), 10, vec![], vec![], None, false)
+            .unwrap()
+            .diagnostics
+            .len()
+    }
+
+    #[test]
+    fn load_module_graph_ignores_the_modified_modules() {
+        let directory =
+            std::env::temp_dir().join(format!("biome_service_module_graph_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let button = directory.join("button.ts");
+        let icon_button = directory.join("iconButton.ts");
+        let index = directory.join("index.ts");
+        let snapshot = directory.join("snapshot.json");
+        for path in [&button, &icon_button] {
+            fs::write(path, "export const Button = () => {};\n").unwrap();
+        }
+
+        let workspace = create_server_with_module_graph();
+        open_module(workspace.as_ref(), &button);
+        open_module(workspace.as_ref(), &icon_button);
+        workspace
+            .save_module_graph(SaveModuleGraphParams {
+                path: snapshot.clone(),
+            })
+            .unwrap();
+
+        let workspace = create_server_with_module_graph();
+        assert_eq!(count_ambiguous_re_exports(workspace.as_ref(), &index), 0);
+        workspace
+            .load_module_graph(LoadModuleGraphParams {
+                path: snapshot.clone(),
+            })
+            .unwrap();
+        assert_eq!(count_ambiguous_re_exports(workspace.as_ref(), &index), 1);
+
+        fs::File::options()
+            .write(true)
+            .open(&icon_button)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+        let workspace = create_server_with_module_graph();
+        workspace
+            .load_module_graph(LoadModuleGraphParams { path: snapshot })
+            .unwrap();
+        assert_eq!(count_ambiguous_re_exports(workspace.as_ref(), &index), 0);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
 }
@@ -1,5 +1,8 @@
 use biome_diagnostics::{Category, Diagnostic};
 use biome_rowan::{TextRange, TextSize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Single instance of a suppression comment, with the following syntax:
 ///
@@ -14,6 +17,9 @@ use biome_rowan::{TextRange, TextSize};
 /// A suppression must specify a reason: this part has no semantic meaning but
 /// is required to document why a particular feature is being disable for this
 /// line (lint false-positive, specific formatting requirements, ...)
+///
+/// The reason can end with an expiration date, such as `[expires:2025-12-31]`,
+/// after which the suppression is reported.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Suppression<'a> {
     /// List of categories for this suppression
@@ -25,6 +31,99 @@ pub struct Suppression<'a> {
     pub reason: &'a str,
     /// If the comment is `// biome-ignore`
     pub is_legacy: bool,
+    /// The last day of validity of the suppression, written `[expires:<date>]` at the end of the reason
+    pub expires: Option<ExpirationDate>,
+}
+
+/// A date in the `YYYY-MM-DD` format, in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExpirationDate {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+impl ExpirationDate {
+    /// Returns the current date, in UTC.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        Self::from_days_since_epoch(seconds / 86_400)
+    }
+
+    /// Returns `true` if the date is before the current date.
+    pub fn is_past(&self) -> bool {
+        *self < Self::today()
+    }
+
+    /// Converts a number of days since 1970-01-01 to a date of the Gregorian calendar.
+    ///
+    /// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+    fn from_days_since_epoch(days: u64) -> Self {
+        // The days since 0000-03-01, so that the leap day is the last day of the year
+        let days = days + 719_468;
+        let era = days / 146_097;
+        let day_of_era = days % 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + u64::from(month <= 2);
+        Self {
+            year: year as u32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+impl FromStr for ExpirationDate {
+    type Err = ();
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut parts = text.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(());
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(());
+        }
+        let parse = |part: &str| {
+            if part.bytes().all(|byte| byte.is_ascii_digit()) {
+                part.parse::<u32>().map_err(|_| ())
+            } else {
+                Err(())
+            }
+        };
+        let (year, month, day) = (parse(year)?, parse(month)?, parse(day)?);
+        let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return Err(()),
+        };
+        if day == 0 || day > days_in_month {
+            return Err(());
+        }
+        Ok(Self { year, month, day })
+    }
+}
+
+impl Display for ExpirationDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
 }
 
 pub fn parse_suppression_comment(
@@ -134,6 +233,7 @@ enum SuppressionDiagnosticKind {
     ParseCategory(String),
     MissingCategory,
     MissingParen,
+    InvalidExpiration(String),
 }
 
 impl std::fmt::Display for SuppressionDiagnosticKind {
@@ -150,6 +250,10 @@ impl std::fmt::Display for SuppressionDiagnosticKind {
                 write!(f, "unexpected token, expected one of ':' or whitespace")
             }
             SuppressionDiagnosticKind::MissingParen => write!(f, "unexpected token, expected ')'"),
+            SuppressionDiagnosticKind::InvalidExpiration(date) => write!(
+                f,
+                "failed to parse the expiration date {date:?}, expected a date such as 2025-12-31"
+            ),
         }
     }
 }
@@ -170,6 +274,10 @@ impl biome_console::fmt::Display for SuppressionDiagnosticKind {
             SuppressionDiagnosticKind::MissingParen => {
                 write!(fmt, "unexpected token, expected ')'")
             }
+            SuppressionDiagnosticKind::InvalidExpiration(date) => write!(
+                fmt,
+                "failed to parse the expiration date {date:?}, expected a date such as 2025-12-31"
+            ),
         }
     }
 }
@@ -248,11 +356,27 @@ fn parse_suppression_line(
         }
     }
 
-    let reason = line.trim_end();
+    let mut reason = line.trim_end();
+    let mut expires = None;
+    if let Some(start) = reason.rfind("[expires:") {
+        if let Some(date) = reason[start..]
+            .strip_prefix("[expires:")
+            .and_then(|date| date.strip_suffix(']'))
+        {
+            let date = date.trim();
+            expires = Some(date.parse().map_err(|()| SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::InvalidExpiration(date.into()),
+                span: TextRange::at(offset_from(base, date), TextSize::of(date)),
+            })?);
+            reason = reason[..start].trim_end();
+        }
+    }
+
     Ok(Suppression {
         categories,
         reason,
         is_legacy,
+        expires,
     })
 }
 
@@ -297,7 +421,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
 
@@ -306,7 +431,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
 
@@ -320,7 +446,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
 
@@ -335,7 +462,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
     }
@@ -346,7 +474,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
 
@@ -355,7 +484,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
 
@@ -364,7 +494,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
     }
@@ -380,7 +511,8 @@ mod tests {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
 
@@ -393,7 +525,8 @@ mod tests {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
 
@@ -410,7 +543,8 @@ mod tests {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
 
@@ -428,7 +562,8 @@ mod tests {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
     }
@@ -441,7 +576,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                expires: None
             })],
         );
     }
@@ -509,7 +645,7 @@ mod tests_biome_ignore {
     use biome_diagnostics::category;
     use biome_rowan::{TextRange, TextSize};
 
-    use crate::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
+    use crate::{offset_from, ExpirationDate, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
     use super::{parse_suppression_comment, Suppression};
 
@@ -520,7 +656,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
 
@@ -530,7 +667,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
 
@@ -544,7 +682,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
 
@@ -559,7 +698,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
     }
@@ -570,7 +710,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
 
@@ -579,7 +720,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
 
@@ -588,7 +730,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
     }
@@ -604,7 +747,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
 
@@ -617,7 +761,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
 
@@ -634,7 +779,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
 
@@ -652,7 +798,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
     }
@@ -665,7 +812,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                expires: None
             })],
         );
     }
@@ -726,4 +874,74 @@ mod tests_biome_ignore {
             })],
         );
     }
+
+    #[test]
+    fn parse_expiration_date() {
+        assert_eq!(
+            parse_suppression_comment(
+                "// biome-ignore lint/style/useConst: explanation [expires:2025-12-31]"
+            )
+            .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint/style/useConst"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                expires: "2025-12-31".parse().ok()
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment(
+                "/* biome-ignore format: explanation [expires: 2024-02-29] */"
+            )
+            .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("format"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                expires: "2024-02-29".parse().ok()
+            })],
+        );
+    }
+
+    #[test]
+    fn diagnostic_invalid_expiration_date() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore format: explanation [expires:2023-02-29]")
+                .collect::<Vec<_>>(),
+            vec![Err(SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::InvalidExpiration(String::from("2023-02-29")),
+                span: TextRange::new(TextSize::from(45), TextSize::from(55))
+            })],
+        );
+    }
+
+    #[test]
+    fn expiration_date() {
+        assert!("2025-13-01".parse::<ExpirationDate>().is_err());
+        assert!("2025-1-01".parse::<ExpirationDate>().is_err());
+        assert!("2025-01-+1".parse::<ExpirationDate>().is_err());
+        assert!("1900-02-29".parse::<ExpirationDate>().is_err());
+        assert_eq!(
+            "2000-02-29"
+                .parse::<ExpirationDate>()
+                .map(|date| date.to_string()),
+            Ok(String::from("2000-02-29"))
+        );
+
+        assert_eq!(
+            ExpirationDate::from_days_since_epoch(0).to_string(),
+            "1970-01-01"
+        );
+        assert_eq!(
+            ExpirationDate::from_days_since_epoch(19_782).to_string(),
+            "2024-02-29"
+        );
+        assert_eq!(
+            ExpirationDate::from_days_since_epoch(20_453).to_string(),
+            "2025-12-31"
+        );
+        assert!("2000-01-01".parse::<ExpirationDate>().unwrap().is_past());
+        assert!(!"2999-12-31".parse::<ExpirationDate>().unwrap().is_past());
+    }
 }
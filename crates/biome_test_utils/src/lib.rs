@@ -7,9 +7,9 @@ use biome_diagnostics::termcolor::Buffer;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic};
 use biome_json_parser::{JsonParserOptions, ParseDiagnostic};
 use biome_project::PackageJson;
-use biome_rowan::{SyntaxKind, SyntaxNode, SyntaxSlot};
+use biome_rowan::{Language, SyntaxKind, SyntaxNode, SyntaxSlot};
 use biome_service::configuration::to_analyzer_rules;
-use biome_service::settings::{ServiceLanguage, Settings};
+use biome_service::settings::Settings;
 use json_comments::StripComments;
 use similar::TextDiff;
 use std::ffi::{c_int, OsStr};
@@ -43,6 +43,8 @@ pub fn create_analyzer_options(
         globals: vec![],
         preferred_quote: PreferredQuote::Double,
         jsx_runtime: Some(JsxRuntime::Transparent),
+        aria_roles: vec![],
+        aria_properties: vec![],
     };
     let options_file = input_file.with_extension("options.json");
     if let Ok(json) = std::fs::read_to_string(options_file.clone()) {
@@ -98,6 +100,13 @@ pub fn create_analyzer_options(
                 .merge_with_configuration(configuration, None, None, &[])
                 .unwrap();
             analyzer_configuration.rules = to_analyzer_rules(&settings, input_file);
+            let aria = &settings.linter.aria;
+            analyzer_configuration.aria_roles = aria
+                .roles
+                .iter()
+                .map(|(role, standard_role)| (role.clone(), standard_role.clone()))
+                .collect();
+            analyzer_configuration.aria_properties = aria.properties.iter().cloned().collect();
         }
     }
 
@@ -176,7 +185,7 @@ pub fn register_leak_checker() {
     });
 }
 
-pub fn code_fix_to_string<L: ServiceLanguage>(source: &str, action: AnalyzerAction<L>) -> String {
+pub fn code_fix_to_string<L: Language>(source: &str, action: AnalyzerAction<L>) -> String {
     let (_, text_edit) = action.mutation.as_text_range_and_edit().unwrap_or_default();
 
     let output = text_edit.new_string(source);
@@ -186,7 +195,20 @@ pub fn code_fix_to_string<L: ServiceLanguage>(source: &str, action: AnalyzerActi
     let mut diff = diff.unified_diff();
     diff.context_radius(3);
 
-    diff.to_string()
+    let mut result = diff.to_string();
+    // The other files edited by the action are read from the disk
+    for file_edit in action.file_edits {
+        let source = std::fs::read_to_string(&file_edit.path)
+            .unwrap_or_else(|err| panic!("failed to read {:?}: {err:?}", file_edit.path));
+        let output = file_edit.edit.new_string(&source);
+        let name = file_edit.path.file_name().unwrap().to_string_lossy();
+
+        let diff = TextDiff::from_lines(&source, &output);
+        let mut diff = diff.unified_diff();
+        diff.context_radius(3).header(&name, &name);
+        write!(result, "{diff}").unwrap();
+    }
+    result
 }
 
 /// The test runner for the analyzer is currently designed to have a
@@ -227,7 +249,7 @@ pub fn has_bogus_nodes_or_empty_slots<L: biome_rowan::Language>(node: &SyntaxNod
 /// This function analyzes the parsing result of a file and panic with a
 /// detailed message if it contains any error-level diagnostic, bogus nodes,
 /// empty list slots or missing required children
-pub fn assert_errors_are_absent<L: ServiceLanguage>(
+pub fn assert_errors_are_absent<L: Language>(
     program: &SyntaxNode<L>,
     diagnostics: &[ParseDiagnostic],
     path: &Path,
@@ -134,6 +134,59 @@ impl TextEdit {
 
         output
     }
+
+    /// Returns the range of the "old" revision of the text that this [TextEdit] changes,
+    /// from the first to the last deleted or inserted text. It's [None] if this [TextEdit]
+    /// doesn't change the text.
+    pub fn changed_range(&self, old_string: &str) -> Option<TextRange> {
+        let changed_ranges = self.changed_ranges(old_string);
+        let first = changed_ranges.first()?;
+        let last = changed_ranges.last()?;
+        Some(first.cover(*last))
+    }
+
+    /// Returns the ranges of the "old" revision of the text that this [TextEdit] changes,
+    /// one for each hunk of consecutive deleted or inserted texts, in order.
+    pub fn changed_ranges(&self, old_string: &str) -> Vec<TextRange> {
+        let mut changed_ranges: Vec<TextRange> = Vec::new();
+        let mut input_position = TextSize::from(0);
+        let mut in_hunk = false;
+
+        for op in &self.ops {
+            let changed = match op {
+                CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                    input_position += range.len();
+                    in_hunk = false;
+                    continue;
+                }
+                CompressedOp::EqualLines { line_count } => {
+                    let start = u32::from(input_position) as usize;
+                    let line_break_count = line_count.get() as usize + 1;
+                    for line in old_string[start..]
+                        .split_inclusive('\n')
+                        .take(line_break_count)
+                    {
+                        input_position += TextSize::of(line);
+                    }
+                    in_hunk = false;
+                    continue;
+                }
+                CompressedOp::DiffOp(DiffOp::Insert { .. }) => TextRange::empty(input_position),
+                CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                    let deleted = TextRange::at(input_position, range.len());
+                    input_position += range.len();
+                    deleted
+                }
+            };
+            match changed_ranges.last_mut() {
+                Some(hunk) if in_hunk => *hunk = hunk.cover(changed),
+                _ => changed_ranges.push(changed),
+            }
+            in_hunk = true;
+        }
+
+        changed_ranges
+    }
 }
 
 impl IntoIterator for TextEdit {
@@ -310,6 +363,7 @@ mod tests {
     use std::num::NonZeroU32;
 
     use crate::{compress_equal_op, TextEdit};
+    use biome_text_size::{TextRange, TextSize};
 
     #[test]
     fn compress_short() {
@@ -372,4 +426,54 @@ line 7 new";
 
         assert_eq!(new_string, NEW);
     }
+
+    #[test]
+    fn changed_range_compressed() {
+        const OLD: &str = "line 1
+line 2
+line 3
+line 4
+line 5
+line 6
+line 7 old";
+
+        const NEW: &str = "line 1
+line 2
+line 3
+line 4
+line 5
+line 6
+line 7 new";
+
+        let diff = TextEdit::from_unicode_words(OLD, NEW);
+
+        assert_eq!(
+            diff.changed_range(OLD),
+            Some(TextRange::new(TextSize::from(49), TextSize::from(52)))
+        );
+        assert_eq!(
+            TextEdit::from_unicode_words(OLD, OLD).changed_range(OLD),
+            None
+        );
+    }
+
+    #[test]
+    fn changed_ranges_hunks() {
+        const OLD: &str = "  statement(  )  ";
+        const NEW: &str = "statement();\n";
+
+        let diff = TextEdit::from_unicode_words(OLD, NEW);
+
+        assert_eq!(
+            diff.changed_ranges(OLD),
+            vec![
+                TextRange::new(TextSize::from(0), TextSize::from(2)),
+                TextRange::new(TextSize::from(12), TextSize::from(14)),
+                TextRange::new(TextSize::from(15), TextSize::from(17)),
+            ]
+        );
+        assert!(TextEdit::from_unicode_words(OLD, OLD)
+            .changed_ranges(OLD)
+            .is_empty());
+    }
 }
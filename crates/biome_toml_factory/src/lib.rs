@@ -0,0 +1,12 @@
+use biome_rowan::TreeBuilder;
+use biome_toml_syntax::TomlLanguage;
+
+mod generated;
+pub use crate::generated::TomlSyntaxFactory;
+pub mod make;
+
+// Re-exported for tests
+#[doc(hidden)]
+pub use biome_toml_syntax as syntax;
+
+pub type TomlSyntaxTreeBuilder = TreeBuilder<'static, TomlLanguage, TomlSyntaxFactory>;
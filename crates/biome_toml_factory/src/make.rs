@@ -0,0 +1,26 @@
+use biome_toml_syntax::{TomlSyntaxKind, TomlSyntaxToken};
+
+pub use crate::generated::node_factory::*;
+
+pub fn ident(text: &str) -> TomlSyntaxToken {
+    TomlSyntaxToken::new_detached(TomlSyntaxKind::IDENT, text, [], [])
+}
+
+/// Create a new token with the specified syntax kind and no attached trivia
+pub fn token(kind: TomlSyntaxKind) -> TomlSyntaxToken {
+    if let Some(text) = kind.to_string() {
+        TomlSyntaxToken::new_detached(kind, text, [], [])
+    } else {
+        panic!("token kind {kind:?} cannot be transformed to text")
+    }
+}
+
+/// Create a new basic string literal token with no attached trivia
+pub fn toml_string_literal(text: &str) -> TomlSyntaxToken {
+    TomlSyntaxToken::new_detached(
+        TomlSyntaxKind::TOML_STRING_LITERAL,
+        &format!("\"{text}\""),
+        [],
+        [],
+    )
+}
@@ -0,0 +1,63 @@
+use crate::prelude::*;
+use biome_diagnostics::category;
+use biome_formatter::comments::{
+    CommentKind, CommentPlacement, CommentStyle, Comments, DecoratedComment, SourceComment,
+};
+use biome_formatter::formatter::Formatter;
+use biome_formatter::{write, FormatResult, FormatRule};
+use biome_rowan::SyntaxTriviaPieceComments;
+use biome_suppression::parse_suppression_comment;
+use biome_toml_syntax::{TomlArray, TomlLanguage};
+
+pub type TomlComments = Comments<TomlLanguage>;
+
+#[derive(Default)]
+pub struct FormatTomlLeadingComment;
+
+impl FormatRule<SourceComment<TomlLanguage>> for FormatTomlLeadingComment {
+    type Context = TomlFormatContext;
+
+    fn fmt(
+        &self,
+        comment: &SourceComment<TomlLanguage>,
+        f: &mut Formatter<Self::Context>,
+    ) -> FormatResult<()> {
+        write!(f, [comment.piece().as_piece()])
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct TomlCommentStyle;
+
+impl CommentStyle for TomlCommentStyle {
+    type Language = TomlLanguage;
+
+    fn is_suppression(text: &str) -> bool {
+        parse_suppression_comment(text)
+            .filter_map(Result::ok)
+            .flat_map(|suppression| suppression.categories)
+            .any(|(key, _)| key == category!("format"))
+    }
+
+    fn get_comment_kind(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
+        CommentKind::Line
+    }
+
+    fn place_comment(
+        &self,
+        comment: DecoratedComment<Self::Language>,
+    ) -> CommentPlacement<Self::Language> {
+        handle_empty_array_comment(comment)
+    }
+}
+
+fn handle_empty_array_comment(
+    comment: DecoratedComment<TomlLanguage>,
+) -> CommentPlacement<TomlLanguage> {
+    match TomlArray::cast_ref(comment.enclosing_node()) {
+        Some(array) if array.elements().is_empty() => {
+            CommentPlacement::dangling(comment.enclosing_node().clone(), comment)
+        }
+        _ => CommentPlacement::Default(comment),
+    }
+}
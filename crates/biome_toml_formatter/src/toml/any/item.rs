@@ -0,0 +1,17 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_toml_syntax::AnyTomlItem;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyTomlItem;
+impl FormatRule<AnyTomlItem> for FormatAnyTomlItem {
+    type Context = TomlFormatContext;
+    fn fmt(&self, node: &AnyTomlItem, f: &mut TomlFormatter) -> FormatResult<()> {
+        match node {
+            AnyTomlItem::TomlArrayOfTables(node) => node.format().fmt(f),
+            AnyTomlItem::TomlBogus(node) => node.format().fmt(f),
+            AnyTomlItem::TomlKeyValue(node) => node.format().fmt(f),
+            AnyTomlItem::TomlTable(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::{TomlArrayOfTables, TomlArrayOfTablesFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlArrayOfTables;
+impl FormatNodeRule<TomlArrayOfTables> for FormatTomlArrayOfTables {
+    fn fmt_fields(&self, node: &TomlArrayOfTables, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlArrayOfTablesFields {
+            l_brack_outer_token,
+            l_brack_inner_token,
+            key,
+            r_brack_inner_token,
+            r_brack_outer_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_brack_outer_token.format(),
+                l_brack_inner_token.format(),
+                key.format(),
+                r_brack_inner_token.format(),
+                r_brack_outer_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,41 @@
+use crate::prelude::*;
+use biome_formatter::{write, FormatRuleWithOptions};
+use biome_toml_syntax::{TomlKeyValue, TomlKeyValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlKeyValue {
+    alignment_padding: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct FormatTomlKeyValueOptions {
+    /// The number of spaces printed between the key and the `=`, used to align
+    /// the `=` of consecutive key-value pairs.
+    pub(crate) alignment_padding: usize,
+}
+
+impl FormatRuleWithOptions<TomlKeyValue> for FormatTomlKeyValue {
+    type Options = FormatTomlKeyValueOptions;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.alignment_padding = options.alignment_padding;
+        self
+    }
+}
+
+impl FormatNodeRule<TomlKeyValue> for FormatTomlKeyValue {
+    fn fmt_fields(&self, node: &TomlKeyValue, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlKeyValueFields {
+            key,
+            eq_token,
+            value,
+        } = node.as_fields();
+
+        write!(f, [key.format()])?;
+        // Spaces can't be used here because consecutive spaces collapse into a single one
+        for _ in 0..self.alignment_padding {
+            write!(f, [text(" ")])?;
+        }
+        write!(f, [space(), eq_token.format(), space(), value.format()])
+    }
+}
@@ -0,0 +1,6 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod array_of_tables;
+pub(crate) mod key_value;
+pub(crate) mod root;
+pub(crate) mod table;
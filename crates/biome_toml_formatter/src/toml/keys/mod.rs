@@ -0,0 +1,5 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod bare_key;
+pub(crate) mod key;
+pub(crate) mod quoted_key;
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use crate::separated::FormatAstSeparatedListExtension;
+use biome_formatter::separated::TrailingSeparator;
+use biome_toml_syntax::TomlArrayElementList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlArrayElementList;
+impl FormatRule<TomlArrayElementList> for FormatTomlArrayElementList {
+    type Context = TomlFormatContext;
+    fn fmt(&self, node: &TomlArrayElementList, f: &mut TomlFormatter) -> FormatResult<()> {
+        // Arrays that don't fit on a single line print every element on its own line,
+        // with a trailing comma after the last one
+        let mut join = f.join_nodes_with_soft_line();
+
+        for (element, formatted) in node
+            .elements()
+            .zip(node.format_separated(",", TrailingSeparator::Allowed))
+        {
+            join.entry(element.node()?.syntax(), &formatted);
+        }
+
+        join.finish()
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::TomlInlineTableEntryList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlInlineTableEntryList;
+impl FormatRule<TomlInlineTableEntryList> for FormatTomlInlineTableEntryList {
+    type Context = TomlFormatContext;
+    fn fmt(&self, node: &TomlInlineTableEntryList, f: &mut TomlFormatter) -> FormatResult<()> {
+        for (index, element) in node.elements().enumerate() {
+            if index != 0 {
+                write!(f, [space()])?;
+            }
+
+            write!(f, [element.node().format()])?;
+
+            if let Some(separator) = element.trailing_separator()? {
+                write!(f, [separator.format()])?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::prelude::*;
+use crate::toml::auxiliary::key_value::FormatTomlKeyValueOptions;
+use biome_rowan::Direction;
+use biome_toml_syntax::{AnyTomlItem, TomlItemList, TomlKey};
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlItemList;
+impl FormatRule<TomlItemList> for FormatTomlItemList {
+    type Context = TomlFormatContext;
+    fn fmt(&self, node: &TomlItemList, f: &mut TomlFormatter) -> FormatResult<()> {
+        let paddings = if f.options().align_entries() {
+            alignment_paddings(node)
+        } else {
+            Vec::new()
+        };
+
+        let mut join = f.join_nodes_with_hardline();
+
+        for (index, item) in node.iter().enumerate() {
+            if let AnyTomlItem::TomlKeyValue(key_value) = &item {
+                let options = FormatTomlKeyValueOptions {
+                    alignment_padding: paddings.get(index).copied().unwrap_or_default(),
+                };
+                join.entry(
+                    item.syntax(),
+                    &format_or_verbatim(key_value.format().with_options(options)),
+                );
+            } else {
+                join.entry(item.syntax(), &format_or_verbatim(item.format()));
+            }
+        }
+
+        join.finish()
+    }
+}
+
+/// Returns, for every item of the list, the number of spaces to print after its key
+/// so that the `=` of consecutive key-value pairs line up.
+///
+/// A run of aligned key-value pairs ends at a table header or at an empty line.
+fn alignment_paddings(list: &TomlItemList) -> Vec<usize> {
+    let mut paddings = vec![0; list.len()];
+    let mut run: Vec<(usize, usize)> = Vec::new();
+
+    let mut align_run = |run: &mut Vec<(usize, usize)>| {
+        let max_width = run
+            .iter()
+            .map(|(_, width)| *width)
+            .max()
+            .unwrap_or_default();
+        for (index, width) in run.drain(..) {
+            paddings[index] = max_width - width;
+        }
+    };
+
+    for (index, item) in list.iter().enumerate() {
+        let width = match &item {
+            AnyTomlItem::TomlKeyValue(key_value) => key_value.key().ok().map(|key| key_width(&key)),
+            _ => None,
+        };
+
+        if width.is_none() || get_lines_before(item.syntax()) > 1 {
+            align_run(&mut run);
+        }
+        if let Some(width) = width {
+            run.push((index, width));
+        }
+    }
+    align_run(&mut run);
+
+    paddings
+}
+
+/// Returns the width of the formatted key, which is printed as is.
+fn key_width(key: &TomlKey) -> usize {
+    key.syntax()
+        .descendants_tokens(Direction::Next)
+        .map(|token| token.text_trimmed().width())
+        .sum()
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::TomlKeyPartList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlKeyPartList;
+impl FormatRule<TomlKeyPartList> for FormatTomlKeyPartList {
+    type Context = TomlFormatContext;
+    fn fmt(&self, node: &TomlKeyPartList, f: &mut TomlFormatter) -> FormatResult<()> {
+        // The parts of a dotted key are printed without spaces: `a.b.c`
+        for element in node.elements() {
+            write!(f, [element.node().format()])?;
+
+            if let Some(separator) = element.trailing_separator()? {
+                write!(f, [separator.format()])?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod array;
+pub(crate) mod boolean;
+pub(crate) mod date_time;
+pub(crate) mod inline_table;
+pub(crate) mod number;
+pub(crate) mod string;
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::{TomlNumber, TomlNumberFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlNumber;
+impl FormatNodeRule<TomlNumber> for FormatTomlNumber {
+    fn fmt_fields(&self, node: &TomlNumber, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlNumberFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,44 @@
+use biome_formatter_test::TestFormatLanguage;
+use biome_fs::BiomePath;
+use biome_parser::AnyParse;
+use biome_service::{
+    settings::{ServiceLanguage, Settings},
+    workspace::DocumentFileSource,
+};
+use biome_toml_formatter::context::TomlFormatContext;
+use biome_toml_formatter::TomlFormatLanguage;
+use biome_toml_parser::parse_toml;
+use biome_toml_syntax::{TomlFileSource, TomlLanguage};
+
+#[derive(Default)]
+pub struct TomlTestFormatLanguage {
+    _source_type: TomlFileSource,
+}
+
+impl TestFormatLanguage for TomlTestFormatLanguage {
+    type ServiceLanguage = TomlLanguage;
+    type Context = TomlFormatContext;
+    type FormatLanguage = TomlFormatLanguage;
+
+    fn parse(&self, text: &str) -> AnyParse {
+        let parse = parse_toml(text);
+
+        AnyParse::new(parse.syntax().as_send().unwrap(), parse.into_diagnostics())
+    }
+
+    fn to_format_language(
+        &self,
+        settings: &Settings,
+        file_source: &DocumentFileSource,
+    ) -> Self::FormatLanguage {
+        let language_settings = &settings.languages.toml.formatter;
+        let options = Self::ServiceLanguage::resolve_format_options(
+            Some(&settings.formatter),
+            Some(&settings.override_settings),
+            Some(language_settings),
+            &BiomePath::new(""),
+            file_source,
+        );
+        TomlFormatLanguage::new(options)
+    }
+}
@@ -0,0 +1,44 @@
+use biome_formatter::{IndentStyle, LineWidth};
+use biome_formatter_test::check_reformat::CheckReformat;
+use biome_toml_formatter::context::TomlFormatOptions;
+use biome_toml_formatter::{format_node, TomlFormatLanguage};
+use biome_toml_parser::parse_toml;
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"
+[package]
+name="biome"
+# comment
+keywords=[ "a","b" ]
+"#;
+    let parse = parse_toml(src);
+    println!("{:#?}", parse);
+
+    let options = TomlFormatOptions::default()
+        .with_line_width(LineWidth::try_from(80).unwrap())
+        .with_indent_style(IndentStyle::Space);
+    let doc = format_node(options.clone(), &parse.syntax()).unwrap();
+    let result = doc.print().unwrap();
+
+    let root = &parse.syntax();
+    let language = language::TomlTestFormatLanguage::default();
+
+    println!("{}", doc.into_document());
+    eprintln!("{}", result.as_code());
+
+    CheckReformat::new(
+        root,
+        result.as_code(),
+        "quick_test",
+        &language,
+        TomlFormatLanguage::new(options),
+    )
+    .check_reformat();
+}
@@ -0,0 +1,8 @@
+mod quick_test;
+mod spec_test;
+
+mod formatter {
+    mod toml_module {
+        tests_macros::gen_tests! {"tests/specs/toml/**/*.toml", crate::spec_test::run, ""}
+    }
+}
@@ -0,0 +1,518 @@
+//! A lossless TOML lexer which yields SyntaxKind tokens used by the biome TOML parser.
+
+#[rustfmt::skip]
+mod tests;
+
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_toml_syntax::{TextLen, TextRange, TextSize, TomlSyntaxKind, TomlSyntaxKind::*, T};
+use std::iter::FusedIterator;
+use unicode_bom::Bom;
+
+pub struct Token {
+    kind: TomlSyntaxKind,
+    range: TextRange,
+}
+
+impl Token {
+    pub fn kind(&self) -> TomlSyntaxKind {
+        self.kind
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+}
+
+/// The context in which the next token is lexed.
+///
+/// TOML can't be lexed without knowing where a token appears, because the same text means different
+/// things in keys and in values: `1979-05-27` is a bare key on the left of `=` and a date on its right.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub(crate) enum TomlLexContext {
+    /// Keys, table headers and punctuation: sequences of `[A-Za-z0-9_-]` are lexed as bare keys.
+    #[default]
+    Key,
+
+    /// The right side of a key-value pair: numbers, dates, booleans and strings.
+    ///
+    /// The value must start on the same line as its key, the tokens that follow a line break are
+    /// lexed in the [TomlLexContext::Key] context.
+    Value,
+
+    /// Array elements, which can be written on multiple lines.
+    ArrayValue,
+}
+
+impl TomlLexContext {
+    const fn is_value(self) -> bool {
+        matches!(self, TomlLexContext::Value | TomlLexContext::ArrayValue)
+    }
+}
+
+/// A lossless TOML lexer
+#[derive(Debug)]
+pub(crate) struct Lexer<'src> {
+    /// Source text
+    source: &'src str,
+
+    /// The start byte position in the source text of the next token.
+    position: usize,
+
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'src> Lexer<'src> {
+    /// Make a new lexer from a str, this is safe because strs are valid utf8
+    pub fn from_str(string: &'src str) -> Self {
+        Self {
+            source: string,
+            position: 0,
+            diagnostics: vec![],
+        }
+    }
+
+    /// Returns the source code
+    pub fn source(&self) -> &'src str {
+        self.source
+    }
+
+    pub fn finish(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    /// Lexes the next token in the given context.
+    ///
+    /// ## Return
+    /// Returns its kind and any potential error.
+    pub(crate) fn next_token(&mut self, context: TomlLexContext) -> Option<Token> {
+        let start = self.text_position();
+
+        match self.current_byte() {
+            Some(current) => {
+                let kind = self.lex_token(current, context);
+
+                debug_assert!(start < self.text_position(), "Lexer did not progress");
+                Some(Token {
+                    kind,
+                    range: TextRange::new(start, self.text_position()),
+                })
+            }
+            None if self.position == self.source.len() => {
+                self.advance(1);
+                Some(Token {
+                    kind: EOF,
+                    range: TextRange::new(start, start),
+                })
+            }
+            None => None,
+        }
+    }
+
+    fn text_position(&self) -> TextSize {
+        TextSize::try_from(self.position).expect("Input to be smaller than 4 GB")
+    }
+
+    /// Bumps the current byte and creates a lexed token of the passed in kind
+    fn eat_byte(&mut self, tok: TomlSyntaxKind) -> TomlSyntaxKind {
+        self.advance(1);
+        tok
+    }
+
+    /// Consumes a single newline: `\n` or `\r\n`.
+    fn consume_newline(&mut self) -> TomlSyntaxKind {
+        match self.current_byte() {
+            Some(b'\r') if self.peek_byte() == Some(b'\n') => self.advance(2),
+            _ => self.advance(1),
+        }
+        NEWLINE
+    }
+
+    /// Consumes all tabs and spaces.
+    fn consume_whitespaces(&mut self) -> TomlSyntaxKind {
+        while let Some(b'\t' | b' ') = self.current_byte() {
+            self.advance(1);
+        }
+        WHITESPACE
+    }
+
+    /// Check if the source starts with a Unicode BOM character. If it does,
+    /// consume it and return the UNICODE_BOM token kind.
+    ///
+    /// ## Safety
+    /// Must be called at a valid UT8 char boundary (and realistically only at
+    /// the start position of the source).
+    fn consume_potential_bom(&mut self) -> Option<TomlSyntaxKind> {
+        if let Some(first) = self.source().get(0..3) {
+            let bom = Bom::from(first.as_bytes());
+            self.advance(bom.len());
+
+            match bom {
+                Bom::Null => None,
+                _ => Some(UNICODE_BOM),
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Get the UTF8 char which starts at the current byte
+    ///
+    /// ## Safety
+    /// Must be called at a valid UT8 char boundary
+    fn current_char_unchecked(&self) -> char {
+        debug_assert!(!self.is_eof());
+        self.assert_at_char_boundary();
+
+        self.source[self.position..]
+            .chars()
+            .next()
+            .expect("the lexer to be at a valid char")
+    }
+
+    /// Gets the current byte.
+    ///
+    /// ## Returns
+    /// The current byte if the lexer isn't at the end of the file.
+    #[inline]
+    fn current_byte(&self) -> Option<u8> {
+        self.byte_at(0)
+    }
+
+    /// Asserts that the lexer is at a UTF8 char boundary
+    #[inline]
+    fn assert_at_char_boundary(&self) {
+        debug_assert!(self.source.is_char_boundary(self.position));
+    }
+
+    /// Peeks at the next byte
+    #[inline]
+    fn peek_byte(&self) -> Option<u8> {
+        self.byte_at(1)
+    }
+
+    /// Returns the byte at position `self.position + offset` or `None` if it is out of bounds.
+    #[inline]
+    fn byte_at(&self, offset: usize) -> Option<u8> {
+        self.source.as_bytes().get(self.position + offset).copied()
+    }
+
+    /// Advances the current position by `n` bytes.
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        self.position += n;
+    }
+
+    /// Advances the current position by the current char UTF8 length
+    ///
+    /// ## Safety
+    /// Must be called at a valid UT8 char boundary
+    #[inline]
+    fn advance_char_unchecked(&mut self) {
+        let c = self.current_char_unchecked();
+        self.position += c.len_utf8();
+    }
+
+    /// Returns `true` if the parser is at or passed the end of the file.
+    #[inline]
+    fn is_eof(&self) -> bool {
+        self.position >= self.source.len()
+    }
+
+    /// Lexes the next token
+    ///
+    /// Guaranteed to not be at the end of the file
+    fn lex_token(&mut self, current: u8, context: TomlLexContext) -> TomlSyntaxKind {
+        match current {
+            b'\n' | b'\r' => self.consume_newline(),
+            b'\t' | b' ' => self.consume_whitespaces(),
+            b'#' => self.lex_comment(),
+            b'"' | b'\'' => self.lex_string_literal(current),
+            b'=' => self.eat_byte(T![=]),
+            b'.' if context == TomlLexContext::Key => self.eat_byte(T![.]),
+            b',' => self.eat_byte(T![,]),
+            b'[' => self.eat_byte(T!['[']),
+            b']' => self.eat_byte(T![']']),
+            b'{' => self.eat_byte(T!['{']),
+            b'}' => self.eat_byte(T!['}']),
+            _ if context == TomlLexContext::Key && is_bare_key_byte(current) => self.lex_bare_key(),
+            _ if context.is_value() && is_value_byte(current) => self.lex_value(),
+            _ if self.position == 0 && self.consume_potential_bom().is_some() => UNICODE_BOM,
+            _ => self.eat_unexpected_character(),
+        }
+    }
+
+    #[inline]
+    fn eat_unexpected_character(&mut self) -> TomlSyntaxKind {
+        self.assert_at_char_boundary();
+
+        let char = self.current_char_unchecked();
+        let err = ParseDiagnostic::new(
+            format!("unexpected character `{char}`"),
+            self.text_position()..self.text_position() + char.text_len(),
+        );
+        self.diagnostics.push(err);
+        self.advance(char.len_utf8());
+
+        ERROR_TOKEN
+    }
+
+    /// Lexes a comment, which spans until the end of the line
+    fn lex_comment(&mut self) -> TomlSyntaxKind {
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b'\n' => break,
+                b'\r' if self.peek_byte() == Some(b'\n') => break,
+                _ if byte.is_ascii() => self.advance(1),
+                _ => self.advance_char_unchecked(),
+            }
+        }
+        COMMENT
+    }
+
+    /// Lexes a sequence of ASCII letters, digits, underscores and dashes
+    fn lex_bare_key(&mut self) -> TomlSyntaxKind {
+        while let Some(byte) = self.current_byte() {
+            if !is_bare_key_byte(byte) {
+                break;
+            }
+            self.advance(1);
+        }
+        IDENT
+    }
+
+    /// Lexes a boolean, a number, or a date and time.
+    fn lex_value(&mut self) -> TomlSyntaxKind {
+        let start = self.position;
+        self.consume_value_bytes();
+
+        // A date and its time may be separated by a space: `1979-05-27 07:32:00`
+        if is_full_date(&self.source[start..self.position])
+            && self.current_byte() == Some(b' ')
+            && self.byte_at(1).is_some_and(|byte| byte.is_ascii_digit())
+            && self.byte_at(2).is_some_and(|byte| byte.is_ascii_digit())
+            && self.byte_at(3) == Some(b':')
+        {
+            self.advance(1);
+            self.consume_value_bytes();
+        }
+
+        let text = &self.source[start..self.position];
+        match text {
+            "true" => return T![true],
+            "false" => return T![false],
+            _ => {}
+        }
+
+        if is_date_time(text) {
+            TOML_DATE_TIME_LITERAL
+        } else if is_number(text) {
+            TOML_NUMBER_LITERAL
+        } else {
+            let message = if text.starts_with(|c: char| c.is_ascii_digit() || c == '+' || c == '-')
+            {
+                "Invalid number or date."
+            } else {
+                "Strings must be quoted."
+            };
+            self.diagnostics.push(ParseDiagnostic::new(
+                message,
+                TextRange::new(TextSize::from(start as u32), self.text_position()),
+            ));
+            ERROR_TOKEN
+        }
+    }
+
+    fn consume_value_bytes(&mut self) {
+        while let Some(byte) = self.current_byte() {
+            if !is_value_byte(byte) {
+                break;
+            }
+            self.advance(1);
+        }
+    }
+
+    /// Lexes a basic (`"`), a literal (`'`), or a multi-line string.
+    fn lex_string_literal(&mut self, quote: u8) -> TomlSyntaxKind {
+        let start = self.text_position();
+        let multiline = self.peek_byte() == Some(quote) && self.byte_at(2) == Some(quote);
+        if multiline {
+            self.advance(3);
+        } else {
+            self.advance(1);
+        }
+
+        let escapes = quote == b'"';
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b'\\' if escapes => {
+                    self.advance(1);
+                    if self.is_eof() {
+                        break;
+                    }
+                    // Skip the escaped character, which may be a quote
+                    self.advance_char_unchecked();
+                }
+                b'\n' | b'\r' if !multiline => break,
+                _ if byte == quote && !multiline => {
+                    self.advance(1);
+                    return TOML_STRING_LITERAL;
+                }
+                _ if byte == quote
+                    && self.peek_byte() == Some(quote)
+                    && self.byte_at(2) == Some(quote) =>
+                {
+                    self.advance(3);
+                    // Up to two quotes are allowed right before the closing delimiter: `"""a"""""`
+                    for _ in 0..2 {
+                        if self.current_byte() == Some(quote) {
+                            self.advance(1);
+                        }
+                    }
+                    return TOML_STRING_LITERAL;
+                }
+                _ if byte.is_ascii() => self.advance(1),
+                _ => self.advance_char_unchecked(),
+            }
+        }
+
+        let unterminated =
+            ParseDiagnostic::new("Missing closing quote", start..self.text_position()).with_detail(
+                self.text_position()..self.text_position(),
+                if multiline {
+                    "file ends here"
+                } else {
+                    "line ends here"
+                },
+            );
+        self.diagnostics.push(unterminated);
+
+        ERROR_TOKEN
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token(TomlLexContext::default())
+    }
+}
+
+impl FusedIterator for Lexer<'_> {}
+
+fn is_bare_key_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'-')
+}
+
+fn is_value_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'-' | b'+' | b'.' | b':')
+}
+
+/// Returns `true` for a date in the `YYYY-MM-DD` format.
+fn is_full_date(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(index, byte)| match index {
+            4 | 7 => *byte == b'-',
+            _ => byte.is_ascii_digit(),
+        })
+}
+
+/// Returns `true` for an offset date-time, a local date-time, a local date, or a local time.
+fn is_date_time(text: &str) -> bool {
+    let (date, time) = if is_full_date(text.get(..10).unwrap_or_default()) {
+        let rest = &text[10..];
+        match rest.as_bytes().first() {
+            None => return true,
+            Some(b'T' | b't' | b' ') => (true, &rest[1..]),
+            Some(_) => return false,
+        }
+    } else {
+        (false, text)
+    };
+
+    // `HH:MM:SS`, optionally followed by fractional seconds and, after a date, an offset
+    let bytes = time.as_bytes();
+    let has_time = bytes.len() >= 8
+        && bytes[..8]
+            .iter()
+            .enumerate()
+            .all(|(index, byte)| match index {
+                2 | 5 => *byte == b':',
+                _ => byte.is_ascii_digit(),
+            });
+    if !has_time {
+        return false;
+    }
+    let mut rest = &time[8..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return false;
+        }
+        rest = &fraction[digits..];
+    }
+    match rest {
+        "" => true,
+        "Z" | "z" => date,
+        offset => {
+            let bytes = offset.as_bytes();
+            date && bytes.len() == 6
+                && matches!(bytes[0], b'+' | b'-')
+                && bytes[1..]
+                    .iter()
+                    .enumerate()
+                    .all(|(index, byte)| match index {
+                        2 => *byte == b':',
+                        _ => byte.is_ascii_digit(),
+                    })
+        }
+    }
+}
+
+/// Returns `true` for an integer or a float.
+fn is_number(text: &str) -> bool {
+    let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+    if matches!(unsigned, "inf" | "nan") {
+        return true;
+    }
+
+    // Prefixed integers can't have a sign
+    let radix = match text.get(..2) {
+        Some("0x") => Some(u8::is_ascii_hexdigit as fn(&u8) -> bool),
+        Some("0o") => Some((|byte: &u8| matches!(byte, b'0'..=b'7')) as fn(&u8) -> bool),
+        Some("0b") => Some((|byte: &u8| matches!(byte, b'0' | b'1')) as fn(&u8) -> bool),
+        _ => None,
+    };
+    if let Some(is_digit) = radix {
+        return is_digits(&text[2..], is_digit);
+    }
+
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(index) => (&unsigned[..index], Some(&unsigned[index + 1..])),
+        None => (unsigned, None),
+    };
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+
+    // Leading zeros aren't allowed
+    let valid_integer =
+        is_digits(integer, u8::is_ascii_digit) && (integer == "0" || !integer.starts_with('0'));
+    let valid_fraction = fraction.map_or(true, |fraction| is_digits(fraction, u8::is_ascii_digit));
+    let valid_exponent = exponent.map_or(true, |exponent| {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        is_digits(exponent, u8::is_ascii_digit)
+    });
+
+    valid_integer && valid_fraction && valid_exponent
+}
+
+/// Returns `true` if `text` is a non-empty sequence of digits, where each underscore is surrounded by digits.
+fn is_digits(text: &str, is_digit: fn(&u8) -> bool) -> bool {
+    !text.is_empty()
+        && text
+            .split('_')
+            .all(|group| !group.is_empty() && group.bytes().all(|byte| is_digit(&byte)))
+}
@@ -0,0 +1,297 @@
+#![cfg(test)]
+#![allow(unused_mut, unused_variables, unused_assignments)]
+
+use super::{Lexer, TextSize, TomlLexContext};
+use quickcheck_macros::quickcheck;
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::Duration;
+
+// Assert the result of lexing a piece of source code in the given context,
+// and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens
+macro_rules! assert_lex {
+    ($context:ident, $src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let mut lexer = Lexer::from_str($src);
+        let mut idx = 0;
+        let mut tok_idx = TextSize::default();
+
+        let mut new_str = String::with_capacity($src.len());
+        let tokens: Vec<_> = std::iter::from_fn(|| lexer.next_token(TomlLexContext::$context)).collect();
+
+        $(
+            assert_eq!(
+                tokens[idx].kind,
+                biome_toml_syntax::TomlSyntaxKind::$kind,
+                "expected token kind {}, but found {:?}",
+                stringify!($kind),
+                tokens[idx].kind,
+            );
+
+            assert_eq!(
+                tokens[idx].range.len(),
+                TextSize::from($len),
+                "expected token length of {}, but found {:?} for token {:?}",
+                $len,
+                tokens[idx].range.len(),
+                tokens[idx].kind,
+            );
+
+            new_str.push_str(&$src[tokens[idx].range]);
+            tok_idx += tokens[idx].range.len();
+
+            idx += 1;
+        )*
+
+        if idx < tokens.len() {
+            panic!(
+                "expected {} tokens but lexer returned {}, first unexpected token is '{:?}'",
+                idx,
+                tokens.len(),
+                tokens[idx].kind
+            );
+        } else {
+            assert_eq!(idx, tokens.len());
+        }
+
+        assert_eq!($src, new_str, "Failed to reconstruct input");
+    }};
+}
+
+fn is_lossless(string: String, context: TomlLexContext) -> bool {
+    // using an mpsc channel allows us to spawn a thread and spawn the lexer there, then if
+    // it takes more than 2 seconds we panic because it is 100% infinite recursion
+    let cloned = string.clone();
+    let (sender, receiver) = channel();
+    thread::spawn(move || {
+        let mut lexer = Lexer::from_str(&cloned);
+        let tokens: Vec<_> = std::iter::from_fn(|| lexer.next_token(context))
+            .map(|token| token.range)
+            .collect();
+
+        sender
+            .send(tokens)
+            .expect("Could not send tokens to receiver");
+    });
+    let token_ranges = receiver
+        .recv_timeout(Duration::from_secs(2))
+        .unwrap_or_else(|_| {
+            panic!(
+                "Lexer is infinitely recursing with this code: ->{string}<-"
+            )
+        });
+
+    let mut new_str = String::with_capacity(string.len());
+
+    for range in token_ranges {
+        new_str.push_str(&string[range]);
+    }
+
+    string == new_str
+}
+
+// This is for testing if the lexer is truly lossless
+// It parses random strings and puts them back together with the produced tokens and compares
+#[quickcheck]
+fn losslessness(string: String) -> bool {
+    is_lossless(string.clone(), TomlLexContext::Key) && is_lossless(string, TomlLexContext::Value)
+}
+
+#[test]
+fn empty() {
+    assert_lex! {
+        Key,
+        "",
+        EOF:0
+    }
+}
+
+#[test]
+fn key_value() {
+    assert_lex! {
+        Key,
+        "name = \"biome\"",
+        IDENT:4,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_STRING_LITERAL:7,
+        EOF:0
+    }
+}
+
+#[test]
+fn dotted_key() {
+    assert_lex! {
+        Key,
+        "a.'b c'.1234",
+        IDENT:1,
+        DOT:1,
+        TOML_STRING_LITERAL:5,
+        DOT:1,
+        IDENT:4,
+        EOF:0
+    }
+}
+
+#[test]
+fn keywords_in_keys() {
+    assert_lex! {
+        Key,
+        "true",
+        IDENT:4,
+        EOF:0
+    }
+}
+
+#[test]
+fn table_headers() {
+    assert_lex! {
+        Key,
+        "[[a]]\n[b]",
+        L_BRACK:1,
+        L_BRACK:1,
+        IDENT:1,
+        R_BRACK:1,
+        R_BRACK:1,
+        NEWLINE:1,
+        L_BRACK:1,
+        IDENT:1,
+        R_BRACK:1,
+        EOF:0
+    }
+}
+
+#[test]
+fn comment() {
+    assert_lex! {
+        Key,
+        "# comment\r\na",
+        COMMENT:9,
+        NEWLINE:2,
+        IDENT:1,
+        EOF:0
+    }
+}
+
+#[test]
+fn booleans() {
+    assert_lex! {
+        Value,
+        "true false",
+        TRUE_KW:4,
+        WHITESPACE:1,
+        FALSE_KW:5,
+        EOF:0
+    }
+}
+
+#[test]
+fn integers() {
+    assert_lex! {
+        Value,
+        "+99 -17 1_000 0xDEAD_beef 0o755 0b1101",
+        TOML_NUMBER_LITERAL:3,
+        WHITESPACE:1,
+        TOML_NUMBER_LITERAL:3,
+        WHITESPACE:1,
+        TOML_NUMBER_LITERAL:5,
+        WHITESPACE:1,
+        TOML_NUMBER_LITERAL:11,
+        WHITESPACE:1,
+        TOML_NUMBER_LITERAL:5,
+        WHITESPACE:1,
+        TOML_NUMBER_LITERAL:6,
+        EOF:0
+    }
+}
+
+#[test]
+fn floats() {
+    assert_lex! {
+        Value,
+        "3.1415 -0.01 5e+22 6.626e-34 inf -nan",
+        TOML_NUMBER_LITERAL:6,
+        WHITESPACE:1,
+        TOML_NUMBER_LITERAL:5,
+        WHITESPACE:1,
+        TOML_NUMBER_LITERAL:5,
+        WHITESPACE:1,
+        TOML_NUMBER_LITERAL:9,
+        WHITESPACE:1,
+        TOML_NUMBER_LITERAL:3,
+        WHITESPACE:1,
+        TOML_NUMBER_LITERAL:4,
+        EOF:0
+    }
+}
+
+#[test]
+fn invalid_numbers() {
+    assert_lex! {
+        Value,
+        "012 1__0 1. 0x",
+        ERROR_TOKEN:3,
+        WHITESPACE:1,
+        ERROR_TOKEN:4,
+        WHITESPACE:1,
+        ERROR_TOKEN:2,
+        WHITESPACE:1,
+        ERROR_TOKEN:2,
+        EOF:0
+    }
+}
+
+#[test]
+fn date_times() {
+    assert_lex! {
+        Value,
+        "1979-05-27T07:32:00Z 1979-05-27 07:32:00.999-07:00 1979-05-27\t07:32:00",
+        TOML_DATE_TIME_LITERAL:20,
+        WHITESPACE:1,
+        TOML_DATE_TIME_LITERAL:29,
+        WHITESPACE:1,
+        TOML_DATE_TIME_LITERAL:10,
+        WHITESPACE:1,
+        TOML_DATE_TIME_LITERAL:8,
+        EOF:0
+    }
+}
+
+#[test]
+fn strings() {
+    assert_lex! {
+        Value,
+        r#""a\"b" 'C:\path' """
+multi""" '''raw''''"#,
+        TOML_STRING_LITERAL:6,
+        WHITESPACE:1,
+        TOML_STRING_LITERAL:9,
+        WHITESPACE:1,
+        TOML_STRING_LITERAL:12,
+        WHITESPACE:1,
+        TOML_STRING_LITERAL:10,
+        EOF:0
+    }
+}
+
+#[test]
+fn unterminated_string() {
+    assert_lex! {
+        Value,
+        "\"abc\nd",
+        ERROR_TOKEN:4,
+        NEWLINE:1,
+        ERROR_TOKEN:1,
+        EOF:0
+    }
+}
+
+#[test]
+fn unquoted_string() {
+    assert_lex! {
+        Value,
+        "abc",
+        ERROR_TOKEN:3,
+        EOF:0
+    }
+}
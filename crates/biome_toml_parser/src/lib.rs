@@ -0,0 +1,109 @@
+//! Lossless and error tolerant TOML Parser.
+
+use crate::parser::TomlParser;
+use crate::syntax::parse_root;
+pub use biome_parser::prelude::*;
+use biome_parser::{tree_sink::LosslessTreeSink, AnyParse};
+use biome_rowan::{AstNode, NodeCache};
+use biome_toml_factory::TomlSyntaxFactory;
+use biome_toml_syntax::{TomlLanguage, TomlRoot, TomlSyntaxNode};
+
+mod lexer;
+mod parser;
+mod prelude;
+mod syntax;
+mod token_source;
+
+pub(crate) type TomlLosslessTreeSink<'source> =
+    LosslessTreeSink<'source, TomlLanguage, TomlSyntaxFactory>;
+
+pub fn parse_toml(source: &str) -> TomlParse {
+    let mut cache = NodeCache::default();
+    parse_toml_with_cache(source, &mut cache)
+}
+
+/// Parses the provided string as TOML document using the provided node cache.
+pub fn parse_toml_with_cache(source: &str, cache: &mut NodeCache) -> TomlParse {
+    tracing::debug_span!("parse").in_scope(move || {
+        let mut parser = TomlParser::new(source);
+
+        parse_root(&mut parser);
+
+        let (events, diagnostics, trivia) = parser.finish();
+
+        let mut tree_sink = TomlLosslessTreeSink::with_cache(source, &trivia, cache);
+        biome_parser::event::process(&mut tree_sink, events, diagnostics);
+        let (green, diagnostics) = tree_sink.finish();
+
+        TomlParse::new(green, diagnostics)
+    })
+}
+
+/// A utility struct for managing the result of a parser job
+#[derive(Debug)]
+pub struct TomlParse {
+    root: TomlSyntaxNode,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl TomlParse {
+    pub fn new(root: TomlSyntaxNode, diagnostics: Vec<ParseDiagnostic>) -> TomlParse {
+        TomlParse { root, diagnostics }
+    }
+
+    /// The syntax node represented by this Parse result
+    ///
+    /// ```
+    /// # use biome_toml_parser::parse_toml;
+    /// # use biome_toml_syntax::TomlSyntaxKind;
+    /// # use biome_rowan::{AstNode, AstNodeList};
+    ///
+    /// let parse = parse_toml("[package]\nname = \"biome\"");
+    ///
+    /// // Get the items of the document
+    /// let items = parse.tree().items();
+    ///
+    /// assert_eq!(items.len(), 2);
+    /// assert_eq!(items.first().unwrap().syntax().kind(), TomlSyntaxKind::TOML_TABLE);
+    /// ```
+    pub fn syntax(&self) -> TomlSyntaxNode {
+        self.root.clone()
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn diagnostics(&self) -> &[ParseDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn into_diagnostics(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    /// Returns [true] if the parser encountered some errors during the parsing.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.is_error())
+    }
+
+    /// Convert this parse result into a typed AST node.
+    ///
+    /// # Panics
+    /// Panics if the node represented by this parse result mismatches.
+    pub fn tree(&self) -> TomlRoot {
+        TomlRoot::unwrap_cast(self.syntax())
+    }
+}
+
+impl From<TomlParse> for AnyParse {
+    fn from(parse: TomlParse) -> Self {
+        let root = parse.syntax();
+        let diagnostics = parse.into_diagnostics();
+        Self::new(
+            // SAFETY: the parser should always return a root node
+            root.as_send().unwrap(),
+            diagnostics,
+        )
+    }
+}
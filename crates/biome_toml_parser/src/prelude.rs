@@ -0,0 +1,3 @@
+pub(crate) use crate::TomlParser;
+pub use biome_parser::prelude::*;
+pub use biome_toml_syntax::T;
@@ -0,0 +1,284 @@
+use crate::lexer::TomlLexContext;
+use crate::prelude::*;
+use biome_parser::diagnostic::{expected_any, expected_node};
+use biome_parser::parse_recovery::ParseRecoveryTokenSet;
+use biome_parser::parsed_syntax::ParsedSyntax::Absent;
+use biome_parser::prelude::ParsedSyntax::Present;
+use biome_parser::ParserProgress;
+use biome_rowan::TextRange;
+use biome_toml_syntax::TomlSyntaxKind;
+use biome_toml_syntax::TomlSyntaxKind::*;
+
+const KEY_START: TokenSet<TomlSyntaxKind> = token_set![IDENT, TOML_STRING_LITERAL];
+
+const VALUE_RECOVERY_SET: TokenSet<TomlSyntaxKind> = token_set![T![,], T![']'], T!['}']];
+
+pub(crate) fn parse_root(p: &mut TomlParser) {
+    let m = p.start();
+    p.eat(UNICODE_BOM);
+
+    parse_item_list(p);
+
+    p.expect(EOF);
+    m.complete(p, TOML_ROOT);
+}
+
+fn parse_item_list(p: &mut TomlParser) {
+    let list = p.start();
+    let mut progress = ParserProgress::default();
+    let mut first = true;
+
+    while !p.at(EOF) {
+        progress.assert_progressing(p);
+
+        // Every key-value pair and table header must start on its own line
+        if !first && !p.has_preceding_line_break() {
+            p.error(
+                p.err_builder("Expected a new line before this item.", p.cur_range())
+                    .with_hint("Key-value pairs and table headers must each be on their own line."),
+            );
+            recover_item(p);
+            continue;
+        }
+        first = false;
+
+        let item = if p.at(T!['[']) {
+            parse_table(p)
+        } else {
+            parse_key_value(p)
+        };
+
+        if item.is_absent() {
+            p.error(expected_any(&["key-value pair", "table"], p.cur_range(), p));
+            recover_item(p);
+        }
+    }
+
+    list.complete(p, TOML_ITEM_LIST);
+}
+
+/// Skips the tokens until the end of the line.
+fn recover_item(p: &mut TomlParser) {
+    let _ = ParseRecoveryTokenSet::new(TOML_BOGUS, token_set![])
+        .enable_recovery_on_line_break()
+        .recover(p);
+}
+
+/// Parses a table header `[key]` or the header of an array of tables `[[key]]`.
+fn parse_table(p: &mut TomlParser) -> ParsedSyntax {
+    if !p.at(T!['[']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    // The brackets of an array of tables can't be separated: `[ [a] ]` is an array inside a table header
+    let is_array_of_tables = p
+        .source()
+        .text()
+        .as_bytes()
+        .get(usize::from(p.cur_range().end()))
+        == Some(&b'[');
+
+    p.bump(T!['[']);
+    if is_array_of_tables {
+        p.bump(T!['[']);
+    }
+
+    parse_key(p).or_add_diagnostic(p, expected_key);
+
+    p.expect(T![']']);
+    if is_array_of_tables {
+        p.expect(T![']']);
+        Present(m.complete(p, TOML_ARRAY_OF_TABLES))
+    } else {
+        Present(m.complete(p, TOML_TABLE))
+    }
+}
+
+fn parse_key_value(p: &mut TomlParser) -> ParsedSyntax {
+    if !p.at_ts(KEY_START) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_key(p).ok();
+
+    // The value is lexed in a different context, it can only be parsed after a `=`
+    if p.expect_with_context(T![=], TomlLexContext::Value) {
+        parse_value(p).or_add_diagnostic(p, expected_value);
+    }
+
+    Present(m.complete(p, TOML_KEY_VALUE))
+}
+
+/// Parses a bare, quoted, or dotted key: `a`, `"a b"`, `a."b".c`.
+fn parse_key(p: &mut TomlParser) -> ParsedSyntax {
+    if !p.at_ts(KEY_START) {
+        return Absent;
+    }
+
+    let m = p.start();
+    let parts = p.start();
+    parse_key_part(p).ok();
+
+    while p.eat(T![.]) {
+        if parse_key_part(p).is_absent() {
+            p.error(expected_key(p, p.cur_range()));
+            break;
+        }
+    }
+
+    parts.complete(p, TOML_KEY_PART_LIST);
+    Present(m.complete(p, TOML_KEY))
+}
+
+fn parse_key_part(p: &mut TomlParser) -> ParsedSyntax {
+    match p.cur() {
+        IDENT => {
+            let m = p.start();
+            p.bump(IDENT);
+            Present(m.complete(p, TOML_BARE_KEY))
+        }
+        TOML_STRING_LITERAL => {
+            let text = p.cur_text();
+            if text.starts_with("\"\"\"") || text.starts_with("'''") {
+                p.error(p.err_builder("Multi-line strings can't be used as keys.", p.cur_range()));
+            }
+
+            let m = p.start();
+            p.bump(TOML_STRING_LITERAL);
+            Present(m.complete(p, TOML_QUOTED_KEY))
+        }
+        _ => Absent,
+    }
+}
+
+fn parse_value(p: &mut TomlParser) -> ParsedSyntax {
+    let kind = match p.cur() {
+        TOML_STRING_LITERAL => TOML_STRING,
+        TOML_NUMBER_LITERAL => TOML_NUMBER,
+        TOML_DATE_TIME_LITERAL => TOML_DATE_TIME,
+        T![true] | T![false] => TOML_BOOLEAN,
+        // An error is already emitted by the lexer.
+        ERROR_TOKEN => TOML_BOGUS_VALUE,
+        T!['['] => return parse_array(p),
+        T!['{'] => return parse_inline_table(p),
+        _ => return Absent,
+    };
+
+    let m = p.start();
+    p.bump_any();
+    Present(m.complete(p, kind))
+}
+
+fn parse_array(p: &mut TomlParser) -> ParsedSyntax {
+    if !p.at(T!['[']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_with_context(T!['['], TomlLexContext::ArrayValue);
+
+    let list = p.start();
+    let mut progress = ParserProgress::default();
+
+    while !p.at(EOF) && !p.at(T![']']) {
+        progress.assert_progressing(p);
+
+        if parse_value(p).is_absent() {
+            let range = match ParseRecoveryTokenSet::new(TOML_BOGUS_VALUE, VALUE_RECOVERY_SET)
+                .enable_recovery_on_line_break()
+                .recover(p)
+            {
+                Ok(marker) => marker.range(p),
+                Err(_) => {
+                    p.error(expected_value(p, p.cur_range()));
+                    break;
+                }
+            };
+            p.error(expected_value(p, range));
+        }
+
+        // Arrays can have a trailing comma
+        if !p.at(T![']']) && !p.expect_with_context(T![,], TomlLexContext::ArrayValue) {
+            break;
+        }
+    }
+
+    list.complete(p, TOML_ARRAY_ELEMENT_LIST);
+    p.expect(T![']']);
+    Present(m.complete(p, TOML_ARRAY))
+}
+
+fn parse_inline_table(p: &mut TomlParser) -> ParsedSyntax {
+    if !p.at(T!['{']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['{']);
+
+    let list = p.start();
+    let mut progress = ParserProgress::default();
+    let mut first = true;
+
+    while !p.at(EOF) && !p.at(T!['}']) {
+        progress.assert_progressing(p);
+
+        if first {
+            first = false;
+        } else {
+            let comma_range = p.cur_range();
+            if !p.expect(T![,]) {
+                break;
+            }
+            if p.at(T!['}']) {
+                p.error(p.err_builder("Inline tables can't have a trailing comma.", comma_range));
+                break;
+            }
+        }
+
+        if parse_key_value(p).is_absent() {
+            match ParseRecoveryTokenSet::new(TOML_BOGUS, VALUE_RECOVERY_SET)
+                .enable_recovery_on_line_break()
+                .recover(p)
+            {
+                Ok(marker) => {
+                    let range = marker.range(p);
+                    p.error(expected_key_value(p, range));
+                }
+                Err(_) => {
+                    p.error(expected_key_value(p, p.cur_range()));
+                    break;
+                }
+            }
+        }
+    }
+
+    list.complete(p, TOML_INLINE_TABLE_ENTRY_LIST);
+    p.expect(T!['}']);
+    Present(m.complete(p, TOML_INLINE_TABLE))
+}
+
+fn expected_value(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(
+        &[
+            "string",
+            "number",
+            "boolean",
+            "date",
+            "array",
+            "inline table",
+        ],
+        range,
+        p,
+    )
+}
+
+fn expected_key(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("key", range, p)
+}
+
+fn expected_key_value(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("key-value pair", range, p)
+}
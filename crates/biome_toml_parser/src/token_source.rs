@@ -0,0 +1,120 @@
+use crate::lexer::{Lexer, Token, TomlLexContext};
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_parser::prelude::{BumpWithContext, TokenSource};
+use biome_parser::token_source::Trivia;
+use biome_rowan::TriviaPieceKind;
+use biome_toml_syntax::TomlSyntaxKind::{EOF, TOMBSTONE};
+use biome_toml_syntax::{TextRange, TomlSyntaxKind};
+
+pub(crate) struct TomlTokenSource<'source> {
+    lexer: Lexer<'source>,
+    trivia: Vec<Trivia>,
+    current: TomlSyntaxKind,
+    current_range: TextRange,
+    preceding_line_break: bool,
+}
+
+impl<'source> TomlTokenSource<'source> {
+    pub fn from_str(source: &'source str) -> Self {
+        let lexer = Lexer::from_str(source);
+
+        let mut source = Self {
+            lexer,
+            trivia: Vec::new(),
+            current: TOMBSTONE,
+            current_range: TextRange::default(),
+            preceding_line_break: false,
+        };
+
+        source.next_non_trivia_token(TomlLexContext::default(), true);
+        source
+    }
+
+    fn next_non_trivia_token(&mut self, mut context: TomlLexContext, first_token: bool) {
+        let mut trailing = !first_token;
+        self.preceding_line_break = false;
+
+        while let Some(token) = self.lexer.next_token(context) {
+            match TriviaPieceKind::try_from(token.kind()) {
+                Err(_) => {
+                    self.set_current_token(token);
+                    // Not trivia
+                    break;
+                }
+                Ok(trivia_kind) => {
+                    if trivia_kind.is_newline() {
+                        trailing = false;
+                        self.preceding_line_break = true;
+
+                        // A key-value pair ends at the end of the line
+                        if context == TomlLexContext::Value {
+                            context = TomlLexContext::Key;
+                        }
+                    }
+
+                    self.trivia
+                        .push(Trivia::new(trivia_kind, token.range(), trailing));
+                }
+            }
+        }
+    }
+
+    fn set_current_token(&mut self, token: Token) {
+        self.current = token.kind();
+        self.current_range = token.range()
+    }
+}
+
+impl<'source> TokenSource for TomlTokenSource<'source> {
+    type Kind = TomlSyntaxKind;
+
+    fn current(&self) -> Self::Kind {
+        self.current
+    }
+
+    fn current_range(&self) -> TextRange {
+        self.current_range
+    }
+
+    fn text(&self) -> &str {
+        self.lexer.source()
+    }
+
+    fn has_preceding_line_break(&self) -> bool {
+        self.preceding_line_break
+    }
+
+    fn bump(&mut self) {
+        self.bump_with_context(TomlLexContext::default())
+    }
+
+    fn skip_as_trivia(&mut self) {
+        self.skip_as_trivia_with_context(TomlLexContext::default())
+    }
+
+    fn finish(self) -> (Vec<Trivia>, Vec<ParseDiagnostic>) {
+        (self.trivia, self.lexer.finish())
+    }
+}
+
+impl<'source> BumpWithContext for TomlTokenSource<'source> {
+    type Context = TomlLexContext;
+
+    fn bump_with_context(&mut self, context: Self::Context) {
+        if self.current != EOF {
+            self.next_non_trivia_token(context, false)
+        }
+    }
+
+    fn skip_as_trivia_with_context(&mut self, context: Self::Context) {
+        if self.current() != EOF {
+            self.trivia.push(Trivia::new(
+                TriviaPieceKind::Skipped,
+                self.current_range(),
+                false,
+            ));
+
+            self.next_non_trivia_token(context, false)
+        }
+    }
+}
@@ -0,0 +1,9 @@
+#[rustfmt::skip]
+pub(super) mod nodes;
+#[rustfmt::skip]
+pub mod macros;
+#[macro_use]
+pub mod kind;
+
+pub use kind::*;
+pub use nodes::*;
@@ -0,0 +1,105 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+#![allow(clippy::all)]
+#![allow(bad_style, missing_docs, unreachable_pub)]
+#[doc = r" The kind of syntax node, e.g. `IDENT`, `FUNCTION_KW`, or `FOR_STMT`."]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[repr(u16)]
+pub enum TomlSyntaxKind {
+    #[doc(hidden)]
+    TOMBSTONE,
+    #[doc = r" Marks the end of the file. May have trivia attached"]
+    EOF,
+    #[doc = r" Any Unicode BOM character that may be present at the start of"]
+    #[doc = r" a file."]
+    UNICODE_BOM,
+    EQ,
+    DOT,
+    COMMA,
+    L_BRACK,
+    R_BRACK,
+    L_CURLY,
+    R_CURLY,
+    TRUE_KW,
+    FALSE_KW,
+    TOML_STRING_LITERAL,
+    TOML_NUMBER_LITERAL,
+    TOML_DATE_TIME_LITERAL,
+    ERROR_TOKEN,
+    NEWLINE,
+    WHITESPACE,
+    IDENT,
+    COMMENT,
+    TOML_ROOT,
+    TOML_ITEM_LIST,
+    TOML_TABLE,
+    TOML_ARRAY_OF_TABLES,
+    TOML_KEY_VALUE,
+    TOML_KEY,
+    TOML_KEY_PART_LIST,
+    TOML_BARE_KEY,
+    TOML_QUOTED_KEY,
+    TOML_STRING,
+    TOML_NUMBER,
+    TOML_BOOLEAN,
+    TOML_DATE_TIME,
+    TOML_ARRAY,
+    TOML_ARRAY_ELEMENT_LIST,
+    TOML_INLINE_TABLE,
+    TOML_INLINE_TABLE_ENTRY_LIST,
+    TOML_BOGUS,
+    TOML_BOGUS_VALUE,
+    #[doc(hidden)]
+    __LAST,
+}
+use self::TomlSyntaxKind::*;
+impl TomlSyntaxKind {
+    pub const fn is_punct(self) -> bool {
+        match self {
+            EQ | DOT | COMMA | L_BRACK | R_BRACK | L_CURLY | R_CURLY => true,
+            _ => false,
+        }
+    }
+    pub const fn is_literal(self) -> bool {
+        match self {
+            TOML_STRING_LITERAL | TOML_NUMBER_LITERAL | TOML_DATE_TIME_LITERAL => true,
+            _ => false,
+        }
+    }
+    pub const fn is_list(self) -> bool {
+        match self {
+            TOML_ITEM_LIST
+            | TOML_KEY_PART_LIST
+            | TOML_ARRAY_ELEMENT_LIST
+            | TOML_INLINE_TABLE_ENTRY_LIST => true,
+            _ => false,
+        }
+    }
+    pub fn from_keyword(ident: &str) -> Option<TomlSyntaxKind> {
+        let kw = match ident {
+            "true" => TRUE_KW,
+            "false" => FALSE_KW,
+            _ => return None,
+        };
+        Some(kw)
+    }
+    pub const fn to_string(&self) -> Option<&'static str> {
+        let tok = match self {
+            EQ => "=",
+            DOT => ".",
+            COMMA => ",",
+            L_BRACK => "[",
+            R_BRACK => "]",
+            L_CURLY => "{",
+            R_CURLY => "}",
+            TRUE_KW => "true",
+            FALSE_KW => "false",
+            TOML_STRING_LITERAL => "string literal",
+            _ => return None,
+        };
+        Some(tok)
+    }
+}
+#[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
+#[macro_export]
+macro_rules ! T { [=] => { $ crate :: TomlSyntaxKind :: EQ } ; [.] => { $ crate :: TomlSyntaxKind :: DOT } ; [,] => { $ crate :: TomlSyntaxKind :: COMMA } ; ['['] => { $ crate :: TomlSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: TomlSyntaxKind :: R_BRACK } ; ['{'] => { $ crate :: TomlSyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: TomlSyntaxKind :: R_CURLY } ; [true] => { $ crate :: TomlSyntaxKind :: TRUE_KW } ; [false] => { $ crate :: TomlSyntaxKind :: FALSE_KW } ; [ident] => { $ crate :: TomlSyntaxKind :: IDENT } ; [EOF] => { $ crate :: TomlSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: TomlSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: TomlSyntaxKind :: HASH } ; }
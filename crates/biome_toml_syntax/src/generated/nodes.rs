@@ -0,0 +1,2022 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::match_like_matches_macro)]
+use crate::{
+    macros::map_syntax_node,
+    TomlLanguage as Language, TomlSyntaxElement as SyntaxElement,
+    TomlSyntaxElementChildren as SyntaxElementChildren,
+    TomlSyntaxKind::{self as SyntaxKind, *},
+    TomlSyntaxList as SyntaxList, TomlSyntaxNode as SyntaxNode, TomlSyntaxToken as SyntaxToken,
+};
+use biome_rowan::{support, AstNode, RawSyntaxKind, SyntaxKindSet, SyntaxResult};
+#[allow(unused)]
+use biome_rowan::{
+    AstNodeList, AstNodeListIterator, AstNodeSlotMap, AstSeparatedList,
+    AstSeparatedListNodesIterator,
+};
+use serde::ser::SerializeSeq;
+use serde::{Serialize, Serializer};
+use std::fmt::{Debug, Formatter};
+#[doc = r" Sentinel value indicating a missing element in a dynamic node, where"]
+#[doc = r" the slots are not statically known."]
+#[allow(dead_code)]
+pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlArray {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlArray {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlArrayFields {
+        TomlArrayFields {
+            l_brack_token: self.l_brack_token(),
+            elements: self.elements(),
+            r_brack_token: self.r_brack_token(),
+        }
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn elements(&self) -> TomlArrayElementList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for TomlArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlArrayFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub elements: TomlArrayElementList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlArrayOfTables {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlArrayOfTables {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlArrayOfTablesFields {
+        TomlArrayOfTablesFields {
+            l_brack_outer_token: self.l_brack_outer_token(),
+            l_brack_inner_token: self.l_brack_inner_token(),
+            key: self.key(),
+            r_brack_inner_token: self.r_brack_inner_token(),
+            r_brack_outer_token: self.r_brack_outer_token(),
+        }
+    }
+    pub fn l_brack_outer_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn l_brack_inner_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn key(&self) -> SyntaxResult<TomlKey> {
+        support::required_node(&self.syntax, 2usize)
+    }
+    pub fn r_brack_inner_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn r_brack_outer_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 4usize)
+    }
+}
+impl Serialize for TomlArrayOfTables {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlArrayOfTablesFields {
+    pub l_brack_outer_token: SyntaxResult<SyntaxToken>,
+    pub l_brack_inner_token: SyntaxResult<SyntaxToken>,
+    pub key: SyntaxResult<TomlKey>,
+    pub r_brack_inner_token: SyntaxResult<SyntaxToken>,
+    pub r_brack_outer_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlBareKey {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlBareKey {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlBareKeyFields {
+        TomlBareKeyFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlBareKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlBareKeyFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlBoolean {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlBoolean {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlBooleanFields {
+        TomlBooleanFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlBoolean {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlBooleanFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlDateTime {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlDateTime {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlDateTimeFields {
+        TomlDateTimeFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlDateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlDateTimeFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlInlineTable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlInlineTable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlInlineTableFields {
+        TomlInlineTableFields {
+            l_curly_token: self.l_curly_token(),
+            entries: self.entries(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn entries(&self) -> TomlInlineTableEntryList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for TomlInlineTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlInlineTableFields {
+    pub l_curly_token: SyntaxResult<SyntaxToken>,
+    pub entries: TomlInlineTableEntryList,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlKey {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlKey {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlKeyFields {
+        TomlKeyFields {
+            parts: self.parts(),
+        }
+    }
+    pub fn parts(&self) -> TomlKeyPartList {
+        support::list(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlKeyFields {
+    pub parts: TomlKeyPartList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlKeyValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlKeyValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlKeyValueFields {
+        TomlKeyValueFields {
+            key: self.key(),
+            eq_token: self.eq_token(),
+            value: self.value(),
+        }
+    }
+    pub fn key(&self) -> SyntaxResult<TomlKey> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn eq_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn value(&self) -> SyntaxResult<AnyTomlValue> {
+        support::required_node(&self.syntax, 2usize)
+    }
+}
+impl Serialize for TomlKeyValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlKeyValueFields {
+    pub key: SyntaxResult<TomlKey>,
+    pub eq_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<AnyTomlValue>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlNumber {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlNumber {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlNumberFields {
+        TomlNumberFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlNumberFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlQuotedKey {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlQuotedKey {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlQuotedKeyFields {
+        TomlQuotedKeyFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlQuotedKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlQuotedKeyFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlRoot {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlRoot {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlRootFields {
+        TomlRootFields {
+            bom_token: self.bom_token(),
+            items: self.items(),
+            eof_token: self.eof_token(),
+        }
+    }
+    pub fn bom_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn items(&self) -> TomlItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for TomlRoot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlRootFields {
+    pub bom_token: Option<SyntaxToken>,
+    pub items: TomlItemList,
+    pub eof_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlString {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlString {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlStringFields {
+        TomlStringFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlStringFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlTable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlTable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlTableFields {
+        TomlTableFields {
+            l_brack_token: self.l_brack_token(),
+            key: self.key(),
+            r_brack_token: self.r_brack_token(),
+        }
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn key(&self) -> SyntaxResult<TomlKey> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for TomlTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlTableFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub key: SyntaxResult<TomlKey>,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyTomlItem {
+    TomlArrayOfTables(TomlArrayOfTables),
+    TomlBogus(TomlBogus),
+    TomlKeyValue(TomlKeyValue),
+    TomlTable(TomlTable),
+}
+impl AnyTomlItem {
+    pub fn as_toml_array_of_tables(&self) -> Option<&TomlArrayOfTables> {
+        match &self {
+            AnyTomlItem::TomlArrayOfTables(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_bogus(&self) -> Option<&TomlBogus> {
+        match &self {
+            AnyTomlItem::TomlBogus(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_key_value(&self) -> Option<&TomlKeyValue> {
+        match &self {
+            AnyTomlItem::TomlKeyValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_table(&self) -> Option<&TomlTable> {
+        match &self {
+            AnyTomlItem::TomlTable(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyTomlKeyPart {
+    TomlBareKey(TomlBareKey),
+    TomlQuotedKey(TomlQuotedKey),
+}
+impl AnyTomlKeyPart {
+    pub fn as_toml_bare_key(&self) -> Option<&TomlBareKey> {
+        match &self {
+            AnyTomlKeyPart::TomlBareKey(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_quoted_key(&self) -> Option<&TomlQuotedKey> {
+        match &self {
+            AnyTomlKeyPart::TomlQuotedKey(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyTomlValue {
+    TomlArray(TomlArray),
+    TomlBogusValue(TomlBogusValue),
+    TomlBoolean(TomlBoolean),
+    TomlDateTime(TomlDateTime),
+    TomlInlineTable(TomlInlineTable),
+    TomlNumber(TomlNumber),
+    TomlString(TomlString),
+}
+impl AnyTomlValue {
+    pub fn as_toml_array(&self) -> Option<&TomlArray> {
+        match &self {
+            AnyTomlValue::TomlArray(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_bogus_value(&self) -> Option<&TomlBogusValue> {
+        match &self {
+            AnyTomlValue::TomlBogusValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_boolean(&self) -> Option<&TomlBoolean> {
+        match &self {
+            AnyTomlValue::TomlBoolean(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_date_time(&self) -> Option<&TomlDateTime> {
+        match &self {
+            AnyTomlValue::TomlDateTime(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_inline_table(&self) -> Option<&TomlInlineTable> {
+        match &self {
+            AnyTomlValue::TomlInlineTable(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_number(&self) -> Option<&TomlNumber> {
+        match &self {
+            AnyTomlValue::TomlNumber(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_string(&self) -> Option<&TomlString> {
+        match &self {
+            AnyTomlValue::TomlString(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+impl AstNode for TomlArray {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_ARRAY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_ARRAY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlArray")
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("elements", &self.elements())
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlArray> for SyntaxNode {
+    fn from(n: TomlArray) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlArray> for SyntaxElement {
+    fn from(n: TomlArray) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlArrayOfTables {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_ARRAY_OF_TABLES as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_ARRAY_OF_TABLES
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlArrayOfTables {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlArrayOfTables")
+            .field(
+                "l_brack_outer_token",
+                &support::DebugSyntaxResult(self.l_brack_outer_token()),
+            )
+            .field(
+                "l_brack_inner_token",
+                &support::DebugSyntaxResult(self.l_brack_inner_token()),
+            )
+            .field("key", &support::DebugSyntaxResult(self.key()))
+            .field(
+                "r_brack_inner_token",
+                &support::DebugSyntaxResult(self.r_brack_inner_token()),
+            )
+            .field(
+                "r_brack_outer_token",
+                &support::DebugSyntaxResult(self.r_brack_outer_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlArrayOfTables> for SyntaxNode {
+    fn from(n: TomlArrayOfTables) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlArrayOfTables> for SyntaxElement {
+    fn from(n: TomlArrayOfTables) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlBareKey {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_BARE_KEY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_BARE_KEY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlBareKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlBareKey")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlBareKey> for SyntaxNode {
+    fn from(n: TomlBareKey) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlBareKey> for SyntaxElement {
+    fn from(n: TomlBareKey) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlBoolean {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_BOOLEAN as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_BOOLEAN
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlBoolean {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlBoolean")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlBoolean> for SyntaxNode {
+    fn from(n: TomlBoolean) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlBoolean> for SyntaxElement {
+    fn from(n: TomlBoolean) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlDateTime {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_DATE_TIME as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_DATE_TIME
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlDateTime")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlDateTime> for SyntaxNode {
+    fn from(n: TomlDateTime) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlDateTime> for SyntaxElement {
+    fn from(n: TomlDateTime) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlInlineTable {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_INLINE_TABLE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_INLINE_TABLE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlInlineTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlInlineTable")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("entries", &self.entries())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlInlineTable> for SyntaxNode {
+    fn from(n: TomlInlineTable) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlInlineTable> for SyntaxElement {
+    fn from(n: TomlInlineTable) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlKey {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_KEY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_KEY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlKey")
+            .field("parts", &self.parts())
+            .finish()
+    }
+}
+impl From<TomlKey> for SyntaxNode {
+    fn from(n: TomlKey) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlKey> for SyntaxElement {
+    fn from(n: TomlKey) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlKeyValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_KEY_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_KEY_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlKeyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlKeyValue")
+            .field("key", &support::DebugSyntaxResult(self.key()))
+            .field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .finish()
+    }
+}
+impl From<TomlKeyValue> for SyntaxNode {
+    fn from(n: TomlKeyValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlKeyValue> for SyntaxElement {
+    fn from(n: TomlKeyValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlNumber {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_NUMBER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_NUMBER
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlNumber")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlNumber> for SyntaxNode {
+    fn from(n: TomlNumber) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlNumber> for SyntaxElement {
+    fn from(n: TomlNumber) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlQuotedKey {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_QUOTED_KEY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_QUOTED_KEY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlQuotedKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlQuotedKey")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlQuotedKey> for SyntaxNode {
+    fn from(n: TomlQuotedKey) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlQuotedKey> for SyntaxElement {
+    fn from(n: TomlQuotedKey) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlRoot {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_ROOT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_ROOT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlRoot")
+            .field(
+                "bom_token",
+                &support::DebugOptionalElement(self.bom_token()),
+            )
+            .field("items", &self.items())
+            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
+            .finish()
+    }
+}
+impl From<TomlRoot> for SyntaxNode {
+    fn from(n: TomlRoot) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlRoot> for SyntaxElement {
+    fn from(n: TomlRoot) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlString {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_STRING as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_STRING
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlString")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlString> for SyntaxNode {
+    fn from(n: TomlString) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlString> for SyntaxElement {
+    fn from(n: TomlString) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlTable {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_TABLE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_TABLE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlTable")
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("key", &support::DebugSyntaxResult(self.key()))
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlTable> for SyntaxNode {
+    fn from(n: TomlTable) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlTable> for SyntaxElement {
+    fn from(n: TomlTable) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl From<TomlArrayOfTables> for AnyTomlItem {
+    fn from(node: TomlArrayOfTables) -> AnyTomlItem {
+        AnyTomlItem::TomlArrayOfTables(node)
+    }
+}
+impl From<TomlBogus> for AnyTomlItem {
+    fn from(node: TomlBogus) -> AnyTomlItem {
+        AnyTomlItem::TomlBogus(node)
+    }
+}
+impl From<TomlKeyValue> for AnyTomlItem {
+    fn from(node: TomlKeyValue) -> AnyTomlItem {
+        AnyTomlItem::TomlKeyValue(node)
+    }
+}
+impl From<TomlTable> for AnyTomlItem {
+    fn from(node: TomlTable) -> AnyTomlItem {
+        AnyTomlItem::TomlTable(node)
+    }
+}
+impl AstNode for AnyTomlItem {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = TomlArrayOfTables::KIND_SET
+        .union(TomlBogus::KIND_SET)
+        .union(TomlKeyValue::KIND_SET)
+        .union(TomlTable::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            TOML_ARRAY_OF_TABLES | TOML_BOGUS | TOML_KEY_VALUE | TOML_TABLE
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            TOML_ARRAY_OF_TABLES => AnyTomlItem::TomlArrayOfTables(TomlArrayOfTables { syntax }),
+            TOML_BOGUS => AnyTomlItem::TomlBogus(TomlBogus { syntax }),
+            TOML_KEY_VALUE => AnyTomlItem::TomlKeyValue(TomlKeyValue { syntax }),
+            TOML_TABLE => AnyTomlItem::TomlTable(TomlTable { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyTomlItem::TomlArrayOfTables(it) => &it.syntax,
+            AnyTomlItem::TomlBogus(it) => &it.syntax,
+            AnyTomlItem::TomlKeyValue(it) => &it.syntax,
+            AnyTomlItem::TomlTable(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyTomlItem::TomlArrayOfTables(it) => it.syntax,
+            AnyTomlItem::TomlBogus(it) => it.syntax,
+            AnyTomlItem::TomlKeyValue(it) => it.syntax,
+            AnyTomlItem::TomlTable(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyTomlItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyTomlItem::TomlArrayOfTables(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlItem::TomlBogus(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlItem::TomlKeyValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlItem::TomlTable(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyTomlItem> for SyntaxNode {
+    fn from(n: AnyTomlItem) -> SyntaxNode {
+        match n {
+            AnyTomlItem::TomlArrayOfTables(it) => it.into(),
+            AnyTomlItem::TomlBogus(it) => it.into(),
+            AnyTomlItem::TomlKeyValue(it) => it.into(),
+            AnyTomlItem::TomlTable(it) => it.into(),
+        }
+    }
+}
+impl From<AnyTomlItem> for SyntaxElement {
+    fn from(n: AnyTomlItem) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<TomlBareKey> for AnyTomlKeyPart {
+    fn from(node: TomlBareKey) -> AnyTomlKeyPart {
+        AnyTomlKeyPart::TomlBareKey(node)
+    }
+}
+impl From<TomlQuotedKey> for AnyTomlKeyPart {
+    fn from(node: TomlQuotedKey) -> AnyTomlKeyPart {
+        AnyTomlKeyPart::TomlQuotedKey(node)
+    }
+}
+impl AstNode for AnyTomlKeyPart {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = TomlBareKey::KIND_SET.union(TomlQuotedKey::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TOML_BARE_KEY | TOML_QUOTED_KEY)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            TOML_BARE_KEY => AnyTomlKeyPart::TomlBareKey(TomlBareKey { syntax }),
+            TOML_QUOTED_KEY => AnyTomlKeyPart::TomlQuotedKey(TomlQuotedKey { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyTomlKeyPart::TomlBareKey(it) => &it.syntax,
+            AnyTomlKeyPart::TomlQuotedKey(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyTomlKeyPart::TomlBareKey(it) => it.syntax,
+            AnyTomlKeyPart::TomlQuotedKey(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyTomlKeyPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyTomlKeyPart::TomlBareKey(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlKeyPart::TomlQuotedKey(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyTomlKeyPart> for SyntaxNode {
+    fn from(n: AnyTomlKeyPart) -> SyntaxNode {
+        match n {
+            AnyTomlKeyPart::TomlBareKey(it) => it.into(),
+            AnyTomlKeyPart::TomlQuotedKey(it) => it.into(),
+        }
+    }
+}
+impl From<AnyTomlKeyPart> for SyntaxElement {
+    fn from(n: AnyTomlKeyPart) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<TomlArray> for AnyTomlValue {
+    fn from(node: TomlArray) -> AnyTomlValue {
+        AnyTomlValue::TomlArray(node)
+    }
+}
+impl From<TomlBogusValue> for AnyTomlValue {
+    fn from(node: TomlBogusValue) -> AnyTomlValue {
+        AnyTomlValue::TomlBogusValue(node)
+    }
+}
+impl From<TomlBoolean> for AnyTomlValue {
+    fn from(node: TomlBoolean) -> AnyTomlValue {
+        AnyTomlValue::TomlBoolean(node)
+    }
+}
+impl From<TomlDateTime> for AnyTomlValue {
+    fn from(node: TomlDateTime) -> AnyTomlValue {
+        AnyTomlValue::TomlDateTime(node)
+    }
+}
+impl From<TomlInlineTable> for AnyTomlValue {
+    fn from(node: TomlInlineTable) -> AnyTomlValue {
+        AnyTomlValue::TomlInlineTable(node)
+    }
+}
+impl From<TomlNumber> for AnyTomlValue {
+    fn from(node: TomlNumber) -> AnyTomlValue {
+        AnyTomlValue::TomlNumber(node)
+    }
+}
+impl From<TomlString> for AnyTomlValue {
+    fn from(node: TomlString) -> AnyTomlValue {
+        AnyTomlValue::TomlString(node)
+    }
+}
+impl AstNode for AnyTomlValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = TomlArray::KIND_SET
+        .union(TomlBogusValue::KIND_SET)
+        .union(TomlBoolean::KIND_SET)
+        .union(TomlDateTime::KIND_SET)
+        .union(TomlInlineTable::KIND_SET)
+        .union(TomlNumber::KIND_SET)
+        .union(TomlString::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            TOML_ARRAY
+                | TOML_BOGUS_VALUE
+                | TOML_BOOLEAN
+                | TOML_DATE_TIME
+                | TOML_INLINE_TABLE
+                | TOML_NUMBER
+                | TOML_STRING
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            TOML_ARRAY => AnyTomlValue::TomlArray(TomlArray { syntax }),
+            TOML_BOGUS_VALUE => AnyTomlValue::TomlBogusValue(TomlBogusValue { syntax }),
+            TOML_BOOLEAN => AnyTomlValue::TomlBoolean(TomlBoolean { syntax }),
+            TOML_DATE_TIME => AnyTomlValue::TomlDateTime(TomlDateTime { syntax }),
+            TOML_INLINE_TABLE => AnyTomlValue::TomlInlineTable(TomlInlineTable { syntax }),
+            TOML_NUMBER => AnyTomlValue::TomlNumber(TomlNumber { syntax }),
+            TOML_STRING => AnyTomlValue::TomlString(TomlString { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyTomlValue::TomlArray(it) => &it.syntax,
+            AnyTomlValue::TomlBogusValue(it) => &it.syntax,
+            AnyTomlValue::TomlBoolean(it) => &it.syntax,
+            AnyTomlValue::TomlDateTime(it) => &it.syntax,
+            AnyTomlValue::TomlInlineTable(it) => &it.syntax,
+            AnyTomlValue::TomlNumber(it) => &it.syntax,
+            AnyTomlValue::TomlString(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyTomlValue::TomlArray(it) => it.syntax,
+            AnyTomlValue::TomlBogusValue(it) => it.syntax,
+            AnyTomlValue::TomlBoolean(it) => it.syntax,
+            AnyTomlValue::TomlDateTime(it) => it.syntax,
+            AnyTomlValue::TomlInlineTable(it) => it.syntax,
+            AnyTomlValue::TomlNumber(it) => it.syntax,
+            AnyTomlValue::TomlString(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyTomlValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyTomlValue::TomlArray(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlBogusValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlBoolean(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlDateTime(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlInlineTable(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlNumber(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlString(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyTomlValue> for SyntaxNode {
+    fn from(n: AnyTomlValue) -> SyntaxNode {
+        match n {
+            AnyTomlValue::TomlArray(it) => it.into(),
+            AnyTomlValue::TomlBogusValue(it) => it.into(),
+            AnyTomlValue::TomlBoolean(it) => it.into(),
+            AnyTomlValue::TomlDateTime(it) => it.into(),
+            AnyTomlValue::TomlInlineTable(it) => it.into(),
+            AnyTomlValue::TomlNumber(it) => it.into(),
+            AnyTomlValue::TomlString(it) => it.into(),
+        }
+    }
+}
+impl From<AnyTomlValue> for SyntaxElement {
+    fn from(n: AnyTomlValue) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl std::fmt::Display for AnyTomlItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyTomlKeyPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyTomlValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlArrayOfTables {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlBareKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlBoolean {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlInlineTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlKeyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlQuotedKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct TomlBogus {
+    syntax: SyntaxNode,
+}
+impl TomlBogus {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for TomlBogus {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_BOGUS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_BOGUS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlBogus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlBogus")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<TomlBogus> for SyntaxNode {
+    fn from(n: TomlBogus) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlBogus> for SyntaxElement {
+    fn from(n: TomlBogus) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct TomlBogusValue {
+    syntax: SyntaxNode,
+}
+impl TomlBogusValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for TomlBogusValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_BOGUS_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_BOGUS_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlBogusValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlBogusValue")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<TomlBogusValue> for SyntaxNode {
+    fn from(n: TomlBogusValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlBogusValue> for SyntaxElement {
+    fn from(n: TomlBogusValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TomlArrayElementList {
+    syntax_list: SyntaxList,
+}
+impl TomlArrayElementList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for TomlArrayElementList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_ARRAY_ELEMENT_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_ARRAY_ELEMENT_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<TomlArrayElementList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TomlArrayElementList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for TomlArrayElementList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstSeparatedList for TomlArrayElementList {
+    type Language = Language;
+    type Node = AnyTomlValue;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for TomlArrayElementList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TomlArrayElementList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for TomlArrayElementList {
+    type Item = SyntaxResult<AnyTomlValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyTomlValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &TomlArrayElementList {
+    type Item = SyntaxResult<AnyTomlValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyTomlValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TomlInlineTableEntryList {
+    syntax_list: SyntaxList,
+}
+impl TomlInlineTableEntryList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for TomlInlineTableEntryList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_INLINE_TABLE_ENTRY_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_INLINE_TABLE_ENTRY_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<TomlInlineTableEntryList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TomlInlineTableEntryList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for TomlInlineTableEntryList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstSeparatedList for TomlInlineTableEntryList {
+    type Language = Language;
+    type Node = TomlKeyValue;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for TomlInlineTableEntryList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TomlInlineTableEntryList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for TomlInlineTableEntryList {
+    type Item = SyntaxResult<TomlKeyValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, TomlKeyValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &TomlInlineTableEntryList {
+    type Item = SyntaxResult<TomlKeyValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, TomlKeyValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TomlItemList {
+    syntax_list: SyntaxList,
+}
+impl TomlItemList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for TomlItemList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_ITEM_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_ITEM_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<TomlItemList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TomlItemList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for TomlItemList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for TomlItemList {
+    type Language = Language;
+    type Node = AnyTomlItem;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for TomlItemList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TomlItemList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &TomlItemList {
+    type Item = AnyTomlItem;
+    type IntoIter = AstNodeListIterator<Language, AnyTomlItem>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for TomlItemList {
+    type Item = AnyTomlItem;
+    type IntoIter = AstNodeListIterator<Language, AnyTomlItem>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TomlKeyPartList {
+    syntax_list: SyntaxList,
+}
+impl TomlKeyPartList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for TomlKeyPartList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_KEY_PART_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_KEY_PART_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<TomlKeyPartList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TomlKeyPartList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for TomlKeyPartList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstSeparatedList for TomlKeyPartList {
+    type Language = Language;
+    type Node = AnyTomlKeyPart;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for TomlKeyPartList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TomlKeyPartList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for TomlKeyPartList {
+    type Item = SyntaxResult<AnyTomlKeyPart>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyTomlKeyPart>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &TomlKeyPartList {
+    type Item = SyntaxResult<AnyTomlKeyPart>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyTomlKeyPart>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone)]
+pub struct DebugSyntaxElementChildren(pub SyntaxElementChildren);
+impl Debug for DebugSyntaxElementChildren {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.clone().0.map(DebugSyntaxElement))
+            .finish()
+    }
+}
+struct DebugSyntaxElement(SyntaxElement);
+impl Debug for DebugSyntaxElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            SyntaxElement::Node(node) => {
+                map_syntax_node ! (node . clone () , node => std :: fmt :: Debug :: fmt (& node , f))
+            }
+            SyntaxElement::Token(token) => Debug::fmt(token, f),
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use wasm_bindgen::prelude::*;
+
+use biome_diagnostics::serde::Diagnostic as SerdeDiagnostic;
+use biome_diagnostics::{Diagnostic, DiagnosticExt, MessageAndDescription};
+use biome_fs::BiomePath;
+use biome_service::workspace::{
+    CloseFileParams, DropPatternParams, FeaturesBuilder, ForgetFileParams, GetFileContentParams,
+    OpenFileParams, ParsePatternParams, PatternId, SearchPatternParams, SupportsFeatureParams,
+};
+use biome_service::{Workspace, WorkspaceError};
+
+/// The files of a project, that the workspace can index without opening them
+///
+/// The rules that inspect the whole project, such as `noDuplicateImportsAcrossFiles`,
+/// only know about the files of the workspace. A [MemoryFileSystem] gives them
+/// the other files of the project, with [Workspace::scan_project](crate::Workspace::scan_project).
+///
+/// The `.grit` files are Grit plugins: the files that match their pattern are reported by the linter.
+#[wasm_bindgen]
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+    files: BTreeMap<PathBuf, String>,
+    /// The files removed since the project was last scanned, that the workspace must forget
+    removed: BTreeSet<PathBuf>,
+}
+
+#[wasm_bindgen]
+impl MemoryFileSystem {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates or updates a file
+    pub fn insert(&mut self, path: String, content: String) {
+        let path = PathBuf::from(path);
+        self.removed.remove(&path);
+        self.files.insert(path, content);
+    }
+
+    /// Removes a file
+    pub fn remove(&mut self, path: String) {
+        let path = PathBuf::from(path);
+        if self.files.remove(&path).is_some() {
+            self.removed.insert(path);
+        }
+    }
+}
+
+/// A Grit plugin loaded from a `.grit` file of a [MemoryFileSystem]
+#[derive(Debug)]
+pub(crate) struct Plugin {
+    path: String,
+    pattern: PatternId,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "plugin", severity = Warning)]
+struct PluginDiagnostic {
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+}
+
+/// Indexes the files of `fs` that the linter supports, so that the rules
+/// that inspect the whole project take them into account, and loads its Grit plugins.
+///
+/// The files are opened and closed right away, the files opened by the client keep their content.
+/// The files are only indexed for the rules enabled by the current settings.
+/// The files removed from `fs` since the last scan are removed from the indexes.
+pub(crate) fn scan_project(
+    workspace: &dyn Workspace,
+    fs: &mut MemoryFileSystem,
+) -> Result<Vec<Plugin>, WorkspaceError> {
+    for path in std::mem::take(&mut fs.removed) {
+        workspace.forget_file(ForgetFileParams {
+            path: BiomePath::new(path),
+        })?;
+    }
+    let mut plugins = Vec::new();
+    for (path, content) in &fs.files {
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "grit")
+        {
+            let result = workspace.parse_pattern(ParsePatternParams {
+                pattern: content.clone(),
+            })?;
+            plugins.push(Plugin {
+                path: path.display().to_string(),
+                pattern: result.pattern_id,
+            });
+            continue;
+        }
+        let path = BiomePath::new(path);
+        let is_open = workspace
+            .get_file_content(GetFileContentParams { path: path.clone() })
+            .is_ok();
+        if is_open {
+            continue;
+        }
+        let features = workspace.file_features(SupportsFeatureParams {
+            path: path.clone(),
+            features: FeaturesBuilder::new().with_linter().build(),
+        })?;
+        if !features.supports_lint() {
+            continue;
+        }
+        workspace.open_file(OpenFileParams {
+            path: path.clone(),
+            content: content.clone(),
+            version: 0,
+            document_file_source: None,
+        })?;
+        workspace.close_file(CloseFileParams { path })?;
+    }
+    Ok(plugins)
+}
+
+/// Unloads the patterns of `plugins` from the workspace
+pub(crate) fn drop_plugins(workspace: &dyn Workspace, plugins: Vec<Plugin>) {
+    for plugin in plugins {
+        // A pattern can't fail to be dropped
+        workspace
+            .drop_pattern(DropPatternParams {
+                pattern: plugin.pattern,
+            })
+            .ok();
+    }
+}
+
+/// Returns a diagnostic for each match of `plugins` in the open file at `path`.
+///
+/// The files that Grit can't search, because of their language, don't have any match.
+pub(crate) fn pull_plugin_diagnostics(
+    workspace: &dyn Workspace,
+    plugins: &[Plugin],
+    path: &BiomePath,
+) -> Vec<SerdeDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for plugin in plugins {
+        let Ok(results) = workspace.search_pattern(SearchPatternParams {
+            path: path.clone(),
+            pattern: plugin.pattern.clone(),
+        }) else {
+            continue;
+        };
+        diagnostics.extend(results.matches.into_iter().map(|range| {
+            let diagnostic = PluginDiagnostic {
+                message: format!("This code matches the plugin {}.", plugin.path).into(),
+            };
+            SerdeDiagnostic::new(
+                diagnostic
+                    .with_file_path(path.display().to_string())
+                    .with_file_span(range),
+            )
+        }));
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pull_plugin_diagnostics, scan_project, MemoryFileSystem};
+    use biome_analyze::RuleCategoriesBuilder;
+    use biome_configuration::analyzer::Nursery;
+    use biome_configuration::{
+        PartialConfiguration, PartialLinterConfiguration, RuleConfiguration,
+        RulePlainConfiguration, Rules,
+    };
+    use biome_fs::BiomePath;
+    use biome_service::workspace::{
+        server, OpenFileParams, PullDiagnosticsParams, RegisterProjectFolderParams,
+        UpdateSettingsParams,
+    };
+    use biome_service::Workspace;
+
+    /// Returns a workspace where `noDuplicateImportsAcrossFiles` is enabled
+    fn workspace_with_project_rule() -> Box<dyn Workspace> {
+        let workspace = server();
+        workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                path: None,
+                set_as_current_workspace: true,
+            })
+            .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration: PartialConfiguration {
+                    linter: Some(PartialLinterConfiguration {
+                        rules: Some(Rules {
+                            nursery: Some(Nursery {
+                                no_duplicate_imports_across_files: Some(RuleConfiguration::Plain(
+                                    RulePlainConfiguration::Error,
+                                )),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+            .unwrap();
+        workspace
+    }
+
+    /// Opens `index.js` with `content`, and returns the number of errors of its diagnostics
+    fn lint_index(workspace: &dyn Workspace, content: &str) -> usize {
+        let path = BiomePath::new("index.js");
+        workspace
+            .open_file(OpenFileParams {
+                path: path.clone(),
+                content: content.into(),
+                version: 0,
+                document_file_source: None,
+            })
+            .unwrap();
+        workspace
+            .pull_diagnostics(PullDiagnosticsParams {
+                path,
+                categories: RuleCategoriesBuilder::default().with_lint().build(),
+                max_diagnostics: 10,
+                only: Vec::new(),
+                skip: Vec::new(),
+                timeout: None,
+                profile: false,
+            })
+            .unwrap()
+            .errors
+    }
+
+    #[test]
+    fn scanned_files_are_seen_by_project_rules() {
+        let workspace = workspace_with_project_rule();
+
+        let mut fs = MemoryFileSystem::new();
+        fs.insert("button.js".into(), "export const Button = 1;".into());
+        fs.insert("icon-button.js".into(), "export const Button = 2;".into());
+        fs.insert("README.md".into(), "# Buttons".into());
+        scan_project(workspace.as_ref(), &mut fs).unwrap();
+
+        let errors = lint_index(
+            workspace.as_ref(),
+            "export * from \"./button\";\nexport * from \"./icon-button\";\n",
+        );
+        assert_eq!(errors, 1);
+    }
+
+    #[test]
+    fn removed_files_are_forgotten_by_project_rules() {
+        let workspace = workspace_with_project_rule();
+
+        let mut fs = MemoryFileSystem::new();
+        fs.insert("button.js".into(), "export const Button = 1;".into());
+        fs.insert("icon-button.js".into(), "export const Button = 2;".into());
+        scan_project(workspace.as_ref(), &mut fs).unwrap();
+        fs.remove("icon-button.js".into());
+        scan_project(workspace.as_ref(), &mut fs).unwrap();
+
+        let errors = lint_index(
+            workspace.as_ref(),
+            "export * from \"./button\";\nexport * from \"./icon-button\";\n",
+        );
+        assert_eq!(errors, 0);
+    }
+
+    #[test]
+    fn grit_files_are_loaded_as_plugins() {
+        let workspace = workspace_with_project_rule();
+
+        let mut fs = MemoryFileSystem::new();
+        fs.insert("no-foo.grit".into(), "`foo`".into());
+        let plugins = scan_project(workspace.as_ref(), &mut fs).unwrap();
+        assert_eq!(plugins.len(), 1);
+
+        lint_index(workspace.as_ref(), "foo();\nbar();\n");
+        let diagnostics =
+            pull_plugin_diagnostics(workspace.as_ref(), &plugins, &BiomePath::new("index.js"));
+        assert_eq!(diagnostics.len(), 1);
+    }
+}
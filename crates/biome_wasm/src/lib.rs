@@ -1,16 +1,25 @@
+use std::cell::RefCell;
+
 use js_sys::Error;
 use wasm_bindgen::prelude::*;
 
+use biome_analyze::RuleCategory;
+
 use biome_service::workspace::{
-    self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
-    FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams,
-    RegisterProjectFolderParams, RenameParams, UpdateSettingsParams,
+    self, CallHierarchyCallsParams, ChangeFileParams, CloseFileParams, DocumentHighlightsParams,
+    FixFileParams, FormatFileParams, FormatOnTypeParams, FormatRangeParams,
+    GenerateBarrelFileParams, GetControlFlowGraphParams, GetFileContentParams,
+    GetFormatterIRParams, GetSyntaxTreeParams, OrganizeImportsParams, PrepareCallHierarchyParams,
+    PullActionsParams, PullDiagnosticsParams, RegisterProjectFolderParams, RenameParams,
+    SelectionRangesParams, UpdateSettingsParams, VerifyFormatFileParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
+mod fs;
 mod utils;
 
+pub use crate::fs::MemoryFileSystem;
+use crate::fs::Plugin;
 pub use crate::utils::DiagnosticPrinter;
 use crate::utils::{into_error, set_panic_hook};
 
@@ -24,6 +33,8 @@ include!(concat!(env!("OUT_DIR"), "/ts_types.rs"));
 #[wasm_bindgen]
 pub struct Workspace {
     inner: Box<dyn workspace::Workspace>,
+    /// The Grit plugins loaded by [Workspace::scan_project]
+    plugins: RefCell<Vec<Plugin>>,
 }
 
 #[wasm_bindgen]
@@ -33,6 +44,7 @@ impl Workspace {
     pub fn new() -> Workspace {
         Workspace {
             inner: workspace::server(),
+            plugins: RefCell::default(),
         }
     }
 
@@ -138,7 +150,18 @@ impl Workspace {
     ) -> Result<IPullDiagnosticsResult, Error> {
         let params: PullDiagnosticsParams =
             serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
-        let result = self.inner.pull_diagnostics(params).map_err(into_error)?;
+        let path = params.path.clone();
+        let is_lint = params.categories.contains(RuleCategory::Lint);
+        let mut result = self.inner.pull_diagnostics(params).map_err(into_error)?;
+        if is_lint {
+            result
+                .diagnostics
+                .extend(crate::fs::pull_plugin_diagnostics(
+                    self.inner.as_ref(),
+                    &self.plugins.borrow(),
+                    &path,
+                ));
+        }
         to_value(&result)
             .map(IPullDiagnosticsResult::from)
             .map_err(into_error)
@@ -162,6 +185,19 @@ impl Workspace {
         to_value(&result).map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = verifyFormatFile)]
+    pub fn verify_format_file(
+        &self,
+        params: IVerifyFormatFileParams,
+    ) -> Result<IVerifyFormatFileResult, Error> {
+        let params: VerifyFormatFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.verify_format_file(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IVerifyFormatFileResult::from)
+            .map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = formatRange)]
     pub fn format_range(&self, params: IFormatRangeParams) -> Result<JsValue, Error> {
         let params: FormatRangeParams =
@@ -209,6 +245,104 @@ impl Workspace {
             .map(IRenameResult::from)
             .map_err(into_error)
     }
+
+    #[wasm_bindgen(js_name = documentHighlights)]
+    pub fn document_highlights(
+        &self,
+        params: IDocumentHighlightsParams,
+    ) -> Result<IDocumentHighlightsResult, Error> {
+        let params: DocumentHighlightsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.document_highlights(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IDocumentHighlightsResult::from)
+            .map_err(into_error)
+    }
+
+    /// Indexes the files of a virtual file system, so that the rules that inspect
+    /// the whole project, such as `noDuplicateImportsAcrossFiles`, take them into account,
+    /// and loads its `.grit` files as plugins, that replace the plugins of the previous scan.
+    ///
+    /// The files are indexed for the rules enabled by the current settings,
+    /// the project should be scanned again after the settings change, or after files are removed.
+    #[wasm_bindgen(js_name = scanProject)]
+    pub fn scan_project(&self, fs: &mut MemoryFileSystem) -> Result<(), Error> {
+        let plugins = crate::fs::scan_project(self.inner.as_ref(), fs).map_err(into_error)?;
+        let previous = self.plugins.replace(plugins);
+        crate::fs::drop_plugins(self.inner.as_ref(), previous);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = selectionRanges)]
+    pub fn selection_ranges(
+        &self,
+        params: ISelectionRangesParams,
+    ) -> Result<ISelectionRangesResult, Error> {
+        let params: SelectionRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.selection_ranges(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ISelectionRangesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = prepareCallHierarchy)]
+    pub fn prepare_call_hierarchy(
+        &self,
+        params: IPrepareCallHierarchyParams,
+    ) -> Result<IPrepareCallHierarchyResult, Error> {
+        let params: PrepareCallHierarchyParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .prepare_call_hierarchy(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IPrepareCallHierarchyResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = incomingCalls)]
+    pub fn incoming_calls(
+        &self,
+        params: ICallHierarchyCallsParams,
+    ) -> Result<IIncomingCallsResult, Error> {
+        let params: CallHierarchyCallsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.incoming_calls(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IIncomingCallsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = outgoingCalls)]
+    pub fn outgoing_calls(
+        &self,
+        params: ICallHierarchyCallsParams,
+    ) -> Result<IOutgoingCallsResult, Error> {
+        let params: CallHierarchyCallsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.outgoing_calls(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IOutgoingCallsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = generateBarrelFile)]
+    pub fn generate_barrel_file(
+        &self,
+        params: IGenerateBarrelFileParams,
+    ) -> Result<IGenerateBarrelFileResult, Error> {
+        let params: GenerateBarrelFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .generate_barrel_file(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGenerateBarrelFileResult::from)
+            .map_err(into_error)
+    }
 }
 
 fn to_value<T: serde::ser::Serialize + ?Sized>(
@@ -81,6 +81,10 @@ export interface PartialConfiguration {
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
 	overrides?: Overrides;
+	/**
+	 * Specific configuration for the TOML language
+	 */
+	toml?: PartialTomlConfiguration;
 	/**
 	 * The configuration of the VCS integration
 	 */
@@ -159,6 +163,10 @@ export interface PartialFormatterConfiguration {
 	 * Whether to insert spaces around brackets in object literals. Defaults to true.
 	 */
 	bracketSpacing?: BracketSpacing;
+	/**
+	 * How the formatter normalizes the comments. Only applies to the JavaScript files.
+	 */
+	comments?: PartialCommentsConfiguration;
 	enabled?: boolean;
 	/**
 	 * Stores whether formatting should be allowed to proceed if a given file has syntax errors
@@ -192,6 +200,10 @@ export interface PartialFormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * The maximum number of syntax nodes of the files that are formatted. The files above this limit aren't formatted, and a diagnostic is emitted. No limit by default.
+	 */
+	maxComplexity?: number;
 	/**
 	 * Use any `.editorconfig` files to configure the formatter. Configuration in `biome.json` will override `.editorconfig` configuration. Default: false.
 	 */
@@ -261,6 +273,10 @@ export interface PartialJsonConfiguration {
 	parser?: PartialJsonParser;
 }
 export interface PartialLinterConfiguration {
+	/**
+	 * The custom ARIA roles and properties that the accessibility rules should accept
+	 */
+	aria?: AriaConfiguration;
 	/**
 	 * if `false`, it disables the feature and the linter won't be executed. `true` by default
 	 */
@@ -273,6 +289,10 @@ export interface PartialLinterConfiguration {
 	 * A list of Unix shell style patterns. The formatter will include files/folders that will match these patterns.
 	 */
 	include?: StringSet;
+	/**
+	 * A preset of rules, such as `"recommended-strict"`, `"pedantic"` or `"formatting-only"`. The rules of the preset are layered under `rules`.
+	 */
+	preset?: RulePreset;
 	/**
 	 * List of rules
 	 */
@@ -291,8 +311,21 @@ export interface PartialOrganizeImports {
 	 * A list of Unix shell style patterns. The formatter will include files/folders that will match these patterns.
 	 */
 	include?: StringSet;
+	/**
+	 * Whether to merge the type-only imports into the value imports from the same module, using inline `type` modifiers. Defaults to `false`.
+	 */
+	mergeTypeImports?: boolean;
 }
 export type Overrides = OverridePattern[];
+/**
+ * Options applied to TOML files
+ */
+export interface PartialTomlConfiguration {
+	/**
+	 * TOML formatter options
+	 */
+	formatter?: PartialTomlFormatter;
+}
 /**
  * Set of properties to integrate Biome with a VCS software.
  */
@@ -385,6 +418,23 @@ export interface PartialCssParser {
 }
 export type AttributePosition = "auto" | "multiline";
 export type BracketSpacing = boolean;
+/**
+ * The normalizations of the comments. They are all disabled by default.
+ */
+export interface PartialCommentsConfiguration {
+	/**
+	 * Start every line of the multiline documentation comments `/** */` with a `*`, and put the delimiters of the comments on their own line. Defaults to false.
+	 */
+	docCommentStyle?: boolean;
+	/**
+	 * Insert a space after the `//` of the line comments, such as `//comment`. Defaults to false.
+	 */
+	lineCommentSpace?: boolean;
+	/**
+	 * Reflow the paragraphs of the multiline block comments to the line width. Defaults to false.
+	 */
+	reflow?: boolean;
+}
 export type IndentWidth = number;
 export type IndentStyle = "tab" | "space";
 export type LineEnding = "lf" | "crlf" | "cr";
@@ -465,6 +515,14 @@ export interface PartialJavascriptFormatter {
 	 * Whether to insert spaces around brackets in object literals. Defaults to true.
 	 */
 	bracketSpacing?: BracketSpacing;
+	/**
+	* Whether to format the code embedded in the template literals. Defaults to "off".
+
+With "auto", the CSS of the templates tagged with `css`, `keyframes`, `createGlobalStyle`, `injectGlobal`, or `styled`, and of the templates preceded by a `/* css */` comment, is formatted.
+
+Only the CSS is formatted: the templates tagged with `html`, and the templates preceded by a `/* html */` comment, are printed as written. 
+	 */
+	embeddedLanguageFormatting?: EmbeddedLanguageFormatting;
 	/**
 	 * Control the formatter for JavaScript (and its super languages) files.
 	 */
@@ -493,6 +551,18 @@ export interface PartialJavascriptFormatter {
 	 * What's the max width of a line applied to JavaScript (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	* Whether to format the markup of the Svelte and Astro files, and not only their scripts. Defaults to false.
+
+This option is experimental: the formatting it produces may change in future versions. 
+	 */
+	markupFormatting?: boolean;
+	/**
+	* The maximum number of specifiers of an import or an export that are printed on a single line.
+
+The specifiers are printed one per line when there are more, even if they fit in the line width. 
+	 */
+	maxInlineSpecifiers?: number;
 	/**
 	 * When properties in objects are quoted. Defaults to asNeeded.
 	 */
@@ -505,6 +575,12 @@ export interface PartialJavascriptFormatter {
 	 * Whether the formatter prints semicolons for all statements or only in for statements where it is necessary because of ASI.
 	 */
 	semicolons?: Semicolons;
+	/**
+	* Whether to remove the unnecessary escapes of the string literals, such as `\d`. Defaults to "asNeeded".
+
+With "preserve", the escapes are kept as written. With "normalize", the hexadecimal digits of unicode escapes, such as `\u00e9`, are also written in uppercase. 
+	 */
+	stringEscapes?: StringEscapes;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
 	 */
@@ -513,6 +589,12 @@ export interface PartialJavascriptFormatter {
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
 	 */
 	trailingCommas?: TrailingCommas;
+	/**
+	* Whether to vertically align the values of consecutive assignments and object properties. Defaults to false.
+
+This option is experimental: the formatting it produces may change in future versions. 
+	 */
+	verticalAlignment?: boolean;
 }
 /**
  * Indicates the type of runtime or transformation used for interpreting JSX.
@@ -553,6 +635,10 @@ export interface PartialJsonFormatter {
 	 * Control the formatter for JSON (and its super languages) files.
 	 */
 	enabled?: boolean;
+	/**
+	 * The case of the exponent of the numbers: `1e10` or `1E10`. Defaults to "lowercase".
+	 */
+	exponentCase?: ExponentCase;
 	/**
 	 * The size of the indentation applied to JSON (and its super languages) files. Default to 2.
 	 */
@@ -565,6 +651,10 @@ export interface PartialJsonFormatter {
 	 * The size of the indentation applied to JSON (and its super languages) files. Default to 2.
 	 */
 	indentWidth?: IndentWidth;
+	/**
+	 * The maximum width of the arrays of primitive values (strings, numbers, booleans, and `null`) that are printed on a single line, even if they exceed the line width. Disabled by default.
+	 */
+	inlineArrayWidth?: LineWidth;
 	/**
 	 * The type of line ending applied to JSON (and its super languages) files.
 	 */
@@ -573,6 +663,10 @@ export interface PartialJsonFormatter {
 	 * What's the max width of a line applied to JSON (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * Whether to remove the `+` sign of the exponent of the numbers: `1e+10` becomes `1e10`. Defaults to true.
+	 */
+	stripExponentPlusSign?: boolean;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
 	 */
@@ -600,6 +694,27 @@ export interface PartialJsonParser {
 	 */
 	allowTrailingCommas?: boolean;
 }
+/**
+ * The custom ARIA roles and properties, such as the ones used by web components
+ */
+export interface AriaConfiguration {
+	/**
+	 * The custom `aria-*` properties, that are accepted with any value
+	 */
+	properties?: StringSet;
+	/**
+	* The custom roles, mapped to the names of the ARIA roles that they behave like.
+
+For example, `{ "x-toggle": "switch" }` allows `role="x-toggle"`, that has the same requirements as `role="switch"`. 
+	 */
+	roles?: Record<string, string>;
+}
+/**
+	* A named bundle of rule severities.
+
+The rules set by a preset are layered under the rules of the configuration, so every rule of a preset can still be configured individually. 
+	 */
+export type RulePreset = "recommended-strict" | "pedantic" | "formatting-only";
 export interface Rules {
 	a11y?: A11y;
 	/**
@@ -655,25 +770,93 @@ export interface OverridePattern {
 	 * Specific configuration for the Json language
 	 */
 	organizeImports?: OverrideOrganizeImportsConfiguration;
+	/**
+	 * Specific configuration for the TOML language
+	 */
+	toml?: PartialTomlConfiguration;
+}
+/**
+ * Options that changes how the TOML formatter behaves
+ */
+export interface PartialTomlFormatter {
+	/**
+	 * Whether to align the `=` of consecutive key-value pairs. Defaults to false.
+	 */
+	alignEntries?: boolean;
+	/**
+	 * Control the formatter for TOML files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The indent style applied to TOML files.
+	 */
+	indentStyle?: IndentStyle;
+	/**
+	 * The size of the indentation applied to TOML files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to TOML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to TOML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
 }
 export type VcsClientKind = "git";
 /**
  * A list of rules that belong to this group
  */
 export interface Source {
+	/**
+	 * Converts a function declaration or a function expression to an arrow function.
+	 */
+	useArrowFunctionSyntax?: RuleAssistConfiguration;
+	/**
+	 * Converts an arrow function to a function expression or a function declaration.
+	 */
+	useFunctionSyntax?: RuleAssistConfiguration;
+	/**
+	 * Generates a JSDoc comment for a function.
+	 */
+	useJsdocComment?: RuleAssistConfiguration;
+	/**
+	 * Sorts the attributes of a JSX element in natural order.
+	 */
+	useSortedAttributes?: RuleAssistConfiguration;
 	/**
 	 * Sorts the keys of a JSON object in natural order
 	 */
 	useSortedKeys?: RuleAssistConfiguration;
+	/**
+	 * Sorts the members of union types, interfaces, and object types in natural order.
+	 */
+	useSortedTypeMembers?: RuleAssistConfiguration;
+	/**
+	 * Converts a template literal to a string concatenation.
+	 */
+	useStringConcatenation?: RuleAssistConfiguration;
+	/**
+	 * Converts a numeric enum to a union of string literal types.
+	 */
+	useStringLiteralUnion?: RuleAssistConfiguration;
+	/**
+	 * Converts a string concatenation to a template literal.
+	 */
+	useTemplateLiteral?: RuleAssistConfiguration;
 }
 export type QuoteStyle = "double" | "single";
 export type ArrowParentheses = "always" | "asNeeded";
 export type QuoteProperties = "asNeeded" | "preserve";
 export type Semicolons = "always" | "asNeeded";
+export type StringEscapes = "asNeeded" | "preserve" | "normalize";
+export type EmbeddedLanguageFormatting = "off" | "auto";
 /**
  * Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
  */
 export type TrailingCommas = "all" | "es5" | "none";
+export type ExponentCase = "lowercase" | "uppercase" | "preserve";
 export type TrailingCommas2 = "none" | "all";
 /**
  * A list of rules that belong to this group
@@ -1044,7 +1227,7 @@ export interface Correctness {
 	/**
 	 * Disallow unreachable code
 	 */
-	noUnreachable?: RuleConfiguration_for_Null;
+	noUnreachable?: RuleConfiguration_for_NoUnreachableOptions;
 	/**
 	 * Ensures the super() constructor is called exactly once on every code  path in a class constructor before this is accessed if the class has a superclass
 	 */
@@ -1122,6 +1305,18 @@ export interface Nursery {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * Disallow await inside loops.
+	 */
+	noAwaitInLoop?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow the imports that go against the direction of the layers of the architecture.
+	 */
+	noCircularDomainDependencies?: RuleConfiguration_for_NoCircularDomainDependenciesOptions;
+	/**
+	 * Disallow blocks that only contain comments.
+	 */
+	noCommentOnlyBlock?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow the use of console.
 	 */
@@ -1130,6 +1325,10 @@ export interface Nursery {
 	 * Disallow using a callback in asynchronous tests and hooks.
 	 */
 	noDoneCallback?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow asserting a value to a type through unknown or any.
+	 */
+	noDoubleAssertion?: RuleConfiguration_for_NoDoubleAssertionOptions;
 	/**
 	 * Disallow duplicate @import rules.
 	 */
@@ -1142,10 +1341,18 @@ export interface Nursery {
 	 * Disallow duplicate names within font families.
 	 */
 	noDuplicateFontNames?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow re-exporting several modules that export the same name.
+	 */
+	noDuplicateImportsAcrossFiles?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow two keys with the same name inside a JSON object.
 	 */
 	noDuplicateJsonKeys?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the @layer statements that list a layer that is already declared.
+	 */
+	noDuplicateLayerNames?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow duplicate selectors within keyframe blocks.
 	 */
@@ -1158,6 +1365,10 @@ export interface Nursery {
 	 * Disallow accessing namespace imports dynamically.
 	 */
 	noDynamicNamespaceImportAccess?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow empty alternatives in regular expression literals.
+	 */
+	noEmptyAlternatives?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow CSS empty blocks.
 	 */
@@ -1166,14 +1377,26 @@ export interface Nursery {
 	 * Disallow variables from evolving into any type through reassignments.
 	 */
 	noEvolvingTypes?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow using !important more times than allowed in a stylesheet.
+	 */
+	noExcessiveImportant?: RuleConfiguration_for_NoExcessiveImportantOptions;
 	/**
 	 * Disallow exporting an imported variable.
 	 */
 	noExportedImports?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow spreading props onto JSX elements.
+	 */
+	noImplicitSpreadProps?: RuleConfiguration_for_NoImplicitSpreadPropsOptions;
 	/**
 	 * Disallow invalid !important within keyframe declarations
 	 */
 	noImportantInKeyframe?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the features that can't be used in the size queries of the @container rules.
+	 */
+	noInvalidContainerQueryFeature?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow non-standard direction values for linear gradient functions.
 	 */
@@ -1186,14 +1409,38 @@ export interface Nursery {
 	 * Disallows the use of irregular whitespace characters.
 	 */
 	noIrregularWhitespace?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the literal ids of a JSX tree that don't match: the labels that reference unknown ids, and the duplicate ids.
+	 */
+	noJsxLiteralsInAttributesNeedingIds?: RuleConfiguration_for_Null;
 	/**
 	 * Enforce that a label element or component has a text label and an associated input.
 	 */
 	noLabelWithoutControl?: RuleConfiguration_for_NoLabelWithoutControlOptions;
+	/**
+	 * Disallow the event listeners, intervals, and subscriptions of an effect that aren't removed by its cleanup function.
+	 */
+	noLeakedEventListeners?: RuleConfiguration_for_Null;
 	/**
 	 * Checks that the assertion function, for example expect, is placed inside an it() function call.
 	 */
 	noMisplacedAssertion?: RuleConfiguration_for_Null;
+	/**
+	 * Require a fallback in the var() functions of the layout properties.
+	 */
+	noMissingVarFunctionFallback?: RuleConfiguration_for_NoMissingVarFunctionFallbackOptions;
+	/**
+	 * Disallow the mutation of the props, the state, and the values memoized by useMemo in React components.
+	 */
+	noMutatingPropsOrState?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow non-null assertions after an optional chain.
+	 */
+	noNonNullAssertedOptionalChain?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow the use of the __proto__ property.
+	 */
+	noProto?: RuleFixConfiguration_for_Null;
 	/**
 	 * Prevents React-specific JSX properties from being used.
 	 */
@@ -1206,6 +1453,10 @@ export interface Nursery {
 	 * Disallow shorthand properties that override related longhand properties.
 	 */
 	noShorthandPropertyOverrides?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the module-level side effects in the packages that declare that they don't have any.
+	 */
+	noSideEffectImportsInLibraries?: RuleConfiguration_for_Null;
 	/**
 	 * Enforce that static, visible elements (such as \<div>) that have click handlers use the valid role attribute.
 	 */
@@ -1214,10 +1465,38 @@ export interface Nursery {
 	 * Enforce the use of String.slice() over String.substr() and String.substring().
 	 */
 	noSubstr?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow regular expressions that can backtrack exponentially.
+	 */
+	noSuperlinearBacktracking?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow catch clauses that silently discard the caught error.
+	 */
+	noSwallowedErrors?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the untranslated strings displayed to the users in JSX.
+	 */
+	noTranslationLiterals?: RuleConfiguration_for_NoTranslationLiteralsOptions;
 	/**
 	 * Disallow the use of dependencies that aren't specified in the package.json.
 	 */
 	noUndeclaredDependencies?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow passing async functions to APIs that ignore the promises returned by their callbacks.
+	 */
+	noUnhandledRejections?: RuleConfiguration_for_UnhandledRejectionsOptions;
+	/**
+	 * Disallow unknown attributes on the native elements.
+	 */
+	noUnknownAttribute?: RuleFixConfiguration_for_NoUnknownAttributeOptions;
+	/**
+	 * Disallow the @container rules that query a container name that is never declared.
+	 */
+	noUnknownContainerName?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow references to custom properties that are never declared.
+	 */
+	noUnknownCustomProperty?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown CSS value functions.
 	 */
@@ -1226,6 +1505,10 @@ export interface Nursery {
 	 * Disallow unknown media feature names.
 	 */
 	noUnknownMediaFeatureName?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow invalid values of the media features.
+	 */
+	noUnknownMediaFeatureValue?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown properties.
 	 */
@@ -1246,10 +1529,26 @@ export interface Nursery {
 	 * Disallow unmatchable An+B selectors.
 	 */
 	noUnmatchableAnbSelector?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow non-null assertions on the values that a condition already checks.
+	 */
+	noUnnecessaryNonNullAssertion?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow the accesses to the props of the Solid components that lose their reactivity.
+	 */
+	noUntrackedPropsAccess?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the calls of the Solid signals outside of the tracked scopes.
+	 */
+	noUntrackedSignalCalls?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unused function parameters.
 	 */
 	noUnusedFunctionParameters?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow reading the environment variables outside of the modules that validate them.
+	 */
+	noUnvalidatedEnvAccess?: RuleConfiguration_for_NoUnvalidatedEnvAccessOptions;
 	/**
 	 * Disallow unnecessary escape sequence in regular expression literals.
 	 */
@@ -1266,6 +1565,18 @@ export interface Nursery {
 	 * Disallow use of @value rule in css modules.
 	 */
 	noValueAtRule?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the duplicate keys across the props, the data, the computed properties, and the methods of the Vue components.
+	 */
+	noVueDuplicateKeys?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the reserved names as props of the Vue components.
+	 */
+	noVueReservedProps?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the side effects in the computed properties of Vue.
+	 */
+	noVueSideEffectsInComputed?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow the use of yoda expressions.
 	 */
@@ -1278,6 +1589,10 @@ export interface Nursery {
 	 * Disallow the use of overload signatures that are not next to each other.
 	 */
 	useAdjacentOverloadSignatures?: RuleConfiguration_for_Null;
+	/**
+	 * Use at() instead of computing an index from the length to access the last elements.
+	 */
+	useAtIndex?: RuleFixConfiguration_for_Null;
 	/**
 	 * Enforce the use of new for all builtins, except String, Number, Boolean, Symbol and BigInt.
 	 */
@@ -1285,11 +1600,27 @@ export interface Nursery {
 	/**
 	 * This rule enforces consistent use of curly braces inside JSX attributes and JSX children.
 	 */
-	useConsistentCurlyBraces?: RuleFixConfiguration_for_Null;
+	useConsistentCurlyBraces?: RuleFixConfiguration_for_ConsistentCurlyBracesOptions;
+	/**
+	 * Require consistently specifying the type arguments of a generic constructor either on the constructor or on the type annotation.
+	 */
+	useConsistentGenericConstructors?: RuleFixConfiguration_for_ConsistentGenericConstructorsOptions;
 	/**
 	 * Disallows invalid named grid areas in CSS Grid Layouts.
 	 */
 	useConsistentGridAreas?: RuleConfiguration_for_Null;
+	/**
+	 * Require the consistent use of the shorthand or the explicit syntax for the properties and the methods of the object literals.
+	 */
+	useConsistentObjectDefinitions?: RuleFixConfiguration_for_UseConsistentObjectDefinitionsOptions;
+	/**
+	 * Require consistently using either Record\<K, V> or index signatures.
+	 */
+	useConsistentRecordType?: RuleFixConfiguration_for_ConsistentRecordTypeOptions;
+	/**
+	 * Require checking the status of the responses of fetch() before reading their body.
+	 */
+	useConsistentResponseHandling?: RuleConfiguration_for_Null;
 	/**
 	 * Use Date.now() to get the number of milliseconds since the Unix Epoch.
 	 */
@@ -1302,10 +1633,18 @@ export interface Nursery {
 	 * Require specifying the reason argument when using @deprecated directive
 	 */
 	useDeprecatedReason?: RuleConfiguration_for_Null;
+	/**
+	 * Require the caught error to be attached as the cause of the errors thrown in a catch clause.
+	 */
+	useErrorCause?: RuleFixConfiguration_for_Null;
 	/**
 	 * Enforce passing a message value when creating a built-in error.
 	 */
 	useErrorMessage?: RuleConfiguration_for_Null;
+	/**
+	 * Require the switch statements over an enum or a union of literal types to handle all its members.
+	 */
+	useExhaustiveSwitchCases?: RuleFixConfiguration_for_Null;
 	/**
 	 * Enforce explicitly comparing the length, size, byteLength or byteOffset property of a value.
 	 */
@@ -1318,6 +1657,10 @@ export interface Nursery {
 	 * Disallow a missing generic family keyword within font families.
 	 */
 	useGenericFontNames?: RuleConfiguration_for_Null;
+	/**
+	 * Require for...in loops to filter the properties they iterate over.
+	 */
+	useGuardForIn?: RuleConfiguration_for_Null;
 	/**
 	 * Enforce file extensions for relative imports.
 	 */
@@ -1326,18 +1669,50 @@ export interface Nursery {
 	 * Disallows package private imports.
 	 */
 	useImportRestrictions?: RuleConfiguration_for_Null;
+	/**
+	 * Use includes() instead of comparing the result of indexOf() to check for the presence of a value.
+	 */
+	useIncludes?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce that the @param and @returns tags of JSDoc comments match their function.
+	 */
+	useJsDocParamsMatch?: RuleFixConfiguration_for_Null;
 	/**
 	 * Enforce using the digits argument with Number#toFixed().
 	 */
 	useNumberToFixedDigitsArgument?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce the grouping of the digits of the long numeric literals with numeric separators.
+	 */
+	useNumericSeparators?: RuleFixConfiguration_for_NumericSeparatorsOptions;
+	/**
+	 * Use the object spread syntax instead of Object.assign() to copy objects into a new object.
+	 */
+	useObjectSpread?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce the simplest form of the parts of regular expression literals.
+	 */
+	useOptimizedRegex?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce the reactive use of the signals of Preact.
+	 */
+	usePreactSignalsCorrectly?: RuleConfiguration_for_Null;
 	/**
 	 * It detects the use of role attributes in JSX elements and suggests using semantic elements instead.
 	 */
-	useSemanticElements?: RuleConfiguration_for_Null;
+	useSemanticElements?: RuleFixConfiguration_for_Null;
 	/**
 	 * Enforce the sorting of CSS utility classes.
 	 */
 	useSortedClasses?: RuleFixConfiguration_for_UtilityClassSortingOptions;
+	/**
+	 * Enforce sorted keys in the object literals of the configured contexts.
+	 */
+	useSortedObjectKeys?: RuleFixConfiguration_for_UseSortedObjectKeysOptions;
+	/**
+	 * Use the spread syntax instead of Array.prototype.slice.call() to convert a value to an array.
+	 */
+	useSpread?: RuleFixConfiguration_for_Null;
 	/**
 	 * Enforce the use of the directive "use strict" in script files.
 	 */
@@ -1349,7 +1724,7 @@ export interface Nursery {
 	/**
 	 * Disallow throwing non-Error values.
 	 */
-	useThrowOnlyError?: RuleConfiguration_for_Null;
+	useThrowOnlyError?: RuleFixConfiguration_for_Null;
 	/**
 	 * Require regex literals to be declared at the top level.
 	 */
@@ -1362,6 +1737,10 @@ export interface Nursery {
 	 * Use valid values for the autocomplete attribute on input elements.
 	 */
 	useValidAutocomplete?: RuleConfiguration_for_UseValidAutocompleteOptions;
+	/**
+	 * Ensure that the keys passed to the translation functions exist in the message catalogs.
+	 */
+	useValidI18nKeys?: RuleFixConfiguration_for_UseValidI18nKeysOptions;
 }
 /**
  * A list of rules that belong to this group
@@ -1386,7 +1765,7 @@ export interface Performance {
 	/**
 	 * Avoid re-export all.
 	 */
-	noReExportAll?: RuleConfiguration_for_Null;
+	noReExportAll?: RuleFixConfiguration_for_Null;
 	/**
 	 * It enables the recommended rules for this group
 	 */
@@ -1464,7 +1843,7 @@ export interface Style {
 	/**
 	 * Disallow reassigning function parameters.
 	 */
-	noParameterAssign?: RuleConfiguration_for_Null;
+	noParameterAssign?: RuleFixConfiguration_for_NoParameterAssignOptions;
 	/**
 	 * Disallow the use of parameter properties in class constructors.
 	 */
@@ -1721,7 +2100,7 @@ export interface Suspicious {
 	/**
 	 * Disallow focused tests.
 	 */
-	noFocusedTests?: RuleFixConfiguration_for_Null;
+	noFocusedTests?: RuleFixConfiguration_for_NoFocusedTestsOptions;
 	/**
 	 * Disallow reassigning function declarations.
 	 */
@@ -1765,7 +2144,7 @@ export interface Suspicious {
 	/**
 	 * Disallow direct use of Object.prototype builtins.
 	 */
-	noPrototypeBuiltins?: RuleConfiguration_for_Null;
+	noPrototypeBuiltins?: RuleFixConfiguration_for_Null;
 	/**
 	 * Disallow variable, function, class, and type redeclarations in the same scope.
 	 */
@@ -1785,7 +2164,7 @@ export interface Suspicious {
 	/**
 	 * Disallow disabled tests.
 	 */
-	noSkippedTests?: RuleFixConfiguration_for_Null;
+	noSkippedTests?: RuleFixConfiguration_for_NoSkippedTestsOptions;
 	/**
 	 * Disallow sparse arrays
 	 */
@@ -1869,12 +2248,20 @@ export interface OverrideFormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * The maximum number of syntax nodes of the files that are formatted. The files above this limit aren't formatted, and a diagnostic is emitted.
+	 */
+	maxComplexity?: number;
 }
 export interface OverrideLinterConfiguration {
 	/**
 	 * if `false`, it disables the feature and the linter won't be executed. `true` by default
 	 */
 	enabled?: boolean;
+	/**
+	 * A preset of rules, such as `"recommended-strict"`, `"pedantic"` or `"formatting-only"`. The rules of the preset are layered under `rules`.
+	 */
+	preset?: RulePreset;
 	/**
 	 * List of rules
 	 */
@@ -1902,27 +2289,81 @@ export type RuleFixConfiguration_for_ValidAriaRoleOptions =
 export type RuleConfiguration_for_ComplexityOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_ComplexityOptions;
+export type RuleConfiguration_for_NoUnreachableOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUnreachableOptions;
 export type RuleConfiguration_for_HooksOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_HooksOptions;
 export type RuleConfiguration_for_DeprecatedHooksOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_DeprecatedHooksOptions;
+export type RuleConfiguration_for_NoCircularDomainDependenciesOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoCircularDomainDependenciesOptions;
+export type RuleConfiguration_for_NoDoubleAssertionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoDoubleAssertionOptions;
+export type RuleConfiguration_for_NoExcessiveImportantOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoExcessiveImportantOptions;
+export type RuleConfiguration_for_NoImplicitSpreadPropsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoImplicitSpreadPropsOptions;
 export type RuleConfiguration_for_NoLabelWithoutControlOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_NoLabelWithoutControlOptions;
+export type RuleConfiguration_for_NoMissingVarFunctionFallbackOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoMissingVarFunctionFallbackOptions;
 export type RuleConfiguration_for_RestrictedImportsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedImportsOptions;
+export type RuleConfiguration_for_NoTranslationLiteralsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoTranslationLiteralsOptions;
+export type RuleConfiguration_for_UnhandledRejectionsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UnhandledRejectionsOptions;
+export type RuleFixConfiguration_for_NoUnknownAttributeOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_NoUnknownAttributeOptions;
+export type RuleConfiguration_for_NoUnvalidatedEnvAccessOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUnvalidatedEnvAccessOptions;
+export type RuleFixConfiguration_for_ConsistentCurlyBracesOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_ConsistentCurlyBracesOptions;
+export type RuleFixConfiguration_for_ConsistentGenericConstructorsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_ConsistentGenericConstructorsOptions;
+export type RuleFixConfiguration_for_UseConsistentObjectDefinitionsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseConsistentObjectDefinitionsOptions;
+export type RuleFixConfiguration_for_ConsistentRecordTypeOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_ConsistentRecordTypeOptions;
 export type RuleFixConfiguration_for_UseImportExtensionsOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_UseImportExtensionsOptions;
+export type RuleFixConfiguration_for_NumericSeparatorsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_NumericSeparatorsOptions;
 export type RuleFixConfiguration_for_UtilityClassSortingOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_UtilityClassSortingOptions;
+export type RuleFixConfiguration_for_UseSortedObjectKeysOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseSortedObjectKeysOptions;
 export type RuleConfiguration_for_UseValidAutocompleteOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UseValidAutocompleteOptions;
+export type RuleFixConfiguration_for_NoParameterAssignOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_NoParameterAssignOptions;
+export type RuleFixConfiguration_for_UseValidI18nKeysOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseValidI18nKeysOptions;
 export type RuleConfiguration_for_RestrictedGlobalsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedGlobalsOptions;
@@ -1935,12 +2376,26 @@ export type RuleConfiguration_for_FilenamingConventionOptions =
 export type RuleFixConfiguration_for_NamingConventionOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_NamingConventionOptions;
+export type RuleFixConfiguration_for_NoFocusedTestsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_NoFocusedTestsOptions;
+export type RuleFixConfiguration_for_NoSkippedTestsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_NoSkippedTestsOptions;
 export type RulePlainConfiguration = "warn" | "error" | "info" | "off";
 export interface RuleWithFixOptions_for_Null {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
 	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -1951,10 +2406,18 @@ export interface RuleWithFixOptions_for_Null {
 	options: null;
 }
 export interface RuleWithFixOptions_for_AllowDomainOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
 	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -1965,6 +2428,14 @@ export interface RuleWithFixOptions_for_AllowDomainOptions {
 	options: AllowDomainOptions;
 }
 export interface RuleWithOptions_for_Null {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -1975,10 +2446,18 @@ export interface RuleWithOptions_for_Null {
 	options: null;
 }
 export interface RuleWithFixOptions_for_ValidAriaRoleOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
 	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -1989,6 +2468,14 @@ export interface RuleWithFixOptions_for_ValidAriaRoleOptions {
 	options: ValidAriaRoleOptions;
 }
 export interface RuleWithOptions_for_ComplexityOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -1998,17 +2485,15 @@ export interface RuleWithOptions_for_ComplexityOptions {
 	 */
 	options: ComplexityOptions;
 }
-export interface RuleWithOptions_for_HooksOptions {
+export interface RuleWithOptions_for_NoUnreachableOptions {
 	/**
-	 * The severity of the emitted diagnostics by the rule
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
 	 */
-	level: RulePlainConfiguration;
+	excludes?: StringSet;
 	/**
-	 * Rule's options
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
 	 */
-	options: HooksOptions;
-}
-export interface RuleWithOptions_for_DeprecatedHooksOptions {
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2016,19 +2501,17 @@ export interface RuleWithOptions_for_DeprecatedHooksOptions {
 	/**
 	 * Rule's options
 	 */
-	options: DeprecatedHooksOptions;
+	options: NoUnreachableOptions;
 }
-export interface RuleWithOptions_for_NoLabelWithoutControlOptions {
+export interface RuleWithOptions_for_HooksOptions {
 	/**
-	 * The severity of the emitted diagnostics by the rule
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
 	 */
-	level: RulePlainConfiguration;
+	excludes?: StringSet;
 	/**
-	 * Rule's options
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
 	 */
-	options: NoLabelWithoutControlOptions;
-}
-export interface RuleWithOptions_for_RestrictedImportsOptions {
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2036,13 +2519,329 @@ export interface RuleWithOptions_for_RestrictedImportsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: RestrictedImportsOptions;
+	options: HooksOptions;
+}
+export interface RuleWithOptions_for_DeprecatedHooksOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: DeprecatedHooksOptions;
+}
+export interface RuleWithOptions_for_NoCircularDomainDependenciesOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoCircularDomainDependenciesOptions;
+}
+export interface RuleWithOptions_for_NoDoubleAssertionOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoDoubleAssertionOptions;
+}
+export interface RuleWithOptions_for_NoExcessiveImportantOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoExcessiveImportantOptions;
+}
+export interface RuleWithOptions_for_NoImplicitSpreadPropsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoImplicitSpreadPropsOptions;
+}
+export interface RuleWithOptions_for_NoLabelWithoutControlOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoLabelWithoutControlOptions;
+}
+export interface RuleWithOptions_for_NoMissingVarFunctionFallbackOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoMissingVarFunctionFallbackOptions;
+}
+export interface RuleWithOptions_for_RestrictedImportsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: RestrictedImportsOptions;
+}
+export interface RuleWithOptions_for_NoTranslationLiteralsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoTranslationLiteralsOptions;
+}
+export interface RuleWithOptions_for_UnhandledRejectionsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UnhandledRejectionsOptions;
+}
+export interface RuleWithFixOptions_for_NoUnknownAttributeOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnknownAttributeOptions;
+}
+export interface RuleWithOptions_for_NoUnvalidatedEnvAccessOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnvalidatedEnvAccessOptions;
+}
+export interface RuleWithFixOptions_for_ConsistentCurlyBracesOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ConsistentCurlyBracesOptions;
+}
+export interface RuleWithFixOptions_for_ConsistentGenericConstructorsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ConsistentGenericConstructorsOptions;
+}
+export interface RuleWithFixOptions_for_UseConsistentObjectDefinitionsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseConsistentObjectDefinitionsOptions;
+}
+export interface RuleWithFixOptions_for_ConsistentRecordTypeOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ConsistentRecordTypeOptions;
 }
 export interface RuleWithFixOptions_for_UseImportExtensionsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
 	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2052,11 +2851,41 @@ export interface RuleWithFixOptions_for_UseImportExtensionsOptions {
 	 */
 	options: UseImportExtensionsOptions;
 }
+export interface RuleWithFixOptions_for_NumericSeparatorsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NumericSeparatorsOptions;
+}
 export interface RuleWithFixOptions_for_UtilityClassSortingOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
 	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2066,7 +2895,37 @@ export interface RuleWithFixOptions_for_UtilityClassSortingOptions {
 	 */
 	options: UtilityClassSortingOptions;
 }
+export interface RuleWithFixOptions_for_UseSortedObjectKeysOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseSortedObjectKeysOptions;
+}
 export interface RuleWithOptions_for_UseValidAutocompleteOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2076,7 +2935,59 @@ export interface RuleWithOptions_for_UseValidAutocompleteOptions {
 	 */
 	options: UseValidAutocompleteOptions;
 }
+export interface RuleWithFixOptions_for_NoParameterAssignOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoParameterAssignOptions;
+}
+export interface RuleWithFixOptions_for_UseValidI18nKeysOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseValidI18nKeysOptions;
+}
 export interface RuleWithOptions_for_RestrictedGlobalsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2087,20 +2998,80 @@ export interface RuleWithOptions_for_RestrictedGlobalsOptions {
 	options: RestrictedGlobalsOptions;
 }
 export interface RuleWithFixOptions_for_ConsistentArrayTypeOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ConsistentArrayTypeOptions;
+}
+export interface RuleWithOptions_for_FilenamingConventionOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: FilenamingConventionOptions;
+}
+export interface RuleWithFixOptions_for_NamingConventionOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NamingConventionOptions;
+}
+export interface RuleWithFixOptions_for_NoFocusedTestsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
 	fix?: FixKind;
 	/**
-	 * The severity of the emitted diagnostics by the rule
-	 */
-	level: RulePlainConfiguration;
-	/**
-	 * Rule's options
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
 	 */
-	options: ConsistentArrayTypeOptions;
-}
-export interface RuleWithOptions_for_FilenamingConventionOptions {
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2108,13 +3079,21 @@ export interface RuleWithOptions_for_FilenamingConventionOptions {
 	/**
 	 * Rule's options
 	 */
-	options: FilenamingConventionOptions;
+	options: NoFocusedTestsOptions;
 }
-export interface RuleWithFixOptions_for_NamingConventionOptions {
+export interface RuleWithFixOptions_for_NoSkippedTestsOptions {
+	/**
+	 * A list of Unix shell style patterns. The rule doesn't run on the files that match these patterns.
+	 */
+	excludes?: StringSet;
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
 	fix?: FixKind;
+	/**
+	 * A list of Unix shell style patterns. The rule only runs on the files that match these patterns.
+	 */
+	includes?: StringSet;
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2122,7 +3101,7 @@ export interface RuleWithFixOptions_for_NamingConventionOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NamingConventionOptions;
+	options: NoSkippedTestsOptions;
 }
 /**
  * Used to identify the kind of code action emitted by a rule
@@ -2147,6 +3126,15 @@ export interface ComplexityOptions {
 	 */
 	maxAllowedComplexity: number;
 }
+/**
+ * Options for the rule `noUnreachable`.
+ */
+export interface NoUnreachableOptions {
+	/**
+	 * The functions that never return to their caller, such as `process.exit`.
+	 */
+	neverReturningFunctions: string[];
+}
 /**
  * Options for the rule `useExhaustiveDependencies`
  */
@@ -2160,6 +3148,39 @@ export interface HooksOptions {
  * Options for the `useHookAtTopLevel` rule have been deprecated, since we now use the React hook naming convention to determine whether a function is a hook.
  */
 export interface DeprecatedHooksOptions {}
+export interface NoCircularDomainDependenciesOptions {
+	/**
+	 * The layers of the architecture, from the top layer to the bottom layer. A layer can only depend on the layers after it.
+	 */
+	layers: Layer[];
+}
+/**
+ * Options for the rule `noDoubleAssertion`.
+ */
+export interface NoDoubleAssertionOptions {
+	/**
+	 * Allow the double assertions through `unknown`, such as `value as unknown as T`.
+	 */
+	allowUnknown?: boolean;
+}
+/**
+ * Options for the rule `noExcessiveImportant`.
+ */
+export interface NoExcessiveImportantOptions {
+	/**
+	 * The number of `!important` allowed in a stylesheet.
+	 */
+	maxImportant: number;
+}
+/**
+ * Options for the rule `noImplicitSpreadProps`.
+ */
+export interface NoImplicitSpreadPropsOptions {
+	/**
+	 * Allow spreading props onto custom components, such as `<Button {...props} />`.
+	 */
+	ignoreComponents?: boolean;
+}
 export interface NoLabelWithoutControlOptions {
 	/**
 	 * Array of component names that should be considered the same as an `input` element.
@@ -2174,6 +3195,15 @@ export interface NoLabelWithoutControlOptions {
 	 */
 	labelComponents: string[];
 }
+/**
+ * Options for the rule `noMissingVarFunctionFallback`.
+ */
+export interface NoMissingVarFunctionFallbackOptions {
+	/**
+	 * The properties whose `var()` functions must have a fallback.
+	 */
+	properties: string[];
+}
 /**
  * Options for the rule `noRestrictedImports`.
  */
@@ -2181,13 +3211,108 @@ export interface RestrictedImportsOptions {
 	/**
 	 * A list of names that should trigger the rule
 	 */
-	paths: {};
+	paths: Record<string, string>;
+}
+/**
+ * Options for the rule `noTranslationLiterals`.
+ */
+export interface NoTranslationLiteralsOptions {
+	/**
+	 * The strings that don't need a translation, such as the name of the product.
+	 */
+	allowedStrings?: string[];
+	/**
+	 * The attributes whose values are displayed to the users.
+	 */
+	attributes?: string[];
+	/**
+	 * The strings with fewer characters than this length aren't reported.
+	 */
+	minLength?: number;
+}
+/**
+ * Options for the rule `noUnhandledRejections`.
+ */
+export interface UnhandledRejectionsOptions {
+	/**
+	 * Names of the functions that handle the rejections of the async callbacks they wrap.
+	 */
+	safeWrappers: string[];
+}
+export interface NoUnknownAttributeOptions {
+	/**
+	 * The framework that renders the elements.
+	 */
+	framework: JsxFramework;
+	/**
+	 * The names of the attributes that are accepted.
+	 */
+	ignore: string[];
+}
+export interface NoUnvalidatedEnvAccessOptions {
+	/**
+	 * The names of the environment variables that can be read in all the modules.
+	 */
+	allow?: string[];
+	/**
+	 * The globs of the paths of the modules that can read the environment variables.
+	 */
+	validationModules?: string[];
+}
+/**
+ * Options for the rule `useConsistentCurlyBraces`.
+ */
+export interface ConsistentCurlyBracesOptions {
+	/**
+	 * The style of the text of the JSX children
+	 */
+	children?: CurlyBracesPresence;
+	/**
+	 * The style of the string values of the JSX attributes
+	 */
+	props?: CurlyBracesPresence;
+}
+export interface ConsistentGenericConstructorsOptions {
+	style: ConsistentGenericConstructorsStyle;
+}
+/**
+ * Options for the rule `useConsistentObjectDefinitions`.
+ */
+export interface UseConsistentObjectDefinitionsOptions {
+	/**
+	 * The syntax of the properties and the methods of the object literals
+	 */
+	syntax: ObjectPropertySyntax;
+}
+export interface ConsistentRecordTypeOptions {
+	syntax: ConsistentRecordType;
 }
 export interface UseImportExtensionsOptions {
 	/**
 	 * A map of custom import extension mappings, where the key is the inspected file extension, and the value is a pair of `module` extension and `component` import extension
 	 */
-	suggestedExtensions: {};
+	suggestedExtensions: Record<string, SuggestedExtensionMapping>;
+}
+/**
+ * Options for the rule `useNumericSeparators`.
+ */
+export interface NumericSeparatorsOptions {
+	/**
+	 * The style of the binary numbers, such as `0b1010_0001`
+	 */
+	binary?: NumericSeparatorsStyle;
+	/**
+	 * The style of the hexadecimal numbers, such as `0xFF_FF`
+	 */
+	hexadecimal?: NumericSeparatorsStyle;
+	/**
+	 * The style of the decimal numbers
+	 */
+	number?: NumericSeparatorsStyle;
+	/**
+	 * The style of the octal numbers, such as `0o7777_7777`
+	 */
+	octal?: NumericSeparatorsStyle;
 }
 export interface UtilityClassSortingOptions {
 	/**
@@ -2199,12 +3324,34 @@ export interface UtilityClassSortingOptions {
 	 */
 	functions?: string[];
 }
+export interface UseSortedObjectKeysOptions {
+	/**
+	 * The regular expressions that select the names of the checked objects. All the objects are checked when it's empty.
+	 */
+	identifiers: Regex[];
+}
 export interface UseValidAutocompleteOptions {
 	/**
 	 * `input` like custom components that should be checked.
 	 */
 	inputComponents: string[];
 }
+export interface NoParameterAssignOptions {
+	/**
+	 * Whether to report the modifications of the properties of the parameters.
+	 */
+	props?: boolean;
+}
+export interface UseValidI18nKeysOptions {
+	/**
+	 * The paths of the JSON message catalogs, such as `locales/en.json`.
+	 */
+	catalogs?: string[];
+	/**
+	 * The translation functions, such as `t` or `i18n.t`. Their first argument is the key of the message.
+	 */
+	functions?: string[];
+}
 /**
  * Options for the rule `noRestrictedGlobals`.
  */
@@ -2221,6 +3368,10 @@ export interface ConsistentArrayTypeOptions {
  * Rule's options.
  */
 export interface FilenamingConventionOptions {
+	/**
+	 * Allowed cases for the names of the files that match some globs. A file uses the first convention that matches its path.
+	 */
+	conventions: FilenamingConvention[];
 	/**
 	 * Allowed cases for file names.
 	 */
@@ -2255,6 +3406,32 @@ export interface NamingConventionOptions {
 	 */
 	strictCase: boolean;
 }
+/**
+ * Options for the rule `noFocusedTests`.
+ */
+export interface NoFocusedTestsOptions {
+	/**
+	 * Additional functions that declare a focused test, such as `fscenario` or `scenario.only`.
+	 */
+	focusedFunctions: string[];
+	/**
+	 * Additional functions that declare a test or a test suite, such as `testCase` or `feature.scenario`.
+	 */
+	testFunctions: string[];
+}
+/**
+ * Options for the rule `noSkippedTests`.
+ */
+export interface NoSkippedTestsOptions {
+	/**
+	 * Additional functions that declare a disabled test, such as `xscenario` or `scenario.skip`.
+	 */
+	skippedFunctions: string[];
+	/**
+	 * Additional functions that declare a test or a test suite, such as `testCase` or `feature.scenario`.
+	 */
+	testFunctions: string[];
+}
 export interface Hook {
 	/**
 	* The "position" of the closure function, starting from zero.
@@ -2281,9 +3458,62 @@ For example, for React's `useRef()` hook the value would be `true`, while for `u
 	 */
 	stableResult: StableHookResult;
 }
+export interface Layer {
+	/**
+	 * The globs of the paths of the modules of the layer.
+	 */
+	include: string[];
+	/**
+	 * The name of the layer, displayed in the diagnostics.
+	 */
+	name: string;
+}
+export type JsxFramework = "react" | "preact" | "solid";
+export type CurlyBracesPresence = "always" | "never" | "ignore";
+export type ConsistentGenericConstructorsStyle = "constructor" | "typeAnnotation";
+export type ObjectPropertySyntax = "shorthand" | "explicit";
+export type ConsistentRecordType = "record" | "indexSignature";
+export interface NumericSeparatorsStyle {
+	/**
+	 * The count of digits of each group. Defaults to 3 for the decimal numbers, 2 for the hexadecimal numbers, and 4 for the binary and octal numbers.
+	 */
+	groupLength?: number;
+	/**
+	 * The minimum count of digits of a part of the number to group its digits. Defaults to 5 for the decimal numbers, and 0 for the other bases.
+	 */
+	minimumDigits?: number;
+}
+export interface SuggestedExtensionMapping {
+	/**
+	 * Extension that should be used for component file imports
+	 */
+	component: string;
+	/**
+	 * Extension that should be used for module imports
+	 */
+	module: string;
+}
+export type Regex = string;
 export type ConsistentArrayType = "shorthand" | "generic";
 export type FilenameCases = FilenameCase[];
+/**
+ * Allowed cases for the names of the files that match some globs.
+ */
+export interface FilenamingConvention {
+	/**
+	 * Allowed cases for the names of these files.
+	 */
+	filenameCases: FilenameCases;
+	/**
+	 * The globs of the paths of the files that use this convention.
+	 */
+	include: string[];
+}
 export interface Convention {
+	/**
+	 * Regular expression that the name of a declaration must match for the convention to apply
+	 */
+	filter?: Regex;
 	/**
 	 * String cases to enforce
 	 */
@@ -2316,7 +3546,6 @@ export type FilenameCase =
 	| "PascalCase"
 	| "snake_case";
 export type Formats = Format[];
-export type Regex = string;
 export interface Selector {
 	/**
 	 * Declaration kind
@@ -2402,7 +3631,9 @@ export type DocumentFileSource =
 	| { Js: JsFileSource }
 	| { Json: JsonFileSource }
 	| { Css: CssFileSource }
-	| { Graphql: GraphqlFileSource };
+	| { Graphql: GraphqlFileSource }
+	| { Toml: TomlFileSource }
+	| { Html: HtmlFileSource };
 export interface JsFileSource {
 	/**
 	 * Used to mark if the source is being used for an Astro, Svelte or Vue file
@@ -2423,7 +3654,13 @@ export interface CssFileSource {
 export interface GraphqlFileSource {
 	variant: GraphqlVariant;
 }
-export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "None";
+export interface TomlFileSource {
+	variant: TomlVariant;
+}
+export interface HtmlFileSource {
+	variant: HtmlVariant;
+}
+export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "Mdx" | "None";
 export type Language =
 	| "JavaScript"
 	| { TypeScript: { definition_file: boolean } };
@@ -2448,6 +3685,14 @@ export type CssVariant = "Standard";
  * The style of GraphQL contained in the file.
  */
 export type GraphqlVariant = "Standard";
+/**
+ * The flavour of TOML contained in the file.
+ */
+export type TomlVariant = "Standard";
+/**
+ * The flavour of HTML contained in the file.
+ */
+export type HtmlVariant = "Standard" | "Astro";
 export interface ChangeFileParams {
 	content: string;
 	path: BiomePath;
@@ -2485,14 +3730,30 @@ export interface PullDiagnosticsParams {
 	max_diagnostics: number;
 	only: RuleCode[];
 	path: BiomePath;
+	/**
+	 * When `true`, the time spent running each rule on the file is returned in [PullDiagnosticsResult::rule_timings]
+	 */
+	profile: boolean;
 	skip: RuleCode[];
+	/**
+	 * When set, the analysis of the file is stopped after this duration, and a diagnostic is emitted instead
+	 */
+	timeout?: Duration;
 }
 export type RuleCategories = RuleCategory[];
 export type RuleCode = string;
+export interface Duration {
+	nanos: number;
+	secs: number;
+}
 export type RuleCategory = "Syntax" | "Lint" | "Action" | "Transformation";
 export interface PullDiagnosticsResult {
 	diagnostics: Diagnostic[];
 	errors: number;
+	/**
+	 * The time spent running each rule on the file, when [PullDiagnosticsParams::profile] is `true`
+	 */
+	rule_timings: RuleTiming[];
 	skipped_diagnostics: number;
 }
 /**
@@ -2617,69 +3878,118 @@ export type Category =
 	| "lint/correctness/useValidForDirection"
 	| "lint/correctness/useYield"
 	| "lint/nursery/colorNoInvalidHex"
+	| "lint/nursery/noAwaitInLoop"
+	| "lint/nursery/noCircularDomainDependencies"
 	| "lint/nursery/noColorInvalidHex"
+	| "lint/nursery/noCommentOnlyBlock"
 	| "lint/nursery/noConsole"
 	| "lint/nursery/noDoneCallback"
+	| "lint/nursery/noDoubleAssertion"
 	| "lint/nursery/noDuplicateAtImportRules"
 	| "lint/nursery/noDuplicateElseIf"
 	| "lint/nursery/noDuplicateFontNames"
+	| "lint/nursery/noDuplicateImportsAcrossFiles"
 	| "lint/nursery/noDuplicateJsonKeys"
+	| "lint/nursery/noDuplicateLayerNames"
 	| "lint/nursery/noDuplicateSelectorsKeyframeBlock"
 	| "lint/nursery/noDuplicatedFields"
 	| "lint/nursery/noDynamicNamespaceImportAccess"
+	| "lint/nursery/noEmptyAlternatives"
 	| "lint/nursery/noEmptyBlock"
 	| "lint/nursery/noEvolvingTypes"
+	| "lint/nursery/noExcessiveImportant"
 	| "lint/nursery/noExportedImports"
+	| "lint/nursery/noImplicitSpreadProps"
 	| "lint/nursery/noImportantInKeyframe"
+	| "lint/nursery/noInvalidContainerQueryFeature"
 	| "lint/nursery/noInvalidDirectionInLinearGradient"
 	| "lint/nursery/noInvalidPositionAtImportRule"
 	| "lint/nursery/noIrregularWhitespace"
+	| "lint/nursery/noJsxLiteralsInAttributesNeedingIds"
 	| "lint/nursery/noLabelWithoutControl"
+	| "lint/nursery/noLeakedEventListeners"
 	| "lint/nursery/noMisplacedAssertion"
+	| "lint/nursery/noMissingVarFunctionFallback"
 	| "lint/nursery/noMissingGenericFamilyKeyword"
+	| "lint/nursery/noMutatingPropsOrState"
+	| "lint/nursery/noNonNullAssertedOptionalChain"
+	| "lint/nursery/noProto"
 	| "lint/nursery/noReactSpecificProps"
 	| "lint/nursery/noRestrictedImports"
 	| "lint/nursery/noShorthandPropertyOverrides"
+	| "lint/nursery/noSideEffectImportsInLibraries"
 	| "lint/nursery/noStaticElementInteractions"
 	| "lint/nursery/noSubstr"
+	| "lint/nursery/noSuperlinearBacktracking"
+	| "lint/nursery/noSwallowedErrors"
+	| "lint/nursery/noTranslationLiterals"
 	| "lint/nursery/noUndeclaredDependencies"
+	| "lint/nursery/noUnhandledRejections"
+	| "lint/nursery/noUnknownAttribute"
+	| "lint/nursery/noUnknownContainerName"
+	| "lint/nursery/noUnknownCustomProperty"
 	| "lint/nursery/noUnknownFunction"
 	| "lint/nursery/noUnknownMediaFeatureName"
+	| "lint/nursery/noUnknownMediaFeatureValue"
 	| "lint/nursery/noUnknownProperty"
 	| "lint/nursery/noUnknownPseudoClassSelector"
 	| "lint/nursery/noUnknownSelectorPseudoElement"
 	| "lint/nursery/noUnknownUnit"
 	| "lint/nursery/noUnmatchableAnbSelector"
+	| "lint/nursery/noUnnecessaryNonNullAssertion"
+	| "lint/nursery/noUntrackedPropsAccess"
+	| "lint/nursery/noUntrackedSignalCalls"
 	| "lint/nursery/noUnusedFunctionParameters"
+	| "lint/nursery/noUnvalidatedEnvAccess"
 	| "lint/nursery/noUselessEscapeInRegex"
 	| "lint/nursery/noUselessStringConcat"
 	| "lint/nursery/noUselessUndefinedInitialization"
 	| "lint/nursery/noValueAtRule"
+	| "lint/nursery/noVueDuplicateKeys"
+	| "lint/nursery/noVueReservedProps"
+	| "lint/nursery/noVueSideEffectsInComputed"
 	| "lint/nursery/noYodaExpression"
 	| "lint/nursery/useAdjacentOverloadSignatures"
+	| "lint/nursery/useAtIndex"
 	| "lint/nursery/useBiomeSuppressionComment"
 	| "lint/nursery/useConsistentBuiltinInstantiation"
 	| "lint/nursery/useConsistentCurlyBraces"
+	| "lint/nursery/useConsistentGenericConstructors"
 	| "lint/nursery/useConsistentGridAreas"
+	| "lint/nursery/useConsistentObjectDefinitions"
+	| "lint/nursery/useConsistentRecordType"
+	| "lint/nursery/useConsistentResponseHandling"
 	| "lint/nursery/useDateNow"
 	| "lint/nursery/useDefaultSwitchClause"
 	| "lint/nursery/useDeprecatedReason"
+	| "lint/nursery/useErrorCause"
 	| "lint/nursery/useErrorMessage"
+	| "lint/nursery/useExhaustiveSwitchCases"
 	| "lint/nursery/useExplicitLengthCheck"
 	| "lint/nursery/useFocusableInteractive"
 	| "lint/nursery/useGenericFontNames"
+	| "lint/nursery/useGuardForIn"
 	| "lint/nursery/useImportExtensions"
 	| "lint/nursery/useImportRestrictions"
+	| "lint/nursery/useIncludes"
+	| "lint/nursery/useJsDocParamsMatch"
 	| "lint/nursery/useJsxCurlyBraceConvention"
 	| "lint/nursery/useNumberToFixedDigitsArgument"
+	| "lint/nursery/useNumericSeparators"
+	| "lint/nursery/useObjectSpread"
+	| "lint/nursery/useOptimizedRegex"
+	| "lint/nursery/usePreactSignalsCorrectly"
 	| "lint/nursery/useSemanticElements"
 	| "lint/nursery/useSortedClasses"
+	| "lint/nursery/useSortedObjectKeys"
+	| "lint/nursery/useSpread"
 	| "lint/nursery/useStrictMode"
 	| "lint/nursery/useThrowNewError"
 	| "lint/nursery/useThrowOnlyError"
 	| "lint/nursery/useTopLevelRegex"
 	| "lint/nursery/useTrimStartEnd"
 	| "lint/nursery/useValidAutocomplete"
+	| "lint/nursery/useValidI18nKeys"
 	| "lint/performance/noAccumulatingSpread"
 	| "lint/performance/noBarrelFile"
 	| "lint/performance/noDelete"
@@ -2785,7 +4095,15 @@ export type Category =
 	| "lint/suspicious/useIsArray"
 	| "lint/suspicious/useNamespaceKeyword"
 	| "lint/suspicious/useValidTypeof"
+	| "assists/source/useArrowFunctionSyntax"
+	| "assists/source/useFunctionSyntax"
+	| "assists/source/useJsdocComment"
+	| "assists/source/useSortedAttributes"
 	| "assists/source/useSortedKeys"
+	| "assists/source/useSortedTypeMembers"
+	| "assists/source/useStringConcatenation"
+	| "assists/source/useStringLiteralUnion"
+	| "assists/source/useTemplateLiteral"
 	| "syntax/nursery/noTypeOnlyImportAttributes"
 	| "syntax/correctness/noSuperWithoutExtends"
 	| "syntax/correctness/noInitializerWithDefinite"
@@ -2801,9 +4119,11 @@ export type Category =
 	| "deserialize"
 	| "project"
 	| "search"
+	| "plugin"
 	| "internalError/io"
 	| "internalError/fs"
 	| "internalError/panic"
+	| "internalError/timeout"
 	| "parse"
 	| "lint"
 	| "lint/a11y"
@@ -2819,6 +4139,7 @@ export type Category =
 	| "suppressions/unknownRule"
 	| "suppressions/unused"
 	| "suppressions/deprecatedSuppressionComment"
+	| "suppressions/expired"
 	| "args/fileNotFound"
 	| "flags/invalid"
 	| "semanticTests";
@@ -2912,6 +4233,21 @@ export interface BacktraceSymbol {
 	lineno?: number;
 	name?: string;
 }
+/**
+ * The time spent running a rule on a file
+ */
+export interface RuleTiming {
+	/**
+	 * The number of nodes the rule was run on
+	 */
+	count: number;
+	group: string;
+	rule: string;
+	/**
+	 * The total time spent running the rule
+	 */
+	total: Duration;
+}
 export interface PullActionsParams {
 	only: RuleCode[];
 	path: BiomePath;
@@ -2923,6 +4259,10 @@ export interface PullActionsResult {
 }
 export interface CodeAction {
 	category: ActionCategory;
+	/**
+	 * The edits of the other files of the project, when the action spans several files
+	 */
+	file_edits?: FileEdit[];
 	rule_name?: [string, string];
 	suggestion: CodeSuggestion;
 }
@@ -2936,6 +4276,19 @@ export type ActionCategory =
 	| { Refactor: RefactorKind }
 	| { Source: SourceActionKind }
 	| { Other: string };
+/**
+ * An edit of a file other than the one analyzed, emitted by a code action that spans several files
+ */
+export interface FileEdit {
+	/**
+	 * The edit of the content of the file, relative to this content
+	 */
+	edit: TextEdit;
+	/**
+	 * The path of the edited file
+	 */
+	path: string;
+}
 /**
  * A Suggestion that is provided by Biome's linter, and can be reported to the user, and can be automatically applied if it has the right [`Applicability`].
  */
@@ -2991,6 +4344,40 @@ export interface SourceMarker {
 	 */
 	source: TextSize;
 }
+export interface VerifyFormatFileParams {
+	path: BiomePath;
+}
+export interface VerifyFormatFileResult {
+	/**
+	 * The content of the file after formatting it once
+	 */
+	formatted: string;
+	/**
+	 * The issue found while checking the stability of the formatted content, if any
+	 */
+	issue?: FormatStabilityIssue;
+}
+export type FormatStabilityIssue =
+	| { SyntaxErrors: { diagnostics: Diagnostic[] } }
+	| {
+			NotIdempotent: {
+				/**
+				 * The content of the file after formatting it twice
+				 */
+				reformatted: string;
+			};
+	  }
+	| { SyntaxTreeChanged: SyntaxTreeDifference };
+export interface SyntaxTreeDifference {
+	/**
+	 * The range of the first element of the formatted content that differs, or `None` if the formatted content ends before the original content
+	 */
+	formatted_range?: TextRange;
+	/**
+	 * The range of the first element of the original content that differs, or `None` if the original content ends before the formatted content
+	 */
+	original_range?: TextRange;
+}
 export interface FormatRangeParams {
 	path: BiomePath;
 	range: TextRange;
@@ -3006,6 +4393,10 @@ export interface FixFileParams {
 	rule_categories: RuleCategories;
 	should_format: boolean;
 	skip: RuleCode[];
+	/**
+	 * When set, the analysis of the file is stopped after this duration
+	 */
+	timeout?: Duration;
 }
 /**
  * Which fixes should be applied during the analyzing phase
@@ -3030,6 +4421,14 @@ export interface FixFileResult {
 	skipped_suggested_fixes: number;
 }
 export interface FixAction {
+	/**
+	 * Source code inserted by this action
+	 */
+	after: string;
+	/**
+	 * Source code replaced by this action
+	 */
+	before: string;
 	/**
 	 * Source range at which this action was applied
 	 */
@@ -3054,6 +4453,110 @@ export interface RenameResult {
 	 */
 	range: TextRange;
 }
+export interface DocumentHighlightsParams {
+	path: BiomePath;
+	symbol_at: TextSize;
+}
+export interface DocumentHighlightsResult {
+	/**
+	 * The declaration and the references of the symbol, in the order they appear in the file
+	 */
+	highlights: DocumentHighlight[];
+}
+export interface DocumentHighlight {
+	kind: DocumentHighlightKind;
+	range: TextRange;
+}
+export type DocumentHighlightKind = "Read" | "Write";
+export interface SelectionRangesParams {
+	path: BiomePath;
+	positions: TextSize[];
+}
+export interface SelectionRangesResult {
+	/**
+	 * For each position, the ranges of the syntax elements that contain it, from the innermost to the outermost
+	 */
+	ranges: TextRange[][];
+}
+export interface PrepareCallHierarchyParams {
+	path: BiomePath;
+	symbol_at: TextSize;
+}
+export interface PrepareCallHierarchyResult {
+	/**
+	 * The function at the position, if any
+	 */
+	items: CallHierarchyItem[];
+}
+/**
+ * A function, or a module for the calls made at its top level
+ */
+export interface CallHierarchyItem {
+	kind: CallHierarchyItemKind;
+	name: string;
+	path: BiomePath;
+	/**
+	 * The range of the whole declaration
+	 */
+	range: TextRange;
+	/**
+	 * The range of the name of the function
+	 */
+	selection_range: TextRange;
+}
+export type CallHierarchyItemKind = "Function" | "Module";
+export interface CallHierarchyCallsParams {
+	/**
+	 * An item returned by `prepare_call_hierarchy`, or by a previous request for the calls
+	 */
+	item: CallHierarchyItem;
+}
+export interface IncomingCallsResult {
+	calls: IncomingCall[];
+}
+export interface IncomingCall {
+	/**
+	 * The function that makes the calls
+	 */
+	from: CallHierarchyItem;
+	/**
+	 * The ranges of the callees of the calls, in the document of `from`
+	 */
+	from_ranges: TextRange[];
+}
+export interface OutgoingCallsResult {
+	calls: OutgoingCall[];
+}
+export interface OutgoingCall {
+	/**
+	 * The ranges of the callees of the calls, in the document of the item of the request
+	 */
+	from_ranges: TextRange[];
+	/**
+	 * The function that is called
+	 */
+	to: CallHierarchyItem;
+}
+export interface GenerateBarrelFileParams {
+	/**
+	 * The glob patterns of the file names of the modules that aren't re-exported
+	 */
+	exclude: string[];
+	/**
+	 * The modules of the directory of the barrel file. The modules that aren't open in the workspace are ignored.
+	 */
+	modules: BiomePath[];
+	/**
+	 * The path of the barrel file, such as `src/components/index.ts`
+	 */
+	path: BiomePath;
+}
+export interface GenerateBarrelFileResult {
+	/**
+	 * The new content of the barrel file
+	 */
+	content: string;
+}
 export type Configuration = PartialConfiguration;
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<SupportsFeatureResult>;
@@ -3078,10 +4581,27 @@ export interface Workspace {
 	): Promise<PullDiagnosticsResult>;
 	pullActions(params: PullActionsParams): Promise<PullActionsResult>;
 	formatFile(params: FormatFileParams): Promise<Printed>;
+	verifyFormatFile(
+		params: VerifyFormatFileParams,
+	): Promise<VerifyFormatFileResult>;
 	formatRange(params: FormatRangeParams): Promise<Printed>;
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	documentHighlights(
+		params: DocumentHighlightsParams,
+	): Promise<DocumentHighlightsResult>;
+	selectionRanges(
+		params: SelectionRangesParams,
+	): Promise<SelectionRangesResult>;
+	prepareCallHierarchy(
+		params: PrepareCallHierarchyParams,
+	): Promise<PrepareCallHierarchyResult>;
+	incomingCalls(params: CallHierarchyCallsParams): Promise<IncomingCallsResult>;
+	outgoingCalls(params: CallHierarchyCallsParams): Promise<OutgoingCallsResult>;
+	generateBarrelFile(
+		params: GenerateBarrelFileParams,
+	): Promise<GenerateBarrelFileResult>;
 	destroy(): void;
 }
 export function createWorkspace(transport: Transport): Workspace {
@@ -3134,6 +4654,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		formatFile(params) {
 			return transport.request("biome/format_file", params);
 		},
+		verifyFormatFile(params) {
+			return transport.request("biome/verify_format_file", params);
+		},
 		formatRange(params) {
 			return transport.request("biome/format_range", params);
 		},
@@ -3146,6 +4669,24 @@ export function createWorkspace(transport: Transport): Workspace {
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		documentHighlights(params) {
+			return transport.request("biome/document_highlights", params);
+		},
+		selectionRanges(params) {
+			return transport.request("biome/selection_ranges", params);
+		},
+		prepareCallHierarchy(params) {
+			return transport.request("biome/prepare_call_hierarchy", params);
+		},
+		incomingCalls(params) {
+			return transport.request("biome/incoming_calls", params);
+		},
+		outgoingCalls(params) {
+			return transport.request("biome/outgoing_calls", params);
+		},
+		generateBarrelFile(params) {
+			return transport.request("biome/generate_barrel_file", params);
+		},
 		destroy() {
 			transport.destroy();
 		},
@@ -195,6 +195,7 @@ impl Analyze {
                     &options,
                     JsFileSource::default(),
                     None,
+                    biome_js_analyze::ProjectModuleExports::default(),
                     |event| {
                         black_box(event.diagnostic());
                         black_box(event.actions());
@@ -211,7 +212,7 @@ impl Analyze {
                     ..AnalysisFilter::default()
                 };
                 let options = AnalyzerOptions::default();
-                biome_css_analyze::analyze(root, filter, &options, |event| {
+                biome_css_analyze::analyze(root, filter, &options, Default::default(), |event| {
                     black_box(event.diagnostic());
                     black_box(event.actions());
                     ControlFlow::<Never>::Continue(())
@@ -558,6 +558,7 @@ enum NodeDialect {
     Css,
     Grit,
     Graphql,
+    Toml,
 }
 
 impl NodeDialect {
@@ -570,6 +571,7 @@ impl NodeDialect {
             NodeDialect::Css,
             NodeDialect::Grit,
             NodeDialect::Graphql,
+            NodeDialect::Toml,
         ]
     }
 
@@ -586,6 +588,7 @@ impl NodeDialect {
             NodeDialect::Css => "css",
             NodeDialect::Grit => "grit",
             NodeDialect::Graphql => "graphql",
+            NodeDialect::Toml => "toml",
         }
     }
 
@@ -598,6 +601,7 @@ impl NodeDialect {
             "Css" => NodeDialect::Css,
             "Grit" => NodeDialect::Grit,
             "Graphql" => NodeDialect::Graphql,
+            "Toml" => NodeDialect::Toml,
             _ => {
                 eprintln!("missing prefix {name}");
                 NodeDialect::Js
@@ -641,6 +645,9 @@ enum NodeConcept {
     // GraphQL
     Definition,
     Extension,
+
+    // TOML
+    Key,
 }
 
 impl NodeConcept {
@@ -669,6 +676,7 @@ impl NodeConcept {
             NodeConcept::Predicate => "predicates",
             NodeConcept::Definition => "definitions",
             NodeConcept::Extension => "extensions",
+            NodeConcept::Key => "keys",
         }
     }
 }
@@ -821,6 +829,14 @@ fn get_node_concept(
 
             // TODO: implement formatter
             LanguageKind::Yaml => NodeConcept::Auxiliary,
+
+            LanguageKind::Toml => match name {
+                _ if name.ends_with("Key") => NodeConcept::Key,
+                "String" | "Number" | "Boolean" | "DateTime" | "Array" | "InlineTable" => {
+                    NodeConcept::Value
+                }
+                _ => NodeConcept::Auxiliary,
+            },
         }
     }
 }
@@ -886,6 +902,7 @@ impl LanguageKind {
             LanguageKind::Grit => "GritFormatter",
             LanguageKind::Html => "HtmlFormatter",
             LanguageKind::Yaml => "YamlFormatter",
+            LanguageKind::Toml => "TomlFormatter",
         };
 
         Ident::new(name, Span::call_site())
@@ -900,6 +917,7 @@ impl LanguageKind {
             LanguageKind::Grit => "GritFormatContext",
             LanguageKind::Html => "HtmlFormatContext",
             LanguageKind::Yaml => "YamlFormatContext",
+            LanguageKind::Toml => "TomlFormatContext",
         };
 
         Ident::new(name, Span::call_site())
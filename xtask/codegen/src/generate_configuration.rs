@@ -338,6 +338,37 @@ fn generate_for_groups(
                 Some(severity)
             }
 
+            /// Returns the glob patterns that restrict the files the rule runs on,
+            /// if the configuration of the rule sets them.
+            pub fn get_rule_files(&self, group: RuleGroup, rule_name: &str) -> Option<RuleFiles> {
+                match group {
+                    #(
+                        RuleGroup::#group_pascal_idents => self
+                            .#group_idents
+                            .as_ref()
+                            .and_then(|group| group.get_rule_files(rule_name)),
+                    )*
+                }
+            }
+
+            /// Returns the glob patterns of all the rules whose configuration sets them, with the rules.
+            pub fn all_rule_files(&self) -> Vec<(RuleFilter<'static>, RuleFiles)> {
+                let mut files = Vec::new();
+                #(
+                    if let Some(group) = self.#group_idents.as_ref() {
+                        files.extend(
+                            #group_pascal_idents::GROUP_RULES
+                                .iter()
+                                .filter_map(|rule_name| {
+                                    let rule_files = group.get_rule_files(rule_name)?;
+                                    Some((RuleFilter::Rule(#group_pascal_idents::GROUP_NAME, rule_name), rule_files))
+                                }),
+                        );
+                    }
+                )*
+                files
+            }
+
         }
     };
 
@@ -348,7 +379,7 @@ fn generate_for_groups(
         }
     } else {
         quote! {
-            use crate::analyzer::{RuleConfiguration, RulePlainConfiguration, RuleFixConfiguration};
+            use crate::analyzer::{RuleConfiguration, RuleFiles, RulePlainConfiguration, RuleFixConfiguration};
             use biome_analyze::{options::RuleOptions, RuleFilter};
         }
     };
@@ -529,6 +560,24 @@ fn generate_for_groups(
 
                 #severity_fn
 
+                /// Sets the level of the rules of `group`, or only of its recommended rules
+                /// if `only_recommended` is `true`. The options of the rules are preserved.
+                pub fn set_group_rules_level(
+                    &mut self,
+                    group: RuleGroup,
+                    level: RulePlainConfiguration,
+                    only_recommended: bool,
+                ) {
+                    match group {
+                        #(
+                            RuleGroup::#group_pascal_idents => self
+                                .#group_idents
+                                .get_or_insert_with(Default::default)
+                                .set_rules_level(level, only_recommended),
+                        )*
+                    }
+                }
+
                 /// Ensure that `recommended` is set to `true` or implied.
                 pub fn set_recommended(&mut self) {
                     if self.all != Some(true) && self.recommended == Some(false) {
@@ -649,6 +698,9 @@ fn generate_group_struct(
     let mut rule_enabled_check_line = Vec::new();
     let mut rule_disabled_check_line = Vec::new();
     let mut get_rule_configuration_line = Vec::new();
+    let mut get_rule_files_line = Vec::new();
+    let mut set_recommended_rule_level_line = Vec::new();
+    let mut set_rule_level_line = Vec::new();
 
     for (index, (rule, metadata)) in rules
         .iter()
@@ -776,6 +828,15 @@ fn generate_group_struct(
             }
         });
 
+        let set_level = quote! {
+            self.#rule_identifier.get_or_insert_with(Default::default).set_level(level);
+        };
+        if metadata.recommended {
+            set_recommended_rule_level_line.push(set_level);
+        } else {
+            set_rule_level_line.push(set_level);
+        }
+
         if kind == RuleCategory::Action {
             get_rule_configuration_line.push(quote! {
                 #rule => self.#rule_identifier.as_ref().copied()
@@ -784,6 +845,9 @@ fn generate_group_struct(
             get_rule_configuration_line.push(quote! {
                 #rule => self.#rule_identifier.as_ref().map(|conf| (conf.level(), conf.get_options()))
             });
+            get_rule_files_line.push(quote! {
+                #rule => self.#rule_identifier.as_ref().map(|conf| conf.files())
+            });
         }
     }
 
@@ -806,6 +870,13 @@ fn generate_group_struct(
                     _ => None
                 }
             }
+
+            pub(crate) fn get_rule_files(&self, rule_name: &str) -> Option<RuleFiles> {
+                match rule_name {
+                    #( #get_rule_files_line ),*,
+                    _ => None
+                }
+            }
         }
     };
 
@@ -936,6 +1007,15 @@ fn generate_group_struct(
                    index_set
                 }
 
+                /// Sets the level of the rules of this group, or only of its recommended rules
+                /// if `only_recommended` is `true`. The options of the rules are preserved.
+                pub(crate) fn set_rules_level(&mut self, level: RulePlainConfiguration, only_recommended: bool) {
+                    #( #set_recommended_rule_level_line )*
+                    if !only_recommended {
+                        #( #set_rule_level_line )*
+                    }
+                }
+
                 /// Checks if, given a rule name, matches one of the rules contained in this category
                 pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
                     Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
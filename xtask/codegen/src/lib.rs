@@ -15,6 +15,7 @@ mod graphql_kind_src;
 mod grit_kinds_src;
 mod js_kinds_src;
 mod json_kinds_src;
+mod toml_kinds_src;
 mod yaml_kinds_src;
 
 mod generate_crate;
@@ -91,6 +92,8 @@ pub enum TaskCommand {
     Configuration,
     #[bpaf(command)]
     MigrateEslint,
+    #[bpaf(command)]
+    MigrateStylelint,
     /// Generate the JSON schema for the Biome configuration file format
     #[bpaf(command)]
     Schema,